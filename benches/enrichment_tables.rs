@@ -242,6 +242,8 @@ fn benchmark_enrichment_tables_geoip(c: &mut Criterion) {
         Geoip::new(GeoipConfig {
             path: path.to_string(),
             locale: "en".to_string(),
+            download_url: None,
+            refresh_interval_secs: None,
         })
         .unwrap()
     };
@@ -39,10 +39,15 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_ignore_message",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
+                data_dir: None,
                 fields: Some(FieldMatchConfig::IgnoreFields(vec![String::from(
                     "message",
                 )])),
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: NonZeroUsize::new(4).unwrap(),
+                    ttl_secs: None,
+                    persistent: false,
+                },
             },
         },
         // Modification of previous where field "message" is matched.
@@ -50,8 +55,13 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_match_message",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
+                data_dir: None,
                 fields: Some(FieldMatchConfig::MatchFields(vec![String::from("message")])),
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: NonZeroUsize::new(4).unwrap(),
+                    ttl_secs: None,
+                    persistent: false,
+                },
             },
         },
         // Measurement where ignore fields do not exist in the event.
@@ -59,7 +69,12 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_ignore_done",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
-                cache: CacheConfig { num_events: 4 },
+                data_dir: None,
+                cache: CacheConfig {
+                    num_events: NonZeroUsize::new(4).unwrap(),
+                    ttl_secs: None,
+                    persistent: false,
+                },
                 fields: Some(FieldMatchConfig::IgnoreFields(vec![
                     String::from("abcde"),
                     String::from("eabcd"),
@@ -75,7 +90,12 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_match_done",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
-                cache: CacheConfig { num_events: 4 },
+                data_dir: None,
+                cache: CacheConfig {
+                    num_events: NonZeroUsize::new(4).unwrap(),
+                    ttl_secs: None,
+                    persistent: false,
+                },
                 fields: Some(FieldMatchConfig::MatchFields(vec![
                     String::from("abcde"),
                     String::from("eabcd"),
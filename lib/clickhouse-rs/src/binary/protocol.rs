@@ -0,0 +1,32 @@
+pub const DBMS_MIN_REVISION_WITH_TOTAL_ROWS_IN_PROGRESS: u64 = 51554;
+pub const DBMS_MIN_REVISION_WITH_SERVER_TIMEZONE: u64 = 54058;
+pub const DBMS_MIN_REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO: u64 = 54060;
+pub const DBMS_MIN_REVISION_WITH_SERVER_DISPLAY_NAME: u64 = 54372;
+pub const DBMS_MIN_REVISION_WITH_VERSION_PATCH: u64 = 54401;
+pub const _DBMS_MIN_REVISION_WITH_SERVER_LOGS: u64 = 54406;
+pub const DBMS_MIN_REVISION_WITH_CLIENT_WRITE_INFO: u64 = 54420;
+pub const DBMS_MIN_REVISION_WITH_SETTINGS_SERIALIZED_AS_STRINGS: u64 = 54429;
+
+pub const CLIENT_HELLO: u64 = 0;
+pub const CLIENT_QUERY: u64 = 1;
+pub const CLIENT_DATA: u64 = 2;
+pub const CLIENT_CANCEL: u64 = 3;
+pub const CLIENT_PING: u64 = 4;
+
+pub const COMPRESS_ENABLE: u64 = 1;
+pub const COMPRESS_DISABLE: u64 = 0;
+
+pub const STATE_COMPLETE: u64 = 2;
+
+pub const SERVER_HELLO: u64 = 0;
+pub const SERVER_DATA: u64 = 1;
+pub const SERVER_EXCEPTION: u64 = 2;
+pub const SERVER_PROGRESS: u64 = 3;
+pub const SERVER_PONG: u64 = 4;
+pub const SERVER_END_OF_STREAM: u64 = 5;
+pub const SERVER_PROFILE_INFO: u64 = 6;
+pub const SERVER_TOTALS: u64 = 7;
+pub const SERVER_EXTREMES: u64 = 8;
+pub const _SERVER_TABLES_STATUS_RESPONSE: u64 = 9;
+pub const _SERVER_LOG: u64 = 10;
+pub const SERVER_TABLE_COLUMNS: u64 = 11;
@@ -0,0 +1,214 @@
+//! Support for the [Confluent Schema Registry][schema_registry] shared by the Avro codecs.
+//!
+//! Messages in the Confluent wire format are prefixed by a magic byte, `0`, followed by the
+//! big-endian 32-bit id of the schema that the message was encoded with in the registry.
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+
+use std::{fmt::Debug, sync::Arc};
+
+use apache_avro::Schema;
+use bytes::BufMut;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::{config::proxy::ProxyConfig, tls::TlsConfig};
+
+/// The magic byte that messages in the Confluent wire format start with.
+pub const MAGIC_BYTE: u8 = 0;
+
+/// The length of the header of messages in the Confluent wire format.
+pub const HEADER_LEN: usize = 5;
+
+/// Writes the header of a message in the Confluent wire format.
+pub fn write_header<B: BufMut>(schema_id: u32, buffer: &mut B) {
+    buffer.put_u8(MAGIC_BYTE);
+    buffer.put_u32(schema_id);
+}
+
+/// Splits a message in the Confluent wire format into the id of its schema and its payload.
+pub fn split_header(message: &[u8]) -> vector_common::Result<(u32, &[u8])> {
+    if message.len() < HEADER_LEN {
+        return Err(format!(
+            "Message of {} bytes is too short for the schema registry wire format",
+            message.len()
+        )
+        .into());
+    }
+    if message[0] != MAGIC_BYTE {
+        return Err(format!(
+            "Unknown magic byte {} for the schema registry wire format",
+            message[0]
+        )
+        .into());
+    }
+
+    let schema_id = u32::from_be_bytes([message[1], message[2], message[3], message[4]]);
+    Ok((schema_id, &message[HEADER_LEN..]))
+}
+
+/// Resolves the schemas of a schema registry, by id.
+///
+/// Implementations are expected to cache the schemas, as they are resolved for every message.
+pub trait SchemaResolver: Debug + Send + Sync {
+    /// Returns the schema with the given id.
+    fn schema(&self, id: u32) -> vector_common::Result<Arc<Schema>>;
+}
+
+/// Options for the [Confluent Schema Registry][schema_registry].
+///
+/// [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaRegistryOptions {
+    /// The URL of the schema registry.
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    pub url: String,
+
+    /// The username for basic authentication to the schema registry.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_USERNAME}"))]
+    pub username: Option<String>,
+
+    /// The password for basic authentication to the schema registry.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_PASSWORD}"))]
+    pub password: Option<SensitiveString>,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    /// The proxy used to reach the schema registry.
+    ///
+    /// Proxies set in the environment are used unless overridden here.
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub proxy: ProxyConfig,
+
+    /// The strategy naming the subject that the schema is registered under, when encoding.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub subject_name_strategy: SubjectNameStrategy,
+
+    /// The topic that the subject is named after, when encoding.
+    ///
+    /// Required by the `topic_name` and `topic_record_name` strategies.
+    #[configurable(metadata(docs::examples = "logs"))]
+    pub topic: Option<String>,
+}
+
+/// The strategy naming the subject that a schema is registered under.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubjectNameStrategy {
+    /// The subject is named after the topic, as `<topic>-value`.
+    #[default]
+    TopicName,
+
+    /// The subject is named after the full name of the record of the schema.
+    RecordName,
+
+    /// The subject is named after both the topic and the full name of the record of the schema,
+    /// as `<topic>-<record>`.
+    TopicRecordName,
+}
+
+impl SchemaRegistryOptions {
+    /// Returns the name of the subject that the given schema is registered under.
+    pub fn subject(&self, schema: &Schema) -> vector_common::Result<String> {
+        let topic = || {
+            self.topic.as_deref().ok_or_else(|| {
+                format!(
+                    "The `{}` subject name strategy requires a `topic`",
+                    self.subject_name_strategy.as_str()
+                )
+            })
+        };
+        let record_name = || match schema {
+            Schema::Record { name, .. } => Ok(name.fullname(None)),
+            _ => Err(format!(
+                "The `{}` subject name strategy requires a record schema",
+                self.subject_name_strategy.as_str()
+            )),
+        };
+
+        Ok(match self.subject_name_strategy {
+            SubjectNameStrategy::TopicName => format!("{}-value", topic()?),
+            SubjectNameStrategy::RecordName => record_name()?,
+            SubjectNameStrategy::TopicRecordName => format!("{}-{}", topic()?, record_name()?),
+        })
+    }
+}
+
+impl SubjectNameStrategy {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::TopicName => "topic_name",
+            Self::RecordName => "record_name",
+            Self::TopicRecordName => "topic_record_name",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+
+    fn options(strategy: SubjectNameStrategy, topic: Option<&str>) -> SchemaRegistryOptions {
+        SchemaRegistryOptions {
+            url: "http://localhost:8081".to_owned(),
+            username: None,
+            password: None,
+            tls: None,
+            proxy: Default::default(),
+            subject_name_strategy: strategy,
+            topic: topic.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    fn header_round_trip() {
+        let mut buffer = BytesMut::new();
+        write_header(258, &mut buffer);
+        buffer.put_slice(b"payload");
+
+        assert_eq!(&buffer[..HEADER_LEN], &[0, 0, 0, 1, 2]);
+        assert_eq!(split_header(&buffer).unwrap(), (258, b"payload".as_slice()));
+        assert!(split_header(&[1, 0, 0, 1, 2]).is_err());
+        assert!(split_header(&[0, 0]).is_err());
+    }
+
+    #[test]
+    fn subject_names() {
+        let schema = Schema::parse_str(
+            r#"{ "type": "record", "name": "Log", "namespace": "com.example", "fields": [] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            options(SubjectNameStrategy::TopicName, Some("logs"))
+                .subject(&schema)
+                .unwrap(),
+            "logs-value"
+        );
+        assert_eq!(
+            options(SubjectNameStrategy::RecordName, None)
+                .subject(&schema)
+                .unwrap(),
+            "com.example.Log"
+        );
+        assert_eq!(
+            options(SubjectNameStrategy::TopicRecordName, Some("logs"))
+                .subject(&schema)
+                .unwrap(),
+            "logs-com.example.Log"
+        );
+        assert!(options(SubjectNameStrategy::TopicName, None)
+            .subject(&schema)
+            .is_err());
+    }
+}
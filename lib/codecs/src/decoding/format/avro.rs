@@ -0,0 +1,444 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use apache_avro::{types::Value as AvroValue, Duration as AvroDuration, Schema};
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use lookup::PathPrefix;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{Kind, Value};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::Deserializer;
+use crate::avro::{split_header, SchemaRegistryOptions, SchemaResolver};
+
+/// Config used to build an `AvroDeserializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AvroDeserializerConfig {
+    /// Options for the Avro deserializer.
+    pub avro: AvroDeserializerOptions,
+}
+
+/// Apache Avro-specific decoder options.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AvroDeserializerOptions {
+    /// The Avro schema of the messages.
+    ///
+    /// Required, unless a `schema_registry` is set, in which case the messages are decoded with
+    /// the schema they were encoded with, and then resolved to this schema if it's set.
+    #[configurable(metadata(
+        docs::examples = r#"{ "type": "record", "name": "log", "fields": [{ "name": "message", "type": "string" }] }"#
+    ))]
+    pub schema: Option<String>,
+
+    /// The schema registry that the schemas of the messages are fetched from.
+    ///
+    /// If set, messages are expected in the Confluent wire format, prefixed by the id of their
+    /// schema in the registry. Schemas are fetched once, and cached.
+    #[configurable(derived)]
+    pub schema_registry: Option<SchemaRegistryOptions>,
+}
+
+impl AvroDeserializerConfig {
+    /// Creates a new `AvroDeserializerConfig`.
+    pub const fn new(avro: AvroDeserializerOptions) -> Self {
+        Self { avro }
+    }
+
+    /// Build the `AvroDeserializer` from this configuration.
+    ///
+    /// Deserializers using a schema registry must be built with a resolver of the schemas of the
+    /// registry instead, using `build_with_resolver`.
+    pub fn build(&self) -> vector_common::Result<AvroDeserializer> {
+        if self.avro.schema_registry.is_some() {
+            return Err("Failed building Avro deserializer: the schema registry isn't supported by this component".into());
+        }
+        let schema = self.reader_schema()?.ok_or(
+            "Failed building Avro deserializer: a `schema` is required without a `schema_registry`",
+        )?;
+        Ok(AvroDeserializer {
+            writer_schema: WriterSchema::Static(Arc::new(schema)),
+            reader_schema: None,
+        })
+    }
+
+    /// Build the `AvroDeserializer` from this configuration, decoding messages in the Confluent
+    /// wire format with the schemas of the given resolver.
+    pub fn build_with_resolver(
+        &self,
+        resolver: Arc<dyn SchemaResolver>,
+    ) -> vector_common::Result<AvroDeserializer> {
+        Ok(AvroDeserializer {
+            writer_schema: WriterSchema::Registry(resolver),
+            reader_schema: self.reader_schema()?.map(Arc::new),
+        })
+    }
+
+    fn reader_schema(&self) -> vector_common::Result<Option<Schema>> {
+        self.avro
+            .schema
+            .as_deref()
+            .map(|schema| {
+                Schema::parse_str(schema)
+                    .map_err(|error| format!("Failed building Avro deserializer: {}", error).into())
+            })
+            .transpose()
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(Kind::any());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(
+                        timestamp_key,
+                        // Like the JSON decoder, the timestamp is only inserted if the message
+                        // doesn't already have the field.
+                        Kind::any(),
+                        Some("timestamp"),
+                    );
+                }
+                definition
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum WriterSchema {
+    Static(Arc<Schema>),
+    Registry(Arc<dyn SchemaResolver>),
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing an Apache Avro message.
+#[derive(Debug, Clone)]
+pub struct AvroDeserializer {
+    writer_schema: WriterSchema,
+    reader_schema: Option<Arc<Schema>>,
+}
+
+impl Deserializer for AvroDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let datum = match &self.writer_schema {
+            WriterSchema::Static(schema) => {
+                apache_avro::from_avro_datum(schema, &mut bytes.as_ref(), None)
+            }
+            WriterSchema::Registry(resolver) => {
+                let (schema_id, mut payload) = split_header(&bytes)?;
+                let schema = resolver.schema(schema_id)?;
+                apache_avro::from_avro_datum(&schema, &mut payload, self.reader_schema.as_deref())
+            }
+        }
+        .map_err(|error| format!("Error parsing Avro: {}", error))?;
+
+        let value = avro_to_value(datum)?;
+        let mut log = match (log_namespace, value) {
+            (LogNamespace::Vector, value) => LogEvent::from(value),
+            (LogNamespace::Legacy, Value::Object(map)) => LogEvent::from(map),
+            (LogNamespace::Legacy, _) => {
+                return Err("Avro messages must be records when using the legacy namespace".into())
+            }
+        };
+
+        if log_namespace == LogNamespace::Legacy {
+            if let Some(timestamp_key) = log_schema().timestamp_key() {
+                if !log.contains((PathPrefix::Event, timestamp_key)) {
+                    log.insert((PathPrefix::Event, timestamp_key), Utc::now());
+                }
+            }
+        }
+
+        Ok(smallvec![log.into()])
+    }
+}
+
+fn avro_to_value(value: AvroValue) -> vector_common::Result<Value> {
+    Ok(match value {
+        AvroValue::Null => Value::Null,
+        AvroValue::Boolean(value) => Value::Boolean(value),
+        AvroValue::Int(value) | AvroValue::Date(value) | AvroValue::TimeMillis(value) => {
+            Value::Integer(value.into())
+        }
+        AvroValue::Long(value) | AvroValue::TimeMicros(value) => Value::Integer(value),
+        AvroValue::Float(value) => float_to_value(value.into())?,
+        AvroValue::Double(value) => float_to_value(value)?,
+        AvroValue::Bytes(bytes) | AvroValue::Fixed(_, bytes) => Value::Bytes(bytes.into()),
+        AvroValue::String(string) | AvroValue::Enum(_, string) => Value::from(string),
+        AvroValue::Uuid(uuid) => Value::from(uuid.to_string()),
+        // The unscaled value, as the big-endian two's-complement bytes of the Avro encoding, as
+        // the scale is only known from the schema.
+        AvroValue::Decimal(decimal) => Value::Bytes(
+            Vec::<u8>::try_from(decimal)
+                .map_err(|error| format!("Invalid Avro decimal: {}", error))?
+                .into(),
+        ),
+        AvroValue::Duration(duration) => Value::from(duration_to_iso8601(&duration)),
+        AvroValue::TimestampMillis(millis) => Value::Timestamp(
+            Utc.timestamp_millis_opt(millis)
+                .single()
+                .ok_or_else(|| format!("Invalid Avro timestamp-millis {}", millis))?,
+        ),
+        AvroValue::TimestampMicros(micros) => Value::Timestamp(
+            Utc.timestamp_opt(
+                micros.div_euclid(1_000_000),
+                (micros.rem_euclid(1_000_000) * 1_000) as u32,
+            )
+            .single()
+            .ok_or_else(|| format!("Invalid Avro timestamp-micros {}", micros))?,
+        ),
+        AvroValue::Union(_, value) => avro_to_value(*value)?,
+        AvroValue::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(avro_to_value)
+                .collect::<vector_common::Result<_>>()?,
+        ),
+        AvroValue::Map(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| Ok((key, avro_to_value(value)?)))
+                .collect::<vector_common::Result<BTreeMap<_, _>>>()?,
+        ),
+        AvroValue::Record(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| Ok((key, avro_to_value(value)?)))
+                .collect::<vector_common::Result<BTreeMap<_, _>>>()?,
+        ),
+        value => return Err(format!("Unsupported Avro value {:?}", value).into()),
+    })
+}
+
+/// Formats an Avro duration as an ISO 8601 duration, such as `P1M2DT3.456S`.
+///
+/// The months, days and milliseconds of Avro durations are independent of each other, so they are
+/// kept apart rather than summed into a single length of time.
+fn duration_to_iso8601(duration: &AvroDuration) -> String {
+    let millis = u32::from(duration.millis());
+    format!(
+        "P{}M{}DT{}.{:03}S",
+        u32::from(duration.months()),
+        u32::from(duration.days()),
+        millis / 1_000,
+        millis % 1_000
+    )
+}
+
+fn float_to_value(value: f64) -> vector_common::Result<Value> {
+    NotNan::new(value)
+        .map(Value::Float)
+        .map_err(|_| "Avro float is NaN".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::{
+        avro::{write_header, SubjectNameStrategy},
+        encoding::{AvroSerializerConfig, AvroSerializerOptions},
+    };
+
+    const SCHEMA: &str = r#"
+        {
+            "type": "record",
+            "name": "Log",
+            "fields": [
+                { "name": "message", "type": "string" },
+                { "name": "status", "type": "long" },
+                { "name": "timestamp", "type": { "type": "long", "logicalType": "timestamp-millis" } }
+            ]
+        }
+    "#;
+
+    #[derive(Debug)]
+    struct StaticResolver(HashMap<u32, Arc<Schema>>);
+
+    impl SchemaResolver for StaticResolver {
+        fn schema(&self, id: u32) -> vector_common::Result<Arc<Schema>> {
+            self.0
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| format!("Unknown schema {}", id).into())
+        }
+    }
+
+    fn registry() -> SchemaRegistryOptions {
+        SchemaRegistryOptions {
+            url: "http://localhost:8081".to_owned(),
+            username: None,
+            password: None,
+            tls: None,
+            proxy: Default::default(),
+            subject_name_strategy: SubjectNameStrategy::TopicName,
+            topic: Some("logs".to_owned()),
+        }
+    }
+
+    fn encode(schema_id: Option<u32>) -> Bytes {
+        let schema = Schema::parse_str(SCHEMA).unwrap();
+        let datum = AvroValue::Record(vec![
+            ("message".to_owned(), AvroValue::String("hello".to_owned())),
+            ("status".to_owned(), AvroValue::Long(200)),
+            (
+                "timestamp".to_owned(),
+                AvroValue::TimestampMillis(1_680_000_000_123),
+            ),
+        ]);
+        let mut bytes = BytesMut::new();
+        if let Some(schema_id) = schema_id {
+            write_header(schema_id, &mut bytes);
+        }
+        bytes.extend_from_slice(&apache_avro::to_avro_datum(&schema, datum).unwrap());
+        bytes.freeze()
+    }
+
+    fn assert_log(events: SmallVec<[Event; 1]>) {
+        assert_eq!(events.len(), 1);
+        let log = events[0].as_log();
+        assert_eq!(log["message"], "hello".into());
+        assert_eq!(log["status"], 200.into());
+        assert_eq!(
+            log["timestamp"],
+            Utc.timestamp_millis_opt(1_680_000_000_123).unwrap().into()
+        );
+    }
+
+    #[test]
+    fn deserialize_avro() {
+        let deserializer = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: Some(SCHEMA.to_owned()),
+            schema_registry: None,
+        })
+        .build()
+        .unwrap();
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            assert_log(deserializer.parse(encode(None), namespace).unwrap());
+        }
+    }
+
+    #[test]
+    fn deserialize_avro_wire_format() {
+        let resolver = StaticResolver(HashMap::from([(
+            42,
+            Arc::new(Schema::parse_str(SCHEMA).unwrap()),
+        )]));
+        let config = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: None,
+            schema_registry: Some(registry()),
+        });
+        let deserializer = config.build_with_resolver(Arc::new(resolver)).unwrap();
+
+        assert_log(
+            deserializer
+                .parse(encode(Some(42)), LogNamespace::Legacy)
+                .unwrap(),
+        );
+        assert!(deserializer
+            .parse(encode(Some(43)), LogNamespace::Legacy)
+            .is_err());
+        assert!(deserializer
+            .parse(encode(None), LogNamespace::Legacy)
+            .is_err());
+    }
+
+    fn decode_field(field_type: &str, value: AvroValue) -> Value {
+        let schema = format!(
+            r#"{{ "type": "record", "name": "Log", "fields": [{{ "name": "field", "type": {} }}] }}"#,
+            field_type
+        );
+        let deserializer = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: Some(schema.clone()),
+            schema_registry: None,
+        })
+        .build()
+        .unwrap();
+        let datum = AvroValue::Record(vec![("field".to_owned(), value)]);
+        let bytes =
+            apache_avro::to_avro_datum(&Schema::parse_str(&schema).unwrap(), datum).unwrap();
+
+        let events = deserializer
+            .parse(bytes.into(), LogNamespace::Vector)
+            .unwrap();
+        events[0].as_log()["field"].clone()
+    }
+
+    #[test]
+    fn deserialize_decimal_bytes() {
+        let value = decode_field(
+            r#"{ "type": "bytes", "logicalType": "decimal", "precision": 6, "scale": 2 }"#,
+            AvroValue::Decimal(vec![0x30, 0x39].into()),
+        );
+        assert_eq!(value, Value::Bytes(Bytes::from_static(&[0x30, 0x39])));
+    }
+
+    #[test]
+    fn deserialize_decimal_fixed() {
+        let value = decode_field(
+            r#"{ "type": "fixed", "name": "amount", "size": 4, "logicalType": "decimal", "precision": 6, "scale": 2 }"#,
+            AvroValue::Decimal(vec![0xFF, 0xFF, 0xCF, 0xC7].into()),
+        );
+        assert_eq!(
+            value,
+            Value::Bytes(Bytes::from_static(&[0xFF, 0xFF, 0xCF, 0xC7]))
+        );
+    }
+
+    #[test]
+    fn deserialize_duration() {
+        let value = decode_field(
+            r#"{ "type": "fixed", "name": "elapsed", "size": 12, "logicalType": "duration" }"#,
+            AvroValue::Duration(AvroDuration::new(
+                apache_avro::Months::new(1),
+                apache_avro::Days::new(2),
+                apache_avro::Millis::new(3_045),
+            )),
+        );
+        assert_eq!(value, Value::from("P1M2DT3.045S"));
+    }
+
+    #[test]
+    fn requires_schema_or_registry() {
+        assert!(
+            AvroDeserializerConfig::new(AvroDeserializerOptions::default())
+                .build()
+                .is_err()
+        );
+
+        let options = AvroSerializerOptions {
+            schema: SCHEMA.to_owned(),
+            schema_registry: Some(registry()),
+        };
+        assert!(AvroSerializerConfig { avro: options }.build().is_err());
+    }
+}
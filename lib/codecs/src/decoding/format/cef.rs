@@ -0,0 +1,266 @@
+use bytes::Bytes;
+use chrono::Utc;
+use lookup::{event_path, PathPrefix};
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{kind::Collection, Kind};
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::Deserializer;
+
+/// The names of the fields that the header fields of a CEF message are decoded to, in order.
+const HEADER_FIELDS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// Config used to build a `CefDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CefDeserializerConfig;
+
+impl CefDeserializerConfig {
+    /// Build the `CefDeserializer` from this configuration.
+    pub const fn build(&self) -> CefDeserializer {
+        CefDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let definition = match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(Kind::bytes());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(
+                        timestamp_key,
+                        Kind::timestamp(),
+                        Some("timestamp"),
+                    );
+                }
+                definition
+            }
+            LogNamespace::Vector => schema::Definition::new_with_default_metadata(
+                Kind::object(Collection::empty().with_unknown(Kind::bytes())),
+                [log_namespace],
+            ),
+        };
+
+        HEADER_FIELDS.iter().fold(definition, |definition, field| {
+            definition.with_event_field(&lookup::owned_value_path!(*field), Kind::bytes(), None)
+        })
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a message in the [Common Event
+/// Format][cef].
+///
+/// The header fields are decoded to the `cefVersion`, `deviceVendor`, `deviceProduct`,
+/// `deviceVersion`, `deviceEventClassId`, `name` and `severity` fields, and the extension fields
+/// to fields of the same name. Anything before the `CEF:` prefix, such as a syslog header, is
+/// ignored.
+///
+/// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+#[derive(Debug, Clone, Default)]
+pub struct CefDeserializer;
+
+impl Deserializer for CefDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line = String::from_utf8_lossy(&bytes);
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let message = line
+            .find("CEF:")
+            .map(|start| &line[start + "CEF:".len()..])
+            .ok_or("Error parsing CEF: missing `CEF:` prefix")?;
+
+        let (header, extension) = parse_header(message)?;
+
+        let mut log = LogEvent::default();
+        for (field, value) in HEADER_FIELDS.iter().zip(header) {
+            log.insert(event_path!(*field), value);
+        }
+        for (key, value) in parse_extension(extension) {
+            log.insert(event_path!(key), value);
+        }
+
+        if log_namespace == LogNamespace::Legacy {
+            if let Some(timestamp_key) = log_schema().timestamp_key() {
+                if !log.contains((PathPrefix::Event, timestamp_key)) {
+                    log.insert((PathPrefix::Event, timestamp_key), Utc::now());
+                }
+            }
+        }
+
+        Ok(smallvec![log.into()])
+    }
+}
+
+/// Splits the seven header fields from the extension, unescaping `\|` and `\\` in the header.
+fn parse_header(message: &str) -> vector_common::Result<(Vec<String>, &str)> {
+    let mut fields = Vec::with_capacity(HEADER_FIELDS.len());
+    let mut field = String::new();
+    let mut chars = message.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('|' | '\\'))) => field.push(escaped),
+                Some((_, other)) => {
+                    field.push('\\');
+                    field.push(other);
+                }
+                None => field.push('\\'),
+            },
+            '|' => {
+                fields.push(std::mem::take(&mut field));
+                if fields.len() == HEADER_FIELDS.len() {
+                    return Ok((fields, &message[index + 1..]));
+                }
+            }
+            c => field.push(c),
+        }
+    }
+
+    Err(format!(
+        "Error parsing CEF: expected {} header fields, found {}",
+        HEADER_FIELDS.len(),
+        fields.len()
+    )
+    .into())
+}
+
+/// Parses the space-separated `key=value` pairs of the extension.
+///
+/// Values may contain spaces, so a value ends where the next key starts, and trailing spaces are
+/// trimmed. Escaped `\=`, `\\`, `\n` and `\r` sequences are unescaped.
+fn parse_extension(extension: &str) -> Vec<(&str, String)> {
+    // The start of each key, and the position of the `=` following it.
+    let mut keys = Vec::new();
+    let mut escaped = false;
+    for (index, byte) in extension.bytes().enumerate() {
+        match byte {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'=' => {
+                let (start, preceded_by_space) = match extension[..index]
+                    .char_indices()
+                    .rev()
+                    .find(|(_, c)| !is_key_char(*c))
+                {
+                    Some((position, c)) => (position + c.len_utf8(), c.is_whitespace()),
+                    None => (0, true),
+                };
+                if start < index && preceded_by_space {
+                    keys.push((start, index));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    keys.iter()
+        .enumerate()
+        .map(|(i, &(start, separator))| {
+            let end = keys.get(i + 1).map_or(extension.len(), |&(next, _)| next);
+            let value = extension[separator + 1..end].trim_end();
+            (&extension[start..separator], unescape_value(value))
+        })
+        .collect()
+}
+
+const fn is_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '[' | ']')
+}
+
+fn unescape_value(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(escaped @ ('=' | '\\' | '|')) => unescaped.push(escaped),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        CefDeserializer.parse(
+            Bytes::copy_from_slice(input.as_bytes()),
+            LogNamespace::Vector,
+        )
+    }
+
+    #[test]
+    fn deserialize_cef() {
+        let events = parse(
+            r#"Sep 19 08:26:10 host CEF:0|Security|threat\|manager|1.0|100|worm successfully stopped|10|src=10.0.0.1 dst=2.1.2.2 msg=Detected a threat. No action\=needed\n spt=1232"#,
+        )
+        .unwrap();
+        assert_eq!(events.len(), 1);
+
+        let log = events[0].as_log();
+        assert_eq!(log["cefVersion"], "0".into());
+        assert_eq!(log["deviceVendor"], "Security".into());
+        assert_eq!(log["deviceProduct"], "threat|manager".into());
+        assert_eq!(log["deviceVersion"], "1.0".into());
+        assert_eq!(log["deviceEventClassId"], "100".into());
+        assert_eq!(log["name"], "worm successfully stopped".into());
+        assert_eq!(log["severity"], "10".into());
+        assert_eq!(log["src"], "10.0.0.1".into());
+        assert_eq!(log["dst"], "2.1.2.2".into());
+        assert_eq!(log["msg"], "Detected a threat. No action=needed\n".into());
+        assert_eq!(log["spt"], "1232".into());
+    }
+
+    #[test]
+    fn deserialize_cef_without_extension() {
+        let events = parse("CEF:1|Vendor|Product|2|id|name|Low|").unwrap();
+
+        let log = events[0].as_log();
+        assert_eq!(log["severity"], "Low".into());
+        assert_eq!(log.as_map().unwrap().len(), HEADER_FIELDS.len());
+    }
+
+    #[test]
+    fn deserialize_invalid_cef() {
+        assert!(parse("not a CEF message").is_err());
+        assert!(parse("CEF:0|Vendor|Product|1.0").is_err());
+    }
+}
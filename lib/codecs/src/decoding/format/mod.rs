@@ -3,18 +3,24 @@
 
 #![deny(missing_docs)]
 
+mod avro;
 mod bytes;
+mod cef;
 mod gelf;
 mod json;
+mod msgpack;
 mod native;
 mod native_json;
 #[cfg(feature = "syslog")]
 mod syslog;
 
 use ::bytes::Bytes;
+pub use avro::{AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions};
+pub use cef::{CefDeserializer, CefDeserializerConfig};
 use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig};
+pub use msgpack::{MessagePackDeserializer, MessagePackDeserializerConfig};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{NativeJsonDeserializer, NativeJsonDeserializerConfig};
 use smallvec::SmallVec;
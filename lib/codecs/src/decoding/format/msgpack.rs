@@ -0,0 +1,172 @@
+use bytes::Bytes;
+use chrono::Utc;
+use lookup::PathPrefix;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{kind::Collection, Kind, Value};
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::Deserializer;
+use crate::msgpack;
+
+/// Config used to build a `MessagePackDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MessagePackDeserializerConfig;
+
+impl MessagePackDeserializerConfig {
+    /// Build the `MessagePackDeserializer` from this configuration.
+    pub const fn build(&self) -> MessagePackDeserializer {
+        MessagePackDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        // Unlike JSON, MessagePack has a timestamp type.
+        let kind = Kind::json().or_timestamp();
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(kind.clone());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(timestamp_key, kind, Some("timestamp"));
+                }
+                definition
+            }
+            LogNamespace::Vector => schema::Definition::new_with_default_metadata(
+                Kind::object(Collection::empty().with_unknown(kind)),
+                [log_namespace],
+            ),
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a [MessagePack][msgpack] map.
+///
+/// Like the JSON deserializer, a frame containing an array of maps is split into multiple events.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone, Default)]
+pub struct MessagePackDeserializer;
+
+impl Deserializer for MessagePackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let value = rmpv::decode::read_value(&mut bytes.as_ref())
+            .map_err(|error| format!("Error parsing MessagePack: {}", error))?;
+
+        let mut events = match msgpack::to_value(value) {
+            Value::Array(values) => values
+                .into_iter()
+                .map(to_event)
+                .collect::<Result<SmallVec<[Event; 1]>, _>>()?,
+            value => smallvec![to_event(value)?],
+        };
+
+        if log_namespace == LogNamespace::Legacy {
+            let timestamp = Utc::now();
+
+            if let Some(timestamp_key) = log_schema().timestamp_key() {
+                for event in &mut events {
+                    let log = event.as_mut_log();
+                    if !log.contains((PathPrefix::Event, timestamp_key)) {
+                        log.insert((PathPrefix::Event, timestamp_key), timestamp);
+                    }
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+fn to_event(value: Value) -> vector_common::Result<Event> {
+    match value {
+        value @ Value::Object(_) => Ok(Event::Log(LogEvent::from(value))),
+        value => Err(format!(
+            "Attempted to convert non-map MessagePack value into an event: {}",
+            value.kind_str()
+        )
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn encode(value: rmpv::Value) -> Bytes {
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, &value).unwrap();
+        bytes.into()
+    }
+
+    #[test]
+    fn deserialize_msgpack() {
+        let timestamp = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let input = encode(rmpv::Value::Map(vec![
+            (rmpv::Value::from("foo"), rmpv::Value::from(123)),
+            (
+                rmpv::Value::from("time"),
+                msgpack::from_value(&Value::Timestamp(timestamp)),
+            ),
+        ]));
+        let deserializer = MessagePackDeserializer;
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["foo"], 123.into());
+            assert_eq!(log["time"], timestamp.into());
+            assert_eq!(
+                log.get((PathPrefix::Event, log_schema().timestamp_key().unwrap()))
+                    .is_some(),
+                namespace == LogNamespace::Legacy
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_msgpack_array() {
+        let input = encode(rmpv::Value::Array(vec![
+            rmpv::Value::Map(vec![(rmpv::Value::from("foo"), rmpv::Value::from(1))]),
+            rmpv::Value::Map(vec![(rmpv::Value::from("foo"), rmpv::Value::from(2))]),
+        ]));
+
+        let events = MessagePackDeserializer
+            .parse(input, LogNamespace::Vector)
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].as_log()["foo"], 2.into());
+    }
+
+    #[test]
+    fn deserialize_non_map_msgpack() {
+        let input = encode(rmpv::Value::from("foo"));
+
+        assert!(MessagePackDeserializer
+            .parse(input, LogNamespace::Vector)
+            .is_err());
+    }
+}
@@ -6,6 +6,7 @@
 mod bytes;
 mod character_delimited;
 mod length_delimited;
+mod multiline;
 mod newline_delimited;
 mod octet_counting;
 
@@ -17,6 +18,9 @@ pub use character_delimited::{
 };
 use dyn_clone::DynClone;
 pub use length_delimited::{LengthDelimitedDecoder, LengthDelimitedDecoderConfig};
+pub use multiline::{
+    MultilineDecoder, MultilineDecoderConfig, MultilineDecoderOptions, MultilineMode,
+};
 pub use newline_delimited::{
     NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig, NewlineDelimitedDecoderOptions,
 };
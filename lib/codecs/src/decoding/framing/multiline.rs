@@ -0,0 +1,387 @@
+use std::time::{Duration, Instant};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use derivative::Derivative;
+use regex::bytes::Regex;
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Decoder;
+use vector_config::configurable_component;
+
+use super::{BoxedFramingError, NewlineDelimitedDecoder};
+
+/// Config used to build a `MultilineDecoder`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MultilineDecoderConfig {
+    /// Options for the multiline decoder.
+    pub multiline: MultilineDecoderOptions,
+}
+
+impl MultilineDecoderConfig {
+    /// Build the `MultilineDecoder` from this configuration.
+    pub fn build(&self) -> vector_common::Result<MultilineDecoder> {
+        let start_pattern = Regex::new(&self.multiline.start_pattern).map_err(|error| {
+            format!(
+                "unable to parse multiline start pattern from {:?}: {}",
+                self.multiline.start_pattern, error
+            )
+        })?;
+        let condition_pattern = Regex::new(&self.multiline.condition_pattern).map_err(|error| {
+            format!(
+                "unable to parse multiline condition pattern from {:?}: {}",
+                self.multiline.condition_pattern, error
+            )
+        })?;
+        let lines = match self.multiline.max_length {
+            Some(max_length) => NewlineDelimitedDecoder::new_with_max_length(max_length),
+            None => NewlineDelimitedDecoder::new(),
+        };
+
+        Ok(MultilineDecoder {
+            lines,
+            start_pattern,
+            condition_pattern,
+            mode: self.multiline.mode,
+            timeout: Duration::from_millis(self.multiline.timeout_ms),
+            aggregate: None,
+            stashed: None,
+        })
+    }
+}
+
+/// Options for building a `MultilineDecoder`.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub struct MultilineDecoderOptions {
+    /// Regular expression pattern that is used to match the start of a new message.
+    #[configurable(metadata(docs::examples = "^[\\s]+"))]
+    #[configurable(metadata(docs::examples = "\\\\$"))]
+    #[configurable(metadata(docs::examples = "^(INFO|ERROR) "))]
+    #[configurable(metadata(docs::examples = ";$"))]
+    pub start_pattern: String,
+
+    /// Regular expression pattern that is used to determine whether or not more lines should be read.
+    ///
+    /// This setting must be configured in conjunction with `mode`.
+    #[configurable(metadata(docs::examples = "^[\\s]+"))]
+    #[configurable(metadata(docs::examples = "\\\\$"))]
+    #[configurable(metadata(docs::examples = "^(INFO|ERROR) "))]
+    #[configurable(metadata(docs::examples = ";$"))]
+    pub condition_pattern: String,
+
+    /// Aggregation mode.
+    ///
+    /// This setting must be configured in conjunction with `condition_pattern`.
+    #[derivative(Default(value = "MultilineMode::HaltBefore"))]
+    pub mode: MultilineMode,
+
+    /// The maximum amount of time to wait for the next additional line, in milliseconds.
+    ///
+    /// As framing happens as data is read, the timeout is checked when more data is received, and
+    /// the buffered message is flushed as soon as the input ends, even if incomplete.
+    #[serde(default = "default_timeout_ms")]
+    #[derivative(Default(value = "default_timeout_ms()"))]
+    #[configurable(metadata(docs::type_unit = "milliseconds"))]
+    #[configurable(metadata(docs::examples = 1000))]
+    #[configurable(metadata(docs::examples = 600000))]
+    pub timeout_ms: u64,
+
+    /// The maximum length of a line, before aggregation.
+    ///
+    /// This length does *not* include the trailing newline.
+    ///
+    /// By default, there is no maximum length enforced. Lines longer than this are discarded.
+    #[serde(skip_serializing_if = "vector_core::serde::skip_serializing_if_default")]
+    pub max_length: Option<usize>,
+}
+
+const fn default_timeout_ms() -> u64 {
+    1000
+}
+
+/// Mode of operation of the multiline aggregation.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MultilineMode {
+    /// All consecutive lines matching this pattern are included in the group.
+    ///
+    /// The first line (the line that matched the start pattern) does not need to match the `ContinueThrough` pattern.
+    ///
+    /// This is useful in cases such as a Java stack trace, where some indicator in the line (such as a leading
+    /// whitespace) indicates that it is an extension of the proceeding line.
+    ContinueThrough,
+
+    /// All consecutive lines matching this pattern, plus one additional line, are included in the group.
+    ///
+    /// This is useful in cases where a log message ends with a continuation marker, such as a backslash, indicating
+    /// that the following line is part of the same message.
+    ContinuePast,
+
+    /// All consecutive lines not matching this pattern are included in the group.
+    ///
+    /// This is useful where a log line contains a marker indicating that it begins a new message.
+    HaltBefore,
+
+    /// All consecutive lines, up to and including the first line matching this pattern, are included in the group.
+    ///
+    /// This is useful where a log line ends with a termination marker, such as a semicolon.
+    HaltWith,
+}
+
+/// A decoder that splits bytes on newlines, and aggregates consecutive lines into a single frame
+/// the same way as the `multiline` option of the `file` source.
+///
+/// Aggregated lines are joined with a newline.
+#[derive(Debug, Clone)]
+pub struct MultilineDecoder {
+    lines: NewlineDelimitedDecoder,
+    start_pattern: Regex,
+    condition_pattern: Regex,
+    mode: MultilineMode,
+    timeout: Duration,
+    /// The lines aggregated so far, and the time after which they must be flushed.
+    aggregate: Option<(BytesMut, Instant)>,
+    /// A line that ended the previous aggregate without being part of it, which is handled
+    /// before any other line.
+    stashed: Option<Bytes>,
+}
+
+/// What to do with the current aggregate when a line is received.
+enum Decision {
+    Continue,
+    EndInclude,
+    EndExclude,
+}
+
+impl MultilineDecoder {
+    fn decode_lines(
+        &mut self,
+        src: &mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<Bytes>, BoxedFramingError> {
+        loop {
+            let line = match self.stashed.take() {
+                Some(line) => line,
+                None => {
+                    let line = if eof {
+                        self.lines.decode_eof(src)?
+                    } else {
+                        self.lines.decode(src)?
+                    };
+                    match line {
+                        Some(line) => line,
+                        None if eof => return Ok(self.flush()),
+                        None => return Ok(None),
+                    }
+                }
+            };
+
+            // The timeout can only be checked once more data is received, in which case the
+            // expired aggregate is flushed before handling the line.
+            if self
+                .aggregate
+                .as_ref()
+                .map_or(false, |(_, deadline)| Instant::now() >= *deadline)
+            {
+                self.stashed = Some(line);
+                return Ok(self.flush());
+            }
+
+            if let Some(frame) = self.handle_line(line) {
+                return Ok(Some(frame));
+            }
+        }
+    }
+
+    fn handle_line(&mut self, line: Bytes) -> Option<Bytes> {
+        let Some((mut buffered, _)) = self.aggregate.take() else {
+            if self.start_pattern.is_match(&line) {
+                self.aggregate = Some((BytesMut::from(&line[..]), Instant::now() + self.timeout));
+                return None;
+            }
+            return Some(line);
+        };
+
+        let condition_matched = self.condition_pattern.is_match(&line);
+        let decision = match (self.mode, condition_matched) {
+            (MultilineMode::ContinueThrough, true) => Decision::Continue,
+            (MultilineMode::ContinueThrough, false) => Decision::EndExclude,
+            (MultilineMode::ContinuePast, true) => Decision::Continue,
+            (MultilineMode::ContinuePast, false) => Decision::EndInclude,
+            (MultilineMode::HaltBefore, true) => Decision::EndExclude,
+            (MultilineMode::HaltBefore, false) => Decision::Continue,
+            (MultilineMode::HaltWith, true) => Decision::EndInclude,
+            (MultilineMode::HaltWith, false) => Decision::Continue,
+        };
+
+        match decision {
+            Decision::Continue => {
+                buffered.put_u8(b'\n');
+                buffered.extend_from_slice(&line);
+                self.aggregate = Some((buffered, Instant::now() + self.timeout));
+                None
+            }
+            Decision::EndInclude => {
+                buffered.put_u8(b'\n');
+                buffered.extend_from_slice(&line);
+                Some(buffered.freeze())
+            }
+            Decision::EndExclude => {
+                self.stashed = Some(line);
+                Some(buffered.freeze())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> Option<Bytes> {
+        self.aggregate.take().map(|(buffered, _)| buffered.freeze())
+    }
+}
+
+impl Decoder for MultilineDecoder {
+    type Item = Bytes;
+    type Error = BoxedFramingError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.decode_lines(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.decode_lines(src, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoder(
+        start_pattern: &str,
+        condition_pattern: &str,
+        mode: MultilineMode,
+    ) -> MultilineDecoder {
+        MultilineDecoderConfig {
+            multiline: MultilineDecoderOptions {
+                start_pattern: start_pattern.to_owned(),
+                condition_pattern: condition_pattern.to_owned(),
+                mode,
+                ..Default::default()
+            },
+        }
+        .build()
+        .unwrap()
+    }
+
+    fn decode_all(decoder: &mut MultilineDecoder, input: &str) -> Vec<Bytes> {
+        let mut input = BytesMut::from(input);
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.decode_eof(&mut input).unwrap() {
+            frames.push(frame);
+        }
+        frames
+    }
+
+    #[test]
+    fn decode_continue_through() {
+        let mut decoder = decoder("^[^\\s]", "^[\\s]+", MultilineMode::ContinueThrough);
+
+        let frames = decode_all(
+            &mut decoder,
+            "some line\nException in main\n  at foo\n  at bar\nanother line\nlast\n  at baz",
+        );
+
+        assert_eq!(
+            frames,
+            vec![
+                "some line",
+                "Exception in main\n  at foo\n  at bar",
+                "another line",
+                "last\n  at baz",
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_continue_past() {
+        let mut decoder = decoder("\\\\$", "\\\\$", MultilineMode::ContinuePast);
+
+        let frames = decode_all(&mut decoder, "one\ntwo \\\nthree \\\nfour\nfive\n");
+
+        assert_eq!(frames, vec!["one", "two \\\nthree \\\nfour", "five"]);
+    }
+
+    #[test]
+    fn decode_halt_before() {
+        let mut decoder = decoder("", "^(INFO|ERROR) ", MultilineMode::HaltBefore);
+
+        let frames = decode_all(
+            &mut decoder,
+            "INFO first\nERROR second\n  detail\nINFO third\n",
+        );
+
+        assert_eq!(
+            frames,
+            vec!["INFO first", "ERROR second\n  detail", "INFO third"]
+        );
+    }
+
+    #[test]
+    fn decode_halt_with() {
+        let mut decoder = decoder("[^;]$", ";$", MultilineMode::HaltWith);
+
+        let frames = decode_all(&mut decoder, "select *\nfrom t;\nbegin;\n");
+
+        assert_eq!(frames, vec!["select *\nfrom t;", "begin;"]);
+    }
+
+    #[test]
+    fn decode_waits_for_more_lines() {
+        let mut decoder = decoder("^[^\\s]", "^[\\s]+", MultilineMode::ContinueThrough);
+        let mut input = BytesMut::from("first\n  continued\n");
+
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+
+        input.extend_from_slice(b"  more\nsecond\n");
+        assert_eq!(
+            decoder.decode(&mut input).unwrap().unwrap(),
+            "first\n  continued\n  more"
+        );
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+        assert_eq!(decoder.decode_eof(&mut input).unwrap().unwrap(), "second");
+    }
+
+    #[test]
+    fn decode_flushes_after_timeout() {
+        let mut decoder = MultilineDecoderConfig {
+            multiline: MultilineDecoderOptions {
+                start_pattern: "^[^\\s]".to_owned(),
+                condition_pattern: "^[\\s]+".to_owned(),
+                mode: MultilineMode::ContinueThrough,
+                timeout_ms: 0,
+                max_length: None,
+            },
+        }
+        .build()
+        .unwrap();
+        let mut input = BytesMut::from("first\n");
+
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+
+        input.extend_from_slice(b"  late\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "first");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "  late");
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        assert!(MultilineDecoderConfig {
+            multiline: MultilineDecoderOptions {
+                start_pattern: "(".to_owned(),
+                ..Default::default()
+            },
+        }
+        .build()
+        .is_err());
+    }
+}
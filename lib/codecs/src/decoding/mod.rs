@@ -8,8 +8,10 @@ pub mod framing;
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, NativeDeserializer,
+    AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions, BoxedDeserializer,
+    BytesDeserializer, BytesDeserializerConfig, CefDeserializer, CefDeserializerConfig,
+    GelfDeserializer, GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig,
+    MessagePackDeserializer, MessagePackDeserializerConfig, NativeDeserializer,
     NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
 };
 #[cfg(feature = "syslog")]
@@ -17,9 +19,10 @@ pub use format::{SyslogDeserializer, SyslogDeserializerConfig};
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesDecoder, BytesDecoderConfig, CharacterDelimitedDecoder,
     CharacterDelimitedDecoderConfig, CharacterDelimitedDecoderOptions, FramingError,
-    LengthDelimitedDecoder, LengthDelimitedDecoderConfig, NewlineDelimitedDecoder,
-    NewlineDelimitedDecoderConfig, NewlineDelimitedDecoderOptions, OctetCountingDecoder,
-    OctetCountingDecoderConfig, OctetCountingDecoderOptions,
+    LengthDelimitedDecoder, LengthDelimitedDecoderConfig, MultilineDecoder, MultilineDecoderConfig,
+    MultilineDecoderOptions, MultilineMode, NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig,
+    NewlineDelimitedDecoderOptions, OctetCountingDecoder, OctetCountingDecoderConfig,
+    OctetCountingDecoderOptions,
 };
 use smallvec::SmallVec;
 use std::fmt::Debug;
@@ -92,6 +95,16 @@ pub enum FramingConfig {
     /// Byte frames which are prefixed by an unsigned big-endian 32-bit integer indicating the length.
     LengthDelimited,
 
+    /// Byte frames which are delimited by a newline character, with consecutive lines aggregated
+    /// into a single frame according to patterns, such as the lines of a stack trace.
+    Multiline {
+        /// Options for the multiline decoder.
+        ///
+        /// Sources that receive discrete messages, such as `kafka` or `http_server`, frame each
+        /// message on its own, so lines are only aggregated within the same message.
+        multiline: MultilineDecoderOptions,
+    },
+
     /// Byte frames which are delimited by a newline character.
     NewlineDelimited {
         #[serde(
@@ -135,6 +148,14 @@ impl From<LengthDelimitedDecoderConfig> for FramingConfig {
     }
 }
 
+impl From<MultilineDecoderConfig> for FramingConfig {
+    fn from(config: MultilineDecoderConfig) -> Self {
+        Self::Multiline {
+            multiline: config.multiline,
+        }
+    }
+}
+
 impl From<NewlineDelimitedDecoderConfig> for FramingConfig {
     fn from(config: NewlineDelimitedDecoderConfig) -> Self {
         Self::NewlineDelimited {
@@ -153,8 +174,8 @@ impl From<OctetCountingDecoderConfig> for FramingConfig {
 
 impl FramingConfig {
     /// Build the `Framer` from this configuration.
-    pub fn build(&self) -> Framer {
-        match self {
+    pub fn build(&self) -> vector_common::Result<Framer> {
+        Ok(match self {
             FramingConfig::Bytes => Framer::Bytes(BytesDecoderConfig.build()),
             FramingConfig::CharacterDelimited {
                 character_delimited,
@@ -167,6 +188,12 @@ impl FramingConfig {
             FramingConfig::LengthDelimited => {
                 Framer::LengthDelimited(LengthDelimitedDecoderConfig.build())
             }
+            FramingConfig::Multiline { multiline } => Framer::Multiline(
+                MultilineDecoderConfig {
+                    multiline: multiline.clone(),
+                }
+                .build()?,
+            ),
             FramingConfig::NewlineDelimited { newline_delimited } => Framer::NewlineDelimited(
                 NewlineDelimitedDecoderConfig {
                     newline_delimited: newline_delimited.clone(),
@@ -179,7 +206,7 @@ impl FramingConfig {
                 }
                 .build(),
             ),
-        }
+        })
     }
 }
 
@@ -192,6 +219,8 @@ pub enum Framer {
     CharacterDelimited(CharacterDelimitedDecoder),
     /// Uses a `LengthDelimitedDecoder` for framing.
     LengthDelimited(LengthDelimitedDecoder),
+    /// Uses a `MultilineDecoder` for framing.
+    Multiline(MultilineDecoder),
     /// Uses a `NewlineDelimitedDecoder` for framing.
     NewlineDelimited(NewlineDelimitedDecoder),
     /// Uses a `OctetCountingDecoder` for framing.
@@ -209,6 +238,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::Bytes(framer) => framer.decode(src),
             Framer::CharacterDelimited(framer) => framer.decode(src),
             Framer::LengthDelimited(framer) => framer.decode(src),
+            Framer::Multiline(framer) => framer.decode(src),
             Framer::NewlineDelimited(framer) => framer.decode(src),
             Framer::OctetCounting(framer) => framer.decode(src),
             Framer::Boxed(framer) => framer.decode(src),
@@ -220,6 +250,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::Bytes(framer) => framer.decode_eof(src),
             Framer::CharacterDelimited(framer) => framer.decode_eof(src),
             Framer::LengthDelimited(framer) => framer.decode_eof(src),
+            Framer::Multiline(framer) => framer.decode_eof(src),
             Framer::NewlineDelimited(framer) => framer.decode_eof(src),
             Framer::OctetCounting(framer) => framer.decode_eof(src),
             Framer::Boxed(framer) => framer.decode_eof(src),
@@ -275,6 +306,36 @@ pub enum DeserializerConfig {
     ///
     /// [gelf]: https://docs.graylog.org/docs/gelf
     Gelf,
+
+    /// Decodes the raw bytes as an [Apache Avro][apache_avro] message.
+    ///
+    /// Messages are either encoded with the configured schema, or in the Confluent wire format
+    /// with a schema of the configured schema registry.
+    ///
+    /// [apache_avro]: https://avro.apache.org/
+    Avro {
+        /// Apache Avro-specific decoder options.
+        avro: AvroDeserializerOptions,
+    },
+
+    /// Decodes the raw bytes as a [MessagePack][msgpack] map.
+    ///
+    /// Like the `json` codec, an array of maps is decoded as multiple events. Values of the
+    /// timestamp extension type of MessagePack are decoded as timestamps.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    #[serde(rename = "msgpack")]
+    MessagePack,
+
+    /// Decodes the raw bytes as a [Common Event Format (CEF)][cef] message.
+    ///
+    /// The header fields are decoded to the `cefVersion`, `deviceVendor`, `deviceProduct`,
+    /// `deviceVersion`, `deviceEventClassId`, `name`, and `severity` fields, and the extension
+    /// fields to fields of the same name. Anything before the `CEF:` prefix, such as a syslog
+    /// header, is ignored.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+    Cef,
 }
 
 impl From<BytesDeserializerConfig> for DeserializerConfig {
@@ -302,32 +363,62 @@ impl From<GelfDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<MessagePackDeserializerConfig> for DeserializerConfig {
+    fn from(_: MessagePackDeserializerConfig) -> Self {
+        Self::MessagePack
+    }
+}
+
+impl From<CefDeserializerConfig> for DeserializerConfig {
+    fn from(_: CefDeserializerConfig) -> Self {
+        Self::Cef
+    }
+}
+
+impl From<AvroDeserializerConfig> for DeserializerConfig {
+    fn from(config: AvroDeserializerConfig) -> Self {
+        Self::Avro { avro: config.avro }
+    }
+}
+
 impl DeserializerConfig {
     /// Build the `Deserializer` from this configuration.
-    pub fn build(&self) -> Deserializer {
+    pub fn build(&self) -> vector_common::Result<Deserializer> {
         match self {
-            DeserializerConfig::Bytes => Deserializer::Bytes(BytesDeserializerConfig.build()),
-            DeserializerConfig::Json => Deserializer::Json(JsonDeserializerConfig.build()),
+            DeserializerConfig::Bytes => Ok(Deserializer::Bytes(BytesDeserializerConfig.build())),
+            DeserializerConfig::Json => Ok(Deserializer::Json(JsonDeserializerConfig.build())),
             #[cfg(feature = "syslog")]
-            DeserializerConfig::Syslog => {
-                Deserializer::Syslog(SyslogDeserializerConfig::default().build())
-            }
-            DeserializerConfig::Native => Deserializer::Native(NativeDeserializerConfig.build()),
-            DeserializerConfig::NativeJson => {
-                Deserializer::NativeJson(NativeJsonDeserializerConfig.build())
+            DeserializerConfig::Syslog => Ok(Deserializer::Syslog(
+                SyslogDeserializerConfig::default().build(),
+            )),
+            DeserializerConfig::Native => {
+                Ok(Deserializer::Native(NativeDeserializerConfig.build()))
             }
-            DeserializerConfig::Gelf => Deserializer::Gelf(GelfDeserializerConfig.build()),
+            DeserializerConfig::NativeJson => Ok(Deserializer::NativeJson(
+                NativeJsonDeserializerConfig.build(),
+            )),
+            DeserializerConfig::Gelf => Ok(Deserializer::Gelf(GelfDeserializerConfig.build())),
+            DeserializerConfig::Avro { avro } => Ok(Deserializer::Avro(
+                AvroDeserializerConfig::new(avro.clone()).build()?,
+            )),
+            DeserializerConfig::MessagePack => Ok(Deserializer::MessagePack(
+                MessagePackDeserializerConfig.build(),
+            )),
+            DeserializerConfig::Cef => Ok(Deserializer::Cef(CefDeserializerConfig.build())),
         }
     }
 
     /// Return an appropriate default framer for the given deserializer
     pub fn default_stream_framing(&self) -> FramingConfig {
         match self {
-            DeserializerConfig::Native => FramingConfig::LengthDelimited,
+            DeserializerConfig::Native
+            | DeserializerConfig::Avro { .. }
+            | DeserializerConfig::MessagePack => FramingConfig::LengthDelimited,
             DeserializerConfig::Bytes
             | DeserializerConfig::Json
             | DeserializerConfig::Gelf
-            | DeserializerConfig::NativeJson => FramingConfig::NewlineDelimited {
+            | DeserializerConfig::NativeJson
+            | DeserializerConfig::Cef => FramingConfig::NewlineDelimited {
                 newline_delimited: Default::default(),
             },
             #[cfg(feature = "syslog")]
@@ -347,6 +438,11 @@ impl DeserializerConfig {
             DeserializerConfig::Native => NativeDeserializerConfig.output_type(),
             DeserializerConfig::NativeJson => NativeJsonDeserializerConfig.output_type(),
             DeserializerConfig::Gelf => GelfDeserializerConfig.output_type(),
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig::new(avro.clone()).output_type()
+            }
+            DeserializerConfig::MessagePack => MessagePackDeserializerConfig.output_type(),
+            DeserializerConfig::Cef => CefDeserializerConfig.output_type(),
         }
     }
 
@@ -364,6 +460,13 @@ impl DeserializerConfig {
                 NativeJsonDeserializerConfig.schema_definition(log_namespace)
             }
             DeserializerConfig::Gelf => GelfDeserializerConfig.schema_definition(log_namespace),
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig::new(avro.clone()).schema_definition(log_namespace)
+            }
+            DeserializerConfig::MessagePack => {
+                MessagePackDeserializerConfig.schema_definition(log_namespace)
+            }
+            DeserializerConfig::Cef => CefDeserializerConfig.schema_definition(log_namespace),
         }
     }
 
@@ -386,12 +489,16 @@ impl DeserializerConfig {
                         },
                 },
             ) => "application/json",
-            (DeserializerConfig::Native, _) => "application/octet-stream",
+            (DeserializerConfig::Native | DeserializerConfig::Avro { .. }, _) => {
+                "application/octet-stream"
+            }
+            (DeserializerConfig::MessagePack, _) => "application/msgpack",
             (
                 DeserializerConfig::Json
                 | DeserializerConfig::NativeJson
                 | DeserializerConfig::Bytes
-                | DeserializerConfig::Gelf,
+                | DeserializerConfig::Gelf
+                | DeserializerConfig::Cef,
                 _,
             ) => "text/plain",
             #[cfg(feature = "syslog")]
@@ -418,6 +525,12 @@ pub enum Deserializer {
     Boxed(BoxedDeserializer),
     /// Uses a `GelfDeserializer` for deserialization.
     Gelf(GelfDeserializer),
+    /// Uses an `AvroDeserializer` for deserialization.
+    Avro(AvroDeserializer),
+    /// Uses a `MessagePackDeserializer` for deserialization.
+    MessagePack(MessagePackDeserializer),
+    /// Uses a `CefDeserializer` for deserialization.
+    Cef(CefDeserializer),
 }
 
 impl format::Deserializer for Deserializer {
@@ -435,6 +548,9 @@ impl format::Deserializer for Deserializer {
             Deserializer::NativeJson(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Boxed(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Gelf(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Avro(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::MessagePack(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Cef(deserializer) => deserializer.parse(bytes, log_namespace),
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::encoding::BuildError;
+use crate::{
+    avro::{write_header, SchemaRegistryOptions},
+    encoding::BuildError,
+};
 use bytes::{BufMut, BytesMut};
 use serde::{Deserialize, Serialize};
 use tokio_util::codec::Encoder;
@@ -16,15 +19,40 @@ impl AvroSerializerConfig {
     /// Creates a new `AvroSerializerConfig`.
     pub const fn new(schema: String) -> Self {
         Self {
-            avro: AvroSerializerOptions { schema },
+            avro: AvroSerializerOptions {
+                schema,
+                schema_registry: None,
+            },
         }
     }
 
     /// Build the `AvroSerializer` from this configuration.
+    ///
+    /// Serializers using a schema registry must be built with the id of their schema in the
+    /// registry instead, using `build_with_schema_id`.
     pub fn build(&self) -> Result<AvroSerializer, BuildError> {
-        let schema = apache_avro::Schema::parse_str(&self.avro.schema)
-            .map_err(|error| format!("Failed building Avro serializer: {}", error))?;
-        Ok(AvroSerializer { schema })
+        if self.avro.schema_registry.is_some() {
+            return Err("Failed building Avro serializer: the schema registry isn't supported by this component".into());
+        }
+        Ok(AvroSerializer {
+            schema: self.schema()?,
+            schema_id: None,
+        })
+    }
+
+    /// Build the `AvroSerializer` from this configuration, prefixing the messages with the id of
+    /// the schema in the schema registry.
+    pub fn build_with_schema_id(&self, schema_id: u32) -> Result<AvroSerializer, BuildError> {
+        Ok(AvroSerializer {
+            schema: self.schema()?,
+            schema_id: Some(schema_id),
+        })
+    }
+
+    /// Parses the configured schema.
+    pub fn schema(&self) -> Result<apache_avro::Schema, BuildError> {
+        apache_avro::Schema::parse_str(&self.avro.schema)
+            .map_err(|error| format!("Failed building Avro serializer: {}", error).into())
     }
 
     /// The data type of events that are accepted by `AvroSerializer`.
@@ -48,18 +76,29 @@ pub struct AvroSerializerOptions {
         docs::examples = r#"{ "type": "record", "name": "log", "fields": [{ "name": "message", "type": "string" }] }"#
     ))]
     pub schema: String,
+
+    /// The schema registry that the schema is registered in.
+    ///
+    /// If set, the schema is registered under the subject named by the `subject_name_strategy`,
+    /// and messages are encoded in the Confluent wire format, prefixed by the id of the schema.
+    #[configurable(derived)]
+    pub schema_registry: Option<SchemaRegistryOptions>,
 }
 
 /// Serializer that converts an `Event` to bytes using the Apache Avro format.
 #[derive(Debug, Clone)]
 pub struct AvroSerializer {
     schema: apache_avro::Schema,
+    schema_id: Option<u32>,
 }
 
 impl AvroSerializer {
     /// Creates a new `AvroSerializer`.
     pub const fn new(schema: apache_avro::Schema) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            schema_id: None,
+        }
     }
 }
 
@@ -71,6 +110,9 @@ impl Encoder<Event> for AvroSerializer {
         let value = apache_avro::to_value(log)?;
         let value = value.resolve(&self.schema)?;
         let bytes = apache_avro::to_avro_datum(&self.schema, value)?;
+        if let Some(schema_id) = self.schema_id {
+            write_header(schema_id, buffer);
+        }
         buffer.put_slice(&bytes);
         Ok(())
     }
@@ -85,6 +127,36 @@ mod tests {
 
     use super::*;
 
+    fn schema() -> String {
+        indoc! {r#"
+            {
+                "type": "record",
+                "name": "Log",
+                "fields": [
+                    {
+                        "name": "foo",
+                        "type": ["string"]
+                    }
+                ]
+            }
+        "#}
+        .to_owned()
+    }
+
+    #[test]
+    fn serialize_avro_with_schema_id() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "foo" => Value::from("bar")
+        }));
+        let config = AvroSerializerConfig::new(schema());
+        let mut serializer = config.build_with_schema_id(7).unwrap();
+        let mut bytes = BytesMut::new();
+
+        serializer.encode(event, &mut bytes).unwrap();
+
+        assert_eq!(bytes.freeze(), b"\0\0\0\0\x07\0\x06bar".as_slice());
+    }
+
     #[test]
     fn serialize_avro() {
         let event = Event::Log(LogEvent::from(btreemap! {
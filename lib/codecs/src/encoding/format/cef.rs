@@ -0,0 +1,358 @@
+use std::collections::BTreeMap;
+
+use bytes::{BufMut, BytesMut};
+use lookup::lookup_v2::ConfigTargetPath;
+use tokio_util::codec::Encoder;
+use vector_core::{
+    config::DataType,
+    event::{Event, LogEvent, Value},
+    schema,
+};
+
+use crate::encoding::BuildError;
+
+/// Config used to build a `CefSerializer`.
+#[crate::configurable_component]
+#[derive(Debug, Clone)]
+pub struct CefSerializerConfig {
+    /// The CEF Serializer Options.
+    pub cef: CefSerializerOptions,
+}
+
+impl CefSerializerConfig {
+    /// Creates a new `CefSerializerConfig`.
+    pub const fn new(cef: CefSerializerOptions) -> Self {
+        Self { cef }
+    }
+
+    /// Build the `CefSerializer` from this configuration.
+    pub fn build(&self) -> Result<CefSerializer, BuildError> {
+        if let Some(key) = self.cef.extensions.keys().find(|key| !is_valid_key(key)) {
+            return Err(format!(
+                "Invalid CEF extension key {:?}: keys must only contain alphanumeric characters",
+                key
+            )
+            .into());
+        }
+        Ok(CefSerializer::new(self.cef.clone()))
+    }
+
+    /// The data type of events that are accepted by `CefSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Config used to build a `CefSerializer`.
+#[crate::configurable_component]
+#[derive(Debug, Clone)]
+pub struct CefSerializerOptions {
+    /// The vendor of the device that produced the events, written to the `Device Vendor` header
+    /// field.
+    #[configurable(metadata(docs::examples = "Datadog"))]
+    pub device_vendor: String,
+
+    /// The product that produced the events, written to the `Device Product` header field.
+    #[configurable(metadata(docs::examples = "Vector"))]
+    pub device_product: String,
+
+    /// The version of the product that produced the events, written to the `Device Version` header
+    /// field.
+    #[configurable(metadata(docs::examples = "0.30.0"))]
+    pub device_version: String,
+
+    /// The field containing the identifier of the type of event, written to the
+    /// `Device Event Class ID` header field.
+    #[serde(default = "default_device_event_class_id_field")]
+    #[configurable(metadata(docs::examples = "event.code"))]
+    pub device_event_class_id_field: ConfigTargetPath,
+
+    /// The field containing the human-readable description of the event, written to the `Name`
+    /// header field.
+    #[serde(default = "default_name_field")]
+    #[configurable(metadata(docs::examples = "message"))]
+    pub name_field: ConfigTargetPath,
+
+    /// The field containing the importance of the event, written to the `Severity` header field.
+    ///
+    /// CEF severities are integers from 0 to 10, or one of `Unknown`, `Low`, `Medium`, `High`
+    /// and `Very-High`.
+    #[serde(default = "default_severity_field")]
+    #[configurable(metadata(docs::examples = "level"))]
+    pub severity_field: ConfigTargetPath,
+
+    /// The extension fields to encode, as a map of CEF extension keys to the event fields their
+    /// values are read from.
+    ///
+    /// If empty, all top-level fields of the event are encoded, except the header fields, and the
+    /// fields that are objects or arrays, or whose name isn't a valid extension key.
+    ///
+    /// Fields that are missing from the event are skipped. Timestamps are encoded as milliseconds
+    /// since the Unix epoch.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "An event field to read the extension value from."
+    ))]
+    #[configurable(metadata(docs::examples = "example_extensions()"))]
+    pub extensions: BTreeMap<String, ConfigTargetPath>,
+}
+
+impl CefSerializerOptions {
+    /// Creates new `CefSerializerOptions` for the given device, with the default header fields and
+    /// extension mapping.
+    pub fn new(device_vendor: String, device_product: String, device_version: String) -> Self {
+        Self {
+            device_vendor,
+            device_product,
+            device_version,
+            device_event_class_id_field: default_device_event_class_id_field(),
+            name_field: default_name_field(),
+            severity_field: default_severity_field(),
+            extensions: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_device_event_class_id_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("deviceEventClassId".to_string()).expect("valid path")
+}
+
+fn default_name_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("name".to_string()).expect("valid path")
+}
+
+fn default_severity_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("severity".to_string()).expect("valid path")
+}
+
+fn example_extensions() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("src".to_owned(), "source.ip".to_owned()),
+        ("dst".to_owned(), "destination.ip".to_owned()),
+        ("msg".to_owned(), "message".to_owned()),
+    ])
+}
+
+/// The fields that the CEF decoder decodes the header fields to, which aren't encoded as extension
+/// fields when encoding all the fields of an event.
+const HEADER_FIELDS: [&str; 7] = [
+    "cefVersion",
+    "deviceVendor",
+    "deviceProduct",
+    "deviceVersion",
+    "deviceEventClassId",
+    "name",
+    "severity",
+];
+
+/// Serializer that converts an `Event` to bytes using the [Common Event Format][cef].
+///
+/// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+#[derive(Debug, Clone)]
+pub struct CefSerializer {
+    options: CefSerializerOptions,
+}
+
+impl CefSerializer {
+    /// Creates a new `CefSerializer`.
+    pub const fn new(options: CefSerializerOptions) -> Self {
+        Self { options }
+    }
+
+    fn extensions<'a>(&'a self, log: &'a LogEvent) -> Vec<(&'a str, &'a Value)> {
+        if self.options.extensions.is_empty() {
+            // The values already encoded in the header.
+            let header_values = [
+                &self.options.device_event_class_id_field,
+                &self.options.name_field,
+                &self.options.severity_field,
+            ]
+            .into_iter()
+            .filter_map(|field| log.get(field))
+            .collect::<Vec<_>>();
+
+            log.as_map()
+                .into_iter()
+                .flatten()
+                .filter(|(key, value)| {
+                    is_valid_key(key)
+                        && !HEADER_FIELDS.contains(&key.as_str())
+                        && !header_values
+                            .iter()
+                            .any(|header_value| std::ptr::eq(*header_value, *value))
+                        && !matches!(value, Value::Object(_) | Value::Array(_))
+                })
+                .map(|(key, value)| (key.as_str(), value))
+                .collect()
+        } else {
+            self.options
+                .extensions
+                .iter()
+                .filter_map(|(key, field)| log.get(field).map(|value| (key.as_str(), value)))
+                .collect()
+        }
+    }
+}
+
+impl Encoder<Event> for CefSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let header_field = |field: &ConfigTargetPath| {
+            log.get(field)
+                .map(|value| to_string(value).into_owned())
+                .unwrap_or_default()
+        };
+
+        buffer.put_slice(b"CEF:0|");
+        for header in [
+            self.options.device_vendor.as_str(),
+            self.options.device_product.as_str(),
+            self.options.device_version.as_str(),
+            &header_field(&self.options.device_event_class_id_field),
+            &header_field(&self.options.name_field),
+            &header_field(&self.options.severity_field),
+        ] {
+            escape_header(header, buffer);
+            buffer.put_u8(b'|');
+        }
+
+        for (i, (key, value)) in self.extensions(&log).into_iter().enumerate() {
+            if i > 0 {
+                buffer.put_u8(b' ');
+            }
+            buffer.put_slice(key.as_bytes());
+            buffer.put_u8(b'=');
+            escape_extension_value(&to_string(value), buffer);
+        }
+
+        Ok(())
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn to_string(value: &Value) -> std::borrow::Cow<'_, str> {
+    match value {
+        Value::Timestamp(timestamp) => timestamp.timestamp_millis().to_string().into(),
+        value => value.to_string_lossy(),
+    }
+}
+
+fn escape_header(header: &str, buffer: &mut BytesMut) {
+    for c in header.chars() {
+        match c {
+            '\\' => buffer.put_slice(b"\\\\"),
+            '|' => buffer.put_slice(b"\\|"),
+            // Header fields can't span lines.
+            '\n' | '\r' => buffer.put_u8(b' '),
+            c => buffer.put_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+}
+
+fn escape_extension_value(value: &str, buffer: &mut BytesMut) {
+    for c in value.chars() {
+        match c {
+            '\\' => buffer.put_slice(b"\\\\"),
+            '=' => buffer.put_slice(b"\\="),
+            '\n' => buffer.put_slice(b"\\n"),
+            '\r' => buffer.put_slice(b"\\r"),
+            c => buffer.put_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use value::btreemap;
+
+    use super::*;
+
+    fn options(extensions: &[(&str, &str)]) -> CefSerializerOptions {
+        CefSerializerOptions {
+            extensions: extensions
+                .iter()
+                .map(|(key, field)| {
+                    (
+                        key.to_string(),
+                        ConfigTargetPath::try_from(field.to_string()).unwrap(),
+                    )
+                })
+                .collect(),
+            ..CefSerializerOptions::new("Datadog".to_owned(), "Vector".to_owned(), "1.0".to_owned())
+        }
+    }
+
+    fn serialize(options: CefSerializerOptions, event: Event) -> Bytes {
+        let mut buffer = BytesMut::new();
+        CefSerializerConfig::new(options)
+            .build()
+            .unwrap()
+            .encode(event, &mut buffer)
+            .unwrap();
+        buffer.freeze()
+    }
+
+    #[test]
+    fn serialize_cef_with_extension_mapping() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "deviceEventClassId" => 100,
+            "name" => "worm|stopped",
+            "severity" => "High",
+            "source" => btreemap! { "ip" => "10.0.0.1" },
+            "message" => "a=b\nc\\d",
+            "time" => Utc.timestamp_opt(1_600_000_000, 0).unwrap(),
+        }));
+
+        let bytes = serialize(
+            options(&[
+                ("src", "source.ip"),
+                ("msg", "message"),
+                ("rt", "time"),
+                ("dst", "missing"),
+            ]),
+            event,
+        );
+
+        assert_eq!(
+            bytes,
+            r#"CEF:0|Datadog|Vector|1.0|100|worm\|stopped|High|msg=a\=b\nc\\d rt=1600000000000 src=10.0.0.1"#
+        );
+    }
+
+    #[test]
+    fn serialize_cef_with_all_fields() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "deviceVendor" => "Other",
+            "name" => "name",
+            "severity" => 3,
+            "spt" => 1232,
+            "in.valid" => "skipped",
+            "nested" => btreemap! { "skipped" => true },
+        }));
+
+        let bytes = serialize(options(&[]), event);
+
+        assert_eq!(bytes, "CEF:0|Datadog|Vector|1.0||name|3|spt=1232");
+    }
+
+    #[test]
+    fn invalid_extension_key() {
+        assert!(
+            CefSerializerConfig::new(options(&[("in valid", "message")]))
+                .build()
+                .is_err()
+        );
+    }
+}
@@ -4,12 +4,15 @@
 #![deny(missing_docs)]
 
 mod avro;
+mod cef;
 mod csv;
 mod gelf;
 mod json;
 mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
+mod protobuf;
 mod raw_message;
 mod text;
 
@@ -17,12 +20,17 @@ use std::fmt::Debug;
 
 pub use self::csv::{CsvSerializer, CsvSerializerConfig};
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
+pub use cef::{CefSerializer, CefSerializerConfig, CefSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
+pub use msgpack::{MessagePackSerializer, MessagePackSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
+pub use protobuf::{
+    ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions, UnknownFieldsPolicy,
+};
 pub use raw_message::{RawMessageSerializer, RawMessageSerializerConfig};
 pub use text::{TextSerializer, TextSerializerConfig};
 use vector_core::event::Event;
@@ -0,0 +1,161 @@
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::Encoder;
+use vector_core::{config::DataType, event::Event, schema};
+
+use crate::{msgpack, MetricTagValues};
+
+/// Config used to build a `MessagePackSerializer`.
+#[crate::configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct MessagePackSerializerConfig {
+    /// Controls how metric tag values are encoded.
+    ///
+    /// When set to `single`, only the last non-bare value of tags are displayed with the
+    /// metric.  When set to `full`, all metric tags are exposed as separate assignments.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub metric_tag_values: MetricTagValues,
+}
+
+impl MessagePackSerializerConfig {
+    /// Creates a new `MessagePackSerializerConfig`.
+    pub const fn new(metric_tag_values: MetricTagValues) -> Self {
+        Self { metric_tag_values }
+    }
+
+    /// Build the `MessagePackSerializer` from this configuration.
+    pub const fn build(&self) -> MessagePackSerializer {
+        MessagePackSerializer::new(self.metric_tag_values)
+    }
+
+    /// The data type of events that are accepted by `MessagePackSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::all()
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [MessagePack][msgpack] format.
+///
+/// Logs and traces are encoded as a map of their fields, and metrics as a map with the same
+/// structure as the one of the JSON serializer.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone)]
+pub struct MessagePackSerializer {
+    metric_tag_values: MetricTagValues,
+}
+
+impl MessagePackSerializer {
+    /// Creates a new `MessagePackSerializer`.
+    pub const fn new(metric_tag_values: MetricTagValues) -> Self {
+        Self { metric_tag_values }
+    }
+}
+
+impl Encoder<Event> for MessagePackSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let mut writer = buffer.writer();
+        match event {
+            Event::Log(log) => {
+                rmpv::encode::write_value(&mut writer, &msgpack::from_value(log.value()))?
+            }
+            Event::Metric(mut metric) => {
+                if self.metric_tag_values == MetricTagValues::Single {
+                    metric.reduce_tags_to_single();
+                }
+                rmp_serde::encode::write_named(&mut writer, &metric)?
+            }
+            Event::Trace(trace) => {
+                rmpv::encode::write_value(&mut writer, &msgpack::from_value(trace.value()))?
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use value::btreemap;
+    use vector_core::event::{LogEvent, Metric, MetricKind, MetricValue, Value};
+
+    use super::*;
+
+    fn serialize(input: Event) -> Bytes {
+        let mut buffer = BytesMut::new();
+        MessagePackSerializerConfig::default()
+            .build()
+            .encode(input, &mut buffer)
+            .unwrap();
+        buffer.freeze()
+    }
+
+    fn deserialize(bytes: &[u8]) -> rmpv::Value {
+        rmpv::decode::read_value(&mut &bytes[..]).unwrap()
+    }
+
+    #[test]
+    fn serialize_msgpack_log() {
+        let timestamp = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "x" => Value::from("23"),
+            "z" => Value::from(25),
+            "binary" => Value::Bytes(Bytes::from_static(b"\xff")),
+            "time" => Value::Timestamp(timestamp),
+        }));
+
+        let value = deserialize(&serialize(event));
+
+        assert_eq!(
+            value,
+            rmpv::Value::Map(vec![
+                (rmpv::Value::from("binary"), rmpv::Value::Binary(vec![0xff])),
+                (
+                    rmpv::Value::from("time"),
+                    rmpv::Value::Ext(-1, 1_600_000_000_u32.to_be_bytes().to_vec())
+                ),
+                (rmpv::Value::from("x"), rmpv::Value::from("23")),
+                (rmpv::Value::from("z"), rmpv::Value::from(25)),
+            ])
+        );
+    }
+
+    #[test]
+    fn serialize_msgpack_metric() {
+        let event = Event::Metric(Metric::new(
+            "foos",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 100.0 },
+        ));
+
+        let value = deserialize(&serialize(event));
+
+        let field = |name: &str| {
+            value
+                .as_map()
+                .unwrap()
+                .iter()
+                .find(|(key, _)| key.as_str() == Some(name))
+                .map(|(_, value)| value.clone())
+        };
+        assert_eq!(field("name"), Some(rmpv::Value::from("foos")));
+        assert_eq!(field("kind"), Some(rmpv::Value::from("incremental")));
+        assert_eq!(
+            field("counter"),
+            Some(rmpv::Value::Map(vec![(
+                rmpv::Value::from("value"),
+                rmpv::Value::F64(100.0)
+            )]))
+        );
+    }
+}
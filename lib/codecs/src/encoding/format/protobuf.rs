@@ -0,0 +1,480 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use bytes::BytesMut;
+use prost::Message as _;
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MapKey, MessageDescriptor,
+    Value as ProtoValue,
+};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+use crate::encoding::BuildError;
+
+/// The full name of the well-known message type for timestamps.
+const TIMESTAMP_MESSAGE_TYPE: &str = "google.protobuf.Timestamp";
+
+/// Config used to build a `ProtobufSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtobufSerializerConfig {
+    /// Options for the Protobuf serializer.
+    pub protobuf: ProtobufSerializerOptions,
+}
+
+impl ProtobufSerializerConfig {
+    /// Build the `ProtobufSerializer` from this configuration.
+    pub fn build(&self) -> Result<ProtobufSerializer, BuildError> {
+        let descriptor_set = std::fs::read(&self.protobuf.desc_file).map_err(|error| {
+            format!(
+                "Failed reading Protobuf descriptor set {:?}: {}",
+                self.protobuf.desc_file, error
+            )
+        })?;
+        let pool = DescriptorPool::decode(descriptor_set.as_slice()).map_err(|error| {
+            format!(
+                "Failed decoding Protobuf descriptor set {:?}: {}",
+                self.protobuf.desc_file, error
+            )
+        })?;
+        let message_descriptor = pool
+            .get_message_by_name(&self.protobuf.message_type)
+            .ok_or_else(|| {
+                format!(
+                    "Message type {:?} not found in Protobuf descriptor set {:?}",
+                    self.protobuf.message_type, self.protobuf.desc_file
+                )
+            })?;
+
+        Ok(ProtobufSerializer::new(
+            message_descriptor,
+            self.protobuf.unknown_fields,
+        ))
+    }
+
+    /// The data type of events that are accepted by `ProtobufSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // TODO: Convert the message descriptor to a vector schema requirement.
+        schema::Requirement::empty()
+    }
+}
+
+/// Protobuf serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ProtobufSerializerOptions {
+    /// The path to the Protobuf descriptor set file.
+    ///
+    /// This file is the output of `protoc -I <include path> -o <desc output path> <proto>`, and
+    /// must contain the message type to encode events as, along with its dependencies.
+    #[configurable(metadata(docs::examples = "/etc/vector/protobuf_descriptor_set.desc"))]
+    pub desc_file: PathBuf,
+
+    /// The full name of the message type to encode events as.
+    #[configurable(metadata(docs::examples = "package.Message"))]
+    pub message_type: String,
+
+    /// How to handle the fields of events that have no matching field in the message type.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub unknown_fields: UnknownFieldsPolicy,
+}
+
+/// How to handle the fields of events that have no matching field in the message type.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownFieldsPolicy {
+    /// The fields are left out of the encoded message.
+    #[default]
+    Ignore,
+
+    /// Events with such fields fail to encode, and are dropped.
+    Reject,
+}
+
+/// Serializer that converts an `Event` to bytes using a Protobuf message type.
+///
+/// The fields of log events are mapped to the fields of the message type by name, recursively.
+#[derive(Debug, Clone)]
+pub struct ProtobufSerializer {
+    message_descriptor: MessageDescriptor,
+    unknown_fields: UnknownFieldsPolicy,
+}
+
+impl ProtobufSerializer {
+    /// Creates a new `ProtobufSerializer`.
+    pub const fn new(
+        message_descriptor: MessageDescriptor,
+        unknown_fields: UnknownFieldsPolicy,
+    ) -> Self {
+        Self {
+            message_descriptor,
+            unknown_fields,
+        }
+    }
+
+    /// The descriptor of the message type that events are encoded as.
+    pub const fn descriptor(&self) -> &MessageDescriptor {
+        &self.message_descriptor
+    }
+
+    fn to_message(
+        &self,
+        descriptor: &MessageDescriptor,
+        value: &Value,
+    ) -> vector_common::Result<DynamicMessage> {
+        let mut message = DynamicMessage::new(descriptor.clone());
+
+        if descriptor.full_name() == TIMESTAMP_MESSAGE_TYPE {
+            if let Value::Timestamp(timestamp) = value {
+                message.set_field_by_name("seconds", ProtoValue::I64(timestamp.timestamp()));
+                message.set_field_by_name(
+                    "nanos",
+                    ProtoValue::I32(timestamp.timestamp_subsec_nanos() as i32),
+                );
+                return Ok(message);
+            }
+        }
+
+        let Value::Object(fields) = value else {
+            return Err(format!(
+                "Expected an object for message type {:?}, found {}",
+                descriptor.full_name(),
+                value.kind_str()
+            )
+            .into());
+        };
+
+        for (name, value) in fields {
+            let Some(field) = descriptor.get_field_by_name(name) else {
+                match self.unknown_fields {
+                    UnknownFieldsPolicy::Ignore => continue,
+                    UnknownFieldsPolicy::Reject => {
+                        return Err(format!(
+                            "Field {:?} not found in message type {:?}",
+                            name,
+                            descriptor.full_name()
+                        )
+                        .into())
+                    }
+                }
+            };
+            if matches!(value, Value::Null) {
+                continue;
+            }
+
+            let value = self.to_field_value(&field, value).map_err(|error| {
+                format!(
+                    "Failed encoding field {:?} of message type {:?}: {}",
+                    name,
+                    descriptor.full_name(),
+                    error
+                )
+            })?;
+            message.set_field(&field, value);
+        }
+
+        Ok(message)
+    }
+
+    fn to_field_value(
+        &self,
+        field: &FieldDescriptor,
+        value: &Value,
+    ) -> vector_common::Result<ProtoValue> {
+        if field.is_map() {
+            let Value::Object(entries) = value else {
+                return Err(format!("expected an object, found {}", value.kind_str()).into());
+            };
+            let Kind::Message(entry) = field.kind() else {
+                unreachable!("map fields are messages");
+            };
+            let key_kind = entry.map_entry_key_field().kind();
+            let value_kind = entry.map_entry_value_field().kind();
+            entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        to_map_key(&key_kind, key)?,
+                        self.to_value(&value_kind, value)?,
+                    ))
+                })
+                .collect::<vector_common::Result<HashMap<_, _>>>()
+                .map(ProtoValue::Map)
+        } else if field.is_list() {
+            let kind = field.kind();
+            match value {
+                Value::Array(values) => values
+                    .iter()
+                    .map(|value| self.to_value(&kind, value))
+                    .collect::<vector_common::Result<Vec<_>>>()
+                    .map(ProtoValue::List),
+                value => Ok(ProtoValue::List(vec![self.to_value(&kind, value)?])),
+            }
+        } else {
+            self.to_value(&field.kind(), value)
+        }
+    }
+
+    fn to_value(&self, kind: &Kind, value: &Value) -> vector_common::Result<ProtoValue> {
+        let mismatch = || -> vector_common::Error {
+            format!("expected {:?}, found {}", kind, value.kind_str()).into()
+        };
+
+        Ok(match (kind, value) {
+            (Kind::Double, Value::Float(f)) => ProtoValue::F64(f.into_inner()),
+            (Kind::Double, Value::Integer(i)) => ProtoValue::F64(*i as f64),
+            (Kind::Float, Value::Float(f)) => ProtoValue::F32(f.into_inner() as f32),
+            (Kind::Float, Value::Integer(i)) => ProtoValue::F32(*i as f32),
+            (Kind::Int32 | Kind::Sint32 | Kind::Sfixed32, Value::Integer(i)) => {
+                ProtoValue::I32(i32::try_from(*i)?)
+            }
+            (Kind::Int64 | Kind::Sint64 | Kind::Sfixed64, Value::Integer(i)) => ProtoValue::I64(*i),
+            (Kind::Uint32 | Kind::Fixed32, Value::Integer(i)) => {
+                ProtoValue::U32(u32::try_from(*i)?)
+            }
+            (Kind::Uint64 | Kind::Fixed64, Value::Integer(i)) => {
+                ProtoValue::U64(u64::try_from(*i)?)
+            }
+            (Kind::Int64 | Kind::Sint64 | Kind::Sfixed64, Value::Timestamp(ts)) => {
+                ProtoValue::I64(ts.timestamp_nanos())
+            }
+            (Kind::Bool, Value::Boolean(b)) => ProtoValue::Bool(*b),
+            (Kind::String, Value::Bytes(bytes)) => {
+                ProtoValue::String(String::from_utf8_lossy(bytes).into_owned())
+            }
+            (Kind::String, Value::Timestamp(ts)) => ProtoValue::String(ts.to_rfc3339()),
+            (Kind::Bytes, Value::Bytes(bytes)) => ProtoValue::Bytes(bytes.clone()),
+            (Kind::Enum(_), Value::Integer(i)) => ProtoValue::EnumNumber(i32::try_from(*i)?),
+            (Kind::Enum(descriptor), Value::Bytes(bytes)) => {
+                let name = String::from_utf8_lossy(bytes);
+                let value = descriptor.get_value_by_name(&name).ok_or_else(|| {
+                    format!(
+                        "value {:?} not found in enum type {:?}",
+                        name,
+                        descriptor.full_name()
+                    )
+                })?;
+                ProtoValue::EnumNumber(value.number())
+            }
+            (Kind::Message(descriptor), value) => {
+                ProtoValue::Message(self.to_message(descriptor, value)?)
+            }
+            _ => return Err(mismatch()),
+        })
+    }
+}
+
+fn to_map_key(kind: &Kind, key: &str) -> vector_common::Result<MapKey> {
+    Ok(match kind {
+        Kind::String => MapKey::String(key.to_owned()),
+        Kind::Bool => MapKey::Bool(key.parse()?),
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => MapKey::I32(key.parse()?),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => MapKey::I64(key.parse()?),
+        Kind::Uint32 | Kind::Fixed32 => MapKey::U32(key.parse()?),
+        Kind::Uint64 | Kind::Fixed64 => MapKey::U64(key.parse()?),
+        kind => return Err(format!("invalid map key type {:?}", kind).into()),
+    })
+}
+
+impl Encoder<Event> for ProtobufSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let message = self.to_message(&self.message_descriptor, log.value())?;
+        message.encode(buffer)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use prost_types::{
+        field_descriptor_proto::{Label, Type},
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+    use value::btreemap;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    fn field(
+        name: &str,
+        number: i32,
+        r#type: Type,
+        type_name: Option<&str>,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_owned()),
+            number: Some(number),
+            label: Some(Label::Optional as i32),
+            r#type: Some(r#type as i32),
+            type_name: type_name.map(ToOwned::to_owned),
+            ..Default::default()
+        }
+    }
+
+    fn descriptor() -> MessageDescriptor {
+        let file = FileDescriptorProto {
+            name: Some("test.proto".to_owned()),
+            package: Some("test".to_owned()),
+            syntax: Some("proto3".to_owned()),
+            message_type: vec![
+                DescriptorProto {
+                    name: Some("Log".to_owned()),
+                    field: vec![
+                        field("message", 1, Type::String, None),
+                        field("status", 2, Type::Int32, None),
+                        field("level", 3, Type::Enum, Some(".test.Level")),
+                        field("source", 4, Type::Message, Some(".test.Source")),
+                        FieldDescriptorProto {
+                            label: Some(Label::Repeated as i32),
+                            ..field("tags", 5, Type::String, None)
+                        },
+                        field("payload", 6, Type::Bytes, None),
+                    ],
+                    ..Default::default()
+                },
+                DescriptorProto {
+                    name: Some("Source".to_owned()),
+                    field: vec![
+                        field("host", 1, Type::String, None),
+                        field("time", 2, Type::Int64, None),
+                    ],
+                    ..Default::default()
+                },
+            ],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Level".to_owned()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("INFO".to_owned()),
+                        number: Some(0),
+                        ..Default::default()
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("ERROR".to_owned()),
+                        number: Some(1),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let pool = DescriptorPool::from_file_descriptor_set(FileDescriptorSet { file: vec![file] })
+            .unwrap();
+        pool.get_message_by_name("test.Log").unwrap()
+    }
+
+    fn encode(
+        unknown_fields: UnknownFieldsPolicy,
+        event: LogEvent,
+    ) -> vector_common::Result<DynamicMessage> {
+        let descriptor = descriptor();
+        let mut serializer = ProtobufSerializer::new(descriptor.clone(), unknown_fields);
+        let mut bytes = BytesMut::new();
+        serializer.encode(Event::Log(event), &mut bytes)?;
+        Ok(DynamicMessage::decode(descriptor, bytes.freeze()).unwrap())
+    }
+
+    #[test]
+    fn serialize_protobuf() {
+        let time = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let message = encode(
+            UnknownFieldsPolicy::Ignore,
+            LogEvent::from(btreemap! {
+                "message" => "hello",
+                "status" => 200,
+                "level" => "ERROR",
+                "source" => btreemap! {
+                    "host" => "localhost",
+                    "time" => Value::Timestamp(time),
+                },
+                "tags" => vec!["a", "b"],
+                "payload" => Value::Bytes(Bytes::from_static(b"\x00\x01")),
+                "unknown" => true,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            message.get_field_by_name("message").unwrap().as_str(),
+            Some("hello")
+        );
+        assert_eq!(
+            message.get_field_by_name("status").unwrap().as_i32(),
+            Some(200)
+        );
+        assert_eq!(
+            message.get_field_by_name("level").unwrap().as_enum_number(),
+            Some(1)
+        );
+        let source = message.get_field_by_name("source").unwrap();
+        let source = source.as_message().unwrap();
+        assert_eq!(
+            source.get_field_by_name("host").unwrap().as_str(),
+            Some("localhost")
+        );
+        assert_eq!(
+            source.get_field_by_name("time").unwrap().as_i64(),
+            Some(time.timestamp_nanos())
+        );
+        assert_eq!(
+            message.get_field_by_name("tags").unwrap().as_list(),
+            Some(
+                [
+                    ProtoValue::String("a".to_owned()),
+                    ProtoValue::String("b".to_owned())
+                ]
+                .as_slice()
+            )
+        );
+        assert_eq!(
+            message.get_field_by_name("payload").unwrap().as_bytes(),
+            Some(&Bytes::from_static(b"\x00\x01"))
+        );
+    }
+
+    #[test]
+    fn serialize_protobuf_rejects_unknown_fields() {
+        let event = LogEvent::from(btreemap! {
+            "message" => "hello",
+            "unknown" => true,
+        });
+
+        assert!(encode(UnknownFieldsPolicy::Ignore, event.clone()).is_ok());
+        assert!(encode(UnknownFieldsPolicy::Reject, event).is_err());
+    }
+
+    #[test]
+    fn serialize_protobuf_rejects_mismatched_types() {
+        assert!(encode(
+            UnknownFieldsPolicy::Ignore,
+            LogEvent::from(btreemap! { "status" => "ok" })
+        )
+        .is_err());
+        assert!(encode(
+            UnknownFieldsPolicy::Ignore,
+            LogEvent::from(btreemap! { "status" => i64::MAX })
+        )
+        .is_err());
+        assert!(encode(
+            UnknownFieldsPolicy::Ignore,
+            LogEvent::from(btreemap! { "level" => "DEBUG" })
+        )
+        .is_err());
+    }
+}
@@ -8,11 +8,13 @@ use std::fmt::Debug;
 
 use bytes::BytesMut;
 pub use format::{
-    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CsvSerializer,
-    CsvSerializerConfig, GelfSerializer, GelfSerializerConfig, JsonSerializer,
-    JsonSerializerConfig, LogfmtSerializer, LogfmtSerializerConfig, NativeJsonSerializer,
-    NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig, RawMessageSerializer,
-    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
+    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
+    CefSerializerConfig, CefSerializerOptions, CsvSerializer, CsvSerializerConfig, GelfSerializer,
+    GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
+    LogfmtSerializerConfig, MessagePackSerializer, MessagePackSerializerConfig,
+    NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig,
+    ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions, RawMessageSerializer,
+    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig, UnknownFieldsPolicy,
 };
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
@@ -197,6 +199,16 @@ pub enum SerializerConfig {
         avro: AvroSerializerOptions,
     },
 
+    /// Encodes an event as a [Common Event Format (CEF)][cef] message.
+    ///
+    /// This codec must be configured with the device vendor, product, and version.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+    Cef(
+        /// Options for the CEF encoder.
+        CefSerializerConfig,
+    ),
+
     /// Encodes an event as a CSV message.
     ///
     /// This codec must be configured with fields to encode.
@@ -224,6 +236,19 @@ pub enum SerializerConfig {
     /// [logfmt]: https://brandur.org/logfmt
     Logfmt,
 
+    /// Encodes an event as a [MessagePack][msgpack] map.
+    ///
+    /// Logs and traces are encoded as a map of their fields, with timestamps encoded with the
+    /// timestamp extension type of MessagePack. Metrics are encoded as a map with the same
+    /// structure as the one of the `json` codec.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    #[serde(rename = "msgpack")]
+    MessagePack(
+        /// Encoding options specific to the MessagePack serializer.
+        MessagePackSerializerConfig,
+    ),
+
     /// Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
     ///
     /// This codec is **[experimental][experimental]**.
@@ -240,6 +265,16 @@ pub enum SerializerConfig {
     /// [experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
     NativeJson,
 
+    /// Encodes an event as a [Protobuf][protobuf] message of a user-provided message type.
+    ///
+    /// The fields of the event are mapped to the fields of the message type by name.
+    ///
+    /// [protobuf]: https://protobuf.dev/
+    Protobuf {
+        /// Protobuf-specific encoder options.
+        protobuf: ProtobufSerializerOptions,
+    },
+
     /// No encoding.
     ///
     /// This encoding uses the `message` field of a log event.
@@ -269,6 +304,12 @@ impl From<AvroSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<CefSerializerConfig> for SerializerConfig {
+    fn from(config: CefSerializerConfig) -> Self {
+        Self::Cef(config)
+    }
+}
+
 impl From<CsvSerializerConfig> for SerializerConfig {
     fn from(config: CsvSerializerConfig) -> Self {
         Self::Csv(config)
@@ -293,6 +334,12 @@ impl From<LogfmtSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<MessagePackSerializerConfig> for SerializerConfig {
+    fn from(config: MessagePackSerializerConfig) -> Self {
+        Self::MessagePack(config)
+    }
+}
+
 impl From<NativeSerializerConfig> for SerializerConfig {
     fn from(_: NativeSerializerConfig) -> Self {
         Self::Native
@@ -305,6 +352,14 @@ impl From<NativeJsonSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<ProtobufSerializerConfig> for SerializerConfig {
+    fn from(config: ProtobufSerializerConfig) -> Self {
+        Self::Protobuf {
+            protobuf: config.protobuf,
+        }
+    }
+}
+
 impl From<RawMessageSerializerConfig> for SerializerConfig {
     fn from(_: RawMessageSerializerConfig) -> Self {
         Self::RawMessage
@@ -322,16 +377,24 @@ impl SerializerConfig {
     pub fn build(&self) -> Result<Serializer, Box<dyn std::error::Error + Send + Sync + 'static>> {
         match self {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
-                AvroSerializerConfig::new(avro.schema.clone()).build()?,
+                AvroSerializerConfig { avro: avro.clone() }.build()?,
             )),
+            SerializerConfig::Cef(config) => Ok(Serializer::Cef(config.build()?)),
             SerializerConfig::Csv(config) => Ok(Serializer::Csv(config.build()?)),
             SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
             SerializerConfig::Json(config) => Ok(Serializer::Json(config.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
+            SerializerConfig::MessagePack(config) => Ok(Serializer::MessagePack(config.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
             SerializerConfig::NativeJson => {
                 Ok(Serializer::NativeJson(NativeJsonSerializerConfig.build()))
             }
+            SerializerConfig::Protobuf { protobuf } => Ok(Serializer::Protobuf(
+                ProtobufSerializerConfig {
+                    protobuf: protobuf.clone(),
+                }
+                .build()?,
+            )),
             SerializerConfig::RawMessage => {
                 Ok(Serializer::RawMessage(RawMessageSerializerConfig.build()))
             }
@@ -353,10 +416,12 @@ impl SerializerConfig {
             // we should do so accurately, even if practically it doesn't need to be.
             //
             // [1]: https://avro.apache.org/docs/1.11.1/specification/_print/#message-framing
-            SerializerConfig::Avro { .. } | SerializerConfig::Native => {
-                FramingConfig::LengthDelimited
-            }
-            SerializerConfig::Csv(_)
+            SerializerConfig::Avro { .. }
+            | SerializerConfig::MessagePack(_)
+            | SerializerConfig::Native
+            | SerializerConfig::Protobuf { .. } => FramingConfig::LengthDelimited,
+            SerializerConfig::Cef(_)
+            | SerializerConfig::Csv(_)
             | SerializerConfig::Gelf
             | SerializerConfig::Json(_)
             | SerializerConfig::Logfmt
@@ -370,14 +435,20 @@ impl SerializerConfig {
     pub fn input_type(&self) -> DataType {
         match self {
             SerializerConfig::Avro { avro } => {
-                AvroSerializerConfig::new(avro.schema.clone()).input_type()
+                AvroSerializerConfig { avro: avro.clone() }.input_type()
             }
+            SerializerConfig::Cef(config) => config.input_type(),
             SerializerConfig::Csv(config) => config.input_type(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::input_type(),
             SerializerConfig::Json(config) => config.input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
+            SerializerConfig::MessagePack(config) => config.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .input_type(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.input_type(),
             SerializerConfig::Text(config) => config.input_type(),
         }
@@ -387,14 +458,20 @@ impl SerializerConfig {
     pub fn schema_requirement(&self) -> schema::Requirement {
         match self {
             SerializerConfig::Avro { avro } => {
-                AvroSerializerConfig::new(avro.schema.clone()).schema_requirement()
+                AvroSerializerConfig { avro: avro.clone() }.schema_requirement()
             }
+            SerializerConfig::Cef(config) => config.schema_requirement(),
             SerializerConfig::Csv(config) => config.schema_requirement(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::schema_requirement(),
             SerializerConfig::Json(config) => config.schema_requirement(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
+            SerializerConfig::MessagePack(config) => config.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .schema_requirement(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.schema_requirement(),
             SerializerConfig::Text(config) => config.schema_requirement(),
         }
@@ -406,6 +483,8 @@ impl SerializerConfig {
 pub enum Serializer {
     /// Uses an `AvroSerializer` for serialization.
     Avro(AvroSerializer),
+    /// Uses a `CefSerializer` for serialization.
+    Cef(CefSerializer),
     /// Uses a `CsvSerializer` for serialization.
     Csv(CsvSerializer),
     /// Uses a `GelfSerializer` for serialization.
@@ -414,10 +493,14 @@ pub enum Serializer {
     Json(JsonSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
     Logfmt(LogfmtSerializer),
+    /// Uses a `MessagePackSerializer` for serialization.
+    MessagePack(MessagePackSerializer),
     /// Uses a `NativeSerializer` for serialization.
     Native(NativeSerializer),
     /// Uses a `NativeJsonSerializer` for serialization.
     NativeJson(NativeJsonSerializer),
+    /// Uses a `ProtobufSerializer` for serialization.
+    Protobuf(ProtobufSerializer),
     /// Uses a `RawMessageSerializer` for serialization.
     RawMessage(RawMessageSerializer),
     /// Uses a `TextSerializer` for serialization.
@@ -430,10 +513,13 @@ impl Serializer {
         match self {
             Serializer::Json(_) | Serializer::NativeJson(_) | Serializer::Gelf(_) => true,
             Serializer::Avro(_)
+            | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::MessagePack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
+            | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => false,
         }
     }
@@ -450,10 +536,13 @@ impl Serializer {
             Serializer::Json(serializer) => serializer.to_json_value(event),
             Serializer::NativeJson(serializer) => serializer.to_json_value(event),
             Serializer::Avro(_)
+            | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::MessagePack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
+            | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => {
                 panic!("Serializer does not support JSON")
             }
@@ -467,6 +556,12 @@ impl From<AvroSerializer> for Serializer {
     }
 }
 
+impl From<CefSerializer> for Serializer {
+    fn from(serializer: CefSerializer) -> Self {
+        Self::Cef(serializer)
+    }
+}
+
 impl From<CsvSerializer> for Serializer {
     fn from(serializer: CsvSerializer) -> Self {
         Self::Csv(serializer)
@@ -491,6 +586,12 @@ impl From<LogfmtSerializer> for Serializer {
     }
 }
 
+impl From<MessagePackSerializer> for Serializer {
+    fn from(serializer: MessagePackSerializer) -> Self {
+        Self::MessagePack(serializer)
+    }
+}
+
 impl From<NativeSerializer> for Serializer {
     fn from(serializer: NativeSerializer) -> Self {
         Self::Native(serializer)
@@ -503,6 +604,12 @@ impl From<NativeJsonSerializer> for Serializer {
     }
 }
 
+impl From<ProtobufSerializer> for Serializer {
+    fn from(serializer: ProtobufSerializer) -> Self {
+        Self::Protobuf(serializer)
+    }
+}
+
 impl From<RawMessageSerializer> for Serializer {
     fn from(serializer: RawMessageSerializer) -> Self {
         Self::RawMessage(serializer)
@@ -521,12 +628,15 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
         match self {
             Serializer::Avro(serializer) => serializer.encode(event, buffer),
+            Serializer::Cef(serializer) => serializer.encode(event, buffer),
             Serializer::Csv(serializer) => serializer.encode(event, buffer),
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
+            Serializer::MessagePack(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
+            Serializer::Protobuf(serializer) => serializer.encode(event, buffer),
             Serializer::RawMessage(serializer) => serializer.encode(event, buffer),
             Serializer::Text(serializer) => serializer.encode(event, buffer),
         }
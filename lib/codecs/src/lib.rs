@@ -4,18 +4,20 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+pub mod avro;
 pub mod decoding;
 pub mod encoding;
 pub mod gelf;
+mod msgpack;
 
 pub use decoding::{
-    BytesDecoder, BytesDecoderConfig, BytesDeserializer, BytesDeserializerConfig,
-    CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig, GelfDeserializer,
-    GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, LengthDelimitedDecoder,
-    LengthDelimitedDecoderConfig, NativeDeserializer, NativeDeserializerConfig,
-    NativeJsonDeserializer, NativeJsonDeserializerConfig, NewlineDelimitedDecoder,
-    NewlineDelimitedDecoderConfig, OctetCountingDecoder, OctetCountingDecoderConfig,
-    StreamDecodingError,
+    AvroDeserializer, AvroDeserializerConfig, BytesDecoder, BytesDecoderConfig, BytesDeserializer,
+    BytesDeserializerConfig, CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig,
+    GelfDeserializer, GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig,
+    LengthDelimitedDecoder, LengthDelimitedDecoderConfig, MultilineDecoder, MultilineDecoderConfig,
+    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
+    NativeJsonDeserializerConfig, NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig,
+    OctetCountingDecoder, OctetCountingDecoderConfig, StreamDecodingError,
 };
 #[cfg(feature = "syslog")]
 pub use decoding::{SyslogDeserializer, SyslogDeserializerConfig};
@@ -0,0 +1,158 @@
+//! Conversions between [MessagePack][msgpack] values and Vector values, shared by the MessagePack
+//! codecs.
+//!
+//! Timestamps are represented with the [timestamp extension type][timestamp] of MessagePack.
+//!
+//! [msgpack]: https://msgpack.org/
+//! [timestamp]: https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type
+
+use chrono::{DateTime, TimeZone, Utc};
+use ordered_float::NotNan;
+use value::Value;
+
+/// The extension type of timestamps.
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Converts a Vector value to a MessagePack value.
+///
+/// Bytes are converted to strings if they are valid UTF-8, and to binary otherwise.
+pub(crate) fn from_value(value: &Value) -> rmpv::Value {
+    match value {
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(string) => rmpv::Value::from(string),
+            Err(_) => rmpv::Value::Binary(bytes.to_vec()),
+        },
+        Value::Regex(regex) => rmpv::Value::from(regex.as_str()),
+        Value::Integer(integer) => rmpv::Value::from(*integer),
+        Value::Float(float) => rmpv::Value::F64(float.into_inner()),
+        Value::Boolean(boolean) => rmpv::Value::Boolean(*boolean),
+        Value::Timestamp(timestamp) => {
+            rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, encode_timestamp(timestamp))
+        }
+        Value::Object(fields) => rmpv::Value::Map(
+            fields
+                .iter()
+                .map(|(key, value)| (rmpv::Value::from(key.as_str()), from_value(value)))
+                .collect(),
+        ),
+        Value::Array(values) => rmpv::Value::Array(values.iter().map(from_value).collect()),
+        Value::Null => rmpv::Value::Nil,
+    }
+}
+
+/// Converts a MessagePack value to a Vector value.
+///
+/// Integers that don't fit in an `i64` are converted to strings, and floats that are NaN to
+/// `null`, like the conversion of JSON values does. Map keys that aren't strings are converted to
+/// their MessagePack representation as a string.
+pub(crate) fn to_value(value: rmpv::Value) -> Value {
+    match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(boolean) => Value::Boolean(boolean),
+        rmpv::Value::Integer(integer) => integer
+            .as_i64()
+            .map(Value::Integer)
+            .unwrap_or_else(|| Value::Bytes(integer.to_string().into())),
+        rmpv::Value::F32(float) => NotNan::new(float as f64)
+            .map(Value::Float)
+            .unwrap_or(Value::Null),
+        rmpv::Value::F64(float) => NotNan::new(float).map(Value::Float).unwrap_or(Value::Null),
+        rmpv::Value::String(string) => Value::Bytes(string.into_bytes().into()),
+        rmpv::Value::Binary(bytes) => Value::Bytes(bytes.into()),
+        rmpv::Value::Array(values) => Value::Array(values.into_iter().map(to_value).collect()),
+        rmpv::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        rmpv::Value::String(key) => {
+                            String::from_utf8_lossy(key.as_bytes()).into_owned()
+                        }
+                        key => key.to_string(),
+                    };
+                    (key, to_value(value))
+                })
+                .collect(),
+        ),
+        rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, data) => match decode_timestamp(&data) {
+            Some(timestamp) => Value::Timestamp(timestamp),
+            None => Value::Bytes(data.into()),
+        },
+        rmpv::Value::Ext(_, data) => Value::Bytes(data.into()),
+    }
+}
+
+/// Encodes a timestamp in the most compact of the timestamp 32, 64 and 96 formats.
+fn encode_timestamp(timestamp: &DateTime<Utc>) -> Vec<u8> {
+    let seconds = timestamp.timestamp();
+    let nanoseconds = timestamp.timestamp_subsec_nanos();
+
+    if seconds >> 34 == 0 {
+        let data = (u64::from(nanoseconds) << 34) | seconds as u64;
+        if data >> 32 == 0 {
+            (data as u32).to_be_bytes().to_vec()
+        } else {
+            data.to_be_bytes().to_vec()
+        }
+    } else {
+        let mut data = nanoseconds.to_be_bytes().to_vec();
+        data.extend_from_slice(&seconds.to_be_bytes());
+        data
+    }
+}
+
+fn decode_timestamp(data: &[u8]) -> Option<DateTime<Utc>> {
+    let (seconds, nanoseconds) = match data.len() {
+        4 => (i64::from(u32::from_be_bytes(data.try_into().ok()?)), 0),
+        8 => {
+            let data = u64::from_be_bytes(data.try_into().ok()?);
+            ((data & 0x3_ffff_ffff) as i64, (data >> 34) as u32)
+        }
+        12 => (
+            i64::from_be_bytes(data[4..].try_into().ok()?),
+            u32::from_be_bytes(data[..4].try_into().ok()?),
+        ),
+        _ => return None,
+    };
+    Utc.timestamp_opt(seconds, nanoseconds).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_round_trip() {
+        for (seconds, nanoseconds, len) in [
+            (1_600_000_000, 0, 4),
+            (1_600_000_000, 123_456_789, 8),
+            (-1, 500, 12),
+            (1 << 35, 0, 12),
+        ] {
+            let timestamp = Utc.timestamp_opt(seconds, nanoseconds).unwrap();
+            let data = encode_timestamp(&timestamp);
+            assert_eq!(data.len(), len);
+            assert_eq!(decode_timestamp(&data), Some(timestamp));
+        }
+    }
+
+    #[test]
+    fn non_string_keys() {
+        let value = to_value(rmpv::Value::Map(vec![
+            (rmpv::Value::from(1), rmpv::Value::from("one")),
+            (rmpv::Value::from("two"), rmpv::Value::Binary(vec![2])),
+        ]));
+
+        assert_eq!(
+            value,
+            Value::Object(
+                [
+                    ("1".to_owned(), Value::from("one")),
+                    ("two".to_owned(), Value::Bytes(vec![2].into())),
+                ]
+                .into_iter()
+                .collect()
+            )
+        );
+    }
+}
@@ -32,12 +32,12 @@ enum State {
 
 /// A simple JSON-friendly struct of the fingerprint/position pair, since
 /// fingerprints as objects cannot be keys in a plain JSON map.
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
 #[serde(rename_all = "snake_case")]
-struct Checkpoint {
-    fingerprint: FileFingerprint,
-    position: FilePosition,
-    modified: DateTime<Utc>,
+pub struct Checkpoint {
+    pub fingerprint: FileFingerprint,
+    pub position: FilePosition,
+    pub modified: DateTime<Utc>,
 }
 
 pub struct Checkpointer {
@@ -139,6 +139,12 @@ impl CheckpointsView {
         }
     }
 
+    fn clear(&self) {
+        self.checkpoints.clear();
+        self.modified_times.clear();
+        self.removed_times.clear();
+    }
+
     fn get_state(&self) -> State {
         State::V1 {
             checkpoints: self
@@ -329,6 +335,47 @@ impl Checkpointer {
         Ok(self.checkpoints.checkpoints.len())
     }
 
+    /// Get a snapshot of the current checkpoints, ordered by fingerprint.
+    pub fn checkpoints(&self) -> Vec<Checkpoint> {
+        match self.checkpoints.get_state() {
+            State::V1 { checkpoints } => checkpoints.into_iter().collect(),
+        }
+    }
+
+    /// Export the current checkpoints, in the same versioned format as the
+    /// checkpoints file, so they can be imported in another data directory.
+    pub fn export_checkpoints<W: io::Write>(&self, writer: W) -> Result<usize, io::Error> {
+        let state = self.checkpoints.get_state();
+        serde_json::to_writer_pretty(writer, &state)?;
+        Ok(self.checkpoints.checkpoints.len())
+    }
+
+    /// Import checkpoints previously exported with `export_checkpoints`, and
+    /// persist them to disk.
+    ///
+    /// Imported checkpoints take precedence over existing ones for the same
+    /// fingerprint. If `replace` is set, existing checkpoints are dropped
+    /// instead.
+    pub fn import_checkpoints<R: io::Read>(
+        &mut self,
+        reader: R,
+        replace: bool,
+    ) -> Result<usize, io::Error> {
+        let state: State = serde_json::from_reader(reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let imported = match &state {
+            State::V1 { checkpoints } => checkpoints.len(),
+        };
+
+        if replace {
+            self.checkpoints.clear();
+        }
+        self.checkpoints.set_state(state, None);
+        self.write_checkpoints()?;
+
+        Ok(imported)
+    }
+
     /// Write checkpoints to disk in the legacy format. Used for compatibility
     /// testing only.
     #[cfg(test)]
@@ -732,6 +779,55 @@ mod test {
         assert_eq!(chkptr.get_checkpoint(cases[3].0), None);
     }
 
+    #[test]
+    fn test_checkpointer_export_import() {
+        let position: FilePosition = 1234;
+        let mut exported = Vec::new();
+
+        {
+            let data_dir = tempdir().unwrap();
+            let mut chkptr = Checkpointer::new(data_dir.path());
+            chkptr.update_checkpoint(FileFingerprint::BytesChecksum(3456), position);
+            chkptr.update_checkpoint(FileFingerprint::DevInode(1, 2), position);
+
+            assert_eq!(chkptr.export_checkpoints(&mut exported).unwrap(), 2);
+        }
+
+        let data_dir = tempdir().unwrap();
+        {
+            let mut chkptr = Checkpointer::new(data_dir.path());
+            chkptr.update_checkpoint(FileFingerprint::BytesChecksum(3456), 1);
+            chkptr.update_checkpoint(FileFingerprint::FirstLinesChecksum(78910), 1);
+
+            assert_eq!(chkptr.import_checkpoints(&exported[..], false).unwrap(), 2);
+            assert_eq!(chkptr.checkpoints().len(), 3);
+        }
+
+        // The imported checkpoints are persisted, overriding existing ones.
+        {
+            let mut chkptr = Checkpointer::new(data_dir.path());
+            chkptr.read_checkpoints(None);
+            assert_eq!(
+                chkptr.get_checkpoint(FileFingerprint::BytesChecksum(3456)),
+                Some(position)
+            );
+            assert_eq!(
+                chkptr.get_checkpoint(FileFingerprint::FirstLinesChecksum(78910)),
+                Some(1)
+            );
+
+            chkptr.import_checkpoints(&exported[..], true).unwrap();
+            assert_eq!(
+                chkptr.get_checkpoint(FileFingerprint::FirstLinesChecksum(78910)),
+                None
+            );
+            assert_eq!(
+                chkptr.get_checkpoint(FileFingerprint::DevInode(1, 2)),
+                Some(position)
+            );
+        }
+    }
+
     #[test]
     fn test_checkpointer_checksum_updates() {
         let data_dir = tempdir().unwrap();
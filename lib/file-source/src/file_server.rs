@@ -2,7 +2,8 @@ use std::{
     cmp,
     collections::{BTreeMap, HashSet},
     fs::{self, remove_file},
-    path::PathBuf,
+    io,
+    path::{Path, PathBuf},
     sync::Arc,
     time::{self, Duration},
 };
@@ -50,6 +51,7 @@ where
     pub fingerprinter: Fingerprinter,
     pub oldest_first: bool,
     pub remove_after: Option<Duration>,
+    pub archive_dir: Option<PathBuf>,
     pub emitter: E,
     pub handle: tokio::runtime::Handle,
 }
@@ -268,19 +270,38 @@ where
                 if bytes_read > 0 {
                     global_bytes_read = global_bytes_read.saturating_add(bytes_read);
                 } else {
-                    // Should the file be removed
+                    // Should the file be removed. Files are only removed once everything read
+                    // from them has been checkpointed, which, with acknowledgements enabled,
+                    // happens once it has been acknowledged.
                     if let Some(grace_period) = self.remove_after {
-                        if watcher.last_read_success().elapsed() >= grace_period {
-                            // Try to remove
-                            match remove_file(&watcher.path) {
-                                Ok(()) => {
-                                    self.emitter.emit_file_deleted(&watcher.path);
-                                    watcher.set_dead();
-                                }
-                                Err(error) => {
-                                    // We will try again after some time.
-                                    self.emitter.emit_file_delete_error(&watcher.path, error);
-                                }
+                        if watcher.last_read_success().elapsed() >= grace_period
+                            && is_fully_checkpointed(watcher, file_id, &checkpoints)
+                        {
+                            match &self.archive_dir {
+                                // Try to archive
+                                Some(archive_dir) => match archive_file(&watcher.path, archive_dir)
+                                {
+                                    Ok(archive_path) => {
+                                        self.emitter
+                                            .emit_file_archived(&watcher.path, &archive_path);
+                                        watcher.set_dead();
+                                    }
+                                    Err(error) => {
+                                        // We will try again after some time.
+                                        self.emitter.emit_file_archive_error(&watcher.path, error);
+                                    }
+                                },
+                                // Try to remove
+                                None => match remove_file(&watcher.path) {
+                                    Ok(()) => {
+                                        self.emitter.emit_file_deleted(&watcher.path);
+                                        watcher.set_dead();
+                                    }
+                                    Err(error) => {
+                                        // We will try again after some time.
+                                        self.emitter.emit_file_delete_error(&watcher.path, error);
+                                    }
+                                },
                             }
                         }
                     }
@@ -444,6 +465,45 @@ async fn checkpoint_writer(
     checkpointer
 }
 
+/// Whether the checkpoint of the file covers everything read from it.
+fn is_fully_checkpointed(
+    watcher: &FileWatcher,
+    file_id: FileFingerprint,
+    checkpoints: &CheckpointsView,
+) -> bool {
+    let position = watcher.get_file_position();
+    position == watcher.get_start_position()
+        || checkpoints
+            .get(file_id)
+            .map_or(false, |checkpoint| checkpoint >= position)
+}
+
+/// Moves the file into the archive directory, and returns its new path.
+///
+/// The file keeps its name, suffixed with a number if a file of the same name was already archived.
+fn archive_file(path: &Path, archive_dir: &Path) -> io::Result<PathBuf> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+    fs::create_dir_all(archive_dir)?;
+
+    let mut archive_path = archive_dir.join(file_name);
+    let mut suffix = 0;
+    while archive_path.exists() {
+        suffix += 1;
+        let mut suffixed = file_name.to_os_string();
+        suffixed.push(format!(".{}", suffix));
+        archive_path = archive_dir.join(suffixed);
+    }
+
+    if fs::rename(path, &archive_path).is_err() {
+        // Renaming fails across filesystems, so fall back to copying the file.
+        fs::copy(path, &archive_path)?;
+        remove_file(path)?;
+    }
+    Ok(archive_path)
+}
+
 pub fn calculate_ignore_before(ignore_older_secs: Option<u64>) -> Option<DateTime<Utc>> {
     ignore_older_secs.map(|secs| Utc::now() - chrono::Duration::seconds(secs as i64))
 }
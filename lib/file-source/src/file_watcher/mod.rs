@@ -39,6 +39,7 @@ pub struct FileWatcher {
     findable: bool,
     reader: Box<dyn BufRead>,
     file_position: FilePosition,
+    start_position: FilePosition,
     devno: u64,
     inode: u64,
     is_dead: bool,
@@ -140,6 +141,7 @@ impl FileWatcher {
             findable: true,
             reader,
             file_position,
+            start_position: file_position,
             devno,
             inode: ino,
             is_dead: false,
@@ -194,6 +196,11 @@ impl FileWatcher {
         self.file_position
     }
 
+    /// The position the file started being read from.
+    pub fn get_start_position(&self) -> FilePosition {
+        self.start_position
+    }
+
     /// Read a single line from the underlying file
     ///
     /// This function will attempt to read a new line from its file, blocking,
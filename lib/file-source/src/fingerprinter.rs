@@ -537,6 +537,12 @@ mod test {
             panic!();
         }
 
+        fn emit_file_archived(&self, _: &Path, _: &Path) {}
+
+        fn emit_file_archive_error(&self, _: &Path, _: Error) {
+            panic!();
+        }
+
         fn emit_file_fingerprint_read_error(&self, _: &Path, _: Error) {
             panic!();
         }
@@ -15,6 +15,10 @@ pub trait FileSourceInternalEvents: Send + Sync + Clone + 'static {
 
     fn emit_file_delete_error(&self, path: &Path, error: Error);
 
+    fn emit_file_archived(&self, path: &Path, archive_path: &Path);
+
+    fn emit_file_archive_error(&self, path: &Path, error: Error);
+
     fn emit_file_fingerprint_read_error(&self, path: &Path, error: Error);
 
     fn emit_file_checkpointed(&self, count: usize, duration: Duration);
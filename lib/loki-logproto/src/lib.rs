@@ -15,8 +15,8 @@ pub mod util {
 
     const NANOS_RANGE: i64 = 1_000_000_000;
 
-    // (<Timestamp in nanos>, <Line>)
-    pub struct Entry(pub i64, pub String);
+    // (<Timestamp in nanos>, <Line>, <Structured metadata>)
+    pub struct Entry(pub i64, pub String, pub Vec<(String, String)>);
 
     impl From<Entry> for logproto::EntryAdapter {
         fn from(entry: Entry) -> Self {
@@ -26,6 +26,11 @@ pub mod util {
                     nanos: (entry.0 % NANOS_RANGE) as i32,
                 }),
                 line: entry.1,
+                structured_metadata: entry
+                    .2
+                    .into_iter()
+                    .map(|(name, value)| logproto::LabelPairAdapter { name, value })
+                    .collect(),
             }
         }
     }
@@ -103,12 +108,12 @@ mod tests {
             .timestamp_opt(1640244790, 0)
             .single()
             .expect("invalid timestamp");
-        let entry1 = Entry(ts1.timestamp_nanos(), "hello".into());
+        let entry1 = Entry(ts1.timestamp_nanos(), "hello".into(), Vec::new());
         let ts2 = Utc
             .timestamp_opt(1640244791, 0)
             .single()
             .expect("invalid timestamp");
-        let entry2 = Entry(ts2.timestamp_nanos(), "world".into());
+        let entry2 = Entry(ts2.timestamp_nanos(), "world".into(), Vec::new());
         let labels = vec![("source".into(), "protobuf-test".into())]
             .into_iter()
             .collect();
@@ -123,4 +128,28 @@ mod tests {
         let buf = batch.encode();
         assert_eq!(expect, buf);
     }
+    #[test]
+    fn encode_structured_metadata() {
+        use prost::Message;
+
+        let entry = Entry(
+            1_640_244_790_000_000_000,
+            "hello".into(),
+            vec![("trace_id".into(), "abcd".into())],
+        );
+        let labels = vec![("source".into(), "protobuf-test".into())]
+            .into_iter()
+            .collect();
+        let buf = Batch(vec![Stream(labels, vec![entry])]).encode();
+
+        let decoded = snap::raw::Decoder::new().decompress_vec(&buf).unwrap();
+        let request = crate::logproto::PushRequest::decode(decoded.as_slice()).unwrap();
+        assert_eq!(
+            request.streams[0].entries[0].structured_metadata,
+            vec![crate::logproto::LabelPairAdapter {
+                name: "trace_id".into(),
+                value: "abcd".into(),
+            }]
+        );
+    }
 }
@@ -1,18 +1,25 @@
 use bytes::Bytes;
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, SecondsFormat, TimeZone, Utc};
 use lookup::path;
 use ordered_float::NotNan;
 use std::collections::BTreeMap;
 use value::Value;
 use vector_core::{
     config::{log_schema, LegacyKey, LogNamespace},
-    event::{Event, LogEvent},
+    event::{Event, LogEvent, TraceEvent},
 };
 
 use super::proto::{
-    common::v1::{any_value::Value as PBValue, KeyValue},
+    common::v1::{
+        any_value::Value as PBValue, AnyValue, ArrayValue, InstrumentationScope, KeyValue,
+        KeyValueList,
+    },
     logs::v1::{LogRecord, ResourceLogs, SeverityNumber},
     resource::v1::Resource,
+    trace::v1::{
+        span::{Event as SpanEvent, Link},
+        ResourceSpans, Span, Status,
+    },
 };
 
 const SOURCE_NAME: &str = "opentelemetry";
@@ -26,6 +33,9 @@ pub const SEVERITY_NUMBER_KEY: &str = "severity_number";
 pub const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
 pub const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
 pub const FLAGS_KEY: &str = "flags";
+pub const PARENT_SPAN_ID_KEY: &str = "parent_span_id";
+pub const TRACE_STATE_KEY: &str = "trace_state";
+pub const SCOPE_KEY: &str = "scope";
 
 impl ResourceLogs {
     pub fn into_event_iter(self, log_namespace: LogNamespace) -> impl Iterator<Item = Event> {
@@ -45,6 +55,31 @@ impl ResourceLogs {
     }
 }
 
+impl ResourceSpans {
+    pub fn into_event_iter(self) -> impl Iterator<Item = Event> {
+        let resource = self.resource;
+        let now = Utc::now();
+
+        self.scope_spans
+            .into_iter()
+            .flat_map(|scope_spans| {
+                let scope = scope_spans.scope;
+                scope_spans
+                    .spans
+                    .into_iter()
+                    .map(move |span| (scope.clone(), span))
+            })
+            .map(move |(scope, span)| {
+                ResourceSpan {
+                    resource: resource.clone(),
+                    scope,
+                    span,
+                }
+                .into_event(now)
+            })
+    }
+}
+
 impl From<PBValue> for Value {
     fn from(av: PBValue) -> Self {
         match av {
@@ -64,11 +99,53 @@ impl From<PBValue> for Value {
     }
 }
 
+impl From<Value> for AnyValue {
+    fn from(value: Value) -> Self {
+        let value = match value {
+            Value::Bytes(v) => match String::from_utf8(v.to_vec()) {
+                Ok(v) => Some(PBValue::StringValue(v)),
+                Err(_) => Some(PBValue::BytesValue(v.to_vec())),
+            },
+            Value::Regex(v) => Some(PBValue::StringValue(v.as_str().to_owned())),
+            Value::Integer(v) => Some(PBValue::IntValue(v)),
+            Value::Float(v) => Some(PBValue::DoubleValue(v.into_inner())),
+            Value::Boolean(v) => Some(PBValue::BoolValue(v)),
+            Value::Timestamp(v) => Some(PBValue::StringValue(
+                v.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )),
+            Value::Object(v) => Some(PBValue::KvlistValue(KeyValueList {
+                values: value_into_kv_list(v),
+            })),
+            Value::Array(v) => Some(PBValue::ArrayValue(ArrayValue {
+                values: v.into_iter().map(Into::into).collect(),
+            })),
+            Value::Null => None,
+        };
+        AnyValue { value }
+    }
+}
+
+/// Converts the fields of an object into OTLP key/value pairs, as used for attributes.
+pub fn value_into_kv_list(map: BTreeMap<String, Value>) -> Vec<KeyValue> {
+    map.into_iter()
+        .map(|(key, value)| KeyValue {
+            key,
+            value: Some(value.into()),
+        })
+        .collect()
+}
+
 struct ResourceLog {
     resource: Option<Resource>,
     log_record: LogRecord,
 }
 
+struct ResourceSpan {
+    resource: Option<Resource>,
+    scope: Option<InstrumentationScope>,
+    span: Span,
+}
+
 fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     Value::Object(
         arr.into_iter()
@@ -221,3 +298,142 @@ impl ResourceLog {
         log.into()
     }
 }
+
+// https://github.com/open-telemetry/opentelemetry-specification/blob/v1.15.0/specification/trace/api.md#span
+impl ResourceSpan {
+    fn into_event(self, now: DateTime<Utc>) -> Event {
+        let span = self.span;
+        let mut trace = BTreeMap::new();
+
+        trace.insert(TRACE_ID_KEY.into(), Value::from(hex::encode(span.trace_id)));
+        trace.insert(SPAN_ID_KEY.into(), Value::from(hex::encode(span.span_id)));
+        // Root spans have no parent.
+        if !span.parent_span_id.is_empty() {
+            trace.insert(
+                PARENT_SPAN_ID_KEY.into(),
+                Value::from(hex::encode(span.parent_span_id)),
+            );
+        }
+        if !span.trace_state.is_empty() {
+            trace.insert(TRACE_STATE_KEY.into(), Value::from(span.trace_state));
+        }
+        trace.insert("name".into(), Value::from(span.name));
+        trace.insert("kind".into(), Value::from(span.kind));
+        trace.insert(
+            "start_time_unix_nano".into(),
+            Value::from(Utc.timestamp_nanos(span.start_time_unix_nano as i64)),
+        );
+        trace.insert(
+            "end_time_unix_nano".into(),
+            Value::from(Utc.timestamp_nanos(span.end_time_unix_nano as i64)),
+        );
+        if !span.attributes.is_empty() {
+            trace.insert(ATTRIBUTES_KEY.into(), kv_list_into_value(span.attributes));
+        }
+        trace.insert(
+            DROPPED_ATTRIBUTES_COUNT_KEY.into(),
+            Value::from(span.dropped_attributes_count),
+        );
+        if !span.events.is_empty() {
+            trace.insert(
+                "events".into(),
+                Value::Array(span.events.into_iter().map(Into::into).collect()),
+            );
+        }
+        trace.insert(
+            "dropped_events_count".into(),
+            Value::from(span.dropped_events_count),
+        );
+        if !span.links.is_empty() {
+            trace.insert(
+                "links".into(),
+                Value::Array(span.links.into_iter().map(Into::into).collect()),
+            );
+        }
+        trace.insert(
+            "dropped_links_count".into(),
+            Value::from(span.dropped_links_count),
+        );
+        if let Some(status) = span.status {
+            trace.insert("status".into(), status.into());
+        }
+
+        if let Some(resource) = self.resource {
+            if !resource.attributes.is_empty() {
+                trace.insert(RESOURCE_KEY.into(), kv_list_into_value(resource.attributes));
+            }
+        }
+        if let Some(scope) = self.scope {
+            if !scope.name.is_empty() {
+                trace.insert(SCOPE_KEY.into(), scope.into());
+            }
+        }
+
+        trace.insert(
+            log_schema().source_type_key().into(),
+            Value::from(SOURCE_NAME),
+        );
+        trace.insert("ingest_timestamp".into(), Value::from(now));
+
+        TraceEvent::from(trace).into()
+    }
+}
+
+impl From<SpanEvent> for Value {
+    fn from(event: SpanEvent) -> Self {
+        let mut fields = BTreeMap::new();
+        fields.insert("name".into(), Value::from(event.name));
+        fields.insert(
+            "time_unix_nano".into(),
+            Value::from(Utc.timestamp_nanos(event.time_unix_nano as i64)),
+        );
+        fields.insert(ATTRIBUTES_KEY.into(), kv_list_into_value(event.attributes));
+        fields.insert(
+            DROPPED_ATTRIBUTES_COUNT_KEY.into(),
+            Value::from(event.dropped_attributes_count),
+        );
+        Value::Object(fields)
+    }
+}
+
+impl From<Link> for Value {
+    fn from(link: Link) -> Self {
+        let mut fields = BTreeMap::new();
+        fields.insert(TRACE_ID_KEY.into(), Value::from(hex::encode(link.trace_id)));
+        fields.insert(SPAN_ID_KEY.into(), Value::from(hex::encode(link.span_id)));
+        if !link.trace_state.is_empty() {
+            fields.insert(TRACE_STATE_KEY.into(), Value::from(link.trace_state));
+        }
+        fields.insert(ATTRIBUTES_KEY.into(), kv_list_into_value(link.attributes));
+        fields.insert(
+            DROPPED_ATTRIBUTES_COUNT_KEY.into(),
+            Value::from(link.dropped_attributes_count),
+        );
+        Value::Object(fields)
+    }
+}
+
+impl From<Status> for Value {
+    fn from(status: Status) -> Self {
+        let mut fields = BTreeMap::new();
+        fields.insert("code".into(), Value::from(status.code));
+        if !status.message.is_empty() {
+            fields.insert("message".into(), Value::from(status.message));
+        }
+        Value::Object(fields)
+    }
+}
+
+impl From<InstrumentationScope> for Value {
+    fn from(scope: InstrumentationScope) -> Self {
+        let mut fields = BTreeMap::new();
+        fields.insert("name".into(), Value::from(scope.name));
+        if !scope.version.is_empty() {
+            fields.insert("version".into(), Value::from(scope.version));
+        }
+        if !scope.attributes.is_empty() {
+            fields.insert(ATTRIBUTES_KEY.into(), kv_list_into_value(scope.attributes));
+        }
+        Value::Object(fields)
+    }
+}
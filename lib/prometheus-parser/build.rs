@@ -1,5 +1,6 @@
 fn main() {
     println!("cargo:rerun-if-changed=proto/prometheus-remote.proto");
+    println!("cargo:rerun-if-changed=proto/prometheus-remote-v2.proto");
     println!("cargo:rerun-if-changed=proto/prometheus-types.proto");
     let mut prost_build = prost_build::Config::new();
     prost_build.btree_map(["."]);
@@ -14,4 +15,14 @@ fn main() {
             &["proto", "../../proto"],
         )
         .unwrap();
+
+    // The messages of the second version of the protocol refer to those of the first one, which
+    // are generated above.
+    prost_build::Config::new()
+        .extern_path(".prometheus", "crate::proto")
+        .compile_protos(
+            &["proto/prometheus-remote-v2.proto"],
+            &["proto", "../../proto"],
+        )
+        .unwrap();
 }
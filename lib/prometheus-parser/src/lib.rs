@@ -1,6 +1,10 @@
 #![deny(warnings)]
 
-use std::{collections::BTreeMap, convert::TryFrom};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
+};
 
 use indexmap::IndexMap;
 use snafu::ResultExt;
@@ -32,6 +36,11 @@ pub mod proto {
             }
         }
     }
+
+    /// The messages of the second version of the remote-write protocol.
+    pub mod v2 {
+        include!(concat!(env!("OUT_DIR"), "/io.prometheus.write.v2.rs"));
+    }
 }
 
 #[derive(Debug, snafu::Snafu, PartialEq)]
@@ -60,6 +69,13 @@ pub enum ParserError {
     MultipleMetricKinds { name: String },
     #[snafu(display("request is missing metric name label"))]
     RequestNoNameLabel,
+
+    #[snafu(display("request refers to unknown symbol {}", reference))]
+    InvalidSymbolReference { reference: u32 },
+    #[snafu(display("request has an odd number of label references"))]
+    OddLabelReferences,
+    #[snafu(display("invalid native histogram for metric `{}`: {}", name, reason))]
+    InvalidNativeHistogram { name: String, reason: String },
 }
 
 vector_common::impl_event_data_eq!(ParserError);
@@ -101,6 +117,17 @@ pub struct SimpleMetric {
     pub value: f64,
 }
 
+/// An exemplar attached to a series of a remote-write request.
+#[derive(Debug, Default, PartialEq)]
+pub struct Exemplar {
+    /// The labels of the series, without the `le` label of the buckets of histograms.
+    pub series_labels: BTreeMap<String, String>,
+    /// The labels of the exemplar itself, such as the ID of a trace.
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    pub timestamp: Option<i64>,
+}
+
 type MetricMap<T> = IndexMap<GroupKey, T>;
 
 #[derive(Debug)]
@@ -236,6 +263,9 @@ impl GroupKind {
 pub struct MetricGroup {
     pub name: String,
     pub metrics: GroupKind,
+    pub help: Option<String>,
+    pub unit: Option<String>,
+    pub exemplars: Vec<Exemplar>,
 }
 
 fn try_f64_to_u64(f: f64) -> Result<u64, ParserError> {
@@ -252,7 +282,13 @@ fn try_f64_to_u64(f: f64) -> Result<u64, ParserError> {
 impl MetricGroup {
     fn new(name: String, kind: MetricKind) -> Self {
         let metrics = GroupKind::new(kind);
-        MetricGroup { name, metrics }
+        MetricGroup {
+            name,
+            metrics,
+            help: None,
+            unit: None,
+            exemplars: Vec::new(),
+        }
     }
 
     // For cases where a metric group was not defined with `# TYPE ...`.
@@ -267,6 +303,9 @@ impl MetricGroup {
         MetricGroup {
             name,
             metrics: GroupKind::new_untyped(key, value),
+            help: None,
+            unit: None,
+            exemplars: Vec::new(),
         }
     }
 
@@ -315,36 +354,57 @@ pub fn parse_text(input: &str) -> Result<Vec<MetricGroup>, ParserError> {
     Ok(groups)
 }
 
+/// The descriptions and the exemplars of a metric group.
 #[derive(Default)]
-struct MetricGroupSet(IndexMap<String, GroupKind>);
+struct GroupDetails {
+    help: Option<String>,
+    unit: Option<String>,
+    exemplars: Vec<Exemplar>,
+}
+
+#[derive(Default)]
+struct MetricGroupSet {
+    groups: IndexMap<String, GroupKind>,
+    details: HashMap<String, GroupDetails>,
+}
 
 impl MetricGroupSet {
     fn get_group<'a>(&'a mut self, name: &str) -> (usize, &'a String, &'a mut GroupKind) {
         let len = name.len();
-        let name = if self.0.contains_key(name) {
+        let name = if self.groups.contains_key(name) {
             name
-        } else if name.ends_with("_bucket") && self.0.contains_key(&name[..len - 7]) {
+        } else if name.ends_with("_bucket") && self.groups.contains_key(&name[..len - 7]) {
             &name[..len - 7]
-        } else if name.ends_with("_sum") && self.0.contains_key(&name[..len - 4]) {
+        } else if name.ends_with("_sum") && self.groups.contains_key(&name[..len - 4]) {
             &name[..len - 4]
-        } else if name.ends_with("_count") && self.0.contains_key(&name[..len - 6]) {
+        } else if name.ends_with("_count") && self.groups.contains_key(&name[..len - 6]) {
             &name[..len - 6]
         } else {
-            self.0
+            self.groups
                 .insert(name.into(), GroupKind::new(MetricKind::Untyped));
             name
         };
-        self.0.get_full_mut(name).unwrap()
+        self.groups.get_full_mut(name).unwrap()
+    }
+
+    fn insert_description(&mut self, name: &str, help: String, unit: String) {
+        let details = self.details.entry(name.into()).or_default();
+        if !help.is_empty() {
+            details.help = Some(help);
+        }
+        if !unit.is_empty() {
+            details.unit = Some(unit);
+        }
     }
 
     fn insert_metadata(&mut self, name: String, kind: MetricKind) -> Result<(), ParserError> {
-        match self.0.get(&name) {
+        match self.groups.get(&name) {
             Some(group) if !group.matches_kind(kind) => {
                 Err(ParserError::MultipleMetricKinds { name })
             }
             Some(_) => Ok(()), // metadata already exists and is the right type
             None => {
-                self.0.insert(name, GroupKind::new(kind));
+                self.groups.insert(name, GroupKind::new(kind));
                 Ok(())
             }
         }
@@ -371,17 +431,202 @@ impl MetricGroupSet {
                 labels: metric.labels,
             };
             let group = GroupKind::new_untyped(key, metric.value);
-            self.0.insert(metric.name, group);
+            self.groups.insert(metric.name, group);
         }
         Ok(())
     }
 
-    fn finish(self) -> Vec<MetricGroup> {
-        self.0
+    fn insert_histogram(
+        &mut self,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+        histogram: proto::Histogram,
+    ) -> Result<(), ParserError> {
+        let timestamp = Some(histogram.timestamp);
+        let metric = native_histogram(name, histogram)?;
+
+        let (_, basename, group) = self.get_group(name);
+        // Native histograms don't need metadata to be told apart from other metrics.
+        if matches!(group, GroupKind::Untyped(metrics) if metrics.is_empty()) {
+            *group = GroupKind::Histogram(IndexMap::default());
+        }
+        match group {
+            GroupKind::Histogram(metrics) => {
+                let key = GroupKey {
+                    timestamp,
+                    labels: labels.clone(),
+                };
+                metrics.insert(key, metric);
+                Ok(())
+            }
+            _ => Err(ParserError::MultipleMetricKinds {
+                name: basename.clone(),
+            }),
+        }
+    }
+
+    fn insert_exemplar(
+        &mut self,
+        name: &str,
+        labels: &BTreeMap<String, String>,
+        exemplar: proto::Exemplar,
+    ) {
+        let mut series_labels = labels.clone();
+        series_labels.remove("le");
+        let exemplar = Exemplar {
+            series_labels,
+            labels: exemplar
+                .labels
+                .into_iter()
+                .map(|label| (label.name, label.value))
+                .collect(),
+            value: exemplar.value,
+            timestamp: (exemplar.timestamp != 0).then_some(exemplar.timestamp),
+        };
+
+        let (_, basename, _) = self.get_group(name);
+        let basename = basename.clone();
+        self.details
+            .entry(basename)
+            .or_default()
+            .exemplars
+            .push(exemplar);
+    }
+
+    fn finish(mut self) -> Vec<MetricGroup> {
+        self.groups
             .into_iter()
-            .map(|(name, metrics)| MetricGroup { name, metrics })
+            .map(|(name, metrics)| {
+                let details = self.details.remove(&name).unwrap_or_default();
+                MetricGroup {
+                    name,
+                    metrics,
+                    help: details.help,
+                    unit: details.unit,
+                    exemplars: details.exemplars,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Converts a native histogram into a conventional one, whose buckets are bounded by the
+/// boundaries of the exponential buckets of the native histogram.
+///
+/// The negative buckets are bounded by their upper boundary, and the zero bucket by its threshold.
+fn native_histogram(
+    name: &str,
+    histogram: proto::Histogram,
+) -> Result<HistogramMetric, ParserError> {
+    use proto::histogram::{Count, ZeroCount};
+
+    let invalid = |reason: &str| ParserError::InvalidNativeHistogram {
+        name: name.into(),
+        reason: reason.into(),
+    };
+    if !(-4..=8).contains(&histogram.schema) {
+        return Err(invalid("unsupported schema"));
+    }
+    // Each bucket boundary is the previous one times 2^(2^-schema), and 1 is a boundary.
+    let factor = 2f64.powi(-histogram.schema);
+    let boundary = |index: i32| 2f64.powf(f64::from(index) * factor);
+
+    let float = matches!(histogram.count, Some(Count::CountFloat(_)));
+    let positive = expand_buckets(
+        &histogram.positive_spans,
+        &histogram.positive_deltas,
+        &histogram.positive_counts,
+        float,
+    )
+    .ok_or_else(|| invalid("positive bucket counts don't match their spans"))?;
+    let negative = expand_buckets(
+        &histogram.negative_spans,
+        &histogram.negative_deltas,
+        &histogram.negative_counts,
+        float,
+    )
+    .ok_or_else(|| invalid("negative bucket counts don't match their spans"))?;
+
+    let mut buckets = positive
+        .into_iter()
+        .map(|(index, count)| (boundary(index), count))
+        .chain(
+            negative
+                .into_iter()
+                .map(|(index, count)| (-boundary(index - 1), count)),
+        )
+        .collect::<Vec<_>>();
+    let zero_count = match histogram.zero_count {
+        Some(ZeroCount::ZeroCountInt(count)) => count as f64,
+        Some(ZeroCount::ZeroCountFloat(count)) => count,
+        None => 0.0,
+    };
+    if zero_count > 0.0 {
+        buckets.push((histogram.zero_threshold, zero_count));
+    }
+    buckets.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut cumulative = 0.0;
+    let buckets = buckets
+        .into_iter()
+        .map(|(bucket, count)| {
+            cumulative += count;
+            let count = try_f64_to_u64(cumulative.round())?;
+            Ok(HistogramBucket { bucket, count })
+        })
+        .collect::<Result<Vec<_>, ParserError>>()?;
+    let count = match histogram.count {
+        Some(Count::CountInt(count)) => count,
+        Some(Count::CountFloat(count)) => try_f64_to_u64(count.round())?,
+        None => 0,
+    };
+
+    Ok(HistogramMetric {
+        buckets,
+        sum: histogram.sum,
+        count,
+    })
+}
+
+/// Lists the indexes and the counts of the populated buckets of one side of a native histogram.
+///
+/// Integer histograms encode each count as the delta to the previous one, float histograms encode
+/// the counts themselves. Returns `None` if the counts don't match the spans.
+fn expand_buckets(
+    spans: &[proto::BucketSpan],
+    deltas: &[i64],
+    counts: &[f64],
+    float: bool,
+) -> Option<Vec<(i32, f64)>> {
+    let counts = if float {
+        counts.to_vec()
+    } else {
+        deltas
+            .iter()
+            .scan(0i64, |count, delta| {
+                *count = count.checked_add(*delta)?;
+                Some(*count as f64)
+            })
             .collect()
+    };
+    let length = spans.iter().map(|span| span.length as usize).sum::<usize>();
+    if counts.len() != length || counts.iter().any(|count| *count < 0.0) {
+        return None;
     }
+
+    // The offset of the first span is the index of its first bucket, the offsets of the others
+    // are the gaps since the previous span.
+    let mut index = 0i32;
+    let mut counts = counts.into_iter();
+    let mut buckets = Vec::with_capacity(length);
+    for span in spans {
+        index = index.checked_add(span.offset)?;
+        for _ in 0..span.length {
+            buckets.push((index, counts.next()?));
+            index = index.checked_add(1)?;
+        }
+    }
+    Some(buckets)
 }
 
 /// Parse the given remote_write request, grouping the metrics into
@@ -394,6 +639,7 @@ pub fn parse_request(request: proto::WriteRequest) -> Result<Vec<MetricGroup>, P
         let kind = proto::MetricType::try_from(metadata.r#type)
             .unwrap_or(proto::MetricType::Unknown)
             .into();
+        groups.insert_description(&name, metadata.help, metadata.unit);
         groups.insert_metadata(name, kind)?;
     }
 
@@ -411,11 +657,110 @@ pub fn parse_request(request: proto::WriteRequest) -> Result<Vec<MetricGroup>, P
         for sample in timeseries.samples {
             groups.insert_sample(&name, &labels, sample)?;
         }
+        for histogram in timeseries.histograms {
+            groups.insert_histogram(&name, &labels, histogram)?;
+        }
+        for exemplar in timeseries.exemplars {
+            groups.insert_exemplar(&name, &labels, exemplar);
+        }
     }
 
     Ok(groups.finish())
 }
 
+/// Returns the name of the metric family of a series of the given type, which is the name of the
+/// series without the suffix of the components of histograms and summaries.
+fn family_name(name: &str, r#type: i32) -> &str {
+    use proto::MetricType::*;
+
+    let suffixes: &[&str] = match proto::MetricType::try_from(r#type) {
+        Ok(Histogram | Gaugehistogram) => &["_bucket", "_sum", "_count"],
+        Ok(Summary) => &["_sum", "_count"],
+        _ => &[],
+    };
+    suffixes
+        .iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(name)
+}
+
+impl TryFrom<proto::v2::Request> for proto::WriteRequest {
+    type Error = ParserError;
+
+    /// Converts a request of the second version of the remote-write protocol into one of the
+    /// first version, resolving its symbols.
+    ///
+    /// The metadata of each series is turned into the metadata of its metric family.
+    fn try_from(request: proto::v2::Request) -> Result<Self, Self::Error> {
+        let symbols = request.symbols;
+        let symbol = |reference: u32| {
+            symbols
+                .get(reference as usize)
+                .cloned()
+                .ok_or(ParserError::InvalidSymbolReference { reference })
+        };
+        // The first symbol is always the empty string, which optional references point to.
+        let optional_symbol = |reference: u32| match reference {
+            0 => Ok(String::new()),
+            reference => symbol(reference),
+        };
+        let labels = |references: &[u32]| {
+            if references.len() % 2 != 0 {
+                return Err(ParserError::OddLabelReferences);
+            }
+            references
+                .chunks(2)
+                .map(|pair| {
+                    Ok(proto::Label {
+                        name: symbol(pair[0])?,
+                        value: symbol(pair[1])?,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let mut metadata = Vec::new();
+        let mut timeseries = Vec::with_capacity(request.timeseries.len());
+        for series in request.timeseries {
+            let series_labels = labels(&series.labels_refs)?;
+            let name = series_labels
+                .iter()
+                .find(|label| label.name == METRIC_NAME_LABEL);
+            if let (Some(name), Some(series_metadata)) = (name, series.metadata) {
+                metadata.push(proto::MetricMetadata {
+                    r#type: series_metadata.r#type,
+                    metric_family_name: family_name(&name.value, series_metadata.r#type).into(),
+                    help: optional_symbol(series_metadata.help_ref)?,
+                    unit: optional_symbol(series_metadata.unit_ref)?,
+                });
+            }
+
+            let exemplars = series
+                .exemplars
+                .into_iter()
+                .map(|exemplar| {
+                    Ok(proto::Exemplar {
+                        labels: labels(&exemplar.labels_refs)?,
+                        value: exemplar.value,
+                        timestamp: exemplar.timestamp,
+                    })
+                })
+                .collect::<Result<Vec<_>, ParserError>>()?;
+            timeseries.push(proto::TimeSeries {
+                labels: series_labels,
+                samples: series.samples,
+                exemplars,
+                histograms: series.histograms,
+            });
+        }
+
+        Ok(Self {
+            timeseries,
+            metadata,
+        })
+    }
+}
+
 impl From<proto::MetricType> for MetricKind {
     fn from(kind: proto::MetricType) -> Self {
         use proto::MetricType::*;
@@ -736,6 +1081,8 @@ mod test {
                     samples: vec![
                         $( proto::Sample { value: $sample as f64, timestamp: $timestamp as i64 }, )*
                     ],
+                    exemplars: Vec::new(),
+                    histograms: Vec::new(),
                 }, )* ],
             }
         };
@@ -886,4 +1233,135 @@ mod test {
             assert_eq!(metrics.get_index(0).unwrap(), simple_metric!(Some(1395066367700), labels!(), 24.0));
         });
     }
+
+    #[test]
+    fn parse_request_native_histogram() {
+        let mut request = write_request!([], [ [__name__ => "one"] => [] ]);
+        request.timeseries[0].histograms.push(proto::Histogram {
+            count: Some(proto::histogram::Count::CountInt(8)),
+            sum: 10.0,
+            schema: 0,
+            zero_threshold: 0.001,
+            zero_count: Some(proto::histogram::ZeroCount::ZeroCountInt(1)),
+            negative_spans: vec![proto::BucketSpan {
+                offset: 0,
+                length: 1,
+            }],
+            negative_deltas: vec![1],
+            positive_spans: vec![
+                proto::BucketSpan {
+                    offset: 0,
+                    length: 2,
+                },
+                proto::BucketSpan {
+                    offset: 1,
+                    length: 1,
+                },
+            ],
+            positive_deltas: vec![2, 1, -2],
+            timestamp: 1395066367700,
+            ..Default::default()
+        });
+        let parsed = parse_request(request).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        match_group!(parsed[0], "one", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+            assert_eq!(
+                metrics.get_index(0).unwrap(), (
+                    &GroupKey {
+                        timestamp: Some(1395066367700),
+                        labels: labels!(),
+                    },
+                    &HistogramMetric {
+                        buckets: vec![
+                            HistogramBucket { bucket: -0.5, count: 1 },
+                            HistogramBucket { bucket: 0.001, count: 2 },
+                            HistogramBucket { bucket: 1.0, count: 4 },
+                            HistogramBucket { bucket: 2.0, count: 7 },
+                            HistogramBucket { bucket: 8.0, count: 8 },
+                        ],
+                        count: 8,
+                        sum: 10.0,
+                    })
+            );
+        });
+
+        let mut request = write_request!([], [ [__name__ => "one"] => [] ]);
+        request.timeseries[0].histograms.push(proto::Histogram {
+            schema: 9,
+            ..Default::default()
+        });
+        assert!(
+            matches!(
+                parse_request(request).unwrap_err(),
+                ParserError::InvalidNativeHistogram { .. }
+            )
+        );
+    }
+
+    #[test]
+    fn parse_request_v2() {
+        let symbols = [
+            "",
+            "__name__",
+            "one_bucket",
+            "le",
+            "1",
+            "The one metric.",
+            "seconds",
+            "trace_id",
+            "abc",
+        ];
+        let request = proto::v2::Request {
+            symbols: symbols.iter().map(|symbol| symbol.to_string()).collect(),
+            timeseries: vec![proto::v2::TimeSeries {
+                labels_refs: vec![1, 2, 3, 4],
+                samples: vec![proto::Sample {
+                    value: 3.0,
+                    timestamp: 1395066367700,
+                }],
+                exemplars: vec![proto::v2::Exemplar {
+                    labels_refs: vec![7, 8],
+                    value: 0.5,
+                    timestamp: 1395066367600,
+                }],
+                metadata: Some(proto::v2::Metadata {
+                    r#type: proto::MetricType::Histogram as i32,
+                    help_ref: 5,
+                    unit_ref: 6,
+                }),
+                ..Default::default()
+            }],
+        };
+        let parsed = parse_request(proto::WriteRequest::try_from(request).unwrap()).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].help.as_deref(), Some("The one metric."));
+        assert_eq!(parsed[0].unit.as_deref(), Some("seconds"));
+        assert_eq!(
+            parsed[0].exemplars,
+            vec![Exemplar {
+                series_labels: labels!(),
+                labels: labels!(trace_id => "abc"),
+                value: 0.5,
+                timestamp: Some(1395066367600),
+            }]
+        );
+        match_group!(parsed[0], "one", Histogram => |metrics: &MetricMap<HistogramMetric>| {
+            assert_eq!(metrics.len(), 1);
+        });
+
+        let request = proto::v2::Request {
+            symbols: vec![String::new()],
+            timeseries: vec![proto::v2::TimeSeries {
+                labels_refs: vec![1, 2],
+                ..Default::default()
+            }],
+        };
+        assert_eq!(
+            proto::WriteRequest::try_from(request).unwrap_err(),
+            ParserError::InvalidSymbolReference { reference: 1 }
+        );
+    }
 }
@@ -32,6 +32,33 @@ pub struct ComponentAddedSubscription;
 )]
 pub struct ComponentRemovedSubscription;
 
+/// Components subscription for the events of each source awaiting end-to-end acknowledgement
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_acknowledgement_backlogs.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentAcknowledgementBacklogsSubscription;
+
+/// Mutation for pausing a source
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/pause_source.graphql",
+    response_derives = "Debug"
+)]
+pub struct PauseSourceMutation;
+
+/// Mutation for resuming a paused source
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/resume_source.graphql",
+    response_derives = "Debug"
+)]
+pub struct ResumeSourceMutation;
+
 #[async_trait]
 pub trait ComponentsQueryExt {
     async fn components_query(&self, first: i64) -> crate::QueryResult<ComponentsQuery>;
@@ -45,9 +72,36 @@ impl ComponentsQueryExt for crate::Client {
     }
 }
 
+#[async_trait]
+pub trait ComponentsMutationExt {
+    async fn pause_source(&self, id: String) -> crate::QueryResult<PauseSourceMutation>;
+    async fn resume_source(&self, id: String) -> crate::QueryResult<ResumeSourceMutation>;
+}
+
+#[async_trait]
+impl ComponentsMutationExt for crate::Client {
+    /// Pauses the source, returning whether it was running
+    async fn pause_source(&self, id: String) -> QueryResult<PauseSourceMutation> {
+        let request_body =
+            PauseSourceMutation::build_query(pause_source_mutation::Variables { id });
+        self.query::<PauseSourceMutation>(&request_body).await
+    }
+
+    /// Resumes the source, returning whether it was paused
+    async fn resume_source(&self, id: String) -> QueryResult<ResumeSourceMutation> {
+        let request_body =
+            ResumeSourceMutation::build_query(resume_source_mutation::Variables { id });
+        self.query::<ResumeSourceMutation>(&request_body).await
+    }
+}
+
 pub trait ComponentsSubscriptionExt {
     fn component_added(&self) -> crate::BoxedSubscription<ComponentAddedSubscription>;
     fn component_removed(&self) -> crate::BoxedSubscription<ComponentRemovedSubscription>;
+    fn component_acknowledgement_backlogs(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentAcknowledgementBacklogsSubscription>;
 }
 
 #[async_trait]
@@ -67,6 +121,18 @@ impl ComponentsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentRemovedSubscription>(&request_body)
     }
+
+    /// Subscription for the events of each source awaiting acknowledgement, every `interval` ms
+    fn component_acknowledgement_backlogs(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentAcknowledgementBacklogsSubscription> {
+        let request_body = ComponentAcknowledgementBacklogsSubscription::build_query(
+            component_acknowledgement_backlogs_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentAcknowledgementBacklogsSubscription>(&request_body)
+    }
 }
 
 impl components_query::ComponentsQueryComponentsEdgesNodeOn {
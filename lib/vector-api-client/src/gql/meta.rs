@@ -10,6 +10,15 @@ use graphql_client::GraphQLQuery;
 )]
 pub struct MetaVersionStringQuery;
 
+/// SetLogLevelsMutation changes the filter of the internal logs of the Vector instance.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/set_log_levels.graphql",
+    response_derives = "Debug"
+)]
+pub struct SetLogLevelsMutation;
+
 /// Extension methods for meta queries.
 #[async_trait]
 pub trait MetaQueryExt {
@@ -27,3 +36,21 @@ impl MetaQueryExt for crate::Client {
         .await
     }
 }
+
+/// Extension methods for meta mutations.
+#[async_trait]
+pub trait MetaMutationExt {
+    /// Executes a set log levels mutation.
+    async fn set_log_levels(&self, levels: String) -> crate::QueryResult<SetLogLevelsMutation>;
+}
+
+#[async_trait]
+impl MetaMutationExt for crate::Client {
+    /// Changes the filter of the internal logs, returning the previous filter.
+    async fn set_log_levels(&self, levels: String) -> crate::QueryResult<SetLogLevelsMutation> {
+        self.query::<SetLogLevelsMutation>(&SetLogLevelsMutation::build_query(
+            set_log_levels_mutation::Variables { levels },
+        ))
+        .await
+    }
+}
@@ -177,6 +177,26 @@ impl component_sent_events_throughputs_subscription::ComponentSentEventsThroughp
 )]
 pub struct ComponentErrorsTotalsSubscription;
 
+/// ComponentErrorsThroughputsSubscription contains metrics on the number of errors
+/// (metrics ending in `_errors_total`) between `interval` samples, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_errors_throughputs.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentErrorsThroughputsSubscription;
+
+/// ComponentBufferUtilizationsSubscription contains the events and bytes in the buffers of
+/// specific components, and the fraction of the buffer limits in use.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_buffer_utilizations.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentBufferUtilizationsSubscription;
+
 /// Extension methods for metrics subscriptions
 pub trait MetricsSubscriptionExt {
     /// Executes an uptime metrics subscription.
@@ -258,6 +278,18 @@ pub trait MetricsSubscriptionExt {
         &self,
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentErrorsTotalsSubscription>;
+
+    /// Executes a component errors throughputs subscription.
+    fn component_errors_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentErrorsThroughputsSubscription>;
+
+    /// Executes a component buffer utilizations subscription.
+    fn component_buffer_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentBufferUtilizationsSubscription>;
 }
 
 impl MetricsSubscriptionExt for crate::SubscriptionClient {
@@ -421,4 +453,28 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentErrorsTotalsSubscription>(&request_body)
     }
+
+    /// Executes a component errors throughputs subscription.
+    fn component_errors_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentErrorsThroughputsSubscription> {
+        let request_body = ComponentErrorsThroughputsSubscription::build_query(
+            component_errors_throughputs_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentErrorsThroughputsSubscription>(&request_body)
+    }
+
+    /// Executes a component buffer utilizations subscription.
+    fn component_buffer_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentBufferUtilizationsSubscription> {
+        let request_body = ComponentBufferUtilizationsSubscription::build_query(
+            component_buffer_utilizations_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentBufferUtilizationsSubscription>(&request_body)
+    }
 }
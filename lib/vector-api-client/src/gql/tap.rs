@@ -17,6 +17,16 @@ type DateTime = chrono::DateTime<chrono::Utc>;
 )]
 pub struct OutputEventsByComponentIdPatternsSubscription;
 
+/// EventTracesSubscription follows the events matching a condition through the topology, with
+/// a span for every component they reach.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/event_traces.graphql",
+    response_derives = "Debug"
+)]
+pub struct EventTracesSubscription;
+
 /// Tap encoding format type that is more convenient to use for public clients than the
 /// generated `output_events_by_component_id_patterns_subscription::EventEncodingType`.
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
@@ -53,16 +63,36 @@ impl From<TapEncodingFormat>
     }
 }
 
+/// Map the public-facing `TapEncodingFormat` to the internal `EventEncodingType`.
+impl From<TapEncodingFormat> for event_traces_subscription::EventEncodingType {
+    fn from(encoding: TapEncodingFormat) -> Self {
+        match encoding {
+            TapEncodingFormat::Json => Self::JSON,
+            TapEncodingFormat::Yaml => Self::YAML,
+            TapEncodingFormat::Logfmt => Self::LOGFMT,
+        }
+    }
+}
+
 pub trait TapSubscriptionExt {
     /// Executes an output events subscription.
     fn output_events_by_component_id_patterns_subscription(
         &self,
         outputs_patterns: Vec<String>,
         inputs_patterns: Vec<String>,
+        filters: Vec<String>,
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
     ) -> crate::BoxedSubscription<OutputEventsByComponentIdPatternsSubscription>;
+
+    /// Executes an event traces subscription.
+    fn event_traces_subscription(
+        &self,
+        condition: String,
+        encoding: TapEncodingFormat,
+        limit: i64,
+    ) -> crate::BoxedSubscription<EventTracesSubscription>;
 }
 
 impl TapSubscriptionExt for crate::SubscriptionClient {
@@ -71,6 +101,7 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
         &self,
         outputs_patterns: Vec<String>,
         inputs_patterns: Vec<String>,
+        filters: Vec<String>,
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
@@ -79,6 +110,7 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
             output_events_by_component_id_patterns_subscription::Variables {
                 outputs_patterns,
                 inputs_patterns: Some(inputs_patterns),
+                filters: Some(filters),
                 limit,
                 interval,
                 encoding: encoding.into(),
@@ -87,4 +119,21 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<OutputEventsByComponentIdPatternsSubscription>(&request_body)
     }
+
+    /// Executes an event traces subscription.
+    fn event_traces_subscription(
+        &self,
+        condition: String,
+        encoding: TapEncodingFormat,
+        limit: i64,
+    ) -> BoxedSubscription<EventTracesSubscription> {
+        let request_body =
+            EventTracesSubscription::build_query(event_traces_subscription::Variables {
+                condition,
+                limit,
+                encoding: encoding.into(),
+            });
+
+        self.start::<EventTracesSubscription>(&request_body)
+    }
 }
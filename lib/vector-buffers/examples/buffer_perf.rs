@@ -272,6 +272,7 @@ where
             );
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
+                max_age: None,
                 when_full,
             }
         }
@@ -1,9 +1,10 @@
 use std::{
+    collections::VecDeque,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use tokio::time::interval;
@@ -11,7 +12,10 @@ use tracing::{Instrument, Span};
 use vector_common::internal_event::emit;
 
 use crate::{
-    internal_events::{BufferCreated, BufferEventsDropped, BufferEventsReceived, BufferEventsSent},
+    internal_events::{
+        BufferCreated, BufferEventsDropped, BufferEventsExpired, BufferEventsPurged,
+        BufferEventsReceived, BufferEventsSent, BufferOldestEventAge,
+    },
     spawn_named,
 };
 
@@ -77,6 +81,79 @@ impl CategoryMetrics {
             event_byte_size: self.event_byte_size.swap(0, Ordering::AcqRel),
         }
     }
+
+    /// Decrements the event count and byte size by the given amounts, saturating at zero.
+    fn decrement(&self, event_count: u64, event_byte_size: u64) {
+        _ = self
+            .event_count
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                Some(current.saturating_sub(event_count))
+            });
+        _ = self
+            .event_byte_size
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                Some(current.saturating_sub(event_byte_size))
+            });
+    }
+}
+
+/// Events received within this long of each other are tracked together when measuring their age.
+const AGE_BUCKET_WIDTH: Duration = Duration::from_secs(1);
+
+/// Tracks when the events currently in a buffer stage were received, to report the age of the
+/// oldest one.
+///
+/// Events are tracked in buckets of [`AGE_BUCKET_WIDTH`] rather than one by one, which keeps the
+/// tracking cheap at the cost of the age being off by up to that much. As buffer stages are FIFO,
+/// events leaving the buffer are taken from the oldest bucket, except for events dropped as soon as
+/// they were received, which are taken from the newest bucket.
+#[derive(Debug, Default)]
+struct EventAges {
+    buckets: Mutex<VecDeque<(Instant, u64)>>,
+}
+
+impl EventAges {
+    fn push(&self, event_count: u64) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().expect("lock should not be poisoned");
+        match buckets.back_mut() {
+            Some((received_at, count)) if now.duration_since(*received_at) < AGE_BUCKET_WIDTH => {
+                *count += event_count;
+            }
+            _ => buckets.push_back((now, event_count)),
+        }
+    }
+
+    fn pop_oldest(&self, mut event_count: u64) {
+        let mut buckets = self.buckets.lock().expect("lock should not be poisoned");
+        while let Some((_, count)) = buckets.front_mut() {
+            if *count > event_count {
+                *count -= event_count;
+                break;
+            }
+            event_count -= *count;
+            buckets.pop_front();
+        }
+    }
+
+    fn pop_newest(&self, mut event_count: u64) {
+        let mut buckets = self.buckets.lock().expect("lock should not be poisoned");
+        while let Some((_, count)) = buckets.back_mut() {
+            if *count > event_count {
+                *count -= event_count;
+                break;
+            }
+            event_count -= *count;
+            buckets.pop_back();
+        }
+    }
+
+    fn oldest(&self) -> Option<Duration> {
+        let buckets = self.buckets.lock().expect("lock should not be poisoned");
+        buckets
+            .front()
+            .map(|(received_at, _)| received_at.elapsed())
+    }
 }
 
 /// Handle to buffer usage metrics for a specific buffer stage.
@@ -118,6 +195,8 @@ impl BufferUsageHandle {
     /// This represents the events being sent into the buffer.
     pub fn increment_received_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
         self.state.received.increment(count, byte_size);
+        self.state.buffered.increment(count, byte_size);
+        self.state.ages.push(count);
     }
 
     /// Increments the number of events (and their total size) sent by this buffer component.
@@ -125,6 +204,8 @@ impl BufferUsageHandle {
     /// This represents the events being read out of the buffer.
     pub fn increment_sent_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
         self.state.sent.increment(count, byte_size);
+        self.state.buffered.decrement(count, byte_size);
+        self.state.ages.pop_oldest(count);
     }
 
     /// Increment the number of dropped events (and their total size) for this buffer component.
@@ -134,10 +215,68 @@ impl BufferUsageHandle {
         byte_size: u64,
         intentional: bool,
     ) {
+        // Intentionally dropped events are the ones rejected as soon as they were received, while
+        // other events are dropped as they're read out of the buffer.
         if intentional {
             self.state.dropped_intentional.increment(count, byte_size);
+            self.state.ages.pop_newest(count);
         } else {
             self.state.dropped.increment(count, byte_size);
+            self.state.ages.pop_oldest(count);
+        }
+        self.state.buffered.decrement(count, byte_size);
+    }
+
+    /// Increment the number of expired events (and their total size) for this buffer component.
+    ///
+    /// This represents events which were read out of the buffer but dropped, rather than sent, because they were older
+    /// than the configured maximum age.
+    pub fn increment_expired_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.expired.increment(count, byte_size);
+        self.state.buffered.decrement(count, byte_size);
+        self.state.ages.pop_oldest(count);
+    }
+
+    /// Requests the events currently in this buffer component to be purged.
+    ///
+    /// The events are discarded, rather than sent, as they're read out of the buffer. Returns the
+    /// number of events to purge.
+    pub fn request_purge(&self) -> u64 {
+        let count = self.state.buffered.get().event_count;
+        self.state.purge_pending.store(count, Ordering::Release);
+        count
+    }
+
+    /// Checks whether an item of the given size, read out of the buffer, should be purged.
+    ///
+    /// If so, the item is counted as purged. Purged items must still be counted as sent, as they
+    /// did leave the buffer.
+    pub fn try_purge(&self, count: u64, byte_size: u64) -> bool {
+        let purged = self
+            .state
+            .purge_pending
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |pending| {
+                (pending > 0).then_some(pending.saturating_sub(count))
+            })
+            .is_ok();
+        if purged {
+            self.state.purged.increment(count, byte_size);
+        }
+        purged
+    }
+
+    /// Gets the current usage of this buffer component.
+    pub fn usage(&self) -> BufferStageUsage {
+        let buffered = self.state.buffered.get();
+        let max_size = self.state.max_size.get();
+
+        BufferStageUsage {
+            idx: self.state.idx,
+            events: buffered.event_count,
+            byte_size: buffered.event_byte_size,
+            max_events: max_size.event_count,
+            max_byte_size: max_size.event_byte_size,
+            oldest_event_age: self.state.ages.oldest(),
         }
     }
 }
@@ -149,7 +288,12 @@ struct BufferUsageData {
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
+    expired: CategoryMetrics,
+    purged: CategoryMetrics,
     max_size: CategoryMetrics,
+    buffered: CategoryMetrics,
+    ages: EventAges,
+    purge_pending: AtomicU64,
 }
 
 impl BufferUsageData {
@@ -165,6 +309,7 @@ impl BufferUsageData {
         let sent = self.sent.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
+        let expired = self.expired.get();
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -176,6 +321,8 @@ impl BufferUsageData {
             dropped_event_byte_size: dropped.event_byte_size,
             dropped_event_count_intentional: dropped_intentional.event_count,
             dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            expired_event_count: expired.event_count,
+            expired_event_byte_size: expired.event_byte_size,
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -196,10 +343,37 @@ pub struct BufferUsageSnapshot {
     pub dropped_event_byte_size: u64,
     pub dropped_event_count_intentional: u64,
     pub dropped_event_byte_size_intentional: u64,
+    pub expired_event_count: u64,
+    pub expired_event_byte_size: u64,
     pub max_size_bytes: u64,
     pub max_size_events: usize,
 }
 
+/// Current usage of a buffer stage.
+#[derive(Clone, Debug)]
+pub struct BufferStageUsage {
+    /// The index of the stage in the buffer topology.
+    pub idx: usize,
+
+    /// The number of events in the stage.
+    pub events: u64,
+
+    /// The total size of the events in the stage, as measured by the stage.
+    pub byte_size: u64,
+
+    /// The maximum number of events in the stage, or zero if not limited by events.
+    pub max_events: u64,
+
+    /// The maximum total size of the events in the stage, or zero if not limited by size.
+    pub max_byte_size: u64,
+
+    /// How long ago the oldest event in the stage was received, if there is one.
+    ///
+    /// For disk buffers, events which were already in the buffer when Vector started are
+    /// considered received at startup.
+    pub oldest_event_age: Option<Duration>,
+}
+
 /// Builder for tracking buffer usage metrics.
 ///
 /// While building a buffer topology, `BufferUsage` can be utilized to create metrics storage for each individual buffer
@@ -300,6 +474,29 @@ impl BufferUsage {
                             byte_size: dropped_intentional.event_byte_size,
                         });
                     }
+
+                    let expired = stage.expired.consume();
+                    if expired.has_updates() {
+                        emit(BufferEventsExpired {
+                            idx: stage.idx,
+                            count: expired.event_count,
+                            byte_size: expired.event_byte_size,
+                        });
+                    }
+
+                    let purged = stage.purged.consume();
+                    if purged.has_updates() {
+                        emit(BufferEventsPurged {
+                            idx: stage.idx,
+                            count: purged.event_count,
+                            byte_size: purged.event_byte_size,
+                        });
+                    }
+
+                    emit(BufferOldestEventAge {
+                        idx: stage.idx,
+                        age: stage.ages.oldest().unwrap_or_default(),
+                    });
                 }
             }
         };
@@ -3,6 +3,7 @@ use std::{
     num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     slice,
+    time::Duration,
 };
 
 use serde::{de, Deserialize, Deserializer, Serialize};
@@ -28,6 +29,20 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display("invalid buffer configuration: {}", source))]
+    InvalidConfig { source: BufferConfigError },
+}
+
+/// An invalid arrangement of buffer stages.
+///
+/// The `when_full` behavior of each stage is checked when building the buffer topology.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum BufferConfigError {
+    #[snafu(display(
+        "stage {} is a disk buffer, which can only be the last stage of a buffer",
+        stage_idx
+    ))]
+    DiskStageNotLast { stage_idx: usize },
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +53,7 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+const ALL_FIELDS: [&str; 5] = ["type", "max_events", "max_size", "max_age", "when_full"];
 
 struct BufferTypeVisitor;
 
@@ -50,6 +65,7 @@ impl BufferTypeVisitor {
         let mut kind: Option<BufferTypeKind> = None;
         let mut max_events: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
+        let mut max_age: Option<NonZeroU64> = None;
         let mut when_full: Option<WhenFull> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
@@ -71,6 +87,12 @@ impl BufferTypeVisitor {
                     }
                     max_size = Some(map.next_value()?);
                 }
+                "max_age" => {
+                    if max_age.is_some() {
+                        return Err(de::Error::duplicate_field("max_age"));
+                    }
+                    max_age = Some(map.next_value()?);
+                }
                 "when_full" => {
                     if when_full.is_some() {
                         return Err(de::Error::duplicate_field("when_full"));
@@ -92,6 +114,12 @@ impl BufferTypeVisitor {
                         &["type", "max_events", "when_full"],
                     ));
                 }
+                if max_age.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "max_age",
+                        &["type", "max_events", "when_full"],
+                    ));
+                }
                 Ok(BufferType::Memory {
                     max_events: max_events.unwrap_or_else(memory_buffer_default_max_events),
                     when_full,
@@ -101,11 +129,12 @@ impl BufferTypeVisitor {
                 if max_events.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_events",
-                        &["type", "max_size", "when_full"],
+                        &["type", "max_size", "max_age", "when_full"],
                     ));
                 }
                 Ok(BufferType::DiskV2 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
+                    max_age,
                     when_full,
                 })
             }
@@ -215,6 +244,19 @@ pub enum BufferType {
         )]
         max_size: NonZeroU64,
 
+        /// The maximum age of buffered events, in seconds.
+        ///
+        /// Data files that have not been written to for longer than this are expired when they are
+        /// read: their events are dropped instead of being sent to the sink, and the data file is
+        /// deleted. This prevents a backlog of stale events from flooding downstream systems once a
+        /// sink that has been failing for a long time recovers.
+        ///
+        /// By default, events never expire.
+        #[configurable(metadata(docs::type_unit = "seconds"))]
+        #[configurable(metadata(docs::examples = 86400))]
+        #[serde(default)]
+        max_age: Option<NonZeroU64>,
+
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
@@ -286,9 +328,14 @@ impl BufferType {
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                max_age,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let max_age = max_age.map(|secs| Duration::from_secs(secs.get()));
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, max_size, max_age),
+                    when_full,
+                );
             }
         };
 
@@ -310,10 +357,9 @@ impl BufferType {
 /// functionality to allow chaining buffers together, you'll see "buffer topology" used in internal
 /// documentation to correctly reflect the internal structure.
 ///
-// TODO: We need to limit chained buffers to only allowing a single copy of each buffer type to be
-// defined, otherwise, for example, two instances of the same disk buffer type in a single chained
-// buffer topology would try to both open the same buffer files on disk, which wouldn't work or
-// would go horribly wrong.
+/// A chained buffer topology is typically used to tier buffers: an in-memory stage set to
+/// `overflow` absorbs events at memory speed, and only spills them to a disk stage once it's full,
+/// such as during an outage of the sink's destination.
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
@@ -350,6 +396,26 @@ impl BufferConfig {
         }
     }
 
+    /// Validates the arrangement of the stages of this buffer.
+    ///
+    /// # Errors
+    ///
+    /// A disk buffer can only be the last stage, as reading from a stage that overflows can be
+    /// interrupted by the stage it overflows to, which a disk buffer can't resume from. This also
+    /// keeps several disk stages of the same buffer from sharing the same data files.
+    pub fn validate(&self) -> Result<(), BufferConfigError> {
+        let stages = self.stages();
+        match stages
+            .iter()
+            .position(|stage| matches!(stage, BufferType::DiskV2 { .. }))
+        {
+            Some(stage_idx) if stage_idx + 1 != stages.len() => {
+                Err(BufferConfigError::DiskStageNotLast { stage_idx })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Builds the buffer components represented by this configuration.
     ///
     /// The caller gets back a `Sink` and `Stream` implementation that represent a way to push items
@@ -357,8 +423,8 @@ impl BufferConfig {
     ///
     /// # Errors
     ///
-    /// If the buffer is configured with anything other than a single stage, an error variant will
-    /// be thrown.
+    /// If the stages of the buffer are arranged in an invalid way, as described by
+    /// [`BufferConfig::validate`], an error variant will be thrown.
     ///
     /// If a disk buffer stage is configured and the data directory provided is `None`, an error
     /// variant will be thrown.
@@ -372,6 +438,8 @@ impl BufferConfig {
     where
         T: Bufferable + Clone + Finalizable,
     {
+        self.validate().context(InvalidConfigSnafu)?;
+
         let mut builder = TopologyBuilder::default();
 
         for stage in self.stages() {
@@ -387,9 +455,19 @@ impl BufferConfig {
 
 #[cfg(test)]
 mod test {
-    use std::num::{NonZeroU64, NonZeroUsize};
+    use std::{
+        num::{NonZeroU64, NonZeroUsize},
+        time::Duration,
+    };
+
+    use tokio::time::timeout;
+    use tracing::Span;
 
-    use crate::{BufferConfig, BufferType, WhenFull};
+    use super::BufferConfigError;
+    use crate::{
+        test::{with_temp_dir, SizedRecord},
+        BufferConfig, BufferType, WhenFull,
+    };
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -406,6 +484,12 @@ mod test {
         }
     }
 
+    fn validate(source: &str) -> Result<(), BufferConfigError> {
+        serde_yaml::from_str::<BufferConfig>(source)
+            .unwrap()
+            .validate()
+    }
+
     const BUFFER_CONFIG_NO_MATCH_ERR: &str =
         "data did not match any variant of untagged enum BufferConfig";
 
@@ -518,8 +602,121 @@ max_events: 42
           "#,
             BufferType::DiskV2 {
                 max_size: NonZeroU64::new(1024).unwrap(),
+                max_age: None,
+                when_full: WhenFull::Block,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_disk_max_age() {
+        check_single_stage(
+            r#"
+          type: disk
+          max_size: 1024
+          max_age: 3600
+          "#,
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                max_age: NonZeroU64::new(3600),
                 when_full: WhenFull::Block,
             },
         );
+
+        let source = r#"
+          type: memory
+          max_age: 3600
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn validate_overflow_to_disk() {
+        assert_eq!(
+            validate(
+                r#"
+          - type: memory
+            max_events: 1000
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+            when_full: drop_newest
+          "#
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_disk_stage_not_last() {
+        assert_eq!(
+            validate(
+                r#"
+          - type: disk
+            max_size: 268435488
+            when_full: overflow
+          - type: memory
+          "#
+            ),
+            Err(BufferConfigError::DiskStageNotLast { stage_idx: 0 })
+        );
+
+        assert_eq!(
+            validate(
+                r#"
+          - type: disk
+            max_size: 268435488
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          "#
+            ),
+            Err(BufferConfigError::DiskStageNotLast { stage_idx: 0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn overflow_to_disk_delivers_every_event() {
+        with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let config: BufferConfig = serde_yaml::from_str(
+                    r#"
+          - type: memory
+            max_events: 10
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          "#,
+                )
+                .unwrap();
+                let (mut sender, mut receiver) = config
+                    .build::<SizedRecord>(Some(data_dir), String::from("overflow"), Span::none())
+                    .await
+                    .unwrap();
+
+                // Nothing is read until every event is sent, so the memory stage fills up and the
+                // rest of the events overflow to the disk stage. Reading then interleaves both
+                // stages, which must not lose any event of the disk stage.
+                for value in 1..=50 {
+                    sender.send(SizedRecord::new(value)).await.unwrap();
+                }
+                sender.flush().await.unwrap();
+
+                let mut received = Vec::new();
+                for _ in 1..=50 {
+                    let record = timeout(Duration::from_secs(10), receiver.next())
+                        .await
+                        .expect("reading should not time out")
+                        .expect("buffer should not be closed");
+                    received.push(record.0);
+                }
+                received.sort_unstable();
+                assert_eq!(received, (1..=50).collect::<Vec<_>>());
+            }
+        })
+        .await;
     }
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use metrics::{counter, decrement_gauge, gauge, increment_gauge};
 use vector_common::internal_event::{error_type, InternalEvent};
 
@@ -89,6 +91,59 @@ impl InternalEvent for BufferEventsDropped {
     }
 }
 
+pub struct BufferEventsExpired {
+    pub idx: usize,
+    pub count: u64,
+    pub byte_size: u64,
+}
+
+impl InternalEvent for BufferEventsExpired {
+    #[allow(clippy::cast_precision_loss)]
+    fn emit(self) {
+        warn!(
+            message = "Events expired from buffer.",
+            count = %self.count,
+            reason = "max_age",
+            stage = %self.idx,
+            internal_log_rate_limit = true,
+        );
+        counter!("buffer_expired_events_total", self.count, "stage" => self.idx.to_string());
+        counter!("buffer_expired_bytes_total", self.byte_size, "stage" => self.idx.to_string());
+        decrement_gauge!("buffer_events", self.count as f64, "stage" => self.idx.to_string());
+        decrement_gauge!("buffer_byte_size", self.byte_size as f64, "stage" => self.idx.to_string());
+    }
+}
+
+pub struct BufferEventsPurged {
+    pub idx: usize,
+    pub count: u64,
+    pub byte_size: u64,
+}
+
+impl InternalEvent for BufferEventsPurged {
+    fn emit(self) {
+        info!(
+            message = "Events purged from buffer.",
+            count = %self.count,
+            stage = %self.idx,
+        );
+        // Purged events are also counted as sent, which already updates the buffer gauges.
+        counter!("buffer_purged_events_total", self.count, "stage" => self.idx.to_string());
+        counter!("buffer_purged_bytes_total", self.byte_size, "stage" => self.idx.to_string());
+    }
+}
+
+pub struct BufferOldestEventAge {
+    pub idx: usize,
+    pub age: Duration,
+}
+
+impl InternalEvent for BufferOldestEventAge {
+    fn emit(self) {
+        gauge!("buffer_oldest_event_age_seconds", self.age.as_secs_f64(), "stage" => self.idx.to_string());
+    }
+}
+
 pub struct BufferReadError {
     pub error_code: &'static str,
     pub error: String,
@@ -16,7 +16,7 @@ extern crate tracing;
 mod buffer_usage_data;
 
 pub mod config;
-pub use config::{BufferConfig, BufferType};
+pub use config::{BufferConfig, BufferConfigError, BufferType};
 use encoding::Encodable;
 use vector_config::configurable_component;
 
@@ -65,8 +65,8 @@ pub enum WhenFull {
     /// buffer topology must use one of the other handling behaviors. This means that next stage may
     /// potentially be able to buffer the event, but it may also block or drop the event.
     ///
-    /// This mode can only be used when two or more buffer stages are configured.
-    #[configurable(metadata(docs::hidden))]
+    /// This mode can only be used when two or more buffer stages are configured, such as to spill
+    /// events from an in-memory buffer to a disk buffer when the in-memory buffer is full.
     Overflow,
 }
 
@@ -7,7 +7,10 @@ use tracing::Span;
 use super::channel::{ReceiverAdapter, SenderAdapter};
 use crate::{
     buffer_usage_data::{BufferUsage, BufferUsageHandle},
-    topology::channel::{BufferReceiver, BufferSender},
+    topology::{
+        channel::{BufferReceiver, BufferSender},
+        control::BufferControl,
+    },
     variants::MemoryBuffer,
     Bufferable, WhenFull,
 };
@@ -111,6 +114,7 @@ impl<T: Bufferable> TopologyBuilder<T> {
     ) -> Result<(BufferSender<T>, BufferReceiver<T>), TopologyError> {
         // We pop stages off in reverse order to build from the inside out.
         let mut buffer_usage = BufferUsage::from_span(span);
+        let mut usage_handles = Vec::new();
         let mut current_stage = None;
 
         for (stage_idx, stage) in self.stages.into_iter().enumerate().rev() {
@@ -156,13 +160,16 @@ impl<T: Bufferable> TopologyBuilder<T> {
 
             if !provides_instrumentation {
                 sender.with_instrumentation(usage_handle.clone());
-                receiver.with_instrumentation(usage_handle);
+                receiver.with_instrumentation(usage_handle.clone());
             }
+            receiver.with_purging(usage_handle.clone());
+            usage_handles.push(usage_handle);
 
             current_stage = Some((sender, receiver));
         }
 
-        let (sender, receiver) = current_stage.ok_or(TopologyError::EmptyTopology)?;
+        let (mut sender, receiver) = current_stage.ok_or(TopologyError::EmptyTopology)?;
+        sender.with_control(BufferControl::new(usage_handles));
 
         // Install the buffer usage handler since we successfully created the buffer topology.  This
         // spawns it in the background and periodically emits aggregated metrics about each of the
@@ -252,8 +259,9 @@ impl<T: Bufferable> Default for TopologyBuilder<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroUsize;
+    use std::{num::NonZeroUsize, time::Duration};
 
+    use tokio::time::timeout;
     use tracing::Span;
 
     use super::TopologyBuilder;
@@ -360,4 +368,68 @@ mod tests {
         let (mut sender, _) = result.unwrap();
         assert_current_send_capacity(&mut sender, Some(1), Some(1));
     }
+
+    #[tokio::test]
+    async fn control_purges_buffered_events() {
+        let mut builder = TopologyBuilder::<Sample>::default();
+        builder.stage(
+            MemoryBuffer::new(NonZeroUsize::new(4).unwrap()),
+            WhenFull::Block,
+        );
+        let (mut sender, mut receiver) = builder
+            .build(String::from("test"), Span::none())
+            .await
+            .unwrap();
+        let control = sender.control().cloned().expect("control should exist");
+
+        for value in 1..=3 {
+            sender.send(Sample(value)).await.unwrap();
+        }
+        let stages = control.stages();
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0].events, 3);
+        assert!(stages[0].oldest_event_age.is_some());
+
+        assert_eq!(control.purge(), 3);
+        sender.send(Sample(4)).await.unwrap();
+        drop(sender);
+
+        assert_eq!(receiver.next().await, Some(Sample(4)));
+        assert_eq!(receiver.next().await, None);
+
+        let stages = control.stages();
+        assert_eq!(stages[0].events, 0);
+        assert!(stages[0].oldest_event_age.is_none());
+    }
+
+    #[tokio::test]
+    async fn control_drains_buffered_events() {
+        let mut builder = TopologyBuilder::<Sample>::default();
+        builder.stage(
+            MemoryBuffer::new(NonZeroUsize::new(4).unwrap()),
+            WhenFull::Block,
+        );
+        let (mut sender, mut receiver) = builder
+            .build(String::from("test"), Span::none())
+            .await
+            .unwrap();
+        let control = sender.control().cloned().expect("control should exist");
+
+        sender.send(Sample(1)).await.unwrap();
+        assert_eq!(control.drain(), 1);
+        assert!(control.is_draining());
+
+        // New events are held back until the buffer is empty.
+        assert!(timeout(Duration::from_millis(250), sender.send(Sample(2)))
+            .await
+            .is_err());
+        assert_eq!(receiver.next().await, Some(Sample(1)));
+
+        timeout(Duration::from_secs(1), sender.send(Sample(2)))
+            .await
+            .expect("buffer should be drained")
+            .unwrap();
+        assert!(!control.is_draining());
+        assert_eq!(receiver.next().await, Some(Sample(2)));
+    }
 }
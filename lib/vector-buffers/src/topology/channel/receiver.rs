@@ -1,11 +1,11 @@
 use std::{
-    mem,
+    fmt, mem,
     pin::Pin,
     task::{ready, Context, Poll},
 };
 
 use async_recursion::async_recursion;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use tokio::select;
 use tokio_util::sync::ReusableBoxFuture;
 use vector_common::internal_event::emit;
@@ -72,11 +72,18 @@ where
 /// for querying the overflow buffer as well.  The ordering of events when operating in "overflow"
 /// is undefined, as the receiver will try to manage polling both its own buffer, as well as the
 /// overflow buffer, in order to fairly balance throughput.
+///
+/// The overflow receiver is read through a [`BufferReceiverStream`], which keeps a pending read
+/// across calls, so that a read of the overflow buffer interrupted by an item of the base buffer is
+/// resumed rather than dropped. Dropping a read of a disk buffer halfway through a record would
+/// desynchronize it from its data file. The base buffer must therefore be an in-memory buffer,
+/// whose reads can be dropped at any point.
 #[derive(Debug)]
 pub struct BufferReceiver<T: Bufferable> {
     base: ReceiverAdapter<T>,
-    overflow: Option<Box<BufferReceiver<T>>>,
+    overflow: Option<Box<BufferReceiverStream<T>>>,
     instrumentation: Option<BufferUsageHandle>,
+    purging: Option<BufferUsageHandle>,
 }
 
 impl<T: Bufferable> BufferReceiver<T> {
@@ -86,6 +93,7 @@ impl<T: Bufferable> BufferReceiver<T> {
             base,
             overflow: None,
             instrumentation: None,
+            purging: None,
         }
     }
 
@@ -93,8 +101,9 @@ impl<T: Bufferable> BufferReceiver<T> {
     pub fn with_overflow(base: ReceiverAdapter<T>, overflow: BufferReceiver<T>) -> Self {
         Self {
             base,
-            overflow: Some(Box::new(overflow)),
+            overflow: Some(Box::new(overflow.into_stream())),
             instrumentation: None,
+            purging: None,
         }
     }
 
@@ -104,7 +113,7 @@ impl<T: Bufferable> BufferReceiver<T> {
     /// when initially constructing `BufferSender<T>`.
     #[cfg(test)]
     pub fn switch_to_overflow(&mut self, overflow: BufferReceiver<T>) {
-        self.overflow = Some(Box::new(overflow));
+        self.overflow = Some(Box::new(overflow.into_stream()));
     }
 
     /// Configures this receiver to instrument the items passing through it.
@@ -112,6 +121,12 @@ impl<T: Bufferable> BufferReceiver<T> {
         self.instrumentation = Some(handle);
     }
 
+    /// Configures this receiver to discard the items read from its base receiver when the buffer
+    /// stage tracked by the given handle is purged.
+    pub(crate) fn with_purging(&mut self, handle: BufferUsageHandle) {
+        self.purging = Some(handle);
+    }
+
     #[async_recursion]
     pub async fn next(&mut self) -> Option<T> {
         // We want to poll both our base and overflow receivers without waiting for one or the
@@ -122,34 +137,42 @@ impl<T: Bufferable> BufferReceiver<T> {
         // occurred, and is over, and items are flowing through the base receiver.  If we waited to
         // entirely drain the overflow receiver, we might cause another small stall of the pipeline
         // attached to the base receiver.
-        let overflow = self.overflow.as_mut().map(Pin::new);
-
-        let (item, from_base) = match overflow {
-            None => match self.base.next().await {
-                Some(item) => (item, true),
-                None => return None,
-            },
-            Some(mut overflow) => {
-                select! {
-                    Some(item) = overflow.next() => (item, false),
-                    Some(item) = self.base.next() => (item, true),
-                    else => return None,
+        loop {
+            let (item, from_base) = match self.overflow.as_mut() {
+                None => match self.base.next().await {
+                    Some(item) => (item, true),
+                    None => return None,
+                },
+                Some(overflow) => {
+                    select! {
+                        Some(item) = overflow.next() => (item, false),
+                        Some(item) = self.base.next() => (item, true),
+                        else => return None,
+                    }
+                }
+            };
+
+            // If instrumentation is enabled, and we got the item from the base receiver, then and
+            // only then do we track sending the event out.
+            if let Some(handle) = self.instrumentation.as_ref() {
+                if from_base {
+                    handle.increment_sent_event_count_and_byte_size(
+                        item.event_count() as u64,
+                        item.size_of() as u64,
+                    );
                 }
             }
-        };
-
-        // If instrumentation is enabled, and we got the item from the base receiver, then and only
-        // then do we track sending the event out.
-        if let Some(handle) = self.instrumentation.as_ref() {
-            if from_base {
-                handle.increment_sent_event_count_and_byte_size(
-                    item.event_count() as u64,
-                    item.size_of() as u64,
-                );
+
+            // Items being purged are dropped rather than returned. They were still read out of the
+            // buffer, so they've already been tracked as sent above, or by the stage itself.
+            if let Some(handle) = self.purging.as_ref() {
+                if from_base && handle.try_purge(item.event_count() as u64, item.size_of() as u64) {
+                    continue;
+                }
             }
-        }
 
-        Some(item)
+            return Some(item);
+        }
     }
 
     pub fn into_stream(self) -> BufferReceiverStream<T> {
@@ -168,6 +191,13 @@ pub struct BufferReceiverStream<T: Bufferable> {
     recv_fut: ReusableBoxFuture<'static, (Option<T>, BufferReceiver<T>)>,
 }
 
+impl<T: Bufferable> fmt::Debug for BufferReceiverStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferReceiverStream")
+            .finish_non_exhaustive()
+    }
+}
+
 impl<T: Bufferable> BufferReceiverStream<T> {
     pub fn new(receiver: BufferReceiver<T>) -> Self {
         Self {
@@ -6,6 +6,7 @@ use tokio::sync::Mutex;
 use super::limited_queue::LimitedSender;
 use crate::{
     buffer_usage_data::BufferUsageHandle,
+    topology::control::BufferControl,
     variants::disk_v2::{self, ProductionFilesystem},
     Bufferable, WhenFull,
 };
@@ -130,6 +131,7 @@ pub struct BufferSender<T: Bufferable> {
     overflow: Option<Box<BufferSender<T>>>,
     when_full: WhenFull,
     instrumentation: Option<BufferUsageHandle>,
+    control: Option<BufferControl>,
 }
 
 impl<T: Bufferable> BufferSender<T> {
@@ -140,6 +142,7 @@ impl<T: Bufferable> BufferSender<T> {
             overflow: None,
             when_full,
             instrumentation: None,
+            control: None,
         }
     }
 
@@ -150,6 +153,7 @@ impl<T: Bufferable> BufferSender<T> {
             overflow: Some(Box::new(overflow)),
             when_full: WhenFull::Overflow,
             instrumentation: None,
+            control: None,
         }
     }
 
@@ -167,6 +171,19 @@ impl<T: Bufferable> BufferSender<T> {
     pub fn with_instrumentation(&mut self, handle: BufferUsageHandle) {
         self.instrumentation = Some(handle);
     }
+
+    /// Configures this sender to hold back items while the buffer is being drained.
+    pub(crate) fn with_control(&mut self, control: BufferControl) {
+        self.control = Some(control);
+    }
+
+    /// Gets the control handle of the buffer this sender sends to, if any.
+    ///
+    /// Only senders of buffers built with [`TopologyBuilder`][crate::topology::builder::TopologyBuilder]
+    /// have a control handle.
+    pub fn control(&self) -> Option<&BufferControl> {
+        self.control.as_ref()
+    }
 }
 
 impl<T: Bufferable> BufferSender<T> {
@@ -182,6 +199,10 @@ impl<T: Bufferable> BufferSender<T> {
 
     #[async_recursion]
     pub async fn send(&mut self, item: T) -> crate::Result<()> {
+        if let Some(control) = self.control.as_ref() {
+            control.ready().await;
+        }
+
         let item_sizing = self
             .instrumentation
             .as_ref()
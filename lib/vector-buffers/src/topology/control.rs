@@ -0,0 +1,124 @@
+use std::{
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use tokio::{sync::watch, time::interval};
+
+pub use crate::buffer_usage_data::BufferStageUsage;
+use crate::{buffer_usage_data::BufferUsageHandle, spawn_named};
+
+/// How often a draining buffer checks whether it has been emptied.
+const DRAIN_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+struct Inner {
+    stages: Vec<BufferUsageHandle>,
+    draining: watch::Sender<bool>,
+}
+
+impl Inner {
+    fn is_empty(&self) -> bool {
+        self.stages.iter().all(|stage| stage.usage().events == 0)
+    }
+}
+
+/// Handle to inspect and administer a buffer topology while it's running.
+///
+/// A control handle is created by [`TopologyBuilder::build`][super::builder::TopologyBuilder::build]
+/// and can be obtained from the resulting [`BufferSender`][super::channel::BufferSender].
+#[derive(Clone, Debug)]
+pub struct BufferControl {
+    inner: Arc<Inner>,
+}
+
+impl BufferControl {
+    pub(crate) fn new(mut stages: Vec<BufferUsageHandle>) -> Self {
+        stages.sort_by_key(|stage| stage.usage().idx);
+        let (draining, _) = watch::channel(false);
+
+        Self {
+            inner: Arc::new(Inner { stages, draining }),
+        }
+    }
+
+    /// Gets the current usage of each stage of the buffer, in order.
+    pub fn stages(&self) -> Vec<BufferStageUsage> {
+        self.inner
+            .stages
+            .iter()
+            .map(BufferUsageHandle::usage)
+            .collect()
+    }
+
+    /// Whether or not the buffer is currently being drained.
+    pub fn is_draining(&self) -> bool {
+        *self.inner.draining.borrow()
+    }
+
+    /// Drains the buffer.
+    ///
+    /// New events are held back from entering the buffer until all of its stages have been emptied
+    /// by the sink reading from it, after which the buffer resumes accepting events. This applies
+    /// backpressure to the components sending events to the buffer in the meantime.
+    ///
+    /// Returns the number of events to drain.
+    pub fn drain(&self) -> u64 {
+        let events = self.stages().iter().map(|stage| stage.events).sum();
+        if self.inner.draining.send_replace(true) {
+            // Already draining.
+            return events;
+        }
+
+        let inner = Arc::downgrade(&self.inner);
+        spawn_named(wait_until_drained(inner), "buffer drain");
+
+        events
+    }
+
+    /// Purges the buffer.
+    ///
+    /// The events in the buffer at the time of the call are discarded, rather than sent, as the sink
+    /// reads them out of the buffer. Events already read by the sink are not affected. Purged events
+    /// are acknowledged, and for disk buffers, their data files are deleted as they would be once
+    /// sent.
+    ///
+    /// Returns the number of events to purge.
+    pub fn purge(&self) -> u64 {
+        self.inner
+            .stages
+            .iter()
+            .map(BufferUsageHandle::request_purge)
+            .sum()
+    }
+
+    /// Waits for the buffer to accept new events, if it's being drained.
+    pub(crate) async fn ready(&self) {
+        if !*self.inner.draining.borrow() {
+            return;
+        }
+
+        let mut draining = self.inner.draining.subscribe();
+        while *draining.borrow_and_update() {
+            if draining.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+async fn wait_until_drained(inner: Weak<Inner>) {
+    let mut interval = interval(DRAIN_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        // Stop once the buffer itself is gone.
+        let Some(inner) = inner.upgrade() else {
+            break;
+        };
+        if inner.is_empty() {
+            inner.draining.send_replace(false);
+            break;
+        }
+    }
+}
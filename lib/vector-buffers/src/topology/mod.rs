@@ -1,6 +1,7 @@
 pub mod acks;
 pub mod builder;
 pub mod channel;
+pub mod control;
 
 #[cfg(test)]
 pub mod test_util;
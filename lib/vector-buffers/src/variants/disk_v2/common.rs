@@ -36,6 +36,11 @@ pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_millis(500);
 // buffer throughput and estimate how many IOPS will be consumed, etc.
 pub const DEFAULT_WRITE_BUFFER_SIZE: usize = 256 * 1024;
 
+// When a maximum age is configured, the reader periodically re-checks the age of the data file it's currently reading,
+// so that a data file which was fresh when it was opened, but sat behind a stalled sink, still gets expired.  Once a
+// second keeps the cost of that down to a single `stat` call per second at most.
+pub const DATA_FILE_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 // We specifically limit ourselves to 0-31 for file IDs in test, because it lets us more quickly
 // create/consume the file IDs so we can test edge cases like file ID rollover and "writer is
 // waiting to open file that reader is still on".
@@ -153,6 +158,13 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Maximum age of records in the buffer.
+    ///
+    /// When the reader encounters a data file that was last written to longer ago than this, the records in it are
+    /// considered expired: they are acknowledged and dropped rather than being returned to the caller, which allows
+    /// the data file to be deleted.
+    pub(crate) max_age: Option<Duration>,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -173,6 +185,7 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) max_age: Option<Duration>,
     pub(crate) filesystem: FS,
 }
 
@@ -188,6 +201,7 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            max_age: None,
             filesystem: ProductionFilesystem,
         }
     }
@@ -268,6 +282,19 @@ where
         self
     }
 
+    /// Sets the maximum age of records in the buffer.
+    ///
+    /// When the reader encounters a data file that was last written to longer ago than this, the records in it are
+    /// considered expired: they are acknowledged and dropped rather than being returned to the caller, which allows
+    /// the data file to be deleted.
+    ///
+    /// Defaults to no maximum age.
+    #[allow(dead_code)]
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -287,6 +314,7 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            max_age: self.max_age,
             filesystem,
         }
     }
@@ -301,6 +329,7 @@ where
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
         let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let max_age = self.max_age;
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -365,6 +394,13 @@ where
             });
         }
 
+        if max_age == Some(Duration::ZERO) {
+            return Err(BuildError::InvalidParameter {
+                param_name: "max_age",
+                reason: "cannot be zero".to_string(),
+            });
+        }
+
         // Users configure the `max_size` of their disk buffers, which translates to the `max_buffer_size` field here,
         // and represents the maximum desired size of a disk buffer in terms of on-disk usage. In order to meet this
         // request, we do a few things internally and also enforce a lower bound on `max_buffer_size` to ensure we can
@@ -412,6 +448,7 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            max_age,
             filesystem,
         })
     }
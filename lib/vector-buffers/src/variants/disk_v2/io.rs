@@ -1,4 +1,4 @@
-use std::{io, path::Path};
+use std::{io, path::Path, time::SystemTime};
 
 use async_trait::async_trait;
 use tokio::io::{AsyncRead, AsyncWrite};
@@ -6,6 +6,7 @@ use tokio::io::{AsyncRead, AsyncWrite};
 /// File metadata.
 pub struct Metadata {
     pub(crate) len: u64,
+    pub(crate) modified: Option<SystemTime>,
 }
 
 impl Metadata {
@@ -13,6 +14,11 @@ impl Metadata {
     pub fn len(&self) -> u64 {
         self.len
     }
+
+    /// Gets the last modification time of the file, if the platform supports it.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
 }
 
 /// Generalized interface for opening and deleting files from a filesystem.
@@ -177,6 +183,7 @@ impl AsyncFile for tokio::fs::File {
         let metadata = self.metadata().await?;
         Ok(Metadata {
             len: metadata.len(),
+            modified: metadata.modified().ok(),
         })
     }
 
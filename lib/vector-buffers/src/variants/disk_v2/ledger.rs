@@ -398,6 +398,13 @@ where
             .increment_sent_event_count_and_byte_size(event_count, total_record_size);
     }
 
+    /// Tracks the statistics of multiple reads which were expired instead of being sent.
+    pub fn track_expired_reads(&self, event_count: u64, total_record_size: u64) {
+        self.decrement_total_buffer_size(total_record_size);
+        self.usage_handle
+            .increment_expired_event_count_and_byte_size(event_count, total_record_size);
+    }
+
     /// Marks the writer as finished.
     ///
     /// If the writer was not yet marked done, `false` is returned.  Otherwise, `true` is returned,
@@ -176,6 +176,7 @@ use std::{
     num::NonZeroU64,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -301,14 +302,21 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    max_age: Option<Duration>,
 }
 
 impl DiskV2Buffer {
-    pub fn new(id: String, data_dir: PathBuf, max_size: NonZeroU64) -> Self {
+    pub fn new(
+        id: String,
+        data_dir: PathBuf,
+        max_size: NonZeroU64,
+        max_age: Option<Duration>,
+    ) -> Self {
         Self {
             id,
             data_dir,
             max_size,
+            max_age,
         }
     }
 }
@@ -331,6 +339,7 @@ where
             &self.data_dir,
             self.id.as_str(),
             self.max_size,
+            self.max_age,
         )
         .await?;
 
@@ -343,6 +352,7 @@ async fn build_disk_v2_buffer<T>(
     data_dir: &Path,
     id: &str,
     max_size: NonZeroU64,
+    max_age: Option<Duration>,
 ) -> Result<
     (
         Writer<T, ProductionFilesystem>,
@@ -356,9 +366,12 @@ where
     usage_handle.set_buffer_limits(Some(max_size.get()), None);
 
     let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
-    let config = DiskBufferConfigBuilder::from_path(buffer_path)
-        .max_buffer_size(max_size.get())
-        .build()?;
+    let mut builder =
+        DiskBufferConfigBuilder::from_path(buffer_path).max_buffer_size(max_size.get());
+    if let Some(max_age) = max_age {
+        builder = builder.max_age(max_age);
+    }
+    let config = builder.build()?;
     Buffer::from_config(config, usage_handle)
         .await
         .map_err(Into::into)
@@ -5,6 +5,7 @@ use std::{
     num::NonZeroU64,
     path::PathBuf,
     sync::Arc,
+    time::Instant,
 };
 
 use crc32fast::Hasher;
@@ -14,7 +15,7 @@ use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use vector_common::{finalization::BatchNotifier, finalizer::OrderedFinalizer};
 
 use super::{
-    common::create_crc32c_hasher,
+    common::{create_crc32c_hasher, DATA_FILE_EXPIRY_CHECK_INTERVAL},
     ledger::Ledger,
     record::{validate_record_archive, ArchivedRecord, Record, RecordStatus},
     Filesystem,
@@ -222,6 +223,11 @@ where
         }
     }
 
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.reader.get_ref()
+    }
+
     #[cfg_attr(test, instrument(skip(self), level = "trace"))]
     async fn read_length_delimiter(
         &mut self,
@@ -405,8 +411,10 @@ where
     data_file_start_record_id: Option<u64>,
     data_file_record_count: u64,
     data_file_marked_record_count: u64,
+    data_file_expired_bytes: Option<u64>,
+    data_file_expiry_checked_at: Option<Instant>,
     ready_to_read: bool,
-    record_acks: OrderedAcknowledgements<u64, u64>,
+    record_acks: OrderedAcknowledgements<u64, (u64, bool)>,
     data_file_acks: OrderedAcknowledgements<u64, (PathBuf, u64)>,
     finalizer: OrderedFinalizer<u64>,
     _t: PhantomData<T>,
@@ -431,6 +439,8 @@ where
             data_file_start_record_id: None,
             data_file_record_count: 0,
             data_file_marked_record_count: 0,
+            data_file_expired_bytes: None,
+            data_file_expiry_checked_at: None,
             ready_to_read: false,
             record_acks: OrderedAcknowledgements::from_acked(next_expected_record_id),
             data_file_acks: OrderedAcknowledgements::from_acked(0),
@@ -443,9 +453,17 @@ where
         self.reader = None;
         self.bytes_read = 0;
         self.data_file_start_record_id = None;
+        self.data_file_expired_bytes = None;
+        self.data_file_expiry_checked_at = None;
     }
 
-    fn track_read(&mut self, record_id: u64, record_bytes: u64, event_count: NonZeroU64) {
+    fn track_read(
+        &mut self,
+        record_id: u64,
+        record_bytes: u64,
+        event_count: NonZeroU64,
+        expired: bool,
+    ) {
         // We explicitly reduce the event count by one here in order to correctly calculate the
         // "last" record ID, which you can visualize as follows...
         //
@@ -473,10 +491,11 @@ where
         // We've done a "real" record read, so we need to track it for acknowledgement.  Check our
         // acknowledge state first to see if this is the next record ID we expected.
         self.data_file_record_count += 1;
-        if let Err(me) =
-            self.record_acks
-                .add_marker(record_id, Some(event_count.get()), Some(record_bytes))
-        {
+        if let Err(me) = self.record_acks.add_marker(
+            record_id,
+            Some(event_count.get()),
+            Some((record_bytes, expired)),
+        ) {
             match me {
                 MarkerError::MonotonicityViolation => {
                     panic!("record ID monotonicity violation detected; this is a serious bug")
@@ -578,6 +597,8 @@ where
         let mut events_acknowledged: u64 = 0;
         let mut events_skipped: u64 = 0;
         let mut bytes_acknowledged: u64 = 0;
+        let mut events_expired: u64 = 0;
+        let mut bytes_expired: u64 = 0;
 
         let consumed_acks = self.ledger.consume_pending_acks();
         if consumed_acks > 0 {
@@ -602,16 +623,26 @@ where
                     }
                     // We got a valid marker representing a known number of events.
                     EligibleMarkerLength::Known(len) => {
-                        // We specifically pass the size of the record, in bytes, as the marker data.
-                        let record_bytes = data.expect("record bytes should always be known");
+                        // We specifically pass the size of the record, in bytes, as the marker data,
+                        // along with whether or not the record was expired rather than sent.
+                        let (record_bytes, expired) =
+                            data.expect("record bytes should always be known");
 
                         records_acknowledged = records_acknowledged.checked_add(1).expect(
                             "acknowledging more than 2^64 records at a time is obviously a bug",
                         );
-                        events_acknowledged = events_acknowledged.checked_add(len).expect(
+
+                        // Expired records are acknowledged like any other, but we track them
+                        // separately so they aren't reported as having been sent.
+                        let (events, bytes) = if expired {
+                            (&mut events_expired, &mut bytes_expired)
+                        } else {
+                            (&mut events_acknowledged, &mut bytes_acknowledged)
+                        };
+                        *events = events.checked_add(len).expect(
                             "acknowledging more than 2^64 events at a time is obviously a bug",
                         );
-                        bytes_acknowledged = bytes_acknowledged.checked_add(record_bytes).expect(
+                        *bytes = bytes.checked_add(record_bytes).expect(
                             "acknowledging more than 2^64 bytes at a time is obviously a bug",
                         );
                     }
@@ -622,10 +653,14 @@ where
             if had_eligible_records {
                 self.ledger
                     .track_reads(events_acknowledged, bytes_acknowledged);
+                if events_expired > 0 {
+                    self.ledger
+                        .track_expired_reads(events_expired, bytes_expired);
+                }
 
-                // We need to account for skipped events, too, so that our "last reader record ID"
-                // value stays correct as we process these gap markers.
-                let last_increment_amount = events_acknowledged + events_skipped;
+                // We need to account for expired and skipped events, too, so that our "last reader
+                // record ID" value stays correct as we process these markers.
+                let last_increment_amount = events_acknowledged + events_expired + events_skipped;
                 self.ledger
                     .state()
                     .increment_last_reader_record_id(last_increment_amount);
@@ -678,6 +713,7 @@ where
                     current_buffer_size = self.ledger.get_total_buffer_size(),
                     records_acknowledged,
                     events_acknowledged,
+                    events_expired,
                     events_skipped,
                     bytes_acknowledged,
                     data_files_deleted,
@@ -790,6 +826,102 @@ where
         }
     }
 
+    /// Checks whether the current data file has outlived the configured maximum age.
+    ///
+    /// A data file's modification time is when its newest record was written, so once that is older
+    /// than the maximum age, every record in the data file -- up to its length at the time of the
+    /// check -- is too.  We remember that length, rather than just a flag, because the writer may
+    /// still be appending fresh records to the same data file.
+    ///
+    /// This is re-checked periodically, and not just when the data file is opened, since the reader
+    /// can sit on a data file for a long time if the sink stops pulling records.
+    async fn refresh_data_file_expiry(&mut self) -> io::Result<()> {
+        let Some(max_age) = self.ledger.config().max_age else {
+            return Ok(());
+        };
+
+        // Records being replayed while seeking have already been acknowledged, so there's nothing to expire.
+        if !self.ready_to_read {
+            return Ok(());
+        }
+
+        if self
+            .data_file_expiry_checked_at
+            .map_or(false, |checked_at| {
+                checked_at.elapsed() < DATA_FILE_EXPIRY_CHECK_INTERVAL
+            })
+        {
+            return Ok(());
+        }
+
+        let Some(reader) = self.reader.as_ref() else {
+            return Ok(());
+        };
+        let metadata = reader.get_ref().metadata().await?;
+        self.data_file_expiry_checked_at = Some(Instant::now());
+
+        let expired = metadata
+            .modified()
+            .and_then(|modified| modified.elapsed().ok())
+            .map_or(false, |age| age >= max_age);
+        if expired {
+            debug!(
+                data_file_path = self
+                    .ledger
+                    .get_current_reader_data_file_path()
+                    .to_string_lossy()
+                    .as_ref(),
+                expired_bytes = metadata.len(),
+                "Data file is older than the maximum age. Expiring its records."
+            );
+            self.data_file_expired_bytes = Some(metadata.len());
+        }
+
+        Ok(())
+    }
+
+    /// Whether or not the next record in the current data file is expired.
+    fn is_next_record_expired(&self) -> bool {
+        self.data_file_expired_bytes
+            .map_or(false, |expired_bytes| self.bytes_read < expired_bytes)
+    }
+
+    /// Reads the record associated with the given [`ReadToken`] and expires it.
+    ///
+    /// Expired records are still tracked and finalized like any other read, so that acknowledgements
+    /// stay in order with any records that are still in flight, and the data file can be deleted
+    /// once all of its records are accounted for.  They are simply never handed to the caller.
+    fn expire_record(&mut self, token: ReadToken) -> Result<(), ReaderError<T>> {
+        let record_id = token.record_id();
+        let record_bytes = token.record_bytes() as u64;
+
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("reader should exist after `ensure_ready_for_read`");
+        let record = reader.read_record(token)?;
+        let record_events = get_record_event_count(&record)?;
+        drop(record);
+
+        self.track_read(record_id, record_bytes, record_events, true);
+
+        // Dropping the batch notifier straight away finalizes the record as soon as everything read
+        // before it has been.
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        drop(batch);
+        self.finalizer.add(record_events.get(), receiver);
+
+        trace!(
+            record_id,
+            record_events,
+            record_bytes,
+            data_file_id = self.ledger.get_current_reader_file_id(),
+            "Expired record."
+        );
+
+        Ok(())
+    }
+
     /// Seeks to where this reader previously left off.
     ///
     /// In cases where Vector has restarted, but the reader hasn't yet finished a file, we would
@@ -986,6 +1118,7 @@ where
             }
 
             self.ensure_ready_for_read().await.context(IoSnafu)?;
+            self.refresh_data_file_expiry().await.context(IoSnafu)?;
 
             let reader = self
                 .reader
@@ -1011,8 +1144,16 @@ where
                 // Not even enough data to read a length delimiter, so we need to wait for the
                 // writer to signal us that there's some actual data to read.
                 Ok(None) => {}
-                // We got a valid record, so keep the token.
-                Ok(Some(token)) => break token,
+                // We got a valid record, so keep the token... unless it's expired, in which case we
+                // expire it and move on to the next one.
+                Ok(Some(token)) => {
+                    if !self.is_next_record_expired() {
+                        break token;
+                    }
+
+                    self.expire_record(token)?;
+                    continue;
+                }
                 // A length-delimited payload was read, but we failed to deserialize it as a valid
                 // record, or we deserialized it and the checksum was invalid.  Either way, we're not
                 // sure the rest of the data file is even valid, so roll to the next file.
@@ -1106,14 +1247,8 @@ where
             .expect("reader should exist after `ensure_ready_for_read`");
         let mut record = reader.read_record(token)?;
 
-        let record_events: u64 = record
-            .event_count()
-            .try_into()
-            .expect("Event count for a record cannot exceed 2^64 events.");
-        let record_events = record_events
-            .try_into()
-            .map_err(|_| ReaderError::EmptyRecord)?;
-        self.track_read(record_id, record_bytes, record_events);
+        let record_events = get_record_event_count(&record)?;
+        self.track_read(record_id, record_bytes, record_events, false);
 
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         record.add_batch_notifier(batch);
@@ -1133,6 +1268,16 @@ where
     }
 }
 
+fn get_record_event_count<T: Bufferable>(record: &T) -> Result<NonZeroU64, ReaderError<T>> {
+    let record_events: u64 = record
+        .event_count()
+        .try_into()
+        .expect("Event count for a record cannot exceed 2^64 events.");
+    record_events
+        .try_into()
+        .map_err(|_| ReaderError::EmptyRecord)
+}
+
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
 ) -> Result<T, ReaderError<T>> {
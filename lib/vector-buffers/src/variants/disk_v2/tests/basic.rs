@@ -1,4 +1,4 @@
-use std::io::Cursor;
+use std::{io::Cursor, time::Duration};
 
 use futures::{stream, StreamExt};
 use tokio_test::{assert_pending, assert_ready, task::spawn};
@@ -9,7 +9,10 @@ use super::{create_default_buffer_v2, read_next, read_next_some};
 use crate::{
     assert_buffer_is_empty, assert_buffer_records,
     test::{acknowledge, install_tracing_helpers, with_temp_dir, MultiEventRecord, SizedRecord},
-    variants::disk_v2::{tests::create_default_buffer_v2_with_usage, writer::RecordWriter},
+    variants::disk_v2::{
+        tests::{create_buffer_v2_with_max_age, create_default_buffer_v2_with_usage},
+        writer::RecordWriter,
+    },
     EventCount,
 };
 
@@ -230,3 +233,40 @@ async fn initial_size_correct_with_multievents() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn reader_expires_records_older_than_max_age() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger, usage) =
+                create_buffer_v2_with_max_age(data_dir, Duration::from_millis(100)).await;
+            assert_buffer_is_empty!(ledger);
+
+            // Write a handful of records, and then let them age past the maximum age.
+            for _ in 0..5 {
+                writer
+                    .write_record(SizedRecord::new(64))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("writer flush should not fail");
+            writer.close();
+            assert_buffer_records!(ledger, 5);
+
+            tokio::time::sleep(Duration::from_millis(250)).await;
+
+            // None of the records should be handed back, but they should all be accounted for as
+            // expired, leaving the buffer empty.
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+
+            let snapshot = usage.snapshot();
+            assert_eq!(snapshot.expired_event_count, 5);
+            assert_eq!(snapshot.sent_event_count, 0);
+            assert_eq!(snapshot.dropped_event_count, 0);
+        }
+    })
+    .await;
+}
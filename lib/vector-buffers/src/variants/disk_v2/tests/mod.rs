@@ -2,6 +2,7 @@ use std::{
     io::{self, Cursor},
     path::Path,
     sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -35,7 +36,10 @@ mod size_limits;
 #[async_trait]
 impl AsyncFile for DuplexStream {
     async fn metadata(&self) -> io::Result<Metadata> {
-        Ok(Metadata { len: 0 })
+        Ok(Metadata {
+            len: 0,
+            modified: None,
+        })
     }
 
     async fn sync_all(&self) -> io::Result<()> {
@@ -46,7 +50,10 @@ impl AsyncFile for DuplexStream {
 #[async_trait]
 impl AsyncFile for Cursor<Vec<u8>> {
     async fn metadata(&self) -> io::Result<Metadata> {
-        Ok(Metadata { len: 0 })
+        Ok(Metadata {
+            len: 0,
+            modified: None,
+        })
     }
 
     async fn sync_all(&self) -> io::Result<()> {
@@ -345,6 +352,31 @@ where
         .expect("should not fail to create buffer")
 }
 
+/// Creates a disk v2 buffer with the specified maximum record age, and returns a handle to the buffer usage tracker.
+pub(crate) async fn create_buffer_v2_with_max_age<P, R>(
+    data_dir: P,
+    max_age: Duration,
+) -> (
+    Writer<R, FilesystemUnderTest>,
+    Reader<R, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+    BufferUsageHandle,
+)
+where
+    P: AsRef<Path>,
+    R: Bufferable,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .max_age(max_age)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    let (writer, reader, ledger) = Buffer::from_config_inner(config, usage_handle.clone())
+        .await
+        .expect("should not fail to create buffer");
+    (writer, reader, ledger, usage_handle)
+}
+
 pub(crate) fn get_corrected_max_record_size<T>(payload: &T) -> usize
 where
     T: FixedEncodable,
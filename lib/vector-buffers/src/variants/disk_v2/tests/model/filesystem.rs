@@ -215,7 +215,11 @@ impl AsyncFile for TestFile {
             inner.buf.as_ref().expect("file buf consumed").len()
         };
 
-        Ok(Metadata { len: len as u64 })
+        // The model doesn't track modification times, so data files never expire.
+        Ok(Metadata {
+            len: len as u64,
+            modified: None,
+        })
     }
 
     async fn sync_all(&self) -> io::Result<()> {
@@ -4,6 +4,7 @@ pub mod component_events_dropped;
 mod events_received;
 mod events_sent;
 mod prelude;
+mod schema_violations;
 pub mod service;
 
 pub use metrics::SharedString;
@@ -14,6 +15,7 @@ pub use component_events_dropped::{ComponentEventsDropped, INTENTIONAL, UNINTENT
 pub use events_received::EventsReceived;
 pub use events_sent::{EventsSent, DEFAULT_OUTPUT};
 pub use prelude::{error_stage, error_type};
+pub use schema_violations::SchemaViolations;
 pub use service::{CallError, PollReadyError};
 
 pub trait InternalEvent: Sized {
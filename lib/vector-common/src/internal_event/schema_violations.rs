@@ -0,0 +1,46 @@
+use metrics::{register_counter, Counter};
+use tracing::debug;
+
+use super::{Output, SharedString};
+
+crate::registered_event!(
+    SchemaViolations {
+        output: Option<SharedString>,
+    } => {
+        violations: Counter = if let Some(output) = &self.output {
+            register_counter!("component_schema_violations_total", "output" => output.clone())
+        } else {
+            register_counter!("component_schema_violations_total")
+        },
+        output: Option<SharedString> = self.output,
+    }
+
+    // The path at which the event doesn't match its schema definition.
+    fn emit(&self, path: String) {
+        match &self.output {
+            Some(output) => {
+                debug!(
+                    message = "Event doesn't match the schema definition of its output.",
+                    %path,
+                    output = %output,
+                    internal_log_rate_limit = true,
+                );
+            }
+            None => {
+                debug!(
+                    message = "Event doesn't match the schema definition of its output.",
+                    %path,
+                    internal_log_rate_limit = true,
+                );
+            }
+        }
+
+        self.violations.increment(1);
+    }
+);
+
+impl From<Output> for SchemaViolations {
+    fn from(output: Output) -> Self {
+        Self { output: output.0 }
+    }
+}
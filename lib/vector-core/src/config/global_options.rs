@@ -1,4 +1,10 @@
-use std::{fs::DirBuilder, path::PathBuf, time::Duration};
+use std::{
+    fs::{self, DirBuilder, OpenOptions},
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use snafu::{ResultExt, Snafu};
 use vector_common::TimeZone;
@@ -14,8 +20,18 @@ pub(crate) enum DataDirError {
     MissingDataDir,
     #[snafu(display("data_dir {:?} does not exist", data_dir))]
     DoesNotExist { data_dir: PathBuf },
-    #[snafu(display("data_dir {:?} is not writable", data_dir))]
-    NotWritable { data_dir: PathBuf },
+    #[snafu(display(
+        "{} {:?} is not writable: {}. If the root filesystem is read-only, set `{}` to a directory on a writable volume",
+        option,
+        data_dir,
+        source,
+        option
+    ))]
+    NotWritable {
+        option: &'static str,
+        data_dir: PathBuf,
+        source: std::io::Error,
+    },
     #[snafu(display(
         "Could not create subdirectory {:?} inside of data dir {:?}: {}",
         subdir,
@@ -29,6 +45,123 @@ pub(crate) enum DataDirError {
     },
 }
 
+/// A subsystem that persists state within the data directory.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataDirSubsystem {
+    /// Source checkpoints, such as those of the `file`, `journald`, and `kubernetes_logs` sources.
+    Checkpoints,
+
+    /// Disk buffers.
+    Buffers,
+
+    /// Cached enrichment table data, such as downloaded GeoIP databases.
+    EnrichmentCache,
+}
+
+impl DataDirSubsystem {
+    /// All subsystems that persist state.
+    pub const ALL: [Self; 3] = [Self::Checkpoints, Self::Buffers, Self::EnrichmentCache];
+
+    /// The name of this subsystem, as used in internal metrics.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Checkpoints => "checkpoints",
+            Self::Buffers => "buffers",
+            Self::EnrichmentCache => "enrichment_cache",
+        }
+    }
+
+    /// The name of the configuration option that overrides this subsystem's directory.
+    pub const fn option_name(self) -> &'static str {
+        match self {
+            Self::Checkpoints => "state_dirs.checkpoints",
+            Self::Buffers => "state_dirs.buffers",
+            Self::EnrichmentCache => "state_dirs.enrichment_cache",
+        }
+    }
+}
+
+/// Per-subsystem overrides of the data directory.
+///
+/// Each subsystem persists its state in the global `data_dir` unless overridden here, which allows
+/// state to be split across multiple volumes.
+#[configurable_component]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct StateDirs {
+    /// The directory used for source checkpoints, such as those of the `file`, `journald`, and
+    /// `kubernetes_logs` sources.
+    #[configurable(metadata(docs::examples = "/var/lib/vector/checkpoints"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoints: Option<PathBuf>,
+
+    /// The directory used for disk buffers.
+    #[configurable(metadata(docs::examples = "/mnt/buffers"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffers: Option<PathBuf>,
+
+    /// The directory used for cached enrichment table data, such as downloaded GeoIP databases.
+    #[configurable(metadata(docs::examples = "/var/cache/vector/enrichment"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enrichment_cache: Option<PathBuf>,
+}
+
+impl StateDirs {
+    /// Gets the directory override for the given subsystem, if any.
+    pub const fn get(&self, subsystem: DataDirSubsystem) -> Option<&PathBuf> {
+        match subsystem {
+            DataDirSubsystem::Checkpoints => self.checkpoints.as_ref(),
+            DataDirSubsystem::Buffers => self.buffers.as_ref(),
+            DataDirSubsystem::EnrichmentCache => self.enrichment_cache.as_ref(),
+        }
+    }
+}
+
+/// Configuration of the watchdog detecting stalled components.
+///
+/// A component is stalled when its task is stuck in a single poll, such as when it blocks its
+/// thread, or when its task was woken up but isn't polled, such as when all the worker threads of
+/// the runtime are blocked.
+///
+/// The stall of a blocked poll is reported along with the stack of the blocked thread, which is
+/// only the kernel stack read from `/proc/<tid>/stack` on Linux, without the frames of Vector
+/// itself. Reading it requires elevated privileges, without which only the kernel function and the
+/// system call the thread is blocked in are reported.
+#[configurable_component]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct WatchdogConfig {
+    /// The amount of time, in seconds, after which a component that made no progress is reported
+    /// as stalled.
+    ///
+    /// Not set by default, which disables the watchdog.
+    #[configurable(metadata(docs::examples = 60))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stall_timeout_secs: Option<NonZeroU64>,
+
+    /// Whether to rebuild stalled components in place.
+    ///
+    /// The stalled task is aborted rather than shut down gracefully, so the events it holds, and
+    /// those in the in-memory buffer of a sink, are lost.
+    ///
+    /// Aborting a task can't interrupt a poll it is blocked in. The component is only rebuilt, and
+    /// the restart reported, once that poll returns, so a component blocked forever is never
+    /// restarted.
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub restart: bool,
+}
+
+impl WatchdogConfig {
+    /// Gets the stall timeout, if the watchdog is enabled.
+    pub fn stall_timeout(&self) -> Option<Duration> {
+        self.stall_timeout_secs
+            .map(|secs| Duration::from_secs(secs.get()))
+    }
+}
+
 /// Global configuration options.
 //
 // If this is modified, make sure those changes are reflected in the `ConfigBuilder::append`
@@ -45,6 +178,27 @@ pub struct GlobalOptions {
     #[serde(default = "crate::default_data_dir")]
     pub data_dir: Option<PathBuf>,
 
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub state_dirs: StateDirs,
+
+    /// The maximum size, in bytes, of the state Vector persists in `data_dir` and the directories
+    /// of `state_dirs`, combined.
+    ///
+    /// Vector refuses to start if its disk buffers could grow its state past this size, and refuses
+    /// to cache enrichment table data that doesn't fit in it.
+    ///
+    /// Not set by default, which leaves the size of the persisted state unbounded.
+    #[configurable(metadata(docs::examples = 10737418240))]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub data_dir_quota: Option<NonZeroU64>,
+
     /// Default log schema for all events.
     ///
     /// This is used if a component does not have its own specific log schema. All events use a log
@@ -115,9 +269,25 @@ pub struct GlobalOptions {
         skip_serializing_if = "crate::serde::skip_serializing_if_default"
     )]
     pub expire_metrics_secs: Option<f64>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub watchdog: WatchdogConfig,
 }
 
 impl GlobalOptions {
+    /// Gets the directory used by the given subsystem, which is either its override in
+    /// `state_dirs` or the global `data_dir`.
+    pub fn data_dir_for(&self, subsystem: DataDirSubsystem) -> Option<PathBuf> {
+        self.state_dirs
+            .get(subsystem)
+            .or(self.data_dir.as_ref())
+            .cloned()
+    }
+
     /// Resolve the `data_dir` option in either the global or local config, and
     /// validate that it exists and is writable.
     ///
@@ -133,16 +303,35 @@ impl GlobalOptions {
             .ok_or(DataDirError::MissingDataDir)
             .map_err(Box::new)?
             .clone();
-        if !data_dir.exists() {
-            return Err(DataDirError::DoesNotExist { data_dir }.into());
-        }
-        let readonly = std::fs::metadata(&data_dir)
-            .map(|meta| meta.permissions().readonly())
-            .unwrap_or(true);
-        if readonly {
-            return Err(DataDirError::NotWritable { data_dir }.into());
-        }
-        Ok(data_dir)
+        validate_data_dir(data_dir, "data_dir")
+    }
+
+    /// Resolve the directory for the given subsystem from either the local config, the subsystem's
+    /// override in `state_dirs`, or the global `data_dir`, and validate that it exists and is
+    /// writable.
+    ///
+    /// # Errors
+    ///
+    /// Function will error if no directory is configured, or if it does not exist or is not
+    /// writable.
+    pub fn resolve_and_validate_data_dir_for(
+        &self,
+        subsystem: DataDirSubsystem,
+        local_data_dir: Option<&PathBuf>,
+    ) -> crate::Result<PathBuf> {
+        let option = if local_data_dir.is_some() {
+            "data_dir"
+        } else if self.state_dirs.get(subsystem).is_some() {
+            subsystem.option_name()
+        } else {
+            "data_dir"
+        };
+        let data_dir = local_data_dir
+            .cloned()
+            .or_else(|| self.data_dir_for(subsystem))
+            .ok_or(DataDirError::MissingDataDir)
+            .map_err(Box::new)?;
+        validate_data_dir(data_dir, option)
     }
 
     /// Resolve the `data_dir` option using `resolve_and_validate_data_dir` and
@@ -157,15 +346,23 @@ impl GlobalOptions {
         subdir: &str,
     ) -> crate::Result<PathBuf> {
         let data_dir = self.resolve_and_validate_data_dir(local)?;
+        make_data_subdir(data_dir, subdir)
+    }
 
-        let mut data_subdir = data_dir.clone();
-        data_subdir.push(subdir);
-
-        DirBuilder::new()
-            .recursive(true)
-            .create(&data_subdir)
-            .with_context(|_| CouldNotCreateSnafu { subdir, data_dir })?;
-        Ok(data_subdir)
+    /// Resolve the directory for the given subsystem using `resolve_and_validate_data_dir_for` and
+    /// then ensure a named subdirectory exists.
+    ///
+    /// # Errors
+    ///
+    /// Function will error if it is unable to make data subdirectory.
+    pub fn resolve_and_make_data_subdir_for(
+        &self,
+        subsystem: DataDirSubsystem,
+        local: Option<&PathBuf>,
+        subdir: &str,
+    ) -> crate::Result<PathBuf> {
+        let data_dir = self.resolve_and_validate_data_dir_for(subsystem, local)?;
+        make_data_subdir(data_dir, subdir)
     }
 
     /// Merge a second global configuration into self, and return the new merged data.
@@ -189,6 +386,29 @@ impl GlobalOptions {
             errors.push("conflicting values for 'proxy.no_proxy' found".to_owned());
         }
 
+        for subsystem in DataDirSubsystem::ALL {
+            if conflicts(
+                &self.state_dirs.get(subsystem),
+                &with.state_dirs.get(subsystem),
+            ) {
+                errors.push(format!(
+                    "conflicting values for '{}' found",
+                    subsystem.option_name()
+                ));
+            }
+        }
+
+        if conflicts(&self.data_dir_quota, &with.data_dir_quota) {
+            errors.push("conflicting values for 'data_dir_quota' found".to_owned());
+        }
+
+        if conflicts(
+            &self.watchdog.stall_timeout_secs,
+            &with.watchdog.stall_timeout_secs,
+        ) {
+            errors.push("conflicting values for 'watchdog.stall_timeout_secs' found".to_owned());
+        }
+
         if conflicts(&self.timezone, &with.timezone) {
             errors.push("conflicting values for 'timezone' found".to_owned());
         }
@@ -219,14 +439,37 @@ impl GlobalOptions {
         }
 
         if errors.is_empty() {
+            let state_dirs = StateDirs {
+                checkpoints: self
+                    .state_dirs
+                    .checkpoints
+                    .clone()
+                    .or(with.state_dirs.checkpoints),
+                buffers: self.state_dirs.buffers.clone().or(with.state_dirs.buffers),
+                enrichment_cache: self
+                    .state_dirs
+                    .enrichment_cache
+                    .clone()
+                    .or(with.state_dirs.enrichment_cache),
+            };
+
             Ok(Self {
                 data_dir,
+                state_dirs,
+                data_dir_quota: self.data_dir_quota.or(with.data_dir_quota),
                 log_schema,
                 acknowledgements: self.acknowledgements.merge_default(&with.acknowledgements),
                 timezone: self.timezone.or(with.timezone),
                 proxy: self.proxy.merge(&with.proxy),
                 expire_metrics: self.expire_metrics.or(with.expire_metrics),
                 expire_metrics_secs: self.expire_metrics_secs.or(with.expire_metrics_secs),
+                watchdog: WatchdogConfig {
+                    stall_timeout_secs: self
+                        .watchdog
+                        .stall_timeout_secs
+                        .or(with.watchdog.stall_timeout_secs),
+                    restart: self.watchdog.restart || with.watchdog.restart,
+                },
             })
         } else {
             Err(errors)
@@ -239,6 +482,51 @@ impl GlobalOptions {
     }
 }
 
+/// Validates that `data_dir` exists and is writable.
+///
+/// Writability is checked by actually creating a file, as permission bits alone do not reveal
+/// whether the directory lives on a read-only filesystem.
+fn validate_data_dir(data_dir: PathBuf, option: &'static str) -> crate::Result<PathBuf> {
+    if !data_dir.exists() {
+        return Err(DataDirError::DoesNotExist { data_dir }.into());
+    }
+    if let Err(source) = probe_writable(&data_dir) {
+        return Err(DataDirError::NotWritable {
+            option,
+            data_dir,
+            source,
+        }
+        .into());
+    }
+    Ok(data_dir)
+}
+
+fn probe_writable(dir: &Path) -> std::io::Result<()> {
+    static PROBE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let probe = dir.join(format!(
+        ".vector-write-probe-{}-{}",
+        std::process::id(),
+        PROBE_COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)?;
+    fs::remove_file(&probe)
+}
+
+fn make_data_subdir(data_dir: PathBuf, subdir: &str) -> crate::Result<PathBuf> {
+    let mut data_subdir = data_dir.clone();
+    data_subdir.push(subdir);
+
+    DirBuilder::new()
+        .recursive(true)
+        .create(&data_subdir)
+        .with_context(|_| CouldNotCreateSnafu { subdir, data_dir })?;
+    Ok(data_subdir)
+}
+
 fn conflicts<T: PartialEq>(this: &Option<T>, that: &Option<T>) -> bool {
     matches!((this, that), (Some(this), Some(that)) if this != that)
 }
@@ -268,6 +556,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merges_data_dir_quota() {
+        let merge = |a, b| {
+            merge("data_dir_quota", a, b, |result| {
+                result.data_dir_quota.map(NonZeroU64::get)
+            })
+        };
+
+        assert_eq!(merge(None, None), Ok(None));
+        assert_eq!(merge(Some(100), None), Ok(Some(100)));
+        assert_eq!(merge(None, Some(200)), Ok(Some(200)));
+        assert_eq!(
+            merge(Some(300), Some(400)),
+            Err(vec!["conflicting values for 'data_dir_quota' found".into()])
+        );
+    }
+
+    #[test]
+    fn merges_state_dirs() {
+        let merge = |a, b| {
+            merge("state_dirs.buffers", a, b, |result| {
+                result.data_dir_for(DataDirSubsystem::Buffers)
+            })
+        };
+
+        assert_eq!(merge(None, None), Ok(default_data_dir()));
+        assert_eq!(merge(Some("/test1"), None), Ok(Some("/test1".into())));
+        assert_eq!(merge(None, Some("/test2")), Ok(Some("/test2".into())));
+        assert_eq!(
+            merge(Some("/test4"), Some("/test5")),
+            Err(vec![
+                "conflicting values for 'state_dirs.buffers' found".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn resolves_data_dir_per_subsystem() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let checkpoints = tempfile::tempdir().unwrap();
+        let options = GlobalOptions {
+            data_dir: Some(data_dir.path().into()),
+            state_dirs: StateDirs {
+                checkpoints: Some(checkpoints.path().into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            options
+                .resolve_and_validate_data_dir_for(DataDirSubsystem::Checkpoints, None)
+                .unwrap(),
+            checkpoints.path()
+        );
+        assert_eq!(
+            options
+                .resolve_and_validate_data_dir_for(DataDirSubsystem::Buffers, None)
+                .unwrap(),
+            data_dir.path()
+        );
+        let local = PathBuf::from(data_dir.path());
+        assert_eq!(
+            options
+                .resolve_and_validate_data_dir_for(DataDirSubsystem::Checkpoints, Some(&local))
+                .unwrap(),
+            local
+        );
+    }
+
+    #[test]
+    fn rejects_missing_data_dir() {
+        let options = GlobalOptions {
+            data_dir: Some("/nonexistent/vector/data".into()),
+            ..Default::default()
+        };
+
+        let error = options
+            .resolve_and_validate_data_dir_for(DataDirSubsystem::Buffers, None)
+            .unwrap_err();
+        assert!(error.to_string().contains("does not exist"));
+    }
+
     #[test]
     fn merges_timezones() {
         let merge = |a, b| merge("timezone", a, b, |result| result.timezone());
@@ -350,6 +721,21 @@ mod tests {
         assert_eq!(merge(Some(4.0), Some(5.0)), Ok(Some(4.0))); // Uses minimum
     }
 
+    #[test]
+    fn merges_watchdog() {
+        let merge = |a, b| {
+            merge("watchdog.stall_timeout_secs", a, b, |result| {
+                result.watchdog.stall_timeout()
+            })
+        };
+
+        assert_eq!(merge(None, None), Ok(None));
+        assert_eq!(merge(Some(1), None), Ok(Some(Duration::from_secs(1))));
+        assert_eq!(merge(None, Some(2)), Ok(Some(Duration::from_secs(2))));
+        assert_eq!(merge(Some(3), Some(3)), Ok(Some(Duration::from_secs(3))));
+        assert!(merge(Some(4), Some(5)).is_err());
+    }
+
     fn merge<P: Debug, T>(
         name: &str,
         dd1: Option<P>,
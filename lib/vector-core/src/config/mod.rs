@@ -10,7 +10,7 @@ pub mod output_id;
 pub mod proxy;
 
 use crate::event::LogEvent;
-pub use global_options::GlobalOptions;
+pub use global_options::{DataDirSubsystem, GlobalOptions, StateDirs, WatchdogConfig};
 pub use log_schema::{init_log_schema, log_schema, LogSchema};
 use lookup::{lookup_v2::ValuePath, path, PathPrefix};
 pub use output_id::OutputId;
@@ -35,6 +35,12 @@ pub struct EventMetadata {
     /// TODO(Jean): must not skip serialization to track schemas across restarts.
     #[serde(default = "default_schema_definition", skip)]
     schema_definition: Arc<schema::Definition>,
+
+    /// The identifier of the trace following this event through the topology, if it is traced.
+    ///
+    /// This is not preserved across disk buffers.
+    #[serde(default, skip)]
+    trace_id: Option<u64>,
 }
 
 fn default_metadata_value() -> Value {
@@ -98,6 +104,7 @@ impl Default for EventMetadata {
             secrets: Secrets::new(),
             finalizers: Default::default(),
             schema_definition: default_schema_definition(),
+            trace_id: None,
         }
     }
 }
@@ -158,9 +165,11 @@ impl EventMetadata {
     /// Merge the other `EventMetadata` into this.
     /// If a Datadog API key is not set in `self`, the one from `other` will be used.
     /// If a Splunk HEC token is not set in `self`, the one from `other` will be used.
+    /// If `self` is not traced, the trace of `other` will be used.
     pub fn merge(&mut self, other: Self) {
         self.finalizers.merge(other.finalizers);
         self.secrets.merge(other.secrets);
+        self.trace_id = self.trace_id.or(other.trace_id);
     }
 
     /// Update the finalizer(s) status.
@@ -202,6 +211,16 @@ impl EventMetadata {
     pub fn set_schema_definition(&mut self, definition: &Arc<schema::Definition>) {
         self.schema_definition = Arc::clone(definition);
     }
+
+    /// Get the identifier of the trace following this event, if it is traced.
+    pub fn trace_id(&self) -> Option<u64> {
+        self.trace_id
+    }
+
+    /// Set the identifier of the trace following this event.
+    pub fn set_trace_id(&mut self, trace_id: u64) {
+        self.trace_id = Some(trace_id);
+    }
 }
 
 impl EventDataEq for EventMetadata {
@@ -1,6 +1,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::config::{log_schema, LegacyKey, LogNamespace};
+use crate::event::LogEvent;
 use lookup::lookup_v2::{parse_value_path, TargetPath};
 use lookup::{owned_value_path, OwnedTargetPath, OwnedValuePath, PathPrefix};
 use value::{kind::Collection, Kind};
@@ -515,12 +516,28 @@ impl Definition {
             PathPrefix::Metadata => self.metadata_kind.at_path(target_path.value_path()),
         }
     }
+
+    /// Checks that the log event matches the definition.
+    ///
+    /// This computes the type of the whole event, so it's too costly to run on every event.
+    ///
+    /// # Errors
+    ///
+    /// If the log event doesn't match, the path at which it doesn't is returned.
+    pub fn validate_log(&self, log: &LogEvent) -> Result<(), OwnedTargetPath> {
+        self.event_kind
+            .is_superset(&Kind::from(log.value()))
+            .map_err(OwnedTargetPath::event)?;
+        self.metadata_kind
+            .is_superset(&Kind::from(log.metadata().value()))
+            .map_err(OwnedTargetPath::metadata)
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
 mod test_utils {
     use super::*;
-    use crate::event::{Event, LogEvent};
+    use crate::event::Event;
 
     impl Definition {
         /// Checks that the schema definition is _valid_ for the given event.
@@ -673,6 +690,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_log() {
+        let definition = Definition::new(
+            Kind::object(Collection::empty().with_known("foo", Kind::integer())),
+            Kind::object(Collection::empty()),
+            [LogNamespace::Legacy],
+        );
+
+        let log = LogEvent::from(BTreeMap::from([("foo".into(), 4.into())]));
+        assert_eq!(definition.validate_log(&log), Ok(()));
+
+        let log = LogEvent::from(BTreeMap::from([("foo".into(), "bar".into())]));
+        assert_eq!(
+            definition.validate_log(&log),
+            Err(OwnedTargetPath::event(owned_value_path!("foo")))
+        );
+
+        let log = LogEvent::from_parts(
+            Value::Object(BTreeMap::new()),
+            EventMetadata::default_with_value(BTreeMap::from([("foo".into(), 4.into())]).into()),
+        );
+        assert_eq!(
+            definition.validate_log(&log),
+            Err(OwnedTargetPath::metadata(owned_value_path!("foo")))
+        );
+    }
+
     #[test]
     fn test_empty_legacy_field() {
         let definition = Definition::default_legacy_namespace().with_vector_metadata(
@@ -45,7 +45,9 @@ where
     type ItemMetadata = usize;
 
     fn is_batch_full(&self, batch: &B) -> bool {
-        batch.len() >= self.batch_item_limit || self.current_size >= self.batch_size_limit
+        batch.len() >= self.batch_item_limit
+            || self.item_size_calculator.batch_overhead() + self.current_size
+                >= self.batch_size_limit
     }
 
     fn item_fits_in_batch(&self, item: &T, batch: &B) -> (bool, Self::ItemMetadata) {
@@ -54,7 +56,8 @@ where
             // make sure any individual item can always fit in a batch
             return (true, item_size);
         }
-        let fits = self.current_size + item_size <= self.batch_size_limit;
+        let fits = self.item_size_calculator.batch_overhead() + self.current_size + item_size
+            <= self.batch_size_limit;
         (fits, item_size)
     }
 
@@ -70,6 +73,12 @@ where
 pub trait ItemBatchSize<T> {
     /// The size of an individual item in a batch.
     fn size(&self, item: &T) -> usize;
+
+    /// The size of an empty batch, such as the envelope that the items of a batch are encoded
+    /// into, which counts against the size limit of every batch.
+    fn batch_overhead(&self) -> usize {
+        0
+    }
 }
 
 pub struct ByteSizeOfItemSize;
@@ -80,6 +89,39 @@ impl<T: ByteSizeOf> ItemBatchSize<T> for ByteSizeOfItemSize {
     }
 }
 
+/// The size of an item once encoded into the payload of a request, in bytes.
+///
+/// Items that are encoded before being batched implement this, so that batches are limited by the
+/// size of the payload they are sent as rather than by their size in memory, as measured by
+/// `ByteSizeOf`, which can be far off from it. Downstream APIs reject payloads above their limits.
+pub trait EncodedSize {
+    /// The number of bytes that the item adds to the encoded payload of a batch.
+    fn encoded_size(&self) -> usize;
+}
+
+impl<T: EncodedSize> EncodedSize for Option<T> {
+    fn encoded_size(&self) -> usize {
+        self.as_ref().map_or(0, EncodedSize::encoded_size)
+    }
+}
+
+/// Sizes items by their `EncodedSize`, with a fixed overhead for the envelope of each batch.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EncodedSizeOfItemSize {
+    /// The size of the encoded payload of an empty batch.
+    pub batch_overhead: usize,
+}
+
+impl<T: EncodedSize> ItemBatchSize<T> for EncodedSizeOfItemSize {
+    fn size(&self, item: &T) -> usize {
+        item.encoded_size()
+    }
+
+    fn batch_overhead(&self) -> usize {
+        self.batch_overhead
+    }
+}
+
 impl<T, F> ItemBatchSize<T> for F
 where
     F: Fn(&T) -> usize,
@@ -107,40 +107,29 @@ where
 /// through the stream manipulated by `Batcher` plus limit information to signal
 /// when the `Batch` is full.
 struct Batch<I> {
-    /// The total number of `I` bytes stored, does not any overhead in this
-    /// structure.
-    allocated_bytes: usize,
+    /// The total size of the stored `I`, as measured by the item size
+    /// calculator of the `Batcher`, including the overhead of the batch itself.
+    size: usize,
     /// The maximum number of elements allowed in this structure.
     element_limit: usize,
-    /// The maximum number of allocated bytes (not including overhead) allowed
-    /// in this structure.
-    allocation_limit: usize,
+    /// The maximum size allowed in this structure.
+    size_limit: usize,
     /// The store of `I` elements.
     elements: Vec<I>,
 }
 
-impl<I> ByteSizeOf for Batch<I> {
-    fn allocated_bytes(&self) -> usize {
-        self.allocated_bytes
-    }
-}
-
-impl<I> Batch<I>
-where
-    I: ByteSizeOf,
-{
+impl<I> Batch<I> {
     /// Create a new Batch instance
     ///
-    /// Creates a new batch instance with specific element and allocation limits. The element limit
-    /// is a maximum cap on the number of `I` instances. The allocation limit is a soft-max on the
-    /// number of allocated bytes stored in this batch, not taking into account overhead from this
-    /// structure itself.
+    /// Creates a new batch instance with specific element and size limits. The element limit is a
+    /// maximum cap on the number of `I` instances. The size limit is a soft-max on the total size
+    /// of the stored items, plus `overhead`, the size of the batch when it's empty.
     ///
-    /// If `allocation_limit` is smaller than the size of `I` as reported by `std::mem::size_of`,
-    /// then the allocation limit will be raised such that the batch can hold a single instance of
-    /// `I`.  Likewise, `element_limit` will be raised such that it is always at least 1, ensuring
-    /// that a new batch can be pushed into.
-    fn new(element_limit: usize, allocation_limit: usize) -> Self {
+    /// If `size_limit` is smaller than the size of `I` as reported by `std::mem::size_of`, then the
+    /// size limit will be raised such that the batch can hold a single instance of `I`.  Likewise,
+    /// `element_limit` will be raised such that it is always at least 1, ensuring that a new batch
+    /// can be pushed into.
+    fn new(element_limit: usize, size_limit: usize, overhead: usize) -> Self {
         // SAFETY: `element_limit` is always non-zero because `BatcherSettings` can only be
         // constructed with `NonZeroUsize` versions of allocation limit/item limit.  `Batch` is also
         // only constructable via `Batcher`.
@@ -152,11 +141,11 @@ where
         // batch where the `allocation_limit` is at or lower than the size of that item.
         //
         // We're counteracting this here by ensuring that the element limit is always at least 1.
-        let allocation_limit = cmp::max(allocation_limit, mem::size_of::<I>());
+        let size_limit = cmp::max(size_limit, mem::size_of::<I>());
         Self {
-            allocated_bytes: 0,
+            size: overhead,
             element_limit,
-            allocation_limit,
+            size_limit,
             elements: Vec::with_capacity(128),
         }
     }
@@ -166,8 +155,8 @@ where
     /// This function is similar to `push` except that the caller does not need
     /// to call `has_space` prior to calling this and it will never
     /// panic. Intended to be used only when insertion must not fail.
-    fn with(mut self, value: I) -> Self {
-        self.allocated_bytes += value.size_of();
+    fn with(mut self, value: I, size: usize) -> Self {
+        self.size += size;
         self.elements.push(value);
         self
     }
@@ -184,10 +173,10 @@ where
     /// Whether the batch has space for a new item
     ///
     /// This function returns true of there is space both in terms of item count
-    /// and byte count for the given item, false otherwise.
-    fn has_space(&self, value: &I) -> bool {
+    /// and size for an item of the given size, false otherwise.
+    fn has_space(&self, size: usize) -> bool {
         let too_many_elements = self.elements.len() + 1 > self.element_limit;
-        let too_many_bytes = self.allocated_bytes + value.size_of() > self.allocation_limit;
+        let too_many_bytes = self.size + size > self.size_limit;
         !(too_many_elements || too_many_bytes)
     }
 
@@ -201,9 +190,9 @@ where
     ///
     /// This function will panic if there is not sufficient space in the batch
     /// for a new element to be inserted.
-    fn push(&mut self, value: I) {
-        assert!(self.has_space(&value));
-        self.allocated_bytes += value.size_of();
+    fn push(&mut self, value: I, size: usize) {
+        assert!(self.has_space(size));
+        self.size += size;
         self.elements.push(value);
     }
 }
@@ -287,15 +276,17 @@ impl BatcherSettings {
 }
 
 #[pin_project]
-pub struct PartitionedBatcher<St, Prt, KT>
+pub struct PartitionedBatcher<St, Prt, KT, IS = ByteSizeOfItemSize>
 where
     Prt: Partitioner,
 {
-    /// The total number of bytes a single batch in this struct is allowed to
-    /// hold.
+    /// The total size a single batch in this struct is allowed to hold.
     batch_allocation_limit: usize,
     /// The maximum number of items that are allowed per-batch
     batch_item_limit: usize,
+    /// Measures the size of items, and of empty batches, against
+    /// `batch_allocation_limit`.
+    item_size: IS,
     /// The store of live batches. Note that the key here is an option type,
     /// on account of the interface of `Prt`.
     batches: HashMap<Prt::Key, Batch<Prt::Item>, BuildHasherDefault<XxHash64>>,
@@ -320,9 +311,29 @@ where
     Prt::Item: ByteSizeOf,
 {
     pub fn new(stream: St, partitioner: Prt, settings: BatcherSettings) -> Self {
+        Self::with_item_size(stream, partitioner, settings, ByteSizeOfItemSize)
+    }
+}
+
+impl<St, Prt, IS> PartitionedBatcher<St, Prt, ExpirationQueue<Prt::Key>, IS>
+where
+    St: Stream<Item = Prt::Item>,
+    Prt: Partitioner + Unpin,
+    Prt::Key: Eq + Hash + Clone,
+    IS: ItemBatchSize<Prt::Item>,
+{
+    /// Creates a batcher measuring the size of items with `item_size` rather than with
+    /// `ByteSizeOf`, such as by their encoded size.
+    pub fn with_item_size(
+        stream: St,
+        partitioner: Prt,
+        settings: BatcherSettings,
+        item_size: IS,
+    ) -> Self {
         Self {
             batch_allocation_limit: settings.size_limit,
             batch_item_limit: settings.item_limit,
+            item_size,
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer: ExpirationQueue::new(settings.timeout),
@@ -350,6 +361,7 @@ where
             batch_allocation_limit: batch_allocation_limit
                 .map_or(usize::max_value(), NonZeroUsize::get),
             batch_item_limit: batch_item_limit.get(),
+            item_size: ByteSizeOfItemSize,
             batches: HashMap::default(),
             closed_batches: Vec::default(),
             timer,
@@ -359,13 +371,13 @@ where
     }
 }
 
-impl<St, Prt, KT> Stream for PartitionedBatcher<St, Prt, KT>
+impl<St, Prt, KT, IS> Stream for PartitionedBatcher<St, Prt, KT, IS>
 where
     St: Stream<Item = Prt::Item>,
     Prt: Partitioner + Unpin,
     Prt::Key: Eq + Hash + Clone,
-    Prt::Item: ByteSizeOf,
     KT: KeyedTimer<Prt::Key>,
+    IS: ItemBatchSize<Prt::Item>,
 {
     type Item = (Prt::Key, Vec<Prt::Item>);
 
@@ -415,14 +427,17 @@ where
                     let item_key = this.partitioner.partition(&item);
                     let item_limit: usize = *this.batch_item_limit;
                     let alloc_limit: usize = *this.batch_allocation_limit;
+                    let overhead = this.item_size.batch_overhead();
+                    let size = this.item_size.size(&item);
 
                     if let Some(batch) = this.batches.get_mut(&item_key) {
-                        if batch.has_space(&item) {
+                        if batch.has_space(size) {
                             // When there's space in the partition batch just
                             // push the item in and loop back around.
-                            batch.push(item);
+                            batch.push(item, size);
                         } else {
-                            let new_batch = Batch::new(item_limit, alloc_limit).with(item);
+                            let new_batch =
+                                Batch::new(item_limit, alloc_limit, overhead).with(item, size);
                             let batch = mem::replace(batch, new_batch);
 
                             // The batch for this partition key was set to
@@ -437,7 +452,7 @@ where
                         // create one and create the expiration entries as well.
                         // This allows the batch to expire before filling up,
                         // and vice versa.
-                        let batch = Batch::new(item_limit, alloc_limit).with(item);
+                        let batch = Batch::new(item_limit, alloc_limit, overhead).with(item, size);
                         this.batches.insert(item_key.clone(), batch);
                         this.timer.insert(item_key);
                     }
@@ -464,7 +479,11 @@ mod test {
 
     use crate::{
         partition::Partitioner,
-        stream::partitioned_batcher::{ExpirationQueue, PartitionedBatcher},
+        stream::{
+            batcher::limiter::{EncodedSize, EncodedSizeOfItemSize},
+            partitioned_batcher::{ExpirationQueue, PartitionedBatcher},
+            BatcherSettings,
+        },
         time::KeyedTimer,
     };
 
@@ -735,6 +754,49 @@ mod test {
         }
     }
 
+    #[derive(Debug)]
+    struct EncodedItem(usize);
+
+    impl EncodedSize for EncodedItem {
+        fn encoded_size(&self) -> usize {
+            self.0
+        }
+    }
+
+    struct SinglePartitioner;
+
+    impl Partitioner for SinglePartitioner {
+        type Item = EncodedItem;
+        type Key = ();
+
+        fn partition(&self, _item: &Self::Item) -> Self::Key {}
+    }
+
+    #[tokio::test]
+    async fn batch_encoded_size_includes_overhead() {
+        // Asserts that batches are limited by the encoded size of their items
+        // plus the overhead of the batch itself.
+        use futures::StreamExt;
+
+        let settings = BatcherSettings::new(
+            Duration::from_secs(1),
+            NonZeroUsize::new(10).unwrap(),
+            NonZeroUsize::new(100).unwrap(),
+        );
+        let batcher = PartitionedBatcher::with_item_size(
+            stream::iter([4, 3, 2, 5, 1].map(EncodedItem)),
+            SinglePartitioner,
+            settings,
+            EncodedSizeOfItemSize { batch_overhead: 3 },
+        );
+
+        let batches: Vec<Vec<usize>> = batcher
+            .map(|((), batch)| batch.into_iter().map(|item| item.0).collect())
+            .collect()
+            .await;
+        assert_eq!(batches, vec![vec![4, 3], vec![2, 5], vec![1]]);
+    }
+
     #[tokio::test(start_paused = true)]
     #[allow(clippy::semicolon_if_nothing_returned)] // https://github.com/rust-lang/rust-clippy/issues/7438
     async fn expiration_queue_impl_keyed_timer() {
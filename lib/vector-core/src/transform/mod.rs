@@ -1,8 +1,9 @@
-use std::{collections::HashMap, error, pin::Pin};
+use std::{collections::HashMap, error, num::NonZeroU64, pin::Pin};
 
 use futures::{Stream, StreamExt};
 use vector_common::internal_event::{
-    self, register, CountByteSize, EventsSent, InternalEventHandle as _, Registered, DEFAULT_OUTPUT,
+    self, register, CountByteSize, EventsSent, InternalEventHandle as _, Registered,
+    SchemaViolations, DEFAULT_OUTPUT,
 };
 use vector_common::EventDataEq;
 
@@ -12,7 +13,7 @@ use crate::{
         into_event_stream, EstimatedJsonEncodedSizeOf, Event, EventArray, EventContainer, EventRef,
     },
     fanout::{self, Fanout},
-    ByteSizeOf,
+    schema, ByteSizeOf,
 };
 
 #[cfg(any(feature = "lua"))]
@@ -26,6 +27,7 @@ pub enum Transform {
     Function(Box<dyn FunctionTransform>),
     Synchronous(Box<dyn SyncTransform>),
     Task(Box<dyn TaskTransform<EventArray>>),
+    MultiOutputTask(Box<dyn MultiOutputTaskTransform>),
 }
 
 impl Transform {
@@ -104,6 +106,17 @@ impl Transform {
         Transform::Task(Box::new(WrapEventTask(v)))
     }
 
+    /// Create a new task transform writing to multiple outputs.
+    ///
+    /// Like [`SyncTransform`], the outputs must be known in advance and returned via
+    /// `TransformConfig::outputs`.
+    ///
+    /// **Note:** You should prefer to implement [`SyncTransform`] over this
+    /// where possible.
+    pub fn multi_output_task(v: impl MultiOutputTaskTransform + 'static) -> Self {
+        Transform::MultiOutputTask(Box::new(v))
+    }
+
     /// Mutably borrow the inner transform as a task transform.
     ///
     /// # Panics
@@ -176,6 +189,18 @@ pub trait TaskTransform<T: EventContainer + 'static>: Send + 'static {
     }
 }
 
+/// Task transforms that write to multiple outputs.
+///
+/// These map a stream of events to a stream of output buffers, each created by cloning the empty
+/// `outputs` buffer given to the transform.
+pub trait MultiOutputTaskTransform: Send + 'static {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn Stream<Item = EventArray> + Send>>,
+        outputs: TransformOutputsBuf,
+    ) -> Pin<Box<dyn Stream<Item = TransformOutputsBuf> + Send>>;
+}
+
 /// Broader than the simple [`FunctionTransform`], this trait allows transforms to write to
 /// multiple outputs. Those outputs must be known in advanced and returned via
 /// `TransformConfig::outputs`. Attempting to send to any output not registered in advance is
@@ -219,6 +244,7 @@ impl SyncTransform for Box<dyn FunctionTransform> {
 struct TransformOutput {
     fanout: Fanout,
     events_sent: Registered<EventsSent>,
+    schema_validator: Option<SchemaValidator>,
 }
 
 pub struct TransformOutputs {
@@ -245,6 +271,7 @@ impl TransformOutputs {
                         events_sent: register(EventsSent::from(internal_event::Output(Some(
                             DEFAULT_OUTPUT.into(),
                         )))),
+                        schema_validator: None,
                     });
                     controls.insert(None, control);
                 }
@@ -256,6 +283,7 @@ impl TransformOutputs {
                             events_sent: register(EventsSent::from(internal_event::Output(Some(
                                 name.clone().into(),
                             )))),
+                            schema_validator: None,
                         },
                     );
                     controls.insert(Some(name.clone()), control);
@@ -272,6 +300,20 @@ impl TransformOutputs {
         (me, controls)
     }
 
+    /// Validates a sample of the log events sent to each output against the schema definition of
+    /// the output, counting the events that don't match it.
+    pub fn enable_schema_validation(&mut self, sample_rate: NonZeroU64) {
+        for spec in &self.outputs_spec {
+            let output = match &spec.port {
+                None => self.primary_output.as_mut(),
+                Some(name) => self.named_outputs.get_mut(name),
+            };
+            if let Some(output) = output {
+                output.schema_validator = SchemaValidator::new(spec, sample_rate);
+            }
+        }
+    }
+
     pub fn new_buf_with_capacity(&self, capacity: usize) -> TransformOutputsBuf {
         TransformOutputsBuf::new_with_capacity(self.outputs_spec.clone(), capacity)
     }
@@ -292,11 +334,14 @@ impl TransformOutputs {
                 0,
                 EstimatedJsonEncodedSizeOf::estimated_json_encoded_size_of,
             );
-            buf.primary_buffer
-                .as_mut()
-                .expect("mismatched outputs")
-                .send(&mut primary.fanout)
-                .await?;
+            let buffer = buf.primary_buffer.as_mut().expect("mismatched outputs");
+            if let Some(validator) = primary.schema_validator.as_mut() {
+                buffer
+                    .0
+                    .iter()
+                    .for_each(|events| validator.validate(events));
+            }
+            buffer.send(&mut primary.fanout).await?;
             primary.events_sent.emit(CountByteSize(count, byte_size));
         }
 
@@ -304,6 +349,9 @@ impl TransformOutputs {
             let count = buf.len();
             let byte_size = buf.estimated_json_encoded_size_of();
             let output = self.named_outputs.get_mut(key).expect("unknown output");
+            if let Some(validator) = output.schema_validator.as_mut() {
+                buf.0.iter().for_each(|events| validator.validate(events));
+            }
             buf.send(&mut output.fanout).await?;
             output.events_sent.emit(CountByteSize(count, byte_size));
         }
@@ -312,6 +360,56 @@ impl TransformOutputs {
     }
 }
 
+/// Validates a sample of the log events sent to an output against its schema definition.
+pub struct SchemaValidator {
+    definition: schema::Definition,
+    sample_rate: NonZeroU64,
+    seen: u64,
+    violations: Registered<SchemaViolations>,
+}
+
+impl SchemaValidator {
+    /// Creates a validator for the output, which validates one out of every `sample_rate` log
+    /// events.
+    ///
+    /// Returns `None` if the output has no schema definitions, as is the case for outputs that
+    /// don't emit logs.
+    pub fn new(output: &config::TransformOutput, sample_rate: NonZeroU64) -> Option<Self> {
+        // The output may have a definition for each of the inputs of the transform, and the
+        // events don't track which of these they originate from, so they're validated against
+        // all of them at once.
+        let definition = output
+            .schema_definitions(true)
+            .into_values()
+            .reduce(schema::Definition::merge)?;
+        let port = output.port.clone().unwrap_or_else(|| DEFAULT_OUTPUT.into());
+
+        Some(Self {
+            definition,
+            sample_rate,
+            seen: 0,
+            violations: register(SchemaViolations::from(internal_event::Output(Some(
+                port.into(),
+            )))),
+        })
+    }
+
+    pub fn validate(&mut self, events: &EventArray) {
+        let EventArray::Logs(logs) = events else {
+            return;
+        };
+
+        for log in logs {
+            self.seen += 1;
+            if self.seen % self.sample_rate.get() == 0 {
+                if let Err(path) = self.definition.validate_log(log) {
+                    self.violations.emit(path.to_string());
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransformOutputsBuf {
     primary_buffer: Option<OutputBuffer>,
@@ -596,4 +694,23 @@ mod test {
         assert_eq!(buf.len(), 4);
         assert_eq!(buf.0.len(), 3);
     }
+
+    #[test]
+    fn schema_validator_requires_log_definitions() {
+        let sample_rate = NonZeroU64::new(1).unwrap();
+
+        let output = config::TransformOutput::new(config::DataType::Metric, HashMap::new());
+        assert!(SchemaValidator::new(&output, sample_rate).is_none());
+
+        let output = config::TransformOutput::new(
+            config::DataType::Log,
+            HashMap::from([(
+                config::OutputId::dummy(),
+                schema::Definition::default_legacy_namespace(),
+            )]),
+        );
+        let mut validator = SchemaValidator::new(&output, sample_rate).unwrap();
+        validator.validate(&EventArray::Logs(vec![LogEvent::default()]));
+        assert_eq!(validator.seen, 1);
+    }
 }
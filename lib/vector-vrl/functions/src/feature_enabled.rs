@@ -0,0 +1,85 @@
+use std::{collections::BTreeMap, sync::RwLock};
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+/// The state of the feature flags, as last polled from the feature flags provider.
+static FEATURE_FLAGS: RwLock<BTreeMap<String, bool>> = RwLock::new(BTreeMap::new());
+
+/// Replaces the state of the feature flags, returning whether it changed.
+pub fn set_feature_flags(flags: BTreeMap<String, bool>) -> bool {
+    let mut current = FEATURE_FLAGS.write().expect("poisoned lock");
+    if *current == flags {
+        false
+    } else {
+        *current = flags;
+        true
+    }
+}
+
+/// Returns whether the feature flag is enabled. Unknown flags are disabled.
+pub fn is_feature_enabled(name: &str) -> bool {
+    FEATURE_FLAGS
+        .read()
+        .expect("poisoned lock")
+        .get(name)
+        .copied()
+        .unwrap_or(false)
+}
+
+fn feature_enabled(name: Value) -> Resolved {
+    let name = name.try_bytes_utf8_lossy()?;
+    Ok(is_feature_enabled(&name).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FeatureEnabled;
+
+impl Function for FeatureEnabled {
+    fn identifier(&self) -> &'static str {
+        "feature_enabled"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "name",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Check whether a feature flag is enabled",
+            source: r#"feature_enabled("new_parser")"#,
+            result: Ok("false"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let name = arguments.required("name");
+        Ok(FeatureEnabledFn { name }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FeatureEnabledFn {
+    name: Box<dyn Expression>,
+}
+
+impl FunctionExpression for FeatureEnabledFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let name = self.name.resolve(ctx)?;
+        feature_enabled(name)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
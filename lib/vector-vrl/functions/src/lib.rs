@@ -1,5 +1,6 @@
 #![deny(warnings)]
 
+pub mod feature_enabled;
 pub mod get_secret;
 pub mod remove_secret;
 pub mod set_secret;
@@ -22,5 +23,6 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(get_secret::GetSecret) as _,
         Box::new(remove_secret::RemoveSecret) as _,
         Box::new(set_secret::SetSecret) as _,
+        Box::new(feature_enabled::FeatureEnabled) as _,
     ]
 }
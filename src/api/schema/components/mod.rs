@@ -1,3 +1,4 @@
+pub mod config;
 pub mod sink;
 pub mod source;
 pub mod state;
@@ -6,11 +7,15 @@ pub mod transform;
 use std::{
     cmp,
     collections::{HashMap, HashSet},
+    time::Duration,
 };
 
-use async_graphql::{Enum, InputObject, Interface, Object, Subscription};
+use async_graphql::{Enum, InputObject, Interface, Object, SimpleObject, Subscription};
 use once_cell::sync::Lazy;
-use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+use tokio_stream::{
+    wrappers::{BroadcastStream, IntervalStream},
+    Stream, StreamExt,
+};
 use vector_config::NamedComponent;
 use vector_core::internal_event::DEFAULT_OUTPUT;
 
@@ -22,12 +27,17 @@ use crate::{
     },
     config::{get_transform_output_ids, ComponentKey, Config},
     filter_check,
+    topology::{ack_backlog, buffer_control, pause},
 };
 
 #[derive(Debug, Clone, Interface)]
 #[graphql(
     field(name = "component_id", type = "String"),
-    field(name = "component_type", type = "String")
+    field(name = "component_type", type = "String"),
+    field(
+        name = "config",
+        type = "Option<async_graphql::Json<serde_json::Value>>"
+    )
 )]
 pub enum Component {
     Source(source::Source),
@@ -104,6 +114,37 @@ impl sort::SortableByField<ComponentsSortFieldName> for Component {
     }
 }
 
+#[derive(Default)]
+pub struct ComponentsMutation;
+
+#[Object]
+impl ComponentsMutation {
+    /// Pauses a source, which stops it from pulling new data while the components downstream of
+    /// it keep draining. Returns `false` if the source was already paused
+    async fn pause_source(&self, id: String) -> async_graphql::Result<bool> {
+        Ok(pause::pause(&ComponentKey::from(id))?)
+    }
+
+    /// Resumes a paused source. Returns `false` if the source wasn't paused
+    async fn resume_source(&self, id: String) -> async_graphql::Result<bool> {
+        Ok(pause::resume(&ComponentKey::from(id))?)
+    }
+
+    /// Drains the buffer of a sink, holding back new events until the sink has emptied it.
+    /// Returns the number of events to drain
+    async fn drain_buffer(&self, id: String) -> async_graphql::Result<i64> {
+        let events = buffer_control::drain(&ComponentKey::from(id))?;
+        Ok(i64::try_from(events).unwrap_or(i64::MAX))
+    }
+
+    /// Purges the buffer of a sink, discarding its events rather than sending them. Returns the
+    /// number of events to purge
+    async fn purge_buffer(&self, id: String) -> async_graphql::Result<i64> {
+        let events = buffer_control::purge(&ComponentKey::from(id))?;
+        Ok(i64::try_from(events).unwrap_or(i64::MAX))
+    }
+}
+
 #[derive(Default)]
 pub struct ComponentsQuery;
 
@@ -229,6 +270,15 @@ static COMPONENT_CHANGED: Lazy<tokio::sync::broadcast::Sender<ComponentChanged>>
         tx
     });
 
+#[derive(SimpleObject)]
+pub struct ComponentAcknowledgementBacklog {
+    /// Source component_id
+    component_id: String,
+
+    /// Events emitted by the source that are awaiting acknowledgement
+    backlog: source::SourceAcknowledgementBacklog,
+}
+
 #[derive(Debug, Default)]
 pub struct ComponentsSubscription;
 
@@ -249,14 +299,34 @@ impl ComponentsSubscription {
             _ => None,
         })
     }
+
+    /// Events emitted by each source that are awaiting end-to-end acknowledgement, every
+    /// `interval` milliseconds
+    async fn component_acknowledgement_backlogs(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentAcknowledgementBacklog>> {
+        let interval = tokio::time::interval(Duration::from_millis(interval as u64));
+        IntervalStream::new(interval).map(|_| {
+            ack_backlog::usages()
+                .into_iter()
+                .map(|(key, usage)| ComponentAcknowledgementBacklog {
+                    component_id: key.id().to_string(),
+                    backlog: usage.into(),
+                })
+                .collect()
+        })
+    }
 }
 
 /// Update the 'global' configuration that will be consumed by component queries
 pub fn update_config(config: &Config) {
     let mut new_components = HashMap::new();
+    let mut new_configs = HashMap::new();
 
     // Sources
     for (component_key, source) in config.sources() {
+        new_configs.insert(component_key.clone(), config::redacted(source));
         new_components.insert(
             component_key.clone(),
             Component::Source(source::Source(source::Data {
@@ -283,6 +353,7 @@ pub fn update_config(config: &Config) {
 
     // Transforms
     for (component_key, transform) in config.transforms() {
+        new_configs.insert(component_key.clone(), config::redacted(transform));
         new_components.insert(
             component_key.clone(),
             Component::Transform(transform::Transform(transform::Data {
@@ -302,6 +373,7 @@ pub fn update_config(config: &Config) {
 
     // Sinks
     for (component_key, sink) in config.sinks() {
+        new_configs.insert(component_key.clone(), config::redacted(sink));
         new_components.insert(
             component_key.clone(),
             Component::Sink(sink::Sink(sink::Data {
@@ -339,7 +411,7 @@ pub fn update_config(config: &Config) {
         });
 
     // Override the old component state
-    state::update(new_components);
+    state::update(new_components, new_configs);
 }
 
 #[cfg(test)]
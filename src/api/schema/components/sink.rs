@@ -1,6 +1,7 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Enum, InputObject, Json, Object, SimpleObject};
+use vector_buffers::topology::control::BufferStageUsage;
 
 use super::{source, state, transform, Component};
 use crate::{
@@ -11,6 +12,7 @@ use crate::{
     },
     config::{ComponentKey, Inputs, OutputId},
     filter_check,
+    topology::buffer_control,
 };
 
 #[derive(Debug, Clone)]
@@ -58,6 +60,46 @@ impl filter::CustomFilter<Sink> for SinksFilter {
     }
 }
 
+#[derive(SimpleObject)]
+pub struct SinkBuffer {
+    /// Stages of the buffer, in order
+    stages: Vec<SinkBufferStage>,
+
+    /// Whether the buffer is being drained, holding back new events until it's empty
+    draining: bool,
+}
+
+#[derive(SimpleObject)]
+pub struct SinkBufferStage {
+    /// Number of events in the stage
+    events: i64,
+
+    /// Total size of the events in the stage, in bytes
+    byte_size: i64,
+
+    /// Maximum number of events in the stage, if limited by events
+    max_events: Option<i64>,
+
+    /// Maximum total size of the events in the stage, in bytes, if limited by size
+    max_byte_size: Option<i64>,
+
+    /// Age of the oldest event in the stage, in seconds, if it isn't empty
+    oldest_event_age_seconds: Option<f64>,
+}
+
+impl From<BufferStageUsage> for SinkBufferStage {
+    fn from(usage: BufferStageUsage) -> Self {
+        let to_i64 = |value: u64| i64::try_from(value).unwrap_or(i64::MAX);
+        Self {
+            events: to_i64(usage.events),
+            byte_size: to_i64(usage.byte_size),
+            max_events: (usage.max_events != 0).then(|| to_i64(usage.max_events)),
+            max_byte_size: (usage.max_byte_size != 0).then(|| to_i64(usage.max_byte_size)),
+            oldest_event_age_seconds: usage.oldest_event_age.map(|age| age.as_secs_f64()),
+        }
+    }
+}
+
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 pub enum SinksSortFieldName {
     ComponentKey,
@@ -89,6 +131,12 @@ impl Sink {
         self.get_component_type()
     }
 
+    /// Sink configuration, as it's running: interpolated, with its defaults applied, and with
+    /// its secrets redacted
+    pub async fn config(&self) -> Option<Json<serde_json::Value>> {
+        state::component_config(self.get_component_key()).map(Json)
+    }
+
     /// Source inputs
     pub async fn sources(&self) -> Vec<source::Source> {
         self.0
@@ -118,6 +166,14 @@ impl Sink {
         metrics::by_component_key(self.get_component_key())
             .into_sink_metrics(self.get_component_type())
     }
+
+    /// Current usage of the sink buffer
+    pub async fn buffer(&self) -> Option<SinkBuffer> {
+        buffer_control::usage(self.get_component_key()).map(|(stages, draining)| SinkBuffer {
+            stages: stages.into_iter().map(Into::into).collect(),
+            draining,
+        })
+    }
 }
 
 #[cfg(test)]
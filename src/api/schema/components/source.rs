@@ -1,6 +1,6 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Enum, InputObject, Json, Object, SimpleObject};
 
 use super::{sink, state, transform, Component};
 use crate::{
@@ -11,6 +11,10 @@ use crate::{
     },
     config::{ComponentKey, DataType, OutputId},
     filter_check,
+    topology::{
+        ack_backlog::{self, AckBacklogUsage},
+        pause,
+    },
 };
 
 #[derive(Debug, Enum, Eq, PartialEq, Copy, Clone, Ord, PartialOrd)]
@@ -28,6 +32,24 @@ pub struct Data {
     pub outputs: Vec<String>,
 }
 
+#[derive(SimpleObject)]
+pub struct SourceAcknowledgementBacklog {
+    /// Number of events emitted by the source that are awaiting acknowledgement by the sinks
+    events: i64,
+
+    /// Age of the oldest event awaiting acknowledgement, in seconds, if there is any
+    oldest_event_age_seconds: Option<f64>,
+}
+
+impl From<AckBacklogUsage> for SourceAcknowledgementBacklog {
+    fn from(usage: AckBacklogUsage) -> Self {
+        Self {
+            events: i64::try_from(usage.events).unwrap_or(i64::MAX),
+            oldest_event_age_seconds: usage.oldest_event_age.map(|age| age.as_secs_f64()),
+        }
+    }
+}
+
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 pub enum SourcesSortFieldName {
     ComponentKey,
@@ -106,6 +128,23 @@ impl Source {
         self.get_output_types()
     }
 
+    /// Source configuration, as it's running: interpolated, with its defaults applied, and with
+    /// its secrets redacted
+    pub async fn config(&self) -> Option<Json<serde_json::Value>> {
+        state::component_config(self.get_component_key()).map(Json)
+    }
+
+    /// Whether the source is paused
+    pub async fn paused(&self) -> bool {
+        pause::is_paused(self.get_component_key())
+    }
+
+    /// Events emitted by the source that are awaiting end-to-end acknowledgement. Only events of
+    /// sources with acknowledgements enabled are awaiting acknowledgement
+    pub async fn acknowledgement_backlog(&self) -> Option<SourceAcknowledgementBacklog> {
+        ack_backlog::usage(self.get_component_key()).map(Into::into)
+    }
+
     /// Source output streams
     pub async fn outputs(&self) -> Vec<Output> {
         outputs_by_component_key(self.get_component_key(), self.get_outputs())
@@ -13,6 +13,11 @@ pub const INVARIANT: &str = "Couldn't acquire lock on Vector components. Please
 pub static COMPONENTS: Lazy<Arc<RwLock<HashMap<ComponentKey, Component>>>> =
     Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
+/// The effective configurations of the components, with their secrets redacted. These are kept
+/// apart from `COMPONENTS`, which are cloned far more often than configurations are queried.
+pub static CONFIGS: Lazy<Arc<RwLock<HashMap<ComponentKey, serde_json::Value>>>> =
+    Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
 /// Filter components with the provided `map_func`
 pub fn filter_components<T>(map_func: impl Fn((&ComponentKey, &Component)) -> Option<T>) -> Vec<T> {
     COMPONENTS
@@ -85,7 +90,16 @@ pub fn component_by_output_id(output_id: &OutputId) -> Option<Component> {
     .pop()
 }
 
-/// Overwrites component state with new components.
-pub fn update(new_components: HashMap<ComponentKey, Component>) {
-    *COMPONENTS.write().expect(INVARIANT) = new_components
+/// Gets the effective configuration of a component by component_key
+pub fn component_config(component_key: &ComponentKey) -> Option<serde_json::Value> {
+    CONFIGS.read().expect(INVARIANT).get(component_key).cloned()
+}
+
+/// Overwrites component state with new components, and their configurations.
+pub fn update(
+    new_components: HashMap<ComponentKey, Component>,
+    new_configs: HashMap<ComponentKey, serde_json::Value>,
+) {
+    *COMPONENTS.write().expect(INVARIANT) = new_components;
+    *CONFIGS.write().expect(INVARIANT) = new_configs;
 }
@@ -1,6 +1,6 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Enum, InputObject, Json, Object};
 
 use super::{sink, source, state, Component};
 use crate::{
@@ -67,6 +67,12 @@ impl Transform {
         self.get_component_type()
     }
 
+    /// Transform configuration, as it's running: interpolated, with its defaults applied, and with
+    /// its secrets redacted
+    pub async fn config(&self) -> Option<Json<serde_json::Value>> {
+        state::component_config(self.get_component_key()).map(Json)
+    }
+
     /// Transform output streams
     pub async fn outputs(&self) -> Vec<Output> {
         outputs_by_component_key(self.get_component_key(), self.get_outputs())
@@ -0,0 +1,92 @@
+use async_graphql::{Enum, Object};
+use chrono::{DateTime, Utc};
+
+use super::{log::Log, metric::Metric, output::OutputEventsPayload, trace::Trace};
+use crate::{
+    event::Event,
+    topology::event_tracing::{TraceSpan, TraceStage},
+};
+
+#[derive(Enum, Debug, Copy, Clone, PartialEq, Eq)]
+/// Where a traced event was seen
+pub enum EventTraceStage {
+    /// Emitted by a source, which started the trace
+    Emitted,
+
+    /// Received by a transform or a sink
+    Received,
+
+    /// Delivered by a sink
+    Delivered,
+
+    /// Had a transient error in delivery by a sink
+    Errored,
+
+    /// Rejected by a sink, or could not be delivered by it
+    Rejected,
+}
+
+impl From<TraceStage> for EventTraceStage {
+    fn from(stage: TraceStage) -> Self {
+        match stage {
+            TraceStage::Emitted => Self::Emitted,
+            TraceStage::Received => Self::Received,
+            TraceStage::Delivered => Self::Delivered,
+            TraceStage::Errored => Self::Errored,
+            TraceStage::Rejected => Self::Rejected,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventTraceSpan(TraceSpan);
+
+impl From<TraceSpan> for EventTraceSpan {
+    fn from(span: TraceSpan) -> Self {
+        Self(span)
+    }
+}
+
+#[Object]
+/// A traced event, seen by a component
+impl EventTraceSpan {
+    /// Id of the trace following the event through the topology
+    async fn trace_id(&self) -> String {
+        format!("{:016x}", self.0.trace_id)
+    }
+
+    /// Id of the component that saw the event
+    async fn component_id(&self) -> &str {
+        self.0.component.output_id.component.id()
+    }
+
+    /// Type of the component that saw the event
+    async fn component_type(&self) -> &str {
+        self.0.component.component_type.as_ref()
+    }
+
+    /// Kind of the component that saw the event
+    async fn component_kind(&self) -> &str {
+        self.0.component.component_kind
+    }
+
+    /// Where the event was seen by the component
+    async fn stage(&self) -> EventTraceStage {
+        self.0.stage.into()
+    }
+
+    /// Time at which the event was seen by the component
+    async fn timestamp(&self) -> &DateTime<Utc> {
+        &self.0.timestamp
+    }
+
+    /// The event as the component saw it. Not set once the event has been finalized by a sink
+    async fn event(&self) -> Option<OutputEventsPayload> {
+        let output = self.0.component.clone();
+        self.0.event.clone().map(|event| match event {
+            Event::Log(log) => OutputEventsPayload::Log(Log::new(output, log)),
+            Event::Metric(metric) => OutputEventsPayload::Metric(Metric::new(output, metric)),
+            Event::Trace(trace) => OutputEventsPayload::Trace(Trace::new(output, trace)),
+        })
+    }
+}
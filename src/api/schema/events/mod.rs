@@ -1,21 +1,33 @@
 mod encoding;
+pub mod event_trace;
 pub mod log;
 pub mod metric;
 pub mod notification;
 pub mod output;
+pub mod session;
 pub mod trace;
 
 use std::collections::HashSet;
 
 use async_graphql::{Context, Subscription};
 use encoding::EventEncodingType;
+use event_trace::EventTraceSpan;
 use futures::{stream, Stream, StreamExt};
 use output::OutputEventsPayload;
 use rand::{rngs::SmallRng, Rng, SeedableRng};
+use session::TapSessionBatch;
 use tokio::{select, sync::mpsc, time};
 use tokio_stream::wrappers::ReceiverStream;
 
-use crate::{api::tap::TapController, topology::WatchRx};
+use crate::{
+    api::tap::{TapController, TapPayload},
+    conditions::{AnyCondition, Condition, ConditionalConfig, FieldMatcher, FieldMatcherConfig},
+    event::Event,
+    topology::{event_tracing, WatchRx},
+};
+
+/// The number of spans buffered for an event traces subscription, beyond which they are dropped.
+const EVENT_TRACES_BUFFER_SIZE: usize = 1_000;
 
 /// Patterns (glob) used by tap to match against components and access events
 /// flowing into (for_inputs) or out of (for_outputs) specified components
@@ -48,23 +60,93 @@ pub struct EventsSubscription;
 
 #[Subscription]
 impl EventsSubscription {
-    /// A stream of events emitted from matched component ID patterns
+    /// A stream of events emitted from matched component ID patterns. If `filters` are given, only
+    /// the events matching all of them are streamed, before sampling. Each filter is a field
+    /// matcher expression, such as `.status >= 500`, `.message =~ timeout` or `!.user.id`
     pub async fn output_events_by_component_id_patterns<'a>(
         &'a self,
         ctx: &'a Context<'a>,
         outputs_patterns: Vec<String>,
         inputs_patterns: Option<Vec<String>>,
+        filters: Option<Vec<String>>,
         #[graphql(default = 500)] interval: u32,
         #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
-    ) -> impl Stream<Item = Vec<OutputEventsPayload>> + 'a {
+    ) -> async_graphql::Result<impl Stream<Item = Vec<OutputEventsPayload>> + 'a> {
         let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
 
+        let filter = match filters.filter(|filters| !filters.is_empty()) {
+            Some(filters) => {
+                let matchers = filters
+                    .iter()
+                    .map(|filter| filter.parse::<FieldMatcher>())
+                    .collect::<Result<_, _>>()?;
+                Some(FieldMatcherConfig { matchers }.build(&Default::default())?)
+            }
+            None => None,
+        };
         let patterns = TapPatterns {
             for_outputs: outputs_patterns.into_iter().collect(),
             for_inputs: inputs_patterns.unwrap_or_default().into_iter().collect(),
         };
         // Client input is confined to `u32` to provide sensible bounds.
-        create_events_stream(watch_rx, patterns, interval as u64, limit as usize)
+        Ok(create_filtered_events_stream(
+            watch_rx,
+            patterns,
+            filter,
+            interval as u64,
+            limit as usize,
+        ))
+    }
+
+    /// A stream of events from a named tap session, which keeps buffering events while no client
+    /// is attached. Reconnecting to a session with the same name and patterns resumes it, from the
+    /// `after` sequence number if given, or from the first event not yet sent otherwise.
+    ///
+    /// A session is closed once no client has been attached for `idle_timeout` seconds. Attaching
+    /// to a session detaches the client previously attached to it.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn tap_session<'a>(
+        &'a self,
+        ctx: &'a Context<'a>,
+        name: String,
+        outputs_patterns: Vec<String>,
+        inputs_patterns: Option<Vec<String>>,
+        #[graphql(default = 1_000, validator(minimum = 1, maximum = 100_000))] buffer_size: u32,
+        #[graphql(default = 300, validator(minimum = 1, maximum = 86_400))] idle_timeout: u32,
+        after: Option<u64>,
+        #[graphql(default = 500)] interval: u32,
+        #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
+    ) -> impl Stream<Item = TapSessionBatch> + 'a {
+        let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
+
+        session::attach_session_stream(
+            watch_rx,
+            name,
+            outputs_patterns,
+            inputs_patterns.unwrap_or_default(),
+            buffer_size as usize,
+            time::Duration::from_secs(idle_timeout as u64),
+            after,
+            interval as u64,
+            limit as usize,
+        )
+    }
+
+    /// A stream of the spans of events followed through the topology. The next `limit` events
+    /// emitted by sources that match the VRL boolean expression `condition` are traced, and
+    /// every transform and sink they reach reports a span for them, along with whether the sinks
+    /// delivered them in the end.
+    pub async fn event_traces(
+        &self,
+        condition: String,
+        #[graphql(default = 1, validator(minimum = 1, maximum = 1_000))] limit: u32,
+    ) -> async_graphql::Result<impl Stream<Item = EventTraceSpan>> {
+        let condition = AnyCondition::String(condition).build(&Default::default())?;
+
+        Ok(
+            event_tracing::start(condition, limit as usize, EVENT_TRACES_BUFFER_SIZE)
+                .map(EventTraceSpan::from),
+        )
     }
 }
 
@@ -76,11 +158,27 @@ pub(crate) fn create_events_stream(
     patterns: TapPatterns,
     interval: u64,
     limit: usize,
+) -> impl Stream<Item = Vec<OutputEventsPayload>> {
+    create_filtered_events_stream(watch_rx, patterns, None, interval, limit)
+}
+
+/// Creates an events stream like `create_events_stream`, keeping only the events matching the
+/// condition, if any, before they're sampled.
+pub(crate) fn create_filtered_events_stream(
+    watch_rx: WatchRx,
+    patterns: TapPatterns,
+    filter: Option<Condition>,
+    interval: u64,
+    limit: usize,
 ) -> impl Stream<Item = Vec<OutputEventsPayload>> {
     // Channel for receiving individual tap payloads. Since we can process at most `limit` per
     // interval, this is capped to the same value.
     let (tap_tx, tap_rx) = mpsc::channel(limit);
     let mut tap_rx = ReceiverStream::new(tap_rx)
+        .map(move |payload| match &filter {
+            Some(filter) => retain_matching(payload, filter),
+            None => payload,
+        })
         .flat_map(|payload| stream::iter(<Vec<OutputEventsPayload>>::from(payload)));
 
     // The resulting vector of `Event` sent to the client. Only one result set will be streamed
@@ -176,3 +274,34 @@ pub(crate) fn create_events_stream(
 
     ReceiverStream::new(event_rx)
 }
+
+/// Keeps the events of a tap payload that match the condition. Notifications are kept as they are.
+fn retain_matching(payload: TapPayload, condition: &Condition) -> TapPayload {
+    let check = |event: Event| match condition.check(event) {
+        (true, event) => Some(event),
+        (false, _) => None,
+    };
+    match payload {
+        TapPayload::Log(output, logs) => TapPayload::Log(
+            output,
+            logs.into_iter()
+                .filter_map(|log| check(log.into()).map(Event::into_log))
+                .collect(),
+        ),
+        TapPayload::Metric(output, metrics) => TapPayload::Metric(
+            output,
+            metrics
+                .into_iter()
+                .filter_map(|metric| check(metric.into()).map(Event::into_metric))
+                .collect(),
+        ),
+        TapPayload::Trace(output, traces) => TapPayload::Trace(
+            output,
+            traces
+                .into_iter()
+                .filter_map(|trace| check(trace.into()).map(Event::into_trace))
+                .collect(),
+        ),
+        notification @ TapPayload::Notification(_) => notification,
+    }
+}
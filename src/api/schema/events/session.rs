@@ -0,0 +1,405 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, Weak},
+    time::Duration,
+};
+
+use async_graphql::SimpleObject;
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use once_cell::sync::Lazy;
+use tokio::{
+    sync::mpsc,
+    time::{self, Instant},
+};
+use tokio_stream::wrappers::ReceiverStream;
+
+use super::{output::OutputEventsPayload, TapPatterns};
+use crate::{
+    api::tap::{TapController, TapPayload},
+    topology::WatchRx,
+};
+
+/// The tap sessions of the API, by name.
+///
+/// Sessions outlive the connections of the clients attached to them, so they are shared by every
+/// connection rather than being part of the per-connection schema data.
+static SESSIONS: Lazy<Mutex<HashMap<String, Arc<TapSession>>>> = Lazy::new(Default::default);
+
+/// How often detached sessions are checked for expiry.
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A batch of tapped events, along with the checkpoint to resume the session from.
+#[derive(Debug, SimpleObject)]
+pub struct TapSessionBatch {
+    /// The sequence number of the last event in the batch. Passing this as `after` when
+    /// reconnecting resumes the session right after this batch.
+    pub sequence: u64,
+
+    /// The number of events that were dropped since the previous batch, because the buffer of
+    /// the session was full.
+    pub dropped: u64,
+
+    /// Events and notifications, in the order they were tapped.
+    pub events: Vec<OutputEventsPayload>,
+}
+
+struct Buffered {
+    sequence: u64,
+    payload: OutputEventsPayload,
+}
+
+/// The state of a session, shared between the task filling its buffer and the attached client.
+struct SessionState {
+    buffer: VecDeque<Buffered>,
+    buffer_size: usize,
+    /// The sequence number of the next tapped event.
+    next_sequence: u64,
+    /// The sequence number of the next event to deliver to the client.
+    cursor: u64,
+    /// Undelivered events evicted from the buffer since the last batch.
+    dropped: u64,
+    /// Incremented every time a client attaches, so a client taken over by another stops.
+    attachment: u64,
+    /// When the last client detached, if no client is attached.
+    detached_at: Option<Instant>,
+}
+
+impl SessionState {
+    fn push(&mut self, payload: OutputEventsPayload) {
+        if self.buffer.len() >= self.buffer_size {
+            if let Some(evicted) = self.buffer.pop_front() {
+                if evicted.sequence >= self.cursor {
+                    self.dropped += 1;
+                }
+            }
+        }
+        self.buffer.push_back(Buffered {
+            sequence: self.next_sequence,
+            payload,
+        });
+        self.next_sequence += 1;
+    }
+
+    fn oldest_sequence(&self) -> u64 {
+        self.buffer
+            .front()
+            .map_or(self.next_sequence, |buffered| buffered.sequence)
+    }
+
+    /// Attaches a new client, resuming right after the `after` checkpoint if given, or from the
+    /// first event not yet delivered otherwise.
+    fn attach(&mut self, after: Option<u64>) -> u64 {
+        if let Some(after) = after {
+            let cursor = after.saturating_add(1).min(self.next_sequence);
+            // Everything between the checkpoint and the buffer was lost to the client, whether it
+            // was delivered to a previous connection or not.
+            self.dropped = self.oldest_sequence().saturating_sub(cursor);
+            self.cursor = cursor;
+        }
+        self.attachment += 1;
+        self.detached_at = None;
+        self.attachment
+    }
+
+    fn detach(&mut self, attachment: u64) {
+        if self.attachment == attachment {
+            self.detached_at = Some(Instant::now());
+        }
+    }
+
+    /// Takes up to `limit` undelivered events, along with the count of dropped events.
+    fn take(&mut self, limit: usize) -> Option<TapSessionBatch> {
+        let oldest = self.oldest_sequence();
+        self.cursor = self.cursor.max(oldest);
+        let start = (self.cursor - oldest) as usize;
+
+        let events = self
+            .buffer
+            .iter()
+            .skip(start)
+            .take(limit)
+            .map(|buffered| buffered.payload.clone())
+            .collect::<Vec<_>>();
+        if events.is_empty() && self.dropped == 0 {
+            return None;
+        }
+
+        self.cursor += events.len() as u64;
+        Some(TapSessionBatch {
+            sequence: self.cursor.saturating_sub(1),
+            dropped: std::mem::take(&mut self.dropped),
+            events,
+        })
+    }
+}
+
+/// A named tap, buffering events while no client is attached.
+struct TapSession {
+    outputs_patterns: Vec<String>,
+    inputs_patterns: Vec<String>,
+    state: Mutex<SessionState>,
+    _tap_controller: TapController,
+}
+
+impl TapSession {
+    fn state(&self) -> MutexGuard<'_, SessionState> {
+        self.state.lock().expect("tap session lock poisoned")
+    }
+}
+
+fn sessions() -> MutexGuard<'static, HashMap<String, Arc<TapSession>>> {
+    SESSIONS.lock().expect("tap sessions lock poisoned")
+}
+
+/// Looks up the session of the given name, creating it if it doesn't exist or if it was created
+/// with different patterns.
+fn get_or_create_session(
+    watch_rx: WatchRx,
+    name: &str,
+    mut outputs_patterns: Vec<String>,
+    mut inputs_patterns: Vec<String>,
+    buffer_size: usize,
+    idle_timeout: Duration,
+) -> Arc<TapSession> {
+    outputs_patterns.sort();
+    outputs_patterns.dedup();
+    inputs_patterns.sort();
+    inputs_patterns.dedup();
+
+    let mut sessions = sessions();
+    if let Some(session) = sessions.get(name) {
+        if session.outputs_patterns == outputs_patterns
+            && session.inputs_patterns == inputs_patterns
+        {
+            return Arc::clone(session);
+        }
+        debug!(message = "Replacing tap session with different patterns.", session = %name);
+    }
+
+    let (tap_tx, tap_rx) = mpsc::channel(buffer_size);
+    let patterns = TapPatterns::new(
+        outputs_patterns.iter().cloned().collect(),
+        inputs_patterns.iter().cloned().collect(),
+    );
+    let session = Arc::new(TapSession {
+        outputs_patterns,
+        inputs_patterns,
+        state: Mutex::new(SessionState {
+            buffer: VecDeque::with_capacity(buffer_size),
+            buffer_size,
+            next_sequence: 0,
+            cursor: 0,
+            dropped: 0,
+            attachment: 0,
+            detached_at: Some(Instant::now()),
+        }),
+        _tap_controller: TapController::new(watch_rx, tap_tx, patterns),
+    });
+    sessions.insert(name.to_owned(), Arc::clone(&session));
+    debug!(message = "Created tap session.", session = %name);
+
+    tokio::spawn(fill_session(
+        name.to_owned(),
+        Arc::downgrade(&session),
+        ReceiverStream::new(tap_rx),
+        idle_timeout,
+    ));
+
+    session
+}
+
+/// Buffers the payloads of the tap of a session, until the session is replaced or has had no
+/// client attached for longer than `idle_timeout`.
+async fn fill_session(
+    name: String,
+    session: Weak<TapSession>,
+    tap_rx: ReceiverStream<TapPayload>,
+    idle_timeout: Duration,
+) {
+    let mut tap_rx =
+        tap_rx.flat_map(|payload| futures::stream::iter(<Vec<OutputEventsPayload>>::from(payload)));
+    let mut expiry_check = time::interval(EXPIRY_CHECK_INTERVAL);
+
+    loop {
+        tokio::select! {
+            Some(payload) = tap_rx.next() => {
+                let Some(session) = session.upgrade() else { break };
+                session.state().push(payload);
+            }
+            _ = expiry_check.tick() => {
+                let Some(session) = session.upgrade() else { break };
+                let expired = session
+                    .state()
+                    .detached_at
+                    .map_or(false, |detached_at| detached_at.elapsed() >= idle_timeout);
+                if expired {
+                    let mut sessions = sessions();
+                    if sessions.get(&name).map_or(false, |current| Arc::ptr_eq(current, &session)) {
+                        sessions.remove(&name);
+                    }
+                    debug!(message = "Expired idle tap session.", session = %name);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Marks the session as detached once the stream of its client is dropped.
+struct Attachment {
+    session: Arc<TapSession>,
+    attachment: u64,
+}
+
+impl Drop for Attachment {
+    fn drop(&mut self) {
+        self.session.state().detach(self.attachment);
+    }
+}
+
+/// Attaches to the named tap session, creating it if needed, and streams batches of its events
+/// every `interval` milliseconds.
+///
+/// The stream ends when another client attaches to the same session.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn attach_session_stream(
+    watch_rx: WatchRx,
+    name: String,
+    outputs_patterns: Vec<String>,
+    inputs_patterns: Vec<String>,
+    buffer_size: usize,
+    idle_timeout: Duration,
+    after: Option<u64>,
+    interval: u64,
+    limit: usize,
+) -> impl Stream<Item = TapSessionBatch> {
+    let session = get_or_create_session(
+        watch_rx,
+        &name,
+        outputs_patterns,
+        inputs_patterns,
+        buffer_size,
+        idle_timeout,
+    );
+    let attachment = session.state().attach(after);
+    let guard = Attachment {
+        session,
+        attachment,
+    };
+
+    stream! {
+        let mut interval = time::interval(Duration::from_millis(interval));
+        loop {
+            interval.tick().await;
+            let batch = {
+                let mut state = guard.session.state();
+                if state.attachment != guard.attachment {
+                    debug!(message = "Tap session taken over by another client.", session = %name);
+                    break;
+                }
+                state.take(limit)
+            };
+            if let Some(batch) = batch {
+                yield batch;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::schema::events::notification::{EventNotification, Notification};
+
+    fn state(buffer_size: usize) -> SessionState {
+        SessionState {
+            buffer: VecDeque::new(),
+            buffer_size,
+            next_sequence: 0,
+            cursor: 0,
+            dropped: 0,
+            attachment: 0,
+            detached_at: None,
+        }
+    }
+
+    fn payload(pattern: &str) -> OutputEventsPayload {
+        let TapPayload::Notification(notification) = TapPayload::matched(pattern) else {
+            unreachable!()
+        };
+        OutputEventsPayload::Notification(EventNotification { notification })
+    }
+
+    fn patterns(batch: &TapSessionBatch) -> Vec<String> {
+        batch
+            .events
+            .iter()
+            .map(|event| match event {
+                OutputEventsPayload::Notification(EventNotification {
+                    notification: Notification::Matched(matched),
+                }) => matched.pattern.clone(),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn takes_undelivered_events_in_batches() {
+        let mut state = state(10);
+        for pattern in ["a", "b", "c"] {
+            state.push(payload(pattern));
+        }
+
+        let batch = state.take(2).unwrap();
+        assert_eq!(patterns(&batch), vec!["a", "b"]);
+        assert_eq!(batch.sequence, 1);
+        assert_eq!(batch.dropped, 0);
+
+        let batch = state.take(2).unwrap();
+        assert_eq!(patterns(&batch), vec!["c"]);
+        assert_eq!(batch.sequence, 2);
+
+        assert!(state.take(2).is_none());
+    }
+
+    #[test]
+    fn counts_undelivered_evicted_events() {
+        let mut state = state(2);
+        state.push(payload("a"));
+        state.take(1).unwrap();
+        for pattern in ["b", "c", "d"] {
+            state.push(payload(pattern));
+        }
+
+        // `a` was delivered before being evicted, `b` was not.
+        let batch = state.take(10).unwrap();
+        assert_eq!(patterns(&batch), vec!["c", "d"]);
+        assert_eq!(batch.dropped, 1);
+        assert_eq!(batch.sequence, 3);
+    }
+
+    #[test]
+    fn resumes_from_checkpoint() {
+        let mut state = state(3);
+        for pattern in ["a", "b", "c"] {
+            state.push(payload(pattern));
+        }
+        state.take(10).unwrap();
+
+        // The client only received the first event before disconnecting.
+        state.attach(Some(0));
+        let batch = state.take(10).unwrap();
+        assert_eq!(patterns(&batch), vec!["b", "c"]);
+        assert_eq!(batch.dropped, 0);
+
+        // Events lost to eviction since the checkpoint are reported as dropped.
+        for pattern in ["d", "e", "f"] {
+            state.push(payload(pattern));
+        }
+        state.attach(Some(1));
+        let batch = state.take(10).unwrap();
+        assert_eq!(patterns(&batch), vec!["d", "e", "f"]);
+        assert_eq!(batch.dropped, 1);
+    }
+}
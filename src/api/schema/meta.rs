@@ -1,4 +1,9 @@
+use std::str::FromStr;
+
 use async_graphql::Object;
+use tracing_subscriber::filter::LevelFilter;
+
+use crate::trace;
 
 #[derive(Default)]
 pub struct Meta;
@@ -14,6 +19,11 @@ impl Meta {
     async fn hostname(&self) -> Option<String> {
         crate::get_hostname().ok()
     }
+
+    /// Filter of the internal logs, in the format of `VECTOR_LOG`
+    async fn log_levels(&self) -> Option<String> {
+        trace::levels()
+    }
 }
 
 #[derive(Default)]
@@ -25,3 +35,20 @@ impl MetaQuery {
         Meta
     }
 }
+
+#[derive(Default)]
+pub struct MetaMutation;
+
+#[Object]
+impl MetaMutation {
+    /// Changes the filter of the internal logs until Vector exits, without reloading the config.
+    /// Accepts a level such as `debug`, or a filter in the format of `VECTOR_LOG`. Returns the
+    /// previous filter
+    async fn set_log_levels(&self, levels: String) -> async_graphql::Result<String> {
+        let levels = match LevelFilter::from_str(&levels) {
+            Ok(_) => trace::default_levels(&levels),
+            Err(_) => levels,
+        };
+        Ok(trace::set_levels(&levels)?)
+    }
+}
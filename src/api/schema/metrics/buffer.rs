@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+
+use async_graphql::Object;
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// Names of the gauges describing the contents of a buffer, and its limits
+pub const BUFFER_GAUGES: [&str; 4] = [
+    "buffer_events",
+    "buffer_byte_size",
+    "buffer_max_event_size",
+    "buffer_max_byte_size",
+];
+
+#[derive(Default)]
+struct Stage {
+    events: f64,
+    byte_size: f64,
+    max_events: Option<f64>,
+    max_byte_size: Option<f64>,
+}
+
+impl Stage {
+    /// Fraction of the limit of the stage in use. Memory buffers are limited in events and disk
+    /// buffers in bytes, so whichever limit is set is used
+    fn utilization(&self) -> Option<f64> {
+        let events = self
+            .max_events
+            .filter(|max| *max > 0.0)
+            .map(|max| self.events / max);
+        let byte_size = self
+            .max_byte_size
+            .filter(|max| *max > 0.0)
+            .map(|max| self.byte_size / max);
+        match (events, byte_size) {
+            (Some(events), Some(byte_size)) => Some(events.max(byte_size)),
+            (utilization, None) | (None, utilization) => utilization,
+        }
+    }
+}
+
+pub struct ComponentBufferUtilization {
+    component_key: ComponentKey,
+    events: f64,
+    byte_size: f64,
+    utilization: Option<f64>,
+}
+
+impl ComponentBufferUtilization {
+    /// Returns the buffer utilization of a component from its buffer gauges. A buffer may have
+    /// several stages, such as an overflow to disk, in which case the utilization is that of the
+    /// fullest stage
+    pub fn new(component_key: ComponentKey, metrics: &[Metric]) -> Self {
+        let mut stages = BTreeMap::<String, Stage>::new();
+        for m in metrics {
+            let value = match m.value() {
+                MetricValue::Gauge { value } => *value,
+                _ => continue,
+            };
+            let stage = stages
+                .entry(m.tag_value("stage").unwrap_or_default())
+                .or_default();
+            match m.name() {
+                "buffer_events" => stage.events += value,
+                "buffer_byte_size" => stage.byte_size += value,
+                "buffer_max_event_size" => stage.max_events = Some(value),
+                "buffer_max_byte_size" => stage.max_byte_size = Some(value),
+                _ => {}
+            }
+        }
+
+        Self {
+            component_key,
+            events: stages.values().map(|stage| stage.events).sum(),
+            byte_size: stages.values().map(|stage| stage.byte_size).sum(),
+            utilization: stages
+                .values()
+                .filter_map(Stage::utilization)
+                .reduce(f64::max),
+        }
+    }
+}
+
+#[Object]
+impl ComponentBufferUtilization {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Events in the buffer of the component
+    async fn events(&self) -> i64 {
+        self.events as i64
+    }
+
+    /// Bytes in the buffer of the component
+    async fn byte_size(&self) -> i64 {
+        self.byte_size as i64
+    }
+
+    /// Fraction of the limit of the fullest stage of the buffer in use, between 0 and 1, if the
+    /// limit is known
+    async fn utilization(&self) -> Option<f64> {
+        self.utilization
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{MetricKind, MetricTags};
+
+    fn gauge(name: &str, stage: &str, value: f64) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value }).with_tags(Some(
+            MetricTags::from_iter([("stage".to_string(), stage.to_string())]),
+        ))
+    }
+
+    #[test]
+    fn utilization_of_fullest_stage() {
+        let utilization = ComponentBufferUtilization::new(
+            ComponentKey::from("sink"),
+            &[
+                gauge("buffer_events", "0", 50.0),
+                gauge("buffer_max_event_size", "0", 500.0),
+                gauge("buffer_events", "1", 10.0),
+                gauge("buffer_byte_size", "1", 768.0),
+                gauge("buffer_max_byte_size", "1", 1024.0),
+            ],
+        );
+
+        assert_eq!(utilization.events, 60.0);
+        assert_eq!(utilization.byte_size, 768.0);
+        assert_eq!(utilization.utilization, Some(0.75));
+    }
+
+    #[test]
+    fn utilization_without_limits() {
+        let utilization = ComponentBufferUtilization::new(
+            ComponentKey::from("sink"),
+            &[gauge("buffer_events", "0", 50.0)],
+        );
+
+        assert_eq!(utilization.utilization, None);
+    }
+}
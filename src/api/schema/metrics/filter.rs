@@ -399,7 +399,7 @@ pub fn component_sent_events_total_throughputs_with_outputs(
 
 /// Returns a map of Component ID to list of metrics where metrics have been
 /// filtered by `filter_fn`
-fn component_to_filtered_metrics(
+pub fn component_to_filtered_metrics(
     interval: i32,
     filter_fn: &'static MetricFilterFn,
 ) -> impl Stream<Item = BTreeMap<String, Vec<Metric>>> {
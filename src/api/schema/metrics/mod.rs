@@ -1,4 +1,5 @@
 mod allocated_bytes;
+mod buffer;
 mod errors;
 mod events_in;
 mod events_out;
@@ -18,8 +19,9 @@ mod host;
 
 pub use allocated_bytes::{AllocatedBytes, ComponentAllocatedBytes};
 use async_graphql::{Interface, Object, Subscription};
+pub use buffer::ComponentBufferUtilization;
 use chrono::{DateTime, Utc};
-pub use errors::{ComponentErrorsTotal, ErrorsTotal};
+pub use errors::{ComponentErrorsThroughput, ComponentErrorsTotal, ErrorsTotal};
 pub use events_in::EventsInTotal;
 pub use events_out::EventsOutTotal;
 pub use filter::*;
@@ -318,6 +320,39 @@ impl MetricsSubscription {
             .map(|m| m.into_iter().map(ComponentErrorsTotal::new).collect())
     }
 
+    /// Component error throughput over `interval`
+    async fn component_errors_throughputs(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentErrorsThroughput>> {
+        component_counter_throughputs(interval, &|m| m.name().ends_with("_errors_total")).map(|m| {
+            m.into_iter()
+                .map(|(m, throughput)| {
+                    ComponentErrorsThroughput::new(
+                        ComponentKey::from(m.tag_value("component_id").unwrap()),
+                        throughput as i64,
+                    )
+                })
+                .collect()
+        })
+    }
+
+    /// Component buffer utilization over `interval`
+    async fn component_buffer_utilizations(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentBufferUtilization>> {
+        component_to_filtered_metrics(interval, &|m| buffer::BUFFER_GAUGES.contains(&m.name())).map(
+            |m| {
+                m.into_iter()
+                    .map(|(id, metrics)| {
+                        ComponentBufferUtilization::new(ComponentKey::from(id), &metrics)
+                    })
+                    .collect()
+            },
+        )
+    }
+
     /// All metrics.
     async fn metrics(
         &self,
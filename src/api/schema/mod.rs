@@ -7,7 +7,7 @@ mod metrics;
 mod relay;
 pub mod sort;
 
-use async_graphql::{EmptyMutation, MergedObject, MergedSubscription, Schema, SchemaBuilder};
+use async_graphql::{MergedObject, MergedSubscription, Schema, SchemaBuilder};
 
 #[derive(MergedObject, Default)]
 pub struct Query(
@@ -17,6 +17,9 @@ pub struct Query(
     meta::MetaQuery,
 );
 
+#[derive(MergedObject, Default)]
+pub struct Mutation(components::ComponentsMutation, meta::MetaMutation);
+
 #[derive(MergedSubscription, Default)]
 pub struct Subscription(
     health::HealthSubscription,
@@ -26,6 +29,10 @@ pub struct Subscription(
 );
 
 /// Build a new GraphQL schema, comprised of Query, Mutation and Subscription types
-pub fn build_schema() -> SchemaBuilder<Query, EmptyMutation, Subscription> {
-    Schema::build(Query::default(), EmptyMutation, Subscription::default())
+pub fn build_schema() -> SchemaBuilder<Query, Mutation, Subscription> {
+    Schema::build(
+        Query::default(),
+        Mutation::default(),
+        Subscription::default(),
+    )
 }
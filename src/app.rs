@@ -24,7 +24,7 @@ use crate::{api, internal_events::ApiStarted};
 use crate::{
     cli::{handle_config_errors, LogFormat, Opts, RootOpts},
     config::{self, Config, ConfigPath},
-    heartbeat,
+    data_dir, heartbeat, sd_notify,
     signal::{SignalHandler, SignalPair, SignalRx, SignalTo},
     topology::{
         self, ReloadOutcome, RunningTopology, SharedTopologyController, TopologyController,
@@ -211,6 +211,8 @@ impl Application {
             signals,
         } = self;
 
+        runtime.spawn(data_dir::account(config.topology.config().global.clone()));
+
         let topology_controller = SharedTopologyController::new(TopologyController {
             #[cfg(feature = "api")]
             api_server: config.setup_api(runtime),
@@ -221,6 +223,8 @@ impl Application {
             enterprise_reporter: config.enterprise,
         });
 
+        sd_notify::ready();
+
         Ok(StartedApplication {
             config_paths: config.config_paths,
             graceful_crash_receiver: config.graceful_crash_receiver,
@@ -255,19 +259,25 @@ impl StartedApplication {
         let mut signal_handler = signals.handler;
         let mut signal_rx = signals.receiver;
 
+        let watchdog = tokio::spawn(sd_notify::watchdog(topology_controller.clone()));
+        let stall_watchdog = tokio::spawn(topology::watchdog::run(topology_controller.clone()));
+
         let signal = loop {
             tokio::select! {
                 signal = signal_rx.recv() => {
                     match signal {
                         Ok(SignalTo::ReloadFromConfigBuilder(config_builder)) => {
                             let mut topology_controller = topology_controller.lock().await;
+                            sd_notify::reloading();
                             let new_config = config_builder.build().map_err(handle_config_errors).ok();
                             if let ReloadOutcome::FatalError = topology_controller.reload(new_config).await {
                                 break SignalTo::Shutdown;
                             }
+                            sd_notify::ready();
                         }
                         Ok(SignalTo::ReloadFromDisk) => {
                             let mut topology_controller = topology_controller.lock().await;
+                            sd_notify::reloading();
 
                             // Reload paths
                             if let Some(paths) = config::process_paths(&config_paths) {
@@ -282,6 +292,7 @@ impl StartedApplication {
                             if let ReloadOutcome::FatalError = topology_controller.reload(new_config).await {
                                 break SignalTo::Shutdown;
                             }
+                            sd_notify::ready();
                         },
                         Err(RecvError::Lagged(amt)) => warn!("Overflow, dropped {} signals.", amt),
                         Err(RecvError::Closed) => break SignalTo::Shutdown,
@@ -298,6 +309,12 @@ impl StartedApplication {
             }
         };
 
+        // The watchdogs hold on to the topology controller, which is needed to shut down.
+        watchdog.abort();
+        _ = watchdog.await;
+        stall_watchdog.abort();
+        _ = stall_watchdog.await;
+
         FinishedApplication {
             signal,
             signal_rx,
@@ -327,6 +344,8 @@ impl FinishedApplication {
             .expect("fail to unwrap topology controller")
             .into_inner();
 
+        sd_notify::stopping();
+
         match signal {
             SignalTo::Shutdown => {
                 emit!(VectorStopped);
@@ -367,21 +386,7 @@ fn get_log_levels(default: &str) -> String {
                 log
             })
         })
-        .unwrap_or_else(|_| match default {
-            "off" => "off".to_owned(),
-            level => [
-                format!("vector={}", level),
-                format!("codec={}", level),
-                format!("vrl={}", level),
-                format!("file_source={}", level),
-                "tower_limit=trace".to_owned(),
-                format!("rdkafka={}", level),
-                format!("buffers={}", level),
-                format!("lapin={}", level),
-                format!("kube={}", level),
-            ]
-            .join(","),
-        })
+        .unwrap_or_else(|_| trace::default_levels(default))
 }
 
 pub fn build_runtime(threads: Option<usize>, thread_name: &str) -> Result<Runtime, ExitCode> {
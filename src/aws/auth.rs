@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use aws_config::{
     default_provider::credentials::DefaultCredentialsChain,
@@ -7,18 +7,30 @@ use aws_config::{
         profile_file::{ProfileFileKind, ProfileFiles},
         ProfileFileCredentialsProvider,
     },
-    sts::AssumeRoleProviderBuilder,
 };
-use aws_types::{credentials::SharedCredentialsProvider, region::Region, Credentials};
+use aws_smithy_types::retry::RetryConfig;
+use aws_types::{
+    credentials::{self, future, CredentialsError, ProvideCredentials, SharedCredentialsProvider},
+    region::Region,
+    Credentials, SdkConfig,
+};
 use serde_with::serde_as;
+use tokio::sync::Mutex;
 use vector_common::sensitive_string::SensitiveString;
 use vector_config::configurable_component;
 
+use super::{create_smithy_client, ClientBuilder, RegionOrEndpoint};
+use crate::{config::ProxyConfig, tls::TlsConfig};
+
 // matches default load timeout from the SDK as of 0.10.1, but lets us confidently document the
 // default rather than relying on the SDK default to not change
 const DEFAULT_LOAD_TIMEOUT: Duration = Duration::from_secs(5);
 const DEFAULT_PROFILE_NAME: &str = "default";
 
+/// How long before their expiration the credentials of an assumed role are refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(300);
+const SESSION_NAME: &str = "vector";
+
 /// IMDS Client Configuration for authenticating with AWS.
 #[serde_as]
 #[configurable_component]
@@ -76,6 +88,15 @@ pub enum AwsAuthentication {
         #[configurable(metadata(docs::examples = "arn:aws:iam::123456789098:role/my_role"))]
         assume_role: Option<String>,
 
+        /// The external ID to pass when assuming the role.
+        ///
+        /// Required by roles whose trust policy checks an [external ID][external_id], as is common
+        /// for cross-account access granted to third parties.
+        ///
+        /// [external_id]: https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html
+        #[configurable(metadata(docs::examples = "randomEXAMPLEidString"))]
+        external_id: Option<String>,
+
         /// The [AWS region][aws_region] to send STS requests to.
         ///
         /// If not set, this will default to the configured region
@@ -84,6 +105,15 @@ pub enum AwsAuthentication {
         /// [aws_region]: https://docs.aws.amazon.com/general/latest/gr/rande.html#regional-endpoints
         #[configurable(metadata(docs::examples = "us-west-2"))]
         region: Option<String>,
+
+        /// Custom endpoint to send STS requests to.
+        ///
+        /// The `{region}` placeholder is replaced by the region STS requests are sent to, so that the
+        /// same endpoint template can be used across regions, such as for VPC endpoints. If not set,
+        /// the regional STS endpoint is used.
+        #[configurable(metadata(docs::examples = "https://sts.{region}.amazonaws.com"))]
+        #[configurable(metadata(docs::advanced))]
+        sts_endpoint: Option<String>,
     },
 
     /// Authenticate using credentials stored in a file.
@@ -112,6 +142,15 @@ pub enum AwsAuthentication {
         #[configurable(metadata(docs::examples = "arn:aws:iam::123456789098:role/my_role"))]
         assume_role: String,
 
+        /// The external ID to pass when assuming the role.
+        ///
+        /// Required by roles whose trust policy checks an [external ID][external_id], as is common
+        /// for cross-account access granted to third parties.
+        ///
+        /// [external_id]: https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html
+        #[configurable(metadata(docs::examples = "randomEXAMPLEidString"))]
+        external_id: Option<String>,
+
         /// Timeout for assuming the role, in seconds.
         ///
         /// Relevant when the default credentials chain or `assume_role` is used.
@@ -131,6 +170,15 @@ pub enum AwsAuthentication {
         /// [aws_region]: https://docs.aws.amazon.com/general/latest/gr/rande.html#regional-endpoints
         #[configurable(metadata(docs::examples = "us-west-2"))]
         region: Option<String>,
+
+        /// Custom endpoint to send STS requests to.
+        ///
+        /// The `{region}` placeholder is replaced by the region STS requests are sent to, so that the
+        /// same endpoint template can be used across regions, such as for VPC endpoints. If not set,
+        /// the regional STS endpoint is used.
+        #[configurable(metadata(docs::examples = "https://sts.{region}.amazonaws.com"))]
+        #[configurable(metadata(docs::advanced))]
+        sts_endpoint: Option<String>,
     },
 
     /// Default authentication strategy which tries a variety of substrategies in sequential order.
@@ -157,13 +205,17 @@ impl AwsAuthentication {
     pub async fn credentials_provider(
         &self,
         service_region: Region,
+        proxy: &ProxyConfig,
+        tls_options: &Option<TlsConfig>,
     ) -> crate::Result<SharedCredentialsProvider> {
         match self {
             Self::AccessKey {
                 access_key_id,
                 secret_access_key,
                 assume_role,
+                external_id,
                 region,
+                sts_endpoint,
             } => {
                 let provider = SharedCredentialsProvider::new(Credentials::from_keys(
                     access_key_id.inner(),
@@ -172,9 +224,17 @@ impl AwsAuthentication {
                 ));
                 if let Some(assume_role) = assume_role {
                     let auth_region = region.clone().map(Region::new).unwrap_or(service_region);
-                    let provider = AssumeRoleProviderBuilder::new(assume_role)
-                        .region(auth_region)
-                        .build(provider);
+                    let provider = AssumeRoleProvider::new(
+                        assume_role,
+                        external_id.clone(),
+                        auth_region,
+                        sts_endpoint.as_deref(),
+                        provider,
+                        DEFAULT_LOAD_TIMEOUT,
+                        proxy,
+                        tls_options,
+                    )
+                    .await?;
                     return Ok(SharedCredentialsProvider::new(provider));
                 }
                 Ok(provider)
@@ -196,17 +256,26 @@ impl AwsAuthentication {
             }
             AwsAuthentication::Role {
                 assume_role,
+                external_id,
                 load_timeout_secs,
                 imds,
                 region,
+                sts_endpoint,
             } => {
                 let auth_region = region.clone().map(Region::new).unwrap_or(service_region);
-                let provider = AssumeRoleProviderBuilder::new(assume_role)
-                    .region(auth_region.clone())
-                    .build(
-                        default_credentials_provider(auth_region, *load_timeout_secs, *imds)
-                            .await?,
-                    );
+                let provider = AssumeRoleProvider::new(
+                    assume_role,
+                    external_id.clone(),
+                    auth_region.clone(),
+                    sts_endpoint.as_deref(),
+                    default_credentials_provider(auth_region, *load_timeout_secs, *imds).await?,
+                    load_timeout_secs
+                        .map(Duration::from_secs)
+                        .unwrap_or(DEFAULT_LOAD_TIMEOUT),
+                    proxy,
+                    tls_options,
+                )
+                .await?;
 
                 Ok(SharedCredentialsProvider::new(provider))
             }
@@ -225,7 +294,9 @@ impl AwsAuthentication {
             access_key_id: "dummy".to_string().into(),
             secret_access_key: "dummy".to_string().into(),
             assume_role: None,
+            external_id: None,
             region: None,
+            sts_endpoint: None,
         }
     }
 }
@@ -254,6 +325,179 @@ async fn default_credentials_provider(
     Ok(SharedCredentialsProvider::new(chain.build().await))
 }
 
+struct StsClientBuilder;
+
+impl ClientBuilder for StsClientBuilder {
+    type Config = aws_sdk_sts::config::Config;
+    type Client = aws_sdk_sts::client::Client;
+    type DefaultMiddleware = aws_sdk_sts::middleware::DefaultMiddleware;
+
+    const ENDPOINT_PREFIX: &'static str = "sts";
+
+    fn default_middleware() -> Self::DefaultMiddleware {
+        aws_sdk_sts::middleware::DefaultMiddleware::new()
+    }
+
+    fn build(client: aws_smithy_client::Client, config: &aws_types::SdkConfig) -> Self::Client {
+        aws_sdk_sts::client::Client::with_config(client, config.into())
+    }
+}
+
+/// Credentials of an assumed role, refreshed ahead of their expiration.
+///
+/// The provider is shared by all the clients of a component, which pick up the refreshed
+/// credentials on their next request, so that long-lived clients never use expired credentials.
+#[derive(Debug)]
+struct AssumeRoleProvider {
+    client: aws_sdk_sts::Client,
+    role_arn: String,
+    external_id: Option<String>,
+    load_timeout: Duration,
+    cached: Mutex<Option<Credentials>>,
+}
+
+impl AssumeRoleProvider {
+    /// Builds a provider whose STS client goes through the same proxy and TLS settings as the
+    /// clients of the component.
+    #[allow(clippy::too_many_arguments)]
+    async fn new(
+        role_arn: &str,
+        external_id: Option<String>,
+        region: Region,
+        sts_endpoint: Option<&str>,
+        base_provider: SharedCredentialsProvider,
+        load_timeout: Duration,
+        proxy: &ProxyConfig,
+        tls_options: &Option<TlsConfig>,
+    ) -> crate::Result<Self> {
+        let retry_config = RetryConfig::disabled();
+        let endpoint = RegionOrEndpoint {
+            endpoint: sts_endpoint.map(ToOwned::to_owned),
+            ..Default::default()
+        }
+        .endpoint(&region, StsClientBuilder::ENDPOINT_PREFIX)?;
+
+        let mut config_builder = SdkConfig::builder()
+            .credentials_provider(base_provider)
+            .region(region.clone())
+            .retry_config(retry_config.clone());
+        if let Some(endpoint) = endpoint {
+            config_builder = config_builder.endpoint_resolver(endpoint);
+        }
+
+        let client = create_smithy_client::<StsClientBuilder>(
+            region,
+            proxy,
+            tls_options,
+            false,
+            retry_config,
+        )
+        .await?;
+
+        Ok(Self {
+            client: StsClientBuilder::build(client, &config_builder.build()),
+            role_arn: role_arn.to_owned(),
+            external_id,
+            load_timeout,
+            cached: Mutex::new(None),
+        })
+    }
+
+    async fn assume_role(&self) -> credentials::Result {
+        let output = self
+            .client
+            .assume_role()
+            .role_arn(&self.role_arn)
+            .role_session_name(SESSION_NAME)
+            .set_external_id(self.external_id.clone())
+            .send()
+            .await
+            .map_err(CredentialsError::provider_error)?;
+        let credentials = output
+            .credentials()
+            .ok_or_else(|| CredentialsError::provider_error("STS returned no credentials."))?;
+
+        let expiry = credentials
+            .expiration()
+            .map(|expiration| SystemTime::try_from(*expiration))
+            .transpose()
+            .map_err(CredentialsError::provider_error)?;
+        match (credentials.access_key_id(), credentials.secret_access_key()) {
+            (Some(access_key_id), Some(secret_access_key)) => Ok(Credentials::new(
+                access_key_id,
+                secret_access_key,
+                credentials.session_token().map(ToOwned::to_owned),
+                expiry,
+                "AssumeRoleProvider",
+            )),
+            _ => Err(CredentialsError::provider_error(
+                "STS returned incomplete credentials.",
+            )),
+        }
+    }
+
+    async fn credentials(&self) -> credentials::Result {
+        // Holding the lock while refreshing ensures that concurrent requests wait for a single
+        // refresh rather than each assuming the role.
+        let mut cached = self.cached.lock().await;
+        if let Some(credentials) = cached.as_ref() {
+            if !needs_refresh(credentials, SystemTime::now()) {
+                return Ok(credentials.clone());
+            }
+        }
+
+        let result = tokio::time::timeout(self.load_timeout, self.assume_role())
+            .await
+            .unwrap_or_else(|_| Err(CredentialsError::provider_timed_out(self.load_timeout)));
+        match result {
+            Ok(credentials) => {
+                debug!(
+                    message = "Assumed AWS role.",
+                    role_arn = %self.role_arn,
+                    expiry = ?credentials.expiry(),
+                );
+                *cached = Some(credentials.clone());
+                Ok(credentials)
+            }
+            // The previous credentials are still valid for the rest of the refresh margin, so
+            // requests aren't failed while STS is unavailable.
+            Err(error) => match cached.as_ref() {
+                Some(credentials)
+                    if credentials
+                        .expiry()
+                        .map_or(true, |expiry| expiry > SystemTime::now()) =>
+                {
+                    warn!(
+                        message = "Failed to refresh the credentials of the assumed AWS role, using the previous ones.",
+                        role_arn = %self.role_arn,
+                        %error,
+                    );
+                    Ok(credentials.clone())
+                }
+                _ => Err(error),
+            },
+        }
+    }
+}
+
+impl ProvideCredentials for AssumeRoleProvider {
+    fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+    where
+        Self: 'a,
+    {
+        future::ProvideCredentials::new(self.credentials())
+    }
+}
+
+/// Whether the credentials expire within the refresh margin.
+fn needs_refresh(credentials: &Credentials, now: SystemTime) -> bool {
+    credentials.expiry().map_or(false, |expiry| {
+        expiry
+            .duration_since(now)
+            .map_or(true, |remaining| remaining < REFRESH_MARGIN)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,6 +609,7 @@ mod tests {
                 load_timeout_secs,
                 imds,
                 region,
+                ..
             } => {
                 assert_eq!(&assume_role, "root");
                 assert_eq!(load_timeout_secs, None);
@@ -400,6 +645,7 @@ mod tests {
                 load_timeout_secs,
                 imds,
                 region,
+                ..
             } => {
                 assert_eq!(&assume_role, "auth.root");
                 assert_eq!(load_timeout_secs, Some(10));
@@ -410,6 +656,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parsing_assume_role_with_external_id() {
+        let config = toml::from_str::<ComponentConfig>(
+            r#"
+            auth.assume_role = "root"
+            auth.external_id = "id"
+            auth.region = "eu-west-1"
+            auth.sts_endpoint = "https://vpce-1a2b3c4d.sts.{region}.vpce.amazonaws.com"
+        "#,
+        )
+        .unwrap();
+
+        match config.auth {
+            AwsAuthentication::Role {
+                external_id,
+                sts_endpoint,
+                ..
+            } => {
+                assert_eq!(external_id.as_deref(), Some("id"));
+                assert_eq!(
+                    sts_endpoint.as_deref(),
+                    Some("https://vpce-1a2b3c4d.sts.{region}.vpce.amazonaws.com")
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn refreshes_expiring_credentials() {
+        let now = SystemTime::now();
+        let expiring_in =
+            |duration| Credentials::new("key", "secret", None, Some(now + duration), "test");
+
+        assert!(!needs_refresh(&expiring_in(Duration::from_secs(3600)), now));
+        assert!(needs_refresh(&expiring_in(Duration::from_secs(60)), now));
+        assert!(needs_refresh(
+            &Credentials::new(
+                "key",
+                "secret",
+                None,
+                Some(now - Duration::from_secs(1)),
+                "test"
+            ),
+            now
+        ));
+        assert!(!needs_refresh(
+            &Credentials::new("key", "secret", None, None, "test"),
+            now
+        ));
+    }
+
     #[test]
     fn parsing_static() {
         let config = toml::from_str::<ComponentConfig>(
@@ -18,7 +18,6 @@ use aws_smithy_client::bounds::SmithyMiddleware;
 use aws_smithy_client::erase::{DynConnector, DynMiddleware};
 use aws_smithy_client::{Builder, SdkError};
 use aws_smithy_http::callback::BodyCallback;
-use aws_smithy_http::endpoint::Endpoint;
 use aws_smithy_http::event_stream::BoxError;
 use aws_smithy_http::operation::{Request, Response};
 use aws_smithy_types::retry::RetryConfig;
@@ -59,11 +58,17 @@ pub fn is_retriable_error<T>(error: &SdkError<T>) -> bool {
             // ECS: RequestExpired, ThrottlingException
             // Kinesis: RequestExpired, ThrottlingException
             // Cloudwatch: RequestExpired, ThrottlingException
+            // Any: ExpiredToken, ExpiredTokenException, as assumed role credentials are refreshed
             //
             // Now just look for those when it's a client_error
             let re = RETRIABLE_CODES.get_or_init(|| {
-                RegexSet::new(["RequestTimeout", "RequestExpired", "ThrottlingException"])
-                    .expect("invalid regex")
+                RegexSet::new([
+                    "RequestTimeout",
+                    "RequestExpired",
+                    "ThrottlingException",
+                    "ExpiredToken",
+                ])
+                .expect("invalid regex")
             });
 
             let status = raw.http().status();
@@ -82,6 +87,9 @@ pub trait ClientBuilder {
     type Client;
     type DefaultMiddleware: SmithyMiddleware<DynConnector> + Clone + Send + Sync + 'static;
 
+    /// The prefix of the endpoints of the service, such as `s3` in `s3.us-east-1.amazonaws.com`.
+    const ENDPOINT_PREFIX: &'static str;
+
     fn default_middleware() -> Self::DefaultMiddleware;
 
     fn build(client: aws_smithy_client::Client, config: &aws_types::SdkConfig) -> Self::Client;
@@ -134,8 +142,7 @@ pub async fn resolve_region(region: Option<Region>) -> crate::Result<Region> {
 
 pub async fn create_client<T: ClientBuilder>(
     auth: &AwsAuthentication,
-    region: Option<Region>,
-    endpoint: Option<Endpoint>,
+    region_or_endpoint: &RegionOrEndpoint,
     proxy: &ProxyConfig,
     tls_options: &Option<TlsConfig>,
     is_sink: bool,
@@ -144,11 +151,15 @@ pub async fn create_client<T: ClientBuilder>(
 
     // The default credentials chains will look for a region if not given but we'd like to
     // error up front if later SDK calls will fail due to lack of region configuration
-    let region = resolve_region(region).await?;
+    let region = resolve_region(region_or_endpoint.region()).await?;
+    let endpoint = region_or_endpoint.endpoint(&region, T::ENDPOINT_PREFIX)?;
 
     // Build the configuration first.
     let mut config_builder = SdkConfig::builder()
-        .credentials_provider(auth.credentials_provider(region.clone()).await?)
+        .credentials_provider(
+            auth.credentials_provider(region.clone(), proxy, tls_options)
+                .await?,
+        )
         .region(region.clone())
         .retry_config(retry_config.clone());
 
@@ -169,6 +180,27 @@ pub async fn sign_request(
     request: &mut http::Request<Bytes>,
     credentials_provider: &SharedCredentialsProvider,
     region: &Option<Region>,
+) -> crate::Result<()> {
+    sign_request_with_settings(
+        service_name,
+        request,
+        credentials_provider,
+        region,
+        SigningSettings::default(),
+    )
+    .await
+}
+
+/// Signs `request` with SigV4, using the given signing settings.
+///
+/// Some services, such as OpenSearch Serverless, require non-default settings like the
+/// `x-amz-content-sha256` header.
+pub async fn sign_request_with_settings(
+    service_name: &str,
+    request: &mut http::Request<Bytes>,
+    credentials_provider: &SharedCredentialsProvider,
+    region: &Option<Region>,
+    settings: SigningSettings,
 ) -> crate::Result<()> {
     let signable_request = SignableRequest::from(&*request);
     let credentials = credentials_provider.provide_credentials().await?;
@@ -178,7 +210,7 @@ pub async fn sign_request(
         .region(region.as_ref().map(|r| r.as_ref()).unwrap_or(""))
         .service_name(service_name)
         .time(SystemTime::now())
-        .settings(SigningSettings::default());
+        .settings(settings);
 
     signing_params_builder.set_security_token(credentials.session_token());
 
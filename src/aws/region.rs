@@ -17,9 +17,33 @@ pub struct RegionOrEndpoint {
     pub region: Option<String>,
 
     /// Custom endpoint for use with AWS-compatible services.
+    ///
+    /// The `{region}` placeholder is replaced by the region of the target service, so that the same
+    /// endpoint template can be used across regions, such as for VPC endpoints.
     #[configurable(metadata(docs::examples = "http://127.0.0.0:5000/path/to/service"))]
+    #[configurable(metadata(
+        docs::examples = "https://bucket.vpce-1a2b3c4d-5e6f.s3.{region}.vpce.amazonaws.com"
+    ))]
     #[configurable(metadata(docs::advanced))]
     pub endpoint: Option<String>,
+
+    /// Whether to use the [FIPS endpoint][fips] of the target service.
+    ///
+    /// FIPS endpoints use cryptographic modules validated under FIPS 140-2, as required for
+    /// some government workloads. Ignored if `endpoint` is set.
+    ///
+    /// [fips]: https://aws.amazon.com/compliance/fips/
+    #[configurable(metadata(docs::advanced))]
+    pub use_fips: bool,
+
+    /// Whether to use the [dual-stack endpoint][dual_stack] of the target service.
+    ///
+    /// Dual-stack endpoints are reachable over both IPv4 and IPv6, as required in IPv6-only
+    /// environments. Ignored if `endpoint` is set.
+    ///
+    /// [dual_stack]: https://docs.aws.amazon.com/general/latest/gr/rande.html#dual-stack-endpoints
+    #[configurable(metadata(docs::advanced))]
+    pub use_dual_stack: bool,
 }
 
 impl RegionOrEndpoint {
@@ -27,6 +51,8 @@ impl RegionOrEndpoint {
         Self {
             region: Some(region),
             endpoint: None,
+            use_fips: false,
+            use_dual_stack: false,
         }
     }
 
@@ -34,12 +60,33 @@ impl RegionOrEndpoint {
         Self {
             region: Some(region.into()),
             endpoint: Some(endpoint.into()),
+            use_fips: false,
+            use_dual_stack: false,
         }
     }
 
-    pub fn endpoint(&self) -> crate::Result<Option<Endpoint>> {
-        let uri = self.endpoint.as_deref().map(Uri::from_str).transpose()?;
-        Ok(uri.map(Endpoint::immutable))
+    /// Resolves the endpoint of the service with the given endpoint prefix in `region`.
+    ///
+    /// Returns `None` if the default endpoint of the service should be used.
+    pub fn endpoint(
+        &self,
+        region: &Region,
+        endpoint_prefix: &str,
+    ) -> crate::Result<Option<Endpoint>> {
+        let endpoint = match &self.endpoint {
+            Some(endpoint) => endpoint.replace("{region}", region.as_ref()),
+            None if self.use_fips || self.use_dual_stack => format!(
+                "https://{}",
+                service_host(
+                    region.as_ref(),
+                    endpoint_prefix,
+                    self.use_fips,
+                    self.use_dual_stack
+                )
+            ),
+            None => return Ok(None),
+        };
+        Ok(Some(Endpoint::immutable(Uri::from_str(&endpoint)?)))
     }
 
     pub fn region(&self) -> Option<Region> {
@@ -47,6 +94,35 @@ impl RegionOrEndpoint {
     }
 }
 
+/// Builds the host name of a FIPS and/or dual-stack endpoint of a service.
+///
+/// S3 predates the `api.aws` domain of dual-stack endpoints, and uses a `dualstack` label instead.
+fn service_host(region: &str, endpoint_prefix: &str, fips: bool, dual_stack: bool) -> String {
+    let service = if fips {
+        format!("{}-fips", endpoint_prefix)
+    } else {
+        endpoint_prefix.to_owned()
+    };
+    let china = region.starts_with("cn-");
+
+    if dual_stack && endpoint_prefix != "s3" {
+        let domain = if china {
+            "api.amazonwebservices.com.cn"
+        } else {
+            "api.aws"
+        };
+        format!("{}.{}.{}", service, region, domain)
+    } else {
+        let dual_stack = if dual_stack { ".dualstack" } else { "" };
+        let domain = if china {
+            "amazonaws.com.cn"
+        } else {
+            "amazonaws.com"
+        };
+        format!("{}{}.{}.{}", service, dual_stack, region, domain)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -75,4 +151,59 @@ mod tests {
         "#})
         .is_ok());
     }
+
+    fn resolve(config: &str, endpoint_prefix: &str) -> Option<String> {
+        toml::from_str::<RegionOrEndpoint>(config)
+            .unwrap()
+            .endpoint(&Region::new("us-gov-west-1"), endpoint_prefix)
+            .unwrap()
+            .map(|endpoint| endpoint.uri().to_string())
+    }
+
+    #[test]
+    fn default_endpoint() {
+        assert_eq!(resolve("", "logs"), None);
+    }
+
+    #[test]
+    fn endpoint_template() {
+        assert_eq!(
+            resolve(
+                indoc! {r#"
+                    endpoint = "https://vpce-1234.logs.{region}.vpce.amazonaws.com"
+                    use_fips = true
+                "#},
+                "logs"
+            ),
+            Some("https://vpce-1234.logs.us-gov-west-1.vpce.amazonaws.com/".to_owned())
+        );
+    }
+
+    #[test]
+    fn fips_and_dual_stack_endpoints() {
+        for (config, endpoint_prefix, expected) in [
+            (
+                "use_fips = true",
+                "logs",
+                "https://logs-fips.us-gov-west-1.amazonaws.com/",
+            ),
+            (
+                "use_dual_stack = true",
+                "firehose",
+                "https://firehose.us-gov-west-1.api.aws/",
+            ),
+            (
+                "use_fips = true\nuse_dual_stack = true",
+                "s3",
+                "https://s3-fips.dualstack.us-gov-west-1.amazonaws.com/",
+            ),
+        ] {
+            assert_eq!(
+                resolve(config, endpoint_prefix),
+                Some(expected.to_owned()),
+                "{}",
+                config
+            );
+        }
+    }
 }
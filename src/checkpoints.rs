@@ -0,0 +1,258 @@
+#![allow(missing_docs)]
+use std::{
+    fs,
+    io::{self, Write as _},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use file_source::{Checkpointer, FileFingerprint};
+
+use crate::config::{self, ComponentKey, DataDirSubsystem};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    #[command(subcommand)]
+    sub_command: SubCommand,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+enum SubCommand {
+    /// List the checkpoints of a `file` source.
+    List(SourceOpts),
+
+    /// Export the checkpoints of a `file` source as JSON.
+    ///
+    /// Vector should be stopped while the checkpoints are exported, so that they're up to date.
+    Export(ExportOpts),
+
+    /// Import checkpoints previously exported with `vector checkpoints export` into a `file` source.
+    ///
+    /// Vector must be stopped while the checkpoints are imported, as the running source would
+    /// otherwise overwrite them.
+    Import(ImportOpts),
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct SourceOpts {
+    /// The ID of the `file` source.
+    source: String,
+
+    /// The data directory the source's checkpoints are stored in.
+    ///
+    /// If not set, the directory is resolved from the `data_dir` option of the source, or the
+    /// global `state_dirs.checkpoints` or `data_dir` options of the configuration.
+    #[arg(long)]
+    data_dir: Option<PathBuf>,
+
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    /// If zero files are specified the default config path
+    /// `/etc/vector/vector.toml` will be targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    paths: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    config_dirs: Vec<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct ExportOpts {
+    #[command(flatten)]
+    source: SourceOpts,
+
+    /// The file to write the checkpoints to. Defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct ImportOpts {
+    #[command(flatten)]
+    source: SourceOpts,
+
+    /// The file to read the checkpoints from. Defaults to stdin.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Drop the existing checkpoints of the source, instead of merging the imported ones with them.
+    #[arg(long)]
+    replace: bool,
+}
+
+impl SourceOpts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        self.paths
+            .iter()
+            .map(|path| config::ConfigPath::File(path.to_path_buf(), None))
+            .chain(
+                self.config_dirs
+                    .iter()
+                    .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+            )
+            .collect()
+    }
+
+    /// Resolves the directory the checkpoints of the source are stored in, the same way as the
+    /// `file` source does.
+    fn checkpoints_dir(&self) -> Result<PathBuf, exitcode::ExitCode> {
+        if let Some(data_dir) = &self.data_dir {
+            return Ok(data_dir.join(&self.source));
+        }
+
+        let paths = config::process_paths(&self.paths_with_formats()).ok_or(exitcode::CONFIG)?;
+        let config = config::load_from_paths(&paths).map_err(|errors| {
+            for error in errors {
+                print_error(&error);
+            }
+            exitcode::CONFIG
+        })?;
+
+        let Some(source) = config.source(&ComponentKey::from(self.source.as_str())) else {
+            print_error(&format!("Source {:?} not found in the configuration.", self.source));
+            return Err(exitcode::CONFIG);
+        };
+        if source.inner.get_component_name() != "file" {
+            print_error(&format!("Source {:?} is not a `file` source.", self.source));
+            return Err(exitcode::CONFIG);
+        }
+
+        // The source's own `data_dir` option takes precedence over the global ones.
+        let local_data_dir = serde_json::to_value(&source.inner)
+            .ok()
+            .and_then(|value| value.get("data_dir")?.as_str().map(PathBuf::from));
+        match local_data_dir.or_else(|| config.global.data_dir_for(DataDirSubsystem::Checkpoints)) {
+            Some(data_dir) => Ok(data_dir.join(&self.source)),
+            None => {
+                print_error("data_dir option required, but not given here or globally.");
+                Err(exitcode::CONFIG)
+            }
+        }
+    }
+
+    /// Reads the persisted checkpoints of the source.
+    fn read_checkpoints(&self) -> Result<Checkpointer, exitcode::ExitCode> {
+        let dir = self.checkpoints_dir()?;
+        if !dir.is_dir() {
+            print_error(&format!("No checkpoints found in {:?}.", dir));
+            return Err(exitcode::NOINPUT);
+        }
+
+        let mut checkpointer = Checkpointer::new(&dir);
+        checkpointer.read_checkpoints(None);
+        Ok(checkpointer)
+    }
+}
+
+pub(crate) fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    let result = match &opts.sub_command {
+        SubCommand::List(opts) => list(opts),
+        SubCommand::Export(opts) => export(opts),
+        SubCommand::Import(opts) => import(opts),
+    };
+    match result {
+        Ok(()) => exitcode::OK,
+        Err(code) => code,
+    }
+}
+
+fn list(opts: &SourceOpts) -> Result<(), exitcode::ExitCode> {
+    let checkpointer = opts.read_checkpoints()?;
+
+    let mut stdout = io::stdout().lock();
+    let mut write = || -> io::Result<()> {
+        writeln!(stdout, "{:<40} {:>20}  MODIFIED", "FINGERPRINT", "POSITION")?;
+        for checkpoint in checkpointer.checkpoints() {
+            writeln!(
+                stdout,
+                "{:<40} {:>20}  {}",
+                fingerprint(&checkpoint.fingerprint),
+                checkpoint.position,
+                checkpoint.modified.to_rfc3339()
+            )?;
+        }
+        Ok(())
+    };
+    write().map_err(|error| io_error("Failed to list checkpoints", error))
+}
+
+fn export(opts: &ExportOpts) -> Result<(), exitcode::ExitCode> {
+    let checkpointer = opts.source.read_checkpoints()?;
+
+    let result = match &opts.output {
+        Some(path) => fs::File::create(path).and_then(|file| {
+            let mut writer = io::BufWriter::new(file);
+            checkpointer.export_checkpoints(&mut writer)?;
+            writer.flush()
+        }),
+        None => checkpointer
+            .export_checkpoints(io::stdout().lock())
+            .map(|_| ()),
+    };
+    result.map_err(|error| io_error("Failed to export checkpoints", error))
+}
+
+fn import(opts: &ImportOpts) -> Result<(), exitcode::ExitCode> {
+    let dir = opts.source.checkpoints_dir()?;
+    fs::create_dir_all(&dir)
+        .map_err(|error| io_error("Failed to create the checkpoints directory", error))?;
+
+    let mut checkpointer = Checkpointer::new(&dir);
+    checkpointer.read_checkpoints(None);
+
+    let result = match &opts.input {
+        Some(path) => fs::File::open(path).and_then(|file| {
+            checkpointer.import_checkpoints(io::BufReader::new(file), opts.replace)
+        }),
+        None => checkpointer.import_checkpoints(io::stdin().lock(), opts.replace),
+    };
+    let imported = result.map_err(|error| io_error("Failed to import checkpoints", error))?;
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("Imported {} checkpoints into {:?}.", imported, dir);
+    }
+    Ok(())
+}
+
+fn fingerprint(fingerprint: &FileFingerprint) -> String {
+    match fingerprint {
+        FileFingerprint::BytesChecksum(checksum) => format!("checksum:{:x}", checksum),
+        FileFingerprint::FirstLinesChecksum(checksum) => {
+            format!("first_lines_checksum:{:x}", checksum)
+        }
+        FileFingerprint::DevInode(dev, ino) => format!("device_and_inode:{}:{}", dev, ino),
+        FileFingerprint::Unknown(checksum) => format!("unknown:{:x}", checksum),
+    }
+}
+
+fn io_error(message: &str, error: io::Error) -> exitcode::ExitCode {
+    print_error(&format!("{}: {}", message, error));
+    exitcode::IOERR
+}
+
+#[allow(clippy::print_stderr)]
+fn print_error(message: &str) {
+    eprintln!("{}", message);
+}
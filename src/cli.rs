@@ -3,13 +3,17 @@ use std::path::PathBuf;
 
 use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
 
+#[cfg(feature = "sources-file")]
+use crate::checkpoints;
+#[cfg(feature = "api-client")]
+use crate::pause;
 #[cfg(windows)]
 use crate::service;
 #[cfg(feature = "api-client")]
 use crate::tap;
 #[cfg(feature = "api-client")]
 use crate::top;
-use crate::{config, generate, get_version, graph, list, unit_test, validate};
+use crate::{config, generate, get_version, graph, list, replay, unit_test, validate};
 use crate::{generate_schema, signal};
 
 #[derive(Parser, Debug)]
@@ -224,6 +228,15 @@ pub enum SubCommand {
     /// Output the topology as visual representation using the DOT language which can be rendered by GraphViz
     Graph(graph::Opts),
 
+    /// List, export and import the checkpoints of `file` sources, such as when moving Vector to
+    /// another host or data directory.
+    #[cfg(feature = "sources-file")]
+    Checkpoints(checkpoints::Opts),
+
+    /// Send the events written to the dead-letter files of sinks back to the sinks, such as once
+    /// the destination they failed to be delivered to has recovered.
+    Replay(replay::Opts),
+
     /// Display topology and metrics in the console, for a local or remote Vector instance
     #[cfg(feature = "api-client")]
     Top(top::Opts),
@@ -232,6 +245,15 @@ pub enum SubCommand {
     #[cfg(feature = "api-client")]
     Tap(tap::Opts),
 
+    /// Pause a source of a running Vector instance, which stops it from pulling new data while the
+    /// components downstream of it keep draining.
+    #[cfg(feature = "api-client")]
+    Pause(pause::Opts),
+
+    /// Resume a source of a running Vector instance that was paused with `vector pause`.
+    #[cfg(feature = "api-client")]
+    Resume(pause::Opts),
+
     /// Manage the vector service.
     #[cfg(windows)]
     Service(service::Opts),
@@ -248,11 +270,18 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            #[cfg(feature = "sources-file")]
+            Self::Checkpoints(c) => checkpoints::cmd(c),
             Self::Config(c) => config::cmd(c),
             Self::Generate(g) => generate::cmd(g),
             Self::GenerateSchema => generate_schema::cmd(),
             Self::Graph(g) => graph::cmd(g),
             Self::List(l) => list::cmd(l),
+            Self::Replay(r) => replay::cmd(r).await,
+            #[cfg(feature = "api-client")]
+            Self::Pause(p) => pause::cmd(p, true).await,
+            #[cfg(feature = "api-client")]
+            Self::Resume(r) => pause::cmd(r, false).await,
             #[cfg(windows)]
             Self::Service(s) => service::cmd(s),
             #[cfg(feature = "api-client")]
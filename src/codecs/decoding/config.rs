@@ -1,8 +1,10 @@
-use codecs::decoding::{DeserializerConfig, FramingConfig};
+use std::sync::Arc;
+
+use codecs::decoding::{AvroDeserializerConfig, Deserializer, DeserializerConfig, FramingConfig};
 use serde::{Deserialize, Serialize};
 use vector_core::config::LogNamespace;
 
-use crate::codecs::Decoder;
+use crate::codecs::{schema_registry::SchemaRegistryClient, Decoder};
 
 /// Config used to build a `Decoder`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -41,13 +43,24 @@ impl DecodingConfig {
     }
 
     /// Builds a `Decoder` from the provided configuration.
-    pub fn build(&self) -> Decoder {
+    pub fn build(&self) -> crate::Result<Decoder> {
         // Build the framer.
-        let framer = self.framing.build();
+        let framer = self.framing.build()?;
 
         // Build the deserializer.
-        let deserializer = self.decoding.build();
+        let deserializer = match &self.decoding {
+            // The schema registry client lives in this crate, as it needs an HTTP client.
+            DeserializerConfig::Avro { avro } => match &avro.schema_registry {
+                Some(schema_registry) => Deserializer::Avro(
+                    AvroDeserializerConfig::new(avro.clone()).build_with_resolver(Arc::new(
+                        SchemaRegistryClient::new(schema_registry)?,
+                    ))?,
+                ),
+                None => self.decoding.build()?,
+            },
+            _ => self.decoding.build()?,
+        };
 
-        Decoder::new(framer, deserializer).with_log_namespace(self.log_namespace)
+        Ok(Decoder::new(framer, deserializer).with_log_namespace(self.log_namespace))
     }
 }
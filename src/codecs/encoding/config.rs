@@ -1,6 +1,6 @@
-use crate::codecs::Transformer;
+use crate::codecs::{schema_registry::SchemaRegistryClient, Transformer};
 use codecs::{
-    encoding::{Framer, FramingConfig, Serializer, SerializerConfig},
+    encoding::{AvroSerializerConfig, Framer, FramingConfig, Serializer, SerializerConfig},
     CharacterDelimitedEncoder, LengthDelimitedEncoder, NewlineDelimitedEncoder,
 };
 use vector_config::configurable_component;
@@ -38,7 +38,20 @@ impl EncodingConfig {
 
     /// Build the `Serializer` for this config.
     pub fn build(&self) -> crate::Result<Serializer> {
-        self.encoding.build()
+        match &self.encoding {
+            // The schema registry client lives in this crate, as it needs an HTTP client.
+            SerializerConfig::Avro { avro } => match &avro.schema_registry {
+                Some(schema_registry) => {
+                    let config = AvroSerializerConfig { avro: avro.clone() };
+                    let subject = schema_registry.subject(&config.schema()?)?;
+                    let schema_id = SchemaRegistryClient::new(schema_registry)?
+                        .register(&subject, &avro.schema)?;
+                    Ok(Serializer::Avro(config.build_with_schema_id(schema_id)?))
+                }
+                None => self.encoding.build(),
+            },
+            _ => self.encoding.build(),
+        }
     }
 }
 
@@ -104,12 +117,17 @@ impl EncodingConfigWithFraming {
                 SinkType::StreamBased => NewlineDelimitedEncoder::new().into(),
                 SinkType::MessageBased => CharacterDelimitedEncoder::new(b',').into(),
             },
-            (None, Serializer::Avro(_) | Serializer::Native(_)) => {
-                LengthDelimitedEncoder::new().into()
-            }
             (
                 None,
-                Serializer::Csv(_)
+                Serializer::Avro(_)
+                | Serializer::MessagePack(_)
+                | Serializer::Native(_)
+                | Serializer::Protobuf(_),
+            ) => LengthDelimitedEncoder::new().into(),
+            (
+                None,
+                Serializer::Cef(_)
+                | Serializer::Csv(_)
                 | Serializer::Gelf(_)
                 | Serializer::Logfmt(_)
                 | Serializer::NativeJson(_)
@@ -114,8 +114,11 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) => "application/octet-stream",
+            (Serializer::Protobuf(_), _) => "application/x-protobuf",
+            (Serializer::MessagePack(_), _) => "application/msgpack",
             (
                 Serializer::Avro(_)
+                | Serializer::Cef(_)
                 | Serializer::Csv(_)
                 | Serializer::Gelf(_)
                 | Serializer::Json(_)
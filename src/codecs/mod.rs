@@ -6,6 +6,7 @@
 mod decoding;
 mod encoding;
 mod ready_frames;
+mod schema_registry;
 
 pub use decoding::{Decoder, DecodingConfig};
 pub use encoding::{
@@ -0,0 +1,210 @@
+//! A client of the [Confluent Schema Registry][schema_registry], for the Avro codecs.
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{mpsc, Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use apache_avro::Schema;
+use codecs::avro::{SchemaRegistryOptions, SchemaResolver};
+use http::{header::CONTENT_TYPE, Method, Request};
+use hyper::Body;
+use serde_json::json;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::{
+    config::ProxyConfig,
+    http::{Auth, HttpClient},
+    tls::TlsSettings,
+};
+
+const CONTENT_TYPE_SCHEMA_REGISTRY: &str = "application/vnd.schemaregistry.v1+json";
+
+/// How long a failed lookup of a schema is remembered, so that a burst of messages referring to a
+/// schema the registry doesn't know doesn't turn into a burst of requests to the registry.
+const NEGATIVE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// A client of a schema registry, caching the schemas it fetched.
+///
+/// Requests are sent by a single HTTP client, built once from the TLS and proxy options of the
+/// registry, and are run as tasks on the runtime the client was built on.
+#[derive(Clone)]
+pub(crate) struct SchemaRegistryClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    url: String,
+    auth: Option<Auth>,
+    client: HttpClient,
+    runtime: Handle,
+    schemas: RwLock<HashMap<u32, Lookup>>,
+}
+
+enum Lookup {
+    Found(Arc<Schema>),
+    Failed { error: String, at: Instant },
+}
+
+impl fmt::Debug for SchemaRegistryClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaRegistryClient")
+            .field("url", &self.inner.url)
+            .finish()
+    }
+}
+
+impl SchemaRegistryClient {
+    /// Builds a client of the given schema registry.
+    ///
+    /// This must be called from the runtime, which the requests to the registry are then run on.
+    pub(crate) fn new(options: &SchemaRegistryOptions) -> crate::Result<Self> {
+        let auth = options.username.as_ref().map(|user| Auth::Basic {
+            user: user.clone(),
+            password: options.password.clone().unwrap_or_default(),
+        });
+        let tls = TlsSettings::from_options(&options.tls)?;
+        let proxy = ProxyConfig::from_env().merge(&options.proxy);
+        let client = HttpClient::new(tls, &proxy)?;
+        let runtime = Handle::try_current()
+            .map_err(|_| "The schema registry client must be built on the runtime")?;
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                url: options.url.trim_end_matches('/').to_owned(),
+                auth,
+                client,
+                runtime,
+                schemas: RwLock::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Registers the given schema under the given subject, returning its id.
+    ///
+    /// Registering a schema that's already registered under the subject returns its existing id.
+    pub(crate) fn register(&self, subject: &str, schema: &str) -> crate::Result<u32> {
+        let inner = Arc::clone(&self.inner);
+        let path = format!("subjects/{}/versions", subject);
+        let body = json!({ "schema": schema });
+        let response =
+            self.wait(async move { inner.request(Method::POST, path, Some(body)).await })?;
+        response["id"]
+            .as_u64()
+            .and_then(|id| u32::try_from(id).ok())
+            .ok_or_else(|| format!("Invalid response of the schema registry: {}", response).into())
+    }
+
+    /// Fetches the schema with the given id, remembering the outcome.
+    fn resolve(&self, id: u32) -> crate::Result<Arc<Schema>> {
+        let inner = Arc::clone(&self.inner);
+        let result = self
+            .wait(async move { inner.fetch(id).await })
+            .map(Arc::new);
+
+        let lookup = match &result {
+            Ok(schema) => Lookup::Found(Arc::clone(schema)),
+            Err(error) => Lookup::Failed {
+                error: error.to_string(),
+                at: Instant::now(),
+            },
+        };
+        self.inner
+            .schemas
+            .write()
+            .expect("poisoned lock")
+            .insert(id, lookup);
+        result
+    }
+
+    /// Runs the given request as a task on the runtime, waiting for its outcome from synchronous
+    /// code, such as the building of components and the decoding of events.
+    ///
+    /// This only happens for the few requests that aren't answered by the cache. Waiting parks the
+    /// calling worker with `block_in_place`, so that the rest of the runtime keeps running, which
+    /// requires the multi-threaded runtime when called from the runtime.
+    fn wait<F, T>(&self, future: F) -> crate::Result<T>
+    where
+        F: Future<Output = crate::Result<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.inner.runtime.spawn(async move {
+            _ = tx.send(future.await);
+        });
+        let recv = move || {
+            rx.recv()
+                .map_err(|_| crate::Error::from("Schema registry request was cancelled"))?
+        };
+
+        match Handle::try_current().map(|handle| handle.runtime_flavor()) {
+            Ok(RuntimeFlavor::CurrentThread) => {
+                Err("Requests to the schema registry require the multi-threaded runtime".into())
+            }
+            Ok(_) => tokio::task::block_in_place(recv),
+            Err(_) => recv(),
+        }
+    }
+}
+
+impl Inner {
+    async fn fetch(&self, id: u32) -> crate::Result<Schema> {
+        let response = self
+            .request(Method::GET, format!("schemas/ids/{}", id), None)
+            .await?;
+        let schema = response["schema"]
+            .as_str()
+            .ok_or_else(|| format!("Invalid response of the schema registry: {}", response))?;
+        Schema::parse_str(schema).map_err(|error| {
+            format!("Invalid schema {} of the schema registry: {}", id, error).into()
+        })
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: String,
+        body: Option<serde_json::Value>,
+    ) -> crate::Result<serde_json::Value> {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(format!("{}/{}", self.url, path))
+            .header(CONTENT_TYPE, CONTENT_TYPE_SCHEMA_REGISTRY)
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))?;
+        if let Some(auth) = &self.auth {
+            auth.apply(&mut request);
+        }
+
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if !status.is_success() {
+            return Err(format!(
+                "Schema registry responded with {}: {}",
+                status,
+                String::from_utf8_lossy(&body)
+            )
+            .into());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+}
+
+impl SchemaResolver for SchemaRegistryClient {
+    fn schema(&self, id: u32) -> vector_common::Result<Arc<Schema>> {
+        match self.inner.schemas.read().expect("poisoned lock").get(&id) {
+            Some(Lookup::Found(schema)) => return Ok(Arc::clone(schema)),
+            Some(Lookup::Failed { error, at }) if at.elapsed() < NEGATIVE_CACHE_TTL => {
+                return Err(error.clone().into())
+            }
+            _ => {}
+        }
+
+        self.resolve(id)
+    }
+}
@@ -7,6 +7,8 @@ impl ClientBuilder for SqsClientBuilder {
     type Client = aws_sdk_sqs::client::Client;
     type DefaultMiddleware = aws_sdk_sqs::middleware::DefaultMiddleware;
 
+    const ENDPOINT_PREFIX: &'static str = "sqs";
+
     fn default_middleware() -> Self::DefaultMiddleware {
         aws_sdk_sqs::middleware::DefaultMiddleware::new()
     }
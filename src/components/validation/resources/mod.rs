@@ -2,9 +2,10 @@ mod event;
 mod http;
 
 use codecs::{
-    decoding::{self, DeserializerConfig, NewlineDelimitedDecoderOptions},
+    decoding::{self, AvroDeserializerOptions, DeserializerConfig, NewlineDelimitedDecoderOptions},
     encoding::{
-        self, Framer, FramingConfig, JsonSerializerConfig, SerializerConfig, TextSerializerConfig,
+        self, AvroSerializerOptions, CefSerializerConfig, CefSerializerOptions, Framer,
+        FramingConfig, JsonSerializerConfig, SerializerConfig, TextSerializerConfig,
     },
     BytesEncoder,
 };
@@ -96,24 +97,30 @@ impl ResourceCodec {
     ///
     /// The decoder is generated as an inverse to the input codec: if an encoding configuration was
     /// given, we generate a decoder that satisfies that encoding configuration, and vice versa.
-    pub fn into_decoder(&self) -> Decoder {
+    ///
+    /// # Errors
+    ///
+    /// If the encoding configuration has no equivalent decoding configuration, an error is returned.
+    pub fn into_decoder(&self) -> Result<Decoder, String> {
         let (framer, deserializer) = match self {
-            Self::Decoding(config) => return config.build(),
+            Self::Decoding(config) => {
+                return Ok(config.build().expect("should not fail to build decoder"))
+            }
             Self::Encoding(config) => (
                 encoder_framing_to_decoding_framer(config.config().default_stream_framing()),
-                serializer_config_to_deserializer(config.config()),
+                serializer_config_to_deserializer(config.config())?,
             ),
             Self::EncodingWithFraming(config) => {
                 let (maybe_framing, serializer) = config.config();
                 let framing = maybe_framing.clone().unwrap_or(FramingConfig::Bytes);
                 (
                     encoder_framing_to_decoding_framer(framing),
-                    serializer_config_to_deserializer(serializer),
+                    serializer_config_to_deserializer(serializer)?,
                 )
             }
         };
 
-        Decoder::new(framer, deserializer)
+        Ok(Decoder::new(framer, deserializer))
     }
 }
 
@@ -142,14 +149,26 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         // `message` field... but it's close enough for now.
         DeserializerConfig::Bytes => SerializerConfig::Text(TextSerializerConfig::default()),
         DeserializerConfig::Json => SerializerConfig::Json(JsonSerializerConfig::default()),
-        // TODO: We need to create an Avro serializer because, certainly, for any source decoding
-        // the data as Avro, we can't possibly send anything else without the source just
-        // immediately barfing.
         #[cfg(feature = "sources-syslog")]
         DeserializerConfig::Syslog => SerializerConfig::Logfmt,
         DeserializerConfig::Native => SerializerConfig::Native,
         DeserializerConfig::NativeJson => SerializerConfig::NativeJson,
         DeserializerConfig::Gelf => SerializerConfig::Gelf,
+        DeserializerConfig::MessagePack => SerializerConfig::MessagePack(Default::default()),
+        DeserializerConfig::Cef => SerializerConfig::Cef(CefSerializerConfig::new(
+            CefSerializerOptions::new("Vector".to_owned(), "Vector".to_owned(), "1.0".to_owned()),
+        )),
+        // TODO: Messages in the schema registry wire format can't be generated without a schema
+        // registry, so only decoders with a static schema are supported.
+        DeserializerConfig::Avro { avro } => SerializerConfig::Avro {
+            avro: AvroSerializerOptions {
+                schema: avro
+                    .schema
+                    .clone()
+                    .expect("Avro decoders without a schema registry have a schema"),
+                schema_registry: None,
+            },
+        },
     };
 
     serializer_config
@@ -168,7 +187,8 @@ fn decoder_framing_to_encoding_framer(framing: &decoding::FramingConfig) -> enco
             },
         },
         decoding::FramingConfig::LengthDelimited => encoding::FramingConfig::LengthDelimited,
-        decoding::FramingConfig::NewlineDelimited { .. } => {
+        decoding::FramingConfig::Multiline { .. }
+        | decoding::FramingConfig::NewlineDelimited { .. } => {
             encoding::FramingConfig::NewlineDelimited
         }
         // TODO: There's no equivalent octet counting framer for encoding... although
@@ -179,19 +199,36 @@ fn decoder_framing_to_encoding_framer(framing: &decoding::FramingConfig) -> enco
     framing_config.build()
 }
 
-fn serializer_config_to_deserializer(config: &SerializerConfig) -> decoding::Deserializer {
+fn serializer_config_to_deserializer(
+    config: &SerializerConfig,
+) -> Result<decoding::Deserializer, String> {
     let deserializer_config = match config {
-        SerializerConfig::Avro { .. } => todo!(),
+        SerializerConfig::Avro { avro } => DeserializerConfig::Avro {
+            avro: AvroDeserializerOptions {
+                schema: Some(avro.schema.clone()),
+                schema_registry: None,
+            },
+        },
+        SerializerConfig::Cef(_) => DeserializerConfig::Cef,
         SerializerConfig::Csv { .. } => todo!(),
         SerializerConfig::Gelf => DeserializerConfig::Gelf,
         SerializerConfig::Json(_) => DeserializerConfig::Json,
         SerializerConfig::Logfmt => todo!(),
+        SerializerConfig::MessagePack(_) => DeserializerConfig::MessagePack,
         SerializerConfig::Native => DeserializerConfig::Native,
         SerializerConfig::NativeJson => DeserializerConfig::NativeJson,
+        SerializerConfig::Protobuf { .. } => {
+            return Err(
+                "Validating the `protobuf` encoding isn't supported, as there is no `protobuf` decoding."
+                    .to_owned(),
+            )
+        }
         SerializerConfig::RawMessage | SerializerConfig::Text(_) => DeserializerConfig::Bytes,
     };
 
-    deserializer_config.build()
+    Ok(deserializer_config
+        .build()
+        .expect("building deserializer should never fail"))
 }
 
 fn encoder_framing_to_decoding_framer(framing: encoding::FramingConfig) -> decoding::Framer {
@@ -211,7 +248,9 @@ fn encoder_framing_to_decoding_framer(framing: encoding::FramingConfig) -> decod
         },
     };
 
-    framing_config.build()
+    framing_config
+        .build()
+        .expect("building framer should never fail")
 }
 
 /// Direction that the resource is operating in.
@@ -308,11 +347,15 @@ impl ExternalResource {
     }
 
     /// Spawns this resource for use as an output for a sink.
+    ///
+    /// # Errors
+    ///
+    /// If the events sent by the sink can't be decoded, an error is returned.
     pub fn spawn_as_output(
         self,
         output_tx: mpsc::Sender<Event>,
         task_coordinator: &TaskCoordinator<Configuring>,
-    ) {
+    ) -> Result<(), String> {
         match self.definition {
             ResourceDefinition::Http(http_config) => {
                 http_config.spawn_as_output(self.direction, self.codec, output_tx, task_coordinator)
@@ -230,7 +230,7 @@ impl Runner {
                 &self.configuration,
                 &input_task_coordinator,
                 &output_task_coordinator,
-            );
+            )?;
             let input_tx = runner_input.into_sender(controlled_edges.input);
             let mut output_rx = runner_output.into_receiver(controlled_edges.output);
             debug!("External resource (if any) and controlled edges built and spawned.");
@@ -397,7 +397,7 @@ fn build_external_resource(
     configuration: &ValidationConfiguration,
     input_task_coordinator: &TaskCoordinator<Configuring>,
     output_task_coordinator: &TaskCoordinator<Configuring>,
-) -> (RunnerInput, RunnerOutput) {
+) -> Result<(RunnerInput, RunnerOutput), String> {
     let component_type = configuration.component_type();
     let maybe_external_resource = configuration.external_resource();
     match component_type {
@@ -411,11 +411,11 @@ fn build_external_resource(
                 maybe_external_resource.expect("a source must always have an external resource");
             resource.spawn_as_input(rx, input_task_coordinator);
 
-            (RunnerInput::External(tx), RunnerOutput::Controlled)
+            Ok((RunnerInput::External(tx), RunnerOutput::Controlled))
         }
         ComponentType::Transform => {
             // Transforms have no external resources.
-            (RunnerInput::Controlled, RunnerOutput::Controlled)
+            Ok((RunnerInput::Controlled, RunnerOutput::Controlled))
         }
         ComponentType::Sink => {
             // As an external resource for a sink, we create a channel that the validation runner
@@ -425,9 +425,9 @@ fn build_external_resource(
             let (tx, rx) = mpsc::channel(1024);
             let resource =
                 maybe_external_resource.expect("a sink must always have an external resource");
-            resource.spawn_as_output(tx, output_task_coordinator);
+            resource.spawn_as_output(tx, output_task_coordinator)?;
 
-            (RunnerInput::Controlled, RunnerOutput::External(rx))
+            Ok((RunnerInput::Controlled, RunnerOutput::External(rx)))
         }
     }
 }
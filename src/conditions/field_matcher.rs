@@ -0,0 +1,355 @@
+use std::str::FromStr;
+
+use lookup::lookup_v2::ConfigTargetPath;
+use regex::Regex;
+use vector_config::configurable_component;
+use vector_core::event::{Event, Value};
+
+use crate::conditions::{Condition, Conditional, ConditionalConfig};
+
+/// A condition that matches the fields of an event against simple predicates, without compiling
+/// VRL.
+///
+/// This is suited to configurations generating many simple conditions, such as the routes of a
+/// `route` transform, as the predicates are cheap to build and to run.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+pub struct FieldMatcherConfig {
+    /// The predicates on the fields of the event, all of which must hold for the event to match.
+    ///
+    /// Only log and trace events can match.
+    pub matchers: Vec<FieldMatcher>,
+}
+
+impl_generate_config_from_default!(FieldMatcherConfig);
+
+/// Predicates on the value of a field.
+///
+/// All the predicates set must hold for the field to match. If none is set, the field only has
+/// to exist.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FieldMatcher {
+    /// The path of the field, such as `.status` or `.user.id`.
+    #[configurable(metadata(docs::examples = ".status"))]
+    pub path: ConfigTargetPath,
+
+    /// Whether the field must exist, or must not exist.
+    pub exists: Option<bool>,
+
+    /// A value the field must be equal to.
+    ///
+    /// Fields that aren't strings, such as integers or booleans, are compared as strings, so `200`
+    /// matches both the string `"200"` and the integer `200`.
+    #[configurable(metadata(docs::examples = "production"))]
+    pub equals: Option<String>,
+
+    /// A regular expression the field must match.
+    ///
+    /// Fields that aren't strings are matched as strings.
+    #[configurable(metadata(docs::examples = "^GET /api/"))]
+    pub regex: Option<String>,
+
+    /// A number the field must be greater than.
+    ///
+    /// Strings are compared as numbers if they can be parsed as such, and never match otherwise.
+    pub gt: Option<f64>,
+
+    /// A number the field must be greater than or equal to.
+    ///
+    /// Strings are compared as numbers if they can be parsed as such, and never match otherwise.
+    pub gte: Option<f64>,
+
+    /// A number the field must be less than.
+    ///
+    /// Strings are compared as numbers if they can be parsed as such, and never match otherwise.
+    pub lt: Option<f64>,
+
+    /// A number the field must be less than or equal to.
+    ///
+    /// Strings are compared as numbers if they can be parsed as such, and never match otherwise.
+    pub lte: Option<f64>,
+}
+
+impl FieldMatcher {
+    const fn new(path: ConfigTargetPath) -> Self {
+        Self {
+            path,
+            exists: None,
+            equals: None,
+            regex: None,
+            gt: None,
+            gte: None,
+            lt: None,
+            lte: None,
+        }
+    }
+}
+
+/// Parses a field matcher from a short expression, such as those given to `vector tap --filter`.
+///
+/// The expression is either a path, to check that the field exists, a path prefixed with `!`, to
+/// check that it doesn't, or a path followed by one of the operators `==`, `=~`, `>`, `>=`, `<`
+/// or `<=` and a value, such as `.status >= 500`. The value can be wrapped in double quotes.
+impl FromStr for FieldMatcher {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_path = |path: &str| {
+            ConfigTargetPath::try_from(path.trim().to_owned())
+                .map_err(|error| format!("Invalid path in filter {:?}: {}.", s, error))
+        };
+
+        let Some(index) = s.find(|c| matches!(c, '=' | '<' | '>')) else {
+            let (path, exists) = match s.strip_prefix('!') {
+                Some(path) => (path, false),
+                None => (s, true),
+            };
+            let mut matcher = Self::new(parse_path(path)?);
+            matcher.exists = Some(exists);
+            return Ok(matcher);
+        };
+
+        let (path, rest) = s.split_at(index);
+        let (operator, value) = ["==", "=~", ">=", "<=", ">", "<"]
+            .into_iter()
+            .find_map(|operator| rest.strip_prefix(operator).map(|value| (operator, value)))
+            .ok_or_else(|| format!("Invalid operator in filter {:?}.", s))?;
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        let number = || {
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number {:?} in filter {:?}.", value, s))
+        };
+
+        let mut matcher = Self::new(parse_path(path)?);
+        match operator {
+            "==" => matcher.equals = Some(value.to_owned()),
+            "=~" => matcher.regex = Some(value.to_owned()),
+            ">" => matcher.gt = Some(number()?),
+            ">=" => matcher.gte = Some(number()?),
+            "<" => matcher.lt = Some(number()?),
+            _ => matcher.lte = Some(number()?),
+        }
+        Ok(matcher)
+    }
+}
+
+/// A predicate on the value of a field.
+#[derive(Clone, Debug)]
+enum Predicate {
+    Exists(bool),
+    Equals(String),
+    Regex(Regex),
+    Gt(f64),
+    Gte(f64),
+    Lt(f64),
+    Lte(f64),
+}
+
+impl Predicate {
+    fn check(&self, value: Option<&Value>) -> bool {
+        let Some(value) = value else {
+            return matches!(self, Predicate::Exists(false));
+        };
+        match self {
+            Predicate::Exists(exists) => *exists,
+            Predicate::Equals(expected) => match value {
+                Value::Bytes(bytes) => bytes == expected.as_bytes(),
+                value => is_scalar(value) && *value.to_string_lossy() == **expected,
+            },
+            Predicate::Regex(regex) => match value {
+                Value::Bytes(bytes) => regex.is_match(&String::from_utf8_lossy(bytes)),
+                value => is_scalar(value) && regex.is_match(&value.to_string_lossy()),
+            },
+            Predicate::Gt(bound) => as_number(value).map_or(false, |n| n > *bound),
+            Predicate::Gte(bound) => as_number(value).map_or(false, |n| n >= *bound),
+            Predicate::Lt(bound) => as_number(value).map_or(false, |n| n < *bound),
+            Predicate::Lte(bound) => as_number(value).map_or(false, |n| n <= *bound),
+        }
+    }
+}
+
+const fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_) | Value::Null)
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Integer(n) => Some(*n as f64),
+        Value::Float(n) => Some(n.into_inner()),
+        Value::Bytes(bytes) => std::str::from_utf8(bytes).ok()?.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Runner checking the predicates of a field matcher condition against events.
+#[derive(Clone, Debug)]
+pub struct FieldMatcherRunner {
+    predicates: Vec<(ConfigTargetPath, Predicate)>,
+}
+
+impl FieldMatcherRunner {
+    /// Returns the first predicate that doesn't hold for the event, if any.
+    fn failed_predicate(&self, event: &Event) -> Option<&(ConfigTargetPath, Predicate)> {
+        self.predicates
+            .iter()
+            .find(|(path, predicate)| match event {
+                Event::Log(log) => !predicate.check(log.get(path)),
+                Event::Trace(trace) => !predicate.check(trace.get(path)),
+                Event::Metric(_) => true,
+            })
+    }
+}
+
+impl Conditional for FieldMatcherRunner {
+    fn check(&self, e: Event) -> (bool, Event) {
+        let matched = !matches!(e, Event::Metric(_)) && self.failed_predicate(&e).is_none();
+        (matched, e)
+    }
+
+    fn check_with_context(&self, e: Event) -> (Result<(), String>, Event) {
+        if matches!(e, Event::Metric(_)) {
+            return (Err("metrics can't match field matchers".into()), e);
+        }
+        let result = match self.failed_predicate(&e) {
+            None => Ok(()),
+            Some((path, predicate)) => Err(format!(
+                "field {} didn't match {:?}",
+                String::from(path.clone()),
+                predicate
+            )),
+        };
+        (result, e)
+    }
+}
+
+impl ConditionalConfig for FieldMatcherConfig {
+    fn build(&self, _enrichment_tables: &enrichment::TableRegistry) -> crate::Result<Condition> {
+        let mut predicates = Vec::new();
+        for matcher in &self.matchers {
+            let regex = matcher
+                .regex
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|error| format!("Invalid regex for {:?}: {}", matcher.path, error))?;
+            let mut matcher_predicates = [
+                matcher.exists.map(Predicate::Exists),
+                matcher.equals.clone().map(Predicate::Equals),
+                regex.map(Predicate::Regex),
+                matcher.gt.map(Predicate::Gt),
+                matcher.gte.map(Predicate::Gte),
+                matcher.lt.map(Predicate::Lt),
+                matcher.lte.map(Predicate::Lte),
+            ]
+            .into_iter()
+            .flatten()
+            .peekable();
+
+            if matcher_predicates.peek().is_none() {
+                predicates.push((matcher.path.clone(), Predicate::Exists(true)));
+            }
+            predicates
+                .extend(matcher_predicates.map(|predicate| (matcher.path.clone(), predicate)));
+        }
+
+        Ok(Condition::FieldMatcher(FieldMatcherRunner { predicates }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    fn build(matchers: &[&str]) -> Condition {
+        FieldMatcherConfig {
+            matchers: matchers.iter().map(|m| m.parse().unwrap()).collect(),
+        }
+        .build(&Default::default())
+        .unwrap()
+    }
+
+    fn path(path: &str) -> ConfigTargetPath {
+        ConfigTargetPath::try_from(path.to_owned()).unwrap()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<FieldMatcherConfig>();
+    }
+
+    #[test]
+    fn parses_expressions() {
+        assert_eq!(
+            ".status >= 500".parse::<FieldMatcher>().unwrap(),
+            FieldMatcher {
+                gte: Some(500.0),
+                ..FieldMatcher::new(path(".status"))
+            }
+        );
+        assert_eq!(
+            r#".env == "prod""#.parse::<FieldMatcher>().unwrap().equals,
+            Some("prod".to_owned())
+        );
+        assert_eq!(
+            ".message=~timeout".parse::<FieldMatcher>().unwrap().regex,
+            Some("timeout".to_owned())
+        );
+        assert_eq!(
+            "!.user.id".parse::<FieldMatcher>().unwrap(),
+            FieldMatcher {
+                exists: Some(false),
+                ..FieldMatcher::new(path(".user.id"))
+            }
+        );
+        assert!(".status => 500".parse::<FieldMatcher>().is_err());
+        assert!(".status > high".parse::<FieldMatcher>().is_err());
+    }
+
+    #[test]
+    fn matches_fields() {
+        let event = Event::Log(
+            LogEvent::try_from(serde_json::json!({
+                "message": "request timed out",
+                "status": 504,
+                "latency": "1.5",
+                "env": "prod",
+                "user": { "id": 42 },
+            }))
+            .unwrap(),
+        );
+
+        for (matchers, expected) in [
+            (&[".status >= 500", ".env == prod"][..], true),
+            (&[".status > 504"], false),
+            (&[".status == 504"], true),
+            (&[".latency < 2", ".latency > 1"], true),
+            (&[".message =~ timed? ?out"], true),
+            (&[".message =~ ^timeout"], false),
+            (&[".user.id"], true),
+            (&["!.user.name"], true),
+            (&[".user == 42"], false),
+            (&[".missing < 1"], false),
+        ] {
+            let (matched, _) = build(matchers).check(event.clone());
+            assert_eq!(matched, expected, "{:?}", matchers);
+        }
+    }
+
+    #[test]
+    fn reports_failed_predicate() {
+        let event = Event::Log(LogEvent::from("hello"));
+
+        let (result, _) = build(&[".message", ".status >= 500"]).check_with_context(event);
+        assert!(result.unwrap_err().contains(".status"));
+    }
+}
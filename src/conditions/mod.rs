@@ -4,14 +4,17 @@ use vector_config::configurable_component;
 use crate::event::Event;
 
 pub(self) mod datadog_search;
+mod field_matcher;
 pub(crate) mod is_log;
 pub(crate) mod is_metric;
 pub(crate) mod is_trace;
 mod vrl;
 
+pub use self::field_matcher::{FieldMatcher, FieldMatcherConfig};
 pub use self::vrl::VrlConfig;
 use self::{
     datadog_search::{DatadogSearchConfig, DatadogSearchRunner},
+    field_matcher::FieldMatcherRunner,
     is_log::{check_is_log, check_is_log_with_context},
     is_metric::{check_is_metric, check_is_metric_with_context},
     is_trace::{check_is_trace, check_is_trace_with_context},
@@ -36,6 +39,9 @@ pub enum Condition {
     /// Matches an event with a [Datadog Search](https://docs.datadoghq.com/logs/explorer/search_syntax/) query.
     DatadogSearch(DatadogSearchRunner),
 
+    /// Matches an event by checking the values of its fields against simple predicates.
+    FieldMatcher(FieldMatcherRunner),
+
     /// Matches any event.
     ///
     /// Used only for internal testing.
@@ -59,6 +65,7 @@ impl Condition {
             Condition::IsTrace => check_is_trace(e),
             Condition::Vrl(x) => x.check(e),
             Condition::DatadogSearch(x) => x.check(e),
+            Condition::FieldMatcher(x) => x.check(e),
             Condition::AlwaysPass => (true, e),
             Condition::AlwaysFail => (false, e),
         }
@@ -75,6 +82,7 @@ impl Condition {
             Condition::IsTrace => check_is_trace_with_context(e),
             Condition::Vrl(x) => x.check_with_context(e),
             Condition::DatadogSearch(x) => x.check_with_context(e),
+            Condition::FieldMatcher(x) => x.check_with_context(e),
             Condition::AlwaysPass => (Ok(()), e),
             Condition::AlwaysFail => (Ok(()), e),
         }
@@ -113,6 +121,10 @@ pub enum ConditionConfig {
 
     /// Matches an event with a [Datadog Search](https://docs.datadoghq.com/logs/explorer/search_syntax/) query.
     DatadogSearch(DatadogSearchConfig),
+
+    /// Matches an event by checking the values of its fields against simple predicates, such as
+    /// equality or numeric comparisons, without compiling VRL.
+    FieldMatcher(FieldMatcherConfig),
 }
 
 impl ConditionConfig {
@@ -123,6 +135,7 @@ impl ConditionConfig {
             ConditionConfig::IsTrace => Ok(Condition::IsTrace),
             ConditionConfig::Vrl(x) => x.build(enrichment_tables),
             ConditionConfig::DatadogSearch(x) => x.build(enrichment_tables),
+            ConditionConfig::FieldMatcher(x) => x.build(enrichment_tables),
         }
     }
 }
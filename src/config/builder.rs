@@ -19,7 +19,8 @@ use super::api;
 use super::enterprise;
 use super::{
     compiler, schema, BoxedSource, BoxedTransform, ComponentKey, Config, EnrichmentTableOuter,
-    HealthcheckOptions, SinkOuter, SourceOuter, TestDefinition, TransformOuter,
+    FeatureFlagsConfig, HealthcheckOptions, PipelineOuter, PipelineTemplate, SinkOuter,
+    SourceOuter, TestDefinition, TransformOuter,
 };
 
 /// A complete Vector configuration.
@@ -49,6 +50,10 @@ pub struct ConfigBuilder {
     #[serde(default)]
     pub healthchecks: HealthcheckOptions,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub feature_flags: Option<FeatureFlagsConfig>,
+
     /// All configured enrichment tables.
     #[serde(default)]
     pub enrichment_tables: IndexMap<ComponentKey, EnrichmentTableOuter>,
@@ -65,6 +70,17 @@ pub struct ConfigBuilder {
     #[serde(default)]
     pub transforms: IndexMap<ComponentKey, TransformOuter<String>>,
 
+    /// All configured pipeline templates.
+    ///
+    /// Pipeline templates are parameterized groups of transforms, which are instantiated by
+    /// `pipelines` with different parameters.
+    #[serde(default)]
+    pub templates: IndexMap<String, PipelineTemplate>,
+
+    /// All configured pipelines, each instantiating the transforms of a template.
+    #[serde(default)]
+    pub pipelines: IndexMap<ComponentKey, PipelineOuter>,
+
     /// All configured unit tests.
     #[serde(default)]
     pub tests: Vec<TestDefinition<String>>,
@@ -89,6 +105,7 @@ struct ConfigBuilderHash<'a> {
     schema: &'a schema::Options,
     global: &'a GlobalOptions,
     healthchecks: &'a HealthcheckOptions,
+    feature_flags: &'a Option<FeatureFlagsConfig>,
     enrichment_tables: BTreeMap<&'a ComponentKey, &'a EnrichmentTableOuter>,
     sources: BTreeMap<&'a ComponentKey, &'a SourceOuter>,
     sinks: BTreeMap<&'a ComponentKey, &'a SinkOuter<String>>,
@@ -168,6 +185,7 @@ impl<'a> From<&'a ConfigBuilder> for ConfigBuilderHash<'a> {
             schema: &value.schema,
             global: &value.global,
             healthchecks: &value.healthchecks,
+            feature_flags: &value.feature_flags,
             enrichment_tables: value.enrichment_tables.iter().collect(),
             sources: value.sources.iter().collect(),
             sinks: value.sinks.iter().collect(),
@@ -189,6 +207,7 @@ impl From<Config> for ConfigBuilder {
             #[cfg(feature = "enterprise")]
             enterprise,
             healthchecks,
+            feature_flags,
             enrichment_tables,
             sources,
             sinks,
@@ -218,10 +237,13 @@ impl From<Config> for ConfigBuilder {
             #[cfg(feature = "enterprise")]
             enterprise,
             healthchecks,
+            feature_flags,
             enrichment_tables,
             sources,
             sinks,
             transforms,
+            templates: IndexMap::new(),
+            pipelines: IndexMap::new(),
             provider: None,
             tests,
             secret,
@@ -318,6 +340,18 @@ impl ConfigBuilder {
             };
         }
 
+        match (self.feature_flags.as_ref(), with.feature_flags) {
+            (Some(_), Some(_)) => {
+                errors.push(
+                    "duplicate 'feature_flags' definition, only one definition allowed".to_owned(),
+                );
+            }
+            (None, Some(other)) => {
+                self.feature_flags = Some(other);
+            }
+            _ => {}
+        };
+
         self.provider = with.provider;
 
         match self.global.merge(with.global) {
@@ -351,6 +385,16 @@ impl ConfigBuilder {
                 errors.push(format!("duplicate transform id found: {}", k));
             }
         });
+        with.templates.keys().for_each(|k| {
+            if self.templates.contains_key(k) {
+                errors.push(format!("duplicate template name found: {}", k));
+            }
+        });
+        with.pipelines.keys().for_each(|k| {
+            if self.pipelines.contains_key(k) {
+                errors.push(format!("duplicate pipeline id found: {}", k));
+            }
+        });
         with.tests.iter().for_each(|wt| {
             if self.tests.iter().any(|t| t.name == wt.name) {
                 errors.push(format!("duplicate test name found: {}", wt.name));
@@ -369,6 +413,8 @@ impl ConfigBuilder {
         self.sources.extend(with.sources);
         self.sinks.extend(with.sinks);
         self.transforms.extend(with.transforms);
+        self.templates.extend(with.templates);
+        self.pipelines.extend(with.pipelines);
         self.tests.extend(with.tests);
         self.secret.extend(with.secret);
 
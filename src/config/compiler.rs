@@ -1,8 +1,8 @@
 use indexmap::IndexSet;
 
 use super::{
-    builder::ConfigBuilder, graph::Graph, id::Inputs, transform::get_transform_output_ids,
-    validation, Config, OutputId,
+    builder::ConfigBuilder, graph::Graph, id::Inputs, pipelines,
+    transform::get_transform_output_ids, validation, Config, OutputId,
 };
 
 pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<String>> {
@@ -16,11 +16,18 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
             .transforms
             .keys()
             .chain(builder.sources.keys())
-            .chain(builder.sinks.keys()),
+            .chain(builder.sinks.keys())
+            .chain(builder.pipelines.keys()),
     ) {
         errors.extend(name_errors);
     }
 
+    // Pipelines expand into transforms named after them, such as `team_a.parse`, so this is done
+    // after checking the names too.
+    if let Err(pipeline_errors) = pipelines::expand_pipelines(&mut builder) {
+        errors.extend(pipeline_errors);
+    }
+
     expand_globs(&mut builder);
 
     if let Err(type_errors) = validation::check_shape(&builder) {
@@ -35,6 +42,18 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         errors.extend(output_errors);
     }
 
+    if let Err(schedule_errors) = validation::check_schedules(&builder) {
+        errors.extend(schedule_errors);
+    }
+
+    if let Err(buffer_errors) = validation::check_buffers(&builder) {
+        errors.extend(buffer_errors);
+    }
+
+    if let Err(feature_flag_errors) = validation::check_feature_flags(&builder) {
+        errors.extend(feature_flag_errors);
+    }
+
     #[cfg(feature = "enterprise")]
     let hash = Some(builder.sha256_hash());
 
@@ -49,10 +68,13 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         #[cfg(feature = "enterprise")]
         enterprise,
         healthchecks,
+        feature_flags,
         enrichment_tables,
         sources,
         sinks,
         transforms,
+        templates: _,
+        pipelines: _,
         tests,
         provider: _,
         secret,
@@ -105,6 +127,7 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
             enterprise,
             hash,
             healthchecks,
+            feature_flags,
             enrichment_tables,
             sources,
             sinks,
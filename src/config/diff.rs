@@ -1,8 +1,8 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use indexmap::IndexMap;
 
-use super::{ComponentKey, Config};
+use super::{ComponentKey, Config, Inputs, OutputId};
 
 #[derive(Debug)]
 pub struct ConfigDiff {
@@ -10,6 +10,11 @@ pub struct ConfigDiff {
     pub transforms: Difference,
     pub sinks: Difference,
     pub enrichment_tables: Difference,
+    /// The new inputs of the sinks whose inputs are their only change.
+    ///
+    /// These sinks aren't part of `sinks`: they keep running, along with their buffers and
+    /// connections, and are only reconnected to their new inputs.
+    pub rewired_sinks: HashMap<ComponentKey, Inputs<OutputId>>,
 }
 
 impl ConfigDiff {
@@ -18,11 +23,23 @@ impl ConfigDiff {
     }
 
     pub fn new(old: &Config, new: &Config) -> Self {
+        let mut sinks = Difference::new(&old.sinks, &new.sinks);
+        let rewired_sinks = sinks
+            .to_change
+            .iter()
+            .filter(|&key| only_inputs_changed(&old.sinks[key], &new.sinks[key]))
+            .map(|key| (key.clone(), new.sinks[key].inputs.clone()))
+            .collect::<HashMap<_, _>>();
+        sinks
+            .to_change
+            .retain(|key| !rewired_sinks.contains_key(key));
+
         ConfigDiff {
             sources: Difference::new(&old.sources, &new.sources),
             transforms: Difference::new(&old.transforms, &new.transforms),
-            sinks: Difference::new(&old.sinks, &new.sinks),
+            sinks,
             enrichment_tables: Difference::new(&old.enrichment_tables, &new.enrichment_tables),
+            rewired_sinks,
         }
     }
 
@@ -31,6 +48,9 @@ impl ConfigDiff {
         self.sources.flip();
         self.transforms.flip();
         self.sinks.flip();
+        // Sinks are only rewired once all the changed components are built, so there is nothing
+        // to undo when restoring the previous configuration.
+        self.rewired_sinks.clear();
         self
     }
 
@@ -54,6 +74,18 @@ impl ConfigDiff {
     }
 }
 
+/// Checks whether the inputs of a component are the only change to its configuration.
+fn only_inputs_changed<C: serde::Serialize>(old: &C, new: &C) -> bool {
+    let without_inputs = |component: &C| {
+        let mut value = serde_json::to_value(component).unwrap();
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("inputs");
+        }
+        value
+    };
+    without_inputs(old) == without_inputs(new)
+}
+
 #[derive(Debug)]
 pub struct Difference {
     pub to_remove: HashSet<ComponentKey>,
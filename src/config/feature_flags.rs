@@ -0,0 +1,98 @@
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use url::Url;
+use vector_config::configurable_component;
+
+use super::ProxyConfig;
+use crate::tls::TlsConfig;
+
+/// Feature flags options.
+///
+/// Feature flags are polled from a provider, and gate the behavior of transforms through their
+/// `enabled_when` option and the `feature_enabled` VRL function. This allows rolling out changes to
+/// the pipeline gradually across a fleet, without pushing new configuration.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlagsConfig {
+    #[configurable(derived)]
+    pub provider: FeatureFlagsProvider,
+
+    /// How often to poll the provider, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    #[configurable(metadata(docs::human_name = "Poll Interval"))]
+    pub poll_interval_secs: u64,
+}
+
+const fn default_poll_interval_secs() -> u64 {
+    30
+}
+
+/// Providers of feature flags.
+///
+/// Providers return a JSON object mapping the name of each flag to either a boolean, or an object
+/// with a `rollout_percentage` between 0 and 100, in which case the flag is enabled on that
+/// percentage of the hosts running Vector.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FeatureFlagsProvider {
+    /// Read the flags from a file.
+    File(FileFeatureFlagsProvider),
+
+    /// Fetch the flags from an HTTP endpoint.
+    Http(HttpFeatureFlagsProvider),
+}
+
+/// Configuration for the `file` feature flags provider.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FileFeatureFlagsProvider {
+    /// The path of the file to read the flags from.
+    #[configurable(metadata(docs::examples = "/etc/vector/feature_flags.json"))]
+    pub path: PathBuf,
+}
+
+/// Configuration for the `http` feature flags provider.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct HttpFeatureFlagsProvider {
+    /// The URL to fetch the flags from.
+    #[configurable(metadata(docs::examples = "https://flags.example.com/vector.json"))]
+    pub url: Url,
+
+    /// HTTP headers to add to the request.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "An HTTP request header and its value."
+    ))]
+    #[configurable(metadata(docs::examples = "headers_examples()"))]
+    pub headers: IndexMap<String, String>,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub proxy: ProxyConfig,
+}
+
+fn headers_examples() -> IndexMap<String, String> {
+    IndexMap::from([(
+        "Authorization".to_owned(),
+        "Bearer ${FLAGS_TOKEN}".to_owned(),
+    )])
+}
+
+// `TlsConfig` can't be compared, so the providers are compared through their serialized form.
+impl PartialEq for HttpFeatureFlagsProvider {
+    fn eq(&self, other: &Self) -> bool {
+        serde_json::to_value(self).ok() == serde_json::to_value(other).ok()
+    }
+}
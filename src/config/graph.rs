@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 
 use super::{
     schema, ComponentKey, DataType, OutputId, SinkConfig, SinkOuter, SourceOuter, SourceOutput,
-    TransformOuter, TransformOutput,
+    TransformOuter, TransformOutput, DEAD_LETTER_OUTPUT,
 };
 
 #[derive(Debug, Clone)]
@@ -17,6 +17,7 @@ pub enum Node {
     },
     Sink {
         ty: DataType,
+        dead_letter: bool,
     },
 }
 
@@ -90,6 +91,7 @@ impl Graph {
                 id.clone(),
                 Node::Sink {
                     ty: config.inner.input().data_type(),
+                    dead_letter: config.dead_letter.enabled,
                 },
             );
         }
@@ -156,7 +158,7 @@ impl Graph {
         match self.nodes[key] {
             Node::Source { .. } => panic!("no inputs on sources"),
             Node::Transform { in_ty, .. } => in_ty,
-            Node::Sink { ty } => ty,
+            Node::Sink { ty, .. } => ty,
         }
     }
 
@@ -164,8 +166,8 @@ impl Graph {
     ///
     /// # Panics
     ///
-    /// Will panic if the given id is not present in the graph or identifies a sink, which can't
-    /// have inputs.
+    /// Will panic if the given id is not present in the graph or identifies a sink without a
+    /// dead-letter output.
     fn get_output_type(&self, id: &OutputId) -> DataType {
         match &self.nodes[&id.component] {
             Node::Source { outputs } => outputs
@@ -178,6 +180,11 @@ impl Graph {
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
+            // The events a sink fails to deliver are sent to its dead-letter output unchanged.
+            Node::Sink {
+                ty,
+                dead_letter: true,
+            } if id.port.as_deref() == Some(DEAD_LETTER_OUTPUT) => *ty,
             Node::Sink { .. } => panic!("no outputs on sinks"),
         }
     }
@@ -263,6 +270,9 @@ impl Graph {
         self.nodes
             .iter()
             .flat_map(|(key, node)| match node {
+                Node::Sink {
+                    dead_letter: true, ..
+                } => vec![OutputId::from((key, DEAD_LETTER_OUTPUT.to_owned()))],
                 Node::Sink { .. } => vec![],
                 Node::Source { outputs } => outputs
                     .iter()
@@ -355,7 +365,7 @@ impl Graph {
             .into_iter()
             .filter(|path| {
                 if let Some(key) = path.last() {
-                    matches!(self.nodes.get(key), Some(Node::Sink { ty: _ }))
+                    matches!(self.nodes.get(key), Some(Node::Sink { .. }))
                 } else {
                     false
                 }
@@ -429,7 +439,13 @@ mod test {
         fn add_sink(&mut self, id: &str, ty: DataType, inputs: Vec<&str>) {
             let id = ComponentKey::from(id);
             let inputs = clean_inputs(inputs);
-            self.nodes.insert(id.clone(), Node::Sink { ty });
+            self.nodes.insert(
+                id.clone(),
+                Node::Sink {
+                    ty,
+                    dead_letter: false,
+                },
+            );
             for from in inputs {
                 self.edges.push(Edge {
                     from,
@@ -438,6 +454,13 @@ mod test {
             }
         }
 
+        fn enable_dead_letter(&mut self, id: &str) {
+            match self.nodes.get_mut(&ComponentKey::from(id)) {
+                Some(Node::Sink { dead_letter, .. }) => *dead_letter = true,
+                _ => panic!("invalid sink"),
+            }
+        }
+
         fn test_add_input(&mut self, node: &str, input: &str) -> Result<(), String> {
             let available_inputs = self.input_map().unwrap();
             self.add_input(input, &node.into(), &available_inputs)
@@ -632,6 +655,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn allows_sink_dead_letter_outputs() {
+        let mut graph = Graph::default();
+        graph.add_source("log_source", DataType::Log);
+        graph.add_sink("primary", DataType::Log, vec!["log_source"]);
+        graph.add_sink("fallback", DataType::Log, vec![]);
+        graph.add_sink("metric_sink", DataType::Metric, vec![]);
+
+        let expected =
+            "Input \"primary.dead_letter\" for sink \"fallback\" doesn't match any components."
+                .to_string();
+        assert_eq!(
+            Err(expected),
+            graph.test_add_input("fallback", "primary.dead_letter")
+        );
+
+        graph.enable_dead_letter("primary");
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("fallback", "primary.dead_letter")
+        );
+        assert_eq!(Ok(()), graph.typecheck());
+        graph.check_for_cycles().unwrap();
+
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("metric_sink", "primary.dead_letter")
+        );
+        assert_eq!(
+            Err(vec![
+                "Data type mismatch between primary.dead_letter (Log) and metric_sink (Metric)"
+                    .into()
+            ]),
+            graph.typecheck()
+        );
+
+        let mut graph = Graph::default();
+        graph.add_source("log_source", DataType::Log);
+        graph.add_sink("primary", DataType::Log, vec!["log_source"]);
+        graph.enable_dead_letter("primary");
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("primary", "primary.dead_letter")
+        );
+        assert!(graph.check_for_cycles().is_err());
+    }
+
     #[test]
     fn disallows_ambiguous_inputs() {
         let mut graph = Graph::default();
@@ -11,8 +11,8 @@ use indexmap::IndexMap;
 pub use vector_config::component::{GenerateConfig, SinkDescription, TransformDescription};
 use vector_config::configurable_component;
 pub use vector_core::config::{
-    AcknowledgementsConfig, DataType, GlobalOptions, Input, LogNamespace,
-    SourceAcknowledgementsConfig, SourceOutput, TransformOutput,
+    AcknowledgementsConfig, DataDirSubsystem, DataType, GlobalOptions, Input, LogNamespace,
+    SourceAcknowledgementsConfig, SourceOutput, StateDirs, TransformOutput,
 };
 
 use crate::{conditions, event::Metric, secrets::SecretBackends, serde::OneOrMany};
@@ -25,11 +25,14 @@ mod diff;
 mod enrichment_table;
 #[cfg(feature = "enterprise")]
 pub mod enterprise;
+mod feature_flags;
 pub mod format;
 mod graph;
 mod id;
 mod loading;
+mod pipelines;
 pub mod provider;
+mod schedule;
 pub mod schema;
 mod secret;
 mod sink;
@@ -44,15 +47,23 @@ pub use builder::ConfigBuilder;
 pub use cmd::{cmd, Opts};
 pub use diff::ConfigDiff;
 pub use enrichment_table::{EnrichmentTableConfig, EnrichmentTableOuter};
+pub use feature_flags::{
+    FeatureFlagsConfig, FeatureFlagsProvider, FileFeatureFlagsProvider, HttpFeatureFlagsProvider,
+};
 pub use format::{Format, FormatHint};
 pub use id::{ComponentKey, Inputs};
 pub use loading::{
     load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
     load_from_str, load_source_from_paths, merge_path_lists, process_paths, CONFIG_PATHS,
 };
+pub use pipelines::{PipelineOuter, PipelineTemplate};
 pub use provider::ProviderConfig;
+pub use schedule::{ComponentScheduleOptions, ScheduleDay, ScheduleWindow};
 pub use secret::SecretBackend;
-pub use sink::{SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter};
+pub use sink::{
+    SinkConfig, SinkContext, SinkDeadLetterOptions, SinkDeliveryFailuresOptions,
+    SinkHealthcheckOptions, SinkOuter, DEAD_LETTER_OUTPUT,
+};
 pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
 pub use transform::{
     get_transform_output_ids, BoxedTransform, TransformConfig, TransformContext, TransformOuter,
@@ -87,7 +98,7 @@ impl ConfigPath {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Config {
     #[cfg(feature = "api")]
     pub api: api::Options,
@@ -97,6 +108,7 @@ pub struct Config {
     pub enterprise: Option<enterprise::Options>,
     pub global: GlobalOptions,
     pub healthchecks: HealthcheckOptions,
+    pub feature_flags: Option<FeatureFlagsConfig>,
     sources: IndexMap<ComponentKey, SourceOuter>,
     sinks: IndexMap<ComponentKey, SinkOuter<OutputId>>,
     transforms: IndexMap<ComponentKey, TransformOuter<OutputId>>,
@@ -793,6 +805,73 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn enabled_when_without_feature_flags() {
+        let errors = load(
+            r#"
+            [sources.in]
+            type = "test_basic"
+
+            [transforms.sample]
+            type = "test_basic"
+            inputs = ["in"]
+            enabled_when = "new_sample"
+            suffix = "foo"
+            increase = 1.25
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["sample"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec!["Transform \"sample\": `enabled_when` requires `feature_flags` to be configured"]
+        )
+    }
+
+    #[tokio::test]
+    async fn pipelines() {
+        let config = r#"
+            [sources.in]
+            type = "test_basic"
+
+            [templates.sampling.transforms.sample]
+            type = "test_basic"
+            suffix = "{{ params.suffix }}"
+            increase = 1.25
+
+            [pipelines.a]
+            template = "sampling"
+            inputs = ["in"]
+            params.suffix = "a"
+
+            [pipelines.b]
+            template = "sampling"
+            inputs = ["in"]
+            params.suffix = "b"
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["*.sample"]
+            "#;
+
+        assert_eq!(load(config, Format::Toml).await, Ok(vec![]));
+
+        let config = load_from_str(config, Format::Toml).unwrap();
+        assert_eq!(
+            config.sink(&ComponentKey::from("out")).unwrap().inputs,
+            vec![
+                super::OutputId::from(ComponentKey::from("a.sample")),
+                super::OutputId::from(ComponentKey::from("b.sample")),
+            ]
+        );
+    }
+
     #[test]
     fn default_data_dir() {
         let config = load_from_str(
@@ -0,0 +1,272 @@
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use vector_config::configurable_component;
+
+use super::{builder::ConfigBuilder, ComponentKey, TransformOuter};
+
+/// Matches the references to the parameters of a template, such as `{{ params.index }}`.
+static PARAM_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\{\{\s*params\.([[:word:]]+)\s*\}\}").unwrap());
+
+/// A template of a group of transforms, instantiated by pipelines with different parameters.
+///
+/// The values of the transforms can reference the parameters of the template as
+/// `{{ params.<name> }}`, which is replaced by the value set by the pipeline, or the default value
+/// of the parameter otherwise.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineTemplate {
+    /// The default values of the parameters of the template.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "The default value of a parameter."
+    ))]
+    pub params: IndexMap<String, String>,
+
+    /// The transforms of the template.
+    ///
+    /// The transforms without `inputs` take the inputs of the pipeline, and the inputs referencing
+    /// the ID of another transform of the template reference that transform in the same pipeline.
+    #[configurable(metadata(docs::additional_props_description = "A transform of the template."))]
+    pub transforms: IndexMap<String, toml::Value>,
+}
+
+/// A pipeline, instantiating the transforms of a template.
+///
+/// The transforms of the pipeline are named after the pipeline and the transform in the template,
+/// such as `team_a.parse` for the `parse` transform of the `team_a` pipeline.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineOuter {
+    /// The name of the template to instantiate.
+    #[configurable(metadata(docs::examples = "team_parsing"))]
+    pub template: String,
+
+    /// The inputs of the transforms of the template that don't have any of their own.
+    #[configurable(metadata(docs::examples = "kafka_in"))]
+    pub inputs: Vec<String>,
+
+    /// The values of the parameters of the template, overriding their default values.
+    #[serde(default)]
+    #[configurable(metadata(docs::additional_props_description = "The value of a parameter."))]
+    pub params: IndexMap<String, String>,
+}
+
+/// Expands the pipelines into the transforms of their templates.
+pub(super) fn expand_pipelines(builder: &mut ConfigBuilder) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    for (id, pipeline) in std::mem::take(&mut builder.pipelines) {
+        let Some(template) = builder.templates.get(&pipeline.template) else {
+            errors.push(format!(
+                "Pipeline \"{}\" references undefined template \"{}\"",
+                id, pipeline.template
+            ));
+            continue;
+        };
+
+        let mut params = template.params.clone();
+        params.extend(pipeline.params.clone());
+
+        for (name, transform) in &template.transforms {
+            let key = ComponentKey::from(format!("{}.{}", id, name));
+            match expand_transform(transform, template, &pipeline, &id, &params) {
+                Ok(_) if builder.transforms.contains_key(&key) => {
+                    errors.push(format!("duplicate transform id found: {}", key));
+                }
+                Ok(transform) => {
+                    builder.transforms.insert(key, transform);
+                }
+                Err(error) => errors.push(format!(
+                    "Pipeline \"{}\", transform \"{}\": {}",
+                    id, name, error
+                )),
+            }
+        }
+    }
+    builder.templates.clear();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn expand_transform(
+    transform: &toml::Value,
+    template: &PipelineTemplate,
+    pipeline: &PipelineOuter,
+    id: &ComponentKey,
+    params: &IndexMap<String, String>,
+) -> Result<TransformOuter<String>, String> {
+    let mut transform = transform.clone();
+    replace_params(&mut transform, params)?;
+
+    let table = transform
+        .as_table_mut()
+        .ok_or_else(|| "expected a table".to_owned())?;
+    let inputs = match table.remove("inputs") {
+        None => pipeline.inputs.clone(),
+        Some(toml::Value::Array(inputs)) => inputs
+            .into_iter()
+            .map(|input| match input {
+                toml::Value::String(input) if template.transforms.contains_key(&input) => {
+                    Ok(format!("{}.{}", id, input))
+                }
+                toml::Value::String(input) => Ok(input),
+                _ => Err("expected `inputs` to be a list of strings".to_owned()),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err("expected `inputs` to be a list of strings".to_owned()),
+    };
+    table.insert(
+        "inputs".to_owned(),
+        toml::Value::Array(inputs.into_iter().map(toml::Value::String).collect()),
+    );
+
+    transform.try_into().map_err(|error| error.to_string())
+}
+
+/// Replaces the references to parameters in the strings of the value.
+fn replace_params(
+    value: &mut toml::Value,
+    params: &IndexMap<String, String>,
+) -> Result<(), String> {
+    match value {
+        toml::Value::String(s) => {
+            let mut undefined = None;
+            let replaced = PARAM_REGEX.replace_all(s, |caps: &Captures<'_>| {
+                let name = &caps[1];
+                params.get(name).cloned().unwrap_or_else(|| {
+                    undefined = Some(name.to_owned());
+                    String::new()
+                })
+            });
+            if let Some(name) = undefined {
+                return Err(format!("undefined parameter \"{}\"", name));
+            }
+            *s = replaced.into_owned();
+        }
+        toml::Value::Array(values) => {
+            for value in values {
+                replace_params(value, params)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for value in table.values_mut() {
+                replace_params(value, params)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{format, Format};
+
+    fn builder(config: &str) -> ConfigBuilder {
+        format::deserialize(config, Format::Toml).unwrap()
+    }
+
+    #[test]
+    fn expands_pipelines() {
+        let mut builder = builder(
+            r#"
+            [templates.team_parsing]
+            params.retention = "30d"
+
+            [templates.team_parsing.transforms.parse]
+            type = "test_basic"
+            suffix = "{{ params.index }}-{{params.retention}}"
+            increase = 1.0
+
+            [templates.team_parsing.transforms.route]
+            type = "test_basic"
+            inputs = ["parse", "other"]
+            suffix = "{{ message }}"
+            increase = 1.0
+
+            [pipelines.team_a]
+            template = "team_parsing"
+            inputs = ["kafka_in"]
+            params.index = "team-a"
+
+            [pipelines.team_b]
+            template = "team_parsing"
+            inputs = ["kafka_in", "http_in"]
+            params = { index = "team-b", retention = "7d" }
+            "#,
+        );
+
+        expand_pipelines(&mut builder).unwrap();
+
+        assert!(builder.pipelines.is_empty());
+        assert!(builder.templates.is_empty());
+        assert_eq!(
+            builder
+                .transforms
+                .iter()
+                .map(|(key, transform)| (key.to_string(), transform.inputs.to_vec()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("team_a.parse".to_owned(), vec!["kafka_in".to_owned()]),
+                (
+                    "team_a.route".to_owned(),
+                    vec!["team_a.parse".to_owned(), "other".to_owned()]
+                ),
+                (
+                    "team_b.parse".to_owned(),
+                    vec!["kafka_in".to_owned(), "http_in".to_owned()]
+                ),
+                (
+                    "team_b.route".to_owned(),
+                    vec!["team_b.parse".to_owned(), "other".to_owned()]
+                ),
+            ]
+        );
+
+        let suffix = |key: &str| {
+            let transform = &builder.transforms[&ComponentKey::from(key)];
+            serde_json::to_value(&transform.inner).unwrap()["suffix"].clone()
+        };
+        assert_eq!(suffix("team_a.parse"), "team-a-30d");
+        assert_eq!(suffix("team_b.parse"), "team-b-7d");
+        // Event templates are left as they are.
+        assert_eq!(suffix("team_a.route"), "{{ message }}");
+    }
+
+    #[test]
+    fn reports_invalid_pipelines() {
+        let mut builder = builder(
+            r#"
+            [templates.team_parsing.transforms.parse]
+            type = "test_basic"
+            suffix = "{{ params.index }}"
+            increase = 1.0
+
+            [pipelines.team_a]
+            template = "team_parsing"
+            inputs = ["in"]
+
+            [pipelines.team_b]
+            template = "missing"
+            inputs = ["in"]
+            "#,
+        );
+
+        assert_eq!(
+            expand_pipelines(&mut builder).unwrap_err(),
+            vec![
+                "Pipeline \"team_a\", transform \"parse\": undefined parameter \"index\"",
+                "Pipeline \"team_b\" references undefined template \"missing\"",
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
+use vector_common::TimeZone;
+use vector_config::configurable_component;
+
+/// Scheduling configuration.
+///
+/// Restricts the times at which the component runs. Outside of its scheduled windows, a source
+/// is paused, and a sink stops taking events from its buffer, until the next window opens.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ComponentScheduleOptions {
+    /// The windows during which the component runs.
+    ///
+    /// If empty, the component runs at all times, except during `maintenance` windows.
+    pub active: Vec<ScheduleWindow>,
+
+    /// The windows during which the component doesn't run, even during an `active` window.
+    pub maintenance: Vec<ScheduleWindow>,
+
+    /// The name of the time zone the windows are expressed in.
+    ///
+    /// This overrides the [global `timezone`][global_timezone] option. The time zone name may be
+    /// any name in the [TZ database][tz_database], or `local` to indicate system local time.
+    ///
+    /// [global_timezone]: https://vector.dev/docs/reference/configuration//global-options#timezone
+    /// [tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+    pub timezone: Option<TimeZone>,
+}
+
+/// A weekly window of time.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleWindow {
+    /// The days of the week the window opens on.
+    ///
+    /// If empty, the window opens every day.
+    #[serde(default)]
+    pub days: Vec<ScheduleDay>,
+
+    /// The time of day the window opens at, in the `HH:MM` format.
+    #[configurable(metadata(docs::examples = "22:00"))]
+    pub start: String,
+
+    /// The time of day the window closes at, in the `HH:MM` format.
+    ///
+    /// If it's earlier than `start`, the window closes on the next day.
+    #[configurable(metadata(docs::examples = "06:00"))]
+    pub end: String,
+}
+
+/// A day of the week.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ScheduleDay {
+    /// Monday.
+    Monday,
+
+    /// Tuesday.
+    Tuesday,
+
+    /// Wednesday.
+    Wednesday,
+
+    /// Thursday.
+    Thursday,
+
+    /// Friday.
+    Friday,
+
+    /// Saturday.
+    Saturday,
+
+    /// Sunday.
+    Sunday,
+}
+
+impl From<ScheduleDay> for Weekday {
+    fn from(day: ScheduleDay) -> Self {
+        match day {
+            ScheduleDay::Monday => Weekday::Mon,
+            ScheduleDay::Tuesday => Weekday::Tue,
+            ScheduleDay::Wednesday => Weekday::Wed,
+            ScheduleDay::Thursday => Weekday::Thu,
+            ScheduleDay::Friday => Weekday::Fri,
+            ScheduleDay::Saturday => Weekday::Sat,
+            ScheduleDay::Sunday => Weekday::Sun,
+        }
+    }
+}
+
+impl ComponentScheduleOptions {
+    /// Whether or not the component is restricted to windows.
+    pub fn is_enabled(&self) -> bool {
+        !self.active.is_empty() || !self.maintenance.is_empty()
+    }
+
+    /// Checks that the times of all the windows are valid.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let errors = self
+            .active
+            .iter()
+            .chain(&self.maintenance)
+            .flat_map(|window| [&window.start, &window.end])
+            .filter(|time| parse_time(time).is_none())
+            .map(|time| format!("invalid schedule time {:?}, expected `HH:MM`", time))
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl ScheduleWindow {
+    /// Whether or not the window is open at the given local time.
+    ///
+    /// The times of the window must have been validated.
+    pub(crate) fn contains(&self, time: NaiveDateTime) -> bool {
+        let start = parse_time(&self.start).expect("schedule times are validated");
+        let end = parse_time(&self.end).expect("schedule times are validated");
+        let time_of_day = time.time();
+
+        // Windows that close on the next day are open from `start` on the days of the window,
+        // and until `end` on the days after them.
+        let (day, open) = if start <= end {
+            (time.weekday(), start <= time_of_day && time_of_day < end)
+        } else if time_of_day >= start {
+            (time.weekday(), true)
+        } else {
+            (time.weekday().pred(), time_of_day < end)
+        };
+
+        open && (self.days.is_empty() || self.days.iter().any(|d| Weekday::from(*d) == day))
+    }
+}
+
+fn parse_time(time: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(time, "%H:%M").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn window(days: &[ScheduleDay], start: &str, end: &str) -> ScheduleWindow {
+        ScheduleWindow {
+            days: days.to_vec(),
+            start: start.to_owned(),
+            end: end.to_owned(),
+        }
+    }
+
+    // 2023-05-01 is a Monday.
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2023, 5, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 30)
+            .unwrap()
+    }
+
+    #[test]
+    fn window_within_day() {
+        let window = window(&[], "09:00", "17:30");
+
+        assert!(!window.contains(at(1, 8, 59)));
+        assert!(window.contains(at(1, 9, 0)));
+        assert!(window.contains(at(3, 17, 29)));
+        assert!(!window.contains(at(3, 17, 30)));
+    }
+
+    #[test]
+    fn window_across_midnight() {
+        let window = window(&[ScheduleDay::Friday], "22:00", "06:00");
+
+        assert!(!window.contains(at(4, 23, 0)));
+        assert!(window.contains(at(5, 22, 0)));
+        assert!(window.contains(at(6, 5, 59)));
+        assert!(!window.contains(at(6, 6, 0)));
+        assert!(!window.contains(at(6, 22, 0)));
+    }
+
+    #[test]
+    fn validates_times() {
+        let options = ComponentScheduleOptions {
+            active: vec![window(&[], "9am", "17:00")],
+            maintenance: vec![window(&[], "01:00", "25:00")],
+            timezone: None,
+        };
+
+        assert_eq!(
+            options.validate().unwrap_err(),
+            vec![
+                r#"invalid schedule time "9am", expected `HH:MM`"#.to_owned(),
+                r#"invalid schedule time "25:00", expected `HH:MM`"#.to_owned(),
+            ]
+        );
+    }
+}
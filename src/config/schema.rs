@@ -1,3 +1,5 @@
+use std::num::NonZeroU64;
+
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
 
@@ -16,6 +18,15 @@ pub struct Options {
     #[serde(default = "default_validation")]
     pub validation: bool,
 
+    /// The rate at which the log events output by transforms are validated against the schema
+    /// definition of their output, when schema is enabled.
+    ///
+    /// One out of every `runtime_validation_sample_rate` events is validated, and the events that
+    /// don't match the definition are counted in the `component_schema_violations_total` metric.
+    /// Set to `0` to disable runtime validation.
+    #[serde(default = "default_runtime_validation_sample_rate")]
+    pub runtime_validation_sample_rate: u64,
+
     /// Whether or not to enable log namespacing.
     pub log_namespace: Option<bool>,
 }
@@ -47,6 +58,17 @@ impl Options {
         // If either config enables these flags, it is enabled.
         self.enabled |= with.enabled;
         self.validation |= with.validation;
+
+        if with.runtime_validation_sample_rate != default_runtime_validation_sample_rate() {
+            self.runtime_validation_sample_rate = with.runtime_validation_sample_rate;
+        }
+    }
+
+    /// Gets the rate at which events output by transforms are validated at runtime, if enabled.
+    pub fn runtime_validation_sample_rate(self) -> Option<NonZeroU64> {
+        self.enabled
+            .then_some(self.runtime_validation_sample_rate)
+            .and_then(NonZeroU64::new)
     }
 }
 
@@ -55,6 +77,7 @@ impl Default for Options {
         Self {
             enabled: default_enabled(),
             validation: default_validation(),
+            runtime_validation_sample_rate: default_runtime_validation_sample_rate(),
             log_namespace: None,
         }
     }
@@ -68,6 +91,10 @@ const fn default_validation() -> bool {
     false
 }
 
+const fn default_runtime_validation_sample_rate() -> u64 {
+    100
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -80,16 +107,19 @@ mod test {
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 },
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: Some(true),
                 },
                 Some(Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: Some(true),
                 }),
             ),
@@ -98,11 +128,13 @@ mod test {
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: Some(false),
                 },
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: Some(true),
                 },
                 None,
@@ -112,16 +144,19 @@ mod test {
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 },
                 Options {
                     enabled: true,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 },
                 Some(Options {
                     enabled: true,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 }),
             ),
@@ -130,16 +165,40 @@ mod test {
                 Options {
                     enabled: false,
                     validation: false,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 },
                 Options {
                     enabled: false,
                     validation: true,
+                    runtime_validation_sample_rate: 100,
                     log_namespace: None,
                 },
                 Some(Options {
                     enabled: false,
                     validation: true,
+                    runtime_validation_sample_rate: 100,
+                    log_namespace: None,
+                }),
+            ),
+            (
+                "set runtime validation sample rate",
+                Options {
+                    enabled: true,
+                    validation: false,
+                    runtime_validation_sample_rate: 100,
+                    log_namespace: None,
+                },
+                Options {
+                    enabled: false,
+                    validation: false,
+                    runtime_validation_sample_rate: 1,
+                    log_namespace: None,
+                },
+                Some(Options {
+                    enabled: true,
+                    validation: false,
+                    runtime_validation_sample_rate: 1,
                     log_namespace: None,
                 }),
             ),
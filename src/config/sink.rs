@@ -1,5 +1,8 @@
+use std::path::PathBuf;
+
 use async_trait::async_trait;
 use enum_dispatch::enum_dispatch;
+use lookup::lookup_v2::ConfigValuePath;
 use serde::Serialize;
 use vector_buffers::{BufferConfig, BufferType};
 use vector_config::{configurable_component, Configurable, NamedComponent};
@@ -8,8 +11,11 @@ use vector_core::{
     sink::VectorSink,
 };
 
-use super::{id::Inputs, schema, ComponentKey, ProxyConfig, Resource};
-use crate::sinks::{util::UriSerde, Healthcheck, Sinks};
+use super::{id::Inputs, schema, ComponentKey, ComponentScheduleOptions, ProxyConfig, Resource};
+use crate::{
+    http::HttpClientOptions,
+    sinks::{util::UriSerde, Healthcheck, Sinks},
+};
 
 /// Fully resolved sink component.
 #[configurable_component]
@@ -47,6 +53,34 @@ where
     )]
     proxy: ProxyConfig,
 
+    #[configurable(derived, metadata(docs::advanced))]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    http_client: HttpClientOptions,
+
+    #[configurable(derived, metadata(docs::advanced))]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub delivery_failures: SinkDeliveryFailuresOptions,
+
+    #[configurable(derived, metadata(docs::advanced))]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub dead_letter: SinkDeadLetterOptions,
+
+    #[configurable(derived, metadata(docs::advanced))]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub schedule: ComponentScheduleOptions,
+
     #[serde(flatten)]
     #[configurable(metadata(docs::hidden))]
     pub inner: Sinks,
@@ -68,6 +102,10 @@ where
             healthcheck_uri: None,
             inner: inner.into(),
             proxy: Default::default(),
+            http_client: Default::default(),
+            delivery_failures: Default::default(),
+            dead_letter: Default::default(),
+            schedule: Default::default(),
         }
     }
 
@@ -104,6 +142,10 @@ where
         &self.proxy
     }
 
+    pub const fn http_client(&self) -> &HttpClientOptions {
+        &self.http_client
+    }
+
     pub(super) fn map_inputs<U>(self, f: impl Fn(&T) -> U) -> SinkOuter<U>
     where
         U: Configurable + Serialize,
@@ -124,6 +166,10 @@ where
             healthcheck: self.healthcheck,
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
+            http_client: self.http_client,
+            delivery_failures: self.delivery_failures,
+            dead_letter: self.dead_letter,
+            schedule: self.schedule,
         }
     }
 }
@@ -159,6 +205,54 @@ impl From<bool> for SinkHealthcheckOptions {
     }
 }
 
+/// Delivery failure reporting configuration.
+///
+/// When enabled, the events that the sink fails to deliver are reported to the
+/// `delivery_failures` sources, which emit an event for each failed batch.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SinkDeliveryFailuresOptions {
+    /// Whether or not to report the events that the sink fails to deliver.
+    pub enabled: bool,
+
+    /// The fields of log and trace events to include in the reports.
+    ///
+    /// Metric events are reported with their name, namespace, and tags.
+    #[configurable(metadata(docs::examples = "message_id"))]
+    #[configurable(metadata(docs::examples = "order.id"))]
+    pub key_fields: Vec<ConfigValuePath>,
+}
+
+/// The name of the output sinks send the events they fail to deliver to.
+pub const DEAD_LETTER_OUTPUT: &str = "dead_letter";
+
+/// Dead-letter configuration.
+///
+/// When enabled, the events that the sink fails to deliver are sent to its `dead_letter` output,
+/// which other components can consume by using `<sink_id>.dead_letter` as an input. Alternatively,
+/// or additionally, they can be written to a local file, and replayed later with `vector replay`.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SinkDeadLetterOptions {
+    /// Whether or not to send the events that the sink fails to deliver to its `dead_letter`
+    /// output.
+    ///
+    /// Events are only sent once the sink has given up on delivering them, such as after
+    /// exhausting its retries. The sent events don't carry acknowledgements, so sources are still
+    /// notified of the failed delivery.
+    pub enabled: bool,
+
+    /// The file to append the events that the sink fails to deliver to.
+    ///
+    /// Each line holds one event, in Vector's native JSON representation, along with the ID of
+    /// the sink and the time and status of the failure. The events can be sent to the sink again
+    /// with `vector replay`.
+    #[configurable(metadata(docs::examples = "/var/lib/vector/dead_letter/http_out.ndjson"))]
+    pub path: Option<PathBuf>,
+}
+
 impl From<UriSerde> for SinkHealthcheckOptions {
     fn from(uri: UriSerde) -> Self {
         Self {
@@ -206,6 +300,7 @@ pub struct SinkContext {
     pub healthcheck: SinkHealthcheckOptions,
     pub globals: GlobalOptions,
     pub proxy: ProxyConfig,
+    pub http_client: HttpClientOptions,
     pub schema: schema::Options,
 }
 
@@ -216,6 +311,7 @@ impl SinkContext {
             healthcheck: SinkHealthcheckOptions::default(),
             globals: GlobalOptions::default(),
             proxy: ProxyConfig::default(),
+            http_client: HttpClientOptions::default(),
             schema: schema::Options::default(),
         }
     }
@@ -227,4 +323,8 @@ impl SinkContext {
     pub const fn proxy(&self) -> &ProxyConfig {
         &self.proxy
     }
+
+    pub const fn http_client(&self) -> &HttpClientOptions {
+        &self.http_client
+    }
 }
@@ -16,7 +16,7 @@ use vector_core::{
     source::Source,
 };
 
-use super::{schema, ComponentKey, ProxyConfig, Resource};
+use super::{schema, ComponentKey, ComponentScheduleOptions, ProxyConfig, Resource};
 use crate::{shutdown::ShutdownSignal, SourceSender};
 
 pub type BoxedSource = Box<dyn SourceConfig>;
@@ -57,6 +57,13 @@ pub struct SourceOuter {
     )]
     pub proxy: ProxyConfig,
 
+    #[configurable(derived, metadata(docs::advanced))]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub schedule: ComponentScheduleOptions,
+
     #[serde(default, skip)]
     pub sink_acknowledgements: bool,
 
@@ -69,6 +76,7 @@ impl SourceOuter {
     pub(crate) fn new<I: Into<BoxedSource>>(inner: I) -> Self {
         Self {
             proxy: Default::default(),
+            schedule: Default::default(),
             sink_acknowledgements: false,
             inner: inner.into(),
         }
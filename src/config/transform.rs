@@ -57,6 +57,15 @@ where
     #[configurable(derived)]
     pub inputs: Inputs<T>,
 
+    /// The name of a feature flag gating this transform.
+    ///
+    /// While the flag is disabled, events pass through the transform unchanged, to its default
+    /// output. Requires `feature_flags` to be configured.
+    #[configurable(metadata(docs::advanced))]
+    #[configurable(metadata(docs::examples = "new_parser"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled_when: Option<String>,
+
     #[configurable(metadata(docs::hidden))]
     #[serde(flatten)]
     pub inner: BoxedTransform,
@@ -73,7 +82,11 @@ where
     {
         let inputs = Inputs::from_iter(inputs);
         let inner = inner.into();
-        TransformOuter { inputs, inner }
+        TransformOuter {
+            inputs,
+            enabled_when: None,
+            inner,
+        }
     }
 
     pub(super) fn map_inputs<U>(self, f: impl Fn(&T) -> U) -> TransformOuter<U>
@@ -91,6 +104,7 @@ where
     {
         TransformOuter {
             inputs: Inputs::from_iter(inputs),
+            enabled_when: self.enabled_when,
             inner: self.inner,
         }
     }
@@ -1,13 +1,13 @@
-use crate::config::schema;
+use crate::{config::schema, data_dir};
 use futures_util::{stream, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use heim::{disk::Partition, units::information::byte};
 use indexmap::IndexMap;
 use std::{collections::HashMap, path::PathBuf};
-use vector_core::internal_event::DEFAULT_OUTPUT;
+use vector_core::{buffers::BufferType, internal_event::DEFAULT_OUTPUT};
 
 use super::{
-    builder::ConfigBuilder, transform::get_transform_output_ids, ComponentKey, Config, OutputId,
-    Resource,
+    builder::ConfigBuilder, transform::get_transform_output_ids, ComponentKey, Config,
+    DataDirSubsystem, OutputId, Resource, DEAD_LETTER_OUTPUT,
 };
 
 /// Check that provide + topology config aren't present in the same builder, which is an error.
@@ -192,6 +192,157 @@ pub fn check_outputs(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     }
 }
 
+/// Check that the windows of the components with a `schedule` are valid.
+pub fn check_schedules(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let schedules = config
+        .sources
+        .iter()
+        .map(|(key, source)| ("Source", key, &source.schedule))
+        .chain(
+            config
+                .sinks
+                .iter()
+                .map(|(key, sink)| ("Sink", key, &sink.schedule)),
+        );
+
+    let errors = schedules
+        .filter_map(|(kind, key, schedule)| {
+            schedule.validate().err().map(|errors| {
+                errors
+                    .into_iter()
+                    .map(move |error| format!("{kind} \"{key}\": {error}"))
+            })
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check that the stages of the buffers of sinks are arranged in a valid way.
+pub fn check_buffers(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let errors = config
+        .sinks
+        .iter()
+        .filter_map(|(key, sink)| {
+            sink.buffer
+                .validate()
+                .err()
+                .map(|error| format!("Sink \"{key}\": buffer {error}"))
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check that the transforms gated by a feature flag can get its state from a provider.
+pub fn check_feature_flags(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    if let Some(feature_flags) = &config.feature_flags {
+        if feature_flags.poll_interval_secs == 0 {
+            errors.push("Feature flags: `poll_interval_secs` must be greater than zero".to_owned());
+        }
+    }
+
+    for (key, transform) in &config.transforms {
+        match transform.enabled_when.as_deref() {
+            None => {}
+            Some("") => errors.push(format!(
+                "Transform \"{key}\": `enabled_when` must be the name of a feature flag"
+            )),
+            Some(_) if config.feature_flags.is_none() => errors.push(format!(
+                "Transform \"{key}\": `enabled_when` requires `feature_flags` to be configured"
+            )),
+            Some(_) => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check that every directory Vector will persist state in exists and is writable.
+///
+/// Directories explicitly configured in `state_dirs` are always checked, as is the buffer
+/// directory when any sink uses a disk buffer. This surfaces problems such as a read-only root
+/// filesystem before any component is built, rather than as a component build failure.
+pub fn check_data_dirs(config: &Config) -> Result<(), Vec<String>> {
+    let uses_disk_buffers = config.sinks().any(|(_, sink)| {
+        sink.buffer
+            .stages()
+            .iter()
+            .any(|stage| matches!(stage, BufferType::DiskV2 { .. }))
+    });
+
+    let errors = DataDirSubsystem::ALL
+        .into_iter()
+        .filter(|subsystem| {
+            config.global.state_dirs.get(*subsystem).is_some()
+                || (*subsystem == DataDirSubsystem::Buffers && uses_disk_buffers)
+        })
+        .filter_map(|subsystem| {
+            config
+                .global
+                .resolve_and_validate_data_dir_for(subsystem, None)
+                .err()
+                .map(|error| error.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check that the state Vector persists can't grow past `data_dir_quota`.
+///
+/// Disk buffers are accounted for at their configured `max_size`, as they may grow to it at any
+/// time, while checkpoints and cached enrichment table data are accounted for at their current size.
+pub fn check_data_dir_quota(config: &Config) -> Result<(), Vec<String>> {
+    let quota = match config.global.data_dir_quota {
+        Some(quota) => quota.get(),
+        None => return Ok(()),
+    };
+
+    let global_data_dir = config.global.data_dir_for(DataDirSubsystem::Buffers);
+    let buffers: u64 = config
+        .sinks()
+        .flat_map(|(id, sink)| {
+            sink.buffer
+                .stages()
+                .iter()
+                .filter_map(|stage| stage.disk_usage(global_data_dir.clone(), id))
+                .map(|usage| usage.max_size())
+        })
+        .sum();
+    let usage = data_dir::Usage::measure(&config.global);
+    let total = usage.checkpoints + usage.enrichment_cache + buffers;
+
+    if total > quota {
+        Err(vec![format!(
+            "Persisted state may grow to {} bytes ({} bytes of disk buffers, {} bytes of checkpoints and {} bytes of cached enrichment data), \
+exceeding `data_dir_quota` of {} bytes. Reduce the `max_size` of disk buffers or raise `data_dir_quota`.",
+            total, buffers, usage.checkpoints, usage.enrichment_cache, quota,
+        )])
+    } else {
+        Ok(())
+    }
+}
+
 pub async fn check_buffer_preconditions(config: &Config) -> Result<(), Vec<String>> {
     // We need to assert that Vector's data directory is located on a mountpoint that has enough
     // capacity to allow all sinks with disk buffers configured to be able to use up to their
@@ -205,7 +356,7 @@ pub async fn check_buffer_preconditions(config: &Config) -> Result<(), Vec<Strin
     // don't always know the upper bound of that usage i.e. file checkpoint state.
 
     // Grab all configured disk buffers, and if none are present, simply return early.
-    let global_data_dir = config.global.data_dir.clone();
+    let global_data_dir = config.global.data_dir_for(DataDirSubsystem::Buffers);
     let configured_disk_buffers = config
         .sinks()
         .flat_map(|(id, sink)| {
@@ -349,8 +500,13 @@ pub fn warnings(config: &Config) -> Vec<String> {
         .map(|output| ("transform", output))
         .collect::<Vec<_>>()
     });
+    let sink_ids = config
+        .sinks
+        .iter()
+        .filter(|(_, sink)| sink.dead_letter.enabled)
+        .map(|(key, _)| ("sink", OutputId::from((key, DEAD_LETTER_OUTPUT.to_owned()))));
 
-    for (input_type, id) in transform_ids.chain(source_ids) {
+    for (input_type, id) in transform_ids.chain(source_ids).chain(sink_ids) {
         if !config
             .transforms
             .iter()
@@ -0,0 +1,278 @@
+//! Accounts for the state Vector persists in its data directories.
+//!
+//! Each [`DataDirSubsystem`] persists its state either in its own directory, when overridden in
+//! `state_dirs`, or within the shared `data_dir`. Within the shared directory, disk buffers live in
+//! `buffer`, cached enrichment table data lives in `geoip`, and everything else is attributed to
+//! source checkpoints, which live in per-component subdirectories.
+use std::{
+    fs, io,
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use snafu::Snafu;
+use tokio::time::interval;
+
+use crate::{
+    config::{DataDirSubsystem, GlobalOptions},
+    internal_events::{DataDirQuotaExceeded, DataDirUsage},
+};
+
+/// How often usage of the data directories is measured.
+const ACCOUNTING_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Snafu)]
+pub enum QuotaError {
+    #[snafu(display(
+        "Writing {} bytes to {:?} would grow persisted state to {} bytes, exceeding `data_dir_quota` of {} bytes",
+        size,
+        path,
+        total,
+        quota
+    ))]
+    WouldExceed {
+        path: PathBuf,
+        size: u64,
+        total: u64,
+        quota: u64,
+    },
+}
+
+/// The number of bytes used by each subsystem.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    pub checkpoints: u64,
+    pub buffers: u64,
+    pub enrichment_cache: u64,
+}
+
+impl Usage {
+    /// Measures the number of bytes currently used by each subsystem.
+    pub fn measure(globals: &GlobalOptions) -> Self {
+        let mut usage = Self::default();
+        let overrides = DataDirSubsystem::ALL
+            .into_iter()
+            .filter_map(|subsystem| {
+                globals
+                    .state_dirs
+                    .get(subsystem)
+                    .map(|dir| (subsystem, dir.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut counted: Vec<&PathBuf> = Vec::new();
+        for (subsystem, dir) in &overrides {
+            if !counted.contains(&dir) {
+                *usage.get_mut(*subsystem) += directory_size(dir);
+                counted.push(dir);
+            }
+        }
+
+        if let Some(data_dir) = globals
+            .data_dir
+            .as_ref()
+            .filter(|dir| !counted.contains(dir))
+        {
+            let entries = match fs::read_dir(data_dir) {
+                Ok(entries) => entries,
+                Err(_) => return usage,
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if counted.contains(&&path) {
+                    continue;
+                }
+                let subsystem = match entry.file_name().to_str() {
+                    Some("buffer") => DataDirSubsystem::Buffers,
+                    Some("geoip") => DataDirSubsystem::EnrichmentCache,
+                    _ => DataDirSubsystem::Checkpoints,
+                };
+                *usage.get_mut(subsystem) += directory_size(&path);
+            }
+        }
+
+        usage
+    }
+
+    /// Gets the number of bytes used by the given subsystem.
+    pub const fn get(&self, subsystem: DataDirSubsystem) -> u64 {
+        match subsystem {
+            DataDirSubsystem::Checkpoints => self.checkpoints,
+            DataDirSubsystem::Buffers => self.buffers,
+            DataDirSubsystem::EnrichmentCache => self.enrichment_cache,
+        }
+    }
+
+    fn get_mut(&mut self, subsystem: DataDirSubsystem) -> &mut u64 {
+        match subsystem {
+            DataDirSubsystem::Checkpoints => &mut self.checkpoints,
+            DataDirSubsystem::Buffers => &mut self.buffers,
+            DataDirSubsystem::EnrichmentCache => &mut self.enrichment_cache,
+        }
+    }
+
+    /// The number of bytes used by all subsystems combined.
+    pub const fn total(&self) -> u64 {
+        self.checkpoints + self.buffers + self.enrichment_cache
+    }
+}
+
+/// Enforces `data_dir_quota` for writes of known size.
+#[derive(Clone, Debug)]
+pub struct Quota {
+    globals: GlobalOptions,
+    limit: NonZeroU64,
+}
+
+impl Quota {
+    /// Creates a quota from the global options, or `None` if no quota is configured.
+    pub fn new(globals: &GlobalOptions) -> Option<Self> {
+        globals.data_dir_quota.map(|limit| Self {
+            globals: globals.clone(),
+            limit,
+        })
+    }
+
+    /// Checks that replacing the file at `path` with one of `size` bytes keeps the persisted state
+    /// within the quota.
+    pub fn check(&self, path: &Path, size: u64) -> Result<(), QuotaError> {
+        let existing = fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .unwrap_or(0);
+        let total = Usage::measure(&self.globals)
+            .total()
+            .saturating_sub(existing)
+            .saturating_add(size);
+        if total > self.limit.get() {
+            return Err(QuotaError::WouldExceed {
+                path: path.to_path_buf(),
+                size,
+                total,
+                quota: self.limit.get(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Periodically measures and reports usage of the data directories.
+pub async fn account(globals: GlobalOptions) {
+    let mut interval = interval(ACCOUNTING_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        let measured = globals.clone();
+        let usage = match tokio::task::spawn_blocking(move || Usage::measure(&measured)).await {
+            Ok(usage) => usage,
+            Err(_) => continue,
+        };
+
+        for subsystem in DataDirSubsystem::ALL {
+            emit!(DataDirUsage {
+                subsystem,
+                used_bytes: usage.get(subsystem),
+                quota_bytes: globals.data_dir_quota.map(NonZeroU64::get),
+            });
+        }
+        if let Some(quota) = globals.data_dir_quota {
+            if usage.total() > quota.get() {
+                emit!(DataDirQuotaExceeded {
+                    used_bytes: usage.total(),
+                    quota_bytes: quota.get(),
+                });
+            }
+        }
+    }
+}
+
+/// Gets the total size of all files under `path`, without following symlinks.
+///
+/// Files that disappear or can't be read while walking are not counted.
+pub fn directory_size(path: &Path) -> u64 {
+    fn walk(path: &Path) -> io::Result<u64> {
+        let metadata = fs::symlink_metadata(path)?;
+        if !metadata.is_dir() {
+            return Ok(metadata.len());
+        }
+        Ok(fs::read_dir(path)?
+            .flatten()
+            .map(|entry| walk(&entry.path()).unwrap_or(0))
+            .sum())
+    }
+
+    walk(path).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, size: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![0; size]).unwrap();
+    }
+
+    #[test]
+    fn attributes_shared_data_dir_to_subsystems() {
+        let data_dir = tempfile::tempdir().unwrap();
+        write(&data_dir.path().join("buffer/v2/sink/buffer.db"), 100);
+        write(&data_dir.path().join("geoip/GeoLite2-City.mmdb"), 20);
+        write(&data_dir.path().join("in/checkpoints.json"), 3);
+
+        let globals = GlobalOptions {
+            data_dir: Some(data_dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        let usage = Usage::measure(&globals);
+
+        assert_eq!(
+            usage,
+            Usage {
+                checkpoints: 3,
+                buffers: 100,
+                enrichment_cache: 20,
+            }
+        );
+        assert_eq!(usage.total(), 123);
+    }
+
+    #[test]
+    fn counts_overridden_dirs_once() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let buffers = data_dir.path().join("mnt");
+        write(&buffers.join("buffer/v2/sink/buffer.db"), 100);
+        write(&data_dir.path().join("in/checkpoints.json"), 3);
+
+        let mut globals = GlobalOptions {
+            data_dir: Some(data_dir.path().to_path_buf()),
+            ..Default::default()
+        };
+        globals.state_dirs.buffers = Some(buffers);
+        let usage = Usage::measure(&globals);
+
+        assert_eq!(usage.buffers, 100);
+        assert_eq!(usage.checkpoints, 3);
+    }
+
+    #[test]
+    fn quota_accounts_for_replaced_file() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let path = data_dir.path().join("geoip/GeoLite2-City.mmdb");
+        write(&path, 60);
+        write(&data_dir.path().join("in/checkpoints.json"), 30);
+
+        let quota = Quota::new(&GlobalOptions {
+            data_dir: Some(data_dir.path().to_path_buf()),
+            data_dir_quota: NonZeroU64::new(100),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(quota.check(&path, 70).is_ok());
+        assert!(matches!(
+            quota.check(&path, 71),
+            Err(QuotaError::WouldExceed { total: 101, .. })
+        ));
+    }
+}
@@ -4,17 +4,21 @@ use std::{
     fs,
     hash::Hasher,
     path::PathBuf,
-    time::SystemTime,
+    sync::{Arc, RwLock, Weak},
+    time::{Duration, SystemTime},
 };
 
 use bytes::Bytes;
 use enrichment::{Case, Condition, IndexHandle, Table};
-use tracing::trace;
+use tracing::{trace, Instrument};
 use value::Value;
 use vector_common::{conversion::Conversion, TimeZone};
 use vector_config::configurable_component;
 
-use crate::config::EnrichmentTableConfig;
+use crate::{
+    config::EnrichmentTableConfig,
+    internal_events::{EnrichmentTableRefreshError, EnrichmentTableRefreshed, RefreshedTable},
+};
 
 /// File encoding configuration.
 #[configurable_component]
@@ -64,6 +68,37 @@ struct FileSettings {
     encoding: Encoding,
 }
 
+/// Settings for reloading the enrichment table file when it is modified.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ReloadSettings {
+    /// Whether or not to reload the file when it is modified.
+    ///
+    /// When enabled, the file is re-parsed whenever its modification time changes, and the new
+    /// data is swapped in atomically, without requiring a configuration reload. If the modified
+    /// file can't be parsed, the previously loaded data continues to be used.
+    #[serde(default)]
+    enabled: bool,
+
+    /// The interval between checks for modifications to the file, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    poll_interval_secs: u64,
+}
+
+const fn default_poll_interval_secs() -> u64 {
+    30
+}
+
+impl Default for ReloadSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_interval_secs: default_poll_interval_secs(),
+        }
+    }
+}
+
 /// Configuration for the `file` enrichment table.
 #[configurable_component(enrichment_table("file"))]
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -116,6 +151,10 @@ pub struct FileConfig {
     /// [chrono_fmt]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers
     #[serde(default)]
     schema: HashMap<String, String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    reload: ReloadSettings,
 }
 
 const fn default_delimiter() -> char {
@@ -242,9 +281,162 @@ impl EnrichmentTableConfig for FileConfig {
         &self,
         globals: &crate::config::GlobalOptions,
     ) -> crate::Result<Box<dyn Table + Send + Sync>> {
-        let (headers, data, modified) = self.load_file(globals.timezone())?;
+        let timezone = globals.timezone();
+        let (headers, data, modified) = self.load_file(timezone)?;
+        let file = File::new(self.clone(), modified, data, headers);
+
+        if !self.reload.enabled {
+            return Ok(Box::new(file));
+        }
+
+        let table = ReloadableFile {
+            file: Arc::new(RwLock::new(Arc::new(file))),
+        };
+
+        let span = info_span!("file: reloader", path = ?self.file.path);
+        let reloader = Reloader {
+            config: self.clone(),
+            timezone,
+            file: Arc::downgrade(&table.file),
+            interval: Duration::from_secs(self.reload.poll_interval_secs.max(1)),
+        };
+        tokio::spawn(reloader.run().instrument(span.or_current()));
+
+        Ok(Box::new(table))
+    }
+}
+
+/// Periodically reloads the file shared by all clones of a [`ReloadableFile`] table when it is
+/// modified.
+///
+/// Runs until every clone of the table has been dropped.
+#[derive(Clone)]
+struct Reloader {
+    config: FileConfig,
+    timezone: TimeZone,
+    file: Weak<RwLock<Arc<File>>>,
+    interval: Duration,
+}
+
+impl Reloader {
+    async fn run(self) {
+        loop {
+            tokio::time::sleep(self.interval).await;
+
+            let file = match self.file.upgrade() {
+                Some(file) => file,
+                None => break,
+            };
+
+            // Parsing a large file can take a while, so keep it off the async runtime.
+            let reloader = self.clone();
+            let result = tokio::task::spawn_blocking(move || reloader.reload(&file))
+                .await
+                .unwrap_or_else(|error| Err(error.into()));
+
+            let path = self.config.file.path.to_string_lossy();
+            match result {
+                Ok(true) => emit!(EnrichmentTableRefreshed {
+                    table: RefreshedTable::File,
+                    path: &path,
+                }),
+                Ok(false) => {}
+                Err(error) => emit!(EnrichmentTableRefreshError {
+                    table: RefreshedTable::File,
+                    path: &path,
+                    error,
+                }),
+            }
+        }
+    }
+
+    /// Returns `true` if the file was reloaded.
+    fn reload(&self, file: &RwLock<Arc<File>>) -> crate::Result<bool> {
+        let current = Arc::clone(&*file.read().expect("lock poisoned"));
+        let modified = fs::metadata(&self.config.file.path)?.modified()?;
+        if modified <= current.last_modified {
+            return Ok(false);
+        }
+
+        let (headers, data, modified) = self.config.load_file(self.timezone)?;
+        let mut reloaded = File::new(self.config.clone(), modified, data, headers);
+
+        // Rebuild the indexes in the same order, so that the index handles held by transforms
+        // remain valid.
+        for (case, fields) in current.index_fields() {
+            let fields = fields.iter().map(String::as_str).collect::<Vec<_>>();
+            reloaded.add_index(case, &fields)?;
+        }
+
+        let mut file = file.write().expect("lock poisoned");
+        if !Arc::ptr_eq(&*file, &current) {
+            // An index was added while the file was being reloaded, so try again on the next
+            // poll.
+            return Ok(false);
+        }
+        // The previous data is still referenced by `current`, so it's freed after the lock is
+        // released.
+        *file = Arc::new(reloaded);
+
+        Ok(true)
+    }
+}
+
+/// A [File] table that is reloaded in the background when its file is modified.
+///
+/// Lookups use the most recently loaded data.
+#[derive(Clone)]
+pub struct ReloadableFile {
+    file: Arc<RwLock<Arc<File>>>,
+}
+
+impl ReloadableFile {
+    fn current(&self) -> Arc<File> {
+        Arc::clone(&*self.file.read().expect("lock poisoned"))
+    }
+}
+
+impl Table for ReloadableFile {
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&'a [String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<BTreeMap<String, Value>, String> {
+        self.current()
+            .find_table_row(case, condition, select, index)
+    }
+
+    fn find_table_rows<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&'a [String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<Vec<BTreeMap<String, Value>>, String> {
+        self.current()
+            .find_table_rows(case, condition, select, index)
+    }
+
+    fn add_index(&mut self, case: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        let mut file = self.file.write().expect("lock poisoned");
+        // Cloning a `File` is cheap, as the data and indexes are shared.
+        Arc::make_mut(&mut *file).add_index(case, fields)
+    }
+
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        self.current().index_fields()
+    }
+
+    fn needs_reload(&self) -> bool {
+        self.current().needs_reload()
+    }
+}
 
-        Ok(Box::new(File::new(self.clone(), modified, data, headers)))
+impl std::fmt::Debug for ReloadableFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Reloadable{:?}", self.current())
     }
 }
 
@@ -255,12 +447,12 @@ impl_generate_config_from_default!(FileConfig);
 pub struct File {
     config: FileConfig,
     last_modified: SystemTime,
-    data: Vec<Vec<Value>>,
+    data: Arc<Vec<Vec<Value>>>,
     headers: Vec<String>,
     indexes: Vec<(
         Case,
         Vec<usize>,
-        HashMap<u64, Vec<usize>, hash_hasher::HashBuildHasher>,
+        Arc<HashMap<u64, Vec<usize>, hash_hasher::HashBuildHasher>>,
     )>,
 }
 
@@ -275,7 +467,7 @@ impl File {
         Self {
             config,
             last_modified,
-            data,
+            data: Arc::new(data),
             headers,
             indexes: Vec::new(),
         }
@@ -546,7 +738,7 @@ impl Table for File {
             }
             None => {
                 let index = self.index_data(&normalized, case)?;
-                self.indexes.push((case, normalized, index));
+                self.indexes.push((case, normalized, Arc::new(index)));
                 // The returned index handle is the position of the index in our list of indexes.
                 Ok(IndexHandle(self.indexes.len() - 1))
             }
@@ -608,6 +800,7 @@ mod tests {
         let config = FileConfig {
             file: Default::default(),
             schema,
+            reload: Default::default(),
         };
 
         assert_eq!(
@@ -1060,4 +1253,111 @@ mod tests {
             file.find_table_row(Case::Sensitive, &[condition], None, Some(handle))
         );
     }
+
+    fn reloadable_file(path: &std::path::Path) -> (ReloadableFile, Reloader) {
+        let config = FileConfig {
+            file: FileSettings {
+                path: path.to_path_buf(),
+                encoding: Encoding::default(),
+            },
+            schema: HashMap::new(),
+            reload: ReloadSettings {
+                enabled: true,
+                poll_interval_secs: 1,
+            },
+        };
+        let (headers, data, modified) = config
+            .load_file(vector_common::TimeZone::default())
+            .unwrap();
+        let table = ReloadableFile {
+            file: Arc::new(RwLock::new(Arc::new(File::new(
+                config.clone(),
+                modified,
+                data,
+                headers,
+            )))),
+        };
+        let reloader = Reloader {
+            config,
+            timezone: vector_common::TimeZone::default(),
+            file: Arc::downgrade(&table.file),
+            interval: Duration::from_secs(1),
+        };
+        (table, reloader)
+    }
+
+    #[test]
+    fn reloads_modified_file() {
+        let path = crate::test_util::temp_file();
+        fs::write(&path, "field1,field2\nzip,zup\n").unwrap();
+
+        let (mut table, reloader) = reloadable_file(&path);
+        let handle = table.add_index(Case::Sensitive, &["field1"]).unwrap();
+
+        // Unmodified files are left alone.
+        assert!(!table.needs_reload());
+        assert!(!reloader.reload(&table.file).unwrap());
+
+        fs::write(&path, "field1,field2\nzip,zap\n").unwrap();
+        assert!(table.needs_reload());
+        assert!(reloader.reload(&table.file).unwrap());
+        assert!(!table.needs_reload());
+
+        let condition = Condition::Equals {
+            field: "field1",
+            value: Value::from("zip"),
+        };
+
+        // Indexes are rebuilt for the new data, and existing handles remain valid.
+        assert_eq!(
+            vec![(Case::Sensitive, vec!["field1".to_string()])],
+            table.index_fields()
+        );
+        assert_eq!(
+            Ok(BTreeMap::from([
+                (String::from("field1"), Value::from("zip")),
+                (String::from("field2"), Value::from("zap")),
+            ])),
+            table.find_table_row(Case::Sensitive, &[condition], None, Some(handle))
+        );
+    }
+
+    #[test]
+    fn keeps_previous_data_when_reload_fails() {
+        let path = crate::test_util::temp_file();
+        fs::write(&path, "field1,field2\nzip,zup\n").unwrap();
+
+        let (mut table, reloader) = reloadable_file(&path);
+        table.add_index(Case::Sensitive, &["field1"]).unwrap();
+
+        // The indexed column no longer exists.
+        fs::write(&path, "field3,field2\nzip,zap\n").unwrap();
+        assert!(reloader.reload(&table.file).is_err());
+
+        let condition = Condition::Equals {
+            field: "field1",
+            value: Value::from("zip"),
+        };
+        assert_eq!(
+            Ok(BTreeMap::from([
+                (String::from("field1"), Value::from("zip")),
+                (String::from("field2"), Value::from("zup")),
+            ])),
+            table.find_table_row(Case::Sensitive, &[condition], None, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn reloader_stops_when_table_dropped() {
+        let path = crate::test_util::temp_file();
+        fs::write(&path, "field1,field2\nzip,zup\n").unwrap();
+
+        let (table, mut reloader) = reloadable_file(&path);
+        reloader.interval = Duration::from_millis(10);
+        drop(table);
+
+        tokio::time::timeout(Duration::from_secs(5), reloader.run())
+            .await
+            .expect("reloader should stop once the table is dropped");
+    }
 }
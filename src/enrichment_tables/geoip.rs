@@ -4,17 +4,38 @@
 //!
 //! [maxmind]: https://dev.maxmind.com/geoip/geoip2/downloadable
 //! [geolite]: https://dev.maxmind.com/geoip/geoip2/geolite2/#Download_Access
-use std::{collections::BTreeMap, fs, net::IpAddr, sync::Arc, time::SystemTime};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::Read,
+    net::IpAddr,
+    path::Path,
+    sync::{Arc, Weak},
+    time::{Duration, SystemTime},
+};
 
+use arc_swap::ArcSwap;
+use bytes::Bytes;
 use enrichment::{Case, Condition, IndexHandle, Table};
+use flate2::read::MultiGzDecoder;
+use headers::{ETag, HeaderMapExt, IfModifiedSince, IfNoneMatch};
+use http::{header::ETAG, Request, StatusCode};
+use hyper::Body;
 use maxminddb::{
     geoip2::{City, ConnectionType, Isp},
     MaxMindDBError, Reader,
 };
+use tracing::Instrument;
 use value::Value;
+use vector_common::sensitive_string::SensitiveString;
 use vector_config::configurable_component;
 
-use crate::config::{EnrichmentTableConfig, GenerateConfig};
+use crate::{
+    config::{DataDirSubsystem, EnrichmentTableConfig, GenerateConfig},
+    data_dir::Quota,
+    http::HttpClient,
+    internal_events::{EnrichmentTableRefreshError, EnrichmentTableRefreshed, RefreshedTable},
+};
 
 // MaxMind GeoIP database files have a type field we can use to recognize specific
 // products. If we encounter one of these two types, we look for ASN/ISP information;
@@ -48,6 +69,10 @@ pub struct GeoipConfig {
     ///
     /// Other databases, such as the country database, are not supported.
     ///
+    /// When `download_url` is set, the downloaded database is written to this path. Relative paths
+    /// are then resolved against the enrichment cache directory (`state_dirs.enrichment_cache`, or
+    /// the global `data_dir`), so that downloads work with a read-only root filesystem.
+    ///
     /// [geoip2]: https://dev.maxmind.com/geoip/geoip2/downloadable
     /// [geolite2]: https://dev.maxmind.com/geoip/geoip2/geolite2/#Download_Access
     pub path: String,
@@ -64,6 +89,32 @@ pub struct GeoipConfig {
     /// [locale_docs]: https://support.maxmind.com/hc/en-us/articles/4414877149467-IP-Geolocation-Data#h_01FRRGRYTGZB29ERDBZCX3MR8Q
     #[serde(default = "default_locale")]
     pub locale: String,
+
+    /// The URL to download the database from.
+    ///
+    /// The URL must serve a single database file, either uncompressed or gzip-compressed. Archives,
+    /// such as the `tar.gz` downloads offered by MaxMind, are not supported.
+    ///
+    /// When set, the database is downloaded to `path` when the table is loaded, including when Vector
+    /// reloads its configuration, and on every `refresh_interval_secs`. Downloads are conditional on the ETag and modification time of the
+    /// file at `path`, so a database that hasn't changed is not downloaded again.
+    ///
+    /// If a download fails, the database already at `path`, if any, continues to be used.
+    #[configurable(metadata(docs::examples = "https://example.com/geoip/GeoLite2-City.mmdb.gz"))]
+    #[serde(default)]
+    pub download_url: Option<SensitiveString>,
+
+    /// The interval between refreshes of the database, in seconds.
+    ///
+    /// On every refresh, the database is downloaded again if `download_url` is set and it has
+    /// changed, and re-read from `path` if the file has been modified. The new database is swapped in
+    /// atomically, so lookups never observe a partially loaded database.
+    ///
+    /// When unset, the database is only reloaded on configuration reload.
+    #[configurable(metadata(docs::examples = 86400))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[serde(default)]
+    pub refresh_interval_secs: Option<u64>,
 }
 
 fn default_locale() -> String {
@@ -83,6 +134,8 @@ impl GenerateConfig for GeoipConfig {
         toml::Value::try_from(Self {
             path: "/path/to/GeoLite2-City.mmdb".to_string(),
             locale: default_locale(),
+            download_url: None,
+            refresh_interval_secs: None,
         })
         .unwrap()
     }
@@ -92,45 +145,224 @@ impl GenerateConfig for GeoipConfig {
 impl EnrichmentTableConfig for GeoipConfig {
     async fn build(
         &self,
-        _: &crate::config::GlobalOptions,
+        globals: &crate::config::GlobalOptions,
     ) -> crate::Result<Box<dyn Table + Send + Sync>> {
-        Ok(Box::new(Geoip::new(self.clone())?))
+        let mut config = self.clone();
+        let client = match self.download_url {
+            Some(_) => Some(HttpClient::new(None, &globals.proxy)?),
+            None => None,
+        };
+        let quota = Quota::new(globals);
+
+        if let (Some(client), Some(url)) = (&client, &self.download_url) {
+            if Path::new(&self.path).is_relative() {
+                let cache_dir = globals.resolve_and_make_data_subdir_for(
+                    DataDirSubsystem::EnrichmentCache,
+                    None,
+                    "geoip",
+                )?;
+                config.path = cache_dir.join(&self.path).to_string_lossy().into_owned();
+            }
+            if let Err(error) = download(client, url.inner(), &config.path, quota.as_ref()).await {
+                if !Path::new(&config.path).exists() {
+                    return Err(error);
+                }
+                warn!(
+                    message = "Failed to download GeoIP database; using the existing database.",
+                    path = %config.path,
+                    %error,
+                );
+            }
+        }
+
+        let table = Geoip::new(config.clone())?;
+
+        if let Some(interval) = config.refresh_interval_secs {
+            let span = info_span!("geoip: refresher", path = %config.path);
+            let refresher = Refresher {
+                config,
+                client,
+                quota,
+                database: Arc::downgrade(&table.database),
+                interval: Duration::from_secs(interval),
+            };
+            tokio::spawn(refresher.run().instrument(span.or_current()));
+        }
+
+        Ok(Box::new(table))
     }
 }
 
-#[derive(Clone)]
-/// A struct that implements [enrichment::Table] to handle loading enrichment data from a GeoIP database.
-pub struct Geoip {
-    config: GeoipConfig,
-    dbreader: Arc<maxminddb::Reader<Vec<u8>>>,
-    dbkind: DatabaseKind,
+/// A loaded GeoIP database.
+struct Database {
+    reader: Reader<Vec<u8>>,
+    kind: DatabaseKind,
     last_modified: SystemTime,
 }
 
-impl Geoip {
-    /// Creates a new GeoIP struct from the provided config.
-    pub fn new(config: GeoipConfig) -> crate::Result<Self> {
-        let dbreader = Arc::new(Reader::open_readfile(config.path.clone())?);
-        let dbkind = DatabaseKind::from(dbreader.metadata.database_type.as_str());
+impl Database {
+    fn open(path: &str) -> crate::Result<Self> {
+        let reader = Reader::open_readfile(path)?;
+        let kind = DatabaseKind::from(reader.metadata.database_type.as_str());
 
         // Check if we can read database with dummy Ip.
         let ip = IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0));
-        let result = match dbkind {
-            DatabaseKind::Asn | DatabaseKind::Isp => dbreader.lookup::<Isp>(ip).map(|_| ()),
-            DatabaseKind::ConnectionType => dbreader.lookup::<ConnectionType>(ip).map(|_| ()),
-            DatabaseKind::City => dbreader.lookup::<City>(ip).map(|_| ()),
+        let result = match kind {
+            DatabaseKind::Asn | DatabaseKind::Isp => reader.lookup::<Isp>(ip).map(|_| ()),
+            DatabaseKind::ConnectionType => reader.lookup::<ConnectionType>(ip).map(|_| ()),
+            DatabaseKind::City => reader.lookup::<City>(ip).map(|_| ()),
         };
 
         match result {
-            Ok(_) | Err(MaxMindDBError::AddressNotFoundError(_)) => Ok(Geoip {
-                last_modified: fs::metadata(&config.path)?.modified()?,
-                dbreader,
-                dbkind,
-                config,
+            Ok(_) | Err(MaxMindDBError::AddressNotFoundError(_)) => Ok(Database {
+                last_modified: fs::metadata(path)?.modified()?,
+                reader,
+                kind,
             }),
             Err(error) => Err(error.into()),
         }
     }
+}
+
+/// Downloads the database at `url` and atomically replaces the file at `path` with it.
+///
+/// The request is conditional on the ETag of the previous download, kept next to `path`, and on
+/// the modification time of `path`, so that `path` is left alone if the database hasn't changed.
+///
+/// The downloaded database is validated before `path` is replaced, so a failed or corrupt
+/// download never clobbers a previously working database. A database that would grow the persisted
+/// state past `quota` is rejected the same way.
+async fn download(
+    client: &HttpClient,
+    url: &str,
+    path: &str,
+    quota: Option<&Quota>,
+) -> crate::Result<()> {
+    let path = Path::new(path);
+    let mut etag_path = path.as_os_str().to_owned();
+    etag_path.push(".etag");
+
+    let mut request = Request::get(url).body(Body::empty())?;
+    if let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        let headers = request.headers_mut();
+        headers.typed_insert(IfModifiedSince::from(modified));
+        if let Some(etag) = fs::read_to_string(&etag_path)
+            .ok()
+            .and_then(|etag| etag.parse::<ETag>().ok())
+        {
+            headers.typed_insert(IfNoneMatch::from(etag));
+        }
+    }
+
+    let response = client.send(request).await?;
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        return Ok(());
+    }
+    if status != StatusCode::OK {
+        return Err(format!("Unexpected status downloading GeoIP database: {}", status).into());
+    }
+    let etag = response.headers().get(ETAG).cloned();
+
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    let data = decompress(body)?;
+    Reader::from_source(data.as_slice())?;
+
+    if let Some(quota) = quota {
+        quota.check(path, data.len() as u64)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".download");
+    tokio::fs::write(&temp_path, &data).await?;
+    tokio::fs::rename(&temp_path, path).await?;
+
+    match etag {
+        Some(etag) => tokio::fs::write(&etag_path, etag.as_bytes()).await?,
+        None => _ = tokio::fs::remove_file(&etag_path).await,
+    }
+
+    Ok(())
+}
+
+fn decompress(body: Bytes) -> std::io::Result<Vec<u8>> {
+    // Gzip magic number.
+    if body.starts_with(&[0x1f, 0x8b]) {
+        let mut data = Vec::new();
+        MultiGzDecoder::new(body.as_ref()).read_to_end(&mut data)?;
+        Ok(data)
+    } else {
+        Ok(body.to_vec())
+    }
+}
+
+/// Periodically refreshes the database shared by all clones of a [`Geoip`] table.
+///
+/// Runs until every clone of the table has been dropped.
+struct Refresher {
+    config: GeoipConfig,
+    client: Option<HttpClient>,
+    quota: Option<Quota>,
+    database: Weak<ArcSwap<Database>>,
+    interval: Duration,
+}
+
+impl Refresher {
+    async fn run(self) {
+        loop {
+            tokio::time::sleep(self.interval).await;
+
+            let database = match self.database.upgrade() {
+                Some(database) => database,
+                None => break,
+            };
+
+            match self.refresh(&database).await {
+                Ok(true) => emit!(EnrichmentTableRefreshed {
+                    table: RefreshedTable::Geoip,
+                    path: &self.config.path,
+                }),
+                Ok(false) => {}
+                Err(error) => emit!(EnrichmentTableRefreshError {
+                    table: RefreshedTable::Geoip,
+                    path: &self.config.path,
+                    error,
+                }),
+            }
+        }
+    }
+
+    /// Returns `true` if a new database was loaded.
+    async fn refresh(&self, database: &ArcSwap<Database>) -> crate::Result<bool> {
+        if let (Some(client), Some(url)) = (&self.client, &self.config.download_url) {
+            download(client, url.inner(), &self.config.path, self.quota.as_ref()).await?;
+        }
+
+        let modified = fs::metadata(&self.config.path)?.modified()?;
+        if modified <= database.load().last_modified {
+            return Ok(false);
+        }
+
+        database.store(Arc::new(Database::open(&self.config.path)?));
+        Ok(true)
+    }
+}
+
+#[derive(Clone)]
+/// A struct that implements [enrichment::Table] to handle loading enrichment data from a GeoIP database.
+pub struct Geoip {
+    config: GeoipConfig,
+    database: Arc<ArcSwap<Database>>,
+}
+
+impl Geoip {
+    /// Creates a new GeoIP struct from the provided config.
+    pub fn new(config: GeoipConfig) -> crate::Result<Self> {
+        let database = Database::open(&config.path)?;
+        Ok(Geoip {
+            database: Arc::new(ArcSwap::from_pointee(database)),
+            config,
+        })
+    }
 
     fn lookup(&self, ip: IpAddr, select: Option<&[String]>) -> Option<BTreeMap<String, Value>> {
         let mut map = BTreeMap::new();
@@ -149,9 +381,10 @@ impl Geoip {
             };
         }
 
-        match self.dbkind {
+        let database = self.database.load();
+        match database.kind {
             DatabaseKind::Asn | DatabaseKind::Isp => {
-                let data = self.dbreader.lookup::<Isp>(ip).ok()?;
+                let data = database.reader.lookup::<Isp>(ip).ok()?;
 
                 add_field!("autonomous_system_number", data.autonomous_system_number);
                 add_field!(
@@ -162,7 +395,7 @@ impl Geoip {
                 add_field!("organization", data.organization);
             }
             DatabaseKind::City => {
-                let data = self.dbreader.lookup::<City>(ip).ok()?;
+                let data = database.reader.lookup::<City>(ip).ok()?;
 
                 add_field!(
                     "city_name",
@@ -203,7 +436,7 @@ impl Geoip {
                 add_field!("postal_code", data.postal.and_then(|p| p.code));
             }
             DatabaseKind::ConnectionType => {
-                let data = self.dbreader.lookup::<ConnectionType>(ip).ok()?;
+                let data = database.reader.lookup::<ConnectionType>(ip).ok()?;
 
                 add_field!("connection_type", data.connection_type);
             }
@@ -290,10 +523,19 @@ impl Table for Geoip {
     }
 
     /// Returns true if the underlying data has changed and the table needs reloading.
+    ///
+    /// Tables with a `download_url` always need reloading, as only rebuilding them checks whether
+    /// the database has changed upstream.
     fn needs_reload(&self) -> bool {
-        matches!(fs::metadata(&self.config.path)
-            .and_then(|metadata| metadata.modified()),
-            Ok(modified) if modified > self.last_modified)
+        if self.config.download_url.is_some() {
+            return true;
+        }
+
+        let last_modified = self.database.load().last_modified;
+        matches!(
+            fs::metadata(&self.config.path).and_then(|metadata| metadata.modified()),
+            Ok(modified) if modified > last_modified
+        )
     }
 }
 
@@ -434,6 +676,96 @@ mod tests {
         assert!(values.is_none());
     }
 
+    #[tokio::test]
+    async fn refresh_swaps_modified_database() {
+        let path = crate::test_util::temp_file();
+        fs::copy("tests/data/GeoIP2-City-Test.mmdb", &path).unwrap();
+
+        let config = GeoipConfig {
+            path: path.to_str().unwrap().to_string(),
+            locale: default_locale(),
+            download_url: None,
+            refresh_interval_secs: Some(1),
+        };
+        let table = Geoip::new(config.clone()).unwrap();
+        let refresher = Refresher {
+            config,
+            client: None,
+            quota: None,
+            database: Arc::downgrade(&table.database),
+            interval: Duration::from_secs(1),
+        };
+
+        // Unmodified databases are left alone.
+        assert!(!refresher.refresh(&table.database).await.unwrap());
+
+        fs::copy("tests/data/GeoIP2-ISP-Test.mmdb", &path).unwrap();
+        assert!(refresher.refresh(&table.database).await.unwrap());
+
+        let ip = "208.192.1.2".parse().unwrap();
+        let values = table.lookup(ip, None).unwrap();
+        assert_eq!(values["isp"], "Verizon Business".into());
+    }
+
+    #[tokio::test]
+    async fn refresher_stops_when_table_dropped() {
+        let config = GeoipConfig {
+            path: "tests/data/GeoIP2-City-Test.mmdb".to_string(),
+            locale: default_locale(),
+            download_url: None,
+            refresh_interval_secs: Some(1),
+        };
+        let table = Geoip::new(config.clone()).unwrap();
+        let refresher = Refresher {
+            config,
+            client: None,
+            quota: None,
+            database: Arc::downgrade(&table.database),
+            interval: Duration::from_millis(10),
+        };
+        drop(table);
+
+        tokio::time::timeout(Duration::from_secs(5), refresher.run())
+            .await
+            .expect("refresher should stop once the table is dropped");
+    }
+
+    #[tokio::test]
+    async fn build_falls_back_to_existing_database() {
+        let path = crate::test_util::temp_file();
+        let addr = crate::test_util::next_addr();
+        let url = format!("http://{}/GeoLite2-City.mmdb", addr);
+        let config = GeoipConfig {
+            path: path.to_str().unwrap().to_string(),
+            locale: default_locale(),
+            download_url: Some(url.into()),
+            refresh_interval_secs: None,
+        };
+        let globals = crate::config::GlobalOptions::default();
+
+        // Without a database to fall back to, the failed download fails the build.
+        assert!(config.build(&globals).await.is_err());
+
+        fs::copy("tests/data/GeoIP2-City-Test.mmdb", &path).unwrap();
+        let table = config.build(&globals).await.unwrap();
+        // Reloading the configuration downloads the database again.
+        assert!(table.needs_reload());
+    }
+
+    #[test]
+    fn decompresses_gzipped_database() {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let data = fs::read("tests/data/GeoIP2-City-Test.mmdb").unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&data).unwrap();
+        let compressed = Bytes::from(encoder.finish().unwrap());
+
+        assert_eq!(decompress(compressed).unwrap(), data);
+        assert_eq!(decompress(Bytes::from(data.clone())).unwrap(), data);
+    }
+
     fn find(ip: &str, database: &str) -> Option<BTreeMap<String, Value>> {
         find_select(ip, database, None)
     }
@@ -446,6 +778,8 @@ mod tests {
         Geoip::new(GeoipConfig {
             path: database.to_string(),
             locale: default_locale(),
+            download_url: None,
+            refresh_interval_secs: None,
         })
         .unwrap()
         .find_table_rows(
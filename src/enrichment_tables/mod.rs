@@ -10,6 +10,9 @@ pub mod file;
 #[cfg(feature = "enrichment-tables-geoip")]
 pub mod geoip;
 
+#[cfg(feature = "enrichment-tables-sqlite")]
+pub mod sqlite;
+
 /// Configurable enrichment tables.
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -25,6 +28,14 @@ pub enum EnrichmentTables {
     /// [geoip2]: https://www.maxmind.com/en/geoip2-databases
     #[cfg(feature = "enrichment-tables-geoip")]
     Geoip(geoip::GeoipConfig),
+
+    /// Exposes data from a table in a [SQLite][sqlite] database as an enrichment table.
+    ///
+    /// Rows are looked up with queries against the database, rather than being loaded into memory.
+    ///
+    /// [sqlite]: https://www.sqlite.org/
+    #[cfg(feature = "enrichment-tables-sqlite")]
+    Sqlite(sqlite::SqliteConfig),
 }
 
 // TODO: Use `enum_dispatch` here.
@@ -34,6 +45,8 @@ impl NamedComponent for EnrichmentTables {
             Self::File(config) => config.get_component_name(),
             #[cfg(feature = "enrichment-tables-geoip")]
             Self::Geoip(config) => config.get_component_name(),
+            #[cfg(feature = "enrichment-tables-sqlite")]
+            Self::Sqlite(config) => config.get_component_name(),
             #[allow(unreachable_patterns)]
             _ => unimplemented!(),
         }
@@ -0,0 +1,904 @@
+//! Handles enrichment tables for `type = sqlite`.
+//!
+//! Unlike the `file` enrichment table, rows are not loaded into memory. Every lookup is run as a
+//! query against the database file, so the table can be far larger than the available memory,
+//! provided that lookups are served by the database's own indexes.
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    ops::Deref,
+    path::PathBuf,
+    sync::{Arc, Mutex, RwLock, Weak},
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use chrono::SecondsFormat;
+use enrichment::{Case, Condition, IndexHandle, Table};
+use ordered_float::NotNan;
+use rusqlite::{
+    params_from_iter,
+    types::{Value as SqlValue, ValueRef},
+    Connection, OpenFlags,
+};
+use tracing::Instrument;
+use value::Value;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{EnrichmentTableConfig, GenerateConfig},
+    internal_events::{EnrichmentTableRefreshError, EnrichmentTableRefreshed, RefreshedTable},
+};
+
+/// Configuration for the `sqlite` enrichment table.
+#[configurable_component(enrichment_table("sqlite"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SqliteConfig {
+    /// The path of the SQLite database file.
+    ///
+    /// The database is opened read-only.
+    #[configurable(metadata(docs::examples = "/data/enrichment.db"))]
+    pub path: PathBuf,
+
+    /// The name of the table to look up rows in.
+    #[configurable(metadata(docs::examples = "hosts"))]
+    pub table: String,
+
+    /// The columns that lookups are keyed on.
+    ///
+    /// Every lookup must include an exact match on at least one of these columns. Each column must
+    /// be the leading column of an index on `table`, or its primary key, so that lookups never scan
+    /// the whole table.
+    ///
+    /// Case-insensitive lookups must include an exact match on a column whose index uses the
+    /// `NOCASE` collation, for example created with `CREATE INDEX ... (column COLLATE NOCASE)`.
+    #[configurable(metadata(docs::examples = "hostname", docs::examples = "ip"))]
+    pub key_columns: Vec<String>,
+
+    /// The interval between checks for modifications to the database file, in seconds.
+    ///
+    /// When the file has been modified, or replaced, the database is reopened. If the new database
+    /// can't be opened, or no longer has the configured table and key columns, the previously
+    /// opened database continues to be used.
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[serde(default = "default_refresh_interval_secs")]
+    pub refresh_interval_secs: u64,
+}
+
+const fn default_refresh_interval_secs() -> u64 {
+    10
+}
+
+impl GenerateConfig for SqliteConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            path: "/path/to/enrichment.db".into(),
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl EnrichmentTableConfig for SqliteConfig {
+    async fn build(
+        &self,
+        _globals: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        let table = Sqlite::new(self.clone())?;
+
+        let span = info_span!("sqlite: refresher", path = ?self.path);
+        let refresher = Refresher {
+            config: self.clone(),
+            database: Arc::downgrade(&table.database),
+            interval: Duration::from_secs(self.refresh_interval_secs.max(1)),
+        };
+        tokio::spawn(refresher.run().instrument(span.or_current()));
+
+        Ok(Box::new(table))
+    }
+}
+
+/// An open SQLite database, validated against the configured table and key columns.
+///
+/// Lookups from different threads run concurrently, each on a read-only connection of its own.
+/// Connections are opened as needed and kept for later lookups once they are released.
+struct Database {
+    path: PathBuf,
+    connections: Mutex<Vec<Connection>>,
+    columns: Vec<String>,
+    /// The key columns with an index using the `NOCASE` collation.
+    nocase_indexed: HashSet<String>,
+    last_modified: SystemTime,
+}
+
+/// A connection of a [`Database`], returned to it when dropped.
+struct PooledConnection<'a> {
+    database: &'a Database,
+    connection: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection
+            .as_ref()
+            .expect("connection already released")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.database
+                .connections
+                .lock()
+                .expect("mutex poisoned")
+                .push(connection);
+        }
+    }
+}
+
+fn open_connection(path: &std::path::Path) -> rusqlite::Result<Connection> {
+    Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+}
+
+impl Database {
+    fn open(config: &SqliteConfig) -> crate::Result<Self> {
+        if config.key_columns.is_empty() {
+            return Err("`key_columns` must contain at least one column".into());
+        }
+
+        // Read the modification time first, so that a modification racing with the open is
+        // picked up by the next refresh.
+        let last_modified = fs::metadata(&config.path)?.modified()?;
+        let connection = open_connection(&config.path)?;
+
+        let table = quote_identifier(&config.table);
+        let mut columns = Vec::new();
+        let mut indexed = HashSet::new();
+        let mut nocase_indexed = HashSet::new();
+
+        let mut statement = connection.prepare(&format!("PRAGMA table_info({})", table))?;
+        let mut rows = statement.query([])?;
+        while let Some(row) = rows.next()? {
+            let name: String = row.get("name")?;
+            // `pk` is the 1-based position of the column within the primary key.
+            if row.get::<_, i64>("pk")? == 1 {
+                indexed.insert(name.clone());
+            }
+            columns.push(name);
+        }
+        drop(rows);
+        drop(statement);
+
+        if columns.is_empty() {
+            return Err(format!(
+                "Table {:?} not found in SQLite database {:?}",
+                config.table, config.path
+            )
+            .into());
+        }
+
+        let mut statement = connection.prepare(&format!("PRAGMA index_list({})", table))?;
+        let indexes = statement
+            .query_map([], |row| row.get::<_, String>("name"))?
+            .collect::<Result<Vec<_>, _>>()?;
+        drop(statement);
+
+        for index in indexes {
+            let mut statement =
+                connection.prepare(&format!("PRAGMA index_xinfo({})", quote_identifier(&index)))?;
+            let mut rows = statement.query([])?;
+            while let Some(row) = rows.next()? {
+                if row.get::<_, i64>("seqno")? == 0 {
+                    // Expression indexes have no column name.
+                    if let Some(name) = row.get::<_, Option<String>>("name")? {
+                        if row
+                            .get::<_, Option<String>>("coll")?
+                            .map_or(false, |coll| coll.eq_ignore_ascii_case("NOCASE"))
+                        {
+                            nocase_indexed.insert(name.clone());
+                        }
+                        indexed.insert(name);
+                    }
+                }
+            }
+        }
+
+        for column in &config.key_columns {
+            if !columns.contains(column) {
+                return Err(format!(
+                    "Key column {:?} not found in table {:?}",
+                    column, config.table
+                )
+                .into());
+            }
+            if !indexed.contains(column) {
+                return Err(format!(
+                    "Key column {:?} is not the leading column of any index on table {:?}",
+                    column, config.table
+                )
+                .into());
+            }
+        }
+
+        Ok(Self {
+            path: config.path.clone(),
+            connections: Mutex::new(vec![connection]),
+            columns,
+            nocase_indexed,
+            last_modified,
+        })
+    }
+
+    /// Takes an idle connection, or opens a new one if all of them are in use.
+    fn connection(&self) -> rusqlite::Result<PooledConnection<'_>> {
+        let idle = self.connections.lock().expect("mutex poisoned").pop();
+        let connection = match idle {
+            Some(connection) => connection,
+            None => open_connection(&self.path)?,
+        };
+        Ok(PooledConnection {
+            database: self,
+            connection: Some(connection),
+        })
+    }
+
+    /// Returns `true` if `field` is a key column that can serve lookups with the given case.
+    fn is_key_column(&self, config: &SqliteConfig, field: &str, case: Case) -> bool {
+        config.key_columns.iter().any(|column| column == field)
+            && (case == Case::Sensitive || self.nocase_indexed.contains(field))
+    }
+}
+
+/// Periodically reopens the database shared by all clones of a [`Sqlite`] table when the database
+/// file is modified.
+///
+/// Runs until every clone of the table has been dropped.
+struct Refresher {
+    config: SqliteConfig,
+    database: Weak<RwLock<Arc<Database>>>,
+    interval: Duration,
+}
+
+impl Refresher {
+    async fn run(self) {
+        loop {
+            tokio::time::sleep(self.interval).await;
+
+            let database = match self.database.upgrade() {
+                Some(database) => database,
+                None => break,
+            };
+
+            let path = self.config.path.to_string_lossy();
+            match self.refresh(&database) {
+                Ok(true) => emit!(EnrichmentTableRefreshed {
+                    table: RefreshedTable::Sqlite,
+                    path: &path,
+                }),
+                Ok(false) => {}
+                Err(error) => emit!(EnrichmentTableRefreshError {
+                    table: RefreshedTable::Sqlite,
+                    path: &path,
+                    error,
+                }),
+            }
+        }
+    }
+
+    /// Returns `true` if the database was reopened.
+    fn refresh(&self, database: &RwLock<Arc<Database>>) -> crate::Result<bool> {
+        let modified = fs::metadata(&self.config.path)?.modified()?;
+        if modified <= database.read().expect("lock poisoned").last_modified {
+            return Ok(false);
+        }
+
+        // Open the new database before taking the lock, so lookups aren't blocked while it is
+        // validated. Lookups already running finish on the previous database.
+        let reopened = Database::open(&self.config)?;
+        *database.write().expect("lock poisoned") = Arc::new(reopened);
+        Ok(true)
+    }
+}
+
+/// A struct that implements [enrichment::Table] to handle looking up enrichment data in a SQLite
+/// database.
+#[derive(Clone)]
+pub struct Sqlite {
+    config: SqliteConfig,
+    database: Arc<RwLock<Arc<Database>>>,
+    indexes: Vec<(Case, Vec<String>)>,
+}
+
+impl Sqlite {
+    /// Creates a new [Sqlite] table from the provided config.
+    pub fn new(config: SqliteConfig) -> crate::Result<Self> {
+        let database = Database::open(&config)?;
+        Ok(Self {
+            config,
+            database: Arc::new(RwLock::new(Arc::new(database))),
+            indexes: Vec::new(),
+        })
+    }
+
+    fn database(&self) -> Arc<Database> {
+        Arc::clone(&self.database.read().expect("lock poisoned"))
+    }
+
+    fn key_column_error(&self, case: Case) -> String {
+        match case {
+            Case::Sensitive => format!(
+                "condition must include an exact match on one of the key columns: {}",
+                self.config.key_columns.join(", ")
+            ),
+            Case::Insensitive => format!(
+                "case-insensitive condition must include an exact match on one of the key columns \
+                 with a `COLLATE NOCASE` index: {}",
+                self.config.key_columns.join(", ")
+            ),
+        }
+    }
+
+    /// Runs a query for the rows matching all of the conditions.
+    ///
+    /// At most `limit` rows are returned, if given.
+    fn query(
+        &self,
+        case: Case,
+        condition: &[Condition],
+        select: Option<&[String]>,
+        limit: Option<usize>,
+    ) -> Result<Vec<BTreeMap<String, Value>>, String> {
+        let database = self.database();
+
+        if !condition.iter().any(|condition| {
+            matches!(condition, Condition::Equals { field, .. }
+                if database.is_key_column(&self.config, field, case))
+        }) {
+            return Err(self.key_column_error(case));
+        }
+
+        let columns = database
+            .columns
+            .iter()
+            .filter(|column| select.map_or(true, |select| select.contains(*column)))
+            .collect::<Vec<_>>();
+
+        let mut clauses = Vec::with_capacity(condition.len());
+        let mut params = Vec::with_capacity(condition.len());
+        for condition in condition {
+            let field = match condition {
+                Condition::Equals { field, .. } | Condition::BetweenDates { field, .. } => field,
+            };
+            if !database.columns.iter().any(|column| column == field) {
+                return Err(format!("field {:?} not found in table", field));
+            }
+            let field = quote_identifier(field);
+
+            match condition {
+                Condition::Equals { value, .. } => {
+                    // `IS` rather than `=`, so that null values match null columns.
+                    clauses.push(match (case, value) {
+                        (Case::Insensitive, Value::Bytes(_)) => {
+                            format!("{} IS ? COLLATE NOCASE", field)
+                        }
+                        _ => format!("{} IS ?", field),
+                    });
+                    params.push(to_sql_value(value)?);
+                }
+                Condition::BetweenDates { from, to, .. } => {
+                    clauses.push(format!(
+                        "datetime({}) BETWEEN datetime(?) AND datetime(?)",
+                        field
+                    ));
+                    params.push(SqlValue::Text(
+                        from.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    ));
+                    params.push(SqlValue::Text(
+                        to.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    ));
+                }
+            }
+        }
+
+        let mut sql = format!(
+            "SELECT {} FROM {} WHERE {}",
+            if columns.is_empty() {
+                "1".to_string()
+            } else {
+                columns
+                    .iter()
+                    .map(|column| quote_identifier(column))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+            quote_identifier(&self.config.table),
+            clauses.join(" AND "),
+        );
+        if let Some(limit) = limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        let connection = database.connection().map_err(|error| error.to_string())?;
+        let mut statement = connection
+            .prepare_cached(&sql)
+            .map_err(|error| error.to_string())?;
+        let mut rows = statement
+            .query(params_from_iter(params))
+            .map_err(|error| error.to_string())?;
+
+        let mut results = Vec::new();
+        while let Some(row) = rows.next().map_err(|error| error.to_string())? {
+            let mut result = BTreeMap::new();
+            for (idx, column) in columns.iter().enumerate() {
+                let value = row.get_ref(idx).map_err(|error| error.to_string())?;
+                result.insert(column.to_string(), from_sql_value(value));
+            }
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
+
+/// Quotes an identifier, so that arbitrary table and column names can be used in queries.
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn to_sql_value(value: &Value) -> Result<SqlValue, String> {
+    match value {
+        Value::Bytes(bytes) => Ok(match std::str::from_utf8(bytes) {
+            Ok(text) => SqlValue::Text(text.to_string()),
+            Err(_) => SqlValue::Blob(bytes.to_vec()),
+        }),
+        Value::Integer(integer) => Ok(SqlValue::Integer(*integer)),
+        Value::Float(float) => Ok(SqlValue::Real(float.into_inner())),
+        Value::Boolean(boolean) => Ok(SqlValue::Integer(i64::from(*boolean))),
+        Value::Timestamp(timestamp) => Ok(SqlValue::Text(
+            timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        )),
+        Value::Null => Ok(SqlValue::Null),
+        _ => Err("only scalar values can be looked up in a SQLite table".to_string()),
+    }
+}
+
+fn from_sql_value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null => Value::Null,
+        ValueRef::Integer(integer) => Value::Integer(integer),
+        ValueRef::Real(real) => NotNan::new(real).map_or(Value::Null, Value::Float),
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => {
+            Value::Bytes(Bytes::copy_from_slice(bytes))
+        }
+    }
+}
+
+fn single_or_err<T>(mut rows: Vec<T>) -> Result<T, String> {
+    match rows.len() {
+        0 => Err("no rows found".to_string()),
+        1 => Ok(rows.remove(0)),
+        _ => Err("more than one row found".to_string()),
+    }
+}
+
+impl Table for Sqlite {
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _index: Option<IndexHandle>,
+    ) -> Result<BTreeMap<String, Value>, String> {
+        // Two rows are enough to tell whether the result is ambiguous.
+        single_or_err(self.query(case, condition, select, Some(2))?)
+    }
+
+    fn find_table_rows<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _index: Option<IndexHandle>,
+    ) -> Result<Vec<BTreeMap<String, Value>>, String> {
+        self.query(case, condition, select, None)
+    }
+
+    /// Lookups are served by the database's own indexes, so this only checks that the lookup is
+    /// keyed on at least one of the key columns.
+    fn add_index(&mut self, case: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        let database = self.database();
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !database.columns.iter().any(|column| column == *field))
+        {
+            return Err(format!("field {:?} not found in table", field));
+        }
+        if !fields
+            .iter()
+            .any(|field| database.is_key_column(&self.config, field, case))
+        {
+            return Err(self.key_column_error(case));
+        }
+
+        let fields = fields.iter().map(|field| field.to_string()).collect();
+        match self
+            .indexes
+            .iter()
+            .position(|index| index.0 == case && index.1 == fields)
+        {
+            Some(pos) => Ok(IndexHandle(pos)),
+            None => {
+                self.indexes.push((case, fields));
+                Ok(IndexHandle(self.indexes.len() - 1))
+            }
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        self.indexes.clone()
+    }
+
+    /// Checks the modified timestamp of the database file to see if data has changed.
+    fn needs_reload(&self) -> bool {
+        let last_modified = self.database().last_modified;
+        matches!(fs::metadata(&self.config.path)
+            .and_then(|metadata| metadata.modified()),
+            Ok(modified) if modified > last_modified)
+    }
+}
+
+impl std::fmt::Debug for Sqlite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sqlite table {:?} {} index(es)",
+            self.config.table,
+            self.indexes.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn create_database(path: &std::path::Path, rows: &[(&str, &str, i64)]) {
+        let connection = Connection::open(path).unwrap();
+        connection
+            .execute_batch(
+                r#"
+                CREATE TABLE hosts (hostname TEXT, region TEXT, weight INTEGER, created TEXT);
+                CREATE INDEX hosts_hostname ON hosts (hostname);
+                "#,
+            )
+            .unwrap();
+        for (hostname, region, weight) in rows {
+            connection
+                .execute(
+                    "INSERT INTO hosts VALUES (?, ?, ?, '2023-05-01T12:00:00Z')",
+                    rusqlite::params![hostname, region, weight],
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SqliteConfig>();
+    }
+
+    #[test]
+    fn finds_row() {
+        let path = crate::test_util::temp_file();
+        create_database(
+            &path,
+            &[("web-1", "us-east-1", 1), ("web-2", "eu-west-1", 2)],
+        );
+        let table = Sqlite::new(SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap();
+
+        let condition = [Condition::Equals {
+            field: "hostname",
+            value: "web-2".into(),
+        }];
+
+        assert_eq!(
+            Ok(BTreeMap::from([
+                ("created".to_string(), "2023-05-01T12:00:00Z".into()),
+                ("hostname".to_string(), "web-2".into()),
+                ("region".to_string(), "eu-west-1".into()),
+                ("weight".to_string(), Value::Integer(2)),
+            ])),
+            table.find_table_row(Case::Sensitive, &condition, None, None)
+        );
+    }
+
+    #[test]
+    fn selects_columns() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[("web-1", "us-east-1", 1)]);
+        let table = Sqlite::new(SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap();
+
+        let condition = [Condition::Equals {
+            field: "hostname",
+            value: "web-1".into(),
+        }];
+        let select = ["region".to_string(), "missing".to_string()];
+
+        assert_eq!(
+            Ok(BTreeMap::from([("region".to_string(), "us-east-1".into())])),
+            table.find_table_row(Case::Sensitive, &condition, Some(&select), None)
+        );
+    }
+
+    #[test]
+    fn finds_rows_case_insensitive() {
+        let path = crate::test_util::temp_file();
+        create_database(
+            &path,
+            &[("web-1", "us-east-1", 1), ("WEB-1", "eu-west-1", 2)],
+        );
+
+        let condition = [Condition::Equals {
+            field: "hostname",
+            value: "Web-1".into(),
+        }];
+
+        let config = SqliteConfig {
+            path: path.clone(),
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        };
+
+        // Without a `NOCASE` index, case-insensitive lookups would scan the whole table.
+        let mut table = Sqlite::new(config.clone()).unwrap();
+        assert!(table
+            .find_table_rows(Case::Insensitive, &condition, None, None)
+            .is_err());
+        assert!(table.add_index(Case::Insensitive, &["hostname"]).is_err());
+
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE INDEX hosts_hostname_nocase ON hosts (hostname COLLATE NOCASE);")
+            .unwrap();
+        let mut table = Sqlite::new(config).unwrap();
+        assert!(table.add_index(Case::Insensitive, &["hostname"]).is_ok());
+
+        assert_eq!(
+            0,
+            table
+                .find_table_rows(Case::Sensitive, &condition, None, None)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            2,
+            table
+                .find_table_rows(Case::Insensitive, &condition, None, None)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            Err("more than one row found".to_string()),
+            table.find_table_row(Case::Insensitive, &condition, None, None)
+        );
+    }
+
+    #[test]
+    fn runs_concurrent_lookups_on_separate_connections() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[("web-1", "us-east-1", 1)]);
+        let table = Sqlite::new(SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap();
+        let database = table.database();
+
+        let first = database.connection().unwrap();
+        let second = database.connection().unwrap();
+        drop((first, second));
+        assert_eq!(2, database.connections.lock().unwrap().len());
+
+        let condition = [Condition::Equals {
+            field: "hostname",
+            value: "web-1".into(),
+        }];
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..100 {
+                        assert!(table
+                            .find_table_row(Case::Sensitive, &condition, None, None)
+                            .is_ok());
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn finds_row_between_dates() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[("web-1", "us-east-1", 1)]);
+        let table = Sqlite::new(SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap();
+
+        let condition = |from, to| {
+            [
+                Condition::Equals {
+                    field: "hostname",
+                    value: "web-1".into(),
+                },
+                Condition::BetweenDates {
+                    field: "created",
+                    from: Utc
+                        .with_ymd_and_hms(2023, 5, from, 0, 0, 0)
+                        .single()
+                        .expect("invalid timestamp"),
+                    to: Utc
+                        .with_ymd_and_hms(2023, 5, to, 0, 0, 0)
+                        .single()
+                        .expect("invalid timestamp"),
+                },
+            ]
+        };
+
+        assert!(table
+            .find_table_row(Case::Sensitive, &condition(1, 2), None, None)
+            .is_ok());
+        assert_eq!(
+            Err("no rows found".to_string()),
+            table.find_table_row(Case::Sensitive, &condition(2, 3), None, None)
+        );
+    }
+
+    #[test]
+    fn requires_key_column_condition() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[("web-1", "us-east-1", 1)]);
+        let mut table = Sqlite::new(SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        })
+        .unwrap();
+
+        let condition = [Condition::Equals {
+            field: "region",
+            value: "us-east-1".into(),
+        }];
+
+        assert!(table
+            .find_table_rows(Case::Sensitive, &condition, None, None)
+            .is_err());
+        assert!(table.add_index(Case::Sensitive, &["region"]).is_err());
+        assert!(table.add_index(Case::Sensitive, &["missing"]).is_err());
+        assert_eq!(
+            Ok(IndexHandle(0)),
+            table.add_index(Case::Sensitive, &["hostname", "region"])
+        );
+        assert_eq!(
+            Ok(IndexHandle(0)),
+            table.add_index(Case::Sensitive, &["hostname", "region"])
+        );
+    }
+
+    #[test]
+    fn errors_on_unindexed_key_column() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[]);
+
+        let mut config = SqliteConfig {
+            path,
+            table: "hosts".to_string(),
+            key_columns: vec!["region".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        };
+        assert!(Sqlite::new(config.clone()).is_err());
+
+        config.key_columns = vec!["missing".to_string()];
+        assert!(Sqlite::new(config.clone()).is_err());
+
+        config.table = "missing".to_string();
+        config.key_columns = vec!["hostname".to_string()];
+        assert!(Sqlite::new(config).is_err());
+    }
+
+    #[test]
+    fn refresh_reopens_modified_database() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[("web-1", "us-east-1", 1)]);
+
+        let config = SqliteConfig {
+            path: path.clone(),
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        };
+        let table = Sqlite::new(config.clone()).unwrap();
+        let refresher = Refresher {
+            config,
+            database: Arc::downgrade(&table.database),
+            interval: Duration::from_secs(1),
+        };
+
+        // Unmodified databases are left alone.
+        assert!(!table.needs_reload());
+        assert!(!refresher.refresh(&table.database).unwrap());
+
+        // Replace the database, as a bulk export would.
+        let replacement = crate::test_util::temp_file();
+        create_database(&replacement, &[("web-1", "ap-south-1", 1)]);
+        fs::rename(&replacement, &path).unwrap();
+
+        assert!(table.needs_reload());
+        assert!(refresher.refresh(&table.database).unwrap());
+        assert!(!table.needs_reload());
+
+        let condition = [Condition::Equals {
+            field: "hostname",
+            value: "web-1".into(),
+        }];
+        let row = table
+            .find_table_row(Case::Sensitive, &condition, None, None)
+            .unwrap();
+        assert_eq!(row["region"], "ap-south-1".into());
+    }
+
+    #[tokio::test]
+    async fn refresher_stops_when_table_dropped() {
+        let path = crate::test_util::temp_file();
+        create_database(&path, &[]);
+
+        let config = SqliteConfig {
+            path: path.clone(),
+            table: "hosts".to_string(),
+            key_columns: vec!["hostname".to_string()],
+            refresh_interval_secs: default_refresh_interval_secs(),
+        };
+        let table = Sqlite::new(config.clone()).unwrap();
+        let refresher = Refresher {
+            config,
+            database: Arc::downgrade(&table.database),
+            interval: Duration::from_millis(10),
+        };
+        drop(table);
+
+        tokio::time::timeout(Duration::from_secs(5), refresher.run())
+            .await
+            .expect("refresher should stop once the table is dropped");
+    }
+}
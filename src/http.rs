@@ -2,6 +2,7 @@
 use std::{
     fmt,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use futures::future::BoxFuture;
@@ -12,10 +13,10 @@ use hyper::{
     client,
     client::{Client, HttpConnector},
 };
-use hyper_openssl::HttpsConnector;
+use hyper_openssl::{HttpsConnector, HttpsLayer, HttpsLayerSettings};
 use hyper_proxy::ProxyConnector;
 use snafu::{ResultExt, Snafu};
-use tower::Service;
+use tower::{Layer, Service};
 use tracing::Instrument;
 use vector_common::sensitive_string::SensitiveString;
 use vector_config::configurable_component;
@@ -72,19 +73,38 @@ where
         HttpClient::new_with_custom_client(tls_settings, proxy_config, &mut Client::builder())
     }
 
+    /// Creates a client whose connection pool and protocol are tuned with `options`.
+    pub fn new_with_options(
+        tls_settings: impl Into<MaybeTlsSettings>,
+        proxy_config: &ProxyConfig,
+        options: &HttpClientOptions,
+    ) -> Result<HttpClient<B>, HttpError> {
+        let mut client_builder = Client::builder();
+        options.apply(&mut client_builder);
+        let proxy = build_proxy_connector_with_options(tls_settings.into(), proxy_config, options)?;
+        Ok(HttpClient::from_connector(&client_builder, proxy))
+    }
+
     pub fn new_with_custom_client(
         tls_settings: impl Into<MaybeTlsSettings>,
         proxy_config: &ProxyConfig,
         client_builder: &mut client::Builder,
     ) -> Result<HttpClient<B>, HttpError> {
         let proxy = build_proxy_connector(tls_settings.into(), proxy_config)?;
+        Ok(HttpClient::from_connector(client_builder, proxy))
+    }
+
+    fn from_connector(
+        client_builder: &client::Builder,
+        proxy: ProxyConnector<HttpsConnector<HttpConnector>>,
+    ) -> HttpClient<B> {
         let client = client_builder.build(proxy);
 
         let version = crate::get_version();
         let user_agent = HeaderValue::from_str(&format!("Vector/{}", version))
             .expect("Invalid header value for version!");
 
-        Ok(HttpClient { client, user_agent })
+        HttpClient { client, user_agent }
     }
 
     pub fn send(
@@ -140,12 +160,20 @@ where
 pub fn build_proxy_connector(
     tls_settings: MaybeTlsSettings,
     proxy_config: &ProxyConfig,
+) -> Result<ProxyConnector<HttpsConnector<HttpConnector>>, HttpError> {
+    build_proxy_connector_with_options(tls_settings, proxy_config, &HttpClientOptions::default())
+}
+
+fn build_proxy_connector_with_options(
+    tls_settings: MaybeTlsSettings,
+    proxy_config: &ProxyConfig,
+    options: &HttpClientOptions,
 ) -> Result<ProxyConnector<HttpsConnector<HttpConnector>>, HttpError> {
     // Create dedicated TLS connector for the proxied connection with user TLS settings.
     let tls = tls_connector_builder(&tls_settings)
         .context(BuildTlsConnectorSnafu)?
         .build();
-    let https = build_tls_connector(tls_settings)?;
+    let https = build_tls_connector_with_options(tls_settings, options)?;
     let mut proxy = ProxyConnector::new(https).unwrap();
     // Make proxy connector aware of user TLS settings by setting the TLS connector:
     // https://github.com/vectordotdev/vector/issues/13683
@@ -158,24 +186,141 @@ pub fn build_proxy_connector(
 
 pub fn build_tls_connector(
     tls_settings: MaybeTlsSettings,
+) -> Result<HttpsConnector<HttpConnector>, HttpError> {
+    build_tls_connector_with_options(tls_settings, &HttpClientOptions::default())
+}
+
+fn build_tls_connector_with_options(
+    tls_settings: MaybeTlsSettings,
+    options: &HttpClientOptions,
 ) -> Result<HttpsConnector<HttpConnector>, HttpError> {
     let mut http = HttpConnector::new();
     http.enforce_http(false);
+    http.set_keepalive(options.tcp_keepalive_secs.map(Duration::from_secs));
 
     let tls = tls_connector_builder(&tls_settings).context(BuildTlsConnectorSnafu)?;
-    let mut https = HttpsConnector::with_connector(http, tls).context(MakeHttpsConnectorSnafu)?;
+    let mut layer_settings = HttpsLayerSettings::builder();
+    layer_settings.set_session_cache_capacity(options.tls_session_cache_capacity);
+    let mut https = HttpsLayer::with_connector_and_settings(tls, layer_settings.build())
+        .context(MakeHttpsConnectorSnafu)?
+        .layer(http);
 
     let settings = tls_settings.tls().cloned();
+    let alpn_protocols = options.http_version.alpn_protocols();
     https.set_callback(move |c, _uri| {
         if let Some(settings) = &settings {
             settings.apply_connect_configuration(c);
         }
+        // The HTTP version preference overrides the ALPN protocols of the TLS settings.
+        if let Some(alpn_protocols) = alpn_protocols {
+            c.set_alpn_protos(alpn_protocols)?;
+        }
 
         Ok(())
     });
     Ok(https)
 }
 
+/// The version of HTTP used by a client.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersion {
+    /// HTTP/2 is used if the server selects it through TLS ALPN, as offered by the
+    /// `tls.alpn_protocols` option, and HTTP/1.1 otherwise.
+    #[default]
+    Auto,
+
+    /// Only HTTP/1.1 is used.
+    Http1,
+
+    /// Only HTTP/2 is used.
+    ///
+    /// It's negotiated through TLS ALPN over HTTPS, and used with prior knowledge over plain HTTP.
+    Http2,
+}
+
+impl HttpVersion {
+    /// The ALPN protocols to offer in the TLS handshake, in their wire format, if they're forced.
+    const fn alpn_protocols(self) -> Option<&'static [u8]> {
+        match self {
+            HttpVersion::Auto => None,
+            HttpVersion::Http1 => Some(b"\x08http/1.1".as_slice()),
+            HttpVersion::Http2 => Some(b"\x02h2".as_slice()),
+        }
+    }
+}
+
+const fn default_tls_session_cache_capacity() -> usize {
+    8
+}
+
+/// Connection pool and protocol options of the HTTP client.
+///
+/// Reusing connections, and resuming TLS sessions when new connections are opened, avoids the
+/// latency of the TCP and TLS handshakes for most requests.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct HttpClientOptions {
+    /// The maximum number of idle connections kept open to each host.
+    ///
+    /// By default, the number of idle connections is not limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::type_unit = "connections"))]
+    pub pool_max_idle_per_host: Option<usize>,
+
+    /// How long an idle connection is kept open, in seconds.
+    ///
+    /// By default, idle connections are closed after 90 seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub pool_idle_timeout_secs: Option<u64>,
+
+    /// The interval of the TCP keepalive probes sent on open connections, in seconds.
+    ///
+    /// By default, TCP keepalive is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub tcp_keepalive_secs: Option<u64>,
+
+    /// The maximum number of TLS sessions cached for each host.
+    ///
+    /// Cached sessions are resumed when new connections are opened to the host, which saves the
+    /// full TLS handshake. Set to `0` to disable TLS session resumption.
+    #[serde(default = "default_tls_session_cache_capacity")]
+    #[configurable(metadata(docs::type_unit = "sessions"))]
+    pub tls_session_cache_capacity: usize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub http_version: HttpVersion,
+}
+
+impl Default for HttpClientOptions {
+    fn default() -> Self {
+        Self {
+            pool_max_idle_per_host: None,
+            pool_idle_timeout_secs: None,
+            tcp_keepalive_secs: None,
+            tls_session_cache_capacity: default_tls_session_cache_capacity(),
+            http_version: HttpVersion::default(),
+        }
+    }
+}
+
+impl HttpClientOptions {
+    fn apply(&self, client_builder: &mut client::Builder) {
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            client_builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool_idle_timeout_secs {
+            client_builder.pool_idle_timeout(Duration::from_secs(idle_timeout));
+        }
+        client_builder.http2_only(self.http_version == HttpVersion::Http2);
+    }
+}
+
 fn default_request_headers<B>(request: &mut Request<B>, user_agent: &HeaderValue) {
     if !request.headers().contains_key("User-Agent") {
         request
@@ -351,4 +496,34 @@ mod tests {
             Some(&HeaderValue::from_static("foo"))
         );
     }
+
+    #[test]
+    fn parses_client_options() {
+        let options: HttpClientOptions = toml::from_str(
+            r#"
+            pool_max_idle_per_host = 4
+            tcp_keepalive_secs = 30
+            http_version = "http1"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            options,
+            HttpClientOptions {
+                pool_max_idle_per_host: Some(4),
+                tcp_keepalive_secs: Some(30),
+                http_version: HttpVersion::Http1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(options.tls_session_cache_capacity, 8);
+        assert_eq!(
+            HttpVersion::Http1.alpn_protocols(),
+            Some(b"\x08http/1.1".as_slice())
+        );
+        assert_eq!(HttpVersion::Auto.alpn_protocols(), None);
+
+        let client = HttpClient::<Body>::new_with_options(None, &ProxyConfig::default(), &options);
+        assert!(client.is_ok());
+    }
 }
@@ -1,4 +1,5 @@
 use metrics::counter;
+use vector_common::internal_event::{error_stage, error_type};
 use vector_core::internal_event::InternalEvent;
 
 #[derive(Debug)]
@@ -27,3 +28,28 @@ impl InternalEvent for AggregateUpdateFailed {
         counter!("aggregate_failed_updates", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct AggregateLogValueInvalid<'a> {
+    pub field: &'a str,
+}
+
+impl InternalEvent for AggregateLogValueInvalid<'_> {
+    fn emit(self) {
+        error!(
+            message = "Field value is not a number; it is not aggregated.",
+            field = %self.field,
+            error_code = "invalid_value",
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "invalid_value",
+            "error_type" => error_type::PARSER_FAILED,
+            "stage" => error_stage::PROCESSING,
+            "field" => self.field.to_string(),
+        );
+    }
+}
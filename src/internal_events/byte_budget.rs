@@ -0,0 +1,58 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct ByteBudgetFieldsTrimmed {
+    pub count: usize,
+}
+
+impl InternalEvent for ByteBudgetFieldsTrimmed {
+    fn emit(self) {
+        trace!(
+            message = "Trimmed fields to fit event within byte budget.",
+            count = %self.count,
+        );
+        counter!("byte_budget_fields_trimmed_total", self.count as u64);
+    }
+}
+
+#[derive(Debug)]
+pub struct ByteBudgetExceeded {
+    pub size: usize,
+    pub max_bytes: usize,
+}
+
+impl InternalEvent for ByteBudgetExceeded {
+    fn emit(self) {
+        debug!(
+            message = "Event exceeds byte budget after trimming all configured fields; forwarding as-is.",
+            size = %self.size,
+            max_bytes = %self.max_bytes,
+            internal_log_rate_limit = true,
+        );
+        counter!("byte_budget_exceeded_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct ByteBudgetEventDropped {
+    pub size: usize,
+    pub max_bytes: usize,
+}
+
+impl InternalEvent for ByteBudgetEventDropped {
+    fn emit(self) {
+        debug!(
+            message = "Event exceeds byte budget after trimming all configured fields; discarding event.",
+            size = %self.size,
+            max_bytes = %self.max_bytes,
+            internal_log_rate_limit = true,
+        );
+        counter!("byte_budget_exceeded_total", 1);
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: 1,
+            reason: "Event exceeds byte budget."
+        })
+    }
+}
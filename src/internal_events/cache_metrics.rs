@@ -0,0 +1,54 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct CacheMetricsEventsReceived<'a> {
+    pub byte_size: usize,
+    pub count: usize,
+    pub endpoint: &'a str,
+}
+
+impl<'a> InternalEvent for CacheMetricsEventsReceived<'a> {
+    fn emit(self) {
+        trace!(
+            message = "Events received.",
+            byte_size = %self.byte_size,
+            count = %self.count,
+            endpoint = self.endpoint,
+        );
+        counter!(
+            "component_received_events_total", self.count as u64,
+            "endpoint" => self.endpoint.to_owned(),
+        );
+        counter!(
+            "component_received_event_bytes_total", self.byte_size as u64,
+            "endpoint" => self.endpoint.to_owned(),
+        );
+    }
+}
+
+pub struct CacheMetricsRequestError<'a> {
+    pub error: crate::Error,
+    pub endpoint: &'a str,
+}
+
+impl<'a> InternalEvent for CacheMetricsRequestError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Cache instance request error.",
+            endpoint = %self.endpoint,
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "endpoint" => self.endpoint.to_owned(),
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use metrics::counter;
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct CloudflareLogpushChallengeReceived<'a> {
+    pub content: &'a str,
+    pub filename: Option<&'a str>,
+}
+
+impl<'a> InternalEvent for CloudflareLogpushChallengeReceived<'a> {
+    fn emit(self) {
+        info!(
+            message = "Received Logpush destination validation request.",
+            content = %self.content,
+            filename = %self.filename.unwrap_or_default(),
+        );
+        counter!("requests_received_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct CloudflareLogpushLineParseError {
+    pub error: serde_json::Error,
+}
+
+impl InternalEvent for CloudflareLogpushLineParseError {
+    fn emit(self) {
+        let reason = "Line is not a JSON object.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::PARSER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
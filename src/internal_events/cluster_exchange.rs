@@ -0,0 +1,78 @@
+use metrics::{counter, gauge};
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct ClusterMembershipChanged {
+    pub peers: usize,
+}
+
+impl InternalEvent for ClusterMembershipChanged {
+    fn emit(self) {
+        info!(message = "Cluster membership changed.", peers = %self.peers);
+        gauge!("cluster_peers", self.peers as f64);
+    }
+}
+
+#[derive(Debug)]
+pub struct ClusterDiscoveryError {
+    pub error: crate::Error,
+}
+
+impl InternalEvent for ClusterDiscoveryError {
+    fn emit(self) {
+        error!(
+            message = "Failed to discover cluster peers; continuing with the previous peers.",
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ClusterForwarderError<'a> {
+    pub peer: &'a str,
+    pub error: crate::Error,
+}
+
+impl<'a> InternalEvent for ClusterForwarderError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to forward event to cluster peer; processing it locally instead.",
+            peer = %self.peer,
+            error = %self.error,
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ClusterEventsForwarded<'a> {
+    pub peer: &'a str,
+    pub count: usize,
+}
+
+impl<'a> InternalEvent for ClusterEventsForwarded<'a> {
+    fn emit(self) {
+        trace!(message = "Events forwarded to cluster peer.", peer = %self.peer, count = %self.count);
+        counter!(
+            "cluster_events_forwarded_total", self.count as u64,
+            "peer" => self.peer.to_owned(),
+        );
+    }
+}
@@ -131,3 +131,27 @@ impl<E: std::fmt::Display> InternalEvent for EncoderWriteError<'_, E> {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct EncoderNullValuesError<'a> {
+    pub column: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for EncoderNullValuesError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Values not matching the type of their column were encoded as nulls.",
+            column = %self.column,
+            count = %self.count,
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use metrics::{counter, gauge};
+use vector_core::{config::DataDirSubsystem, internal_event::InternalEvent};
+
+#[derive(Debug)]
+pub struct DataDirUsage {
+    pub subsystem: DataDirSubsystem,
+    pub used_bytes: u64,
+    pub quota_bytes: Option<u64>,
+}
+
+impl InternalEvent for DataDirUsage {
+    fn emit(self) {
+        trace!(
+            message = "Measured data directory usage.",
+            subsystem = self.subsystem.name(),
+            used_bytes = self.used_bytes,
+        );
+        gauge!(
+            "data_dir_used_bytes", self.used_bytes as f64,
+            "subsystem" => self.subsystem.name(),
+        );
+        if let Some(quota_bytes) = self.quota_bytes {
+            gauge!("data_dir_quota_bytes", quota_bytes as f64);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DataDirQuotaExceeded {
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+}
+
+impl InternalEvent for DataDirQuotaExceeded {
+    fn emit(self) {
+        error!(
+            message = "Persisted state exceeds `data_dir_quota`.",
+            used_bytes = self.used_bytes,
+            quota_bytes = self.quota_bytes,
+            internal_log_rate_limit = true,
+        );
+        counter!("data_dir_quota_exceeded_total", 1);
+    }
+}
@@ -0,0 +1,28 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct DatadogLogsInvalidTag<'a> {
+    pub tag: &'a str,
+    pub error: &'a str,
+}
+
+impl<'a> InternalEvent for DatadogLogsInvalidTag<'a> {
+    fn emit(self) {
+        error!(
+            message = "Rendered tag is not a valid Datadog tag, dropping the tag.",
+            tag = %self.tag,
+            error = %self.error,
+            error_type = error_type::TEMPLATE_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::TEMPLATE_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::Path;
+
+use metrics::counter;
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+use crate::emit;
+
+#[derive(Debug)]
+pub struct DeadLetterFileError<'a> {
+    pub error: std::io::Error,
+    pub path: &'a Path,
+    pub count: usize,
+}
+
+impl<'a> InternalEvent for DeadLetterFileError<'a> {
+    fn emit(self) {
+        let reason = "Failed writing undelivered events to the dead-letter file.";
+        error!(
+            message = reason,
+            path = ?self.path,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::IO_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason,
+        });
+    }
+}
@@ -1,5 +1,6 @@
 use crate::emit;
 use metrics::counter;
+use vector_common::internal_event::{error_stage, error_type};
 use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
 
 #[derive(Debug)]
@@ -16,3 +17,25 @@ impl InternalEvent for DedupeEventsDropped {
         counter!("events_discarded_total", self.count as u64); // Deprecated
     }
 }
+
+#[derive(Debug)]
+pub struct DedupeCacheError {
+    pub error: sled::Error,
+}
+
+impl InternalEvent for DedupeCacheError {
+    fn emit(self) {
+        error!(
+            message = "Failed to access the persistent cache; events are not deduplicated.",
+            error = %self.error,
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use metrics::counter;
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+use crate::emit;
+
+#[derive(Debug)]
+pub struct DeliveryFailuresLagged {
+    pub count: u64,
+}
+
+impl InternalEvent for DeliveryFailuresLagged {
+    fn emit(self) {
+        let reason = "Delivery failures were reported faster than they could be emitted.";
+        error!(
+            message = reason,
+            count = self.count,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count as usize,
+            reason,
+        });
+    }
+}
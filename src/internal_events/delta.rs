@@ -0,0 +1,18 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct DeltaEventsDropped {
+    pub count: usize,
+}
+
+impl InternalEvent for DeltaEventsDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "Events have no changes since the previous event with the same key.",
+        });
+        counter!("events_discarded_total", self.count as u64); // Deprecated
+    }
+}
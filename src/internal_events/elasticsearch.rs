@@ -0,0 +1,38 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+
+#[derive(Debug)]
+pub struct ElasticsearchMappingConflictError<'a> {
+    pub error: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for ElasticsearchMappingConflictError<'_> {
+    fn emit(self) {
+        let reason = "Documents rejected because of mapping conflicts.";
+        error!(
+            message = reason,
+            error = %self.error,
+            count = %self.count,
+            error_code = "mapping_conflict",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "mapping_conflict",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason
+        });
+    }
+}
@@ -0,0 +1,82 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+/// An enrichment table that refreshes its data in the background.
+#[derive(Clone, Copy, Debug)]
+pub enum RefreshedTable {
+    File,
+    #[cfg(feature = "enrichment-tables-geoip")]
+    Geoip,
+    #[cfg(feature = "enrichment-tables-sqlite")]
+    Sqlite,
+}
+
+impl RefreshedTable {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::File => "file",
+            #[cfg(feature = "enrichment-tables-geoip")]
+            Self::Geoip => "geoip",
+            #[cfg(feature = "enrichment-tables-sqlite")]
+            Self::Sqlite => "sqlite",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EnrichmentTableRefreshed<'a> {
+    pub table: RefreshedTable,
+    pub path: &'a str,
+}
+
+impl<'a> InternalEvent for EnrichmentTableRefreshed<'a> {
+    fn emit(self) {
+        debug!(
+            message = "Enrichment table refreshed.",
+            table_type = self.table.as_str(),
+            path = %self.path,
+        );
+        match self.table {
+            RefreshedTable::File => counter!("file_table_refresh_successful_total", 1),
+            #[cfg(feature = "enrichment-tables-geoip")]
+            RefreshedTable::Geoip => counter!("geoip_refresh_successful_total", 1),
+            #[cfg(feature = "enrichment-tables-sqlite")]
+            RefreshedTable::Sqlite => counter!("sqlite_table_refresh_successful_total", 1),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EnrichmentTableRefreshError<'a> {
+    pub table: RefreshedTable,
+    pub path: &'a str,
+    pub error: crate::Error,
+}
+
+impl<'a> InternalEvent for EnrichmentTableRefreshError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Enrichment table refresh failed; continuing to use the previously loaded data.",
+            table_type = self.table.as_str(),
+            path = %self.path,
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+        match self.table {
+            RefreshedTable::File => counter!("file_table_refresh_failed_total", 1),
+            #[cfg(feature = "enrichment-tables-geoip")]
+            RefreshedTable::Geoip => counter!("geoip_refresh_failed_total", 1),
+            #[cfg(feature = "enrichment-tables-sqlite")]
+            RefreshedTable::Sqlite => counter!("sqlite_table_refresh_failed_total", 1),
+        }
+    }
+}
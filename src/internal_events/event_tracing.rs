@@ -0,0 +1,17 @@
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct TracedEventObserved {
+    pub trace_id: u64,
+    pub stage: &'static str,
+}
+
+impl InternalEvent for TracedEventObserved {
+    fn emit(self) {
+        debug!(
+            message = "Traced event observed.",
+            trace_id = %format!("{:016x}", self.trace_id),
+            stage = self.stage,
+        );
+    }
+}
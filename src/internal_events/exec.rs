@@ -75,6 +75,33 @@ impl InternalEvent for ExecFailedError<'_> {
     }
 }
 
+#[derive(Debug)]
+pub struct ExecAcknowledgementWriteError<'a> {
+    pub command: &'a str,
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for ExecAcknowledgementWriteError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Unable to write acknowledgement to the command.",
+            command = %self.command,
+            error = ?self.error,
+            error_type = error_type::COMMAND_FAILED,
+            error_code = %io_error_code(&self.error),
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "command" => self.command.to_owned(),
+            "error_type" => error_type::COMMAND_FAILED,
+            "error_code" => io_error_code(&self.error),
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct ExecTimeoutError<'a> {
     pub command: &'a str,
@@ -0,0 +1,22 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct FeatureFlagsLoadError {
+    pub error: crate::Error,
+}
+
+impl InternalEvent for FeatureFlagsLoadError {
+    fn emit(self) {
+        error!(
+            message = "Failed to load feature flags; continuing to use the previously loaded flags.",
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!("feature_flags_load_errors_total", 1);
+    }
+}
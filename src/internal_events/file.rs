@@ -242,6 +242,55 @@ mod source {
         }
     }
 
+    const ARCHIVING_FAILED: &str = "archiving_failed";
+
+    #[derive(Debug)]
+    pub struct FileArchiveError<'a> {
+        pub file: &'a Path,
+        pub error: Error,
+    }
+
+    impl<'a> InternalEvent for FileArchiveError<'a> {
+        fn emit(self) {
+            error!(
+                message = "Failed in archiving file.",
+                file = %self.file.display(),
+                error = %self.error,
+                error_code = ARCHIVING_FAILED,
+                error_type = error_type::COMMAND_FAILED,
+                stage = error_stage::RECEIVING,
+                internal_log_rate_limit = true,
+            );
+            counter!(
+                "component_errors_total", 1,
+                "file" => self.file.to_string_lossy().into_owned(),
+                "error_code" => ARCHIVING_FAILED,
+                "error_type" => error_type::COMMAND_FAILED,
+                "stage" => error_stage::RECEIVING,
+            );
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct FileArchived<'a> {
+        pub file: &'a Path,
+        pub archive_file: &'a Path,
+    }
+
+    impl<'a> InternalEvent for FileArchived<'a> {
+        fn emit(self) {
+            info!(
+                message = "File archived.",
+                file = %self.file.display(),
+                archive_file = %self.archive_file.display(),
+            );
+            counter!(
+                "files_archived_total", 1,
+                "file" => self.file.to_string_lossy().into_owned(),
+            );
+        }
+    }
+
     #[derive(Debug)]
     pub struct FileUnwatched<'a> {
         pub file: &'a Path,
@@ -435,6 +484,14 @@ mod source {
             emit!(FileDeleteError { file, error });
         }
 
+        fn emit_file_archived(&self, file: &Path, archive_file: &Path) {
+            emit!(FileArchived { file, archive_file });
+        }
+
+        fn emit_file_archive_error(&self, file: &Path, error: Error) {
+            emit!(FileArchiveError { file, error });
+        }
+
         fn emit_file_fingerprint_read_error(&self, file: &Path, error: Error) {
             emit!(FileFingerprintReadError { file, error });
         }
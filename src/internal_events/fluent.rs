@@ -1,7 +1,7 @@
 use metrics::counter;
 use vector_core::internal_event::InternalEvent;
 
-use crate::sources::fluent::DecodeError;
+use crate::sources::fluent::{DecodeError, HandshakeError};
 use vector_common::internal_event::{error_stage, error_type};
 
 #[derive(Debug)]
@@ -42,3 +42,27 @@ impl<'a> InternalEvent for FluentMessageDecodeError<'a> {
         counter!("decode_errors_total", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct FluentHandshakeError {
+    pub error: HandshakeError,
+}
+
+impl InternalEvent for FluentHandshakeError {
+    fn emit(self) {
+        error!(
+            message = "Fluent handshake failed, closing connection.",
+            error = %self.error,
+            error_code = "handshake_failed",
+            error_type = error_type::CONNECTION_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "handshake_failed",
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+
+#[derive(Debug)]
+pub struct ChronicleUdmMappingError<'a> {
+    pub log_type: &'a str,
+    pub error: String,
+}
+
+impl<'a> InternalEvent for ChronicleUdmMappingError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to map event to a UDM event.",
+            log_type = %self.log_type,
+            error = %self.error,
+            error_code = "udm_mapping_failed",
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "udm_mapping_failed",
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: 1,
+            reason: "Event could not be mapped to a UDM event."
+        });
+    }
+}
@@ -0,0 +1,27 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct JoinEventsDropped {
+    pub count: usize,
+    pub reason: &'static str,
+}
+
+impl InternalEvent for JoinEventsDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: self.reason,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct JoinUnmatchedFlushed;
+
+impl InternalEvent for JoinUnmatchedFlushed {
+    fn emit(self) {
+        counter!("unmatched_joins_flushed_total", 1);
+    }
+}
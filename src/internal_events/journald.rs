@@ -122,3 +122,27 @@ impl InternalEvent for JournaldCheckpointFileOpenError {
         );
     }
 }
+
+#[derive(Debug)]
+pub struct JournaldGatewayRequestError {
+    pub endpoint: String,
+    pub error: crate::Error,
+}
+
+impl InternalEvent for JournaldGatewayRequestError {
+    fn emit(self) {
+        error!(
+            message = "Failed reading remote journal.",
+            endpoint = %self.endpoint,
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "stage" => error_stage::RECEIVING,
+            "error_type" => error_type::REQUEST_FAILED,
+        );
+    }
+}
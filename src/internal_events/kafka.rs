@@ -1,4 +1,7 @@
-use metrics::{counter, gauge};
+use std::time::Duration;
+
+use metrics::{counter, gauge, histogram};
+use rdkafka::{error::KafkaError, types::RDKafkaErrorCode};
 use vector_core::{internal_event::InternalEvent, update_counter};
 
 use vector_common::internal_event::{error_stage, error_type};
@@ -111,6 +114,32 @@ impl InternalEvent for KafkaReadError {
     }
 }
 
+#[derive(Debug)]
+pub struct KafkaPartitionPauseError {
+    pub error: rdkafka::error::KafkaError,
+    pub operation: &'static str,
+}
+
+impl InternalEvent for KafkaPartitionPauseError {
+    fn emit(self) {
+        error!(
+            message = "Unable to pause or resume fetching from assigned partitions.",
+            error = %self.error,
+            operation = self.operation,
+            error_code = "kafka_partition_pause",
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "kafka_partition_pause",
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct KafkaStatisticsReceived<'a> {
     pub statistics: &'a rdkafka::Statistics,
@@ -153,7 +182,13 @@ impl InternalEvent for KafkaStatisticsReceived<'_> {
 
         if self.expose_lag_metrics {
             for (topic_id, topic) in &self.statistics.topics {
-                for (partition_id, partition) in &topic.partitions {
+                // The internal `-1` partition of librdkafka and partitions whose lag isn't known yet
+                // don't report a meaningful lag.
+                for (partition_id, partition) in topic
+                    .partitions
+                    .iter()
+                    .filter(|(id, partition)| **id >= 0 && partition.consumer_lag >= 0)
+                {
                     gauge!("kafka_consumer_lag", partition.consumer_lag as f64, "topic_id" => topic_id.clone(), "partition_id" => partition_id.to_string());
                 }
             }
@@ -185,3 +220,132 @@ impl InternalEvent for KafkaHeaderExtractionError<'_> {
         counter!("kafka_header_extraction_failures_total", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct KafkaTransactionInitError {
+    pub error: rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaTransactionInitError {
+    fn emit(self) {
+        error!(
+            message = "Failed to initialize transactions.",
+            error = %self.error,
+            error_code = "kafka_transaction_init",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "kafka_transaction_init",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct KafkaTransactionAbortError {
+    pub error: rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaTransactionAbortError {
+    fn emit(self) {
+        error!(
+            message = "Failed to abort transaction.",
+            error = %self.error,
+            error_code = "kafka_transaction_abort",
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "kafka_transaction_abort",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct KafkaMessageDelivered<'a> {
+    pub topic: &'a str,
+    pub partition: i32,
+    pub latency: Duration,
+}
+
+impl InternalEvent for KafkaMessageDelivered<'_> {
+    fn emit(self) {
+        trace!(
+            message = "Message delivered.",
+            topic = self.topic,
+            partition = %self.partition,
+            latency_ms = %self.latency.as_millis(),
+        );
+        counter!(
+            "kafka_delivery_reports_total", 1,
+            "topic" => self.topic.to_string(),
+            "partition" => self.partition.to_string(),
+            "status" => "delivered",
+        );
+        histogram!(
+            "kafka_delivery_latency_seconds", self.latency,
+            "topic" => self.topic.to_string(),
+            "partition" => self.partition.to_string(),
+            "status" => "delivered",
+        );
+    }
+}
+
+/// A message that the producer failed to deliver.
+///
+/// The failed request is reported as an error by the sink itself, so this only breaks the failures
+/// down by topic, partition, and cause.
+#[derive(Debug)]
+pub struct KafkaMessageDeliveryError<'a> {
+    pub error: &'a KafkaError,
+    pub topic: &'a str,
+    pub partition: i32,
+    pub latency: Duration,
+}
+
+impl KafkaMessageDeliveryError<'_> {
+    /// Distinguishes messages that timed out in the producer's queue, from the ones rejected by the
+    /// brokers, and the ones that failed within the client.
+    fn status(&self) -> &'static str {
+        match self.error {
+            KafkaError::MessageProduction(RDKafkaErrorCode::MessageTimedOut) => "timed_out",
+            // librdkafka's own errors have negative codes, while the brokers' are positive.
+            KafkaError::MessageProduction(code) if *code as i32 > 0 => "broker_error",
+            _ => "client_error",
+        }
+    }
+}
+
+impl InternalEvent for KafkaMessageDeliveryError<'_> {
+    fn emit(self) {
+        let status = self.status();
+        debug!(
+            message = "Failed to deliver message.",
+            error = %self.error,
+            status,
+            topic = self.topic,
+            partition = %self.partition,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "kafka_delivery_reports_total", 1,
+            "topic" => self.topic.to_string(),
+            "partition" => self.partition.to_string(),
+            "status" => status,
+        );
+        histogram!(
+            "kafka_delivery_latency_seconds", self.latency,
+            "topic" => self.topic.to_string(),
+            "partition" => self.partition.to_string(),
+            "status" => status,
+        );
+    }
+}
@@ -164,6 +164,42 @@ impl InternalEvent for KubernetesLogsDockerFormatParseError<'_> {
     }
 }
 
+#[derive(Debug)]
+pub struct KubernetesLogsPartialLinesMerged {
+    pub fragments: usize,
+    pub complete: bool,
+}
+
+impl InternalEvent for KubernetesLogsPartialLinesMerged {
+    fn emit(self) {
+        trace!(
+            message = "Merged partial lines.",
+            fragments = %self.fragments,
+            complete = %self.complete,
+        );
+        counter!(
+            "k8s_partial_lines_merged_total", 1,
+            "complete" => self.complete.to_string(),
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct KubernetesLogsDuplicateLineSuppressed<'a> {
+    pub file: &'a str,
+}
+
+impl InternalEvent for KubernetesLogsDuplicateLineSuppressed<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Suppressed duplicate log line read during a log rotation.",
+            file = %self.file,
+            internal_log_rate_limit = true,
+        );
+        counter!("k8s_duplicate_lines_suppressed_total", 1);
+    }
+}
+
 const KUBERNETES_LIFECYCLE: &str = "kubernetes_lifecycle";
 
 #[derive(Debug)]
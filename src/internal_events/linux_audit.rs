@@ -0,0 +1,50 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct LinuxAuditReceiveError {
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for LinuxAuditReceiveError {
+    fn emit(self) {
+        error!(
+            message = "Error receiving audit records.",
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "stage" => error_stage::RECEIVING,
+            "error_type" => error_type::READER_FAILED,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct LinuxAuditParseError<'a> {
+    pub error: &'a dyn std::error::Error,
+    pub record: &'a str,
+}
+
+impl InternalEvent for LinuxAuditParseError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Invalid audit record, discarding.",
+            error = %self.error,
+            record = %self.record,
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "stage" => error_stage::PROCESSING,
+            "error_type" => error_type::PARSER_FAILED,
+        );
+    }
+}
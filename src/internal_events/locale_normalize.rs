@@ -0,0 +1,30 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+use crate::transforms::locale_normalize::NormalizeError;
+
+#[derive(Debug)]
+pub struct LocaleNormalizeError<'a> {
+    pub field: &'a str,
+    pub error: NormalizeError,
+}
+
+impl<'a> InternalEvent for LocaleNormalizeError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to normalize localized value; leaving the field unchanged.",
+            field = %self.field,
+            error = %self.error,
+            error_type = error_type::CONVERSION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::CONVERSION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct LogPatternCreated<'a> {
+    pub id: u64,
+    pub template: &'a str,
+}
+
+impl<'a> InternalEvent for LogPatternCreated<'a> {
+    fn emit(self) {
+        debug!(
+            message = "New log pattern found.",
+            pattern_id = self.id,
+            pattern = %self.template,
+            internal_log_rate_limit = true,
+        );
+        counter!("log_patterns_created_total", 1);
+    }
+}
@@ -0,0 +1,43 @@
+use metrics::counter;
+use vector_common::internal_event::{ComponentEventsDropped, INTENTIONAL};
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct MetricNameCollision<'a> {
+    /// What collided, either the name of metrics or the key of tags.
+    pub kind: &'static str,
+    pub sanitized: &'a str,
+    pub original: &'a str,
+    pub other: &'a str,
+}
+
+impl<'a> InternalEvent for MetricNameCollision<'a> {
+    fn emit(self) {
+        warn!(
+            message = "Different names are the same once sanitized.",
+            kind = %self.kind,
+            sanitized = %self.sanitized,
+            original = %self.original,
+            other = %self.other,
+            internal_log_rate_limit = true,
+        );
+        counter!("metric_name_collisions_total", 1, "kind" => self.kind);
+    }
+}
+
+#[derive(Debug)]
+pub struct MetricNameInvalid<'a> {
+    pub name: &'a str,
+}
+
+impl<'a> InternalEvent for MetricNameInvalid<'a> {
+    fn emit(self) {
+        let reason = "Metric name or tag key is invalid for the destination.";
+        debug!(
+            message = reason,
+            name = %self.name,
+            internal_log_rate_limit = true,
+        );
+        emit!(ComponentEventsDropped::<INTENTIONAL> { count: 1, reason });
+    }
+}
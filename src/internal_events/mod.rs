@@ -27,32 +27,55 @@ mod aws_kinesis_firehose;
 #[cfg(any(feature = "sources-aws_s3", feature = "sources-aws_sqs",))]
 mod aws_sqs;
 mod batch;
+#[cfg(feature = "transforms-byte_budget")]
+mod byte_budget;
+#[cfg(feature = "sources-cache_metrics")]
+mod cache_metrics;
+#[cfg(feature = "sources-cloudflare_logpush")]
+mod cloudflare_logpush;
+#[cfg(feature = "transforms-cluster_exchange")]
+mod cluster_exchange;
 mod codecs;
 mod common;
 mod conditions;
+mod data_dir;
+#[cfg(feature = "sinks-datadog_logs")]
+mod datadog_logs;
 #[cfg(feature = "sinks-datadog_metrics")]
 mod datadog_metrics;
 #[cfg(feature = "sinks-datadog_traces")]
 mod datadog_traces;
+mod dead_letter;
 #[cfg(feature = "transforms-dedupe")]
 mod dedupe;
+#[cfg(feature = "sources-delivery_failures")]
+mod delivery_failures;
+#[cfg(feature = "transforms-delta")]
+mod delta;
 #[cfg(feature = "sources-demo_logs")]
 mod demo_logs;
 #[cfg(feature = "sources-dnstap")]
 mod dnstap;
 #[cfg(feature = "sources-docker_logs")]
 mod docker_logs;
+#[cfg(feature = "sinks-elasticsearch")]
+mod elasticsearch;
 mod encoding_transcode;
+mod enrichment_table;
+mod event_tracing;
 #[cfg(feature = "sources-eventstoredb_metrics")]
 mod eventstoredb_metrics;
 #[cfg(feature = "sources-exec")]
 mod exec;
+mod feature_flags;
 #[cfg(any(feature = "sources-file-descriptor", feature = "sources-stdin"))]
 mod file_descriptor;
 #[cfg(feature = "transforms-filter")]
 mod filter;
 #[cfg(feature = "sources-fluent")]
 mod fluent;
+#[cfg(feature = "sinks-gcp")]
+mod gcp_chronicle;
 #[cfg(feature = "sources-gcp_pubsub")]
 mod gcp_pubsub;
 #[cfg(any(feature = "sources-vector", feature = "sources-opentelemetry"))]
@@ -70,28 +93,48 @@ mod influxdb;
 mod internal_logs;
 #[cfg(feature = "sources-internal_metrics")]
 mod internal_metrics;
+#[cfg(feature = "transforms-join")]
+mod join;
 #[cfg(all(unix, feature = "sources-journald"))]
 mod journald;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
 mod kafka;
 #[cfg(feature = "sources-kubernetes_logs")]
 mod kubernetes_logs;
+#[cfg(all(target_os = "linux", feature = "sources-linux_audit"))]
+mod linux_audit;
+#[cfg(feature = "transforms-locale_normalize")]
+mod locale_normalize;
+#[cfg(feature = "transforms-log_patterns")]
+mod log_patterns;
 mod log_to_metric;
 mod logplex;
 #[cfg(feature = "sinks-loki")]
 mod loki;
 #[cfg(feature = "transforms-lua")]
 mod lua;
+#[cfg(any(
+    feature = "sinks-datadog_metrics",
+    feature = "sinks-prometheus",
+    feature = "sinks-statsd"
+))]
+mod metric_names;
 #[cfg(feature = "transforms-metric_to_log")]
 mod metric_to_log;
 #[cfg(feature = "sources-mongodb_metrics")]
 mod mongodb_metrics;
+#[cfg(feature = "sinks-mqtt")]
+mod mqtt;
+#[cfg(all(feature = "sinks-socket", windows))]
+mod named_pipe;
 #[cfg(feature = "sinks-nats")]
 mod nats;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
 mod open;
 mod parser;
+#[cfg(feature = "transforms-pii_redact")]
+mod pii_redact;
 #[cfg(feature = "sources-postgresql_metrics")]
 mod postgresql_metrics;
 mod process;
@@ -99,15 +142,21 @@ mod process;
 mod prometheus;
 #[cfg(feature = "sinks-pulsar")]
 mod pulsar;
+#[cfg(feature = "transforms-reassemble")]
+mod reassemble;
 #[cfg(feature = "sources-redis")]
 mod redis;
 #[cfg(feature = "transforms-reduce")]
 mod reduce;
 mod remap;
 mod sample;
+#[cfg(feature = "transforms-schema_validation")]
+mod schema_validation;
 #[cfg(feature = "sinks-sematext")]
 mod sematext_metrics;
 mod socket;
+#[cfg(feature = "transforms-span_metrics")]
+mod span_metrics;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 mod splunk_hec;
 #[cfg(feature = "sinks-statsd")]
@@ -119,7 +168,12 @@ mod template;
 #[cfg(feature = "transforms-throttle")]
 mod throttle;
 mod udp;
+#[cfg(feature = "transforms-unit_convert")]
+mod unit_convert;
 mod unix;
+#[cfg(feature = "transforms-user_agent")]
+mod user_agent;
+mod watchdog;
 #[cfg(feature = "sinks-websocket")]
 mod websocket;
 
@@ -133,6 +187,8 @@ mod windows;
 
 #[cfg(feature = "sources-mongodb_metrics")]
 pub(crate) use mongodb_metrics::*;
+#[cfg(feature = "sinks-mqtt")]
+pub(crate) use mqtt::*;
 
 #[cfg(feature = "transforms-aggregate")]
 pub(crate) use self::aggregate::*;
@@ -159,19 +215,35 @@ pub(crate) use self::aws_kinesis::*;
 pub(crate) use self::aws_kinesis_firehose::*;
 #[cfg(any(feature = "sources-aws_s3", feature = "sources-aws_sqs",))]
 pub(crate) use self::aws_sqs::*;
+#[cfg(feature = "transforms-byte_budget")]
+pub(crate) use self::byte_budget::*;
+#[cfg(feature = "sources-cache_metrics")]
+pub(crate) use self::cache_metrics::*;
+#[cfg(feature = "sources-cloudflare_logpush")]
+pub(crate) use self::cloudflare_logpush::*;
+#[cfg(feature = "transforms-cluster_exchange")]
+pub(crate) use self::cluster_exchange::*;
 pub(crate) use self::codecs::*;
+#[cfg(feature = "sinks-datadog_logs")]
+pub(crate) use self::datadog_logs::*;
 #[cfg(feature = "sinks-datadog_metrics")]
 pub(crate) use self::datadog_metrics::*;
 #[cfg(feature = "sinks-datadog_traces")]
 pub(crate) use self::datadog_traces::*;
 #[cfg(feature = "transforms-dedupe")]
 pub(crate) use self::dedupe::*;
+#[cfg(feature = "sources-delivery_failures")]
+pub(crate) use self::delivery_failures::*;
+#[cfg(feature = "transforms-delta")]
+pub(crate) use self::delta::*;
 #[cfg(feature = "sources-demo_logs")]
 pub(crate) use self::demo_logs::*;
 #[cfg(feature = "sources-dnstap")]
 pub(crate) use self::dnstap::*;
 #[cfg(feature = "sources-docker_logs")]
 pub(crate) use self::docker_logs::*;
+#[cfg(feature = "sinks-elasticsearch")]
+pub(crate) use self::elasticsearch::*;
 #[cfg(feature = "sources-eventstoredb_metrics")]
 pub(crate) use self::eventstoredb_metrics::*;
 #[cfg(feature = "sources-exec")]
@@ -188,6 +260,8 @@ pub(crate) use self::file_descriptor::*;
 pub(crate) use self::filter::*;
 #[cfg(feature = "sources-fluent")]
 pub(crate) use self::fluent::*;
+#[cfg(feature = "sinks-gcp")]
+pub(crate) use self::gcp_chronicle::*;
 #[cfg(feature = "sources-gcp_pubsub")]
 pub(crate) use self::gcp_pubsub::*;
 #[cfg(any(feature = "sources-vector", feature = "sources-opentelemetry"))]
@@ -209,12 +283,20 @@ pub(crate) use self::influxdb::*;
 pub(crate) use self::internal_logs::*;
 #[cfg(feature = "sources-internal_metrics")]
 pub(crate) use self::internal_metrics::*;
+#[cfg(feature = "transforms-join")]
+pub(crate) use self::join::*;
 #[cfg(all(unix, feature = "sources-journald"))]
 pub(crate) use self::journald::*;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
 pub(crate) use self::kafka::*;
 #[cfg(feature = "sources-kubernetes_logs")]
 pub(crate) use self::kubernetes_logs::*;
+#[cfg(all(target_os = "linux", feature = "sources-linux_audit"))]
+pub(crate) use self::linux_audit::*;
+#[cfg(feature = "transforms-locale_normalize")]
+pub(crate) use self::locale_normalize::*;
+#[cfg(feature = "transforms-log_patterns")]
+pub(crate) use self::log_patterns::*;
 pub(crate) use self::log_to_metric::*;
 #[cfg(feature = "sources-heroku_logs")]
 pub(crate) use self::logplex::*;
@@ -222,19 +304,31 @@ pub(crate) use self::logplex::*;
 pub(crate) use self::loki::*;
 #[cfg(feature = "transforms-lua")]
 pub(crate) use self::lua::*;
+#[cfg(any(
+    feature = "sinks-datadog_metrics",
+    feature = "sinks-prometheus",
+    feature = "sinks-statsd"
+))]
+pub(crate) use self::metric_names::*;
 #[cfg(feature = "transforms-metric_to_log")]
 pub(crate) use self::metric_to_log::*;
+#[cfg(all(feature = "sinks-socket", windows))]
+pub(crate) use self::named_pipe::*;
 #[cfg(feature = "sinks-nats")]
 pub(crate) use self::nats::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
 pub(crate) use self::parser::*;
+#[cfg(feature = "transforms-pii_redact")]
+pub(crate) use self::pii_redact::*;
 #[cfg(feature = "sources-postgresql_metrics")]
 pub(crate) use self::postgresql_metrics::*;
 #[cfg(any(feature = "sources-prometheus", feature = "sinks-prometheus"))]
 pub(crate) use self::prometheus::*;
 #[cfg(feature = "sinks-pulsar")]
 pub(crate) use self::pulsar::*;
+#[cfg(feature = "transforms-reassemble")]
+pub(crate) use self::reassemble::*;
 #[cfg(feature = "sources-redis")]
 pub(crate) use self::redis::*;
 #[cfg(feature = "transforms-reduce")]
@@ -243,8 +337,12 @@ pub(crate) use self::reduce::*;
 pub(crate) use self::remap::*;
 #[cfg(feature = "transforms-sample")]
 pub(crate) use self::sample::*;
+#[cfg(feature = "transforms-schema_validation")]
+pub(crate) use self::schema_validation::*;
 #[cfg(feature = "sinks-sematext")]
 pub(crate) use self::sematext_metrics::*;
+#[cfg(feature = "transforms-span_metrics")]
+pub(crate) use self::span_metrics::*;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 pub(crate) use self::splunk_hec::*;
 #[cfg(feature = "sinks-statsd")]
@@ -253,6 +351,8 @@ pub(crate) use self::statsd_sink::*;
 pub(crate) use self::tag_cardinality_limit::*;
 #[cfg(feature = "transforms-throttle")]
 pub(crate) use self::throttle::*;
+#[cfg(feature = "transforms-unit_convert")]
+pub(crate) use self::unit_convert::*;
 #[cfg(all(
     any(
         feature = "sinks-socket",
@@ -266,13 +366,16 @@ pub(crate) use self::throttle::*;
     unix
 ))]
 pub(crate) use self::unix::*;
+#[cfg(feature = "transforms-user_agent")]
+pub(crate) use self::user_agent::*;
 #[cfg(feature = "sinks-websocket")]
 pub(crate) use self::websocket::*;
 #[cfg(windows)]
 pub(crate) use self::windows::*;
 pub(crate) use self::{
-    adaptive_concurrency::*, batch::*, common::*, conditions::*, encoding_transcode::*,
-    heartbeat::*, open::*, process::*, socket::*, tcp::*, template::*, udp::*,
+    adaptive_concurrency::*, batch::*, common::*, conditions::*, data_dir::*, dead_letter::*,
+    encoding_transcode::*, enrichment_table::*, event_tracing::*, feature_flags::*, heartbeat::*,
+    open::*, process::*, socket::*, tcp::*, template::*, udp::*, watchdog::*,
 };
 
 // this version won't be needed once all `InternalEvent`s implement `name()`
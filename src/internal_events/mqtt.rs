@@ -0,0 +1,53 @@
+use crate::emit;
+use metrics::counter;
+use rumqttc::{ClientError, ConnectionError};
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct MqttConnectionError {
+    pub error: ConnectionError,
+}
+
+impl InternalEvent for MqttConnectionError {
+    fn emit(self) {
+        error!(
+            message = "MQTT connection error.",
+            error = %self.error,
+            error_type = error_type::CONNECTION_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct MqttPublishError {
+    pub error: ClientError,
+}
+
+impl InternalEvent for MqttPublishError {
+    fn emit(self) {
+        let reason = "Failed to publish message.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
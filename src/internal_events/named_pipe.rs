@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use metrics::counter;
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+use crate::{emit, internal_events::SocketOutgoingConnectionError};
+
+#[derive(Debug)]
+pub struct NamedPipeConnectionEstablished<'a> {
+    pub path: &'a Path,
+}
+
+impl InternalEvent for NamedPipeConnectionEstablished<'_> {
+    fn emit(self) {
+        debug!(message = "Connected.", path = ?self.path);
+        counter!("connection_established_total", 1, "mode" => "named_pipe");
+    }
+}
+
+#[derive(Debug)]
+pub struct NamedPipeOutgoingConnectionError<E> {
+    pub error: E,
+}
+
+impl<E: std::error::Error> InternalEvent for NamedPipeOutgoingConnectionError<E> {
+    fn emit(self) {
+        // ## skip check-duplicate-events ##
+        // ## skip check-validity-events ##
+        emit!(SocketOutgoingConnectionError { error: self.error });
+        // deprecated
+        counter!("connection_failed_total", 1, "mode" => "named_pipe");
+    }
+}
+
+#[derive(Debug)]
+pub struct NamedPipeSendError<'a, E> {
+    pub(crate) error: &'a E,
+    pub path: &'a Path,
+}
+
+impl<E: std::fmt::Display> InternalEvent for NamedPipeSendError<'_, E> {
+    fn emit(self) {
+        let reason = "Named pipe send error.";
+        error!(
+            message = reason,
+            error = %self.error,
+            path = ?self.path,
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        // deprecated
+        counter!("connection_errors_total", 1, "mode" => "named_pipe");
+
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
@@ -0,0 +1,27 @@
+use crate::emit;
+use metrics::counter;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct ReassembleEventsDropped {
+    pub count: usize,
+    pub reason: &'static str,
+}
+
+impl InternalEvent for ReassembleEventsDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: self.reason,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct ReassembleIncompleteFlushed;
+
+impl InternalEvent for ReassembleIncompleteFlushed {
+    fn emit(self) {
+        counter!("incomplete_reassemblies_flushed_total", 1);
+    }
+}
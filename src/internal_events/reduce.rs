@@ -9,3 +9,12 @@ impl InternalEvent for ReduceStaleEventFlushed {
         counter!("stale_events_flushed_total", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct ReduceGroupEvicted;
+
+impl InternalEvent for ReduceGroupEvicted {
+    fn emit(self) {
+        counter!("groups_evicted_total", 1);
+    }
+}
@@ -61,3 +61,18 @@ impl InternalEvent for RemapMappingAbort {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct RemapCacheLookup {
+    pub hit: bool,
+}
+
+impl InternalEvent for RemapCacheLookup {
+    fn emit(self) {
+        if self.hit {
+            counter!("remap_cache_hits_total", 1);
+        } else {
+            counter!("remap_cache_misses_total", 1);
+        }
+    }
+}
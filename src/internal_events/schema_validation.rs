@@ -0,0 +1,27 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct SchemaValidationFailed {
+    pub count: usize,
+}
+
+impl InternalEvent for SchemaValidationFailed {
+    fn emit(self) {
+        error!(
+            message = "Event failed schema validation; rerouting to the invalid output.",
+            violations = %self.count,
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+        counter!("schema_violations_total", self.count as u64);
+    }
+}
@@ -10,6 +10,8 @@ pub enum SocketMode {
     Tcp,
     Udp,
     Unix,
+    #[cfg(windows)]
+    NamedPipe,
 }
 
 impl SocketMode {
@@ -18,6 +20,8 @@ impl SocketMode {
             Self::Tcp => "tcp",
             Self::Udp => "udp",
             Self::Unix => "unix",
+            #[cfg(windows)]
+            Self::NamedPipe => "named_pipe",
         }
     }
 }
@@ -0,0 +1,22 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct SpanMetricsSpansRecorded {
+    pub count: usize,
+}
+
+impl InternalEvent for SpanMetricsSpansRecorded {
+    fn emit(self) {
+        counter!("span_metrics_spans_recorded_total", self.count as u64);
+    }
+}
+
+#[derive(Debug)]
+pub struct SpanMetricsFlushed;
+
+impl InternalEvent for SpanMetricsFlushed {
+    fn emit(self) {
+        counter!("span_metrics_flushes_total", 1);
+    }
+}
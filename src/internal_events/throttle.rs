@@ -5,16 +5,22 @@ use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTI
 #[derive(Debug)]
 pub(crate) struct ThrottleEventDiscarded {
     pub key: String,
+    /// If set to true, the event was sent to the `overflow` output rather than dropped.
+    pub rerouted: bool,
 }
 
 impl InternalEvent for ThrottleEventDiscarded {
     fn emit(self) {
+        // Rerouted events aren't discarded.
+        if self.rerouted {
+            return;
+        }
+
         debug!(message = "Rate limit exceeded.", key = ?self.key); // Deprecated.
         counter!(
             "events_discarded_total", 1,
             "key" => self.key,
         ); // Deprecated.
-
         emit!(ComponentEventsDropped::<INTENTIONAL> {
             count: 1,
             reason: "Rate limit exceeded."
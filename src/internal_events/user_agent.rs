@@ -0,0 +1,41 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use vector_common::internal_event::{error_stage, error_type};
+
+#[derive(Debug)]
+pub struct UserAgentRulesetRefreshed<'a> {
+    pub path: &'a str,
+}
+
+impl<'a> InternalEvent for UserAgentRulesetRefreshed<'a> {
+    fn emit(self) {
+        debug!(message = "User agent ruleset refreshed.", path = %self.path);
+        counter!("user_agent_ruleset_refresh_successful_total", 1);
+    }
+}
+
+#[derive(Debug)]
+pub struct UserAgentRulesetRefreshError<'a> {
+    pub path: &'a str,
+    pub error: crate::Error,
+}
+
+impl<'a> InternalEvent for UserAgentRulesetRefreshError<'a> {
+    fn emit(self) {
+        error!(
+            message = "User agent ruleset refresh failed; continuing to use the previously loaded ruleset.",
+            path = %self.path,
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+        counter!("user_agent_ruleset_refresh_failed_total", 1);
+    }
+}
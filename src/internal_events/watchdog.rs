@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+use crate::config::ComponentKey;
+
+#[derive(Debug)]
+pub struct ComponentStalled<'a> {
+    pub component_id: &'a ComponentKey,
+    pub component_kind: &'static str,
+    pub component_type: &'a str,
+    pub reason: &'static str,
+    pub stalled_for: Duration,
+    pub stack: Option<String>,
+}
+
+impl<'a> InternalEvent for ComponentStalled<'a> {
+    fn emit(self) {
+        error!(
+            message = "Component stalled.",
+            component_id = %self.component_id,
+            component_kind = self.component_kind,
+            component_type = self.component_type,
+            reason = self.reason,
+            stalled_for_secs = self.stalled_for.as_secs(),
+            stack = self.stack.as_deref().unwrap_or("unavailable"),
+        );
+        counter!(
+            "component_stalls_total", 1,
+            "component_id" => self.component_id.to_string(),
+            "component_kind" => self.component_kind,
+            "component_type" => self.component_type.to_owned(),
+            "reason" => self.reason,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ComponentStallRecovered<'a> {
+    pub component_id: &'a ComponentKey,
+    pub stalled_for: Duration,
+}
+
+impl<'a> InternalEvent for ComponentStallRecovered<'a> {
+    fn emit(self) {
+        warn!(
+            message = "Component recovered from stall.",
+            component_id = %self.component_id,
+            stalled_for_secs = self.stalled_for.as_secs(),
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ComponentRestarted<'a> {
+    pub component_id: &'a ComponentKey,
+    pub success: bool,
+}
+
+impl<'a> InternalEvent for ComponentRestarted<'a> {
+    fn emit(self) {
+        if self.success {
+            warn!(message = "Stalled component restarted.", component_id = %self.component_id);
+        } else {
+            error!(
+                message = "Failed to restart stalled component.",
+                component_id = %self.component_id,
+            );
+        }
+        counter!(
+            "component_restarts_total", 1,
+            "component_id" => self.component_id.to_string(),
+            "success" => if self.success { "true" } else { "false" },
+        );
+    }
+}
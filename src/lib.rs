@@ -64,9 +64,12 @@ pub mod app;
 pub mod async_read;
 #[cfg(feature = "aws-config")]
 pub mod aws;
+#[cfg(feature = "sources-file")]
+pub mod checkpoints;
 #[allow(unreachable_pub)]
 pub mod codecs;
 pub(crate) mod common;
+pub mod data_dir;
 pub mod encoding_transcode;
 pub mod enrichment_tables;
 #[cfg(feature = "gcp")]
@@ -83,9 +86,13 @@ pub mod line_agg;
 pub mod list;
 #[cfg(any(feature = "sources-nats", feature = "sinks-nats"))]
 pub(crate) mod nats;
+#[cfg(feature = "api-client")]
+pub(crate) mod pause;
 #[allow(unreachable_pub)]
 pub(crate) mod proto;
 pub mod providers;
+pub mod replay;
+pub mod sd_notify;
 pub mod secrets;
 pub mod serde;
 #[cfg(windows)]
@@ -0,0 +1,76 @@
+#![allow(missing_docs)]
+use clap::Parser;
+use url::Url;
+use vector_api_client::{gql::ComponentsMutationExt, Client};
+
+use crate::config;
+
+#[derive(Parser, Debug, Clone)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// The ID of the source
+    component_id: String,
+
+    /// Vector GraphQL API server endpoint
+    #[arg(short, long)]
+    url: Option<Url>,
+}
+
+/// CLI command func for pausing or resuming a source of a local/remote Vector instance, through
+/// its API server.
+pub(crate) async fn cmd(opts: &Opts, pause: bool) -> exitcode::ExitCode {
+    // Use the provided URL as the Vector GraphQL API server, or default to the local port
+    // provided by the API config. This will work despite `api` and `api-client` being distinct
+    // features; the config is available even if `api` is disabled.
+    let url = opts.url.clone().unwrap_or_else(|| {
+        let addr = config::api::default_address().unwrap();
+        Url::parse(&format!("http://{}/graphql", addr))
+            .expect("Couldn't parse default API URL. Please report this.")
+    });
+
+    let client = match Client::new_with_healthcheck(url).await {
+        Some(client) => client,
+        None => return exitcode::UNAVAILABLE,
+    };
+
+    let id = opts.component_id.clone();
+    let (changed, errors) = if pause {
+        match client.pause_source(id).await {
+            Ok(response) => (response.data.map(|data| data.pause_source), response.errors),
+            Err(error) => return request_error(error),
+        }
+    } else {
+        match client.resume_source(id).await {
+            Ok(response) => (
+                response.data.map(|data| data.resume_source),
+                response.errors,
+            ),
+            Err(error) => return request_error(error),
+        }
+    };
+
+    let Some(changed) = changed else {
+        #[allow(clippy::print_stderr)]
+        for error in errors.into_iter().flatten() {
+            eprintln!("{}", error.message);
+        }
+        return exitcode::SOFTWARE;
+    };
+
+    let state = if pause { "paused" } else { "resumed" };
+    #[allow(clippy::print_stdout)]
+    {
+        if changed {
+            println!("Source {:?} {}.", opts.component_id, state);
+        } else {
+            println!("Source {:?} was already {}.", opts.component_id, state);
+        }
+    }
+    exitcode::OK
+}
+
+#[allow(clippy::print_stderr)]
+fn request_error(error: impl std::fmt::Display) -> exitcode::ExitCode {
+    eprintln!("{:#}", error);
+    exitcode::UNAVAILABLE
+}
@@ -0,0 +1,152 @@
+use bytes::Bytes;
+use url::Url;
+use vector_config::configurable_component;
+
+use crate::{
+    aws::{create_client, AwsAuthentication, RegionOrEndpoint},
+    common::s3::S3ClientBuilder,
+    config::{provider::ProviderConfig, ProxyConfig},
+    signal,
+    tls::TlsConfig,
+};
+
+use super::{
+    object_storage::{self, Location, ObjectStore},
+    BuildResult,
+};
+
+/// Configuration for the `aws_s3` provider.
+#[configurable_component(provider("aws_s3"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, default)]
+pub struct AwsS3Config {
+    /// The S3 URI of the configuration file.
+    ///
+    /// If the URI ends with a `/`, all the configuration files under that prefix are loaded and
+    /// merged, in the lexicographical order of their keys. The format of each file is determined
+    /// by its extension.
+    #[configurable(metadata(docs::examples = "s3://my-bucket/vector/vector.toml"))]
+    #[configurable(metadata(docs::examples = "s3://my-bucket/vector/"))]
+    uri: Option<Url>,
+
+    #[serde(flatten)]
+    region: RegionOrEndpoint,
+
+    #[configurable(derived)]
+    auth: AwsAuthentication,
+
+    /// Whether to verify the checksum of each configuration file before loading it.
+    ///
+    /// The checksum is read from an object named after the file with a `.sha256` suffix, such as
+    /// `vector.toml.sha256`, containing the hex-encoded SHA-256 digest of the file, as written by
+    /// `sha256sum`. The configuration isn't loaded if any of the checksums is missing or doesn't
+    /// match.
+    verify_checksum: bool,
+
+    /// How often to poll the provider, in seconds.
+    ///
+    /// Vector is only reloaded when the configuration files change.
+    poll_interval_secs: u64,
+
+    #[configurable(derived)]
+    tls_options: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    proxy: ProxyConfig,
+}
+
+impl Default for AwsS3Config {
+    fn default() -> Self {
+        Self {
+            uri: None,
+            region: Default::default(),
+            auth: Default::default(),
+            verify_checksum: false,
+            poll_interval_secs: 30,
+            tls_options: None,
+            proxy: Default::default(),
+        }
+    }
+}
+
+impl_generate_config_from_default!(AwsS3Config);
+
+struct S3Store {
+    client: aws_sdk_s3::Client,
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for S3Store {
+    async fn list(&self, bucket: &str, prefix: &str) -> crate::Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            let output = self
+                .client
+                .list_objects_v2()
+                .bucket(bucket)
+                .prefix(prefix)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await?;
+            keys.extend(
+                output
+                    .contents()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|object| object.key().map(ToOwned::to_owned)),
+            );
+
+            match output.next_continuation_token() {
+                Some(token) => continuation_token = Some(token.to_owned()),
+                None => return Ok(keys),
+            }
+        }
+    }
+
+    async fn get(&self, bucket: &str, key: &str) -> crate::Result<Bytes> {
+        let object = self
+            .client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await?;
+        Ok(object.body.collect().await?.into_bytes())
+    }
+}
+
+#[async_trait::async_trait]
+impl ProviderConfig for AwsS3Config {
+    async fn build(&mut self, signal_handler: &mut signal::SignalHandler) -> BuildResult {
+        let uri = self
+            .uri
+            .take()
+            .ok_or_else(|| vec!["URI is required for the `aws_s3` provider.".to_owned()])?;
+        let location = Location::parse(&uri, "s3").map_err(|error| vec![error.to_string()])?;
+
+        let proxy = ProxyConfig::from_env().merge(&self.proxy);
+        let client = create_client::<S3ClientBuilder>(
+            &self.auth,
+            &self.region,
+            &proxy,
+            &self.tls_options,
+            false,
+        )
+        .await
+        .map_err(|error| vec![format!("Failed to create S3 client: {}", error)])?;
+
+        object_storage::build(
+            S3Store { client },
+            location,
+            self.verify_checksum,
+            self.poll_interval_secs,
+            signal_handler,
+        )
+        .await
+    }
+}
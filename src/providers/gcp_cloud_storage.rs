@@ -0,0 +1,204 @@
+use bytes::Bytes;
+use http::{Request, StatusCode, Uri};
+use hyper::Body;
+use serde::Deserialize;
+use url::Url;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{provider::ProviderConfig, ProxyConfig},
+    gcp::{GcpAuthConfig, GcpAuthenticator, Scope},
+    http::HttpClient,
+    signal,
+    tls::{TlsConfig, TlsSettings},
+};
+
+use super::{
+    object_storage::{self, Location, ObjectStore},
+    BuildResult,
+};
+
+/// Configuration for the `gcp_cloud_storage` provider.
+#[configurable_component(provider("gcp_cloud_storage"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, default)]
+pub struct GcsConfig {
+    /// The Cloud Storage URI of the configuration file.
+    ///
+    /// If the URI ends with a `/`, all the configuration files under that prefix are loaded and
+    /// merged, in the lexicographical order of their names. The format of each file is determined
+    /// by its extension.
+    #[configurable(metadata(docs::examples = "gs://my-bucket/vector/vector.toml"))]
+    #[configurable(metadata(docs::examples = "gs://my-bucket/vector/"))]
+    uri: Option<Url>,
+
+    /// The endpoint of the Cloud Storage JSON API.
+    #[configurable(metadata(docs::hidden))]
+    endpoint: String,
+
+    #[serde(flatten)]
+    auth: GcpAuthConfig,
+
+    /// Whether to verify the checksum of each configuration file before loading it.
+    ///
+    /// The checksum is read from an object named after the file with a `.sha256` suffix, such as
+    /// `vector.toml.sha256`, containing the hex-encoded SHA-256 digest of the file, as written by
+    /// `sha256sum`. The configuration isn't loaded if any of the checksums is missing or doesn't
+    /// match.
+    verify_checksum: bool,
+
+    /// How often to poll the provider, in seconds.
+    ///
+    /// Vector is only reloaded when the configuration files change.
+    poll_interval_secs: u64,
+
+    #[configurable(derived)]
+    tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    proxy: ProxyConfig,
+}
+
+impl Default for GcsConfig {
+    fn default() -> Self {
+        Self {
+            uri: None,
+            endpoint: "https://storage.googleapis.com".to_owned(),
+            auth: Default::default(),
+            verify_checksum: false,
+            poll_interval_secs: 30,
+            tls: None,
+            proxy: Default::default(),
+        }
+    }
+}
+
+impl_generate_config_from_default!(GcsConfig);
+
+/// A page of the objects listed by the JSON API.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ObjectList {
+    #[serde(default)]
+    items: Vec<ObjectItem>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ObjectItem {
+    name: String,
+}
+
+struct GcsStore {
+    client: HttpClient,
+    endpoint: String,
+    auth: GcpAuthenticator,
+}
+
+impl GcsStore {
+    fn bucket_url(&self, bucket: &str) -> crate::Result<Url> {
+        let mut url = Url::parse(&self.endpoint)?;
+        url.path_segments_mut()
+            .map_err(|_| format!("Invalid endpoint {:?}.", self.endpoint))?
+            .pop_if_empty()
+            .extend(["storage", "v1", "b", bucket, "o"]);
+        Ok(url)
+    }
+
+    async fn send(&self, url: Url) -> crate::Result<Bytes> {
+        let mut uri = url.as_str().parse::<Uri>()?;
+        self.auth.apply_uri(&mut uri);
+        let mut request = Request::get(uri).body(Body::empty())?;
+        self.auth.apply(&mut request);
+
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if status != StatusCode::OK {
+            return Err(format!(
+                "Unexpected status {} from {}: {}",
+                status,
+                url,
+                String::from_utf8_lossy(&body)
+            )
+            .into());
+        }
+        Ok(body)
+    }
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for GcsStore {
+    async fn list(&self, bucket: &str, prefix: &str) -> crate::Result<Vec<String>> {
+        let mut names = Vec::new();
+        let mut page_token = None;
+        loop {
+            let mut url = self.bucket_url(bucket)?;
+            url.query_pairs_mut()
+                .append_pair("prefix", prefix)
+                .append_pair("fields", "items(name),nextPageToken");
+            if let Some(page_token) = &page_token {
+                url.query_pairs_mut().append_pair("pageToken", page_token);
+            }
+
+            let list: ObjectList = serde_json::from_slice(&self.send(url).await?)?;
+            names.extend(list.items.into_iter().map(|item| item.name));
+
+            match list.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => return Ok(names),
+            }
+        }
+    }
+
+    async fn get(&self, bucket: &str, key: &str) -> crate::Result<Bytes> {
+        let mut url = self.bucket_url(bucket)?;
+        url.path_segments_mut()
+            .map_err(|_| format!("Invalid endpoint {:?}.", self.endpoint))?
+            .push(key);
+        url.query_pairs_mut().append_pair("alt", "media");
+
+        self.send(url).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ProviderConfig for GcsConfig {
+    async fn build(&mut self, signal_handler: &mut signal::SignalHandler) -> BuildResult {
+        let uri = self.uri.take().ok_or_else(|| {
+            vec!["URI is required for the `gcp_cloud_storage` provider.".to_owned()]
+        })?;
+        let location = Location::parse(&uri, "gs").map_err(|error| vec![error.to_string()])?;
+
+        let auth = self
+            .auth
+            .build(Scope::DevStorageReadOnly)
+            .await
+            .map_err(|error| vec![format!("Invalid GCP authentication: {}", error)])?;
+        // Keeps the token fresh for as long as the provider polls.
+        auth.spawn_regenerate_token();
+
+        let tls_settings =
+            TlsSettings::from_options(&self.tls).map_err(|error| vec![error.to_string()])?;
+        let proxy = ProxyConfig::from_env().merge(&self.proxy);
+        let client =
+            HttpClient::new(tls_settings, &proxy).map_err(|error| vec![error.to_string()])?;
+
+        object_storage::build(
+            GcsStore {
+                client,
+                endpoint: self.endpoint.clone(),
+                auth,
+            },
+            location,
+            self.verify_checksum,
+            self.poll_interval_secs,
+            signal_handler,
+        )
+        .await
+    }
+}
@@ -0,0 +1,331 @@
+use std::{collections::BTreeMap, fmt::Debug, path::PathBuf};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::{
+    api::Api,
+    config::{self, KubeConfigOptions},
+    core::{ApiResource, DynamicObject, GroupVersionKind},
+    runtime::{watcher, WatchStreamExt},
+    Client, Config as ClientConfig,
+};
+use serde::de::DeserializeOwned;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{format::Format, provider::ProviderConfig},
+    signal,
+};
+
+use super::{load_files, BuildResult};
+
+/// The configuration files held by a Kubernetes object, by name.
+type Files = BTreeMap<String, String>;
+
+/// The kind of Kubernetes object holding the configuration.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    /// A ConfigMap, whose entries are configuration files named after their format, such as
+    /// `vector.yaml`.
+    #[default]
+    ConfigMap,
+
+    /// A `VectorPipeline` custom resource, of the `vector.dev/v1alpha1` API version, whose spec is
+    /// a configuration.
+    VectorPipeline,
+}
+
+/// Configuration for the `kubernetes` provider.
+#[configurable_component(provider("kubernetes"))]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields, default)]
+pub struct KubernetesConfig {
+    #[configurable(derived)]
+    kind: ResourceKind,
+
+    /// The name of the object holding the configuration.
+    #[configurable(metadata(docs::examples = "vector-config"))]
+    name: Option<String>,
+
+    /// The namespace of the object holding the configuration.
+    ///
+    /// If not set, the namespace Vector runs in is used when it runs in a cluster, or the
+    /// namespace of the current context of the kubeconfig file otherwise.
+    #[configurable(metadata(docs::examples = "observability"))]
+    namespace: Option<String>,
+
+    /// The entry of the ConfigMap holding the configuration.
+    ///
+    /// If not set, all the entries of the ConfigMap whose names have the extension of a
+    /// configuration format are loaded and merged, in the lexicographical order of their names.
+    #[configurable(metadata(docs::examples = "vector.yaml"))]
+    key: Option<String>,
+
+    /// Optional path to a readable [kubeconfig][kubeconfig] file.
+    ///
+    /// If not set, a connection to Kubernetes is made using the in-cluster configuration.
+    ///
+    /// [kubeconfig]: https://kubernetes.io/docs/concepts/configuration/organize-cluster-access-kubeconfig/
+    #[configurable(metadata(docs::examples = "/path/to/.kube/config"))]
+    kube_config_file: Option<PathBuf>,
+}
+
+impl_generate_config_from_default!(KubernetesConfig);
+
+/// The `VectorPipeline` custom resource.
+fn vector_pipeline() -> ApiResource {
+    ApiResource::from_gvk(&GroupVersionKind::gvk(
+        "vector.dev",
+        "v1alpha1",
+        "VectorPipeline",
+    ))
+}
+
+/// Extracts the configuration files from the entries of a ConfigMap.
+fn config_map_files(config_map: &ConfigMap, key: Option<&str>) -> Result<Files, String> {
+    let data = config_map.data.clone().unwrap_or_default();
+    let files = match key {
+        Some(key) => {
+            let content = data
+                .get(key)
+                .ok_or_else(|| format!("ConfigMap has no entry {:?}.", key))?;
+            Files::from([(key.to_owned(), content.clone())])
+        }
+        None => data
+            .into_iter()
+            .filter(|(name, _)| Format::from_path(name).is_ok())
+            .collect(),
+    };
+
+    if files.is_empty() {
+        Err("ConfigMap has no configuration file entries.".to_owned())
+    } else {
+        Ok(files)
+    }
+}
+
+/// Extracts the configuration from the spec of a `VectorPipeline`.
+fn vector_pipeline_files(pipeline: &DynamicObject) -> Result<Files, String> {
+    let spec = pipeline
+        .data
+        .get("spec")
+        .ok_or_else(|| "VectorPipeline has no spec.".to_owned())?;
+    let content = serde_json::to_string(spec).map_err(|error| error.to_string())?;
+    Ok(Files::from([("spec.json".to_owned(), content)]))
+}
+
+fn load(files: &Files) -> BuildResult {
+    load_files(
+        files
+            .iter()
+            .map(|(name, content)| (name.as_str(), content.as_bytes())),
+    )
+}
+
+/// Loads the configuration from the object, then watches it and reloads Vector when it changes.
+async fn build<K, F>(
+    api: Api<K>,
+    name: String,
+    extract: F,
+    signal_handler: &mut signal::SignalHandler,
+) -> BuildResult
+where
+    K: kube::Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    F: Fn(&K) -> Result<Files, String> + Send + 'static,
+{
+    info!(message = "Attempting to retrieve configuration.", %name);
+    let object = api
+        .get(&name)
+        .await
+        .map_err(|error| vec![format!("Failed to get {:?}: {}", name, error)])?;
+    let files = extract(&object).map_err(|error| vec![error])?;
+    let config_builder = load(&files)?;
+
+    signal_handler.add(watch(api, name, extract, files));
+
+    Ok(config_builder)
+}
+
+fn watch<K, F>(
+    api: Api<K>,
+    name: String,
+    extract: F,
+    mut files: Files,
+) -> impl Stream<Item = signal::SignalTo>
+where
+    K: kube::Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    F: Fn(&K) -> Result<Files, String> + Send + 'static,
+{
+    let events = watcher(
+        api,
+        watcher::Config::default().fields(&format!("metadata.name={}", name)),
+    )
+    .backoff(watcher::default_backoff());
+
+    stream! {
+        futures::pin_mut!(events);
+        while let Some(event) = events.next().await {
+            let object = match event {
+                Ok(watcher::Event::Applied(object)) => object,
+                // Sent when the watch starts, and when it restarts after an error.
+                Ok(watcher::Event::Restarted(objects)) => match objects.into_iter().next() {
+                    Some(object) => object,
+                    None => {
+                        warn!(message = "Configuration object not found.", %name);
+                        continue;
+                    }
+                },
+                Ok(watcher::Event::Deleted(_)) => {
+                    warn!(
+                        message = "Configuration object deleted, keeping the running configuration.",
+                        %name,
+                    );
+                    continue;
+                }
+                Err(error) => {
+                    warn!(message = "Failed to watch configuration. Retrying.", %error, %name);
+                    continue;
+                }
+            };
+
+            let new_files = match extract(&object) {
+                Ok(new_files) => new_files,
+                Err(error) => {
+                    error!(message = "Failed to retrieve configuration.", %error, %name);
+                    continue;
+                }
+            };
+            // Changes to the metadata of the object are ignored.
+            if new_files == files {
+                debug!(message = "Configuration is unchanged.", %name);
+                continue;
+            }
+
+            match load(&new_files) {
+                Ok(config_builder) => {
+                    info!(message = "Configuration changed.", %name);
+                    files = new_files;
+                    yield signal::SignalTo::ReloadFromConfigBuilder(config_builder);
+                }
+                Err(errors) => {
+                    for error in errors {
+                        error!(message = "Failed to load configuration.", %error);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProviderConfig for KubernetesConfig {
+    async fn build(&mut self, signal_handler: &mut signal::SignalHandler) -> BuildResult {
+        let name = self
+            .name
+            .take()
+            .ok_or_else(|| vec!["Name is required for the `kubernetes` provider.".to_owned()])?;
+
+        // If the user passed a custom Kubeconfig use it, otherwise we attempt to load the local
+        // kubeconfig, followed by the in-cluster environment variables.
+        let client_config = match &self.kube_config_file {
+            Some(kc) => {
+                let kubeconfig = config::Kubeconfig::read_from(kc)
+                    .map_err(|error| vec![format!("Invalid kubeconfig file: {}", error)])?;
+                ClientConfig::from_custom_kubeconfig(kubeconfig, &KubeConfigOptions::default())
+                    .await
+                    .map_err(|error| vec![format!("Invalid kubeconfig file: {}", error)])?
+            }
+            None => ClientConfig::infer().await.map_err(|error| {
+                vec![format!(
+                    "Failed to infer Kubernetes configuration: {}",
+                    error
+                )]
+            })?,
+        };
+        let client = Client::try_from(client_config)
+            .map_err(|error| vec![format!("Failed to create Kubernetes client: {}", error)])?;
+        let namespace = self
+            .namespace
+            .clone()
+            .unwrap_or_else(|| client.default_namespace().to_owned());
+
+        match self.kind {
+            ResourceKind::ConfigMap => {
+                let key = self.key.clone();
+                build(
+                    Api::<ConfigMap>::namespaced(client, &namespace),
+                    name,
+                    move |config_map: &ConfigMap| config_map_files(config_map, key.as_deref()),
+                    signal_handler,
+                )
+                .await
+            }
+            ResourceKind::VectorPipeline => {
+                build(
+                    Api::<DynamicObject>::namespaced_with(client, &namespace, &vector_pipeline()),
+                    name,
+                    vector_pipeline_files,
+                    signal_handler,
+                )
+                .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [sources.in]
+        type = "test_basic"
+
+        [sinks.out]
+        type = "test_basic"
+        inputs = ["in"]
+    "#;
+
+    fn config_map(data: &[(&str, &str)]) -> ConfigMap {
+        ConfigMap {
+            data: Some(
+                data.iter()
+                    .map(|(name, content)| (name.to_string(), content.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn loads_config_map_entries() {
+        let config_map = config_map(&[("vector.toml", CONFIG), ("README", "Not a config.")]);
+
+        let files = config_map_files(&config_map, None).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["vector.toml"]);
+        let builder = load(&files).unwrap();
+        assert_eq!(builder.sources.len(), 1);
+        assert_eq!(builder.sinks.len(), 1);
+
+        assert!(config_map_files(&config_map, Some("vector.toml")).is_ok());
+        assert!(config_map_files(&config_map, Some("missing.toml")).is_err());
+    }
+
+    #[test]
+    fn loads_vector_pipeline_spec() {
+        let pipeline =
+            DynamicObject::new("pipelines", &vector_pipeline()).data(serde_json::json!({
+                "spec": {
+                    "sources": { "in": { "type": "test_basic" } },
+                    "sinks": { "out": { "type": "test_basic", "inputs": ["in"] } },
+                }
+            }));
+
+        let builder = load(&vector_pipeline_files(&pipeline).unwrap()).unwrap();
+        assert_eq!(builder.sources.len(), 1);
+        assert_eq!(builder.sinks.len(), 1);
+    }
+}
@@ -2,30 +2,99 @@
 use enum_dispatch::enum_dispatch;
 use vector_config::{configurable_component, NamedComponent};
 
+#[cfg(any(
+    feature = "providers-aws_s3",
+    feature = "providers-gcp_cloud_storage",
+    feature = "providers-kubernetes"
+))]
+use crate::config::{self, format::Format};
 use crate::{
     config::{ConfigBuilder, ProviderConfig},
     signal,
 };
 
+#[cfg(feature = "providers-aws_s3")]
+pub mod aws_s3;
+#[cfg(feature = "providers-gcp_cloud_storage")]
+pub mod gcp_cloud_storage;
 pub mod http;
+#[cfg(feature = "providers-kubernetes")]
+pub mod kubernetes;
+#[cfg(any(feature = "providers-aws_s3", feature = "providers-gcp_cloud_storage"))]
+mod object_storage;
 
 pub type BuildResult = std::result::Result<ConfigBuilder, Vec<String>>;
 
+/// Loads configuration files, merging them into a single `ConfigBuilder`. The format of each file
+/// is determined by the extension of its name.
+#[cfg(any(
+    feature = "providers-aws_s3",
+    feature = "providers-gcp_cloud_storage",
+    feature = "providers-kubernetes"
+))]
+fn load_files<'a>(files: impl IntoIterator<Item = (&'a str, &'a [u8])>) -> BuildResult {
+    let mut builder = ConfigBuilder::default();
+    let mut errors = Vec::new();
+
+    for (name, content) in files {
+        let format = Format::from_path(name).unwrap_or_default();
+        match config::load::<_, ConfigBuilder>(content, format) {
+            Ok((loaded, warnings)) => {
+                for warning in warnings {
+                    warn!("{}", warning);
+                }
+                if let Err(append_errors) = builder.append(loaded) {
+                    errors.extend(append_errors);
+                }
+            }
+            Err(load_errors) => errors.extend(
+                load_errors
+                    .into_iter()
+                    .map(|error| format!("{}: {}", name, error)),
+            ),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(builder)
+    } else {
+        Err(errors)
+    }
+}
+
 /// Configurable providers in Vector.
 #[configurable_component]
 #[derive(Clone, Debug)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[enum_dispatch(ProviderConfig)]
 pub enum Providers {
+    /// AWS S3.
+    #[cfg(feature = "providers-aws_s3")]
+    AwsS3(aws_s3::AwsS3Config),
+
+    /// GCP Cloud Storage.
+    #[cfg(feature = "providers-gcp_cloud_storage")]
+    GcpCloudStorage(gcp_cloud_storage::GcsConfig),
+
     /// HTTP.
     Http(http::HttpConfig),
+
+    /// Kubernetes.
+    #[cfg(feature = "providers-kubernetes")]
+    Kubernetes(kubernetes::KubernetesConfig),
 }
 
 // TODO: Use `enum_dispatch` here.
 impl NamedComponent for Providers {
     fn get_component_name(&self) -> &'static str {
         match self {
+            #[cfg(feature = "providers-aws_s3")]
+            Self::AwsS3(config) => config.get_component_name(),
+            #[cfg(feature = "providers-gcp_cloud_storage")]
+            Self::GcpCloudStorage(config) => config.get_component_name(),
             Self::Http(config) => config.get_component_name(),
+            #[cfg(feature = "providers-kubernetes")]
+            Self::Kubernetes(config) => config.get_component_name(),
         }
     }
 }
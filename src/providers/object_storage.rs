@@ -0,0 +1,385 @@
+//! Shared behavior of the providers fetching configuration from object storage.
+
+use async_stream::stream;
+use bytes::Bytes;
+use futures::Stream;
+use sha2::{Digest, Sha256};
+use snafu::Snafu;
+use tokio::time;
+use url::Url;
+
+use crate::{
+    config::{format::Format, ConfigBuilder},
+    signal,
+};
+
+use super::{load_files, BuildResult};
+
+/// The suffix of the objects holding the SHA-256 checksums of the configuration files.
+const CHECKSUM_SUFFIX: &str = ".sha256";
+
+#[derive(Debug, Snafu)]
+pub(super) enum ObjectStorageError {
+    #[snafu(display(
+        "Invalid URI {:?}: expected `{}://<bucket>/<key or prefix/>`.",
+        uri,
+        scheme
+    ))]
+    InvalidUri { uri: String, scheme: &'static str },
+    #[snafu(display("No configuration files found under {:?}.", prefix))]
+    NoObjects { prefix: String },
+    #[snafu(display("Invalid checksum file for {:?}.", key))]
+    InvalidChecksum { key: String },
+    #[snafu(display(
+        "Checksum mismatch for {:?}: expected {}, found {}.",
+        key,
+        expected,
+        actual
+    ))]
+    ChecksumMismatch {
+        key: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// The location of the configuration in a bucket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct Location {
+    pub(super) bucket: String,
+    /// The key of the configuration file, or the prefix of the configuration files if it's empty
+    /// or ends with a `/`.
+    pub(super) key: String,
+}
+
+impl Location {
+    pub(super) fn parse(uri: &Url, scheme: &'static str) -> Result<Self, ObjectStorageError> {
+        let invalid = || ObjectStorageError::InvalidUri {
+            uri: uri.to_string(),
+            scheme,
+        };
+        if uri.scheme() != scheme {
+            return Err(invalid());
+        }
+        let bucket = uri
+            .host_str()
+            .filter(|b| !b.is_empty())
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            bucket: bucket.to_owned(),
+            key: uri.path().trim_start_matches('/').to_owned(),
+        })
+    }
+
+    fn is_prefix(&self) -> bool {
+        self.key.is_empty() || self.key.ends_with('/')
+    }
+}
+
+/// An object storage service the configuration can be fetched from.
+#[async_trait::async_trait]
+pub(super) trait ObjectStore: Send + Sync + 'static {
+    /// Lists the keys of the objects of the bucket starting with the prefix.
+    async fn list(&self, bucket: &str, prefix: &str) -> crate::Result<Vec<String>>;
+
+    /// Fetches the content of an object.
+    async fn get(&self, bucket: &str, key: &str) -> crate::Result<Bytes>;
+}
+
+/// The configuration files fetched from object storage.
+struct Objects {
+    files: Vec<(String, Bytes)>,
+    digest: Vec<u8>,
+}
+
+/// Fetches the configuration files, verifying their checksums if required.
+async fn fetch<S: ObjectStore>(
+    store: &S,
+    location: &Location,
+    verify_checksum: bool,
+) -> crate::Result<Objects> {
+    let keys = if location.is_prefix() {
+        let mut keys = store
+            .list(&location.bucket, &location.key)
+            .await?
+            .into_iter()
+            .filter(|key| !key.ends_with(CHECKSUM_SUFFIX) && Format::from_path(key).is_ok())
+            .collect::<Vec<_>>();
+        if keys.is_empty() {
+            return Err(ObjectStorageError::NoObjects {
+                prefix: location.key.clone(),
+            }
+            .into());
+        }
+        // Sorted so the files are always merged in the same order.
+        keys.sort();
+        keys
+    } else {
+        vec![location.key.clone()]
+    };
+
+    let mut files = Vec::with_capacity(keys.len());
+    let mut digest = Sha256::new();
+    for key in keys {
+        let content = store.get(&location.bucket, &key).await?;
+        if verify_checksum {
+            let checksum = store
+                .get(&location.bucket, &format!("{}{}", key, CHECKSUM_SUFFIX))
+                .await?;
+            verify(&key, &content, &checksum)?;
+        }
+        digest.update(key.as_bytes());
+        digest.update(Sha256::digest(&content));
+        files.push((key, content));
+    }
+
+    Ok(Objects {
+        files,
+        digest: digest.finalize().to_vec(),
+    })
+}
+
+/// Verifies the content of a file against a checksum file, in the format written by `sha256sum`.
+fn verify(key: &str, content: &[u8], checksum: &[u8]) -> Result<(), ObjectStorageError> {
+    let expected = std::str::from_utf8(checksum)
+        .ok()
+        .and_then(|checksum| checksum.split_whitespace().next())
+        .filter(|expected| expected.len() == 64)
+        .ok_or_else(|| ObjectStorageError::InvalidChecksum {
+            key: key.to_owned(),
+        })?
+        .to_ascii_lowercase();
+    let actual = hex::encode(Sha256::digest(content));
+
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(ObjectStorageError::ChecksumMismatch {
+            key: key.to_owned(),
+            expected,
+            actual,
+        })
+    }
+}
+
+/// Loads the configuration files, merging them into a single `ConfigBuilder`.
+fn load(objects: &Objects) -> BuildResult {
+    load_files(
+        objects
+            .files
+            .iter()
+            .map(|(key, content)| (key.as_str(), content.as_ref())),
+    )
+}
+
+/// Fetches and loads the configuration, returning it along with the digest of its files.
+async fn fetch_config<S: ObjectStore>(
+    store: &S,
+    location: &Location,
+    verify_checksum: bool,
+) -> Result<(ConfigBuilder, Vec<u8>), Vec<String>> {
+    info!(
+        message = "Attempting to retrieve configuration.",
+        bucket = %location.bucket,
+        key = %location.key,
+    );
+    let objects = fetch(store, location, verify_checksum)
+        .await
+        .map_err(|error| vec![error.to_string()])?;
+
+    load(&objects).map(|builder| (builder, objects.digest))
+}
+
+/// Fetches the configuration, then polls it every `poll_interval_secs` and reloads Vector when it
+/// changes.
+pub(super) async fn build<S: ObjectStore>(
+    store: S,
+    location: Location,
+    verify_checksum: bool,
+    poll_interval_secs: u64,
+    signal_handler: &mut signal::SignalHandler,
+) -> BuildResult {
+    let (config_builder, digest) = fetch_config(&store, &location, verify_checksum).await?;
+
+    signal_handler.add(poll(
+        store,
+        location,
+        verify_checksum,
+        poll_interval_secs,
+        digest,
+    ));
+
+    Ok(config_builder)
+}
+
+fn poll<S: ObjectStore>(
+    store: S,
+    location: Location,
+    verify_checksum: bool,
+    poll_interval_secs: u64,
+    mut digest: Vec<u8>,
+) -> impl Stream<Item = signal::SignalTo> {
+    let duration = time::Duration::from_secs(poll_interval_secs);
+    let mut interval = time::interval_at(time::Instant::now() + duration, duration);
+
+    stream! {
+        loop {
+            interval.tick().await;
+
+            let objects = match fetch(&store, &location, verify_checksum).await {
+                Ok(objects) => objects,
+                Err(error) => {
+                    error!(
+                        message = "Failed to retrieve configuration.",
+                        %error,
+                        bucket = %location.bucket,
+                        key = %location.key,
+                    );
+                    continue;
+                }
+            };
+            if objects.digest == digest {
+                debug!(message = "Configuration is unchanged.", bucket = %location.bucket);
+                continue;
+            }
+
+            match load(&objects) {
+                Ok(config_builder) => {
+                    info!(message = "Configuration changed.", bucket = %location.bucket);
+                    digest = objects.digest;
+                    yield signal::SignalTo::ReloadFromConfigBuilder(config_builder);
+                }
+                Err(errors) => {
+                    for error in errors {
+                        error!(message = "Failed to load configuration.", %error);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<BTreeMap<String, Bytes>>);
+
+    impl MemoryStore {
+        fn put(&self, key: &str, content: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(key.to_owned(), Bytes::from(content.to_owned()));
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ObjectStore for MemoryStore {
+        async fn list(&self, _bucket: &str, prefix: &str) -> crate::Result<Vec<String>> {
+            Ok(self
+                .0
+                .lock()
+                .unwrap()
+                .keys()
+                .filter(|key| key.starts_with(prefix))
+                .cloned()
+                .collect())
+        }
+
+        async fn get(&self, _bucket: &str, key: &str) -> crate::Result<Bytes> {
+            self.0
+                .lock()
+                .unwrap()
+                .get(key)
+                .cloned()
+                .ok_or_else(|| format!("{} not found", key).into())
+        }
+    }
+
+    fn location(uri: &str) -> Location {
+        Location::parse(&uri.parse().unwrap(), "s3").unwrap()
+    }
+
+    const SOURCE: &str = r#"
+        [sources.in]
+        type = "test_basic"
+    "#;
+
+    const SINK: &str = r#"
+        [sinks.out]
+        type = "test_basic"
+        inputs = ["in"]
+    "#;
+
+    #[test]
+    fn parses_locations() {
+        assert_eq!(
+            location("s3://configs/vector/vector.toml"),
+            Location {
+                bucket: "configs".to_owned(),
+                key: "vector/vector.toml".to_owned(),
+            }
+        );
+        assert!(location("s3://configs/vector/").is_prefix());
+        assert!(location("s3://configs").is_prefix());
+        assert!(Location::parse(&"gs://configs/vector.toml".parse().unwrap(), "s3").is_err());
+    }
+
+    #[tokio::test]
+    async fn merges_files_under_prefix() {
+        let store = MemoryStore::default();
+        store.put("vector/sources.toml", SOURCE);
+        store.put("vector/sinks.toml", SINK);
+        store.put("vector/README.md", "Not a configuration file.");
+        store.put("other/vector.toml", "invalid");
+
+        let (builder, digest) = fetch_config(&store, &location("s3://configs/vector/"), false)
+            .await
+            .unwrap();
+        assert_eq!(builder.sources.len(), 1);
+        assert_eq!(builder.sinks.len(), 1);
+
+        let (_, unchanged) = fetch_config(&store, &location("s3://configs/vector/"), false)
+            .await
+            .unwrap();
+        assert_eq!(digest, unchanged);
+
+        store.put("vector/sinks.toml", &SINK.replace("out", "other_out"));
+        let (_, changed) = fetch_config(&store, &location("s3://configs/vector/"), false)
+            .await
+            .unwrap();
+        assert_ne!(digest, changed);
+    }
+
+    #[tokio::test]
+    async fn verifies_checksums() {
+        let store = MemoryStore::default();
+        let config = format!("{}{}", SOURCE, SINK);
+        store.put("vector.toml", &config);
+        store.put(
+            "vector.toml.sha256",
+            &format!("{}  vector.toml\n", hex::encode(Sha256::digest(&config))),
+        );
+
+        fetch_config(&store, &location("s3://configs/vector.toml"), true)
+            .await
+            .unwrap();
+
+        store.put("vector.toml", SOURCE);
+        let errors = fetch_config(&store, &location("s3://configs/vector.toml"), true)
+            .await
+            .unwrap_err();
+        assert!(errors[0].starts_with("Checksum mismatch"), "{:?}", errors);
+
+        store.put("vector.toml.sha256", "not a checksum");
+        let errors = fetch_config(&store, &location("s3://configs/vector.toml"), true)
+            .await
+            .unwrap_err();
+        assert!(errors[0].starts_with("Invalid checksum"), "{:?}", errors);
+    }
+}
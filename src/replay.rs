@@ -0,0 +1,191 @@
+#![allow(missing_docs)]
+use std::{
+    fs,
+    io::{self, BufRead as _},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use indexmap::IndexMap;
+use vector_common::finalization::{BatchNotifier, BatchStatus};
+
+use crate::{
+    config::{self, ComponentKey, ProxyConfig, SinkConfig, SinkContext},
+    event::Event,
+    topology::dead_letter::DeadLetterRecord,
+};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// The dead-letter files to replay, as written by sinks with a `dead_letter.path`.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+
+    /// The ID of the sink to send the events to.
+    ///
+    /// If not set, each event is sent to the sink that failed to deliver it.
+    #[arg(long)]
+    sink: Option<String>,
+
+    /// Remove the files once all of their events have been delivered.
+    #[arg(long)]
+    remove: bool,
+
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    /// If zero files are specified the default config path
+    /// `/etc/vector/vector.toml` will be targeted.
+    #[arg(
+        id = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        value_delimiter(',')
+    )]
+    paths: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[arg(
+        id = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        value_delimiter(',')
+    )]
+    config_dirs: Vec<PathBuf>,
+}
+
+impl Opts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        self.paths
+            .iter()
+            .map(|path| config::ConfigPath::File(path.to_path_buf(), None))
+            .chain(
+                self.config_dirs
+                    .iter()
+                    .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+            )
+            .collect()
+    }
+
+    /// Reads the events of the files, grouped by the sink they're sent to, in order.
+    fn read_events(&self) -> Result<IndexMap<String, Vec<Event>>, exitcode::ExitCode> {
+        let mut events = IndexMap::<String, Vec<Event>>::new();
+        for path in &self.files {
+            let file = fs::File::open(path)
+                .map_err(|error| io_error(&format!("Failed to open {:?}", path), error))?;
+            for (number, line) in io::BufReader::new(file).lines().enumerate() {
+                let line =
+                    line.map_err(|error| io_error(&format!("Failed to read {:?}", path), error))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let record = serde_json::from_str::<DeadLetterRecord>(&line).map_err(|error| {
+                    print_error(&format!(
+                        "Invalid dead-letter record on line {} of {:?}: {}",
+                        number + 1,
+                        path,
+                        error
+                    ));
+                    exitcode::DATAERR
+                })?;
+                let sink = self.sink.clone().unwrap_or(record.sink);
+                events.entry(sink).or_default().push(record.event);
+            }
+        }
+        Ok(events)
+    }
+}
+
+pub(crate) async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    match replay(opts).await {
+        Ok(()) => exitcode::OK,
+        Err(code) => code,
+    }
+}
+
+async fn replay(opts: &Opts) -> Result<(), exitcode::ExitCode> {
+    let events = opts.read_events()?;
+
+    let paths = config::process_paths(&opts.paths_with_formats()).ok_or(exitcode::CONFIG)?;
+    let config = config::load_from_paths(&paths).map_err(|errors| {
+        for error in errors {
+            print_error(&error);
+        }
+        exitcode::CONFIG
+    })?;
+
+    let mut delivered = true;
+    for (id, events) in events {
+        let Some(sink) = config.sink(&ComponentKey::from(id.as_str())) else {
+            print_error(&format!("Sink {:?} not found in the configuration.", id));
+            return Err(exitcode::CONFIG);
+        };
+
+        let cx = SinkContext {
+            healthcheck: sink.healthcheck(),
+            globals: config.global.clone(),
+            proxy: ProxyConfig::merge_with_env(&config.global.proxy, sink.proxy()),
+            http_client: sink.http_client().clone(),
+            schema: config.schema,
+        };
+        // The healthcheck is skipped, as delivering the events tells whether the sink is healthy.
+        let (built, _healthcheck) = sink.inner.build(cx).await.map_err(|error| {
+            print_error(&format!("Sink {:?}: {}", id, error));
+            exitcode::CONFIG
+        })?;
+
+        let count = events.len();
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let events = events
+            .into_iter()
+            .map(|event| event.with_batch_notifier(&batch))
+            .collect::<Vec<_>>();
+        drop(batch);
+
+        if built.run_events(events).await.is_err() {
+            print_error(&format!("Sink {:?} finished with an error.", id));
+            return Err(exitcode::SOFTWARE);
+        }
+        let status = receiver.await;
+
+        #[allow(clippy::print_stdout)]
+        {
+            match status {
+                BatchStatus::Delivered => println!("Replayed {} events to sink {:?}.", count, id),
+                BatchStatus::Errored | BatchStatus::Rejected => {
+                    delivered = false;
+                    println!(
+                        "Failed to replay some of {} events to sink {:?}.",
+                        count, id
+                    );
+                }
+            }
+        }
+    }
+
+    if !delivered {
+        return Err(exitcode::UNAVAILABLE);
+    }
+    if opts.remove {
+        for path in &opts.files {
+            fs::remove_file(path)
+                .map_err(|error| io_error(&format!("Failed to remove {:?}", path), error))?;
+        }
+    }
+    Ok(())
+}
+
+fn io_error(message: &str, error: io::Error) -> exitcode::ExitCode {
+    print_error(&format!("{}: {}", message, error));
+    exitcode::IOERR
+}
+
+#[allow(clippy::print_stderr)]
+fn print_error(message: &str) {
+    eprintln!("{}", message);
+}
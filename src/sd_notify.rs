@@ -0,0 +1,149 @@
+//! Notifies systemd of the state of Vector.
+//!
+//! When Vector runs as a `Type=notify` service, it tells systemd once the topology is running,
+//! while it's reloading, and when it stops. When the service has a `WatchdogSec`, Vector also
+//! pings the watchdog as long as the topology is healthy, so that systemd restarts a wedged Vector.
+//!
+//! <https://www.freedesktop.org/software/systemd/man/sd_notify.html>
+
+use std::{env, ffi::OsStr, io, time::Duration};
+
+use tokio::time::{interval, timeout};
+
+use crate::topology::SharedTopologyController;
+
+/// Tells systemd that Vector finished starting up, or reloading.
+pub fn ready() {
+    notify("READY=1");
+}
+
+/// Tells systemd that Vector is reloading its configuration.
+pub fn reloading() {
+    notify(&format!("RELOADING=1\nMONOTONIC_USEC={}", monotonic_usec()));
+}
+
+/// Tells systemd that Vector is shutting down.
+pub fn stopping() {
+    notify("STOPPING=1");
+}
+
+/// Pings the watchdog of systemd for as long as the topology is healthy.
+///
+/// The topology is healthy when all of its sources are running, and it isn't stuck reloading. If
+/// the service has no watchdog, this returns immediately.
+pub async fn watchdog(topology_controller: SharedTopologyController) {
+    let Some(watchdog_timeout) = watchdog_timeout() else {
+        return;
+    };
+
+    // Pinging twice per timeout, as recommended, tolerates a late ping.
+    let period = watchdog_timeout / 2;
+    let mut interval = interval(period);
+    loop {
+        interval.tick().await;
+
+        // The controller is only held for long while reloading the topology.
+        let healthy = match timeout(period, topology_controller.lock()).await {
+            Ok(controller) => controller.topology.sources_running(),
+            Err(_) => false,
+        };
+
+        if healthy {
+            notify("WATCHDOG=1");
+        } else {
+            warn!(
+                message = "Topology is unhealthy, skipping the systemd watchdog ping.",
+                internal_log_rate_limit = true
+            );
+        }
+    }
+}
+
+/// The watchdog timeout of the service, if it has one.
+fn watchdog_timeout() -> Option<Duration> {
+    // The watchdog is meant for the main process only, not for the processes it spawns.
+    if let Some(pid) = env::var_os("WATCHDOG_PID") {
+        if pid.to_str() != Some(std::process::id().to_string().as_str()) {
+            return None;
+        }
+    }
+
+    env::var("WATCHDOG_USEC")
+        .ok()?
+        .parse()
+        .ok()
+        .filter(|usec| *usec > 0)
+        .map(Duration::from_micros)
+}
+
+fn notify(state: &str) {
+    let Some(path) = env::var_os("NOTIFY_SOCKET") else {
+        return;
+    };
+
+    if let Err(error) = send(&path, state) {
+        warn!(message = "Failed to notify systemd.", %error, internal_log_rate_limit = true);
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn send(path: &OsStr, state: &str) -> io::Result<()> {
+    use std::os::unix::{ffi::OsStrExt, io::AsRawFd, net::UnixDatagram};
+
+    use nix::sys::socket::{sendto, MsgFlags, UnixAddr};
+
+    let socket = UnixDatagram::unbound()?;
+    match path.as_bytes() {
+        // Sockets in the abstract namespace are prefixed with `@`.
+        [b'@', name @ ..] => {
+            let addr = UnixAddr::new_abstract(name)?;
+            sendto(
+                socket.as_raw_fd(),
+                state.as_bytes(),
+                &addr,
+                MsgFlags::empty(),
+            )?;
+        }
+        _ => {
+            socket.send_to(state.as_bytes(), path)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn send(_path: &OsStr, _state: &str) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn monotonic_usec() -> u128 {
+    use nix::time::{clock_gettime, ClockId};
+
+    clock_gettime(ClockId::CLOCK_MONOTONIC).map_or(0, |now| Duration::from(now).as_micros())
+}
+
+#[cfg(not(target_os = "linux"))]
+const fn monotonic_usec() -> u128 {
+    0
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::os::unix::net::UnixDatagram;
+
+    use super::*;
+
+    #[test]
+    fn sends_state_to_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify.sock");
+        let socket = UnixDatagram::bind(&path).unwrap();
+
+        send(path.as_os_str(), "READY=1").unwrap();
+
+        let mut buf = [0; 64];
+        let len = socket.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"READY=1");
+    }
+}
@@ -8,6 +8,7 @@ use crate::{config::SecretBackend, signal};
 
 mod exec;
 mod test;
+mod vault;
 
 /// Configurable secret backends in Vector.
 #[configurable_component]
@@ -21,6 +22,9 @@ pub enum SecretBackends {
     /// Test.
     #[configurable(metadata(docs::hidden))]
     Test(test::TestBackend),
+
+    /// HashiCorp Vault.
+    Vault(vault::VaultBackend),
 }
 
 // TODO: Use `enum_dispatch` here.
@@ -29,6 +33,7 @@ impl NamedComponent for SecretBackends {
         match self {
             Self::Exec(config) => config.get_component_name(),
             Self::Test(config) => config.get_component_name(),
+            Self::Vault(config) => config.get_component_name(),
         }
     }
 }
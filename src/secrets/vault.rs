@@ -0,0 +1,675 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use futures::executor;
+use http::{Method, Request};
+use hyper::Body;
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use tokio::{task::JoinHandle, time};
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::{component::GenerateConfig, configurable_component};
+
+use crate::{
+    config::{ProxyConfig, SecretBackend},
+    http::HttpClient,
+    signal,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// How long before it expires a token is considered expired, so that it's still valid when used.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(10);
+
+/// The tokens obtained from Vault, by server and credentials, kept across configuration reloads.
+static TOKENS: Lazy<Mutex<HashMap<u64, CachedToken>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The secrets retrieved from Vault, kept across configuration reloads until they expire.
+static SECRETS: Lazy<Mutex<HashMap<String, CachedSecret>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The version of a KV secrets engine.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum KvVersion {
+    /// Version 1, whose secrets aren't versioned.
+    V1,
+
+    /// Version 2, whose secrets are versioned. The latest version of each secret is retrieved.
+    #[default]
+    V2,
+}
+
+/// Authentication strategies.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+#[configurable(metadata(docs::enum_tag_description = "The authentication strategy to use."))]
+pub enum VaultAuth {
+    /// Token authentication.
+    ///
+    /// The token is renewed for as long as Vector runs, if it's renewable.
+    Token {
+        /// The Vault token.
+        token: SensitiveString,
+    },
+
+    /// AppRole authentication.
+    #[serde(rename = "approle")]
+    AppRole {
+        /// The ID of the role.
+        role_id: String,
+
+        /// The secret ID of the role.
+        secret_id: SensitiveString,
+
+        /// The path the AppRole authentication method is mounted at.
+        #[serde(default = "default_approle_mount")]
+        mount: String,
+    },
+
+    /// Kubernetes authentication, with the token of the service account Vector runs as.
+    Kubernetes {
+        /// The role to log in as.
+        role: String,
+
+        /// The path of the token of the service account.
+        #[serde(default = "default_kubernetes_token_path")]
+        token_path: PathBuf,
+
+        /// The path the Kubernetes authentication method is mounted at.
+        #[serde(default = "default_kubernetes_mount")]
+        mount: String,
+    },
+}
+
+fn default_approle_mount() -> String {
+    "approle".to_owned()
+}
+
+fn default_kubernetes_mount() -> String {
+    "kubernetes".to_owned()
+}
+
+fn default_kubernetes_token_path() -> PathBuf {
+    "/var/run/secrets/kubernetes.io/serviceaccount/token".into()
+}
+
+/// Configuration for the `vault` secrets backend.
+#[configurable_component(secrets("vault"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct VaultBackend {
+    /// The address of the Vault server.
+    #[configurable(metadata(docs::examples = "https://vault.example.com:8200"))]
+    pub endpoint: String,
+
+    /// The Vault Enterprise namespace the secrets are in.
+    #[configurable(metadata(docs::examples = "team-a"))]
+    pub namespace: Option<String>,
+
+    /// The path the KV secrets engine holding the secrets is mounted at.
+    #[serde(default = "default_mount")]
+    pub mount: String,
+
+    /// The version of the KV secrets engine.
+    #[serde(default)]
+    pub kv_version: KvVersion,
+
+    #[configurable(derived)]
+    pub auth: VaultAuth,
+
+    /// How long to cache each secret for, in seconds.
+    ///
+    /// Secrets are retrieved when Vector starts and when its configuration is reloaded. Cached
+    /// secrets aren't retrieved again on reload until they expire, which is sooner if their
+    /// lease is shorter. Set to `0` to disable the cache.
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+
+    /// The timeout, in seconds, to wait for the secrets to be retrieved.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout: u64,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub proxy: ProxyConfig,
+}
+
+fn default_mount() -> String {
+    "secret".to_owned()
+}
+
+const fn default_cache_ttl_secs() -> u64 {
+    300
+}
+
+const fn default_timeout_secs() -> u64 {
+    5
+}
+
+impl GenerateConfig for VaultBackend {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(VaultBackend {
+            endpoint: "https://vault.example.com:8200".to_owned(),
+            namespace: None,
+            mount: default_mount(),
+            kv_version: KvVersion::V2,
+            auth: VaultAuth::AppRole {
+                role_id: "vector".to_owned(),
+                secret_id: String::from("${VAULT_SECRET_ID}").into(),
+                mount: default_approle_mount(),
+            },
+            cache_ttl_secs: default_cache_ttl_secs(),
+            timeout: default_timeout_secs(),
+            tls: None,
+            proxy: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+struct CachedToken {
+    token: String,
+    expires_at: Option<Instant>,
+    renewal: Option<JoinHandle<()>>,
+}
+
+struct CachedSecret {
+    value: String,
+    expires_at: Instant,
+}
+
+/// A token along with its lease.
+struct Lease {
+    token: String,
+    ttl: Option<Duration>,
+    renewable: bool,
+}
+
+impl Lease {
+    fn new(token: String, ttl_secs: u64, renewable: bool) -> Self {
+        // Tokens without a TTL, such as root tokens, never expire.
+        let ttl = (ttl_secs > 0).then_some(Duration::from_secs(ttl_secs));
+        Self {
+            token,
+            ttl,
+            renewable,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AuthResponse {
+    auth: AuthInfo,
+}
+
+#[derive(Deserialize)]
+struct AuthInfo {
+    client_token: String,
+    lease_duration: u64,
+    renewable: bool,
+}
+
+#[derive(Deserialize)]
+struct LookupResponse {
+    data: LookupData,
+}
+
+#[derive(Deserialize)]
+struct LookupData {
+    ttl: u64,
+    renewable: bool,
+}
+
+#[derive(Deserialize)]
+struct SecretResponse {
+    data: Value,
+    #[serde(default)]
+    lease_duration: u64,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    errors: Vec<String>,
+}
+
+/// A client of the HTTP API of Vault.
+#[derive(Clone)]
+struct VaultClient {
+    client: HttpClient,
+    endpoint: String,
+    namespace: Option<String>,
+}
+
+impl VaultClient {
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<Value>,
+    ) -> crate::Result<T> {
+        let uri = format!("{}/v1/{}", self.endpoint.trim_end_matches('/'), path);
+        let mut builder = Request::builder().method(method).uri(&uri);
+        if let Some(token) = token {
+            builder = builder.header("X-Vault-Token", token);
+        }
+        if let Some(namespace) = &self.namespace {
+            builder = builder.header("X-Vault-Namespace", namespace);
+        }
+        let body = match body {
+            Some(body) => Body::from(serde_json::to_vec(&body)?),
+            None => Body::empty(),
+        };
+
+        let response = self.client.send(builder.body(body)?).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if !status.is_success() {
+            // The errors never include the secrets or the credentials.
+            let errors = serde_json::from_slice::<ErrorResponse>(&body)
+                .map(|response| response.errors.join(", "))
+                .unwrap_or_default();
+            return Err(format!("Vault responded to {} with {}: {}", uri, status, errors).into());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn renew_self(&self, token: &str) -> crate::Result<Lease> {
+        let response: AuthResponse = self
+            .request(
+                Method::POST,
+                "auth/token/renew-self",
+                Some(token),
+                Some(Value::Object(Default::default())),
+            )
+            .await?;
+        let auth = response.auth;
+        Ok(Lease::new(
+            auth.client_token,
+            auth.lease_duration,
+            auth.renewable,
+        ))
+    }
+}
+
+impl VaultAuth {
+    /// Identifies the server and the credentials the tokens are obtained from.
+    fn cache_key(&self, client: &VaultClient) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        client.endpoint.hash(&mut hasher);
+        client.namespace.hash(&mut hasher);
+        match self {
+            Self::Token { token } => ("token", token.inner()).hash(&mut hasher),
+            Self::AppRole { role_id, mount, .. } => ("approle", role_id, mount).hash(&mut hasher),
+            Self::Kubernetes {
+                role,
+                token_path,
+                mount,
+            } => ("kubernetes", role, token_path, mount).hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+
+    async fn login(&self, client: &VaultClient) -> crate::Result<Lease> {
+        let (mount, body) = match self {
+            Self::Token { token } => {
+                let response: LookupResponse = client
+                    .request(
+                        Method::GET,
+                        "auth/token/lookup-self",
+                        Some(token.inner()),
+                        None,
+                    )
+                    .await?;
+                let data = response.data;
+                return Ok(Lease::new(
+                    token.inner().to_owned(),
+                    data.ttl,
+                    data.renewable,
+                ));
+            }
+            Self::AppRole {
+                role_id,
+                secret_id,
+                mount,
+            } => (
+                mount,
+                serde_json::json!({ "role_id": role_id, "secret_id": secret_id.inner() }),
+            ),
+            Self::Kubernetes {
+                role,
+                token_path,
+                mount,
+            } => {
+                let jwt = tokio::fs::read_to_string(token_path)
+                    .await
+                    .map_err(|error| {
+                        format!(
+                            "Could not read the service account token from {:?}: {}",
+                            token_path, error
+                        )
+                    })?;
+                (
+                    mount,
+                    serde_json::json!({ "role": role, "jwt": jwt.trim() }),
+                )
+            }
+        };
+
+        let response: AuthResponse = client
+            .request(
+                Method::POST,
+                &format!("auth/{}/login", mount.trim_matches('/')),
+                None,
+                Some(body),
+            )
+            .await?;
+        let auth = response.auth;
+        Ok(Lease::new(
+            auth.client_token,
+            auth.lease_duration,
+            auth.renewable,
+        ))
+    }
+
+    /// Returns a valid token, logging in again if the cached one expired.
+    async fn token(&self, client: &VaultClient) -> crate::Result<String> {
+        let key = self.cache_key(client);
+        let cached = TOKENS
+            .lock()
+            .expect("poisoned lock")
+            .get(&key)
+            .and_then(|cached| {
+                cached
+                    .expires_at
+                    .map_or(true, |expires_at| {
+                        Instant::now() + EXPIRY_MARGIN < expires_at
+                    })
+                    .then(|| cached.token.clone())
+            });
+        if let Some(token) = cached {
+            return Ok(token);
+        }
+
+        debug!(message = "Logging in to Vault.", endpoint = %client.endpoint);
+        let lease = self.login(client).await?;
+        let token = lease.token.clone();
+        let renewal = match lease.ttl {
+            Some(ttl) if lease.renewable && tokio::runtime::Handle::try_current().is_ok() => {
+                Some(tokio::spawn(renew(client.clone(), key, token.clone(), ttl)))
+            }
+            _ => None,
+        };
+        let previous = TOKENS.lock().expect("poisoned lock").insert(
+            key,
+            CachedToken {
+                token: token.clone(),
+                expires_at: lease.ttl.map(|ttl| Instant::now() + ttl),
+                renewal,
+            },
+        );
+        if let Some(renewal) = previous.and_then(|previous| previous.renewal) {
+            renewal.abort();
+        }
+
+        Ok(token)
+    }
+}
+
+/// Renews a token until it can't be renewed anymore, after two thirds of each of its leases.
+///
+/// Tokens that aren't renewed are replaced by logging in again the next time secrets are
+/// retrieved.
+async fn renew(client: VaultClient, key: u64, token: String, mut ttl: Duration) {
+    loop {
+        time::sleep(ttl * 2 / 3).await;
+
+        match client.renew_self(&token).await {
+            Ok(lease) => {
+                debug!(message = "Renewed Vault token.", endpoint = %client.endpoint);
+                if let Some(cached) = TOKENS.lock().expect("poisoned lock").get_mut(&key) {
+                    cached.expires_at = lease.ttl.map(|ttl| Instant::now() + ttl);
+                }
+                match lease.ttl {
+                    // Tokens can't be renewed past their maximum TTL.
+                    Some(lease_ttl) if lease.renewable && lease_ttl > EXPIRY_MARGIN => {
+                        ttl = lease_ttl
+                    }
+                    _ => break,
+                }
+            }
+            Err(error) => {
+                warn!(message = "Failed to renew Vault token.", %error, endpoint = %client.endpoint);
+                break;
+            }
+        }
+    }
+}
+
+/// Splits a secret key into the path of the secret and the name of its field.
+///
+/// The path is made of all the segments of the key but the last one, separated by slashes.
+fn split_key(key: &str) -> crate::Result<(String, &str)> {
+    match key.rsplit_once('.') {
+        Some((path, field))
+            if !field.is_empty() && path.split('.').all(|segment| !segment.is_empty()) =>
+        {
+            Ok((path.replace('.', "/"), field))
+        }
+        _ => Err(format!(
+            "secret key '{}' is not of the form <path>.<field>, such as 'vector.api_key'",
+            key
+        )
+        .into()),
+    }
+}
+
+/// Extracts the fields of a secret from the data of its response.
+fn secret_fields(data: Value, kv_version: KvVersion) -> crate::Result<BTreeMap<String, Value>> {
+    let data = match kv_version {
+        KvVersion::V1 => Some(data),
+        KvVersion::V2 => match data {
+            Value::Object(mut data) => data.remove("data"),
+            _ => None,
+        },
+    };
+    match data {
+        Some(Value::Object(fields)) => Ok(fields.into_iter().collect()),
+        _ => Err("secret has no data".into()),
+    }
+}
+
+impl VaultBackend {
+    fn client(&self) -> crate::Result<VaultClient> {
+        let tls_settings = TlsSettings::from_options(&self.tls)?;
+        let proxy = ProxyConfig::from_env().merge(&self.proxy);
+        Ok(VaultClient {
+            client: HttpClient::new(tls_settings, &proxy)?,
+            endpoint: self.endpoint.clone(),
+            namespace: self.namespace.clone(),
+        })
+    }
+
+    fn secret_cache_key(&self, path: &str, field: &str) -> String {
+        format!(
+            "{}|{}|{}|{}|{}",
+            self.endpoint,
+            self.namespace.as_deref().unwrap_or_default(),
+            self.mount,
+            path,
+            field
+        )
+    }
+
+    fn secret_uri(&self, path: &str) -> String {
+        let mount = self.mount.trim_matches('/');
+        match self.kv_version {
+            KvVersion::V1 => format!("{}/{}", mount, path),
+            KvVersion::V2 => format!("{}/data/{}", mount, path),
+        }
+    }
+
+    /// Retrieves the fields of the secrets at the given paths, by secret key.
+    async fn fetch(
+        &self,
+        paths: BTreeMap<String, Vec<(String, String)>>,
+    ) -> crate::Result<HashMap<String, String>> {
+        let client = self.client()?;
+        let token = self.auth.token(&client).await?;
+
+        let mut secrets = HashMap::new();
+        for (path, fields) in paths {
+            let response: SecretResponse = client
+                .request(Method::GET, &self.secret_uri(&path), Some(&token), None)
+                .await
+                .map_err(|error| {
+                    format!("secret at path '{}' was not retrieved: {}", path, error)
+                })?;
+            let mut values = secret_fields(response.data, self.kv_version)
+                .map_err(|error| format!("secret at path '{}' is invalid: {}", path, error))?;
+
+            let mut ttl = Duration::from_secs(self.cache_ttl_secs);
+            if response.lease_duration > 0 {
+                ttl = ttl.min(Duration::from_secs(response.lease_duration));
+            }
+            for (key, field) in fields {
+                let value = match values.remove(&field) {
+                    Some(Value::String(value)) => value,
+                    Some(Value::Number(value)) => value.to_string(),
+                    Some(Value::Bool(value)) => value.to_string(),
+                    Some(_) => {
+                        return Err(format!("secret for key '{}' is not a string", key).into())
+                    }
+                    None => {
+                        return Err(format!("secret for key '{}' was not retrieved", key).into())
+                    }
+                };
+                if value.is_empty() {
+                    return Err(format!("secret for key '{}' was empty", key).into());
+                }
+
+                if !ttl.is_zero() {
+                    SECRETS.lock().expect("poisoned lock").insert(
+                        self.secret_cache_key(&path, &field),
+                        CachedSecret {
+                            value: value.clone(),
+                            expires_at: Instant::now() + ttl,
+                        },
+                    );
+                }
+                secrets.insert(key, value);
+            }
+        }
+        Ok(secrets)
+    }
+}
+
+impl SecretBackend for VaultBackend {
+    fn retrieve(
+        &mut self,
+        secret_keys: HashSet<String>,
+        signal_rx: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let mut secrets = HashMap::new();
+        // The fields to retrieve, by path, so that each secret is only retrieved once.
+        let mut paths = BTreeMap::<String, Vec<(String, String)>>::new();
+        {
+            let mut cache = SECRETS.lock().expect("poisoned lock");
+            let now = Instant::now();
+            cache.retain(|_, cached| now < cached.expires_at);
+            for key in secret_keys {
+                let (path, field) = split_key(&key)?;
+                match cache.get(&self.secret_cache_key(&path, field)) {
+                    Some(cached) => {
+                        trace!(message = "Using cached secret.", secret_key = ?key);
+                        secrets.insert(key, cached.value.clone());
+                    }
+                    None => {
+                        let field = field.to_owned();
+                        paths.entry(path).or_default().push((key, field));
+                    }
+                }
+            }
+        }
+        if paths.is_empty() {
+            return Ok(secrets);
+        }
+
+        let retrieved = executor::block_on(async {
+            tokio::select! {
+                biased;
+                Ok(signal::SignalTo::Shutdown | signal::SignalTo::Quit) = signal_rx.recv() => {
+                    Err("Secret retrieval was interrupted.".into())
+                }
+                result = time::timeout(Duration::from_secs(self.timeout), self.fetch(paths)) => {
+                    result.unwrap_or_else(|_| Err("Secret retrieval timed out.".into()))
+                }
+            }
+        })?;
+        secrets.extend(retrieved);
+        Ok(secrets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<VaultBackend>();
+    }
+
+    #[test]
+    fn splits_keys() {
+        assert_eq!(
+            split_key("vector.api_key").unwrap(),
+            ("vector".to_owned(), "api_key")
+        );
+        assert_eq!(
+            split_key("team.vector.api_key").unwrap(),
+            ("team/vector".to_owned(), "api_key")
+        );
+        assert!(split_key("api_key").is_err());
+        assert!(split_key(".vector.api_key").is_err());
+        assert!(split_key("vector..api_key").is_err());
+    }
+
+    #[test]
+    fn extracts_secret_fields() {
+        let v1 = serde_json::json!({ "api_key": "abc" });
+        assert_eq!(
+            secret_fields(v1, KvVersion::V1).unwrap(),
+            BTreeMap::from([("api_key".to_owned(), Value::from("abc"))])
+        );
+
+        let v2 = serde_json::json!({
+            "data": { "api_key": "abc" },
+            "metadata": { "version": 3 },
+        });
+        assert_eq!(
+            secret_fields(v2.clone(), KvVersion::V2).unwrap(),
+            BTreeMap::from([("api_key".to_owned(), Value::from("abc"))])
+        );
+        assert_eq!(
+            secret_fields(v2, KvVersion::V1).unwrap().len(),
+            2,
+            "the data of version 1 is the secret itself"
+        );
+        assert!(secret_fields(serde_json::json!({ "data": null }), KvVersion::V2).is_err());
+    }
+}
@@ -118,7 +118,7 @@ impl SinkConfig for AppsignalSinkConfig {
         let buffer = JsonArrayBuffer::new(batch_settings.size);
 
         let tls_settings = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls_settings, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
 
         let sink = BatchedHttpSink::new(
             self.clone(),
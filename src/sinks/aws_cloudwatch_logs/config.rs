@@ -39,6 +39,8 @@ impl ClientBuilder for CloudwatchLogsClientBuilder {
     type Client = aws_sdk_cloudwatchlogs::client::Client;
     type DefaultMiddleware = aws_sdk_cloudwatchlogs::middleware::DefaultMiddleware;
 
+    const ENDPOINT_PREFIX: &'static str = "logs";
+
     fn default_middleware() -> Self::DefaultMiddleware {
         aws_sdk_cloudwatchlogs::middleware::DefaultMiddleware::new()
     }
@@ -135,8 +137,7 @@ impl CloudwatchLogsSinkConfig {
     pub async fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<CloudwatchLogsClient> {
         create_client::<CloudwatchLogsClientBuilder>(
             &self.auth,
-            self.region.region(),
-            self.region.endpoint()?,
+            &self.region,
             proxy,
             &self.tls,
             true,
@@ -171,6 +172,7 @@ impl SinkConfig for CloudwatchLogsSinkConfig {
         let client = self.create_client(cx.proxy()).await?;
         let smithy_client = self.create_smithy_client(cx.proxy()).await?;
         let svc = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
             .settings(request_settings, CloudwatchRetryLogic::new())
             .service(CloudwatchLogsPartitionSvc::new(
                 self.clone(),
@@ -1,12 +1,9 @@
 use std::convert::TryFrom;
-use std::str::FromStr;
 
 use aws_sdk_cloudwatchlogs::Client as CloudwatchLogsClient;
-use aws_sdk_cloudwatchlogs::{Endpoint, Region};
 use chrono::Duration;
 use codecs::TextSerializerConfig;
 use futures::{stream, StreamExt};
-use http::Uri;
 use similar_asserts::assert_eq;
 
 use super::*;
@@ -460,14 +457,10 @@ async fn cloudwatch_healthcheck() {
 
 async fn create_client_test() -> CloudwatchLogsClient {
     let auth = AwsAuthentication::test_auth();
-    let region = Some(Region::new("localstack"));
-    let watchlogs_address = watchlogs_address();
-    let endpoint = Some(Endpoint::immutable(
-        Uri::from_str(&watchlogs_address).unwrap(),
-    ));
+    let region = RegionOrEndpoint::with_both("localstack", watchlogs_address());
     let proxy = ProxyConfig::default();
 
-    create_client::<CloudwatchLogsClientBuilder>(&auth, region, endpoint, &proxy, &None, true)
+    create_client::<CloudwatchLogsClientBuilder>(&auth, &region, &proxy, &None, true)
         .await
         .unwrap()
 }
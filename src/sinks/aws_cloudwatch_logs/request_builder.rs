@@ -6,7 +6,7 @@ use tokio_util::codec::Encoder as _;
 use vector_common::request_metadata::{MetaDescriptive, RequestMetadata};
 use vector_core::{
     event::{EventFinalizers, Finalizable},
-    ByteSizeOf,
+    stream::batcher::limiter::EncodedSize,
 };
 
 use super::TemplateRenderingError;
@@ -117,18 +117,12 @@ impl CloudwatchRequestBuilder {
     }
 }
 
-/// ByteSizeOf is being abused to represent the encoded size of a request for the Partitioned Batcher
-///
 /// The maximum batch size is 1,048,576 bytes. This size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.
 /// source: <https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutLogEvents.html>
-impl ByteSizeOf for CloudwatchRequest {
-    fn size_of(&self) -> usize {
+impl EncodedSize for CloudwatchRequest {
+    fn encoded_size(&self) -> usize {
         self.message.len() + 26
     }
-
-    fn allocated_bytes(&self) -> usize {
-        0
-    }
 }
 
 #[cfg(test)]
@@ -8,7 +8,7 @@ use vector_common::request_metadata::{MetaDescriptive, RequestMetadata};
 use vector_core::{
     partition::Partitioner,
     sink::StreamSink,
-    stream::{BatcherSettings, DriverResponse},
+    stream::{batcher::limiter::EncodedSizeOfItemSize, BatcherSettings, DriverResponse},
 };
 
 use crate::{
@@ -49,7 +49,11 @@ where
                 let age_range = start..end;
                 future::ready(age_range.contains(&req.timestamp))
             })
-            .batched_partitioned(CloudwatchPartitioner, batcher_settings)
+            .batched_partitioned_by_size(
+                CloudwatchPartitioner,
+                batcher_settings,
+                EncodedSizeOfItemSize::default(),
+            )
             .map(|(key, events)| {
                 let metadata =
                     RequestMetadata::from_batch(events.iter().map(|req| req.get_metadata()));
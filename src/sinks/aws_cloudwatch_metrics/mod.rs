@@ -110,6 +110,8 @@ impl ClientBuilder for CloudwatchMetricsClientBuilder {
     type Client = aws_sdk_cloudwatch::client::Client;
     type DefaultMiddleware = aws_sdk_cloudwatch::middleware::DefaultMiddleware;
 
+    const ENDPOINT_PREFIX: &'static str = "monitoring";
+
     fn default_middleware() -> Self::DefaultMiddleware {
         aws_sdk_cloudwatch::middleware::DefaultMiddleware::new()
     }
@@ -158,22 +160,14 @@ impl CloudWatchMetricsSinkConfig {
     }
 
     async fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<CloudwatchClient> {
-        let region = if cfg!(test) {
+        let mut region = self.region.clone();
+        if cfg!(test) {
             // Moto (used for mocking AWS) doesn't recognize 'custom' as valid region name
-            Some(Region::new("us-east-1"))
-        } else {
-            self.region.region()
-        };
-
-        create_client::<CloudwatchMetricsClientBuilder>(
-            &self.auth,
-            region,
-            self.region.endpoint()?,
-            proxy,
-            &self.tls,
-            true,
-        )
-        .await
+            region.region = Some("us-east-1".to_owned());
+        }
+
+        create_client::<CloudwatchMetricsClientBuilder>(&self.auth, &region, proxy, &self.tls, true)
+            .await
     }
 }
 
@@ -219,6 +213,9 @@ impl CloudWatchMetricsSvc {
         config: CloudWatchMetricsSinkConfig,
         client: CloudwatchClient,
     ) -> crate::Result<VectorSink> {
+        config
+            .request
+            .reject_bandwidth_limit("aws_cloudwatch_metrics")?;
         let default_namespace = config.default_namespace.clone();
         let batch = config.batch.into_batch_settings()?;
         let request_settings = config.request.unwrap_with(
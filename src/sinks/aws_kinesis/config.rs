@@ -98,6 +98,7 @@ where
 
     let region = config.region.region();
     let service = ServiceBuilder::new()
+        .bandwidth_limit(&request_limits)
         .settings::<RT, BatchKinesisRequest<RR>>(request_limits, RT::default())
         .service(KinesisService::<C, R, E> {
             client,
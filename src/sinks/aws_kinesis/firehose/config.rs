@@ -41,6 +41,8 @@ impl ClientBuilder for KinesisFirehoseClientBuilder {
     type Client = KinesisClient;
     type DefaultMiddleware = DefaultMiddleware;
 
+    const ENDPOINT_PREFIX: &'static str = "firehose";
+
     fn default_middleware() -> Self::DefaultMiddleware {
         DefaultMiddleware::new()
     }
@@ -107,8 +109,7 @@ impl KinesisFirehoseSinkConfig {
     pub async fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<KinesisClient> {
         create_client::<KinesisFirehoseClientBuilder>(
             &self.base.auth,
-            self.base.region.region(),
-            self.base.region.endpoint()?,
+            &self.base.region,
             proxy,
             &self.base.tls,
             true,
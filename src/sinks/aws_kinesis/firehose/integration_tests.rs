@@ -134,16 +134,9 @@ async fn firehose_client() -> aws_sdk_firehose::Client {
     let auth = AwsAuthentication::test_auth();
     let proxy = ProxyConfig::default();
 
-    create_client::<KinesisFirehoseClientBuilder>(
-        &auth,
-        region_endpoint.region(),
-        region_endpoint.endpoint().unwrap(),
-        &proxy,
-        &None,
-        true,
-    )
-    .await
-    .unwrap()
+    create_client::<KinesisFirehoseClientBuilder>(&auth, &region_endpoint, &proxy, &None, true)
+        .await
+        .unwrap()
 }
 
 /// creates ES domain with the given name and returns the ARN
@@ -152,11 +145,17 @@ async fn ensure_elasticsearch_domain(domain_name: String) -> String {
         aws_sdk_elasticsearch::config::Builder::new()
             .credentials_provider(
                 AwsAuthentication::test_auth()
-                    .credentials_provider(test_region_endpoint().region().unwrap())
+                    .credentials_provider(
+                        test_region_endpoint().region().unwrap(),
+                        &Default::default(),
+                        &None,
+                    )
                     .await
                     .unwrap(),
             )
-            .endpoint_resolver(test_region_endpoint().endpoint().unwrap().unwrap())
+            .endpoint_resolver(aws_sdk_elasticsearch::Endpoint::immutable(
+                kinesis_address().parse().unwrap(),
+            ))
             .region(test_region_endpoint().region())
             .build(),
     );
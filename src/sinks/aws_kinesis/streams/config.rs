@@ -44,6 +44,8 @@ impl ClientBuilder for KinesisClientBuilder {
     type Client = KinesisClient;
     type DefaultMiddleware = aws_sdk_kinesis::middleware::DefaultMiddleware;
 
+    const ENDPOINT_PREFIX: &'static str = "kinesis";
+
     fn default_middleware() -> Self::DefaultMiddleware {
         aws_sdk_kinesis::middleware::DefaultMiddleware::new()
     }
@@ -114,8 +116,7 @@ impl KinesisStreamsSinkConfig {
     pub async fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<KinesisClient> {
         create_client::<KinesisClientBuilder>(
             &self.base.auth,
-            self.base.region.region(),
-            self.base.region.endpoint()?,
+            &self.base.region,
             proxy,
             &self.base.tls,
             true,
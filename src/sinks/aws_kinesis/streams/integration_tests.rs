@@ -171,16 +171,9 @@ async fn client() -> aws_sdk_kinesis::Client {
     let auth = AwsAuthentication::test_auth();
     let proxy = ProxyConfig::default();
     let region = RegionOrEndpoint::with_both("localstack", kinesis_address());
-    create_client::<KinesisClientBuilder>(
-        &auth,
-        region.region(),
-        region.endpoint().unwrap(),
-        &proxy,
-        &None,
-        true,
-    )
-    .await
-    .unwrap()
+    create_client::<KinesisClientBuilder>(&auth, &region, &proxy, &None, true)
+        .await
+        .unwrap()
 }
 
 async fn ensure_stream(stream_name: String) {
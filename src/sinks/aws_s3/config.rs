@@ -1,10 +1,7 @@
 use std::convert::TryInto;
 
 use aws_sdk_s3::Client as S3Client;
-use codecs::{
-    encoding::{Framer, FramingConfig},
-    TextSerializerConfig,
-};
+use codecs::{encoding::FramingConfig, TextSerializerConfig};
 use tower::ServiceBuilder;
 use vector_config::configurable_component;
 use vector_core::sink::VectorSink;
@@ -12,11 +9,7 @@ use vector_core::sink::VectorSink;
 use super::sink::S3RequestOptions;
 use crate::{
     aws::{AwsAuthentication, RegionOrEndpoint},
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
-    config::{
-        AcknowledgementsConfig, DataType, GenerateConfig, Input, ProxyConfig, SinkConfig,
-        SinkContext,
-    },
+    config::{AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext},
     sinks::{
         s3_common::{
             self,
@@ -26,8 +19,8 @@ use crate::{
             sink::S3Sink,
         },
         util::{
-            BatchConfig, BulkSizeBasedDefaultBatchSettings, Compression, ServiceBuilderExt,
-            TowerRequestConfig,
+            parquet::ObjectEncodingConfig, BatchConfig, BulkSizeBasedDefaultBatchSettings,
+            Compression, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck,
     },
@@ -90,7 +83,8 @@ pub struct S3SinkConfig {
 
     /// The filename extension to use in the object key.
     ///
-    /// This overrides setting the extension based on the configured `compression`.
+    /// This overrides setting the extension based on the configured `compression`, or
+    /// `batch_encoding`.
     #[configurable(metadata(docs::examples = "json"))]
     pub filename_extension: Option<String>,
 
@@ -101,7 +95,7 @@ pub struct S3SinkConfig {
     pub region: RegionOrEndpoint,
 
     #[serde(flatten)]
-    pub encoding: EncodingConfigWithFraming,
+    pub encoding: ObjectEncodingConfig,
 
     /// Compression configuration.
     ///
@@ -109,6 +103,9 @@ pub struct S3SinkConfig {
     ///
     /// Some cloud storage API clients and browsers handle decompression transparently, so
     /// depending on how they are accessed, files may not always appear to be compressed.
+    ///
+    /// This is ignored when `batch_encoding` is set, as batch encodings compress their files
+    /// themselves.
     #[configurable(derived)]
     #[serde(default = "Compression::gzip_default")]
     pub compression: Compression,
@@ -177,7 +174,7 @@ impl SinkConfig for S3SinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        Input::new(self.encoding.input_type())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -193,6 +190,7 @@ impl S3SinkConfig {
         // limits, rate limits, and whatever else the client should have.
         let request_limits = self.request.unwrap_with(&Default::default());
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, S3RetryLogic)
             .service(service);
 
@@ -208,9 +206,7 @@ impl S3SinkConfig {
             .transpose()?;
         let partitioner = S3KeyPartitioner::new(key_prefix, ssekms_key_id);
 
-        let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let encoder = self.encoding.build()?;
 
         let request_options = S3RequestOptions {
             bucket: self.bucket.clone(),
@@ -218,8 +214,8 @@ impl S3SinkConfig {
             filename_extension: self.filename_extension.clone(),
             filename_time_format: self.filename_time_format.clone(),
             filename_append_uuid: self.filename_append_uuid,
-            encoder: (transformer, encoder),
-            compression: self.compression,
+            compression: encoder.compression(self.compression),
+            encoder,
         };
 
         let sink = S3Sink::new(service, request_options, partitioner, batch_settings);
@@ -239,9 +235,37 @@ impl S3SinkConfig {
 #[cfg(test)]
 mod tests {
     use super::S3SinkConfig;
+    use crate::sinks::util::{parquet::ObjectEncoder, Compression};
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<S3SinkConfig>();
     }
+
+    #[test]
+    fn parse_parquet_batch_encoding() {
+        let config: S3SinkConfig = toml::from_str(
+            r#"
+            bucket = "logs"
+            batch_encoding.codec = "parquet"
+            batch_encoding.except_fields = ["source_type"]
+            batch_encoding.parquet.row_group_size = 5000
+            batch_encoding.parquet.schema.message = "string"
+            batch_encoding.parquet.schema.timestamp = "timestamp"
+            "#,
+        )
+        .unwrap();
+
+        let encoder = config.encoding.build().unwrap();
+        assert!(matches!(encoder, ObjectEncoder::Batch(_)));
+        assert_eq!(encoder.extension(config.compression), "parquet");
+        assert_eq!(encoder.compression(config.compression), Compression::None);
+    }
+
+    #[test]
+    fn requires_encoding_without_batch_encoding() {
+        let config: S3SinkConfig = toml::from_str(r#"bucket = "logs""#).unwrap();
+
+        assert!(config.encoding.build().is_err());
+    }
 }
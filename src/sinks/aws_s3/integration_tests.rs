@@ -366,16 +366,9 @@ async fn client() -> S3Client {
     let region = RegionOrEndpoint::with_both("minio", s3_address());
     let proxy = ProxyConfig::default();
     let tls_options = None;
-    create_client::<S3ClientBuilder>(
-        &auth,
-        region.region(),
-        region.endpoint().unwrap(),
-        &proxy,
-        &tls_options,
-        true,
-    )
-    .await
-    .unwrap()
+    create_client::<S3ClientBuilder>(&auth, &region, &proxy, &tls_options, true)
+        .await
+        .unwrap()
 }
 
 fn config(bucket: &str, batch_size: usize) -> S3SinkConfig {
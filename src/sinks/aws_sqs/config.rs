@@ -146,15 +146,7 @@ impl SqsSinkConfig {
     }
 
     pub async fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<SqsClient> {
-        create_client::<SqsClientBuilder>(
-            &self.auth,
-            self.region.region(),
-            self.region.endpoint()?,
-            proxy,
-            &self.tls,
-            true,
-        )
-        .await
+        create_client::<SqsClientBuilder>(&self.auth, &self.region, proxy, &self.tls, true).await
     }
 
     pub fn message_group_id(&self) -> crate::Result<Option<Template>> {
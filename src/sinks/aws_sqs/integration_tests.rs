@@ -1,11 +1,9 @@
 #![cfg(all(test, feature = "aws-sqs-integration-tests"))]
 
 use std::collections::HashMap;
-use std::str::FromStr;
 
-use aws_sdk_sqs::{model::QueueAttributeName, Client as SqsClient, Endpoint, Region};
+use aws_sdk_sqs::{model::QueueAttributeName, Client as SqsClient};
 use codecs::TextSerializerConfig;
-use http::Uri;
 use tokio::time::{sleep, Duration};
 
 use super::{config::SqsSinkConfig, sink::SqsSink};
@@ -27,18 +25,11 @@ fn sqs_address() -> String {
 async fn create_test_client() -> SqsClient {
     let auth = AwsAuthentication::test_auth();
 
-    let endpoint = sqs_address();
+    let region = RegionOrEndpoint::with_both("localstack", sqs_address());
     let proxy = ProxyConfig::default();
-    create_client::<SqsClientBuilder>(
-        &auth,
-        Some(Region::new("localstack")),
-        Some(Endpoint::immutable(Uri::from_str(&endpoint).unwrap())),
-        &proxy,
-        &None,
-        true,
-    )
-    .await
-    .unwrap()
+    create_client::<SqsClientBuilder>(&auth, &region, &proxy, &None, true)
+        .await
+        .unwrap()
 }
 
 #[tokio::test]
@@ -32,7 +32,7 @@ pub(crate) struct SqsSink {
 
 impl SqsSink {
     pub fn new(config: SqsSinkConfig, client: SqsClient) -> crate::Result<Self> {
-        let request = config.request;
+        let request = config.request.clone();
         Ok(SqsSink {
             request_builder: SqsRequestBuilder::new(config)?,
             service: SqsService::new(client),
@@ -46,6 +46,7 @@ impl SqsSink {
             .unwrap_with(&TowerRequestConfig::default().timeout_secs(30));
         let request_builder_concurrency_limit = NonZeroUsize::new(50);
         let service = tower::ServiceBuilder::new()
+            .bandwidth_limit(&request)
             .settings(request, super::retry::SqsRetryLogic)
             .service(self.service);
 
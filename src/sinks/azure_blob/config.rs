@@ -1,22 +1,25 @@
 use std::sync::Arc;
 
 use azure_storage_blobs::prelude::*;
-use codecs::{encoding::Framer, JsonSerializerConfig, NewlineDelimitedEncoderConfig};
+use codecs::{JsonSerializerConfig, NewlineDelimitedEncoderConfig};
 use tower::ServiceBuilder;
 use vector_common::sensitive_string::SensitiveString;
 use vector_config::configurable_component;
 
 use super::request_builder::AzureBlobRequestOptions;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
-    config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
     sinks::{
         azure_common::{
-            self, config::AzureBlobRetryLogic, service::AzureBlobService, sink::AzureBlobSink,
+            self,
+            append::{AppendBlobSettings, MAX_APPEND_BLOCK_SIZE},
+            config::AzureBlobRetryLogic,
+            service::AzureBlobService,
+            sink::AzureBlobSink,
         },
         util::{
-            partitioner::KeyPartitioner, BatchConfig, BulkSizeBasedDefaultBatchSettings,
-            Compression, ServiceBuilderExt, TowerRequestConfig,
+            parquet::ObjectEncodingConfig, partitioner::KeyPartitioner, BatchConfig,
+            BulkSizeBasedDefaultBatchSettings, Compression, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -115,8 +118,31 @@ pub struct AzureBlobSinkConfig {
     /// blob keys must be unique.
     pub blob_append_uuid: Option<bool>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub blob_type: AzureBlobType,
+
+    /// The size of an append blob after which it is rotated to a new blob, in bytes.
+    ///
+    /// A batch is never split across blobs, so a batch larger than this is written to a blob of its
+    /// own. Append blobs are also rotated once they reach the limit of 50,000 blocks.
+    ///
+    /// Only applies when `blob_type` is `append`.
+    #[serde(default = "default_max_blob_size_bytes")]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_blob_size_bytes: u64,
+
+    /// The maximum size of each block appended to an append blob, in bytes.
+    ///
+    /// Batches larger than this are appended as several blocks. The maximum is 100 MiB.
+    ///
+    /// Only applies when `blob_type` is `append`.
+    #[serde(default = "default_block_size_bytes")]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub block_size_bytes: usize,
+
     #[serde(flatten)]
-    pub encoding: EncodingConfigWithFraming,
+    pub encoding: ObjectEncodingConfig,
 
     #[configurable(derived)]
     #[serde(default = "Compression::gzip_default")]
@@ -139,10 +165,38 @@ pub struct AzureBlobSinkConfig {
     pub(super) acknowledgements: AcknowledgementsConfig,
 }
 
+/// The type of blob to write batches to.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AzureBlobType {
+    /// Write each batch to a new block blob.
+    #[default]
+    Block,
+
+    /// Append the batches of each blob prefix to an append blob, until it's rotated.
+    ///
+    /// This keeps continuously written logs in a small number of blobs. A new blob is started
+    /// when Vector restarts. As the blocks of a batch are appended individually, a batch that's
+    /// retried after a partial failure may be written more than once.
+    ///
+    /// Only `gzip` compression, whose concatenated streams remain valid, or no compression is
+    /// supported.
+    Append,
+}
+
 pub fn default_blob_prefix() -> Template {
     Template::try_from(DEFAULT_KEY_PREFIX).unwrap()
 }
 
+const fn default_max_blob_size_bytes() -> u64 {
+    1024 * 1024 * 1024
+}
+
+const fn default_block_size_bytes() -> usize {
+    4 * 1024 * 1024
+}
+
 impl GenerateConfig for AzureBlobSinkConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
@@ -153,6 +207,9 @@ impl GenerateConfig for AzureBlobSinkConfig {
             blob_prefix: default_blob_prefix(),
             blob_time_format: Some(String::from("%s")),
             blob_append_uuid: Some(true),
+            blob_type: AzureBlobType::default(),
+            max_blob_size_bytes: default_max_blob_size_bytes(),
+            block_size_bytes: default_block_size_bytes(),
             encoding: (Some(NewlineDelimitedEncoderConfig::new()), JsonSerializerConfig::default()).into(),
             compression: Compression::gzip_default(),
             batch: BatchConfig::default(),
@@ -184,7 +241,7 @@ impl SinkConfig for AzureBlobSinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        Input::new(self.encoding.input_type())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -201,9 +258,14 @@ impl AzureBlobSinkConfig {
         let request_limits = self
             .request
             .unwrap_with(&TowerRequestConfig::default().rate_limit_num(250));
+        let mut service = AzureBlobService::new(client);
+        if let Some(settings) = self.append_blob_settings()? {
+            service = service.with_append_blobs(settings);
+        }
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, AzureBlobRetryLogic)
-            .service(AzureBlobService::new(client));
+            .service(service);
 
         // Configure our partitioning/batching.
         let batcher_settings = self.batch.into_batcher_settings()?;
@@ -217,16 +279,14 @@ impl AzureBlobSinkConfig {
             .blob_append_uuid
             .unwrap_or(DEFAULT_FILENAME_APPEND_UUID);
 
-        let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let encoder = self.encoding.build()?;
 
         let request_options = AzureBlobRequestOptions {
             container_name: self.container_name.clone(),
             blob_time_format,
             blob_append_uuid,
-            encoder: (transformer, encoder),
-            compression: self.compression,
+            compression: encoder.compression(self.compression),
+            encoder,
         };
 
         let sink = AzureBlobSink::new(
@@ -239,6 +299,37 @@ impl AzureBlobSinkConfig {
         Ok(VectorSink::from_event_streamsink(sink))
     }
 
+    pub(super) fn append_blob_settings(&self) -> crate::Result<Option<AppendBlobSettings>> {
+        if self.blob_type != AzureBlobType::Append {
+            return Ok(None);
+        }
+
+        // Files of batch encodings, unlike gzip streams, can't be concatenated.
+        if self.encoding.is_batch() {
+            return Err("`batch_encoding` is not supported with append blobs".into());
+        }
+        if matches!(self.compression, Compression::Zlib(_)) {
+            return Err("`zlib` compression is not supported with append blobs".into());
+        }
+        // Blobs are named when they're rotated, which could otherwise overwrite a blob of the
+        // same name.
+        if self.blob_append_uuid == Some(false) {
+            return Err("`blob_append_uuid` can't be disabled with append blobs".into());
+        }
+        if self.block_size_bytes == 0 || self.block_size_bytes > MAX_APPEND_BLOCK_SIZE {
+            return Err(format!(
+                "`block_size_bytes` must be between 1 and {}",
+                MAX_APPEND_BLOCK_SIZE
+            )
+            .into());
+        }
+
+        Ok(Some(AppendBlobSettings {
+            max_blob_size: self.max_blob_size_bytes,
+            block_size: self.block_size_bytes,
+        }))
+    }
+
     pub fn key_partitioner(&self) -> crate::Result<KeyPartitioner> {
         Ok(KeyPartitioner::new(self.blob_prefix.clone()))
     }
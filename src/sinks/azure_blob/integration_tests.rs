@@ -15,7 +15,7 @@ use futures::{stream, Stream, StreamExt};
 use http::StatusCode;
 use vector_core::ByteSizeOf;
 
-use super::config::AzureBlobSinkConfig;
+use super::config::{AzureBlobSinkConfig, AzureBlobType};
 use crate::{
     event::{Event, EventArray, LogEvent},
     sinks::{
@@ -215,6 +215,30 @@ async fn azure_blob_rotate_files_after_the_buffer_size_is_reached() {
     }
 }
 
+#[tokio::test]
+async fn azure_blob_append_batches_into_blob() {
+    let blob_prefix = format!("lines/append/blob/{}/", random_string(10));
+    let mut config = AzureBlobSinkConfig::new_emulator().await;
+    config.batch.max_events = Some(10);
+    let config = AzureBlobSinkConfig {
+        blob_prefix: blob_prefix.clone().try_into().unwrap(),
+        blob_type: AzureBlobType::Append,
+        block_size_bytes: 64,
+        ..config
+    };
+    let (mut lines, input) = random_lines_with_stream(100, 100, None);
+
+    config.run_assert(input).await;
+
+    // All the batches are appended to a single blob, though not necessarily in order.
+    let blobs = config.list_blobs(blob_prefix).await;
+    assert_eq!(blobs.len(), 1);
+    let (_, mut blob_lines) = config.get_blob(blobs[0].clone()).await;
+    lines.sort();
+    blob_lines.sort();
+    assert_eq!(lines, blob_lines);
+}
+
 impl AzureBlobSinkConfig {
     pub async fn new_emulator() -> AzureBlobSinkConfig {
         let address = std::env::var("AZURE_ADDRESS").unwrap_or_else(|_| "localhost".into());
@@ -226,6 +250,9 @@ impl AzureBlobSinkConfig {
                 blob_prefix: Default::default(),
                 blob_time_format: None,
                 blob_append_uuid: None,
+                blob_type: AzureBlobType::Block,
+                max_blob_size_bytes: 1024 * 1024 * 1024,
+                block_size_bytes: 4 * 1024 * 1024,
                 encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
                 compression: Compression::None,
                 batch: Default::default(),
@@ -1,18 +1,16 @@
 use bytes::Bytes;
 use chrono::Utc;
-use codecs::encoding::Framer;
 use uuid::Uuid;
 use vector_common::request_metadata::RequestMetadata;
 use vector_core::ByteSizeOf;
 
 use crate::{
-    codecs::{Encoder, Transformer},
     event::{Event, Finalizable},
     sinks::{
         azure_common::config::{AzureBlobMetadata, AzureBlobRequest},
         util::{
-            metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
-            RequestBuilder,
+            metadata::RequestMetadataBuilder, parquet::ObjectEncoder,
+            request_builder::EncodeResult, Compression, RequestBuilder,
         },
     },
 };
@@ -22,14 +20,14 @@ pub struct AzureBlobRequestOptions {
     pub container_name: String,
     pub blob_time_format: String,
     pub blob_append_uuid: bool,
-    pub encoder: (Transformer, Encoder<Framer>),
+    pub encoder: ObjectEncoder,
     pub compression: Compression,
 }
 
 impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
     type Metadata = AzureBlobMetadata;
     type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Encoder = ObjectEncoder;
     type Payload = Bytes;
     type Request = AzureBlobRequest;
     type Error = std::io::Error;
@@ -49,6 +47,7 @@ impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
         let (partition_key, mut events) = input;
         let finalizers = events.take_finalizers();
         let azure_metadata = AzureBlobMetadata {
+            blob_prefix: partition_key.clone(),
             partition_key,
             count: events.len(),
             byte_size: events.size_of(),
@@ -74,7 +73,7 @@ impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
                 .unwrap_or_else(|| formatted_ts.to_string())
         };
 
-        let extension = self.compression.extension();
+        let extension = self.encoder.extension(self.compression);
         azure_metadata.partition_key = format!(
             "{}{}.{}",
             azure_metadata.partition_key, blob_name, extension
@@ -93,7 +92,10 @@ impl RequestBuilder<(String, Vec<Event>)> for AzureBlobRequestOptions {
         AzureBlobRequest {
             blob_data,
             content_encoding: self.compression.content_encoding(),
-            content_type: self.compression.content_type(),
+            content_type: match self.encoder {
+                ObjectEncoder::Framed(_) => self.compression.content_type(),
+                ObjectEncoder::Batch(_) => self.encoder.content_type(),
+            },
             metadata: azure_metadata,
             request_metadata,
         }
@@ -6,14 +6,17 @@ use codecs::{
 };
 use vector_core::partition::Partitioner;
 
-use super::config::AzureBlobSinkConfig;
+use super::config::{AzureBlobSinkConfig, AzureBlobType};
 use super::request_builder::AzureBlobRequestOptions;
-use crate::codecs::EncodingConfigWithFraming;
 use crate::event::{Event, LogEvent};
-use crate::sinks::util::{request_builder::RequestBuilder, Compression};
+use crate::sinks::util::{
+    parquet::{BatchEncodingConfig, BatchSerializerConfig, ObjectEncoder, ObjectEncodingConfig},
+    request_builder::RequestBuilder,
+    Compression,
+};
 use crate::{codecs::Encoder, sinks::util::request_builder::EncodeResult};
 
-fn default_config(encoding: EncodingConfigWithFraming) -> AzureBlobSinkConfig {
+fn default_config(encoding: ObjectEncodingConfig) -> AzureBlobSinkConfig {
     AzureBlobSinkConfig {
         connection_string: Default::default(),
         storage_account: Default::default(),
@@ -22,6 +25,9 @@ fn default_config(encoding: EncodingConfigWithFraming) -> AzureBlobSinkConfig {
         blob_prefix: Default::default(),
         blob_time_format: Default::default(),
         blob_append_uuid: Default::default(),
+        blob_type: Default::default(),
+        max_blob_size_bytes: 1024 * 1024 * 1024,
+        block_size_bytes: 4 * 1024 * 1024,
         encoding,
         compression: Compression::gzip_default(),
         batch: Default::default(),
@@ -58,13 +64,13 @@ fn azure_blob_build_request_without_compression() {
         container_name,
         blob_time_format,
         blob_append_uuid,
-        encoder: (
+        encoder: ObjectEncoder::Framed((
             Default::default(),
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::new().into(),
                 TextSerializerConfig::default().build().into(),
             ),
-        ),
+        )),
         compression,
     };
 
@@ -103,13 +109,13 @@ fn azure_blob_build_request_with_compression() {
         container_name,
         blob_time_format,
         blob_append_uuid,
-        encoder: (
+        encoder: ObjectEncoder::Framed((
             Default::default(),
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::new().into(),
                 TextSerializerConfig::default().build().into(),
             ),
-        ),
+        )),
         compression,
     };
     let (metadata, request_metadata_builder, _events) =
@@ -124,6 +130,46 @@ fn azure_blob_build_request_with_compression() {
     assert_eq!(request.content_type, "application/gzip");
 }
 
+#[test]
+fn azure_blob_build_request_with_batch_encoding() {
+    let log = Event::Log(LogEvent::from("test message"));
+    let sink_config = AzureBlobSinkConfig {
+        blob_prefix: "blob".try_into().unwrap(),
+        container_name: String::from("logs"),
+        ..default_config(
+            BatchEncodingConfig::from(BatchSerializerConfig::Parquet {
+                parquet: Default::default(),
+            })
+            .into(),
+        )
+    };
+
+    let key = sink_config
+        .key_partitioner()
+        .unwrap()
+        .partition(&log)
+        .expect("key wasn't provided");
+
+    let encoder = sink_config.encoding.build().unwrap();
+    let request_options = AzureBlobRequestOptions {
+        container_name: sink_config.container_name.clone(),
+        blob_time_format: String::from(""),
+        blob_append_uuid: false,
+        compression: encoder.compression(sink_config.compression),
+        encoder,
+    };
+    let (metadata, request_metadata_builder, _events) =
+        request_options.split_input((key, vec![log]));
+
+    let payload = EncodeResult::uncompressed(Bytes::new());
+    let request_metadata = request_metadata_builder.build(&payload);
+    let request = request_options.build_request(metadata, request_metadata, payload);
+
+    assert_eq!(request.metadata.partition_key, "blob.parquet".to_string());
+    assert_eq!(request.content_encoding, None);
+    assert_eq!(request.content_type, "application/vnd.apache.parquet");
+}
+
 #[test]
 fn azure_blob_build_request_with_time_format() {
     let log = Event::Log(LogEvent::from("test message"));
@@ -147,13 +193,13 @@ fn azure_blob_build_request_with_time_format() {
         container_name,
         blob_time_format,
         blob_append_uuid,
-        encoder: (
+        encoder: ObjectEncoder::Framed((
             Default::default(),
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::new().into(),
                 TextSerializerConfig::default().build().into(),
             ),
-        ),
+        )),
         compression,
     };
 
@@ -195,13 +241,13 @@ fn azure_blob_build_request_with_uuid() {
         container_name,
         blob_time_format,
         blob_append_uuid,
-        encoder: (
+        encoder: ObjectEncoder::Framed((
             Default::default(),
             Encoder::<Framer>::new(
                 NewlineDelimitedEncoder::new().into(),
                 TextSerializerConfig::default().build().into(),
             ),
-        ),
+        )),
         compression,
     };
 
@@ -216,3 +262,55 @@ fn azure_blob_build_request_with_uuid() {
     assert_eq!(request.content_encoding, None);
     assert_eq!(request.content_type, "text/plain");
 }
+
+#[test]
+fn azure_blob_append_blob_settings() {
+    let config: AzureBlobSinkConfig = toml::from_str(
+        r#"
+        connection_string = "UseDevelopmentStorage=true"
+        container_name = "logs"
+        blob_type = "append"
+        max_blob_size_bytes = 1048576
+        block_size_bytes = 65536
+        encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    let settings = config.append_blob_settings().unwrap().unwrap();
+    assert_eq!(settings.max_blob_size, 1024 * 1024);
+    assert_eq!(settings.block_size, 64 * 1024);
+
+    let block_blobs = AzureBlobSinkConfig {
+        blob_type: AzureBlobType::Block,
+        ..config.clone()
+    };
+    assert!(block_blobs.append_blob_settings().unwrap().is_none());
+
+    let zlib = AzureBlobSinkConfig {
+        compression: Compression::zlib_default(),
+        ..config.clone()
+    };
+    assert!(zlib.append_blob_settings().is_err());
+
+    let without_uuid = AzureBlobSinkConfig {
+        blob_append_uuid: Some(false),
+        ..config.clone()
+    };
+    assert!(without_uuid.append_blob_settings().is_err());
+
+    let parquet = AzureBlobSinkConfig {
+        encoding: BatchEncodingConfig::from(BatchSerializerConfig::Parquet {
+            parquet: Default::default(),
+        })
+        .into(),
+        ..config.clone()
+    };
+    assert!(parquet.append_blob_settings().is_err());
+
+    let oversized_blocks = AzureBlobSinkConfig {
+        block_size_bytes: 200 * 1024 * 1024,
+        ..config
+    };
+    assert!(oversized_blocks.append_blob_settings().is_err());
+}
@@ -0,0 +1,161 @@
+//! Tracking of the append blobs that batches are written to.
+//!
+//! Each partition of the sink appends its batches to a single blob until that blob reaches its
+//! maximum size or number of blocks, at which point it's rotated to a new blob.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::OnceCell;
+
+/// The maximum number of blocks that can be appended to a single append blob.
+pub const MAX_APPEND_BLOCKS: usize = 50_000;
+
+/// The maximum size of a single appended block.
+pub const MAX_APPEND_BLOCK_SIZE: usize = 100 * 1024 * 1024;
+
+/// Blobs of partitions that haven't been written to for this long are forgotten, so that
+/// partitions which are no longer used, such as those of past dates, don't accumulate.
+const IDLE_BLOB_TIMEOUT: Duration = Duration::from_secs(3600);
+
+#[derive(Clone, Copy, Debug)]
+pub struct AppendBlobSettings {
+    /// The size after which a blob is rotated.
+    pub max_blob_size: u64,
+
+    /// The maximum size of each appended block.
+    pub block_size: usize,
+}
+
+/// The blob a batch is appended to.
+pub struct AppendTarget {
+    pub blob_name: String,
+
+    /// Initialized once the blob has been created.
+    pub created: Arc<OnceCell<()>>,
+}
+
+struct CurrentBlob {
+    name: String,
+    size: u64,
+    blocks: usize,
+    created: Arc<OnceCell<()>>,
+    last_used: Instant,
+}
+
+pub struct AppendBlobs {
+    settings: AppendBlobSettings,
+    blobs: Mutex<HashMap<String, CurrentBlob>>,
+}
+
+impl AppendBlobs {
+    pub fn new(settings: AppendBlobSettings) -> Self {
+        Self {
+            settings,
+            blobs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub const fn settings(&self) -> AppendBlobSettings {
+        self.settings
+    }
+
+    /// Reserves room for a payload of `size` bytes, split into `blocks` blocks, in the current blob
+    /// of the partition.
+    ///
+    /// When the partition has no current blob, or the payload doesn't fit in it, the partition is
+    /// rotated to a new blob named `new_blob_name`. A payload is never split across blobs, so that
+    /// compressed payloads remain readable.
+    pub fn reserve(
+        &self,
+        partition: &str,
+        new_blob_name: &str,
+        size: u64,
+        blocks: usize,
+    ) -> AppendTarget {
+        let now = Instant::now();
+        let mut blobs = self.blobs.lock().expect("poisoned lock");
+
+        let fits = blobs.get(partition).map_or(false, |blob| {
+            blob.size.saturating_add(size) <= self.settings.max_blob_size
+                && blob.blocks + blocks <= MAX_APPEND_BLOCKS
+        });
+        if !fits {
+            blobs.retain(|_, blob| now.duration_since(blob.last_used) < IDLE_BLOB_TIMEOUT);
+            blobs.insert(
+                partition.to_owned(),
+                CurrentBlob {
+                    name: new_blob_name.to_owned(),
+                    size: 0,
+                    blocks: 0,
+                    created: Arc::new(OnceCell::new()),
+                    last_used: now,
+                },
+            );
+        }
+
+        let blob = blobs.get_mut(partition).expect("blob was just inserted");
+        blob.size += size;
+        blob.blocks += blocks;
+        blob.last_used = now;
+        AppendTarget {
+            blob_name: blob.name.clone(),
+            created: Arc::clone(&blob.created),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn append_blobs(max_blob_size: u64) -> AppendBlobs {
+        AppendBlobs::new(AppendBlobSettings {
+            max_blob_size,
+            block_size: 4,
+        })
+    }
+
+    #[test]
+    fn appends_to_the_current_blob_until_full() {
+        let blobs = append_blobs(10);
+
+        assert_eq!(blobs.reserve("a/", "a/1", 4, 1).blob_name, "a/1");
+        assert_eq!(blobs.reserve("a/", "a/2", 6, 2).blob_name, "a/1");
+        // Partitions have separate blobs.
+        assert_eq!(blobs.reserve("b/", "b/1", 4, 1).blob_name, "b/1");
+        // The payload doesn't fit in the current blob anymore.
+        assert_eq!(blobs.reserve("a/", "a/3", 1, 1).blob_name, "a/3");
+        assert_eq!(blobs.reserve("a/", "a/4", 9, 3).blob_name, "a/3");
+        // Payloads larger than the maximum blob size get a blob of their own.
+        assert_eq!(blobs.reserve("a/", "a/5", 20, 5).blob_name, "a/5");
+        assert_eq!(blobs.reserve("a/", "a/6", 1, 1).blob_name, "a/6");
+    }
+
+    #[test]
+    fn rotates_blobs_at_the_block_limit() {
+        let blobs = append_blobs(u64::MAX);
+
+        assert_eq!(
+            blobs
+                .reserve("a/", "a/1", 1, MAX_APPEND_BLOCKS - 1)
+                .blob_name,
+            "a/1"
+        );
+        assert_eq!(blobs.reserve("a/", "a/2", 1, 1).blob_name, "a/1");
+        assert_eq!(blobs.reserve("a/", "a/3", 1, 1).blob_name, "a/3");
+    }
+
+    #[test]
+    fn shares_creation_of_blobs() {
+        let blobs = append_blobs(10);
+
+        let first = blobs.reserve("a/", "a/1", 1, 1);
+        first.created.set(()).unwrap();
+        assert!(blobs.reserve("a/", "a/2", 1, 1).created.initialized());
+        assert!(!blobs.reserve("a/", "a/3", 10, 1).created.initialized());
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use azure_core::{error::HttpError, RetryOptions};
 use azure_identity::{AutoRefreshingTokenCredential, DefaultAzureCredential};
 use azure_storage::{prelude::*, CloudLocation, ConnectionString};
-use azure_storage_blobs::{blob::operations::PutBlockBlobResponse, prelude::*};
+use azure_storage_blobs::prelude::*;
 use bytes::Bytes;
 use futures::FutureExt;
 use http::StatusCode;
@@ -39,7 +39,10 @@ impl MetaDescriptive for AzureBlobRequest {
 
 #[derive(Clone, Debug)]
 pub struct AzureBlobMetadata {
+    /// The name of the blob to write to.
     pub partition_key: String,
+    /// The rendered blob prefix, identifying the partition of the sink the request belongs to.
+    pub blob_prefix: String,
     pub count: usize,
     pub byte_size: usize,
     pub finalizers: EventFinalizers,
@@ -60,7 +63,6 @@ impl RetryLogic for AzureBlobRetryLogic {
 
 #[derive(Debug)]
 pub struct AzureBlobResponse {
-    pub inner: PutBlockBlobResponse,
     pub count: usize,
     pub events_byte_size: usize,
     pub byte_size: usize,
@@ -5,26 +5,114 @@ use std::{
 };
 
 use azure_storage_blobs::prelude::*;
+use bytes::Bytes;
 use futures::future::BoxFuture;
 use tower::Service;
 use tracing::Instrument;
 
-use crate::sinks::azure_common::config::{AzureBlobRequest, AzureBlobResponse};
+use crate::sinks::azure_common::{
+    append::{AppendBlobSettings, AppendBlobs},
+    config::{AzureBlobRequest, AzureBlobResponse},
+};
+
+type Error = Box<dyn std::error::Error + std::marker::Send + std::marker::Sync>;
 
 #[derive(Clone)]
 pub(crate) struct AzureBlobService {
     client: Arc<ContainerClient>,
+    append_blobs: Option<Arc<AppendBlobs>>,
 }
 
 impl AzureBlobService {
     pub fn new(client: Arc<ContainerClient>) -> AzureBlobService {
-        AzureBlobService { client }
+        AzureBlobService {
+            client,
+            append_blobs: None,
+        }
+    }
+
+    /// Appends the batches of each partition to an append blob, instead of writing each batch to
+    /// its own block blob.
+    pub fn with_append_blobs(mut self, settings: AppendBlobSettings) -> AzureBlobService {
+        self.append_blobs = Some(Arc::new(AppendBlobs::new(settings)));
+        self
+    }
+
+    async fn put_block_blob(&self, request: AzureBlobRequest) -> StdResult<(), Error> {
+        let client = self
+            .client
+            .blob_client(request.metadata.partition_key.as_str());
+        let blob = client
+            .put_block_blob(request.blob_data)
+            .content_type(request.content_type);
+        let blob = match request.content_encoding {
+            Some(encoding) => blob.content_encoding(encoding),
+            None => blob,
+        };
+
+        blob.into_future()
+            .instrument(info_span!("request").or_current())
+            .await?;
+        Ok(())
+    }
+
+    async fn append_blocks(
+        &self,
+        append_blobs: &AppendBlobs,
+        request: AzureBlobRequest,
+    ) -> StdResult<(), Error> {
+        let blocks = split_blocks(request.blob_data, append_blobs.settings().block_size);
+        let size = blocks.iter().map(|block| block.len() as u64).sum();
+        let target = append_blobs.reserve(
+            &request.metadata.blob_prefix,
+            &request.metadata.partition_key,
+            size,
+            blocks.len(),
+        );
+        let client = self.client.blob_client(target.blob_name.as_str());
+
+        target
+            .created
+            .get_or_try_init(|| async {
+                debug!(message = "Creating append blob.", blob = ?target.blob_name);
+                let blob = client.put_append_blob().content_type(request.content_type);
+                let blob = match request.content_encoding {
+                    Some(encoding) => blob.content_encoding(encoding),
+                    None => blob,
+                };
+                blob.into_future()
+                    .instrument(info_span!("request").or_current())
+                    .await
+                    .map(|_| ())
+            })
+            .await?;
+
+        for block in blocks {
+            client
+                .append_block(block)
+                .into_future()
+                .instrument(info_span!("request").or_current())
+                .await?;
+        }
+        Ok(())
     }
 }
 
+/// Splits the payload into blocks of at most `block_size` bytes.
+fn split_blocks(mut payload: Bytes, block_size: usize) -> Vec<Bytes> {
+    let mut blocks = Vec::with_capacity(payload.len() / block_size + 1);
+    while payload.len() > block_size {
+        blocks.push(payload.split_to(block_size));
+    }
+    if !payload.is_empty() {
+        blocks.push(payload);
+    }
+    blocks
+}
+
 impl Service<AzureBlobRequest> for AzureBlobService {
     type Response = AzureBlobResponse;
-    type Error = Box<dyn std::error::Error + std::marker::Send + std::marker::Sync>;
+    type Error = Error;
     type Future = BoxFuture<'static, StdResult<Self::Response, Self::Error>>;
 
     // Emission of an internal event in case of errors is handled upstream by the caller.
@@ -37,30 +125,36 @@ impl Service<AzureBlobRequest> for AzureBlobService {
         let this = self.clone();
 
         Box::pin(async move {
-            let client = this
-                .client
-                .blob_client(request.metadata.partition_key.as_str());
             let byte_size = request.blob_data.len();
-            let blob = client
-                .put_block_blob(request.blob_data)
-                .content_type(request.content_type);
-            let blob = match request.content_encoding {
-                Some(encoding) => blob.content_encoding(encoding),
-                None => blob,
-            };
-
-            let result = blob
-                .into_future()
-                .instrument(info_span!("request").or_current())
-                .await
-                .map_err(|err| err.into());
+            let count = request.metadata.count;
+            let events_byte_size = request.metadata.byte_size;
+
+            match &this.append_blobs {
+                Some(append_blobs) => this.append_blocks(append_blobs, request).await?,
+                None => this.put_block_blob(request).await?,
+            }
 
-            result.map(|inner| AzureBlobResponse {
-                inner,
-                count: request.metadata.count,
-                events_byte_size: request.metadata.byte_size,
+            Ok(AzureBlobResponse {
+                count,
+                events_byte_size,
                 byte_size,
             })
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_payloads_into_blocks() {
+        let blocks = split_blocks(Bytes::from_static(b"abcdefghij"), 4);
+        assert_eq!(blocks, vec!["abcd", "efgh", "ij"]);
+
+        let blocks = split_blocks(Bytes::from_static(b"abcdefgh"), 4);
+        assert_eq!(blocks, vec!["abcd", "efgh"]);
+
+        assert!(split_blocks(Bytes::new(), 4).is_empty());
+    }
+}
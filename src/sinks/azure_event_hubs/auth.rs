@@ -0,0 +1,149 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use azure_core::auth::TokenCredential;
+use azure_identity::DefaultAzureCredential;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use openssl::{
+    error::ErrorStack,
+    hash::MessageDigest,
+    pkey::{PKey, Private},
+    sign::Signer,
+};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use snafu::ResultExt;
+
+use super::{EventHubsError, SignSnafu, TokenSnafu};
+
+/// How long shared access signatures are valid for.
+const SAS_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// The resource Azure Active Directory tokens are requested for.
+const EVENT_HUBS_RESOURCE: &str = "https://eventhubs.azure.net/";
+
+/// A token authorizing access to an event hub, to be put on the claims-based security node.
+pub(super) struct Token {
+    pub value: String,
+    pub token_type: &'static str,
+    /// The expiry of the token, in seconds since the epoch.
+    pub expires_at: u64,
+}
+
+/// The credentials used to create tokens.
+pub(super) enum Credentials {
+    SharedAccessKey {
+        key_name: String,
+        key: PKey<Private>,
+    },
+    AzureAd(Arc<DefaultAzureCredential>),
+}
+
+impl Credentials {
+    pub(super) fn shared_access_key(key_name: &str, key: &str) -> Result<Self, ErrorStack> {
+        // Shared access keys are used as is, rather than base64 decoded, to sign tokens.
+        Ok(Self::SharedAccessKey {
+            key_name: key_name.to_owned(),
+            key: PKey::hmac(key.as_bytes())?,
+        })
+    }
+
+    pub(super) fn azure_ad() -> Self {
+        Self::AzureAd(Arc::new(DefaultAzureCredential::default()))
+    }
+
+    /// Creates a token for the given audience, the AMQP address of the event hub.
+    pub(super) async fn token(&self, audience: &str) -> Result<Token, EventHubsError> {
+        match self {
+            Self::SharedAccessKey { key_name, key } => {
+                let expires_at = now() + SAS_LIFETIME.as_secs();
+                Ok(Token {
+                    value: shared_access_signature(audience, key_name, key, expires_at)
+                        .context(SignSnafu)?,
+                    token_type: "servicebus.windows.net:sastoken",
+                    expires_at,
+                })
+            }
+            Self::AzureAd(credential) => {
+                let response = credential
+                    .get_token(EVENT_HUBS_RESOURCE)
+                    .await
+                    .context(TokenSnafu)?;
+                Ok(Token {
+                    value: response.token.secret().to_owned(),
+                    token_type: "jwt",
+                    expires_at: response.expires_on.unix_timestamp().max(0) as u64,
+                })
+            }
+        }
+    }
+}
+
+/// The current time, in seconds since the epoch.
+pub(super) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is after the epoch")
+        .as_secs()
+}
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+/// Creates a [shared access signature][sas] for the given resource.
+///
+/// [sas]: https://learn.microsoft.com/en-us/azure/event-hubs/authenticate-shared-access-signature
+fn shared_access_signature(
+    resource: &str,
+    key_name: &str,
+    key: &PKey<Private>,
+    expires_at: u64,
+) -> Result<String, ErrorStack> {
+    let resource = encode(&resource.to_lowercase());
+
+    let mut signer = Signer::new(MessageDigest::sha256(), key)?;
+    signer.update(format!("{}\n{}", resource, expires_at).as_bytes())?;
+    let signature = STANDARD.encode(signer.sign_to_vec()?);
+
+    Ok(format!(
+        "SharedAccessSignature sr={}&sig={}&se={}&skn={}",
+        resource,
+        encode(&signature),
+        expires_at,
+        encode(key_name)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_shared_access_signature() {
+        let key = PKey::hmac(b"secret").unwrap();
+        let token = shared_access_signature(
+            "amqp://Vector.servicebus.windows.net/logs",
+            "send",
+            &key,
+            1_700_000_000,
+        )
+        .unwrap();
+
+        let resource = "amqp%3A%2F%2Fvector%2Eservicebus%2Ewindows%2Enet%2Flogs";
+        let mut signer = Signer::new(MessageDigest::sha256(), &key).unwrap();
+        signer
+            .update(format!("{}\n1700000000", resource).as_bytes())
+            .unwrap();
+        let signature = encode(&STANDARD.encode(signer.sign_to_vec().unwrap()));
+
+        assert_eq!(
+            token,
+            format!(
+                "SharedAccessSignature sr={}&sig={}&se=1700000000&skn=send",
+                resource, signature
+            )
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use fe2o3_amqp::{
+    connection::ConnectionHandle,
+    sasl_profile::SaslProfile,
+    session::SessionHandle,
+    types::{
+        messaging::{Batch, Data, Message, MessageAnnotations, Outcome},
+        primitives::{Binary, Timestamp},
+    },
+    Connection, Sendable, Sender, Session,
+};
+use fe2o3_amqp_cbs::{client::CbsClient, token::CbsToken};
+use snafu::ResultExt;
+use tokio::time::Instant;
+
+use super::{
+    auth::{now, Credentials},
+    message::{split_messages, BATCH_MESSAGE_FORMAT},
+    AttachSnafu, ConnectSnafu, EventHubsError, SendSnafu, SessionSnafu,
+};
+
+/// The message annotation Event Hubs routes messages with.
+const PARTITION_KEY_ANNOTATION: &str = "x-opt-partition-key";
+
+/// Tokens are renewed this long before they expire, so the link is never unauthorized.
+const TOKEN_RENEWAL_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// An open sender to the event hub, along with the connection it was opened on.
+pub(super) struct Link {
+    connection: ConnectionHandle<()>,
+    session: SessionHandle<()>,
+    cbs: CbsClient,
+    sender: Sender,
+    renew_token_at: Instant,
+}
+
+impl Link {
+    pub(super) async fn close(mut self) {
+        // Errors closing the link are of no consequence, as it isn't used anymore.
+        _ = self.sender.close().await;
+        _ = self.cbs.close().await;
+        _ = self.session.end().await;
+        _ = self.connection.close().await;
+    }
+}
+
+/// A client of an event hub.
+pub(super) struct EventHubsClient {
+    namespace: String,
+    event_hub: String,
+    credentials: Credentials,
+}
+
+impl EventHubsClient {
+    pub(super) const fn new(
+        namespace: String,
+        event_hub: String,
+        credentials: Credentials,
+    ) -> Self {
+        Self {
+            namespace,
+            event_hub,
+            credentials,
+        }
+    }
+
+    /// The address of the event hub, which is also the audience of its tokens.
+    fn audience(&self) -> String {
+        format!("amqp://{}/{}", self.namespace, self.event_hub)
+    }
+
+    /// Opens a connection to the namespace, and attaches a sender to the event hub.
+    pub(super) async fn connect(&self) -> Result<Link, EventHubsError> {
+        // Event Hubs expects the TLS handshake to happen before the AMQP protocol header, and
+        // authentication to happen through the claims-based security node, after an anonymous
+        // SASL exchange.
+        let mut connection = Connection::builder()
+            .container_id(format!("vector-{}", uuid::Uuid::new_v4()))
+            .hostname(self.namespace.as_str())
+            .alt_tls_establishment(true)
+            .sasl_profile(SaslProfile::Anonymous)
+            .open(format!("amqps://{}:5671", self.namespace).as_str())
+            .await
+            .context(ConnectSnafu)?;
+        let mut session = Session::begin(&mut connection)
+            .await
+            .context(SessionSnafu)?;
+        let mut cbs =
+            CbsClient::attach(&mut session)
+                .await
+                .map_err(|error| EventHubsError::Authorize {
+                    source: error.into(),
+                })?;
+        let renew_token_at = self.authorize(&mut cbs).await?;
+
+        let sender = Sender::attach(
+            &mut session,
+            format!("vector-sender-{}", uuid::Uuid::new_v4()),
+            self.event_hub.as_str(),
+        )
+        .await
+        .context(AttachSnafu)?;
+
+        Ok(Link {
+            connection,
+            session,
+            cbs,
+            sender,
+            renew_token_at,
+        })
+    }
+
+    /// Puts a new token for the event hub, returning when it should be renewed.
+    async fn authorize(&self, cbs: &mut CbsClient) -> Result<Instant, EventHubsError> {
+        let audience = self.audience();
+        let token = self.credentials.token(&audience).await?;
+
+        let valid_for = Duration::from_secs(token.expires_at.saturating_sub(now()));
+        let cbs_token = CbsToken::new(
+            token.value,
+            token.token_type,
+            Some(Timestamp::from_milliseconds(
+                token.expires_at.saturating_mul(1000) as i64,
+            )),
+        );
+        cbs.put_token(audience, cbs_token)
+            .await
+            .map_err(|error| EventHubsError::Authorize {
+                source: error.into(),
+            })?;
+
+        Ok(Instant::now() + valid_for.saturating_sub(TOKEN_RENEWAL_MARGIN))
+    }
+
+    /// Sends the encoded messages of a request as one batch, renewing the token of the link
+    /// first if it is about to expire.
+    pub(super) async fn send(
+        &self,
+        link: &mut Link,
+        partition_key: Option<&str>,
+        payload: Bytes,
+    ) -> Result<(), EventHubsError> {
+        if link.renew_token_at <= Instant::now() {
+            link.renew_token_at = self.authorize(&mut link.cbs).await?;
+        }
+
+        let sections = split_messages(payload)
+            .ok_or(EventHubsError::InvalidPayload)?
+            .into_iter()
+            .map(|message| Data(Binary::from(message.to_vec())))
+            .collect::<Vec<_>>();
+
+        let mut annotations = MessageAnnotations::builder();
+        if let Some(partition_key) = partition_key {
+            annotations = annotations.insert(PARTITION_KEY_ANNOTATION, partition_key.to_owned());
+        }
+        let message = Message::builder()
+            .message_annotations(annotations.build())
+            .data_batch(Batch::new(sections))
+            .build();
+        let sendable = Sendable::builder()
+            .message(message)
+            .message_format(BATCH_MESSAGE_FORMAT)
+            .build();
+
+        match link.sender.send(sendable).await.context(SendSnafu)? {
+            Outcome::Accepted(_) => Ok(()),
+            Outcome::Rejected(rejected) => Err(EventHubsError::Rejected {
+                description: rejected
+                    .error
+                    .and_then(|error| error.description)
+                    .unwrap_or_else(|| "no description".to_owned()),
+            }),
+            outcome => Err(EventHubsError::NotAccepted {
+                outcome: format!("{:?}", outcome),
+            }),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use futures::FutureExt;
+use tower::ServiceBuilder;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::config::DataType;
+
+use super::{
+    auth::Credentials,
+    client::EventHubsClient,
+    request_builder::{EventHubsEncoder, EventHubsRequestBuilder},
+    service::{EventHubsRetryLogic, EventHubsService},
+    sink::EventHubsSink,
+};
+use crate::{
+    codecs::{Encoder, EncodingConfig},
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    sinks::{
+        util::{BatchConfig, ServiceBuilderExt, SinkBatchSettings, TowerRequestConfig},
+        Healthcheck, VectorSink,
+    },
+    template::Template,
+};
+
+/// Event Hubs accepts batches of up to 1 MB, including the overhead of each event.
+const MAX_BATCH_BYTES: usize = 1_000_000;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EventHubsDefaultBatchSettings;
+
+// Batches are sized below the limit of Event Hubs, to leave room for the overhead of encoding.
+impl SinkBatchSettings for EventHubsDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = None;
+    const MAX_BYTES: Option<usize> = Some(900_000);
+    const TIMEOUT_SECS: f64 = 1.0;
+}
+
+/// Event Hubs authentication strategies.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+#[configurable(metadata(docs::enum_tag_description = "The authentication strategy to use."))]
+pub enum EventHubsAuth {
+    /// Authenticate with a [shared access key][sas] of the namespace or the event hub.
+    ///
+    /// [sas]: https://learn.microsoft.com/en-us/azure/event-hubs/authenticate-shared-access-signature
+    SharedAccessKey {
+        /// The name of the shared access policy.
+        #[configurable(metadata(docs::examples = "RootManageSharedAccessKey"))]
+        key_name: String,
+
+        /// The shared access key of the policy.
+        #[configurable(metadata(docs::examples = "${EVENT_HUBS_SHARED_ACCESS_KEY}"))]
+        key: SensitiveString,
+    },
+
+    /// Authenticate with [Azure Active Directory][aad].
+    ///
+    /// Credentials are looked up in the environment, then from the managed identity of the host,
+    /// then from the Azure CLI. The identity must have the `Azure Event Hubs Data Sender` role.
+    ///
+    /// [aad]: https://learn.microsoft.com/en-us/azure/event-hubs/authenticate-application
+    AzureAd,
+}
+
+/// Configuration for the `azure_event_hubs` sink.
+#[configurable_component(sink("azure_event_hubs"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AzureEventHubsSinkConfig {
+    /// The fully qualified name of the Event Hubs namespace.
+    #[configurable(metadata(docs::examples = "mynamespace.servicebus.windows.net"))]
+    pub namespace: String,
+
+    /// The name of the event hub to publish events to.
+    #[configurable(metadata(docs::examples = "logs"))]
+    pub event_hub: String,
+
+    #[configurable(derived)]
+    pub auth: EventHubsAuth,
+
+    /// The partition key of events.
+    ///
+    /// Events with the same partition key are sent to the same partition, in order. If not set,
+    /// Event Hubs distributes events across partitions.
+    #[configurable(metadata(docs::examples = "{{ host }}"))]
+    #[configurable(metadata(docs::examples = "{{ tenant_id }}"))]
+    pub partition_key: Option<Template>,
+
+    #[configurable(derived)]
+    pub encoding: EncodingConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<EventHubsDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for AzureEventHubsSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"namespace = "mynamespace.servicebus.windows.net"
+            event_hub = "logs"
+            auth.strategy = "azure_ad"
+            encoding.codec = "json""#,
+        )
+        .unwrap()
+    }
+}
+
+impl AzureEventHubsSinkConfig {
+    fn credentials(&self) -> crate::Result<Credentials> {
+        Ok(match &self.auth {
+            EventHubsAuth::SharedAccessKey { key_name, key } => {
+                Credentials::shared_access_key(key_name, key.inner())?
+            }
+            EventHubsAuth::AzureAd => Credentials::azure_ad(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for AzureEventHubsSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let client = Arc::new(EventHubsClient::new(
+            self.namespace.clone(),
+            self.event_hub.clone(),
+            self.credentials()?,
+        ));
+
+        let healthcheck = {
+            let client = Arc::clone(&client);
+            async move {
+                client.connect().await?.close().await;
+                crate::Result::Ok(())
+            }
+            .boxed()
+        };
+
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self
+            .batch
+            .validate()?
+            .limit_max_bytes(MAX_BATCH_BYTES)?
+            .into_batcher_settings()?;
+        let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
+            .settings(request_settings, EventHubsRetryLogic)
+            .service(EventHubsService::new(client));
+
+        let request_builder = EventHubsRequestBuilder {
+            encoder: EventHubsEncoder {
+                transformer: self.encoding.transformer(),
+                encoder: Encoder::<()>::new(self.encoding.build()?),
+            },
+        };
+
+        let sink = EventHubsSink::new(
+            service,
+            batch_settings,
+            request_builder,
+            self.partition_key.clone(),
+        );
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(self.encoding.config().input_type() & (DataType::Log | DataType::Metric))
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
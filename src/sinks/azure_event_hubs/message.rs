@@ -0,0 +1,94 @@
+//! Encoding of events as the messages of Event Hubs batches.
+//!
+//! A batch is a single AMQP message with the [`BATCH_MESSAGE_FORMAT`] format, in which each data
+//! section holds one encoded message, and so one event. Each event is encoded here as a bare
+//! message made of a single data section, and the encoded messages are concatenated into the
+//! payload of the request, to be split apart again when building the batch.
+use std::io;
+
+use bytes::{Buf, Bytes};
+
+/// The message format of batches, as used by the Event Hubs clients.
+pub(super) const BATCH_MESSAGE_FORMAT: u32 = 0x8001_3700;
+
+/// The descriptor of data sections: a described type (`0x00`) with the `smallulong` (`0x53`)
+/// descriptor code of data sections (`0x75`).
+const DATA_SECTION: [u8; 3] = [0x00, 0x53, 0x75];
+
+/// The constructor of binary values with a 32-bit length.
+const VBIN32: u8 = 0xb0;
+
+const HEADER_LEN: usize = DATA_SECTION.len() + 1 + 4;
+
+/// Writes the payload of an event as a message made of a single data section.
+pub(super) fn write_message(payload: &[u8], writer: &mut dyn io::Write) -> io::Result<usize> {
+    let len = u32::try_from(payload.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "event is too large"))?;
+
+    writer.write_all(&DATA_SECTION)?;
+    writer.write_all(&[VBIN32])?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(payload)?;
+
+    Ok(HEADER_LEN + payload.len())
+}
+
+/// Splits a payload written with [`write_message`] back into the encoded messages.
+pub(super) fn split_messages(mut payload: Bytes) -> Option<Vec<Bytes>> {
+    let mut messages = Vec::new();
+    while !payload.is_empty() {
+        if payload.len() < HEADER_LEN
+            || payload[..DATA_SECTION.len()] != DATA_SECTION
+            || payload[DATA_SECTION.len()] != VBIN32
+        {
+            return None;
+        }
+        let len = (&payload[DATA_SECTION.len() + 1..HEADER_LEN]).get_u32() as usize;
+        if payload.len() < HEADER_LEN + len {
+            return None;
+        }
+        messages.push(payload.split_to(HEADER_LEN + len));
+    }
+    Some(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_data_section() {
+        let mut buffer = Vec::new();
+        let written = write_message(b"hi", &mut buffer).unwrap();
+
+        assert_eq!(written, buffer.len());
+        assert_eq!(
+            buffer,
+            [0x00, 0x53, 0x75, 0xb0, 0x00, 0x00, 0x00, 0x02, b'h', b'i']
+        );
+    }
+
+    #[test]
+    fn splits_messages() {
+        let mut buffer = Vec::new();
+        for payload in [&b"first"[..], b"", b"third"] {
+            write_message(payload, &mut buffer).unwrap();
+        }
+
+        let messages = split_messages(Bytes::from(buffer)).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].ends_with(b"first"));
+        assert_eq!(messages[1].len(), HEADER_LEN);
+        assert!(messages[2].ends_with(b"third"));
+    }
+
+    #[test]
+    fn rejects_truncated_payload() {
+        let mut buffer = Vec::new();
+        write_message(b"payload", &mut buffer).unwrap();
+        buffer.pop();
+
+        assert!(split_messages(Bytes::from(buffer)).is_none());
+        assert!(split_messages(Bytes::from_static(b"garbage")).is_none());
+    }
+}
@@ -0,0 +1,67 @@
+//! The Azure Event Hubs [`vector_core::sink::VectorSink`].
+//!
+//! This module contains the [`vector_core::sink::VectorSink`] instance that is responsible for
+//! publishing events to an event hub over [AMQP 1.0][amqp], authenticating with either a shared
+//! access key or Azure Active Directory through [claims-based security][cbs].
+//!
+//! Events are batched by partition key, and each batch is sent as a single batched message, so a
+//! batch is accepted or rejected as a whole.
+//!
+//! [amqp]: https://learn.microsoft.com/en-us/azure/event-hubs/event-hubs-amqp-troubleshoot
+//! [cbs]: https://learn.microsoft.com/en-us/azure/service-bus-messaging/service-bus-amqp-protocol-guide#claims-based-authorization
+use snafu::Snafu;
+
+mod auth;
+mod client;
+mod config;
+mod message;
+mod request_builder;
+mod service;
+mod sink;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::AzureEventHubsSinkConfig;
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(super)))]
+pub enum EventHubsError {
+    #[snafu(display("Failed to open connection: {}", source))]
+    Connect {
+        source: fe2o3_amqp::connection::OpenError,
+    },
+
+    #[snafu(display("Failed to begin session: {}", source))]
+    Session {
+        source: fe2o3_amqp::session::BeginError,
+    },
+
+    #[snafu(display("Failed to attach sender: {}", source))]
+    Attach {
+        source: fe2o3_amqp::link::SenderAttachError,
+    },
+
+    #[snafu(display("Failed to authorize the sender: {}", source))]
+    Authorize { source: BoxedError },
+
+    #[snafu(display("Failed to sign the shared access signature: {}", source))]
+    Sign { source: openssl::error::ErrorStack },
+
+    #[snafu(display("Failed to get an Azure Active Directory token: {}", source))]
+    Token { source: azure_core::Error },
+
+    #[snafu(display("Failed to send batch: {}", source))]
+    Send { source: fe2o3_amqp::link::SendError },
+
+    #[snafu(display("Event Hubs rejected the batch: {}", description))]
+    Rejected { description: String },
+
+    #[snafu(display("Event Hubs did not accept the batch: {}", outcome))]
+    NotAccepted { outcome: String },
+
+    #[snafu(display("Invalid batch payload."))]
+    InvalidPayload,
+}
@@ -0,0 +1,97 @@
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use tokio_util::codec::Encoder as _;
+use vector_common::{
+    finalization::{EventFinalizers, Finalizable},
+    request_metadata::RequestMetadata,
+};
+use vector_core::event::Event;
+
+use super::{message::write_message, service::EventHubsRequest, sink::PartitionKey};
+use crate::{
+    codecs::{Encoder, Transformer},
+    sinks::util::{
+        encoding::{self, write_all},
+        metadata::RequestMetadataBuilder,
+        request_builder::EncodeResult,
+        Compression, RequestBuilder,
+    },
+};
+
+/// Encodes each event of a batch as its own message.
+#[derive(Clone)]
+pub(super) struct EventHubsEncoder {
+    pub(super) transformer: Transformer,
+    pub(super) encoder: Encoder<()>,
+}
+
+impl encoding::Encoder<Vec<Event>> for EventHubsEncoder {
+    fn encode_input(&self, events: Vec<Event>, writer: &mut dyn io::Write) -> io::Result<usize> {
+        let mut encoder = self.encoder.clone();
+        let mut n_events_pending = events.len();
+        let mut bytes_written = 0;
+        let mut message = Vec::new();
+
+        for mut event in events {
+            self.transformer.transform(&mut event);
+            let mut body = BytesMut::new();
+            encoder
+                .encode(event, &mut body)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+            message.clear();
+            bytes_written += write_message(&body, &mut message)?;
+            write_all(writer, n_events_pending, &message)?;
+            n_events_pending -= 1;
+        }
+
+        Ok(bytes_written)
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct EventHubsRequestBuilder {
+    pub(super) encoder: EventHubsEncoder,
+}
+
+impl RequestBuilder<(PartitionKey, Vec<Event>)> for EventHubsRequestBuilder {
+    type Metadata = (PartitionKey, EventFinalizers);
+    type Events = Vec<Event>;
+    type Encoder = EventHubsEncoder;
+    type Payload = Bytes;
+    type Request = EventHubsRequest;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        Compression::None
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        input: (PartitionKey, Vec<Event>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let (partition_key, mut events) = input;
+        let finalizers = events.take_finalizers();
+        let builder = RequestMetadataBuilder::from_events(&events);
+        ((partition_key, finalizers), builder, events)
+    }
+
+    fn build_request(
+        &self,
+        (partition_key, finalizers): Self::Metadata,
+        metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        EventHubsRequest {
+            payload: payload.into_payload(),
+            partition_key: partition_key.0,
+            finalizers,
+            metadata,
+        }
+    }
+}
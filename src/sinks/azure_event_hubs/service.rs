@@ -0,0 +1,138 @@
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use tokio::sync::Mutex;
+use tower::Service;
+use vector_common::{
+    finalization::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::CountByteSize,
+    request_metadata::{MetaDescriptive, RequestMetadata},
+};
+use vector_core::stream::DriverResponse;
+
+use super::{
+    client::{EventHubsClient, Link},
+    EventHubsError,
+};
+use crate::sinks::util::retries::RetryLogic;
+
+#[derive(Clone)]
+pub(super) struct EventHubsRequest {
+    pub payload: Bytes,
+    pub partition_key: Option<String>,
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+}
+
+impl Finalizable for EventHubsRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for EventHubsRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub(super) struct EventHubsResponse {
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for EventHubsResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(
+            self.metadata.event_count(),
+            self.metadata.events_byte_size(),
+        )
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.metadata.request_encoded_size())
+    }
+}
+
+/// Sends batches on a single link to the event hub, one batch at a time.
+///
+/// The link is opened on the first request, and reopened on the request following any error, as
+/// errors may have left the link or its connection unusable.
+#[derive(Clone)]
+pub(super) struct EventHubsService {
+    client: Arc<EventHubsClient>,
+    link: Arc<Mutex<Option<Link>>>,
+}
+
+impl EventHubsService {
+    pub(super) fn new(client: Arc<EventHubsClient>) -> Self {
+        Self {
+            client,
+            link: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl Service<EventHubsRequest> for EventHubsService {
+    type Response = EventHubsResponse;
+    type Error = EventHubsError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: EventHubsRequest) -> Self::Future {
+        let client = Arc::clone(&self.client);
+        let link = Arc::clone(&self.link);
+
+        Box::pin(async move {
+            let mut link = link.lock().await;
+            let mut current = match link.take() {
+                Some(current) => current,
+                None => client.connect().await?,
+            };
+
+            let result = client
+                .send(
+                    &mut current,
+                    request.partition_key.as_deref(),
+                    request.payload,
+                )
+                .await;
+            match result {
+                // A rejected batch says nothing about the state of the link.
+                Ok(()) | Err(EventHubsError::Rejected { .. }) => *link = Some(current),
+                Err(_) => current.close().await,
+            }
+
+            result.map(|()| EventHubsResponse {
+                metadata: request.metadata,
+            })
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub(super) struct EventHubsRetryLogic;
+
+impl RetryLogic for EventHubsRetryLogic {
+    type Error = EventHubsError;
+    type Response = EventHubsResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        // Batches are rejected when they are invalid, such as when they are too large, and would
+        // be rejected again.
+        !matches!(
+            error,
+            EventHubsError::Rejected { .. } | EventHubsError::InvalidPayload
+        )
+    }
+}
@@ -0,0 +1,118 @@
+use std::{fmt, num::NonZeroUsize};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tower::Service;
+use vector_core::{
+    event::Event,
+    partition::Partitioner,
+    sink::StreamSink,
+    stream::{BatcherSettings, DriverResponse},
+};
+
+use super::{request_builder::EventHubsRequestBuilder, service::EventHubsRequest};
+use crate::{
+    internal_events::{SinkRequestBuildError, TemplateRenderingError},
+    sinks::util::SinkBuilderExt,
+    template::Template,
+};
+
+/// The rendered partition key of a batch, if the sink has one.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(super) struct PartitionKey(pub Option<String>);
+
+/// Batches events by partition key, as all the events of a batch are sent to the same partition.
+///
+/// Events whose partition key can't be rendered have no key, and are dropped.
+pub(super) struct PartitionKeyPartitioner(pub Option<Template>);
+
+impl Partitioner for PartitionKeyPartitioner {
+    type Item = Event;
+    type Key = Option<PartitionKey>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        let Some(template) = &self.0 else {
+            return Some(PartitionKey(None));
+        };
+        template
+            .render_string(item)
+            .map_err(|error| {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("partition_key"),
+                    drop_event: true,
+                });
+            })
+            .ok()
+            .map(|key| PartitionKey(Some(key)))
+    }
+}
+
+pub(super) struct EventHubsSink<S> {
+    service: S,
+    batch_settings: BatcherSettings,
+    request_builder: EventHubsRequestBuilder,
+    partition_key: Option<Template>,
+}
+
+impl<S> EventHubsSink<S>
+where
+    S: Service<EventHubsRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    pub(super) const fn new(
+        service: S,
+        batch_settings: BatcherSettings,
+        request_builder: EventHubsRequestBuilder,
+        partition_key: Option<Template>,
+    ) -> Self {
+        Self {
+            service,
+            batch_settings,
+            request_builder,
+            partition_key,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let builder_limit = NonZeroUsize::new(64);
+        input
+            .batched_partitioned(
+                PartitionKeyPartitioner(self.partition_key),
+                self.batch_settings,
+            )
+            .filter_map(|(key, batch)| async move {
+                // A `TemplateRenderingError` was already emitted for the dropped events.
+                key.map(move |key| (key, batch))
+            })
+            .request_builder(builder_limit, self.request_builder)
+            .filter_map(|request| async move {
+                match request {
+                    Err(error) => {
+                        emit!(SinkRequestBuildError { error });
+                        None
+                    }
+                    Ok(request) => Some(request),
+                }
+            })
+            .into_driver(self.service)
+            .protocol("amqps")
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for EventHubsSink<S>
+where
+    S: Service<EventHubsRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
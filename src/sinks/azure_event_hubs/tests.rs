@@ -0,0 +1,91 @@
+use vector_core::{event::LogEvent, partition::Partitioner};
+
+use super::{
+    config::{AzureEventHubsSinkConfig, EventHubsAuth},
+    service::EventHubsRetryLogic,
+    sink::{PartitionKey, PartitionKeyPartitioner},
+    EventHubsError,
+};
+use crate::{sinks::util::retries::RetryLogic, template::Template};
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<AzureEventHubsSinkConfig>();
+}
+
+#[test]
+fn parses_shared_access_key_auth() {
+    let config: AzureEventHubsSinkConfig = toml::from_str(
+        r#"
+            namespace = "vector.servicebus.windows.net"
+            event_hub = "logs"
+            partition_key = "{{ host }}"
+            auth.strategy = "shared_access_key"
+            auth.key_name = "send"
+            auth.key = "secret"
+            encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    match config.auth {
+        EventHubsAuth::SharedAccessKey { key_name, key } => {
+            assert_eq!(key_name, "send");
+            assert_eq!(key.inner(), "secret");
+        }
+        EventHubsAuth::AzureAd => panic!("unexpected auth strategy"),
+    }
+}
+
+#[test]
+fn rejects_batch_size_over_limit() {
+    let config: AzureEventHubsSinkConfig = toml::from_str(
+        r#"
+            namespace = "vector.servicebus.windows.net"
+            event_hub = "logs"
+            auth.strategy = "azure_ad"
+            encoding.codec = "json"
+            batch.max_bytes = 2000000
+        "#,
+    )
+    .unwrap();
+
+    assert!(config
+        .batch
+        .validate()
+        .unwrap()
+        .limit_max_bytes(1_000_000)
+        .is_err());
+}
+
+#[test]
+fn partitions_by_rendered_key() {
+    let partitioner = PartitionKeyPartitioner(Some(Template::try_from("{{ host }}").unwrap()));
+
+    let mut log = LogEvent::from("message");
+    log.insert("host", "a");
+    let event = log.into();
+    assert_eq!(
+        partitioner.partition(&event),
+        Some(PartitionKey(Some("a".to_owned())))
+    );
+
+    // Events whose key can't be rendered are dropped.
+    let event = LogEvent::from("no host").into();
+    assert_eq!(partitioner.partition(&event), None);
+
+    let partitioner = PartitionKeyPartitioner(None);
+    assert_eq!(partitioner.partition(&event), Some(PartitionKey(None)));
+}
+
+#[test]
+fn does_not_retry_rejected_batches() {
+    let logic = EventHubsRetryLogic;
+
+    assert!(!logic.is_retriable_error(&EventHubsError::Rejected {
+        description: "message too large".to_owned()
+    }));
+    assert!(logic.is_retriable_error(&EventHubsError::NotAccepted {
+        outcome: "Released".to_owned()
+    }));
+}
@@ -188,7 +188,8 @@ impl SinkConfig for AzureMonitorLogsConfig {
         let time_generated_key = self.time_generated_key.clone().and_then(|k| k.path);
 
         let tls_settings = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(Some(tls_settings), &cx.proxy)?;
+        let client =
+            HttpClient::new_with_options(Some(tls_settings), cx.proxy(), cx.http_client())?;
 
         let sink = AzureMonitorLogsSink::new(self, time_generated_key)?;
         let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
@@ -28,7 +28,7 @@ pub(crate) async fn build_http_sink(
     let batch = cfg.batch.into_batch_settings()?;
     let request = cfg.request.unwrap_with(&TowerRequestConfig::default());
     let tls_settings = TlsSettings::from_options(&cfg.tls)?;
-    let client = HttpClient::new(tls_settings, &cx.proxy)?;
+    let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
 
     let config = ClickhouseConfig {
         auth: cfg.auth.choose_one(&cfg.endpoint.auth)?,
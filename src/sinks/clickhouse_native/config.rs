@@ -0,0 +1,346 @@
+use std::str::FromStr;
+
+use clickhouse_rs::{CompressionMethod, Options, Pool};
+use futures::FutureExt;
+use tower::ServiceBuilder;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+
+use super::{
+    schema::{quote_identifier, Schema},
+    service::{ClickhouseNativeRetryLogic, ClickhouseNativeService},
+    sink::ClickhouseNativeSink,
+};
+use crate::{
+    codecs::Transformer,
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    sinks::{
+        util::{
+            BatchConfig, RealtimeSizeBasedDefaultBatchSettings, ServiceBuilderExt,
+            TowerRequestConfig,
+        },
+        Healthcheck, VectorSink,
+    },
+};
+
+/// Configuration for the `clickhouse_native` sink.
+#[configurable_component(sink("clickhouse_native"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClickhouseNativeConfig {
+    /// The endpoint of the ClickHouse server's native protocol interface.
+    ///
+    /// Additional connection options, such as `connection_timeout` or `pool_max`, can be set as
+    /// query parameters.
+    #[configurable(metadata(docs::examples = "tcp://localhost:9000"))]
+    #[configurable(metadata(docs::examples = "tcp://localhost:9000?connection_timeout=5s"))]
+    pub endpoint: String,
+
+    /// The table that data is inserted into.
+    ///
+    /// Events are inserted into the columns of the table that match their top-level fields by name.
+    #[configurable(metadata(docs::examples = "mytable"))]
+    pub table: String,
+
+    /// The database that contains the table that data is inserted into.
+    ///
+    /// If not set, the default database of the user is used.
+    #[configurable(metadata(docs::examples = "mydatabase"))]
+    pub database: Option<String>,
+
+    #[configurable(derived)]
+    pub auth: Option<ClickhouseNativeAuth>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: ClickhouseNativeCompression,
+
+    #[configurable(derived)]
+    pub tls: Option<ClickhouseNativeTlsConfig>,
+
+    /// Sets `async_insert`, so that ClickHouse buffers inserted data and writes it to the table in
+    /// larger batches.
+    ///
+    /// This reduces the number of parts created when many Vector instances insert small batches
+    /// into the same table.
+    #[serde(default)]
+    pub async_insert: bool,
+
+    /// Sets `wait_for_async_insert`, so that inserts are only acknowledged once the buffered data
+    /// has been written to the table.
+    ///
+    /// Disabling this acknowledges events as soon as they are buffered by ClickHouse, so they can
+    /// be lost if the server fails before the buffer is flushed. Only applies when `async_insert`
+    /// is enabled.
+    #[serde(default = "crate::serde::default_true")]
+    pub wait_for_async_insert: bool,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub encoding: Transformer,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeSizeBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+/// Credentials for authenticating with ClickHouse.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClickhouseNativeAuth {
+    /// The username.
+    #[configurable(metadata(docs::examples = "vector"))]
+    pub user: String,
+
+    /// The password.
+    #[configurable(metadata(docs::examples = "${CLICKHOUSE_PASSWORD}"))]
+    pub password: SensitiveString,
+}
+
+/// Configuration of TLS when connecting to ClickHouse.
+///
+/// When set, connections are secured with TLS, which the server must accept on the port of
+/// `endpoint`, usually `9440`. The certificate of the server is verified against the certificate
+/// authorities trusted by the system, as the native protocol client doesn't support additional
+/// ones.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClickhouseNativeTlsConfig {
+    /// Enables certificate verification.
+    ///
+    /// Do NOT set this to `false` unless you understand the risks of not verifying the validity of
+    /// certificates.
+    #[serde(default = "crate::serde::default_true")]
+    pub verify_certificate: bool,
+}
+
+/// Compression of the data blocks sent to ClickHouse.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClickhouseNativeCompression {
+    /// No compression.
+    None,
+
+    /// [LZ4][lz4] compression.
+    ///
+    /// [lz4]: https://lz4.org/
+    #[default]
+    Lz4,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// This compresses better than LZ4, at the cost of more CPU time.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+}
+
+impl GenerateConfig for ClickhouseNativeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"endpoint = "tcp://localhost:9000"
+            table = "default"
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+impl ClickhouseNativeConfig {
+    fn build_options(&self) -> crate::Result<Options> {
+        let mut options = Options::from_str(&self.endpoint)?;
+
+        if let Some(auth) = &self.auth {
+            options = options.username(&auth.user).password(auth.password.inner());
+        }
+        if let Some(tls) = &self.tls {
+            options = options.secure(true).skip_verify(!tls.verify_certificate);
+        }
+        match self.compression {
+            ClickhouseNativeCompression::None => {}
+            ClickhouseNativeCompression::Lz4 => {
+                options = options.with_compression_method(CompressionMethod::Lz4)
+            }
+            ClickhouseNativeCompression::Zstd => {
+                options = options.with_compression_method(CompressionMethod::Zstd)
+            }
+        }
+        if self.async_insert {
+            options = options
+                .with_setting("async_insert", true, true)
+                .with_setting("wait_for_async_insert", self.wait_for_async_insert, true);
+        }
+
+        Ok(options)
+    }
+
+    /// The table name, qualified by the database if one is configured.
+    fn qualified_table(&self) -> String {
+        match &self.database {
+            Some(database) => format!(
+                "{}.{}",
+                quote_identifier(database),
+                quote_identifier(&self.table)
+            ),
+            None => quote_identifier(&self.table),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for ClickhouseNativeConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let pool = Pool::new(self.build_options()?);
+        let table = self.qualified_table();
+
+        let healthcheck = healthcheck(pool.clone(), table.clone()).boxed();
+
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
+            .settings(request_settings, ClickhouseNativeRetryLogic)
+            .service(ClickhouseNativeService::new(pool, table));
+        let sink = ClickhouseNativeSink::new(batch_settings, self.encoding.clone(), service);
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+/// Checks that the server is reachable and that events can be inserted into the table.
+async fn healthcheck(pool: Pool, table: String) -> crate::Result<()> {
+    let mut handle = pool.get_handle().await?;
+    handle.ping().await?;
+    Schema::load(&mut handle, &table).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ClickhouseNativeConfig>();
+    }
+
+    #[test]
+    fn parse_config() {
+        let config = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "tcp://localhost:9000"
+            table = "logs"
+            database = "vector"
+            compression = "none"
+            async_insert = true
+            auth.user = "vector"
+            auth.password = "secret"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.compression, ClickhouseNativeCompression::None);
+        assert!(config.async_insert);
+        assert!(config.wait_for_async_insert);
+        assert_eq!(config.qualified_table(), "`vector`.`logs`");
+        assert!(config.build_options().is_ok());
+    }
+
+    #[test]
+    fn defaults_to_lz4_compression() {
+        let config = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "tcp://localhost:9000"
+            table = "logs"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.compression, ClickhouseNativeCompression::Lz4);
+        assert_eq!(config.qualified_table(), "`logs`");
+    }
+
+    #[test]
+    fn parse_zstd_compression() {
+        let config = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "tcp://localhost:9000"
+            table = "logs"
+            compression = "zstd"
+        "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.compression, ClickhouseNativeCompression::Zstd);
+        assert_eq!(
+            config.build_options().unwrap(),
+            Options::from_str("tcp://localhost:9000?compression=zstd").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_tls_config() {
+        let config = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "tcp://localhost:9440"
+            table = "logs"
+            tls.verify_certificate = false
+        "#,
+        )
+        .unwrap();
+
+        let tls = config.tls.as_ref().unwrap();
+        assert!(!tls.verify_certificate);
+        assert!(config.build_options().is_ok());
+
+        let error = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "tcp://localhost:9440"
+            table = "logs"
+            tls.ca_file = "ca.crt"
+        "#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("ca_file"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_http_endpoint() {
+        let config = toml::from_str::<ClickhouseNativeConfig>(
+            r#"
+            endpoint = "http://localhost:8123"
+            table = "logs"
+        "#,
+        )
+        .unwrap();
+
+        assert!(config.build_options().is_err());
+    }
+}
@@ -0,0 +1,466 @@
+use std::{mem, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
+use clickhouse_rs::{
+    types::{column::ColumnFrom, Decimal},
+    Block,
+};
+use uuid::Uuid;
+use vector_core::event::{Event, Value};
+
+use super::{
+    error::ClickhouseNativeError,
+    schema::{Column, ColumnType, ScalarType, Schema},
+};
+
+/// A batch of events converted into a block of columns.
+pub(super) struct EncodedBlock {
+    pub(super) block: Block,
+    /// The approximate size of the block when sent uncompressed, in bytes.
+    pub(super) byte_size: usize,
+}
+
+/// Converts `events` into a block with a column for each column of `schema`.
+///
+/// Each column is populated from the top-level field of the same name. Missing fields are inserted
+/// as `NULL` for nullable columns, and otherwise as the default value of the column type. Columns
+/// that none of the events have a value for are left out entirely, so that ClickHouse fills them
+/// using the column's default expression.
+pub(super) fn encode_block(
+    schema: &Schema,
+    events: &[Event],
+) -> Result<EncodedBlock, ClickhouseNativeError> {
+    let rows = events
+        .iter()
+        .filter_map(|event| event.maybe_as_log())
+        .map(|log| log.as_map())
+        .collect::<Vec<_>>();
+
+    let values = |column: &Column| {
+        rows.iter()
+            .map(|row| {
+                row.and_then(|fields| fields.get(&column.name))
+                    .filter(|value| !matches!(value, Value::Null))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let present = schema
+        .columns
+        .iter()
+        .filter(|column| values(column).iter().any(Option::is_some))
+        .collect::<Vec<_>>();
+    // If none of the columns have a value, every row is made up of default values.
+    let columns = if present.is_empty() {
+        schema.columns.iter().collect()
+    } else {
+        present
+    };
+
+    let mut encoded = EncodedBlock {
+        block: Block::with_capacity(rows.len()),
+        byte_size: 0,
+    };
+    for column in columns {
+        encoded = encode_column(encoded, column, &values(column))?;
+    }
+
+    Ok(encoded)
+}
+
+fn encode_column(
+    encoded: EncodedBlock,
+    column: &Column,
+    values: &[Option<&Value>],
+) -> Result<EncodedBlock, ClickhouseNativeError> {
+    use ScalarType as S;
+
+    let (scalar, nullable) = match column.column_type {
+        ColumnType::Scalar(scalar) => (scalar, false),
+        ColumnType::Nullable(scalar) => (scalar, true),
+        ColumnType::Array(scalar) => {
+            return match scalar {
+                S::UInt8 => push_array(encoded, column, values, to_integer::<u8>),
+                S::UInt16 => push_array(encoded, column, values, to_integer::<u16>),
+                S::UInt32 => push_array(encoded, column, values, to_integer::<u32>),
+                S::UInt64 => push_array(encoded, column, values, to_integer::<u64>),
+                S::Int8 => push_array(encoded, column, values, to_integer::<i8>),
+                S::Int16 => push_array(encoded, column, values, to_integer::<i16>),
+                S::Int32 => push_array(encoded, column, values, to_integer::<i32>),
+                S::Int64 => push_array(encoded, column, values, to_integer::<i64>),
+                S::Float32 => push_array(encoded, column, values, to_f32),
+                S::Float64 => push_array(encoded, column, values, to_f64),
+                S::String => push_array(encoded, column, values, to_string),
+                S::Bool | S::Date | S::DateTime | S::Uuid | S::Decimal { .. } => {
+                    unreachable!("unsupported array element type")
+                }
+            }
+        }
+    };
+
+    match scalar {
+        S::Bool => push(encoded, column, values, nullable, false, to_bool),
+        S::UInt8 => push(encoded, column, values, nullable, 0, to_integer::<u8>),
+        S::UInt16 => push(encoded, column, values, nullable, 0, to_integer::<u16>),
+        S::UInt32 => push(encoded, column, values, nullable, 0, to_integer::<u32>),
+        S::UInt64 => push(encoded, column, values, nullable, 0, to_integer::<u64>),
+        S::Int8 => push(encoded, column, values, nullable, 0, to_integer::<i8>),
+        S::Int16 => push(encoded, column, values, nullable, 0, to_integer::<i16>),
+        S::Int32 => push(encoded, column, values, nullable, 0, to_integer::<i32>),
+        S::Int64 => push(encoded, column, values, nullable, 0, to_integer::<i64>),
+        S::Float32 => push(encoded, column, values, nullable, 0.0, to_f32),
+        S::Float64 => push(encoded, column, values, nullable, 0.0, to_f64),
+        S::String => push(encoded, column, values, nullable, "".to_owned(), to_string),
+        S::Date => push(
+            encoded,
+            column,
+            values,
+            nullable,
+            epoch().date_naive(),
+            to_date,
+        ),
+        S::DateTime => push(encoded, column, values, nullable, epoch(), to_datetime),
+        S::Uuid => push(encoded, column, values, nullable, Uuid::nil(), to_uuid),
+        S::Decimal { precision, scale } => push(
+            encoded,
+            column,
+            values,
+            nullable,
+            Decimal::new(0, scale),
+            |value| to_decimal(value, precision, scale),
+        ),
+    }
+}
+
+/// Appends a column of scalar values to the block.
+fn push<T>(
+    mut encoded: EncodedBlock,
+    column: &Column,
+    values: &[Option<&Value>],
+    nullable: bool,
+    default: T,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Result<EncodedBlock, ClickhouseNativeError>
+where
+    T: Clone + NativeSize,
+    Vec<T>: ColumnFrom,
+    Vec<Option<T>>: ColumnFrom,
+{
+    let values = values
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| convert_value(column, value, &convert))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    encoded.byte_size += values
+        .iter()
+        .map(|value| value.as_ref().map_or(default.native_size(), T::native_size))
+        .sum::<usize>();
+
+    encoded.block = if nullable {
+        // Nullable columns are sent with an additional byte per row marking null values.
+        encoded.byte_size += values.len();
+        encoded.block.column(&column.name, values)
+    } else {
+        let values = values
+            .into_iter()
+            .map(|value| value.unwrap_or_else(|| default.clone()))
+            .collect::<Vec<_>>();
+        encoded.block.column(&column.name, values)
+    };
+
+    Ok(encoded)
+}
+
+/// Appends a column of arrays to the block.
+fn push_array<T>(
+    mut encoded: EncodedBlock,
+    column: &Column,
+    values: &[Option<&Value>],
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Result<EncodedBlock, ClickhouseNativeError>
+where
+    T: NativeSize,
+    Vec<Vec<T>>: ColumnFrom,
+{
+    let values = values
+        .iter()
+        .map(|value| match value {
+            None => Ok(Vec::new()),
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|item| convert_value(column, item, &convert))
+                .collect(),
+            Some(value) => Err(invalid_value(column, value)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Each array is sent with its offset in the flattened column.
+    encoded.byte_size += values.iter().flatten().map(T::native_size).sum::<usize>()
+        + values.len() * mem::size_of::<u64>();
+    encoded.block = encoded.block.column(&column.name, values);
+
+    Ok(encoded)
+}
+
+fn convert_value<T>(
+    column: &Column,
+    value: &Value,
+    convert: impl Fn(&Value) -> Option<T>,
+) -> Result<T, ClickhouseNativeError> {
+    convert(value).ok_or_else(|| invalid_value(column, value))
+}
+
+fn invalid_value(column: &Column, value: &Value) -> ClickhouseNativeError {
+    ClickhouseNativeError::InvalidValue {
+        column: column.name.clone(),
+        column_type: column.type_name.clone(),
+        value: value.to_string_lossy().into_owned(),
+    }
+}
+
+fn epoch() -> DateTime<Tz> {
+    Tz::UTC.timestamp_opt(0, 0).unwrap()
+}
+
+fn to_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Boolean(value) => Some(*value),
+        Value::Integer(0) => Some(false),
+        Value::Integer(1) => Some(true),
+        Value::Bytes(bytes) => match bytes.as_ref() {
+            b"true" => Some(true),
+            b"false" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn to_integer<T>(value: &Value) -> Option<T>
+where
+    T: TryFrom<i64> + FromStr,
+{
+    match value {
+        Value::Integer(value) => T::try_from(*value).ok(),
+        Value::Float(value) if value.fract() == 0.0 => T::try_from(value.into_inner() as i64).ok(),
+        Value::Boolean(value) => T::try_from(i64::from(*value)).ok(),
+        Value::Bytes(bytes) => std::str::from_utf8(bytes).ok()?.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn to_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(value) => Some(value.into_inner()),
+        Value::Integer(value) => Some(*value as f64),
+        Value::Bytes(bytes) => std::str::from_utf8(bytes).ok()?.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn to_f32(value: &Value) -> Option<f32> {
+    to_f64(value).map(|value| value as f32)
+}
+
+/// Converts `value` to a decimal of the given scale, if it fits within `precision` digits.
+fn to_decimal(value: &Value, precision: u8, scale: u8) -> Option<Decimal> {
+    let factor = 10_i128.pow(u32::from(scale));
+    let underlying = match value {
+        Value::Integer(value) => i128::from(*value).checked_mul(factor)?,
+        value => {
+            let scaled = (to_f64(value)? * factor as f64).round();
+            if !scaled.is_finite() || scaled.abs() >= i64::MAX as f64 {
+                return None;
+            }
+            scaled as i128
+        }
+    };
+
+    (underlying.unsigned_abs() < 10_u128.pow(u32::from(precision)))
+        .then(|| Decimal::new(underlying as i64, scale))
+}
+
+fn to_string(value: &Value) -> Option<String> {
+    Some(value.to_string_lossy().into_owned())
+}
+
+fn to_datetime(value: &Value) -> Option<DateTime<Tz>> {
+    let timestamp = match value {
+        Value::Timestamp(timestamp) => *timestamp,
+        Value::Bytes(bytes) => DateTime::parse_from_rfc3339(std::str::from_utf8(bytes).ok()?)
+            .ok()?
+            .with_timezone(&Utc),
+        Value::Integer(seconds) => Utc.timestamp_opt(*seconds, 0).single()?,
+        _ => return None,
+    };
+    Some(timestamp.with_timezone(&Tz::UTC))
+}
+
+fn to_date(value: &Value) -> Option<NaiveDate> {
+    match value {
+        Value::Bytes(bytes) => {
+            let value = std::str::from_utf8(bytes).ok()?;
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .or_else(|| to_datetime(&Value::Bytes(bytes.clone())).map(|ts| ts.date_naive()))
+        }
+        value => to_datetime(value).map(|timestamp| timestamp.date_naive()),
+    }
+}
+
+fn to_uuid(value: &Value) -> Option<Uuid> {
+    match value {
+        Value::Bytes(bytes) => Uuid::parse_str(std::str::from_utf8(bytes).ok()?).ok(),
+        _ => None,
+    }
+}
+
+/// The size of a value when encoded in a native protocol block.
+trait NativeSize {
+    fn native_size(&self) -> usize;
+}
+
+macro_rules! fixed_native_size {
+    ($($ty:ty => $size:expr),* $(,)?) => {
+        $(
+            impl NativeSize for $ty {
+                fn native_size(&self) -> usize {
+                    $size
+                }
+            }
+        )*
+    };
+}
+
+fixed_native_size! {
+    bool => 1,
+    u8 => 1,
+    u16 => 2,
+    u32 => 4,
+    u64 => 8,
+    i8 => 1,
+    i16 => 2,
+    i32 => 4,
+    i64 => 8,
+    f32 => 4,
+    f64 => 8,
+    NaiveDate => 2,
+    DateTime<Tz> => 4,
+    Uuid => 16,
+    // Decimals are sent as 64-bit integers, whatever the precision of the column.
+    Decimal => 8,
+}
+
+impl NativeSize for String {
+    fn native_size(&self) -> usize {
+        // Strings are prefixed by their length, which is usually a single byte varint.
+        self.len() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column_names(block: &Block) -> Vec<&str> {
+        block.columns().iter().map(|column| column.name()).collect()
+    }
+
+    #[test]
+    fn encodes_columns_from_fields() {
+        let schema = Schema::from_description([
+            ("message", "String", ""),
+            ("status", "UInt16", ""),
+            ("duration", "Nullable(Float64)", ""),
+            ("tags", "Array(String)", ""),
+            ("timestamp", "DateTime64(3)", ""),
+        ])
+        .unwrap();
+        let events = [
+            Event::try_from(serde_json::json!({
+                "message": "hello",
+                "status": 200,
+                "duration": 1.5,
+                "tags": ["a", "b"],
+                "timestamp": "2023-05-01T12:00:00Z",
+                "ignored": true,
+            }))
+            .unwrap(),
+            Event::try_from(serde_json::json!({ "message": "world", "status": "404" })).unwrap(),
+        ];
+
+        let encoded = encode_block(&schema, &events).unwrap();
+
+        assert_eq!(encoded.block.row_count(), 2);
+        assert_eq!(
+            column_names(&encoded.block),
+            ["message", "status", "duration", "tags", "timestamp"]
+        );
+        assert_eq!(
+            encoded.block.get::<u16, _>(1, "status").unwrap(),
+            404,
+            "numbers are parsed from strings"
+        );
+        assert_eq!(
+            encoded.block.get::<Option<f64>, _>(1, "duration").unwrap(),
+            None
+        );
+        assert_eq!(
+            encoded.block.get::<Vec<String>, _>(0, "tags").unwrap(),
+            ["a", "b"]
+        );
+        assert!(encoded.byte_size > 0);
+    }
+
+    #[test]
+    fn leaves_out_columns_without_values() {
+        let schema =
+            Schema::from_description([("message", "String", ""), ("host", "String", "")]).unwrap();
+        let events =
+            [Event::try_from(serde_json::json!({ "message": "hello", "host": null })).unwrap()];
+
+        let encoded = encode_block(&schema, &events).unwrap();
+
+        assert_eq!(column_names(&encoded.block), ["message"]);
+    }
+
+    #[test]
+    fn encodes_decimals_with_the_column_scale() {
+        let schema = Schema::from_description([("price", "Decimal(9, 2)", "")]).unwrap();
+        let events = [
+            Event::try_from(serde_json::json!({ "price": 12.345 })).unwrap(),
+            Event::try_from(serde_json::json!({ "price": "7" })).unwrap(),
+        ];
+
+        let encoded = encode_block(&schema, &events).unwrap();
+
+        let price = |row| encoded.block.get::<Decimal, _>(row, "price").unwrap();
+        assert_eq!(price(0), Decimal::new(1235, 2));
+        assert_eq!(price(1), Decimal::new(700, 2));
+
+        let events = [Event::try_from(serde_json::json!({ "price": 10_000_000 })).unwrap()];
+        assert!(matches!(
+            encode_block(&schema, &events).err().unwrap(),
+            ClickhouseNativeError::InvalidValue { column, .. } if column == "price"
+        ));
+    }
+
+    #[test]
+    fn rejects_values_of_the_wrong_type() {
+        let schema = Schema::from_description([("status", "UInt8", "")]).unwrap();
+        let events = [Event::try_from(serde_json::json!({ "status": 1000 })).unwrap()];
+
+        let error = encode_block(&schema, &events).err().unwrap();
+
+        assert!(matches!(
+            error,
+            ClickhouseNativeError::InvalidValue { column, value, .. }
+                if column == "status" && value == "1000"
+        ));
+    }
+}
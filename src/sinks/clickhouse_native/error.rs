@@ -0,0 +1,85 @@
+use snafu::Snafu;
+
+#[derive(Debug, Snafu)]
+pub enum ClickhouseNativeError {
+    #[snafu(display("ClickHouse request failed: {}", source))]
+    Client {
+        source: clickhouse_rs::errors::Error,
+    },
+
+    #[snafu(display("Table {} does not exist or has no columns.", table))]
+    MissingTable { table: String },
+
+    #[snafu(display(
+        "Column `{}` has type `{}`, which the native protocol client can't insert into. Give the \
+         column a DEFAULT expression, or change its type.",
+        column,
+        column_type
+    ))]
+    UnsupportedColumnType { column: String, column_type: String },
+
+    #[snafu(display(
+        "Value {} can not be inserted into column `{}` of type `{}`.",
+        value,
+        column,
+        column_type
+    ))]
+    InvalidValue {
+        column: String,
+        column_type: String,
+        value: String,
+    },
+}
+
+/// ClickHouse error codes for failures that may succeed if the insert is retried.
+///
+/// See <https://github.com/ClickHouse/ClickHouse/blob/master/src/Common/ErrorCodes.cpp>.
+const RETRIABLE_SERVER_ERRORS: &[u32] = &[
+    159, // TIMEOUT_EXCEEDED
+    164, // READONLY
+    202, // TOO_MANY_SIMULTANEOUS_QUERIES
+    203, // NO_FREE_CONNECTION
+    209, // SOCKET_TIMEOUT
+    210, // NETWORK_ERROR
+    241, // MEMORY_LIMIT_EXCEEDED
+    242, // TABLE_IS_READ_ONLY
+    252, // TOO_MANY_PARTS
+    285, // TOO_FEW_LIVE_REPLICAS
+    319, // UNKNOWN_STATUS_OF_INSERT
+    425, // SYSTEM_ERROR
+    999, // KEEPER_EXCEPTION
+];
+
+impl ClickhouseNativeError {
+    /// Whether the failed request may succeed if retried.
+    pub(super) fn is_retriable(&self) -> bool {
+        use clickhouse_rs::errors::Error;
+
+        match self {
+            Self::Client { source } => match source {
+                Error::Server(error) => RETRIABLE_SERVER_ERRORS.contains(&error.code),
+                Error::Io(_) | Error::Connection(_) | Error::Driver(_) => true,
+                Error::Other(_) | Error::Url(_) | Error::FromSql(_) => false,
+            },
+            Self::MissingTable { .. }
+            | Self::UnsupportedColumnType { .. }
+            | Self::InvalidValue { .. } => false,
+        }
+    }
+
+    /// Whether the error may have been caused by the cached table schema being out of date.
+    pub(super) fn invalidates_schema(&self) -> bool {
+        matches!(
+            self,
+            Self::Client {
+                source: clickhouse_rs::errors::Error::Server(_)
+            }
+        )
+    }
+}
+
+impl From<clickhouse_rs::errors::Error> for ClickhouseNativeError {
+    fn from(source: clickhouse_rs::errors::Error) -> Self {
+        Self::Client { source }
+    }
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use clickhouse_rs::{types::Complex, Block, Options, Pool};
+use futures::{future::ready, stream};
+use vector_core::event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event, LogEvent};
+
+use super::*;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    test_util::{
+        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        random_string, trace_init,
+    },
+};
+
+fn clickhouse_address() -> String {
+    std::env::var("CLICKHOUSE_NATIVE_ADDRESS").unwrap_or_else(|_| "tcp://localhost:9000".into())
+}
+
+#[tokio::test]
+async fn insert_events() {
+    trace_init();
+
+    let table = gen_table();
+    let config: ClickhouseNativeConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        batch.max_events = 1
+        request.retry_attempts = 1
+        "#,
+        clickhouse_address(),
+        table
+    ))
+    .unwrap();
+
+    let client = ClickhouseClient::new();
+    client
+        .create_table(
+            &table,
+            "host String, timestamp DateTime64(3), message String, count Nullable(Int64), items Array(String)",
+        )
+        .await;
+
+    let (sink, _hc) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (mut input_event, mut receiver) = make_event();
+    input_event.as_mut_log().insert("count", 42);
+    input_event
+        .as_mut_log()
+        .insert("items", vec!["item1", "item2"]);
+
+    run_and_assert_sink_compliance(sink, stream::once(ready(input_event)), &SINK_TAGS).await;
+
+    let rows = client
+        .select_all::<(String, String, Option<i64>, Vec<String>)>(
+            &table,
+            "host, message, count, items",
+        )
+        .await;
+    assert_eq!(
+        rows,
+        vec![(
+            "example.com".to_string(),
+            "raw log line".to_string(),
+            Some(42),
+            vec!["item1".to_string(), "item2".to_string()],
+        )]
+    );
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+}
+
+#[tokio::test]
+async fn insert_events_with_async_insert() {
+    trace_init();
+
+    let table = gen_table();
+    let config: ClickhouseNativeConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        batch.max_events = 1
+        request.retry_attempts = 1
+        compression = "none"
+        async_insert = true
+        "#,
+        clickhouse_address(),
+        table
+    ))
+    .unwrap();
+
+    let client = ClickhouseClient::new();
+    client
+        .create_table(&table, "host String, message String")
+        .await;
+
+    let (sink, _hc) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (input_event, mut receiver) = make_event();
+
+    run_and_assert_sink_compliance(sink, stream::once(ready(input_event)), &SINK_TAGS).await;
+
+    let rows = client
+        .select_all::<(String, String)>(&table, "host, message")
+        .await;
+    assert_eq!(
+        rows,
+        vec![("example.com".to_string(), "raw log line".to_string())]
+    );
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+}
+
+#[tokio::test]
+async fn healthcheck_fails_for_missing_table() {
+    trace_init();
+
+    let config: ClickhouseNativeConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        batch.max_events = 1
+        request.retry_attempts = 1
+        "#,
+        clickhouse_address(),
+        gen_table()
+    ))
+    .unwrap();
+    let (_sink, healthcheck) = config.build(SinkContext::new_test()).await.unwrap();
+
+    assert!(healthcheck.await.is_err());
+}
+
+fn make_event() -> (Event, BatchStatusReceiver) {
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let mut event = LogEvent::from("raw log line").with_batch_notifier(&batch);
+    event.insert("host", "example.com");
+    (event.into(), receiver)
+}
+
+struct ClickhouseClient {
+    pool: Pool,
+}
+
+impl ClickhouseClient {
+    fn new() -> Self {
+        let options = Options::from_str(&clickhouse_address()).unwrap();
+        Self {
+            pool: Pool::new(options),
+        }
+    }
+
+    async fn create_table(&self, table: &str, schema: &str) {
+        let mut handle = self.pool.get_handle().await.unwrap();
+        handle
+            .execute(format!(
+                "CREATE TABLE {} ({}) ENGINE = MergeTree ORDER BY tuple()",
+                table, schema
+            ))
+            .await
+            .unwrap();
+    }
+
+    async fn select_all<T: FromRow>(&self, table: &str, columns: &str) -> Vec<T> {
+        let mut handle = self.pool.get_handle().await.unwrap();
+        let block = handle
+            .query(format!("SELECT {} FROM {}", columns, table))
+            .fetch_all()
+            .await
+            .unwrap();
+        (0..block.row_count())
+            .map(|row| T::from_row(&block, row))
+            .collect()
+    }
+}
+
+type ResultBlock = Block<Complex>;
+
+trait FromRow {
+    fn from_row(block: &ResultBlock, row: usize) -> Self;
+}
+
+impl FromRow for (String, String) {
+    fn from_row(block: &ResultBlock, row: usize) -> Self {
+        (block.get(row, 0).unwrap(), block.get(row, 1).unwrap())
+    }
+}
+
+impl FromRow for (String, String, Option<i64>, Vec<String>) {
+    fn from_row(block: &ResultBlock, row: usize) -> Self {
+        (
+            block.get(row, 0).unwrap(),
+            block.get(row, 1).unwrap(),
+            block.get(row, 2).unwrap(),
+            block.get(row, 3).unwrap(),
+        )
+    }
+}
+
+fn gen_table() -> String {
+    format!("test_{}", random_string(10).to_lowercase())
+}
@@ -0,0 +1,16 @@
+//! The `clickhouse_native` sink.
+//!
+//! Inserts events into ClickHouse over its native TCP protocol. Each batch of events is converted
+//! into a columnar block using the schema of the destination table, rather than being serialized
+//! as JSON for the HTTP interface.
+mod config;
+mod encoder;
+mod error;
+mod schema;
+mod service;
+mod sink;
+
+#[cfg(all(test, feature = "clickhouse-integration-tests"))]
+mod integration_tests;
+
+pub use self::config::ClickhouseNativeConfig;
@@ -0,0 +1,305 @@
+use clickhouse_rs::ClientHandle;
+
+use super::error::ClickhouseNativeError;
+
+/// A column type that values can be converted to from events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum ScalarType {
+    Bool,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    /// Both `String` and `FixedString(N)`, which the client converts as needed.
+    String,
+    Date,
+    /// Both `DateTime` and `DateTime64`, with any precision or time zone.
+    DateTime,
+    Uuid,
+    /// `Decimal(P, S)`, `Decimal32(S)` and `Decimal64(S)`. Wider decimals aren't supported by the
+    /// client.
+    Decimal {
+        precision: u8,
+        scale: u8,
+    },
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "Bool" => Self::Bool,
+            "UInt8" => Self::UInt8,
+            "UInt16" => Self::UInt16,
+            "UInt32" => Self::UInt32,
+            "UInt64" => Self::UInt64,
+            "Int8" => Self::Int8,
+            "Int16" => Self::Int16,
+            "Int32" => Self::Int32,
+            "Int64" => Self::Int64,
+            "Float32" => Self::Float32,
+            "Float64" => Self::Float64,
+            "String" => Self::String,
+            "Date" => Self::Date,
+            "DateTime" => Self::DateTime,
+            "UUID" => Self::Uuid,
+            _ if name.starts_with("FixedString(") => Self::String,
+            _ if name.starts_with("DateTime(") || name.starts_with("DateTime64(") => Self::DateTime,
+            _ => return Self::parse_decimal(name),
+        })
+    }
+
+    fn parse_decimal(name: &str) -> Option<Self> {
+        let (kind, arguments) = name.strip_suffix(')')?.split_once('(')?;
+        let arguments = arguments
+            .split(',')
+            .map(|argument| argument.trim().parse::<u8>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (precision, scale) = match (kind, arguments.as_slice()) {
+            ("Decimal", [precision, scale]) => (*precision, *scale),
+            ("Decimal32", [scale]) => (9, *scale),
+            ("Decimal64", [scale]) => (18, *scale),
+            _ => return None,
+        };
+        ((1..=18).contains(&precision) && scale <= precision)
+            .then_some(Self::Decimal { precision, scale })
+    }
+
+    const fn is_numeric(self) -> bool {
+        !matches!(
+            self,
+            Self::Bool
+                | Self::String
+                | Self::Date
+                | Self::DateTime
+                | Self::Uuid
+                | Self::Decimal { .. }
+        )
+    }
+}
+
+/// The type of a column in the destination table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum ColumnType {
+    Scalar(ScalarType),
+    Nullable(ScalarType),
+    /// Arrays are supported for strings and numbers.
+    Array(ScalarType),
+}
+
+impl ColumnType {
+    pub(super) fn parse(name: &str) -> Option<Self> {
+        let unwrap = |prefix: &str| {
+            name.strip_prefix(prefix)
+                .and_then(|inner| inner.strip_suffix(')'))
+        };
+
+        if let Some(inner) = unwrap("Nullable(") {
+            ScalarType::parse(inner).map(Self::Nullable)
+        } else if let Some(inner) = unwrap("Array(") {
+            ScalarType::parse(inner)
+                .filter(|scalar| *scalar == ScalarType::String || scalar.is_numeric())
+                .map(Self::Array)
+        } else {
+            ScalarType::parse(name).map(Self::Scalar)
+        }
+    }
+}
+
+/// A column of the destination table that events are inserted into.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct Column {
+    pub(super) name: String,
+    pub(super) column_type: ColumnType,
+    /// The type of the column as reported by ClickHouse.
+    pub(super) type_name: String,
+}
+
+/// The insertable columns of the destination table.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct Schema {
+    pub(super) columns: Vec<Column>,
+}
+
+impl Schema {
+    /// Builds the schema from the `name`, `type` and `default_type` of each column, as returned by
+    /// `DESCRIBE TABLE`.
+    ///
+    /// Columns that can't be inserted into, such as `MATERIALIZED` and `ALIAS` columns, are
+    /// skipped. Columns of unsupported types are skipped if they have a default expression that
+    /// ClickHouse can fill them with, and are otherwise an error naming the column, so that a table
+    /// the sink can't insert into, for example because of a `LowCardinality` or `Map` column, fails
+    /// the healthcheck.
+    pub(super) fn from_description<'a>(
+        description: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+    ) -> Result<Self, ClickhouseNativeError> {
+        let mut columns = Vec::new();
+
+        for (name, type_name, default_type) in description {
+            match default_type {
+                "MATERIALIZED" | "ALIAS" => continue,
+                _ => {}
+            }
+
+            match ColumnType::parse(type_name) {
+                Some(column_type) => columns.push(Column {
+                    name: name.to_owned(),
+                    column_type,
+                    type_name: type_name.to_owned(),
+                }),
+                None if default_type == "DEFAULT" => {
+                    debug!(
+                        message = "Skipping column of unsupported type with a default expression.",
+                        column = %name,
+                        column_type = %type_name,
+                    );
+                }
+                None => {
+                    return Err(ClickhouseNativeError::UnsupportedColumnType {
+                        column: name.to_owned(),
+                        column_type: type_name.to_owned(),
+                    })
+                }
+            }
+        }
+
+        Ok(Self { columns })
+    }
+
+    /// Fetches the schema of `table`.
+    pub(super) async fn load(
+        handle: &mut ClientHandle,
+        table: &str,
+    ) -> Result<Self, ClickhouseNativeError> {
+        let description = handle
+            .query(format!("DESCRIBE TABLE {}", table))
+            .fetch_all()
+            .await?;
+
+        let mut rows = Vec::with_capacity(description.row_count());
+        for row in description.rows() {
+            let name: String = row.get("name")?;
+            let type_name: String = row.get("type")?;
+            let default_type: String = row.get("default_type")?;
+            rows.push((name, type_name, default_type));
+        }
+
+        let schema = Self::from_description(rows.iter().map(|(name, type_name, default_type)| {
+            (name.as_str(), type_name.as_str(), default_type.as_str())
+        }))?;
+        if schema.columns.is_empty() {
+            return Err(ClickhouseNativeError::MissingTable {
+                table: table.to_owned(),
+            });
+        }
+
+        Ok(schema)
+    }
+}
+
+/// Quotes `identifier` for use in a query.
+pub(super) fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_column_types() {
+        let cases = [
+            ("String", Some(ColumnType::Scalar(ScalarType::String))),
+            (
+                "FixedString(16)",
+                Some(ColumnType::Scalar(ScalarType::String)),
+            ),
+            ("UInt16", Some(ColumnType::Scalar(ScalarType::UInt16))),
+            ("Float64", Some(ColumnType::Scalar(ScalarType::Float64))),
+            ("DateTime", Some(ColumnType::Scalar(ScalarType::DateTime))),
+            (
+                "DateTime64(3, 'UTC')",
+                Some(ColumnType::Scalar(ScalarType::DateTime)),
+            ),
+            (
+                "DateTime('Europe/Berlin')",
+                Some(ColumnType::Scalar(ScalarType::DateTime)),
+            ),
+            (
+                "Nullable(Int32)",
+                Some(ColumnType::Nullable(ScalarType::Int32)),
+            ),
+            ("Array(String)", Some(ColumnType::Array(ScalarType::String))),
+            ("Array(Int64)", Some(ColumnType::Array(ScalarType::Int64))),
+            (
+                "Decimal(10, 2)",
+                Some(ColumnType::Scalar(ScalarType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                })),
+            ),
+            (
+                "Nullable(Decimal64(4))",
+                Some(ColumnType::Nullable(ScalarType::Decimal {
+                    precision: 18,
+                    scale: 4,
+                })),
+            ),
+            ("Decimal128(4)", None),
+            ("Decimal(38, 4)", None),
+            ("Array(Decimal32(2))", None),
+            ("Array(Date)", None),
+            ("Array(Nullable(String))", None),
+            ("LowCardinality(String)", None),
+            ("Map(String, String)", None),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(ColumnType::parse(name), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn skips_columns_that_cannot_be_inserted() {
+        let schema = Schema::from_description([
+            ("timestamp", "DateTime64(3)", ""),
+            ("message", "String", ""),
+            ("length", "UInt64", "MATERIALIZED"),
+            ("level", "LowCardinality(String)", "DEFAULT"),
+            ("upper", "String", "ALIAS"),
+        ])
+        .unwrap();
+
+        let names = schema
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["timestamp", "message"]);
+    }
+
+    #[test]
+    fn rejects_unsupported_columns_without_default() {
+        let error = Schema::from_description([
+            ("message", "String", ""),
+            ("level", "LowCardinality(String)", ""),
+        ])
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            ClickhouseNativeError::UnsupportedColumnType { column, .. } if column == "level"
+        ));
+    }
+
+    #[test]
+    fn quotes_identifiers() {
+        assert_eq!(quote_identifier("logs"), "`logs`");
+        assert_eq!(quote_identifier("we`ird"), "`we\\`ird`");
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use clickhouse_rs::Pool;
+use futures::future::BoxFuture;
+use tokio::sync::Mutex;
+use tower::Service;
+use vector_common::{
+    finalization::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::CountByteSize,
+    request_metadata::{MetaDescriptive, RequestMetadata},
+};
+use vector_core::{event::Event, stream::DriverResponse};
+
+use super::{encoder::encode_block, error::ClickhouseNativeError, schema::Schema};
+use crate::sinks::util::retries::RetryLogic;
+
+#[derive(Clone)]
+pub(super) struct ClickhouseNativeRetryLogic;
+
+impl RetryLogic for ClickhouseNativeRetryLogic {
+    type Error = ClickhouseNativeError;
+    type Response = ClickhouseNativeResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        error.is_retriable()
+    }
+}
+
+/// A batch of events to insert.
+#[derive(Clone)]
+pub(super) struct ClickhouseNativeRequest {
+    pub(super) events: Vec<Event>,
+    pub(super) finalizers: EventFinalizers,
+    pub(super) metadata: RequestMetadata,
+}
+
+impl Finalizable for ClickhouseNativeRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for ClickhouseNativeRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub(super) struct ClickhouseNativeResponse {
+    metadata: RequestMetadata,
+    byte_size: usize,
+}
+
+impl DriverResponse for ClickhouseNativeResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(
+            self.metadata.event_count(),
+            self.metadata.events_byte_size(),
+        )
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.byte_size)
+    }
+}
+
+/// Inserts batches of events into a table.
+///
+/// The schema of the table is fetched before the first insert, and fetched again after any insert
+/// that fails with a server error, in case the table has been altered.
+#[derive(Clone)]
+pub(super) struct ClickhouseNativeService {
+    pool: Pool,
+    table: String,
+    schema: Arc<Mutex<Option<Arc<Schema>>>>,
+}
+
+impl ClickhouseNativeService {
+    pub(super) fn new(pool: Pool, table: String) -> Self {
+        Self {
+            pool,
+            table,
+            schema: Arc::default(),
+        }
+    }
+
+    async fn insert(&self, events: &[Event]) -> Result<usize, ClickhouseNativeError> {
+        let mut handle = self.pool.get_handle().await?;
+
+        let schema = {
+            let mut schema = self.schema.lock().await;
+            match schema.as_ref() {
+                Some(schema) => Arc::clone(schema),
+                None => {
+                    let loaded = Arc::new(Schema::load(&mut handle, &self.table).await?);
+                    *schema = Some(Arc::clone(&loaded));
+                    loaded
+                }
+            }
+        };
+
+        let encoded = encode_block(&schema, events)?;
+        handle.insert(self.table.as_str(), encoded.block).await?;
+
+        Ok(encoded.byte_size)
+    }
+}
+
+impl Service<ClickhouseNativeRequest> for ClickhouseNativeService {
+    type Response = ClickhouseNativeResponse;
+    type Error = ClickhouseNativeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: ClickhouseNativeRequest) -> Self::Future {
+        let service = self.clone();
+
+        Box::pin(async move {
+            match service.insert(&request.events).await {
+                Ok(byte_size) => Ok(ClickhouseNativeResponse {
+                    metadata: request.metadata,
+                    byte_size,
+                }),
+                Err(error) => {
+                    if error.invalidates_schema() {
+                        *service.schema.lock().await = None;
+                    }
+                    Err(error)
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use std::num::NonZeroUsize;
+
+use futures_util::{stream::BoxStream, StreamExt};
+use vector_common::finalization::Finalizable;
+use vector_core::{
+    event::Event, sink::StreamSink, stream::BatcherSettings, EstimatedJsonEncodedSizeOf,
+};
+
+use super::service::{
+    ClickhouseNativeRequest, ClickhouseNativeRetryLogic, ClickhouseNativeService,
+};
+use crate::{
+    codecs::Transformer,
+    sinks::util::{metadata::RequestMetadataBuilder, service::Svc, SinkBuilderExt},
+};
+
+pub(super) struct ClickhouseNativeSink {
+    batch_settings: BatcherSettings,
+    transformer: Transformer,
+    service: Svc<ClickhouseNativeService, ClickhouseNativeRetryLogic>,
+}
+
+impl ClickhouseNativeSink {
+    pub(super) const fn new(
+        batch_settings: BatcherSettings,
+        transformer: Transformer,
+        service: Svc<ClickhouseNativeService, ClickhouseNativeRetryLogic>,
+    ) -> Self {
+        Self {
+            batch_settings,
+            transformer,
+            service,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let Self {
+            batch_settings,
+            transformer,
+            service,
+        } = *self;
+
+        input
+            .batched(batch_settings.into_byte_size_config())
+            .map(move |events| build_request(&transformer, events))
+            .into_driver(service)
+            .protocol("tcp")
+            .run()
+            .await
+    }
+}
+
+fn build_request(transformer: &Transformer, mut events: Vec<Event>) -> ClickhouseNativeRequest {
+    let finalizers = events.take_finalizers();
+    for event in &mut events {
+        transformer.transform(event);
+    }
+
+    // The size of the block isn't known until it's built by the service, so the request is sized
+    // by its events instead.
+    let request_size = NonZeroUsize::new(events.estimated_json_encoded_size_of())
+        .unwrap_or_else(|| NonZeroUsize::new(1).unwrap());
+    let metadata = RequestMetadataBuilder::from_events(&events).with_request_size(request_size);
+
+    ClickhouseNativeRequest {
+        events,
+        finalizers,
+        metadata,
+    }
+}
+
+#[async_trait::async_trait]
+impl StreamSink<Event> for ClickhouseNativeSink {
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
@@ -93,7 +93,7 @@ impl GenerateConfig for DatabendConfig {
 impl DatabendConfig {
     pub(super) fn build_client(&self, cx: &SinkContext) -> crate::Result<HttpClient> {
         let tls = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
         Ok(client)
     }
 
@@ -156,6 +156,7 @@ impl SinkConfig for DatabendConfig {
         let service =
             DatabendService::new(client, database, table, file_format_options, copy_options)?;
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
             .settings(request_settings, DatabendRetryLogic)
             .service(service);
 
@@ -8,7 +8,7 @@ use vector_core::schema;
 use crate::{
     common::datadog::{get_base_domain_region, Region},
     config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
-    http::HttpClient,
+    http::{HttpClient, HttpClientOptions},
     sinks::{
         datadog::{
             events::{
@@ -61,9 +61,13 @@ impl DatadogEventsConfig {
         http::Uri::try_from(format!("{}/api/v1/events", api_base_endpoint)).expect("URI not valid")
     }
 
-    fn build_client(&self, proxy: &ProxyConfig) -> crate::Result<HttpClient> {
+    fn build_client(
+        &self,
+        proxy: &ProxyConfig,
+        options: &HttpClientOptions,
+    ) -> crate::Result<HttpClient> {
         let tls = MaybeTlsSettings::from_config(&self.dd_common.tls, false)?;
-        let client = HttpClient::new(tls, proxy)?;
+        let client = HttpClient::new_with_options(tls, proxy, options)?;
         Ok(client)
     }
 
@@ -74,11 +78,12 @@ impl DatadogEventsConfig {
             client,
         );
 
-        let request_opts = self.request;
+        let request_opts = &self.request;
         let request_settings = request_opts.unwrap_with(&TowerRequestConfig::default());
         let retry_logic = HttpStatusRetryLogic::new(|req: &DatadogEventsResponse| req.http_status);
 
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
             .settings(request_settings, retry_logic)
             .service(service);
 
@@ -91,7 +96,7 @@ impl DatadogEventsConfig {
 #[async_trait::async_trait]
 impl SinkConfig for DatadogEventsConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let client = self.build_client(cx.proxy())?;
+        let client = self.build_client(cx.proxy(), cx.http_client())?;
         let healthcheck = self
             .dd_common
             .build_healthcheck(client.clone(), self.region.as_ref())?;
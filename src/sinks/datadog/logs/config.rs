@@ -6,12 +6,14 @@ use value::Kind;
 use vector_config::configurable_component;
 use vector_core::config::proxy::ProxyConfig;
 
-use super::{service::LogApiRetry, sink::LogSinkBuilder};
+use super::{
+    reserved_attributes::ReservedAttributesConfig, service::LogApiRetry, sink::LogSinkBuilder,
+};
 use crate::{
     codecs::Transformer,
     common::datadog::Region,
     config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
-    http::HttpClient,
+    http::{HttpClient, HttpClientOptions},
     schema,
     sinks::{
         datadog::{logs::service::LogApiService, DatadogCommonConfig},
@@ -69,6 +71,13 @@ pub struct DatadogLogsConfig {
     )]
     pub encoding: Transformer,
 
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub reserved_attributes: ReservedAttributesConfig,
+
     #[configurable(derived)]
     #[serde(default)]
     pub batch: BatchConfig<DatadogLogsDefaultBatchSettings>,
@@ -117,6 +126,7 @@ impl DatadogLogsConfig {
     pub fn build_processor(&self, client: HttpClient) -> crate::Result<VectorSink> {
         let default_api_key: Arc<str> = Arc::from(self.dd_common.default_api_key.inner());
         let request_limits = self.request.tower.unwrap_with(&Default::default());
+        self.reserved_attributes.validate()?;
 
         // We forcefully cap the provided batch configuration to the size/log line limits imposed by
         // the Datadog Logs API, but we still allow them to be lowered if need be.
@@ -128,6 +138,7 @@ impl DatadogLogsConfig {
             .into_batcher_settings()?;
 
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, LogApiRetry)
             .service(LogApiService::new(
                 client,
@@ -140,12 +151,17 @@ impl DatadogLogsConfig {
 
         let sink = LogSinkBuilder::new(encoding, service, default_api_key, batch, protocol)
             .compression(self.compression.unwrap_or_default())
+            .reserved_attributes(self.reserved_attributes.clone())
             .build();
 
         Ok(VectorSink::from_event_streamsink(sink))
     }
 
-    pub fn create_client(&self, proxy: &ProxyConfig) -> crate::Result<HttpClient> {
+    pub fn create_client(
+        &self,
+        proxy: &ProxyConfig,
+        options: &HttpClientOptions,
+    ) -> crate::Result<HttpClient> {
         let tls_settings = MaybeTlsSettings::from_config(
             &Some(
                 self.dd_common
@@ -155,14 +171,14 @@ impl DatadogLogsConfig {
             ),
             false,
         )?;
-        Ok(HttpClient::new(tls_settings, proxy)?)
+        Ok(HttpClient::new_with_options(tls_settings, proxy, options)?)
     }
 }
 
 #[async_trait::async_trait]
 impl SinkConfig for DatadogLogsConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let client = self.create_client(&cx.proxy)?;
+        let client = self.create_client(cx.proxy(), cx.http_client())?;
 
         let healthcheck = self
             .dd_common
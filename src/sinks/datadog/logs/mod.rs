@@ -25,6 +25,7 @@ mod integration_tests;
 mod tests;
 
 mod config;
+mod reserved_attributes;
 mod service;
 mod sink;
 
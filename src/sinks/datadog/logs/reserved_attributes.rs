@@ -0,0 +1,248 @@
+use snafu::Snafu;
+use vector_config::configurable_component;
+use vector_core::event::{LogEvent, Value};
+
+use crate::{
+    internal_events::{DatadogLogsInvalidTag, TemplateRenderingError},
+    template::Template,
+};
+
+/// The maximum length of a tag accepted by Datadog.
+const MAX_TAG_LENGTH: usize = 200;
+
+/// Templates for the Datadog [reserved attributes][reserved_attributes] of each log.
+///
+/// Attributes are rendered from each event as it is sent, and take precedence over any value the
+/// event already has. When a template fails to render, the event is sent with the attribute it
+/// already has, if any.
+///
+/// [reserved_attributes]: https://docs.datadoghq.com/logs/log_configuration/attributes_naming_convention/#reserved-attributes
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ReservedAttributesConfig {
+    /// The name of the application or service that generated the log, sent as the `service`
+    /// attribute.
+    #[configurable(metadata(docs::examples = "{{ kubernetes.pod_labels.app }}"))]
+    #[configurable(metadata(docs::examples = "checkout"))]
+    pub service: Option<Template>,
+
+    /// The technology that the log originated from, sent as the `ddsource` attribute.
+    ///
+    /// Datadog uses this attribute to select the integration pipeline that processes the log.
+    #[configurable(metadata(docs::examples = "nginx"))]
+    #[configurable(metadata(docs::examples = "{{ source_type }}"))]
+    pub ddsource: Option<Template>,
+
+    /// The host that the log originated from, sent as the `hostname` attribute.
+    #[configurable(metadata(docs::examples = "{{ kubernetes.pod_node_name }}"))]
+    pub hostname: Option<Template>,
+
+    /// Tags to add to the `ddtags` attribute.
+    ///
+    /// Each tag is rendered separately, and should be of the form `key:value`. Tags must start with
+    /// a letter, be at most 200 characters long, and only contain alphanumerics, underscores,
+    /// minuses, colons, periods, and slashes. Tags that don't render to a valid tag are dropped.
+    ///
+    /// Tags already present in the `ddtags` field of the event are kept.
+    #[configurable(metadata(docs::examples = "env:production"))]
+    #[configurable(metadata(docs::examples = "team:{{ team }}"))]
+    #[serde(default)]
+    pub ddtags: Vec<Template>,
+}
+
+#[derive(Debug, Eq, PartialEq, Snafu)]
+pub enum TagError {
+    #[snafu(display("tag is empty"))]
+    Empty,
+
+    #[snafu(display("tag is longer than {} characters", MAX_TAG_LENGTH))]
+    TooLong,
+
+    #[snafu(display("tag must start with a letter"))]
+    InvalidStart,
+
+    #[snafu(display("tag contains the invalid character {:?}", character))]
+    InvalidCharacter { character: char },
+
+    #[snafu(display("tag must not end with a colon"))]
+    TrailingColon,
+}
+
+/// Checks that the tag would be accepted by Datadog as is.
+///
+/// Datadog rewrites tags that don't follow its [tag syntax][tag_syntax], which makes them hard to
+/// find, so they are rejected instead.
+///
+/// [tag_syntax]: https://docs.datadoghq.com/getting_started/tagging/#define-tags
+pub fn validate_tag(tag: &str) -> Result<(), TagError> {
+    let first = tag.chars().next().ok_or(TagError::Empty)?;
+    if tag.chars().count() > MAX_TAG_LENGTH {
+        return Err(TagError::TooLong);
+    }
+    if !first.is_alphabetic() {
+        return Err(TagError::InvalidStart);
+    }
+    if let Some(character) = tag
+        .chars()
+        .find(|c| !(c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.' | '/')))
+    {
+        return Err(TagError::InvalidCharacter { character });
+    }
+    if tag.ends_with(':') {
+        return Err(TagError::TrailingColon);
+    }
+    Ok(())
+}
+
+impl ReservedAttributesConfig {
+    /// Checks the tags that don't depend on the event, so mistakes are caught at startup.
+    pub fn validate(&self) -> crate::Result<()> {
+        for tag in self.ddtags.iter().filter(|tag| !tag.is_dynamic()) {
+            validate_tag(tag.get_ref()).map_err(|error| {
+                format!("Invalid tag {:?} in `ddtags`: {}", tag.get_ref(), error)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Sets the reserved attributes of the log, after its fields have been renamed to their
+    /// Datadog names.
+    pub fn apply(&self, log: &mut LogEvent) {
+        for (field, template) in [
+            ("service", &self.service),
+            ("ddsource", &self.ddsource),
+            ("hostname", &self.hostname),
+        ] {
+            if let Some(value) = template
+                .as_ref()
+                .and_then(|template| render(template, field, log))
+            {
+                log.insert(field, value);
+            }
+        }
+
+        if self.ddtags.is_empty() {
+            return;
+        }
+
+        let mut tags = match log.get("ddtags") {
+            Some(Value::Bytes(tags)) if !tags.is_empty() => {
+                String::from_utf8_lossy(tags).into_owned()
+            }
+            _ => String::new(),
+        };
+        for template in &self.ddtags {
+            let Some(tag) = render(template, "ddtags", log) else {
+                continue;
+            };
+            if let Err(error) = validate_tag(&tag) {
+                emit!(DatadogLogsInvalidTag {
+                    tag: &tag,
+                    error: &error.to_string(),
+                });
+                continue;
+            }
+            if !tags.is_empty() {
+                tags.push(',');
+            }
+            tags.push_str(&tag);
+        }
+        if !tags.is_empty() {
+            log.insert("ddtags", tags);
+        }
+    }
+}
+
+fn render(template: &Template, field: &str, log: &LogEvent) -> Option<String> {
+    template
+        .render_string(log)
+        .map_err(|error| {
+            emit!(TemplateRenderingError {
+                error,
+                field: Some(field),
+                drop_event: false,
+            })
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_tags() {
+        assert_eq!(validate_tag("env:production"), Ok(()));
+        assert_eq!(validate_tag("path:/var/log/app.log"), Ok(()));
+        assert_eq!(validate_tag("canary"), Ok(()));
+        assert_eq!(validate_tag(""), Err(TagError::Empty));
+        assert_eq!(validate_tag("1env:prod"), Err(TagError::InvalidStart));
+        assert_eq!(
+            validate_tag("team:data eng"),
+            Err(TagError::InvalidCharacter { character: ' ' })
+        );
+        assert_eq!(
+            validate_tag("env:prod,team:web"),
+            Err(TagError::InvalidCharacter { character: ',' })
+        );
+        assert_eq!(validate_tag("env:"), Err(TagError::TrailingColon));
+        assert_eq!(validate_tag(&"a".repeat(201)), Err(TagError::TooLong));
+    }
+
+    #[test]
+    fn rejects_invalid_static_tags() {
+        let config = toml::from_str::<ReservedAttributesConfig>(
+            r#"ddtags = ["env:prod", "team:{{ team }}"]"#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+
+        let config =
+            toml::from_str::<ReservedAttributesConfig>(r#"ddtags = ["env prod"]"#).unwrap();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn sets_reserved_attributes() {
+        let config = toml::from_str::<ReservedAttributesConfig>(
+            r#"
+            service = "{{ app }}"
+            ddsource = "nginx"
+            hostname = "{{ node }}"
+            ddtags = ["env:production", "team:{{ team }}", "owner:{{ owner }}", "bad:{{ app }} tag"]
+        "#,
+        )
+        .unwrap();
+
+        let mut log = LogEvent::from("GET /");
+        log.insert("app", "checkout");
+        log.insert("node", "node-1");
+        log.insert("team", "payments");
+        log.insert("hostname", "pod-1");
+        log.insert("ddtags", "version:1.2");
+        config.apply(&mut log);
+
+        assert_eq!(log["service"], "checkout".into());
+        assert_eq!(log["ddsource"], "nginx".into());
+        assert_eq!(log["hostname"], "node-1".into());
+        // The tag referencing a missing field, and the tag rendering to an invalid tag, are dropped.
+        assert_eq!(
+            log["ddtags"],
+            "version:1.2,env:production,team:payments".into()
+        );
+    }
+
+    #[test]
+    fn keeps_attributes_that_fail_to_render() {
+        let config =
+            toml::from_str::<ReservedAttributesConfig>(r#"service = "{{ app }}""#).unwrap();
+
+        let mut log = LogEvent::from("GET /");
+        log.insert("service", "web");
+        config.apply(&mut log);
+
+        assert_eq!(log["service"], "web".into());
+        assert!(log.get("ddtags").is_none());
+    }
+}
@@ -15,7 +15,10 @@ use vector_core::{
     stream::{BatcherSettings, DriverResponse},
 };
 
-use super::{config::MAX_PAYLOAD_BYTES, service::LogApiRequest};
+use super::{
+    config::MAX_PAYLOAD_BYTES, reserved_attributes::ReservedAttributesConfig,
+    service::LogApiRequest,
+};
 use crate::{
     codecs::{Encoder, Transformer},
     internal_events::SinkRequestBuildError,
@@ -71,6 +74,11 @@ impl<S> LogSinkBuilder<S> {
         self
     }
 
+    pub fn reserved_attributes(mut self, reserved_attributes: ReservedAttributesConfig) -> Self {
+        self.encoding.reserved_attributes = reserved_attributes;
+        self
+    }
+
     pub fn build(self) -> LogSink<S> {
         LogSink {
             default_api_key: self.default_api_key,
@@ -108,6 +116,7 @@ pub struct LogSink<S> {
 #[derive(Clone, Debug)]
 pub struct JsonEncoding {
     encoder: (Transformer, Encoder<Framer>),
+    reserved_attributes: ReservedAttributesConfig,
 }
 
 impl JsonEncoding {
@@ -120,6 +129,7 @@ impl JsonEncoding {
                     JsonSerializerConfig::default().build().into(),
                 ),
             ),
+            reserved_attributes: ReservedAttributesConfig::default(),
         }
     }
 }
@@ -148,6 +158,8 @@ impl crate::sinks::util::encoding::Encoder<Vec<Event>> for JsonEncoding {
                     Value::Integer(ts.timestamp_millis()),
                 );
             }
+
+            self.reserved_attributes.apply(log);
         }
 
         self.encoder.encode_input(input, writer)
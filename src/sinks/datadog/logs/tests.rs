@@ -467,6 +467,60 @@ async fn no_enterprise_headers_inner(api_status: ApiStatus) {
     assert!(parts.headers.get("DD-EVP-ORIGIN-VERSION").is_some());
 }
 
+#[tokio::test]
+/// Assert that reserved attributes are rendered from their templates
+///
+/// The configured templates take precedence over the fields of the event, and
+/// tags are appended to any tags the event already has.
+async fn reserved_attributes() {
+    let (mut config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            compression = "none"
+
+            [reserved_attributes]
+            service = "{{ app }}"
+            ddsource = "nginx"
+            ddtags = ["env:production", "team:{{ team }}"]
+        "#})
+    .unwrap();
+
+    let addr = next_addr();
+    // Swap out the endpoint so we can force send it to our local server
+    let endpoint = format!("http://{}", addr);
+    config.dd_common.endpoint = Some(endpoint.clone());
+
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (rx, _trigger, server) = test_server(addr, ApiStatus::OKv2);
+    tokio::spawn(server);
+
+    let mut log = LogEvent::from("GET /");
+    log.insert("app", "checkout");
+    log.insert("team", "payments");
+    log.insert("service", "web");
+    log.insert("ddtags", "version:1.2");
+
+    run_and_assert_sink_compliance(sink, futures::stream::iter(vec![log.into()]), &SINK_TAGS).await;
+
+    let output: (Parts, Bytes) = rx.take(1).collect::<Vec<_>>().await.pop().unwrap();
+    let payload: serde_json::Value = serde_json::from_slice(&output.1[..]).unwrap();
+    let log = payload.get(0).unwrap();
+    assert_eq!(log["service"], "checkout");
+    assert_eq!(log["ddsource"], "nginx");
+    assert_eq!(log["ddtags"], "version:1.2,env:production,team:payments");
+}
+
+#[tokio::test]
+async fn rejects_invalid_static_tags() {
+    let (config, cx) = load_sink::<DatadogLogsConfig>(indoc! {r#"
+            default_api_key = "atoken"
+            reserved_attributes.ddtags = ["env production"]
+        "#})
+    .unwrap();
+
+    assert!(config.build(cx).await.is_err());
+}
+
 #[tokio::test]
 /// Assert the RetryLogic implementation of LogApiRetry
 async fn error_is_retriable() {
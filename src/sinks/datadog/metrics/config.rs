@@ -12,10 +12,14 @@ use super::{
 use crate::{
     common::datadog::{get_base_domain_region, Region},
     config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext},
-    http::HttpClient,
+    http::{HttpClient, HttpClientOptions},
     sinks::{
         datadog::DatadogCommonConfig,
-        util::{batch::BatchConfig, ServiceBuilderExt, SinkBatchSettings, TowerRequestConfig},
+        util::{
+            batch::BatchConfig,
+            metric_names::{MetricNameSanitizer, NameSanitizationConfig, NamingRules},
+            ServiceBuilderExt, SinkBatchSettings, TowerRequestConfig,
+        },
         Healthcheck, UriParseSnafu, VectorSink,
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
@@ -113,6 +117,13 @@ pub struct DatadogMetricsConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub name_sanitization: NameSanitizationConfig,
 }
 
 impl_generate_config_from_default!(DatadogMetricsConfig);
@@ -120,7 +131,7 @@ impl_generate_config_from_default!(DatadogMetricsConfig);
 #[async_trait::async_trait]
 impl SinkConfig for DatadogMetricsConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let client = self.build_client(&cx.proxy)?;
+        let client = self.build_client(cx.proxy(), cx.http_client())?;
         let healthcheck = self
             .dd_common
             .build_healthcheck(client.clone(), self.region.as_ref())?;
@@ -172,7 +183,11 @@ impl DatadogMetricsConfig {
         ))
     }
 
-    fn build_client(&self, proxy: &ProxyConfig) -> crate::Result<HttpClient> {
+    fn build_client(
+        &self,
+        proxy: &ProxyConfig,
+        options: &HttpClientOptions,
+    ) -> crate::Result<HttpClient> {
         let tls_settings = MaybeTlsSettings::from_config(
             &Some(
                 self.dd_common
@@ -182,7 +197,7 @@ impl DatadogMetricsConfig {
             ),
             false,
         )?;
-        let client = HttpClient::new(tls_settings, proxy)?;
+        let client = HttpClient::new_with_options(tls_settings, proxy, options)?;
         Ok(client)
     }
 
@@ -196,6 +211,7 @@ impl DatadogMetricsConfig {
 
         let endpoint_configuration = self.generate_metrics_endpoint_configuration()?;
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, DatadogMetricsRetryLogic)
             .service(DatadogMetricsService::new(
                 client,
@@ -207,8 +223,20 @@ impl DatadogMetricsConfig {
             self.default_namespace.clone(),
         )?;
 
+        let sanitizer = MetricNameSanitizer::new(
+            &self.name_sanitization,
+            NamingRules::DATADOG,
+            self.default_namespace.clone(),
+        )?;
+
         let protocol = self.get_protocol();
-        let sink = DatadogMetricsSink::new(service, request_builder, batcher_settings, protocol);
+        let sink = DatadogMetricsSink::new(
+            service,
+            request_builder,
+            batcher_settings,
+            protocol,
+            sanitizer,
+        );
 
         Ok(VectorSink::from_event_streamsink(sink))
     }
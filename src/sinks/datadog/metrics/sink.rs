@@ -25,6 +25,7 @@ use crate::{
     sinks::util::{
         buffer::metrics::sort::sort_for_compression,
         buffer::metrics::{AggregatedSummarySplitter, MetricSplitter},
+        metric_names::MetricNameSanitizer,
         SinkBuilderExt,
     },
 };
@@ -59,6 +60,7 @@ pub(crate) struct DatadogMetricsSink<S> {
     request_builder: DatadogMetricsRequestBuilder,
     batch_settings: BatcherSettings,
     protocol: String,
+    sanitizer: MetricNameSanitizer,
 }
 
 impl<S> DatadogMetricsSink<S>
@@ -74,21 +76,27 @@ where
         request_builder: DatadogMetricsRequestBuilder,
         batch_settings: BatcherSettings,
         protocol: String,
+        sanitizer: MetricNameSanitizer,
     ) -> Self {
         DatadogMetricsSink {
             service,
             request_builder,
             batch_settings,
             protocol,
+            sanitizer,
         }
     }
 
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
         let mut splitter: MetricSplitter<AggregatedSummarySplitter> = MetricSplitter::default();
+        let mut sanitizer = self.sanitizer;
 
         input
             // Convert `Event` to `Metric` so we don't have to deal with constant conversions.
             .filter_map(|event| ready(event.try_into_metric()))
+            // Sanitize names and tag keys before splitting and normalizing, as both key metrics by
+            // their series.
+            .filter_map(move |metric| ready(sanitizer.sanitize(metric)))
             // Split aggregated summaries into individual metrics for count, sum, and the quantiles, which lets us
             // ensure that aggregated summaries effectively make it through normalization, as we can't actually
             // normalize them and so they would be dropped during normalization otherwise.
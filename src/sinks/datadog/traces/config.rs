@@ -14,7 +14,7 @@ use super::{
 };
 use crate::{
     config::{GenerateConfig, Input, SinkConfig, SinkContext},
-    http::HttpClient,
+    http::{HttpClient, HttpClientOptions},
     sinks::{
         datadog::{
             traces::{
@@ -145,6 +145,7 @@ impl DatadogTracesConfig {
             .into_batcher_settings()?;
 
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, TraceApiRetry)
             .service(TraceApiService::new(client.clone()));
 
@@ -188,7 +189,11 @@ impl DatadogTracesConfig {
         Ok(VectorSink::from_event_streamsink(sink))
     }
 
-    pub fn build_client(&self, proxy: &ProxyConfig) -> crate::Result<HttpClient> {
+    pub fn build_client(
+        &self,
+        proxy: &ProxyConfig,
+        options: &HttpClientOptions,
+    ) -> crate::Result<HttpClient> {
         let tls_settings = MaybeTlsSettings::from_config(
             &Some(
                 self.dd_common
@@ -198,7 +203,7 @@ impl DatadogTracesConfig {
             ),
             false,
         )?;
-        Ok(HttpClient::new(tls_settings, proxy)?)
+        Ok(HttpClient::new_with_options(tls_settings, proxy, options)?)
     }
 
     fn get_protocol(&self) -> String {
@@ -213,7 +218,7 @@ impl DatadogTracesConfig {
 #[async_trait::async_trait]
 impl SinkConfig for DatadogTracesConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let client = self.build_client(&cx.proxy)?;
+        let client = self.build_client(cx.proxy(), cx.http_client())?;
         let healthcheck = self.dd_common.build_healthcheck(client.clone(), None)?;
         let sink = self.build_sink(client)?;
 
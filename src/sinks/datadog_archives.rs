@@ -328,7 +328,7 @@ impl DatadogArchivesSinkConfig {
                 let auth = gcs_config.auth.build(Scope::DevStorageReadWrite).await?;
                 let base_url = format!("{}{}/", BASE_URL, self.bucket);
                 let tls = TlsSettings::from_options(&self.tls)?;
-                let client = HttpClient::new(tls, cx.proxy())?;
+                let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
                 let healthcheck = gcs_common::config::build_healthcheck(
                     self.bucket.clone(),
                     client.clone(),
@@ -356,6 +356,7 @@ impl DatadogArchivesSinkConfig {
         // thus no need of the higher number of outgoing requests
         let request_limits = self.request.unwrap_with(&Default::default());
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, S3RetryLogic)
             .service(service);
 
@@ -408,6 +409,7 @@ impl DatadogArchivesSinkConfig {
             .expect("invalid batch settings");
 
         let svc = ServiceBuilder::new()
+            .bandwidth_limit(&request)
             .settings(request, GcsRetryLogic)
             .service(GcsService::new(client, base_url, auth));
 
@@ -458,6 +460,7 @@ impl DatadogArchivesSinkConfig {
     fn build_azure_sink(&self, client: Arc<ContainerClient>) -> crate::Result<VectorSink> {
         let request_limits = self.request.unwrap_with(&Default::default());
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, AzureBlobRetryLogic)
             .service(AzureBlobService::new(client));
 
@@ -814,6 +817,7 @@ impl RequestBuilder<(String, Vec<Event>)> for DatadogAzureRequestBuilder {
         let (partition_key, mut events) = input;
         let finalizers = events.take_finalizers();
         let metadata = AzureBlobMetadata {
+            blob_prefix: partition_key.clone(),
             partition_key,
             count: events.len(),
             byte_size: events.size_of(),
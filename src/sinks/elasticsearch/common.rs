@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use aws_sigv4::http_request::{PayloadChecksumKind, SigningSettings};
 use aws_types::credentials::SharedCredentialsProvider;
 use aws_types::region::Region;
 use bytes::{Buf, Bytes};
@@ -18,11 +19,13 @@ use crate::{
     http::{Auth, HttpClient, MaybeAuth},
     sinks::{
         elasticsearch::{
-            ElasticsearchAuth, ElasticsearchCommonMode, ElasticsearchConfig, ParseError,
+            ElasticsearchAuth, ElasticsearchCommonMode, ElasticsearchConfig, OpenSearchServiceType,
+            ParseError,
         },
         util::{http::RequestConfig, TowerRequestConfig, UriSerde},
         HealthcheckError,
     },
+    template::Template,
     tls::TlsSettings,
     transforms::metric_to_log::MetricToLog,
 };
@@ -40,6 +43,8 @@ pub struct ElasticsearchCommon {
     pub request: RequestConfig,
     pub query_params: HashMap<String, String>,
     pub metric_to_log: MetricToLog,
+    pub service_type: OpenSearchServiceType,
+    pub pipeline: Option<Template>,
 }
 
 impl ElasticsearchCommon {
@@ -61,6 +66,22 @@ impl ElasticsearchCommon {
             .into());
         }
 
+        let service_type = config.opensearch_service_type;
+        if service_type == OpenSearchServiceType::Serverless {
+            if !matches!(config.auth, Some(ElasticsearchAuth::Aws(_))) {
+                return Err(ParseError::ServerlessRequiresAwsAuth.into());
+            }
+            if !matches!(
+                config.api_version,
+                ElasticsearchApiVersion::Auto | ElasticsearchApiVersion::V8
+            ) {
+                return Err(ParseError::ServerlessApiVersion.into());
+            }
+            if config.pipeline.is_some() {
+                return Err(ParseError::ServerlessPipeline.into());
+            }
+        }
+
         let authorization = match &config.auth {
             Some(ElasticsearchAuth::Basic { user, password }) => Some(Auth::Basic {
                 user: user.clone(),
@@ -82,7 +103,10 @@ impl ElasticsearchCommon {
                     .ok_or(ParseError::RegionRequired)?
                     .ok_or(ParseError::RegionRequired)?;
 
-                Some(aws.credentials_provider(region).await?)
+                Some(
+                    aws.credentials_provider(region, proxy_config, &config.tls)
+                        .await?,
+                )
             }
         };
 
@@ -99,9 +123,14 @@ impl ElasticsearchCommon {
             format!("{}s", tower_request.timeout.as_secs()),
         );
 
-        if let Some(pipeline) = &config.pipeline {
-            query_params.insert("pipeline".into(), pipeline.into());
-        }
+        // Templated pipelines are set on each document instead.
+        let pipeline = match &config.pipeline {
+            Some(pipeline) if !pipeline.is_dynamic() => {
+                query_params.insert("pipeline".into(), pipeline.get_ref().into());
+                None
+            }
+            pipeline => pipeline.clone(),
+        };
 
         let bulk_url = {
             let mut query = url::form_urlencoded::Serializer::new(String::new());
@@ -133,6 +162,13 @@ impl ElasticsearchCommon {
                 ElasticsearchApiVersion::V6 => 6,
                 ElasticsearchApiVersion::V7 => 7,
                 ElasticsearchApiVersion::V8 => 8,
+                // OpenSearch Serverless doesn't expose the cluster state API, and only supports
+                // requests without a mapping type, as with the 8.x API.
+                ElasticsearchApiVersion::Auto
+                    if service_type == OpenSearchServiceType::Serverless =>
+                {
+                    8
+                }
                 ElasticsearchApiVersion::Auto => {
                     match get_version(
                         &base_url,
@@ -142,6 +178,7 @@ impl ElasticsearchCommon {
                         &request,
                         &tls_settings,
                         proxy_config,
+                        service_type,
                     )
                     .await
                     {
@@ -201,6 +238,8 @@ impl ElasticsearchCommon {
             region,
             tls_settings,
             metric_to_log,
+            service_type,
+            pipeline,
         })
     }
 
@@ -241,6 +280,14 @@ impl ElasticsearchCommon {
     }
 
     pub async fn healthcheck(self, client: HttpClient) -> crate::Result<()> {
+        if self.service_type == OpenSearchServiceType::Serverless {
+            // OpenSearch Serverless doesn't expose the cluster health API.
+            info!(
+                message = "Skipping healthcheck, as it is not supported by OpenSearch Serverless."
+            );
+            return Ok(());
+        }
+
         match get(
             &self.base_url,
             &self.http_auth,
@@ -249,6 +296,7 @@ impl ElasticsearchCommon {
             &self.request,
             client,
             "/_cluster/health",
+            self.service_type,
         )
         .await?
         .status()
@@ -263,10 +311,25 @@ pub async fn sign_request(
     request: &mut http::Request<Bytes>,
     credentials_provider: &SharedCredentialsProvider,
     region: &Option<Region>,
+    service_type: OpenSearchServiceType,
 ) -> crate::Result<()> {
-    crate::aws::sign_request("es", request, credentials_provider, region).await
+    let mut settings = SigningSettings::default();
+    if service_type == OpenSearchServiceType::Serverless {
+        // OpenSearch Serverless rejects signed requests without an `x-amz-content-sha256` header.
+        settings.payload_checksum_kind = PayloadChecksumKind::XAmzSha256;
+    }
+
+    crate::aws::sign_request_with_settings(
+        service_type.signing_service_name(),
+        request,
+        credentials_provider,
+        region,
+        settings,
+    )
+    .await
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn get_version(
     base_url: &str,
     http_auth: &Option<Auth>,
@@ -275,6 +338,7 @@ async fn get_version(
     request: &RequestConfig,
     tls_settings: &TlsSettings,
     proxy_config: &ProxyConfig,
+    service_type: OpenSearchServiceType,
 ) -> crate::Result<usize> {
     #[derive(Deserialize)]
     struct ClusterState {
@@ -290,6 +354,7 @@ async fn get_version(
         request,
         client,
         "/_cluster/state/version",
+        service_type,
     )
     .await
     .map_err(|error| format!("Failed to get Elasticsearch API version: {}", error))?;
@@ -301,6 +366,7 @@ async fn get_version(
     version.ok_or_else(||"Unexpected response from Elasticsearch endpoint `/_cluster/state/version`. Missing `version`. Consider setting `api_version` option.".into())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn get(
     base_url: &str,
     http_auth: &Option<Auth>,
@@ -309,6 +375,7 @@ async fn get(
     request: &RequestConfig,
     client: HttpClient,
     path: &str,
+    service_type: OpenSearchServiceType,
 ) -> crate::Result<Response<Body>> {
     let mut builder = Request::get(format!("{}{}", base_url, path));
 
@@ -323,7 +390,7 @@ async fn get(
     let mut request = builder.body(Bytes::new())?;
 
     if let Some(credentials_provider) = aws_auth {
-        sign_request(&mut request, credentials_provider, region).await?;
+        sign_request(&mut request, credentials_provider, region, service_type).await?;
     }
     client
         .send(request.map(hyper::Body::from))
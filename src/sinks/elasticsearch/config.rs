@@ -4,6 +4,7 @@ use std::{
 };
 
 use futures::{FutureExt, TryFutureExt};
+use tower::ServiceBuilder;
 use vector_config::configurable_component;
 
 use crate::{
@@ -20,11 +21,11 @@ use crate::{
             service::{ElasticsearchService, HttpRequestBuilder},
             sink::ElasticsearchSink,
             ElasticsearchApiVersion, ElasticsearchAuth, ElasticsearchCommon,
-            ElasticsearchCommonMode, ElasticsearchMode,
+            ElasticsearchCommonMode, ElasticsearchMode, OpenSearchServiceType,
         },
         util::{
             http::RequestConfig, service::HealthConfig, BatchConfig, Compression,
-            RealtimeSizeBasedDefaultBatchSettings, TowerRequestConfig,
+            RealtimeSizeBasedDefaultBatchSettings, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -110,11 +111,14 @@ pub struct ElasticsearchConfig {
     #[configurable(metadata(docs::examples = "_id"))]
     pub id_key: Option<String>,
 
-    /// The name of the pipeline to apply.
+    /// The name of the ingest pipeline to apply.
+    ///
+    /// When the name is templated, each document is sent with the pipeline rendered from its event.
     #[serde(default)]
     #[configurable(metadata(docs::advanced))]
     #[configurable(metadata(docs::examples = "pipeline-name"))]
-    pub pipeline: Option<String>,
+    #[configurable(metadata(docs::examples = "{{ pipeline }}"))]
+    pub pipeline: Option<Template>,
 
     #[serde(default)]
     #[configurable(derived)]
@@ -143,6 +147,10 @@ pub struct ElasticsearchConfig {
     #[configurable(derived)]
     pub auth: Option<ElasticsearchAuth>,
 
+    #[serde(default)]
+    #[configurable(derived)]
+    pub opensearch_service_type: OpenSearchServiceType,
+
     /// Custom parameters to add to the query string for each HTTP request sent to Elasticsearch.
     #[serde(default)]
     #[configurable(metadata(docs::advanced))]
@@ -213,6 +221,7 @@ impl Default for ElasticsearchConfig {
             batch: Default::default(),
             request: Default::default(),
             auth: None,
+            opensearch_service_type: Default::default(),
             query: None,
             aws: None,
             tls: None,
@@ -471,7 +480,11 @@ impl SinkConfig for ElasticsearchConfig {
         let commons = ElasticsearchCommon::parse_many(self, cx.proxy()).await?;
         let common = commons[0].clone();
 
-        let client = HttpClient::new(common.tls_settings.clone(), cx.proxy())?;
+        let client = HttpClient::new_with_options(
+            common.tls_settings.clone(),
+            cx.proxy(),
+            cx.http_client(),
+        )?;
 
         let request_limits = self
             .request
@@ -493,14 +506,16 @@ impl SinkConfig for ElasticsearchConfig {
             })
             .collect::<Vec<_>>();
 
-        let service = request_limits.distributed_service(
+        let bandwidth_limit = ServiceBuilder::new().bandwidth_limit(&request_limits);
+        let service = bandwidth_limit.service(request_limits.distributed_service(
             ElasticsearchRetryLogic {
                 retry_partial: self.request_retry_partial,
+                service_type: self.opensearch_service_type,
             },
             services,
             health_config,
             ElasticsearchHealthLogic,
-        );
+        ));
 
         let sink = ElasticsearchSink::new(&common, self, service)?;
 
@@ -605,6 +620,33 @@ mod tests {
         assert_eq!(config.api_version, ElasticsearchApiVersion::Auto);
     }
 
+    #[test]
+    fn parse_opensearch_service_type() {
+        let config = toml::from_str::<ElasticsearchConfig>(
+            r#"
+            endpoints = [""]
+            opensearch_service_type = "serverless"
+            auth.strategy = "aws"
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.opensearch_service_type,
+            OpenSearchServiceType::Serverless
+        );
+
+        let config = toml::from_str::<ElasticsearchConfig>(
+            r#"
+            endpoints = [""]
+        "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.opensearch_service_type,
+            OpenSearchServiceType::Managed
+        );
+    }
+
     #[test]
     fn parse_default_bulk() {
         let config = toml::from_str::<ElasticsearchConfig>(
@@ -0,0 +1,155 @@
+//! Rejection of the documents that Elasticsearch rejects because of mapping conflicts.
+//!
+//! Such documents can't be indexed by retrying them, so their events are rejected one by one, which
+//! sends them to the dead-letter output of the sink when it's enabled, while the other documents of
+//! the request can still be acknowledged as delivered.
+
+use super::retry::EsResultResponse;
+use crate::event::{EventFinalizers, EventStatus};
+
+/// The types of the errors of documents that don't match the mapping of their index.
+const MAPPING_ERROR_TYPES: &[&str] = &[
+    "mapper_parsing_exception",
+    "document_parsing_exception",
+    "strict_dynamic_mapping_exception",
+];
+
+/// A document of a bulk request, as it was sent.
+#[derive(Clone, Debug)]
+pub struct BulkDocument {
+    pub finalizers: EventFinalizers,
+    pub byte_size: usize,
+}
+
+/// The documents of a bulk request that were rejected because of mapping conflicts.
+#[derive(Debug)]
+pub struct MappingConflicts {
+    pub count: usize,
+    pub byte_size: usize,
+
+    /// The error of the first of the documents.
+    pub error: String,
+
+    /// Whether these are all the documents of the request that were rejected.
+    pub complete: bool,
+}
+
+/// Rejects the events of the documents that were rejected because of mapping conflicts,
+/// according to the body of the response to their bulk request.
+///
+/// The status of the events can't be changed afterwards, so retrying the request doesn't deliver
+/// them, or send them to the dead-letter output more than once.
+pub fn reject_mapping_conflicts(
+    body: &[u8],
+    documents: &[BulkDocument],
+) -> Option<MappingConflicts> {
+    let response = EsResultResponse::parse(&String::from_utf8_lossy(body)).ok()?;
+
+    let mut conflicts: Option<MappingConflicts> = None;
+    let mut complete = response.items.len() == documents.len();
+    // Items of the response are in the same order as the documents of the request.
+    for (item, document) in response.items.iter().zip(documents) {
+        let Some(error) = &item.result().error else {
+            continue;
+        };
+        if !is_mapping_conflict(&error.err_type, &error.reason) {
+            complete = false;
+            continue;
+        }
+
+        document.finalizers.update_status(EventStatus::Rejected);
+        let conflicts = conflicts.get_or_insert_with(|| MappingConflicts {
+            count: 0,
+            byte_size: 0,
+            error: format!("error type: {}, reason: {}", error.err_type, error.reason),
+            complete: false,
+        });
+        conflicts.count += 1;
+        conflicts.byte_size += document.byte_size;
+    }
+
+    conflicts.map(|conflicts| MappingConflicts {
+        complete,
+        ..conflicts
+    })
+}
+
+fn is_mapping_conflict(err_type: &str, reason: &str) -> bool {
+    MAPPING_ERROR_TYPES.contains(&err_type)
+        // Such as "mapper [message] cannot be changed from type [long] to [text]".
+        || (err_type == "illegal_argument_exception" && reason.starts_with("mapper ["))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{BatchNotifier, BatchStatus, BatchStatusReceiver, EventFinalizer};
+
+    fn documents() -> (Vec<BulkDocument>, Vec<BatchStatusReceiver>) {
+        (0..3)
+            .map(|_| {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                let document = BulkDocument {
+                    finalizers: EventFinalizers::new(EventFinalizer::new(batch)),
+                    byte_size: 10,
+                };
+                (document, receiver)
+            })
+            .unzip()
+    }
+
+    #[tokio::test]
+    async fn rejects_mapping_conflicts() {
+        let body = r#"{"took":3,"errors":true,"items":[
+            {"create":{"_index":"logs","status":201}},
+            {"create":{"_index":"logs","status":400,"error":{"type":"mapper_parsing_exception","reason":"failed to parse field [n] of type [long]"}}},
+            {"create":{"_index":"logs","status":201}}
+        ]}"#;
+        let (documents, receivers) = documents();
+
+        let conflicts = reject_mapping_conflicts(body.as_bytes(), &documents).unwrap();
+        assert_eq!(conflicts.count, 1);
+        assert_eq!(conflicts.byte_size, 10);
+        assert!(conflicts.complete);
+        assert_eq!(
+            conflicts.error,
+            "error type: mapper_parsing_exception, reason: failed to parse field [n] of type [long]"
+        );
+
+        // The driver then updates all of the events of the request with the status of its response.
+        for document in documents {
+            document.finalizers.update_status(EventStatus::Delivered);
+        }
+        let mut statuses = Vec::new();
+        for receiver in receivers {
+            statuses.push(receiver.await);
+        }
+        assert_eq!(
+            statuses,
+            vec![
+                BatchStatus::Delivered,
+                BatchStatus::Rejected,
+                BatchStatus::Delivered
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_other_rejections() {
+        let body = r#"{"took":3,"errors":true,"items":[
+            {"index":{"_index":"logs","status":400,"error":{"type":"illegal_argument_exception","reason":"mapper [n] cannot be changed from type [long] to [text]"}}},
+            {"index":{"_index":"logs","status":429,"error":{"type":"es_rejected_execution_exception","reason":"rejected execution"}}},
+            {"index":{"_index":"logs","status":201}}
+        ]}"#;
+        let (documents, _receivers) = documents();
+
+        let conflicts = reject_mapping_conflicts(body.as_bytes(), &documents).unwrap();
+        assert_eq!(conflicts.count, 1);
+        assert!(!conflicts.complete);
+
+        let body = r#"{"took":3,"errors":true,"items":[
+            {"index":{"_index":"logs","status":429,"error":{"type":"es_rejected_execution_exception","reason":"rejected execution"}}}
+        ]}"#;
+        assert!(reject_mapping_conflicts(body.as_bytes(), &documents).is_none());
+    }
+}
@@ -19,6 +19,7 @@ pub struct ProcessedEvent {
     pub bulk_action: BulkAction,
     pub log: LogEvent,
     pub id: Option<String>,
+    pub pipeline: Option<String>,
 }
 
 impl Finalizable for ProcessedEvent {
@@ -29,7 +30,10 @@ impl Finalizable for ProcessedEvent {
 
 impl ByteSizeOf for ProcessedEvent {
     fn allocated_bytes(&self) -> usize {
-        self.index.allocated_bytes() + self.log.allocated_bytes() + self.id.allocated_bytes()
+        self.index.allocated_bytes()
+            + self.log.allocated_bytes()
+            + self.id.allocated_bytes()
+            + self.pipeline.allocated_bytes()
     }
 }
 
@@ -73,6 +77,7 @@ impl Encoder<Vec<ProcessedEvent>> for ElasticsearchEncoder {
                 &self.doc_type,
                 self.suppress_type_name,
                 &event.id,
+                &event.pipeline,
             )?;
             written_bytes +=
                 as_tracked_write::<_, _, io::Error>(writer, &log, |mut writer, log| {
@@ -93,35 +98,23 @@ fn write_bulk_action(
     doc_type: &str,
     suppress_type: bool,
     id: &Option<String>,
+    pipeline: &Option<String>,
 ) -> std::io::Result<usize> {
     as_tracked_write(
         writer,
-        (bulk_action, index, doc_type, id, suppress_type),
-        |writer, (bulk_action, index, doc_type, id, suppress_type)| match (id, suppress_type) {
-            (Some(id), true) => {
-                write!(
-                    writer,
-                    r#"{{"{}":{{"_index":"{}","_id":"{}"}}}}"#,
-                    bulk_action, index, id
-                )
+        (bulk_action, index, doc_type, id, suppress_type, pipeline),
+        |writer, (bulk_action, index, doc_type, id, suppress_type, pipeline)| {
+            write!(writer, r#"{{"{}":{{"_index":"{}""#, bulk_action, index)?;
+            if !suppress_type {
+                write!(writer, r#","_type":"{}""#, doc_type)?;
             }
-            (Some(id), false) => {
-                write!(
-                    writer,
-                    r#"{{"{}":{{"_index":"{}","_type":"{}","_id":"{}"}}}}"#,
-                    bulk_action, index, doc_type, id
-                )
+            if let Some(id) = id {
+                write!(writer, r#","_id":"{}""#, id)?;
             }
-            (None, true) => {
-                write!(writer, r#"{{"{}":{{"_index":"{}"}}}}"#, bulk_action, index)
-            }
-            (None, false) => {
-                write!(
-                    writer,
-                    r#"{{"{}":{{"_index":"{}","_type":"{}"}}}}"#,
-                    bulk_action, index, doc_type
-                )
+            if let Some(pipeline) = pipeline {
+                write!(writer, r#","pipeline":"{}""#, pipeline)?;
             }
+            write!(writer, "}}}}")
         },
     )
 }
@@ -141,6 +134,7 @@ mod tests {
             "TYPE",
             true,
             &Some("ID".to_string()),
+            &None,
         );
 
         let value: serde_json::Value = serde_json::from_slice(&writer).unwrap();
@@ -162,7 +156,7 @@ mod tests {
     fn suppress_type_without_id() {
         let mut writer = Vec::new();
 
-        _ = write_bulk_action(&mut writer, "ACTION", "INDEX", "TYPE", true, &None);
+        _ = write_bulk_action(&mut writer, "ACTION", "INDEX", "TYPE", true, &None, &None);
 
         let value: serde_json::Value = serde_json::from_slice(&writer).unwrap();
         let value = value.as_object().unwrap();
@@ -189,6 +183,7 @@ mod tests {
             "TYPE",
             false,
             &Some("ID".to_string()),
+            &None,
         );
 
         let value: serde_json::Value = serde_json::from_slice(&writer).unwrap();
@@ -211,7 +206,7 @@ mod tests {
     fn type_without_id() {
         let mut writer = Vec::new();
 
-        _ = write_bulk_action(&mut writer, "ACTION", "INDEX", "TYPE", false, &None);
+        _ = write_bulk_action(&mut writer, "ACTION", "INDEX", "TYPE", false, &None, &None);
 
         let value: serde_json::Value = serde_json::from_slice(&writer).unwrap();
         let value = value.as_object().unwrap();
@@ -227,4 +222,26 @@ mod tests {
         assert!(nested.contains_key("_type"));
         assert_eq!(nested.get("_type").unwrap().as_str(), Some("TYPE"));
     }
+
+    #[test]
+    fn with_pipeline() {
+        let mut writer = Vec::new();
+
+        _ = write_bulk_action(
+            &mut writer,
+            "ACTION",
+            "INDEX",
+            "TYPE",
+            true,
+            &None,
+            &Some("PIPELINE".to_string()),
+        );
+
+        let value: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+        let nested = value.get("ACTION").unwrap().as_object().unwrap();
+
+        assert_eq!(nested.get("_index").unwrap().as_str(), Some("INDEX"));
+        assert_eq!(nested.get("pipeline").unwrap().as_str(), Some("PIPELINE"));
+        assert!(!nested.contains_key("_id"));
+    }
 }
@@ -65,7 +65,13 @@ impl ElasticsearchCommon {
         let mut request = builder.body(Bytes::new())?;
 
         if let Some(credentials_provider) = &self.aws_auth {
-            sign_request(&mut request, credentials_provider, &self.region).await?;
+            sign_request(
+                &mut request,
+                credentials_provider,
+                &self.region,
+                self.service_type,
+            )
+            .await?;
         }
 
         let proxy = ProxyConfig::default();
@@ -115,7 +121,7 @@ async fn ensure_pipeline_in_params() {
             index,
             ..Default::default()
         },
-        pipeline: Some(pipeline.clone()),
+        pipeline: Some(Template::try_from(pipeline.as_str()).unwrap()),
         batch: batch_settings(),
         ..Default::default()
     };
@@ -212,6 +218,66 @@ async fn structures_events_correctly() {
     assert_eq!(&expected, value);
 }
 
+#[tokio::test]
+async fn rejects_mapping_conflicts_on_their_own() {
+    trace_init();
+
+    let index = gen_index();
+    let config = ElasticsearchConfig {
+        endpoints: vec![http_server()],
+        bulk: BulkConfig {
+            index: index.clone(),
+            ..Default::default()
+        },
+        doc_type: "log_lines".to_string(),
+        compression: Compression::None,
+        batch: batch_settings(),
+        ..Default::default()
+    };
+    let common = ElasticsearchCommon::parse_single(&config)
+        .await
+        .expect("Config error");
+    let base_url = common.base_url.clone();
+
+    let cx = SinkContext::new_test();
+    let (sink, _hc) = config.build(cx.clone()).await.unwrap();
+
+    // The second event conflicts with the mapping of `count` as a number by the first one.
+    let mut events = Vec::new();
+    let mut receivers = Vec::new();
+    for count in [json!(1), json!({"nested": true})] {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let mut event = LogEvent::from("raw log line").with_batch_notifier(&batch);
+        event.insert("count", count);
+        events.push(Event::from(event));
+        receivers.push(receiver);
+    }
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+    assert_eq!(receivers[0].try_recv(), Ok(BatchStatus::Delivered));
+    assert_eq!(receivers[1].try_recv(), Ok(BatchStatus::Rejected));
+
+    flush(common).await.unwrap();
+
+    let response = reqwest::Client::new()
+        .get(&format!("{}/{}/_search", base_url, index))
+        .json(&json!({
+            "query": { "query_string": { "query": "*" } }
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json::<Value>()
+        .await
+        .unwrap();
+
+    let hits = response["hits"]["hits"]
+        .as_array()
+        .expect("Elasticsearch response does not include hits->hits");
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0]["_source"]["count"], json!(1));
+}
+
 #[tokio::test]
 async fn auto_version_http() {
     trace_init();
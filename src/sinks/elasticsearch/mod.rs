@@ -1,5 +1,6 @@
 mod common;
 mod config;
+mod dead_letter;
 mod encoder;
 mod health;
 mod request_builder;
@@ -199,6 +200,38 @@ impl Default for ElasticsearchApiVersion {
     }
 }
 
+/// Amazon OpenSearch service type.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum OpenSearchServiceType {
+    /// Elasticsearch or an OpenSearch Managed domain.
+    Managed,
+
+    /// An OpenSearch Serverless collection.
+    ///
+    /// OpenSearch Serverless only supports the `aws` authentication strategy. It doesn't expose the
+    /// cluster APIs, so the API version is not detected and healthchecks are skipped.
+    Serverless,
+}
+
+impl Default for OpenSearchServiceType {
+    fn default() -> Self {
+        Self::Managed
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+impl OpenSearchServiceType {
+    /// The service name used when signing requests with SigV4.
+    pub const fn signing_service_name(&self) -> &'static str {
+        match self {
+            OpenSearchServiceType::Managed => "es",
+            OpenSearchServiceType::Serverless => "aoss",
+        }
+    }
+}
+
 #[derive(Debug, Snafu)]
 #[snafu(visibility(pub))]
 pub enum ParseError {
@@ -218,4 +251,14 @@ pub enum ParseError {
         "`endpoint` and `endpoints` options are mutually exclusive. Please use `endpoints` option."
     ))]
     EndpointsExclusive,
+    #[snafu(display(
+        "`auth.strategy` must be `aws` when `opensearch_service_type` is `serverless`"
+    ))]
+    ServerlessRequiresAwsAuth,
+    #[snafu(display(
+        "`api_version` must be `auto` or `v8` when `opensearch_service_type` is `serverless`"
+    ))]
+    ServerlessApiVersion,
+    #[snafu(display("`pipeline` is not supported by OpenSearch Serverless"))]
+    ServerlessPipeline,
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use bytes::Bytes;
 use vector_common::request_metadata::RequestMetadata;
 use vector_core::ByteSizeOf;
@@ -6,6 +8,7 @@ use crate::{
     event::{EventFinalizers, Finalizable},
     sinks::{
         elasticsearch::{
+            dead_letter::BulkDocument,
             encoder::{ElasticsearchEncoder, ProcessedEvent},
             service::ElasticsearchRequest,
         },
@@ -26,6 +29,7 @@ pub struct Metadata {
     finalizers: EventFinalizers,
     batch_size: usize,
     events_byte_size: usize,
+    documents: Arc<Vec<BulkDocument>>,
 }
 
 impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
@@ -56,10 +60,23 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
 
         let metadata_builder = RequestMetadataBuilder::from_events(&events);
 
+        // The finalizers of each document are kept, so that the documents rejected because of
+        // mapping conflicts can be rejected on their own.
+        let documents = Arc::new(
+            events
+                .iter()
+                .map(|event| BulkDocument {
+                    finalizers: event.log.metadata().finalizers().clone(),
+                    byte_size: event.log.size_of(),
+                })
+                .collect(),
+        );
+
         let es_metadata = Metadata {
             finalizers: events.take_finalizers(),
             batch_size: events.len(),
             events_byte_size,
+            documents,
         };
         (es_metadata, metadata_builder, events)
     }
@@ -76,6 +93,7 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
             batch_size: es_metadata.batch_size,
             events_byte_size: es_metadata.events_byte_size,
             metadata,
+            documents: es_metadata.documents,
         }
     }
 }
@@ -1,21 +1,27 @@
+use std::time::Duration;
+
 use http::StatusCode;
 use serde::Deserialize;
 
 use crate::{
+    event::EventStatus,
     http::HttpError,
     sinks::{
-        elasticsearch::service::ElasticsearchResponse,
-        util::retries::{RetryAction, RetryLogic},
+        elasticsearch::{service::ElasticsearchResponse, OpenSearchServiceType},
+        util::{
+            http::retry_after,
+            retries::{RetryAction, RetryLogic},
+        },
     },
 };
 
 #[derive(Deserialize, Debug)]
-struct EsResultResponse {
-    items: Vec<EsResultItem>,
+pub(super) struct EsResultResponse {
+    pub(super) items: Vec<EsResultItem>,
 }
 
 impl EsResultResponse {
-    fn parse(body: &str) -> Result<Self, String> {
+    pub(super) fn parse(body: &str) -> Result<Self, String> {
         serde_json::from_str::<EsResultResponse>(body).map_err(|json_error| {
             format!(
                 "some messages failed, could not parse response, error: {}",
@@ -51,7 +57,7 @@ impl EsResultResponse {
 }
 
 #[derive(Deserialize, Debug)]
-enum EsResultItem {
+pub(super) enum EsResultItem {
     #[serde(rename = "index")]
     Index(EsIndexResult),
     #[serde(rename = "create")]
@@ -60,7 +66,7 @@ enum EsResultItem {
 
 impl EsResultItem {
     #[allow(clippy::missing_const_for_fn)] // const cannot run destructor
-    fn result(&self) -> &EsIndexResult {
+    pub(super) fn result(&self) -> &EsIndexResult {
         match self {
             EsResultItem::Index(r) => r,
             EsResultItem::Create(r) => r,
@@ -69,21 +75,22 @@ impl EsResultItem {
 }
 
 #[derive(Deserialize, Debug)]
-struct EsIndexResult {
+pub(super) struct EsIndexResult {
     status: Option<u16>,
-    error: Option<EsErrorDetails>,
+    pub(super) error: Option<EsErrorDetails>,
 }
 
 #[derive(Deserialize, Debug)]
-struct EsErrorDetails {
-    reason: String,
+pub(super) struct EsErrorDetails {
+    pub(super) reason: String,
     #[serde(rename = "type")]
-    err_type: String,
+    pub(super) err_type: String,
 }
 
 #[derive(Clone)]
 pub struct ElasticsearchRetryLogic {
     pub retry_partial: bool,
+    pub service_type: OpenSearchServiceType,
 }
 
 impl RetryLogic for ElasticsearchRetryLogic {
@@ -110,10 +117,28 @@ impl RetryLogic for ElasticsearchRetryLogic {
                 )
                 .into(),
             ),
+            StatusCode::FORBIDDEN if self.service_type == OpenSearchServiceType::Serverless => {
+                // OpenSearch Serverless answers requests that aren't allowed by the collection's
+                // data access policy with a bare `403 Forbidden`, which gives no hint as to why.
+                let body = String::from_utf8_lossy(response.http_response.body());
+                RetryAction::DontRetry(
+                    format!(
+                        "forbidden, {}: {}. Check that the data access policy of the collection \
+                         grants `aoss:WriteDocument` and `aoss:CreateIndex` to the IAM principal \
+                         used by Vector. Policy changes can take a few minutes to take effect.",
+                        status, body
+                    )
+                    .into(),
+                )
+            }
             _ if status.is_client_error() => {
                 let body = String::from_utf8_lossy(response.http_response.body());
                 RetryAction::DontRetry(format!("client-side error, {}: {}", status, body).into())
             }
+            // The documents that failed were all rejected because of mapping conflicts.
+            _ if status.is_success() && response.event_status == EventStatus::Delivered => {
+                RetryAction::Successful
+            }
             _ if status.is_success() => {
                 let body = String::from_utf8_lossy(response.http_response.body());
 
@@ -153,6 +178,10 @@ impl RetryLogic for ElasticsearchRetryLogic {
             _ => RetryAction::DontRetry(format!("response status: {}", status).into()),
         }
     }
+
+    fn retry_after(&self, response: &ElasticsearchResponse) -> Option<Duration> {
+        retry_after(&response.http_response)
+    }
 }
 
 #[cfg(test)]
@@ -162,7 +191,6 @@ mod tests {
     use similar_asserts::assert_eq;
 
     use super::*;
-    use crate::event::EventStatus;
 
     #[test]
     fn handles_error_response() {
@@ -173,6 +201,7 @@ mod tests {
             .unwrap();
         let logic = ElasticsearchRetryLogic {
             retry_partial: false,
+            service_type: OpenSearchServiceType::Managed,
         };
         assert!(matches!(
             logic.should_retry_response(&ElasticsearchResponse {
@@ -194,6 +223,7 @@ mod tests {
             .unwrap();
         let logic = ElasticsearchRetryLogic {
             retry_partial: true,
+            service_type: OpenSearchServiceType::Managed,
         };
         assert!(matches!(
             logic.should_retry_response(&ElasticsearchResponse {
@@ -206,6 +236,70 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn explains_serverless_forbidden_response() {
+        let json = "{\"status\":403,\"request-id\":\"f1d0a2c9-7b0e-4f3a-9d43-5a0e2b7c1e55\",\"error\":{\"reason\":\"403 Forbidden\",\"type\":\"Forbidden\"}}";
+        let response = Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Bytes::from(json))
+            .unwrap();
+        let logic = ElasticsearchRetryLogic {
+            retry_partial: false,
+            service_type: OpenSearchServiceType::Serverless,
+        };
+        match logic.should_retry_response(&ElasticsearchResponse {
+            http_response: response,
+            event_status: EventStatus::Rejected,
+            batch_size: 1,
+            events_byte_size: 1,
+        }) {
+            RetryAction::DontRetry(reason) => assert!(reason.contains("data access policy")),
+            _ => panic!("forbidden responses should not be retried"),
+        }
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let response = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("Retry-After", "30")
+            .body(Bytes::new())
+            .unwrap();
+        let logic = ElasticsearchRetryLogic {
+            retry_partial: false,
+            service_type: OpenSearchServiceType::Managed,
+        };
+        let response = ElasticsearchResponse {
+            http_response: response,
+            event_status: EventStatus::Errored,
+            batch_size: 1,
+            events_byte_size: 1,
+        };
+        assert!(logic.should_retry_response(&response).is_retryable());
+        assert_eq!(logic.retry_after(&response), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn succeeds_when_only_mapping_conflicts_were_rejected() {
+        let json = "{\"took\":185,\"errors\":true,\"items\":[{\"index\":{\"_index\":\"test-hgw28jv10u\",\"_id\":\"3GhQLXEBE62DvOOUKdFH\",\"status\":400,\"error\":{\"type\":\"mapper_parsing_exception\",\"reason\":\"failed to parse field [count] of type [long]\"}}}]}";
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .body(Bytes::from(json))
+            .unwrap();
+        let logic = ElasticsearchRetryLogic {
+            retry_partial: false,
+            service_type: OpenSearchServiceType::Managed,
+        };
+        assert!(logic
+            .should_retry_response(&ElasticsearchResponse {
+                http_response: response,
+                event_status: EventStatus::Delivered,
+                batch_size: 1,
+                events_byte_size: 1,
+            })
+            .is_successful());
+    }
+
     #[test]
     fn get_index_error_reason() {
         let json = "{\"took\":185,\"errors\":true,\"items\":[{\"index\":{\"_index\":\"test-hgw28jv10u\",\"_type\":\"log_lines\",\"_id\":\"3GhQLXEBE62DvOOUKdFH\",\"status\":400,\"error\":{\"type\":\"illegal_argument_exception\",\"reason\":\"mapper [message] of different type, current_type [long], merged_type [text]\"}}}]}";
@@ -18,13 +18,17 @@ use crate::sinks::elasticsearch::sign_request;
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
     http::{Auth, HttpClient},
+    internal_events::ElasticsearchMappingConflictError,
     sinks::util::{
         http::{HttpBatchService, RequestConfig},
         Compression, ElementCount,
     },
 };
 
-use super::{ElasticsearchCommon, ElasticsearchConfig};
+use super::{
+    dead_letter::{reject_mapping_conflicts, BulkDocument},
+    ElasticsearchCommon, ElasticsearchConfig, OpenSearchServiceType,
+};
 
 #[derive(Clone, Debug)]
 pub struct ElasticsearchRequest {
@@ -33,6 +37,7 @@ pub struct ElasticsearchRequest {
     pub batch_size: usize,
     pub events_byte_size: usize,
     pub metadata: RequestMetadata,
+    pub documents: Arc<Vec<BulkDocument>>,
 }
 
 impl ByteSizeOf for ElasticsearchRequest {
@@ -91,6 +96,7 @@ pub struct HttpRequestBuilder {
     pub http_request_config: RequestConfig,
     pub http_auth: Option<Auth>,
     pub credentials_provider: Option<SharedCredentialsProvider>,
+    pub service_type: OpenSearchServiceType,
 }
 
 impl HttpRequestBuilder {
@@ -103,6 +109,7 @@ impl HttpRequestBuilder {
             region: common.region.clone(),
             compression: config.compression,
             credentials_provider: common.aws_auth.clone(),
+            service_type: common.service_type,
         }
     }
 
@@ -135,7 +142,13 @@ impl HttpRequestBuilder {
             .expect("Invalid http request value used");
 
         if let Some(credentials_provider) = &self.credentials_provider {
-            sign_request(&mut request, credentials_provider, &self.region).await?;
+            sign_request(
+                &mut request,
+                credentials_provider,
+                &self.region,
+                self.service_type,
+            )
+            .await?;
         }
 
         Ok(request)
@@ -174,11 +187,29 @@ impl Service<ElasticsearchRequest> for ElasticsearchService {
         let mut http_service = self.batch_service.clone();
         Box::pin(async move {
             http_service.ready().await?;
-            let batch_size = req.batch_size;
-            let events_byte_size = req.events_byte_size;
+            let mut batch_size = req.batch_size;
+            let mut events_byte_size = req.events_byte_size;
+            let documents = Arc::clone(&req.documents);
             let http_response = http_service.call(req).await?;
 
-            let event_status = get_event_status(&http_response);
+            let mut event_status = get_event_status(&http_response);
+            if event_status == EventStatus::Rejected && http_response.status().is_success() {
+                if let Some(conflicts) = reject_mapping_conflicts(http_response.body(), &documents)
+                {
+                    // The other documents were delivered, and the events of the rejected ones are
+                    // sent to the dead-letter output of the sink, if any.
+                    if conflicts.complete {
+                        emit!(ElasticsearchMappingConflictError {
+                            error: &conflicts.error,
+                            count: conflicts.count,
+                        });
+                        event_status = EventStatus::Delivered;
+                        batch_size -= conflicts.count;
+                        events_byte_size = events_byte_size.saturating_sub(conflicts.byte_size);
+                    }
+                }
+            }
+
             Ok(ElasticsearchResponse {
                 event_status,
                 http_response,
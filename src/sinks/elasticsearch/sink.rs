@@ -8,7 +8,7 @@ use vector_core::stream::{BatcherSettings, DriverResponse};
 use crate::{
     codecs::Transformer,
     event::{Event, LogEvent, Value},
-    internal_events::SinkRequestBuildError,
+    internal_events::{SinkRequestBuildError, TemplateRenderingError},
     sinks::{
         elasticsearch::{
             encoder::ProcessedEvent, request_builder::ElasticsearchRequestBuilder,
@@ -16,6 +16,7 @@ use crate::{
         },
         util::{SinkBuilderExt, StreamSink},
     },
+    template::Template,
     transforms::metric_to_log::MetricToLog,
 };
 
@@ -35,6 +36,7 @@ pub struct ElasticsearchSink<S> {
     pub metric_to_log: MetricToLog,
     pub mode: ElasticsearchCommonMode,
     pub id_key_field: Option<String>,
+    pub pipeline: Option<Template>,
 }
 
 impl<S> ElasticsearchSink<S> {
@@ -53,6 +55,7 @@ impl<S> ElasticsearchSink<S> {
             metric_to_log: common.metric_to_log.clone(),
             mode: common.mode.clone(),
             id_key_field: config.id_key.clone(),
+            pipeline: common.pipeline.clone(),
         })
     }
 }
@@ -69,6 +72,7 @@ where
 
         let mode = self.mode;
         let id_key_field = self.id_key_field;
+        let pipeline = self.pipeline;
         let transformer = self.transformer.clone();
 
         input
@@ -86,7 +90,13 @@ where
             })
             .filter_map(|x| async move { x })
             .filter_map(move |log| {
-                future::ready(process_log(log, &mode, &id_key_field, &transformer))
+                future::ready(process_log(
+                    log,
+                    &mode,
+                    &id_key_field,
+                    &pipeline,
+                    &transformer,
+                ))
             })
             .batched(self.batch_settings.into_byte_size_config())
             .request_builder(request_builder_concurrency_limit, self.request_builder)
@@ -111,10 +121,26 @@ pub(super) fn process_log(
     mut log: LogEvent,
     mode: &ElasticsearchCommonMode,
     id_key_field: &Option<String>,
+    pipeline: &Option<Template>,
     transformer: &Transformer,
 ) -> Option<ProcessedEvent> {
     let index = mode.index(&log)?;
     let bulk_action = mode.bulk_action(&log)?;
+    let pipeline = match pipeline {
+        Some(pipeline) => Some(
+            pipeline
+                .render_string(&log)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("pipeline"),
+                        drop_event: true,
+                    });
+                })
+                .ok()?,
+        ),
+        None => None,
+    };
 
     if let Some(cfg) = mode.as_data_stream_config() {
         cfg.sync_fields(&mut log);
@@ -138,6 +164,7 @@ pub(super) fn process_log(
         bulk_action,
         log,
         id,
+        pipeline,
     })
 }
 
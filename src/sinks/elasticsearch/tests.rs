@@ -1,12 +1,14 @@
 use std::{collections::BTreeMap, convert::TryFrom};
 
 use crate::{
+    aws::{AwsAuthentication, RegionOrEndpoint},
     codecs::Transformer,
     event::{LogEvent, Metric, MetricKind, MetricValue, Value},
     sinks::{
         elasticsearch::{
             sink::process_log, BulkAction, BulkConfig, DataStreamConfig, ElasticsearchApiVersion,
-            ElasticsearchCommon, ElasticsearchConfig, ElasticsearchMode,
+            ElasticsearchAuth, ElasticsearchCommon, ElasticsearchConfig, ElasticsearchMode,
+            OpenSearchServiceType,
         },
         util::encoding::Encoder,
     },
@@ -53,7 +55,7 @@ async fn sets_create_action_when_configured() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -107,7 +109,7 @@ async fn encode_datastream_mode() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -158,7 +160,7 @@ async fn encode_datastream_mode_no_routing() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -194,7 +196,7 @@ async fn handle_metrics() {
     es.request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -244,6 +246,64 @@ async fn default_bulk_settings() {
     assert!(ElasticsearchCommon::parse_single(&config).await.is_ok());
 }
 
+fn aws_access_key_auth() -> ElasticsearchAuth {
+    ElasticsearchAuth::Aws(AwsAuthentication::AccessKey {
+        access_key_id: "dummy".to_string().into(),
+        secret_access_key: "dummy".to_string().into(),
+        assume_role: None,
+        external_id: None,
+        region: None,
+        sts_endpoint: None,
+    })
+}
+
+#[tokio::test]
+async fn serverless_skips_version_detection() {
+    let config = ElasticsearchConfig {
+        endpoints: vec![String::from("https://example.us-east-1.aoss.amazonaws.com")],
+        opensearch_service_type: OpenSearchServiceType::Serverless,
+        aws: Some(RegionOrEndpoint::with_region(String::from("us-east-1"))),
+        auth: Some(aws_access_key_auth()),
+        ..Default::default()
+    };
+    let es = ElasticsearchCommon::parse_single(&config).await.unwrap();
+
+    assert_eq!(es.service_type, OpenSearchServiceType::Serverless);
+    assert!(es.request_builder.encoder.suppress_type_name);
+}
+
+#[tokio::test]
+async fn serverless_rejects_unsupported_settings() {
+    // Only SigV4 authentication is supported.
+    let config = ElasticsearchConfig {
+        endpoints: vec![String::from("https://example.us-east-1.aoss.amazonaws.com")],
+        opensearch_service_type: OpenSearchServiceType::Serverless,
+        aws: Some(RegionOrEndpoint::with_region(String::from("us-east-1"))),
+        ..Default::default()
+    };
+    assert!(ElasticsearchCommon::parse_single(&config).await.is_err());
+
+    let config = ElasticsearchConfig {
+        endpoints: vec![String::from("https://example.us-east-1.aoss.amazonaws.com")],
+        opensearch_service_type: OpenSearchServiceType::Serverless,
+        aws: Some(RegionOrEndpoint::with_region(String::from("us-east-1"))),
+        auth: Some(aws_access_key_auth()),
+        api_version: ElasticsearchApiVersion::V7,
+        ..Default::default()
+    };
+    assert!(ElasticsearchCommon::parse_single(&config).await.is_err());
+
+    let config = ElasticsearchConfig {
+        endpoints: vec![String::from("https://example.us-east-1.aoss.amazonaws.com")],
+        opensearch_service_type: OpenSearchServiceType::Serverless,
+        aws: Some(RegionOrEndpoint::with_region(String::from("us-east-1"))),
+        auth: Some(aws_access_key_auth()),
+        pipeline: Some(parse_template("pipeline-name")),
+        ..Default::default()
+    };
+    assert!(ElasticsearchCommon::parse_single(&config).await.is_err());
+}
+
 #[tokio::test]
 async fn decode_bulk_action() {
     let config = ElasticsearchConfig {
@@ -303,7 +363,7 @@ async fn encode_datastream_mode_no_sync() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -343,7 +403,7 @@ async fn allows_using_except_fields() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -378,7 +438,7 @@ async fn allows_using_only_fields() {
         .request_builder
         .encoder
         .encode_input(
-            vec![process_log(log, &es.mode, &None, &config.encoding).unwrap()],
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
             &mut encoded,
         )
         .unwrap();
@@ -389,3 +449,56 @@ async fn allows_using_only_fields() {
     assert_eq!(std::str::from_utf8(&encoded).unwrap(), expected);
     assert_eq!(encoded.len(), encoded_size);
 }
+
+#[tokio::test]
+async fn templated_pipeline_is_set_per_document() {
+    let config = ElasticsearchConfig {
+        bulk: BulkConfig {
+            action: parse_template("index"),
+            index: parse_template("vector"),
+        },
+        pipeline: Some(parse_template("{{ pipeline }}")),
+        endpoints: vec![String::from("https://example.com")],
+        api_version: ElasticsearchApiVersion::V7,
+        ..Default::default()
+    };
+    let es = ElasticsearchCommon::parse_single(&config).await.unwrap();
+    assert!(!es.query_params.contains_key("pipeline"));
+
+    let mut log = LogEvent::from("hello there");
+    log.insert("pipeline", "geoip");
+
+    let mut encoded = vec![];
+    es.request_builder
+        .encoder
+        .encode_input(
+            vec![process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).unwrap()],
+            &mut encoded,
+        )
+        .unwrap();
+
+    let action = String::from_utf8(encoded).unwrap();
+    let action = action.lines().next().unwrap();
+    assert_eq!(
+        action,
+        r#"{"index":{"_index":"vector","pipeline":"geoip"}}"#
+    );
+
+    // Events without a pipeline to render are dropped.
+    let log = LogEvent::from("hello there");
+    assert!(process_log(log, &es.mode, &None, &es.pipeline, &config.encoding).is_none());
+}
+
+#[tokio::test]
+async fn static_pipeline_is_a_query_parameter() {
+    let config = ElasticsearchConfig {
+        pipeline: Some(parse_template("geoip")),
+        endpoints: vec![String::from("https://example.com")],
+        api_version: ElasticsearchApiVersion::V7,
+        ..Default::default()
+    };
+    let es = ElasticsearchCommon::parse_single(&config).await.unwrap();
+
+    assert_eq!(es.query_params["pipeline"], "geoip");
+    assert!(es.pipeline.is_none());
+}
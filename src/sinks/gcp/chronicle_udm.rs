@@ -0,0 +1,606 @@
+//! This sink maps log events to Google Chronicle UDM events, and sends them to the UDM events
+//! endpoint. See <https://cloud.google.com/chronicle/docs/reference/ingestion-api#udmevents>
+//! for more information.
+use std::{fmt, future::ready, io};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{stream::BoxStream, StreamExt};
+use goauth::scopes::Scope;
+use indexmap::IndexMap;
+use indoc::indoc;
+use serde_json::json;
+use tower::{Service, ServiceBuilder};
+use value::{Kind, Value};
+use vector_common::request_metadata::RequestMetadata;
+use vector_config::configurable_component;
+use vector_core::{
+    config::{AcknowledgementsConfig, Input},
+    event::{Event, EventFinalizers, EventStatus, Finalizable, LogEvent},
+    sink::{StreamSink, VectorSink},
+    stream::DriverResponse,
+};
+
+use super::chronicle_unstructured::{
+    build_healthcheck, create_endpoint, ChronicleRequest, ChronicleService, Region,
+};
+use crate::{
+    config::{GenerateConfig, SinkConfig, SinkContext},
+    gcp::{GcpAuthConfig, GcpAuthenticator},
+    http::HttpClient,
+    internal_events::{ChronicleUdmMappingError, TemplateRenderingError},
+    schema,
+    sinks::{
+        gcs_common::{config::GcsRetryLogic, sink::GcsSink},
+        util::{
+            encoding::{as_tracked_write, Encoder},
+            metadata::RequestMetadataBuilder,
+            partitioner::KeyPartitioner,
+            request_builder::EncodeResult,
+            BatchConfig, Compression, RequestBuilder, SinkBatchSettings, TowerRequestConfig,
+        },
+        Healthcheck,
+    },
+    template::Template,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// The UDM field the log type of an event is recorded in, and which the events are batched by.
+const LOG_TYPE_FIELD: &str = "metadata.log_type";
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChronicleUdmDefaultBatchSettings;
+
+// Chronicle Ingestion API has a 1MB limit[1] for UDM events, as for unstructured log entries.
+//
+// [1]: https://cloud.google.com/chronicle/docs/reference/ingestion-api#udmevents
+impl SinkBatchSettings for ChronicleUdmDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = None;
+    const MAX_BYTES: Option<usize> = Some(1_000_000);
+    const TIMEOUT_SECS: f64 = 15.0;
+}
+
+/// Configuration for the `gcp_chronicle_udm` sink.
+#[configurable_component(sink("gcp_chronicle_udm"))]
+#[derive(Clone, Debug)]
+pub struct ChronicleUdmConfig {
+    /// The endpoint to send data to.
+    #[configurable(metadata(
+        docs::examples = "127.0.0.1:8080",
+        docs::examples = "example.com:12345"
+    ))]
+    pub endpoint: Option<String>,
+
+    /// The GCP region to use.
+    #[configurable(derived)]
+    pub region: Option<Region>,
+
+    /// The Unique identifier (UUID) corresponding to the Chronicle instance.
+    #[configurable(validation(format = "uuid"))]
+    #[configurable(metadata(docs::examples = "c8c65bfa-5f2c-42d4-9189-64bb7b939f2c"))]
+    pub customer_id: String,
+
+    #[serde(flatten)]
+    pub auth: GcpAuthConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<ChronicleUdmDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    /// The log type of the events.
+    ///
+    /// The log type selects the mapping applied to each event, and events are batched per log
+    /// type. It is also recorded in the `metadata.log_type` field of the UDM events.
+    #[configurable(metadata(docs::examples = "OKTA", docs::examples = "{{ log_type }}"))]
+    pub log_type: Template,
+
+    /// The mapping of the events of each log type to UDM events.
+    ///
+    /// Events of a log type without a mapping are dropped.
+    #[configurable(metadata(
+        docs::additional_props_description = "The mapping of the events of a log type."
+    ))]
+    pub mappings: IndexMap<String, UdmMapping>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+/// The mapping of the events of a log type to UDM events.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct UdmMapping {
+    /// The [UDM event type][udm_event_types] of the events.
+    ///
+    /// The event type determines the UDM fields the events are required to have, in addition to
+    /// `required_fields`. For example, `NETWORK_CONNECTION` events require a `principal` and a
+    /// `target`.
+    ///
+    /// [udm_event_types]: https://cloud.google.com/chronicle/docs/reference/udm-field-list#metadataevent_type
+    #[configurable(metadata(
+        docs::examples = "NETWORK_CONNECTION",
+        docs::examples = "USER_LOGIN"
+    ))]
+    pub event_type: String,
+
+    /// The name of the product that generated the events.
+    #[configurable(metadata(docs::examples = "Okta"))]
+    pub product_name: Option<String>,
+
+    /// The name of the vendor of the product that generated the events.
+    #[configurable(metadata(docs::examples = "Okta"))]
+    pub vendor_name: Option<String>,
+
+    /// The UDM fields of the events, and the event fields they are taken from.
+    ///
+    /// Event fields that are missing or null are skipped. `metadata.event_timestamp` is taken from
+    /// the timestamp of the event, unless mapped here.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "The path of an event field, keyed by the UDM field it is mapped to."
+    ))]
+    #[configurable(metadata(docs::examples = "fields_examples()"))]
+    pub fields: IndexMap<String, String>,
+
+    /// Additional UDM fields the events are required to have.
+    ///
+    /// Events missing any of the required fields are dropped.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "principal.user.userid"))]
+    pub required_fields: Vec<String>,
+}
+
+fn fields_examples() -> IndexMap<String, String> {
+    IndexMap::from([
+        ("principal.ip".to_owned(), "client.ip".to_owned()),
+        ("principal.user.userid".to_owned(), "actor.id".to_owned()),
+        (
+            "target.user.email_addresses".to_owned(),
+            "target.email".to_owned(),
+        ),
+    ])
+}
+
+/// The UDM fields required by all events.
+const COMMON_REQUIRED_FIELDS: &[&str] = &["metadata.event_timestamp", "metadata.event_type"];
+
+/// The UDM fields required by the events of each event type.
+///
+/// See <https://cloud.google.com/chronicle/docs/unified-data-model/udm-usage#required_fields>.
+fn required_fields(event_type: &str) -> &'static [&'static str] {
+    match event_type {
+        "NETWORK_CONNECTION" | "NETWORK_DHCP" | "NETWORK_DNS" | "NETWORK_FLOW" | "NETWORK_HTTP"
+        | "NETWORK_FTP" | "NETWORK_SMTP" => &["principal", "target"],
+        "USER_LOGIN" | "USER_LOGOUT" => &["target.user"],
+        "USER_CREATION" | "USER_DELETION" | "USER_CHANGE_PASSWORD" | "USER_CHANGE_PERMISSIONS" => {
+            &["principal", "target.user"]
+        }
+        "PROCESS_LAUNCH" | "PROCESS_TERMINATION" | "PROCESS_OPEN" => {
+            &["principal", "target.process"]
+        }
+        "FILE_CREATION" | "FILE_DELETION" | "FILE_MODIFICATION" | "FILE_READ" | "FILE_OPEN"
+        | "FILE_COPY" | "FILE_MOVE" => &["principal", "target.file"],
+        "REGISTRY_CREATION" | "REGISTRY_DELETION" | "REGISTRY_MODIFICATION" => {
+            &["principal", "target.registry"]
+        }
+        "STATUS_HEARTBEAT" | "STATUS_STARTUP" | "STATUS_SHUTDOWN" | "STATUS_UPDATE" => {
+            &["principal"]
+        }
+        _ => &[],
+    }
+}
+
+impl UdmMapping {
+    /// Maps the event to a UDM event of the log type.
+    fn map(&self, log: &LogEvent, log_type: &str) -> Result<LogEvent, String> {
+        let mut udm = LogEvent::default();
+        udm.insert(LOG_TYPE_FIELD, log_type);
+        udm.insert("metadata.event_type", self.event_type.as_str());
+        if let Some(product_name) = &self.product_name {
+            udm.insert("metadata.product_name", product_name.as_str());
+        }
+        if let Some(vendor_name) = &self.vendor_name {
+            udm.insert("metadata.vendor_name", vendor_name.as_str());
+        }
+        if let Some(Value::Timestamp(timestamp)) = log.get_timestamp() {
+            udm.insert(
+                "metadata.event_timestamp",
+                timestamp
+                    .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+                    .as_str(),
+            );
+        }
+
+        for (udm_field, field) in &self.fields {
+            match log.get(field.as_str()) {
+                None | Some(Value::Null) => {}
+                Some(Value::Timestamp(timestamp)) => {
+                    udm.insert(
+                        udm_field.as_str(),
+                        timestamp
+                            .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+                            .as_str(),
+                    );
+                }
+                Some(value) => {
+                    udm.insert(udm_field.as_str(), value.clone());
+                }
+            }
+        }
+
+        let missing = COMMON_REQUIRED_FIELDS
+            .iter()
+            .copied()
+            .chain(required_fields(&self.event_type).iter().copied())
+            .chain(self.required_fields.iter().map(String::as_str))
+            .filter(|field| match udm.get(*field) {
+                None | Some(Value::Null) => true,
+                Some(Value::Object(object)) => object.is_empty(),
+                Some(Value::Array(array)) => array.is_empty(),
+                Some(_) => false,
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(format!(
+                "Missing required UDM fields for {} events: {}",
+                self.event_type,
+                missing.join(", ")
+            ));
+        }
+
+        Ok(udm)
+    }
+}
+
+impl GenerateConfig for ChronicleUdmConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(indoc! {r#"
+            credentials_path = "/path/to/credentials.json"
+            customer_id = "customer_id"
+            log_type = "log_type"
+
+            [mappings.log_type]
+            event_type = "GENERIC_EVENT"
+            fields."metadata.description" = "message"
+        "#})
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for ChronicleUdmConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let creds = self.auth.build(Scope::MalachiteIngestion).await?;
+
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
+
+        let endpoint = create_endpoint(
+            self.endpoint.as_deref(),
+            self.region,
+            "v2/udmevents:batchCreate",
+        )?;
+
+        // For the healthcheck we see if we can fetch the list of available log types.
+        let healthcheck_endpoint =
+            create_endpoint(self.endpoint.as_deref(), self.region, "v2/logtypes")?;
+
+        let healthcheck = build_healthcheck(client.clone(), &healthcheck_endpoint, creds.clone())?;
+        creds.spawn_regenerate_token();
+        let sink = self.build_sink(client, endpoint, creds)?;
+
+        Ok((sink, healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        let requirement =
+            schema::Requirement::empty().required_meaning("timestamp", Kind::timestamp());
+
+        Input::log().with_schema_requirement(requirement)
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+impl ChronicleUdmConfig {
+    fn build_sink(
+        &self,
+        client: HttpClient,
+        base_url: String,
+        creds: GcpAuthenticator,
+    ) -> crate::Result<VectorSink> {
+        use crate::sinks::util::service::ServiceBuilderExt;
+
+        let request = self.request.unwrap_with(&TowerRequestConfig {
+            rate_limit_num: Some(1000),
+            ..Default::default()
+        });
+
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        // The events are mapped before being batched, so they are partitioned by the log type
+        // recorded in the UDM events.
+        let partitioner = KeyPartitioner::new(
+            Template::try_from(format!("{{{{ {} }}}}", LOG_TYPE_FIELD).as_str())
+                .expect("valid template"),
+        );
+
+        let svc = ServiceBuilder::new()
+            .bandwidth_limit(&request)
+            .settings(request, GcsRetryLogic)
+            .service(ChronicleService::new(client, base_url, creds));
+
+        let request_settings = UdmRequestSettings {
+            encoder: UdmEncoder {
+                customer_id: self.customer_id.clone(),
+            },
+        };
+
+        let sink = ChronicleUdmSink {
+            log_type: self.log_type.clone(),
+            mappings: self.mappings.clone(),
+            inner: GcsSink::new(svc, request_settings, partitioner, batch_settings, "http"),
+        };
+
+        Ok(VectorSink::from_event_streamsink(sink))
+    }
+}
+
+/// Maps the events to UDM events, and sends them on to the UDM events endpoint.
+struct ChronicleUdmSink<Svc> {
+    log_type: Template,
+    mappings: IndexMap<String, UdmMapping>,
+    inner: GcsSink<Svc, UdmRequestSettings>,
+}
+
+/// Maps the event to a UDM event, rejecting it if it can't be mapped.
+fn map_event(
+    log_type: &Template,
+    mappings: &IndexMap<String, UdmMapping>,
+    event: Event,
+) -> Option<Event> {
+    let mut log = event.into_log();
+    let mapped = match log_type.render_string(&log) {
+        Ok(log_type) => match mappings.get(&log_type) {
+            Some(mapping) => mapping.map(&log, &log_type).map_err(|error| {
+                emit!(ChronicleUdmMappingError {
+                    log_type: &log_type,
+                    error,
+                });
+            }),
+            None => {
+                emit!(ChronicleUdmMappingError {
+                    log_type: &log_type,
+                    error: "No mapping is configured for the log type.".to_owned(),
+                });
+                Err(())
+            }
+        },
+        Err(error) => {
+            emit!(TemplateRenderingError {
+                error,
+                field: Some("log_type"),
+                drop_event: true,
+            });
+            Err(())
+        }
+    };
+
+    match mapped {
+        Ok(udm) => {
+            let (_, metadata) = log.into_parts();
+            let (value, _) = udm.into_parts();
+            Some(LogEvent::from_parts(value, metadata).into())
+        }
+        Err(()) => {
+            log.take_finalizers().update_status(EventStatus::Rejected);
+            None
+        }
+    }
+}
+
+#[async_trait]
+impl<Svc> StreamSink<Event> for ChronicleUdmSink<Svc>
+where
+    Svc: Service<ChronicleRequest> + Send + 'static,
+    Svc::Future: Send + 'static,
+    Svc::Response: DriverResponse + Send + 'static,
+    Svc::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let Self {
+            log_type,
+            mappings,
+            inner,
+        } = *self;
+
+        let input = input
+            .filter_map(move |event| ready(map_event(&log_type, &mappings, event)))
+            .boxed();
+        Box::new(inner).run(input).await
+    }
+}
+
+#[derive(Clone, Debug)]
+struct UdmEncoder {
+    customer_id: String,
+}
+
+impl Encoder<(String, Vec<Event>)> for UdmEncoder {
+    fn encode_input(
+        &self,
+        input: (String, Vec<Event>),
+        writer: &mut dyn io::Write,
+    ) -> io::Result<usize> {
+        let (_, events) = input;
+        let events = events
+            .iter()
+            .map(|event| event.as_log().value())
+            .collect::<Vec<_>>();
+
+        let json = json!({
+            "customer_id": self.customer_id,
+            "events": events,
+        });
+
+        let size = as_tracked_write::<_, _, io::Error>(writer, &json, |writer, json| {
+            serde_json::to_writer(writer, json)?;
+            Ok(())
+        })?;
+
+        Ok(size)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct UdmRequestSettings {
+    encoder: UdmEncoder,
+}
+
+impl RequestBuilder<(String, Vec<Event>)> for UdmRequestSettings {
+    type Metadata = EventFinalizers;
+    type Events = (String, Vec<Event>);
+    type Encoder = UdmEncoder;
+    type Payload = Bytes;
+    type Request = ChronicleRequest;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        Compression::None
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        input: (String, Vec<Event>),
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let (partition_key, mut events) = input;
+        let finalizers = events.take_finalizers();
+
+        let builder = RequestMetadataBuilder::from_events(&events);
+        (finalizers, builder, (partition_key, events))
+    }
+
+    fn build_request(
+        &self,
+        finalizers: Self::Metadata,
+        metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        ChronicleRequest {
+            body: payload.into_payload(),
+            finalizers,
+            metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::{BatchNotifier, BatchStatus};
+
+    use super::*;
+
+    fn mapping(event_type: &str) -> UdmMapping {
+        UdmMapping {
+            event_type: event_type.to_owned(),
+            product_name: Some("Okta".to_owned()),
+            vendor_name: None,
+            fields: IndexMap::from([
+                ("principal.ip".to_owned(), "client.ip".to_owned()),
+                ("target.user.userid".to_owned(), "user".to_owned()),
+            ]),
+            required_fields: Vec::new(),
+        }
+    }
+
+    fn log() -> LogEvent {
+        let mut log = LogEvent::from("login");
+        log.insert(
+            "timestamp",
+            Utc.with_ymd_and_hms(2023, 2, 14, 10, 0, 0)
+                .single()
+                .unwrap(),
+        );
+        log.insert("client.ip", "10.0.0.1");
+        log.insert("user", "alice");
+        log
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ChronicleUdmConfig>();
+    }
+
+    #[test]
+    fn maps_fields() {
+        let udm = mapping("USER_LOGIN").map(&log(), "OKTA").unwrap();
+
+        assert_eq!(
+            serde_json::to_value(udm.value()).unwrap(),
+            json!({
+                "metadata": {
+                    "event_timestamp": "2023-02-14T10:00:00Z",
+                    "event_type": "USER_LOGIN",
+                    "log_type": "OKTA",
+                    "product_name": "Okta",
+                },
+                "principal": {"ip": "10.0.0.1"},
+                "target": {"user": {"userid": "alice"}},
+            })
+        );
+    }
+
+    #[test]
+    fn validates_required_fields() {
+        let mut log = log();
+        log.remove("client.ip");
+        assert!(mapping("USER_LOGIN").map(&log, "OKTA").is_ok());
+
+        let error = mapping("NETWORK_CONNECTION").map(&log, "OKTA").unwrap_err();
+        assert!(error.contains("principal"), "{}", error);
+
+        let mut strict = mapping("USER_LOGIN");
+        strict.required_fields.push("principal.hostname".to_owned());
+        let error = strict.map(&log, "OKTA").unwrap_err();
+        assert!(error.contains("principal.hostname"), "{}", error);
+
+        log.remove("timestamp");
+        let error = mapping("USER_LOGIN").map(&log, "OKTA").unwrap_err();
+        assert!(error.contains("metadata.event_timestamp"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_unmapped_events() {
+        let mappings = IndexMap::from([("OKTA".to_owned(), mapping("USER_LOGIN"))]);
+        let log_type = Template::try_from("{{ source }}").unwrap();
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let mut log = log().with_batch_notifier(&batch);
+        log.insert("source", "WINDOWS_DNS");
+        drop(batch);
+
+        let mapped = map_event(&log_type, &mappings, log.into());
+        assert!(mapped.is_none());
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+    }
+}
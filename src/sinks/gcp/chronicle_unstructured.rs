@@ -73,7 +73,7 @@ pub enum Region {
 
 impl Region {
     /// Each region has a its own endpoint.
-    const fn endpoint(self) -> &'static str {
+    pub(super) const fn endpoint(self) -> &'static str {
         match self {
             Region::Eu => "https://europe-malachiteingestion-pa.googleapis.com",
             Region::Us => "https://malachiteingestion-pa.googleapis.com",
@@ -195,7 +195,7 @@ impl SinkConfig for ChronicleUnstructuredConfig {
         let creds = self.auth.build(Scope::MalachiteIngestion).await?;
 
         let tls = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
 
         let endpoint = self.create_endpoint("v2/unstructuredlogentries:batchCreate")?;
 
@@ -240,6 +240,7 @@ impl ChronicleUnstructuredConfig {
         let partitioner = self.key_partitioner()?;
 
         let svc = ServiceBuilder::new()
+            .bandwidth_limit(&request)
             .settings(request, GcsRetryLogic)
             .service(ChronicleService::new(client, base_url, creds));
 
@@ -255,24 +256,33 @@ impl ChronicleUnstructuredConfig {
     }
 
     fn create_endpoint(&self, path: &str) -> Result<String, ChronicleError> {
-        Ok(format!(
-            "{}/{}",
-            match (&self.endpoint, self.region) {
-                (Some(endpoint), None) => endpoint.trim_end_matches('/'),
-                (None, Some(region)) => region.endpoint(),
-                (Some(_), Some(_)) => return Err(ChronicleError::BothRegionAndEndpoint),
-                (None, None) => return Err(ChronicleError::RegionOrEndpoint),
-            },
-            path
-        ))
+        create_endpoint(self.endpoint.as_deref(), self.region, path)
     }
 }
 
+/// Builds the URL of an API of the endpoint, or of the region's endpoint.
+pub(super) fn create_endpoint(
+    endpoint: Option<&str>,
+    region: Option<Region>,
+    path: &str,
+) -> Result<String, ChronicleError> {
+    Ok(format!(
+        "{}/{}",
+        match (endpoint, region) {
+            (Some(endpoint), None) => endpoint.trim_end_matches('/'),
+            (None, Some(region)) => region.endpoint(),
+            (Some(_), Some(_)) => return Err(ChronicleError::BothRegionAndEndpoint),
+            (None, None) => return Err(ChronicleError::RegionOrEndpoint),
+        },
+        path
+    ))
+}
+
 #[derive(Clone, Debug)]
 pub struct ChronicleRequest {
     pub body: Bytes,
     pub finalizers: EventFinalizers,
-    metadata: RequestMetadata,
+    pub(super) metadata: RequestMetadata,
 }
 
 impl Finalizable for ChronicleRequest {
@@ -2,7 +2,6 @@ use std::{collections::HashMap, convert::TryFrom, io};
 
 use bytes::Bytes;
 use chrono::Utc;
-use codecs::encoding::Framer;
 use http::header::{HeaderName, HeaderValue};
 use http::Uri;
 use indoc::indoc;
@@ -16,8 +15,7 @@ use vector_core::event::{EventFinalizers, Finalizable};
 
 use crate::sinks::util::metadata::RequestMetadataBuilder;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType, Transformer},
-    config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     gcp::{GcpAuthConfig, GcpAuthenticator, Scope},
     http::{get_http_scheme_from_uri, HttpClient},
@@ -31,7 +29,10 @@ use crate::{
             sink::GcsSink,
         },
         util::{
-            batch::BatchConfig, partitioner::KeyPartitioner, request_builder::EncodeResult,
+            batch::BatchConfig,
+            parquet::{ObjectEncoder, ObjectEncodingConfig},
+            partitioner::KeyPartitioner,
+            request_builder::EncodeResult,
             BulkSizeBasedDefaultBatchSettings, Compression, RequestBuilder, ServiceBuilderExt,
             TowerRequestConfig,
         },
@@ -129,12 +130,13 @@ pub struct GcsSinkConfig {
 
     /// The filename extension to use in the object key.
     ///
-    /// If not specified, the extension is determined by the compression scheme used.
+    /// If not specified, the extension is determined by the compression scheme used, or the
+    /// `batch_encoding`.
     #[configurable(metadata(docs::advanced))]
     filename_extension: Option<String>,
 
     #[serde(flatten)]
-    encoding: EncodingConfigWithFraming,
+    encoding: ObjectEncodingConfig,
 
     #[configurable(derived)]
     #[serde(default)]
@@ -168,7 +170,7 @@ fn default_time_format() -> String {
 }
 
 #[cfg(test)]
-fn default_config(encoding: EncodingConfigWithFraming) -> GcsSinkConfig {
+fn default_config(encoding: ObjectEncodingConfig) -> GcsSinkConfig {
     GcsSinkConfig {
         bucket: Default::default(),
         acl: Default::default(),
@@ -206,7 +208,7 @@ impl SinkConfig for GcsSinkConfig {
         let auth = self.auth.build(Scope::DevStorageReadWrite).await?;
         let base_url = format!("{}{}/", BASE_URL, self.bucket);
         let tls = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
         let healthcheck = build_healthcheck(
             self.bucket.clone(),
             client.clone(),
@@ -220,7 +222,7 @@ impl SinkConfig for GcsSinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        Input::new(self.encoding.input_type())
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -247,6 +249,7 @@ impl GcsSinkConfig {
         let protocol = get_http_scheme_from_uri(&base_url.parse::<Uri>().unwrap());
 
         let svc = ServiceBuilder::new()
+            .bandwidth_limit(&request)
             .settings(request, GcsRetryLogic)
             .service(GcsService::new(client, base_url, auth));
 
@@ -278,14 +281,14 @@ struct RequestSettings {
     extension: String,
     time_format: String,
     append_uuid: bool,
-    encoder: (Transformer, Encoder<Framer>),
+    encoder: ObjectEncoder,
     compression: Compression,
 }
 
 impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
     type Metadata = (String, EventFinalizers);
     type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Encoder = ObjectEncoder;
     type Payload = Bytes;
     type Request = GcsRequest;
     type Error = io::Error;
@@ -349,15 +352,13 @@ impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
 
 impl RequestSettings {
     fn new(config: &GcsSinkConfig) -> crate::Result<Self> {
-        let transformer = config.encoding.transformer();
-        let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let encoder = config.encoding.build()?;
+        let compression = encoder.compression(config.compression);
         let acl = config
             .acl
             .map(|acl| HeaderValue::from_str(&to_string(acl)).unwrap());
         let content_type = HeaderValue::from_str(encoder.content_type()).unwrap();
-        let content_encoding = config
-            .compression
+        let content_encoding = compression
             .content_encoding()
             .map(|ce| HeaderValue::from_str(&to_string(ce)).unwrap());
         let storage_class = config.storage_class.unwrap_or_default();
@@ -375,7 +376,7 @@ impl RequestSettings {
         let extension = config
             .filename_extension
             .clone()
-            .unwrap_or_else(|| config.compression.extension().into());
+            .unwrap_or_else(|| encoder.extension(compression).into());
         let time_format = config.filename_time_format.clone();
         let append_uuid = config.filename_append_uuid;
         Ok(Self {
@@ -387,8 +388,8 @@ impl RequestSettings {
             extension,
             time_format,
             append_uuid,
-            compression: config.compression,
-            encoder: (transformer, encoder),
+            compression,
+            encoder,
         })
     }
 }
@@ -409,6 +410,7 @@ mod tests {
     use vector_core::partition::Partitioner;
 
     use crate::event::LogEvent;
+    use crate::sinks::util::parquet::{BatchEncodingConfig, BatchSerializerConfig};
     use crate::test_util::{
         components::{run_and_assert_sink_compliance, SINK_TAGS},
         http::{always_200_response, spawn_blackhole_http_server},
@@ -467,19 +469,31 @@ mod tests {
     }
 
     fn build_request(extension: Option<&str>, uuid: bool, compression: Compression) -> GcsRequest {
+        build_request_with_encoding(
+            extension,
+            uuid,
+            compression,
+            (
+                Some(NewlineDelimitedEncoderConfig::new()),
+                JsonSerializerConfig::default(),
+            )
+                .into(),
+        )
+    }
+
+    fn build_request_with_encoding(
+        extension: Option<&str>,
+        uuid: bool,
+        compression: Compression,
+        encoding: ObjectEncodingConfig,
+    ) -> GcsRequest {
         let sink_config = GcsSinkConfig {
             key_prefix: Some("key/".into()),
             filename_time_format: "date".into(),
             filename_extension: extension.map(Into::into),
             filename_append_uuid: uuid,
             compression,
-            ..default_config(
-                (
-                    Some(NewlineDelimitedEncoderConfig::new()),
-                    JsonSerializerConfig::default(),
-                )
-                    .into(),
-            )
+            ..default_config(encoding)
         };
         let log = LogEvent::default().into();
         let key = sink_config
@@ -510,4 +524,17 @@ mod tests {
         let req = build_request(None, true, Compression::gzip_default());
         assert_ne!(req.key, "key/date.log.gz".to_string());
     }
+
+    #[test]
+    fn gcs_build_request_with_batch_encoding() {
+        let encoding = BatchEncodingConfig::from(BatchSerializerConfig::Parquet {
+            parquet: Default::default(),
+        });
+        let req =
+            build_request_with_encoding(None, false, Compression::gzip_default(), encoding.into());
+
+        assert_eq!(req.key, "key/date.parquet".to_string());
+        assert_eq!(req.settings.content_type, "application/vnd.apache.parquet");
+        assert_eq!(req.settings.content_encoding, None);
+    }
 }
@@ -121,7 +121,7 @@ impl SinkConfig for PubsubConfig {
             .into_batch_settings()?;
         let request_settings = self.request.unwrap_with(&Default::default());
         let tls_settings = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls_settings, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
 
         let healthcheck = healthcheck(client.clone(), sink.uri("")?, sink.auth.clone()).boxed();
         sink.auth.spawn_regenerate_token();
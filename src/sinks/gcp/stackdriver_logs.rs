@@ -217,7 +217,7 @@ impl SinkConfig for StackdriverConfig {
                 .rate_limit_num(1000),
         );
         let tls_settings = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls_settings, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
 
         let sink = StackdriverSink {
             config: self.clone(),
@@ -95,7 +95,7 @@ impl SinkConfig for HoneycombConfig {
 
         let buffer = JsonArrayBuffer::new(batch_settings.size);
 
-        let client = HttpClient::new(None, cx.proxy())?;
+        let client = HttpClient::new_with_options(None, cx.proxy(), cx.http_client())?;
 
         let sink = BatchedHttpSink::new(
             self.clone(),
@@ -10,6 +10,7 @@ use http::{
 };
 use hyper::Body;
 use indexmap::IndexMap;
+use regex::bytes::Regex;
 use tokio_util::codec::Encoder as _;
 use vector_config::configurable_component;
 
@@ -22,7 +23,7 @@ use crate::{
     register_validatable_component,
     sinks::util::{
         self,
-        http::{BatchedHttpSink, HttpEventEncoder, RequestConfig},
+        http::{BatchedHttpSink, HttpEventEncoder, RequestConfig, ResponseClassification},
         BatchConfig, Buffer, Compression, RealtimeSizeBasedDefaultBatchSettings,
         TowerRequestConfig, UriSerde,
     },
@@ -97,6 +98,53 @@ pub struct HttpSinkConfig {
         skip_serializing_if = "crate::serde::skip_serializing_if_default"
     )]
     pub acknowledgements: AcknowledgementsConfig,
+
+    /// Rules for classifying responses from the downstream service.
+    ///
+    /// Rules are evaluated in order, and the first rule that matches a response determines its
+    /// outcome. Responses that match no rule are classified according to their status code alone.
+    ///
+    /// This allows handling services that report errors in the body of an otherwise successful
+    /// response.
+    #[serde(default)]
+    pub response_rules: Vec<ResponseRule>,
+}
+
+/// A rule for classifying responses from the downstream service.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ResponseRule {
+    /// The response status codes this rule applies to.
+    ///
+    /// If empty, the rule applies to responses with any status code.
+    #[configurable(metadata(docs::examples = 200))]
+    #[serde(default)]
+    pub status: Vec<u16>,
+
+    /// A regular expression that the response body must match for this rule to apply.
+    ///
+    /// If not set, the rule applies regardless of the response body.
+    #[configurable(metadata(docs::examples = "\"success\":\\s*false"))]
+    pub body: Option<String>,
+
+    #[configurable(derived)]
+    pub outcome: ResponseOutcome,
+}
+
+/// How a response matching a rule is handled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseOutcome {
+    /// The request succeeded, and its events are marked as delivered.
+    Success,
+
+    /// The request failed, and is retried.
+    Retry,
+
+    /// The request failed permanently, and its events are marked as rejected without retrying.
+    Reject,
 }
 
 /// HTTP method.
@@ -163,7 +211,11 @@ impl GenerateConfig for HttpSinkConfig {
 impl HttpSinkConfig {
     fn build_http_client(&self, cx: &SinkContext) -> crate::Result<HttpClient> {
         let tls = TlsSettings::from_options(&self.tls)?;
-        Ok(HttpClient::new(tls, cx.proxy())?)
+        Ok(HttpClient::new_with_options(
+            tls,
+            cx.proxy(),
+            cx.http_client(),
+        )?)
     }
 }
 
@@ -179,6 +231,46 @@ struct HttpSink {
     pub batch: BatchConfig<RealtimeSizeBasedDefaultBatchSettings>,
     pub tower: TowerRequestConfig,
     pub headers: IndexMap<HeaderName, HeaderValue>,
+    pub response_rules: Vec<ResponseMatcher>,
+}
+
+struct ResponseMatcher {
+    status: Vec<StatusCode>,
+    body: Option<Regex>,
+    outcome: ResponseOutcome,
+}
+
+impl ResponseMatcher {
+    fn new(rule: &ResponseRule) -> crate::Result<Self> {
+        let status = rule
+            .status
+            .iter()
+            .map(|&code| {
+                StatusCode::from_u16(code)
+                    .map_err(|_| format!("Invalid response rule status code: {}", code))
+            })
+            .collect::<Result<_, _>>()?;
+        let body = rule
+            .body
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|error| format!("Invalid response rule body pattern: {}", error))?;
+
+        Ok(Self {
+            status,
+            body,
+            outcome: rule.outcome,
+        })
+    }
+
+    fn matches(&self, response: &http::Response<Bytes>) -> bool {
+        (self.status.is_empty() || self.status.contains(&response.status()))
+            && self
+                .body
+                .as_ref()
+                .map_or(true, |body| body.is_match(response.body()))
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +290,7 @@ fn default_sink(encoding: EncodingConfigWithFraming) -> HttpSink {
         batch: Default::default(),
         tower: Default::default(),
         headers: Default::default(),
+        response_rules: Default::default(),
     }
 }
 
@@ -226,6 +319,12 @@ impl SinkConfig for HttpSinkConfig {
         let (payload_prefix, payload_suffix) =
             validate_payload_wrapper(&self.payload_prefix, &self.payload_suffix, &encoder)?;
 
+        let response_rules = self
+            .response_rules
+            .iter()
+            .map(ResponseMatcher::new)
+            .collect::<crate::Result<_>>()?;
+
         let sink = HttpSink {
             uri: self.uri.with_default_parts(),
             method: self.method,
@@ -238,6 +337,7 @@ impl SinkConfig for HttpSinkConfig {
             headers,
             payload_prefix,
             payload_suffix,
+            response_rules,
         };
 
         let request = sink.tower.unwrap_with(&TowerRequestConfig::default());
@@ -289,6 +389,7 @@ impl ValidatableComponent for HttpSinkConfig {
             acknowledgements: AcknowledgementsConfig::default(),
             payload_prefix: String::new(),
             payload_suffix: String::new(),
+            response_rules: Vec::new(),
         };
 
         let external_resource = ExternalResource::new(
@@ -411,6 +512,29 @@ impl util::http::HttpSink for HttpSink {
 
         Ok(request)
     }
+
+    fn classify_response(
+        &self,
+        response: &http::Response<Bytes>,
+    ) -> Option<ResponseClassification> {
+        let rule = self
+            .response_rules
+            .iter()
+            .find(|rule| rule.matches(response))?;
+        let reason = || {
+            format!(
+                "{}: {}",
+                response.status(),
+                String::from_utf8_lossy(response.body())
+            )
+        };
+
+        Some(match rule.outcome {
+            ResponseOutcome::Success => ResponseClassification::Success,
+            ResponseOutcome::Retry => ResponseClassification::Retry(reason()),
+            ResponseOutcome::Reject => ResponseClassification::Reject(reason()),
+        })
+    }
 }
 
 async fn healthcheck(uri: UriSerde, auth: Option<Auth>, client: HttpClient) -> crate::Result<()> {
@@ -811,6 +935,145 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn rejects_on_matching_response_body() {
+        components::assert_sink_error(&COMPONENT_ERROR_TAGS, async {
+            let num_lines = 1000;
+
+            let (in_addr, sink) = build_sink(
+                r#"
+                [[response_rules]]
+                status = [200]
+                body = '"success":\s*false'
+                outcome = "reject"
+                "#,
+            )
+            .await;
+
+            let (_rx, trigger, server) = build_test_server_generic(in_addr, || {
+                Response::new(Body::from(r#"{"success": false, "error": "invalid key"}"#))
+            });
+
+            let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+            let (_input_lines, events) = random_lines_with_stream(100, num_lines, Some(batch));
+            let pump = sink.run(events);
+
+            tokio::spawn(server);
+
+            pump.await.unwrap();
+            drop(trigger);
+
+            assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn retries_on_matching_response_body() {
+        components::assert_sink_compliance(&HTTP_SINK_TAGS, async {
+            const NUM_LINES: usize = 1000;
+            const NUM_FAILURES: usize = 2;
+
+            let (in_addr, sink) = build_sink(
+                r#"
+                [[response_rules]]
+                body = "try again"
+                outcome = "retry"
+                "#,
+            )
+            .await;
+
+            let counter = Arc::new(atomic::AtomicUsize::new(0));
+            let in_counter = Arc::clone(&counter);
+            let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+                let count = in_counter.fetch_add(1, atomic::Ordering::Relaxed);
+                if count < NUM_FAILURES {
+                    Response::new(Body::from("busy, try again later"))
+                } else {
+                    Response::new(Body::from("ok"))
+                }
+            });
+
+            let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+            let (input_lines, events) = random_lines_with_stream(100, NUM_LINES, Some(batch));
+            let pump = sink.run(events);
+
+            tokio::spawn(server);
+
+            pump.await.unwrap();
+            drop(trigger);
+
+            assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+            let output_lines = get_received(rx, |_| {}).await;
+
+            let tries = counter.load(atomic::Ordering::Relaxed);
+            assert!(tries > NUM_FAILURES);
+            // Requests that were retried are received by the test server more than once.
+            assert!(output_lines.len() > NUM_LINES);
+            assert!(input_lines.iter().all(|line| output_lines.contains(line)));
+        })
+        .await;
+    }
+
+    #[test]
+    fn classifies_responses_with_first_matching_rule() {
+        let config: HttpSinkConfig = toml::from_str(
+            r#"
+            uri = "http://localhost/frames"
+            encoding.codec = "json"
+
+            [[response_rules]]
+            status = [200]
+            body = "error"
+            outcome = "reject"
+
+            [[response_rules]]
+            status = [503]
+            outcome = "success"
+            "#,
+        )
+        .unwrap();
+
+        let mut sink = default_sink(config.encoding.clone());
+        sink.response_rules = config
+            .response_rules
+            .iter()
+            .map(|rule| ResponseMatcher::new(rule).unwrap())
+            .collect();
+
+        let response = |status: u16, body: &'static str| {
+            Response::builder()
+                .status(status)
+                .body(Bytes::from(body))
+                .unwrap()
+        };
+
+        assert!(matches!(
+            sink.classify_response(&response(200, "an error occurred")),
+            Some(ResponseClassification::Reject(_))
+        ));
+        assert_eq!(
+            sink.classify_response(&response(503, "")),
+            Some(ResponseClassification::Success)
+        );
+        assert_eq!(sink.classify_response(&response(200, "ok")), None);
+        assert_eq!(sink.classify_response(&response(500, "error")), None);
+    }
+
+    #[test]
+    fn rejects_invalid_response_rules() {
+        let rule = |status: u16, body: Option<&str>| ResponseRule {
+            status: vec![status],
+            body: body.map(Into::into),
+            outcome: ResponseOutcome::Reject,
+        };
+
+        assert!(ResponseMatcher::new(&rule(200, Some("("))).is_err());
+        assert!(ResponseMatcher::new(&rule(42, None)).is_err());
+        assert!(ResponseMatcher::new(&rule(200, Some("error"))).is_ok());
+    }
+
     #[tokio::test]
     async fn json_compression() {
         components::assert_sink_compliance(&HTTP_SINK_TAGS, async {
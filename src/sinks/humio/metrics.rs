@@ -167,7 +167,7 @@ impl SinkConfig for HumioMetricsConfig {
             indexed_fields: self.indexed_fields.clone(),
             index: self.index.clone(),
             compression: self.compression,
-            request: self.request,
+            request: self.request.clone(),
             batch: self.batch,
             tls: self.tls.clone(),
             timestamp_nanos_key: None,
@@ -163,7 +163,7 @@ impl SinkConfig for InfluxDbLogsConfig {
         let tags: HashSet<String> = self.tags.clone().into_iter().collect();
 
         let tls_settings = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls_settings, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
         let healthcheck = self.healthcheck(client.clone())?;
 
         let batch = self.batch.into_batch_settings()?;
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, num::NonZeroUsize, time::Duration};
 
 use codecs::JsonSerializerConfig;
 use futures::FutureExt;
@@ -66,10 +66,31 @@ pub struct KafkaSinkConfig {
     #[serde(default)]
     pub batch: BatchConfig<NoDefaultsBatchSettings>,
 
+    /// Supported compression types for Kafka.
+    ///
+    /// If not set, the compression codec of the `profile` is used.
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<KafkaCompression>,
+
     #[configurable(derived)]
     #[configurable(metadata(docs::advanced))]
     #[serde(default)]
-    pub compression: KafkaCompression,
+    pub profile: KafkaProfile,
+
+    /// The target time, in milliseconds, for events to be produced to the brokers.
+    ///
+    /// When set, the time the producer waits for messages to accumulate into batches
+    /// (`queue.buffering.max.ms`) is derived from it, by subtracting the typical produce latency
+    /// of the brokers of the `profile`. Larger budgets allow larger batches, and so higher
+    /// throughput.
+    ///
+    /// This can't be set along with `batch.timeout_secs`.
+    #[configurable(metadata(docs::type_unit = "milliseconds"))]
+    #[configurable(metadata(docs::examples = 50, docs::examples = 1000))]
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default)]
+    pub produce_latency_budget_ms: Option<u64>,
 
     #[configurable(derived)]
     #[serde(flatten)]
@@ -110,6 +131,23 @@ pub struct KafkaSinkConfig {
     #[configurable(metadata(docs::examples = "headers"))]
     pub headers_key: Option<String>,
 
+    /// Whether to produce events with the identity of the Kafka message they were consumed from.
+    ///
+    /// When enabled, events consumed by a `kafka` source with `passthrough` enabled are produced to
+    /// the same topic and partition, with the same key, timestamp, and headers, as the original
+    /// message, ignoring `topic`, `key_field`, and `headers_key`. Other events are produced as usual.
+    ///
+    /// The partition is only preserved when it exists in the destination topic, so the destination
+    /// topic must have at least as many partitions as the original one.
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default)]
+    pub passthrough: bool,
+
+    #[configurable(derived)]
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exactly_once: Option<KafkaExactlyOnceConfig>,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -119,6 +157,127 @@ pub struct KafkaSinkConfig {
     pub acknowledgements: AcknowledgementsConfig,
 }
 
+/// Producer tuning presets for Kafka-compatible brokers.
+///
+/// A profile sets the defaults of the producer options it tunes. Options set through `batch`,
+/// `compression`, `exactly_once`, or `librdkafka_options` take precedence over them.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaProfile {
+    /// The `librdkafka` defaults, suited to Apache Kafka.
+    #[default]
+    Kafka,
+
+    /// Tuned for [Redpanda][redpanda].
+    ///
+    /// Redpanda brokers acknowledge produce requests within a few milliseconds, so batches are
+    /// only lingered on briefly, and compressed with LZ4, while the producer is idempotent so
+    /// that retries don't reorder or duplicate messages.
+    ///
+    /// [redpanda]: https://redpanda.com/
+    Redpanda,
+
+    /// Tuned for [WarpStream][warpstream].
+    ///
+    /// WarpStream agents write to object storage before acknowledging produce requests, which
+    /// takes hundreds of milliseconds, so batches are lingered on for longer, made larger, and
+    /// many requests are kept in flight. WarpStream doesn't support idempotent producers.
+    ///
+    /// [warpstream]: https://www.warpstream.com/
+    Warpstream,
+}
+
+impl KafkaProfile {
+    /// The typical time taken by the brokers to acknowledge a produce request.
+    const fn produce_latency(self) -> Duration {
+        match self {
+            Self::Kafka => Duration::from_millis(20),
+            Self::Redpanda => Duration::from_millis(10),
+            Self::Warpstream => Duration::from_millis(400),
+        }
+    }
+
+    const fn compression(self) -> KafkaCompression {
+        match self {
+            Self::Kafka => KafkaCompression::None,
+            Self::Redpanda | Self::Warpstream => KafkaCompression::Lz4,
+        }
+    }
+
+    /// The `librdkafka` producer options set by the profile.
+    const fn producer_options(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Kafka => &[],
+            Self::Redpanda => &[
+                ("queue.buffering.max.ms", "10"),
+                ("enable.idempotence", "true"),
+                ("max.in.flight.requests.per.connection", "5"),
+            ],
+            Self::Warpstream => &[
+                ("queue.buffering.max.ms", "100"),
+                ("enable.idempotence", "false"),
+                ("max.in.flight.requests.per.connection", "100"),
+                ("batch.num.messages", "100000"),
+                ("batch.size", "16000000"),
+                // Agents are stateless and may be replaced at any time.
+                ("metadata.max.age.ms", "60000"),
+            ],
+        }
+    }
+}
+
+/// Transactional writes to Kafka.
+///
+/// Events are written by an idempotent producer within transactions, and are only acknowledged
+/// once the transaction containing them has been committed. A transaction that fails, or that was
+/// left open by a crashed instance of Vector, is aborted, so consumers reading with
+/// `isolation.level=read_committed` never see its events.
+///
+/// This only covers the writes of the producer, and doesn't make delivery exactly-once end to end.
+/// Events of an aborted transaction are written again if the source redelivers them, and events
+/// whose transaction was committed are written again if the source redelivers them anyway, for
+/// example because Vector stopped before the source recorded their acknowledgement.
+///
+/// This requires [end-to-end acknowledgements][e2e_acks] to be enabled, so that the source only
+/// advances once events are committed.
+///
+/// [e2e_acks]: https://vector.dev/docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaExactlyOnceConfig {
+    /// The transactional ID of the producer.
+    ///
+    /// This must be unique to this sink and stay the same across restarts, as it is what lets the
+    /// broker abort any transaction left open by a previous instance.
+    #[configurable(metadata(docs::examples = "vector-kafka-sink-1"))]
+    pub transactional_id: String,
+
+    /// Maximum time, in milliseconds, that a transaction may remain open before the broker aborts
+    /// it.
+    ///
+    /// The local message timeout is lowered to this value if it is larger.
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    #[serde(default = "default_transaction_timeout_ms")]
+    #[configurable(metadata(docs::examples = 60000))]
+    pub transaction_timeout_ms: Duration,
+
+    /// The maximum number of events written in a single transaction.
+    #[serde(default = "default_max_events_per_transaction")]
+    #[configurable(metadata(docs::examples = 1000))]
+    pub max_events: NonZeroUsize,
+}
+
+const fn default_transaction_timeout_ms() -> Duration {
+    Duration::from_millis(60000) // default in librdkafka
+}
+
+fn default_max_events_per_transaction() -> NonZeroUsize {
+    NonZeroUsize::new(1000).expect("static non-zero number")
+}
+
 const fn default_socket_timeout_ms() -> Duration {
     Duration::from_millis(60000) // default in librdkafka
 }
@@ -162,13 +321,66 @@ impl KafkaSinkConfig {
         match kafka_role {
             // All batch options are producer only.
             KafkaRole::Producer => {
+                for (key, value) in self.profile.producer_options() {
+                    client_config.set(*key, *value);
+                }
+
+                let compression = self
+                    .compression
+                    .unwrap_or_else(|| self.profile.compression());
                 client_config
-                    .set("compression.codec", &to_string(self.compression))
+                    .set("compression.codec", &to_string(compression))
                     .set(
                         "message.timeout.ms",
                         &self.message_timeout_ms.as_millis().to_string(),
                     );
 
+                if let Some(exactly_once) = &self.exactly_once {
+                    // librdkafka refuses to create a transactional producer whose messages may
+                    // outlive the transaction they were written in.
+                    let message_timeout = self
+                        .message_timeout_ms
+                        .min(exactly_once.transaction_timeout_ms);
+                    client_config
+                        .set("enable.idempotence", "true")
+                        .set("transactional.id", &exactly_once.transactional_id)
+                        .set(
+                            "transaction.timeout.ms",
+                            &exactly_once.transaction_timeout_ms.as_millis().to_string(),
+                        )
+                        .set(
+                            "message.timeout.ms",
+                            &message_timeout.as_millis().to_string(),
+                        );
+                }
+
+                if let Some(budget) = self.produce_latency_budget_ms {
+                    let key = "queue.buffering.max.ms";
+                    if self.batch.timeout_secs.is_some() {
+                        return Err("`produce_latency_budget_ms` can't be set along with `batch.timeout_secs`.".into());
+                    }
+                    if let Some(val) = self.librdkafka_options.get(key) {
+                        return Err(format!(
+                            "`produce_latency_budget_ms` sets `librdkafka_options.{}`.\
+                                        The config already sets this as `librdkafka_options.{}={}`.\
+                                        Please delete one.",
+                            key, key, val
+                        )
+                        .into());
+                    }
+                    let linger = linger_for_latency_budget(
+                        Duration::from_millis(budget),
+                        self.profile.produce_latency(),
+                    );
+                    debug!(
+                        librdkafka_option = key,
+                        produce_latency_budget_ms = budget,
+                        value = linger.as_millis() as u64,
+                        "Applying produce latency budget as librdkafka option."
+                    );
+                    client_config.set(key, &linger.as_millis().to_string());
+                }
+
                 if let Some(value) = self.batch.timeout_secs {
                     // Delay in milliseconds to wait for messages in the producer queue to accumulate before
                     // constructing message batches (MessageSets) to transmit to brokers. A higher value
@@ -244,6 +456,19 @@ impl KafkaSinkConfig {
     }
 }
 
+/// Derives how long the producer waits for batches to fill from the produce latency budget, by
+/// leaving the brokers enough time to acknowledge the batches.
+fn linger_for_latency_budget(budget: Duration, produce_latency: Duration) -> Duration {
+    if budget <= produce_latency {
+        warn!(
+            message = "Produce latency budget is lower than the typical produce latency of the brokers, batches won't wait to be filled.",
+            budget_ms = budget.as_millis() as u64,
+            produce_latency_ms = produce_latency.as_millis() as u64,
+        );
+    }
+    budget.saturating_sub(produce_latency)
+}
+
 impl GenerateConfig for KafkaSinkConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
@@ -252,12 +477,16 @@ impl GenerateConfig for KafkaSinkConfig {
             key_field: Some("user_id".to_owned()),
             encoding: JsonSerializerConfig::default().into(),
             batch: Default::default(),
-            compression: KafkaCompression::None,
+            compression: None,
+            profile: KafkaProfile::Kafka,
+            produce_latency_budget_ms: None,
             auth: Default::default(),
             socket_timeout_ms: default_socket_timeout_ms(),
             message_timeout_ms: default_message_timeout_ms(),
             librdkafka_options: Default::default(),
             headers_key: None,
+            passthrough: false,
+            exactly_once: None,
             acknowledgements: Default::default(),
         })
         .unwrap()
@@ -266,7 +495,16 @@ impl GenerateConfig for KafkaSinkConfig {
 
 #[async_trait::async_trait]
 impl SinkConfig for KafkaSinkConfig {
-    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        if self.exactly_once.is_some()
+            && !self
+                .acknowledgements
+                .merge_default(&cx.globals.acknowledgements)
+                .enabled()
+        {
+            return Err("`exactly_once` requires `acknowledgements` to be enabled.".into());
+        }
+
         let sink = KafkaSink::new(self.clone())?;
         let hc = healthcheck(self.clone()).boxed();
         Ok((VectorSink::from_event_streamsink(sink), hc))
@@ -292,4 +530,105 @@ mod tests {
     fn generate_config() {
         KafkaSinkConfig::generate_config();
     }
+
+    #[test]
+    fn exactly_once_configures_transactional_producer() {
+        let config: KafkaSinkConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topic = "logs"
+            encoding.codec = "json"
+            exactly_once.transactional_id = "vector-1"
+            "#,
+        )
+        .unwrap();
+
+        let client_config = config.to_rdkafka(KafkaRole::Producer).unwrap();
+        assert_eq!(client_config.get("enable.idempotence"), Some("true"));
+        assert_eq!(client_config.get("transactional.id"), Some("vector-1"));
+        assert_eq!(client_config.get("transaction.timeout.ms"), Some("60000"));
+        // The message timeout may not exceed the transaction timeout.
+        assert_eq!(client_config.get("message.timeout.ms"), Some("60000"));
+    }
+
+    #[tokio::test]
+    async fn exactly_once_builds_without_contacting_the_cluster() {
+        let config: KafkaSinkConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:1"
+            topic = "logs"
+            encoding.codec = "json"
+            exactly_once.transactional_id = "vector-1"
+            exactly_once.transaction_timeout_ms = 600000
+            acknowledgements.enabled = true
+            "#,
+        )
+        .unwrap();
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            config.build(SinkContext::new_test()),
+        )
+        .await
+        .expect("building the sink should not wait for the cluster")
+        .unwrap();
+    }
+
+    #[test]
+    fn profile_sets_producer_defaults() {
+        let config: KafkaSinkConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topic = "logs"
+            encoding.codec = "json"
+            profile = "warpstream"
+            batch.max_events = 1000
+            librdkafka_options."max.in.flight.requests.per.connection" = "10"
+            "#,
+        )
+        .unwrap();
+
+        let client_config = config.to_rdkafka(KafkaRole::Producer).unwrap();
+        assert_eq!(client_config.get("compression.codec"), Some("lz4"));
+        assert_eq!(client_config.get("queue.buffering.max.ms"), Some("100"));
+        assert_eq!(client_config.get("enable.idempotence"), Some("false"));
+        // Explicit options take precedence over the profile.
+        assert_eq!(client_config.get("batch.num.messages"), Some("1000"));
+        assert_eq!(
+            client_config.get("max.in.flight.requests.per.connection"),
+            Some("10")
+        );
+
+        let client_config = config.to_rdkafka(KafkaRole::Consumer).unwrap();
+        assert_eq!(client_config.get("enable.idempotence"), None);
+    }
+
+    #[test]
+    fn produce_latency_budget_sets_linger() {
+        let config: KafkaSinkConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topic = "logs"
+            encoding.codec = "json"
+            profile = "redpanda"
+            compression = "zstd"
+            produce_latency_budget_ms = 50
+            "#,
+        )
+        .unwrap();
+
+        let client_config = config.to_rdkafka(KafkaRole::Producer).unwrap();
+        assert_eq!(client_config.get("compression.codec"), Some("zstd"));
+        assert_eq!(client_config.get("queue.buffering.max.ms"), Some("40"));
+
+        // The budget can't be lower than the produce latency.
+        assert_eq!(
+            linger_for_latency_budget(Duration::from_millis(100), Duration::from_millis(400)),
+            Duration::ZERO
+        );
+
+        let mut config = config;
+        config.batch.timeout_secs = Some(1.0);
+        assert!(config.to_rdkafka(KafkaRole::Producer).is_err());
+    }
 }
@@ -1,6 +1,7 @@
 use std::num::NonZeroUsize;
 
 use bytes::{Bytes, BytesMut};
+use lookup::path;
 use rdkafka::message::{Header, OwnedHeaders};
 use tokio_util::codec::Encoder as _;
 
@@ -18,6 +19,7 @@ use crate::{
 pub struct KafkaRequestBuilder {
     pub key_field: Option<String>,
     pub headers_key: Option<String>,
+    pub passthrough: bool,
     pub topic_template: Template,
     pub transformer: Transformer,
     pub encoder: Encoder<()>,
@@ -25,26 +27,44 @@ pub struct KafkaRequestBuilder {
 
 impl KafkaRequestBuilder {
     pub fn build_request(&mut self, mut event: Event) -> Option<KafkaRequest> {
-        let topic = self
-            .topic_template
-            .render_string(&event)
-            .map_err(|error| {
-                emit!(TemplateRenderingError {
-                    field: None,
-                    drop_event: true,
-                    error,
-                });
-            })
-            .ok()?;
+        let original = self
+            .passthrough
+            .then(|| OriginalMessage::from_event(&event))
+            .flatten();
 
         let metadata_builder = RequestMetadataBuilder::from_events(&event);
 
-        let metadata = KafkaRequestMetadata {
-            finalizers: event.take_finalizers(),
-            key: get_key(&event, &self.key_field),
-            timestamp_millis: get_timestamp_millis(&event),
-            headers: get_headers(&event, &self.headers_key),
-            topic,
+        let metadata = match original {
+            Some(original) => KafkaRequestMetadata {
+                finalizers: event.take_finalizers(),
+                key: original.key,
+                timestamp_millis: original.timestamp_millis,
+                headers: original.headers,
+                topic: original.topic,
+                partition: original.partition,
+            },
+            None => {
+                let topic = self
+                    .topic_template
+                    .render_string(&event)
+                    .map_err(|error| {
+                        emit!(TemplateRenderingError {
+                            field: None,
+                            drop_event: true,
+                            error,
+                        });
+                    })
+                    .ok()?;
+
+                KafkaRequestMetadata {
+                    finalizers: event.take_finalizers(),
+                    key: get_key(&event, &self.key_field),
+                    timestamp_millis: get_timestamp_millis(&event),
+                    headers: get_headers(&event, &self.headers_key),
+                    topic,
+                    partition: None,
+                }
+            }
         };
         self.transformer.transform(&mut event);
         let mut body = BytesMut::new();
@@ -62,6 +82,50 @@ impl KafkaRequestBuilder {
     }
 }
 
+/// The Kafka message an event was consumed from, as stored in the event metadata by a `kafka`
+/// source with `passthrough` enabled.
+struct OriginalMessage {
+    topic: String,
+    partition: Option<i32>,
+    key: Option<Bytes>,
+    timestamp_millis: Option<i64>,
+    headers: Option<OwnedHeaders>,
+}
+
+impl OriginalMessage {
+    fn from_event(event: &Event) -> Option<Self> {
+        let metadata = event.maybe_as_log()?.metadata().value();
+        let topic = metadata
+            .get(path!("kafka", "topic"))?
+            .as_str()?
+            .into_owned();
+
+        let partition = metadata
+            .get(path!("kafka", "partition"))
+            .and_then(Value::as_integer)
+            .and_then(|partition| i32::try_from(partition).ok());
+        let key = match metadata.get(path!("kafka", "message_key")) {
+            Some(Value::Bytes(key)) => Some(key.clone()),
+            _ => None,
+        };
+        let timestamp_millis = metadata
+            .get(path!("kafka", "timestamp"))
+            .and_then(Value::as_timestamp)
+            .map(|timestamp| timestamp.timestamp_millis());
+        let headers = metadata
+            .get(path!("kafka", "headers"))
+            .and_then(|headers| to_owned_headers(headers, "%kafka.headers"));
+
+        Some(Self {
+            topic,
+            partition,
+            key,
+            timestamp_millis,
+            headers,
+        })
+    }
+}
+
 fn get_key(event: &Event, key_field: &Option<String>) -> Option<Bytes> {
     key_field.as_ref().and_then(|key_field| match event {
         Event::Log(log) => log
@@ -88,40 +152,42 @@ fn get_headers(event: &Event, headers_key: &Option<String>) -> Option<OwnedHeade
     headers_key.as_ref().and_then(|headers_key| {
         if let Event::Log(log) = event {
             if let Some(headers) = log.get(headers_key.as_str()) {
-                match headers {
-                    Value::Object(headers_map) => {
-                        let mut owned_headers = OwnedHeaders::new_with_capacity(headers_map.len());
-                        for (key, value) in headers_map {
-                            if let Value::Bytes(value_bytes) = value {
-                                owned_headers = owned_headers.insert(Header {
-                                    key,
-                                    value: Some(value_bytes.as_ref()),
-                                });
-                            } else {
-                                emit!(KafkaHeaderExtractionError {
-                                    header_field: headers_key
-                                });
-                            }
-                        }
-                        return Some(owned_headers);
-                    }
-                    _ => {
-                        emit!(KafkaHeaderExtractionError {
-                            header_field: headers_key
-                        });
-                    }
-                }
+                return to_owned_headers(headers, headers_key);
             }
         }
         None
     })
 }
 
+fn to_owned_headers(headers: &Value, header_field: &str) -> Option<OwnedHeaders> {
+    match headers {
+        Value::Object(headers_map) => {
+            let mut owned_headers = OwnedHeaders::new_with_capacity(headers_map.len());
+            for (key, value) in headers_map {
+                if let Value::Bytes(value_bytes) = value {
+                    owned_headers = owned_headers.insert(Header {
+                        key,
+                        value: Some(value_bytes.as_ref()),
+                    });
+                } else {
+                    emit!(KafkaHeaderExtractionError { header_field });
+                }
+            }
+            Some(owned_headers)
+        }
+        _ => {
+            emit!(KafkaHeaderExtractionError { header_field });
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
 
     use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
     use rdkafka::message::Headers;
 
     use super::*;
@@ -143,4 +209,36 @@ mod tests {
         assert_eq!(headers.get(1).key, "b-key");
         assert_eq!(headers.get(1).value.unwrap(), "b-value".as_bytes());
     }
+
+    #[test]
+    fn kafka_original_message() {
+        let mut header_values = BTreeMap::new();
+        header_values.insert("a-key".to_string(), Value::Bytes(Bytes::from("a-value")));
+
+        let mut log = LogEvent::from("hello");
+        let metadata = log.metadata_mut().value_mut();
+        metadata.insert(path!("kafka", "topic"), "orders");
+        metadata.insert(path!("kafka", "partition"), 3);
+        metadata.insert(path!("kafka", "message_key"), Bytes::from("order-1"));
+        metadata.insert(
+            path!("kafka", "timestamp"),
+            Utc.timestamp_millis_opt(1_600_000_000_000).unwrap(),
+        );
+        metadata.insert(path!("kafka", "headers"), header_values);
+
+        let original = OriginalMessage::from_event(&Event::Log(log)).unwrap();
+        assert_eq!(original.topic, "orders");
+        assert_eq!(original.partition, Some(3));
+        assert_eq!(original.key, Some(Bytes::from("order-1")));
+        assert_eq!(original.timestamp_millis, Some(1_600_000_000_000));
+        let headers = original.headers.unwrap();
+        assert_eq!(headers.get(0).key, "a-key");
+        assert_eq!(headers.get(0).value.unwrap(), "a-value".as_bytes());
+    }
+
+    #[test]
+    fn kafka_original_message_requires_topic() {
+        let event = Event::Log(LogEvent::from("hello"));
+        assert!(OriginalMessage::from_event(&event).is_none());
+    }
 }
@@ -1,11 +1,14 @@
-use std::task::{Context, Poll};
+use std::{
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
-use futures::future::BoxFuture;
+use futures::future::{self, BoxFuture, FutureExt};
 use rdkafka::{
     error::KafkaError,
-    message::OwnedHeaders,
-    producer::{FutureProducer, FutureRecord},
+    message::{Message, OwnedHeaders, OwnedMessage},
+    producer::{FutureProducer, FutureRecord, Producer},
     util::Timeout,
 };
 use tower::Service;
@@ -19,6 +22,9 @@ use vector_core::{
 
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
+    internal_events::{
+        KafkaMessageDelivered, KafkaMessageDeliveryError, KafkaTransactionAbortError,
+    },
     kafka::KafkaStatisticsContext,
 };
 
@@ -34,9 +40,35 @@ pub struct KafkaRequestMetadata {
     pub timestamp_millis: Option<i64>,
     pub headers: Option<OwnedHeaders>,
     pub topic: String,
+    pub partition: Option<i32>,
+}
+
+/// A batch of requests written to Kafka within a single transaction.
+pub struct KafkaTransaction {
+    pub requests: Vec<KafkaRequest>,
+    pub finalizers: EventFinalizers,
+    pub request_metadata: RequestMetadata,
+}
+
+impl KafkaTransaction {
+    pub fn new(mut requests: Vec<KafkaRequest>) -> Self {
+        let mut finalizers = EventFinalizers::default();
+        for request in &mut requests {
+            finalizers.merge(request.take_finalizers());
+        }
+        let request_metadata =
+            RequestMetadata::from_batch(requests.iter().map(|request| request.request_metadata));
+
+        Self {
+            requests,
+            finalizers,
+            request_metadata,
+        }
+    }
 }
 
 pub struct KafkaResponse {
+    event_count: usize,
     event_byte_size: usize,
 }
 
@@ -46,7 +78,7 @@ impl DriverResponse for KafkaResponse {
     }
 
     fn events_sent(&self) -> CountByteSize {
-        CountByteSize(1, self.event_byte_size)
+        CountByteSize(self.event_count, self.event_byte_size)
     }
 }
 
@@ -62,6 +94,48 @@ impl MetaDescriptive for KafkaRequest {
     }
 }
 
+impl Finalizable for KafkaTransaction {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl MetaDescriptive for KafkaTransaction {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.request_metadata
+    }
+}
+
+fn to_record<'a>(
+    metadata: &'a KafkaRequestMetadata,
+    body: &'a Bytes,
+    headers: Option<OwnedHeaders>,
+) -> FutureRecord<'a, [u8], [u8]> {
+    let mut record = FutureRecord::to(&metadata.topic).payload(body.as_ref());
+    if let Some(key) = &metadata.key {
+        record = record.key(&key[..]);
+    }
+    if let Some(partition) = metadata.partition {
+        record = record.partition(partition);
+    }
+    if let Some(timestamp) = metadata.timestamp_millis {
+        record = record.timestamp(timestamp);
+    }
+    if let Some(headers) = headers {
+        record = record.headers(headers);
+    }
+    record
+}
+
+fn emit_delivery_error(error: &KafkaError, message: &OwnedMessage, latency: Duration) {
+    emit!(KafkaMessageDeliveryError {
+        error,
+        topic: message.topic(),
+        partition: message.partition(),
+        latency,
+    });
+}
+
 #[derive(Clone)]
 pub struct KafkaService {
     kafka_producer: FutureProducer<KafkaStatisticsContext>,
@@ -86,33 +160,156 @@ impl Service<KafkaRequest> for KafkaService {
         Poll::Ready(Ok(()))
     }
 
-    fn call(&mut self, request: KafkaRequest) -> Self::Future {
+    fn call(&mut self, mut request: KafkaRequest) -> Self::Future {
         let this = self.clone();
 
         Box::pin(async move {
             let event_byte_size = request.get_metadata().events_byte_size();
 
-            let mut record =
-                FutureRecord::to(&request.metadata.topic).payload(request.body.as_ref());
-            if let Some(key) = &request.metadata.key {
-                record = record.key(&key[..]);
-            }
-            if let Some(timestamp) = request.metadata.timestamp_millis {
-                record = record.timestamp(timestamp);
-            }
-            if let Some(headers) = request.metadata.headers {
-                record = record.headers(headers);
-            }
+            let headers = request.metadata.headers.take();
+            let record = to_record(&request.metadata, &request.body, headers);
 
             // rdkafka will internally retry forever if the queue is full
+            let started = Instant::now();
             match this.kafka_producer.send(record, Timeout::Never).await {
-                Ok((_partition, _offset)) => {
+                Ok((partition, _offset)) => {
+                    emit!(KafkaMessageDelivered {
+                        topic: &request.metadata.topic,
+                        partition,
+                        latency: started.elapsed(),
+                    });
                     this.bytes_sent.emit(ByteSize(
                         request.body.len() + request.metadata.key.map(|x| x.len()).unwrap_or(0),
                     ));
-                    Ok(KafkaResponse { event_byte_size })
+                    Ok(KafkaResponse {
+                        event_count: 1,
+                        event_byte_size,
+                    })
+                }
+                Err((kafka_err, message)) => {
+                    emit_delivery_error(&kafka_err, &message, started.elapsed());
+                    Err(kafka_err)
+                }
+            }
+        })
+    }
+}
+
+/// Writes batches of requests to Kafka, each within its own transaction.
+///
+/// The producer must have been created with a `transactional.id`, and have had its transactions
+/// initialized. As a producer can only have one transaction open at a time, transactions must not
+/// be written concurrently.
+#[derive(Clone)]
+pub struct KafkaTransactionalService {
+    kafka_producer: FutureProducer<KafkaStatisticsContext>,
+    transaction_timeout: Duration,
+    bytes_sent: Registered<BytesSent>,
+}
+
+impl KafkaTransactionalService {
+    pub(crate) fn new(
+        kafka_producer: FutureProducer<KafkaStatisticsContext>,
+        transaction_timeout: Duration,
+    ) -> Self {
+        Self {
+            kafka_producer,
+            transaction_timeout,
+            bytes_sent: register!(BytesSent::from(Protocol("kafka".into()))),
+        }
+    }
+
+    async fn write(&self, mut transaction: KafkaTransaction) -> Result<usize, KafkaError> {
+        self.kafka_producer.begin_transaction()?;
+
+        let mut deliveries = Vec::with_capacity(transaction.requests.len());
+        let mut byte_size = 0;
+        for request in &mut transaction.requests {
+            let headers = request.metadata.headers.take();
+            let request = &*request;
+            byte_size +=
+                request.body.len() + request.metadata.key.as_ref().map_or(0, |key| key.len());
+
+            let record = to_record(&request.metadata, &request.body, headers);
+            let started = Instant::now();
+            match self.kafka_producer.send_result(record) {
+                Ok(delivery) => {
+                    deliveries.push(delivery.map(move |result| (result, started.elapsed())))
+                }
+                Err((error, _record)) => return Err(error),
+            }
+        }
+
+        // Every delivery is reported before failing, so that the metrics cover all messages.
+        let mut result = Ok(());
+        let deliveries = future::join_all(deliveries).await;
+        for (request, (delivery, latency)) in transaction.requests.iter().zip(deliveries) {
+            match delivery {
+                Ok(Ok((partition, _offset))) => emit!(KafkaMessageDelivered {
+                    topic: &request.metadata.topic,
+                    partition,
+                    latency,
+                }),
+                Ok(Err((error, message))) => {
+                    emit_delivery_error(&error, &message, latency);
+                    result = result.and(Err(error));
+                }
+                Err(_canceled) => result = result.and(Err(KafkaError::Canceled)),
+            }
+        }
+        result?;
+
+        // Committing flushes any messages still queued, and so blocks.
+        let producer = self.kafka_producer.clone();
+        let timeout = self.transaction_timeout;
+        tokio::task::spawn_blocking(move || producer.commit_transaction(timeout))
+            .await
+            .unwrap_or(Err(KafkaError::Canceled))?;
+
+        Ok(byte_size)
+    }
+
+    async fn abort(&self) {
+        let producer = self.kafka_producer.clone();
+        let timeout = self.transaction_timeout;
+        let result = tokio::task::spawn_blocking(move || producer.abort_transaction(timeout))
+            .await
+            .unwrap_or(Err(KafkaError::Canceled));
+        if let Err(error) = result {
+            emit!(KafkaTransactionAbortError { error });
+        }
+    }
+}
+
+impl Service<KafkaTransaction> for KafkaTransactionalService {
+    type Response = KafkaResponse;
+    type Error = KafkaError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, transaction: KafkaTransaction) -> Self::Future {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let metadata = transaction.get_metadata();
+
+            match this.write(transaction).await {
+                Ok(byte_size) => {
+                    this.bytes_sent.emit(ByteSize(byte_size));
+                    Ok(KafkaResponse {
+                        event_count: metadata.event_count(),
+                        event_byte_size: metadata.events_byte_size(),
+                    })
+                }
+                Err(error) => {
+                    // Nothing written within the transaction is visible to consumers reading only
+                    // committed messages once it is aborted, so its events can be safely retried.
+                    this.abort().await;
+                    Err(error)
                 }
-                Err((kafka_err, _original_record)) => Err(kafka_err),
             }
         })
     }
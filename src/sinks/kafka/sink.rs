@@ -3,22 +3,24 @@ use futures::{future, stream::BoxStream, StreamExt};
 use rdkafka::{
     consumer::{BaseConsumer, Consumer},
     error::KafkaError,
-    producer::FutureProducer,
+    producer::{FutureProducer, Producer},
     ClientConfig,
 };
 use snafu::{ResultExt, Snafu};
 use tokio::time::Duration;
 use tower::limit::ConcurrencyLimit;
 
-use super::config::{KafkaRole, KafkaSinkConfig};
+use super::config::{KafkaExactlyOnceConfig, KafkaRole, KafkaSinkConfig};
 use crate::{
     codecs::{Encoder, Transformer},
     event::{Event, LogEvent},
+    internal_events::KafkaTransactionInitError,
     kafka::KafkaStatisticsContext,
     sinks::{
         kafka::{
-            config::QUEUED_MIN_MESSAGES, request_builder::KafkaRequestBuilder,
-            service::KafkaService,
+            config::QUEUED_MIN_MESSAGES,
+            request_builder::KafkaRequestBuilder,
+            service::{KafkaService, KafkaTransaction, KafkaTransactionalService},
         },
         util::{builder::SinkBuilderExt, StreamSink},
     },
@@ -37,10 +39,12 @@ pub(super) enum BuildError {
 pub struct KafkaSink {
     transformer: Transformer,
     encoder: Encoder<()>,
-    service: KafkaService,
+    producer: FutureProducer<KafkaStatisticsContext>,
     topic: Template,
     key_field: Option<String>,
     headers_key: Option<String>,
+    passthrough: bool,
+    exactly_once: Option<KafkaExactlyOnceConfig>,
 }
 
 pub(crate) fn create_producer(
@@ -64,31 +68,76 @@ impl KafkaSink {
             headers_key: config.headers_key,
             transformer,
             encoder,
-            service: KafkaService::new(producer),
+            producer,
             topic: config.topic,
             key_field: config.key_field,
+            passthrough: config.passthrough,
+            exactly_once: config.exactly_once,
         })
     }
 
+    /// Registers the transactional ID of the producer with the cluster, aborting any transaction
+    /// left open by a previous producer with the same ID.
+    ///
+    /// This contacts the cluster, so it is only done once the sink runs, rather than when it is
+    /// built, which also happens when validating the configuration.
+    async fn init_transactions(&self) -> Result<(), KafkaError> {
+        if let Some(exactly_once) = &self.exactly_once {
+            let producer = self.producer.clone();
+            let timeout = exactly_once.transaction_timeout_ms;
+            tokio::task::spawn_blocking(move || producer.init_transactions(timeout))
+                .await
+                .unwrap_or(Err(KafkaError::Canceled))?;
+        }
+        Ok(())
+    }
+
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
-        // rdkafka will internally retry forever, so we need some limit to prevent this from overflowing
-        let service = ConcurrencyLimit::new(self.service, QUEUED_MIN_MESSAGES as usize);
+        if let Err(error) = self.init_transactions().await {
+            emit!(KafkaTransactionInitError { error });
+            return Err(());
+        }
+
         let mut request_builder = KafkaRequestBuilder {
             key_field: self.key_field,
             headers_key: self.headers_key,
+            passthrough: self.passthrough,
             topic_template: self.topic,
             transformer: self.transformer,
             encoder: self.encoder,
         };
 
-        input
-            .filter_map(|event|
+        let requests = input.filter_map(|event|
                 // request_builder is fallible but the places it can fail are emitting
                 // `Error` and `DroppedEvent` internal events appropriately so no need to here.
-                future::ready(request_builder.build_request(event)))
-            .into_driver(service)
-            .run()
-            .await
+                future::ready(request_builder.build_request(event)));
+
+        match self.exactly_once {
+            None => {
+                // rdkafka will internally retry forever, so we need some limit to prevent this from overflowing
+                let service = ConcurrencyLimit::new(
+                    KafkaService::new(self.producer),
+                    QUEUED_MIN_MESSAGES as usize,
+                );
+                requests.into_driver(service).run().await
+            }
+            Some(exactly_once) => {
+                // A producer can only have a single transaction open at a time.
+                let service = ConcurrencyLimit::new(
+                    KafkaTransactionalService::new(
+                        self.producer,
+                        exactly_once.transaction_timeout_ms,
+                    ),
+                    1,
+                );
+                requests
+                    .ready_chunks(exactly_once.max_events.get())
+                    .map(KafkaTransaction::new)
+                    .into_driver(service)
+                    .run()
+                    .await
+            }
+        }
     }
 }
 
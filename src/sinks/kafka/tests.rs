@@ -25,7 +25,7 @@ mod integration_test {
         kafka::{KafkaAuthConfig, KafkaCompression, KafkaSaslConfig},
         sinks::{
             kafka::{
-                config::{KafkaRole, KafkaSinkConfig},
+                config::{KafkaExactlyOnceConfig, KafkaProfile, KafkaRole, KafkaSinkConfig},
                 sink::KafkaSink,
                 *,
             },
@@ -60,12 +60,16 @@ mod integration_test {
             key_field: None,
             encoding: TextSerializerConfig::default().into(),
             batch: BatchConfig::default(),
-            compression: KafkaCompression::None,
+            compression: Some(KafkaCompression::None),
+            profile: KafkaProfile::Kafka,
+            produce_latency_budget_ms: None,
             auth: KafkaAuthConfig::default(),
             socket_timeout_ms: Duration::from_millis(60000),
             message_timeout_ms: Duration::from_millis(300000),
             librdkafka_options: HashMap::new(),
             headers_key: None,
+            passthrough: false,
+            exactly_once: None,
             acknowledgements: Default::default(),
         };
         self::sink::healthcheck(config).await.unwrap();
@@ -109,7 +113,9 @@ mod integration_test {
         let config = KafkaSinkConfig {
             bootstrap_servers: kafka_address(9091),
             topic: Template::try_from(format!("{}-%Y%m%d", topic)).unwrap(),
-            compression: KafkaCompression::None,
+            compression: Some(KafkaCompression::None),
+            profile: KafkaProfile::Kafka,
+            produce_latency_budget_ms: None,
             encoding: TextSerializerConfig::default().into(),
             key_field: None,
             auth: KafkaAuthConfig {
@@ -121,6 +127,8 @@ mod integration_test {
             batch,
             librdkafka_options,
             headers_key: None,
+            passthrough: false,
+            exactly_once: None,
             acknowledgements: Default::default(),
         };
         config.clone().to_rdkafka(KafkaRole::Consumer)?;
@@ -231,6 +239,80 @@ mod integration_test {
         .await;
     }
 
+    #[tokio::test]
+    async fn kafka_exactly_once() {
+        crate::test_util::trace_init();
+
+        let server = kafka_address(9091);
+        let topic = format!("test-{}", random_string(10));
+        let config = KafkaSinkConfig {
+            bootstrap_servers: server.clone(),
+            topic: Template::try_from(topic.clone()).unwrap(),
+            key_field: None,
+            encoding: TextSerializerConfig::default().into(),
+            batch: BatchConfig::default(),
+            compression: Some(KafkaCompression::None),
+            profile: KafkaProfile::Kafka,
+            produce_latency_budget_ms: None,
+            auth: KafkaAuthConfig::default(),
+            socket_timeout_ms: Duration::from_millis(60000),
+            message_timeout_ms: Duration::from_millis(300000),
+            librdkafka_options: HashMap::new(),
+            headers_key: None,
+            passthrough: false,
+            exactly_once: Some(KafkaExactlyOnceConfig {
+                transactional_id: format!("vector-{}", random_string(10)),
+                transaction_timeout_ms: Duration::from_millis(60000),
+                max_events: 100.try_into().unwrap(),
+            }),
+            acknowledgements: Default::default(),
+        };
+
+        let num_events = 1000;
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let (input, events) = random_lines_with_stream(100, num_events, Some(batch));
+
+        assert_sink_compliance(&SINK_TAGS, async move {
+            let sink = KafkaSink::new(config).unwrap();
+            let sink = VectorSink::from_event_streamsink(sink);
+            sink.run(events).await
+        })
+        .await
+        .expect("Running sink failed");
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+        // only committed messages are read back
+        let mut client_config = rdkafka::ClientConfig::new();
+        client_config.set("bootstrap.servers", server.as_str());
+        client_config.set("group.id", &random_string(10));
+        client_config.set("isolation.level", "read_committed");
+
+        let mut tpl = TopicPartitionList::new();
+        tpl.add_partition(&topic, 0)
+            .set_offset(Offset::Beginning)
+            .unwrap();
+
+        let consumer: BaseConsumer = client_config.create().unwrap();
+        consumer.assign(&tpl).unwrap();
+
+        let mut failures = 0;
+        let mut out = Vec::new();
+        while failures < 100 && out.len() < input.len() {
+            match consumer.poll(Duration::from_secs(3)) {
+                Some(Ok(msg)) => {
+                    let s: &str = msg.payload_view().unwrap().unwrap();
+                    out.push(s.to_owned());
+                }
+                _ => {
+                    failures += 1;
+                    thread::sleep(Duration::from_millis(50));
+                }
+            }
+        }
+
+        assert_eq!(out, input);
+    }
+
     async fn kafka_happy_path(
         server: String,
         sasl: Option<KafkaSaslConfig>,
@@ -246,12 +328,16 @@ mod integration_test {
             key_field: None,
             encoding: TextSerializerConfig::default().into(),
             batch: BatchConfig::default(),
-            compression,
+            compression: Some(compression),
+            profile: KafkaProfile::Kafka,
+            produce_latency_budget_ms: None,
             auth: kafka_auth.clone(),
             socket_timeout_ms: Duration::from_millis(60000),
             message_timeout_ms: Duration::from_millis(300000),
             librdkafka_options: HashMap::new(),
             headers_key: Some(headers_key.clone()),
+            passthrough: false,
+            exactly_once: None,
             acknowledgements: Default::default(),
         };
         let topic = format!("{}-{}", topic, chrono::Utc::now().format("%Y%m%d"));
@@ -109,6 +109,28 @@ pub struct LokiConfig {
     #[serde(default = "crate::serde::default_false")]
     pub remove_label_fields: bool,
 
+    /// A set of [structured metadata][structured_metadata] that is attached to each event.
+    ///
+    /// Unlike labels, structured metadata isn't indexed and doesn't define streams, so it's suited
+    /// to high-cardinality values such as trace IDs, which would otherwise put pressure on Loki's
+    /// index. Keys and values are templateable, and keys support the same expansion of objects as
+    /// [labels][label_expansion].
+    ///
+    /// Requires Loki 3.0 or newer, with structured metadata enabled.
+    ///
+    /// [structured_metadata]: https://grafana.com/docs/loki/latest/get-started/labels/structured-metadata/
+    /// [label_expansion]: https://vector.dev/docs/reference/configuration/sinks/loki/#label-expansion
+    #[configurable(metadata(docs::examples = "loki_structured_metadata_examples()"))]
+    #[configurable(metadata(
+        docs::additional_props_description = "A Loki structured metadata entry."
+    ))]
+    #[serde(default)]
+    pub structured_metadata: HashMap<Template, Template>,
+
+    /// Whether or not to delete fields from the event when they are used as structured metadata.
+    #[serde(default = "crate::serde::default_false")]
+    pub remove_structured_metadata_fields: bool,
+
     /// Whether or not to remove the timestamp from the event payload.
     ///
     /// The timestamp is still sent as event metadata for Loki to use for indexing.
@@ -161,6 +183,16 @@ fn loki_labels_examples() -> HashMap<String, String> {
     examples
 }
 
+fn loki_structured_metadata_examples() -> HashMap<String, String> {
+    let mut examples = HashMap::new();
+    examples.insert("trace_id".to_string(), "{{ trace_id }}".to_string());
+    examples.insert(
+        "\"pod_annotations_*\"".to_string(),
+        "{{ kubernetes.pod_annotations }}".to_string(),
+    );
+    examples
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct LokiDefaultBatchSettings;
 
@@ -214,7 +246,7 @@ impl GenerateConfig for LokiConfig {
 impl LokiConfig {
     pub(super) fn build_client(&self, cx: SinkContext) -> crate::Result<HttpClient> {
         let tls = TlsSettings::from_options(&self.tls)?;
-        let client = HttpClient::new(tls, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
         Ok(client)
     }
 }
@@ -235,6 +267,12 @@ impl SinkConfig for LokiConfig {
             }
         }
 
+        for key in self.structured_metadata.keys() {
+            if !valid_label_name(key) {
+                return Err(format!("Invalid structured metadata name {:?}", key.get_ref()).into());
+            }
+        }
+
         let client = self.build_client(cx)?;
 
         let config = LokiConfig {
@@ -5,6 +5,7 @@ use serde::{ser::SerializeSeq, Serialize};
 use vector_buffers::EventCount;
 use vector_core::{
     event::{EventFinalizers, Finalizable},
+    stream::batcher::limiter::EncodedSize,
     ByteSizeOf, EstimatedJsonEncodedSizeOf,
 };
 
@@ -43,11 +44,12 @@ impl Encoder<Vec<LokiRecord>> for LokiBatchEncoder {
                             let labels = stream.stream;
                             let entries = stream
                                 .values
-                                .iter()
+                                .into_iter()
                                 .map(|event| {
                                     loki_logproto::util::Entry(
                                         event.timestamp,
                                         String::from_utf8_lossy(&event.event).into_owned(),
+                                        event.structured_metadata,
                                     )
                                 })
                                 .collect();
@@ -62,6 +64,31 @@ impl Encoder<Vec<LokiRecord>> for LokiBatchEncoder {
     }
 }
 
+/// The size of the envelope of the JSON encoded batch, `{"streams":[]}`.
+pub const BATCH_OVERHEAD: usize = 14;
+
+/// The size of a string once encoded as a JSON string, including its quotes and escape sequences.
+fn json_string_size(s: &str) -> usize {
+    2 + s
+        .bytes()
+        .map(|b| match b {
+            b'"' | b'\\' | b'\n' | b'\r' | b'\t' | 0x08 | 0x0c => 2,
+            0..=0x1f => 6,
+            _ => 1,
+        })
+        .sum::<usize>()
+}
+
+/// The size of key-value pairs once encoded as a JSON object.
+fn json_object_size(pairs: &[(String, String)]) -> usize {
+    let separators = pairs.len().saturating_sub(1);
+    2 + separators
+        + pairs
+            .iter()
+            .map(|(key, value)| json_string_size(key) + 1 + json_string_size(value))
+            .sum::<usize>()
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct LokiBatch {
     stream_by_labels: HashMap<String, LokiStream>,
@@ -130,11 +157,18 @@ impl From<Vec<LokiRecord>> for LokiBatch {
 pub struct LokiEvent {
     pub timestamp: i64,
     pub event: Bytes,
+    /// The structured metadata of the event, sent alongside the line without being indexed as
+    /// labels.
+    pub structured_metadata: Labels,
 }
 
 impl ByteSizeOf for LokiEvent {
     fn allocated_bytes(&self) -> usize {
-        self.timestamp.allocated_bytes() + self.event.allocated_bytes()
+        self.timestamp.allocated_bytes()
+            + self.event.allocated_bytes()
+            + self.structured_metadata.iter().fold(0, |res, item| {
+                res + item.0.allocated_bytes() + item.1.allocated_bytes()
+            })
     }
 }
 
@@ -145,11 +179,30 @@ impl EstimatedJsonEncodedSizeOf for LokiEvent {
         static COLON_SIZE: usize = 1;
         static QUOTES_SIZE: usize = 2;
 
+        let structured_metadata_size = if self.structured_metadata.is_empty() {
+            0
+        } else {
+            // The separating comma, then the object of structured metadata.
+            COLON_SIZE
+                + BRACKETS_SIZE
+                + self
+                    .structured_metadata
+                    .iter()
+                    .map(|(key, value)| {
+                        key.estimated_json_encoded_size_of()
+                            + COLON_SIZE
+                            + value.estimated_json_encoded_size_of()
+                            + COLON_SIZE
+                    })
+                    .sum::<usize>()
+        };
+
         BRACKETS_SIZE
             + QUOTES_SIZE
             + self.timestamp.estimated_json_encoded_size_of()
             + COLON_SIZE
             + self.event.estimated_json_encoded_size_of()
+            + structured_metadata_size
     }
 }
 
@@ -158,10 +211,23 @@ impl Serialize for LokiEvent {
     where
         S: serde::Serializer,
     {
-        let mut seq = serializer.serialize_seq(Some(2))?;
+        let len = if self.structured_metadata.is_empty() {
+            2
+        } else {
+            3
+        };
+        let mut seq = serializer.serialize_seq(Some(len))?;
         seq.serialize_element(&self.timestamp.to_string())?;
         let event = String::from_utf8_lossy(&self.event);
         seq.serialize_element(&event)?;
+        if !self.structured_metadata.is_empty() {
+            let structured_metadata: HashMap<&str, &str> = self
+                .structured_metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect();
+            seq.serialize_element(&structured_metadata)?;
+        }
         seq.end()
     }
 }
@@ -190,6 +256,35 @@ impl EstimatedJsonEncodedSizeOf for LokiRecord {
     }
 }
 
+/// The size that the record adds to a JSON encoded batch, which is also an upper bound of what it
+/// adds to a protobuf encoded batch.
+///
+/// Records are counted as if each of them was in its own stream, as they're grouped into streams
+/// only once the batch is encoded, so this overestimates the size of batches of records sharing
+/// labels.
+impl EncodedSize for LokiRecord {
+    fn encoded_size(&self) -> usize {
+        // `{"stream":`, the labels, `,"values":[`, the event, `]}`, and the separators of streams
+        // and of values.
+        const STREAM_OVERHEAD: usize = 10 + 11 + 2 + 2;
+
+        let line = String::from_utf8_lossy(&self.event.event);
+        let structured_metadata_size = if self.event.structured_metadata.is_empty() {
+            0
+        } else {
+            1 + json_object_size(&self.event.structured_metadata)
+        };
+        let event_size = 2
+            + self.event.timestamp.estimated_json_encoded_size_of()
+            + 2
+            + 1
+            + json_string_size(&line)
+            + structured_metadata_size;
+
+        STREAM_OVERHEAD + json_object_size(&self.labels) + event_size
+    }
+}
+
 impl EventCount for LokiRecord {
     fn event_count(&self) -> usize {
         // A Loki record is mapped one-to-one with an event.
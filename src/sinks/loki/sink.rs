@@ -11,13 +11,15 @@ use vector_core::{
     event::{Event, EventFinalizers, Finalizable, Value},
     partition::Partitioner,
     sink::StreamSink,
-    stream::BatcherSettings,
-    ByteSizeOf,
+    stream::{
+        batcher::limiter::{EncodedSize, EncodedSizeOfItemSize},
+        BatcherSettings,
+    },
 };
 
 use super::{
     config::{LokiConfig, OutOfOrderAction},
-    event::{LokiBatchEncoder, LokiEvent, LokiRecord, PartitionKey},
+    event::{LokiBatchEncoder, LokiEvent, LokiRecord, PartitionKey, BATCH_OVERHEAD},
     service::{LokiRequest, LokiRetryLogic, LokiService},
 };
 use crate::sinks::loki::event::LokiBatchEncoding;
@@ -158,110 +160,29 @@ pub(super) struct EventEncoder {
     encoder: Encoder<()>,
     labels: HashMap<Template, Template>,
     remove_label_fields: bool,
+    structured_metadata: HashMap<Template, Template>,
+    remove_structured_metadata_fields: bool,
     remove_timestamp: bool,
 }
 
 impl EventEncoder {
     fn build_labels(&self, event: &Event) -> Vec<(String, String)> {
-        let mut static_labels: HashMap<String, String> = HashMap::new();
-        let mut dynamic_labels: HashMap<String, String> = HashMap::new();
-
-        for (key_template, value_template) in self.labels.iter() {
-            let key = key_template.render_string(event);
-            let value = value_template.render_string(event);
-
-            if key.is_err() || value.is_err() {
-                if key.is_err() {
-                    emit!(TemplateRenderingError {
-                        field: Some(
-                            format!(
-                                "label_key \"{}\" with label_value \"{}\"",
-                                key_template, value_template
-                            )
-                            .as_str()
-                        ),
-                        drop_event: false,
-                        error: key.err().unwrap(),
-                    });
-                }
-                if value.is_err() {
-                    emit!(TemplateRenderingError {
-                        field: Some(
-                            format!(
-                                "label_value \"{}\" with label_key \"{}\"",
-                                value_template, key_template
-                            )
-                            .as_str()
-                        ),
-                        drop_event: false,
-                        error: value.err().unwrap(),
-                    });
-                }
-                continue;
-            }
-
-            let key_s = key.unwrap();
-            let value_s = value.unwrap();
-
-            if let Some(opening_prefix) = key_s.strip_suffix('*') {
-                let output: Result<
-                    serde_json::map::Map<String, serde_json::Value>,
-                    serde_json::Error,
-                > = serde_json::from_str(value_s.clone().as_str());
-
-                if output.is_err() {
-                    warn!(
-                        "Failed to expand dynamic label. value: {}, err: {}",
-                        value_s,
-                        output.err().unwrap()
-                    );
-                    continue;
-                }
-
-                // key_* -> key_one, key_two, key_three
-                // * -> one, two, three
-                for (k, v) in output.unwrap() {
-                    let key = slugify_text(format!("{}{}", opening_prefix, k));
-                    let val = Value::from(v).to_string_lossy().into_owned();
-                    if val == "<null>" {
-                        warn!("Encountered \"null\" value for dynamic label. key: {}", key);
-                        continue;
-                    }
-                    if let Some(prev) = dynamic_labels.insert(key.clone(), val.clone()) {
-                        warn!(
-                            "Encountered duplicated dynamic label. \
-                                key: {}, value: {}, discarded value: {}",
-                            key, val, prev
-                        );
-                    };
-                }
-            } else {
-                static_labels.insert(key_s, value_s);
-            }
-        }
-
-        for (k, v) in static_labels {
-            if let Some(discarded_v) = dynamic_labels.insert(k.clone(), v.clone()) {
-                warn!(
-                    "Static label overrides dynamic label. \
-                key: {}, value: {}, discarded value: {}",
-                    k, v, discarded_v
-                );
-            };
-        }
+        render_key_values(&self.labels, event)
+    }
 
-        Vec::from_iter(dynamic_labels)
+    fn build_structured_metadata(&self, event: &Event) -> Vec<(String, String)> {
+        let mut structured_metadata = render_key_values(&self.structured_metadata, event);
+        // Sorted, so that the encoded entries are stable.
+        structured_metadata.sort();
+        structured_metadata
     }
 
     fn remove_label_fields(&self, event: &mut Event) {
         if self.remove_label_fields {
-            for template in self.labels.values() {
-                if let Some(fields) = template.get_fields() {
-                    for field in fields {
-                        event.as_mut_log().remove(field.as_str());
-                    }
-                }
-            }
+            remove_template_fields(&self.labels, event);
+        }
+        if self.remove_structured_metadata_fields {
+            remove_template_fields(&self.structured_metadata, event);
         }
     }
 
@@ -269,6 +190,7 @@ impl EventEncoder {
         let tenant_id = self.key_partitioner.partition(&event);
         let finalizers = event.take_finalizers();
         let mut labels = self.build_labels(&event);
+        let structured_metadata = self.build_structured_metadata(&event);
         self.remove_label_fields(&mut event);
 
         let timestamp = match event.as_log().get_timestamp() {
@@ -299,6 +221,7 @@ impl EventEncoder {
             event: LokiEvent {
                 timestamp,
                 event: bytes.freeze(),
+                structured_metadata,
             },
             partition,
             finalizers,
@@ -306,6 +229,110 @@ impl EventEncoder {
     }
 }
 
+/// Renders the templated keys and values of labels or structured metadata, expanding the objects
+/// of keys ending with `*` into one entry per field.
+fn render_key_values(
+    templates: &HashMap<Template, Template>,
+    event: &Event,
+) -> Vec<(String, String)> {
+    let mut static_labels: HashMap<String, String> = HashMap::new();
+    let mut dynamic_labels: HashMap<String, String> = HashMap::new();
+
+    for (key_template, value_template) in templates.iter() {
+        let key = key_template.render_string(event);
+        let value = value_template.render_string(event);
+
+        if key.is_err() || value.is_err() {
+            if key.is_err() {
+                emit!(TemplateRenderingError {
+                    field: Some(
+                        format!(
+                            "label_key \"{}\" with label_value \"{}\"",
+                            key_template, value_template
+                        )
+                        .as_str()
+                    ),
+                    drop_event: false,
+                    error: key.err().unwrap(),
+                });
+            }
+            if value.is_err() {
+                emit!(TemplateRenderingError {
+                    field: Some(
+                        format!(
+                            "label_value \"{}\" with label_key \"{}\"",
+                            value_template, key_template
+                        )
+                        .as_str()
+                    ),
+                    drop_event: false,
+                    error: value.err().unwrap(),
+                });
+            }
+            continue;
+        }
+
+        let key_s = key.unwrap();
+        let value_s = value.unwrap();
+
+        if let Some(opening_prefix) = key_s.strip_suffix('*') {
+            let output: Result<serde_json::map::Map<String, serde_json::Value>, serde_json::Error> =
+                serde_json::from_str(value_s.clone().as_str());
+
+            if output.is_err() {
+                warn!(
+                    "Failed to expand dynamic label. value: {}, err: {}",
+                    value_s,
+                    output.err().unwrap()
+                );
+                continue;
+            }
+
+            // key_* -> key_one, key_two, key_three
+            // * -> one, two, three
+            for (k, v) in output.unwrap() {
+                let key = slugify_text(format!("{}{}", opening_prefix, k));
+                let val = Value::from(v).to_string_lossy().into_owned();
+                if val == "<null>" {
+                    warn!("Encountered \"null\" value for dynamic label. key: {}", key);
+                    continue;
+                }
+                if let Some(prev) = dynamic_labels.insert(key.clone(), val.clone()) {
+                    warn!(
+                        "Encountered duplicated dynamic label. \
+                                key: {}, value: {}, discarded value: {}",
+                        key, val, prev
+                    );
+                };
+            }
+        } else {
+            static_labels.insert(key_s, value_s);
+        }
+    }
+
+    for (k, v) in static_labels {
+        if let Some(discarded_v) = dynamic_labels.insert(k.clone(), v.clone()) {
+            warn!(
+                "Static label overrides dynamic label. \
+                key: {}, value: {}, discarded value: {}",
+                k, v, discarded_v
+            );
+        };
+    }
+
+    Vec::from_iter(dynamic_labels)
+}
+
+fn remove_template_fields(templates: &HashMap<Template, Template>, event: &mut Event) {
+    for template in templates.values() {
+        if let Some(fields) = template.get_fields() {
+            for field in fields {
+                event.as_mut_log().remove(field.as_str());
+            }
+        }
+    }
+}
+
 struct FilteredRecord {
     pub rewritten: bool,
     pub inner: LokiRecord,
@@ -331,13 +358,9 @@ impl FilteredRecord {
     }
 }
 
-impl ByteSizeOf for FilteredRecord {
-    fn size_of(&self) -> usize {
-        self.inner.size_of()
-    }
-
-    fn allocated_bytes(&self) -> usize {
-        self.inner.allocated_bytes()
+impl EncodedSize for FilteredRecord {
+    fn encoded_size(&self) -> usize {
+        self.inner.encoded_size()
     }
 }
 
@@ -412,6 +435,7 @@ impl LokiSink {
 
         let protocol = get_http_scheme_from_uri(&config.endpoint.uri);
         let service = tower::ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, LokiRetryLogic)
             .service(LokiService::new(
                 client,
@@ -441,6 +465,8 @@ impl LokiSink {
                 encoder,
                 labels: config.labels,
                 remove_label_fields: config.remove_label_fields,
+                structured_metadata: config.structured_metadata,
+                remove_structured_metadata_fields: config.remove_structured_metadata_fields,
                 remove_timestamp: config.remove_timestamp,
             },
             batch_settings: config.batch.into_batcher_settings()?,
@@ -467,7 +493,13 @@ impl LokiSink {
             .map(|event| encoder.encode_event(event))
             .filter_map(|event| async { event })
             .map(|record| filter.filter_record(record))
-            .batched_partitioned(RecordPartitioner::default(), self.batch_settings)
+            .batched_partitioned_by_size(
+                RecordPartitioner::default(),
+                self.batch_settings,
+                EncodedSizeOfItemSize {
+                    batch_overhead: BATCH_OVERHEAD,
+                },
+            )
             .filter_map(|(partition, batch)| async {
                 if let Some(partition) = partition {
                     let mut count: usize = 0;
@@ -532,10 +564,21 @@ mod tests {
     use futures::stream::StreamExt;
     use vector_core::event::{Event, LogEvent, Value};
 
+    use vector_core::stream::batcher::limiter::EncodedSize;
+
     use super::{EventEncoder, KeyPartitioner, RecordFilter};
     use crate::{
-        codecs::Encoder, config::log_schema, sinks::loki::config::OutOfOrderAction,
-        template::Template, test_util::random_lines,
+        codecs::Encoder,
+        config::log_schema,
+        sinks::{
+            loki::{
+                config::OutOfOrderAction,
+                event::{LokiBatchEncoder, LokiBatchEncoding, LokiRecord, BATCH_OVERHEAD},
+            },
+            util::encoding::Encoder as _,
+        },
+        template::Template,
+        test_util::random_lines,
     };
 
     #[test]
@@ -546,6 +589,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels: HashMap::default(),
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -592,6 +637,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels,
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -623,6 +670,51 @@ mod tests {
         assert_eq!(labels["test_key_two"], "baz".to_string());
     }
 
+    #[test]
+    fn encoded_size_bounds_json_batch() {
+        let mut structured_metadata = HashMap::default();
+        structured_metadata.insert(
+            Template::try_from("trace_id").unwrap(),
+            Template::try_from("{{ trace_id }}").unwrap(),
+        );
+        let mut encoder = EventEncoder {
+            key_partitioner: KeyPartitioner::new(None),
+            transformer: Default::default(),
+            encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
+            labels: HashMap::default(),
+            remove_label_fields: false,
+            structured_metadata,
+            remove_structured_metadata_fields: false,
+            remove_timestamp: false,
+        };
+        let records = ["hello \"world\"", "tab\tand\u{1}control", "naïve"]
+            .into_iter()
+            .map(|message| {
+                let mut event = Event::Log(LogEvent::from(message));
+                event.as_mut_log().insert("trace_id", "abc");
+                encoder.encode_event(event).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let encoded_size = |records: &[LokiRecord]| {
+            BATCH_OVERHEAD + records.iter().map(EncodedSize::encoded_size).sum::<usize>()
+        };
+        let encode = |records: Vec<LokiRecord>| {
+            let mut body = Vec::new();
+            LokiBatchEncoder(LokiBatchEncoding::Json)
+                .encode_input(records, &mut body)
+                .unwrap()
+        };
+
+        // A single record is sized exactly, but for the separators of streams and values.
+        let single = encoded_size(&records[..1]);
+        assert_eq!(encode(records[..1].to_vec()) + 2, single);
+
+        // Records sharing labels are grouped into a single stream.
+        let all = encoded_size(&records);
+        assert!(encode(records) <= all);
+    }
+
     #[test]
     fn encoder_with_dynamic_labels() -> Result<(), serde_json::Error> {
         let mut labels = HashMap::default();
@@ -645,6 +737,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels,
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
 
@@ -695,6 +789,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels,
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
 
@@ -733,6 +829,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels,
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
 
@@ -754,6 +852,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels: HashMap::default(),
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: true,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -787,6 +887,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels,
             remove_label_fields: true,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -812,6 +914,8 @@ mod tests {
             encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
             labels: HashMap::default(),
             remove_label_fields: false,
+            structured_metadata: HashMap::default(),
+            remove_structured_metadata_fields: false,
             remove_timestamp: false,
         };
         let base = chrono::Utc::now();
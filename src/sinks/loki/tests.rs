@@ -86,6 +86,54 @@ async fn use_label_from_dropped_fields() {
     assert_eq!(record.labels[0], ("bar".to_string(), "bar".to_string()));
 }
 
+#[tokio::test]
+async fn interpolate_structured_metadata() {
+    let (config, cx) = load_sink::<LokiConfig>(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels.app = "vector"
+        structured_metadata = {trace_id = "{{ trace_id }}", "attr_*" = "{{ attributes }}"}
+        encoding.codec = "json"
+        remove_structured_metadata_fields = true
+    "#,
+    )
+    .unwrap();
+    let client = config.build_client(cx).unwrap();
+    let mut sink = LokiSink::new(config, client).unwrap();
+
+    let mut e1 = Event::Log(LogEvent::from("hello world"));
+    e1.as_mut_log().insert("trace_id", "abcd");
+    e1.as_mut_log().insert("attributes.user", "alice");
+
+    let record = sink.encoder.encode_event(e1).unwrap();
+
+    // The structured metadata doesn't create labels, and its fields are removed from the line.
+    assert_eq!(
+        record.labels,
+        vec![("app".to_string(), "vector".to_string())]
+    );
+    let expected_line = serde_json::to_string(&serde_json::json!({
+        "message": "hello world",
+    }))
+    .unwrap();
+    assert_eq!(record.event.event, expected_line);
+    assert_eq!(
+        record.event.structured_metadata,
+        vec![
+            ("attr_user".to_string(), "alice".to_string()),
+            ("trace_id".to_string(), "abcd".to_string()),
+        ]
+    );
+
+    // Structured metadata is sent as the third element of the entry.
+    let entry = serde_json::to_value(&record.event).unwrap();
+    assert_eq!(entry[1], serde_json::json!(expected_line));
+    assert_eq!(
+        entry[2],
+        serde_json::json!({"attr_user": "alice", "trace_id": "abcd"})
+    );
+}
+
 #[tokio::test]
 async fn healthcheck_includes_auth() {
     let (mut config, _cx) = load_sink::<LokiConfig>(
@@ -28,12 +28,16 @@ pub mod axiom;
 pub mod azure_blob;
 #[cfg(any(feature = "sinks-azure_blob", feature = "sinks-datadog_archives"))]
 pub mod azure_common;
+#[cfg(feature = "sinks-azure_event_hubs")]
+pub mod azure_event_hubs;
 #[cfg(feature = "sinks-azure_monitor_logs")]
 pub mod azure_monitor_logs;
 #[cfg(feature = "sinks-blackhole")]
 pub mod blackhole;
 #[cfg(feature = "sinks-clickhouse")]
 pub mod clickhouse;
+#[cfg(feature = "sinks-clickhouse_native")]
+pub mod clickhouse_native;
 #[cfg(feature = "sinks-console")]
 pub mod console;
 #[cfg(feature = "sinks-databend")]
@@ -69,12 +73,16 @@ pub mod kafka;
 pub mod loki;
 #[cfg(feature = "sinks-mezmo")]
 pub mod mezmo;
+#[cfg(feature = "sinks-mqtt")]
+pub mod mqtt;
 #[cfg(feature = "sinks-nats")]
 pub mod nats;
 #[cfg(feature = "sinks-new_relic")]
 pub mod new_relic;
 #[cfg(feature = "sinks-webhdfs")]
 pub mod opendal_common;
+#[cfg(feature = "sinks-opentelemetry")]
+pub mod opentelemetry;
 #[cfg(feature = "sinks-papertrail")]
 pub mod papertrail;
 #[cfg(feature = "sinks-prometheus")]
@@ -90,6 +98,8 @@ pub mod redis;
 pub mod s3_common;
 #[cfg(feature = "sinks-sematext")]
 pub mod sematext;
+#[cfg(feature = "sinks-snowflake")]
+pub mod snowflake;
 #[cfg(feature = "sinks-socket")]
 pub mod socket;
 #[cfg(feature = "sinks-splunk_hec")]
@@ -183,6 +193,11 @@ pub enum Sinks {
     #[configurable(metadata(docs::human_name = "Azure Blob Storage"))]
     AzureBlob(azure_blob::AzureBlobSinkConfig),
 
+    /// Publish observability events to Azure Event Hubs.
+    #[cfg(feature = "sinks-azure_event_hubs")]
+    #[configurable(metadata(docs::human_name = "Azure Event Hubs"))]
+    AzureEventHubs(azure_event_hubs::AzureEventHubsSinkConfig),
+
     /// Publish log events to the Azure Monitor Logs service.
     #[cfg(feature = "sinks-azure_monitor_logs")]
     AzureMonitorLogs(azure_monitor_logs::AzureMonitorLogsConfig),
@@ -195,6 +210,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-clickhouse")]
     Clickhouse(clickhouse::ClickhouseConfig),
 
+    /// Deliver log data to a ClickHouse database over the native TCP protocol.
+    #[cfg(feature = "sinks-clickhouse_native")]
+    ClickhouseNative(clickhouse_native::ClickhouseNativeConfig),
+
     /// Display observability events in the console, which can be useful for debugging purposes.
     #[cfg(feature = "sinks-console")]
     Console(console::ConsoleSinkConfig),
@@ -231,6 +250,11 @@ pub enum Sinks {
     #[cfg(feature = "sinks-file")]
     File(file::FileSinkConfig),
 
+    /// Map log events to UDM events, and store them in Google Chronicle.
+    #[cfg(feature = "sinks-gcp")]
+    #[configurable(metadata(docs::human_name = "GCP Chronicle UDM"))]
+    GcpChronicleUdm(gcp::chronicle_udm::ChronicleUdmConfig),
+
     /// Store unstructured log events in Google Chronicle.
     #[cfg(feature = "sinks-gcp")]
     GcpChronicleUnstructured(gcp::chronicle_unstructured::ChronicleUnstructuredConfig),
@@ -297,6 +321,11 @@ pub enum Sinks {
     #[cfg(feature = "sinks-loki")]
     Loki(loki::LokiConfig),
 
+    /// Publish observability events to topics on an MQTT broker.
+    #[cfg(feature = "sinks-mqtt")]
+    #[configurable(metadata(docs::human_name = "MQTT"))]
+    Mqtt(mqtt::MqttSinkConfig),
+
     /// Publish observability data to subjects on the NATS messaging system.
     #[cfg(feature = "sinks-nats")]
     Nats(self::nats::NatsSinkConfig),
@@ -305,6 +334,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-new_relic")]
     NewRelic(new_relic::NewRelicConfig),
 
+    /// Export logs, metrics, and traces to an OpenTelemetry Protocol (OTLP) receiver.
+    #[cfg(feature = "sinks-opentelemetry")]
+    Opentelemetry(opentelemetry::OpentelemetryConfig),
+
     /// Deliver log events to Papertrail from SolarWinds.
     #[cfg(feature = "sinks-papertrail")]
     Papertrail(papertrail::PapertrailConfig),
@@ -333,6 +366,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-sematext")]
     SematextMetrics(sematext::metrics::SematextMetricsConfig),
 
+    /// Stream log events into Snowflake with Snowpipe Streaming.
+    #[cfg(feature = "sinks-snowflake")]
+    Snowflake(snowflake::SnowflakeConfig),
+
     /// Deliver logs to a remote socket endpoint.
     #[cfg(feature = "sinks-socket")]
     Socket(socket::SocketSinkConfig),
@@ -411,12 +448,16 @@ impl NamedComponent for Sinks {
             Self::Axiom(config) => config.get_component_name(),
             #[cfg(feature = "sinks-azure_blob")]
             Self::AzureBlob(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-azure_event_hubs")]
+            Self::AzureEventHubs(config) => config.get_component_name(),
             #[cfg(feature = "sinks-azure_monitor_logs")]
             Self::AzureMonitorLogs(config) => config.get_component_name(),
             #[cfg(feature = "sinks-blackhole")]
             Self::Blackhole(config) => config.get_component_name(),
             #[cfg(feature = "sinks-clickhouse")]
             Self::Clickhouse(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-clickhouse_native")]
+            Self::ClickhouseNative(config) => config.get_component_name(),
             #[cfg(feature = "sinks-console")]
             Self::Console(config) => config.get_component_name(),
             #[cfg(feature = "sinks-databend")]
@@ -436,6 +477,8 @@ impl NamedComponent for Sinks {
             #[cfg(feature = "sinks-file")]
             Self::File(config) => config.get_component_name(),
             #[cfg(feature = "sinks-gcp")]
+            Self::GcpChronicleUdm(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-gcp")]
             Self::GcpChronicleUnstructured(config) => config.get_component_name(),
             #[cfg(feature = "sinks-gcp")]
             Self::GcpStackdriverLogs(config) => config.get_component_name(),
@@ -467,10 +510,14 @@ impl NamedComponent for Sinks {
             Self::Logdna(config) => config.get_component_name(),
             #[cfg(feature = "sinks-loki")]
             Self::Loki(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-mqtt")]
+            Self::Mqtt(config) => config.get_component_name(),
             #[cfg(feature = "sinks-nats")]
             Self::Nats(config) => config.get_component_name(),
             #[cfg(feature = "sinks-new_relic")]
             Self::NewRelic(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-opentelemetry")]
+            Self::Opentelemetry(config) => config.get_component_name(),
             #[cfg(feature = "sinks-papertrail")]
             Self::Papertrail(config) => config.get_component_name(),
             #[cfg(feature = "sinks-prometheus")]
@@ -485,6 +532,8 @@ impl NamedComponent for Sinks {
             Self::SematextLogs(config) => config.get_component_name(),
             #[cfg(feature = "sinks-sematext")]
             Self::SematextMetrics(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-snowflake")]
+            Self::Snowflake(config) => config.get_component_name(),
             #[cfg(feature = "sinks-socket")]
             Self::Socket(config) => config.get_component_name(),
             #[cfg(feature = "sinks-splunk_hec")]
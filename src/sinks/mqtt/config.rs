@@ -0,0 +1,249 @@
+use std::time::Duration;
+
+use codecs::JsonSerializerConfig;
+use futures::FutureExt;
+use rand::{thread_rng, Rng};
+use rand_distr::Alphanumeric;
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS, TlsConfiguration, Transport};
+use snafu::ResultExt;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+
+use super::{
+    sink::{MqttSink, MAX_QUEUED_PUBLISHES},
+    ConnectSnafu, KeepAliveSnafu, MissingCaSnafu, MqttError, TlsSnafu,
+};
+use crate::{
+    codecs::EncodingConfig,
+    config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
+    sinks::{Healthcheck, VectorSink},
+    template::Template,
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+/// MQTT quality of service levels.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MqttQoS {
+    /// Messages are delivered at most once, and may be lost.
+    ///
+    /// Events are acknowledged as soon as they are written to the connection.
+    AtMostOnce,
+
+    /// Messages are delivered at least once, and may be duplicated.
+    ///
+    /// Events are acknowledged once the broker acknowledges the message.
+    #[default]
+    AtLeastOnce,
+
+    /// Messages are delivered exactly once.
+    ///
+    /// Events are acknowledged once the broker completes the delivery of the message.
+    ExactlyOnce,
+}
+
+impl From<MqttQoS> for QoS {
+    fn from(qos: MqttQoS) -> Self {
+        match qos {
+            MqttQoS::AtMostOnce => QoS::AtMostOnce,
+            MqttQoS::AtLeastOnce => QoS::AtLeastOnce,
+            MqttQoS::ExactlyOnce => QoS::ExactlyOnce,
+        }
+    }
+}
+
+/// Configuration for the `mqtt` sink.
+#[configurable_component(sink("mqtt"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MqttSinkConfig {
+    /// The host name or IP address of the MQTT broker.
+    #[configurable(metadata(docs::examples = "mqtt.example.com"))]
+    #[configurable(metadata(docs::examples = "127.0.0.1"))]
+    pub host: String,
+
+    /// The port of the MQTT broker.
+    ///
+    /// Brokers usually listen on port 1883, or port 8883 when TLS is enabled.
+    #[serde(default = "default_port")]
+    pub port: u16,
+
+    /// The user name to authenticate with.
+    #[configurable(metadata(docs::examples = "vector"))]
+    pub user: Option<String>,
+
+    /// The password to authenticate with.
+    #[configurable(metadata(docs::examples = "${MQTT_PASSWORD}"))]
+    pub password: Option<SensitiveString>,
+
+    /// The client ID of the connection.
+    ///
+    /// Brokers disconnect clients that connect with the ID of another connected client, so each
+    /// Vector instance must use its own ID. If not set, a random ID is generated on startup.
+    ///
+    /// The healthcheck connects with this ID suffixed with `-healthcheck`.
+    #[configurable(metadata(docs::examples = "vector-edge-1"))]
+    pub client_id: Option<String>,
+
+    /// The interval, in seconds, at which the connection is kept alive.
+    ///
+    /// Must be at least 5 seconds.
+    #[serde(default = "default_keep_alive_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub keep_alive_secs: u16,
+
+    /// Whether to start a clean session when connecting.
+    ///
+    /// When disabled, the broker keeps the session of the client ID across connections.
+    #[serde(default = "crate::serde::default_true")]
+    pub clean_session: bool,
+
+    /// The topic to publish events to.
+    #[configurable(metadata(docs::templateable))]
+    #[configurable(metadata(docs::examples = "devices/{{ device_id }}/logs"))]
+    #[configurable(metadata(docs::examples = "vector"))]
+    pub topic: Template,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub quality_of_service: MqttQoS,
+
+    /// Whether the broker retains the last message of each topic.
+    ///
+    /// Retained messages are delivered to clients when they subscribe to the topic.
+    #[serde(default)]
+    pub retain: bool,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    pub encoding: EncodingConfig,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+/// The client panics on keep alive intervals shorter than this.
+const MIN_KEEP_ALIVE_SECS: u16 = 5;
+
+const fn default_port() -> u16 {
+    1883
+}
+
+const fn default_keep_alive_secs() -> u16 {
+    60
+}
+
+impl GenerateConfig for MqttSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            host: "localhost".into(),
+            port: default_port(),
+            user: None,
+            password: None,
+            client_id: None,
+            keep_alive_secs: default_keep_alive_secs(),
+            clean_session: true,
+            topic: Template::try_from("vector").unwrap(),
+            quality_of_service: MqttQoS::default(),
+            retain: false,
+            tls: None,
+            encoding: JsonSerializerConfig::default().into(),
+            acknowledgements: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for MqttSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let client_id = self.client_id.clone().unwrap_or_else(|| {
+            let suffix = thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(8)
+                .map(char::from)
+                .collect::<String>();
+            format!("vector-{}", suffix)
+        });
+
+        // The healthcheck connects with its own client ID, so that the broker doesn't disconnect
+        // the sink when the healthcheck connects.
+        let healthcheck = healthcheck(self.options(format!("{}-healthcheck", client_id))?).boxed();
+
+        let options = self.options(client_id)?;
+        let (client, eventloop) = AsyncClient::new(options, MAX_QUEUED_PUBLISHES);
+        let sink = MqttSink::new(self, client, eventloop)?;
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(self.encoding.config().input_type() & (DataType::Log | DataType::Metric))
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+impl MqttSinkConfig {
+    pub(super) fn options(&self, client_id: String) -> Result<MqttOptions, MqttError> {
+        if self.keep_alive_secs < MIN_KEEP_ALIVE_SECS {
+            return KeepAliveSnafu {
+                keep_alive_secs: self.keep_alive_secs,
+            }
+            .fail();
+        }
+
+        let mut options = MqttOptions::new(client_id, &self.host, self.port);
+        options.set_keep_alive(Duration::from_secs(self.keep_alive_secs.into()));
+        options.set_clean_session(self.clean_session);
+
+        if let Some(user) = &self.user {
+            let password = self
+                .password
+                .as_ref()
+                .map(|password| password.inner().to_owned())
+                .unwrap_or_default();
+            options.set_credentials(user, password);
+        }
+
+        if let Some(tls) = MaybeTlsSettings::from_config(&self.tls, false)
+            .context(TlsSnafu)?
+            .tls()
+        {
+            let ca: Vec<u8> = tls.authorities_pem().flatten().collect();
+            // The client only trusts the given authorities, and not those of the system.
+            if ca.is_empty() {
+                return MissingCaSnafu.fail();
+            }
+            let client_auth = tls.identity_pem();
+            options.set_transport(Transport::Tls(TlsConfiguration::Simple {
+                ca,
+                alpn: None,
+                client_auth,
+            }));
+        }
+
+        Ok(options)
+    }
+}
+
+/// Connects to the broker, succeeding once it accepts the connection.
+async fn healthcheck(options: MqttOptions) -> crate::Result<()> {
+    // The event loop stops when its client is dropped, so the client is held until it's done.
+    let (_client, mut eventloop) = AsyncClient::new(options, 1);
+    loop {
+        if let Event::Incoming(Packet::ConnAck(_)) = eventloop.poll().await.context(ConnectSnafu)? {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! The MQTT [`vector_core::sink::VectorSink`].
+//!
+//! This module contains the [`vector_core::sink::VectorSink`] instance that is responsible for
+//! publishing events to an [MQTT][mqtt] broker, one message per event, on a templated topic.
+//!
+//! Events are acknowledged according to the quality of service of their messages: as soon as they
+//! are written for `at_most_once`, and once the broker acknowledges them otherwise.
+//!
+//! [mqtt]: https://mqtt.org/
+use snafu::Snafu;
+
+mod config;
+mod sink;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::MqttSinkConfig;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(super)))]
+pub enum MqttError {
+    #[snafu(display("Invalid TLS configuration: {}", source))]
+    Tls { source: crate::tls::TlsError },
+
+    #[snafu(display(
+        "Keep alive interval must be at least 5 seconds, got {} seconds.",
+        keep_alive_secs
+    ))]
+    KeepAlive { keep_alive_secs: u16 },
+
+    #[snafu(display("TLS requires the certificate authority to be set with `tls.ca_file`."))]
+    MissingCa,
+
+    #[snafu(display("Invalid encoding: {}", source))]
+    Encoding {
+        source: codecs::encoding::BuildError,
+    },
+
+    #[snafu(display("Failed to connect to the broker: {}", source))]
+    Connect { source: rumqttc::ConnectionError },
+}
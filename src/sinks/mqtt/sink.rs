@@ -0,0 +1,228 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::BytesMut;
+use futures::{stream::BoxStream, StreamExt};
+use rumqttc::{
+    AsyncClient, ConnectionError, Event as Notification, EventLoop, Outgoing, Packet, QoS,
+};
+use snafu::ResultExt;
+use tokio::sync::mpsc;
+use tokio_util::codec::Encoder as _;
+use vector_common::internal_event::{
+    ByteSize, BytesSent, CountByteSize, EventsSent, InternalEventHandle, Output, Protocol,
+    Registered,
+};
+
+use super::{config::MqttSinkConfig, EncodingSnafu, MqttError};
+use crate::{
+    codecs::{Encoder, Transformer},
+    event::{EstimatedJsonEncodedSizeOf, Event, EventFinalizers, EventStatus, Finalizable},
+    internal_events::{MqttConnectionError, MqttPublishError, TemplateRenderingError},
+    sinks::util::StreamSink,
+    template::Template,
+};
+
+/// The maximum number of publishes queued for the event loop.
+///
+/// Events are only read from the input while fewer publishes are queued, so queueing a publish
+/// never fails because the queue is full.
+pub(super) const MAX_QUEUED_PUBLISHES: usize = 100;
+
+/// The delay before reconnecting after a connection error.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// A published event that is not yet delivered.
+struct Pending {
+    finalizers: EventFinalizers,
+    event_byte_size: usize,
+    byte_size: usize,
+}
+
+impl Pending {
+    fn deliver(self, events_sent: &Registered<EventsSent>, bytes_sent: &Registered<BytesSent>) {
+        self.finalizers.update_status(EventStatus::Delivered);
+        events_sent.emit(CountByteSize(1, self.event_byte_size));
+        bytes_sent.emit(ByteSize(self.byte_size));
+    }
+}
+
+pub(super) struct MqttSink {
+    publisher: Publisher,
+    eventloop: EventLoop,
+}
+
+impl MqttSink {
+    pub(super) fn new(
+        config: &MqttSinkConfig,
+        client: AsyncClient,
+        eventloop: EventLoop,
+    ) -> Result<Self, MqttError> {
+        let transformer = config.encoding.transformer();
+        let serializer = config.encoding.build().context(EncodingSnafu)?;
+
+        Ok(Self {
+            publisher: Publisher {
+                transformer,
+                encoder: Encoder::<()>::new(serializer),
+                topic: config.topic.clone(),
+                quality_of_service: config.quality_of_service.into(),
+                retain: config.retain,
+                client,
+            },
+            eventloop,
+        })
+    }
+}
+
+/// Queues the publishes of events on the client, for the event loop to write them.
+struct Publisher {
+    transformer: Transformer,
+    encoder: Encoder<()>,
+    topic: Template,
+    quality_of_service: QoS,
+    retain: bool,
+    client: AsyncClient,
+}
+
+impl Publisher {
+    /// Encodes the event and queues its publish, returning the event as pending if it was queued.
+    fn publish(&mut self, mut event: Event) -> Option<Pending> {
+        let finalizers = event.take_finalizers();
+
+        let topic = match self.topic.render_string(&event) {
+            Ok(topic) => topic,
+            Err(error) => {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("topic"),
+                    drop_event: true,
+                });
+                finalizers.update_status(EventStatus::Rejected);
+                return None;
+            }
+        };
+
+        self.transformer.transform(&mut event);
+        let event_byte_size = event.estimated_json_encoded_size_of();
+
+        let mut bytes = BytesMut::new();
+        if self.encoder.encode(event, &mut bytes).is_err() {
+            // Error is handled by `Encoder`.
+            finalizers.update_status(EventStatus::Rejected);
+            return None;
+        }
+
+        let byte_size = bytes.len();
+        match self.client.try_publish_bytes(
+            topic,
+            self.quality_of_service,
+            self.retain,
+            bytes.freeze(),
+        ) {
+            Ok(()) => Some(Pending {
+                finalizers,
+                event_byte_size,
+                byte_size,
+            }),
+            // Publishes are only rejected for invalid topics, as the queue is never full.
+            Err(error) => {
+                emit!(MqttPublishError { error });
+                finalizers.update_status(EventStatus::Rejected);
+                None
+            }
+        }
+    }
+}
+
+/// Polls the event loop in its own task, as polling it isn't cancellation safe.
+///
+/// The task stops once the receiver is dropped.
+fn spawn_eventloop(
+    mut eventloop: EventLoop,
+) -> mpsc::Receiver<Result<Notification, ConnectionError>> {
+    let (tx, rx) = mpsc::channel(1);
+    tokio::spawn(async move {
+        loop {
+            let notification = eventloop.poll().await;
+            let failed = notification.is_err();
+            if tx.send(notification).await.is_err() {
+                break;
+            }
+            // The event loop reconnects on the next poll.
+            if failed {
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        }
+    });
+    rx
+}
+
+#[async_trait]
+impl StreamSink<Event> for MqttSink {
+    async fn run(self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let bytes_sent = register!(BytesSent::from(Protocol::from("mqtt")));
+        let events_sent = register!(EventsSent::from(Output(None)));
+
+        let MqttSink {
+            mut publisher,
+            eventloop,
+        } = *self;
+        let mut notifications = spawn_eventloop(eventloop);
+
+        // Publishes that are queued but not yet written to the connection, in order.
+        let mut queued = VecDeque::new();
+        // Publishes that are written to the connection but not yet acknowledged, by packet ID.
+        let mut in_flight = HashMap::new();
+        let mut input_done = false;
+
+        while !(input_done && queued.is_empty() && in_flight.is_empty()) {
+            tokio::select! {
+                event = input.next(), if !input_done && queued.len() < MAX_QUEUED_PUBLISHES => {
+                    match event {
+                        Some(event) => queued.extend(publisher.publish(event)),
+                        None => input_done = true,
+                    }
+                }
+                Some(notification) = notifications.recv() => match notification {
+                    Ok(Notification::Outgoing(Outgoing::Publish(pkid))) => {
+                        // Unacknowledged publishes are written again after reconnecting, with the
+                        // same packet ID.
+                        if in_flight.contains_key(&pkid) {
+                            continue;
+                        }
+                        let Some(pending) = queued.pop_front() else {
+                            continue;
+                        };
+                        if publisher.quality_of_service == QoS::AtMostOnce {
+                            pending.deliver(&events_sent, &bytes_sent);
+                        } else {
+                            in_flight.insert(pkid, pending);
+                        }
+                    }
+                    Ok(Notification::Incoming(Packet::PubAck(ack))) => {
+                        if let Some(pending) = in_flight.remove(&ack.pkid) {
+                            pending.deliver(&events_sent, &bytes_sent);
+                        }
+                    }
+                    Ok(Notification::Incoming(Packet::PubComp(comp))) => {
+                        if let Some(pending) = in_flight.remove(&comp.pkid) {
+                            pending.deliver(&events_sent, &bytes_sent);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(error) => emit!(MqttConnectionError { error }),
+                },
+                else => break,
+            }
+        }
+
+        // Everything is delivered, so failing to disconnect cleanly is of no consequence.
+        _ = publisher.client.try_disconnect();
+
+        Ok(())
+    }
+}
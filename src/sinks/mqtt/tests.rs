@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use rumqttc::QoS;
+
+use super::{
+    config::{MqttQoS, MqttSinkConfig},
+    MqttError,
+};
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<MqttSinkConfig>();
+}
+
+#[test]
+fn parses_config() {
+    let config: MqttSinkConfig = toml::from_str(
+        r#"
+            host = "mqtt.example.com"
+            port = 8883
+            user = "vector"
+            password = "secret"
+            client_id = "vector-edge-1"
+            topic = "devices/{{ device_id }}/logs"
+            quality_of_service = "exactly_once"
+            retain = true
+            encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.quality_of_service, MqttQoS::ExactlyOnce);
+    assert_eq!(QoS::from(config.quality_of_service), QoS::ExactlyOnce);
+    assert!(config.retain);
+
+    let options = config.options("vector-edge-1".into()).unwrap();
+    assert_eq!(options.broker_address(), ("mqtt.example.com".into(), 8883));
+    assert_eq!(options.client_id(), "vector-edge-1");
+    assert_eq!(
+        options.credentials(),
+        Some(("vector".into(), "secret".into()))
+    );
+    assert_eq!(options.keep_alive(), Duration::from_secs(60));
+    assert!(options.clean_session());
+}
+
+#[test]
+fn defaults_to_at_least_once() {
+    let config: MqttSinkConfig = toml::from_str(
+        r#"
+            host = "localhost"
+            topic = "vector"
+            encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.port, 1883);
+    assert_eq!(config.quality_of_service, MqttQoS::AtLeastOnce);
+    assert!(!config.retain);
+}
+
+#[test]
+fn rejects_short_keep_alive() {
+    let config: MqttSinkConfig = toml::from_str(
+        r#"
+            host = "localhost"
+            topic = "vector"
+            keep_alive_secs = 1
+            encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        config.options("vector".into()),
+        Err(MqttError::KeepAlive { keep_alive_secs: 1 })
+    ));
+}
+
+#[test]
+fn requires_ca_with_tls() {
+    let config: MqttSinkConfig = toml::from_str(
+        r#"
+            host = "localhost"
+            topic = "vector"
+            tls.enabled = true
+            encoding.codec = "json"
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        config.options("vector".into()),
+        Err(MqttError::MissingCa)
+    ));
+}
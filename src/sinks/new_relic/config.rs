@@ -152,12 +152,13 @@ impl SinkConfig for NewRelicConfig {
 
         let request_limits = self.request.unwrap_with(&Default::default());
         let tls_settings = TlsSettings::from_options(&None)?;
-        let client = HttpClient::new(tls_settings, &cx.proxy)?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
         let credentials = Arc::from(NewRelicCredentials::from(self));
 
         let healthcheck = self.build_healthcheck(client.clone(), Arc::clone(&credentials))?;
 
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_limits)
             .settings(request_limits, NewRelicApiRetry)
             .service(NewRelicApiService { client });
 
@@ -0,0 +1,209 @@
+use http::Uri;
+use indexmap::IndexMap;
+use opentelemetry_proto::proto::common::v1::{any_value::Value as PBValue, AnyValue, KeyValue};
+use tower::ServiceBuilder;
+use vector_config::configurable_component;
+
+use super::{
+    service::{ExportRequest, OpentelemetryRetryLogic, OpentelemetryService, Transport},
+    sink::OpentelemetrySink,
+};
+use crate::{
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    http::HttpClient,
+    sinks::{
+        util::{
+            http::validate_headers, BatchConfig, RealtimeSizeBasedDefaultBatchSettings,
+            ServiceBuilderExt, TowerRequestConfig,
+        },
+        vector::{new_client, with_default_scheme},
+        Healthcheck, VectorSink,
+    },
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+/// The protocol used to export events.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Protocol {
+    /// Export over gRPC, using the OTLP collector services.
+    #[default]
+    Grpc,
+
+    /// Export over HTTP, posting binary protobuf to the `/v1/logs`, `/v1/metrics`, and
+    /// `/v1/traces` paths of the endpoint.
+    Http,
+}
+
+impl Protocol {
+    /// The protocol reported in the `protocol` tag of internal metrics.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Grpc => "grpc",
+            Self::Http => "http",
+        }
+    }
+}
+
+/// Configuration for the `opentelemetry` sink.
+#[configurable_component(sink("opentelemetry"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OpentelemetryConfig {
+    /// The endpoint of the OTLP receiver.
+    ///
+    /// For the `grpc` protocol, the scheme defaults to `http`, or to `https` if TLS is enabled.
+    /// For the `http` protocol, this is the base URL to which the signal paths are appended.
+    #[configurable(validation(format = "uri"))]
+    #[configurable(metadata(docs::examples = "localhost:4317"))]
+    #[configurable(metadata(docs::examples = "https://otlp.example.com:4318"))]
+    pub endpoint: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub protocol: Protocol,
+
+    /// Attributes added to the resource of every exported log, metric, and trace.
+    ///
+    /// These take precedence over any resource attributes of the same name already present on
+    /// an event.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "A resource attribute and its value."
+    ))]
+    #[configurable(metadata(docs::examples = "example_resource_attributes()"))]
+    pub resource_attributes: IndexMap<String, String>,
+
+    /// A list of custom headers to add to each request.
+    ///
+    /// For the `grpc` protocol, these are sent as request metadata.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "An HTTP request header and its value."
+    ))]
+    #[configurable(metadata(docs::examples = "example_headers()"))]
+    pub headers: IndexMap<String, String>,
+
+    /// Whether or not to compress requests.
+    ///
+    /// If set to `true`, requests are compressed with [`gzip`][gzip_docs].
+    ///
+    /// [gzip_docs]: https://www.gzip.org/
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default)]
+    pub compression: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeSizeBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+fn example_resource_attributes() -> IndexMap<String, String> {
+    IndexMap::<_, _>::from_iter([
+        ("service.name".to_owned(), "checkout".to_owned()),
+        ("deployment.environment".to_owned(), "production".to_owned()),
+    ])
+}
+
+fn example_headers() -> IndexMap<String, String> {
+    IndexMap::<_, _>::from_iter([("x-api-key".to_owned(), "${OTLP_API_KEY}".to_owned())])
+}
+
+impl GenerateConfig for OpentelemetryConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"endpoint = "localhost:4317"
+            protocol = "grpc""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for OpentelemetryConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let tls = MaybeTlsSettings::from_config(&self.tls, false)?;
+        let headers = validate_headers(&self.headers)?;
+
+        let transport = match self.protocol {
+            Protocol::Grpc => {
+                let uri = with_default_scheme(&self.endpoint, tls.is_tls())?;
+                let client = new_client(&tls, cx.proxy())?;
+                Transport::grpc(client, uri, headers, self.compression)
+            }
+            Protocol::Http => {
+                let endpoint = self.endpoint.trim_end_matches('/').to_owned();
+                // Validate the endpoint up front, rather than on the first request.
+                format!("{}/v1/logs", endpoint).parse::<Uri>()?;
+                let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
+                Transport::http(client, endpoint, headers, self.compression)
+            }
+        };
+
+        let service = OpentelemetryService::new(transport);
+        let healthcheck = Box::pin(healthcheck(service.clone()));
+
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+        let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
+            .settings(request_settings, OpentelemetryRetryLogic)
+            .service(service);
+
+        let sink = OpentelemetrySink::new(
+            service,
+            batch_settings,
+            resource_attributes(&self.resource_attributes),
+            self.protocol,
+        );
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+fn resource_attributes(attributes: &IndexMap<String, String>) -> Vec<KeyValue> {
+    attributes
+        .iter()
+        .map(|(key, value)| KeyValue {
+            key: key.clone(),
+            value: Some(AnyValue {
+                value: Some(PBValue::StringValue(value.clone())),
+            }),
+        })
+        .collect()
+}
+
+/// Checks that the receiver accepts an empty export.
+///
+/// OTLP has no health check service, but receivers are required to accept requests that contain
+/// no data, so this verifies connectivity, TLS, and authentication without exporting anything.
+async fn healthcheck(service: OpentelemetryService) -> crate::Result<()> {
+    service
+        .export(ExportRequest::Logs(Default::default()))
+        .await
+        .map_err(Into::into)
+}
@@ -0,0 +1,978 @@
+//! Conversion of Vector events into OTLP export requests.
+//!
+//! Records are grouped by the resource that produced them, with the configured resource
+//! attributes applied on top, and are reported under a single `vector` instrumentation scope.
+//!
+//! Logs and spans are mapped back from the layout used by the `opentelemetry` source, so data
+//! received over OTLP is exported with its resource, attributes, and trace context intact.
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use opentelemetry_proto::{
+    convert::{
+        value_into_kv_list, ATTRIBUTES_KEY, DROPPED_ATTRIBUTES_COUNT_KEY, FLAGS_KEY,
+        OBSERVED_TIMESTAMP_KEY, RESOURCE_KEY, SEVERITY_NUMBER_KEY, SEVERITY_TEXT_KEY, SPAN_ID_KEY,
+        TRACE_ID_KEY, TRACE_STATE_KEY,
+    },
+    proto::{
+        collector::{
+            logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            trace::v1::ExportTraceServiceRequest,
+        },
+        common::v1::{any_value::Value as PBValue, AnyValue, InstrumentationScope, KeyValue},
+        logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+        metrics::v1::{
+            metric::Data, number_data_point, summary_data_point::ValueAtQuantile,
+            AggregationTemporality, Gauge, Histogram, HistogramDataPoint, Metric as OtlpMetric,
+            NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum, Summary, SummaryDataPoint,
+        },
+        resource::v1::Resource,
+        trace::v1::{
+            span::{Event as SpanEvent, Link, SpanKind},
+            status::StatusCode,
+            ResourceSpans, ScopeSpans, Span, Status,
+        },
+    },
+};
+use vector_core::{
+    config::LogNamespace,
+    event::{
+        metric::{Bucket, MetricSketch, Quantile},
+        LogEvent, Metric, MetricKind, MetricValue, TraceEvent, Value,
+    },
+};
+
+/// The namespace of the metadata written by the `opentelemetry` source.
+const SOURCE_NAME: &str = "opentelemetry";
+
+/// The bucket bounds used when converting distributions into histograms.
+///
+/// These are the default buckets of the Prometheus client libraries.
+const DISTRIBUTION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The quantiles reported when converting sketches into summaries.
+const SKETCH_QUANTILES: [f64; 5] = [0.5, 0.75, 0.9, 0.95, 0.99];
+
+/// Records grouped by the attributes of the resource that produced them.
+struct ResourceGroups<T> {
+    groups: Vec<(Vec<KeyValue>, Vec<T>)>,
+}
+
+impl<T> Default for ResourceGroups<T> {
+    fn default() -> Self {
+        Self { groups: Vec::new() }
+    }
+}
+
+impl<T> ResourceGroups<T> {
+    fn push(&mut self, resource: Vec<KeyValue>, record: T) {
+        // Events typically come from a handful of resources, so a linear scan is cheaper than
+        // hashing every attribute of every event.
+        match self.groups.iter_mut().find(|(r, _)| *r == resource) {
+            Some((_, records)) => records.push(record),
+            None => self.groups.push((resource, vec![record])),
+        }
+    }
+}
+
+pub(super) struct OtlpEncoder {
+    resource_attributes: Vec<KeyValue>,
+    scope: InstrumentationScope,
+}
+
+impl OtlpEncoder {
+    pub(super) fn new(resource_attributes: Vec<KeyValue>) -> Self {
+        Self {
+            resource_attributes,
+            scope: InstrumentationScope {
+                name: "vector".to_owned(),
+                version: crate::get_version(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Applies the configured resource attributes to the attributes of an event's resource.
+    fn resource(&self, mut attributes: Vec<KeyValue>) -> Vec<KeyValue> {
+        attributes.retain(|attribute| {
+            !self
+                .resource_attributes
+                .iter()
+                .any(|configured| configured.key == attribute.key)
+        });
+        attributes.extend(self.resource_attributes.iter().cloned());
+        attributes
+    }
+
+    fn into_resource(attributes: Vec<KeyValue>) -> Option<Resource> {
+        Some(Resource {
+            attributes,
+            dropped_attributes_count: 0,
+        })
+    }
+
+    pub(super) fn encode_logs(&self, logs: Vec<LogEvent>) -> ExportLogsServiceRequest {
+        let mut groups = ResourceGroups::default();
+        for log in logs {
+            let (resource, record) = encode_log(log);
+            groups.push(self.resource(resource), record);
+        }
+
+        ExportLogsServiceRequest {
+            resource_logs: groups
+                .groups
+                .into_iter()
+                .map(|(resource, log_records)| ResourceLogs {
+                    resource: Self::into_resource(resource),
+                    scope_logs: vec![ScopeLogs {
+                        scope: Some(self.scope.clone()),
+                        log_records,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    pub(super) fn encode_metrics(&self, metrics: Vec<Metric>) -> ExportMetricsServiceRequest {
+        let metrics = metrics.iter().map(encode_metric).collect();
+        let resource = self.resource(Vec::new());
+
+        ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Self::into_resource(resource),
+                scope_metrics: vec![ScopeMetrics {
+                    scope: Some(self.scope.clone()),
+                    metrics,
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        }
+    }
+
+    pub(super) fn encode_traces(&self, traces: Vec<TraceEvent>) -> ExportTraceServiceRequest {
+        let mut groups = ResourceGroups::default();
+        for trace in traces {
+            let resource = trace
+                .get(RESOURCE_KEY)
+                .and_then(Value::as_object)
+                .cloned()
+                .map(value_into_kv_list)
+                .unwrap_or_default();
+
+            let spans = match trace.get("spans") {
+                Some(Value::Array(spans)) => spans.iter().filter_map(Value::as_object).collect(),
+                // The `opentelemetry` source emits each span as a trace of its own.
+                _ if trace.contains(SPAN_ID_KEY) => vec![trace.as_map()],
+                _ => Vec::new(),
+            };
+            for span in spans {
+                let mut resource = resource.clone();
+                // Spans from the `datadog_agent` source carry the service that produced them.
+                if let Some(service) = span.get("service") {
+                    resource.retain(|attribute| attribute.key != "service.name");
+                    resource.push(string_attribute("service.name", service));
+                }
+                groups.push(self.resource(resource), encode_span(span));
+            }
+        }
+
+        ExportTraceServiceRequest {
+            resource_spans: groups
+                .groups
+                .into_iter()
+                .map(|(resource, spans)| ResourceSpans {
+                    resource: Self::into_resource(resource),
+                    scope_spans: vec![ScopeSpans {
+                        scope: Some(self.scope.clone()),
+                        spans,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts a log into its resource attributes and an OTLP log record.
+///
+/// The body is taken from the message of the log. Any fields other than those written by the
+/// `opentelemetry` source are exported as attributes, as log records have nowhere else to put
+/// them.
+fn encode_log(mut log: LogEvent) -> (Vec<KeyValue>, LogRecord) {
+    let namespace = log.namespace();
+    let field = |key: &str| match namespace {
+        LogNamespace::Vector => format!("%{}.{}", SOURCE_NAME, key),
+        LogNamespace::Legacy => key.to_owned(),
+    };
+
+    let resource = match log.remove(field(RESOURCE_KEY).as_str()) {
+        Some(Value::Object(resource)) => value_into_kv_list(resource),
+        _ => Vec::new(),
+    };
+    let mut attributes = match log.remove(field(ATTRIBUTES_KEY).as_str()) {
+        Some(Value::Object(attributes)) => attributes,
+        _ => BTreeMap::new(),
+    };
+
+    let trace_id = log
+        .remove(field(TRACE_ID_KEY).as_str())
+        .map(|id| encode_id(&id, 16))
+        .unwrap_or_default();
+    let span_id = log
+        .remove(field(SPAN_ID_KEY).as_str())
+        .map(|id| encode_id(&id, 8))
+        .unwrap_or_default();
+    let severity_text = log
+        .remove(field(SEVERITY_TEXT_KEY).as_str())
+        .map(|text| text.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let severity_number = log
+        .remove(field(SEVERITY_NUMBER_KEY).as_str())
+        .and_then(|number| number.as_integer())
+        .unwrap_or_default() as i32;
+    let flags = log
+        .remove(field(FLAGS_KEY).as_str())
+        .and_then(|flags| flags.as_integer())
+        .unwrap_or_default() as u32;
+    let dropped_attributes_count = log
+        .remove(field(DROPPED_ATTRIBUTES_COUNT_KEY).as_str())
+        .and_then(|count| count.as_integer())
+        .unwrap_or_default() as u32;
+    let observed_time_unix_nano = log
+        .remove(field(OBSERVED_TIMESTAMP_KEY).as_str())
+        .and_then(|timestamp| timestamp.as_timestamp().copied())
+        .map(unix_nanos)
+        .unwrap_or_default();
+    let time_unix_nano = log
+        .remove_timestamp()
+        .and_then(|timestamp| timestamp.as_timestamp().copied())
+        .map(unix_nanos)
+        .unwrap_or_default();
+
+    let source_type_path = log.source_type_path();
+    log.remove(source_type_path);
+    let body = log
+        .message_path()
+        .and_then(|path| log.remove(path.as_str()));
+
+    let (value, _metadata) = log.into_parts();
+    let body = match (body, value) {
+        (Some(body), Value::Object(fields)) => {
+            for (key, value) in fields {
+                attributes.entry(key).or_insert(value);
+            }
+            body
+        }
+        (Some(body), _) => body,
+        // Without a message, the whole event is the body.
+        (None, value) => value,
+    };
+
+    let record = LogRecord {
+        time_unix_nano,
+        observed_time_unix_nano,
+        severity_number,
+        severity_text,
+        body: (!body.is_null()).then(|| body.into()),
+        attributes: value_into_kv_list(attributes),
+        dropped_attributes_count,
+        flags,
+        trace_id,
+        span_id,
+    };
+
+    (resource, record)
+}
+
+fn encode_metric(metric: &Metric) -> OtlpMetric {
+    let name = match metric.namespace() {
+        Some(namespace) => format!("{}.{}", namespace, metric.name()),
+        None => metric.name().to_owned(),
+    };
+
+    let attributes = metric
+        .tags()
+        .map(|tags| {
+            tags.iter_single()
+                .map(|(key, value)| KeyValue {
+                    key: key.to_owned(),
+                    value: Some(AnyValue {
+                        value: Some(PBValue::StringValue(value.to_owned())),
+                    }),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let time_unix_nano = metric.timestamp().map(unix_nanos).unwrap_or_default();
+    // Incremental metrics cover their interval, if they have one.
+    let start_time_unix_nano = match (metric.kind(), metric.interval_ms()) {
+        (MetricKind::Incremental, Some(interval_ms)) => {
+            time_unix_nano.saturating_sub(u64::from(interval_ms.get()) * 1_000_000)
+        }
+        _ => 0,
+    };
+    let temporality = match metric.kind() {
+        MetricKind::Incremental => AggregationTemporality::Delta,
+        MetricKind::Absolute => AggregationTemporality::Cumulative,
+    } as i32;
+
+    let number = |value: f64| NumberDataPoint {
+        attributes: attributes.clone(),
+        start_time_unix_nano,
+        time_unix_nano,
+        value: Some(number_data_point::Value::AsDouble(value)),
+        ..Default::default()
+    };
+    let histogram = |buckets: &[Bucket], count: u64, sum: f64| {
+        let (explicit_bounds, bucket_counts) = histogram_buckets(buckets, count);
+        HistogramDataPoint {
+            attributes: attributes.clone(),
+            start_time_unix_nano,
+            time_unix_nano,
+            count,
+            sum: Some(sum),
+            bucket_counts,
+            explicit_bounds,
+            ..Default::default()
+        }
+    };
+    let summary = |quantiles: &[Quantile], count: u64, sum: f64| SummaryDataPoint {
+        attributes: attributes.clone(),
+        start_time_unix_nano,
+        time_unix_nano,
+        count,
+        sum,
+        quantile_values: quantiles
+            .iter()
+            .map(|quantile| ValueAtQuantile {
+                quantile: quantile.quantile,
+                value: quantile.value,
+            })
+            .collect(),
+        ..Default::default()
+    };
+
+    let data = match metric.value() {
+        MetricValue::Counter { value } => Data::Sum(Sum {
+            data_points: vec![number(*value)],
+            aggregation_temporality: temporality,
+            is_monotonic: true,
+        }),
+        MetricValue::Gauge { value } => match metric.kind() {
+            MetricKind::Absolute => Data::Gauge(Gauge {
+                data_points: vec![number(*value)],
+            }),
+            // An incremental gauge is a change in value, which OTLP represents as a delta sum.
+            MetricKind::Incremental => Data::Sum(Sum {
+                data_points: vec![number(*value)],
+                aggregation_temporality: temporality,
+                is_monotonic: false,
+            }),
+        },
+        // Sets are reported as the number of unique values, as the `prometheus_exporter` sink does.
+        MetricValue::Set { values } => Data::Gauge(Gauge {
+            data_points: vec![number(values.len() as f64)],
+        }),
+        distribution @ MetricValue::Distribution { .. } => {
+            match distribution.distribution_to_agg_histogram(&DISTRIBUTION_BUCKETS) {
+                Some(MetricValue::AggregatedHistogram {
+                    buckets,
+                    count,
+                    sum,
+                }) => Data::Histogram(Histogram {
+                    data_points: vec![histogram(&buckets, count, sum)],
+                    aggregation_temporality: temporality,
+                }),
+                _ => unreachable!("distributions always convert to histograms"),
+            }
+        }
+        MetricValue::AggregatedHistogram {
+            buckets,
+            count,
+            sum,
+        } => Data::Histogram(Histogram {
+            data_points: vec![histogram(buckets, *count, *sum)],
+            aggregation_temporality: temporality,
+        }),
+        MetricValue::AggregatedSummary {
+            quantiles,
+            count,
+            sum,
+        } => Data::Summary(Summary {
+            data_points: vec![summary(quantiles, *count, *sum)],
+        }),
+        MetricValue::Sketch { sketch } => {
+            let MetricSketch::AgentDDSketch(sketch) = sketch;
+            let quantiles = SKETCH_QUANTILES
+                .iter()
+                .filter_map(|&quantile| {
+                    sketch
+                        .quantile(quantile)
+                        .map(|value| Quantile { quantile, value })
+                })
+                .collect::<Vec<_>>();
+            Data::Summary(Summary {
+                data_points: vec![summary(
+                    &quantiles,
+                    u64::from(sketch.count()),
+                    sketch.sum().unwrap_or_default(),
+                )],
+            })
+        }
+    };
+
+    OtlpMetric {
+        name,
+        description: String::new(),
+        unit: String::new(),
+        data: Some(data),
+    }
+}
+
+/// Converts Vector's buckets into OTLP's explicit bounds and per-bucket counts.
+///
+/// OTLP always has one more count than bounds, for the values above the last bound. Vector
+/// omits the `+Inf` bucket, so its count is whatever the other buckets don't account for.
+fn histogram_buckets(buckets: &[Bucket], count: u64) -> (Vec<f64>, Vec<u64>) {
+    let mut bounds = Vec::with_capacity(buckets.len());
+    let mut counts = Vec::with_capacity(buckets.len() + 1);
+    for bucket in buckets
+        .iter()
+        .filter(|bucket| bucket.upper_limit.is_finite())
+    {
+        bounds.push(bucket.upper_limit);
+        counts.push(bucket.count);
+    }
+    let counted: u64 = counts.iter().sum();
+    counts.push(count.saturating_sub(counted));
+    (bounds, counts)
+}
+
+/// Converts a span of a trace into an OTLP span.
+///
+/// Both the field names of OTLP spans and those of spans from the `datadog_agent` source are
+/// understood.
+fn encode_span(span: &BTreeMap<String, Value>) -> Span {
+    let get = |keys: &[&str]| keys.iter().find_map(|key| span.get(*key));
+
+    let start_time_unix_nano = match get(&["start_time_unix_nano", "start"]) {
+        Some(Value::Timestamp(start)) => unix_nanos(*start),
+        Some(Value::Integer(start)) => *start as u64,
+        _ => 0,
+    };
+    let end_time_unix_nano = match (get(&["end_time_unix_nano", "end"]), span.get("duration")) {
+        (Some(Value::Timestamp(end)), _) => unix_nanos(*end),
+        (Some(Value::Integer(end)), _) => *end as u64,
+        (None, Some(Value::Integer(duration))) => start_time_unix_nano + *duration as u64,
+        _ => start_time_unix_nano,
+    };
+
+    let mut attributes = Vec::new();
+    for key in ["attributes", "meta", "metrics"] {
+        if let Some(Value::Object(fields)) = span.get(key) {
+            attributes.extend(value_into_kv_list(fields.clone()));
+        }
+    }
+    // Datadog's names for these attributes, as used by its own OTLP exporter.
+    if let Some(resource) = span.get("resource") {
+        attributes.push(string_attribute("resource.name", resource));
+    }
+    if let Some(span_type) = span.get("type") {
+        attributes.push(string_attribute("span.type", span_type));
+    }
+
+    let kind = match span.get("kind") {
+        Some(Value::Integer(kind)) => *kind as i32,
+        Some(Value::Bytes(kind)) => match kind.as_ref() {
+            b"internal" => SpanKind::Internal as i32,
+            b"server" => SpanKind::Server as i32,
+            b"client" => SpanKind::Client as i32,
+            b"producer" => SpanKind::Producer as i32,
+            b"consumer" => SpanKind::Consumer as i32,
+            _ => SpanKind::Unspecified as i32,
+        },
+        _ => SpanKind::Unspecified as i32,
+    };
+
+    let status = match (span.get("status"), span.get("error")) {
+        (Some(Value::Object(status)), _) => Some(Status {
+            message: status
+                .get("message")
+                .map(|message| message.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            code: count(status.get("code")) as i32,
+        }),
+        (_, Some(Value::Integer(error))) if *error != 0 => Some(Status {
+            message: String::new(),
+            code: StatusCode::Error as i32,
+        }),
+        _ => None,
+    };
+
+    let events = match span.get("events") {
+        Some(Value::Array(events)) => events
+            .iter()
+            .filter_map(Value::as_object)
+            .map(encode_span_event)
+            .collect(),
+        _ => Vec::new(),
+    };
+    let links = match span.get("links") {
+        Some(Value::Array(links)) => links
+            .iter()
+            .filter_map(Value::as_object)
+            .map(encode_span_link)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    Span {
+        trace_id: get(&["trace_id"])
+            .map(|id| encode_id(id, 16))
+            .unwrap_or_default(),
+        span_id: get(&["span_id"])
+            .map(|id| encode_id(id, 8))
+            .unwrap_or_default(),
+        trace_state: span
+            .get(TRACE_STATE_KEY)
+            .map(|state| state.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        parent_span_id: get(&["parent_span_id", "parent_id"])
+            .map(|id| encode_id(id, 8))
+            .unwrap_or_default(),
+        name: get(&["name"])
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        kind,
+        start_time_unix_nano,
+        end_time_unix_nano,
+        attributes,
+        dropped_attributes_count: count(span.get(DROPPED_ATTRIBUTES_COUNT_KEY)),
+        events,
+        dropped_events_count: count(span.get("dropped_events_count")),
+        links,
+        dropped_links_count: count(span.get("dropped_links_count")),
+        status,
+    }
+}
+
+fn encode_span_event(event: &BTreeMap<String, Value>) -> SpanEvent {
+    SpanEvent {
+        time_unix_nano: match event.get("time_unix_nano") {
+            Some(Value::Timestamp(time)) => unix_nanos(*time),
+            Some(Value::Integer(time)) => *time as u64,
+            _ => 0,
+        },
+        name: event
+            .get("name")
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        attributes: encode_attributes(event.get(ATTRIBUTES_KEY)),
+        dropped_attributes_count: count(event.get(DROPPED_ATTRIBUTES_COUNT_KEY)),
+    }
+}
+
+fn encode_span_link(link: &BTreeMap<String, Value>) -> Link {
+    Link {
+        trace_id: link
+            .get(TRACE_ID_KEY)
+            .map(|id| encode_id(id, 16))
+            .unwrap_or_default(),
+        span_id: link
+            .get(SPAN_ID_KEY)
+            .map(|id| encode_id(id, 8))
+            .unwrap_or_default(),
+        trace_state: link
+            .get(TRACE_STATE_KEY)
+            .map(|state| state.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        attributes: encode_attributes(link.get(ATTRIBUTES_KEY)),
+        dropped_attributes_count: count(link.get(DROPPED_ATTRIBUTES_COUNT_KEY)),
+    }
+}
+
+fn encode_attributes(attributes: Option<&Value>) -> Vec<KeyValue> {
+    match attributes {
+        Some(Value::Object(fields)) => value_into_kv_list(fields.clone()),
+        _ => Vec::new(),
+    }
+}
+
+/// Reads a count, such as the number of dropped attributes, treating anything invalid as zero.
+fn count(value: Option<&Value>) -> u32 {
+    match value {
+        Some(Value::Integer(count)) => u32::try_from(*count).unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+/// Converts a trace or span ID into the `len` bytes that OTLP expects.
+///
+/// IDs may be hex encoded, as written by the `opentelemetry` source, raw bytes, or integers, as
+/// written by the `datadog_agent` source. Anything else is treated as a missing ID.
+fn encode_id(id: &Value, len: usize) -> Vec<u8> {
+    match id {
+        Value::Bytes(id) if id.len() == len * 2 => hex::decode(id).unwrap_or_default(),
+        Value::Bytes(id) if id.len() == len => id.to_vec(),
+        Value::Integer(id) if *id != 0 => {
+            let mut bytes = vec![0; len - 8];
+            bytes.extend(id.to_be_bytes());
+            bytes
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn string_attribute(key: &str, value: &Value) -> KeyValue {
+    KeyValue {
+        key: key.to_owned(),
+        value: Some(AnyValue {
+            value: Some(PBValue::StringValue(value.to_string_lossy().into_owned())),
+        }),
+    }
+}
+
+fn unix_nanos(timestamp: DateTime<Utc>) -> u64 {
+    timestamp.timestamp_nanos().max(0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use chrono::TimeZone;
+    use vector_core::metric_tags;
+
+    use super::*;
+
+    fn encoder() -> OtlpEncoder {
+        OtlpEncoder::new(vec![KeyValue {
+            key: "service.name".to_owned(),
+            value: Some(AnyValue {
+                value: Some(PBValue::StringValue("checkout".to_owned())),
+            }),
+        }])
+    }
+
+    fn string(value: &str) -> Option<AnyValue> {
+        Some(AnyValue {
+            value: Some(PBValue::StringValue(value.to_owned())),
+        })
+    }
+
+    fn attribute<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a AnyValue> {
+        attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .and_then(|attribute| attribute.value.as_ref())
+    }
+
+    #[test]
+    fn encodes_logs_from_opentelemetry_source() {
+        let timestamp = Utc.timestamp_nanos(1_679_000_000_000_000_000);
+        let mut log = LogEvent::from("checkout failed");
+        log.insert("timestamp", timestamp);
+        log.insert("source_type", "opentelemetry");
+        log.insert("resources.\"service.name\"", "payments");
+        log.insert("resources.\"host.name\"", "web-1");
+        log.insert("attributes.\"http.status_code\"", 500_i64);
+        log.insert("trace_id", "4bf92f3577b34da6a3ce929d0e0e4736");
+        log.insert("span_id", "00f067aa0ba902b7");
+        log.insert("severity_text", "ERROR");
+        log.insert("severity_number", 17_i64);
+        log.insert("dropped_attributes_count", 0_i64);
+        log.insert("order_id", "o-1234");
+
+        let request = encoder().encode_logs(vec![log]);
+
+        assert_eq!(request.resource_logs.len(), 1);
+        let resource_logs = &request.resource_logs[0];
+        let resource = &resource_logs.resource.as_ref().unwrap().attributes;
+        // Configured attributes take precedence over those of the event.
+        assert_eq!(
+            attribute(resource, "service.name"),
+            string("checkout").as_ref()
+        );
+        assert_eq!(attribute(resource, "host.name"), string("web-1").as_ref());
+
+        let scope_logs = &resource_logs.scope_logs[0];
+        assert_eq!(scope_logs.scope.as_ref().unwrap().name, "vector");
+
+        let record = &scope_logs.log_records[0];
+        assert_eq!(record.body, string("checkout failed"));
+        assert_eq!(record.time_unix_nano, 1_679_000_000_000_000_000);
+        assert_eq!(record.severity_text, "ERROR");
+        assert_eq!(record.severity_number, 17);
+        assert_eq!(
+            record.trace_id,
+            hex::decode("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+        );
+        assert_eq!(record.span_id, hex::decode("00f067aa0ba902b7").unwrap());
+        assert_eq!(
+            attribute(&record.attributes, "order_id"),
+            string("o-1234").as_ref()
+        );
+        assert!(attribute(&record.attributes, "http.status_code").is_some());
+        assert!(attribute(&record.attributes, "source_type").is_none());
+    }
+
+    #[test]
+    fn groups_logs_by_resource() {
+        let logs = ["web-1", "web-2", "web-1"]
+            .into_iter()
+            .map(|host| {
+                let mut log = LogEvent::from("message");
+                log.insert("resources.\"host.name\"", host);
+                log
+            })
+            .collect();
+
+        let request = encoder().encode_logs(logs);
+
+        let counts = request
+            .resource_logs
+            .iter()
+            .map(|resource_logs| resource_logs.scope_logs[0].log_records.len())
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 1]);
+    }
+
+    #[test]
+    fn encodes_metrics() {
+        let timestamp = Utc.timestamp_nanos(1_679_000_000_000_000_000);
+        let counter = Metric::new(
+            "requests_total",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 3.0 },
+        )
+        .with_namespace(Some("http"))
+        .with_tags(Some(metric_tags!("method" => "GET")))
+        .with_timestamp(Some(timestamp));
+        let gauge = Metric::new(
+            "temperature",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 21.5 },
+        );
+        let set = Metric::new(
+            "users",
+            MetricKind::Incremental,
+            MetricValue::Set {
+                values: BTreeSet::from(["alice".to_owned(), "bob".to_owned()]),
+            },
+        );
+
+        let request = encoder().encode_metrics(vec![counter, gauge, set]);
+
+        let metrics = &request.resource_metrics[0].scope_metrics[0].metrics;
+        assert_eq!(metrics[0].name, "http.requests_total");
+        match metrics[0].data.as_ref().unwrap() {
+            Data::Sum(sum) => {
+                assert!(sum.is_monotonic);
+                assert_eq!(
+                    sum.aggregation_temporality,
+                    AggregationTemporality::Delta as i32
+                );
+                let point = &sum.data_points[0];
+                assert_eq!(point.value, Some(number_data_point::Value::AsDouble(3.0)));
+                assert_eq!(point.time_unix_nano, 1_679_000_000_000_000_000);
+                assert_eq!(
+                    attribute(&point.attributes, "method"),
+                    string("GET").as_ref()
+                );
+            }
+            data => panic!("unexpected data {:?}", data),
+        }
+        match metrics[1].data.as_ref().unwrap() {
+            Data::Gauge(gauge) => assert_eq!(
+                gauge.data_points[0].value,
+                Some(number_data_point::Value::AsDouble(21.5))
+            ),
+            data => panic!("unexpected data {:?}", data),
+        }
+        match metrics[2].data.as_ref().unwrap() {
+            Data::Gauge(gauge) => assert_eq!(
+                gauge.data_points[0].value,
+                Some(number_data_point::Value::AsDouble(2.0))
+            ),
+            data => panic!("unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn encodes_histogram_overflow_bucket() {
+        let histogram = Metric::new(
+            "latency",
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 0.1,
+                        count: 4,
+                    },
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 5,
+                    },
+                ],
+                count: 10,
+                sum: 4.2,
+            },
+        );
+
+        let request = encoder().encode_metrics(vec![histogram]);
+
+        match request.resource_metrics[0].scope_metrics[0].metrics[0]
+            .data
+            .as_ref()
+            .unwrap()
+        {
+            Data::Histogram(histogram) => {
+                assert_eq!(
+                    histogram.aggregation_temporality,
+                    AggregationTemporality::Cumulative as i32
+                );
+                let point = &histogram.data_points[0];
+                assert_eq!(point.explicit_bounds, vec![0.1, 1.0]);
+                assert_eq!(point.bucket_counts, vec![4, 5, 1]);
+                assert_eq!(point.count, 10);
+                assert_eq!(point.sum, Some(4.2));
+            }
+            data => panic!("unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn encodes_datadog_traces() {
+        let start = Utc.timestamp_nanos(1_679_000_000_000_000_000);
+        let span = Value::from(BTreeMap::from([
+            ("service".to_owned(), Value::from("checkout")),
+            ("name".to_owned(), Value::from("http.request")),
+            ("resource".to_owned(), Value::from("GET /cart")),
+            ("trace_id".to_owned(), Value::from(42_i64)),
+            ("span_id".to_owned(), Value::from(7_i64)),
+            ("parent_id".to_owned(), Value::from(0_i64)),
+            ("start".to_owned(), Value::from(start)),
+            ("duration".to_owned(), Value::from(1_500_i64)),
+            ("error".to_owned(), Value::from(1_i64)),
+            (
+                "meta".to_owned(),
+                Value::from(BTreeMap::from([(
+                    "http.method".to_owned(),
+                    Value::from("GET"),
+                )])),
+            ),
+        ]));
+        let mut trace = TraceEvent::default();
+        trace.insert("spans", Value::Array(vec![span]));
+
+        let request = OtlpEncoder::new(Vec::new()).encode_traces(vec![trace]);
+
+        let resource_spans = &request.resource_spans[0];
+        assert_eq!(
+            attribute(
+                &resource_spans.resource.as_ref().unwrap().attributes,
+                "service.name"
+            ),
+            string("checkout").as_ref()
+        );
+
+        let span = &resource_spans.scope_spans[0].spans[0];
+        assert_eq!(span.name, "http.request");
+        assert_eq!(span.trace_id, [[0; 8], 42u64.to_be_bytes()].concat());
+        assert_eq!(span.span_id, 7u64.to_be_bytes().to_vec());
+        assert!(span.parent_span_id.is_empty());
+        assert_eq!(span.start_time_unix_nano, 1_679_000_000_000_000_000);
+        assert_eq!(span.end_time_unix_nano, 1_679_000_000_000_001_500);
+        assert_eq!(span.status.as_ref().unwrap().code, StatusCode::Error as i32);
+        assert_eq!(
+            attribute(&span.attributes, "http.method"),
+            string("GET").as_ref()
+        );
+        assert_eq!(
+            attribute(&span.attributes, "resource.name"),
+            string("GET /cart").as_ref()
+        );
+    }
+
+    #[test]
+    fn encodes_spans_from_opentelemetry_source() {
+        let mut trace = TraceEvent::default();
+        trace.insert("trace_id", "4bf92f3577b34da6a3ce929d0e0e4736");
+        trace.insert("span_id", "00f067aa0ba902b7");
+        trace.insert("parent_span_id", "10f067aa0ba902b7");
+        trace.insert("trace_state", "vendor=value");
+        trace.insert("name", "GET /cart");
+        trace.insert("kind", 2_i64);
+        trace.insert(
+            "start_time_unix_nano",
+            Utc.timestamp_nanos(1_679_000_000_000_000_000),
+        );
+        trace.insert(
+            "end_time_unix_nano",
+            Utc.timestamp_nanos(1_679_000_000_000_001_500),
+        );
+        trace.insert("dropped_attributes_count", 1_i64);
+        trace.insert(
+            "events",
+            vrl::value!([{name: "cache miss", time_unix_nano: 1_679_000_000_000_000_500, attributes: {key: "cart:1"}}]),
+        );
+        trace.insert(
+            "links",
+            vrl::value!([{trace_id: "5bf92f3577b34da6a3ce929d0e0e4736", span_id: "20f067aa0ba902b7"}]),
+        );
+        trace.insert("dropped_links_count", 3_i64);
+        trace.insert(
+            "status",
+            vrl::value!({code: 2, message: "upstream timed out"}),
+        );
+        trace.insert("resources", vrl::value!({"host.name": "web-1"}));
+
+        let request = encoder().encode_traces(vec![trace]);
+        let resource_spans = &request.resource_spans[0];
+        assert_eq!(
+            attribute(
+                &resource_spans.resource.as_ref().unwrap().attributes,
+                "host.name"
+            ),
+            string("web-1").as_ref()
+        );
+
+        let span = &resource_spans.scope_spans[0].spans[0];
+        assert_eq!(span.name, "GET /cart");
+        assert_eq!(span.kind, SpanKind::Server as i32);
+        assert_eq!(span.trace_state, "vendor=value");
+        assert_eq!(
+            span.parent_span_id,
+            hex::decode("10f067aa0ba902b7").unwrap()
+        );
+        assert_eq!(span.dropped_attributes_count, 1);
+        assert_eq!(span.events[0].name, "cache miss");
+        assert_eq!(span.events[0].time_unix_nano, 1_679_000_000_000_000_500);
+        assert_eq!(
+            attribute(&span.events[0].attributes, "key"),
+            string("cart:1").as_ref()
+        );
+        assert_eq!(
+            span.links[0].trace_id,
+            hex::decode("5bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+        );
+        assert_eq!(span.dropped_links_count, 3);
+        let status = span.status.as_ref().unwrap();
+        assert_eq!(status.code, StatusCode::Error as i32);
+        assert_eq!(status.message, "upstream timed out");
+    }
+
+    #[test]
+    fn ignores_traces_without_spans() {
+        let trace = TraceEvent::from(LogEvent::from("not a span"));
+        let request = encoder().encode_traces(vec![trace]);
+        assert!(request.resource_spans.is_empty());
+    }
+}
@@ -0,0 +1,30 @@
+//! The OpenTelemetry [`vector_core::sink::VectorSink`].
+//!
+//! This module contains the [`vector_core::sink::VectorSink`] instance that is responsible for
+//! exporting logs, metrics, and traces to an OpenTelemetry Protocol (OTLP) receiver, over either
+//! gRPC or HTTP.
+use http::StatusCode;
+use snafu::Snafu;
+
+mod config;
+mod encoder;
+mod service;
+mod sink;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::OpentelemetryConfig;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(super)))]
+pub enum OpentelemetrySinkError {
+    #[snafu(display("gRPC request failed: {}", source))]
+    Grpc { source: tonic::Status },
+
+    #[snafu(display("HTTP request failed: {}", source))]
+    Http { source: crate::http::HttpError },
+
+    #[snafu(display("Receiver responded with an error: {}", status))]
+    Server { status: StatusCode },
+}
@@ -0,0 +1,302 @@
+use std::{
+    io::Write,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    HeaderMap, HeaderName, HeaderValue, Request, Uri,
+};
+use hyper::{client::HttpConnector, Body};
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use indexmap::IndexMap;
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    metrics::v1::{metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest},
+    trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+};
+use prost::Message;
+use snafu::ResultExt;
+use tonic::{body::BoxBody, codec::CompressionEncoding, metadata::MetadataMap};
+use tower::Service;
+use vector_common::request_metadata::{MetaDescriptive, RequestMetadata};
+use vector_core::{
+    event::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::CountByteSize,
+    stream::DriverResponse,
+};
+
+use super::{GrpcSnafu, HttpSnafu, OpentelemetrySinkError, ServerSnafu};
+use crate::{
+    http::HttpClient,
+    sinks::{
+        util::{retries::RetryLogic, Compression, Compressor},
+        vector::HyperSvc,
+    },
+};
+
+/// An OTLP export request for a single signal.
+#[derive(Clone, Debug)]
+pub enum ExportRequest {
+    Logs(ExportLogsServiceRequest),
+    Metrics(ExportMetricsServiceRequest),
+    Traces(ExportTraceServiceRequest),
+}
+
+impl ExportRequest {
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Self::Logs(request) => request.encoded_len(),
+            Self::Metrics(request) => request.encoded_len(),
+            Self::Traces(request) => request.encoded_len(),
+        }
+    }
+
+    fn encode_to_vec(&self) -> Vec<u8> {
+        match self {
+            Self::Logs(request) => request.encode_to_vec(),
+            Self::Metrics(request) => request.encode_to_vec(),
+            Self::Traces(request) => request.encode_to_vec(),
+        }
+    }
+
+    /// The path of the OTLP/HTTP endpoint for this signal.
+    const fn path(&self) -> &'static str {
+        match self {
+            Self::Logs(_) => "/v1/logs",
+            Self::Metrics(_) => "/v1/metrics",
+            Self::Traces(_) => "/v1/traces",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpentelemetryRequest {
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+    pub payload: ExportRequest,
+}
+
+impl Finalizable for OpentelemetryRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for OpentelemetryRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub struct OpentelemetryResponse {
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for OpentelemetryResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(
+            self.metadata.event_count(),
+            self.metadata.events_estimated_json_encoded_byte_size(),
+        )
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.metadata.request_encoded_size())
+    }
+}
+
+/// How export requests reach the receiver.
+#[derive(Clone)]
+pub enum Transport {
+    Grpc {
+        logs: LogsServiceClient<HyperSvc>,
+        metrics: MetricsServiceClient<HyperSvc>,
+        traces: TraceServiceClient<HyperSvc>,
+        metadata: MetadataMap,
+    },
+    Http {
+        client: HttpClient,
+        endpoint: String,
+        headers: IndexMap<HeaderName, HeaderValue>,
+        compression: bool,
+    },
+}
+
+impl Transport {
+    pub fn grpc(
+        client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+        uri: Uri,
+        headers: IndexMap<HeaderName, HeaderValue>,
+        compression: bool,
+    ) -> Self {
+        let service = HyperSvc::new(uri, client);
+        let mut logs = LogsServiceClient::new(service.clone());
+        let mut metrics = MetricsServiceClient::new(service.clone());
+        let mut traces = TraceServiceClient::new(service);
+
+        if compression {
+            logs = logs.send_compressed(CompressionEncoding::Gzip);
+            metrics = metrics.send_compressed(CompressionEncoding::Gzip);
+            traces = traces.send_compressed(CompressionEncoding::Gzip);
+        }
+
+        Self::Grpc {
+            logs,
+            metrics,
+            traces,
+            metadata: MetadataMap::from_headers(headers.into_iter().collect::<HeaderMap>()),
+        }
+    }
+
+    pub const fn http(
+        client: HttpClient,
+        endpoint: String,
+        headers: IndexMap<HeaderName, HeaderValue>,
+        compression: bool,
+    ) -> Self {
+        Self::Http {
+            client,
+            endpoint,
+            headers,
+            compression,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpentelemetryService {
+    transport: Transport,
+}
+
+impl OpentelemetryService {
+    pub const fn new(transport: Transport) -> Self {
+        Self { transport }
+    }
+
+    pub async fn export(self, payload: ExportRequest) -> Result<(), OpentelemetrySinkError> {
+        match self.transport {
+            Transport::Grpc {
+                mut logs,
+                mut metrics,
+                mut traces,
+                metadata,
+            } => match payload {
+                ExportRequest::Logs(request) => logs
+                    .export(with_metadata(request, metadata))
+                    .await
+                    .map(drop),
+                ExportRequest::Metrics(request) => metrics
+                    .export(with_metadata(request, metadata))
+                    .await
+                    .map(drop),
+                ExportRequest::Traces(request) => traces
+                    .export(with_metadata(request, metadata))
+                    .await
+                    .map(drop),
+            }
+            .context(GrpcSnafu),
+            Transport::Http {
+                client,
+                endpoint,
+                headers,
+                compression,
+            } => {
+                let mut body = payload.encode_to_vec();
+                let mut builder = Request::post(format!("{}{}", endpoint, payload.path()))
+                    .header(CONTENT_TYPE, "application/x-protobuf");
+                if compression {
+                    let mut compressor = Compressor::from(Compression::gzip_default());
+                    compressor
+                        .write_all(&body)
+                        .expect("writing to a buffer cannot fail");
+                    body = compressor
+                        .finish()
+                        .expect("writing to a buffer cannot fail")
+                        .to_vec();
+                    builder = builder.header(CONTENT_ENCODING, "gzip");
+                }
+                for (name, value) in headers {
+                    builder = builder.header(name, value);
+                }
+
+                let request = builder
+                    .body(Body::from(Bytes::from(body)))
+                    .expect("endpoint is validated when the sink is built");
+                let response = client.send(request).await.context(HttpSnafu)?;
+
+                let status = response.status();
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    ServerSnafu { status }.fail()
+                }
+            }
+        }
+    }
+}
+
+fn with_metadata<T>(message: T, metadata: MetadataMap) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    *request.metadata_mut() = metadata;
+    request
+}
+
+impl Service<OpentelemetryRequest> for OpentelemetryService {
+    type Response = OpentelemetryResponse;
+    type Error = OpentelemetrySinkError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Emission of an internal event in case of errors is handled upstream by the caller.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, request: OpentelemetryRequest) -> Self::Future {
+        let service = self.clone();
+        let metadata = request.get_metadata();
+
+        Box::pin(async move {
+            service.export(request.payload).await?;
+            Ok(OpentelemetryResponse { metadata })
+        })
+    }
+}
+
+/// Retries the failures that the [OTLP specification][otlp_retry] marks as retryable.
+///
+/// [otlp_retry]: https://github.com/open-telemetry/opentelemetry-proto/blob/main/docs/specification.md#failures
+#[derive(Debug, Clone)]
+pub struct OpentelemetryRetryLogic;
+
+impl RetryLogic for OpentelemetryRetryLogic {
+    type Error = OpentelemetrySinkError;
+    type Response = OpentelemetryResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match error {
+            OpentelemetrySinkError::Grpc { source } => matches!(
+                source.code(),
+                Cancelled
+                    | DeadlineExceeded
+                    | ResourceExhausted
+                    | Aborted
+                    | OutOfRange
+                    | Unavailable
+                    | DataLoss
+            ),
+            OpentelemetrySinkError::Http { .. } => true,
+            OpentelemetrySinkError::Server { status } => matches!(status.as_u16(), 429 | 502..=504),
+        }
+    }
+}
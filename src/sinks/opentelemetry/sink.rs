@@ -0,0 +1,122 @@
+use std::{fmt, num::NonZeroUsize};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use opentelemetry_proto::proto::common::v1::KeyValue;
+use tower::Service;
+use vector_core::{
+    event::{Event, Finalizable},
+    partition::Partitioner,
+    sink::StreamSink,
+    stream::{BatcherSettings, DriverResponse},
+};
+
+use super::{
+    config::Protocol,
+    encoder::OtlpEncoder,
+    service::{ExportRequest, OpentelemetryRequest},
+};
+use crate::sinks::util::{metadata::RequestMetadataBuilder, SinkBuilderExt};
+
+/// The OTLP signals, each of which is exported with its own request.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Signal {
+    Logs,
+    Metrics,
+    Traces,
+}
+
+struct SignalPartitioner;
+
+impl Partitioner for SignalPartitioner {
+    type Item = Event;
+    type Key = Signal;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        match item {
+            Event::Log(_) => Signal::Logs,
+            Event::Metric(_) => Signal::Metrics,
+            Event::Trace(_) => Signal::Traces,
+        }
+    }
+}
+
+pub struct OpentelemetrySink<S> {
+    service: S,
+    batch_settings: BatcherSettings,
+    encoder: OtlpEncoder,
+    protocol: Protocol,
+}
+
+impl<S> OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    pub(super) fn new(
+        service: S,
+        batch_settings: BatcherSettings,
+        resource_attributes: Vec<KeyValue>,
+        protocol: Protocol,
+    ) -> Self {
+        Self {
+            service,
+            batch_settings,
+            encoder: OtlpEncoder::new(resource_attributes),
+            protocol,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let encoder = self.encoder;
+
+        input
+            .batched_partitioned(SignalPartitioner, self.batch_settings)
+            .map(|(signal, mut events)| {
+                let finalizers = events.take_finalizers();
+                let builder = RequestMetadataBuilder::from_events(&events);
+
+                let payload = match signal {
+                    Signal::Logs => ExportRequest::Logs(
+                        encoder.encode_logs(events.into_iter().map(Event::into_log).collect()),
+                    ),
+                    Signal::Metrics => ExportRequest::Metrics(
+                        encoder
+                            .encode_metrics(events.into_iter().map(Event::into_metric).collect()),
+                    ),
+                    Signal::Traces => ExportRequest::Traces(
+                        encoder.encode_traces(events.into_iter().map(Event::into_trace).collect()),
+                    ),
+                };
+
+                // An empty export request encodes to nothing, so count it as a single byte.
+                let request_size = NonZeroUsize::new(payload.encoded_len())
+                    .unwrap_or_else(|| NonZeroUsize::new(1).unwrap());
+
+                OpentelemetryRequest {
+                    finalizers,
+                    metadata: builder.with_request_size(request_size),
+                    payload,
+                }
+            })
+            .into_driver(self.service)
+            .protocol(self.protocol.as_str())
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
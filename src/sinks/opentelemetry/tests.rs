@@ -0,0 +1,268 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::{stream, StreamExt};
+use http::{request::Parts, StatusCode};
+use hyper::Method;
+use opentelemetry_proto::proto::{
+    collector::{
+        logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+        metrics::v1::ExportMetricsServiceRequest,
+    },
+    common::v1::any_value::Value as PBValue,
+};
+use prost::Message;
+use vector_core::event::{BatchNotifier, BatchStatus, MetricKind, MetricValue};
+
+use super::{
+    config::OpentelemetryConfig, service::OpentelemetryRetryLogic, OpentelemetrySinkError,
+};
+use crate::{
+    config::{SinkConfig, SinkContext},
+    event::{Event, Metric},
+    sinks::util::{retries::RetryLogic, test::build_test_server_generic},
+    test_util::{
+        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        next_addr, random_lines_with_stream,
+    },
+};
+
+// one byte for the compression flag plus four bytes for the length
+const GRPC_HEADER_SIZE: usize = 5;
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<OpentelemetryConfig>();
+}
+
+#[tokio::test]
+async fn exports_logs_over_grpc() {
+    let num_lines = 10;
+    let in_addr = next_addr();
+
+    let config: OpentelemetryConfig = toml::from_str(&format!(
+        r#"
+            endpoint = "http://{}"
+            resource_attributes."service.name" = "checkout"
+            headers.x-api-key = "secret"
+        "#,
+        in_addr
+    ))
+    .unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("grpc-status", "0") // OK
+            .header("content-type", "application/grpc")
+            .body(hyper::Body::from(encode_grpc_body(
+                ExportLogsServiceResponse {},
+            )))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (input_lines, events) = random_lines_with_stream(8, num_lines, Some(batch));
+
+    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let requests = rx
+        .map(|(parts, body)| {
+            assert_eq!(Method::POST, parts.method);
+            assert_eq!(
+                "/opentelemetry.proto.collector.logs.v1.LogsService/Export",
+                parts.uri.path()
+            );
+            assert_eq!(parts.headers.get("x-api-key").unwrap(), "secret");
+            ExportLogsServiceRequest::decode(body.slice(GRPC_HEADER_SIZE..)).unwrap()
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut output_lines = Vec::new();
+    for request in requests {
+        for resource_logs in request.resource_logs {
+            let resource = resource_logs.resource.unwrap();
+            assert_eq!(resource.attributes[0].key, "service.name");
+
+            for record in resource_logs
+                .scope_logs
+                .into_iter()
+                .flat_map(|scope_logs| scope_logs.log_records)
+            {
+                match record.body.and_then(|body| body.value) {
+                    Some(PBValue::StringValue(line)) => output_lines.push(line),
+                    body => panic!("unexpected body {:?}", body),
+                }
+            }
+        }
+    }
+    assert_eq!(input_lines, output_lines);
+}
+
+#[tokio::test]
+async fn exports_metrics_over_http() {
+    let in_addr = next_addr();
+
+    let config: OpentelemetryConfig = toml::from_str(&format!(
+        r#"
+            endpoint = "http://{}/otlp/"
+            protocol = "http"
+        "#,
+        in_addr
+    ))
+    .unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .status(StatusCode::OK)
+            .body(hyper::Body::empty())
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..5)
+        .map(|i| {
+            Event::from(
+                Metric::new(
+                    format!("counter_{}", i),
+                    MetricKind::Incremental,
+                    MetricValue::Counter { value: i as f64 },
+                )
+                .with_batch_notifier(&batch),
+            )
+        })
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let requests = rx.collect::<Vec<(Parts, Bytes)>>().await;
+    let mut names = Vec::new();
+    for (parts, body) in requests {
+        assert_eq!("/otlp/v1/metrics", parts.uri.path());
+        assert_eq!(
+            parts.headers.get("content-type").unwrap(),
+            "application/x-protobuf"
+        );
+        let request = ExportMetricsServiceRequest::decode(body).unwrap();
+        names.extend(
+            request
+                .resource_metrics
+                .into_iter()
+                .flat_map(|resource_metrics| resource_metrics.scope_metrics)
+                .flat_map(|scope_metrics| scope_metrics.metrics)
+                .map(|metric| metric.name),
+        );
+    }
+    assert_eq!(
+        names,
+        vec![
+            "counter_0",
+            "counter_1",
+            "counter_2",
+            "counter_3",
+            "counter_4"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rejects_events_refused_by_receiver() {
+    let in_addr = next_addr();
+
+    let config: OpentelemetryConfig = toml::from_str(&format!(
+        r#"
+            endpoint = "http://{}"
+            protocol = "http"
+        "#,
+        in_addr
+    ))
+    .unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (_rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(hyper::Body::empty())
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (_, events) = random_lines_with_stream(8, 10, Some(batch));
+
+    sink.run(events).await.expect("Running sink failed");
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
+
+#[test]
+fn retries_transient_failures() {
+    let logic = OpentelemetryRetryLogic;
+
+    for (status, retriable) in [
+        (StatusCode::TOO_MANY_REQUESTS, true),
+        (StatusCode::SERVICE_UNAVAILABLE, true),
+        (StatusCode::BAD_REQUEST, false),
+        (StatusCode::INTERNAL_SERVER_ERROR, false),
+    ] {
+        assert_eq!(
+            logic.is_retriable_error(&OpentelemetrySinkError::Server { status }),
+            retriable,
+            "{}",
+            status
+        );
+    }
+
+    for (status, retriable) in [
+        (tonic::Status::unavailable("restarting"), true),
+        (tonic::Status::invalid_argument("bad data"), false),
+    ] {
+        assert_eq!(
+            logic.is_retriable_error(&OpentelemetrySinkError::Grpc { source: status }),
+            retriable
+        );
+    }
+}
+
+// taken from <https://github.com/hyperium/tonic/blob/5aa8ae1fec27377cd4c2a41d309945d7e38087d0/examples/src/grpc-web/client.rs#L45-L75>
+fn encode_grpc_body<T>(msg: T) -> Bytes
+where
+    T: prost::Message,
+{
+    let mut buf = BytesMut::with_capacity(1024);
+
+    // first skip past the header
+    // cannot write it yet since we don't know the size of the
+    // encoded message
+    buf.reserve(GRPC_HEADER_SIZE);
+    unsafe {
+        buf.advance_mut(GRPC_HEADER_SIZE);
+    }
+
+    // write the message
+    msg.encode(&mut buf).unwrap();
+
+    // now we know the size of encoded message and can write the
+    // header
+    let len = buf.len() - GRPC_HEADER_SIZE;
+    {
+        let mut buf = &mut buf[..GRPC_HEADER_SIZE];
+
+        // compression flag, 0 means "no compression"
+        buf.put_u8(0);
+
+        buf.put_u32(len as u32);
+    }
+
+    buf.split_to(len + GRPC_HEADER_SIZE).freeze()
+}
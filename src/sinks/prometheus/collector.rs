@@ -2,11 +2,15 @@ use std::{collections::BTreeMap, fmt::Write as _};
 
 use chrono::Utc;
 use indexmap::map::IndexMap;
+use lookup::path;
 use prometheus_parser::{proto, METRIC_NAME_LABEL};
 use vector_core::event::metric::{samples_to_buckets, MetricSketch, MetricTags, Quantile};
 
 use crate::{
-    event::metric::{Metric, MetricKind, MetricValue, StatisticKind},
+    event::{
+        metric::{Metric, MetricKind, MetricValue, StatisticKind},
+        Value,
+    },
     sinks::util::{encode_namespace, statistic::DistributionStatistic},
 };
 
@@ -27,6 +31,9 @@ pub(super) trait MetricCollector {
         extra: Option<(&str, String)>,
     );
 
+    /// Records the description and the exemplars the metric was received with, if any.
+    fn emit_annotations(&mut self, _name: &str, _fullname: &str, _metric: &Metric) {}
+
     fn finish(self) -> Self::Output;
 
     fn encode_metric(
@@ -214,6 +221,8 @@ pub(super) trait MetricCollector {
                     }
                 },
             }
+
+            self.emit_annotations(metric.name(), name, metric);
         }
     }
 }
@@ -318,6 +327,7 @@ type Labels = Vec<proto::Label>;
 
 pub(super) struct TimeSeries {
     buffer: IndexMap<Labels, Vec<proto::Sample>>,
+    exemplars: IndexMap<Labels, Vec<proto::Exemplar>>,
     metadata: IndexMap<String, proto::MetricMetadata>,
     timestamp: Option<i64>,
 }
@@ -354,6 +364,38 @@ impl TimeSeries {
             .timestamp
             .get_or_insert_with(|| Utc::now().timestamp_millis())
     }
+
+    /// Decodes an exemplar stored in the metadata of a metric by the `prometheus_remote_write`
+    /// source.
+    fn decode_exemplar(&mut self, exemplar: &Value) -> Option<proto::Exemplar> {
+        let value = match exemplar.get(path!("value"))? {
+            Value::Float(value) => value.into_inner(),
+            Value::Integer(value) => *value as f64,
+            _ => return None,
+        };
+        let labels = match exemplar.get(path!("labels")) {
+            Some(Value::Object(labels)) => labels
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some(proto::Label {
+                        name: name.clone(),
+                        value: value.as_str()?.into_owned(),
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let timestamp = match exemplar.get(path!("timestamp")) {
+            Some(Value::Timestamp(timestamp)) => timestamp.timestamp_millis(),
+            _ => self.default_timestamp(),
+        };
+
+        Some(proto::Exemplar {
+            labels,
+            value,
+            timestamp,
+        })
+    }
 }
 
 impl MetricCollector for TimeSeries {
@@ -362,6 +404,7 @@ impl MetricCollector for TimeSeries {
     fn new() -> Self {
         Self {
             buffer: Default::default(),
+            exemplars: Default::default(),
             metadata: Default::default(),
             timestamp: None,
         }
@@ -396,12 +439,72 @@ impl MetricCollector for TimeSeries {
             .push(proto::Sample { value, timestamp });
     }
 
-    fn finish(self) -> proto::WriteRequest {
-        let timeseries = self
+    fn emit_annotations(&mut self, name: &str, fullname: &str, metric: &Metric) {
+        let annotations = metric.metadata().value();
+        if let Some(metadata) = self.metadata.get_mut(name) {
+            if let Some(help) = annotations
+                .get(path!("prometheus", "help"))
+                .and_then(Value::as_str)
+            {
+                metadata.help = help.into_owned();
+            }
+            if let Some(unit) = annotations
+                .get(path!("prometheus", "unit"))
+                .and_then(Value::as_str)
+            {
+                metadata.unit = unit.into_owned();
+            }
+        }
+
+        let Some(Value::Array(exemplars)) = annotations.get(path!("prometheus", "exemplars")) else {
+            return;
+        };
+        for exemplar in exemplars {
+            let Some(exemplar) = self.decode_exemplar(exemplar) else {
+                continue;
+            };
+            // The exemplars of histograms belong to the first bucket their value fits in.
+            let (suffix, extra) = match metric.value() {
+                MetricValue::AggregatedHistogram { buckets, .. } => {
+                    let le = buckets
+                        .iter()
+                        .map(|bucket| bucket.upper_limit)
+                        .find(|limit| limit.is_finite() && exemplar.value <= *limit)
+                        .map_or_else(|| "+Inf".to_string(), |limit| limit.to_string());
+                    ("_bucket", Some(("le", le)))
+                }
+                _ => ("", None),
+            };
+            self.exemplars
+                .entry(Self::make_labels(metric.tags(), fullname, suffix, extra))
+                .or_default()
+                .push(exemplar);
+        }
+    }
+
+    fn finish(mut self) -> proto::WriteRequest {
+        let mut timeseries = self
             .buffer
             .into_iter()
-            .map(|(labels, samples)| proto::TimeSeries { labels, samples })
+            .map(|(labels, samples)| {
+                let exemplars = self.exemplars.remove(&labels).unwrap_or_default();
+                proto::TimeSeries {
+                    labels,
+                    samples,
+                    exemplars,
+                    histograms: Vec::new(),
+                }
+            })
             .collect::<Vec<_>>();
+        // Exemplars whose series have no samples are still sent on their own.
+        timeseries.extend(self.exemplars.into_iter().map(|(labels, exemplars)| {
+            proto::TimeSeries {
+                labels,
+                samples: Vec::new(),
+                exemplars,
+                histograms: Vec::new(),
+            }
+        }));
         let metadata = self
             .metadata
             .into_iter()
@@ -439,6 +542,7 @@ mod tests {
 
     use chrono::{DateTime, TimeZone, Timelike};
     use indoc::indoc;
+    use ordered_float::NotNan;
     use similar_asserts::assert_eq;
     use vector_core::metric_tags;
 
@@ -490,6 +594,8 @@ mod tests {
                                 value: $svalue,
                                 timestamp: $timestamp,
                             }],
+                            exemplars: vec![],
+                            histograms: vec![],
                         },
                     )*
                 ],
@@ -911,6 +1017,66 @@ mod tests {
         assert!(encoded.timeseries[0].samples[0].timestamp >= now);
     }
 
+    #[test]
+    fn encodes_annotations_request() {
+        let exemplar = |value: f64| {
+            Value::from(BTreeMap::from([
+                (
+                    "labels".to_owned(),
+                    Value::from(BTreeMap::from([(
+                        "trace_id".to_owned(),
+                        Value::from("abc"),
+                    )])),
+                ),
+                ("value".to_owned(), Value::from(NotNan::new(value).unwrap())),
+                ("timestamp".to_owned(), Value::from(timestamp())),
+            ]))
+        };
+        let mut metric = Metric::new(
+            "requests".to_owned(),
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vector_core::buckets![1.0 => 1, 2.1 => 2, 3.0 => 3],
+                count: 6,
+                sum: 11.5,
+            },
+        )
+        .with_timestamp(Some(timestamp()));
+        let metadata = metric.metadata_mut().value_mut();
+        metadata.insert(path!("prometheus", "help"), "How long requests take.");
+        metadata.insert(path!("prometheus", "unit"), "seconds");
+        metadata.insert(
+            path!("prometheus", "exemplars"),
+            vec![exemplar(2.05), exemplar(5.0)],
+        );
+
+        let encoded = encode_one::<TimeSeries>(Some("vector"), &[], &[], &metric);
+        assert_eq!(encoded.metadata[0].help, "How long requests take.");
+        assert_eq!(encoded.metadata[0].unit, "seconds");
+
+        let exemplars = encoded
+            .timeseries
+            .iter()
+            .filter(|series| !series.exemplars.is_empty())
+            .map(|series| {
+                let le = series
+                    .labels
+                    .iter()
+                    .find(|label| label.name == "le")
+                    .map(|label| label.value.as_str());
+                (le, series.exemplars[0].value)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(exemplars, vec![(Some("2.1"), 2.05), (Some("+Inf"), 5.0)]);
+        assert_eq!(
+            encoded.timeseries[1].exemplars[0].labels,
+            vec![proto::Label {
+                name: "trace_id".into(),
+                value: "abc".into(),
+            }]
+        );
+    }
+
     fn timestamp() -> DateTime<Utc> {
         Utc.with_ymd_and_hms(2021, 2, 3, 4, 5, 6)
             .single()
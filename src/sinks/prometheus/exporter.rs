@@ -41,6 +41,7 @@ use crate::{
     sinks::{
         util::{
             buffer::metrics::{MetricNormalize, MetricNormalizer, MetricSet},
+            metric_names::{MetricNameSanitizer, NameSanitizationConfig, NamingRules},
             statistic::validate_quantiles,
             StreamSink,
         },
@@ -137,6 +138,14 @@ pub struct PrometheusExporterConfig {
     #[configurable(metadata(docs::advanced))]
     pub suppress_timestamp: bool,
 
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    #[configurable(metadata(docs::advanced))]
+    pub name_sanitization: NameSanitizationConfig,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -158,6 +167,7 @@ impl Default for PrometheusExporterConfig {
             distributions_as_summaries: default_distributions_as_summaries(),
             flush_period_secs: default_flush_period_secs(),
             suppress_timestamp: default_suppress_timestamp(),
+            name_sanitization: Default::default(),
             acknowledgements: Default::default(),
         }
     }
@@ -198,7 +208,7 @@ impl SinkConfig for PrometheusExporterConfig {
 
         validate_quantiles(&self.quantiles)?;
 
-        let sink = PrometheusExporter::new(self.clone());
+        let sink = PrometheusExporter::new(self.clone())?;
         let healthcheck = future::ok(()).boxed();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
@@ -220,6 +230,7 @@ impl SinkConfig for PrometheusExporterConfig {
 struct PrometheusExporter {
     server_shutdown_trigger: Option<Trigger>,
     config: PrometheusExporterConfig,
+    sanitizer: MetricNameSanitizer,
     metrics: Arc<RwLock<IndexMap<MetricRef, (Metric, MetricMetadata)>>>,
 }
 
@@ -452,12 +463,19 @@ impl Handler {
 }
 
 impl PrometheusExporter {
-    fn new(config: PrometheusExporterConfig) -> Self {
-        Self {
+    fn new(config: PrometheusExporterConfig) -> crate::Result<Self> {
+        let sanitizer = MetricNameSanitizer::new(
+            &config.name_sanitization,
+            NamingRules::PROMETHEUS,
+            config.default_namespace.clone(),
+        )?;
+
+        Ok(Self {
             server_shutdown_trigger: None,
             config,
+            sanitizer,
             metrics: Arc::new(RwLock::new(IndexMap::new())),
-        }
+        })
     }
 
     async fn start_server_if_needed(&mut self) -> crate::Result<()> {
@@ -566,6 +584,11 @@ impl StreamSink<Event> for PrometheusExporter {
             let mut metric = event.into_metric();
             let finalizers = metric.take_finalizers();
 
+            let Some(metric) = self.sanitizer.sanitize(metric) else {
+                finalizers.update_status(EventStatus::Delivered);
+                continue;
+            };
+
             if let Some(normalized) = normalizer.normalize(metric) {
                 let normalized = if self.config.suppress_timestamp {
                     normalized.with_timestamp(None)
@@ -1062,7 +1085,7 @@ mod tests {
             ..Default::default()
         };
 
-        let sink = PrometheusExporter::new(config);
+        let sink = PrometheusExporter::new(config).unwrap();
 
         let m1 = Metric::new(
             "absolute",
@@ -1116,7 +1139,7 @@ mod tests {
         };
         let buckets = config.buckets.clone();
 
-        let sink = PrometheusExporter::new(config);
+        let sink = PrometheusExporter::new(config).unwrap();
 
         // Define a series of incremental distribution updates.
         let base_summary_metric = Metric::new(
@@ -1235,7 +1258,7 @@ mod tests {
             ..Default::default()
         };
 
-        let sink = PrometheusExporter::new(config);
+        let sink = PrometheusExporter::new(config).unwrap();
 
         // Define a series of incremental distribution updates.
         let base_summary_metric = Metric::new(
@@ -26,6 +26,7 @@ use crate::{
             batch::BatchConfig,
             buffer::metrics::{MetricNormalize, MetricNormalizer, MetricSet, MetricsBuffer},
             http::HttpRetryLogic,
+            metric_names::{MetricNameSanitizer, NameSanitizationConfig, NamingRules},
             uri, EncodedEvent, PartitionBuffer, PartitionInnerBuffer, SinkBatchSettings,
             TowerRequestConfig,
         },
@@ -116,6 +117,14 @@ pub struct RemoteWriteConfig {
     #[configurable(metadata(docs::advanced))]
     pub aws: Option<RegionOrEndpoint>,
 
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    #[configurable(metadata(docs::advanced))]
+    pub name_sanitization: NameSanitizationConfig,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -136,11 +145,13 @@ impl SinkConfig for RemoteWriteConfig {
         let endpoint = self.endpoint.parse::<Uri>().context(sinks::UriParseSnafu)?;
         let tls_settings = TlsSettings::from_options(&self.tls)?;
         let batch = self.batch.into_batch_settings()?;
+        self.request
+            .reject_bandwidth_limit("prometheus_remote_write")?;
         let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
         let buckets = self.buckets.clone();
         let quantiles = self.quantiles.clone();
 
-        let client = HttpClient::new(tls_settings, cx.proxy())?;
+        let client = HttpClient::new_with_options(tls_settings, cx.proxy(), cx.http_client())?;
         let tenant_id = self.tenant_id.clone();
 
         let (http_auth, credentials_provider, aws_region) = match &self.auth {
@@ -169,7 +180,11 @@ impl SinkConfig for RemoteWriteConfig {
 
                 (
                     None,
-                    Some(aws_auth.credentials_provider(region.clone()).await?),
+                    Some(
+                        aws_auth
+                            .credentials_provider(region.clone(), cx.proxy(), &self.tls)
+                            .await?,
+                    ),
                     Some(region),
                 )
             }
@@ -192,6 +207,12 @@ impl SinkConfig for RemoteWriteConfig {
             http_request_builder,
         };
 
+        let mut sanitizer = MetricNameSanitizer::new(
+            &self.name_sanitization,
+            NamingRules::PROMETHEUS,
+            self.default_namespace.clone(),
+        )?;
+
         let sink = {
             let buffer = PartitionBuffer::new(MetricsBuffer::new(batch.size));
             let mut normalizer = MetricNormalizer::<PrometheusMetricNormalize>::default();
@@ -200,7 +221,10 @@ impl SinkConfig for RemoteWriteConfig {
                 .partition_sink(HttpRetryLogic, service, buffer, batch.timeout)
                 .with_flat_map(move |event: Event| {
                     let byte_size = event.size_of();
-                    stream::iter(normalizer.normalize(event.into_metric()).map(|event| {
+                    let metric = sanitizer
+                        .sanitize(event.into_metric())
+                        .and_then(|metric| normalizer.normalize(metric));
+                    stream::iter(metric.map(|event| {
                         let tenant_id = tenant_id.as_ref().and_then(|template| {
                             template
                                 .render_string(&event)
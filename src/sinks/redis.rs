@@ -196,6 +196,7 @@ impl SinkConfig for RedisSinkConfig {
 
 impl RedisSinkConfig {
     pub fn new(&self, conn: ConnectionManager) -> crate::Result<super::VectorSink> {
+        self.request.reject_bandwidth_limit("redis")?;
         let request = self.request.unwrap_with(&TowerRequestConfig {
             concurrency: Concurrency::Fixed(1),
             ..Default::default()
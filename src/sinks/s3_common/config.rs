@@ -359,11 +359,7 @@ pub async fn create_service(
     proxy: &ProxyConfig,
     tls_options: &Option<TlsConfig>,
 ) -> crate::Result<S3Service> {
-    let endpoint = region.endpoint()?;
-    let region = region.region();
-    let client =
-        create_client::<S3ClientBuilder>(auth, region.clone(), endpoint, proxy, tls_options, true)
-            .await?;
+    let client = create_client::<S3ClientBuilder>(auth, region, proxy, tls_options, true).await?;
     Ok(S3Service::new(client))
 }
 
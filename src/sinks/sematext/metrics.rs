@@ -123,7 +123,7 @@ const EU_ENDPOINT: &str = "https://spm-receiver.eu.sematext.com";
 #[async_trait::async_trait]
 impl SinkConfig for SematextMetricsConfig {
     async fn build(&self, cx: SinkContext) -> Result<(VectorSink, Healthcheck)> {
-        let client = HttpClient::new(None, cx.proxy())?;
+        let client = HttpClient::new_with_options(None, cx.proxy(), cx.http_client())?;
 
         let endpoint = match (&self.endpoint, &self.region) {
             (Some(endpoint), _) => endpoint.clone(),
@@ -169,7 +169,8 @@ impl SematextMetricsService {
             retry_attempts: Some(5),
             ..Default::default()
         });
-        let http_service = HttpBatchService::new(client, create_build_request(endpoint));
+        let http_service = HttpBatchService::new(client, create_build_request(endpoint))
+            .with_bandwidth_limit(request.bandwidth_limit.clone());
         let sematext_service = SematextMetricsService {
             config,
             inner: http_service,
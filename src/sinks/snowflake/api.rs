@@ -0,0 +1,249 @@
+use bytes::Bytes;
+use http::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    Method, Request, StatusCode, Uri,
+};
+use hyper::Body;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Deserialize;
+use snafu::ResultExt;
+use tokio::{sync::Mutex, time::Instant};
+
+use super::{
+    auth::{KeyPairAuth, TOKEN_LIFETIME},
+    BodySnafu, HttpSnafu, ParseSnafu, SignSnafu, SnowflakeError, UrlSnafu,
+};
+use crate::http::HttpClient;
+
+const TOKEN_TYPE_HEADER: &str = "X-Snowflake-Authorization-Token-Type";
+
+const JWT_BEARER_GRANT: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// Everything but the unreserved characters of RFC 3986 is escaped in path segments and query values.
+const ESCAPED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// The streaming channel that rows are appended to.
+#[derive(Clone, Debug)]
+pub(super) struct Channel {
+    pub database: String,
+    pub schema: String,
+    pub pipe: String,
+    pub name: String,
+}
+
+impl Channel {
+    fn path(&self) -> String {
+        format!(
+            "/v2/streaming/databases/{}/schemas/{}/pipes/{}/channels/{}",
+            encode(&self.database),
+            encode(&self.schema),
+            encode(&self.pipe),
+            encode(&self.name),
+        )
+    }
+
+    fn rows_path(&self) -> String {
+        format!(
+            "/v2/streaming/data/databases/{}/schemas/{}/pipes/{}/channels/{}/rows",
+            encode(&self.database),
+            encode(&self.schema),
+            encode(&self.pipe),
+            encode(&self.name),
+        )
+    }
+}
+
+fn encode(segment: &str) -> String {
+    utf8_percent_encode(segment, ESCAPED).to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct OpenChannelResponse {
+    pub next_continuation_token: String,
+    #[serde(default)]
+    pub channel_status: ChannelStatus,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub(super) struct ChannelStatus {
+    pub last_committed_offset_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct AppendRowsResponse {
+    pub next_continuation_token: String,
+}
+
+/// The ingest host of the account, along with a token scoped to it.
+struct Session {
+    ingest_url: String,
+    token: String,
+    expires_at: Instant,
+}
+
+/// A client of the Snowpipe Streaming REST API.
+pub(super) struct SnowflakeClient {
+    client: HttpClient,
+    endpoint: Uri,
+    auth: KeyPairAuth,
+    session: Mutex<Option<Session>>,
+}
+
+impl SnowflakeClient {
+    pub(super) fn new(client: HttpClient, endpoint: Uri, auth: KeyPairAuth) -> Self {
+        Self {
+            client,
+            endpoint,
+            auth,
+            session: Mutex::new(None),
+        }
+    }
+
+    pub(super) fn protocol(&self) -> &str {
+        self.endpoint.scheme_str().unwrap_or("https")
+    }
+
+    /// Authenticates and looks up the ingest host of the account.
+    pub(super) async fn healthcheck(&self) -> Result<(), SnowflakeError> {
+        self.start_session().await.map(drop)
+    }
+
+    /// Opens the channel, invalidating any previously returned continuation token.
+    pub(super) async fn open_channel(
+        &self,
+        channel: &Channel,
+    ) -> Result<OpenChannelResponse, SnowflakeError> {
+        let body = self
+            .send_ingest(
+                Method::PUT,
+                channel.path(),
+                "application/json",
+                Bytes::from_static(b"{}"),
+            )
+            .await?;
+        serde_json::from_slice(&body).context(ParseSnafu)
+    }
+
+    /// Appends newline delimited JSON rows to the channel.
+    pub(super) async fn append_rows(
+        &self,
+        channel: &Channel,
+        continuation_token: &str,
+        offset_token: u64,
+        rows: Bytes,
+    ) -> Result<AppendRowsResponse, SnowflakeError> {
+        let path = format!(
+            "{}?continuationToken={}&offsetToken={}",
+            channel.rows_path(),
+            encode(continuation_token),
+            offset_token,
+        );
+        let body = self
+            .send_ingest(Method::POST, path, "application/x-ndjson", rows)
+            .await?;
+        serde_json::from_slice(&body).context(ParseSnafu)
+    }
+
+    async fn send_ingest(
+        &self,
+        method: Method,
+        path: String,
+        content_type: &str,
+        body: Bytes,
+    ) -> Result<Bytes, SnowflakeError> {
+        let (ingest_url, token) = {
+            let mut session = self.session.lock().await;
+            match session.as_ref() {
+                Some(session) if session.expires_at > Instant::now() => {
+                    (session.ingest_url.clone(), session.token.clone())
+                }
+                _ => {
+                    let new_session = self.start_session().await?;
+                    let credentials = (new_session.ingest_url.clone(), new_session.token.clone());
+                    *session = Some(new_session);
+                    credentials
+                }
+            }
+        };
+
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("{}{}", ingest_url, path))
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .context(UrlSnafu)?;
+
+        let result = self.send(request).await;
+        if let Err(SnowflakeError::Server {
+            status: StatusCode::UNAUTHORIZED,
+            ..
+        }) = result
+        {
+            // Start over with a new token on the next request.
+            self.session.lock().await.take();
+        }
+        result
+    }
+
+    async fn start_session(&self) -> Result<Session, SnowflakeError> {
+        let jwt = self.auth.token().context(SignSnafu)?;
+        let expires_at = Instant::now() + TOKEN_LIFETIME / 2;
+
+        let request = Request::get(format!("{}v2/streaming/hostname", self.base_url()))
+            .header(AUTHORIZATION, format!("Bearer {}", jwt))
+            .header(TOKEN_TYPE_HEADER, "KEYPAIR_JWT")
+            .body(Body::empty())
+            .context(UrlSnafu)?;
+        let body = self.send(request).await?;
+        let host = String::from_utf8_lossy(&body).trim().to_owned();
+
+        let form = format!(
+            "grant_type={}&scope={}",
+            encode(JWT_BEARER_GRANT),
+            encode(&host)
+        );
+        let request = Request::post(format!("{}oauth/token", self.base_url()))
+            .header(AUTHORIZATION, format!("Bearer {}", jwt))
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(Body::from(form))
+            .context(UrlSnafu)?;
+        let token = self.send(request).await?;
+
+        Ok(Session {
+            ingest_url: format!("{}://{}", self.protocol(), host),
+            token: String::from_utf8_lossy(&token).trim().to_owned(),
+            expires_at,
+        })
+    }
+
+    fn base_url(&self) -> String {
+        let base_url = self.endpoint.to_string();
+        if base_url.ends_with('/') {
+            base_url
+        } else {
+            format!("{}/", base_url)
+        }
+    }
+
+    async fn send(&self, request: Request<Body>) -> Result<Bytes, SnowflakeError> {
+        let response = self.client.send(request).await.context(HttpSnafu)?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body())
+            .await
+            .context(BodySnafu)?;
+
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(SnowflakeError::Server {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            })
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD},
+    Engine as _,
+};
+use openssl::{
+    error::ErrorStack,
+    hash::MessageDigest,
+    pkey::{PKey, Private},
+    sha::sha256,
+    sign::Signer,
+};
+use serde::Serialize;
+use snafu::{ResultExt, Snafu};
+
+/// Snowflake rejects key pair tokens that are valid for more than an hour.
+pub(super) const TOKEN_LIFETIME: Duration = Duration::from_secs(59 * 60);
+
+#[derive(Debug, Snafu)]
+pub enum PrivateKeyError {
+    #[snafu(display("Could not read private key {:?}: {}", path, source))]
+    ReadKey {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[snafu(display("Could not parse private key {:?}: {}", path, source))]
+    ParseKey { path: PathBuf, source: ErrorStack },
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+/// Authenticates as a Snowflake user with [key pair authentication][key_pair_auth].
+///
+/// [key_pair_auth]: https://docs.snowflake.com/en/user-guide/key-pair-auth
+pub(super) struct KeyPairAuth {
+    key: PKey<Private>,
+    issuer: String,
+    subject: String,
+}
+
+impl KeyPairAuth {
+    /// Loads the PEM encoded private key of the user, which may be encrypted with a passphrase.
+    pub(super) fn load(
+        account: &str,
+        user: &str,
+        path: &Path,
+        passphrase: Option<&str>,
+    ) -> crate::Result<Self> {
+        let pem = std::fs::read(path).context(ReadKeySnafu { path })?;
+        let key = match passphrase {
+            Some(passphrase) => PKey::private_key_from_pem_passphrase(&pem, passphrase.as_bytes()),
+            None => PKey::private_key_from_pem(&pem),
+        }
+        .context(ParseKeySnafu { path })?;
+
+        Ok(Self::new(account, user, key)?)
+    }
+
+    pub(super) fn new(account: &str, user: &str, key: PKey<Private>) -> Result<Self, ErrorStack> {
+        let fingerprint = STANDARD.encode(sha256(&key.public_key_to_der()?));
+        let subject = format!("{}.{}", account_name(account), user.to_uppercase());
+
+        Ok(Self {
+            key,
+            issuer: format!("{}.SHA256:{}", subject, fingerprint),
+            subject,
+        })
+    }
+
+    /// Creates a signed JWT, valid for [`TOKEN_LIFETIME`].
+    pub(super) fn token(&self) -> Result<String, ErrorStack> {
+        let issued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time is after the epoch")
+            .as_secs();
+        let claims = Claims {
+            iss: &self.issuer,
+            sub: &self.subject,
+            iat: issued_at,
+            exp: issued_at + TOKEN_LIFETIME.as_secs(),
+        };
+
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"RS256","typ":"JWT"}"#);
+        let claims =
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims).expect("claims serialize to JSON"));
+        let message = format!("{}.{}", header, claims);
+
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.key)?;
+        signer.update(message.as_bytes())?;
+        let signature = URL_SAFE_NO_PAD.encode(signer.sign_to_vec()?);
+
+        Ok(format!("{}.{}", message, signature))
+    }
+}
+
+/// The account name used in the claims of the token.
+///
+/// Account locators may include the region and cloud, as in `xy12345.us-east-2.aws`, of which only
+/// the locator itself is used.
+fn account_name(account: &str) -> String {
+    account.split('.').next().unwrap_or(account).to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use openssl::{rsa::Rsa, sign::Verifier};
+
+    use super::*;
+
+    #[test]
+    fn signs_token_with_key_fingerprint() {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let auth = KeyPairAuth::new("xy12345.us-east-2.aws", "vector", key.clone()).unwrap();
+
+        let token = auth.token().unwrap();
+        let parts = token.split('.').collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3);
+
+        let claims: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).unwrap()).unwrap();
+        let fingerprint = STANDARD.encode(sha256(&key.public_key_to_der().unwrap()));
+        assert_eq!(claims["sub"], "XY12345.VECTOR");
+        assert_eq!(
+            claims["iss"],
+            format!("XY12345.VECTOR.SHA256:{}", fingerprint)
+        );
+        assert_eq!(
+            claims["exp"].as_u64().unwrap() - claims["iat"].as_u64().unwrap(),
+            TOKEN_LIFETIME.as_secs()
+        );
+
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &key).unwrap();
+        verifier
+            .update(format!("{}.{}", parts[0], parts[1]).as_bytes())
+            .unwrap();
+        assert!(verifier
+            .verify(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap())
+            .unwrap());
+    }
+}
@@ -0,0 +1,204 @@
+use std::{path::PathBuf, sync::Arc};
+
+use codecs::{
+    encoding::{Framer, FramingConfig},
+    JsonSerializerConfig,
+};
+use futures::FutureExt;
+use http::Uri;
+use tower::ServiceBuilder;
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::tls::TlsSettings;
+
+use super::{
+    api::{Channel, SnowflakeClient},
+    auth::KeyPairAuth,
+    request_builder::SnowflakeRequestBuilder,
+    service::{SnowflakeRetryLogic, SnowflakeService},
+    sink::SnowflakeSink,
+};
+use crate::{
+    codecs::{Encoder, Transformer},
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    http::HttpClient,
+    sinks::{
+        util::{BatchConfig, ServiceBuilderExt, SinkBatchSettings, TowerRequestConfig, UriSerde},
+        Healthcheck, VectorSink,
+    },
+    tls::TlsConfig,
+};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SnowflakeDefaultBatchSettings;
+
+// Snowpipe Streaming accepts up to 4 MB of rows per append request.
+impl SinkBatchSettings for SnowflakeDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = None;
+    const MAX_BYTES: Option<usize> = Some(4_000_000);
+    const TIMEOUT_SECS: f64 = 1.0;
+}
+
+/// Configuration for the `snowflake` sink.
+#[configurable_component(sink("snowflake"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SnowflakeConfig {
+    /// The identifier of the Snowflake account.
+    ///
+    /// This is the account identifier as it appears in the account URL, such as `myorg-myaccount`
+    /// or `xy12345.us-east-2.aws`.
+    #[configurable(metadata(docs::examples = "myorg-myaccount"))]
+    pub account: String,
+
+    /// The URL of the Snowflake account.
+    ///
+    /// Defaults to `https://<account>.snowflakecomputing.com`.
+    #[configurable(metadata(docs::examples = "https://myorg-myaccount.snowflakecomputing.com"))]
+    #[configurable(metadata(docs::advanced))]
+    pub endpoint: Option<UriSerde>,
+
+    /// The user to authenticate as.
+    ///
+    /// The user must have a public key registered, matching the configured private key, and a
+    /// default role that is allowed to insert into the pipe.
+    #[configurable(metadata(docs::examples = "vector"))]
+    pub user: String,
+
+    /// The path to the PEM encoded private key of the user.
+    #[configurable(metadata(docs::examples = "/etc/vector/snowflake_key.p8"))]
+    pub private_key_path: PathBuf,
+
+    /// The passphrase of the private key, if it is encrypted.
+    #[configurable(metadata(docs::examples = "${SNOWFLAKE_KEY_PASSPHRASE}"))]
+    pub private_key_passphrase: Option<SensitiveString>,
+
+    /// The database that contains the pipe.
+    #[configurable(metadata(docs::examples = "LOGS"))]
+    pub database: String,
+
+    /// The schema that contains the pipe.
+    #[configurable(metadata(docs::examples = "PUBLIC"))]
+    pub schema: String,
+
+    /// The pipe that rows are streamed into.
+    ///
+    /// Every event is sent as a JSON object, which the pipe maps to the columns of its table.
+    #[configurable(metadata(docs::examples = "VECTOR_PIPE"))]
+    pub pipe: String,
+
+    /// The name of the streaming channel.
+    ///
+    /// Channels can't be shared between writers, so each Vector instance writing to the same pipe
+    /// must use a different channel. Defaults to `vector_<hostname>`.
+    #[configurable(metadata(docs::examples = "vector_{{ host }}"))]
+    #[configurable(metadata(docs::advanced))]
+    pub channel: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub encoding: Transformer,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<SnowflakeDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for SnowflakeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"account = "myorg-myaccount"
+            user = "vector"
+            private_key_path = "/etc/vector/snowflake_key.p8"
+            database = "LOGS"
+            schema = "PUBLIC"
+            pipe = "VECTOR_PIPE""#,
+        )
+        .unwrap()
+    }
+}
+
+impl SnowflakeConfig {
+    pub(super) fn endpoint(&self) -> crate::Result<Uri> {
+        match &self.endpoint {
+            Some(endpoint) => Ok(endpoint.uri.clone()),
+            None => Ok(format!("https://{}.snowflakecomputing.com", self.account).parse()?),
+        }
+    }
+
+    pub(super) fn channel(&self) -> Channel {
+        Channel {
+            database: self.database.clone(),
+            schema: self.schema.clone(),
+            pipe: self.pipe.clone(),
+            name: self
+                .channel
+                .clone()
+                .unwrap_or_else(|| format!("vector_{}", crate::get_hostname().unwrap_or_default())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for SnowflakeConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let auth = KeyPairAuth::load(
+            &self.account,
+            &self.user,
+            &self.private_key_path,
+            self.private_key_passphrase.as_ref().map(|p| p.inner()),
+        )?;
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new_with_options(tls, cx.proxy(), cx.http_client())?;
+        let client = Arc::new(SnowflakeClient::new(client, self.endpoint()?, auth));
+
+        let healthcheck = {
+            let client = Arc::clone(&client);
+            async move { client.healthcheck().await.map_err(Into::into) }.boxed()
+        };
+
+        let protocol = client.protocol().to_owned();
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+        let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
+            .settings(request_settings, SnowflakeRetryLogic)
+            .service(SnowflakeService::new(client, self.channel()));
+
+        let encoder = Encoder::<Framer>::new(
+            FramingConfig::NewlineDelimited.build(),
+            JsonSerializerConfig::default().build().into(),
+        );
+        let request_builder = SnowflakeRequestBuilder::new((self.encoding.clone(), encoder));
+
+        let sink = SnowflakeSink::new(service, batch_settings, request_builder, protocol);
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
@@ -0,0 +1,46 @@
+//! The Snowflake [`vector_core::sink::VectorSink`].
+//!
+//! This module contains the [`vector_core::sink::VectorSink`] instance that is responsible for
+//! ingesting logs into a Snowflake table through the [Snowpipe Streaming][snowpipe_streaming]
+//! REST API, authenticating with a key pair.
+//!
+//! Each sink appends rows to a single streaming channel of a pipe, which it opens on the first
+//! request and reopens whenever Snowflake invalidates it.
+//!
+//! [snowpipe_streaming]: https://docs.snowflake.com/en/user-guide/data-load-snowpipe-streaming-overview
+use http::StatusCode;
+use snafu::Snafu;
+
+mod api;
+mod auth;
+mod config;
+mod request_builder;
+mod service;
+mod sink;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::SnowflakeConfig;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(super)))]
+pub enum SnowflakeError {
+    #[snafu(display("HTTP request failed: {}", source))]
+    Http { source: crate::http::HttpError },
+
+    #[snafu(display("Failed to read response: {}", source))]
+    Body { source: hyper::Error },
+
+    #[snafu(display("Failed to parse response: {}", source))]
+    Parse { source: serde_json::Error },
+
+    #[snafu(display("Snowflake responded with {}: {}", status, body))]
+    Server { status: StatusCode, body: String },
+
+    #[snafu(display("Failed to sign the authentication token: {}", source))]
+    Sign { source: openssl::error::ErrorStack },
+
+    #[snafu(display("Invalid URL: {}", source))]
+    Url { source: http::Error },
+}
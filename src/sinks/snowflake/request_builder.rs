@@ -0,0 +1,70 @@
+use std::io;
+
+use bytes::Bytes;
+use codecs::encoding::Framer;
+use vector_common::{
+    finalization::{EventFinalizers, Finalizable},
+    request_metadata::RequestMetadata,
+};
+use vector_core::event::Event;
+
+use super::service::SnowflakeRequest;
+use crate::{
+    codecs::{Encoder, Transformer},
+    sinks::util::{
+        metadata::RequestMetadataBuilder, request_builder::EncodeResult, Compression,
+        RequestBuilder,
+    },
+};
+
+/// Encodes batches of events as newline delimited JSON rows.
+#[derive(Clone)]
+pub(super) struct SnowflakeRequestBuilder {
+    encoder: (Transformer, Encoder<Framer>),
+}
+
+impl SnowflakeRequestBuilder {
+    pub(super) const fn new(encoder: (Transformer, Encoder<Framer>)) -> Self {
+        Self { encoder }
+    }
+}
+
+impl RequestBuilder<Vec<Event>> for SnowflakeRequestBuilder {
+    type Metadata = EventFinalizers;
+    type Events = Vec<Event>;
+    type Encoder = (Transformer, Encoder<Framer>);
+    type Payload = Bytes;
+    type Request = SnowflakeRequest;
+    type Error = io::Error;
+
+    fn compression(&self) -> Compression {
+        Compression::None
+    }
+
+    fn encoder(&self) -> &Self::Encoder {
+        &self.encoder
+    }
+
+    fn split_input(
+        &self,
+        input: Vec<Event>,
+    ) -> (Self::Metadata, RequestMetadataBuilder, Self::Events) {
+        let mut events = input;
+        let finalizers = events.take_finalizers();
+        let builder = RequestMetadataBuilder::from_events(&events);
+        (finalizers, builder, events)
+    }
+
+    fn build_request(
+        &self,
+        finalizers: Self::Metadata,
+        metadata: RequestMetadata,
+        payload: EncodeResult<Self::Payload>,
+    ) -> Self::Request {
+        SnowflakeRequest {
+            rows: payload.into_payload(),
+            finalizers,
+            metadata,
+        }
+    }
+}
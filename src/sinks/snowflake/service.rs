@@ -0,0 +1,182 @@
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::StatusCode;
+use tokio::sync::Mutex;
+use tower::Service;
+use vector_common::{
+    finalization::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::CountByteSize,
+    request_metadata::{MetaDescriptive, RequestMetadata},
+};
+use vector_core::stream::DriverResponse;
+
+use super::{
+    api::{Channel, SnowflakeClient},
+    SnowflakeError,
+};
+use crate::sinks::util::retries::RetryLogic;
+
+#[derive(Clone)]
+pub(super) struct SnowflakeRequest {
+    pub rows: Bytes,
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+}
+
+impl Finalizable for SnowflakeRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for SnowflakeRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub(super) struct SnowflakeResponse {
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for SnowflakeResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(
+            self.metadata.event_count(),
+            self.metadata.events_byte_size(),
+        )
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.metadata.request_encoded_size())
+    }
+}
+
+/// The position of the sink in its open channel.
+struct ChannelState {
+    continuation_token: String,
+    offset_token: u64,
+}
+
+/// Appends rows to a single channel, one request at a time.
+///
+/// Each append must carry the continuation token returned by the previous one, so requests are
+/// serialized on the channel state. The channel is reopened after any failed append, as Snowflake
+/// may have invalidated it.
+#[derive(Clone)]
+pub(super) struct SnowflakeService {
+    client: Arc<SnowflakeClient>,
+    channel: Arc<Channel>,
+    state: Arc<Mutex<Option<ChannelState>>>,
+}
+
+impl SnowflakeService {
+    pub(super) fn new(client: Arc<SnowflakeClient>, channel: Channel) -> Self {
+        Self {
+            client,
+            channel: Arc::new(channel),
+            state: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn append(&self, rows: Bytes) -> Result<(), SnowflakeError> {
+        let mut state = self.state.lock().await;
+
+        let channel = match state.as_mut() {
+            Some(channel) => channel,
+            None => {
+                let response = self.client.open_channel(&self.channel).await?;
+                debug!(
+                    message = "Opened Snowflake channel.",
+                    channel = %self.channel.name,
+                    last_committed_offset_token = ?response.channel_status.last_committed_offset_token,
+                );
+                state.insert(ChannelState {
+                    continuation_token: response.next_continuation_token,
+                    offset_token: response
+                        .channel_status
+                        .last_committed_offset_token
+                        .and_then(|offset_token| offset_token.parse().ok())
+                        .unwrap_or(0),
+                })
+            }
+        };
+
+        let offset_token = channel.offset_token + 1;
+        match self
+            .client
+            .append_rows(
+                &self.channel,
+                &channel.continuation_token,
+                offset_token,
+                rows,
+            )
+            .await
+        {
+            Ok(response) => {
+                channel.continuation_token = response.next_continuation_token;
+                channel.offset_token = offset_token;
+                Ok(())
+            }
+            Err(error) => {
+                *state = None;
+                Err(error)
+            }
+        }
+    }
+}
+
+impl Service<SnowflakeRequest> for SnowflakeService {
+    type Response = SnowflakeResponse;
+    type Error = SnowflakeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Emission of an internal event in case of errors is handled upstream by the caller.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, request: SnowflakeRequest) -> Self::Future {
+        let service = self.clone();
+        let metadata = request.get_metadata();
+
+        Box::pin(async move {
+            service.append(request.rows).await?;
+            Ok(SnowflakeResponse { metadata })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct SnowflakeRetryLogic;
+
+impl RetryLogic for SnowflakeRetryLogic {
+    type Error = SnowflakeError;
+    type Response = SnowflakeResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        match error {
+            SnowflakeError::Http { .. } | SnowflakeError::Body { .. } => true,
+            SnowflakeError::Server { status, .. } => {
+                // A conflict means the channel was invalidated, which is recovered from by
+                // reopening it.
+                *status == StatusCode::CONFLICT
+                    || *status == StatusCode::TOO_MANY_REQUESTS
+                    || status.is_server_error()
+            }
+            SnowflakeError::Parse { .. }
+            | SnowflakeError::Sign { .. }
+            | SnowflakeError::Url { .. } => false,
+        }
+    }
+}
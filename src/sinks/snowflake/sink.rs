@@ -0,0 +1,75 @@
+use std::{fmt, num::NonZeroUsize};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tower::Service;
+use vector_core::{
+    event::Event,
+    sink::StreamSink,
+    stream::{BatcherSettings, DriverResponse},
+};
+
+use super::{request_builder::SnowflakeRequestBuilder, service::SnowflakeRequest};
+use crate::{internal_events::SinkRequestBuildError, sinks::util::SinkBuilderExt};
+
+pub(super) struct SnowflakeSink<S> {
+    service: S,
+    batch_settings: BatcherSettings,
+    request_builder: SnowflakeRequestBuilder,
+    protocol: String,
+}
+
+impl<S> SnowflakeSink<S>
+where
+    S: Service<SnowflakeRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    pub(super) const fn new(
+        service: S,
+        batch_settings: BatcherSettings,
+        request_builder: SnowflakeRequestBuilder,
+        protocol: String,
+    ) -> Self {
+        Self {
+            service,
+            batch_settings,
+            request_builder,
+            protocol,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let builder_limit = NonZeroUsize::new(64);
+        input
+            .batched(self.batch_settings.into_byte_size_config())
+            .request_builder(builder_limit, self.request_builder)
+            .filter_map(|request| async move {
+                match request {
+                    Err(error) => {
+                        emit!(SinkRequestBuildError { error });
+                        None
+                    }
+                    Ok(request) => Some(request),
+                }
+            })
+            .into_driver(self.service)
+            .protocol(self.protocol)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for SnowflakeSink<S>
+where
+    S: Service<SnowflakeRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
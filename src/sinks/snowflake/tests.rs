@@ -0,0 +1,205 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use http::{Method, StatusCode};
+use openssl::{pkey::PKey, rsa::Rsa};
+use vector_core::event::{BatchNotifier, BatchStatus};
+use warp::{path::FullPath, Filter};
+
+use super::{config::SnowflakeConfig, service::SnowflakeRetryLogic, SnowflakeError};
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::util::retries::RetryLogic,
+    test_util::{
+        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        next_addr, random_lines_with_stream, temp_file,
+    },
+};
+
+const CHANNEL_PATH: &str =
+    "/v2/streaming/databases/LOGS/schemas/PUBLIC/pipes/VECTOR_PIPE/channels/vector_test";
+const ROWS_PATH: &str =
+    "/v2/streaming/data/databases/LOGS/schemas/PUBLIC/pipes/VECTOR_PIPE/channels/vector_test/rows";
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<SnowflakeConfig>();
+}
+
+/// The rows appended to the mock channel, along with their offset tokens.
+type Appended = Arc<Mutex<Vec<(String, Bytes)>>>;
+
+/// Serves the Snowpipe Streaming API, acting as both the account and the ingest host.
+fn mock_snowflake(addr: SocketAddr) -> Appended {
+    let appended = Appended::default();
+
+    let routes = warp::method()
+        .and(warp::path::full())
+        .and(warp::query::<HashMap<String, String>>())
+        .and(warp::body::bytes())
+        .map({
+            let appended = Arc::clone(&appended);
+            move |method: Method, path: FullPath, query: HashMap<String, String>, body: Bytes| {
+                let (status, response) = match (method, path.as_str()) {
+                    (Method::GET, "/v2/streaming/hostname") => (StatusCode::OK, addr.to_string()),
+                    (Method::POST, "/oauth/token") => (StatusCode::OK, "scoped-token".to_owned()),
+                    (Method::PUT, CHANNEL_PATH) => (
+                        StatusCode::OK,
+                        r#"{"next_continuation_token":"0","channel_status":{"last_committed_offset_token":"41"}}"#
+                            .to_owned(),
+                    ),
+                    (Method::POST, ROWS_PATH) => {
+                        let mut appended = appended.lock().unwrap();
+                        assert_eq!(
+                            query.get("continuationToken").unwrap(),
+                            &appended.len().to_string()
+                        );
+                        appended.push((query["offsetToken"].clone(), body));
+                        (
+                            StatusCode::OK,
+                            format!(r#"{{"next_continuation_token":"{}"}}"#, appended.len()),
+                        )
+                    }
+                    _ => (StatusCode::NOT_FOUND, String::new()),
+                };
+                warp::reply::with_status(response, status)
+            }
+        });
+
+    tokio::spawn(warp::serve(routes).bind(addr));
+    appended
+}
+
+#[tokio::test]
+async fn appends_rows_to_channel() {
+    let addr = next_addr();
+    let appended = mock_snowflake(addr);
+    let key_path = temp_file();
+    let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+    std::fs::write(&key_path, key.private_key_to_pem_pkcs8().unwrap()).unwrap();
+    let config = toml::from_str::<SnowflakeConfig>(&format!(
+        r#"
+            account = "myorg-myaccount"
+            endpoint = "http://{}"
+            user = "vector"
+            private_key_path = {:?}
+            database = "LOGS"
+            schema = "PUBLIC"
+            pipe = "VECTOR_PIPE"
+            channel = "vector_test"
+            batch.max_events = 5
+        "#,
+        addr, key_path
+    ))
+    .unwrap();
+
+    let (sink, healthcheck) = config.build(SinkContext::new_test()).await.unwrap();
+    healthcheck.await.expect("healthcheck failed");
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (input_lines, events) = random_lines_with_stream(8, 10, Some(batch));
+    run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let appended = appended.lock().unwrap();
+    // Offsets continue from the last one committed to the channel.
+    let offsets = appended
+        .iter()
+        .map(|(offset, _)| offset.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(offsets, vec!["42", "43"]);
+
+    let output_lines = appended
+        .iter()
+        .flat_map(|(_, rows)| rows.split(|b| *b == b'\n').filter(|row| !row.is_empty()))
+        .map(|row| {
+            let row: serde_json::Value = serde_json::from_slice(row).unwrap();
+            row["message"].as_str().unwrap().to_owned()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(input_lines, output_lines);
+}
+
+#[tokio::test]
+async fn rejects_rows_refused_by_snowflake() {
+    let addr = next_addr();
+    mock_snowflake(addr);
+    let key_path = temp_file();
+    let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+    std::fs::write(&key_path, key.private_key_to_pem_pkcs8().unwrap()).unwrap();
+    let config = toml::from_str::<SnowflakeConfig>(&format!(
+        r#"
+            account = "myorg-myaccount"
+            endpoint = "http://{}"
+            user = "vector"
+            private_key_path = {:?}
+            database = "LOGS"
+            schema = "PUBLIC"
+            pipe = "MISSING_PIPE"
+            channel = "vector_test"
+            batch.max_events = 5
+        "#,
+        addr, key_path
+    ))
+    .unwrap();
+
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (_, events) = random_lines_with_stream(8, 3, Some(batch));
+    sink.run(events).await.expect("Running sink failed");
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
+
+#[test]
+fn retries_transient_failures() {
+    let logic = SnowflakeRetryLogic;
+
+    for (status, retriable) in [
+        (StatusCode::CONFLICT, true),
+        (StatusCode::TOO_MANY_REQUESTS, true),
+        (StatusCode::SERVICE_UNAVAILABLE, true),
+        (StatusCode::BAD_REQUEST, false),
+        (StatusCode::NOT_FOUND, false),
+    ] {
+        assert_eq!(
+            logic.is_retriable_error(&SnowflakeError::Server {
+                status,
+                body: String::new()
+            }),
+            retriable,
+            "{}",
+            status
+        );
+    }
+}
+
+#[test]
+fn defaults_to_account_url_and_hostname_channel() {
+    let config: SnowflakeConfig = toml::from_str(
+        r#"
+            account = "myorg-myaccount"
+            user = "vector"
+            private_key_path = "/etc/vector/snowflake_key.p8"
+            database = "LOGS"
+            schema = "PUBLIC"
+            pipe = "VECTOR_PIPE"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.endpoint().unwrap().host(),
+        Some("myorg-myaccount.snowflakecomputing.com")
+    );
+    assert_eq!(
+        config.channel().name,
+        format!("vector_{}", crate::get_hostname().unwrap())
+    );
+}
@@ -4,6 +4,8 @@ use codecs::{
 };
 use vector_config::configurable_component;
 
+#[cfg(windows)]
+use crate::sinks::util::named_pipe::NamedPipeSinkConfig;
 #[cfg(unix)]
 use crate::sinks::util::unix::UnixSinkConfig;
 use crate::{
@@ -43,6 +45,16 @@ pub enum Mode {
     /// Send over a Unix domain socket (UDS).
     #[cfg(unix)]
     Unix(UnixMode),
+
+    /// Send over a Unix domain socket (UDS), in datagram mode.
+    ///
+    /// Each event is sent as a single datagram.
+    #[cfg(unix)]
+    UnixDatagram(UnixDatagramMode),
+
+    /// Send over a Windows named pipe.
+    #[cfg(windows)]
+    NamedPipe(NamedPipeMode),
 }
 
 /// TCP configuration.
@@ -79,6 +91,30 @@ pub struct UnixMode {
     encoding: EncodingConfigWithFraming,
 }
 
+/// Unix Domain Socket datagram configuration.
+#[cfg(unix)]
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct UnixDatagramMode {
+    #[serde(flatten)]
+    config: UnixSinkConfig,
+
+    #[configurable(derived)]
+    encoding: EncodingConfig,
+}
+
+/// Windows named pipe configuration.
+#[cfg(windows)]
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct NamedPipeMode {
+    #[serde(flatten)]
+    config: NamedPipeSinkConfig,
+
+    #[serde(flatten)]
+    encoding: EncodingConfigWithFraming,
+}
+
 impl GenerateConfig for SocketSinkConfig {
     fn generate_config() -> toml::Value {
         toml::from_str(
@@ -138,6 +174,20 @@ impl SinkConfig for SocketSinkConfig {
                 let encoder = Encoder::<Framer>::new(framer, serializer);
                 config.build(transformer, encoder)
             }
+            #[cfg(unix)]
+            Mode::UnixDatagram(UnixDatagramMode { config, encoding }) => {
+                let transformer = encoding.transformer();
+                let serializer = encoding.build()?;
+                let encoder = Encoder::<()>::new(serializer);
+                config.build_datagram(transformer, encoder)
+            }
+            #[cfg(windows)]
+            Mode::NamedPipe(NamedPipeMode { config, encoding }) => {
+                let transformer = encoding.transformer();
+                let (framer, serializer) = encoding.build(SinkType::StreamBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                config.build(transformer, encoder)
+            }
         }
     }
 
@@ -147,6 +197,10 @@ impl SinkConfig for SocketSinkConfig {
             Mode::Udp(UdpMode { encoding, .. }) => encoding.config().input_type(),
             #[cfg(unix)]
             Mode::Unix(UnixMode { encoding, .. }) => encoding.config().1.input_type(),
+            #[cfg(unix)]
+            Mode::UnixDatagram(UnixDatagramMode { encoding, .. }) => encoding.config().input_type(),
+            #[cfg(windows)]
+            Mode::NamedPipe(NamedPipeMode { encoding, .. }) => encoding.config().1.input_type(),
         };
         Input::new(encoder_input_type & DataType::Log)
     }
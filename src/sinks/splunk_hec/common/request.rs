@@ -7,7 +7,7 @@ use vector_core::{
     ByteSizeOf,
 };
 
-use crate::sinks::util::ElementCount;
+use crate::sinks::util::{ElementCount, Partition};
 
 #[derive(Clone, Debug)]
 pub struct HecRequest {
@@ -44,3 +44,11 @@ impl MetaDescriptive for HecRequest {
         self.metadata
     }
 }
+
+/// Requests are partitioned by the token and index they are sent with, so that the adaptive
+/// concurrency limit can be managed separately for each of them.
+impl Partition<(Option<Arc<str>>, Option<String>)> for HecRequest {
+    fn partition(&self) -> (Option<Arc<str>>, Option<String>) {
+        (self.passthrough_token.clone(), self.index.clone())
+    }
+}
@@ -14,7 +14,7 @@ use super::{
     EndpointTarget,
 };
 use crate::{
-    http::HttpClient,
+    http::{HttpClient, HttpClientOptions},
     internal_events::TemplateRenderingError,
     sinks::{
         self,
@@ -45,9 +45,14 @@ pub enum HealthcheckError {
 pub fn create_client(
     tls: &Option<TlsConfig>,
     proxy_config: &ProxyConfig,
+    options: &HttpClientOptions,
 ) -> crate::Result<HttpClient> {
     let tls_settings = TlsSettings::from_options(tls)?;
-    Ok(HttpClient::new(tls_settings, proxy_config)?)
+    Ok(HttpClient::new_with_options(
+        tls_settings,
+        proxy_config,
+        options,
+    )?)
 }
 
 pub fn build_http_batch_service(
@@ -189,7 +194,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(mock_server.uri(), "token".to_string(), client);
 
         assert!(healthcheck.await.is_ok())
@@ -206,7 +211,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(mock_server.uri(), "token".to_string(), client);
 
         assert_eq!(
@@ -226,7 +231,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(mock_server.uri(), "token".to_string(), client);
 
         assert_eq!(
@@ -246,7 +251,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(mock_server.uri(), "token".to_string(), client);
 
         assert_eq!(
@@ -406,7 +411,7 @@ mod integration_tests {
 
     #[tokio::test]
     async fn splunk_healthcheck_ok() {
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let address = splunk_hec_address();
         let token = get_token().await;
 
@@ -420,7 +425,7 @@ mod integration_tests {
 
     #[tokio::test]
     async fn splunk_healthcheck_server_not_listening() {
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(
             "http://localhost:1111/".to_string(),
             get_token().await,
@@ -432,7 +437,7 @@ mod integration_tests {
 
     #[tokio::test]
     async fn splunk_healthcheck_server_unavailable() {
-        let client = create_client(&None, &ProxyConfig::default()).unwrap();
+        let client = create_client(&None, &ProxyConfig::default(), &Default::default()).unwrap();
         let healthcheck = build_healthcheck(
             "http://localhost:5503/".to_string(),
             get_token().await,
@@ -189,7 +189,7 @@ impl SinkConfig for HecLogsSinkConfig {
             return Err("`auto_extract_timestamp` cannot be set for the `raw` endpoint.".into());
         }
 
-        let client = create_client(&self.tls, cx.proxy())?;
+        let client = create_client(&self.tls, cx.proxy(), cx.http_client())?;
         let healthcheck = build_healthcheck(
             self.endpoint.clone(),
             self.default_token.inner().to_owned(),
@@ -243,7 +243,8 @@ impl HecLogsSinkConfig {
             self.compression,
         ));
         let http_service = ServiceBuilder::new()
-            .settings(request_settings, HttpRetryLogic)
+            .bandwidth_limit(&request_settings)
+            .partitioned_settings(request_settings, HttpRetryLogic)
             .service(build_http_batch_service(
                 client,
                 Arc::clone(&http_request_builder),
@@ -6,7 +6,9 @@ use vector_common::sensitive_string::SensitiveString;
 use vector_config::configurable_component;
 use vector_core::sink::VectorSink;
 
-use super::{request_builder::HecMetricsRequestBuilder, sink::HecMetricsSink};
+use super::{
+    encoder::HecMetricsEncoder, request_builder::HecMetricsRequestBuilder, sink::HecMetricsSink,
+};
 use crate::{
     config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
     http::HttpClient,
@@ -97,6 +99,16 @@ pub struct HecMetricsSinkConfig {
     ))]
     pub source: Option<Template>,
 
+    /// Whether to combine the metrics of a batch into [multiple-metric events][multi_metric].
+    ///
+    /// Metrics with the same timestamp, host, index, source, sourcetype, and tags are sent as a
+    /// single event holding all of their values, which reduces the size of the payloads sent to and
+    /// indexed by Splunk. Requires Splunk 8.0 or later.
+    ///
+    /// [multi_metric]: https://docs.splunk.com/Documentation/Splunk/8.0.0/Metrics/GetMetricsInOther#The_multiple-metric_JSON_format
+    #[serde(default)]
+    pub multi_metric: bool,
+
     #[configurable(derived)]
     #[serde(default)]
     pub compression: Compression,
@@ -127,6 +139,7 @@ impl GenerateConfig for HecMetricsSinkConfig {
             index: None,
             sourcetype: None,
             source: None,
+            multi_metric: false,
             compression: Compression::default(),
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
@@ -140,7 +153,7 @@ impl GenerateConfig for HecMetricsSinkConfig {
 #[async_trait::async_trait]
 impl SinkConfig for HecMetricsSinkConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
-        let client = create_client(&self.tls, cx.proxy())?;
+        let client = create_client(&self.tls, cx.proxy(), cx.http_client())?;
         let healthcheck = build_healthcheck(
             self.endpoint.clone(),
             self.default_token.inner().to_owned(),
@@ -174,6 +187,9 @@ impl HecMetricsSinkConfig {
 
         let request_builder = HecMetricsRequestBuilder {
             compression: self.compression,
+            encoder: HecMetricsEncoder {
+                multi_metric: self.multi_metric,
+            },
         };
 
         let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
@@ -184,7 +200,8 @@ impl HecMetricsSinkConfig {
             self.compression,
         ));
         let http_service = ServiceBuilder::new()
-            .settings(request_settings, HttpRetryLogic)
+            .bandwidth_limit(&request_settings)
+            .partitioned_settings(request_settings, HttpRetryLogic)
             .service(build_http_batch_service(
                 client,
                 Arc::clone(&http_request_builder),
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, iter};
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter,
+};
 
 use serde::Serialize;
 
@@ -43,19 +46,63 @@ impl<'a> HecData<'a> {
     }
 }
 
-pub struct HecMetricsEncoder;
+/// Identifies the multiple-metric event a metric is combined into.
+#[derive(Eq, Hash, PartialEq)]
+struct MultiMetricKey<'a> {
+    time_millis: i64,
+    host: Option<&'a str>,
+    index: Option<&'a str>,
+    source: Option<&'a str>,
+    sourcetype: Option<&'a str>,
+    dimensions: Vec<(&'a str, &'a str)>,
+}
 
-impl HecMetricsEncoder {
-    pub fn encode_event(processed_event: HecProcessedEvent) -> Option<Vec<u8>> {
-        let metadata = processed_event.metadata;
-        let metric = processed_event.event;
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HecMetricsEncoder {
+    /// Whether to combine the metrics of a batch into [multiple-metric events][multi_metric].
+    ///
+    /// [multi_metric]: https://docs.splunk.com/Documentation/Splunk/8.0.0/Metrics/GetMetricsInOther#The_multiple-metric_JSON_format
+    pub multi_metric: bool,
+}
 
-        let fields = metric
+impl HecMetricsEncoder {
+    /// The tags of the metric sent as dimensions, skipping those used for templating.
+    fn dimensions<'a>(
+        processed_event: &'a HecProcessedEvent,
+    ) -> impl Iterator<Item = (&'a str, &'a str)> {
+        let metadata = &processed_event.metadata;
+        processed_event
+            .event
             .tags()
             .into_iter()
             .flat_map(|tags| tags.iter_single())
-            // skip the metric tags used for templating
             .filter(|(k, _)| !metadata.templated_field_keys.iter().any(|f| f == k))
+    }
+
+    fn time_millis(processed_event: &HecProcessedEvent) -> i64 {
+        processed_event
+            .event
+            .timestamp()
+            .unwrap_or_else(chrono::Utc::now)
+            .timestamp_millis()
+    }
+
+    fn to_vec(hec_data: &HecData) -> Option<Vec<u8>> {
+        match serde_json::to_vec(hec_data) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                emit!(SplunkEventEncodeError {
+                    error: error.into()
+                });
+                None
+            }
+        }
+    }
+
+    pub fn encode_event(processed_event: HecProcessedEvent) -> Option<Vec<u8>> {
+        let metadata = &processed_event.metadata;
+
+        let fields = Self::dimensions(&processed_event)
             .map(|(k, v)| (k, HecFieldValue::Str(v)))
             .chain(iter::once((
                 "metric_name",
@@ -66,27 +113,66 @@ impl HecMetricsEncoder {
                 HecFieldValue::Float(metadata.metric_value),
             )))
             .collect::<HecFieldMap>();
-        let time = metric
-            .timestamp()
-            .unwrap_or_else(chrono::Utc::now)
-            .timestamp_millis() as f64
-            / 1000f64;
+        let time = Self::time_millis(&processed_event) as f64 / 1000f64;
         let mut hec_data = HecData::new(fields, time);
 
-        hec_data.host = metadata.host;
-        hec_data.index = metadata.index;
-        hec_data.source = metadata.source;
-        hec_data.sourcetype = metadata.sourcetype;
+        hec_data.host = metadata.host.clone();
+        hec_data.index = metadata.index.clone();
+        hec_data.source = metadata.source.clone();
+        hec_data.sourcetype = metadata.sourcetype.clone();
 
-        match serde_json::to_vec(&hec_data) {
-            Ok(value) => Some(value),
-            Err(error) => {
-                emit!(SplunkEventEncodeError {
-                    error: error.into()
-                });
-                None
+        Self::to_vec(&hec_data)
+    }
+
+    /// Encodes the metrics as multiple-metric events, each holding the values of all the metrics
+    /// with the same time, metadata and dimensions as `metric_name:<name>` fields.
+    ///
+    /// Metrics whose name is already present in the matching event start a new event, so that no
+    /// value is lost.
+    pub fn encode_multi_metric_events(processed_events: &[HecProcessedEvent]) -> Vec<u8> {
+        let names = processed_events
+            .iter()
+            .map(|processed_event| format!("metric_name:{}", processed_event.metadata.metric_name))
+            .collect::<Vec<_>>();
+
+        let mut events: Vec<HecData> = Vec::new();
+        let mut latest_events: HashMap<MultiMetricKey, usize> = HashMap::new();
+        for (processed_event, name) in processed_events.iter().zip(&names) {
+            let metadata = &processed_event.metadata;
+            let key = MultiMetricKey {
+                time_millis: Self::time_millis(processed_event),
+                host: metadata.host.as_deref(),
+                index: metadata.index.as_deref(),
+                source: metadata.source.as_deref(),
+                sourcetype: metadata.sourcetype.as_deref(),
+                dimensions: Self::dimensions(processed_event).collect(),
+            };
+            let value = HecFieldValue::Float(metadata.metric_value);
+
+            if let Some(&i) = latest_events.get(&key) {
+                if !events[i].fields.contains_key(name.as_str()) {
+                    events[i].fields.insert(name.as_str(), value);
+                    continue;
+                }
             }
+
+            let fields = key
+                .dimensions
+                .iter()
+                .map(|&(k, v)| (k, HecFieldValue::Str(v)))
+                .chain(iter::once((name.as_str(), value)))
+                .collect::<HecFieldMap>();
+            let mut hec_data = HecData::new(fields, key.time_millis as f64 / 1000f64);
+            hec_data.host = metadata.host.clone();
+            hec_data.index = metadata.index.clone();
+            hec_data.source = metadata.source.clone();
+            hec_data.sourcetype = metadata.sourcetype.clone();
+
+            latest_events.insert(key, events.len());
+            events.push(hec_data);
         }
+
+        events.iter().filter_map(Self::to_vec).flatten().collect()
     }
 }
 
@@ -96,11 +182,15 @@ impl Encoder<Vec<HecProcessedEvent>> for HecMetricsEncoder {
         input: Vec<HecProcessedEvent>,
         writer: &mut dyn std::io::Write,
     ) -> std::io::Result<usize> {
-        let encoded_input: Vec<u8> = input
-            .into_iter()
-            .filter_map(Self::encode_event)
-            .flatten()
-            .collect();
+        let encoded_input: Vec<u8> = if self.multi_metric {
+            Self::encode_multi_metric_events(&input)
+        } else {
+            input
+                .into_iter()
+                .filter_map(Self::encode_event)
+                .flatten()
+                .collect()
+        };
         let encoded_size = encoded_input.len();
         writer.write_all(encoded_input.as_slice())?;
         Ok(encoded_size)
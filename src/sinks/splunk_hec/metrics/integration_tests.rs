@@ -34,6 +34,7 @@ async fn config() -> HecMetricsSinkConfig {
         index: None,
         sourcetype: None,
         source: None,
+        multi_metric: false,
         compression: Compression::None,
         batch,
         request: TowerRequestConfig::default(),
@@ -168,6 +169,47 @@ async fn splunk_insert_multiple_gauge_metrics() {
     );
 }
 
+#[tokio::test]
+async fn splunk_insert_multi_metric_events() {
+    let cx = SinkContext::new_test();
+
+    let mut config = config().await;
+    config.index = Template::try_from("testmetrics".to_string()).ok();
+    config.multi_metric = true;
+    let (sink, _) = config.build(cx).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let timestamp = chrono::Utc::now();
+    let events = ["example-multi-gauge", "example-multi-counter"]
+        .into_iter()
+        .map(|name| {
+            Event::from(
+                Metric::new(
+                    name,
+                    MetricKind::Absolute,
+                    MetricValue::Gauge { value: 1.5 },
+                )
+                .with_timestamp(Some(timestamp))
+                .with_tags(Some(metric_tags! {
+                    "tag_multi_test".to_string() => "tag_multi_value".to_string()
+                }))
+                .with_batch_notifier(&batch),
+            )
+        })
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &HTTP_SINK_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    for name in ["example-multi-gauge", "example-multi-counter"] {
+        assert!(
+            metric_dimensions_exist(name, &["host", "source", "sourcetype", "tag_multi_test"])
+                .await
+        );
+    }
+}
+
 // It usually takes ~1 second for the metric to show up in search with all dimensions, so poll
 // multiple times.
 // Note, Splunk automatically adds [host, source, sourcetype] as default metric dimensions
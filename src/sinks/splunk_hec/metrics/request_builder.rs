@@ -15,6 +15,7 @@ use crate::sinks::{
 
 pub struct HecMetricsRequestBuilder {
     pub(super) compression: Compression,
+    pub(super) encoder: HecMetricsEncoder,
 }
 
 impl RequestBuilder<(Option<Arc<str>>, Vec<HecProcessedEvent>)> for HecMetricsRequestBuilder {
@@ -30,7 +31,7 @@ impl RequestBuilder<(Option<Arc<str>>, Vec<HecProcessedEvent>)> for HecMetricsRe
     }
 
     fn encoder(&self) -> &Self::Encoder {
-        &HecMetricsEncoder
+        &self.encoder
     }
 
     fn split_input(
@@ -313,6 +313,71 @@ fn test_encode_event_gauge_overridden_namespace_returns_expected_json() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn test_encode_multi_metric_events_combines_metrics() {
+    let timestamp = DateTime::parse_from_rfc3339("2005-12-12T14:12:55.123-00:00")
+        .unwrap()
+        .with_timezone(&Utc);
+    let metric = |name: &str, value: f64, tag: &str| {
+        let metric = Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value })
+            .with_timestamp(Some(timestamp))
+            .with_tags(Some(metric_tags! {
+                "host".to_string() => "host_value".to_string(),
+                "tag".to_string() => tag.to_string(),
+            }));
+        get_processed_event(metric, None, None, None, None)
+    };
+
+    let processed_events = vec![
+        metric("cpu.idle", 90.0, "a"),
+        metric("cpu.user", 8.0, "a"),
+        metric("cpu.idle", 80.0, "b"),
+        // Already present in the event of `a`.
+        metric("cpu.idle", 70.0, "a"),
+    ];
+
+    let encoded = HecMetricsEncoder::encode_multi_metric_events(&processed_events);
+    let actual = serde_json::Deserializer::from_slice(&encoded)
+        .into_iter::<JsonValue>()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let expected = vec![
+        json!({
+            "time": 1134396775.123,
+            "host": "host_value",
+            "fields": {
+                "host": "host_value",
+                "tag": "a",
+                "metric_name:cpu.idle": 90.0,
+                "metric_name:cpu.user": 8.0,
+            },
+            "event": "metric",
+        }),
+        json!({
+            "time": 1134396775.123,
+            "host": "host_value",
+            "fields": {
+                "host": "host_value",
+                "tag": "b",
+                "metric_name:cpu.idle": 80.0,
+            },
+            "event": "metric",
+        }),
+        json!({
+            "time": 1134396775.123,
+            "host": "host_value",
+            "fields": {
+                "host": "host_value",
+                "tag": "a",
+                "metric_name:cpu.idle": 70.0,
+            },
+            "event": "metric",
+        }),
+    ];
+    assert_eq!(expected, actual);
+}
+
 #[tokio::test]
 async fn splunk_passthrough_token() {
     let addr = next_addr();
@@ -323,6 +388,7 @@ async fn splunk_passthrough_token() {
         index: None,
         sourcetype: None,
         source: None,
+        multi_metric: false,
         compression: Compression::None,
         batch: Default::default(),
         request: Default::default(),
@@ -25,6 +25,7 @@ use crate::{
     sinks::util::{
         buffer::metrics::compress_distribution,
         encode_namespace,
+        metric_names::{MetricNameSanitizer, NameSanitizationConfig, NamingRules},
         tcp::TcpSinkConfig,
         udp::{UdpService, UdpSinkConfig},
         BatchConfig, BatchSink, Buffer, Compression, EncodedEvent,
@@ -52,6 +53,13 @@ pub struct StatsdSinkConfig {
     #[serde(flatten)]
     pub mode: Mode,
 
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub name_sanitization: NameSanitizationConfig,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -111,6 +119,7 @@ impl GenerateConfig for StatsdSinkConfig {
                 batch: Default::default(),
                 udp: UdpSinkConfig::from_address(default_address().to_string()),
             }),
+            name_sanitization: Default::default(),
             acknowledgements: Default::default(),
         })
         .unwrap()
@@ -124,7 +133,15 @@ impl SinkConfig for StatsdSinkConfig {
         _cx: SinkContext,
     ) -> crate::Result<(super::VectorSink, super::Healthcheck)> {
         let default_namespace = self.default_namespace.clone();
-        let mut encoder = StatsdEncoder { default_namespace };
+        let sanitizer = MetricNameSanitizer::new(
+            &self.name_sanitization,
+            NamingRules::STATSD,
+            default_namespace.clone(),
+        )?;
+        let mut encoder = StatsdEncoder {
+            default_namespace,
+            sanitizer,
+        };
         match &self.mode {
             Mode::Tcp(config) => config.build(Default::default(), encoder),
             Mode::Udp(config) => {
@@ -209,6 +226,7 @@ fn push_event<V: Display>(
 #[derive(Debug, Clone)]
 struct StatsdEncoder {
     default_namespace: Option<String>,
+    sanitizer: MetricNameSanitizer,
 }
 
 impl Encoder<Event> for StatsdEncoder {
@@ -217,7 +235,10 @@ impl Encoder<Event> for StatsdEncoder {
     fn encode(&mut self, event: Event, bytes: &mut BytesMut) -> Result<(), Self::Error> {
         let mut buf = Vec::new();
 
-        let metric = event.as_metric();
+        let Some(metric) = self.sanitizer.sanitize(event.into_metric()) else {
+            return Ok(());
+        };
+        let metric = &metric;
         match metric.value() {
             MetricValue::Counter { value } => {
                 push_event(&mut buf, metric, value, "c", None);
@@ -328,6 +349,7 @@ mod test {
     use super::*;
     use crate::{
         event::Metric,
+        sinks::util::metric_names::SanitizationStrategy,
         test_util::{
             components::{assert_sink_compliance, SINK_TAGS},
             *,
@@ -339,6 +361,14 @@ mod test {
         crate::test_util::test_generate_config::<StatsdSinkConfig>();
     }
 
+    fn encoder(name_sanitization: NameSanitizationConfig) -> StatsdEncoder {
+        StatsdEncoder {
+            default_namespace: None,
+            sanitizer: MetricNameSanitizer::new(&name_sanitization, NamingRules::STATSD, None)
+                .unwrap(),
+        }
+    }
+
     fn tags() -> MetricTags {
         metric_tags!(
             "normal_tag" => "value",
@@ -393,15 +423,35 @@ mod test {
         )
         .with_tags(Some(tags()));
         let event = Event::Metric(metric1.clone());
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         let metric2 = parse(from_utf8(&frame).unwrap().trim()).unwrap();
         vector_common::assert_event_data_eq!(metric1, metric2);
     }
 
+    #[test]
+    fn test_encode_sanitized_names() {
+        let metric = Metric::new(
+            "requests total",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 1.5 },
+        )
+        .with_namespace(Some("my|app"))
+        .with_tags(Some(metric_tags!("status:code" => "200")));
+        let mut encoder = encoder(NameSanitizationConfig {
+            strategy: SanitizationStrategy::Replace,
+            ..Default::default()
+        });
+        let mut frame = BytesMut::new();
+        encoder.encode(Event::Metric(metric), &mut frame).unwrap();
+
+        assert_eq!(
+            std::str::from_utf8(&frame).unwrap(),
+            "my_app.requests_total:1.5|c|#status_code:200\n"
+        );
+    }
+
     #[cfg(feature = "sources-statsd")]
     #[test]
     fn test_encode_absolute_counter() {
@@ -411,9 +461,7 @@ mod test {
             MetricValue::Counter { value: 1.5 },
         );
         let event = Event::Metric(metric1);
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         // The statsd parser will parse the counter as Incremental,
@@ -431,9 +479,7 @@ mod test {
         )
         .with_tags(Some(tags()));
         let event = Event::Metric(metric1.clone());
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         let metric2 = parse(from_utf8(&frame).unwrap().trim()).unwrap();
@@ -450,9 +496,7 @@ mod test {
         )
         .with_tags(Some(tags()));
         let event = Event::Metric(metric1.clone());
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         let metric2 = parse(from_utf8(&frame).unwrap().trim()).unwrap();
@@ -483,9 +527,7 @@ mod test {
         .with_tags(Some(tags()));
 
         let event = Event::Metric(metric1);
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         let metric2 = parse(from_utf8(&frame).unwrap().trim()).unwrap();
@@ -524,9 +566,7 @@ mod test {
         )
         .with_tags(Some(tags()));
         let event = Event::Metric(metric1);
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
 
@@ -552,9 +592,7 @@ mod test {
         )
         .with_tags(Some(tags()));
         let event = Event::Metric(metric1.clone());
-        let mut encoder = StatsdEncoder {
-            default_namespace: None,
-        };
+        let mut encoder = encoder(NameSanitizationConfig::default());
         let mut frame = BytesMut::new();
         encoder.encode(event, &mut frame).unwrap();
         let metric2 = parse(from_utf8(&frame).unwrap().trim()).unwrap();
@@ -576,6 +614,7 @@ mod test {
                 batch,
                 udp: UdpSinkConfig::from_address(addr.to_string()),
             }),
+            name_sanitization: Default::default(),
             acknowledgements: Default::default(),
         };
 
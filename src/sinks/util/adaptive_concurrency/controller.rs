@@ -68,7 +68,7 @@ impl<L> Controller<L> {
         // If a `concurrency` is specified, it becomes both the
         // current limit and the maximum, effectively bypassing all the
         // mechanisms. Otherwise, the current limit is set to 1 and the
-        // maximum to MAX_CONCURRENCY.
+        // maximum to `max_concurrency_limit`.
         let current_limit = concurrency.unwrap_or(1);
         Self {
             semaphore: Arc::new(ShrinkableSemaphore::new(current_limit)),
@@ -177,7 +177,7 @@ impl<L> Controller<L> {
                 // No past measurements, set up initial values.
                 if let Some(current_rtt) = current_rtt {
                     inner.past_rtt.update(current_rtt);
-                    inner.next_update = now + Duration::from_secs_f64(current_rtt);
+                    inner.next_update = now + self.measurement_window(current_rtt);
                 }
             }
             Some(mut past_rtt) => {
@@ -204,7 +204,7 @@ impl<L> Controller<L> {
                     if let Some(current_rtt) = current_rtt {
                         past_rtt = inner.past_rtt.update(current_rtt);
                     }
-                    inner.next_update = now + Duration::from_secs_f64(past_rtt.mean);
+                    inner.next_update = now + self.measurement_window(past_rtt.mean);
                     inner.current_rtt = Default::default();
                     inner.had_back_pressure = false;
                     inner.reached_limit = false;
@@ -213,6 +213,11 @@ impl<L> Controller<L> {
         }
     }
 
+    /// The time over which to average the RTTs before the next adjustment of the limit.
+    fn measurement_window(&self, past_rtt: f64) -> Duration {
+        Duration::from_secs_f64(past_rtt.max(self.settings.min_rtt_window_secs))
+    }
+
     fn manage_limit(
         &self,
         inner: &mut MutexGuard<Inner>,
@@ -226,7 +231,7 @@ impl<L> Controller<L> {
         // concurrency limit. Note that we only check this if we had
         // requests to go beyond the current limit to prevent
         // increasing the limit beyond what we have evidence for.
-        if inner.current_limit < super::MAX_CONCURRENCY
+        if inner.current_limit < self.settings.max_concurrency()
             && inner.reached_limit
             && !inner.had_back_pressure
             && current_rtt.is_some()
@@ -3,6 +3,7 @@
 mod controller;
 mod future;
 mod layer;
+mod partition;
 mod semaphore;
 mod service;
 
@@ -12,7 +13,12 @@ pub mod tests;
 pub(super) const MAX_CONCURRENCY: usize = 200;
 
 pub(crate) use layer::AdaptiveConcurrencyLimitLayer;
+pub(crate) use partition::{
+    PartitionedAdaptiveConcurrencyLimit, PartitionedAdaptiveConcurrencyLimitLayer,
+};
 pub(crate) use service::AdaptiveConcurrencyLimit;
+use std::num::NonZeroUsize;
+
 use vector_config::configurable_component;
 
 pub(self) fn instant_now() -> std::time::Instant {
@@ -61,6 +67,37 @@ pub struct AdaptiveConcurrencySettings {
     #[configurable(validation(range(min = 0.0)))]
     #[serde(default = "default_rtt_deviation_scale")]
     pub(super) rtt_deviation_scale: f64,
+
+    /// The maximum concurrency limit.
+    ///
+    /// ARC never raises the concurrency limit above this value, which caps the number of in-flight requests
+    /// regardless of how well the service responds. When `partitioned` is enabled, this applies to each partition.
+    #[configurable(validation(range(min = 1)))]
+    #[serde(default = "default_max_concurrency_limit")]
+    pub(super) max_concurrency_limit: NonZeroUsize,
+
+    /// The minimum amount of time, in seconds, over which RTTs are averaged before adjusting the limit.
+    ///
+    /// By default, the RTTs are averaged over an interval equal to the past RTT average, which is short for services
+    /// responding quickly. Larger values smooth out short spikes in the RTT, which may otherwise decrease the limit,
+    /// at the cost of adjusting the limit more slowly.
+    #[configurable(validation(range(min = 0.0)))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[serde(default)]
+    pub(super) min_rtt_window_secs: f64,
+
+    /// Whether or not to manage a separate concurrency limit for each partition of the requests.
+    ///
+    /// By default, a single limit applies to all of the requests of the sink, so one slow backend can throttle the
+    /// whole sink. When enabled, requests are grouped by their partition, such as the token and index of the
+    /// `splunk_hec_logs` sink, and each group adapts its own limit. Sinks that don't partition their requests ignore
+    /// this option, and sinks distributing requests across several endpoints, such as `elasticsearch`, always keep a
+    /// separate limit for each endpoint.
+    ///
+    /// The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the
+    /// partition receives requests again.
+    #[serde(default)]
+    pub(super) partitioned: bool,
 }
 
 const fn default_decrease_ratio() -> f64 {
@@ -75,9 +112,13 @@ const fn default_rtt_deviation_scale() -> f64 {
     2.5
 }
 
+fn default_max_concurrency_limit() -> NonZeroUsize {
+    NonZeroUsize::new(MAX_CONCURRENCY).expect("static non-zero number")
+}
+
 impl AdaptiveConcurrencySettings {
-    pub const fn max_concurrency() -> usize {
-        MAX_CONCURRENCY
+    pub const fn max_concurrency(&self) -> usize {
+        self.max_concurrency_limit.get()
     }
 }
 
@@ -87,6 +128,9 @@ impl Default for AdaptiveConcurrencySettings {
             decrease_ratio: default_decrease_ratio(),
             ewma_alpha: default_ewma_alpha(),
             rtt_deviation_scale: default_rtt_deviation_scale(),
+            max_concurrency_limit: default_max_concurrency_limit(),
+            min_rtt_window_secs: 0.0,
+            partitioned: false,
         }
     }
 }
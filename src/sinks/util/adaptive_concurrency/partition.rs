@@ -0,0 +1,370 @@
+//! Separate concurrency limits for each partition of the requests of a sink.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::PollSemaphore;
+use tower::{Layer, Service, ServiceExt};
+
+use super::{instant_now, AdaptiveConcurrencyLimit, AdaptiveConcurrencySettings};
+use crate::sinks::util::{retries::RetryLogic, Partition};
+
+/// How long a partition must go without requests before its limit is dropped, so that sinks whose
+/// partitions come and go, such as one per index, don't keep a limit for every partition they've
+/// ever seen.
+const IDLE_PARTITION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Enforces a limit on the concurrent number of requests of each partition the underlying service
+/// handles, adapting each limit on its own.
+///
+/// When the settings don't enable partitioning, all requests share a single limit, as with
+/// [`AdaptiveConcurrencyLimit`]. Requests in flight only count against the limit of their
+/// partition, while the requests waiting for their partition to accept them are capped at the
+/// maximum concurrency limit, or the fixed concurrency if higher, so that a slow partition can't
+/// make requests pile up without bounds.
+pub struct PartitionedAdaptiveConcurrencyLimit<S, L, K> {
+    inner: S,
+    logic: L,
+    concurrency: Option<usize>,
+    settings: AdaptiveConcurrencySettings,
+    partitions: Arc<Mutex<Partitions<S, L, K>>>,
+    waiting: PollSemaphore,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+struct Partitions<S, L, K> {
+    limits: HashMap<Option<K>, PartitionLimit<S, L>>,
+    last_sweep: Instant,
+}
+
+struct PartitionLimit<S, L> {
+    limit: AdaptiveConcurrencyLimit<S, L>,
+    /// The number of requests of the partition waiting or in flight.
+    active: usize,
+    last_active: Instant,
+}
+
+impl<S, L, K> Partitions<S, L, K> {
+    /// Drops the limits of the partitions that have been idle for a while, at most once per
+    /// timeout.
+    fn sweep(&mut self, now: Instant) {
+        if now.duration_since(self.last_sweep) < IDLE_PARTITION_TIMEOUT {
+            return;
+        }
+        self.last_sweep = now;
+        self.limits.retain(|_, partition| {
+            partition.active > 0
+                || now.duration_since(partition.last_active) < IDLE_PARTITION_TIMEOUT
+        });
+    }
+}
+
+impl<S, L, K> PartitionedAdaptiveConcurrencyLimit<S, L, K> {
+    /// Create a new partitioned concurrency limiter.
+    pub(crate) fn new(
+        inner: S,
+        logic: L,
+        concurrency: Option<usize>,
+        settings: AdaptiveConcurrencySettings,
+    ) -> Self {
+        let waiting = Arc::new(Semaphore::new(
+            concurrency
+                .unwrap_or_default()
+                .max(settings.max_concurrency()),
+        ));
+        Self {
+            inner,
+            logic,
+            concurrency,
+            settings,
+            partitions: Arc::new(Mutex::new(Partitions {
+                limits: HashMap::new(),
+                last_sweep: instant_now(),
+            })),
+            waiting: PollSemaphore::new(waiting),
+            permit: None,
+        }
+    }
+}
+
+/// Marks a request of a partition as done when dropped, whether it completed or was cancelled.
+struct ActiveRequest<S, L, K: Hash + Eq> {
+    partitions: Arc<Mutex<Partitions<S, L, K>>>,
+    key: Option<K>,
+}
+
+impl<S, L, K: Hash + Eq> Drop for ActiveRequest<S, L, K> {
+    fn drop(&mut self) {
+        let mut partitions = self
+            .partitions
+            .lock()
+            .expect("Partitions mutex is poisoned");
+        if let Some(partition) = partitions.limits.get_mut(&self.key) {
+            partition.active -= 1;
+            partition.last_active = instant_now();
+        }
+    }
+}
+
+impl<S, L, K, Request> Service<Request> for PartitionedAdaptiveConcurrencyLimit<S, L, K>
+where
+    S: Service<Request> + Clone + Send + 'static,
+    S::Response: Send,
+    S::Error: Into<crate::Error>,
+    S::Future: Send,
+    L: RetryLogic<Response = S::Response>,
+    K: Clone + Hash + Eq + Send + 'static,
+    Request: Partition<K> + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = crate::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if self.permit.is_none() {
+            match ready!(self.waiting.poll_acquire(cx)) {
+                Some(permit) => self.permit = Some(permit),
+                None => {
+                    return Poll::Ready(Err("Concurrency semaphore unexpectedly closed".into()))
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let permit = self
+            .permit
+            .take()
+            .expect("Maximum requests waiting; poll_ready must be called first");
+
+        let key = self.settings.partitioned.then(|| request.partition());
+        let now = instant_now();
+        let mut partitions = self
+            .partitions
+            .lock()
+            .expect("Partitions mutex is poisoned");
+        partitions.sweep(now);
+        let partition = partitions
+            .limits
+            .entry(key.clone())
+            .or_insert_with(|| PartitionLimit {
+                limit: AdaptiveConcurrencyLimit::new(
+                    self.inner.clone(),
+                    self.logic.clone(),
+                    self.concurrency,
+                    self.settings,
+                ),
+                active: 0,
+                last_active: now,
+            });
+        partition.active += 1;
+        let mut limit = partition.limit.clone();
+        drop(partitions);
+
+        let active = ActiveRequest {
+            partitions: Arc::clone(&self.partitions),
+            key,
+        };
+        Box::pin(async move {
+            let _active = active;
+            // Only the wait for the partition to accept the request counts against the shared
+            // permits, the request in flight counts against the limit of its partition.
+            limit.ready().await?;
+            drop(permit);
+            limit.call(request).await
+        })
+    }
+}
+
+impl<S, L, K> Clone for PartitionedAdaptiveConcurrencyLimit<S, L, K>
+where
+    S: Clone,
+    L: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            logic: self.logic.clone(),
+            concurrency: self.concurrency,
+            settings: self.settings,
+            partitions: Arc::clone(&self.partitions),
+            waiting: self.waiting.clone(),
+            permit: None,
+        }
+    }
+}
+
+/// Enforces a limit on the concurrent number of requests of each partition the underlying service
+/// handles.
+#[derive(Debug)]
+pub struct PartitionedAdaptiveConcurrencyLimitLayer<L, K> {
+    concurrency: Option<usize>,
+    settings: AdaptiveConcurrencySettings,
+    logic: L,
+    _pd: PhantomData<fn() -> K>,
+}
+
+impl<L, K> PartitionedAdaptiveConcurrencyLimitLayer<L, K> {
+    /// Create a new partitioned concurrency limit layer.
+    pub const fn new(
+        concurrency: Option<usize>,
+        settings: AdaptiveConcurrencySettings,
+        logic: L,
+    ) -> Self {
+        Self {
+            concurrency,
+            settings,
+            logic,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<L: Clone, K> Clone for PartitionedAdaptiveConcurrencyLimitLayer<L, K> {
+    fn clone(&self) -> Self {
+        Self::new(self.concurrency, self.settings, self.logic.clone())
+    }
+}
+
+impl<S, L: RetryLogic, K> Layer<S> for PartitionedAdaptiveConcurrencyLimitLayer<L, K> {
+    type Service = PartitionedAdaptiveConcurrencyLimit<S, L, K>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        PartitionedAdaptiveConcurrencyLimit::new(
+            service,
+            self.logic.clone(),
+            self.concurrency,
+            self.settings,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+    use tokio_test::assert_ready_ok;
+    use tower_test::mock;
+
+    use super::*;
+
+    #[derive(Clone, Copy, Debug)]
+    struct TestRetryLogic;
+
+    impl RetryLogic for TestRetryLogic {
+        type Error = std::io::Error;
+        type Response = String;
+
+        fn is_retriable_error(&self, _error: &Self::Error) -> bool {
+            true
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct TestRequest(&'static str);
+
+    impl Partition<&'static str> for TestRequest {
+        fn partition(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    type TestService = PartitionedAdaptiveConcurrencyLimit<
+        mock::Mock<TestRequest, String>,
+        TestRetryLogic,
+        &'static str,
+    >;
+
+    fn spawn(partitioned: bool) -> (mock::Spawn<TestService>, mock::Handle<TestRequest, String>) {
+        let settings = AdaptiveConcurrencySettings {
+            partitioned,
+            ..Default::default()
+        };
+        mock::spawn_layer(PartitionedAdaptiveConcurrencyLimitLayer::new(
+            None,
+            settings,
+            TestRetryLogic,
+        ))
+    }
+
+    #[tokio::test]
+    async fn slow_partition_does_not_block_others() {
+        let (mut service, mut handle) = spawn(true);
+
+        // The concurrency limit starts at 1 for each partition.
+        assert_ready_ok!(service.poll_ready());
+        let slow = tokio::spawn(service.call(TestRequest("slow")));
+        let (request, slow_response) = handle.next_request().await.unwrap();
+        assert_eq!(request, TestRequest("slow"));
+
+        assert_ready_ok!(service.poll_ready());
+        let fast = tokio::spawn(service.call(TestRequest("fast")));
+        let (request, fast_response) = timeout(Duration::from_secs(1), handle.next_request())
+            .await
+            .expect("request of another partition should not wait")
+            .unwrap();
+        assert_eq!(request, TestRequest("fast"));
+        fast_response.send_response("fast".to_string());
+        assert_eq!(fast.await.unwrap().unwrap(), "fast");
+
+        slow_response.send_response("slow".to_string());
+        assert_eq!(slow.await.unwrap().unwrap(), "slow");
+    }
+
+    #[tokio::test]
+    async fn unpartitioned_requests_share_a_limit() {
+        let (mut service, mut handle) = spawn(false);
+
+        assert_ready_ok!(service.poll_ready());
+        let first = tokio::spawn(service.call(TestRequest("a")));
+        let (_, first_response) = handle.next_request().await.unwrap();
+
+        assert_ready_ok!(service.poll_ready());
+        let second = tokio::spawn(service.call(TestRequest("b")));
+        assert!(timeout(Duration::from_millis(50), handle.next_request())
+            .await
+            .is_err());
+
+        first_response.send_response("a".to_string());
+        assert_eq!(first.await.unwrap().unwrap(), "a");
+        let (request, second_response) = handle.next_request().await.unwrap();
+        assert_eq!(request, TestRequest("b"));
+        second_response.send_response("b".to_string());
+        assert_eq!(second.await.unwrap().unwrap(), "b");
+    }
+
+    #[tokio::test]
+    async fn idle_partitions_are_dropped() {
+        tokio::time::pause();
+        let (mut service, mut handle) = spawn(true);
+
+        assert_ready_ok!(service.poll_ready());
+        let first = tokio::spawn(service.call(TestRequest("a")));
+        let (_, response) = handle.next_request().await.unwrap();
+        response.send_response("a".to_string());
+        assert_eq!(first.await.unwrap().unwrap(), "a");
+
+        tokio::time::advance(IDLE_PARTITION_TIMEOUT).await;
+
+        assert_ready_ok!(service.poll_ready());
+        let second = tokio::spawn(service.call(TestRequest("b")));
+        let (_, response) = handle.next_request().await.unwrap();
+        {
+            let partitions = service.get_ref().partitions.lock().unwrap();
+            assert!(!partitions.limits.contains_key(&Some("a")));
+            assert!(partitions.limits.contains_key(&Some("b")));
+        }
+        response.send_response("b".to_string());
+        assert_eq!(second.await.unwrap().unwrap(), "b");
+    }
+}
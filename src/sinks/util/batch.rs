@@ -100,7 +100,8 @@ where
     /// The maximum size of a batch that is processed by a sink.
     ///
     /// This is based on the uncompressed size of the batched events, before they are
-    /// serialized/compressed.
+    /// serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+    /// `aws_cloudwatch_logs`, use the size of the encoded payload instead.
     #[serde(default = "default_max_bytes::<D>")]
     #[configurable(metadata(docs::type_unit = "bytes"))]
     pub max_bytes: Option<usize>,
@@ -6,7 +6,7 @@ use vector_core::{
     event::{Finalizable, Metric},
     partition::Partitioner,
     stream::{
-        batcher::{config::BatchConfig, Batcher},
+        batcher::{config::BatchConfig, limiter::ItemBatchSize, Batcher},
         BatcherSettings, ConcurrentMap, Driver, DriverResponse, ExpirationQueue,
         PartitionedBatcher,
     },
@@ -39,6 +39,29 @@ pub trait SinkBuilderExt: Stream {
         PartitionedBatcher::new(self, partitioner, settings)
     }
 
+    /// Batches the stream based on the given partitioner, batch settings and item size calculator.
+    ///
+    /// This is like [`batched_partitioned`](Self::batched_partitioned), except that the size of
+    /// batches is measured against `max_bytes` with `item_size` rather than with `ByteSizeOf`. Sinks
+    /// that encode events before batching them use [`EncodedSizeOfItemSize`] so that batches fit in
+    /// the payload limits of the API they send to.
+    ///
+    /// [`EncodedSizeOfItemSize`]: vector_core::stream::batcher::limiter::EncodedSizeOfItemSize
+    fn batched_partitioned_by_size<P, I>(
+        self,
+        partitioner: P,
+        settings: BatcherSettings,
+        item_size: I,
+    ) -> PartitionedBatcher<Self, P, ExpirationQueue<P::Key>, I>
+    where
+        Self: Stream<Item = P::Item> + Sized,
+        P: Partitioner + Unpin,
+        P::Key: Eq + Hash + Clone,
+        I: ItemBatchSize<P::Item>,
+    {
+        PartitionedBatcher::with_item_size(self, partitioner, settings, item_size)
+    }
+
     /// Batches the stream based on the given batch settings and item size calculator.
     ///
     /// The stream will yield batches of events, when either a batch fills
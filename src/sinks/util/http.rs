@@ -10,6 +10,7 @@ use std::{
 };
 
 use bytes::{Buf, Bytes};
+use chrono::{DateTime, Utc};
 use futures::{future::BoxFuture, Sink};
 use headers::HeaderName;
 use http::{header, HeaderValue, StatusCode};
@@ -24,6 +25,7 @@ use vector_core::ByteSizeOf;
 
 use super::{
     retries::{RetryAction, RetryLogic},
+    service::BandwidthLimiter,
     sink, uri, Batch, EncodedEvent, Partition, TowerBatchedSink, TowerPartitionSink,
     TowerRequestConfig, TowerRequestSettings,
 };
@@ -46,6 +48,32 @@ pub trait HttpSink: Send + Sync + 'static {
 
     fn build_encoder(&self) -> Self::Encoder;
     async fn build_request(&self, events: Self::Output) -> crate::Result<http::Request<Bytes>>;
+
+    /// Classifies a response received from the downstream service.
+    ///
+    /// Returning `None` leaves the response to be handled according to its status code.
+    fn classify_response(
+        &self,
+        _response: &http::Response<Bytes>,
+    ) -> Option<ResponseClassification> {
+        None
+    }
+}
+
+/// The outcome of a request, as determined by inspecting its response.
+///
+/// When present in the extensions of a response, this takes precedence over the response status
+/// code when deciding whether the request should be retried and what status its events are given.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResponseClassification {
+    /// The request succeeded.
+    Success,
+
+    /// The request failed, but may succeed if retried.
+    Retry(String),
+
+    /// The request failed, and should not be retried.
+    Reject(String),
 }
 
 /// Provides a simple wrapper around internal tower and
@@ -132,7 +160,10 @@ where
             Box::pin(async move { sink.build_request(b).await })
         };
 
-        let svc = HttpBatchService::new(client, request_builder);
+        let sink2 = Arc::clone(&sink);
+        let svc = HttpBatchService::new(client, request_builder)
+            .with_response_classifier(move |response| sink2.classify_response(response))
+            .with_bandwidth_limit(request_settings.bandwidth_limit.clone());
         let inner = request_settings.batch_sink(retry_logic, svc, batch, batch_timeout);
         let encoder = sink.build_encoder();
 
@@ -274,7 +305,8 @@ where
             Box::pin(async move { sink.build_request(b).await })
         };
 
-        let svc = HttpBatchService::new(client, request_builder);
+        let svc = HttpBatchService::new(client, request_builder)
+            .with_bandwidth_limit(request_settings.bandwidth_limit.clone());
         let inner = request_settings.partition_sink(retry_logic, svc, batch, batch_timeout);
         let encoder = sink.build_encoder();
 
@@ -350,9 +382,14 @@ where
     }
 }
 
+type ResponseClassifier =
+    Arc<dyn Fn(&http::Response<Bytes>) -> Option<ResponseClassification> + Send + Sync>;
+
 pub struct HttpBatchService<F, B = Bytes> {
     inner: HttpClient<Body>,
     request_builder: Arc<dyn Fn(B) -> F + Send + Sync>,
+    response_classifier: Option<ResponseClassifier>,
+    bandwidth_limit: Option<BandwidthLimiter>,
 }
 
 impl<F, B> HttpBatchService<F, B> {
@@ -363,8 +400,29 @@ impl<F, B> HttpBatchService<F, B> {
         HttpBatchService {
             inner,
             request_builder: Arc::new(Box::new(request_builder)),
+            response_classifier: None,
+            bandwidth_limit: None,
         }
     }
+
+    /// Delays requests to stay under the given bandwidth limit, based on the size of their body.
+    pub fn with_bandwidth_limit(mut self, bandwidth_limit: Option<BandwidthLimiter>) -> Self {
+        self.bandwidth_limit = bandwidth_limit;
+        self
+    }
+
+    /// Classifies each response with the given function, recording the result in the response's
+    /// extensions.
+    pub fn with_response_classifier(
+        mut self,
+        response_classifier: impl Fn(&http::Response<Bytes>) -> Option<ResponseClassification>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.response_classifier = Some(Arc::new(response_classifier));
+        self
+    }
 }
 
 impl<F, B> Service<B> for HttpBatchService<F, B>
@@ -382,6 +440,8 @@ where
 
     fn call(&mut self, body: B) -> Self::Future {
         let request_builder = Arc::clone(&self.request_builder);
+        let response_classifier = self.response_classifier.clone();
+        let bandwidth_limit = self.bandwidth_limit.clone();
         let http_client = self.inner.clone();
 
         Box::pin(async move {
@@ -390,6 +450,9 @@ where
                 error
             })?;
             let byte_size = request.body().len();
+            if let Some(bandwidth_limit) = bandwidth_limit {
+                bandwidth_limit.acquire(byte_size).await;
+            }
             let request = request.map(Body::from);
             let (protocol, endpoint) = uri::protocol_endpoint(request.uri().clone());
 
@@ -411,10 +474,17 @@ where
 
             let (parts, body) = response.into_parts();
             let mut body = body::aggregate(body).await?;
-            Ok(hyper::Response::from_parts(
-                parts,
-                body.copy_to_bytes(body.remaining()),
-            ))
+            let mut response =
+                hyper::Response::from_parts(parts, body.copy_to_bytes(body.remaining()));
+
+            if let Some(classification) = response_classifier
+                .as_ref()
+                .and_then(|classify| classify(&response))
+            {
+                response.extensions_mut().insert(classification);
+            }
+
+            Ok(response)
         })
     }
 }
@@ -424,17 +494,25 @@ impl<F, B> Clone for HttpBatchService<F, B> {
         Self {
             inner: self.inner.clone(),
             request_builder: Arc::clone(&self.request_builder),
+            response_classifier: self.response_classifier.clone(),
+            bandwidth_limit: self.bandwidth_limit.clone(),
         }
     }
 }
 
 impl<T: fmt::Debug> sink::Response for http::Response<T> {
     fn is_successful(&self) -> bool {
-        self.status().is_success()
+        match self.extensions().get::<ResponseClassification>() {
+            Some(classification) => *classification == ResponseClassification::Success,
+            None => self.status().is_success(),
+        }
     }
 
     fn is_transient(&self) -> bool {
-        self.status().is_server_error()
+        match self.extensions().get::<ResponseClassification>() {
+            Some(classification) => matches!(classification, ResponseClassification::Retry(_)),
+            None => self.status().is_server_error(),
+        }
     }
 }
 
@@ -450,6 +528,17 @@ impl RetryLogic for HttpRetryLogic {
     }
 
     fn should_retry_response(&self, response: &Self::Response) -> RetryAction {
+        match response.extensions().get::<ResponseClassification>() {
+            Some(ResponseClassification::Success) => return RetryAction::Successful,
+            Some(ResponseClassification::Retry(reason)) => {
+                return RetryAction::Retry(reason.clone().into())
+            }
+            Some(ResponseClassification::Reject(reason)) => {
+                return RetryAction::DontRetry(reason.clone().into())
+            }
+            None => {}
+        }
+
         let status = response.status();
 
         match status {
@@ -593,6 +682,28 @@ pub fn validate_headers(
     Ok(validated_headers)
 }
 
+/// Returns the delay requested by the `Retry-After` header of the response, which is either a
+/// number of seconds or a date.
+pub fn retry_after<B>(response: &http::Response<B>) -> Option<Duration> {
+    let value = response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    // Dates in the past request no delay at all.
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod test {
     #![allow(clippy::print_stderr)] //tests
@@ -625,6 +736,63 @@ mod test {
             .is_not_retryable());
     }
 
+    #[test]
+    fn parses_retry_after() {
+        let response = |value: &str| {
+            Response::builder()
+                .status(429)
+                .header("Retry-After", value)
+                .body(Bytes::new())
+                .unwrap()
+        };
+
+        assert_eq!(
+            retry_after(&response("120")),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            retry_after(&response("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Duration::ZERO)
+        );
+        let date = (Utc::now() + chrono::Duration::seconds(600)).to_rfc2822();
+        let delay = retry_after(&response(&date)).unwrap();
+        assert!(delay > Duration::from_secs(590) && delay <= Duration::from_secs(600));
+        assert_eq!(retry_after(&response("soon")), None);
+        assert_eq!(
+            retry_after(&Response::builder().status(429).body(Bytes::new()).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn util_http_retry_logic_uses_response_classification() {
+        let logic = HttpRetryLogic;
+
+        let classified = |status: u16, classification: ResponseClassification| {
+            let mut response = Response::builder()
+                .status(status)
+                .body(Bytes::new())
+                .unwrap();
+            response.extensions_mut().insert(classification);
+            response
+        };
+
+        let retry = classified(200, ResponseClassification::Retry("busy".into()));
+        let reject = classified(200, ResponseClassification::Reject("invalid".into()));
+        let success = classified(500, ResponseClassification::Success);
+
+        assert!(logic.should_retry_response(&retry).is_retryable());
+        assert!(sink::Response::is_transient(&retry));
+        assert!(!sink::Response::is_successful(&retry));
+
+        assert!(logic.should_retry_response(&reject).is_not_retryable());
+        assert!(!sink::Response::is_transient(&reject));
+        assert!(!sink::Response::is_successful(&reject));
+
+        assert!(logic.should_retry_response(&success).is_successful());
+        assert!(sink::Response::is_successful(&success));
+    }
+
     #[tokio::test]
     async fn util_http_it_makes_http_requests() {
         let addr = next_addr();
@@ -0,0 +1,550 @@
+//! Sanitization of metric names and tag keys, following the naming rules of the destination of a
+//! sink.
+
+use std::{borrow::Cow, collections::HashMap};
+
+use snafu::Snafu;
+use vector_config::configurable_component;
+use vector_core::event::{metric::MetricTags, Metric};
+
+use super::encode_namespace;
+use crate::internal_events::{MetricNameCollision, MetricNameInvalid};
+
+/// The number of distinct metric names remembered to detect names colliding once sanitized.
+const MAX_TRACKED_NAMES: usize = 100_000;
+
+/// How metric names and tag keys which are invalid for the destination are handled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SanitizationStrategy {
+    /// Metrics are sent as they are, leaving it to the destination to reject or rewrite them.
+    #[default]
+    None,
+
+    /// Invalid characters are replaced with the `replacement`, and names which are too long are
+    /// truncated.
+    Replace,
+
+    /// Invalid characters are removed, and names which are too long are truncated.
+    Remove,
+
+    /// Metrics with an invalid name or tag key are dropped.
+    Drop,
+}
+
+/// Configuration for sanitizing metric names and tag keys.
+///
+/// Names include the namespace of metrics. Metrics whose name is empty once sanitized are dropped.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NameSanitizationConfig {
+    #[configurable(derived)]
+    #[serde(default)]
+    pub strategy: SanitizationStrategy,
+
+    /// The string which invalid characters are replaced with, when the strategy is `replace`.
+    ///
+    /// It must only contain characters which are valid in the names and tag keys of the
+    /// destination.
+    #[serde(default = "default_replacement")]
+    #[configurable(metadata(docs::examples = "_"))]
+    pub replacement: String,
+}
+
+impl Default for NameSanitizationConfig {
+    fn default() -> Self {
+        Self {
+            strategy: SanitizationStrategy::default(),
+            replacement: default_replacement(),
+        }
+    }
+}
+
+fn default_replacement() -> String {
+    "_".to_owned()
+}
+
+/// The naming rules of a destination.
+#[derive(Clone, Copy, Debug)]
+pub struct NamingRules {
+    /// Whether a character is valid in names, given whether it starts the name.
+    name_char: fn(char, bool) -> bool,
+    /// Whether a character is valid in tag keys, given whether it starts the key.
+    tag_key_char: fn(char, bool) -> bool,
+    /// The delimiter between the namespace and the name of metrics.
+    delimiter: char,
+    /// The maximum length, in bytes, of names including their namespace.
+    max_name_length: Option<usize>,
+    /// The maximum length, in bytes, of tag keys.
+    max_tag_key_length: Option<usize>,
+}
+
+impl NamingRules {
+    /// The rules of Prometheus [metric and label names][names].
+    ///
+    /// [names]: https://prometheus.io/docs/concepts/data_model/#metric-names-and-labels
+    pub const PROMETHEUS: Self = Self {
+        name_char: prometheus_name_char,
+        tag_key_char: prometheus_label_char,
+        delimiter: '_',
+        max_name_length: None,
+        max_tag_key_length: None,
+    };
+
+    /// The rules of StatsD, where names are dot-separated paths as in Graphite, and the characters
+    /// delimiting the fields of lines are not allowed.
+    pub const STATSD: Self = Self {
+        name_char: statsd_char,
+        tag_key_char: statsd_char,
+        delimiter: '.',
+        max_name_length: None,
+        max_tag_key_length: None,
+    };
+
+    /// The rules of Datadog [metric names][names] and [tags][tags].
+    ///
+    /// [names]: https://docs.datadoghq.com/metrics/custom_metrics/#naming-custom-metrics
+    /// [tags]: https://docs.datadoghq.com/getting_started/tagging/#define-tags
+    pub const DATADOG: Self = Self {
+        name_char: datadog_name_char,
+        tag_key_char: datadog_tag_key_char,
+        delimiter: '.',
+        max_name_length: Some(200),
+        max_tag_key_length: Some(200),
+    };
+}
+
+fn prometheus_name_char(c: char, leading: bool) -> bool {
+    c == ':' || prometheus_label_char(c, leading)
+}
+
+fn prometheus_label_char(c: char, leading: bool) -> bool {
+    c.is_ascii_alphabetic() || c == '_' || (!leading && c.is_ascii_digit())
+}
+
+fn statsd_char(c: char, _leading: bool) -> bool {
+    !matches!(c, ':' | '|' | '@' | '#' | ',') && !c.is_whitespace() && !c.is_control()
+}
+
+fn datadog_name_char(c: char, leading: bool) -> bool {
+    c.is_ascii_alphabetic() || (!leading && (c.is_ascii_digit() || c == '_' || c == '.'))
+}
+
+fn datadog_tag_key_char(c: char, leading: bool) -> bool {
+    c.is_alphabetic() || (!leading && (c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/')))
+}
+
+#[derive(Debug, Snafu)]
+pub enum NameSanitizationError {
+    #[snafu(display(
+        "The replacement {:?} contains {:?}, which is not valid in names or tag keys",
+        replacement,
+        character
+    ))]
+    InvalidReplacement {
+        replacement: String,
+        character: char,
+    },
+}
+
+/// Sanitizes the names and tag keys of metrics for a destination.
+///
+/// The names which were sanitized are remembered, to detect distinct names that collide once
+/// sanitized.
+#[derive(Clone, Debug)]
+pub struct MetricNameSanitizer {
+    rules: NamingRules,
+    strategy: SanitizationStrategy,
+    replacement: String,
+    default_namespace: Option<String>,
+    originals: HashMap<String, String>,
+}
+
+impl MetricNameSanitizer {
+    /// Creates a sanitizer following the given rules.
+    ///
+    /// Metrics without a namespace are given the default namespace, if any, as it is part of the
+    /// name being sanitized.
+    pub fn new(
+        config: &NameSanitizationConfig,
+        rules: NamingRules,
+        default_namespace: Option<String>,
+    ) -> Result<Self, NameSanitizationError> {
+        if config.strategy == SanitizationStrategy::Replace {
+            if let Some(character) = config
+                .replacement
+                .chars()
+                .find(|&c| !(rules.name_char)(c, false) || !(rules.tag_key_char)(c, false))
+            {
+                return Err(NameSanitizationError::InvalidReplacement {
+                    replacement: config.replacement.clone(),
+                    character,
+                });
+            }
+        }
+
+        Ok(Self {
+            rules,
+            strategy: config.strategy,
+            replacement: config.replacement.clone(),
+            default_namespace,
+            originals: HashMap::new(),
+        })
+    }
+
+    /// Sanitizes the name, namespace, and tag keys of a metric.
+    ///
+    /// Returns `None` if the metric is dropped.
+    pub fn sanitize(&mut self, mut metric: Metric) -> Option<Metric> {
+        if self.strategy == SanitizationStrategy::None {
+            return Some(metric);
+        }
+
+        let namespace = metric
+            .namespace()
+            .or(self.default_namespace.as_deref())
+            .map(str::to_owned);
+        let original = encode_namespace(namespace.as_deref(), self.rules.delimiter, metric.name());
+        let sanitized_name = self.sanitize_name(namespace.as_deref(), metric.name());
+        let Some((sanitized_namespace, name)) = sanitized_name else {
+            emit!(MetricNameInvalid { name: &original });
+            return None;
+        };
+
+        let sanitized =
+            encode_namespace(sanitized_namespace.as_deref(), self.rules.delimiter, &*name);
+        self.track(sanitized, original);
+
+        if let Cow::Owned(name) = name {
+            metric = metric.with_name(name);
+        }
+        metric = metric.with_namespace(sanitized_namespace);
+
+        if let Some(tags) = metric.tags() {
+            match self.sanitize_tags(tags) {
+                None => {
+                    emit!(MetricNameInvalid {
+                        name: metric.name()
+                    });
+                    return None;
+                }
+                Some(Some(sanitized)) => metric = metric.with_tags(Some(sanitized)),
+                Some(None) => {}
+            }
+        }
+
+        Some(metric)
+    }
+
+    /// Sanitizes the namespace and name of a metric, truncating the name if needed.
+    fn sanitize_name<'a>(
+        &self,
+        namespace: Option<&str>,
+        name: &'a str,
+    ) -> Option<(Option<String>, Cow<'a, str>)> {
+        let namespace = match namespace {
+            Some(namespace) => Some(
+                self.sanitize_str(namespace, self.rules.name_char, true)?
+                    .into_owned(),
+            ),
+            None => None,
+        };
+        let mut name = self.sanitize_str(name, self.rules.name_char, namespace.is_none())?;
+
+        if let Some(max_length) = self.rules.max_name_length {
+            let prefix_length = namespace
+                .as_ref()
+                .map_or(0, |namespace| namespace.len() + 1);
+            if prefix_length + name.len() > max_length {
+                if self.strategy == SanitizationStrategy::Drop {
+                    return None;
+                }
+                let name = name.to_mut();
+                truncate(name, max_length.saturating_sub(prefix_length));
+                if name.is_empty() {
+                    return None;
+                }
+            }
+        }
+
+        Some((namespace, name))
+    }
+
+    /// Sanitizes the keys of tags.
+    ///
+    /// Returns `None` if a key is invalid and the metric must be dropped, and `Some(None)` if all
+    /// the keys are valid as they are.
+    fn sanitize_tags(&self, tags: &MetricTags) -> Option<Option<MetricTags>> {
+        let is_valid = |key: &str| {
+            self.rules
+                .max_tag_key_length
+                .map_or(true, |max_length| key.len() <= max_length)
+                && key
+                    .chars()
+                    .enumerate()
+                    .all(|(i, c)| (self.rules.tag_key_char)(c, i == 0))
+        };
+        if tags.keys().all(is_valid) {
+            return Some(None);
+        }
+
+        let mut sanitized = MetricTags::default();
+        let mut originals = HashMap::new();
+        for (key, values) in tags.iter_sets() {
+            let mut new_key = self.sanitize_str(key, self.rules.tag_key_char, true)?;
+            if let Some(max_length) = self.rules.max_tag_key_length {
+                if new_key.len() > max_length {
+                    if self.strategy == SanitizationStrategy::Drop {
+                        return None;
+                    }
+                    truncate(new_key.to_mut(), max_length);
+                }
+            }
+
+            if let Some(other) = originals.get(&*new_key) {
+                emit!(MetricNameCollision {
+                    kind: "tag_key",
+                    sanitized: &new_key,
+                    original: key,
+                    other,
+                });
+                continue;
+            }
+            originals.insert(new_key.to_string(), key);
+            sanitized.set_multi_value(new_key.into_owned(), values.clone());
+        }
+
+        Some(Some(sanitized))
+    }
+
+    /// Sanitizes a string following the strategy.
+    ///
+    /// Returns `None` if the string is invalid and must be dropped, or if it is empty once
+    /// sanitized.
+    fn sanitize_str<'a>(
+        &self,
+        value: &'a str,
+        valid: fn(char, bool) -> bool,
+        leading: bool,
+    ) -> Option<Cow<'a, str>> {
+        if value
+            .chars()
+            .enumerate()
+            .all(|(i, c)| valid(c, leading && i == 0))
+        {
+            return (!value.is_empty()).then_some(Cow::Borrowed(value));
+        }
+
+        let mut sanitized = String::with_capacity(value.len());
+        for c in value.chars() {
+            let start = leading && sanitized.is_empty();
+            if valid(c, start) {
+                sanitized.push(c);
+                continue;
+            }
+            match self.strategy {
+                SanitizationStrategy::None | SanitizationStrategy::Remove => {}
+                SanitizationStrategy::Drop => return None,
+                SanitizationStrategy::Replace => {
+                    // The replacement is only valid at the start of names if its first character is.
+                    let replace = !start
+                        || self
+                            .replacement
+                            .chars()
+                            .next()
+                            .map_or(false, |c| valid(c, true));
+                    if replace {
+                        sanitized.push_str(&self.replacement);
+                    }
+                    // Characters only invalid at the start of names, like digits, are kept behind
+                    // the replacement.
+                    if start && valid(c, false) && !sanitized.is_empty() {
+                        sanitized.push(c);
+                    }
+                }
+            }
+        }
+
+        (!sanitized.is_empty()).then_some(Cow::Owned(sanitized))
+    }
+
+    /// Remembers the original name of a sanitized name, detecting distinct names colliding.
+    fn track(&mut self, sanitized: String, original: String) {
+        match self.originals.get(&sanitized) {
+            Some(other) if *other != original => emit!(MetricNameCollision {
+                kind: "name",
+                sanitized: &sanitized,
+                original: &original,
+                other,
+            }),
+            Some(_) => {}
+            None if self.originals.len() < MAX_TRACKED_NAMES => {
+                self.originals.insert(sanitized, original);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Truncates a string to at most `max_length` bytes, on a character boundary.
+fn truncate(value: &mut String, mut max_length: usize) {
+    if value.len() > max_length {
+        while !value.is_char_boundary(max_length) {
+            max_length -= 1;
+        }
+        value.truncate(max_length);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::{
+        event::{MetricKind, MetricValue},
+        metric_tags,
+    };
+
+    use super::*;
+
+    fn sanitizer(strategy: SanitizationStrategy, rules: NamingRules) -> MetricNameSanitizer {
+        let config = NameSanitizationConfig {
+            strategy,
+            ..Default::default()
+        };
+        MetricNameSanitizer::new(&config, rules, None).unwrap()
+    }
+
+    fn metric(namespace: Option<&str>, name: &str, tags: Option<MetricTags>) -> Metric {
+        Metric::new(
+            name,
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 1.0 },
+        )
+        .with_namespace(namespace)
+        .with_tags(tags)
+    }
+
+    #[test]
+    fn replaces_invalid_characters() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Replace, NamingRules::PROMETHEUS);
+
+        let sanitized = sanitizer
+            .sanitize(metric(
+                Some("my-app"),
+                "requests.total",
+                Some(metric_tags!("status.code" => "200", "0path" => "/")),
+            ))
+            .unwrap();
+
+        assert_eq!(sanitized.namespace(), Some("my_app"));
+        assert_eq!(sanitized.name(), "requests_total");
+        assert_eq!(
+            sanitized.tags(),
+            Some(&metric_tags!("status_code" => "200", "_0path" => "/"))
+        );
+    }
+
+    #[test]
+    fn keeps_valid_characters_only_invalid_at_start() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Replace, NamingRules::PROMETHEUS);
+
+        let sanitized = sanitizer.sanitize(metric(None, "5xx", None)).unwrap();
+        assert_eq!(sanitized.name(), "_5xx");
+
+        let sanitized = sanitizer
+            .sanitize(metric(Some("http"), "5xx", None))
+            .unwrap();
+        assert_eq!(sanitized.name(), "5xx");
+    }
+
+    #[test]
+    fn removes_invalid_characters() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Remove, NamingRules::DATADOG);
+
+        let sanitized = sanitizer
+            .sanitize(metric(None, "_1 requests-total", None))
+            .unwrap();
+        assert_eq!(sanitized.name(), "requeststotal");
+
+        assert!(sanitizer.sanitize(metric(None, "-_-", None)).is_none());
+    }
+
+    #[test]
+    fn drops_invalid_metrics() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Drop, NamingRules::STATSD);
+
+        assert!(sanitizer
+            .sanitize(metric(Some("app"), "requests.total", None))
+            .is_some());
+        assert!(sanitizer
+            .sanitize(metric(None, "requests|total", None))
+            .is_none());
+        assert!(sanitizer
+            .sanitize(metric(
+                None,
+                "requests",
+                Some(metric_tags!("status code" => "200"))
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn passes_metrics_through_without_strategy() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::None, NamingRules::PROMETHEUS);
+
+        let metric = metric(Some("my-app"), "requests.total", None);
+        assert_eq!(sanitizer.sanitize(metric.clone()), Some(metric));
+    }
+
+    #[test]
+    fn truncates_long_names() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Replace, NamingRules::DATADOG);
+
+        let sanitized = sanitizer
+            .sanitize(metric(Some("app"), &"a".repeat(300), None))
+            .unwrap();
+        assert_eq!(sanitized.name().len(), 196);
+    }
+
+    #[test]
+    fn merges_colliding_tag_keys() {
+        let mut sanitizer = sanitizer(SanitizationStrategy::Replace, NamingRules::PROMETHEUS);
+
+        let sanitized = sanitizer
+            .sanitize(metric(
+                None,
+                "requests",
+                Some(metric_tags!("a.b" => "1", "a-b" => "2")),
+            ))
+            .unwrap();
+        assert_eq!(
+            sanitized.tags().unwrap().keys().collect::<Vec<_>>(),
+            ["a_b"]
+        );
+    }
+
+    #[test]
+    fn applies_default_namespace() {
+        let config = NameSanitizationConfig {
+            strategy: SanitizationStrategy::Replace,
+            ..Default::default()
+        };
+        let mut sanitizer =
+            MetricNameSanitizer::new(&config, NamingRules::PROMETHEUS, Some("my-app".into()))
+                .unwrap();
+
+        let sanitized = sanitizer.sanitize(metric(None, "requests", None)).unwrap();
+        assert_eq!(sanitized.namespace(), Some("my_app"));
+    }
+
+    #[test]
+    fn rejects_invalid_replacement() {
+        let config = NameSanitizationConfig {
+            strategy: SanitizationStrategy::Replace,
+            replacement: ".".into(),
+        };
+        assert!(MetricNameSanitizer::new(&config, NamingRules::PROMETHEUS, None).is_err());
+        assert!(MetricNameSanitizer::new(&config, NamingRules::STATSD, None).is_ok());
+    }
+}
@@ -6,7 +6,21 @@ pub mod compressor;
 pub mod encoding;
 pub mod http;
 pub mod metadata;
+#[cfg(any(
+    feature = "sinks-datadog_metrics",
+    feature = "sinks-prometheus",
+    feature = "sinks-statsd"
+))]
+pub mod metric_names;
+#[cfg(all(feature = "sinks-socket", windows))]
+pub mod named_pipe;
 pub mod normalizer;
+#[cfg(any(
+    feature = "sinks-aws_s3",
+    feature = "sinks-azure_blob",
+    feature = "sinks-gcp"
+))]
+pub mod parquet;
 pub mod partitioner;
 pub mod processed_event;
 pub mod request_builder;
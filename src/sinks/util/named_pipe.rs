@@ -0,0 +1,262 @@
+use std::{path::PathBuf, pin::Pin, time::Duration};
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::{stream::BoxStream, SinkExt, StreamExt};
+use snafu::{ResultExt, Snafu};
+use tokio::{
+    net::windows::named_pipe::{ClientOptions, NamedPipeClient},
+    time::sleep,
+};
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+use vector_core::ByteSizeOf;
+
+use crate::{
+    codecs::Transformer,
+    event::{Event, Finalizable},
+    internal_events::{
+        ConnectionOpen, NamedPipeConnectionEstablished, NamedPipeOutgoingConnectionError,
+        NamedPipeSendError, OpenGauge, SocketMode,
+    },
+    sink::VecSinkExt,
+    sinks::{
+        util::{
+            retries::ExponentialBackoff,
+            socket_bytes_sink::{BytesSink, ShutdownCheck},
+            EncodedEvent, StreamSink,
+        },
+        Healthcheck, VectorSink,
+    },
+};
+
+#[derive(Debug, Snafu)]
+pub enum NamedPipeError {
+    #[snafu(display("Failed connecting to named pipe {}: {}", path.display(), source))]
+    ConnectionError {
+        source: tokio::io::Error,
+        path: PathBuf,
+    },
+}
+
+/// A Windows named pipe sink.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct NamedPipeSinkConfig {
+    /// The name of the named pipe.
+    ///
+    /// The pipe must be created by the server reading from it, which is reconnected to if it
+    /// closes the pipe.
+    #[configurable(metadata(docs::examples = r"\\.\pipe\vector"))]
+    pub path: PathBuf,
+}
+
+impl NamedPipeSinkConfig {
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn build(
+        &self,
+        transformer: Transformer,
+        encoder: impl Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync + 'static,
+    ) -> crate::Result<(VectorSink, Healthcheck)> {
+        let connector = NamedPipeConnector::new(self.path.clone());
+        let sink = NamedPipeSink::new(connector.clone(), transformer, encoder);
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            Box::pin(async move { connector.healthcheck().await }),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NamedPipeConnector {
+    pub path: PathBuf,
+}
+
+impl NamedPipeConnector {
+    const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    const fn fresh_backoff() -> ExponentialBackoff {
+        // TODO: make configurable
+        ExponentialBackoff::from_millis(2)
+            .factor(250)
+            .max_delay(Duration::from_secs(60))
+    }
+
+    fn connect(&self) -> Result<NamedPipeClient, NamedPipeError> {
+        // Opening the pipe fails while all of its instances are busy, which is retried like any
+        // other connection failure.
+        ClientOptions::new()
+            .read(false)
+            .open(&self.path)
+            .context(ConnectionSnafu {
+                path: self.path.clone(),
+            })
+    }
+
+    async fn connect_backoff(&self) -> NamedPipeClient {
+        let mut backoff = Self::fresh_backoff();
+        loop {
+            match self.connect() {
+                Ok(client) => {
+                    emit!(NamedPipeConnectionEstablished { path: &self.path });
+                    return client;
+                }
+                Err(error) => {
+                    emit!(NamedPipeOutgoingConnectionError { error });
+                    sleep(backoff.next().unwrap()).await;
+                }
+            }
+        }
+    }
+
+    async fn healthcheck(&self) -> crate::Result<()> {
+        // The healthcheck takes up an instance of the pipe, which is released once it's dropped.
+        self.connect().map(|_| ()).map_err(Into::into)
+    }
+}
+
+struct NamedPipeSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    connector: NamedPipeConnector,
+    transformer: Transformer,
+    encoder: E,
+}
+
+impl<E> NamedPipeSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    pub const fn new(connector: NamedPipeConnector, transformer: Transformer, encoder: E) -> Self {
+        Self {
+            connector,
+            transformer,
+            encoder,
+        }
+    }
+
+    async fn connect(&mut self) -> BytesSink<NamedPipeClient> {
+        let client = self.connector.connect_backoff().await;
+        BytesSink::new(client, |_| ShutdownCheck::Alive, SocketMode::NamedPipe)
+    }
+}
+
+#[async_trait]
+impl<E> StreamSink<Event> for NamedPipeSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    // Same as UnixSink, more details there.
+    async fn run(mut self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let mut encoder = self.encoder.clone();
+        let transformer = self.transformer.clone();
+        let mut input = input
+            .map(|mut event| {
+                let byte_size = event.size_of();
+
+                transformer.transform(&mut event);
+
+                let finalizers = event.take_finalizers();
+                let mut bytes = BytesMut::new();
+
+                // Errors are handled by `Encoder`.
+                if encoder.encode(event, &mut bytes).is_ok() {
+                    let item = bytes.freeze();
+                    EncodedEvent {
+                        item,
+                        finalizers,
+                        byte_size,
+                    }
+                } else {
+                    EncodedEvent::new(Bytes::new(), 0)
+                }
+            })
+            .peekable();
+
+        while Pin::new(&mut input).peek().await.is_some() {
+            let mut sink = self.connect().await;
+            let _open_token = OpenGauge::new().open(|count| emit!(ConnectionOpen { count }));
+
+            let result = match sink.send_all_peekable(&mut (&mut input).peekable()).await {
+                Ok(()) => sink.close().await,
+                Err(error) => Err(error),
+            };
+
+            if let Err(error) = result {
+                emit!(NamedPipeSendError {
+                    error: &error,
+                    path: &self.connector.path
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use codecs::{encoding::Framer, NewlineDelimitedEncoder, TextSerializerConfig};
+    use tokio::{
+        io::{AsyncBufReadExt, BufReader},
+        net::windows::named_pipe::ServerOptions,
+    };
+
+    use super::*;
+    use crate::{
+        codecs::Encoder,
+        test_util::{
+            components::{assert_sink_compliance, SINK_TAGS},
+            random_lines_with_stream, random_string,
+        },
+    };
+
+    fn pipe_name() -> PathBuf {
+        PathBuf::from(format!(r"\\.\pipe\vector-test-{}", random_string(10)))
+    }
+
+    #[tokio::test]
+    async fn basic_named_pipe_sink() {
+        let num_lines = 1000;
+        let path = pipe_name();
+
+        let server = ServerOptions::new()
+            .first_pipe_instance(true)
+            .create(&path)
+            .unwrap();
+        let receiving = tokio::spawn(async move {
+            server.connect().await.unwrap();
+            let mut lines = BufReader::new(server).lines();
+            let mut received = Vec::new();
+            while let Some(line) = lines.next_line().await.unwrap() {
+                received.push(line);
+            }
+            received
+        });
+
+        let config = NamedPipeSinkConfig::new(path);
+        let (sink, _healthcheck) = config
+            .build(
+                Default::default(),
+                Encoder::<Framer>::new(
+                    NewlineDelimitedEncoder::new().into(),
+                    TextSerializerConfig::default().build().into(),
+                ),
+            )
+            .unwrap();
+
+        let (input_lines, events) = random_lines_with_stream(100, num_lines, None);
+
+        assert_sink_compliance(&SINK_TAGS, async move { sink.run(events).await })
+            .await
+            .expect("Running sink failed");
+
+        assert_eq!(input_lines, receiving.await.unwrap());
+    }
+}
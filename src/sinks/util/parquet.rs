@@ -0,0 +1,676 @@
+//! Parquet encoding of batches of log events, for the sinks writing files to object storage.
+//!
+//! Each batch is encoded as one Parquet file. The columns of the file are either configured,
+//! derived from the schema definition of the events, or inferred from the events of the batch.
+
+use std::{io, num::NonZeroUsize, sync::Arc};
+
+use arrow::{
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    json::reader::{infer_json_schema_from_iterator, ReaderBuilder},
+};
+use chrono::SecondsFormat;
+use codecs::encoding::{Framer, FramingConfig, SerializerConfig};
+use indexmap::IndexMap;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::Compression as ParquetFileCompression,
+    file::properties::{WriterProperties, WriterVersion},
+};
+use value::Kind;
+use vector_config::configurable_component;
+
+use crate::{
+    codecs::{Encoder, EncodingConfig, EncodingConfigWithFraming, SinkType, Transformer},
+    config,
+    event::{Event, LogEvent, Value},
+    internal_events::EncoderNullValuesError,
+    schema,
+    sinks::util::{
+        encoding::{write_all, Encoder as _},
+        Compression,
+    },
+};
+
+/// The content type of Parquet files.
+const PARQUET_CONTENT_TYPE: &str = "application/vnd.apache.parquet";
+
+/// Encoding configuration of the sinks writing files to object storage.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectEncodingConfig {
+    #[configurable(derived)]
+    framing: Option<FramingConfig>,
+
+    /// Configures how events are encoded into raw bytes.
+    ///
+    /// This is required, unless `batch_encoding` is set, in which case it's ignored.
+    encoding: Option<EncodingConfig>,
+
+    #[configurable(derived)]
+    batch_encoding: Option<BatchEncodingConfig>,
+}
+
+impl ObjectEncodingConfig {
+    /// The type of the events that can be encoded.
+    pub fn input_type(&self) -> config::DataType {
+        match (&self.batch_encoding, &self.encoding) {
+            (None, Some(encoding)) => encoding.config().input_type() & config::DataType::Log,
+            _ => config::DataType::Log,
+        }
+    }
+
+    /// Whether the batches are encoded as a whole.
+    pub const fn is_batch(&self) -> bool {
+        self.batch_encoding.is_some()
+    }
+
+    /// Builds the encoder of the batches.
+    pub fn build(&self) -> crate::Result<ObjectEncoder> {
+        match (&self.batch_encoding, &self.encoding) {
+            (Some(batch_encoding), _) => Ok(ObjectEncoder::Batch((
+                batch_encoding.transformer.clone(),
+                batch_encoding.codec.build(),
+            ))),
+            (None, Some(encoding)) => {
+                let transformer = encoding.transformer();
+                let (framer, serializer) = EncodingConfigWithFraming::new(
+                    self.framing.clone(),
+                    encoding.config().clone(),
+                    transformer.clone(),
+                )
+                .build(SinkType::MessageBased)?;
+                Ok(ObjectEncoder::Framed((
+                    transformer,
+                    Encoder::<Framer>::new(framer, serializer),
+                )))
+            }
+            (None, None) => Err("`encoding` must be set, unless `batch_encoding` is".into()),
+        }
+    }
+}
+
+impl<F, S> From<(Option<F>, S)> for ObjectEncodingConfig
+where
+    F: Into<FramingConfig>,
+    S: Into<SerializerConfig>,
+{
+    fn from((framing, encoding): (Option<F>, S)) -> Self {
+        Self {
+            framing: framing.map(Into::into),
+            encoding: Some(encoding.into().into()),
+            batch_encoding: None,
+        }
+    }
+}
+
+impl From<BatchEncodingConfig> for ObjectEncodingConfig {
+    fn from(batch_encoding: BatchEncodingConfig) -> Self {
+        Self {
+            framing: None,
+            encoding: None,
+            batch_encoding: Some(batch_encoding),
+        }
+    }
+}
+
+/// Batch encoding configuration.
+///
+/// Batch encodings encode a whole batch of events as a single file, as opposed to the `encoding`
+/// of each event. Their files are compressed by the encoding itself, so `compression` is ignored.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct BatchEncodingConfig {
+    #[serde(flatten)]
+    pub codec: BatchSerializerConfig,
+
+    #[serde(flatten)]
+    pub transformer: Transformer,
+}
+
+impl From<BatchSerializerConfig> for BatchEncodingConfig {
+    fn from(codec: BatchSerializerConfig) -> Self {
+        Self {
+            codec,
+            transformer: Transformer::default(),
+        }
+    }
+}
+
+/// Configures how batches of events are serialized.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "codec", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The codec to use for encoding batches."))]
+pub enum BatchSerializerConfig {
+    /// Encodes each batch as an [Apache Parquet][apache_parquet] file.
+    ///
+    /// [apache_parquet]: https://parquet.apache.org/
+    Parquet {
+        /// Apache Parquet-specific encoder options.
+        #[serde(default)]
+        parquet: ParquetEncodingOptions,
+    },
+}
+
+impl BatchSerializerConfig {
+    pub fn build(&self) -> ParquetSerializer {
+        match self {
+            Self::Parquet { parquet } => ParquetSerializer::new(parquet),
+        }
+    }
+}
+
+/// Encodes the events of a batch, either one by one or as a whole.
+#[derive(Clone)]
+pub enum ObjectEncoder {
+    Framed((Transformer, Encoder<Framer>)),
+    Batch((Transformer, ParquetSerializer)),
+}
+
+impl ObjectEncoder {
+    /// The compression of the files, as batch encodings compress their files themselves.
+    pub const fn compression(&self, compression: Compression) -> Compression {
+        match self {
+            Self::Framed(_) => compression,
+            Self::Batch(_) => Compression::None,
+        }
+    }
+
+    /// The filename extension of the files, given their compression.
+    pub const fn extension(&self, compression: Compression) -> &'static str {
+        match self {
+            Self::Framed(_) => compression.extension(),
+            Self::Batch(_) => "parquet",
+        }
+    }
+
+    /// The content type of the files, before their compression.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            Self::Framed((_, encoder)) => encoder.content_type(),
+            Self::Batch(_) => PARQUET_CONTENT_TYPE,
+        }
+    }
+}
+
+impl super::encoding::Encoder<Vec<Event>> for ObjectEncoder {
+    fn encode_input(&self, events: Vec<Event>, writer: &mut dyn io::Write) -> io::Result<usize> {
+        match self {
+            Self::Framed(encoder) => encoder.encode_input(events, writer),
+            Self::Batch(encoder) => encoder.encode_input(events, writer),
+        }
+    }
+}
+
+/// Apache Parquet-specific encoder options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ParquetEncodingOptions {
+    /// The columns of the files, and their types, by field name.
+    ///
+    /// Fields of the events that aren't columns are left out of the files, and columns that the
+    /// events lack are null. Values that don't match the type of their column are written as
+    /// nulls, and reported as errors.
+    ///
+    /// If empty, the columns are derived from the schema definition of the events, for the fields
+    /// whose type is known. If the schema definition doesn't describe any field, the columns are
+    /// inferred from the events of each batch, which means the files written under the same prefix
+    /// may have different columns.
+    #[serde(default)]
+    #[configurable(metadata(docs::additional_props_description = "The type of a column."))]
+    #[configurable(metadata(docs::examples = "example_schema()"))]
+    pub schema: IndexMap<String, ParquetColumnType>,
+
+    /// The maximum number of rows of a row group.
+    ///
+    /// Smaller row groups use less memory to write and read, while larger ones compress better and
+    /// allow query engines to skip more data.
+    #[serde(default = "default_row_group_size")]
+    pub row_group_size: NonZeroUsize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: ParquetCompression,
+}
+
+impl Default for ParquetEncodingOptions {
+    fn default() -> Self {
+        Self {
+            schema: IndexMap::new(),
+            row_group_size: default_row_group_size(),
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+fn default_row_group_size() -> NonZeroUsize {
+    NonZeroUsize::new(100_000).expect("static non-zero number")
+}
+
+fn example_schema() -> IndexMap<String, ParquetColumnType> {
+    IndexMap::from([
+        ("timestamp".to_owned(), ParquetColumnType::Timestamp),
+        ("message".to_owned(), ParquetColumnType::String),
+        ("status".to_owned(), ParquetColumnType::Int64),
+    ])
+}
+
+/// The type of a Parquet column.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetColumnType {
+    /// A boolean.
+    Boolean,
+
+    /// A 64-bit signed integer.
+    Int64,
+
+    /// A 64-bit floating point number.
+    ///
+    /// Integers are converted to floating point numbers.
+    Float64,
+
+    /// A UTF-8 string.
+    ///
+    /// Values of other types are converted to strings.
+    String,
+
+    /// A timestamp, with microsecond precision.
+    Timestamp,
+}
+
+impl ParquetColumnType {
+    fn data_type(self) -> DataType {
+        match self {
+            Self::Boolean => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            Self::String => DataType::Utf8,
+            Self::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
+        }
+    }
+
+    /// The column type of the values of the given kind, or `None` if they're only ever null.
+    fn from_kind(kind: &Kind) -> Option<Self> {
+        let column_types = [
+            (kind.contains_boolean(), Self::Boolean),
+            (kind.contains_integer(), Self::Int64),
+            (kind.contains_float(), Self::Float64),
+            (kind.contains_timestamp(), Self::Timestamp),
+            (
+                kind.contains_bytes()
+                    || kind.contains_regex()
+                    || kind.contains_array()
+                    || kind.contains_object(),
+                Self::String,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(contained, column_type)| contained.then_some(column_type))
+        .collect::<Vec<_>>();
+
+        match column_types.as_slice() {
+            [] => None,
+            [column_type] => Some(*column_type),
+            [Self::Int64, Self::Float64] => Some(Self::Float64),
+            _ => Some(Self::String),
+        }
+    }
+
+    fn from_data_type(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Boolean => Self::Boolean,
+            DataType::Int64 => Self::Int64,
+            DataType::Float64 => Self::Float64,
+            _ => Self::String,
+        }
+    }
+
+    /// Converts a value to a JSON value of this column type, or `None` if it doesn't match it.
+    fn json_value(self, value: &Value) -> Option<serde_json::Value> {
+        match (self, value) {
+            (_, Value::Null) => Some(serde_json::Value::Null),
+            (Self::Boolean, Value::Boolean(boolean)) => Some((*boolean).into()),
+            (Self::Int64, Value::Integer(integer)) => Some((*integer).into()),
+            (Self::Float64, Value::Integer(integer)) => Some((*integer as f64).into()),
+            (Self::Float64, Value::Float(float)) => Some(float.into_inner().into()),
+            (Self::String, value) => Some(value.to_string_lossy().into_owned().into()),
+            (Self::Timestamp, Value::Timestamp(timestamp)) => Some(
+                timestamp
+                    .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                    .into(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// The compression of the column chunks of Parquet files.
+///
+/// Unlike the compression of the whole file, this leaves the files readable by query engines.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetCompression {
+    /// No compression.
+    None,
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// [snappy]: https://github.com/google/snappy
+    #[default]
+    Snappy,
+
+    /// [Gzip][gzip] compression.
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+}
+
+impl From<ParquetCompression> for ParquetFileCompression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::None => ParquetFileCompression::UNCOMPRESSED,
+            ParquetCompression::Snappy => ParquetFileCompression::SNAPPY,
+            ParquetCompression::Gzip => ParquetFileCompression::GZIP(Default::default()),
+            ParquetCompression::Zstd => ParquetFileCompression::ZSTD(Default::default()),
+        }
+    }
+}
+
+/// Serializes batches of log events as Parquet files.
+#[derive(Clone, Debug)]
+pub struct ParquetSerializer {
+    columns: Vec<(String, ParquetColumnType)>,
+    row_group_size: usize,
+    properties: WriterProperties,
+}
+
+impl ParquetSerializer {
+    pub fn new(options: &ParquetEncodingOptions) -> Self {
+        let columns = options
+            .schema
+            .iter()
+            .map(|(name, column_type)| (name.clone(), *column_type))
+            .collect();
+        let row_group_size = options.row_group_size.get();
+        let properties = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_1_0)
+            .set_max_row_group_size(row_group_size)
+            .set_compression(options.compression.into())
+            .build();
+
+        Self {
+            columns,
+            row_group_size,
+            properties,
+        }
+    }
+
+    /// Serializes the given events into a Parquet file.
+    ///
+    /// Values that don't match the type of their column are written as nulls. The columns that had
+    /// such values are returned along with the file, with the number of them.
+    pub fn serialize(&self, events: &[LogEvent]) -> io::Result<(Vec<u8>, Vec<(String, usize)>)> {
+        let columns = self.columns(events)?;
+        let schema = Arc::new(Schema::new(
+            columns
+                .iter()
+                .map(|(name, column_type)| Field::new(name, column_type.data_type(), true))
+                .collect::<Vec<_>>(),
+        ));
+
+        let mut nulled = vec![0; columns.len()];
+        let rows = events
+            .iter()
+            .map(|log| {
+                let row = columns
+                    .iter()
+                    .zip(nulled.iter_mut())
+                    .filter_map(|((name, column_type), nulled)| {
+                        let value = log.as_map()?.get(name.as_str())?;
+                        let value = column_type.json_value(value).unwrap_or_else(|| {
+                            *nulled += 1;
+                            serde_json::Value::Null
+                        });
+                        Some((name.clone(), value))
+                    })
+                    .collect();
+                serde_json::Value::Object(row)
+            })
+            .collect::<Vec<_>>();
+
+        let mut decoder = ReaderBuilder::new(Arc::clone(&schema))
+            .with_batch_size(self.row_group_size)
+            .build_decoder()
+            .map_err(invalid_data)?;
+
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, schema, Some(self.properties.clone()))
+            .map_err(invalid_data)?;
+        for rows in rows.chunks(self.row_group_size) {
+            decoder.serialize(rows).map_err(invalid_data)?;
+            if let Some(batch) = decoder.flush().map_err(invalid_data)? {
+                writer.write(&batch).map_err(invalid_data)?;
+            }
+        }
+        writer.close().map_err(invalid_data)?;
+
+        let nulled = columns
+            .into_iter()
+            .zip(nulled)
+            .filter(|(_, count)| *count > 0)
+            .map(|((name, _), count)| (name, count))
+            .collect();
+        Ok((file, nulled))
+    }
+
+    /// The columns of the file of the given events.
+    fn columns(&self, events: &[LogEvent]) -> io::Result<Vec<(String, ParquetColumnType)>> {
+        if !self.columns.is_empty() {
+            return Ok(self.columns.clone());
+        }
+
+        // The events of a batch usually come from the same component, and share its definition.
+        let columns = events
+            .first()
+            .map(|log| definition_columns(log.metadata().schema_definition()))
+            .unwrap_or_default();
+        if !columns.is_empty() {
+            return Ok(columns);
+        }
+
+        let values = events
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema =
+            infer_json_schema_from_iterator(values.into_iter().map(Ok)).map_err(invalid_data)?;
+        Ok(schema
+            .fields()
+            .iter()
+            .map(|field| {
+                (
+                    field.name().clone(),
+                    ParquetColumnType::from_data_type(field.data_type()),
+                )
+            })
+            .collect())
+    }
+}
+
+/// The columns of the fields whose type the schema definition knows.
+fn definition_columns(definition: &schema::Definition) -> Vec<(String, ParquetColumnType)> {
+    definition
+        .event_kind()
+        .as_object()
+        .map(|object| {
+            object
+                .known()
+                .iter()
+                .filter_map(|(field, kind)| {
+                    Some((field.to_string(), ParquetColumnType::from_kind(kind)?))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+impl super::encoding::Encoder<Vec<Event>> for (Transformer, ParquetSerializer) {
+    fn encode_input(&self, events: Vec<Event>, writer: &mut dyn io::Write) -> io::Result<usize> {
+        let count = events.len();
+        let logs = events
+            .into_iter()
+            .map(|mut event| {
+                self.0.transform(&mut event);
+                event.into_log()
+            })
+            .collect::<Vec<_>>();
+
+        let (file, nulled) = self.1.serialize(&logs)?;
+        for (column, count) in nulled {
+            emit!(EncoderNullValuesError {
+                column: &column,
+                count,
+            });
+        }
+        write_all(writer, count, &file)?;
+        Ok(file.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bytes::Bytes;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use vector_core::config::LogNamespace;
+
+    use super::*;
+    use crate::{event::EventMetadata, sinks::util::encoding::Encoder as _};
+
+    fn encode(options: ParquetEncodingOptions, events: Vec<Event>) -> SerializedFileReader<Bytes> {
+        let encoder = (Transformer::default(), ParquetSerializer::new(&options));
+        let mut file = Vec::new();
+        encoder.encode_input(events, &mut file).unwrap();
+        SerializedFileReader::new(Bytes::from(file)).unwrap()
+    }
+
+    fn events(count: usize) -> Vec<Event> {
+        (0..count)
+            .map(|i| {
+                let mut log = LogEvent::from_str_legacy(format!("line {}", i));
+                log.insert("status", 200);
+                Event::from(log)
+            })
+            .collect()
+    }
+
+    fn column_names(reader: &SerializedFileReader<Bytes>) -> Vec<String> {
+        reader
+            .metadata()
+            .file_metadata()
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|column| column.name().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn infers_schema() {
+        let reader = encode(ParquetEncodingOptions::default(), events(10));
+
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 10);
+        let mut columns = column_names(&reader);
+        columns.sort();
+        assert_eq!(columns, vec!["message", "status", "timestamp"]);
+    }
+
+    #[test]
+    fn derives_schema_from_definition() {
+        let definition = schema::Definition::new_with_default_metadata(
+            Kind::object(BTreeMap::from([
+                ("message".into(), Kind::bytes()),
+                ("status".into(), Kind::integer().or_null()),
+                ("tags".into(), Kind::null()),
+            ])),
+            [LogNamespace::Legacy],
+        );
+        let metadata = EventMetadata::default().with_schema_definition(&Arc::new(definition));
+        let log = LogEvent::from_map(
+            BTreeMap::from([
+                ("message".to_owned(), Value::from("line")),
+                ("status".to_owned(), Value::from(200)),
+                ("host".to_owned(), Value::from("localhost")),
+            ]),
+            metadata,
+        );
+
+        let reader = encode(ParquetEncodingOptions::default(), vec![Event::from(log)]);
+
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 1);
+        assert_eq!(column_names(&reader), vec!["message", "status"]);
+    }
+
+    #[test]
+    fn uses_configured_schema_and_row_groups() {
+        let options = ParquetEncodingOptions {
+            schema: IndexMap::from([
+                ("message".to_owned(), ParquetColumnType::String),
+                ("status".to_owned(), ParquetColumnType::Int64),
+                ("host".to_owned(), ParquetColumnType::String),
+            ]),
+            row_group_size: NonZeroUsize::new(4).unwrap(),
+            compression: ParquetCompression::Zstd,
+        };
+        let reader = encode(options, events(10));
+
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 10);
+        assert_eq!(reader.metadata().num_row_groups(), 3);
+        assert_eq!(column_names(&reader), vec!["message", "status", "host"]);
+    }
+
+    #[test]
+    fn nulls_values_not_matching_their_column() {
+        let options = ParquetEncodingOptions {
+            schema: IndexMap::from([
+                ("message".to_owned(), ParquetColumnType::String),
+                ("status".to_owned(), ParquetColumnType::Int64),
+            ]),
+            ..Default::default()
+        };
+        let mut logs = events(3)
+            .into_iter()
+            .map(Event::into_log)
+            .collect::<Vec<_>>();
+        logs[1].insert("status", "ok");
+        logs[2].insert(
+            "message",
+            BTreeMap::from([("text".to_owned(), Value::from("line 2"))]),
+        );
+
+        let (file, nulled) = ParquetSerializer::new(&options).serialize(&logs).unwrap();
+
+        assert_eq!(nulled, vec![("status".to_owned(), 1)]);
+        let reader = SerializedFileReader::new(Bytes::from(file)).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 3);
+    }
+}
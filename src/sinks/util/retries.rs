@@ -32,6 +32,12 @@ pub trait RetryLogic: Clone + Send + Sync + 'static {
         // Treat the default as the request is successful
         RetryAction::Successful
     }
+
+    /// The minimum delay before retrying the request that got the response, such as from a
+    /// `Retry-After` header.
+    fn retry_after(&self, _response: &Self::Response) -> Option<Duration> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -80,11 +86,15 @@ impl<L: RetryLogic> FixedRetryPolicy<L> {
         self.current_duration
     }
 
-    fn build_retry(&self) -> RetryPolicyFuture<L> {
+    fn build_retry(&self, retry_after: Option<Duration>) -> RetryPolicyFuture<L> {
         let policy = self.advance();
-        let delay = Box::pin(sleep(self.backoff()));
+        // The delay requested by the destination is honored up to the maximum backoff.
+        let backoff = retry_after.map_or(self.backoff(), |retry_after| {
+            cmp::min(cmp::max(retry_after, self.backoff()), self.max_duration)
+        });
+        let delay = Box::pin(sleep(backoff));
 
-        debug!(message = "Retrying request.", delay_ms = %self.backoff().as_millis());
+        debug!(message = "Retrying request.", delay_ms = %backoff.as_millis());
         RetryPolicyFuture { delay, policy }
     }
 }
@@ -112,7 +122,7 @@ where
                     }
 
                     warn!(message = "Retrying after response.", reason = %reason, internal_log_rate_limit = true);
-                    Some(self.build_retry())
+                    Some(self.build_retry(self.logic.retry_after(response)))
                 }
 
                 RetryAction::DontRetry(reason) => {
@@ -131,7 +141,7 @@ where
                 if let Some(expected) = error.downcast_ref::<L::Error>() {
                     if self.logic.is_retriable_error(expected) {
                         warn!(message = "Retrying after error.", error = %expected, internal_log_rate_limit = true);
-                        Some(self.build_retry())
+                        Some(self.build_retry(None))
                     } else {
                         error!(
                             message = "Non-retriable error; dropping the request.",
@@ -145,7 +155,7 @@ where
                         message = "Request timed out. If this happens often while the events are actually reaching their destination, try decreasing `batch.max_bytes` and/or using `compression` if applicable. Alternatively `request.timeout_secs` can be increased.",
                         internal_log_rate_limit = true
                     );
-                    Some(self.build_retry())
+                    Some(self.build_retry(None))
                 } else {
                     error!(
                         message = "Unexpected error type; dropping the request.",
@@ -393,6 +403,39 @@ mod tests {
         assert_eq!(Duration::from_secs(10), policy.backoff());
     }
 
+    #[tokio::test]
+    async fn honors_retry_after() {
+        trace_init();
+
+        time::pause();
+
+        let policy = FixedRetryPolicy::new(
+            5,
+            Duration::from_secs(1),
+            Duration::from_secs(10),
+            SvcRetryLogic,
+        );
+
+        let (mut svc, mut handle) = mock::spawn_layer(RetryLayer::new(policy));
+
+        assert_ready_ok!(svc.poll_ready());
+
+        let mut fut = task::spawn(svc.call("hello"));
+        assert_request_eq!(handle, "hello").send_response("retry after 5");
+        assert_pending!(fut.poll());
+
+        // The backoff alone would have retried after one second.
+        time::advance(Duration::from_secs(2)).await;
+        assert_pending!(fut.poll());
+        assert!(handle.poll_request().is_pending());
+
+        time::advance(Duration::from_secs(4)).await;
+        assert_pending!(fut.poll());
+
+        assert_request_eq!(handle, "hello").send_response("world");
+        assert_eq!(fut.await.unwrap(), "world");
+    }
+
     #[derive(Debug, Clone)]
     struct SvcRetryLogic;
 
@@ -403,6 +446,21 @@ mod tests {
         fn is_retriable_error(&self, error: &Self::Error) -> bool {
             error.0
         }
+
+        fn should_retry_response(&self, response: &Self::Response) -> RetryAction {
+            if response.starts_with("retry") {
+                RetryAction::Retry((*response).into())
+            } else {
+                RetryAction::Successful
+            }
+        }
+
+        fn retry_after(&self, response: &Self::Response) -> Option<Duration> {
+            response
+                .strip_prefix("retry after ")
+                .and_then(|secs| secs.parse().ok())
+                .map(Duration::from_secs)
+        }
     }
 
     #[derive(Debug)]
@@ -1,4 +1,4 @@
-use std::{hash::Hash, marker::PhantomData, pin::Pin, sync::Arc, time::Duration};
+use std::{hash::Hash, marker::PhantomData, num::NonZeroU64, pin::Pin, sync::Arc, time::Duration};
 
 use futures_util::stream::{self, BoxStream};
 use serde_with::serde_as;
@@ -15,6 +15,7 @@ use tower::{
 use vector_config::configurable_component;
 
 pub use crate::sinks::util::service::{
+    bandwidth::{BandwidthLimit, BandwidthLimitLayer, BandwidthLimiter},
     concurrency::{concurrency_is_none, Concurrency},
     health::{HealthConfig, HealthLogic, HealthService},
     map::Map,
@@ -24,6 +25,7 @@ use crate::{
     sinks::util::{
         adaptive_concurrency::{
             AdaptiveConcurrencyLimit, AdaptiveConcurrencyLimitLayer, AdaptiveConcurrencySettings,
+            PartitionedAdaptiveConcurrencyLimit, PartitionedAdaptiveConcurrencyLimitLayer,
         },
         retries::{FixedRetryPolicy, RetryLogic},
         service::map::MapLayer,
@@ -32,6 +34,7 @@ use crate::{
     },
 };
 
+mod bandwidth;
 mod concurrency;
 mod health;
 mod map;
@@ -39,6 +42,8 @@ mod map;
 pub type Svc<S, L> = RateLimit<AdaptiveConcurrencyLimit<Retry<FixedRetryPolicy<L>, Timeout<S>>, L>>;
 pub type TowerBatchedSink<S, B, RL> = BatchSink<Svc<S, RL>, B>;
 pub type TowerPartitionSink<S, B, RL, K> = PartitionBatchSink<Svc<S, RL>, B, K>;
+pub type PartitionedSvc<S, L, K> =
+    RateLimit<PartitionedAdaptiveConcurrencyLimit<Retry<FixedRetryPolicy<L>, Timeout<S>>, L, K>>;
 
 // Distributed service types
 pub type DistributedService<S, RL, HL, K, Req> = RateLimit<
@@ -59,6 +64,17 @@ pub trait ServiceBuilderExt<L> {
         settings: TowerRequestSettings,
         retry_logic: RL,
     ) -> ServiceBuilder<Stack<TowerRequestLayer<RL, Request>, L>>;
+
+    fn partitioned_settings<RL, Request, K>(
+        self,
+        settings: TowerRequestSettings,
+        retry_logic: RL,
+    ) -> ServiceBuilder<Stack<PartitionedTowerRequestLayer<RL, Request, K>, L>>;
+
+    fn bandwidth_limit(
+        self,
+        settings: &TowerRequestSettings,
+    ) -> ServiceBuilder<Stack<BandwidthLimitLayer, L>>;
 }
 
 impl<L> ServiceBuilderExt<L> for ServiceBuilder<L> {
@@ -80,6 +96,25 @@ impl<L> ServiceBuilderExt<L> for ServiceBuilder<L> {
             _pd: std::marker::PhantomData,
         })
     }
+
+    fn partitioned_settings<RL, Request, K>(
+        self,
+        settings: TowerRequestSettings,
+        retry_logic: RL,
+    ) -> ServiceBuilder<Stack<PartitionedTowerRequestLayer<RL, Request, K>, L>> {
+        self.layer(PartitionedTowerRequestLayer {
+            settings,
+            retry_logic,
+            _pd: std::marker::PhantomData,
+        })
+    }
+
+    fn bandwidth_limit(
+        self,
+        settings: &TowerRequestSettings,
+    ) -> ServiceBuilder<Stack<BandwidthLimitLayer, L>> {
+        self.layer(BandwidthLimitLayer::new(settings.bandwidth_limit.clone()))
+    }
 }
 
 /// Middleware settings for outbound requests.
@@ -88,7 +123,7 @@ impl<L> ServiceBuilderExt<L> for ServiceBuilder<L> {
 #[serde_as]
 #[configurable_component]
 #[configurable(metadata(docs::advanced))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct TowerRequestConfig {
     #[configurable(derived)]
     #[serde(default = "default_concurrency")]
@@ -132,6 +167,24 @@ pub struct TowerRequestConfig {
     #[serde(default = "default_retry_initial_backoff_secs")]
     pub retry_initial_backoff_secs: Option<u64>,
 
+    /// The maximum number of bytes per second the sink is allowed to send.
+    ///
+    /// Requests are delayed, not dropped, to stay under the limit. The limit is measured on the
+    /// encoded requests, before any compression applied by the transport. Sinks that don't
+    /// measure the size of their requests reject this option.
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_bytes_per_second: Option<NonZeroU64>,
+
+    /// The name of a group of sinks sharing a single `max_bytes_per_second` limit.
+    ///
+    /// All the sinks of a group draw from the same budget, which lets a set of sinks be capped
+    /// as a whole, for example when they all egress over the same link. The group uses the
+    /// `max_bytes_per_second` of the first of its sinks to be built.
+    #[configurable(metadata(docs::examples = "wan_backups"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bandwidth_group: Option<String>,
+
     #[configurable(derived)]
     #[serde(default)]
     pub adaptive_concurrency: AdaptiveConcurrencySettings,
@@ -177,6 +230,8 @@ impl Default for TowerRequestConfig {
             retry_attempts: default_retry_attempts(),
             retry_max_duration_secs: default_retry_max_duration_secs(),
             retry_initial_backoff_secs: default_retry_initial_backoff_secs(),
+            max_bytes_per_second: None,
+            bandwidth_group: None,
             adaptive_concurrency: AdaptiveConcurrencySettings::default(),
         }
     }
@@ -190,6 +245,19 @@ impl TowerRequestConfig {
         }
     }
 
+    /// Fails if a bandwidth limit is set, for sinks that don't measure the size of their requests
+    /// and so can't apply it.
+    pub fn reject_bandwidth_limit(&self, sink: &str) -> crate::Result<()> {
+        if self.max_bytes_per_second.is_some() || self.bandwidth_group.is_some() {
+            return Err(format!(
+                "The `{}` sink doesn't support `request.max_bytes_per_second`",
+                sink
+            )
+            .into());
+        }
+        Ok(())
+    }
+
     pub const fn timeout_secs(mut self, timeout_secs: u64) -> Self {
         self.timeout_secs = Some(timeout_secs);
         self
@@ -258,6 +326,12 @@ impl TowerRequestConfig {
                     .or(default_retry_initial_backoff_secs())
                     .unwrap(),
             ),
+            bandwidth_limit: self
+                .max_bytes_per_second
+                .or(defaults.max_bytes_per_second)
+                .map(|max_bytes_per_second| {
+                    BandwidthLimiter::new(max_bytes_per_second, self.bandwidth_group.as_deref())
+                }),
             adaptive_concurrency: self.adaptive_concurrency,
         }
     }
@@ -272,6 +346,7 @@ pub struct TowerRequestSettings {
     pub retry_attempts: usize,
     pub retry_max_duration_secs: Duration,
     pub retry_initial_backoff_secs: Duration,
+    pub bandwidth_limit: Option<BandwidthLimiter>,
     pub adaptive_concurrency: AdaptiveConcurrencySettings,
 }
 
@@ -355,7 +430,7 @@ impl TowerRequestSettings {
 
         // Build services
         let open = OpenGauge::new();
-        let max_concurrency = services.len() * AdaptiveConcurrencySettings::max_concurrency();
+        let max_concurrency = services.len() * settings.adaptive_concurrency.max_concurrency();
         let services = services
             .into_iter()
             .map(|(endpoint, inner)| {
@@ -428,6 +503,44 @@ where
     }
 }
 
+/// Like [`TowerRequestLayer`], but with a separate adaptive concurrency limit for each partition of
+/// the requests when `adaptive_concurrency.partitioned` is enabled.
+#[derive(Debug, Clone)]
+pub struct PartitionedTowerRequestLayer<L, Request, K> {
+    settings: TowerRequestSettings,
+    retry_logic: L,
+    _pd: PhantomData<(Request, K)>,
+}
+
+impl<S, RL, Request, K> Layer<S> for PartitionedTowerRequestLayer<RL, Request, K>
+where
+    S: Service<Request> + Send + 'static,
+    S::Response: Send + 'static,
+    S::Error: Into<crate::Error> + Send + Sync + 'static,
+    S::Future: Send + 'static,
+    RL: RetryLogic<Response = S::Response> + Send + 'static,
+    Request: Clone + Send + 'static,
+{
+    type Service = PartitionedSvc<S, RL, K>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        let policy = self.settings.retry_policy(self.retry_logic.clone());
+        ServiceBuilder::new()
+            .rate_limit(
+                self.settings.rate_limit_num,
+                self.settings.rate_limit_duration,
+            )
+            .layer(PartitionedAdaptiveConcurrencyLimitLayer::new(
+                self.settings.concurrency,
+                self.settings.adaptive_concurrency,
+                self.retry_logic.clone(),
+            ))
+            .retry(policy)
+            .timeout(self.settings.timeout)
+            .service(inner)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{
@@ -473,6 +586,17 @@ mod tests {
             .expect_err("Invalid concurrency setting didn't fail on negative number");
     }
 
+    #[test]
+    fn max_concurrency_limit_rejects_zero() {
+        let cfg =
+            toml::from_str::<TowerRequestConfig>("adaptive_concurrency.max_concurrency_limit = 8")
+                .expect("Concurrency ceiling setting failed");
+        assert_eq!(cfg.adaptive_concurrency.max_concurrency(), 8);
+
+        toml::from_str::<TowerRequestConfig>("adaptive_concurrency.max_concurrency_limit = 0")
+            .expect_err("Invalid concurrency ceiling didn't fail on zero");
+    }
+
     #[test]
     fn config_merging_defaults_concurrency_to_none_if_unset() {
         let cfg = TowerRequestConfig::default().unwrap_with(&TowerRequestConfig::default());
@@ -480,6 +604,28 @@ mod tests {
         assert_eq!(cfg.concurrency, None);
     }
 
+    #[test]
+    fn bandwidth_limit_is_optional() {
+        let cfg = toml::from_str::<TowerRequestConfig>("").expect("Empty config failed");
+        let settings = cfg.unwrap_with(&TowerRequestConfig::default());
+        assert!(settings.bandwidth_limit.is_none());
+
+        let cfg = toml::from_str::<TowerRequestConfig>(
+            r#"
+                max_bytes_per_second = 1048576
+                bandwidth_group = "wan"
+            "#,
+        )
+        .expect("Bandwidth limit setting failed");
+        assert_eq!(cfg.max_bytes_per_second, NonZeroU64::new(1_048_576));
+        assert_eq!(cfg.bandwidth_group.as_deref(), Some("wan"));
+        let settings = cfg.unwrap_with(&TowerRequestConfig::default());
+        assert!(settings.bandwidth_limit.is_some());
+
+        toml::from_str::<TowerRequestConfig>("max_bytes_per_second = 0")
+            .expect_err("Invalid bandwidth limit didn't fail on zero");
+    }
+
     #[tokio::test]
     async fn partition_sink_retry_concurrency() {
         let cfg = TowerRequestConfig {
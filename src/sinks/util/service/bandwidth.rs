@@ -0,0 +1,226 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    num::NonZeroU64,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{ready, Context, Poll},
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+use tokio::time::{sleep, Instant, Sleep};
+use tower::{Layer, Service};
+use vector_common::request_metadata::MetaDescriptive;
+
+/// The token buckets of the bandwidth groups, shared by every sink configured with the same group.
+///
+/// Weak references are kept so a group is dropped along with the last sink using it, while sinks
+/// rebuilt on reload pick up the bucket of the sinks they replace.
+static GROUPS: Lazy<Mutex<HashMap<String, Weak<Mutex<TokenBucket>>>>> = Lazy::new(Default::default);
+
+/// A token bucket refilled at a constant number of bytes per second, holding up to one second
+/// worth of bytes.
+///
+/// Requests are charged after the fact, which may put the bucket in debt, so requests larger than
+/// the bucket can still be sent. Further requests wait until the debt has been paid back.
+#[derive(Debug)]
+struct TokenBucket {
+    bytes_per_second: f64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(bytes_per_second: NonZeroU64) -> Self {
+        let bytes_per_second = bytes_per_second.get() as f64;
+        Self {
+            bytes_per_second,
+            tokens: bytes_per_second,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.bytes_per_second).min(self.bytes_per_second);
+        self.refilled_at = now;
+    }
+
+    /// The time left until the bucket is out of debt, if it is in debt.
+    fn debt_delay(&mut self) -> Option<Duration> {
+        self.refill();
+        (self.tokens < 0.0).then(|| Duration::from_secs_f64(-self.tokens / self.bytes_per_second))
+    }
+
+    fn consume(&mut self, bytes: usize) {
+        self.refill();
+        self.tokens -= bytes as f64;
+    }
+}
+
+/// Limits the number of bytes per second sent by one or more sinks.
+#[derive(Clone, Debug)]
+pub struct BandwidthLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+impl BandwidthLimiter {
+    /// Creates a limiter, or joins the limiter of the given group if it already exists.
+    ///
+    /// All the sinks of a group share the limit of the sink that created the group.
+    pub fn new(bytes_per_second: NonZeroU64, group: Option<&str>) -> Self {
+        let Some(group) = group else {
+            return Self {
+                bucket: Arc::new(Mutex::new(TokenBucket::new(bytes_per_second))),
+            };
+        };
+
+        let mut groups = GROUPS.lock().expect("bandwidth groups lock poisoned");
+        groups.retain(|_, bucket| bucket.strong_count() > 0);
+
+        if let Some(bucket) = groups.get(group).and_then(Weak::upgrade) {
+            let group_bytes_per_second = bucket
+                .lock()
+                .expect("bandwidth bucket lock poisoned")
+                .bytes_per_second;
+            if group_bytes_per_second != bytes_per_second.get() as f64 {
+                warn!(
+                    message = "Sinks of the same bandwidth group have different limits, using the limit the group was created with.",
+                    group,
+                    max_bytes_per_second = group_bytes_per_second,
+                );
+            }
+            return Self { bucket };
+        }
+
+        let bucket = Arc::new(Mutex::new(TokenBucket::new(bytes_per_second)));
+        groups.insert(group.to_owned(), Arc::downgrade(&bucket));
+        Self { bucket }
+    }
+
+    fn debt_delay(&self) -> Option<Duration> {
+        self.bucket
+            .lock()
+            .expect("bandwidth bucket lock poisoned")
+            .debt_delay()
+    }
+
+    fn consume(&self, bytes: usize) {
+        self.bucket
+            .lock()
+            .expect("bandwidth bucket lock poisoned")
+            .consume(bytes);
+    }
+
+    /// Waits until the limit allows sending more bytes, then charges the given number of bytes.
+    pub async fn acquire(&self, bytes: usize) {
+        while let Some(delay) = self.debt_delay() {
+            sleep(delay).await;
+        }
+        self.consume(bytes);
+    }
+}
+
+/// Applies an optional [`BandwidthLimiter`] to requests, based on their encoded size.
+#[derive(Clone, Debug)]
+pub struct BandwidthLimitLayer {
+    limiter: Option<BandwidthLimiter>,
+}
+
+impl BandwidthLimitLayer {
+    pub const fn new(limiter: Option<BandwidthLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for BandwidthLimitLayer {
+    type Service = BandwidthLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BandwidthLimit {
+            inner,
+            limiter: self.limiter.clone(),
+            sleep: None,
+        }
+    }
+}
+
+/// A service that isn't ready for as long as its bandwidth limiter is in debt.
+pub struct BandwidthLimit<S> {
+    inner: S,
+    limiter: Option<BandwidthLimiter>,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S, Request> Service<Request> for BandwidthLimit<S>
+where
+    S: Service<Request>,
+    Request: MetaDescriptive,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        if let Some(limiter) = &self.limiter {
+            loop {
+                if let Some(sleep) = self.sleep.as_mut() {
+                    ready!(sleep.as_mut().poll(cx));
+                    self.sleep = None;
+                }
+                match limiter.debt_delay() {
+                    Some(delay) => self.sleep = Some(Box::pin(sleep(delay))),
+                    None => break,
+                }
+            }
+        }
+
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        if let Some(limiter) = &self.limiter {
+            limiter.consume(request.get_metadata().request_encoded_size());
+        }
+        self.inner.call(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(bytes_per_second: u64, group: Option<&str>) -> BandwidthLimiter {
+        BandwidthLimiter::new(NonZeroU64::new(bytes_per_second).unwrap(), group)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_debt_to_be_paid_back() {
+        let limiter = limiter(100, None);
+
+        let start = Instant::now();
+        limiter.acquire(300).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // The bucket started full, so 200 bytes are owed after the first request.
+        limiter.acquire(50).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn groups_share_a_limit() {
+        let first = limiter(100, Some("test-wan"));
+        let second = limiter(1_000, Some("test-wan"));
+        let other = limiter(100, Some("test-lan"));
+
+        let start = Instant::now();
+        first.acquire(200).await;
+        other.acquire(200).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        second.acquire(1).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+}
@@ -4,16 +4,22 @@ use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
 use futures::{stream::BoxStream, SinkExt, StreamExt};
 use snafu::{ResultExt, Snafu};
-use tokio::{net::UnixStream, time::sleep};
+use tokio::{
+    net::{UnixDatagram, UnixStream},
+    time::sleep,
+};
 use tokio_util::codec::Encoder;
+use vector_common::internal_event::{
+    ByteSize, BytesSent, InternalEventHandle, Protocol, Registered,
+};
 use vector_config::configurable_component;
-use vector_core::ByteSizeOf;
+use vector_core::{ByteSizeOf, EstimatedJsonEncodedSizeOf};
 
 use crate::{
     codecs::Transformer,
-    event::{Event, Finalizable},
+    event::{Event, EventStatus, Finalizable},
     internal_events::{
-        ConnectionOpen, OpenGauge, SocketMode, UnixSocketConnectionEstablished,
+        ConnectionOpen, OpenGauge, SocketEventsSent, SocketMode, UnixSocketConnectionEstablished,
         UnixSocketOutgoingConnectionError, UnixSocketSendError,
     },
     sink::VecSinkExt,
@@ -64,6 +70,20 @@ impl UnixSinkConfig {
             Box::pin(async move { connector.healthcheck().await }),
         ))
     }
+
+    /// Builds a sink sending each event as a datagram, rather than over a stream.
+    pub fn build_datagram(
+        &self,
+        transformer: Transformer,
+        encoder: impl Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync + 'static,
+    ) -> crate::Result<(VectorSink, Healthcheck)> {
+        let connector = UnixConnector::new(self.path.clone());
+        let sink = UnixDatagramSink::new(connector.clone(), transformer, encoder);
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            Box::pin(async move { connector.healthcheck_datagram().await }),
+        ))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -110,6 +130,36 @@ impl UnixConnector {
     async fn healthcheck(&self) -> crate::Result<()> {
         self.connect().await.map(|_| ()).map_err(Into::into)
     }
+
+    fn connect_datagram(&self) -> Result<UnixDatagram, UnixError> {
+        let socket = UnixDatagram::unbound().context(ConnectionSnafu {
+            path: self.path.clone(),
+        })?;
+        socket.connect(&self.path).context(ConnectionSnafu {
+            path: self.path.clone(),
+        })?;
+        Ok(socket)
+    }
+
+    async fn connect_datagram_backoff(&self) -> UnixDatagram {
+        let mut backoff = Self::fresh_backoff();
+        loop {
+            match self.connect_datagram() {
+                Ok(socket) => {
+                    emit!(UnixSocketConnectionEstablished { path: &self.path });
+                    return socket;
+                }
+                Err(error) => {
+                    emit!(UnixSocketOutgoingConnectionError { error });
+                    sleep(backoff.next().unwrap()).await;
+                }
+            }
+        }
+    }
+
+    async fn healthcheck_datagram(&self) -> crate::Result<()> {
+        self.connect_datagram().map(|_| ()).map_err(Into::into)
+    }
 }
 
 struct UnixSink<E>
@@ -192,10 +242,86 @@ where
     }
 }
 
+struct UnixDatagramSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    connector: UnixConnector,
+    transformer: Transformer,
+    encoder: E,
+    bytes_sent: Registered<BytesSent>,
+}
+
+impl<E> UnixDatagramSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    fn new(connector: UnixConnector, transformer: Transformer, encoder: E) -> Self {
+        Self {
+            connector,
+            transformer,
+            encoder,
+            bytes_sent: register!(BytesSent::from(Protocol::UNIX)),
+        }
+    }
+}
+
+#[async_trait]
+impl<E> StreamSink<Event> for UnixDatagramSink<E>
+where
+    E: Encoder<Event, Error = codecs::encoding::Error> + Clone + Send + Sync,
+{
+    // Same as UdpSink, more details there.
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let mut input = input.peekable();
+
+        let mut encoder = self.encoder.clone();
+        while Pin::new(&mut input).peek().await.is_some() {
+            let socket = self.connector.connect_datagram_backoff().await;
+            while let Some(mut event) = input.next().await {
+                let byte_size = event.estimated_json_encoded_size_of();
+
+                self.transformer.transform(&mut event);
+
+                let finalizers = event.take_finalizers();
+                let mut bytes = BytesMut::new();
+
+                // Errors are handled by `Encoder`.
+                if encoder.encode(event, &mut bytes).is_err() {
+                    continue;
+                }
+
+                match socket.send(&bytes).await {
+                    Ok(_) => {
+                        emit!(SocketEventsSent {
+                            mode: SocketMode::Unix,
+                            count: 1,
+                            byte_size,
+                        });
+
+                        self.bytes_sent.emit(ByteSize(bytes.len()));
+                        finalizers.update_status(EventStatus::Delivered);
+                    }
+                    Err(error) => {
+                        emit!(UnixSocketSendError {
+                            error: &error,
+                            path: &self.connector.path
+                        });
+                        finalizers.update_status(EventStatus::Errored);
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use codecs::{encoding::Framer, NewlineDelimitedEncoder, TextSerializerConfig};
-    use tokio::net::UnixListener;
+    use tokio::net::{UnixDatagram, UnixListener};
 
     use super::*;
     use crate::{
@@ -269,4 +395,39 @@ mod tests {
         // Receive the data sent by the Sink to the receiver
         assert_eq!(input_lines, receiver.await);
     }
+
+    #[tokio::test]
+    async fn basic_unix_datagram_sink() {
+        let num_lines = 1000;
+        let out_path = temp_uds_path("unix_datagram_test");
+
+        // Set up the receiver first, as the sink blocks once the queue of the socket is full.
+        let receiver = UnixDatagram::bind(&out_path).unwrap();
+        let receiving = tokio::spawn(async move {
+            let mut buf = [0; 256];
+            let mut lines = Vec::new();
+            for _ in 0..num_lines {
+                let size = receiver.recv(&mut buf).await.unwrap();
+                lines.push(String::from_utf8_lossy(&buf[..size]).into_owned());
+            }
+            lines
+        });
+
+        let config = UnixSinkConfig::new(out_path);
+        let (sink, healthcheck) = config
+            .build_datagram(
+                Default::default(),
+                Encoder::<()>::new(TextSerializerConfig::default().build().into()),
+            )
+            .unwrap();
+        healthcheck.await.unwrap();
+
+        let (input_lines, events) = random_lines_with_stream(100, num_lines, None);
+
+        assert_sink_compliance(&SINK_TAGS, async move { sink.run(events).await })
+            .await
+            .expect("Running sink failed");
+
+        assert_eq!(input_lines, receiving.await.unwrap());
+    }
 }
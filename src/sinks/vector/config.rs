@@ -84,6 +84,19 @@ impl VectorConfig {
         let addr = addr.to_string();
         default_config(addr.as_str())
     }
+
+    /// Creates a `VectorConfig` for forwarding events to another Vector instance in a cluster.
+    pub(crate) fn for_peer(
+        address: &str,
+        compression: bool,
+        tls: Option<TlsEnableableConfig>,
+    ) -> Self {
+        VectorConfig {
+            compression,
+            tls,
+            ..default_config(address)
+        }
+    }
 }
 
 impl GenerateConfig for VectorConfig {
@@ -125,6 +138,7 @@ impl SinkConfig for VectorConfig {
         let batch_settings = self.batch.into_batcher_settings()?;
 
         let service = ServiceBuilder::new()
+            .bandwidth_limit(&request_settings)
             .settings(request_settings, VectorGrpcRetryLogic)
             .service(service);
 
@@ -204,7 +218,7 @@ pub fn with_default_scheme(address: &str, tls: bool) -> crate::Result<Uri> {
     }
 }
 
-fn new_client(
+pub(crate) fn new_client(
     tls_settings: &MaybeTlsSettings,
     proxy_config: &ProxyConfig,
 ) -> crate::Result<hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>> {
@@ -68,10 +68,7 @@ impl VectorService {
         compression: bool,
     ) -> Self {
         let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
-        let mut proto_client = proto_vector::Client::new(HyperSvc {
-            uri,
-            client: hyper_client,
-        });
+        let mut proto_client = proto_vector::Client::new(HyperSvc::new(uri, hyper_client));
 
         if compression {
             proto_client = proto_client.send_compressed(tonic::codec::CompressionEncoding::Gzip);
@@ -135,6 +132,15 @@ pub struct HyperSvc {
     client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
 }
 
+impl HyperSvc {
+    pub fn new(
+        uri: Uri,
+        client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+    ) -> Self {
+        Self { uri, client }
+    }
+}
+
 impl Service<hyper::Request<BoxBody>> for HyperSvc {
     type Response = hyper::Response<hyper::Body>;
     type Error = hyper::Error;
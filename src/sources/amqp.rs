@@ -127,7 +127,7 @@ fn default_offset_key() -> OptionalValuePath {
 impl_generate_config_from_default!(AmqpSourceConfig);
 
 impl AmqpSourceConfig {
-    fn decoder(&self, log_namespace: LogNamespace) -> Decoder {
+    fn decoder(&self, log_namespace: LogNamespace) -> crate::Result<Decoder> {
         DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build()
     }
 }
@@ -212,6 +212,7 @@ pub(crate) async fn amqp_source(
     acknowledgements: bool,
 ) -> crate::Result<super::Source> {
     let config = config.clone();
+    let decoder = config.decoder(log_namespace)?;
     let (_conn, channel) = config
         .connection
         .connect()
@@ -220,6 +221,7 @@ pub(crate) async fn amqp_source(
 
     Ok(Box::pin(run_amqp_source(
         config,
+        decoder,
         shutdown,
         out,
         channel,
@@ -311,13 +313,14 @@ fn populate_event(
 /// Receives an event from `AMQP` and pushes it along the pipeline.
 async fn receive_event(
     config: &AmqpSourceConfig,
+    decoder: &Decoder,
     out: &mut SourceSender,
     log_namespace: LogNamespace,
     finalizer: Option<&UnorderedFinalizer<FinalizerEntry>>,
     msg: Delivery,
 ) -> Result<(), ()> {
     let payload = Cursor::new(Bytes::copy_from_slice(&msg.data));
-    let mut stream = FramedRead::new(payload, config.decoder(log_namespace));
+    let mut stream = FramedRead::new(payload, decoder.clone());
 
     // Extract timestamp from AMQP message
     let timestamp = msg
@@ -417,6 +420,7 @@ async fn finalize_event_stream(
 /// Runs the `AMQP` source involving the main loop pulling data from the server.
 async fn run_amqp_source(
     config: AmqpSourceConfig,
+    decoder: Decoder,
     shutdown: ShutdownSignal,
     mut out: SourceSender,
     channel: Channel,
@@ -456,7 +460,7 @@ async fn run_amqp_source(
                             return Err(());
                         }
                         Ok(msg) => {
-                            receive_event(&config, &mut out, log_namespace, finalizer.as_ref(), msg).await?
+                            receive_event(&config, &decoder, &mut out, log_namespace, finalizer.as_ref(), msg).await?
                         }
                     }
                 } else {
@@ -51,10 +51,18 @@ pub enum RequestError {
     },
     #[snafu(display("Unsupported protocol version: {}", version))]
     UnsupportedProtocolVersion { version: String },
-    #[snafu(display("Delivery errored"))]
-    DeliveryErrored { request_id: String },
-    #[snafu(display("Delivery failed"))]
-    DeliveryFailed { request_id: String },
+    #[snafu(display("Delivery errored for {} of {} records", failed, total))]
+    DeliveryErrored {
+        request_id: String,
+        failed: usize,
+        total: usize,
+    },
+    #[snafu(display("Delivery failed for {} of {} records", failed, total))]
+    DeliveryFailed {
+        request_id: String,
+        failed: usize,
+        total: usize,
+    },
 }
 
 impl warp::reject::Reject for RequestError {}
@@ -87,8 +95,8 @@ impl RequestError {
             Decode { ref request_id, .. } => Some(request_id),
             ShuttingDown { ref request_id, .. } => Some(request_id),
             UnsupportedProtocolVersion { .. } => None,
-            DeliveryErrored { ref request_id } => Some(request_id),
-            DeliveryFailed { ref request_id } => Some(request_id),
+            DeliveryErrored { ref request_id, .. } => Some(request_id),
+            DeliveryFailed { ref request_id, .. } => Some(request_id),
         }
     }
 }
@@ -21,10 +21,12 @@ use crate::{
 };
 
 /// Handles routing of incoming HTTP requests from AWS Kinesis Firehose
+#[allow(clippy::too_many_arguments)]
 pub fn firehose(
     access_keys: Vec<String>,
     store_access_key: bool,
     record_compression: Compression,
+    deaggregate_records: bool,
     decoder: codecs::Decoder,
     acknowledgements: bool,
     out: SourceSender,
@@ -34,8 +36,10 @@ pub fn firehose(
     let context = handlers::Context {
         compression: record_compression,
         store_access_key,
+        deaggregate_records,
         decoder,
         acknowledgements,
+        delivered_records: Default::default(),
         bytes_received,
         out,
         log_namespace,
@@ -1,11 +1,16 @@
-use std::io::Read;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 use bytes::Bytes;
 use chrono::Utc;
 use codecs::StreamDecodingError;
 use flate2::read::MultiGzDecoder;
-use futures::StreamExt;
+use futures::{future::join_all, StreamExt};
 use lookup::{metadata_path, path, PathPrefix};
 use snafu::{ResultExt, Snafu};
 use tokio_util::codec::FramedRead;
@@ -25,6 +30,7 @@ use warp::reject;
 
 use super::{
     errors::{ParseRecordsSnafu, RequestError},
+    kpl,
     models::{EncodedFirehoseRecord, FirehoseRequest, FirehoseResponse},
     Compression,
 };
@@ -43,13 +49,49 @@ use crate::{
 pub(super) struct Context {
     pub(super) compression: Compression,
     pub(super) store_access_key: bool,
+    pub(super) deaggregate_records: bool,
     pub(super) decoder: Decoder,
     pub(super) acknowledgements: bool,
+    pub(super) delivered_records: DeliveredRecords,
     pub(super) bytes_received: Registered<BytesReceived>,
     pub(super) out: SourceSender,
     pub(super) log_namespace: LogNamespace,
 }
 
+/// How long to remember which records of a partially failed request were delivered.
+///
+/// This matches the maximum retry duration that can be configured for a Firehose HTTP endpoint
+/// destination.
+const DELIVERED_RECORDS_TTL: Duration = Duration::from_secs(7200);
+
+/// Tracks the records of partially failed requests that were delivered successfully.
+///
+/// Firehose retries a failed request in its entirety, reusing the same request ID. Remembering
+/// which records were already delivered lets retries skip them, so that only the records Vector
+/// failed to deliver are sent downstream again.
+#[derive(Clone, Default)]
+pub(super) struct DeliveredRecords(Arc<Mutex<HashMap<String, (Instant, HashSet<usize>)>>>);
+
+impl DeliveredRecords {
+    /// Takes the indices of the records of `request_id` delivered in previous attempts.
+    pub(super) fn take(&self, request_id: &str) -> HashSet<usize> {
+        self.0
+            .lock()
+            .expect("mutex poisoned")
+            .remove(request_id)
+            .map(|(_, delivered)| delivered)
+            .unwrap_or_default()
+    }
+
+    /// Records the indices of the records of `request_id` that have been delivered.
+    pub(super) fn insert(&self, request_id: String, delivered: HashSet<usize>) {
+        let now = Instant::now();
+        let mut requests = self.0.lock().expect("mutex poisoned");
+        requests.retain(|_, (inserted, _)| now.duration_since(*inserted) < DELIVERED_RECORDS_TTL);
+        requests.insert(request_id, (now, delivered));
+    }
+}
+
 /// Publishes decoded events from the FirehoseRequest to the pipeline
 pub(super) async fn firehose(
     request_id: String,
@@ -60,129 +102,177 @@ pub(super) async fn firehose(
     let log_namespace = context.log_namespace;
     let events_received = register!(EventsReceived);
 
-    for record in request.records {
-        let bytes = decode_record(&record, context.compression)
+    let previously_delivered = context.delivered_records.take(&request_id);
+    let mut pending = Vec::new();
+
+    for (index, record) in request.records.iter().enumerate() {
+        if previously_delivered.contains(&index) {
+            continue;
+        }
+
+        let bytes = decode_record(record, context.compression)
             .with_context(|_| ParseRecordsSnafu {
                 request_id: request_id.clone(),
             })
             .map_err(reject::custom)?;
         context.bytes_received.emit(ByteSize(bytes.len()));
 
-        let mut stream = FramedRead::new(bytes.as_ref(), context.decoder.clone());
-        loop {
-            match stream.next().await {
-                Some(Ok((mut events, _byte_size))) => {
-                    events_received.emit(CountByteSize(
-                        events.len(),
-                        events.estimated_json_encoded_size_of(),
-                    ));
-
-                    let (batch, receiver) = context
-                        .acknowledgements
-                        .then(|| {
-                            let (batch, receiver) = BatchNotifier::new_with_receiver();
-                            (Some(batch), Some(receiver))
-                        })
-                        .unwrap_or((None, None));
-
-                    let now = Utc::now();
-                    for event in &mut events {
-                        if let Some(batch) = &batch {
-                            event.add_batch_notifier(batch.clone());
-                        }
-                        if let Event::Log(ref mut log) = event {
-                            log_namespace.insert_vector_metadata(
-                                log,
-                                Some(log_schema().source_type_key()),
-                                path!("source_type"),
-                                Bytes::from_static(AwsKinesisFirehoseConfig::NAME.as_bytes()),
-                            );
-                            // This handles the transition from the original timestamp logic. Originally the
-                            // `timestamp_key` was always populated by the `request.timestamp` time.
-                            match log_namespace {
-                                LogNamespace::Vector => {
-                                    log.insert(metadata_path!("vector", "ingest_timestamp"), now);
-                                    log.insert(
-                                        metadata_path!(AwsKinesisFirehoseConfig::NAME, "timestamp"),
-                                        request.timestamp,
-                                    );
-                                }
-                                LogNamespace::Legacy => {
-                                    if let Some(timestamp_key) = log_schema().timestamp_key() {
-                                        log.try_insert(
-                                            (PathPrefix::Event, timestamp_key),
+        let payloads = if context.deaggregate_records {
+            kpl::deaggregate(bytes)
+        } else {
+            vec![bytes]
+        };
+
+        // Each record gets its own batch notifier, so that its delivery status can be tracked
+        // independently of the other records in the request.
+        let (batch, receiver) = context
+            .acknowledgements
+            .then(|| {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                (Some(batch), Some(receiver))
+            })
+            .unwrap_or((None, None));
+
+        for payload in payloads {
+            let mut stream = FramedRead::new(payload.as_ref(), context.decoder.clone());
+            loop {
+                match stream.next().await {
+                    Some(Ok((mut events, _byte_size))) => {
+                        events_received.emit(CountByteSize(
+                            events.len(),
+                            events.estimated_json_encoded_size_of(),
+                        ));
+
+                        let now = Utc::now();
+                        for event in &mut events {
+                            if let Some(batch) = &batch {
+                                event.add_batch_notifier(batch.clone());
+                            }
+                            if let Event::Log(ref mut log) = event {
+                                log_namespace.insert_vector_metadata(
+                                    log,
+                                    Some(log_schema().source_type_key()),
+                                    path!("source_type"),
+                                    Bytes::from_static(AwsKinesisFirehoseConfig::NAME.as_bytes()),
+                                );
+                                // This handles the transition from the original timestamp logic. Originally the
+                                // `timestamp_key` was always populated by the `request.timestamp` time.
+                                match log_namespace {
+                                    LogNamespace::Vector => {
+                                        log.insert(
+                                            metadata_path!("vector", "ingest_timestamp"),
+                                            now,
+                                        );
+                                        log.insert(
+                                            metadata_path!(
+                                                AwsKinesisFirehoseConfig::NAME,
+                                                "timestamp"
+                                            ),
                                             request.timestamp,
                                         );
                                     }
-                                }
-                            };
-
-                            log_namespace.insert_source_metadata(
-                                AwsKinesisFirehoseConfig::NAME,
-                                log,
-                                Some(LegacyKey::InsertIfEmpty(path!("request_id"))),
-                                path!("request_id"),
-                                request_id.to_owned(),
-                            );
-                            log_namespace.insert_source_metadata(
-                                AwsKinesisFirehoseConfig::NAME,
-                                log,
-                                Some(LegacyKey::InsertIfEmpty(path!("source_arn"))),
-                                path!("source_arn"),
-                                source_arn.to_owned(),
-                            );
-
-                            if context.store_access_key {
-                                if let Some(access_key) = &request.access_key {
-                                    log.metadata_mut().secrets_mut().insert_secret(
-                                        "aws_kinesis_firehose_access_key",
-                                        access_key,
-                                    );
+                                    LogNamespace::Legacy => {
+                                        if let Some(timestamp_key) = log_schema().timestamp_key() {
+                                            log.try_insert(
+                                                (PathPrefix::Event, timestamp_key),
+                                                request.timestamp,
+                                            );
+                                        }
+                                    }
+                                };
+
+                                log_namespace.insert_source_metadata(
+                                    AwsKinesisFirehoseConfig::NAME,
+                                    log,
+                                    Some(LegacyKey::InsertIfEmpty(path!("request_id"))),
+                                    path!("request_id"),
+                                    request_id.to_owned(),
+                                );
+                                log_namespace.insert_source_metadata(
+                                    AwsKinesisFirehoseConfig::NAME,
+                                    log,
+                                    Some(LegacyKey::InsertIfEmpty(path!("source_arn"))),
+                                    path!("source_arn"),
+                                    source_arn.to_owned(),
+                                );
+
+                                if context.store_access_key {
+                                    if let Some(access_key) = &request.access_key {
+                                        log.metadata_mut().secrets_mut().insert_secret(
+                                            "aws_kinesis_firehose_access_key",
+                                            access_key,
+                                        );
+                                    }
                                 }
                             }
                         }
-                    }
 
-                    let count = events.len();
-                    if let Err(error) = context.out.send_batch(events).await {
-                        emit!(StreamClosedError {
-                            error: error.clone(),
-                            count,
-                        });
-                        let error = RequestError::ShuttingDown {
-                            request_id: request_id.clone(),
-                            source: error,
-                        };
-                        warp::reject::custom(error);
-                    }
-
-                    drop(batch);
-                    if let Some(receiver) = receiver {
-                        match receiver.await {
-                            BatchStatus::Delivered => Ok(()),
-                            BatchStatus::Rejected => {
-                                Err(warp::reject::custom(RequestError::DeliveryFailed {
-                                    request_id: request_id.clone(),
-                                }))
-                            }
-                            BatchStatus::Errored => {
-                                Err(warp::reject::custom(RequestError::DeliveryErrored {
-                                    request_id: request_id.clone(),
-                                }))
-                            }
-                        }?;
+                        let count = events.len();
+                        if let Err(error) = context.out.send_batch(events).await {
+                            emit!(StreamClosedError {
+                                error: error.clone(),
+                                count,
+                            });
+                            return Err(warp::reject::custom(RequestError::ShuttingDown {
+                                request_id: request_id.clone(),
+                                source: error,
+                            }));
+                        }
                     }
-                }
-                Some(Err(error)) => {
-                    // Error is logged by `crate::codecs::Decoder`, no further
-                    // handling is needed here.
-                    if !error.can_continue() {
-                        break;
+                    Some(Err(error)) => {
+                        // Error is logged by `crate::codecs::Decoder`, no further
+                        // handling is needed here.
+                        if !error.can_continue() {
+                            break;
+                        }
                     }
+                    None => break,
                 }
-                None => break,
             }
         }
+
+        drop(batch);
+        if let Some(receiver) = receiver {
+            pending.push(async move { (index, receiver.await) });
+        }
+    }
+
+    let statuses = join_all(pending).await;
+    let failed = statuses
+        .iter()
+        .filter(|(_, status)| *status != BatchStatus::Delivered)
+        .count();
+
+    if failed > 0 {
+        let total = request.records.len();
+        let errored = statuses
+            .iter()
+            .any(|(_, status)| *status == BatchStatus::Errored);
+
+        let delivered = statuses
+            .into_iter()
+            .filter(|(_, status)| *status == BatchStatus::Delivered)
+            .map(|(index, _)| index)
+            .chain(previously_delivered)
+            .collect();
+        context
+            .delivered_records
+            .insert(request_id.clone(), delivered);
+
+        let request_id = request_id.clone();
+        return Err(warp::reject::custom(if errored {
+            RequestError::DeliveryErrored {
+                request_id,
+                failed,
+                total,
+            }
+        } else {
+            RequestError::DeliveryFailed {
+                request_id,
+                failed,
+                total,
+            }
+        }));
     }
 
     Ok(warp::reply::json(&FirehoseResponse {
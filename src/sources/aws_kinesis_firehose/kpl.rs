@@ -0,0 +1,98 @@
+//! De-aggregation of records produced by the Amazon Kinesis Producer Library (KPL).
+//!
+//! <https://github.com/awslabs/amazon-kinesis-producer/blob/master/aggregation-format.md>
+use bytes::Bytes;
+use md5::{Digest, Md5};
+use prost::Message;
+
+#[allow(warnings, clippy::all, clippy::pedantic)]
+mod proto {
+    include!(concat!(env!("OUT_DIR"), "/kpl.rs"));
+}
+
+const MAGIC: [u8; 4] = [0xF3, 0x89, 0x9A, 0xC2];
+const DIGEST_LEN: usize = 16;
+
+/// Splits a KPL-aggregated record into the user records it contains.
+///
+/// Records that are not in the aggregation format, or whose digest does not match, are returned
+/// as-is, matching the behavior of the Kinesis Client Library.
+pub(super) fn deaggregate(data: Bytes) -> Vec<Bytes> {
+    try_deaggregate(&data).unwrap_or_else(|| vec![data])
+}
+
+fn try_deaggregate(data: &[u8]) -> Option<Vec<Bytes>> {
+    let body = data.strip_prefix(&MAGIC)?;
+    if body.len() < DIGEST_LEN {
+        return None;
+    }
+
+    let (message, digest) = body.split_at(body.len() - DIGEST_LEN);
+    if Md5::digest(message).as_slice() != digest {
+        return None;
+    }
+
+    let aggregated = proto::AggregatedRecord::decode(message).ok()?;
+    Some(
+        aggregated
+            .records
+            .into_iter()
+            .map(|record| Bytes::from(record.data))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+pub(super) fn aggregate(records: &[&[u8]]) -> Vec<u8> {
+    let message = proto::AggregatedRecord {
+        partition_key_table: vec!["key".to_owned()],
+        explicit_hash_key_table: vec![],
+        records: records
+            .iter()
+            .map(|data| proto::Record {
+                partition_key_index: 0,
+                explicit_hash_key_index: None,
+                data: data.to_vec(),
+                tags: vec![],
+            })
+            .collect(),
+    }
+    .encode_to_vec();
+
+    let mut data = MAGIC.to_vec();
+    data.extend_from_slice(&message);
+    data.extend_from_slice(&Md5::digest(&message));
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deaggregates_records() {
+        let data = aggregate(&[b"first", b"second"]);
+
+        assert_eq!(
+            deaggregate(Bytes::from(data)),
+            vec![Bytes::from("first"), Bytes::from("second")]
+        );
+    }
+
+    #[test]
+    fn passes_through_unaggregated_records() {
+        let data = Bytes::from("plain record");
+
+        assert_eq!(deaggregate(data.clone()), vec![data]);
+    }
+
+    #[test]
+    fn passes_through_records_with_invalid_digest() {
+        let mut data = aggregate(&[b"first"]);
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+        let data = Bytes::from(data);
+
+        assert_eq!(deaggregate(data.clone()), vec![data]);
+    }
+}
@@ -23,6 +23,7 @@ use crate::{
 pub mod errors;
 mod filters;
 mod handlers;
+mod kpl;
 mod models;
 
 /// Configuration for the `aws_kinesis_firehose` source.
@@ -73,6 +74,15 @@ pub struct AwsKinesisFirehoseConfig {
     #[serde(default)]
     record_compression: Compression,
 
+    /// Whether to de-aggregate records produced with the [KPL aggregation format][kpl_aggregation].
+    ///
+    /// When enabled, each user record within an aggregated record is decoded separately. Records
+    /// that are not aggregated are decoded as-is.
+    ///
+    /// [kpl_aggregation]: https://github.com/awslabs/amazon-kinesis-producer/blob/master/aggregation-format.md
+    #[serde(default)]
+    deaggregate_records: bool,
+
     #[configurable(derived)]
     tls: Option<TlsEnableableConfig>,
 
@@ -143,7 +153,8 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
         let log_namespace = cx.log_namespace(self.log_namespace);
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
 
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
@@ -162,6 +173,7 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
             access_keys.map(|key| key.inner().to_string()).collect(),
             self.store_access_key,
             self.record_compression,
+            self.deaggregate_records,
             decoder,
             acknowledgements,
             cx.out,
@@ -228,6 +240,7 @@ impl GenerateConfig for AwsKinesisFirehoseConfig {
             store_access_key: false,
             tls: None,
             record_compression: Default::default(),
+            deaggregate_records: false,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             acknowledgements: Default::default(),
@@ -305,31 +318,34 @@ mod tests {
         record_compression: Compression,
         delivered: bool,
         log_namespace: bool,
+    ) -> (impl Stream<Item = Event> + Unpin, SocketAddr) {
+        let address = next_addr();
+        let config = AwsKinesisFirehoseConfig {
+            address,
+            tls: None,
+            access_key,
+            access_keys,
+            store_access_key,
+            record_compression,
+            deaggregate_records: false,
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            acknowledgements: true.into(),
+            log_namespace: Some(log_namespace),
+        };
+        spawn_source(config, delivered).await
+    }
+
+    async fn spawn_source(
+        config: AwsKinesisFirehoseConfig,
+        delivered: bool,
     ) -> (impl Stream<Item = Event> + Unpin, SocketAddr) {
         use EventStatus::*;
         let status = if delivered { Delivered } else { Rejected };
         let (sender, recv) = SourceSender::new_test_finalize(status);
-        let address = next_addr();
+        let address = config.address;
         let cx = SourceContext::new_test(sender, None);
-        tokio::spawn(async move {
-            AwsKinesisFirehoseConfig {
-                address,
-                tls: None,
-                access_key,
-                access_keys,
-                store_access_key,
-                record_compression,
-                framing: default_framing_message_based(),
-                decoding: default_decoding(),
-                acknowledgements: true.into(),
-                log_namespace: Some(log_namespace),
-            }
-            .build(cx)
-            .await
-            .unwrap()
-            .await
-            .unwrap()
-        });
+        tokio::spawn(async move { config.build(cx).await.unwrap().await.unwrap() });
         wait_for_tcp(address).await;
         (recv, address)
     }
@@ -906,4 +922,82 @@ mod tests {
             .get("aws_kinesis_firehose_access_key")
             .is_none());
     }
+
+    #[tokio::test]
+    async fn aws_kinesis_firehose_deaggregates_kpl_records() {
+        let address = next_addr();
+        let config = AwsKinesisFirehoseConfig {
+            address,
+            tls: None,
+            access_key: None,
+            access_keys: None,
+            store_access_key: false,
+            record_compression: Compression::None,
+            deaggregate_records: true,
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            acknowledgements: true.into(),
+            log_namespace: Some(false),
+        };
+        let (rx, address) = spawn_source(config, true).await;
+
+        let aggregated: &'static [u8] = Box::leak(kpl::aggregate(&[b"first", b"second"]).into());
+        let res = spawn_send(
+            address,
+            Utc::now(),
+            vec![aggregated, b"third"],
+            None,
+            false,
+            Compression::None,
+        )
+        .await;
+
+        let events = collect_ready(rx).await;
+        let res = res.await.unwrap().unwrap();
+        assert_eq!(200, res.status().as_u16());
+
+        let messages = events
+            .iter()
+            .map(|event| event.as_log()["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec!["first".into(), "second".into(), "third".into()]
+        );
+    }
+
+    #[tokio::test]
+    async fn aws_kinesis_firehose_reports_failed_record_count() {
+        let (_rx, addr) = source(None, None, false, Compression::None, false, false).await;
+
+        let res = send(
+            addr,
+            Utc::now(),
+            vec![RECORD.as_bytes(), RECORD.as_bytes()],
+            None,
+            false,
+            Compression::None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(406, res.status().as_u16());
+
+        let response: models::FirehoseResponse = res.json().await.unwrap();
+        assert_eq!(
+            response.error_message.as_deref(),
+            Some("Delivery failed for 2 of 2 records")
+        );
+    }
+
+    #[test]
+    fn delivered_records_are_taken_once() {
+        let delivered = handlers::DeliveredRecords::default();
+        delivered.insert(REQUEST_ID.to_owned(), [0, 2].into_iter().collect());
+
+        assert_eq!(
+            delivered.take(REQUEST_ID),
+            [0, 2].into_iter().collect::<std::collections::HashSet<_>>()
+        );
+        assert!(delivered.take(REQUEST_ID).is_empty());
+    }
 }
@@ -206,15 +206,9 @@ impl AwsS3Config {
             .region()
             .ok_or(CreateSqsIngestorError::RegionMissing)?;
 
-        let endpoint = self
-            .region
-            .endpoint()
-            .map_err(|_| CreateSqsIngestorError::InvalidEndpoint)?;
-
         let s3_client = create_client::<S3ClientBuilder>(
             &self.auth,
-            Some(region.clone()),
-            endpoint.clone(),
+            &self.region,
             proxy,
             &self.tls_options,
             false,
@@ -225,8 +219,7 @@ impl AwsS3Config {
             Some(ref sqs) => {
                 let sqs_client = create_client::<SqsClientBuilder>(
                     &self.auth,
-                    Some(region.clone()),
-                    endpoint,
+                    &self.region,
                     proxy,
                     &sqs.tls_options,
                     false,
@@ -262,8 +255,6 @@ enum CreateSqsIngestorError {
     ConfigMissing,
     #[snafu(display("Region is required"))]
     RegionMissing,
-    #[snafu(display("Endpoint is invalid"))]
-    InvalidEndpoint,
 }
 
 /// None if body is empty
@@ -888,39 +879,19 @@ mod integration_tests {
 
     async fn s3_client() -> S3Client {
         let auth = AwsAuthentication::test_auth();
-        let region_endpoint = RegionOrEndpoint {
-            region: Some("us-east-1".to_owned()),
-            endpoint: Some(s3_address()),
-        };
+        let region_endpoint = RegionOrEndpoint::with_both("us-east-1", s3_address());
         let proxy_config = ProxyConfig::default();
-        create_client::<S3ClientBuilder>(
-            &auth,
-            region_endpoint.region(),
-            region_endpoint.endpoint().unwrap(),
-            &proxy_config,
-            &None,
-            false,
-        )
-        .await
-        .unwrap()
+        create_client::<S3ClientBuilder>(&auth, &region_endpoint, &proxy_config, &None, false)
+            .await
+            .unwrap()
     }
 
     async fn sqs_client() -> SqsClient {
         let auth = AwsAuthentication::test_auth();
-        let region_endpoint = RegionOrEndpoint {
-            region: Some("us-east-1".to_owned()),
-            endpoint: Some(s3_address()),
-        };
+        let region_endpoint = RegionOrEndpoint::with_both("us-east-1", s3_address());
         let proxy_config = ProxyConfig::default();
-        create_client::<SqsClientBuilder>(
-            &auth,
-            region_endpoint.region(),
-            region_endpoint.endpoint().unwrap(),
-            &proxy_config,
-            &None,
-            false,
-        )
-        .await
-        .unwrap()
+        create_client::<SqsClientBuilder>(&auth, &region_endpoint, &proxy_config, &None, false)
+            .await
+            .unwrap()
     }
 }
@@ -109,7 +109,8 @@ impl SourceConfig for AwsSqsConfig {
 
         let client = self.build_client(&cx).await?;
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
         Ok(Box::pin(
@@ -157,15 +158,8 @@ impl SourceConfig for AwsSqsConfig {
 
 impl AwsSqsConfig {
     async fn build_client(&self, cx: &SourceContext) -> crate::Result<aws_sdk_sqs::Client> {
-        create_client::<SqsClientBuilder>(
-            &self.auth,
-            self.region.region(),
-            self.region.endpoint()?,
-            &cx.proxy,
-            &self.tls,
-            false,
-        )
-        .await
+        create_client::<SqsClientBuilder>(&self.auth, &self.region, &cx.proxy, &self.tls, false)
+            .await
     }
 }
 
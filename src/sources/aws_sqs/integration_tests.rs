@@ -54,7 +54,7 @@ async fn get_sqs_client() -> aws_sdk_sqs::Client {
     let config = aws_sdk_sqs::config::Builder::new()
         .credentials_provider(
             AwsAuthentication::test_auth()
-                .credentials_provider(Region::new("custom"))
+                .credentials_provider(Region::new("custom"), &Default::default(), &None)
                 .await
                 .unwrap(),
         )
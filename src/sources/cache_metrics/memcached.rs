@@ -0,0 +1,154 @@
+use snafu::{ResultExt, Snafu};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+use vector_core::event::metric::MetricValue;
+
+use super::Sample;
+
+/// The default port of memcached.
+pub const DEFAULT_PORT: u16 = 11211;
+
+/// The statistics that are numeric, but aren't measurements.
+const IGNORED_STATS: &[&str] = &["libevent", "pid", "pointer_size", "time", "version"];
+
+/// The statistics that only ever increase, besides those starting with `cmd_` or `total_`, and
+/// those ending with `_hits` or `_misses`.
+const COUNTER_STATS: &[&str] = &[
+    "accepting_conns_disabled",
+    "auth_cmds",
+    "auth_errors",
+    "bytes_read",
+    "bytes_written",
+    "conn_yields",
+    "evicted_unfetched",
+    "evictions",
+    "expired_unfetched",
+    "get_expired",
+    "get_flushed",
+    "listen_disabled_num",
+    "reclaimed",
+    "rejected_connections",
+    "rusage_system",
+    "rusage_user",
+];
+
+#[derive(Debug, Snafu)]
+pub enum StatsError {
+    #[snafu(display("Failed to query the stats: {}", source))]
+    Io { source: std::io::Error },
+
+    #[snafu(display("Server replied with an error: {}", line))]
+    Server { line: String },
+
+    #[snafu(display("Connection closed before the end of the stats"))]
+    Truncated,
+}
+
+/// Queries the general-purpose statistics of a memcached server, returning its reply.
+pub async fn fetch_stats(address: &str) -> Result<String, StatsError> {
+    let mut stream = TcpStream::connect(address).await.context(IoSnafu)?;
+    stream.write_all(b"stats\r\n").await.context(IoSnafu)?;
+
+    let mut reader = BufReader::new(stream);
+    let mut reply = String::new();
+    loop {
+        let start = reply.len();
+        if reader.read_line(&mut reply).await.context(IoSnafu)? == 0 {
+            return Err(StatsError::Truncated);
+        }
+        match reply[start..].trim_end() {
+            "END" => return Ok(reply),
+            line if line.ends_with("ERROR") || line.starts_with("SERVER_ERROR") => {
+                return Err(StatsError::Server {
+                    line: line.to_owned(),
+                })
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses the reply of `stats` into samples named after the statistics.
+pub fn parse_stats(reply: &str) -> Vec<Sample> {
+    reply
+        .lines()
+        .filter_map(|line| line.trim_end().strip_prefix("STAT "))
+        .filter_map(|line| line.split_once(' '))
+        .filter(|(name, _)| !IGNORED_STATS.contains(name))
+        .filter_map(|(name, value)| {
+            let value = value
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())?;
+            let value = if is_counter(name) {
+                MetricValue::Counter { value }
+            } else {
+                MetricValue::Gauge { value }
+            };
+            Some(Sample::new(name, value))
+        })
+        .collect()
+}
+
+fn is_counter(name: &str) -> bool {
+    name.starts_with("cmd_")
+        || name.starts_with("total_")
+        || name.ends_with("_hits")
+        || name.ends_with("_misses")
+        || COUNTER_STATS.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+
+    #[test]
+    fn parses_stats() {
+        let reply = concat!(
+            "STAT pid 1\r\n",
+            "STAT uptime 3600\r\n",
+            "STAT version 1.6.21\r\n",
+            "STAT rusage_user 0.512345\r\n",
+            "STAT curr_connections 10\r\n",
+            "STAT cmd_get 120\r\n",
+            "STAT get_hits 100\r\n",
+            "STAT evictions 0\r\n",
+            "END\r\n",
+        );
+
+        assert_eq!(
+            parse_stats(reply),
+            vec![
+                Sample::new("uptime", MetricValue::Gauge { value: 3600.0 }),
+                Sample::new("rusage_user", MetricValue::Counter { value: 0.512345 }),
+                Sample::new("curr_connections", MetricValue::Gauge { value: 10.0 }),
+                Sample::new("cmd_get", MetricValue::Counter { value: 120.0 }),
+                Sample::new("get_hits", MetricValue::Counter { value: 100.0 }),
+                Sample::new("evictions", MetricValue::Counter { value: 0.0 }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetches_stats() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0; 7];
+            stream.read_exact(&mut request).await.unwrap();
+            assert_eq!(&request, b"stats\r\n");
+            stream
+                .write_all(b"STAT curr_items 4\r\nEND\r\n")
+                .await
+                .unwrap();
+        });
+
+        let reply = fetch_stats(&address).await.unwrap();
+        assert_eq!(reply, "STAT curr_items 4\r\nEND\r\n");
+    }
+}
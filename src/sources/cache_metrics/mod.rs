@@ -0,0 +1,578 @@
+//! Polls the statistics of Redis and memcached instances.
+//!
+//! Redis is queried with `INFO` and `SLOWLOG GET`, and memcached with `stats`. The statistics are
+//! emitted as metrics, and the entries of the slow log of Redis as log events on the `slowlog`
+//! output, with the tags of the instance in both.
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future::join_all, StreamExt};
+use lookup::{owned_value_path, path};
+use serde_with::serde_as;
+use snafu::{ResultExt, Snafu};
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use url::Url;
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{LegacyKey, LogNamespace},
+    metric_tags,
+    schema::Definition,
+    EstimatedJsonEncodedSizeOf,
+};
+
+use crate::{
+    config::{log_schema, DataType, SourceConfig, SourceContext, SourceOutput},
+    event::{
+        metric::{Metric, MetricKind, MetricTags, MetricValue},
+        LogEvent, Value,
+    },
+    internal_events::{
+        CacheMetricsEventsReceived, CacheMetricsRequestError, CollectionCompleted,
+        EndpointBytesReceived, StreamClosedError,
+    },
+};
+
+mod memcached;
+mod redis;
+
+use self::redis::SlowlogEntry;
+
+/// The name of the output of the entries of the slow log of Redis.
+pub const SLOWLOG: &str = "slowlog";
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Failed to parse endpoint: {}", source))]
+    InvalidEndpoint { source: url::ParseError },
+
+    #[snafu(display(
+        "Unsupported endpoint scheme {:?}, expected `redis`, `rediss`, `redis+unix` or `memcached`",
+        scheme
+    ))]
+    UnsupportedScheme { scheme: String },
+
+    #[snafu(display("Memcached endpoint is missing a host"))]
+    MissingHost,
+
+    #[snafu(display("Failed to build redis client: {}", source))]
+    Client { source: ::redis::RedisError },
+}
+
+/// Configuration for the `cache_metrics` source.
+#[serde_as]
+#[configurable_component(source(
+    "cache_metrics",
+    "Collect metrics and slow log entries from Redis and memcached."
+))]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CacheMetricsConfig {
+    /// A list of Redis and memcached instances to poll.
+    ///
+    /// Redis instances are given as `redis://`, `rediss://` or `redis+unix://` URLs, which may
+    /// include a password and a database. Memcached instances are given as `memcached://` URLs,
+    /// with the port defaulting to `11211`.
+    #[configurable(metadata(docs::examples = "redis://127.0.0.1:6379"))]
+    #[configurable(metadata(docs::examples = "memcached://127.0.0.1:11211"))]
+    endpoints: Vec<String>,
+
+    /// The interval between polls.
+    ///
+    /// Instances that don't reply within the interval are reported as down.
+    #[serde(default = "default_scrape_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    scrape_interval_secs: Duration,
+
+    /// Overrides the default namespace for the metrics emitted by the source.
+    ///
+    /// If set to an empty string, no namespace is added to the metrics.
+    ///
+    /// By default, `redis` is used for Redis instances, and `memcached` for memcached instances.
+    #[configurable(metadata(docs::examples = "cache"))]
+    namespace: Option<String>,
+
+    /// The maximum number of entries to read from the slow log of Redis instances on each poll.
+    ///
+    /// Only the entries logged since the previous poll are emitted. If set to `0`, the slow log
+    /// isn't read.
+    #[serde(default = "default_slowlog_max_entries")]
+    slowlog_max_entries: usize,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+pub(super) const fn default_scrape_interval_secs() -> Duration {
+    Duration::from_secs(15)
+}
+
+const fn default_slowlog_max_entries() -> usize {
+    128
+}
+
+impl_generate_config_from_default!(CacheMetricsConfig);
+
+impl CacheMetricsConfig {
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        Definition::new_with_default_metadata(Kind::object(Collection::empty()), [log_namespace])
+            .with_event_field(
+                &owned_value_path!("message"),
+                Kind::bytes(),
+                Some("message"),
+            )
+            .with_event_field(&owned_value_path!("id"), Kind::integer(), None)
+            .with_event_field(
+                &owned_value_path!("duration_microseconds"),
+                Kind::integer(),
+                None,
+            )
+            .with_event_field(
+                &owned_value_path!("command"),
+                Kind::array(Collection::empty().with_unknown(Kind::bytes())),
+                None,
+            )
+            .with_event_field(
+                &owned_value_path!("client_address"),
+                Kind::bytes().or_undefined(),
+                None,
+            )
+            .with_event_field(
+                &owned_value_path!("client_name"),
+                Kind::bytes().or_undefined(),
+                None,
+            )
+            .with_event_field(&owned_value_path!("endpoint"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("host"), Kind::bytes(), Some("host"))
+            .with_source_metadata(
+                Self::NAME,
+                log_schema()
+                    .timestamp_key()
+                    .cloned()
+                    .map(LegacyKey::Overwrite),
+                &owned_value_path!("timestamp"),
+                Kind::timestamp(),
+                Some("timestamp"),
+            )
+            .with_standard_vector_source_metadata()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "cache_metrics")]
+impl SourceConfig for CacheMetricsConfig {
+    async fn build(&self, mut cx: SourceContext) -> crate::Result<super::Source> {
+        let log_namespace = cx.log_namespace(self.log_namespace);
+        let mut instances = self
+            .endpoints
+            .iter()
+            .map(|endpoint| Instance::new(endpoint, self.namespace.as_deref()))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let duration = self.scrape_interval_secs;
+        let slowlog_max_entries = self.slowlog_max_entries;
+        let shutdown = cx.shutdown;
+        Ok(Box::pin(async move {
+            let mut interval = IntervalStream::new(time::interval(duration)).take_until(shutdown);
+            while interval.next().await.is_some() {
+                let start = Instant::now();
+                let collected = join_all(instances.iter_mut().map(|instance| {
+                    instance.collect(duration, slowlog_max_entries, log_namespace)
+                }))
+                .await;
+                emit!(CollectionCompleted {
+                    start,
+                    end: Instant::now()
+                });
+
+                let (metrics, logs): (Vec<_>, Vec<_>) = collected.into_iter().unzip();
+                let metrics = metrics.into_iter().flatten().collect::<Vec<_>>();
+                let logs = logs.into_iter().flatten().collect::<Vec<_>>();
+
+                let count = metrics.len();
+                if let Err(error) = cx.out.send_batch(metrics).await {
+                    emit!(StreamClosedError { error, count });
+                    return Err(());
+                }
+
+                let count = logs.len();
+                if count > 0 {
+                    if let Err(error) = cx.out.send_batch_named(SLOWLOG, logs).await {
+                        emit!(StreamClosedError { error, count });
+                        return Err(());
+                    }
+                }
+            }
+
+            Ok(())
+        }))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_definition =
+            self.schema_definition(global_log_namespace.merge(self.log_namespace));
+
+        vec![
+            SourceOutput::new_metrics(),
+            SourceOutput::new_logs(DataType::Log, schema_definition).with_port(SLOWLOG),
+        ]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+/// A statistic of an instance, named after the field of its reply.
+#[derive(Debug, PartialEq)]
+struct Sample {
+    name: String,
+    value: MetricValue,
+    tags: Vec<(String, String)>,
+}
+
+impl Sample {
+    fn new(name: &str, value: MetricValue) -> Self {
+        Self {
+            name: name.to_owned(),
+            value,
+            tags: Vec::new(),
+        }
+    }
+
+    fn with_tag(mut self, key: &str, value: &str) -> Self {
+        self.tags.push((key.to_owned(), value.to_owned()));
+        self
+    }
+}
+
+#[derive(Debug)]
+enum Engine {
+    Redis {
+        client: ::redis::Client,
+        last_slowlog_id: Option<i64>,
+    },
+    Memcached {
+        address: String,
+    },
+}
+
+impl Engine {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Redis { .. } => "redis",
+            Self::Memcached { .. } => "memcached",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Instance {
+    /// The endpoint, without its credentials.
+    endpoint: String,
+    host: String,
+    engine: Engine,
+    namespace: Option<String>,
+    tags: MetricTags,
+}
+
+impl Instance {
+    fn new(endpoint: &str, namespace: Option<&str>) -> crate::Result<Self> {
+        let mut url = Url::parse(endpoint).context(InvalidEndpointSnafu)?;
+        let (engine, host) = match url.scheme() {
+            "redis" | "rediss" | "redis+unix" | "unix" => {
+                let client = ::redis::Client::open(endpoint).context(ClientSnafu)?;
+                let host = match url.host_str() {
+                    Some(host) => format!("{}:{}", host, url.port().unwrap_or(6379)),
+                    None => url.path().to_owned(),
+                };
+                let engine = Engine::Redis {
+                    client,
+                    last_slowlog_id: None,
+                };
+                (engine, host)
+            }
+            "memcached" => {
+                let host = url.host_str().ok_or(BuildError::MissingHost)?;
+                let host = format!("{}:{}", host, url.port().unwrap_or(memcached::DEFAULT_PORT));
+                let engine = Engine::Memcached {
+                    address: host.clone(),
+                };
+                (engine, host)
+            }
+            scheme => {
+                return Err(BuildError::UnsupportedScheme {
+                    scheme: scheme.to_owned(),
+                }
+                .into())
+            }
+        };
+
+        // The endpoint is reported in tags and logs, which mustn't leak the password.
+        if url.password().is_some() {
+            _ = url.set_password(Some("****"));
+        }
+        let endpoint = url.to_string();
+        let namespace = namespace.unwrap_or_else(|| engine.name()).to_owned();
+
+        Ok(Self {
+            tags: metric_tags!(
+                "endpoint" => endpoint.clone(),
+                "host" => host.clone(),
+            ),
+            endpoint,
+            host,
+            engine,
+            namespace: Some(namespace).filter(|namespace| !namespace.is_empty()),
+        })
+    }
+
+    async fn collect(
+        &mut self,
+        timeout: Duration,
+        slowlog_max_entries: usize,
+        log_namespace: LogNamespace,
+    ) -> (Vec<Metric>, Vec<LogEvent>) {
+        let result = match time::timeout(timeout, self.query(slowlog_max_entries)).await {
+            Ok(result) => result,
+            Err(_) => Err("Timed out waiting for the instance to reply.".into()),
+        };
+        let (up_value, samples, entries) = match result {
+            Ok((samples, entries)) => (1.0, samples, entries),
+            Err(error) => {
+                emit!(CacheMetricsRequestError {
+                    error,
+                    endpoint: &self.endpoint,
+                });
+                (0.0, Vec::new(), Vec::new())
+            }
+        };
+
+        let now = Utc::now();
+        let mut metrics = samples
+            .into_iter()
+            .map(|sample| self.create_metric(sample, now))
+            .collect::<Vec<_>>();
+        let logs = entries
+            .into_iter()
+            .map(|entry| self.create_log(entry, log_namespace))
+            .collect::<Vec<_>>();
+
+        let byte_size =
+            metrics.estimated_json_encoded_size_of() + logs.estimated_json_encoded_size_of();
+        metrics.push(self.create_metric(
+            Sample::new("up", MetricValue::Gauge { value: up_value }),
+            now,
+        ));
+
+        emit!(CacheMetricsEventsReceived {
+            count: metrics.len() + logs.len(),
+            byte_size,
+            endpoint: &self.endpoint,
+        });
+
+        (metrics, logs)
+    }
+
+    async fn query(
+        &mut self,
+        slowlog_max_entries: usize,
+    ) -> crate::Result<(Vec<Sample>, Vec<SlowlogEntry>)> {
+        match &mut self.engine {
+            Engine::Redis {
+                client,
+                last_slowlog_id,
+            } => {
+                let mut connection = client.get_async_connection().await?;
+                let info: String = ::redis::cmd("INFO").query_async(&mut connection).await?;
+                let mut byte_size = info.len();
+
+                let mut entries = Vec::new();
+                if slowlog_max_entries > 0 {
+                    let reply: ::redis::Value = ::redis::cmd("SLOWLOG")
+                        .arg("GET")
+                        .arg(slowlog_max_entries)
+                        .query_async(&mut connection)
+                        .await?;
+                    byte_size += redis::reply_size(&reply);
+                    entries = redis::parse_slowlog(&reply, *last_slowlog_id);
+                    if let Some(entry) = entries.last() {
+                        *last_slowlog_id = Some(entry.id);
+                    }
+                }
+
+                emit!(EndpointBytesReceived {
+                    byte_size,
+                    protocol: "tcp",
+                    endpoint: &self.endpoint,
+                });
+                Ok((redis::parse_info(&info), entries))
+            }
+            Engine::Memcached { address } => {
+                let reply = memcached::fetch_stats(address).await?;
+                emit!(EndpointBytesReceived {
+                    byte_size: reply.len(),
+                    protocol: "tcp",
+                    endpoint: &self.endpoint,
+                });
+                Ok((memcached::parse_stats(&reply), Vec::new()))
+            }
+        }
+    }
+
+    fn create_log(&self, entry: SlowlogEntry, log_namespace: LogNamespace) -> LogEvent {
+        let SlowlogEntry {
+            id,
+            timestamp,
+            duration_microseconds,
+            command,
+            client_address,
+            client_name,
+        } = entry;
+
+        let mut fields = BTreeMap::new();
+        fields.insert("message".to_owned(), Value::from(command.join(" ")));
+        fields.insert("id".to_owned(), Value::from(id));
+        fields.insert(
+            "duration_microseconds".to_owned(),
+            Value::from(duration_microseconds),
+        );
+        fields.insert(
+            "command".to_owned(),
+            Value::Array(command.into_iter().map(Value::from).collect()),
+        );
+        if let Some(client_address) = client_address {
+            fields.insert("client_address".to_owned(), Value::from(client_address));
+        }
+        if let Some(client_name) = client_name {
+            fields.insert("client_name".to_owned(), Value::from(client_name));
+        }
+        fields.insert("endpoint".to_owned(), Value::from(self.endpoint.clone()));
+        fields.insert("host".to_owned(), Value::from(self.host.clone()));
+
+        let mut log = LogEvent::from(fields);
+        log_namespace.insert_source_metadata(
+            CacheMetricsConfig::NAME,
+            &mut log,
+            log_schema().timestamp_key().map(LegacyKey::Overwrite),
+            path!("timestamp"),
+            timestamp,
+        );
+        log_namespace.insert_standard_vector_source_metadata(
+            &mut log,
+            CacheMetricsConfig::NAME,
+            Utc::now(),
+        );
+        log
+    }
+
+    fn create_metric(&self, sample: Sample, timestamp: DateTime<Utc>) -> Metric {
+        let mut tags = self.tags.clone();
+        for (key, value) in sample.tags {
+            tags.replace(key, value);
+        }
+        Metric::new(sample.name, MetricKind::Absolute, sample.value)
+            .with_namespace(self.namespace.clone())
+            .with_tags(Some(tags))
+            .with_timestamp(Some(timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::{
+        test_util::components::{assert_source_compliance, SOURCE_TAGS},
+        SourceSender,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<CacheMetricsConfig>();
+    }
+
+    #[test]
+    fn hides_endpoint_passwords() {
+        let instance = Instance::new("redis://:hunter2@cache.internal:6380/1", None).unwrap();
+        assert_eq!(instance.endpoint, "redis://:****@cache.internal:6380/1");
+        assert_eq!(instance.host, "cache.internal:6380");
+        assert_eq!(instance.namespace.as_deref(), Some("redis"));
+
+        let instance = Instance::new("memcached://cache.internal", Some("")).unwrap();
+        assert_eq!(instance.host, "cache.internal:11211");
+        assert_eq!(instance.namespace, None);
+
+        assert!(Instance::new("http://cache.internal", None).is_err());
+    }
+
+    #[tokio::test]
+    async fn polls_memcached() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 7];
+                stream.read_exact(&mut request).await.unwrap();
+                stream
+                    .write_all(b"STAT curr_connections 3\r\nSTAT get_hits 7\r\nEND\r\n")
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let config = CacheMetricsConfig {
+            endpoints: vec![format!("memcached://{}", address)],
+            scrape_interval_secs: Duration::from_secs(1),
+            namespace: None,
+            slowlog_max_entries: default_slowlog_max_entries(),
+            log_namespace: None,
+        };
+        let events = assert_source_compliance(&SOURCE_TAGS, async {
+            let (tx, rx) = SourceSender::new_test();
+            let cx = SourceContext::new_test(tx, None);
+            tokio::spawn(config.build(cx).await.unwrap());
+            rx.take(3).collect::<Vec<_>>().await
+        })
+        .await;
+
+        let metrics = events
+            .into_iter()
+            .map(|event| event.into_metric())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            metrics
+                .iter()
+                .map(|metric| (metric.namespace(), metric.name(), metric.value().clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Some("memcached"),
+                    "curr_connections",
+                    MetricValue::Gauge { value: 3.0 }
+                ),
+                (
+                    Some("memcached"),
+                    "get_hits",
+                    MetricValue::Counter { value: 7.0 }
+                ),
+                (Some("memcached"), "up", MetricValue::Gauge { value: 1.0 }),
+            ]
+        );
+        assert_eq!(
+            metrics[0].tag_value("host"),
+            Some(format!("127.0.0.1:{}", address.port()))
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use chrono::{DateTime, TimeZone, Utc};
+use redis::Value;
+use vector_core::event::metric::MetricValue;
+
+use super::Sample;
+
+/// The fields of `INFO` that are numeric, but aren't measurements.
+const IGNORED_FIELDS: &[&str] = &[
+    "arch_bits",
+    "configured_hz",
+    "master_port",
+    "process_id",
+    "redis_build_id",
+    "redis_git_dirty",
+    "redis_git_sha1",
+    "run_id",
+    "server_time_usec",
+    "tcp_port",
+];
+
+/// The fields of `INFO` that only ever increase, besides those starting with `total_`.
+const COUNTER_FIELDS: &[&str] = &[
+    "acl_access_denied_auth",
+    "acl_access_denied_channel",
+    "acl_access_denied_cmd",
+    "acl_access_denied_key",
+    "dump_payload_sanitizations",
+    "evicted_clients",
+    "evicted_keys",
+    "expired_keys",
+    "expired_time_cap_reached_count",
+    "io_threaded_reads_processed",
+    "io_threaded_writes_processed",
+    "keyspace_hits",
+    "keyspace_misses",
+    "rejected_connections",
+    "sync_full",
+    "sync_partial_err",
+    "sync_partial_ok",
+    "unexpected_error_replies",
+    "used_cpu_sys",
+    "used_cpu_sys_children",
+    "used_cpu_user",
+    "used_cpu_user_children",
+];
+
+/// Parses the reply of `INFO` into samples named after its fields.
+///
+/// The fields of the keyspace section, such as `db0:keys=1,expires=0,avg_ttl=0`, are split into
+/// `db_keys`, `db_expires` and `db_avg_ttl_seconds` samples, tagged with the database.
+pub fn parse_info(info: &str) -> Vec<Sample> {
+    info.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once(':'))
+        .flat_map(|(field, value)| match keyspace_database(field) {
+            Some(db) => parse_keyspace(db, value),
+            None => parse_field(field, value).into_iter().collect(),
+        })
+        .collect()
+}
+
+fn parse_field(field: &str, value: &str) -> Option<Sample> {
+    if IGNORED_FIELDS.contains(&field) {
+        return None;
+    }
+    let value = value
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())?;
+    let value = if field.starts_with("total_") || COUNTER_FIELDS.contains(&field) {
+        MetricValue::Counter { value }
+    } else {
+        MetricValue::Gauge { value }
+    };
+    Some(Sample::new(field, value))
+}
+
+/// Returns the database of the keyspace fields, such as `0` for `db0`.
+fn keyspace_database(field: &str) -> Option<&str> {
+    field
+        .strip_prefix("db")
+        .filter(|db| !db.is_empty() && db.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn parse_keyspace(db: &str, value: &str) -> Vec<Sample> {
+    value
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .filter_map(|(key, value)| {
+            let value = value.parse::<f64>().ok()?;
+            let (name, value) = match key {
+                "keys" => ("db_keys", value),
+                "expires" => ("db_expires", value),
+                "avg_ttl" => ("db_avg_ttl_seconds", value / 1000.0),
+                _ => return None,
+            };
+            Some(Sample::new(name, MetricValue::Gauge { value }).with_tag("db", db))
+        })
+        .collect()
+}
+
+/// An entry of the slow log of Redis.
+#[derive(Debug, PartialEq)]
+pub struct SlowlogEntry {
+    pub id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub duration_microseconds: i64,
+    pub command: Vec<String>,
+    pub client_address: Option<String>,
+    pub client_name: Option<String>,
+}
+
+impl SlowlogEntry {
+    /// Parses an entry of the reply of `SLOWLOG GET`.
+    ///
+    /// Redis before 4.0 doesn't report the client of the entries.
+    fn parse(value: &Value) -> Option<Self> {
+        let Value::Bulk(fields) = value else {
+            return None;
+        };
+        let (id, timestamp, duration, command) = match fields.as_slice() {
+            [Value::Int(id), Value::Int(timestamp), Value::Int(duration), Value::Bulk(command), ..] => {
+                (*id, *timestamp, *duration, command)
+            }
+            _ => return None,
+        };
+        let client = |index: usize| match fields.get(index) {
+            Some(Value::Data(data)) if !data.is_empty() => {
+                Some(String::from_utf8_lossy(data).into_owned())
+            }
+            _ => None,
+        };
+
+        Some(Self {
+            id,
+            timestamp: Utc.timestamp_opt(timestamp, 0).single()?,
+            duration_microseconds: duration,
+            command: command
+                .iter()
+                .filter_map(|arg| match arg {
+                    Value::Data(data) => Some(String::from_utf8_lossy(data).into_owned()),
+                    Value::Status(status) => Some(status.clone()),
+                    _ => None,
+                })
+                .collect(),
+            client_address: client(4),
+            client_name: client(5),
+        })
+    }
+}
+
+/// Parses the reply of `SLOWLOG GET`, returning the entries logged after the one with the given
+/// ID, oldest first.
+///
+/// If all the entries are older than the given one, the server is assumed to have been restarted,
+/// which resets the IDs, and all the entries are returned.
+pub fn parse_slowlog(value: &Value, last_id: Option<i64>) -> Vec<SlowlogEntry> {
+    let Value::Bulk(entries) = value else {
+        return Vec::new();
+    };
+    let mut entries = entries
+        .iter()
+        .filter_map(SlowlogEntry::parse)
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.id);
+
+    if let Some(last_id) = last_id {
+        if entries.last().map_or(false, |entry| entry.id > last_id) {
+            entries.retain(|entry| entry.id > last_id);
+        } else if entries.last().map_or(false, |entry| entry.id == last_id) {
+            entries.clear();
+        }
+    }
+    entries
+}
+
+/// Returns the size of a reply, as received from the server.
+pub fn reply_size(value: &Value) -> usize {
+    match value {
+        Value::Nil | Value::Okay => 0,
+        Value::Int(_) => std::mem::size_of::<i64>(),
+        Value::Data(data) => data.len(),
+        Value::Bulk(values) => values.iter().map(reply_size).sum(),
+        Value::Status(status) => status.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(s: &str) -> Value {
+        Value::Data(s.as_bytes().to_vec())
+    }
+
+    fn entry(id: i64, command: &[&str]) -> Value {
+        Value::Bulk(vec![
+            Value::Int(id),
+            Value::Int(1_681_000_000 + id),
+            Value::Int(12_000),
+            Value::Bulk(command.iter().map(|arg| data(arg)).collect()),
+            data("127.0.0.1:52340"),
+            data(""),
+        ])
+    }
+
+    #[test]
+    fn parses_info() {
+        let info = concat!(
+            "# Server\r\n",
+            "redis_version:7.0.11\r\n",
+            "redis_git_sha1:00000000\r\n",
+            "process_id:1\r\n",
+            "uptime_in_seconds:3600\r\n",
+            "\r\n",
+            "# Clients\r\n",
+            "connected_clients:12\r\n",
+            "# Stats\r\n",
+            "total_commands_processed:1500\r\n",
+            "keyspace_hits:42\r\n",
+            "used_memory_human:1.04M\r\n",
+            "# Keyspace\r\n",
+            "db0:keys=10,expires=2,avg_ttl=1500\r\n",
+        );
+
+        assert_eq!(
+            parse_info(info),
+            vec![
+                Sample::new("uptime_in_seconds", MetricValue::Gauge { value: 3600.0 }),
+                Sample::new("connected_clients", MetricValue::Gauge { value: 12.0 }),
+                Sample::new(
+                    "total_commands_processed",
+                    MetricValue::Counter { value: 1500.0 }
+                ),
+                Sample::new("keyspace_hits", MetricValue::Counter { value: 42.0 }),
+                Sample::new("db_keys", MetricValue::Gauge { value: 10.0 }).with_tag("db", "0"),
+                Sample::new("db_expires", MetricValue::Gauge { value: 2.0 }).with_tag("db", "0"),
+                Sample::new("db_avg_ttl_seconds", MetricValue::Gauge { value: 1.5 })
+                    .with_tag("db", "0"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_new_slowlog_entries() {
+        let reply = Value::Bulk(vec![
+            entry(3, &["KEYS", "*"]),
+            entry(2, &["HGETALL", "sessions"]),
+            entry(1, &["FLUSHALL"]),
+        ]);
+
+        let entries = parse_slowlog(&reply, None);
+        assert_eq!(
+            entries.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(entries[1].command, vec!["HGETALL", "sessions"]);
+        assert_eq!(
+            entries[1].client_address.as_deref(),
+            Some("127.0.0.1:52340")
+        );
+        assert_eq!(entries[1].client_name, None);
+        assert_eq!(entries[1].duration_microseconds, 12_000);
+
+        let entries = parse_slowlog(&reply, Some(2));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, 3);
+
+        assert!(parse_slowlog(&reply, Some(3)).is_empty());
+
+        // The server restarted and its IDs started over.
+        assert_eq!(parse_slowlog(&reply, Some(40)).len(), 3);
+    }
+}
@@ -0,0 +1,400 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use bytes::Bytes;
+use chrono::Utc;
+use codecs::JsonDeserializerConfig;
+use lookup::owned_value_path;
+use serde::Deserialize;
+use value::{kind::Collection, Kind};
+use warp::http::{HeaderMap, StatusCode};
+
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace},
+    schema::Definition,
+};
+
+use crate::{
+    config::{
+        GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
+        SourceOutput,
+    },
+    event::Event,
+    internal_events::{CloudflareLogpushChallengeReceived, CloudflareLogpushLineParseError},
+    serde::bool_or_struct,
+    sources::util::{
+        http::{add_query_parameters, HttpMethod},
+        ErrorMessage, HttpSource, HttpSourceAuthConfig,
+    },
+    tls::TlsEnableableConfig,
+};
+
+/// Configuration for the `cloudflare_logpush` source.
+#[configurable_component(source(
+    "cloudflare_logpush",
+    "Receive logs pushed by Cloudflare Logpush jobs to an HTTP destination."
+))]
+#[derive(Clone, Debug)]
+pub struct CloudflareLogpushConfig {
+    /// The socket address to listen for connections on.
+    ///
+    /// Logpush jobs can push to any path of the address.
+    #[configurable(metadata(docs::examples = "0.0.0.0:443"))]
+    #[configurable(metadata(docs::examples = "localhost:443"))]
+    address: SocketAddr,
+
+    /// A list of URL query parameters to include in the log event.
+    ///
+    /// These override any values included in the body with conflicting names. Query parameters
+    /// are the only way to tell the jobs, and so the datasets, pushing to the source apart.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "dataset", docs::examples = "zone"))]
+    query_parameters: Vec<String>,
+
+    #[configurable(derived)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    auth: Option<HttpSourceAuthConfig>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+impl CloudflareLogpushConfig {
+    /// Builds the `schema::Definition` for this source using the provided `LogNamespace`.
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        JsonDeserializerConfig::new()
+            .schema_definition(log_namespace)
+            .with_standard_vector_source_metadata()
+            // for metadata that is added to the events dynamically from the self.query_parameters
+            .with_source_metadata(
+                CloudflareLogpushConfig::NAME,
+                None,
+                &owned_value_path!("query_parameters"),
+                Kind::object(Collection::empty().with_unknown(Kind::bytes())).or_undefined(),
+                None,
+            )
+    }
+}
+
+impl Default for CloudflareLogpushConfig {
+    fn default() -> Self {
+        Self {
+            address: "0.0.0.0:443".parse().unwrap(),
+            query_parameters: Vec::new(),
+            tls: None,
+            auth: None,
+            acknowledgements: SourceAcknowledgementsConfig::default(),
+            log_namespace: None,
+        }
+    }
+}
+
+impl GenerateConfig for CloudflareLogpushConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(CloudflareLogpushConfig::default()).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "cloudflare_logpush")]
+impl SourceConfig for CloudflareLogpushConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let source = CloudflareLogpushSource {
+            query_parameters: self.query_parameters.clone(),
+            log_namespace: cx.log_namespace(self.log_namespace),
+        };
+
+        source.run(
+            self.address,
+            "",
+            HttpMethod::Post,
+            false,
+            &self.tls,
+            &self.auth,
+            cx,
+            self.acknowledgements,
+        )
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_def = self.schema_definition(global_log_namespace.merge(self.log_namespace));
+        vec![SourceOutput::new_logs(DataType::Log, schema_def)]
+    }
+
+    fn resources(&self) -> Vec<Resource> {
+        vec![Resource::tcp(self.address)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+/// The payload pushed when a job is created, to validate the destination, and when the ownership
+/// of the destination is challenged, in which case `content` holds the challenge token.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Challenge {
+    content: String,
+    filename: Option<String>,
+}
+
+#[derive(Clone)]
+struct CloudflareLogpushSource {
+    query_parameters: Vec<String>,
+    log_namespace: LogNamespace,
+}
+
+impl CloudflareLogpushSource {
+    /// Decodes a batch of logs, one JSON object per line.
+    fn decode_body(&self, body: &[u8]) -> Result<Vec<Event>, ErrorMessage> {
+        let body = std::str::from_utf8(body).map_err(|error| {
+            ErrorMessage::new(
+                StatusCode::BAD_REQUEST,
+                format!("Body is not valid UTF-8: {}", error),
+            )
+        })?;
+
+        if let Ok(challenge) = serde_json::from_str::<Challenge>(body) {
+            emit!(CloudflareLogpushChallengeReceived {
+                content: &challenge.content,
+                filename: challenge.filename.as_deref(),
+            });
+            return Ok(Vec::new());
+        }
+
+        let now = Utc::now();
+        let events = body
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(line)
+                    .map_err(|error| emit!(CloudflareLogpushLineParseError { error }))
+                    .ok()
+            })
+            .map(|fields| {
+                let mut log = self
+                    .log_namespace
+                    .new_log_from_data(serde_json::Value::Object(fields));
+                self.log_namespace.insert_standard_vector_source_metadata(
+                    &mut log,
+                    CloudflareLogpushConfig::NAME,
+                    now,
+                );
+                Event::from(log)
+            })
+            .collect();
+
+        Ok(events)
+    }
+}
+
+impl HttpSource for CloudflareLogpushSource {
+    fn build_events(
+        &self,
+        body: Bytes,
+        _header_map: &HeaderMap,
+        _query_parameters: &HashMap<String, String>,
+        _full_path: &str,
+    ) -> Result<Vec<Event>, ErrorMessage> {
+        self.decode_body(&body)
+    }
+
+    fn enrich_events(
+        &self,
+        events: &mut [Event],
+        _request_path: &str,
+        _headers_config: &HeaderMap,
+        query_parameters: &HashMap<String, String>,
+    ) {
+        add_query_parameters(
+            events,
+            &self.query_parameters,
+            query_parameters,
+            self.log_namespace,
+            CloudflareLogpushConfig::NAME,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, net::SocketAddr};
+
+    use flate2::{write::GzEncoder, Compression};
+    use futures::Stream;
+    use similar_asserts::assert_eq;
+    use vector_core::event::{Event, EventStatus, Value};
+
+    use super::{CloudflareLogpushConfig, HttpSourceAuthConfig};
+    use crate::{
+        config::{log_schema, SourceConfig, SourceContext},
+        test_util::{
+            collect_ready,
+            components::{assert_source_compliance, HTTP_PUSH_SOURCE_TAGS},
+            next_addr, random_string, spawn_collect_n, wait_for_tcp,
+        },
+        SourceSender,
+    };
+
+    const SAMPLE_BODY: &str = concat!(
+        r#"{"ClientIP":"192.0.2.1","ClientRequestHost":"example.com","EdgeResponseStatus":200,"RayID":"7d1a2b3c4d5e6f70"}"#,
+        "\n",
+        r#"{"ClientIP":"192.0.2.2","ClientRequestHost":"example.com","EdgeResponseStatus":404,"RayID":"7d1a2b3c4d5e6f71"}"#,
+        "\n",
+    );
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<CloudflareLogpushConfig>();
+    }
+
+    async fn source(
+        auth: Option<HttpSourceAuthConfig>,
+        status: EventStatus,
+    ) -> (impl Stream<Item = Event> + Unpin, SocketAddr) {
+        let (sender, recv) = SourceSender::new_test_finalize(status);
+        let address = next_addr();
+        let context = SourceContext::new_test(sender, None);
+        tokio::spawn(async move {
+            CloudflareLogpushConfig {
+                address,
+                query_parameters: vec!["dataset".to_string()],
+                auth,
+                ..Default::default()
+            }
+            .build(context)
+            .await
+            .unwrap()
+            .await
+            .unwrap()
+        });
+        wait_for_tcp(address).await;
+        (recv, address)
+    }
+
+    fn gzip(body: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(body.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    async fn send(address: SocketAddr, body: &str, auth: Option<HttpSourceAuthConfig>) -> u16 {
+        let mut req = reqwest::Client::new().post(format!(
+            "http://{}/cloudflare/logs?dataset=http_requests",
+            address
+        ));
+        if let Some(auth) = auth {
+            req = req.basic_auth(auth.username, Some(auth.password.inner()));
+        }
+        req.header("Content-Encoding", "gzip")
+            .body(gzip(body))
+            .send()
+            .await
+            .unwrap()
+            .status()
+            .as_u16()
+    }
+
+    fn make_auth() -> HttpSourceAuthConfig {
+        HttpSourceAuthConfig {
+            username: random_string(16),
+            password: random_string(16).into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn receives_gzipped_ndjson() {
+        assert_source_compliance(&HTTP_PUSH_SOURCE_TAGS, async {
+            let auth = make_auth();
+            let (rx, addr) = source(Some(auth.clone()), EventStatus::Delivered).await;
+
+            let events = spawn_collect_n(
+                async move { assert_eq!(200, send(addr, SAMPLE_BODY, Some(auth)).await) },
+                rx,
+                2,
+            )
+            .await;
+
+            let log = events[0].as_log();
+            assert_eq!(log["ClientIP"], "192.0.2.1".into());
+            assert_eq!(log["EdgeResponseStatus"], 200.into());
+            assert_eq!(log["dataset"], "http_requests".into());
+            assert_eq!(
+                log[log_schema().source_type_key()],
+                "cloudflare_logpush".into()
+            );
+            assert!(log
+                .get(log_schema().timestamp_key().unwrap().to_string().as_str())
+                .is_some());
+
+            let log = events[1].as_log();
+            assert_eq!(log["RayID"], "7d1a2b3c4d5e6f71".into());
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn answers_validation_request() {
+        let (mut rx, addr) = source(None, EventStatus::Delivered).await;
+
+        assert_eq!(200, send(addr, r#"{"content":"tests"}"#, None).await);
+        assert_eq!(
+            200,
+            send(
+                addr,
+                r#"{"content":"0123456789abcdef","filename":"ownership-challenge-01234567.txt"}"#,
+                None
+            )
+            .await
+        );
+
+        assert!(collect_ready(&mut rx).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn skips_malformed_lines() {
+        let (rx, addr) = source(None, EventStatus::Delivered).await;
+        let body = format!("not json\n[1, 2]\n{}", SAMPLE_BODY);
+
+        let events = spawn_collect_n(
+            async move { assert_eq!(200, send(addr, &body, None).await) },
+            rx,
+            2,
+        )
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["ClientIP"], "192.0.2.1".into());
+    }
+
+    #[tokio::test]
+    async fn rejects_failed_deliveries() {
+        let (rx, addr) = source(None, EventStatus::Rejected).await;
+
+        let events = spawn_collect_n(
+            async move { assert_eq!(400, send(addr, SAMPLE_BODY, None).await) },
+            rx,
+            2,
+        )
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["dataset"], Value::from("http_requests"));
+    }
+
+    #[tokio::test]
+    async fn auth_failure() {
+        let (_rx, addr) = source(Some(make_auth()), EventStatus::Delivered).await;
+
+        assert_eq!(401, send(addr, SAMPLE_BODY, Some(make_auth())).await);
+    }
+}
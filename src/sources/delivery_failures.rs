@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use lookup::owned_value_path;
+use tokio::sync::broadcast::{self, error::RecvError};
+use value::{kind::Collection, Kind};
+use vector_common::{
+    finalization::BatchStatus,
+    internal_event::{CountByteSize, EventsReceived, InternalEventHandle as _},
+};
+use vector_config::configurable_component;
+use vector_core::{
+    config::LogNamespace,
+    event::{EstimatedJsonEncodedSizeOf, Event, LogEvent, Value},
+    schema::Definition,
+};
+
+use crate::{
+    config::{DataType, SourceConfig, SourceContext, SourceOutput},
+    internal_events::{DeliveryFailuresLagged, StreamClosedError},
+    shutdown::ShutdownSignal,
+    topology::delivery_failures::{self, DeliveryFailure},
+    SourceSender,
+};
+
+/// Configuration for the `delivery_failures` source.
+#[configurable_component(source(
+    "delivery_failures",
+    "Emit an event for each batch of events that a sink fails to deliver."
+))]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct DeliveryFailuresConfig {
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+impl_generate_config_from_default!(DeliveryFailuresConfig);
+
+impl DeliveryFailuresConfig {
+    /// Generates the `schema::Definition` for this component.
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        Definition::new_with_default_metadata(Kind::object(Collection::empty()), [log_namespace])
+            .with_event_field(&owned_value_path!("component_id"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("status"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("count"), Kind::integer(), None)
+            .with_event_field(
+                &owned_value_path!("events"),
+                Kind::array(Collection::empty().with_unknown(Kind::object(Collection::any()))),
+                None,
+            )
+            .with_standard_vector_source_metadata()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "delivery_failures")]
+impl SourceConfig for DeliveryFailuresConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let log_namespace = cx.log_namespace(self.log_namespace);
+        let failures = delivery_failures::subscribe();
+
+        Ok(Box::pin(run(failures, cx.out, cx.shutdown, log_namespace)))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_definition =
+            self.schema_definition(global_log_namespace.merge(self.log_namespace));
+
+        vec![SourceOutput::new_logs(DataType::Log, schema_definition)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+async fn run(
+    mut failures: broadcast::Receiver<DeliveryFailure>,
+    mut out: SourceSender,
+    mut shutdown: ShutdownSignal,
+    log_namespace: LogNamespace,
+) -> Result<(), ()> {
+    let events_received = register!(EventsReceived);
+
+    loop {
+        let failure = tokio::select! {
+            _ = &mut shutdown => break,
+            failure = failures.recv() => failure,
+        };
+        let failure = match failure {
+            Ok(failure) => failure,
+            Err(RecvError::Lagged(count)) => {
+                emit!(DeliveryFailuresLagged { count });
+                continue;
+            }
+            Err(RecvError::Closed) => break,
+        };
+
+        let log = to_log(failure, log_namespace);
+        events_received.emit(CountByteSize(1, log.estimated_json_encoded_size_of()));
+
+        if let Err(error) = out.send_event(Event::from(log)).await {
+            emit!(StreamClosedError { error, count: 1 });
+            return Err(());
+        }
+    }
+
+    Ok(())
+}
+
+fn to_log(failure: DeliveryFailure, log_namespace: LogNamespace) -> LogEvent {
+    let status = match failure.status {
+        BatchStatus::Delivered => "delivered",
+        BatchStatus::Errored => "errored",
+        BatchStatus::Rejected => "rejected",
+    };
+
+    let mut fields = BTreeMap::new();
+    fields.insert("component_id".to_owned(), Value::from(failure.component_id));
+    fields.insert("status".to_owned(), Value::from(status));
+    fields.insert("count".to_owned(), Value::from(failure.events.len() as i64));
+    fields.insert("events".to_owned(), Value::Array(failure.events));
+
+    let mut log = LogEvent::from(fields);
+    log_namespace.insert_standard_vector_source_metadata(
+        &mut log,
+        DeliveryFailuresConfig::NAME,
+        Utc::now(),
+    );
+    log
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<DeliveryFailuresConfig>();
+    }
+
+    #[test]
+    fn converts_failures_to_logs() {
+        let mut key = BTreeMap::new();
+        key.insert("id".to_owned(), Value::from("a"));
+        let failure = DeliveryFailure {
+            component_id: "out".to_owned(),
+            status: BatchStatus::Rejected,
+            events: vec![Value::Object(key.clone())],
+        };
+
+        let log = to_log(failure, LogNamespace::Legacy);
+
+        assert_eq!(log["component_id"], "out".into());
+        assert_eq!(log["status"], "rejected".into());
+        assert_eq!(log["count"], 1.into());
+        assert_eq!(log["events"], Value::Array(vec![Value::Object(key)]));
+        assert_eq!(log["source_type"], "delivery_failures".into());
+    }
+}
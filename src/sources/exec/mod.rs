@@ -9,11 +9,11 @@ use codecs::{
     decoding::{DeserializerConfig, FramingConfig},
     StreamDecodingError,
 };
-use futures::StreamExt;
+use futures::{stream::FuturesOrdered, StreamExt};
 use smallvec::SmallVec;
 use snafu::Snafu;
 use tokio::{
-    io::{AsyncRead, BufReader},
+    io::{AsyncRead, AsyncWriteExt, BufReader},
     process::Command,
     sync::mpsc::{channel, Sender},
     time::{self, sleep, Duration, Instant},
@@ -23,17 +23,22 @@ use tokio_util::codec::FramedRead;
 use value::Kind;
 use vector_common::internal_event::{ByteSize, BytesReceived, InternalEventHandle as _, Protocol};
 use vector_config::configurable_component;
-use vector_core::{config::LegacyKey, EstimatedJsonEncodedSizeOf};
+use vector_core::{
+    config::LegacyKey,
+    event::{BatchNotifier, BatchStatus},
+    EstimatedJsonEncodedSizeOf,
+};
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{SourceConfig, SourceContext, SourceOutput},
+    config::{SourceAcknowledgementsConfig, SourceConfig, SourceContext, SourceOutput},
     event::Event,
     internal_events::{
-        ExecChannelClosedError, ExecCommandExecuted, ExecEventsReceived, ExecFailedError,
-        ExecFailedToSignalChild, ExecFailedToSignalChildError, ExecTimeoutError, StreamClosedError,
+        ExecAcknowledgementWriteError, ExecChannelClosedError, ExecCommandExecuted,
+        ExecEventsReceived, ExecFailedError, ExecFailedToSignalChild, ExecFailedToSignalChildError,
+        ExecTimeoutError, StreamClosedError,
     },
-    serde::default_decoding,
+    serde::{bool_or_struct, default_decoding},
     shutdown::ShutdownSignal,
     SourceSender,
 };
@@ -78,6 +83,20 @@ pub struct ExecConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// Whether to write the delivery status of the output of the command to its standard input.
+    ///
+    /// When enabled, a line reading `ack` is written to the standard input of the command once the
+    /// events decoded from a frame of its output are delivered, or `nack` if they are rejected or
+    /// fail to be delivered. The lines are written in the order of the output, while the output
+    /// keeps being read, so the command must read them as it goes. This requires acknowledgements
+    /// to be enabled.
+    #[serde(default)]
+    pub stdin_acknowledgements: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -128,6 +147,8 @@ pub enum ExecConfigError {
     CommandEmpty,
     #[snafu(display("The maximum buffer size must be greater than zero"))]
     ZeroBuffer,
+    #[snafu(display("Acknowledgements must be enabled to write them to stdin"))]
+    StdinAcknowledgementsDisabled,
 }
 
 impl Default for ExecConfig {
@@ -144,6 +165,8 @@ impl Default for ExecConfig {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            acknowledgements: Default::default(),
+            stdin_acknowledgements: false,
             log_namespace: None,
         }
     }
@@ -226,13 +249,17 @@ impl SourceConfig for ExecConfig {
         self.validate()?;
         let hostname = get_hostname();
         let log_namespace = cx.log_namespace(self.log_namespace);
+        let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+        if self.stdin_acknowledgements && !acknowledgements {
+            return Err(ExecConfigError::StdinAcknowledgementsDisabled.into());
+        }
 
         let framing = self
             .framing
             .clone()
             .unwrap_or_else(|| self.decoding.default_stream_framing());
         let decoder =
-            DecodingConfig::new(framing, self.decoding.clone(), LogNamespace::Legacy).build();
+            DecodingConfig::new(framing, self.decoding.clone(), LogNamespace::Legacy).build()?;
 
         match &self.mode {
             Mode::Scheduled => {
@@ -246,6 +273,7 @@ impl SourceConfig for ExecConfig {
                     cx.shutdown,
                     cx.out,
                     log_namespace,
+                    acknowledgements,
                 )))
             }
             Mode::Streaming => {
@@ -261,6 +289,7 @@ impl SourceConfig for ExecConfig {
                     cx.shutdown,
                     cx.out,
                     log_namespace,
+                    acknowledgements,
                 )))
             }
         }
@@ -311,10 +340,11 @@ impl SourceConfig for ExecConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        true
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_scheduled(
     config: ExecConfig,
     hostname: Option<String>,
@@ -323,6 +353,7 @@ async fn run_scheduled(
     shutdown: ShutdownSignal,
     out: SourceSender,
     log_namespace: LogNamespace,
+    acknowledgements: bool,
 ) -> Result<(), ()> {
     debug!("Starting scheduled exec runs.");
     let schedule = Duration::from_secs(exec_interval_secs);
@@ -340,6 +371,7 @@ async fn run_scheduled(
                 shutdown.clone(),
                 out.clone(),
                 log_namespace,
+                acknowledgements,
             ),
         )
         .await;
@@ -377,6 +409,7 @@ async fn run_streaming(
     mut shutdown: ShutdownSignal,
     out: SourceSender,
     log_namespace: LogNamespace,
+    acknowledgements: bool,
 ) -> Result<(), ()> {
     if respawn_on_exit {
         let duration = Duration::from_secs(respawn_interval_secs);
@@ -390,6 +423,7 @@ async fn run_streaming(
                 shutdown.clone(),
                 out.clone(),
                 log_namespace,
+                acknowledgements,
             )
             .await;
 
@@ -414,6 +448,7 @@ async fn run_streaming(
             shutdown,
             out,
             log_namespace,
+            acknowledgements,
         )
         .await;
 
@@ -435,6 +470,7 @@ async fn run_command(
     mut shutdown: ShutdownSignal,
     mut out: SourceSender,
     log_namespace: LogNamespace,
+    acknowledgements: bool,
 ) -> Result<Option<ExitStatus>, Error> {
     debug!("Starting command run.");
     let mut command = build_command(&config);
@@ -470,20 +506,50 @@ async fn run_command(
 
     let pid = child.id();
 
+    // The delivery status of the output is written back to the command, if requested.
+    let mut stdin = if config.stdin_acknowledgements {
+        child.stdin.take()
+    } else {
+        None
+    };
+
     spawn_reader_thread(stdout_reader, decoder.clone(), STDOUT, sender);
 
     let bytes_received = register!(BytesReceived::from(Protocol::NONE));
 
-    'outer: loop {
+    // The delivery status of each frame of the output, in the order of the output, which is
+    // written back to the command as it becomes known.
+    let mut pending_acks = FuturesOrdered::new();
+    let mut output_open = true;
+    let mut shutting_down = false;
+
+    loop {
         tokio::select! {
-            _ = &mut shutdown => {
+            _ = &mut shutdown, if !shutting_down => {
+                shutting_down = true;
                 if !shutdown_child(&mut child, &command).await {
-                        break 'outer; // couldn't signal, exit early
+                    break; // couldn't signal, exit early
                 }
             }
-            v = receiver.recv() => {
+            Some(status) = pending_acks.next(), if !pending_acks.is_empty() => {
+                if let Some(writer) = stdin.as_mut() {
+                    let line: &[u8] = match status {
+                        BatchStatus::Delivered => b"ack\n",
+                        BatchStatus::Errored | BatchStatus::Rejected => b"nack\n",
+                    };
+                    if let Err(error) = writer.write_all(line).await {
+                        emit!(ExecAcknowledgementWriteError {
+                            command: config.command_line().as_str(),
+                            error,
+                        });
+                        stdin = None;
+                        pending_acks = FuturesOrdered::new();
+                    }
+                }
+            }
+            v = receiver.recv(), if output_open => {
                 match v {
-                    None => break 'outer,
+                    None => output_open = false,
                     Some(((mut events, byte_size), stream)) => {
                         bytes_received.emit(ByteSize(byte_size));
 
@@ -497,14 +563,31 @@ async fn run_command(
                         for event in &mut events {
                             handle_event(&config, &hostname, &Some(stream.to_string()), pid, event, log_namespace);
                         }
+
+                        let (batch, receiver) = BatchNotifier::maybe_new_with_receiver(acknowledgements);
+                        let events = events
+                            .into_iter()
+                            .map(|event| event.with_batch_notifier_option(&batch))
+                            .collect::<SmallVec<[Event; 1]>>();
+                        drop(batch);
                         if let Err(error) = out.send_batch(events).await {
                             emit!(StreamClosedError { count, error });
                             break;
                         }
+
+                        if let (Some(receiver), Some(_)) = (receiver, &stdin) {
+                            pending_acks.push_back(receiver);
+                        }
                     },
                 }
             }
         }
+
+        // Once the output is exhausted, the delivery status of the rest of it is still written
+        // back, unless shutting down, as the rest may then never be delivered.
+        if !output_open && (pending_acks.is_empty() || shutting_down) {
+            break;
+        }
     }
 
     let elapsed = start.elapsed();
@@ -621,8 +704,12 @@ fn build_command(config: &ExecConfig) -> Command {
         command.stderr(std::process::Stdio::null());
     }
 
-    // Stdin is not needed
-    command.stdin(std::process::Stdio::null());
+    // Stdin is only needed to write acknowledgements to the process
+    if config.stdin_acknowledgements {
+        command.stdin(std::process::Stdio::piped());
+    } else {
+        command.stdin(std::process::Stdio::null());
+    }
 
     command
 }
@@ -721,7 +808,7 @@ fn spawn_reader_thread<R: 'static + AsyncRead + Unpin + std::marker::Send>(
 mod tests {
     use bytes::Bytes;
     use std::io::Cursor;
-    use vector_core::event::EventMetadata;
+    use vector_core::event::{EventMetadata, EventStatus};
 
     #[cfg(unix)]
     use futures::task::Poll;
@@ -987,6 +1074,7 @@ mod tests {
                 shutdown,
                 tx,
                 LogNamespace::Legacy,
+                false,
             ),
         );
 
@@ -1022,6 +1110,7 @@ mod tests {
                         shutdown,
                         tx,
                         LogNamespace::Legacy,
+                        false,
                     ),
                 )
                 .await;
@@ -1080,6 +1169,7 @@ mod tests {
             shutdown,
             tx,
             LogNamespace::Legacy,
+            false,
         ));
 
         tokio::time::sleep(Duration::from_secs(1)).await; // let the source start the command
@@ -1112,6 +1202,46 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_stdin_acknowledgements() {
+        trace_init();
+        let mut config = standard_streaming_test_config();
+        config.command = vec![
+            String::from("bash"),
+            String::from("-c"),
+            String::from(r#"echo first ; read status ; echo "$status""#),
+        ];
+        config.stdin_acknowledgements = true;
+        let hostname = Some("Some.Machine".to_string());
+        let decoder = Default::default();
+        let shutdown = ShutdownSignal::noop();
+        let (tx, mut rx) = SourceSender::new_test_finalize(EventStatus::Rejected);
+
+        let task = tokio::spawn(run_command(
+            config,
+            hostname,
+            decoder,
+            shutdown,
+            tx,
+            LogNamespace::Legacy,
+            true,
+        ));
+
+        let messages = tokio::time::timeout(
+            time::Duration::from_secs(5),
+            (&mut rx).take(2).collect::<Vec<_>>(),
+        )
+        .await
+        .expect("command timed out")
+        .into_iter()
+        .map(|event| event.as_log()[log_schema().message_key()].clone())
+        .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["first".into(), "nack".into()]);
+
+        task.await.unwrap().expect("command error");
+    }
+
     fn standard_scheduled_test_config() -> ExecConfig {
         Default::default()
     }
@@ -1130,6 +1260,8 @@ mod tests {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            acknowledgements: Default::default(),
+            stdin_acknowledgements: false,
             log_namespace: None,
         }
     }
@@ -24,8 +24,8 @@ use vector_core::config::{LegacyKey, LogNamespace};
 use super::util::{EncodingConfig, MultilineConfig};
 use crate::{
     config::{
-        log_schema, DataType, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
-        SourceOutput,
+        log_schema, DataDirSubsystem, DataType, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext, SourceOutput,
     },
     encoding_transcode::{Decoder, Encoder},
     event::{BatchNotifier, BatchStatus, LogEvent},
@@ -66,6 +66,8 @@ enum BuildError {
         indicator: String,
         source: regex::Error,
     },
+    #[snafu(display("archive_dir option requires remove_after_secs to be set"))]
+    ArchiveDirWithoutRemoveAfter,
 }
 
 /// Configuration for the `file` source.
@@ -210,6 +212,10 @@ pub struct FileConfig {
 
     /// Timeout from reaching `EOF` after which the file is removed from the filesystem, unless new data is written in the meantime.
     ///
+    /// Files are only removed once all the data read from them has been checkpointed. When
+    /// acknowledgements are enabled, this is once all the events read from them have been
+    /// acknowledged.
+    ///
     /// If not specified, files are not removed.
     #[serde(alias = "remove_after", default)]
     #[configurable(metadata(docs::type_unit = "seconds"))]
@@ -218,6 +224,15 @@ pub struct FileConfig {
     #[configurable(metadata(docs::examples = 60))]
     pub remove_after_secs: Option<u64>,
 
+    /// The directory that files are moved to, instead of being deleted, when `remove_after_secs` is set.
+    ///
+    /// Archived files keep their name, suffixed with a number if a file of the same name was already
+    /// archived. The directory is created if it doesn't exist, and must not be matched by the
+    /// `include` patterns, otherwise archived files are read again.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/log/archive"))]
+    pub archive_dir: Option<PathBuf>,
+
     /// String sequence used to separate one file line from another.
     #[serde(default = "default_line_delimiter")]
     #[configurable(metadata(docs::examples = "\r\n"))]
@@ -382,6 +397,7 @@ impl Default for FileConfig {
             max_read_bytes: default_max_read_bytes(),
             oldest_first: false,
             remove_after_secs: None,
+            archive_dir: None,
             line_delimiter: default_line_delimiter(),
             encoding: None,
             acknowledgements: Default::default(),
@@ -403,7 +419,11 @@ impl SourceConfig for FileConfig {
         let data_dir = cx
             .globals
             // source are only global, name can be used for subdir
-            .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())?;
+            .resolve_and_make_data_subdir_for(
+                DataDirSubsystem::Checkpoints,
+                self.data_dir.as_ref(),
+                cx.key.id(),
+            )?;
 
         // Clippy rule, because async_trait?
         #[allow(clippy::suspicious_else_formatting)]
@@ -416,6 +436,10 @@ impl SourceConfig for FileConfig {
                 Regex::new(indicator)
                     .with_context(|_| InvalidMessageStartIndicatorSnafu { indicator })?;
             }
+
+            if self.archive_dir.is_some() && self.remove_after_secs.is_none() {
+                return Err(BuildError::ArchiveDirWithoutRemoveAfter.into());
+            }
         }
 
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
@@ -532,6 +556,7 @@ pub fn file_source(
         },
         oldest_first: config.oldest_first,
         remove_after: config.remove_after_secs.map(Duration::from_secs),
+        archive_dir: config.archive_dir.clone(),
         emitter: FileSourceInternalEventsEmitter,
         handle: tokio::runtime::Handle::current(),
     };
@@ -2242,6 +2267,79 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn remove_file_waits_for_acknowledgements() {
+        let remove_after_secs = 1;
+
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            remove_after_secs: Some(remove_after_secs),
+            ..test_default_file_config(&dir)
+        };
+
+        let path = dir.path().join("file");
+        let received = run_file_source(&config, false, Unfinalized, LogNamespace::Legacy, async {
+            let mut file = File::create(&path).unwrap();
+
+            sleep_500_millis().await; // The files must be observed at their original lengths before writing to them
+
+            writeln!(&mut file, "unacknowledged").unwrap();
+            drop(file);
+
+            sleep(Duration::from_secs(remove_after_secs * 3)).await;
+        })
+        .await;
+
+        assert_eq!(received.len(), 1);
+        assert!(path.exists(), "File was removed before being acknowledged");
+    }
+
+    #[tokio::test]
+    async fn archive_file() {
+        let n = 5;
+        let remove_after_secs = 1;
+
+        let dir = tempdir().unwrap();
+        let archive_dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            remove_after_secs: Some(remove_after_secs),
+            archive_dir: Some(archive_dir.path().join("archive")),
+            ..test_default_file_config(&dir)
+        };
+
+        let path = dir.path().join("file");
+        let archived_path = archive_dir.path().join("archive").join("file");
+        let received = run_file_source(&config, false, Acks, LogNamespace::Legacy, async {
+            let mut file = File::create(&path).unwrap();
+
+            sleep_500_millis().await; // The files must be observed at their original lengths before writing to them
+
+            for i in 0..n {
+                writeln!(&mut file, "{}", i).unwrap();
+            }
+            drop(file);
+
+            for _ in 0..10 {
+                // Wait for remove grace period to end.
+                sleep(Duration::from_secs(remove_after_secs + 1)).await;
+
+                if archived_path.exists() {
+                    break;
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(received.len(), n);
+        assert!(!path.exists(), "File wasn't moved");
+        assert_eq!(
+            std::fs::read_to_string(&archived_path).unwrap(),
+            "0\n1\n2\n3\n4\n"
+        );
+    }
+
     #[derive(Clone, Copy, Eq, PartialEq)]
     enum AckingMode {
         NoAcks,      // No acknowledgement handling and no finalization
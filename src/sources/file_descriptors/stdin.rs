@@ -115,14 +115,82 @@ mod tests {
         test_util::components::assert_source_compliance, test_util::components::SOURCE_TAGS,
         SourceSender,
     };
+    use bytes::BytesMut;
+    use codecs::{encoding::Framer, LengthDelimitedEncoder, NativeSerializerConfig};
     use futures::StreamExt;
     use lookup::path;
+    use tokio_util::codec::Encoder as _;
+    use vector_core::event::{Event, LogEvent, Metric, MetricKind, MetricValue};
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<StdinConfig>();
     }
 
+    #[tokio::test]
+    async fn stdin_decodes_native_events() {
+        let mut log = LogEvent::from("hello world");
+        log.insert("source_type", "demo_logs");
+        log.metadata_mut()
+            .value_mut()
+            .insert(path!("origin"), "upstream");
+        let events = vec![
+            Event::Log(log),
+            Event::Metric(Metric::new(
+                "requests",
+                MetricKind::Incremental,
+                MetricValue::Counter { value: 1.0 },
+            )),
+        ];
+
+        // Encoded as the `console` sink does with `encoding.codec = "native"`.
+        let mut encoder = crate::codecs::Encoder::<Framer>::new(
+            LengthDelimitedEncoder::new().into(),
+            NativeSerializerConfig.build().into(),
+        );
+        let mut buf = BytesMut::new();
+        for event in events.clone() {
+            encoder.encode(event, &mut buf).unwrap();
+        }
+
+        assert_source_compliance(&SOURCE_TAGS, async {
+            let (tx, rx) = SourceSender::new_test();
+            let config = StdinConfig {
+                decoding: DeserializerConfig::Native,
+                ..Default::default()
+            };
+
+            config
+                .source(
+                    Cursor::new(buf.freeze()),
+                    ShutdownSignal::noop(),
+                    tx,
+                    LogNamespace::Legacy,
+                )
+                .unwrap()
+                .await
+                .unwrap();
+
+            let received = rx.collect::<Vec<_>>().await;
+            assert_eq!(received.len(), 2);
+
+            let log = received[0].as_log();
+            assert_eq!(log["message"], "hello world".into());
+            // Fields set upstream are kept rather than overwritten by the source.
+            assert_eq!(log["source_type"], "demo_logs".into());
+            assert_eq!(
+                log.metadata().value().get(path!("origin")),
+                Some(&vrl::value!("upstream"))
+            );
+            assert_eq!(received[1].as_metric().name(), "requests");
+            assert_eq!(
+                received[1].as_metric().value(),
+                events[1].as_metric().value()
+            );
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn stdin_decodes_line() {
         assert_source_compliance(&SOURCE_TAGS, async {
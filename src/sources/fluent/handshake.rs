@@ -0,0 +1,307 @@
+//! Shared key authentication of the Forward protocol.
+//!
+//! When a shared key is configured, the source sends a `HELO` message as soon as a client
+//! connects, and waits for the client to answer with a `PING` message proving that it knows the
+//! key. The source then answers with a `PONG` message, proving that it knows the key too, and
+//! only reads events from the connection if the client was authenticated.
+//!
+//! <https://github.com/fluent/fluentd/wiki/Forward-Protocol-Specification-v1#handshake-messages>
+
+use std::{io, time::Duration};
+
+use bytes::BytesMut;
+use rmpv::Value;
+use sha2::{Digest, Sha512};
+use snafu::{ResultExt, Snafu};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+
+/// How long clients have to complete the handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The maximum size of the `PING` message sent by clients.
+const MAX_PING_BYTES: usize = 64 * 1024;
+
+/// Shared key authentication of the clients.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FluentSecurityConfig {
+    /// The hostname the source identifies itself with during the handshake.
+    #[configurable(metadata(docs::examples = "vector.example.com"))]
+    pub self_hostname: String,
+
+    /// The key shared with the clients.
+    ///
+    /// Clients must prove that they know the key, with the handshake of the Forward protocol,
+    /// before sending events.
+    #[configurable(metadata(docs::examples = "${FLUENT_SHARED_KEY}"))]
+    pub shared_key: SensitiveString,
+}
+
+#[derive(Debug, Snafu)]
+pub enum HandshakeError {
+    #[snafu(display("handshake timed out"))]
+    TimedOut,
+    #[snafu(display("i/o error: {}", source))]
+    Io { source: io::Error },
+    #[snafu(display("invalid PING message: {}", reason))]
+    InvalidPing { reason: &'static str },
+    #[snafu(display("shared key mismatch for client {:?}", hostname))]
+    SharedKeyMismatch { hostname: String },
+}
+
+/// Authenticates the client connected to the socket.
+pub(super) async fn handshake<S>(
+    socket: &mut S,
+    security: &FluentSecurityConfig,
+) -> Result<(), HandshakeError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    tokio::time::timeout(HANDSHAKE_TIMEOUT, authenticate(socket, security))
+        .await
+        .map_err(|_| HandshakeError::TimedOut)?
+}
+
+async fn authenticate<S>(
+    socket: &mut S,
+    security: &FluentSecurityConfig,
+) -> Result<(), HandshakeError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let nonce: [u8; 16] = rand::random();
+    write_message(socket, helo(&nonce)).await?;
+
+    let ping = Ping::parse(read_message(socket).await?)?;
+    let shared_key = security.shared_key.inner().as_bytes();
+    let authenticated =
+        ping.digest == digest(&ping.salt, ping.hostname.as_bytes(), &nonce, shared_key);
+
+    let pong = if authenticated {
+        let digest = digest(
+            &ping.salt,
+            security.self_hostname.as_bytes(),
+            &nonce,
+            shared_key,
+        );
+        pong(true, "", &security.self_hostname, &digest)
+    } else {
+        pong(false, "shared_key mismatch", &security.self_hostname, "")
+    };
+    write_message(socket, pong).await?;
+
+    if authenticated {
+        Ok(())
+    } else {
+        Err(HandshakeError::SharedKeyMismatch {
+            hostname: ping.hostname,
+        })
+    }
+}
+
+/// The `PING` message, sent by clients in answer to the `HELO` message.
+struct Ping {
+    hostname: String,
+    salt: Vec<u8>,
+    digest: String,
+}
+
+impl Ping {
+    fn parse(message: Value) -> Result<Self, HandshakeError> {
+        let invalid = |reason| HandshakeError::InvalidPing { reason };
+
+        let fields = message.as_array().ok_or_else(|| invalid("not an array"))?;
+        if fields.first().and_then(Value::as_str) != Some("PING") {
+            return Err(invalid("not a PING message"));
+        }
+        let hostname = fields
+            .get(1)
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("missing client hostname"))?;
+        let salt = fields
+            .get(2)
+            .and_then(bytes_of)
+            .ok_or_else(|| invalid("missing shared key salt"))?;
+        let digest = fields
+            .get(3)
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("missing shared key digest"))?;
+
+        Ok(Self {
+            hostname: hostname.to_owned(),
+            salt: salt.to_vec(),
+            digest: digest.to_owned(),
+        })
+    }
+}
+
+fn bytes_of(value: &Value) -> Option<&[u8]> {
+    match value {
+        Value::Binary(bytes) => Some(bytes),
+        Value::String(string) => Some(string.as_bytes()),
+        _ => None,
+    }
+}
+
+fn helo(nonce: &[u8]) -> Value {
+    Value::Array(vec![
+        Value::from("HELO"),
+        Value::Map(vec![
+            (Value::from("nonce"), Value::Binary(nonce.to_vec())),
+            // User authentication isn't supported, so no salt is sent for it.
+            (Value::from("auth"), Value::Binary(Vec::new())),
+            (Value::from("keepalive"), Value::Boolean(true)),
+        ]),
+    ])
+}
+
+fn pong(authenticated: bool, reason: &str, hostname: &str, digest: &str) -> Value {
+    Value::Array(vec![
+        Value::from("PONG"),
+        Value::Boolean(authenticated),
+        Value::from(reason),
+        Value::from(hostname),
+        Value::from(digest),
+    ])
+}
+
+/// The hex encoded SHA-512 digest proving the knowledge of the shared key.
+fn digest(salt: &[u8], hostname: &[u8], nonce: &[u8], shared_key: &[u8]) -> String {
+    hex::encode(
+        Sha512::new()
+            .chain_update(salt)
+            .chain_update(hostname)
+            .chain_update(nonce)
+            .chain_update(shared_key)
+            .finalize(),
+    )
+}
+
+async fn write_message<S>(socket: &mut S, message: Value) -> Result<(), HandshakeError>
+where
+    S: AsyncWrite + Unpin,
+{
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, &message).expect("writing to a Vec can't fail");
+    socket.write_all(&buf).await.context(IoSnafu)?;
+    socket.flush().await.context(IoSnafu)
+}
+
+async fn read_message<S>(socket: &mut S) -> Result<Value, HandshakeError>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut buf = BytesMut::new();
+    loop {
+        if socket.read_buf(&mut buf).await.context(IoSnafu)? == 0 {
+            return Err(HandshakeError::Io {
+                source: io::ErrorKind::UnexpectedEof.into(),
+            });
+        }
+
+        match rmpv::decode::read_value(&mut &buf[..]) {
+            Ok(message) => return Ok(message),
+            Err(
+                rmpv::decode::Error::InvalidMarkerRead(error)
+                | rmpv::decode::Error::InvalidDataRead(error),
+            ) if error.kind() == io::ErrorKind::UnexpectedEof && buf.len() < MAX_PING_BYTES => {
+                continue
+            }
+            Err(_) => {
+                return Err(HandshakeError::InvalidPing {
+                    reason: "malformed message",
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::DuplexStream;
+
+    use super::*;
+
+    fn security() -> FluentSecurityConfig {
+        FluentSecurityConfig {
+            self_hostname: "vector".to_owned(),
+            shared_key: "secret".to_owned().into(),
+        }
+    }
+
+    /// Runs the client side of the handshake, returning the `PONG` message.
+    async fn client(mut socket: DuplexStream, shared_key: &str) -> Value {
+        let helo = read_message(&mut socket).await.unwrap();
+        assert_eq!(helo[0].as_str(), Some("HELO"));
+        let nonce = helo[1]
+            .as_map()
+            .unwrap()
+            .iter()
+            .find(|(key, _)| key.as_str() == Some("nonce"))
+            .and_then(|(_, value)| bytes_of(value))
+            .unwrap()
+            .to_vec();
+
+        let salt = b"0123456789abcdef";
+        let ping = Value::Array(vec![
+            Value::from("PING"),
+            Value::from("fluent-bit"),
+            Value::Binary(salt.to_vec()),
+            Value::from(digest(salt, b"fluent-bit", &nonce, shared_key.as_bytes())),
+            Value::from(""),
+            Value::from(""),
+        ]);
+        write_message(&mut socket, ping).await.unwrap();
+
+        let pong = read_message(&mut socket).await.unwrap();
+        assert_eq!(pong[0].as_str(), Some("PONG"));
+        if pong[1].as_bool() == Some(true) {
+            assert_eq!(
+                pong[4].as_str(),
+                Some(digest(salt, b"vector", &nonce, shared_key.as_bytes()).as_str())
+            );
+        }
+        pong
+    }
+
+    #[tokio::test]
+    async fn accepts_shared_key() {
+        let (mut server, client_socket) = tokio::io::duplex(1024);
+        let client = tokio::spawn(client(client_socket, "secret"));
+
+        handshake(&mut server, &security()).await.unwrap();
+
+        let pong = client.await.unwrap();
+        assert_eq!(pong[1].as_bool(), Some(true));
+        assert_eq!(pong[3].as_str(), Some("vector"));
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_shared_key() {
+        let (mut server, client_socket) = tokio::io::duplex(1024);
+        let client = tokio::spawn(client(client_socket, "wrong"));
+
+        let error = handshake(&mut server, &security()).await.unwrap_err();
+        assert!(
+            matches!(error, HandshakeError::SharedKeyMismatch { hostname } if hostname == "fluent-bit")
+        );
+
+        let pong = client.await.unwrap();
+        assert_eq!(pong[1].as_bool(), Some(false));
+        assert_eq!(pong[2].as_str(), Some("shared_key mismatch"));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ping() {
+        let (mut server, mut client) = tokio::io::duplex(1024);
+        write_message(&mut client, Value::Array(vec![Value::from("EVENT")]))
+            .await
+            .unwrap();
+
+        let error = handshake(&mut server, &security()).await.unwrap_err();
+        assert!(matches!(error, HandshakeError::InvalidPing { .. }));
+    }
+}
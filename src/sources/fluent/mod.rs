@@ -1,5 +1,6 @@
 use std::io::{self, Read};
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use base64::prelude::{Engine as _, BASE64_STANDARD};
@@ -7,11 +8,13 @@ use bytes::{Buf, Bytes, BytesMut};
 use chrono::Utc;
 use codecs::{BytesDeserializerConfig, StreamDecodingError};
 use flate2::read::MultiGzDecoder;
+use futures::future::BoxFuture;
 use lookup::lookup_v2::parse_value_path;
 use lookup::{metadata_path, owned_value_path, path, OwnedValuePath, PathPrefix};
 use rmp_serde::{decode, Deserializer};
 use serde::Deserialize;
 use smallvec::{smallvec, SmallVec};
+use tokio::net::TcpStream;
 use tokio_util::codec::Decoder;
 use value::kind::Collection;
 use value::{Kind, Value};
@@ -26,13 +29,15 @@ use crate::{
         SourceContext, SourceOutput,
     },
     event::{Event, LogEvent},
-    internal_events::{FluentMessageDecodeError, FluentMessageReceived},
+    internal_events::{FluentHandshakeError, FluentMessageDecodeError, FluentMessageReceived},
     serde::bool_or_struct,
     tcp::TcpKeepaliveConfig,
-    tls::{MaybeTlsSettings, TlsSourceConfig},
+    tls::{MaybeTlsIncomingStream, MaybeTlsSettings, TlsSourceConfig},
 };
 
+mod handshake;
 mod message;
+pub use self::handshake::{FluentSecurityConfig, HandshakeError};
 use self::message::{FluentEntry, FluentMessage, FluentRecord, FluentTag, FluentTimestamp};
 
 /// Configuration for the `fluent` source.
@@ -59,6 +64,9 @@ pub struct FluentConfig {
     #[configurable(derived)]
     tls: Option<TlsSourceConfig>,
 
+    #[configurable(derived)]
+    security: Option<FluentSecurityConfig>,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -75,6 +83,7 @@ impl GenerateConfig for FluentConfig {
             address: SocketListenAddr::SocketAddr("0.0.0.0:24224".parse().unwrap()),
             keepalive: None,
             tls: None,
+            security: None,
             receive_buffer_bytes: None,
             acknowledgements: Default::default(),
             connection_limit: Some(2),
@@ -89,7 +98,7 @@ impl GenerateConfig for FluentConfig {
 impl SourceConfig for FluentConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
         let log_namespace = cx.log_namespace(self.log_namespace);
-        let source = FluentSource::new(log_namespace);
+        let source = FluentSource::new(log_namespace, self.security.clone());
         let shutdown_secs = Duration::from_secs(30);
         let tls_config = self.tls.as_ref().map(|tls| tls.tls_config.clone());
         let tls_client_metadata_key = self
@@ -202,13 +211,15 @@ impl FluentConfig {
 struct FluentSource {
     log_namespace: LogNamespace,
     legacy_host_key_path: Option<OwnedValuePath>,
+    security: Option<Arc<FluentSecurityConfig>>,
 }
 
 impl FluentSource {
-    fn new(log_namespace: LogNamespace) -> Self {
+    fn new(log_namespace: LogNamespace, security: Option<FluentSecurityConfig>) -> Self {
         Self {
             log_namespace,
             legacy_host_key_path: parse_value_path(log_schema().host_key()).ok(),
+            security: security.map(Arc::new),
         }
     }
 }
@@ -245,6 +256,20 @@ impl TcpSource for FluentSource {
     fn build_acker(&self, frame: &[Self::Item]) -> Self::Acker {
         FluentAcker::new(frame)
     }
+
+    fn handshake<'a>(
+        &'a self,
+        socket: &'a mut MaybeTlsIncomingStream<TcpStream>,
+    ) -> BoxFuture<'a, Result<(), ()>> {
+        Box::pin(async move {
+            let Some(security) = &self.security else {
+                return Ok(());
+            };
+            handshake::handshake(socket, security)
+                .await
+                .map_err(|error| emit!(FluentHandshakeError { error }))
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -527,18 +552,17 @@ impl FluentAcker {
 }
 
 impl TcpSourceAcker for FluentAcker {
+    // Acks are msgpack maps of the chunk identifier. When the events aren't delivered, no ack is
+    // sent, so that clients send the chunk again.
     fn build_ack(self, ack: TcpSourceAck) -> Option<Bytes> {
-        if self.chunks.is_empty() {
+        if self.chunks.is_empty() || ack != TcpSourceAck::Ack {
             return None;
         }
 
-        let mut acks = String::new();
+        let mut acks = Vec::new();
         for chunk in self.chunks {
-            let ack = match ack {
-                TcpSourceAck::Ack => format!(r#"{{"ack": "{}"}}"#, chunk),
-                _ => String::from("{}"),
-            };
-            acks.push_str(&ack);
+            let ack = rmpv::Value::Map(vec![("ack".into(), chunk.into())]);
+            rmpv::encode::write_value(&mut acks, &ack).expect("writing to a Vec can't fail");
         }
         Some(acks.into())
     }
@@ -861,7 +885,11 @@ mod tests {
     async fn ack_delivered_with_chunk() {
         let (result, output) = check_acknowledgements(EventStatus::Delivered, true).await;
         assert_eq!(result.unwrap().unwrap(), output.len());
-        assert!(output.starts_with(b"{\"ack\":"));
+        let ack = rmpv::decode::read_value(&mut &output[..]).unwrap();
+        let ack = ack.as_map().unwrap();
+        assert_eq!(ack.len(), 1);
+        assert_eq!(ack[0].0.as_str(), Some("ack"));
+        assert!(ack[0].1.is_str());
     }
 
     #[tokio::test]
@@ -875,7 +903,7 @@ mod tests {
     async fn ack_failed_with_chunk() {
         let (result, output) = check_acknowledgements(EventStatus::Rejected, true).await;
         assert_eq!(result.unwrap().unwrap(), output.len());
-        assert_eq!(output, &b"{}"[..]);
+        assert!(output.is_empty());
     }
 
     async fn check_acknowledgements(
@@ -889,6 +917,7 @@ mod tests {
         let source = FluentConfig {
             address: address.into(),
             tls: None,
+            security: None,
             keepalive: None,
             receive_buffer_bytes: None,
             acknowledgements: true.into(),
@@ -953,6 +982,7 @@ mod tests {
         let config = FluentConfig {
             address: SocketListenAddr::SocketAddr("0.0.0.0:24224".parse().unwrap()),
             tls: None,
+            security: None,
             keepalive: None,
             receive_buffer_bytes: None,
             acknowledgements: false.into(),
@@ -1008,6 +1038,7 @@ mod tests {
         let config = FluentConfig {
             address: SocketListenAddr::SocketAddr("0.0.0.0:24224".parse().unwrap()),
             tls: None,
+            security: None,
             keepalive: None,
             receive_buffer_bytes: None,
             acknowledgements: false.into(),
@@ -1229,6 +1260,7 @@ mod integration_tests {
             FluentConfig {
                 address: address.into(),
                 tls: None,
+                security: None,
                 keepalive: None,
                 receive_buffer_bytes: None,
                 acknowledgements: false.into(),
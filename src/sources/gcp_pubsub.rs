@@ -1,7 +1,7 @@
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::{
-    error::Error as _, future::Future, pin::Pin, sync::Arc, task::Context, task::Poll,
-    time::Duration,
+    collections::HashMap, error::Error as _, future::Future, pin::Pin, sync::Arc, task::Context,
+    task::Poll, time::Duration,
 };
 
 use chrono::{DateTime, NaiveDateTime, Utc};
@@ -13,7 +13,10 @@ use lookup::owned_value_path;
 use once_cell::sync::Lazy;
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
-use tokio::sync::{mpsc, watch};
+use tokio::{
+    sync::{mpsc, watch},
+    time::Instant,
+};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{
     metadata::{errors::InvalidMetadataValue, MetadataValue},
@@ -189,6 +192,37 @@ pub struct PubsubConfig {
     )]
     pub retry_delay_seconds: Option<f64>,
 
+    /// The maximum number of messages delivered to Vector and not yet acknowledged.
+    ///
+    /// Once reached, Pub/Sub stops delivering messages on a stream until some of the outstanding
+    /// messages are acknowledged. With end-to-end acknowledgements enabled, this bounds the number
+    /// of messages buffered in the topology. Set to `0` for no limit.
+    #[serde(default = "default_max_outstanding_messages")]
+    #[derivative(Default(value = "default_max_outstanding_messages()"))]
+    pub max_outstanding_messages: i64,
+
+    /// The maximum total size, in bytes, of the messages delivered to Vector and not yet
+    /// acknowledged.
+    ///
+    /// Once reached, Pub/Sub stops delivering messages on a stream until some of the outstanding
+    /// messages are acknowledged. Set to `0` for no limit.
+    #[serde(default = "default_max_outstanding_bytes")]
+    #[derivative(Default(value = "default_max_outstanding_bytes()"))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_outstanding_bytes: i64,
+
+    /// The maximum amount of time, in seconds, to extend the acknowledgement deadline of messages
+    /// awaiting end-to-end acknowledgement.
+    ///
+    /// While events are still being delivered by the sinks, the acknowledgement deadline of their
+    /// messages is periodically extended, so they are not redelivered by Pub/Sub in the meantime.
+    /// Messages still awaiting acknowledgement after this long are left to expire, and may be
+    /// redelivered. Set to `0` to never extend the deadline.
+    #[serde(default = "default_max_ack_extension")]
+    #[derivative(Default(value = "default_max_ack_extension()"))]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    pub max_ack_extension_secs: Duration,
+
     /// The amount of time, in seconds, with no received activity
     /// before sending a keepalive request. If this is set larger than
     /// `60`, you may see periodic errors sent from the server.
@@ -232,6 +266,18 @@ const fn default_keepalive() -> Duration {
     Duration::from_secs(60)
 }
 
+const fn default_max_outstanding_messages() -> i64 {
+    1_000
+}
+
+const fn default_max_outstanding_bytes() -> i64 {
+    100 * 1024 * 1024
+}
+
+const fn default_max_ack_extension() -> Duration {
+    Duration::from_secs(3600)
+}
+
 const fn default_max_concurrency() -> usize {
     10
 }
@@ -308,11 +354,14 @@ impl SourceConfig for PubsubConfig {
                 self.decoding.clone(),
                 log_namespace,
             )
-            .build(),
+            .build()?,
             acknowledgements: cx.do_acknowledgements(self.acknowledgements),
             shutdown: cx.shutdown,
             out: cx.out,
             ack_deadline_secs,
+            max_outstanding_messages: self.max_outstanding_messages,
+            max_outstanding_bytes: self.max_outstanding_bytes,
+            max_ack_extension: self.max_ack_extension_secs,
             retry_delay: retry_delay_secs,
             keepalive: self.keepalive_secs,
             concurrency: Default::default(),
@@ -373,6 +422,9 @@ struct PubsubSource {
     decoder: Decoder,
     acknowledgements: bool,
     ack_deadline_secs: Duration,
+    max_outstanding_messages: i64,
+    max_outstanding_bytes: i64,
+    max_ack_extension: Duration,
     shutdown: ShutdownSignal,
     out: SourceSender,
     retry_delay: Duration,
@@ -503,15 +555,28 @@ impl PubsubSource {
         let (finalizer, mut ack_stream) =
             Finalizer::maybe_new(self.acknowledgements, Some(self.shutdown.clone()));
         let mut pending_acks = 0;
+        // The messages awaiting end-to-end acknowledgement, by ack ID, with the time they were
+        // received.
+        let mut outstanding = HashMap::new();
+        // Deadlines are extended well before they expire, to leave room for delays in sending
+        // the extension.
+        let extension_period = self.ack_deadline_secs / 2;
+        let mut extend_deadlines =
+            tokio::time::interval_at(Instant::now() + extension_period, extension_period);
 
         loop {
             tokio::select! {
                 biased;
                 receipts = ack_stream.next() => if let Some((status, receipts)) = receipts {
                     pending_acks -= 1;
+                    for ack_id in &receipts {
+                        outstanding.remove(ack_id);
+                    }
+                    // Messages that weren't delivered are not acknowledged, and are redelivered
+                    // once their deadline expires.
                     if status == BatchStatus::Delivered {
                         ack_ids_sender
-                            .send(receipts)
+                            .send(acknowledge(receipts))
                             .await
                             .unwrap_or_else(|_| unreachable!("request stream never closes"));
                     }
@@ -523,6 +588,7 @@ impl PubsubSource {
                             &finalizer,
                             &ack_ids_sender,
                             &mut pending_acks,
+                            &mut outstanding,
                             busy_flag,
                         ).await;
                     }
@@ -530,6 +596,16 @@ impl PubsubSource {
                     None => break State::RetryNow,
                 },
                 _ = &mut self.shutdown, if pending_acks == 0 => return State::Shutdown,
+                _ = extend_deadlines.tick(), if !self.max_ack_extension.is_zero() => {
+                    let ack_ids = self.extendable(&mut outstanding);
+                    if !ack_ids.is_empty() {
+                        debug!(message = "Extending acknowledgement deadlines.", count = ack_ids.len());
+                        ack_ids_sender
+                            .send(modify_deadline(ack_ids, self.ack_deadline_secs))
+                            .await
+                            .unwrap_or_else(|_| unreachable!("request stream never closes"));
+                    }
+                },
                 _ = self.token_generator.changed() => {
                     debug!("New authentication token generated, restarting stream.");
                     break State::RetryNow;
@@ -554,7 +630,7 @@ impl PubsubSource {
                     // in a new request with empty fields, effectively
                     // a keepalive.
                     ack_ids_sender
-                        .send(Vec::new())
+                        .send(Default::default())
                         .await
                         .unwrap_or_else(|_| unreachable!("request stream never closes"));
                 }
@@ -564,12 +640,14 @@ impl PubsubSource {
 
     fn request_stream(
         &self,
-        ack_ids: mpsc::Receiver<Vec<String>>,
+        updates: mpsc::Receiver<proto::StreamingPullRequest>,
     ) -> impl Stream<Item = proto::StreamingPullRequest> + 'static {
         let subscription = self.subscription.clone();
         let client_id = CLIENT_ID.clone();
         let stream_ack_deadline_seconds = self.ack_deadline_secs.as_secs() as i32;
-        let ack_ids = ReceiverStream::new(ack_ids).ready_chunks(ACK_QUEUE_SIZE);
+        let max_outstanding_messages = self.max_outstanding_messages;
+        let max_outstanding_bytes = self.max_outstanding_bytes;
+        let updates = ReceiverStream::new(updates).ready_chunks(ACK_QUEUE_SIZE);
 
         stream::once(async move {
             // These fields are only valid on the first request in the
@@ -578,28 +656,29 @@ impl PubsubSource {
                 subscription,
                 client_id,
                 stream_ack_deadline_seconds,
+                max_outstanding_messages,
+                max_outstanding_bytes,
                 ..Default::default()
             }
         })
-        .chain(ack_ids.map(|chunks| {
-            // These "requests" serve only to send updates about
-            // acknowledgements to the server. None of the above
-            // fields need to be repeated and, in fact, will cause
-            // an stream error and cancellation if they are
-            // present.
-            proto::StreamingPullRequest {
-                ack_ids: chunks.into_iter().flatten().collect(),
-                ..Default::default()
-            }
-        }))
+        .chain(updates.map(merge_updates))
+    }
+
+    /// Takes the messages whose deadline should be extended, forgetting those that have been
+    /// awaiting acknowledgement for longer than `max_ack_extension`.
+    fn extendable(&self, outstanding: &mut HashMap<String, Instant>) -> Vec<String> {
+        let now = Instant::now();
+        outstanding.retain(|_, received| now.duration_since(*received) < self.max_ack_extension);
+        outstanding.keys().cloned().collect()
     }
 
     async fn handle_response(
         &mut self,
         response: proto::StreamingPullResponse,
         finalizer: &Option<Finalizer>,
-        ack_ids: &mpsc::Sender<Vec<String>>,
+        ack_ids: &mpsc::Sender<proto::StreamingPullRequest>,
         pending_acks: &mut usize,
+        outstanding: &mut HashMap<String, Instant>,
         busy_flag: &Arc<AtomicBool>,
     ) {
         if response.received_messages.len() >= self.full_response_size {
@@ -615,10 +694,12 @@ impl PubsubSource {
             Err(error) => emit!(StreamClosedError { error, count }),
             Ok(()) => match notifier {
                 None => ack_ids
-                    .send(ids)
+                    .send(acknowledge(ids))
                     .await
                     .unwrap_or_else(|_| unreachable!("request stream never closes")),
                 Some(notifier) => {
+                    let received = Instant::now();
+                    outstanding.extend(ids.iter().map(|ack_id| (ack_id.clone(), received)));
                     finalizer
                         .as_ref()
                         .expect("Finalizer must have been set up for acknowledgements")
@@ -698,6 +779,43 @@ impl PubsubSource {
     }
 }
 
+/// An update of the stream that acknowledges the given messages.
+fn acknowledge(ack_ids: Vec<String>) -> proto::StreamingPullRequest {
+    proto::StreamingPullRequest {
+        ack_ids,
+        ..Default::default()
+    }
+}
+
+/// An update of the stream that sets the acknowledgement deadline of the given messages.
+fn modify_deadline(ack_ids: Vec<String>, deadline: Duration) -> proto::StreamingPullRequest {
+    proto::StreamingPullRequest {
+        modify_deadline_seconds: vec![deadline.as_secs() as i32; ack_ids.len()],
+        modify_deadline_ack_ids: ack_ids,
+        ..Default::default()
+    }
+}
+
+/// Merges updates of the stream into a single request.
+///
+/// These requests serve only to send updates about acknowledgements to the server. None of the
+/// fields of the first request need to be repeated and, in fact, will cause a stream error and
+/// cancellation if they are present.
+fn merge_updates(updates: Vec<proto::StreamingPullRequest>) -> proto::StreamingPullRequest {
+    updates
+        .into_iter()
+        .fold(Default::default(), |mut request, update| {
+            request.ack_ids.extend(update.ack_ids);
+            request
+                .modify_deadline_seconds
+                .extend(update.modify_deadline_seconds);
+            request
+                .modify_deadline_ack_ids
+                .extend(update.modify_deadline_ack_ids);
+            request
+        })
+}
+
 fn translate_error(error: tonic::Status) -> State {
     // GCP occasionally issues a connection reset
     // in the middle of the streaming pull. This
@@ -749,6 +867,22 @@ mod tests {
         crate::test_util::test_generate_config::<PubsubConfig>();
     }
 
+    #[test]
+    fn merges_stream_updates() {
+        let request = merge_updates(vec![
+            acknowledge(vec!["a".into(), "b".into()]),
+            modify_deadline(vec!["c".into(), "d".into()], Duration::from_secs(60)),
+            Default::default(),
+            acknowledge(vec!["e".into()]),
+        ]);
+
+        assert_eq!(request.ack_ids, vec!["a", "b", "e"]);
+        assert_eq!(request.modify_deadline_ack_ids, vec!["c", "d"]);
+        assert_eq!(request.modify_deadline_seconds, vec![60, 60]);
+        assert!(request.subscription.is_empty());
+        assert_eq!(request.stream_ack_deadline_seconds, 0);
+    }
+
     #[test]
     fn output_schema_definition_vector_namespace() {
         let config = PubsubConfig {
@@ -31,7 +31,7 @@ use crate::{
     serde::{bool_or_struct, default_decoding},
     sources::util::{
         http::{add_query_parameters, HttpMethod},
-        Encoding, ErrorMessage, HttpSource, HttpSourceAuthConfig,
+        Encoding, ErrorMessage, HttpSource, HttpSourceAuthConfig, SchemaDeclaration,
     },
     tls::TlsEnableableConfig,
 };
@@ -138,6 +138,10 @@ pub struct SimpleHttpConfig {
     #[configurable(derived)]
     decoding: Option<DeserializerConfig>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    schema: SchemaDeclaration,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -186,7 +190,7 @@ impl SimpleHttpConfig {
             schema_definition = schema_definition.unknown_fields(Kind::bytes());
         }
 
-        schema_definition
+        self.schema.apply(schema_definition)
     }
 
     fn get_decoding_config(&self) -> crate::Result<DecodingConfig> {
@@ -245,6 +249,7 @@ impl Default for SimpleHttpConfig {
             strict_path: true,
             framing: None,
             decoding: Some(default_decoding()),
+            schema: SchemaDeclaration::default(),
             acknowledgements: SourceAcknowledgementsConfig::default(),
             log_namespace: None,
         }
@@ -314,7 +319,7 @@ fn remove_duplicates(mut list: Vec<String>, list_name: &str) -> Vec<String> {
 #[typetag::serde(name = "http_server")]
 impl SourceConfig for SimpleHttpConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
-        let decoder = self.get_decoding_config()?.build();
+        let decoder = self.get_decoding_config()?.build()?;
         let log_namespace = cx.log_namespace(self.log_namespace);
 
         let source = SimpleHttpSource {
@@ -1379,6 +1384,44 @@ mod tests {
         assert_eq!(definitions, Some(expected_definition))
     }
 
+    #[test]
+    fn output_schema_definition_declared_schema() {
+        let config = toml::from_str::<SimpleHttpConfig>(
+            r#"
+            address = "0.0.0.0:8080"
+            decoding.codec = "json"
+            schema.fields = [
+                { path = "status", kind = "integer", required = true },
+                { path = "user.id", kind = "bytes", meaning = "user_id" },
+            ]
+            "#,
+        )
+        .unwrap();
+
+        let definition = config
+            .outputs(LogNamespace::Legacy)
+            .remove(0)
+            .schema_definition(true)
+            .unwrap();
+
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("status")),
+            Kind::integer()
+        );
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("user", "id")),
+            Kind::bytes().or_undefined()
+        );
+        assert_eq!(
+            definition.meaning_path("user_id"),
+            Some(&OwnedTargetPath::event(owned_value_path!("user", "id")))
+        );
+    }
+
     #[test]
     fn validate_remove_duplicates() {
         let mut list = vec![
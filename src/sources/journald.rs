@@ -44,8 +44,8 @@ use vector_core::{
 
 use crate::{
     config::{
-        log_schema, DataType, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
-        SourceOutput,
+        log_schema, DataDirSubsystem, DataType, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext, SourceOutput,
     },
     event::{BatchNotifier, BatchStatus, BatchStatusReceiver, LogEvent},
     internal_events::{
@@ -61,13 +61,17 @@ use crate::{
 const BATCH_TIMEOUT: Duration = Duration::from_millis(10);
 
 const CHECKPOINT_FILENAME: &str = "checkpoint.txt";
-const CURSOR: &str = "__CURSOR";
+pub(crate) const CURSOR: &str = "__CURSOR";
 const HOSTNAME: &str = "_HOSTNAME";
 const MESSAGE: &str = "MESSAGE";
 const SYSTEMD_UNIT: &str = "_SYSTEMD_UNIT";
 const SOURCE_TIMESTAMP: &str = "_SOURCE_REALTIME_TIMESTAMP";
 const RECEIVED_TIMESTAMP: &str = "__REALTIME_TIMESTAMP";
 
+/// The fields the source relies on to build log events, which are never filtered out by
+/// `include_fields` or `exclude_fields`.
+const REQUIRED_FIELDS: [&str; 4] = [MESSAGE, HOSTNAME, SOURCE_TIMESTAMP, RECEIVED_TIMESTAMP];
+
 const BACKOFF_DURATION: Duration = Duration::from_secs(1);
 
 static JOURNALCTL: Lazy<PathBuf> = Lazy::new(|| "journalctl".into());
@@ -89,6 +93,11 @@ enum BuildError {
         value,
     ))]
     DuplicatedMatches { field: String, value: String },
+    #[snafu(display(
+        "The Journal field {:?} is duplicated in both include_fields and exclude_fields",
+        field
+    ))]
+    DuplicatedField { field: String },
 }
 
 type Matches = HashMap<String, HashSet<String>>;
@@ -146,6 +155,29 @@ pub struct JournaldConfig {
     #[configurable(metadata(docs::examples = "matches_examples()"))]
     pub exclude_matches: Matches,
 
+    /// A list of journal fields to keep in log events.
+    ///
+    /// If empty or not present, all fields are kept. The `MESSAGE`, `_HOSTNAME`,
+    /// `_SOURCE_REALTIME_TIMESTAMP`, and `__REALTIME_TIMESTAMP` fields are always kept, as they
+    /// are used to build the log event.
+    ///
+    /// Fields are filtered after `include_matches` and `exclude_matches` are applied, so entries
+    /// can still be matched on fields that are not kept.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "_SYSTEMD_UNIT", docs::examples = "PRIORITY"))]
+    pub include_fields: Vec<String>,
+
+    /// A list of journal fields to remove from log events.
+    ///
+    /// The `MESSAGE`, `_HOSTNAME`, `_SOURCE_REALTIME_TIMESTAMP`, and `__REALTIME_TIMESTAMP`
+    /// fields are always kept, as they are used to build the log event.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::examples = "_CAP_EFFECTIVE",
+        docs::examples = "_SYSTEMD_INVOCATION_ID"
+    ))]
+    pub exclude_fields: Vec<String>,
+
     /// The directory used to persist file checkpoint positions.
     ///
     /// By default, the global `data_dir` option is used. Make sure the running user has write
@@ -156,6 +188,9 @@ pub struct JournaldConfig {
 
     /// The systemd journal is read in batches, and a checkpoint is set at the end of each batch.
     ///
+    /// When acknowledgements are enabled, the checkpoint is only set once all the events of the
+    /// batch have been delivered.
+    ///
     /// This option limits the size of the batch.
     #[serde(default = "default_batch_size")]
     #[configurable(metadata(docs::type_unit = "events"))]
@@ -229,50 +264,7 @@ impl JournaldConfig {
 
     /// Builds the `schema::Definition` for this source using the provided `LogNamespace`.
     fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
-        let schema_definition = match log_namespace {
-            LogNamespace::Vector => Definition::new_with_default_metadata(
-                Kind::bytes().or_null(),
-                [LogNamespace::Vector],
-            ),
-            LogNamespace::Legacy => Definition::new_with_default_metadata(
-                Kind::object(Collection::empty()),
-                [LogNamespace::Legacy],
-            ),
-        };
-
-        let mut schema_definition = schema_definition
-            .with_standard_vector_source_metadata()
-            // for metadata that is added to the events dynamically through the Record
-            .with_source_metadata(
-                JournaldConfig::NAME,
-                None,
-                &owned_value_path!("metadata"),
-                Kind::object(Collection::empty().with_unknown(Kind::bytes())).or_undefined(),
-                None,
-            )
-            .with_source_metadata(
-                JournaldConfig::NAME,
-                None,
-                &owned_value_path!("timestamp"),
-                Kind::timestamp().or_undefined(),
-                Some("timestamp"),
-            )
-            .with_source_metadata(
-                JournaldConfig::NAME,
-                parse_value_path(log_schema().host_key())
-                    .ok()
-                    .map(LegacyKey::Overwrite),
-                &owned_value_path!("host"),
-                Kind::bytes().or_undefined(),
-                Some("host"),
-            );
-
-        // for metadata that is added to the events dynamically through the Record
-        if log_namespace == LogNamespace::Legacy {
-            schema_definition = schema_definition.unknown_fields(Kind::bytes());
-        }
-
-        schema_definition
+        journal_schema_definition(JournaldConfig::NAME, log_namespace)
     }
 }
 
@@ -285,6 +277,8 @@ impl Default for JournaldConfig {
             exclude_units: vec![],
             include_matches: Default::default(),
             exclude_matches: Default::default(),
+            include_fields: vec![],
+            exclude_fields: vec![],
             data_dir: None,
             batch_size: default_batch_size(),
             journalctl_path: None,
@@ -298,7 +292,57 @@ impl Default for JournaldConfig {
 
 impl_generate_config_from_default!(JournaldConfig);
 
-type Record = HashMap<String, String>;
+/// Builds the `schema::Definition` of journal entries read by the source named `source_name`.
+pub(crate) fn journal_schema_definition(
+    source_name: &'static str,
+    log_namespace: LogNamespace,
+) -> Definition {
+    let schema_definition = match log_namespace {
+        LogNamespace::Vector => {
+            Definition::new_with_default_metadata(Kind::bytes().or_null(), [LogNamespace::Vector])
+        }
+        LogNamespace::Legacy => Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [LogNamespace::Legacy],
+        ),
+    };
+
+    let mut schema_definition = schema_definition
+        .with_standard_vector_source_metadata()
+        // for metadata that is added to the events dynamically through the Record
+        .with_source_metadata(
+            source_name,
+            None,
+            &owned_value_path!("metadata"),
+            Kind::object(Collection::empty().with_unknown(Kind::bytes())).or_undefined(),
+            None,
+        )
+        .with_source_metadata(
+            source_name,
+            None,
+            &owned_value_path!("timestamp"),
+            Kind::timestamp().or_undefined(),
+            Some("timestamp"),
+        )
+        .with_source_metadata(
+            source_name,
+            parse_value_path(log_schema().host_key())
+                .ok()
+                .map(LegacyKey::Overwrite),
+            &owned_value_path!("host"),
+            Kind::bytes().or_undefined(),
+            Some("host"),
+        );
+
+    // for metadata that is added to the events dynamically through the Record
+    if log_namespace == LogNamespace::Legacy {
+        schema_definition = schema_definition.unknown_fields(Kind::bytes());
+    }
+
+    schema_definition
+}
+
+pub(crate) type Record = HashMap<String, String>;
 
 #[async_trait::async_trait]
 #[typetag::serde(name = "journald")]
@@ -311,7 +355,11 @@ impl SourceConfig for JournaldConfig {
         let data_dir = cx
             .globals
             // source are only global, name can be used for subdir
-            .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())?;
+            .resolve_and_make_data_subdir_for(
+                DataDirSubsystem::Checkpoints,
+                self.data_dir.as_ref(),
+                cx.key.id(),
+            )?;
 
         if let Some(unit) = self
             .include_units
@@ -329,6 +377,15 @@ impl SourceConfig for JournaldConfig {
             return Err(BuildError::DuplicatedMatches { field, value }.into());
         }
 
+        if let Some(field) = self
+            .include_fields
+            .iter()
+            .find(|field| self.exclude_fields.contains(field))
+        {
+            let field = field.into();
+            return Err(BuildError::DuplicatedField { field }.into());
+        }
+
         let mut checkpoint_path = data_dir;
         checkpoint_path.push(CHECKPOINT_FILENAME);
 
@@ -352,6 +409,8 @@ impl SourceConfig for JournaldConfig {
             JournaldSource {
                 include_matches,
                 exclude_matches,
+                include_fields: self.include_fields.iter().cloned().collect(),
+                exclude_fields: self.exclude_fields.iter().cloned().collect(),
                 checkpoint_path,
                 batch_size,
                 remap_priority: self.remap_priority,
@@ -379,6 +438,8 @@ impl SourceConfig for JournaldConfig {
 struct JournaldSource {
     include_matches: Matches,
     exclude_matches: Matches,
+    include_fields: HashSet<String>,
+    exclude_fields: HashSet<String>,
     checkpoint_path: PathBuf,
     batch_size: usize,
     remap_priority: bool,
@@ -547,13 +608,24 @@ impl<'a> Batch<'a> {
                         ) {
                             self.record_size += bytes.len();
 
+                            filter_fields(
+                                &mut record,
+                                &self.source.include_fields,
+                                &self.source.exclude_fields,
+                            );
+
                             let mut event = create_log_event_from_record(
                                 record,
                                 &self.batch,
+                                JournaldConfig::NAME,
                                 self.source.log_namespace,
                             );
 
-                            enrich_log_event(&mut event, self.source.log_namespace);
+                            enrich_log_event(
+                                &mut event,
+                                JournaldConfig::NAME,
+                                self.source.log_namespace,
+                            );
 
                             self.events.push(event);
                         }
@@ -684,10 +756,14 @@ impl Drop for RunningJournalctl {
     }
 }
 
-fn enrich_log_event(log: &mut LogEvent, log_namespace: LogNamespace) {
+pub(crate) fn enrich_log_event(
+    log: &mut LogEvent,
+    source_name: &'static str,
+    log_namespace: LogNamespace,
+) {
     if let Some(host) = log.remove(HOSTNAME) {
         log_namespace.insert_source_metadata(
-            JournaldConfig::NAME,
+            source_name,
             log,
             parse_value_path(log_schema().host_key())
                 .ok()
@@ -721,7 +797,7 @@ fn enrich_log_event(log: &mut LogEvent, log_namespace: LogNamespace) {
             log.insert(metadata_path!("vector", "ingest_timestamp"), Utc::now());
 
             if let Some(ts) = timestamp {
-                log.insert(metadata_path!(JournaldConfig::NAME, "timestamp"), ts);
+                log.insert(metadata_path!(source_name, "timestamp"), ts);
             }
         }
         LogNamespace::Legacy => {
@@ -738,13 +814,14 @@ fn enrich_log_event(log: &mut LogEvent, log_namespace: LogNamespace) {
         log,
         Some(log_schema().source_type_key()),
         path!("source_type"),
-        JournaldConfig::NAME,
+        source_name,
     );
 }
 
-fn create_log_event_from_record(
+pub(crate) fn create_log_event_from_record(
     mut record: Record,
     batch: &Option<BatchNotifier>,
+    source_name: &'static str,
     log_namespace: LogNamespace,
 ) -> LogEvent {
     match log_namespace {
@@ -760,7 +837,7 @@ fn create_log_event_from_record(
             record.iter().for_each(|(key, value)| {
                 log.metadata_mut()
                     .value_mut()
-                    .insert(path!(JournaldConfig::NAME, "metadata", key), value.as_str());
+                    .insert(path!(source_name, "metadata", key), value.as_str());
             });
 
             log
@@ -787,7 +864,7 @@ fn fixup_unit(unit: &str) -> String {
     }
 }
 
-fn decode_record(line: &[u8], remap: bool) -> Result<Record, JsonError> {
+pub(crate) fn decode_record(line: &[u8], remap: bool) -> Result<Record, JsonError> {
     let mut record = serde_json::from_str::<JsonValue>(&String::from_utf8_lossy(line))?;
     // journalctl will output non-ASCII values using an array
     // of integers. Look for those values and re-parse them.
@@ -851,6 +928,18 @@ fn filter_matches(record: &Record, includes: &Matches, excludes: &Matches) -> bo
     }
 }
 
+/// Removes the fields of the record that aren't included, or are excluded.
+fn filter_fields(record: &mut Record, includes: &HashSet<String>, excludes: &HashSet<String>) {
+    if includes.is_empty() && excludes.is_empty() {
+        return;
+    }
+
+    record.retain(|field, _| {
+        REQUIRED_FIELDS.contains(&field.as_str())
+            || ((includes.is_empty() || includes.contains(field)) && !excludes.contains(field))
+    });
+}
+
 fn contains_match(record: &Record, matches: &Matches) -> bool {
     let f = move |(field, value)| {
         matches
@@ -1306,6 +1395,82 @@ mod tests {
         assert_eq!(checkpointer.get().await.unwrap().as_deref(), Some("8"));
     }
 
+    #[tokio::test]
+    async fn does_not_checkpoint_rejected_events() {
+        let (tx, mut rx) = SourceSender::new_test();
+
+        let tempdir = tempdir().unwrap();
+        let tempdir = tempdir.path().to_path_buf();
+        let mut checkpoint_path = tempdir.clone();
+        checkpoint_path.push(TEST_COMPONENT);
+        fs::create_dir(&checkpoint_path).unwrap();
+        checkpoint_path.push(CHECKPOINT_FILENAME);
+
+        let mut checkpointer = Checkpointer::new(checkpoint_path.clone())
+            .await
+            .expect("Creating checkpointer failed!");
+
+        let config = JournaldConfig {
+            journalctl_path: Some(TEST_JOURNALCTL.into()),
+            data_dir: Some(tempdir),
+            acknowledgements: true.into(),
+            ..Default::default()
+        };
+        let (cx, _shutdown) = SourceContext::new_shutdown(&ComponentKey::from(TEST_COMPONENT), tx);
+        let source = config.build(cx).await.unwrap();
+        tokio::spawn(async move { source.await.unwrap() });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let mut count = 0;
+        while let Poll::Ready(Some(event)) = futures::poll!(rx.next()) {
+            event.metadata().update_status(EventStatus::Rejected);
+            count += 1;
+        }
+        assert_eq!(count, 8);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(checkpointer.get().await.unwrap(), None);
+    }
+
+    #[test]
+    fn filter_fields_works_correctly() {
+        let record: Record = [
+            (MESSAGE, "message"),
+            (SOURCE_TIMESTAMP, "1578529839140001"),
+            (SYSTEMD_UNIT, "unit.service"),
+            ("PRIORITY", "6"),
+            ("_CAP_EFFECTIVE", "0"),
+        ]
+        .into_iter()
+        .map(|(field, value)| (field.to_owned(), value.to_owned()))
+        .collect();
+        let fields = |fields: &[&str]| -> HashSet<String> {
+            fields.iter().map(|field| field.to_string()).collect()
+        };
+        let filtered = |includes: &[&str], excludes: &[&str]| {
+            let mut record = record.clone();
+            filter_fields(&mut record, &fields(includes), &fields(excludes));
+            let mut fields = record.into_keys().collect::<Vec<_>>();
+            fields.sort();
+            fields
+        };
+
+        assert_eq!(filtered(&[], &[]).len(), 5);
+        assert_eq!(
+            filtered(&["PRIORITY"], &[]),
+            vec![MESSAGE, "PRIORITY", SOURCE_TIMESTAMP]
+        );
+        assert_eq!(
+            filtered(&[], &["_CAP_EFFECTIVE", MESSAGE]),
+            vec![MESSAGE, "PRIORITY", SOURCE_TIMESTAMP, SYSTEMD_UNIT]
+        );
+        assert_eq!(
+            filtered(&["PRIORITY", SYSTEMD_UNIT], &[SYSTEMD_UNIT]),
+            vec![MESSAGE, "PRIORITY", SOURCE_TIMESTAMP]
+        );
+    }
+
     #[test]
     fn filter_matches_works_correctly() {
         let empty: Matches = HashMap::new();
@@ -0,0 +1,667 @@
+//! Pulls journals from remote hosts through the HTTP API of `systemd-journal-gatewayd`.
+//!
+//! Each endpoint is followed independently. The cursor of the last entry read is checkpointed per
+//! machine ID, so reading resumes where it left off, even if a host moves to another address.
+use std::{collections::HashMap, io, path::PathBuf, sync::Arc, time::Duration};
+
+use bytes::Bytes;
+use codecs::{decoding::BoxedFramingError, CharacterDelimitedDecoder};
+use futures::{future::join_all, StreamExt, TryStreamExt};
+use http::{
+    header::{ACCEPT, RANGE},
+    Request, StatusCode, Uri,
+};
+use hyper::Body;
+use serde::Deserialize;
+use snafu::{ResultExt, Snafu};
+use tokio::{fs, sync::Mutex, time::sleep};
+use tokio_util::{codec::FramedRead, io::StreamReader};
+use vector_common::{
+    finalizer::OrderedFinalizer,
+    internal_event::{
+        ByteSize, BytesReceived, CountByteSize, InternalEventHandle as _, Protocol, Registered,
+    },
+};
+use vector_config::configurable_component;
+use vector_core::{config::LogNamespace, EstimatedJsonEncodedSizeOf};
+
+use crate::{
+    config::{
+        DataDirSubsystem, DataType, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
+        SourceOutput,
+    },
+    event::{BatchNotifier, BatchStatus, BatchStatusReceiver, LogEvent},
+    http::{get_http_scheme_from_uri, Auth, HttpClient},
+    internal_events::{
+        EventsReceived, JournaldCheckpointFileOpenError, JournaldCheckpointSetError,
+        JournaldGatewayRequestError, JournaldInvalidRecordError, JournaldReadError,
+        StreamClosedError,
+    },
+    serde::bool_or_struct,
+    shutdown::ShutdownSignal,
+    sources::journald::{
+        create_log_event_from_record, decode_record, enrich_log_event, journal_schema_definition,
+        CURSOR,
+    },
+    tls::{TlsConfig, TlsSettings},
+    SourceSender,
+};
+
+const CHECKPOINT_FILENAME: &str = "checkpoints.json";
+
+const BACKOFF_DURATION: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("At least one endpoint must be configured"))]
+    NoEndpoints,
+    #[snafu(display("Invalid endpoint {:?}: {}", endpoint, source))]
+    InvalidEndpoint {
+        endpoint: String,
+        source: http::uri::InvalidUri,
+    },
+}
+
+/// Configuration for the `journald_gateway` source.
+#[configurable_component(source(
+    "journald_gateway",
+    "Pull logs from remote journals through systemd-journal-gatewayd."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JournaldGatewayConfig {
+    /// The base URLs of the `systemd-journal-gatewayd` instances to pull journals from.
+    #[configurable(metadata(
+        docs::examples = "http://10.0.0.5:19531",
+        docs::examples = "https://db-1.internal:19531"
+    ))]
+    pub endpoints: Vec<String>,
+
+    /// Only include entries that occurred after the current boot of each remote system.
+    #[serde(default)]
+    pub current_boot_only: bool,
+
+    /// The directory used to persist the cursor of each remote machine.
+    ///
+    /// By default, the global `data_dir` option is used. Make sure the running user has write
+    /// permissions to this directory.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    pub data_dir: Option<PathBuf>,
+
+    /// Each journal is read in batches, and a checkpoint is set at the end of each batch.
+    ///
+    /// This option limits the size of the batch.
+    #[serde(default = "default_batch_size")]
+    #[configurable(metadata(docs::type_unit = "events"))]
+    pub batch_size: usize,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    #[configurable(derived)]
+    pub auth: Option<Auth>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+const fn default_batch_size() -> usize {
+    16
+}
+
+impl Default for JournaldGatewayConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: vec!["http://localhost:19531".to_owned()],
+            current_boot_only: false,
+            data_dir: None,
+            batch_size: default_batch_size(),
+            tls: None,
+            auth: None,
+            acknowledgements: Default::default(),
+            log_namespace: None,
+        }
+    }
+}
+
+impl_generate_config_from_default!(JournaldGatewayConfig);
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "journald_gateway")]
+impl SourceConfig for JournaldGatewayConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        if self.endpoints.is_empty() {
+            return Err(BuildError::NoEndpoints.into());
+        }
+        let endpoints = self
+            .endpoints
+            .iter()
+            .map(|endpoint| {
+                endpoint
+                    .trim_end_matches('/')
+                    .parse::<Uri>()
+                    .context(InvalidEndpointSnafu { endpoint })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let data_dir = cx.globals.resolve_and_make_data_subdir_for(
+            DataDirSubsystem::Checkpoints,
+            self.data_dir.as_ref(),
+            cx.key.id(),
+        )?;
+
+        let tls = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::new(tls, &cx.proxy)?;
+
+        Ok(Box::pin(
+            JournaldGatewaySource {
+                endpoints,
+                client,
+                auth: self.auth.clone(),
+                current_boot_only: self.current_boot_only,
+                batch_size: self.batch_size,
+                checkpoint_path: data_dir.join(CHECKPOINT_FILENAME),
+                out: cx.out,
+                acknowledgements: cx.do_acknowledgements(self.acknowledgements),
+                log_namespace: cx.log_namespace(self.log_namespace),
+            }
+            .run(cx.shutdown),
+        ))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_definition =
+            journal_schema_definition(Self::NAME, global_log_namespace.merge(self.log_namespace));
+
+        vec![SourceOutput::new_logs(DataType::Log, schema_definition)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        true
+    }
+}
+
+struct JournaldGatewaySource {
+    endpoints: Vec<Uri>,
+    client: HttpClient,
+    auth: Option<Auth>,
+    current_boot_only: bool,
+    batch_size: usize,
+    checkpoint_path: PathBuf,
+    out: SourceSender,
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+}
+
+impl JournaldGatewaySource {
+    async fn run(self, shutdown: ShutdownSignal) -> Result<(), ()> {
+        let checkpoints = Checkpoints::load(self.checkpoint_path.clone())
+            .await
+            .map_err(|error| {
+                emit!(JournaldCheckpointFileOpenError {
+                    error,
+                    path: self
+                        .checkpoint_path
+                        .to_str()
+                        .unwrap_or("unknown")
+                        .to_string(),
+                });
+            })?;
+        let checkpoints = Arc::new(checkpoints);
+
+        let readers = self.endpoints.into_iter().map(|endpoint| {
+            JournalReader {
+                bytes_received: register!(BytesReceived::from(Protocol::from(
+                    get_http_scheme_from_uri(&endpoint)
+                ))),
+                events_received: register!(EventsReceived),
+                endpoint,
+                client: self.client.clone(),
+                auth: self.auth.clone(),
+                current_boot_only: self.current_boot_only,
+                batch_size: self.batch_size,
+                out: self.out.clone(),
+                acknowledgements: self.acknowledgements,
+                log_namespace: self.log_namespace,
+            }
+            .run(
+                Finalizer::new(
+                    self.acknowledgements,
+                    Arc::clone(&checkpoints),
+                    shutdown.clone(),
+                ),
+                shutdown.clone(),
+            )
+        });
+        join_all(readers).await;
+
+        Ok(())
+    }
+}
+
+/// How reading a journal came to an end.
+enum ReadOutcome {
+    /// The connection to the endpoint was closed, and should be reopened.
+    Disconnected,
+    /// The output of the source was closed, so there is no point reading any further.
+    OutputClosed,
+}
+
+/// The response of `systemd-journal-gatewayd` describing the machine it runs on.
+#[derive(Deserialize)]
+struct Machine {
+    machine_id: String,
+}
+
+/// Follows the journal served by a single endpoint.
+struct JournalReader {
+    endpoint: Uri,
+    client: HttpClient,
+    auth: Option<Auth>,
+    current_boot_only: bool,
+    batch_size: usize,
+    out: SourceSender,
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+    bytes_received: Registered<BytesReceived>,
+    events_received: Registered<EventsReceived>,
+}
+
+impl JournalReader {
+    async fn run(mut self, finalizer: Finalizer, mut shutdown: ShutdownSignal) {
+        loop {
+            info!(message = "Reading remote journal.", endpoint = %self.endpoint);
+            let result = tokio::select! {
+                _ = &mut shutdown => return,
+                result = self.read_journal(&finalizer) => result,
+            };
+            match result {
+                Ok(ReadOutcome::OutputClosed) => return,
+                Ok(ReadOutcome::Disconnected) => {
+                    warn!(message = "Remote journal disconnected.", endpoint = %self.endpoint);
+                }
+                Err(error) => emit!(JournaldGatewayRequestError {
+                    endpoint: self.endpoint.to_string(),
+                    error,
+                }),
+            }
+
+            tokio::select! {
+                _ = &mut shutdown => return,
+                _ = sleep(BACKOFF_DURATION) => (),
+            }
+        }
+    }
+
+    fn request(&self, path_and_query: &str) -> Request<Body> {
+        let mut request = Request::get(format!("{}{}", self.endpoint, path_and_query))
+            .header(ACCEPT, "application/json")
+            .body(Body::empty())
+            .expect("endpoint was validated when the source was built");
+        if let Some(auth) = &self.auth {
+            auth.apply(&mut request);
+        }
+        request
+    }
+
+    async fn machine_id(&self) -> crate::Result<String> {
+        let response = self.client.send(self.request("/machine")).await?;
+        let status = response.status();
+        if status != StatusCode::OK {
+            return Err(format!("Unexpected status {} fetching the machine ID", status).into());
+        }
+
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        let machine: Machine = serde_json::from_slice(&body)?;
+        Ok(machine.machine_id)
+    }
+
+    async fn read_journal(&mut self, finalizer: &Finalizer) -> crate::Result<ReadOutcome> {
+        let machine_id = self.machine_id().await?;
+        let cursor = finalizer.checkpoints().get(&machine_id).await;
+
+        let mut request = self.request(if self.current_boot_only {
+            "/entries?follow&boot"
+        } else {
+            "/entries?follow"
+        });
+        if let Some(cursor) = cursor {
+            // Skip the entry at the cursor, as it has already been read.
+            let range = format!("entries={}:1:", cursor).parse()?;
+            request.headers_mut().insert(RANGE, range);
+        }
+
+        let response = self.client.send(request).await?;
+        let status = response.status();
+        if status != StatusCode::OK {
+            return Err(format!("Unexpected status {} reading the journal", status).into());
+        }
+
+        let body = response
+            .into_body()
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error));
+        let mut batches = FramedRead::new(
+            StreamReader::new(body),
+            CharacterDelimitedDecoder::new(b'\n'),
+        )
+        .ready_chunks(self.batch_size.max(1));
+
+        while let Some(lines) = batches.next().await {
+            let (outcome, cursor, receiver) = self.handle_lines(lines).await;
+            if let Some(cursor) = cursor {
+                let checkpoint = Checkpoint {
+                    machine_id: machine_id.clone(),
+                    cursor,
+                };
+                finalizer.finalize(checkpoint, receiver).await;
+            }
+            if let Some(outcome) = outcome {
+                return Ok(outcome);
+            }
+        }
+
+        Ok(ReadOutcome::Disconnected)
+    }
+
+    /// Sends the entries read from the journal, returning the cursor of the last of them.
+    async fn handle_lines(
+        &mut self,
+        lines: Vec<Result<Bytes, BoxedFramingError>>,
+    ) -> (
+        Option<ReadOutcome>,
+        Option<String>,
+        Option<BatchStatusReceiver>,
+    ) {
+        let (batch, receiver) = BatchNotifier::maybe_new_with_receiver(self.acknowledgements);
+        let mut outcome = None;
+        let mut cursor = None;
+        let mut events: Vec<LogEvent> = Vec::with_capacity(lines.len());
+        let mut byte_size = 0;
+
+        for line in lines {
+            let bytes = match line {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    emit!(JournaldReadError { error });
+                    outcome = Some(ReadOutcome::Disconnected);
+                    break;
+                }
+            };
+            byte_size += bytes.len();
+
+            match decode_record(&bytes, false) {
+                Ok(mut record) => {
+                    if let Some(entry_cursor) = record.remove(CURSOR) {
+                        cursor = Some(entry_cursor);
+                    }
+
+                    let mut event = create_log_event_from_record(
+                        record,
+                        &batch,
+                        JournaldGatewayConfig::NAME,
+                        self.log_namespace,
+                    );
+                    enrich_log_event(&mut event, JournaldGatewayConfig::NAME, self.log_namespace);
+                    events.push(event);
+                }
+                Err(error) => {
+                    emit!(JournaldInvalidRecordError {
+                        error,
+                        text: String::from_utf8_lossy(&bytes).into_owned()
+                    });
+                }
+            }
+        }
+        drop(batch);
+
+        if byte_size > 0 {
+            self.bytes_received.emit(ByteSize(byte_size));
+        }
+
+        if !events.is_empty() {
+            let count = events.len();
+            self.events_received.emit(CountByteSize(
+                count,
+                events.estimated_json_encoded_size_of(),
+            ));
+
+            if let Err(error) = self.out.send_batch(events).await {
+                emit!(StreamClosedError { error, count });
+                return (Some(ReadOutcome::OutputClosed), None, None);
+            }
+        }
+
+        (outcome, cursor, receiver)
+    }
+}
+
+/// The position in the journal of a machine up to which entries have been read.
+struct Checkpoint {
+    machine_id: String,
+    cursor: String,
+}
+
+/// The cursors of every machine read from, persisted as a single JSON file.
+struct Checkpoints {
+    path: PathBuf,
+    cursors: Mutex<HashMap<String, String>>,
+}
+
+impl Checkpoints {
+    async fn load(path: PathBuf) -> Result<Self, io::Error> {
+        let cursors = match fs::read(&path).await {
+            Ok(contents) => serde_json::from_slice(&contents)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error),
+        };
+
+        Ok(Self {
+            path,
+            cursors: Mutex::new(cursors),
+        })
+    }
+
+    async fn get(&self, machine_id: &str) -> Option<String> {
+        self.cursors.lock().await.get(machine_id).cloned()
+    }
+
+    async fn set(&self, checkpoint: Checkpoint) {
+        let mut cursors = self.cursors.lock().await;
+        cursors.insert(checkpoint.machine_id, checkpoint.cursor);
+
+        if let Err(error) = self.write(&cursors).await {
+            emit!(JournaldCheckpointSetError {
+                error,
+                filename: self.path.to_str().unwrap_or("unknown").to_string(),
+            });
+        }
+    }
+
+    /// Writes the cursors to a temporary file that then replaces the checkpoint file, so a crash
+    /// while writing can't leave the checkpoint file truncated.
+    async fn write(&self, cursors: &HashMap<String, String>) -> Result<(), io::Error> {
+        let contents = serde_json::to_vec(cursors)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, contents).await?;
+        fs::rename(&tmp_path, &self.path).await
+    }
+}
+
+enum Finalizer {
+    Sync(Arc<Checkpoints>),
+    Async(Arc<Checkpoints>, OrderedFinalizer<Checkpoint>),
+}
+
+impl Finalizer {
+    fn new(
+        acknowledgements: bool,
+        checkpoints: Arc<Checkpoints>,
+        shutdown: ShutdownSignal,
+    ) -> Self {
+        if acknowledgements {
+            let (finalizer, mut ack_stream) = OrderedFinalizer::new(Some(shutdown));
+            let acked_checkpoints = Arc::clone(&checkpoints);
+            tokio::spawn(async move {
+                while let Some((status, checkpoint)) = ack_stream.next().await {
+                    if status == BatchStatus::Delivered {
+                        acked_checkpoints.set(checkpoint).await;
+                    }
+                }
+            });
+            Self::Async(checkpoints, finalizer)
+        } else {
+            Self::Sync(checkpoints)
+        }
+    }
+
+    fn checkpoints(&self) -> &Checkpoints {
+        match self {
+            Self::Sync(checkpoints) | Self::Async(checkpoints, _) => checkpoints,
+        }
+    }
+
+    async fn finalize(&self, checkpoint: Checkpoint, receiver: Option<BatchStatusReceiver>) {
+        match (self, receiver) {
+            (Self::Sync(checkpoints), None) => checkpoints.set(checkpoint).await,
+            (Self::Async(_, finalizer), Some(receiver)) => finalizer.add(checkpoint, receiver),
+            _ => unreachable!(
+                "Cannot have async finalization without a receiver in journald_gateway source"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use tempfile::tempdir;
+    use tokio::time::{timeout, Instant};
+    use warp::Filter;
+
+    use super::*;
+    use crate::{
+        config::{log_schema, ComponentKey},
+        event::{Event, EventStatus},
+        test_util::{components::assert_source_compliance, next_addr, wait_for_tcp},
+    };
+
+    const TEST_COMPONENT: &str = "journald-gateway-test";
+    const MACHINE_ID: &str = "a5bfbd0f04fa4d9a8e5b6bbe7d1c9a16";
+    const ENTRIES: &str = concat!(
+        r#"{"__CURSOR":"s=1;i=1","__REALTIME_TIMESTAMP":"1578529839140001","MESSAGE":"System Initialization","PRIORITY":"6","_HOSTNAME":"db-1"}"#,
+        "\n",
+        r#"{"__CURSOR":"s=1;i=2","__REALTIME_TIMESTAMP":"1578529839140002","MESSAGE":"unit message","PRIORITY":"7","_SYSTEMD_UNIT":"unit.service"}"#,
+        "\n",
+    );
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JournaldGatewayConfig>();
+    }
+
+    async fn serve_journal(expected_range: Option<&'static str>) -> SocketAddr {
+        let in_addr = next_addr();
+
+        let machine = warp::path!("machine")
+            .and(warp::header::exact("Accept", "application/json"))
+            .map(|| format!(r#"{{"machine_id":"{}","hostname":"db-1"}}"#, MACHINE_ID));
+        let entries = warp::path!("entries")
+            .and(warp::header::exact("Accept", "application/json"))
+            .and(warp::header::optional::<String>("Range"))
+            .map(move |range: Option<String>| {
+                assert_eq!(range.as_deref(), expected_range);
+                ENTRIES
+            });
+
+        tokio::spawn(warp::serve(machine.or(entries)).run(in_addr));
+        wait_for_tcp(in_addr).await;
+
+        in_addr
+    }
+
+    async fn run_gateway(in_addr: SocketAddr, data_dir: PathBuf) -> Vec<Event> {
+        assert_source_compliance(&["protocol"], async move {
+            let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
+            let (cx, shutdown) =
+                SourceContext::new_shutdown(&ComponentKey::from(TEST_COMPONENT), tx);
+            let config = JournaldGatewayConfig {
+                endpoints: vec![format!("http://{}", in_addr)],
+                data_dir: Some(data_dir),
+                ..Default::default()
+            };
+            let source = config.build(cx).await.unwrap();
+            tokio::spawn(async move { source.await.unwrap() });
+
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            shutdown
+                .shutdown_all(Instant::now() + Duration::from_secs(1))
+                .await;
+
+            timeout(Duration::from_secs(1), rx.collect()).await.unwrap()
+        })
+        .await
+    }
+
+    async fn read_checkpoints(data_dir: &std::path::Path) -> HashMap<String, String> {
+        let path = data_dir.join(TEST_COMPONENT).join(CHECKPOINT_FILENAME);
+        serde_json::from_slice(&fs::read(path).await.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_remote_journal() {
+        let in_addr = serve_journal(None).await;
+        let data_dir = tempdir().unwrap();
+
+        let received = run_gateway(in_addr, data_dir.path().to_path_buf()).await;
+        assert_eq!(received.len(), 2);
+
+        let log = received[0].as_log();
+        assert_eq!(
+            log[log_schema().message_key()],
+            "System Initialization".into()
+        );
+        assert_eq!(log[log_schema().host_key()], "db-1".into());
+        assert_eq!(
+            log[log_schema().source_type_key()],
+            "journald_gateway".into()
+        );
+        assert!(log.get("__CURSOR").is_none());
+        assert_eq!(
+            received[1].as_log()[log_schema().message_key()],
+            "unit message".into()
+        );
+
+        let checkpoints = read_checkpoints(data_dir.path()).await;
+        assert_eq!(checkpoints[MACHINE_ID], "s=1;i=2");
+    }
+
+    #[tokio::test]
+    async fn resumes_from_checkpoint() {
+        let in_addr = serve_journal(Some("entries=s=1;i=1:1:")).await;
+        let data_dir = tempdir().unwrap();
+
+        let checkpoint_dir = data_dir.path().join(TEST_COMPONENT);
+        fs::create_dir(&checkpoint_dir).await.unwrap();
+        fs::write(
+            checkpoint_dir.join(CHECKPOINT_FILENAME),
+            format!(r#"{{"{}":"s=1;i=1"}}"#, MACHINE_ID),
+        )
+        .await
+        .unwrap();
+
+        let received = run_gateway(in_addr, data_dir.path().to_path_buf()).await;
+        assert_eq!(received.len(), 2);
+
+        let checkpoints = read_checkpoints(data_dir.path()).await;
+        assert_eq!(checkpoints[MACHINE_ID], "s=1;i=2");
+    }
+}
@@ -1,7 +1,12 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     io::Cursor,
-    sync::Arc,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Poll,
     time::Duration,
 };
 
@@ -18,7 +23,7 @@ use once_cell::sync::OnceCell;
 use rdkafka::{
     consumer::{CommitMode, Consumer, ConsumerContext, Rebalance, StreamConsumer},
     message::{BorrowedMessage, Headers as _, Message},
-    ClientConfig, ClientContext, Statistics,
+    ClientConfig, ClientContext, Statistics, TopicPartitionList,
 };
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
@@ -38,14 +43,16 @@ use crate::{
         log_schema, LogSchema, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
         SourceOutput,
     },
-    event::{BatchNotifier, BatchStatus, Event, Value},
+    event::{BatchNotifier, BatchStatus, Event, LogEvent, Value},
     internal_events::{
-        KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaReadError,
-        StreamClosedError,
+        KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaPartitionPauseError,
+        KafkaReadError, StreamClosedError,
     },
     kafka,
     serde::{bool_or_struct, default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
+    source_sender::ClosedError,
+    sources::util::SchemaDeclaration,
     SourceSender,
 };
 
@@ -174,6 +181,16 @@ pub struct KafkaSourceConfig {
     #[configurable(metadata(docs::examples = "headers"))]
     headers_key: OptionalValuePath,
 
+    /// Whether to keep the identity of each Kafka message in the event metadata, whatever the log
+    /// namespace.
+    ///
+    /// When enabled, the topic, partition, timestamp, key, and headers of the message are stored
+    /// under `%kafka`, so that a `kafka` sink with `passthrough` enabled can produce the event as
+    /// the same message. With the `vector` log namespace, they are always stored there.
+    #[configurable(metadata(docs::advanced))]
+    #[serde(default)]
+    passthrough: bool,
+
     /// Advanced options set directly on the underlying `librdkafka` client.
     ///
     /// See the [librdkafka documentation](https://github.com/edenhill/librdkafka/blob/master/CONFIGURATION.md) for details.
@@ -184,6 +201,18 @@ pub struct KafkaSourceConfig {
     ))]
     librdkafka_options: Option<HashMap<String, String>>,
 
+    /// The maximum number of consumed messages waiting to be acknowledged, across all assigned
+    /// partitions.
+    ///
+    /// Once reached, which happens when the buffers of the downstream components fill up, fetching
+    /// from all the assigned partitions is paused, so that the backlog is left in Kafka instead of
+    /// piling up in memory. Fetching resumes once half of these messages are acknowledged.
+    ///
+    /// Only applies when acknowledgements are enabled.
+    #[configurable(metadata(docs::examples = 100000))]
+    #[configurable(metadata(docs::advanced))]
+    max_unacknowledged_messages: Option<NonZeroUsize>,
+
     #[serde(flatten)]
     auth: kafka::KafkaAuthConfig,
 
@@ -198,6 +227,10 @@ pub struct KafkaSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    schema: SchemaDeclaration,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -291,8 +324,14 @@ impl SourceConfig for KafkaSourceConfig {
 
         let consumer = create_consumer(self)?;
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+        if self.max_unacknowledged_messages.is_some() && !acknowledgements {
+            warn!(
+                message = "The `max_unacknowledged_messages` option has no effect without acknowledgements."
+            );
+        }
 
         Ok(Box::pin(kafka_source(
             self.clone(),
@@ -355,6 +394,7 @@ impl SourceConfig for KafkaSourceConfig {
                 Kind::bytes(),
                 None,
             );
+        let schema_definition = self.schema.apply(schema_definition);
 
         vec![SourceOutput::new_logs(
             self.decoding.output_type(),
@@ -388,12 +428,19 @@ async fn kafka_source(
             .expect("Finalizer is only set once");
     }
 
+    let max_unacknowledged = config
+        .max_unacknowledged_messages
+        .filter(|_| acknowledgements)
+        .map(NonZeroUsize::get);
+    let mut paused = PausedPartitions::default();
+
     let mut stream = consumer.stream();
 
     loop {
         tokio::select! {
             _ = &mut shutdown => break,
             entry = ack_stream.next() => if let Some((status, entry)) = entry {
+                consumer.context().acknowledged();
                 if status == BatchStatus::Delivered {
                     if let Err(error) =
                         consumer.store_offset(&entry.topic, entry.partition, entry.offset)
@@ -401,6 +448,12 @@ async fn kafka_source(
                         emit!(KafkaOffsetUpdateError { error });
                     }
                 }
+
+                if max_unacknowledged.map_or(false, |max| consumer.context().unacknowledged() <= max / 2)
+                    && paused.resume(&consumer, PauseReason::Unacknowledged)
+                {
+                    debug!(message = "Resumed fetching after acknowledgements caught up.");
+                }
             },
             message = stream.next() => match message {
                 None => break,  // WHY?
@@ -413,7 +466,23 @@ async fn kafka_source(
                         partition: msg.partition(),
                     });
 
-                    parse_message(msg, decoder.clone(), config.keys(), &finalizer, &mut out, &consumer, log_namespace).await;
+                    parse_message(msg, decoder.clone(), config.keys(), &finalizer, &mut out, &consumer, &mut paused, log_namespace).await;
+
+                    // Messages can still be received while paused, such as those fetched before
+                    // pausing or from partitions assigned since then, so the current assignment is
+                    // paused again.
+                    if let Some(max) = max_unacknowledged {
+                        let unacknowledged = consumer.context().unacknowledged();
+                        if unacknowledged >= max {
+                            if let Some(partitions) = paused.pause(&consumer, PauseReason::Unacknowledged) {
+                                debug!(
+                                    message = "Paused fetching while too many messages are unacknowledged.",
+                                    unacknowledged,
+                                    partitions,
+                                );
+                            }
+                        }
+                    }
                 }
             },
         }
@@ -429,6 +498,7 @@ async fn kafka_source(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn parse_message(
     msg: BorrowedMessage<'_>,
     decoder: Decoder,
@@ -436,6 +506,7 @@ async fn parse_message(
     finalizer: &Option<Arc<OrderedFinalizer<FinalizerEntry>>>,
     out: &mut SourceSender,
     consumer: &Arc<StreamConsumer<CustomContext>>,
+    paused: &mut PausedPartitions,
     log_namespace: LogNamespace,
 ) {
     if let Some((count, mut stream)) = parse_stream(&msg, decoder, keys, log_namespace) {
@@ -443,7 +514,7 @@ async fn parse_message(
             Some(finalizer) => {
                 let (batch, receiver) = BatchNotifier::new_with_receiver();
                 let mut stream = stream.map(|event| event.with_batch_notifier(&batch));
-                match out.send_event_stream(&mut stream).await {
+                match send_with_backpressure(out, &mut stream, consumer, paused).await {
                     Err(error) => {
                         emit!(StreamClosedError { error, count });
                     }
@@ -452,10 +523,14 @@ async fn parse_message(
                         // here, when `stream` is dropped and runs the destructor [...]".
                         drop(stream);
                         finalizer.add(msg.into(), receiver);
+                        consumer
+                            .context()
+                            .unacknowledged
+                            .fetch_add(1, Ordering::Relaxed);
                     }
                 }
             }
-            None => match out.send_event_stream(&mut stream).await {
+            None => match send_with_backpressure(out, &mut stream, consumer, paused).await {
                 Err(error) => {
                     emit!(StreamClosedError { error, count });
                 }
@@ -471,6 +546,117 @@ async fn parse_message(
     }
 }
 
+/// Sends the events downstream, pausing the assigned partitions for as long as the output is full.
+///
+/// Without pausing, librdkafka keeps fetching messages into its own queues while the source waits
+/// on a stalled sink, which can use an unbounded amount of memory.
+async fn send_with_backpressure<S>(
+    out: &mut SourceSender,
+    events: S,
+    consumer: &StreamConsumer<CustomContext>,
+    paused: &mut PausedPartitions,
+) -> Result<(), ClosedError>
+where
+    S: Stream<Item = Event> + Unpin,
+{
+    let send = out.send_event_stream(events);
+    tokio::pin!(send);
+    if let Poll::Ready(result) = futures::poll!(&mut send) {
+        return result;
+    }
+
+    if let Some(partitions) = paused.pause(consumer, PauseReason::OutputFull) {
+        debug!(
+            message = "Paused fetching while the output is full.",
+            partitions,
+        );
+    }
+
+    let result = send.await;
+
+    if paused.resume(consumer, PauseReason::OutputFull) {
+        debug!(message = "Resumed fetching.");
+    }
+
+    result
+}
+
+/// Why fetching from a partition is paused.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum PauseReason {
+    /// Too many messages are waiting to be acknowledged.
+    Unacknowledged,
+    /// The output of the source is full.
+    OutputFull,
+}
+
+/// The partitions fetching is paused for, along with the reasons they're paused for.
+///
+/// Both reasons can overlap, so a partition is only resumed once neither holds anymore, rather than
+/// when the first of them clears.
+#[derive(Default)]
+struct PausedPartitions {
+    reasons: HashMap<(String, i32), HashSet<PauseReason>>,
+}
+
+impl PausedPartitions {
+    /// Pauses fetching from all the assigned partitions for the given reason, returning how many
+    /// partitions are assigned.
+    fn pause(
+        &mut self,
+        consumer: &StreamConsumer<CustomContext>,
+        reason: PauseReason,
+    ) -> Option<usize> {
+        match consumer
+            .assignment()
+            .and_then(|partitions| consumer.pause(&partitions).map(|()| partitions))
+        {
+            Ok(partitions) => {
+                for element in partitions.elements() {
+                    self.reasons
+                        .entry((element.topic().to_owned(), element.partition()))
+                        .or_default()
+                        .insert(reason);
+                }
+                Some(partitions.count())
+            }
+            Err(error) => {
+                emit!(KafkaPartitionPauseError {
+                    error,
+                    operation: "pause"
+                });
+                None
+            }
+        }
+    }
+
+    /// Clears the given reason, resuming the partitions no other reason keeps paused. Returns
+    /// whether any partition was paused for this reason.
+    fn resume(&mut self, consumer: &StreamConsumer<CustomContext>, reason: PauseReason) -> bool {
+        let mut cleared = false;
+        let mut resumed = TopicPartitionList::new();
+        self.reasons.retain(|(topic, partition), reasons| {
+            cleared |= reasons.remove(&reason);
+            if reasons.is_empty() {
+                resumed.add_partition(topic, *partition);
+                false
+            } else {
+                true
+            }
+        });
+
+        if resumed.count() > 0 {
+            if let Err(error) = consumer.resume(&resumed) {
+                emit!(KafkaPartitionPauseError {
+                    error,
+                    operation: "resume"
+                });
+            }
+        }
+        cleared
+    }
+}
+
 // Turn the received message into a stream of parsed events.
 fn parse_stream<'a>(
     msg: &BorrowedMessage<'a>,
@@ -523,6 +709,7 @@ struct Keys<'a> {
     partition: &'a Option<OwnedValuePath>,
     offset: &'a Option<OwnedValuePath>,
     headers: &'a Option<OwnedValuePath>,
+    passthrough: bool,
 }
 
 impl<'a> Keys<'a> {
@@ -534,6 +721,7 @@ impl<'a> Keys<'a> {
             partition: &config.partition_key.path,
             offset: &config.offset_key.path,
             headers: &config.headers_key.path,
+            passthrough: config.passthrough,
         }
     }
 }
@@ -648,8 +836,24 @@ impl ReceivedMessage {
                 path!("headers"),
                 self.headers.clone(),
             );
+
+            if keys.passthrough && log_namespace == LogNamespace::Legacy {
+                self.insert_passthrough_metadata(log);
+            }
         }
     }
+
+    /// Stores the identity of the message in the event metadata, as the `vector` log namespace
+    /// does, for a `kafka` sink to produce the event as the same message.
+    fn insert_passthrough_metadata(&self, log: &mut LogEvent) {
+        let source_name = KafkaSourceConfig::NAME;
+        let metadata = log.metadata_mut().value_mut();
+        metadata.insert(path!(source_name, "message_key"), self.key.clone());
+        metadata.insert(path!(source_name, "timestamp"), self.timestamp);
+        metadata.insert(path!(source_name, "topic"), self.topic.clone());
+        metadata.insert(path!(source_name, "partition"), self.partition);
+        metadata.insert(path!(source_name, "headers"), self.headers.clone());
+    }
 }
 
 #[derive(Debug)]
@@ -720,6 +924,8 @@ fn create_consumer(config: &KafkaSourceConfig) -> crate::Result<StreamConsumer<C
 struct CustomContext {
     stats: kafka::KafkaStatisticsContext,
     finalizer: OnceCell<Arc<OrderedFinalizer<FinalizerEntry>>>,
+    /// The number of messages handed to the finalizer and not acknowledged yet.
+    unacknowledged: AtomicUsize,
 }
 
 impl CustomContext {
@@ -729,6 +935,20 @@ impl CustomContext {
             ..Default::default()
         }
     }
+
+    fn unacknowledged(&self) -> usize {
+        self.unacknowledged.load(Ordering::Relaxed)
+    }
+
+    fn acknowledged(&self) {
+        // Saturates, as the count is reset when the pending acknowledgements are flushed, while
+        // some of them may still be on their way.
+        _ = self
+            .unacknowledged
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+                count.checked_sub(1)
+            });
+    }
 }
 
 impl ClientContext for CustomContext {
@@ -742,6 +962,7 @@ impl ConsumerContext for CustomContext {
         if matches!(rebalance, Rebalance::Revoke(_)) {
             if let Some(finalizer) = self.finalizer.get() {
                 finalizer.flush();
+                self.unacknowledged.store(0, Ordering::Relaxed);
             }
         }
     }
@@ -875,6 +1096,61 @@ mod test {
         )
     }
 
+    #[test]
+    fn passthrough_metadata_legacy_namespace() {
+        let config = KafkaSourceConfig {
+            passthrough: true,
+            ..make_config("topic", "group", LogNamespace::Legacy)
+        };
+        let mut headers = BTreeMap::new();
+        headers.insert("trace-id".to_owned(), Value::from("abc"));
+        let message = ReceivedMessage {
+            timestamp: Utc.timestamp_millis_opt(1_600_000_000_000).latest(),
+            key: Value::from("key"),
+            headers,
+            topic: "orders".to_owned(),
+            partition: 3,
+            offset: 42,
+        };
+
+        let mut event = Event::Log(LogEvent::from("hello"));
+        message.apply(&config.keys(), &mut event, LogNamespace::Legacy);
+
+        let log = event.as_log();
+        assert_eq!(log["topic"], "orders".into());
+        let metadata = log.metadata().value();
+        assert_eq!(
+            metadata.get(path!("kafka", "topic")),
+            Some(&Value::from("orders"))
+        );
+        assert_eq!(
+            metadata.get(path!("kafka", "partition")),
+            Some(&Value::from(3))
+        );
+        assert_eq!(
+            metadata.get(path!("kafka", "message_key")),
+            Some(&Value::from("key"))
+        );
+        assert_eq!(
+            metadata.get(path!("kafka", "headers", "trace-id")),
+            Some(&Value::from("abc"))
+        );
+    }
+
+    #[test]
+    fn unacknowledged_count_saturates() {
+        let context = CustomContext::new(false);
+        context.unacknowledged.fetch_add(2, Ordering::Relaxed);
+
+        context.acknowledged();
+        assert_eq!(context.unacknowledged(), 1);
+
+        // Acknowledgements still on their way after a flush reset the count.
+        context.unacknowledged.store(0, Ordering::Relaxed);
+        context.acknowledged();
+        assert_eq!(context.unacknowledged(), 0);
+    }
+
     #[tokio::test]
     async fn consumer_create_ok() {
         let config = make_config("topic", "group", LogNamespace::Legacy);
@@ -1146,7 +1422,8 @@ mod integration_test {
             config.decoding.clone(),
             log_namespace,
         )
-        .build();
+        .build()
+        .unwrap();
 
         tokio::spawn(kafka_source(
             config,
@@ -42,8 +42,8 @@ use vector_core::{
 
 use crate::{
     config::{
-        log_schema, ComponentKey, DataType, GenerateConfig, GlobalOptions, SourceConfig,
-        SourceContext, SourceOutput,
+        log_schema, ComponentKey, DataDirSubsystem, DataType, GenerateConfig, GlobalOptions,
+        SourceConfig, SourceContext, SourceOutput,
     },
     event::Event,
     internal_events::{
@@ -135,7 +135,9 @@ pub struct Config {
     /// Whether or not to automatically merge partial events.
     ///
     /// Partial events are messages that were split by the Kubernetes Container Runtime
-    /// log driver.
+    /// log driver. They are merged per container output stream, including across the rotations
+    /// of the log files, and the lines read twice while a log file is being rotated are
+    /// discarded.
     auto_partial_merge: bool,
 
     /// The directory used to persist file checkpoint positions.
@@ -568,7 +570,11 @@ impl Source {
         };
         let client = Client::try_from(client_config)?;
 
-        let data_dir = globals.resolve_and_make_data_subdir(config.data_dir.as_ref(), key.id())?;
+        let data_dir = globals.resolve_and_make_data_subdir_for(
+            DataDirSubsystem::Checkpoints,
+            config.data_dir.as_ref(),
+            key.id(),
+        )?;
 
         let exclude_paths = prepare_exclude_paths(config)?;
 
@@ -772,6 +778,7 @@ impl Source {
             oldest_first: true,
             // We do not remove the log files, `kubelet` is responsible for it.
             remove_after: None,
+            archive_dir: None,
             // The standard emitter.
             emitter: FileSourceInternalEventsEmitter,
             // A handle to the current tokio runtime
@@ -1,60 +1,444 @@
 #![deny(missing_docs)]
 
-use enrichment::TableRegistry;
-use indexmap::IndexMap;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt};
+use lookup::{event_path, metadata_path};
 use vector_core::config::LogNamespace;
 
-use super::{transform_utils::optional::Optional, FILE_KEY};
+use super::{transform_utils::optional::Optional, Config, FILE_KEY};
 use crate::{
-    conditions::AnyCondition,
     config::log_schema,
-    event,
-    transforms::reduce::{MergeStrategy, Reduce, ReduceConfig},
+    event::{self, Event, LogEvent, Value},
+    internal_events::{KubernetesLogsDuplicateLineSuppressed, KubernetesLogsPartialLinesMerged},
+    transforms::TaskTransform,
 };
 
+/// How long a partial line is waited on to be completed before being emitted as is.
+const EXPIRE_AFTER: Duration = Duration::from_secs(30);
+
+/// How often the partial lines are checked for expiration.
+const FLUSH_PERIOD: Duration = Duration::from_secs(1);
+
+/// How long the lines of an idle stream are remembered to suppress duplicates.
+const DEDUPLICATION_WINDOW: Duration = Duration::from_secs(300);
+
+/// How many of the latest lines of each stream are remembered to suppress duplicates.
+const DEDUPLICATION_LINES: usize = 256;
+
+const STREAM_KEY: &str = "stream";
+
+/// Returns the path of the log file, without the suffix added when it's rotated.
+///
+/// The kubelet rotates `0.log` by renaming it to `0.log.20230101-000000`, so the end of a line may
+/// be read from the rotated file, and the rest of it from the new one.
+fn unrotated_path(file: &str) -> &str {
+    match file.rfind(".log.") {
+        Some(index) if !file[index..].contains('/') => &file[..index + ".log".len()],
+        _ => file,
+    }
+}
+
+/// Returns a field set by the source, which is in the metadata of the event in the Vector namespace.
+fn source_field<'a>(
+    log_namespace: LogNamespace,
+    log: &'a LogEvent,
+    field: &str,
+) -> Option<&'a Value> {
+    match log_namespace {
+        LogNamespace::Vector => log.get(metadata_path!(Config::NAME, field)),
+        LogNamespace::Legacy => log.get(event_path!(field)),
+    }
+}
+
+/// The lines of a container output stream, such as `stdout`, read from one log file and its
+/// rotations.
+struct LineStream {
+    /// The line being reassembled, if its last fragment wasn't read yet.
+    partial: Option<PartialLine>,
+    /// The fingerprints of the latest fragments, oldest first.
+    recent: VecDeque<u64>,
+    /// The fingerprints in `recent`, for fast lookups.
+    recent_set: HashSet<u64>,
+    last_seen: Instant,
+}
+
+impl LineStream {
+    fn new(now: Instant) -> Self {
+        Self {
+            partial: None,
+            recent: VecDeque::with_capacity(DEDUPLICATION_LINES + 1),
+            recent_set: HashSet::with_capacity(DEDUPLICATION_LINES),
+            last_seen: now,
+        }
+    }
+
+    /// Remembers the fingerprint of a fragment, returning `false` if it was seen recently.
+    fn remember(&mut self, fingerprint: u64) -> bool {
+        if !self.recent_set.insert(fingerprint) {
+            return false;
+        }
+
+        self.recent.push_back(fingerprint);
+        if self.recent.len() > DEDUPLICATION_LINES {
+            if let Some(oldest) = self.recent.pop_front() {
+                self.recent_set.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// A line split into several fragments by the container runtime.
+struct PartialLine {
+    /// The first fragment, holding the fields of the line.
+    event: LogEvent,
+    message: BytesMut,
+    fragments: usize,
+}
+
 /// Partial event merger.
-pub type PartialEventsMerger = Optional<Reduce>;
+///
+/// Reassembles the lines split by the container runtime, and suppresses the lines read twice when
+/// racing with the rotation of the log files.
+pub struct PartialEventsMerger {
+    log_namespace: LogNamespace,
+    streams: HashMap<(String, Bytes), LineStream>,
+}
+
+pub fn build(enabled: bool, log_namespace: LogNamespace) -> Optional<PartialEventsMerger> {
+    Optional(enabled.then(|| PartialEventsMerger {
+        log_namespace,
+        streams: HashMap::new(),
+    }))
+}
+
+impl PartialEventsMerger {
+    fn message<'a>(&self, log: &'a LogEvent) -> Option<&'a Bytes> {
+        let message = match self.log_namespace {
+            LogNamespace::Vector => log.get("."),
+            LogNamespace::Legacy => log.get(log_schema().message_key()),
+        };
+        message.and_then(Value::as_bytes)
+    }
+
+    fn set_message(&self, log: &mut LogEvent, message: Bytes) {
+        match self.log_namespace {
+            LogNamespace::Vector => log.insert(".", message),
+            LogNamespace::Legacy => log.insert(log_schema().message_key(), message),
+        };
+    }
+
+    fn is_partial(&self, log: &LogEvent) -> bool {
+        source_field(self.log_namespace, log, event::PARTIAL).is_some()
+    }
+
+    fn remove_partial(&self, log: &mut LogEvent) {
+        match self.log_namespace {
+            LogNamespace::Vector => log.remove(metadata_path!(Config::NAME, event::PARTIAL)),
+            LogNamespace::Legacy => log.remove(event_path!(event::PARTIAL)),
+        };
+    }
+
+    /// Returns the output stream of the container the event was read from.
+    fn stream_key(&self, log: &LogEvent) -> Option<(String, Bytes)> {
+        let file = source_field(self.log_namespace, log, FILE_KEY)?.as_bytes()?;
+        let file = String::from_utf8_lossy(file);
+        let stream = source_field(self.log_namespace, log, STREAM_KEY)
+            .and_then(Value::as_bytes)
+            .cloned()
+            .unwrap_or_default();
+        Some((unrotated_path(&file).to_owned(), stream))
+    }
+
+    /// Returns the fingerprint of a fragment, if it has a timestamp to tell it apart from the
+    /// fragments with the same content.
+    fn fingerprint(&self, log: &LogEvent) -> Option<u64> {
+        let timestamp = match self.log_namespace {
+            LogNamespace::Vector => log.get(metadata_path!(Config::NAME, "timestamp")),
+            LogNamespace::Legacy => log.get_timestamp(),
+        }?
+        .as_timestamp()?;
+
+        let mut hasher = DefaultHasher::new();
+        timestamp.hash(&mut hasher);
+        self.is_partial(log).hash(&mut hasher);
+        self.message(log).hash(&mut hasher);
+        Some(hasher.finish())
+    }
 
-pub fn build(enabled: bool, log_namespace: LogNamespace) -> PartialEventsMerger {
-    let reducer = if enabled {
-        let key = match log_namespace {
-            LogNamespace::Vector => ".".to_string(),
-            LogNamespace::Legacy => log_schema().message_key().to_string(),
+    /// Turns a reassembled line back into an event, flagged as partial if it's incomplete.
+    fn finish(&self, line: PartialLine, complete: bool) -> Event {
+        let PartialLine {
+            mut event,
+            message,
+            fragments,
+        } = line;
+
+        self.set_message(&mut event, message.freeze());
+        if complete {
+            self.remove_partial(&mut event);
+        }
+        emit!(KubernetesLogsPartialLinesMerged {
+            fragments,
+            complete
+        });
+        event.into()
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let log = event.into_log();
+        let Some(key) = self.stream_key(&log) else {
+            output.push(log.into());
+            return;
         };
 
-        // Merge the message field of each event by concatenating it, with a space delimiter.
-        let mut merge_strategies = IndexMap::new();
-        merge_strategies.insert(key, MergeStrategy::ConcatRaw);
-
-        // Group events by their file.
-        let group_by = vec![FILE_KEY.to_string()];
-
-        // As soon as we see an event that has no "partial" field, that's when we've hit the end of the split-up message
-        // we've been incrementally aggregating.. or the message was never split up to begin with because it was already
-        // small enough.
-        let ends_when = Some(AnyCondition::String(format!(
-            "!exists(.{})",
-            event::PARTIAL
-        )));
-
-        // This will default to expiring yet-to-be-completed reduced events after 30 seconds of inactivity, with an
-        // interval of 1 second between checking if any reduced events have expired.
-        let reduce_config = ReduceConfig {
-            group_by,
-            merge_strategies,
-            ends_when,
-            ..Default::default()
+        let now = Instant::now();
+        let fingerprint = self.fingerprint(&log);
+        let partial = self.is_partial(&log);
+        let message = self.message(&log).cloned().unwrap_or_default();
+
+        let stream = self
+            .streams
+            .entry(key)
+            .or_insert_with(|| LineStream::new(now));
+        stream.last_seen = now;
+
+        if let Some(fingerprint) = fingerprint {
+            if !stream.remember(fingerprint) {
+                let file = source_field(self.log_namespace, &log, FILE_KEY)
+                    .map(Value::to_string_lossy)
+                    .unwrap_or_default();
+                emit!(KubernetesLogsDuplicateLineSuppressed { file: &file });
+                return;
+            }
+        }
+
+        let line = match stream.partial.take() {
+            Some(mut line) => {
+                line.message.extend_from_slice(&message);
+                line.fragments += 1;
+                line
+            }
+            None if partial => PartialLine {
+                message: BytesMut::from(&message[..]),
+                event: log,
+                fragments: 1,
+            },
+            None => {
+                output.push(log.into());
+                return;
+            }
         };
 
-        // TODO: This is _slightly_ gross because the semantics of `Reduce::new` could change and break things in a way
-        // that isn't super visible in unit tests, if at all visible.
-        let reduce = Reduce::new(&reduce_config, &TableRegistry::default())
-            .expect("should not fail to build `kubernetes_logs`-specific partial event reducer");
+        if partial {
+            stream.partial = Some(line);
+        } else {
+            output.push(self.finish(line, true));
+        }
+    }
+
+    /// Emits the partial lines that weren't completed in time, and forgets the idle streams.
+    fn flush_expired(&mut self, now: Instant, output: &mut Vec<Event>) {
+        let mut expired = Vec::new();
+        self.streams.retain(|_, stream| {
+            let idle_for = now.saturating_duration_since(stream.last_seen);
+            if idle_for >= EXPIRE_AFTER {
+                expired.extend(stream.partial.take());
+            }
+            idle_for < DEDUPLICATION_WINDOW
+        });
+        output.extend(expired.into_iter().map(|line| self.finish(line, false)));
+    }
+
+    fn flush_all(&mut self, output: &mut Vec<Event>) {
+        let lines = self
+            .streams
+            .drain()
+            .filter_map(|(_, stream)| stream.partial)
+            .collect::<Vec<_>>();
+        output.extend(lines.into_iter().map(|line| self.finish(line, false)));
+    }
+}
+
+impl TaskTransform<Event> for PartialEventsMerger {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+        let mut flush_stream = tokio::time::interval(FLUSH_PERIOD);
+
+        Box::pin(stream! {
+            loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                        me.flush_expired(Instant::now(), &mut output);
+                        false
+                    }
+                    maybe_event = input_rx.next() => match maybe_event {
+                        None => {
+                            me.flush_all(&mut output);
+                            true
+                        }
+                        Some(event) => {
+                            me.transform_one(&mut output, event);
+                            false
+                        }
+                    }
+                };
+                for event in output {
+                    yield event;
+                }
+                if done {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+
+    fn line(file: &str, stream: &str, timestamp: &str, message: &str, partial: bool) -> Event {
+        let mut log = LogEvent::default();
+        log.insert(event_path!("message"), message);
+        log.insert(event_path!(FILE_KEY), file);
+        log.insert(event_path!(STREAM_KEY), stream);
+        log.insert(
+            event_path!("timestamp"),
+            DateTime::parse_from_rfc3339(timestamp)
+                .unwrap()
+                .with_timezone(&Utc),
+        );
+        if partial {
+            log.insert(event_path!(event::PARTIAL), true);
+        }
+        log.into()
+    }
+
+    fn merge(merger: &mut PartialEventsMerger, events: Vec<Event>) -> Vec<LogEvent> {
+        let mut output = Vec::new();
+        for event in events {
+            merger.transform_one(&mut output, event);
+        }
+        output.into_iter().map(Event::into_log).collect()
+    }
+
+    fn merger() -> PartialEventsMerger {
+        build(true, LogNamespace::Legacy).0.unwrap()
+    }
+
+    #[test]
+    fn strips_rotation_suffix() {
+        let path = "/var/log/pods/ns_pod_uid/container/0.log";
+        assert_eq!(unrotated_path(path), path);
+        assert_eq!(unrotated_path(&format!("{}.20230101-000000", path)), path);
+        assert_eq!(
+            unrotated_path("/var/log/pods/ns_pod.log.uid/container/0.log"),
+            "/var/log/pods/ns_pod.log.uid/container/0.log"
+        );
+    }
+
+    #[test]
+    fn merges_partial_lines_per_stream() {
+        let file = "/var/log/pods/ns_pod_uid/container/0.log";
+        let output = merge(
+            &mut merger(),
+            vec![
+                line(file, "stdout", "2023-01-01T00:00:00.1Z", "{\"a\":", true),
+                line(file, "stderr", "2023-01-01T00:00:00.2Z", "error: ", true),
+                line(file, "stdout", "2023-01-01T00:00:00.3Z", "1}", false),
+                line(file, "stderr", "2023-01-01T00:00:00.4Z", "oops", false),
+            ],
+        );
+
+        assert_eq!(output.len(), 2);
+        assert_eq!(output[0]["message"], "{\"a\":1}".into());
+        assert_eq!(output[0]["stream"], "stdout".into());
+        assert!(!output[0].contains(event_path!(event::PARTIAL)));
+        assert_eq!(output[1]["message"], "error: oops".into());
+    }
+
+    #[test]
+    fn merges_partial_lines_across_rotations() {
+        let file = "/var/log/pods/ns_pod_uid/container/0.log";
+        let rotated = format!("{}.20230101-000000", file);
+        let output = merge(
+            &mut merger(),
+            vec![
+                line(
+                    &rotated,
+                    "stdout",
+                    "2023-01-01T00:00:00.1Z",
+                    "{\"a\":",
+                    true,
+                ),
+                line(file, "stdout", "2023-01-01T00:00:00.2Z", "1}", false),
+            ],
+        );
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0]["message"], "{\"a\":1}".into());
+        assert_eq!(output[0]["file"], rotated.into());
+    }
+
+    #[test]
+    fn suppresses_duplicate_lines() {
+        let file = "/var/log/pods/ns_pod_uid/container/0.log";
+        let rotated = format!("{}.20230101-000000", file);
+        let output = merge(
+            &mut merger(),
+            vec![
+                line(file, "stdout", "2023-01-01T00:00:00.1Z", "first", false),
+                line(&rotated, "stdout", "2023-01-01T00:00:00.1Z", "first", false),
+                line(file, "stdout", "2023-01-01T00:00:00.2Z", "first", false),
+            ],
+        );
+
+        assert_eq!(output.len(), 2);
+        assert!(output.iter().all(|log| log["message"] == "first".into()));
+    }
+
+    #[test]
+    fn flushes_expired_partial_lines() {
+        let file = "/var/log/pods/ns_pod_uid/container/0.log";
+        let mut merger = merger();
+        let output = merge(
+            &mut merger,
+            vec![line(
+                file,
+                "stdout",
+                "2023-01-01T00:00:00.1Z",
+                "start",
+                true,
+            )],
+        );
+        assert!(output.is_empty());
 
-        Some(reduce)
-    } else {
-        None
-    };
+        let mut output = Vec::new();
+        merger.flush_expired(Instant::now(), &mut output);
+        assert!(output.is_empty());
 
-    Optional(reducer)
+        merger.flush_expired(Instant::now() + EXPIRE_AFTER, &mut output);
+        assert_eq!(output.len(), 1);
+        let log = output[0].as_log();
+        assert_eq!(log["message"], "start".into());
+        assert!(log.contains(event_path!(event::PARTIAL)));
+    }
 }
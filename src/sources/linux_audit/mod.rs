@@ -0,0 +1,415 @@
+//! Collects the events of the Linux audit framework.
+//!
+//! Records are read either from the audit netlink socket of the kernel, as a read-only subscriber
+//! that doesn't interfere with `auditd`, or from the `af_unix` plugin of `audisp`. The records of
+//! each event are reassembled into a single log event, with a field for each type of record.
+use std::{
+    io,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use futures::StreamExt;
+use lookup::{owned_value_path, path};
+use nix::errno::Errno;
+use serde_with::serde_as;
+use snafu::{ResultExt, Snafu};
+use tokio::{net::UnixStream, time::sleep};
+use tokio_util::codec::{FramedRead, LinesCodec};
+use value::{kind::Collection, Kind};
+use vector_common::internal_event::{
+    ByteSize, BytesReceived, CountByteSize, InternalEventHandle as _, Protocol, Registered,
+};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{LegacyKey, LogNamespace},
+    schema::Definition,
+    EstimatedJsonEncodedSizeOf,
+};
+
+use crate::{
+    config::{log_schema, DataType, GenerateConfig, SourceConfig, SourceContext, SourceOutput},
+    event::LogEvent,
+    internal_events::{
+        EventsReceived, LinuxAuditParseError, LinuxAuditReceiveError, StreamClosedError,
+    },
+    shutdown::ShutdownSignal,
+    SourceSender,
+};
+
+mod netlink;
+mod record;
+
+use self::{
+    netlink::AuditSocket,
+    record::{AuditEvent, ParseError, Reassembler, Record},
+};
+
+/// The types of netlink messages below this one are control messages of netlink itself.
+const FIRST_RECORD_TYPE: u16 = 1000;
+
+/// The maximum length of the lines read from the socket of `audisp`.
+const MAX_LINE_LENGTH: usize = 64 * 1024;
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The interval at which events missing their last record are checked for expiry.
+const FLUSH_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Failed to subscribe to the audit netlink socket: {}", source))]
+    Subscribe { source: io::Error },
+}
+
+/// Configuration for the `linux_audit` source.
+#[serde_as]
+#[configurable_component(source(
+    "linux_audit",
+    "Collect the events of the Linux audit framework."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LinuxAuditConfig {
+    /// The path of the socket of the `af_unix` plugin of `audisp`, to read the records from.
+    ///
+    /// The plugin must be configured with the `string` format. If not set, the records are read
+    /// from the audit netlink socket of the kernel, which requires the `CAP_AUDIT_READ` capability.
+    #[configurable(metadata(docs::examples = "/var/run/audispd_events"))]
+    socket_path: Option<PathBuf>,
+
+    /// The time to wait for the last record of an event.
+    ///
+    /// Once elapsed, the records of the event received so far are emitted as the event, as some
+    /// kernel events aren't terminated by an `EOE` record.
+    #[serde(default = "default_reassembly_timeout_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    reassembly_timeout_secs: Duration,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+pub const fn default_reassembly_timeout_secs() -> Duration {
+    Duration::from_secs(2)
+}
+
+impl GenerateConfig for LinuxAuditConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            socket_path: None,
+            reassembly_timeout_secs: default_reassembly_timeout_secs(),
+            log_namespace: None,
+        })
+        .unwrap()
+    }
+}
+
+impl LinuxAuditConfig {
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        Definition::new_with_default_metadata(Kind::object(Collection::empty()), [log_namespace])
+            .with_event_field(
+                &owned_value_path!("message"),
+                Kind::bytes(),
+                Some("message"),
+            )
+            .with_event_field(&owned_value_path!("serial"), Kind::integer(), None)
+            .with_event_field(
+                &owned_value_path!("record_types"),
+                Kind::array(Collection::empty().with_unknown(Kind::bytes())),
+                None,
+            )
+            // The fields of the records, keyed by their type.
+            .unknown_fields(Kind::object(Collection::any()).or_array(Collection::any()))
+            .with_source_metadata(
+                Self::NAME,
+                log_schema()
+                    .timestamp_key()
+                    .cloned()
+                    .map(LegacyKey::Overwrite),
+                &owned_value_path!("timestamp"),
+                Kind::timestamp(),
+                Some("timestamp"),
+            )
+            .with_standard_vector_source_metadata()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "linux_audit")]
+impl SourceConfig for LinuxAuditConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let (reader, protocol) = match &self.socket_path {
+            Some(path) => (
+                Reader::Socket {
+                    path: path.clone(),
+                    lines: None,
+                },
+                "unix",
+            ),
+            None => (
+                Reader::Netlink(AuditSocket::open().context(SubscribeSnafu)?),
+                "netlink",
+            ),
+        };
+
+        let source = AuditSource {
+            reassembler: Reassembler::new(self.reassembly_timeout_secs),
+            log_namespace: cx.log_namespace(self.log_namespace),
+            events_received: register!(EventsReceived),
+            bytes_received: register!(BytesReceived::from(Protocol::from(protocol))),
+        };
+
+        Ok(Box::pin(source.run(reader, cx.out, cx.shutdown)))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_definition =
+            self.schema_definition(global_log_namespace.merge(self.log_namespace));
+
+        vec![SourceOutput::new_logs(DataType::Log, schema_definition)]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+/// Where the records are read from.
+enum Reader {
+    Netlink(AuditSocket),
+    Socket {
+        path: PathBuf,
+        lines: Option<FramedRead<UnixStream, LinesCodec>>,
+    },
+}
+
+impl Reader {
+    /// Reads the next records.
+    ///
+    /// Fails if the netlink socket can no longer be read from, while the socket of `audisp` is
+    /// reconnected to, as it goes away whenever `auditd` restarts.
+    async fn read(
+        &mut self,
+        bytes_received: &Registered<BytesReceived>,
+    ) -> Result<Vec<Record>, ()> {
+        match self {
+            Self::Netlink(socket) => loop {
+                match socket.recv().await {
+                    Ok(messages) => {
+                        bytes_received.emit(ByteSize(
+                            messages.iter().map(|(_, payload)| payload.len()).sum(),
+                        ));
+                        return Ok(messages
+                            .into_iter()
+                            .filter(|(record_type, _)| *record_type >= FIRST_RECORD_TYPE)
+                            .filter_map(|(record_type, payload)| {
+                                parse(Record::from_netlink(record_type, &payload), &payload)
+                            })
+                            .collect());
+                    }
+                    // The kernel dropped records, but more can still be read.
+                    Err(error) if error.raw_os_error() == Some(Errno::ENOBUFS as i32) => {
+                        emit!(LinuxAuditReceiveError { error });
+                    }
+                    Err(error) => {
+                        emit!(LinuxAuditReceiveError { error });
+                        return Err(());
+                    }
+                }
+            },
+            Self::Socket { path, lines } => loop {
+                let Some(stream) = lines.as_mut() else {
+                    match UnixStream::connect(&*path).await {
+                        Ok(stream) => {
+                            *lines = Some(FramedRead::new(
+                                stream,
+                                LinesCodec::new_with_max_length(MAX_LINE_LENGTH),
+                            ));
+                        }
+                        Err(error) => {
+                            emit!(LinuxAuditReceiveError { error });
+                            sleep(RECONNECT_DELAY).await;
+                        }
+                    }
+                    continue;
+                };
+
+                match stream.next().await {
+                    Some(Ok(line)) => {
+                        bytes_received.emit(ByteSize(line.len() + 1));
+                        return Ok(parse(Record::from_line(&line), &line).into_iter().collect());
+                    }
+                    Some(Err(error)) => {
+                        emit!(LinuxAuditReceiveError {
+                            error: io::Error::new(io::ErrorKind::InvalidData, error),
+                        });
+                    }
+                    None => {
+                        emit!(LinuxAuditReceiveError {
+                            error: io::Error::new(
+                                io::ErrorKind::UnexpectedEof,
+                                "audisp closed the socket",
+                            ),
+                        });
+                        *lines = None;
+                        sleep(RECONNECT_DELAY).await;
+                    }
+                }
+            },
+        }
+    }
+}
+
+fn parse(result: Result<Record, ParseError>, text: &str) -> Option<Record> {
+    result
+        .map_err(|error| {
+            emit!(LinuxAuditParseError {
+                error: &error,
+                record: text,
+            })
+        })
+        .ok()
+}
+
+struct AuditSource {
+    reassembler: Reassembler,
+    log_namespace: LogNamespace,
+    events_received: Registered<EventsReceived>,
+    bytes_received: Registered<BytesReceived>,
+}
+
+impl AuditSource {
+    async fn run(
+        mut self,
+        mut reader: Reader,
+        mut out: SourceSender,
+        mut shutdown: ShutdownSignal,
+    ) -> Result<(), ()> {
+        let mut flush = tokio::time::interval(FLUSH_INTERVAL);
+        let mut result = Ok(());
+
+        loop {
+            let events = tokio::select! {
+                _ = &mut shutdown => break,
+                _ = flush.tick() => self.reassembler.flush_expired(Instant::now()),
+                records = reader.read(&self.bytes_received) => {
+                    let now = Instant::now();
+                    let Ok(records) = records else {
+                        result = Err(());
+                        break;
+                    };
+                    records
+                        .into_iter()
+                        .flat_map(|record| self.reassembler.push(record, now))
+                        .collect()
+                }
+            };
+            self.send(&mut out, events).await?;
+        }
+
+        // The events missing their last record are emitted as is, rather than lost.
+        let events = self.reassembler.flush_all();
+        self.send(&mut out, events).await.and(result)
+    }
+
+    async fn send(&self, out: &mut SourceSender, events: Vec<AuditEvent>) -> Result<(), ()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let events = events
+            .into_iter()
+            .map(|event| self.to_log(event))
+            .collect::<Vec<_>>();
+        let count = events.len();
+        self.events_received.emit(CountByteSize(
+            count,
+            events.estimated_json_encoded_size_of(),
+        ));
+
+        out.send_batch(events).await.map_err(|error| {
+            emit!(StreamClosedError { error, count });
+        })
+    }
+
+    fn to_log(&self, event: AuditEvent) -> LogEvent {
+        let timestamp = event.timestamp;
+        let mut log = LogEvent::from(event.into_fields());
+        self.log_namespace.insert_source_metadata(
+            LinuxAuditConfig::NAME,
+            &mut log,
+            log_schema().timestamp_key().map(LegacyKey::Overwrite),
+            path!("timestamp"),
+            timestamp,
+        );
+        self.log_namespace.insert_standard_vector_source_metadata(
+            &mut log,
+            LinuxAuditConfig::NAME,
+            Utc::now(),
+        );
+        log
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{io::AsyncWriteExt, net::UnixListener};
+
+    use super::*;
+    use crate::test_util::components::{assert_source_compliance, SOURCE_TAGS};
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<LinuxAuditConfig>();
+    }
+
+    #[tokio::test]
+    async fn reads_events_from_audisp() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audispd_events");
+        let listener = UnixListener::bind(&path).unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream
+                .write_all(
+                    concat!(
+                        "type=SYSCALL msg=audit(1364481363.243:24287): syscall=59 comm=\"ls\"\n",
+                        "type=PROCTITLE msg=audit(1364481363.243:24287): proctitle=6C73\n",
+                        "type=EOE msg=audit(1364481363.243:24287): \n",
+                    )
+                    .as_bytes(),
+                )
+                .await
+                .unwrap();
+            // Keep the connection open until the events are read.
+            std::future::pending::<()>().await;
+        });
+
+        let config = LinuxAuditConfig {
+            socket_path: Some(path),
+            reassembly_timeout_secs: default_reassembly_timeout_secs(),
+            log_namespace: None,
+        };
+        let events = assert_source_compliance(&SOURCE_TAGS, async {
+            let (tx, rx) = SourceSender::new_test();
+            let cx = SourceContext::new_test(tx, None);
+            tokio::spawn(config.build(cx).await.unwrap());
+            rx.take(1).collect::<Vec<_>>().await
+        })
+        .await;
+
+        let log = events[0].as_log();
+        assert_eq!(log["serial"], 24287.into());
+        assert_eq!(log["syscall.comm"], "ls".into());
+        assert_eq!(log["proctitle.proctitle"], "ls".into());
+        assert_eq!(log["source_type"], "linux_audit".into());
+        assert_eq!(
+            log["timestamp"].as_timestamp().unwrap().timestamp_millis(),
+            1_364_481_363_243
+        );
+    }
+}
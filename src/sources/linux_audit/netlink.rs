@@ -0,0 +1,120 @@
+//! Subscription to the audit netlink socket of the kernel.
+
+use std::{
+    io,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+use nix::sys::socket::{
+    bind, recv, socket, AddressFamily, MsgFlags, NetlinkAddr, SockFlag, SockProtocol, SockType,
+};
+use tokio::io::unix::AsyncFd;
+
+/// The multicast group the kernel sends a copy of the audit records to, without interfering with
+/// the audit daemon.
+const AUDIT_NLGRP_READLOG: u32 = 1;
+
+/// The length of the `nlmsghdr` header preceding each message.
+const HEADER_LEN: usize = 16;
+
+/// The maximum size of the datagrams of the socket, which may hold several messages.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// A read-only subscription to the audit records of the kernel.
+///
+/// Joining the multicast group requires the `CAP_AUDIT_READ` capability.
+pub(super) struct AuditSocket {
+    fd: AsyncFd<OwnedFd>,
+    buffer: Vec<u8>,
+}
+
+impl AuditSocket {
+    pub(super) fn open() -> io::Result<Self> {
+        let fd = socket(
+            AddressFamily::Netlink,
+            SockType::Raw,
+            SockFlag::SOCK_NONBLOCK | SockFlag::SOCK_CLOEXEC,
+            SockProtocol::NetlinkAudit,
+        )?;
+        // SAFETY: the file descriptor was just opened, and isn't owned by anything else.
+        let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+        bind(
+            fd.as_raw_fd(),
+            &NetlinkAddr::new(0, 1 << (AUDIT_NLGRP_READLOG - 1)),
+        )?;
+
+        Ok(Self {
+            fd: AsyncFd::new(fd)?,
+            buffer: vec![0; BUFFER_SIZE],
+        })
+    }
+
+    /// Receives the next datagram, returning the type and payload of each of its messages.
+    ///
+    /// Returns an `ENOBUFS` error if the kernel dropped records because they weren't read fast
+    /// enough, after which the socket can still be read from.
+    pub(super) async fn recv(&mut self) -> io::Result<Vec<(u16, String)>> {
+        loop {
+            let mut guard = self.fd.readable().await?;
+            let result = guard.try_io(|fd| {
+                recv(fd.as_raw_fd(), &mut self.buffer, MsgFlags::empty()).map_err(io::Error::from)
+            });
+            match result {
+                Ok(Ok(len)) => return Ok(parse_messages(&self.buffer[..len])),
+                Ok(Err(error)) => return Err(error),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+/// Splits a datagram into its messages, each made of a `nlmsghdr` header followed by a payload.
+fn parse_messages(mut datagram: &[u8]) -> Vec<(u16, String)> {
+    let mut messages = Vec::new();
+    while datagram.len() >= HEADER_LEN {
+        let len = u32::from_ne_bytes(datagram[0..4].try_into().expect("slice of 4 bytes")) as usize;
+        let message_type = u16::from_ne_bytes(datagram[4..6].try_into().expect("slice of 2 bytes"));
+        if len < HEADER_LEN || len > datagram.len() {
+            break;
+        }
+
+        let payload = &datagram[HEADER_LEN..len];
+        messages.push((message_type, String::from_utf8_lossy(payload).into_owned()));
+
+        // Messages are aligned to 4 bytes.
+        let aligned = (len + 3) & !3;
+        datagram = datagram.get(aligned..).unwrap_or_default();
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(message_type: u16, payload: &str) -> Vec<u8> {
+        let len = HEADER_LEN + payload.len();
+        let mut message = Vec::new();
+        message.extend_from_slice(&(len as u32).to_ne_bytes());
+        message.extend_from_slice(&message_type.to_ne_bytes());
+        message.extend_from_slice(&[0; 10]);
+        message.extend_from_slice(payload.as_bytes());
+        message.resize((len + 3) & !3, 0);
+        message
+    }
+
+    #[test]
+    fn parses_datagrams() {
+        let mut datagram = message(1300, "audit(1.000:1): a=b");
+        datagram.extend(message(1320, "audit(1.000:1): "));
+
+        assert_eq!(
+            parse_messages(&datagram),
+            vec![
+                (1300, "audit(1.000:1): a=b".to_owned()),
+                (1320, "audit(1.000:1): ".to_owned()),
+            ]
+        );
+        assert!(parse_messages(&datagram[..10]).is_empty());
+    }
+}
@@ -0,0 +1,657 @@
+//! Parsing of audit records, and their reassembly into events.
+//!
+//! The kernel reports each audit event as a sequence of records sharing the same timestamp and
+//! serial number, such as a `SYSCALL` record followed by `CWD`, `PATH` and `PROCTITLE` records.
+//! Events spanning multiple records are terminated by an `EOE` record, while events reported by
+//! user space programs, such as `USER_LOGIN`, consist of a single record.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant},
+};
+
+use bytes::Bytes;
+use chrono::{DateTime, TimeZone, Utc};
+use snafu::Snafu;
+use vector_core::event::Value;
+
+/// The type of the record terminating multi-record events.
+const AUDIT_EOE: u16 = 1320;
+
+/// The maximum number of events being reassembled at once, beyond which the oldest one is flushed.
+const MAX_IN_FLIGHT: usize = 1024;
+
+/// The names of the common record types, as reported by `auditd`.
+const RECORD_TYPES: &[(u16, &str)] = &[
+    (1006, "LOGIN"),
+    (1100, "USER_AUTH"),
+    (1101, "USER_ACCT"),
+    (1102, "USER_MGMT"),
+    (1103, "CRED_ACQ"),
+    (1104, "CRED_DISP"),
+    (1105, "USER_START"),
+    (1106, "USER_END"),
+    (1107, "USER_AVC"),
+    (1108, "USER_CHAUTHTOK"),
+    (1109, "USER_ERR"),
+    (1110, "CRED_REFR"),
+    (1111, "USYS_CONFIG"),
+    (1112, "USER_LOGIN"),
+    (1113, "USER_LOGOUT"),
+    (1114, "ADD_USER"),
+    (1115, "DEL_USER"),
+    (1116, "ADD_GROUP"),
+    (1117, "DEL_GROUP"),
+    (1120, "GRP_AUTH"),
+    (1123, "USER_CMD"),
+    (1124, "USER_TTY"),
+    (1130, "SERVICE_START"),
+    (1131, "SERVICE_STOP"),
+    (1200, "DAEMON_START"),
+    (1201, "DAEMON_END"),
+    (1202, "DAEMON_ABORT"),
+    (1203, "DAEMON_CONFIG"),
+    (1204, "DAEMON_RECONFIG"),
+    (1205, "DAEMON_ROTATE"),
+    (1300, "SYSCALL"),
+    (1302, "PATH"),
+    (1303, "IPC"),
+    (1304, "SOCKETCALL"),
+    (1305, "CONFIG_CHANGE"),
+    (1306, "SOCKADDR"),
+    (1307, "CWD"),
+    (1309, "EXECVE"),
+    (1311, "IPC_SET_PERM"),
+    (1316, "KERNEL_OTHER"),
+    (1317, "FD_PAIR"),
+    (1318, "OBJ_PID"),
+    (1319, "TTY"),
+    (AUDIT_EOE, "EOE"),
+    (1321, "BPRM_FCAPS"),
+    (1322, "CAPSET"),
+    (1323, "MMAP"),
+    (1324, "NETFILTER_PKT"),
+    (1325, "NETFILTER_CFG"),
+    (1326, "SECCOMP"),
+    (1327, "PROCTITLE"),
+    (1328, "FEATURE_CHANGE"),
+    (1329, "REPLACE"),
+    (1330, "KERN_MODULE"),
+    (1331, "FANOTIFY"),
+    (1334, "BPF"),
+    (1400, "AVC"),
+    (1401, "SELINUX_ERR"),
+    (1402, "AVC_PATH"),
+    (1403, "MAC_POLICY_LOAD"),
+    (1404, "MAC_STATUS"),
+    (1405, "MAC_CONFIG_CHANGE"),
+    (1700, "ANOM_PROMISCUOUS"),
+    (1701, "ANOM_ABEND"),
+    (1702, "ANOM_LINK"),
+    (1703, "ANOM_CREAT"),
+    (2100, "ANOM_LOGIN_FAILURES"),
+    (2101, "ANOM_LOGIN_TIME"),
+    (2102, "ANOM_LOGIN_SESSIONS"),
+    (2103, "ANOM_LOGIN_ACCT"),
+    (2104, "ANOM_LOGIN_LOCATION"),
+];
+
+/// The record types that may occur multiple times in an event, which are always mapped to arrays.
+const REPEATED_RECORD_TYPES: &[&str] = &["PATH", "OBJ_PID"];
+
+/// The fields whose values are hex encoded when they contain spaces or special characters.
+const ENCODED_FIELDS: &[&str] = &[
+    "acct",
+    "cmd",
+    "comm",
+    "cwd",
+    "data",
+    "dir",
+    "exe",
+    "key",
+    "name",
+    "new",
+    "ocomm",
+    "old",
+    "path",
+    "proctitle",
+    "vm",
+];
+
+#[derive(Debug, PartialEq, Eq, Snafu)]
+pub(super) enum ParseError {
+    #[snafu(display("Missing record type"))]
+    MissingType,
+    #[snafu(display("Unknown record type {:?}", name))]
+    UnknownType { name: String },
+    #[snafu(display("Missing or invalid `audit(<time>:<serial>)` header"))]
+    InvalidHeader,
+}
+
+/// A single record of an audit event.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Record {
+    pub(super) record_type: u16,
+    pub(super) timestamp: DateTime<Utc>,
+    pub(super) serial: u64,
+    /// The `key=value` pairs following the header, in order.
+    pub(super) fields: Vec<(String, String)>,
+    /// The record as read, for the `message` of the event.
+    pub(super) text: String,
+}
+
+impl Record {
+    /// Parses the payload of a message of the audit netlink socket, of the given type.
+    ///
+    /// The payload is of the form `audit(1364481363.243:24287): arch=c000003e syscall=2 ...`.
+    pub(super) fn from_netlink(record_type: u16, payload: &str) -> Result<Self, ParseError> {
+        let payload = payload.trim_end_matches(['\0', '\n']);
+        let (timestamp, serial, fields) = parse_header(payload)?;
+        Ok(Self {
+            record_type,
+            timestamp,
+            serial,
+            fields: parse_fields(fields),
+            text: format!("type={} msg={}", type_name(record_type), payload),
+        })
+    }
+
+    /// Parses a line as written by `auditd`, or by the `af_unix` plugin of `audisp`.
+    ///
+    /// The line is of the form `type=SYSCALL msg=audit(1364481363.243:24287): arch=c000003e ...`,
+    /// and may be prefixed with the `node` the record comes from.
+    pub(super) fn from_line(line: &str) -> Result<Self, ParseError> {
+        let line = line.trim_end();
+        let rest = match line.strip_prefix("node=") {
+            Some(rest) => rest.split_once(' ').map_or("", |(_, rest)| rest),
+            None => line,
+        };
+        let (name, rest) = rest
+            .strip_prefix("type=")
+            .and_then(|rest| rest.split_once(' '))
+            .ok_or(ParseError::MissingType)?;
+        let record_type = type_number(name).ok_or_else(|| ParseError::UnknownType {
+            name: name.to_owned(),
+        })?;
+        let payload = rest.strip_prefix("msg=").ok_or(ParseError::InvalidHeader)?;
+        let (timestamp, serial, fields) = parse_header(payload)?;
+        Ok(Self {
+            record_type,
+            timestamp,
+            serial,
+            fields: parse_fields(fields),
+            text: line.to_owned(),
+        })
+    }
+
+    /// Whether the record forms an event on its own, rather than being followed by an `EOE` record.
+    ///
+    /// These are the records reported by user space programs.
+    const fn is_standalone(&self) -> bool {
+        matches!(self.record_type, 1100..=1299 | 2100..=2999)
+    }
+}
+
+/// Gets the name of the record type, such as `SYSCALL`, or `UNKNOWN[<type>]` for uncommon types.
+pub(super) fn type_name(record_type: u16) -> String {
+    RECORD_TYPES
+        .iter()
+        .find(|(number, _)| *number == record_type)
+        .map_or_else(
+            || format!("UNKNOWN[{}]", record_type),
+            |(_, name)| (*name).to_owned(),
+        )
+}
+
+fn type_number(name: &str) -> Option<u16> {
+    RECORD_TYPES
+        .iter()
+        .find(|(_, known)| *known == name)
+        .map(|(number, _)| *number)
+        .or_else(|| {
+            name.strip_prefix("UNKNOWN[")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|number| number.parse().ok())
+        })
+}
+
+/// Splits the `audit(<seconds>.<milliseconds>:<serial>): ` header from the fields of a record.
+fn parse_header(payload: &str) -> Result<(DateTime<Utc>, u64, &str), ParseError> {
+    let (header, fields) = payload
+        .strip_prefix("audit(")
+        .and_then(|rest| rest.split_once("):"))
+        .ok_or(ParseError::InvalidHeader)?;
+    let (time, serial) = header.split_once(':').ok_or(ParseError::InvalidHeader)?;
+    let (seconds, millis) = time.split_once('.').ok_or(ParseError::InvalidHeader)?;
+
+    let seconds = seconds.parse().map_err(|_| ParseError::InvalidHeader)?;
+    let millis: u32 = millis.parse().map_err(|_| ParseError::InvalidHeader)?;
+    let timestamp = Utc
+        .timestamp_opt(seconds, millis * 1_000_000)
+        .single()
+        .ok_or(ParseError::InvalidHeader)?;
+    let serial = serial.parse().map_err(|_| ParseError::InvalidHeader)?;
+
+    Ok((timestamp, serial, fields.trim_start()))
+}
+
+/// Parses the space separated `key=value` pairs of a record.
+///
+/// Values may be quoted with double or single quotes, which are kept, such that they can be told
+/// apart from hex encoded values. Words that aren't `key=value` pairs, such as the `{ read }`
+/// permissions of `AVC` records, are skipped. Enriched records written by `auditd` separate the
+/// interpreted fields with a group separator, which is treated as a space.
+fn parse_fields(fields: &str) -> Vec<(String, String)> {
+    let mut parsed = Vec::new();
+    let mut rest = fields;
+    loop {
+        rest = rest.trim_start_matches([' ', '\x1d']);
+        if rest.is_empty() {
+            break;
+        }
+
+        let word_end = rest.find([' ', '\x1d', '=']).unwrap_or(rest.len());
+        if !rest[word_end..].starts_with('=') {
+            rest = &rest[word_end..];
+            continue;
+        }
+        let key = &rest[..word_end];
+        rest = &rest[word_end + 1..];
+
+        let value_end = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => rest[1..].find(quote).map_or(rest.len(), |end| end + 2),
+            _ => rest.find([' ', '\x1d']).unwrap_or(rest.len()),
+        };
+        parsed.push((key.to_owned(), rest[..value_end].to_owned()));
+        rest = &rest[value_end..];
+    }
+    parsed
+}
+
+/// Decodes a raw value, removing its quotes, or decoding it if it's a hex encoded string.
+fn decode_value(key: &str, value: &str) -> Value {
+    if let Some(inner) = value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+    {
+        // The `msg` of user space records holds the fields reported by the program.
+        if key == "msg" {
+            return fields_to_value(parse_fields(inner));
+        }
+        return Value::from(inner);
+    }
+    if let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        return Value::from(inner);
+    }
+
+    if ENCODED_FIELDS.contains(&key) {
+        if let Some(decoded) = decode_hex(value) {
+            // The arguments of the process title are separated by NUL characters.
+            let decoded = if key == "proctitle" {
+                decoded.replace('\0', " ")
+            } else {
+                decoded
+            };
+            return Value::from(decoded);
+        }
+    }
+    Value::from(value)
+}
+
+fn decode_hex(value: &str) -> Option<String> {
+    if value.is_empty() || value.len() % 2 != 0 {
+        return None;
+    }
+    let bytes = (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn fields_to_value(fields: Vec<(String, String)>) -> Value {
+    Value::Object(
+        fields
+            .into_iter()
+            .map(|(key, value)| {
+                let value = decode_value(&key, &value);
+                (key, value)
+            })
+            .collect(),
+    )
+}
+
+/// Maps the fields of an `EXECVE` record, whose arguments are the `a<n>` fields, to an `args`
+/// array. Long arguments are split into `a<n>[<i>]` fields, which are joined back together.
+fn execve_to_value(fields: Vec<(String, String)>) -> Value {
+    let mut args = BTreeMap::<usize, String>::new();
+    let mut other = Vec::new();
+    for (key, value) in fields {
+        let index = key
+            .strip_prefix('a')
+            .map(|rest| rest.split_once('[').map_or(rest, |(index, _)| index))
+            .and_then(|index| index.parse().ok());
+        match index {
+            Some(index) => {
+                let value = match decode_value("data", &value) {
+                    Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                    value => value.to_string_lossy().into_owned(),
+                };
+                args.entry(index).or_default().push_str(&value);
+            }
+            None if key.ends_with("_len") => {}
+            None => other.push((key, value)),
+        }
+    }
+
+    let mut value = fields_to_value(other);
+    if let Value::Object(object) = &mut value {
+        object.insert(
+            "args".to_owned(),
+            Value::Array(args.into_values().map(Value::from).collect()),
+        );
+    }
+    value
+}
+
+/// The fields of an event, reassembled from its records.
+#[derive(Debug, PartialEq)]
+pub(super) struct AuditEvent {
+    pub(super) timestamp: DateTime<Utc>,
+    pub(super) serial: u64,
+    /// The records of the event, as read, joined by newlines.
+    pub(super) message: String,
+    pub(super) record_types: Vec<String>,
+    /// The fields of each record, keyed by the lowercase name of its type.
+    pub(super) records: BTreeMap<String, Value>,
+}
+
+impl AuditEvent {
+    fn new(records: Vec<Record>) -> Self {
+        let first = records.first().expect("events have at least one record");
+        let timestamp = first.timestamp;
+        let serial = first.serial;
+
+        let mut message = Vec::with_capacity(records.len());
+        let mut record_types = Vec::with_capacity(records.len());
+        let mut fields = BTreeMap::<String, Value>::new();
+        for record in records {
+            let name = type_name(record.record_type);
+            let value = match record.record_type {
+                1309 => execve_to_value(record.fields),
+                _ => fields_to_value(record.fields),
+            };
+
+            let key = if name.starts_with("UNKNOWN[") {
+                format!("unknown_{}", record.record_type)
+            } else {
+                name.to_lowercase()
+            };
+            // Records are objects, so an array holds the records of the type seen so far.
+            let repeated = REPEATED_RECORD_TYPES.contains(&name.as_str());
+            let value = match (fields.remove(&key), repeated) {
+                (Some(Value::Array(mut values)), _) => {
+                    values.push(value);
+                    Value::Array(values)
+                }
+                (Some(previous), _) => Value::Array(vec![previous, value]),
+                (None, true) => Value::Array(vec![value]),
+                (None, false) => value,
+            };
+            fields.insert(key, value);
+
+            message.push(record.text);
+            record_types.push(name);
+        }
+
+        Self {
+            timestamp,
+            serial,
+            message: message.join("\n"),
+            record_types,
+            records: fields,
+        }
+    }
+
+    /// Converts the event into the fields of a log event.
+    pub(super) fn into_fields(self) -> BTreeMap<String, Value> {
+        let mut fields = self.records;
+        fields.insert("message".to_owned(), Value::from(self.message));
+        fields.insert("serial".to_owned(), Value::Integer(self.serial as i64));
+        fields.insert(
+            "record_types".to_owned(),
+            Value::Array(
+                self.record_types
+                    .into_iter()
+                    .map(|name| Value::Bytes(Bytes::from(name)))
+                    .collect(),
+            ),
+        );
+        fields
+    }
+}
+
+/// Reassembles the records of events, which may be interleaved with the records of other events.
+pub(super) struct Reassembler {
+    /// The records of the events being reassembled, keyed by serial number.
+    in_flight: HashMap<u64, (Instant, Vec<Record>)>,
+    timeout: Duration,
+}
+
+impl Reassembler {
+    pub(super) fn new(timeout: Duration) -> Self {
+        Self {
+            in_flight: HashMap::new(),
+            timeout,
+        }
+    }
+
+    /// Adds a record, returning the events it completes.
+    pub(super) fn push(&mut self, record: Record, now: Instant) -> Vec<AuditEvent> {
+        let mut events = Vec::new();
+
+        if record.record_type == AUDIT_EOE {
+            if let Some((_, records)) = self.in_flight.remove(&record.serial) {
+                events.push(AuditEvent::new(records));
+            }
+        } else if record.is_standalone() && !self.in_flight.contains_key(&record.serial) {
+            events.push(AuditEvent::new(vec![record]));
+        } else {
+            self.in_flight
+                .entry(record.serial)
+                .or_insert_with(|| (now, Vec::new()))
+                .1
+                .push(record);
+
+            if self.in_flight.len() > MAX_IN_FLIGHT {
+                let oldest = self
+                    .in_flight
+                    .iter()
+                    .min_by_key(|(serial, (started, _))| (*started, **serial))
+                    .map(|(serial, _)| *serial);
+                if let Some((_, records)) = oldest.and_then(|serial| self.in_flight.remove(&serial))
+                {
+                    events.push(AuditEvent::new(records));
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Removes the events which haven't been terminated within the timeout, such as those whose
+    /// records don't end with an `EOE` record.
+    pub(super) fn flush_expired(&mut self, now: Instant) -> Vec<AuditEvent> {
+        let expired = self
+            .in_flight
+            .iter()
+            .filter(|(_, (started, _))| now.duration_since(*started) >= self.timeout)
+            .map(|(serial, _)| *serial)
+            .collect::<Vec<_>>();
+        self.remove_sorted(expired)
+    }
+
+    /// Removes all the events being reassembled.
+    pub(super) fn flush_all(&mut self) -> Vec<AuditEvent> {
+        let serials = self.in_flight.keys().copied().collect();
+        self.remove_sorted(serials)
+    }
+
+    fn remove_sorted(&mut self, mut serials: Vec<u64>) -> Vec<AuditEvent> {
+        serials.sort_unstable();
+        serials
+            .into_iter()
+            .filter_map(|serial| self.in_flight.remove(&serial))
+            .map(|(_, records)| AuditEvent::new(records))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use value::btreemap;
+
+    use super::*;
+
+    const SYSCALL: &str = r#"type=SYSCALL msg=audit(1364481363.243:24287): arch=c000003e syscall=59 success=yes exit=0 ppid=1000 pid=1234 auid=1000 uid=0 comm="ls" exe="/usr/bin/ls" key="exec""#;
+    const EXECVE: &str =
+        "type=EXECVE msg=audit(1364481363.243:24287): argc=3 a0=\"ls\" a1=\"-l\" a2=2F746D702F612062";
+    const PATH: &str = "type=PATH msg=audit(1364481363.243:24287): item=0 name=\"/usr/bin/ls\" inode=1234 nametype=NORMAL";
+    const PROCTITLE: &str = "type=PROCTITLE msg=audit(1364481363.243:24287): proctitle=6C73002D6C";
+    const EOE: &str = "type=EOE msg=audit(1364481363.243:24287): ";
+    const USER_LOGIN: &str = "node=host1 type=USER_LOGIN msg=audit(1364481364.000:24288): pid=99 uid=0 msg='op=login acct=\"alice\" exe=\"/usr/sbin/sshd\" addr=10.0.0.1 res=success'";
+
+    fn field<'a>(value: &'a Value, key: &str) -> &'a Value {
+        &value.as_object().expect("records are objects")[key]
+    }
+
+    fn strings(values: &[&str]) -> Value {
+        Value::Array(values.iter().map(|value| Value::from(*value)).collect())
+    }
+
+    fn push_all(reassembler: &mut Reassembler, lines: &[&str]) -> Vec<AuditEvent> {
+        let now = Instant::now();
+        lines
+            .iter()
+            .flat_map(|line| reassembler.push(Record::from_line(line).unwrap(), now))
+            .collect()
+    }
+
+    #[test]
+    fn parses_records() {
+        let record = Record::from_netlink(
+            1300,
+            "audit(1364481363.243:24287): arch=c000003e syscall=59 comm=\"ls\"\0",
+        )
+        .unwrap();
+        assert_eq!(record.record_type, 1300);
+        assert_eq!(record.serial, 24287);
+        assert_eq!(record.timestamp.timestamp_millis(), 1_364_481_363_243);
+        assert_eq!(
+            record.fields,
+            vec![
+                ("arch".to_owned(), "c000003e".to_owned()),
+                ("syscall".to_owned(), "59".to_owned()),
+                ("comm".to_owned(), "\"ls\"".to_owned()),
+            ]
+        );
+        assert_eq!(
+            record.text,
+            "type=SYSCALL msg=audit(1364481363.243:24287): arch=c000003e syscall=59 comm=\"ls\""
+        );
+
+        assert_eq!(Record::from_line(USER_LOGIN).unwrap().record_type, 1112);
+        assert_eq!(
+            Record::from_line("type=UNKNOWN[1999] msg=audit(1.000:1): a=b")
+                .unwrap()
+                .record_type,
+            1999
+        );
+        assert_eq!(
+            Record::from_line("type=SYSCALL arch=c000003e"),
+            Err(ParseError::InvalidHeader)
+        );
+        assert_eq!(
+            Record::from_line("type=NOPE msg=audit(1.000:1): a=b"),
+            Err(ParseError::UnknownType {
+                name: "NOPE".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn reassembles_multi_record_events() {
+        let mut reassembler = Reassembler::new(Duration::from_secs(2));
+        let mut events = push_all(&mut reassembler, &[SYSCALL, EXECVE, PATH, PROCTITLE]);
+        assert!(events.is_empty());
+        events = push_all(&mut reassembler, &[EOE]);
+        assert_eq!(events.len(), 1);
+
+        let event = events.remove(0);
+        assert_eq!(event.serial, 24287);
+        assert_eq!(
+            event.record_types,
+            vec!["SYSCALL", "EXECVE", "PATH", "PROCTITLE"]
+        );
+        assert_eq!(event.message, [SYSCALL, EXECVE, PATH, PROCTITLE].join("\n"));
+
+        let fields = event.into_fields();
+        assert_eq!(field(&fields["syscall"], "comm"), &Value::from("ls"));
+        assert_eq!(field(&fields["syscall"], "key"), &Value::from("exec"));
+        assert_eq!(
+            field(&fields["execve"], "args"),
+            &strings(&["ls", "-l", "/tmp/a b"])
+        );
+        assert_eq!(
+            fields["path"],
+            Value::Array(vec![Value::from(btreemap! {
+                "item" => "0",
+                "name" => "/usr/bin/ls",
+                "inode" => "1234",
+                "nametype" => "NORMAL",
+            })])
+        );
+        assert_eq!(
+            field(&fields["proctitle"], "proctitle"),
+            &Value::from("ls -l")
+        );
+    }
+
+    #[test]
+    fn emits_user_records_on_their_own() {
+        let mut reassembler = Reassembler::new(Duration::from_secs(2));
+        let mut events = push_all(&mut reassembler, &[SYSCALL, USER_LOGIN]);
+        assert_eq!(events.len(), 1);
+
+        let fields = events.remove(0).into_fields();
+        assert_eq!(fields["record_types"], strings(&["USER_LOGIN"]));
+        assert_eq!(
+            field(&fields["user_login"], "msg"),
+            &Value::from(btreemap! {
+                "op" => "login",
+                "acct" => "alice",
+                "exe" => "/usr/sbin/sshd",
+                "addr" => "10.0.0.1",
+                "res" => "success",
+            })
+        );
+    }
+
+    #[test]
+    fn flushes_unterminated_events() {
+        let mut reassembler = Reassembler::new(Duration::from_secs(2));
+        let now = Instant::now();
+        reassembler.push(Record::from_line(SYSCALL).unwrap(), now);
+
+        assert!(reassembler
+            .flush_expired(now + Duration::from_secs(1))
+            .is_empty());
+        let events = reassembler.flush_expired(now + Duration::from_secs(2));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].record_types, vec!["SYSCALL"]);
+        assert!(reassembler.flush_all().is_empty());
+    }
+}
@@ -13,8 +13,14 @@ pub mod aws_kinesis_firehose;
 pub mod aws_s3;
 #[cfg(feature = "sources-aws_sqs")]
 pub mod aws_sqs;
+#[cfg(feature = "sources-cache_metrics")]
+pub mod cache_metrics;
+#[cfg(feature = "sources-cloudflare_logpush")]
+pub mod cloudflare_logpush;
 #[cfg(any(feature = "sources-datadog_agent"))]
 pub mod datadog_agent;
+#[cfg(feature = "sources-delivery_failures")]
+pub mod delivery_failures;
 #[cfg(feature = "sources-demo_logs")]
 pub mod demo_logs;
 #[cfg(all(unix, feature = "sources-dnstap"))]
@@ -50,10 +56,14 @@ pub mod internal_logs;
 pub mod internal_metrics;
 #[cfg(all(unix, feature = "sources-journald"))]
 pub mod journald;
+#[cfg(all(unix, feature = "sources-journald_gateway"))]
+pub mod journald_gateway;
 #[cfg(feature = "sources-kafka")]
 pub mod kafka;
 #[cfg(feature = "sources-kubernetes_logs")]
 pub mod kubernetes_logs;
+#[cfg(all(target_os = "linux", feature = "sources-linux_audit"))]
+pub mod linux_audit;
 #[cfg(all(feature = "sources-logstash"))]
 pub mod logstash;
 #[cfg(feature = "sources-mongodb_metrics")]
@@ -66,6 +76,8 @@ pub mod nginx_metrics;
 pub mod opentelemetry;
 #[cfg(feature = "sources-postgresql_metrics")]
 pub mod postgresql_metrics;
+#[cfg(feature = "sources-probe")]
+pub mod probe;
 #[cfg(feature = "sources-prometheus")]
 pub mod prometheus;
 #[cfg(feature = "sources-redis")]
@@ -1,6 +1,11 @@
 use futures::TryFutureExt;
-use opentelemetry_proto::proto::collector::logs::v1::{
-    logs_service_server::LogsService, ExportLogsServiceRequest, ExportLogsServiceResponse,
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{
+        logs_service_server::LogsService, ExportLogsServiceRequest, ExportLogsServiceResponse,
+    },
+    trace::v1::{
+        trace_service_server::TraceService, ExportTraceServiceRequest, ExportTraceServiceResponse,
+    },
 };
 use tonic::{Request, Response, Status};
 use vector_common::internal_event::{CountByteSize, InternalEventHandle as _, Registered};
@@ -12,7 +17,7 @@ use vector_core::{
 
 use crate::{
     internal_events::{EventsReceived, StreamClosedError},
-    sources::opentelemetry::LOGS,
+    sources::opentelemetry::{LOGS, TRACES},
     SourceSender,
 };
 
@@ -24,19 +29,8 @@ pub(super) struct Service {
     pub log_namespace: LogNamespace,
 }
 
-#[tonic::async_trait]
-impl LogsService for Service {
-    async fn export(
-        &self,
-        request: Request<ExportLogsServiceRequest>,
-    ) -> Result<Response<ExportLogsServiceResponse>, Status> {
-        let mut events: Vec<Event> = request
-            .into_inner()
-            .resource_logs
-            .into_iter()
-            .flat_map(|v| v.into_event_iter(self.log_namespace))
-            .collect();
-
+impl Service {
+    async fn handle_events(&self, mut events: Vec<Event>, output: &str) -> Result<(), Status> {
         let count = events.len();
         let byte_size = events.estimated_json_encoded_size_of();
         self.events_received.emit(CountByteSize(count, byte_size));
@@ -45,18 +39,53 @@ impl LogsService for Service {
 
         self.pipeline
             .clone()
-            .send_batch_named(LOGS, events)
+            .send_batch_named(output, events)
             .map_err(|error| {
                 let message = error.to_string();
                 emit!(StreamClosedError { error, count });
                 Status::unavailable(message)
             })
             .and_then(|_| handle_batch_status(receiver))
-            .await?;
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl LogsService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportLogsServiceRequest>,
+    ) -> Result<Response<ExportLogsServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_logs
+            .into_iter()
+            .flat_map(|v| v.into_event_iter(self.log_namespace))
+            .collect();
+
+        self.handle_events(events, LOGS).await?;
         Ok(Response::new(ExportLogsServiceResponse {}))
     }
 }
 
+#[tonic::async_trait]
+impl TraceService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_spans
+            .into_iter()
+            .flat_map(|v| v.into_event_iter())
+            .collect();
+
+        self.handle_events(events, TRACES).await?;
+        Ok(Response::new(ExportTraceServiceResponse {}))
+    }
+}
+
 async fn handle_batch_status(receiver: Option<BatchStatusReceiver>) -> Result<(), Status> {
     let status = match receiver {
         Some(receiver) => receiver.await,
@@ -3,8 +3,9 @@ use std::net::SocketAddr;
 use bytes::Bytes;
 use futures_util::FutureExt;
 use http::StatusCode;
-use opentelemetry_proto::proto::collector::logs::v1::{
-    ExportLogsServiceRequest, ExportLogsServiceResponse,
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+    trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse},
 };
 use prost::Message;
 use snafu::Snafu;
@@ -65,37 +66,91 @@ pub(crate) fn build_warp_filter(
     out: SourceSender,
     bytes_received: Registered<BytesReceived>,
     events_received: Registered<EventsReceived>,
+) -> BoxedFilter<(Response,)> {
+    let log_filters = build_log_filter(
+        acknowledgements,
+        log_namespace,
+        out.clone(),
+        bytes_received.clone(),
+        events_received.clone(),
+    );
+    let trace_filters = build_trace_filter(acknowledgements, out, bytes_received, events_received);
+
+    log_filters.or(trace_filters).unify().boxed()
+}
+
+fn build_log_filter(
+    acknowledgements: bool,
+    log_namespace: LogNamespace,
+    out: SourceSender,
+    bytes_received: Registered<BytesReceived>,
+    events_received: Registered<EventsReceived>,
 ) -> BoxedFilter<(Response,)> {
     warp::post()
         .and(warp::path!("v1" / "logs"))
-        .and(warp::header::exact_ignore_case(
-            "content-type",
-            "application/x-protobuf",
-        ))
-        .and(warp::header::optional::<String>("content-encoding"))
-        .and(warp::body::bytes())
+        .and(protobuf_body())
         .and_then(move |encoding_header: Option<String>, body: Bytes| {
             let events = decode(&encoding_header, body).and_then(|body| {
                 bytes_received.emit(ByteSize(body.len()));
-                decode_body(body, log_namespace, &events_received)
+                decode_log_body(body, log_namespace, &events_received)
             });
 
-            handle_request(events, acknowledgements, out.clone(), super::LOGS)
+            handle_request::<ExportLogsServiceResponse>(
+                events,
+                acknowledgements,
+                out.clone(),
+                super::LOGS,
+            )
         })
         .boxed()
 }
 
-fn decode_body(
-    body: Bytes,
-    log_namespace: LogNamespace,
-    events_received: &Registered<EventsReceived>,
-) -> Result<Vec<Event>, ErrorMessage> {
-    let request = ExportLogsServiceRequest::decode(body).map_err(|error| {
+fn build_trace_filter(
+    acknowledgements: bool,
+    out: SourceSender,
+    bytes_received: Registered<BytesReceived>,
+    events_received: Registered<EventsReceived>,
+) -> BoxedFilter<(Response,)> {
+    warp::post()
+        .and(warp::path!("v1" / "traces"))
+        .and(protobuf_body())
+        .and_then(move |encoding_header: Option<String>, body: Bytes| {
+            let events = decode(&encoding_header, body).and_then(|body| {
+                bytes_received.emit(ByteSize(body.len()));
+                decode_trace_body(body, &events_received)
+            });
+
+            handle_request::<ExportTraceServiceResponse>(
+                events,
+                acknowledgements,
+                out.clone(),
+                super::TRACES,
+            )
+        })
+        .boxed()
+}
+
+fn protobuf_body() -> impl Filter<Extract = (Option<String>, Bytes), Error = Rejection> + Clone {
+    warp::header::exact_ignore_case("content-type", "application/x-protobuf")
+        .and(warp::header::optional::<String>("content-encoding"))
+        .and(warp::body::bytes())
+}
+
+fn decode_request<T: Message + Default>(body: Bytes) -> Result<T, ErrorMessage> {
+    T::decode(body).map_err(|error| {
         ErrorMessage::new(
             StatusCode::BAD_REQUEST,
             format!("Could not decode request: {}", error),
         )
-    })?;
+    })
+}
+
+fn decode_log_body(
+    body: Bytes,
+    log_namespace: LogNamespace,
+    events_received: &Registered<EventsReceived>,
+) -> Result<Vec<Event>, ErrorMessage> {
+    let request: ExportLogsServiceRequest = decode_request(body)?;
 
     let events: Vec<Event> = request
         .resource_logs
@@ -111,7 +166,27 @@ fn decode_body(
     Ok(events)
 }
 
-async fn handle_request(
+fn decode_trace_body(
+    body: Bytes,
+    events_received: &Registered<EventsReceived>,
+) -> Result<Vec<Event>, ErrorMessage> {
+    let request: ExportTraceServiceRequest = decode_request(body)?;
+
+    let events: Vec<Event> = request
+        .resource_spans
+        .into_iter()
+        .flat_map(|v| v.into_event_iter())
+        .collect();
+
+    events_received.emit(CountByteSize(
+        events.len(),
+        events.estimated_json_encoded_size_of(),
+    ));
+
+    Ok(events)
+}
+
+async fn handle_request<R: Message + Default>(
     events: Result<Vec<Event>, ErrorMessage>,
     acknowledgements: bool,
     mut out: SourceSender,
@@ -130,11 +205,9 @@ async fn handle_request(
                 })?;
 
             match receiver {
-                None => Ok(protobuf(ExportLogsServiceResponse {}).into_response()),
+                None => Ok(protobuf(R::default()).into_response()),
                 Some(receiver) => match receiver.await {
-                    BatchStatus::Delivered => {
-                        Ok(protobuf(ExportLogsServiceResponse {}).into_response())
-                    }
+                    BatchStatus::Delivered => Ok(protobuf(R::default()).into_response()),
                     BatchStatus::Errored => Err(warp::reject::custom(Status {
                         code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
                         message: "Error delivering contents to sink".into(),
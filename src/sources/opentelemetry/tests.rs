@@ -3,10 +3,18 @@ use futures::Stream;
 use futures_util::StreamExt;
 use lookup::path;
 use opentelemetry_proto::proto::{
-    collector::logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
-    common::v1::{any_value, AnyValue, KeyValue},
+    collector::{
+        logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    },
+    common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
     resource::v1::Resource as OtelResource,
+    trace::v1::{
+        span::{Event as SpanEvent, Link},
+        status::StatusCode,
+        ResourceSpans, ScopeSpans, Span, Status,
+    },
 };
 use similar_asserts::assert_eq;
 use std::collections::BTreeMap;
@@ -15,8 +23,8 @@ use vector_core::config::LogNamespace;
 
 use crate::{
     config::{SourceConfig, SourceContext},
-    event::{into_event_stream, Event, EventStatus, LogEvent, Value},
-    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS},
+    event::{into_event_stream, Event, EventStatus, LogEvent, TraceEvent, Value},
+    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS, TRACES},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -274,6 +282,146 @@ async fn receive_grpc_logs_legacy_namespace() {
     .await;
 }
 
+#[tokio::test]
+async fn receive_grpc_traces() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let grpc_addr = next_addr();
+        let http_addr = next_addr();
+
+        let source = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+            },
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+        };
+
+        let (mut sender, _, _) = new_source(EventStatus::Delivered);
+        let traces_output = sender
+            .add_outputs(EventStatus::Delivered, TRACES.to_string())
+            .flat_map(into_event_stream);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(grpc_addr).await;
+
+        let mut client = TraceServiceClient::connect(format!("http://{}", grpc_addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(OtelResource {
+                    attributes: vec![string_attribute("service.name", "checkout")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_spans: vec![ScopeSpans {
+                    scope: Some(InstrumentationScope {
+                        name: "tracer".into(),
+                        version: "1.0.0".into(),
+                        attributes: vec![],
+                        dropped_attributes_count: 0,
+                    }),
+                    spans: vec![Span {
+                        trace_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+                        span_id: str_into_hex_bytes("0b9e4bda2a55530d"),
+                        trace_state: "vendor=value".into(),
+                        parent_span_id: str_into_hex_bytes("1b9e4bda2a55530d"),
+                        name: "GET /cart".into(),
+                        kind: 2,
+                        start_time_unix_nano: 1,
+                        end_time_unix_nano: 2,
+                        attributes: vec![string_attribute("http.method", "GET")],
+                        dropped_attributes_count: 1,
+                        events: vec![SpanEvent {
+                            time_unix_nano: 3,
+                            name: "cache miss".into(),
+                            attributes: vec![string_attribute("key", "cart:1")],
+                            dropped_attributes_count: 0,
+                        }],
+                        dropped_events_count: 2,
+                        links: vec![Link {
+                            trace_id: str_into_hex_bytes("5ac52aadf321c2e531db005df08792f5"),
+                            span_id: str_into_hex_bytes("2b9e4bda2a55530d"),
+                            trace_state: "".into(),
+                            attributes: vec![],
+                            dropped_attributes_count: 0,
+                        }],
+                        dropped_links_count: 3,
+                        status: Some(Status {
+                            message: "upstream timed out".into(),
+                            code: StatusCode::Error as i32,
+                        }),
+                    }],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        _ = client.export(req).await;
+        let mut output = test_util::collect_ready(traces_output).await;
+        // we just send one, so only one output
+        assert_eq!(output.len(), 1);
+        let actual_event = output.pop().unwrap();
+        // the ingest timestamp is set to the time the request was received
+        let ingest_timestamp = actual_event
+            .as_trace()
+            .get("ingest_timestamp")
+            .cloned()
+            .unwrap();
+        assert!(ingest_timestamp.is_timestamp());
+
+        let expect_event = Event::from(TraceEvent::from(vec_into_btmap(vec![
+            ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
+            ("span_id", "0b9e4bda2a55530d".into()),
+            ("parent_span_id", "1b9e4bda2a55530d".into()),
+            ("trace_state", "vendor=value".into()),
+            ("name", "GET /cart".into()),
+            ("kind", 2.into()),
+            ("start_time_unix_nano", Utc.timestamp_nanos(1).into()),
+            ("end_time_unix_nano", Utc.timestamp_nanos(2).into()),
+            ("attributes", vrl::value!({"http.method": "GET"})),
+            ("dropped_attributes_count", 1.into()),
+            (
+                "events",
+                vrl::value!([{
+                    name: "cache miss",
+                    time_unix_nano: (Utc.timestamp_nanos(3)),
+                    attributes: {key: "cart:1"},
+                    dropped_attributes_count: 0,
+                }]),
+            ),
+            ("dropped_events_count", 2.into()),
+            (
+                "links",
+                vrl::value!([{
+                    trace_id: "5ac52aadf321c2e531db005df08792f5",
+                    span_id: "2b9e4bda2a55530d",
+                    attributes: {},
+                    dropped_attributes_count: 0,
+                }]),
+            ),
+            ("dropped_links_count", 3.into()),
+            (
+                "status",
+                vrl::value!({code: 2, message: "upstream timed out"}),
+            ),
+            ("resources", vrl::value!({"service.name": "checkout"})),
+            ("scope", vrl::value!({name: "tracer", version: "1.0.0"})),
+            ("source_type", "opentelemetry".into()),
+            ("ingest_timestamp", ingest_timestamp),
+        ])));
+        assert_eq!(actual_event, expect_event);
+    })
+    .await;
+}
+
 pub(super) fn new_source(
     status: EventStatus,
 ) -> (
@@ -288,6 +436,15 @@ pub(super) fn new_source(
     (sender, logs_output, recv)
 }
 
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
+}
+
 fn str_into_hex_bytes(s: &str) -> Vec<u8> {
     // unwrap is okay in test
     hex::decode(s).unwrap()
@@ -0,0 +1,169 @@
+//! ICMP echo requests, sent over unprivileged "ping" sockets.
+
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+};
+
+use snafu::{ResultExt, Snafu};
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+
+const ECHO_REQUEST_V4: u8 = 8;
+const ECHO_REPLY_V4: u8 = 0;
+const ECHO_REQUEST_V6: u8 = 128;
+const ECHO_REPLY_V6: u8 = 129;
+
+/// The length of the ICMP echo header: type, code, checksum, identifier and sequence number.
+const HEADER_LEN: usize = 8;
+
+#[derive(Debug, Snafu)]
+pub(super) enum PingError {
+    #[snafu(display("Failed to resolve {:?}: {}", host, source))]
+    Resolve { host: String, source: io::Error },
+    #[snafu(display("No addresses found for {:?}", host))]
+    NoAddress { host: String },
+    #[snafu(display("Failed to open ICMP socket: {}", source))]
+    OpenSocket { source: io::Error },
+    #[snafu(display("Failed to send echo request: {}", source))]
+    Send { source: io::Error },
+    #[snafu(display("Failed to receive echo reply: {}", source))]
+    Receive { source: io::Error },
+}
+
+/// Sends an echo request to the host, and waits for the matching reply.
+pub(super) async fn ping(host: &str) -> Result<(), PingError> {
+    let ip = resolve(host).await?;
+    let socket = open_socket(ip).context(OpenSocketSnafu)?;
+
+    // Ping sockets rewrite the identifier, so replies are matched on a random payload instead.
+    let payload: [u8; 8] = rand::random();
+    let request = echo_request(ip, rand::random(), 1, &payload);
+    socket
+        .send_to(&request, SocketAddr::new(ip, 0))
+        .await
+        .context(SendSnafu)?;
+
+    let mut buffer = [0; 1500];
+    loop {
+        let (len, from) = socket.recv_from(&mut buffer).await.context(ReceiveSnafu)?;
+        if from.ip() == ip && is_echo_reply(ip, &buffer[..len], &payload) {
+            return Ok(());
+        }
+    }
+}
+
+async fn resolve(host: &str) -> Result<IpAddr, PingError> {
+    if let Ok(ip) = host.parse() {
+        return Ok(ip);
+    }
+
+    tokio::net::lookup_host((host, 0))
+        .await
+        .context(ResolveSnafu { host })?
+        .next()
+        .map(|address| address.ip())
+        .ok_or_else(|| PingError::NoAddress {
+            host: host.to_owned(),
+        })
+}
+
+fn open_socket(ip: IpAddr) -> io::Result<UdpSocket> {
+    let (domain, protocol) = match ip {
+        IpAddr::V4(_) => (Domain::IPV4, Protocol::ICMPV4),
+        IpAddr::V6(_) => (Domain::IPV6, Protocol::ICMPV6),
+    };
+    let socket = Socket::new(domain, Type::DGRAM, Some(protocol))?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
+fn echo_request(ip: IpAddr, identifier: u16, sequence: u16, payload: &[u8]) -> Vec<u8> {
+    let kind = match ip {
+        IpAddr::V4(_) => ECHO_REQUEST_V4,
+        IpAddr::V6(_) => ECHO_REQUEST_V6,
+    };
+
+    let mut packet = Vec::with_capacity(HEADER_LEN + payload.len());
+    packet.extend_from_slice(&[kind, 0, 0, 0]);
+    packet.extend_from_slice(&identifier.to_be_bytes());
+    packet.extend_from_slice(&sequence.to_be_bytes());
+    packet.extend_from_slice(payload);
+
+    // The checksum of ICMPv6 messages covers a pseudo-header, and is always filled in by the
+    // kernel.
+    if ip.is_ipv4() {
+        let checksum = checksum(&packet);
+        packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+    }
+    packet
+}
+
+fn is_echo_reply(ip: IpAddr, mut packet: &[u8], payload: &[u8]) -> bool {
+    let kind = match ip {
+        IpAddr::V4(_) => {
+            // Some platforms, such as macOS, include the IP header in what ping sockets receive.
+            if packet.first().map_or(false, |byte| byte >> 4 == 4) {
+                let header_len = usize::from(packet[0] & 0x0f) * 4;
+                packet = packet.get(header_len..).unwrap_or_default();
+            }
+            ECHO_REPLY_V4
+        }
+        IpAddr::V6(_) => ECHO_REPLY_V6,
+    };
+
+    packet.len() >= HEADER_LEN && packet[0] == kind && &packet[HEADER_LEN..] == payload
+}
+
+/// The Internet checksum, as defined by RFC 1071.
+fn checksum(data: &[u8]) -> u16 {
+    let mut sum = data
+        .chunks(2)
+        .map(|chunk| u32::from(u16::from_be_bytes([chunk[0], *chunk.get(1).unwrap_or(&0)])))
+        .sum::<u32>();
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    const V4: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    const V6: IpAddr = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+    #[test]
+    fn builds_echo_requests() {
+        let request = echo_request(V4, 0x1234, 1, b"abcdefgh");
+
+        assert_eq!(&request[..2], &[ECHO_REQUEST_V4, 0]);
+        assert_eq!(&request[4..8], &[0x12, 0x34, 0, 1]);
+        assert_eq!(&request[8..], b"abcdefgh");
+        // A packet including its checksum sums to zero.
+        assert_eq!(checksum(&request), 0);
+
+        let request = echo_request(V6, 0x1234, 1, b"abcdefgh");
+        assert_eq!(&request[..4], &[ECHO_REQUEST_V6, 0, 0, 0]);
+    }
+
+    #[test]
+    fn matches_echo_replies() {
+        let mut reply = echo_request(V4, 0x1234, 1, b"abcdefgh");
+        reply[0] = ECHO_REPLY_V4;
+        assert!(is_echo_reply(V4, &reply, b"abcdefgh"));
+        assert!(!is_echo_reply(V4, &reply, b"12345678"));
+
+        // With an IP header.
+        let mut with_header = vec![0x45];
+        with_header.extend_from_slice(&[0; 19]);
+        with_header.extend_from_slice(&reply);
+        assert!(is_echo_reply(V4, &with_header, b"abcdefgh"));
+
+        let request = echo_request(V6, 0x1234, 1, b"abcdefgh");
+        assert!(!is_echo_reply(V6, &request, b"abcdefgh"));
+    }
+}
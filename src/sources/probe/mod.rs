@@ -0,0 +1,578 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use futures::{future::join_all, StreamExt};
+use hyper::{Body, Request};
+use lookup::owned_value_path;
+use serde_with::serde_as;
+use snafu::ResultExt;
+use tokio_stream::wrappers::IntervalStream;
+use value::{kind::Collection, Kind};
+use vector_common::internal_event::{CountByteSize, EventsReceived, InternalEventHandle as _};
+use vector_config::configurable_component;
+use vector_core::{
+    config::LogNamespace,
+    event::{EstimatedJsonEncodedSizeOf, Event, LogEvent, Value},
+    metric_tags,
+    schema::Definition,
+};
+
+use crate::{
+    config::{DataType, GenerateConfig, SourceConfig, SourceContext, SourceOutput},
+    event::metric::{Metric, MetricKind, MetricTags, MetricValue},
+    http::HttpClient,
+    internal_events::StreamClosedError,
+    shutdown::ShutdownSignal,
+    tls::{TlsConfig, TlsSettings},
+    SourceSender,
+};
+
+mod icmp;
+
+/// Configuration for the `probe` source.
+#[serde_as]
+#[configurable_component(source(
+    "probe",
+    "Periodically probe hosts with ICMP pings, TCP connections or HTTP requests."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ProbeConfig {
+    /// The targets to probe.
+    targets: Vec<ProbeTarget>,
+
+    /// The interval between probes.
+    #[serde(default = "default_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    interval_secs: Duration,
+
+    /// The time to wait for a probe to complete before considering it failed.
+    #[serde(default = "default_timeout_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    timeout_secs: Duration,
+
+    /// The namespace of the metrics.
+    ///
+    /// Disabled if empty.
+    #[serde(default = "default_namespace")]
+    namespace: String,
+
+    #[configurable(derived)]
+    tls: Option<TlsConfig>,
+
+    /// The namespace to use for logs. This overrides the global setting.
+    #[configurable(metadata(docs::hidden))]
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+/// A target to probe.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The type of probe."))]
+pub enum ProbeTarget {
+    /// Send an ICMP echo request to the host, and wait for the reply.
+    ///
+    /// The echo request is sent from an unprivileged ICMP socket. On Linux, the group Vector runs
+    /// as must be allowed to open these with the `net.ipv4.ping_group_range` sysctl.
+    Icmp {
+        /// The hostname or IP address of the host.
+        #[configurable(metadata(docs::examples = "example.com"))]
+        #[configurable(metadata(docs::examples = "10.0.0.1"))]
+        host: String,
+    },
+
+    /// Open a TCP connection to the address.
+    Tcp {
+        /// The address to connect to, as `<host>:<port>`.
+        #[configurable(metadata(docs::examples = "example.com:443"))]
+        address: String,
+    },
+
+    /// Send a `GET` request to the URL.
+    Http {
+        /// The URL to request.
+        #[configurable(metadata(docs::examples = "https://example.com/health"))]
+        url: String,
+
+        /// The status codes of successful responses.
+        ///
+        /// If empty, any `2xx` status code is successful.
+        #[serde(default)]
+        #[configurable(metadata(docs::examples = 200))]
+        expected_status_codes: Vec<u16>,
+    },
+}
+
+pub const fn default_interval_secs() -> Duration {
+    Duration::from_secs(15)
+}
+
+pub const fn default_timeout_secs() -> Duration {
+    Duration::from_secs(5)
+}
+
+pub fn default_namespace() -> String {
+    "probe".to_string()
+}
+
+impl GenerateConfig for ProbeConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            targets: vec![ProbeTarget::Http {
+                url: "http://localhost:8080/health".to_owned(),
+                expected_status_codes: Vec::new(),
+            }],
+            interval_secs: default_interval_secs(),
+            timeout_secs: default_timeout_secs(),
+            namespace: default_namespace(),
+            tls: None,
+            log_namespace: None,
+        })
+        .unwrap()
+    }
+}
+
+impl ProbeConfig {
+    /// Generates the `schema::Definition` for the logs of failed probes.
+    fn schema_definition(&self, log_namespace: LogNamespace) -> Definition {
+        Definition::new_with_default_metadata(Kind::object(Collection::empty()), [log_namespace])
+            .with_event_field(&owned_value_path!("message"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("probe"), Kind::bytes(), None)
+            .with_event_field(&owned_value_path!("target"), Kind::bytes(), None)
+            .with_event_field(
+                &owned_value_path!("status_code"),
+                Kind::integer().or_undefined(),
+                None,
+            )
+            .with_standard_vector_source_metadata()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "probe")]
+impl SourceConfig for ProbeConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let targets = self
+            .targets
+            .iter()
+            .map(Target::new)
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let client = if targets
+            .iter()
+            .any(|target| matches!(target, Target::Http { .. }))
+        {
+            let tls = TlsSettings::from_options(&self.tls)?;
+            Some(HttpClient::new(tls, &cx.proxy)?)
+        } else {
+            None
+        };
+
+        let prober = Prober {
+            targets,
+            client,
+            timeout: self.timeout_secs,
+            namespace: Some(self.namespace.clone()).filter(|namespace| !namespace.is_empty()),
+            log_namespace: cx.log_namespace(self.log_namespace),
+        };
+
+        Ok(Box::pin(prober.run(
+            self.interval_secs,
+            cx.out,
+            cx.shutdown,
+        )))
+    }
+
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let schema_definition =
+            self.schema_definition(global_log_namespace.merge(self.log_namespace));
+
+        vec![SourceOutput::new_logs(
+            DataType::Log | DataType::Metric,
+            schema_definition,
+        )]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+/// A validated probe target.
+#[derive(Debug)]
+enum Target {
+    Icmp {
+        host: String,
+    },
+    Tcp {
+        address: String,
+    },
+    Http {
+        url: http::Uri,
+        expected_status_codes: Vec<u16>,
+    },
+}
+
+impl Target {
+    fn new(target: &ProbeTarget) -> crate::Result<Self> {
+        Ok(match target {
+            ProbeTarget::Icmp { host } => Self::Icmp { host: host.clone() },
+            ProbeTarget::Tcp { address } => Self::Tcp {
+                address: address.clone(),
+            },
+            ProbeTarget::Http {
+                url,
+                expected_status_codes,
+            } => Self::Http {
+                url: url.parse().context(super::UriParseSnafu)?,
+                expected_status_codes: expected_status_codes.clone(),
+            },
+        })
+    }
+
+    const fn probe_type(&self) -> &'static str {
+        match self {
+            Self::Icmp { .. } => "icmp",
+            Self::Tcp { .. } => "tcp",
+            Self::Http { .. } => "http",
+        }
+    }
+
+    /// The target, as reported in the events. URLs are stripped of any credentials.
+    fn name(&self) -> String {
+        match self {
+            Self::Icmp { host } => host.clone(),
+            Self::Tcp { address } => address.clone(),
+            Self::Http { url, .. } => {
+                let mut parts = url.clone().into_parts();
+                parts.authority = url.host().and_then(|host| {
+                    let authority = match url.port() {
+                        Some(port) => format!("{}:{}", host, port),
+                        None => host.to_owned(),
+                    };
+                    authority.parse().ok()
+                });
+                http::Uri::from_parts(parts).map_or_else(|_| url.to_string(), |url| url.to_string())
+            }
+        }
+    }
+}
+
+/// The outcome of a single probe.
+#[derive(Debug, Default)]
+struct ProbeResult {
+    /// The time to get a reply, connect, or receive the response headers.
+    duration: Option<Duration>,
+    status_code: Option<u16>,
+    error: Option<String>,
+}
+
+struct Prober {
+    targets: Vec<Target>,
+    client: Option<HttpClient>,
+    timeout: Duration,
+    namespace: Option<String>,
+    log_namespace: LogNamespace,
+}
+
+impl Prober {
+    async fn run(
+        self,
+        interval: Duration,
+        mut out: SourceSender,
+        shutdown: ShutdownSignal,
+    ) -> Result<(), ()> {
+        let events_received = register!(EventsReceived);
+        let mut ticks = IntervalStream::new(tokio::time::interval(interval)).take_until(shutdown);
+
+        while ticks.next().await.is_some() {
+            let events = self.probe_all().await;
+            let count = events.len();
+            events_received.emit(CountByteSize(
+                count,
+                events.estimated_json_encoded_size_of(),
+            ));
+
+            if let Err(error) = out.send_batch(events).await {
+                emit!(StreamClosedError { error, count });
+                return Err(());
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn probe_all(&self) -> Vec<Event> {
+        let results = join_all(self.targets.iter().map(|target| async move {
+            let result = match tokio::time::timeout(self.timeout, self.probe(target)).await {
+                Ok(result) => result,
+                Err(_) => ProbeResult {
+                    error: Some(format!(
+                        "Probe timed out after {} seconds.",
+                        self.timeout.as_secs_f64()
+                    )),
+                    ..Default::default()
+                },
+            };
+            (target, result)
+        }))
+        .await;
+
+        results
+            .into_iter()
+            .flat_map(|(target, result)| self.to_events(target, result))
+            .collect()
+    }
+
+    async fn probe(&self, target: &Target) -> ProbeResult {
+        let start = Instant::now();
+        match target {
+            Target::Icmp { host } => match icmp::ping(host).await {
+                Ok(()) => ProbeResult {
+                    duration: Some(start.elapsed()),
+                    ..Default::default()
+                },
+                Err(error) => ProbeResult {
+                    error: Some(error.to_string()),
+                    ..Default::default()
+                },
+            },
+            Target::Tcp { address } => match tokio::net::TcpStream::connect(address).await {
+                Ok(_) => ProbeResult {
+                    duration: Some(start.elapsed()),
+                    ..Default::default()
+                },
+                Err(error) => ProbeResult {
+                    error: Some(format!("Failed to connect: {}", error)),
+                    ..Default::default()
+                },
+            },
+            Target::Http {
+                url,
+                expected_status_codes,
+            } => {
+                let client = self
+                    .client
+                    .as_ref()
+                    .expect("client is built for HTTP targets");
+                let request = Request::get(url)
+                    .body(Body::empty())
+                    .expect("error creating request");
+
+                match client.send(request).await {
+                    Ok(response) => {
+                        let status = response.status();
+                        let expected = if expected_status_codes.is_empty() {
+                            status.is_success()
+                        } else {
+                            expected_status_codes.contains(&status.as_u16())
+                        };
+                        ProbeResult {
+                            duration: Some(start.elapsed()),
+                            status_code: Some(status.as_u16()),
+                            error: (!expected)
+                                .then(|| format!("Unexpected status code: {}", status)),
+                        }
+                    }
+                    Err(error) => ProbeResult {
+                        error: Some(format!("HTTP request failed: {}", error)),
+                        ..Default::default()
+                    },
+                }
+            }
+        }
+    }
+
+    fn to_events(&self, target: &Target, result: ProbeResult) -> Vec<Event> {
+        let now = Utc::now();
+        let probe = target.probe_type();
+        let name = target.name();
+        let tags = metric_tags! {
+            "probe" => probe,
+            "target" => name.clone(),
+        };
+
+        let mut events = vec![self.gauge(
+            "success",
+            if result.error.is_none() { 1.0 } else { 0.0 },
+            &tags,
+        )];
+        if let Some(duration) = result.duration {
+            events.push(self.gauge("duration_seconds", duration.as_secs_f64(), &tags));
+        }
+        if let Some(status_code) = result.status_code {
+            events.push(self.gauge("http_status_code", f64::from(status_code), &tags));
+        }
+
+        if let Some(error) = result.error {
+            let mut fields = BTreeMap::new();
+            fields.insert("message".to_owned(), Value::from(error));
+            fields.insert("probe".to_owned(), Value::from(probe));
+            fields.insert("target".to_owned(), Value::from(name));
+            if let Some(status_code) = result.status_code {
+                fields.insert(
+                    "status_code".to_owned(),
+                    Value::from(i64::from(status_code)),
+                );
+            }
+
+            let mut log = LogEvent::from(fields);
+            self.log_namespace.insert_standard_vector_source_metadata(
+                &mut log,
+                ProbeConfig::NAME,
+                now,
+            );
+            events.push(log.into());
+        }
+
+        events
+    }
+
+    fn gauge(&self, name: &str, value: f64, tags: &MetricTags) -> Event {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value })
+            .with_namespace(self.namespace.clone())
+            .with_tags(Some(tags.clone()))
+            .with_timestamp(Some(Utc::now()))
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Response, Server, StatusCode,
+    };
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::test_util::next_addr;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ProbeConfig>();
+    }
+
+    fn prober(targets: Vec<ProbeTarget>) -> Prober {
+        Prober {
+            targets: targets
+                .iter()
+                .map(|target| Target::new(target).unwrap())
+                .collect(),
+            client: Some(HttpClient::new(None, &Default::default()).unwrap()),
+            timeout: Duration::from_secs(5),
+            namespace: Some(default_namespace()),
+            log_namespace: LogNamespace::Legacy,
+        }
+    }
+
+    fn gauge_value(events: &[Event], name: &str) -> Option<f64> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Metric(metric) => Some(metric),
+                _ => None,
+            })
+            .find(|metric| metric.name() == name)
+            .map(|metric| match metric.value() {
+                MetricValue::Gauge { value } => *value,
+                value => panic!("unexpected metric value {:?}", value),
+            })
+    }
+
+    #[tokio::test]
+    async fn probes_tcp_targets() {
+        let listener = TcpListener::bind(next_addr()).await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+
+        let events = prober(vec![ProbeTarget::Tcp { address }]).probe_all().await;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(gauge_value(&events, "success"), Some(1.0));
+        assert!(gauge_value(&events, "duration_seconds").is_some());
+
+        let metric = events[0].as_metric();
+        assert_eq!(metric.namespace(), Some("probe"));
+        assert_eq!(metric.tag_value("probe"), Some("tcp".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn reports_failed_tcp_probes() {
+        let address = next_addr().to_string();
+
+        let events = prober(vec![ProbeTarget::Tcp {
+            address: address.clone(),
+        }])
+        .probe_all()
+        .await;
+
+        assert_eq!(gauge_value(&events, "success"), Some(0.0));
+        assert_eq!(gauge_value(&events, "duration_seconds"), None);
+
+        let log = events
+            .iter()
+            .find_map(Event::maybe_as_log)
+            .expect("failed probes emit a log");
+        assert_eq!(log["probe"], "tcp".into());
+        assert_eq!(log["target"], address.into());
+        assert_eq!(log["source_type"], "probe".into());
+        assert!(log["message"]
+            .to_string_lossy()
+            .starts_with("Failed to connect"));
+    }
+
+    #[tokio::test]
+    async fn probes_http_targets() {
+        let address = next_addr();
+        let make_svc = make_service_fn(|_| async {
+            Ok::<_, crate::Error>(service_fn(|request: Request<Body>| async move {
+                let status = if request.uri().path() == "/up" {
+                    StatusCode::OK
+                } else {
+                    StatusCode::SERVICE_UNAVAILABLE
+                };
+                Ok::<_, crate::Error>(
+                    Response::builder()
+                        .status(status)
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+            }))
+        });
+        tokio::spawn(Server::bind(&address).serve(make_svc));
+
+        let up = prober(vec![ProbeTarget::Http {
+            url: format!("http://user:password@{}/up", address),
+            expected_status_codes: Vec::new(),
+        }])
+        .probe_all()
+        .await;
+
+        assert_eq!(gauge_value(&up, "success"), Some(1.0));
+        assert_eq!(gauge_value(&up, "http_status_code"), Some(200.0));
+        assert_eq!(
+            up[0].as_metric().tag_value("target"),
+            Some(format!("http://{}/up", address))
+        );
+
+        let down = prober(vec![ProbeTarget::Http {
+            url: format!("http://{}/down", address),
+            expected_status_codes: vec![200, 204],
+        }])
+        .probe_all()
+        .await;
+
+        assert_eq!(gauge_value(&down, "success"), Some(0.0));
+        assert_eq!(gauge_value(&down, "http_status_code"), Some(503.0));
+        let log = down
+            .iter()
+            .find_map(Event::maybe_as_log)
+            .expect("failed probes emit a log");
+        assert_eq!(log["status_code"], 503.into());
+    }
+}
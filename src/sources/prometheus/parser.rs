@@ -1,11 +1,13 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 
 use chrono::{DateTime, TimeZone, Utc};
-use prometheus_parser::{proto, GroupKind, MetricGroup, ParserError};
+use lookup::path;
+use ordered_float::NotNan;
+use prometheus_parser::{proto, Exemplar, GroupKind, MetricGroup, ParserError};
 
 use crate::event::{
     metric::{Bucket, Metric, MetricKind, MetricTags, MetricValue, Quantile},
-    Event,
+    Event, Value,
 };
 
 fn utc_timestamp(timestamp: Option<i64>, default: DateTime<Utc>) -> DateTime<Utc> {
@@ -30,6 +32,7 @@ fn reparse_groups(groups: Vec<MetricGroup>) -> Vec<Event> {
     let start = Utc::now();
 
     for group in groups {
+        let first = result.len();
         match group.metrics {
             GroupKind::Counter(metrics) => {
                 for (key, metric) in metrics {
@@ -123,11 +126,76 @@ fn reparse_groups(groups: Vec<MetricGroup>) -> Vec<Event> {
                 }
             }
         }
+
+        annotate(
+            &mut result[first..],
+            group.help,
+            group.unit,
+            &group.exemplars,
+            start,
+        );
     }
 
     result
 }
 
+/// Stores the description and the exemplars of a metric group in the metadata of its metrics, for
+/// the `prometheus_remote_write` sink to forward them.
+///
+/// Each exemplar is attached to the metrics of its series, and dropped if there are none.
+fn annotate(
+    events: &mut [Event],
+    help: Option<String>,
+    unit: Option<String>,
+    exemplars: &[Exemplar],
+    start: DateTime<Utc>,
+) {
+    let exemplars = exemplars
+        .iter()
+        .filter_map(|exemplar| {
+            let tags = MetricTags::from(exemplar.series_labels.clone());
+            Some((tags, exemplar_value(exemplar, start)?))
+        })
+        .collect::<Vec<_>>();
+
+    for event in events {
+        let metric = event.as_mut_metric();
+        let tags = metric.tags().cloned().unwrap_or_default();
+        let metadata = metric.metadata_mut().value_mut();
+        if let Some(help) = &help {
+            metadata.insert(path!("prometheus", "help"), help.clone());
+        }
+        if let Some(unit) = &unit {
+            metadata.insert(path!("prometheus", "unit"), unit.clone());
+        }
+
+        let matching = exemplars
+            .iter()
+            .filter(|(series_tags, _)| *series_tags == tags)
+            .map(|(_, exemplar)| exemplar.clone())
+            .collect::<Vec<_>>();
+        if !matching.is_empty() {
+            metadata.insert(path!("prometheus", "exemplars"), matching);
+        }
+    }
+}
+
+fn exemplar_value(exemplar: &Exemplar, start: DateTime<Utc>) -> Option<Value> {
+    let labels = exemplar
+        .labels
+        .iter()
+        .map(|(name, value)| (name.clone(), Value::from(value.as_str())))
+        .collect::<BTreeMap<_, _>>();
+    let value = NotNan::new(exemplar.value).ok()?;
+    let timestamp = utc_timestamp(exemplar.timestamp, start);
+
+    Some(Value::from(BTreeMap::from([
+        ("labels".to_owned(), Value::from(labels)),
+        ("value".to_owned(), Value::from(value)),
+        ("timestamp".to_owned(), Value::from(timestamp)),
+    ])))
+}
+
 #[cfg(test)]
 mod test {
     use chrono::{TimeZone, Timelike, Utc};
@@ -1015,4 +1083,67 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn annotates_remote_write_metrics() {
+        let label = |name: &str, value: &str| proto::Label {
+            name: name.into(),
+            value: value.into(),
+        };
+        let request = proto::WriteRequest {
+            timeseries: vec![
+                proto::TimeSeries {
+                    labels: vec![label("__name__", "one_bucket"), label("le", "1")],
+                    samples: vec![proto::Sample {
+                        value: 2.0,
+                        timestamp: 1612411506789,
+                    }],
+                    exemplars: vec![proto::Exemplar {
+                        labels: vec![label("trace_id", "abc")],
+                        value: 0.5,
+                        timestamp: 1612411506789,
+                    }],
+                    histograms: vec![],
+                },
+                proto::TimeSeries {
+                    labels: vec![label("__name__", "one_count")],
+                    samples: vec![proto::Sample {
+                        value: 2.0,
+                        timestamp: 1612411506789,
+                    }],
+                    exemplars: vec![],
+                    histograms: vec![],
+                },
+            ],
+            metadata: vec![proto::MetricMetadata {
+                r#type: proto::MetricType::Histogram as i32,
+                metric_family_name: "one".into(),
+                help: "The one metric.".into(),
+                unit: String::new(),
+            }],
+        };
+
+        let events = super::parse_request(request).unwrap();
+        assert_eq!(events.len(), 1);
+        let metadata = events[0].metadata().value();
+        assert_eq!(
+            metadata.get(path!("prometheus", "help")),
+            Some(&Value::from("The one metric."))
+        );
+        assert_eq!(metadata.get(path!("prometheus", "unit")), None);
+        assert_eq!(
+            metadata.get(path!("prometheus", "exemplars")),
+            Some(&Value::from(vec![Value::from(BTreeMap::from([
+                (
+                    "labels".to_owned(),
+                    Value::from(BTreeMap::from([(
+                        "trace_id".to_owned(),
+                        Value::from("abc")
+                    )]))
+                ),
+                ("value".to_owned(), Value::from(NotNan::new(0.5).unwrap())),
+                ("timestamp".to_owned(), Value::from(*TIMESTAMP)),
+            ]))]))
+        );
+    }
 }
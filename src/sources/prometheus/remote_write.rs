@@ -5,7 +5,7 @@ use prometheus_parser::proto;
 use prost::Message;
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
-use warp::http::{HeaderMap, StatusCode};
+use warp::http::{header::CONTENT_TYPE, HeaderMap, StatusCode};
 
 use super::parser;
 use crate::{
@@ -97,12 +97,56 @@ impl SourceConfig for PrometheusRemoteWriteConfig {
     }
 }
 
+/// The versions of the remote-write protocol, told apart by the `proto` parameter of the content
+/// type of requests.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ProtocolVersion {
+    V1,
+    V2,
+}
+
+impl ProtocolVersion {
+    fn from_headers(header_map: &HeaderMap) -> Result<Self, ErrorMessage> {
+        let proto = header_map
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .and_then(|content_type| {
+                content_type
+                    .split(';')
+                    .skip(1)
+                    .filter_map(|parameter| parameter.split_once('='))
+                    .find(|(name, _)| name.trim().eq_ignore_ascii_case("proto"))
+                    .map(|(_, value)| value.trim().trim_matches('"'))
+            });
+
+        match proto {
+            None | Some("prometheus.WriteRequest") => Ok(Self::V1),
+            Some("io.prometheus.write.v2.Request") => Ok(Self::V2),
+            // Senders fall back to the first version of the protocol on this status.
+            Some(proto) => Err(ErrorMessage::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!("Unsupported write request message: {}", proto),
+            )),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct RemoteWriteSource;
 
 impl RemoteWriteSource {
-    fn decode_body(&self, body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
-        let request = proto::WriteRequest::decode(body).map_err(|error| {
+    fn decode_body(
+        &self,
+        body: Bytes,
+        version: ProtocolVersion,
+    ) -> Result<Vec<Event>, ErrorMessage> {
+        let request = match version {
+            ProtocolVersion::V1 => proto::WriteRequest::decode(body).map(Ok),
+            ProtocolVersion::V2 => {
+                proto::v2::Request::decode(body).map(proto::WriteRequest::try_from)
+            }
+        };
+        let request = request.map_err(|error| {
             emit!(PrometheusRemoteWriteParseError {
                 error: error.clone()
             });
@@ -111,7 +155,7 @@ impl RemoteWriteSource {
                 format!("Could not decode write request: {}", error),
             )
         })?;
-        parser::parse_request(request).map_err(|error| {
+        request.and_then(parser::parse_request).map_err(|error| {
             ErrorMessage::new(
                 StatusCode::BAD_REQUEST,
                 format!("Could not decode write request: {}", error),
@@ -128,6 +172,7 @@ impl HttpSource for RemoteWriteSource {
         _query_parameters: &HashMap<String, String>,
         _full_path: &str,
     ) -> Result<Vec<Event>, ErrorMessage> {
+        let version = ProtocolVersion::from_headers(header_map)?;
         // If `Content-Encoding` header isn't `snappy` HttpSource won't decode it for us
         // se we need to.
         if header_map
@@ -137,7 +182,7 @@ impl HttpSource for RemoteWriteSource {
         {
             body = decode(&Some("snappy".to_string()), body)?;
         }
-        let events = self.decode_body(body)?;
+        let events = self.decode_body(body, version)?;
         Ok(events)
     }
 }
@@ -341,6 +386,39 @@ mod test {
         })
         .await;
     }
+
+    #[test]
+    fn negotiates_protocol_version() {
+        let version = |content_type: Option<&str>| {
+            let mut header_map = HeaderMap::new();
+            if let Some(content_type) = content_type {
+                header_map.insert(CONTENT_TYPE, content_type.parse().unwrap());
+            }
+            ProtocolVersion::from_headers(&header_map).map_err(|error| error.code())
+        };
+
+        assert_eq!(version(None), Ok(ProtocolVersion::V1));
+        assert_eq!(
+            version(Some("application/x-protobuf")),
+            Ok(ProtocolVersion::V1)
+        );
+        assert_eq!(
+            version(Some("application/x-protobuf;proto=prometheus.WriteRequest")),
+            Ok(ProtocolVersion::V1)
+        );
+        assert_eq!(
+            version(Some(
+                "application/x-protobuf; proto=io.prometheus.write.v2.Request"
+            )),
+            Ok(ProtocolVersion::V2)
+        );
+        assert_eq!(
+            version(Some(
+                "application/x-protobuf;proto=io.prometheus.write.v3.Request"
+            )),
+            Err(415)
+        );
+    }
 }
 
 #[cfg(all(test, feature = "prometheus-integration-tests"))]
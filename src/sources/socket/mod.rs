@@ -14,7 +14,7 @@ use crate::serde::default_framing_message_based;
 use crate::{
     codecs::DecodingConfig,
     config::{GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput},
-    sources::util::net::TcpSource,
+    sources::util::{net::TcpSource, SchemaDeclaration},
     tls::MaybeTlsSettings,
 };
 
@@ -69,6 +69,17 @@ impl SocketConfig {
         }
     }
 
+    fn schema(&self) -> &SchemaDeclaration {
+        match &self.mode {
+            Mode::Tcp(config) => config.schema(),
+            Mode::Udp(config) => config.schema(),
+            #[cfg(unix)]
+            Mode::UnixDatagram(config) => config.schema(),
+            #[cfg(unix)]
+            Mode::UnixStream(config) => config.schema(),
+        }
+    }
+
     fn log_namespace(&self) -> LogNamespace {
         match &self.mode {
             Mode::Tcp(config) => config.log_namespace.unwrap_or(false).into(),
@@ -124,7 +135,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
-                .build();
+                .build()?;
 
                 let tcp = tcp::RawTcpSource::new(config.clone(), decoder, log_namespace);
                 let tls_config = config.tls().as_ref().map(|tls| tls.tls_config.clone());
@@ -156,7 +167,7 @@ impl SourceConfig for SocketConfig {
                     config.decoding().clone(),
                     log_namespace,
                 )
-                .build();
+                .build()?;
                 Ok(udp::udp(
                     config,
                     decoder,
@@ -176,7 +187,7 @@ impl SourceConfig for SocketConfig {
                     config.decoding.clone(),
                     log_namespace,
                 )
-                .build();
+                .build()?;
 
                 unix::unix_datagram(config, decoder, cx.shutdown, cx.out, log_namespace)
             }
@@ -193,7 +204,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
-                .build();
+                .build()?;
 
                 unix::unix_stream(config, decoder, cx.shutdown, cx.out, log_namespace)
             }
@@ -291,6 +302,7 @@ impl SourceConfig for SocketConfig {
                 )
             }
         };
+        let schema_definition = self.schema().apply(schema_definition);
 
         vec![SourceOutput::new_logs(
             self.decoding().output_type(),
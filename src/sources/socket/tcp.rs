@@ -12,7 +12,10 @@ use crate::{
     codecs::Decoder,
     event::Event,
     serde::default_decoding,
-    sources::util::net::{SocketListenAddr, TcpNullAcker, TcpSource},
+    sources::util::{
+        net::{SocketListenAddr, TcpNullAcker, TcpSource},
+        SchemaDeclaration,
+    },
     tcp::TcpKeepaliveConfig,
     tls::TlsSourceConfig,
 };
@@ -81,6 +84,10 @@ pub struct TcpConfig {
     #[serde(default = "default_decoding")]
     pub(super) decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub(super) schema: SchemaDeclaration,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -108,6 +115,7 @@ impl TcpConfig {
             max_connection_duration_secs: None,
             framing: None,
             decoding: default_decoding(),
+            schema: SchemaDeclaration::default(),
             connection_limit: None,
             log_namespace: None,
         }
@@ -133,6 +141,10 @@ impl TcpConfig {
         &self.decoding
     }
 
+    pub const fn schema(&self) -> &SchemaDeclaration {
+        &self.schema
+    }
+
     pub const fn address(&self) -> SocketListenAddr {
         self.address
     }
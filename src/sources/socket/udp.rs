@@ -26,7 +26,10 @@ use crate::{
     shutdown::ShutdownSignal,
     sources::{
         socket::SocketConfig,
-        util::net::{try_bind_udp_socket, SocketListenAddr},
+        util::{
+            net::{try_bind_udp_socket, SocketListenAddr},
+            SchemaDeclaration,
+        },
         Source,
     },
     udp, SourceSender,
@@ -81,6 +84,10 @@ pub struct UdpConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    schema: SchemaDeclaration,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -116,6 +123,10 @@ impl UdpConfig {
         &self.decoding
     }
 
+    pub(super) const fn schema(&self) -> &SchemaDeclaration {
+        &self.schema
+    }
+
     pub(super) const fn address(&self) -> SocketListenAddr {
         self.address
     }
@@ -129,6 +140,7 @@ impl UdpConfig {
             receive_buffer_bytes: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            schema: SchemaDeclaration::default(),
             log_namespace: None,
         }
     }
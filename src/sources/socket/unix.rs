@@ -13,7 +13,7 @@ use crate::{
     event::Event,
     serde::default_decoding,
     sources::{
-        util::{build_unix_datagram_source, build_unix_stream_source},
+        util::{build_unix_datagram_source, build_unix_stream_source, SchemaDeclaration},
         Source,
     },
     SourceSender,
@@ -61,6 +61,10 @@ pub struct UnixConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub schema: SchemaDeclaration,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -75,6 +79,7 @@ impl UnixConfig {
             host_key: default_host_key(),
             framing: None,
             decoding: default_decoding(),
+            schema: SchemaDeclaration::default(),
             log_namespace: None,
         }
     }
@@ -83,6 +88,10 @@ impl UnixConfig {
         &self.decoding
     }
 
+    pub const fn schema(&self) -> &SchemaDeclaration {
+        &self.schema
+    }
+
     pub const fn host_key(&self) -> &OptionalValuePath {
         &self.host_key
     }
@@ -21,6 +21,12 @@ mod message_decoding;
 pub mod multiline_config;
 #[cfg(any(feature = "sources-utils-net-tcp", feature = "sources-utils-net-udp"))]
 pub mod net;
+#[cfg(any(
+    feature = "sources-http_server",
+    feature = "sources-kafka",
+    feature = "sources-socket"
+))]
+mod schema_declaration;
 #[cfg(all(
     unix,
     any(feature = "sources-socket", feature = "sources-utils-net-unix",)
@@ -35,6 +41,12 @@ mod wrappers;
 #[cfg(feature = "sources-file")]
 pub use encoding_config::EncodingConfig;
 pub use multiline_config::MultilineConfig;
+#[cfg(any(
+    feature = "sources-http_server",
+    feature = "sources-kafka",
+    feature = "sources-socket"
+))]
+pub use schema_declaration::{DeclaredKind, FieldDeclaration, SchemaDeclaration};
 #[cfg(all(
     unix,
     any(feature = "sources-socket", feature = "sources-utils-net-unix",)
@@ -102,6 +102,15 @@ where
 
     fn handle_events(&self, _events: &mut [Event], _host: std::net::SocketAddr) {}
 
+    /// Performs the handshake of the protocol, if it has one, before events are read from the
+    /// connection. The connection is closed if it fails.
+    fn handshake<'a>(
+        &'a self,
+        _socket: &'a mut MaybeTlsIncomingStream<TcpStream>,
+    ) -> BoxFuture<'a, Result<(), ()>> {
+        Box::pin(futures::future::ok(()))
+    }
+
     fn build_acker(&self, item: &[Self::Item]) -> Self::Acker;
 
     #[allow(clippy::too_many_arguments)]
@@ -273,6 +282,17 @@ async fn handle_stream<T>(
         }
     }
 
+    tokio::select! {
+        result = source.handshake(&mut socket) => {
+            if result.is_err() {
+                return;
+            }
+        },
+        _ = &mut shutdown_signal => {
+            return;
+        }
+    };
+
     let socket = socket.after_read(move |byte_size| {
         emit!(TcpBytesReceived {
             byte_size,
@@ -0,0 +1,186 @@
+use lookup::lookup_v2::ConfigValuePath;
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+
+use crate::schema;
+
+/// The schema of the events of a source, declared by the user.
+///
+/// Sources receiving untyped data, such as raw bytes or arbitrary JSON, can't tell what fields
+/// their events have. Declaring them lets the components downstream, such as the `remap`
+/// transform, type check their use of those fields.
+///
+/// The declaration isn't checked against the events, so events that don't match it can fail at
+/// runtime in ways that were deemed impossible when type checking.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaDeclaration {
+    /// The declared fields of the events.
+    ///
+    /// Declaring a field asserts that the events are objects, and overrides the type of the field
+    /// that the source would otherwise infer.
+    #[serde(default)]
+    pub fields: Vec<FieldDeclaration>,
+}
+
+/// The declaration of a field.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FieldDeclaration {
+    /// The path of the field.
+    #[configurable(metadata(docs::examples = "status"))]
+    #[configurable(metadata(docs::examples = "request.duration"))]
+    pub path: ConfigValuePath,
+
+    #[configurable(derived)]
+    pub kind: DeclaredKind,
+
+    /// Whether or not all events have the field.
+    #[serde(default)]
+    pub required: bool,
+
+    /// The semantic meaning of the field, such as `timestamp`, `host` or `message`.
+    #[configurable(metadata(docs::examples = "timestamp"))]
+    pub meaning: Option<String>,
+}
+
+/// The type of a declared field.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclaredKind {
+    /// A string.
+    Bytes,
+
+    /// An integer.
+    Integer,
+
+    /// A floating point number.
+    Float,
+
+    /// A boolean.
+    Boolean,
+
+    /// A timestamp.
+    Timestamp,
+
+    /// An object, with any fields.
+    Object,
+
+    /// An array, of any values.
+    Array,
+
+    /// Any type.
+    Any,
+}
+
+impl From<DeclaredKind> for Kind {
+    fn from(kind: DeclaredKind) -> Self {
+        match kind {
+            DeclaredKind::Bytes => Kind::bytes(),
+            DeclaredKind::Integer => Kind::integer(),
+            DeclaredKind::Float => Kind::float(),
+            DeclaredKind::Boolean => Kind::boolean(),
+            DeclaredKind::Timestamp => Kind::timestamp(),
+            DeclaredKind::Object => Kind::object(Collection::any()),
+            DeclaredKind::Array => Kind::array(Collection::any()),
+            DeclaredKind::Any => Kind::any(),
+        }
+    }
+}
+
+impl SchemaDeclaration {
+    /// Applies the declaration to the schema definition of the events of a source.
+    pub fn apply(&self, mut definition: schema::Definition) -> schema::Definition {
+        if self.fields.is_empty() {
+            return definition;
+        }
+
+        if definition.event_kind().as_object().is_none() {
+            // Like the types of the fields, the declaration overrides what the source infers, such
+            // as the events being raw bytes when using the `Vector` namespace.
+            *definition.event_kind_mut() = Kind::object(Collection::any());
+        }
+
+        self.fields.iter().fold(definition, |definition, field| {
+            let kind = Kind::from(field.kind);
+            let kind = if field.required {
+                kind
+            } else {
+                kind.or_undefined()
+            };
+            definition.with_event_field(&field.path.0, kind, field.meaning.as_deref())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lookup::owned_value_path;
+    use vector_core::config::LogNamespace;
+
+    use super::*;
+
+    fn declaration() -> SchemaDeclaration {
+        toml::from_str(
+            r#"
+            fields = [
+                { path = "status", kind = "integer", required = true },
+                { path = "request.duration", kind = "float" },
+                { path = "ts", kind = "timestamp", required = true, meaning = "timestamp" },
+            ]
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn declares_fields() {
+        let definition = declaration().apply(schema::Definition::default_legacy_namespace());
+
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("status")),
+            Kind::integer()
+        );
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("request", "duration")),
+            Kind::float().or_undefined()
+        );
+        assert_eq!(
+            definition.meaning_path("timestamp"),
+            Some(&lookup::OwnedTargetPath::event(owned_value_path!("ts")))
+        );
+    }
+
+    #[test]
+    fn declares_fields_of_raw_events() {
+        let definition = declaration().apply(schema::Definition::new_with_default_metadata(
+            Kind::bytes(),
+            [LogNamespace::Vector],
+        ));
+
+        assert!(definition.event_kind().as_object().is_some());
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("status")),
+            Kind::integer()
+        );
+    }
+
+    #[test]
+    fn empty_declaration_is_noop() {
+        let definition =
+            schema::Definition::new_with_default_metadata(Kind::bytes(), [LogNamespace::Vector]);
+        assert_eq!(
+            SchemaDeclaration::default().apply(definition.clone()),
+            definition
+        );
+    }
+}
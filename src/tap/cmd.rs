@@ -6,10 +6,11 @@ use url::Url;
 use vector_api_client::{
     connect_subscription_client,
     gql::{
+        event_traces_subscription::{EventTraceStage, EventTracesSubscriptionEventTracesEvent},
         output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns,
         TapEncodingFormat, TapSubscriptionExt,
     },
-    Client,
+    Client, SubscriptionClient,
 };
 
 use crate::{
@@ -99,10 +100,15 @@ async fn run(
         }
     };
 
+    if let Some(condition) = &opts.trace {
+        return trace(subscription_client, opts, condition.clone(), formatter).await;
+    }
+
     tokio::pin! {
         let stream = subscription_client.output_events_by_component_id_patterns_subscription(
             outputs_patterns,
             opts.inputs_of.clone(),
+            opts.filters.clone(),
             opts.format,
             opts.limit as i64,
             opts.interval as i64,
@@ -141,6 +147,74 @@ async fn run(
     }
 }
 
+/// Prints the spans of the events followed through the topology, as each component reaches them.
+async fn trace(
+    subscription_client: SubscriptionClient,
+    opts: &super::Opts,
+    condition: String,
+    formatter: EventFormatter,
+) -> exitcode::ExitCode {
+    tokio::pin! {
+        let stream = subscription_client.event_traces_subscription(
+            condition,
+            opts.format,
+            opts.trace_limit as i64,
+        );
+    };
+
+    #[allow(clippy::print_stdout)]
+    #[allow(clippy::print_stderr)]
+    loop {
+        let message = stream.next().await;
+        if let Some(Some(res)) = message {
+            if let Some(errors) = res.errors {
+                for error in errors {
+                    eprintln!("[tap] {}", error.message);
+                }
+                return exitcode::DATAERR;
+            }
+            if let Some(d) = res.data {
+                let span = d.event_traces;
+                let stage = match &span.stage {
+                    EventTraceStage::EMITTED => "emitted",
+                    EventTraceStage::RECEIVED => "received",
+                    EventTraceStage::DELIVERED => "delivered",
+                    EventTraceStage::ERRORED => "errored",
+                    EventTraceStage::REJECTED => "rejected",
+                    EventTraceStage::Other(stage) => stage.as_str(),
+                };
+                println!(
+                    "{} {} {} {} {}",
+                    format!("[trace {}]", span.trace_id).yellow(),
+                    span.timestamp.to_rfc3339(),
+                    span.component_kind,
+                    span.component_id.green(),
+                    stage,
+                );
+                let event = match &span.event {
+                    Some(EventTracesSubscriptionEventTracesEvent::Log(ev)) => Some(&ev.string),
+                    Some(EventTracesSubscriptionEventTracesEvent::Metric(ev)) => Some(&ev.string),
+                    Some(EventTracesSubscriptionEventTracesEvent::Trace(ev)) => Some(&ev.string),
+                    _ => None,
+                };
+                if let Some(event) = event {
+                    println!(
+                        "{}",
+                        formatter.format(
+                            span.component_id.as_ref(),
+                            span.component_kind.as_ref(),
+                            span.component_type.as_ref(),
+                            event.as_ref(),
+                        )
+                    );
+                }
+            }
+        } else {
+            return exitcode::TEMPFAIL;
+        }
+    }
+}
+
 #[derive(Clone)]
 struct EventFormatter {
     meta: bool,
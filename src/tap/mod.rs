@@ -44,6 +44,20 @@ pub struct Opts {
     #[arg(short, long)]
     meta: bool,
 
+    /// Only observe the events matching a field filter, such as `.status >= 500`, `.message =~ timeout`
+    /// or `!.user.id`. Can be repeated; events must match every filter
+    #[arg(long = "filter")]
+    filters: Vec<String>,
+
+    /// Follow the next events emitted by sources that match a VRL boolean expression through the
+    /// topology, printing each component they reach, instead of sampling component outputs
+    #[arg(long)]
+    trace: Option<String>,
+
+    /// Maximum number of events to follow with `--trace`
+    #[arg(default_value = "1", long)]
+    trace_limit: u32,
+
     /// Whether to reconnect if the underlying Vector API connection drops. By default, tap will attempt to reconnect if the connection drops.
     #[arg(short, long)]
     no_reconnect: bool,
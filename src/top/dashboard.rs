@@ -104,12 +104,76 @@ fn format_metric(total: i64, throughput: i64, human_metrics: bool) -> String {
     }
 }
 
+/// Format the events awaiting acknowledgement, along with the age of the oldest one
+fn format_acknowledgement_backlog(
+    events: i64,
+    age_sec: Option<f64>,
+    human_metrics: bool,
+) -> String {
+    let formatted = if human_metrics {
+        events.human_format()
+    } else {
+        events.thousands_format()
+    };
+    match (events, age_sec) {
+        (0, _) | (_, None) => formatted,
+        (_, Some(age)) => format!("{} ({:.0}s)", formatted, age),
+    }
+}
+
+/// Format the errors, along with their rate
+fn format_errors(total: i64, throughput: i64, human_metrics: bool) -> String {
+    let formatted = if human_metrics {
+        total.human_format()
+    } else {
+        total.thousands_format()
+    };
+    match throughput {
+        0 => formatted,
+        throughput => format!("{} ({}/s)", formatted, throughput.human_format()),
+    }
+}
+
+/// Format the events in the buffer, along with the fraction of the buffer limit in use
+fn format_buffer(events: i64, utilization: Option<f64>, human_metrics: bool) -> String {
+    let formatted = if human_metrics {
+        events.human_format()
+    } else {
+        events.thousands_format()
+    };
+    match utilization {
+        Some(utilization) => format!("{} ({:.0}%)", formatted, utilization * 100.0),
+        None => formatted,
+    }
+}
+
+/// Highlights components currently erroring, and buffers filling up
+fn metric_style(errors_throughput: i64, buffer_utilization: Option<f64>) -> (Style, Style) {
+    let errors = if errors_throughput > 0 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+    let buffer = match buffer_utilization {
+        Some(u) if u >= 0.9 => Style::default().fg(Color::Red),
+        Some(u) if u >= 0.5 => Style::default().fg(Color::Yellow),
+        _ => Style::default(),
+    };
+    (errors, buffer)
+}
+
 const NUM_COLUMNS: usize = if is_allocation_tracking_enabled() {
-    9
+    11
 } else {
-    8
+    10
 };
 
+/// Index of the `Errors` column
+const ERRORS_COLUMN: usize = 7;
+
+/// Index of the `Buffer` column
+const BUFFER_COLUMN: usize = 8;
+
 static HEADER: [&str; NUM_COLUMNS] = [
     "ID",
     "Output",
@@ -119,6 +183,8 @@ static HEADER: [&str; NUM_COLUMNS] = [
     "Events Out",
     "Bytes",
     "Errors",
+    "Buffer",
+    "Pending Acks",
     #[cfg(feature = "allocation-tracing")]
     "Mem Usage Bytes",
 ];
@@ -211,16 +277,29 @@ impl<'a> Widgets<'a> {
                     r.processed_bytes_throughput_sec,
                     self.opts.human_metrics,
                 ),
-                if self.opts.human_metrics {
-                    r.errors.human_format()
-                } else {
-                    r.errors.thousands_format()
-                },
+                format_errors(r.errors, r.errors_throughput_sec, self.opts.human_metrics),
+                format_buffer(
+                    r.buffer_events,
+                    r.buffer_utilization,
+                    self.opts.human_metrics,
+                ),
+                format_acknowledgement_backlog(
+                    r.acknowledgement_backlog,
+                    r.acknowledgement_backlog_age_sec,
+                    self.opts.human_metrics,
+                ),
                 #[cfg(feature = "allocation-tracing")]
                 r.allocated_bytes.human_format(),
             ];
 
             data.extend_from_slice(&formatted_metrics);
+            let (errors_style, buffer_style) =
+                metric_style(r.errors_throughput_sec, r.buffer_utilization);
+            let data = data.into_iter().enumerate().map(|(i, s)| match i {
+                ERRORS_COLUMN => Cell::from(s).style(errors_style),
+                BUFFER_COLUMN => Cell::from(s).style(buffer_style),
+                _ => Cell::from(s),
+            });
             items.push(Row::new(data).style(Style::default()));
 
             // Add output rows
@@ -248,26 +327,30 @@ impl<'a> Widgets<'a> {
             .column_spacing(2)
             .widths(if is_allocation_tracking_enabled() {
                 &[
-                    Constraint::Percentage(15), // ID
+                    Constraint::Percentage(13), // ID
                     Constraint::Percentage(6),  // Output
-                    Constraint::Percentage(8),  // Kind
-                    Constraint::Percentage(10), // Type
+                    Constraint::Percentage(7),  // Kind
+                    Constraint::Percentage(8),  // Type
                     Constraint::Percentage(10), // Events In
                     Constraint::Percentage(10), // Events Out
                     Constraint::Percentage(10), // Bytes
-                    Constraint::Percentage(5),  // Errors
-                    Constraint::Percentage(16), // Allocated Bytes
+                    Constraint::Percentage(8),  // Errors
+                    Constraint::Percentage(8),  // Buffer
+                    Constraint::Percentage(8),  // Pending Acks
+                    Constraint::Percentage(10), // Allocated Bytes
                 ]
             } else {
                 &[
                     Constraint::Percentage(15), // ID
-                    Constraint::Percentage(15), // Output
-                    Constraint::Percentage(10), // Kind
+                    Constraint::Percentage(8),  // Output
+                    Constraint::Percentage(8),  // Kind
                     Constraint::Percentage(10), // Type
                     Constraint::Percentage(10), // Events In
                     Constraint::Percentage(10), // Events Out
                     Constraint::Percentage(10), // Bytes
                     Constraint::Percentage(10), // Errors
+                    Constraint::Percentage(10), // Buffer
+                    Constraint::Percentage(9),  // Pending Acks
                 ]
             });
         f.render_widget(w, area);
@@ -467,6 +550,22 @@ mod tests {
         assert_eq!(N.human_format(), "1.10 T");
     }
 
+    #[test]
+    /// Should show the error rate only while errors occur
+    fn format_error_rate() {
+        assert_eq!(format_errors(0, 0, false), "--");
+        assert_eq!(format_errors(1_500, 0, false), "1,500");
+        assert_eq!(format_errors(1_500, 20, true), "1.50 k (20/s)");
+    }
+
+    #[test]
+    /// Should show the buffer utilization as a percentage when the limit is known
+    fn format_buffer_utilization() {
+        assert_eq!(format_buffer(0, None, false), "--");
+        assert_eq!(format_buffer(1_200, None, false), "1,200");
+        assert_eq!(format_buffer(1_200, Some(0.456), false), "1,200 (46%)");
+    }
+
     #[test]
     /// Should format bytes
     fn format_bytes() {
@@ -38,6 +38,11 @@ async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
                     #[cfg(feature = "allocation-tracing")]
                     allocated_bytes: 0,
                     errors: 0,
+                    errors_throughput_sec: 0,
+                    buffer_events: 0,
+                    buffer_utilization: None,
+                    acknowledgement_backlog: 0,
+                    acknowledgement_backlog_age_sec: None,
                 }))
                 .await;
         }
@@ -263,6 +268,82 @@ async fn errors_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, inte
     }
 }
 
+async fn errors_throughputs(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_errors_throughputs_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_errors_throughputs;
+            _ = tx
+                .send(state::EventType::ErrorsThroughputs(
+                    interval,
+                    c.into_iter()
+                        .map(|c| (ComponentKey::from(c.component_id.as_str()), c.throughput))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Events in the buffer of each component, and the fraction of the buffer limit in use
+async fn buffer_utilizations(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_buffer_utilizations_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_buffer_utilizations;
+            _ = tx
+                .send(state::EventType::BufferUtilizations(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.events,
+                                c.utilization,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Events of each source awaiting end-to-end acknowledgement
+async fn acknowledgement_backlogs(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+) {
+    tokio::pin! {
+        let stream = client.component_acknowledgement_backlogs(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_acknowledgement_backlogs;
+            _ = tx
+                .send(state::EventType::AcknowledgementBacklogs(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.backlog.events,
+                                c.backlog.oldest_event_age_seconds,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 /// Subscribe to each metrics channel through a separate client. This is a temporary workaround
 /// until client multiplexing is fixed. In future, we should be able to use a single client
 pub fn subscribe(
@@ -307,6 +388,21 @@ pub fn subscribe(
         )),
         #[cfg(feature = "allocation-tracing")]
         tokio::spawn(allocated_bytes(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(acknowledgement_backlogs(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
+        tokio::spawn(buffer_utilizations(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
+        tokio::spawn(errors_throughputs(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
         tokio::spawn(errors_totals(Arc::clone(&client), tx, interval)),
     ]
 }
@@ -353,6 +449,11 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
                         #[cfg(feature = "allocation-tracing")]
                         allocated_bytes: 0,
                         errors: 0,
+                        errors_throughput_sec: 0,
+                        buffer_events: 0,
+                        buffer_utilization: None,
+                        acknowledgement_backlog: 0,
+                        acknowledgement_backlog_age_sec: None,
                     },
                 ))
             })
@@ -32,6 +32,12 @@ pub enum EventType {
     /// Interval + identified metric
     ProcessedBytesThroughputs(i64, Vec<IdentifiedMetric>),
     ErrorsTotals(Vec<IdentifiedMetric>),
+    /// Interval in ms + identified metric
+    ErrorsThroughputs(i64, Vec<IdentifiedMetric>),
+    /// Events in the buffer of each component + fraction of the buffer limit in use
+    BufferUtilizations(Vec<(ComponentKey, i64, Option<f64>)>),
+    /// Events of each source awaiting acknowledgement + age of the oldest one in seconds
+    AcknowledgementBacklogs(Vec<(ComponentKey, i64, Option<f64>)>),
     #[cfg(feature = "allocation-tracing")]
     AllocatedBytes(Vec<IdentifiedMetric>),
     ComponentAdded(ComponentRow),
@@ -123,6 +129,11 @@ pub struct ComponentRow {
     #[cfg(feature = "allocation-tracing")]
     pub allocated_bytes: i64,
     pub errors: i64,
+    pub errors_throughput_sec: i64,
+    pub buffer_events: i64,
+    pub buffer_utilization: Option<f64>,
+    pub acknowledgement_backlog: i64,
+    pub acknowledgement_backlog_age_sec: Option<f64>,
 }
 
 impl ComponentRow {
@@ -212,6 +223,30 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::ErrorsThroughputs(interval, rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.errors_throughput_sec =
+                                (v as f64 * (1000.0 / interval as f64)) as i64;
+                        }
+                    }
+                }
+                EventType::BufferUtilizations(rows) => {
+                    for (key, events, utilization) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.buffer_events = events;
+                            r.buffer_utilization = utilization;
+                        }
+                    }
+                }
+                EventType::AcknowledgementBacklogs(rows) => {
+                    for (key, events, age) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.acknowledgement_backlog = events;
+                            r.acknowledgement_backlog_age_sec = age;
+                        }
+                    }
+                }
                 #[cfg(feature = "allocation-tracing")]
                 EventType::AllocatedBytes(rows) => {
                     for (key, v) in rows {
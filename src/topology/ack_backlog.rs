@@ -0,0 +1,174 @@
+//! Tracking of the events of each source that are awaiting end-to-end acknowledgement.
+//!
+//! The pumps of a source track the events it emits with a finalizer, which is only the case when
+//! acknowledgements are enabled for it, until all of the sinks they reach have acknowledged them.
+//! This is what piles up while a sink downstream is failing, and is exposed through the API.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+use vector_core::event::{BatchNotifier, EventArray, EventFinalizer};
+
+use crate::config::ComponentKey;
+
+/// The backlog of each running source.
+static BACKLOGS: Lazy<Mutex<HashMap<ComponentKey, AckBacklog>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The events of a source awaiting acknowledgement.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AckBacklogUsage {
+    /// The number of events awaiting acknowledgement.
+    pub events: u64,
+
+    /// How long the oldest event has been awaiting acknowledgement, if any.
+    pub oldest_event_age: Option<Duration>,
+}
+
+/// Returns the events awaiting acknowledgement of the source.
+pub fn usage(key: &ComponentKey) -> Option<AckBacklogUsage> {
+    BACKLOGS
+        .lock()
+        .expect("poisoned lock")
+        .get(key)
+        .map(AckBacklog::usage)
+}
+
+/// Returns the events awaiting acknowledgement of each running source.
+pub fn usages() -> Vec<(ComponentKey, AckBacklogUsage)> {
+    BACKLOGS
+        .lock()
+        .expect("poisoned lock")
+        .iter()
+        .map(|(key, backlog)| (key.clone(), backlog.usage()))
+        .collect()
+}
+
+/// Registers a source, returning the backlog its pumps track events with. A rebuilt source keeps
+/// the backlog of the events emitted before it was rebuilt.
+pub(super) fn register(key: &ComponentKey) -> AckBacklog {
+    BACKLOGS
+        .lock()
+        .expect("poisoned lock")
+        .entry(key.clone())
+        .or_default()
+        .clone()
+}
+
+/// Forgets a source that is being shut down for good.
+pub(super) fn unregister(key: &ComponentKey) {
+    BACKLOGS.lock().expect("poisoned lock").remove(key);
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    next_id: u64,
+    events: u64,
+    /// The time each batch of events was emitted, and the number of events in it, by ID. IDs are
+    /// assigned in order, so the first batch is the oldest one.
+    batches: BTreeMap<u64, (Instant, u64)>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct AckBacklog(Arc<Mutex<Pending>>);
+
+impl AckBacklog {
+    /// Tracks the events awaiting acknowledgement, until they are finalized.
+    pub(super) fn track(&self, events: &mut EventArray) {
+        let count = events
+            .iter_events()
+            .filter(|event| !event.metadata().finalizers().is_empty())
+            .count() as u64;
+        if count == 0 {
+            return;
+        }
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        for mut event in events.iter_events_mut() {
+            let metadata = event.metadata_mut();
+            if !metadata.finalizers().is_empty() {
+                metadata.add_finalizer(EventFinalizer::new(batch.clone()));
+            }
+        }
+        drop(batch);
+
+        let id = {
+            let mut pending = self.0.lock().expect("poisoned lock");
+            let id = pending.next_id;
+            pending.next_id += 1;
+            pending.events += count;
+            pending.batches.insert(id, (Instant::now(), count));
+            id
+        };
+
+        let backlog = self.clone();
+        tokio::spawn(async move {
+            receiver.await;
+            let mut pending = backlog.0.lock().expect("poisoned lock");
+            if let Some((_, count)) = pending.batches.remove(&id) {
+                pending.events -= count;
+            }
+        });
+    }
+
+    fn usage(&self) -> AckBacklogUsage {
+        let pending = self.0.lock().expect("poisoned lock");
+        AckBacklogUsage {
+            events: pending.events,
+            oldest_event_age: pending
+                .batches
+                .values()
+                .next()
+                .map(|(emitted, _)| emitted.elapsed()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::{BatchStatus, LogEvent};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn tracks_events_until_acknowledged() {
+        let key = ComponentKey::from("ack_backlog_test_source");
+        let backlog = register(&key);
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let mut events = EventArray::from(vec![
+            LogEvent::from("tracked").with_batch_notifier(&batch),
+            LogEvent::from("untracked"),
+        ]);
+        drop(batch);
+        backlog.track(&mut events);
+
+        let tracked = usage(&key).unwrap();
+        assert_eq!(tracked.events, 1);
+        assert!(tracked.oldest_event_age.is_some());
+
+        drop(events);
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while usage(&key).unwrap().events != 0 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("events should be acknowledged");
+        assert_eq!(
+            usage(&key),
+            Some(AckBacklogUsage {
+                events: 0,
+                oldest_event_age: None
+            })
+        );
+
+        unregister(&key);
+        assert_eq!(usage(&key), None);
+    }
+}
@@ -0,0 +1,105 @@
+//! Inspection and administration of the buffers of running sinks through the API.
+//!
+//! Buffers can be drained, which holds back new events until the sink has emptied the buffer, or
+//! purged, which discards the events in the buffer without having to delete disk buffer files by
+//! hand.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use snafu::Snafu;
+use vector_buffers::topology::control::{BufferControl, BufferStageUsage};
+
+use crate::config::ComponentKey;
+
+/// The buffer of each running sink.
+static BUFFERS: Lazy<Mutex<HashMap<ComponentKey, BufferControl>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum BufferControlError {
+    #[snafu(display("No running sink with ID {:?}", id))]
+    SinkNotFound { id: String },
+}
+
+/// Returns the current usage of each stage of the sink's buffer, and whether it's being drained.
+pub fn usage(key: &ComponentKey) -> Option<(Vec<BufferStageUsage>, bool)> {
+    get(key)
+        .ok()
+        .map(|control| (control.stages(), control.is_draining()))
+}
+
+/// Drains the buffer of the sink, returning the number of events to drain.
+pub fn drain(key: &ComponentKey) -> Result<u64, BufferControlError> {
+    let events = get(key)?.drain();
+    info!(component_id = %key.id(), events, "Draining buffer.");
+    Ok(events)
+}
+
+/// Purges the buffer of the sink, returning the number of events to purge.
+pub fn purge(key: &ComponentKey) -> Result<u64, BufferControlError> {
+    let events = get(key)?.purge();
+    info!(component_id = %key.id(), events, "Purging buffer.");
+    Ok(events)
+}
+
+fn get(key: &ComponentKey) -> Result<BufferControl, BufferControlError> {
+    BUFFERS
+        .lock()
+        .expect("poisoned lock")
+        .get(key)
+        .cloned()
+        .ok_or_else(|| BufferControlError::SinkNotFound {
+            id: key.id().to_string(),
+        })
+}
+
+/// Registers the buffer of a sink. A reused buffer keeps its registration.
+pub(super) fn register(key: &ComponentKey, control: BufferControl) {
+    BUFFERS
+        .lock()
+        .expect("poisoned lock")
+        .insert(key.clone(), control);
+}
+
+/// Forgets the buffer of a sink that is being shut down for good.
+pub(super) fn unregister(key: &ComponentKey) {
+    BUFFERS.lock().expect("poisoned lock").remove(key);
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::Span;
+    use vector_buffers::BufferConfig;
+
+    use super::*;
+    use crate::event::{EventArray, LogEvent};
+
+    #[tokio::test]
+    async fn controls_registered_buffers() {
+        let key = ComponentKey::from("buffer_control_test_sink");
+        assert_eq!(
+            purge(&key),
+            Err(BufferControlError::SinkNotFound {
+                id: key.id().to_string()
+            })
+        );
+
+        let (mut tx, _rx) = BufferConfig::default()
+            .build::<EventArray>(None, key.to_string(), Span::none())
+            .await
+            .unwrap();
+        register(&key, tx.control().cloned().unwrap());
+
+        tx.send(LogEvent::from("foo").into()).await.unwrap();
+        let (stages, draining) = usage(&key).unwrap();
+        assert_eq!(stages.len(), 1);
+        assert_eq!(stages[0].events, 1);
+        assert!(!draining);
+
+        assert_eq!(purge(&key), Ok(1));
+
+        unregister(&key);
+        assert!(usage(&key).is_none());
+    }
+}
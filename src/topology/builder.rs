@@ -1,7 +1,7 @@
 use std::{
     collections::HashMap,
     future::ready,
-    num::NonZeroUsize,
+    num::{NonZeroU64, NonZeroUsize},
     sync::{Arc, Mutex},
     time::Instant,
 };
@@ -20,7 +20,6 @@ use vector_common::internal_event::{
     self, CountByteSize, EventsSent, InternalEventHandle as _, Registered,
 };
 use vector_config::NamedComponent;
-use vector_core::config::LogNamespace;
 use vector_core::{
     buffers::{
         topology::{
@@ -34,15 +33,22 @@ use vector_core::{
 };
 
 use super::{
+    ack_backlog, buffer_control,
+    dead_letter::DeadLetterOutput,
+    delivery_failures::DeliveryFailureTracker,
+    event_tracing::{self, TraceStage},
     fanout::{self, Fanout},
+    feature_flags, pause,
+    schedule::Schedule,
     schema,
     task::{Task, TaskOutput, TaskResult},
-    BuiltBuffer, ConfigDiff,
+    BuiltBuffer, ConfigDiff, TapOutput,
 };
 use crate::{
     config::{
-        ComponentKey, DataType, EnrichmentTableConfig, Input, Inputs, OutputId, ProxyConfig,
-        SinkConfig, SinkContext, SourceContext, TransformContext, TransformOuter, TransformOutput,
+        ComponentKey, DataDirSubsystem, DataType, EnrichmentTableConfig, Input, Inputs, OutputId,
+        ProxyConfig, SinkConfig, SinkContext, SourceContext, TransformContext, TransformOuter,
+        TransformOutput,
     },
     event::{EventArray, EventContainer},
     internal_events::EventsReceived,
@@ -50,7 +56,10 @@ use crate::{
     source_sender::CHUNK_SIZE,
     spawn_named,
     topology::task::TaskError,
-    transforms::{SyncTransform, TaskTransform, Transform, TransformOutputs, TransformOutputsBuf},
+    transforms::{
+        MultiOutputTaskTransform, SchemaValidator, SyncTransform, TaskTransform, Transform,
+        TransformOutputs, TransformOutputsBuf,
+    },
     utilization::wrap,
     SourceSender,
 };
@@ -237,6 +246,8 @@ impl<'a> Builder<'a> {
                 key.id()
             );
 
+            let schedule =
+                Schedule::new(&source.schedule, self.config.global.timezone()).map(Arc::new);
             let mut builder = SourceSender::builder().with_buffer(*SOURCE_SENDER_BUFFER_SIZE);
             let mut pumps = Vec::new();
             let mut controls = HashMap::new();
@@ -246,10 +257,27 @@ impl<'a> Builder<'a> {
                 let mut rx = builder.add_source_output(output.clone());
 
                 let (mut fanout, control) = Fanout::new();
+                let schedule = schedule.clone();
+                let mut pause = pause::register(key);
+                let ack_backlog = ack_backlog::register(key);
+                let traced_component = TapOutput {
+                    output_id: OutputId {
+                        component: key.clone(),
+                        port: output.port.clone(),
+                    },
+                    component_kind: "source",
+                    component_type: typetag.to_string(),
+                };
                 let pump = async move {
                     debug!("Source pump starting.");
 
-                    while let Some(array) = rx.next().await {
+                    while let Some(mut array) = rx.next().await {
+                        event_tracing::start_traces(&traced_component, &mut array);
+                        ack_backlog.track(&mut array);
+                        if let Some(schedule) = &schedule {
+                            schedule.wait_until_open().await;
+                        }
+                        pause.wait_until_resumed().await;
                         fanout.send(array).await.map_err(|e| {
                             debug!("Source pump finished with an error.");
                             TaskError::wrapped(e)
@@ -447,6 +475,8 @@ impl<'a> Builder<'a> {
                     (output.port, definitions)
                 })
                 .collect::<HashMap<_, _>>();
+            let has_default_output = schema_definitions.contains_key(&None);
+            let enabled_when = transform.enabled_when.clone();
 
             let context = TransformContext {
                 key: Some(key.clone()),
@@ -462,7 +492,7 @@ impl<'a> Builder<'a> {
                 enrichment_tables.clone(),
                 transform,
                 &input_definitions,
-                self.config.schema.log_namespace(),
+                self.config.schema,
             );
 
             let transform = match transform
@@ -479,6 +509,25 @@ impl<'a> Builder<'a> {
                 Ok(transform) => transform,
             };
 
+            let transform = match enabled_when {
+                None => transform,
+                Some(_) if !has_default_output => {
+                    self.errors.push(format!(
+                        "Transform \"{}\": `enabled_when` requires a default output",
+                        key
+                    ));
+                    continue;
+                }
+                Some(flag) => match feature_flags::gate(transform, flag) {
+                    Err(error) => {
+                        self.errors
+                            .push(format!("Transform \"{}\": {}", key, error));
+                        continue;
+                    }
+                    Ok(transform) => transform,
+                },
+            };
+
             let (input_tx, input_rx) =
                 TopologyBuilder::standalone_memory(TOPOLOGY_BUFFER_SIZE, WhenFull::Block).await;
 
@@ -509,6 +558,14 @@ impl<'a> Builder<'a> {
 
             let typetag = sink.inner.get_component_name();
             let input_type = sink.inner.input().data_type();
+            let delivery_failures = DeliveryFailureTracker::new(key, &sink.delivery_failures);
+            let (dead_letter, dead_letter_task, dead_letter_control) =
+                match DeadLetterOutput::new(key, typetag, &sink.dead_letter) {
+                    Some((output, task, control)) => (Some(output), Some(task), control),
+                    None => (None, None, None),
+                };
+            let schedule =
+                Schedule::new(&sink.schedule, self.config.global.timezone()).map(Arc::new);
 
             // At this point, we've validated that all transforms are valid, including any
             // transform that mutates the schema provided by their sources. We can now validate the
@@ -540,7 +597,7 @@ impl<'a> Builder<'a> {
                 let buffer = sink
                     .buffer
                     .build(
-                        self.config.global.data_dir.clone(),
+                        self.config.global.data_dir_for(DataDirSubsystem::Buffers),
                         key.to_string(),
                         buffer_span,
                     )
@@ -550,7 +607,12 @@ impl<'a> Builder<'a> {
                         self.errors.push(format!("Sink \"{}\": {}", key, error));
                         continue;
                     }
-                    Ok((tx, rx)) => (tx, Arc::new(Mutex::new(Some(rx.into_stream())))),
+                    Ok((tx, rx)) => {
+                        if let Some(control) = tx.control() {
+                            buffer_control::register(key, control.clone());
+                        }
+                        (tx, Arc::new(Mutex::new(Some(rx.into_stream()))))
+                    }
                 }
             };
 
@@ -558,6 +620,7 @@ impl<'a> Builder<'a> {
                 healthcheck,
                 globals: self.config.global.clone(),
                 proxy: ProxyConfig::merge_with_env(&self.config.global.proxy, sink.proxy()),
+                http_client: sink.http_client().clone(),
                 schema: self.config.schema,
             };
 
@@ -571,6 +634,11 @@ impl<'a> Builder<'a> {
 
             let (trigger, tripwire) = Tripwire::new();
 
+            let traced_component = TapOutput {
+                output_id: OutputId::from(key),
+                component_kind: "sink",
+                component_type: typetag.to_string(),
+            };
             let sink = async move {
                 debug!("Sink starting.");
 
@@ -588,10 +656,36 @@ impl<'a> Builder<'a> {
 
                 let mut rx = wrap(rx);
 
+                // The dead-letter output outlives the sink until all of its pending deliveries
+                // have finished, so it runs as a separate task.
+                if let Some(task) = dead_letter_task {
+                    tokio::spawn(task.in_current_span());
+                }
+
                 let events_received = register!(EventsReceived);
                 sink.run(
                     rx.by_ref()
                         .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
+                        .then(|events| {
+                            let schedule = schedule.clone();
+                            async move {
+                                if let Some(schedule) = schedule {
+                                    schedule.wait_until_open().await;
+                                }
+                                events
+                            }
+                        })
+                        .map(|mut events| {
+                            event_tracing::record(&traced_component, TraceStage::Received, &events);
+                            event_tracing::track_delivery(&traced_component, &mut events);
+                            if let Some(tracker) = &delivery_failures {
+                                tracker.track(&mut events);
+                            }
+                            if let Some(output) = &dead_letter {
+                                output.track(&mut events);
+                            }
+                            events
+                        })
                         .inspect(|events| {
                             events_received.emit(CountByteSize(
                                 events.len(),
@@ -657,6 +751,7 @@ impl<'a> Builder<'a> {
             let healthcheck_task = Task::new(key.clone(), typetag, healthcheck_task);
 
             self.inputs.insert(key.clone(), (tx, sink_inputs.clone()));
+            self.outputs.extend(dead_letter_control);
             self.healthchecks.insert(key.clone(), healthcheck_task);
             self.tasks.insert(key.clone(), task);
             self.detach_triggers.insert(key.clone(), trigger);
@@ -690,6 +785,7 @@ struct TransformNode {
     input_details: Input,
     outputs: Vec<TransformOutput>,
     enable_concurrency: bool,
+    schema_validation_sample_rate: Option<NonZeroU64>,
 }
 
 impl TransformNode {
@@ -698,7 +794,7 @@ impl TransformNode {
         enrichment_tables: enrichment::TableRegistry,
         transform: &TransformOuter<OutputId>,
         schema_definition: &[(OutputId, Definition)],
-        global_log_namespace: LogNamespace,
+        schema: crate::config::schema::Options,
     ) -> Self {
         Self {
             key,
@@ -708,10 +804,24 @@ impl TransformNode {
             outputs: transform.inner.outputs(
                 enrichment_tables,
                 schema_definition,
-                global_log_namespace,
+                schema.log_namespace(),
             ),
             enable_concurrency: transform.inner.enable_concurrency(),
+            schema_validation_sample_rate: schema.runtime_validation_sample_rate(),
+        }
+    }
+
+    fn transform_outputs(
+        &self,
+    ) -> (
+        TransformOutputs,
+        HashMap<Option<String>, fanout::ControlChannel>,
+    ) {
+        let (mut outputs, controls) = TransformOutputs::new(self.outputs.clone());
+        if let Some(sample_rate) = self.schema_validation_sample_rate {
+            outputs.enable_schema_validation(sample_rate);
         }
+        (outputs, controls)
     }
 }
 
@@ -724,13 +834,22 @@ fn build_transform(
         // TODO: avoid the double boxing for function transforms here
         Transform::Function(t) => build_sync_transform(Box::new(t), node, input_rx),
         Transform::Synchronous(t) => build_sync_transform(t, node, input_rx),
-        Transform::Task(t) => build_task_transform(
-            t,
-            input_rx,
-            node.input_details.data_type(),
-            node.typetag,
-            &node.key,
-        ),
+        Transform::Task(t) => {
+            let schema_validator = node.schema_validation_sample_rate.and_then(|sample_rate| {
+                node.outputs
+                    .first()
+                    .and_then(|output| SchemaValidator::new(output, sample_rate))
+            });
+            build_task_transform(
+                t,
+                input_rx,
+                node.input_details.data_type(),
+                node.typetag,
+                &node.key,
+                schema_validator,
+            )
+        }
+        Transform::MultiOutputTask(t) => build_multi_output_task_transform(t, node, input_rx),
     }
 }
 
@@ -739,9 +858,20 @@ fn build_sync_transform(
     node: TransformNode,
     input_rx: BufferReceiver<EventArray>,
 ) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
-    let (outputs, controls) = TransformOutputs::new(node.outputs);
+    let (outputs, controls) = node.transform_outputs();
 
-    let runner = Runner::new(t, input_rx, node.input_details.data_type(), outputs);
+    let traced_component = TapOutput {
+        output_id: OutputId::from(&node.key),
+        component_kind: "transform",
+        component_type: node.typetag.to_string(),
+    };
+    let runner = Runner::new(
+        traced_component,
+        t,
+        input_rx,
+        node.input_details.data_type(),
+        outputs,
+    );
     let transform = if node.enable_concurrency {
         runner.run_concurrently().boxed()
     } else {
@@ -777,6 +907,7 @@ fn build_sync_transform(
 }
 
 struct Runner {
+    traced_component: TapOutput,
     transform: Box<dyn SyncTransform>,
     input_rx: Option<BufferReceiver<EventArray>>,
     input_type: DataType,
@@ -788,12 +919,14 @@ struct Runner {
 
 impl Runner {
     fn new(
+        traced_component: TapOutput,
         transform: Box<dyn SyncTransform>,
         input_rx: BufferReceiver<EventArray>,
         input_type: DataType,
         outputs: TransformOutputs,
     ) -> Self {
         Self {
+            traced_component,
             transform,
             input_rx: Some(input_rx),
             input_type,
@@ -815,6 +948,7 @@ impl Runner {
             events.len(),
             events.estimated_json_encoded_size_of(),
         ));
+        event_tracing::record(&self.traced_component, TraceStage::Received, events);
     }
 
     async fn send_outputs(&mut self, outputs_buf: &mut TransformOutputsBuf) -> crate::Result<()> {
@@ -921,24 +1055,34 @@ fn build_task_transform(
     input_type: DataType,
     typetag: &str,
     key: &ComponentKey,
+    mut schema_validator: Option<SchemaValidator>,
 ) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
     let (mut fanout, control) = Fanout::new();
 
     let input_rx = crate::utilization::wrap(input_rx.into_stream());
 
     let events_received = register!(EventsReceived);
+    let traced_component = TapOutput {
+        output_id: OutputId::from(key),
+        component_kind: "transform",
+        component_type: typetag.to_string(),
+    };
     let filtered = input_rx
         .filter(move |events| ready(filter_events_type(events, input_type)))
         .inspect(move |events| {
             events_received.emit(CountByteSize(
                 events.len(),
                 events.estimated_json_encoded_size_of(),
-            ))
+            ));
+            event_tracing::record(&traced_component, TraceStage::Received, events);
         });
     let events_sent = register!(EventsSent::from(internal_event::Output(None)));
     let stream = t
         .transform(Box::pin(filtered))
         .inspect(move |events: &EventArray| {
+            if let Some(validator) = schema_validator.as_mut() {
+                validator.validate(events);
+            }
             events_sent.emit(CountByteSize(
                 events.len(),
                 events.estimated_json_encoded_size_of(),
@@ -967,3 +1111,57 @@ fn build_task_transform(
 
     (task, outputs)
 }
+
+fn build_multi_output_task_transform(
+    t: Box<dyn MultiOutputTaskTransform>,
+    node: TransformNode,
+    input_rx: BufferReceiver<EventArray>,
+) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
+    let (mut outputs, controls) = node.transform_outputs();
+
+    let input_rx = crate::utilization::wrap(input_rx.into_stream());
+
+    let input_type = node.input_details.data_type();
+    let events_received = register!(EventsReceived);
+    let traced_component = TapOutput {
+        output_id: OutputId::from(&node.key),
+        component_kind: "transform",
+        component_type: node.typetag.to_string(),
+    };
+    let filtered = input_rx
+        .filter(move |events| ready(filter_events_type(events, input_type)))
+        .inspect(move |events| {
+            events_received.emit(CountByteSize(
+                events.len(),
+                events.estimated_json_encoded_size_of(),
+            ));
+            event_tracing::record(&traced_component, TraceStage::Received, events);
+        });
+    let mut stream = t.transform(Box::pin(filtered), outputs.new_buf_with_capacity(0));
+    let transform = async move {
+        debug!("Task transform starting.");
+
+        while let Some(mut outputs_buf) = stream.next().await {
+            if let Err(e) = outputs.send(&mut outputs_buf).await {
+                debug!("Task transform finished with an error.");
+                return Err(TaskError::wrapped(e));
+            }
+        }
+
+        debug!("Task transform finished normally.");
+        Ok(TaskOutput::Transform)
+    }
+    .boxed();
+
+    let mut output_controls = HashMap::new();
+    for (name, control) in controls {
+        let id = name
+            .map(|name| OutputId::from((&node.key, name)))
+            .unwrap_or_else(|| OutputId::from(&node.key));
+        output_controls.insert(id, control);
+    }
+
+    let task = Task::new(node.key.clone(), node.typetag, transform);
+
+    (task, output_controls)
+}
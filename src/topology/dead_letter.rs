@@ -0,0 +1,392 @@
+//! Dead-letter handling of sinks.
+//!
+//! Sinks with `dead_letter.enabled`, or a `dead_letter.path`, keep a copy of each array of events
+//! they receive, and attach a batch notifier to each of its events. Once the events of an array are
+//! finalized, the copies of those that weren't delivered are sent to the sink's `dead_letter`
+//! output, which other components consume like the output of any transform, and appended to the
+//! dead-letter file, from which `vector replay` reads them back.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use futures::{future::join_all, FutureExt};
+use serde::{Deserialize, Serialize};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::mpsc};
+use vector_common::{
+    finalization::{BatchNotifier, BatchStatus, EventFinalizer, Finalizable},
+    internal_event::{self, CountByteSize, EventsSent, InternalEventHandle as _},
+};
+use vector_core::{
+    event::{Event, EventArray, EventContainer},
+    EstimatedJsonEncodedSizeOf,
+};
+
+use super::{
+    fanout::{self, Fanout},
+    task::{Task, TaskOutput},
+};
+use crate::{
+    config::{ComponentKey, OutputId, SinkDeadLetterOptions, DEAD_LETTER_OUTPUT},
+    internal_events::DeadLetterFileError,
+    topology::task::TaskError,
+};
+
+/// The number of undelivered arrays buffered before being handled.
+const CAPACITY: usize = 100;
+
+/// A line of a dead-letter file.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct DeadLetterRecord {
+    /// The ID of the sink that failed to deliver the event.
+    pub(crate) sink: String,
+
+    /// Whether the delivery failed with an error, or was rejected.
+    pub(crate) status: DeadLetterStatus,
+
+    /// When the sink gave up on delivering the event.
+    pub(crate) failed_at: DateTime<Utc>,
+
+    pub(crate) event: Event,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DeadLetterStatus {
+    Errored,
+    Rejected,
+}
+
+/// Appends undelivered events to a dead-letter file.
+struct DeadLetterFile {
+    sink: String,
+    path: PathBuf,
+}
+
+impl DeadLetterFile {
+    async fn write(&self, events: EventArray, status: BatchStatus) {
+        let count = events.len();
+        let status = match status {
+            BatchStatus::Rejected => DeadLetterStatus::Rejected,
+            _ => DeadLetterStatus::Errored,
+        };
+        let failed_at = Utc::now();
+
+        let mut buffer = Vec::new();
+        for event in events.into_events() {
+            let record = DeadLetterRecord {
+                sink: self.sink.clone(),
+                status,
+                failed_at,
+                event,
+            };
+            if let Err(error) = serde_json::to_writer(&mut buffer, &record) {
+                emit!(DeadLetterFileError {
+                    error: error.into(),
+                    path: &self.path,
+                    count: 1,
+                });
+                continue;
+            }
+            buffer.push(b'\n');
+        }
+
+        if let Err(error) = append(&self.path, &buffer).await {
+            emit!(DeadLetterFileError {
+                error,
+                path: &self.path,
+                count,
+            });
+        }
+    }
+}
+
+async fn append(path: &Path, buffer: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    // Each array is written at once, so that concurrent writers don't interleave their lines.
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(buffer).await?;
+    file.flush().await
+}
+
+/// Handles the events a sink fails to deliver.
+pub(super) struct DeadLetterOutput {
+    sender: mpsc::Sender<(EventArray, BatchStatus)>,
+}
+
+impl DeadLetterOutput {
+    /// Creates the dead-letter handling of the sink, along with the task handling undelivered
+    /// events and, if the `dead_letter` output is enabled, the control channel of its fanout.
+    pub(super) fn new(
+        key: &ComponentKey,
+        typetag: &'static str,
+        config: &SinkDeadLetterOptions,
+    ) -> Option<(Self, Task, Option<(OutputId, fanout::ControlChannel)>)> {
+        if !config.enabled && config.path.is_none() {
+            return None;
+        }
+
+        let (sender, mut receiver) = mpsc::channel(CAPACITY);
+
+        let (mut output, control) = if config.enabled {
+            let (fanout, control) = Fanout::new();
+            let events_sent = register!(EventsSent::from(internal_event::Output(Some(
+                DEAD_LETTER_OUTPUT.into()
+            ))));
+            let id = OutputId::from((key, DEAD_LETTER_OUTPUT.to_owned()));
+            (Some((fanout, events_sent)), Some((id, control)))
+        } else {
+            (None, None)
+        };
+        let file = config.path.clone().map(|path| DeadLetterFile {
+            sink: key.to_string(),
+            path,
+        });
+
+        let forwarder = async move {
+            debug!("Dead-letter output starting.");
+
+            // The channel closes once the sink and all of its pending deliveries have finished.
+            while let Some((events, status)) = receiver.recv().await {
+                let Some((fanout, events_sent)) = &mut output else {
+                    if let Some(file) = &file {
+                        file.write(events, status).await;
+                    }
+                    continue;
+                };
+
+                if let Some(file) = &file {
+                    file.write(events.clone(), status).await;
+                }
+                events_sent.emit(CountByteSize(
+                    events.len(),
+                    events.estimated_json_encoded_size_of(),
+                ));
+                if let Err(e) = fanout.send(events).await {
+                    debug!("Dead-letter output finished with an error.");
+                    return Err(TaskError::wrapped(e));
+                }
+            }
+
+            debug!("Dead-letter output finished normally.");
+            Ok(TaskOutput::Transform)
+        }
+        .boxed();
+
+        let task = Task::new(key.clone(), typetag, forwarder);
+
+        Some((Self { sender }, task, control))
+    }
+
+    /// Attaches a batch notifier to each of the events, handling a copy of those that aren't
+    /// delivered.
+    ///
+    /// Each event has its own notifier, as sinks such as `elasticsearch` can reject some of the
+    /// events of a request while delivering the others.
+    pub(super) fn track(&self, events: &mut EventArray) {
+        // The copy must not hold on to the finalizers of the events, as that would delay their
+        // acknowledgement until the copy is dropped.
+        let mut copy = events.clone();
+        drop(copy.take_finalizers());
+
+        let receivers = events
+            .iter_events_mut()
+            .map(|mut event| {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                event
+                    .metadata_mut()
+                    .add_finalizer(EventFinalizer::new(batch));
+                receiver
+            })
+            .collect::<Vec<_>>();
+
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let statuses = join_all(receivers).await;
+            for status in [BatchStatus::Errored, BatchStatus::Rejected] {
+                if !statuses.contains(&status) {
+                    continue;
+                }
+                let events = retain_status(copy.clone(), &statuses, status);
+                // Sending only fails once the output has shut down, in which case there's no one
+                // left to receive the events.
+                _ = sender.send((events, status)).await;
+            }
+        });
+    }
+}
+
+/// Keeps the events whose status, given in the same order as the events, is `status`.
+fn retain_status(events: EventArray, statuses: &[BatchStatus], status: BatchStatus) -> EventArray {
+    let mut statuses = statuses.iter();
+    let mut keep = || statuses.next() == Some(&status);
+    match events {
+        EventArray::Logs(mut logs) => {
+            logs.retain(|_| keep());
+            EventArray::Logs(logs)
+        }
+        EventArray::Metrics(mut metrics) => {
+            metrics.retain(|_| keep());
+            EventArray::Metrics(metrics)
+        }
+        EventArray::Traces(mut traces) => {
+            traces.retain(|_| keep());
+            EventArray::Traces(traces)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use vector_common::finalization::EventStatus;
+    use vector_core::{
+        buffers::{topology::builder::TopologyBuilder, WhenFull},
+        event::LogEvent,
+    };
+
+    use super::*;
+    use crate::topology::builder::TOPOLOGY_BUFFER_SIZE;
+
+    #[test]
+    fn disabled_without_config() {
+        assert!(
+            DeadLetterOutput::new(&ComponentKey::from("out"), "test", &Default::default())
+                .is_none()
+        );
+    }
+
+    /// Tracks a delivered and a rejected array of events, waiting for the task handling them.
+    async fn deliver_and_reject(output: DeadLetterOutput, task: Task) {
+        let task = tokio::spawn(task);
+
+        let mut delivered = EventArray::from(LogEvent::from("delivered"));
+        output.track(&mut delivered);
+        let mut rejected = EventArray::from(LogEvent::from("rejected"));
+        output.track(&mut rejected);
+        drop(output);
+
+        delivered
+            .take_finalizers()
+            .update_status(EventStatus::Delivered);
+        rejected
+            .take_finalizers()
+            .update_status(EventStatus::Rejected);
+
+        task.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn sends_undelivered_events() {
+        let config = SinkDeadLetterOptions {
+            enabled: true,
+            ..Default::default()
+        };
+        let (output, task, control) =
+            DeadLetterOutput::new(&ComponentKey::from("out"), "test", &config).unwrap();
+        let (id, control) = control.unwrap();
+        assert_eq!(id.to_string(), "out.dead_letter");
+
+        let (tx, rx) =
+            TopologyBuilder::standalone_memory(TOPOLOGY_BUFFER_SIZE, WhenFull::Block).await;
+        control
+            .send(fanout::ControlMessage::Add(
+                ComponentKey::from("fallback"),
+                tx,
+            ))
+            .unwrap();
+
+        deliver_and_reject(output, task).await;
+        let events = rx
+            .into_stream()
+            .flat_map(|events| futures::stream::iter(events.into_events()))
+            .map(Event::into_log)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(events, vec![LogEvent::from("rejected")]);
+    }
+
+    #[tokio::test]
+    async fn sends_undelivered_events_of_partially_delivered_arrays() {
+        let config = SinkDeadLetterOptions {
+            enabled: true,
+            ..Default::default()
+        };
+        let (output, task, control) =
+            DeadLetterOutput::new(&ComponentKey::from("out"), "test", &config).unwrap();
+        let (_, control) = control.unwrap();
+
+        let (tx, rx) =
+            TopologyBuilder::standalone_memory(TOPOLOGY_BUFFER_SIZE, WhenFull::Block).await;
+        control
+            .send(fanout::ControlMessage::Add(
+                ComponentKey::from("fallback"),
+                tx,
+            ))
+            .unwrap();
+        let task = tokio::spawn(task);
+
+        let mut events = EventArray::from(vec![
+            LogEvent::from("delivered"),
+            LogEvent::from("rejected"),
+            LogEvent::from("errored"),
+        ]);
+        output.track(&mut events);
+        drop(output);
+
+        let statuses = [
+            EventStatus::Delivered,
+            EventStatus::Rejected,
+            EventStatus::Errored,
+        ];
+        for (mut event, status) in events.iter_events_mut().zip(statuses) {
+            event.metadata_mut().take_finalizers().update_status(status);
+        }
+        drop(events);
+
+        task.await.unwrap().unwrap();
+        // The errored events are handled before the rejected ones.
+        let events = rx
+            .into_stream()
+            .flat_map(|events| futures::stream::iter(events.into_events()))
+            .map(Event::into_log)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            events,
+            vec![LogEvent::from("errored"), LogEvent::from("rejected")]
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_undelivered_events_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dead_letter/out.ndjson");
+        let config = SinkDeadLetterOptions {
+            path: Some(path.clone()),
+            ..Default::default()
+        };
+        let (output, task, control) =
+            DeadLetterOutput::new(&ComponentKey::from("out"), "test", &config).unwrap();
+        assert!(control.is_none());
+
+        deliver_and_reject(output, task).await;
+        let contents = std::fs::read_to_string(path).unwrap();
+        let records = contents
+            .lines()
+            .map(|line| serde_json::from_str::<DeadLetterRecord>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].sink, "out");
+        assert_eq!(records[0].status, DeadLetterStatus::Rejected);
+        assert_eq!(
+            records[0].event.clone().into_log(),
+            LogEvent::from("rejected")
+        );
+    }
+}
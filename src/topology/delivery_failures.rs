@@ -0,0 +1,202 @@
+//! Reporting of the events that sinks fail to deliver.
+//!
+//! Sinks with `delivery_failures.enabled` attach a batch notifier to each array of events they
+//! receive. When an array isn't delivered, the key fields of its events are broadcast to the
+//! `delivery_failures` sources.
+
+use std::collections::BTreeMap;
+
+use lookup::lookup_v2::ConfigValuePath;
+use once_cell::sync::Lazy;
+use tokio::sync::broadcast;
+use vector_common::finalization::{AddBatchNotifier, BatchNotifier, BatchStatus};
+use vector_core::event::{EventArray, EventRef, Value};
+
+use crate::config::{ComponentKey, SinkDeliveryFailuresOptions};
+
+/// The number of reports buffered for each subscriber.
+const CAPACITY: usize = 1024;
+
+static SENDER: Lazy<broadcast::Sender<DeliveryFailure>> =
+    Lazy::new(|| broadcast::channel(CAPACITY).0);
+
+/// A batch of events that a sink failed to deliver.
+#[derive(Clone, Debug)]
+pub struct DeliveryFailure {
+    /// The ID of the sink.
+    pub component_id: String,
+
+    /// Whether the delivery of the batch errored or was rejected.
+    pub status: BatchStatus,
+
+    /// The key fields of each event of the batch.
+    pub events: Vec<Value>,
+}
+
+/// Subscribes to the delivery failures of all sinks.
+pub fn subscribe() -> broadcast::Receiver<DeliveryFailure> {
+    SENDER.subscribe()
+}
+
+/// Tracks the delivery of the events received by a sink.
+pub(super) struct DeliveryFailureTracker {
+    component_id: String,
+    key_fields: Vec<ConfigValuePath>,
+}
+
+impl DeliveryFailureTracker {
+    pub(super) fn new(key: &ComponentKey, config: &SinkDeliveryFailuresOptions) -> Option<Self> {
+        config.enabled.then(|| Self {
+            component_id: key.id().to_string(),
+            key_fields: config.key_fields.clone(),
+        })
+    }
+
+    /// Attaches a batch notifier to the events, reporting them if they aren't delivered.
+    pub(super) fn track(&self, events: &mut EventArray) {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        events.add_batch_notifier(batch);
+
+        // The key fields are extracted up front, as the sink takes ownership of the events.
+        let keys = events
+            .iter_events()
+            .map(|event| self.key_fields(event))
+            .collect();
+        let component_id = self.component_id.clone();
+
+        tokio::spawn(async move {
+            let status = receiver.await;
+            if status != BatchStatus::Delivered {
+                // Sending only fails when no `delivery_failures` source is running, in which case
+                // the report isn't needed.
+                _ = SENDER.send(DeliveryFailure {
+                    component_id,
+                    status,
+                    events: keys,
+                });
+            }
+        });
+    }
+
+    fn key_fields(&self, event: EventRef<'_>) -> Value {
+        let value = match event {
+            EventRef::Log(log) => log.value(),
+            EventRef::Trace(trace) => trace.value(),
+            EventRef::Metric(metric) => {
+                let mut fields = BTreeMap::new();
+                fields.insert("name".to_owned(), Value::from(metric.name()));
+                if let Some(namespace) = metric.namespace() {
+                    fields.insert("namespace".to_owned(), Value::from(namespace));
+                }
+                if let Some(tags) = metric.tags() {
+                    let tags = tags
+                        .iter_single()
+                        .map(|(key, value)| (key.to_owned(), Value::from(value)))
+                        .collect::<BTreeMap<_, _>>();
+                    fields.insert("tags".to_owned(), Value::Object(tags));
+                }
+                return Value::Object(fields);
+            }
+        };
+
+        let mut fields = Value::Object(BTreeMap::new());
+        for path in &self.key_fields {
+            if let Some(field) = value.get(&path.0) {
+                fields.insert(&path.0, field.clone());
+            }
+        }
+        fields
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::finalization::{EventStatus, Finalizable};
+    use vector_core::event::{LogEvent, Metric, MetricKind, MetricValue};
+
+    use super::*;
+
+    fn tracker(id: &str, key_fields: &[&str]) -> DeliveryFailureTracker {
+        let config = SinkDeliveryFailuresOptions {
+            enabled: true,
+            key_fields: key_fields
+                .iter()
+                .map(|field| ConfigValuePath::try_from(field.to_string()).unwrap())
+                .collect(),
+        };
+        DeliveryFailureTracker::new(&ComponentKey::from(id), &config).unwrap()
+    }
+
+    /// Receives the next failure of the sink, as the failures of all sinks are broadcast.
+    async fn next_failure(
+        failures: &mut broadcast::Receiver<DeliveryFailure>,
+        id: &str,
+    ) -> DeliveryFailure {
+        loop {
+            let failure = failures.recv().await.unwrap();
+            if failure.component_id == id {
+                return failure;
+            }
+        }
+    }
+
+    #[test]
+    fn disabled_without_config() {
+        assert!(
+            DeliveryFailureTracker::new(&ComponentKey::from("out"), &Default::default()).is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_failed_batches() {
+        let mut failures = subscribe();
+        let tracker = tracker("logs_out", &["id", "order.id"]);
+
+        let mut log = LogEvent::from("failed");
+        log.insert("id", "a");
+        log.insert("order.id", 7);
+        log.insert("other", "ignored");
+        let mut events = EventArray::from(log);
+        tracker.track(&mut events);
+        events
+            .take_finalizers()
+            .update_status(EventStatus::Rejected);
+
+        let failure = next_failure(&mut failures, "logs_out").await;
+        assert_eq!(failure.status, BatchStatus::Rejected);
+        assert_eq!(failure.events.len(), 1);
+        assert_eq!(failure.events[0].get("id").cloned(), Some(Value::from("a")));
+        assert_eq!(
+            failure.events[0].get("order.id").cloned(),
+            Some(Value::from(7))
+        );
+        assert!(failure.events[0].get("other").is_none());
+    }
+
+    #[tokio::test]
+    async fn reports_metric_names() {
+        let mut failures = subscribe();
+        let tracker = tracker("metrics_out", &[]);
+
+        let metric = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 1.0 },
+        )
+        .with_namespace(Some("app"));
+        let mut events = EventArray::from(metric);
+        tracker.track(&mut events);
+        events.take_finalizers().update_status(EventStatus::Errored);
+
+        let failure = next_failure(&mut failures, "metrics_out").await;
+        assert_eq!(failure.status, BatchStatus::Errored);
+        assert_eq!(
+            failure.events[0].get("name").cloned(),
+            Some(Value::from("requests"))
+        );
+        assert_eq!(
+            failure.events[0].get("namespace").cloned(),
+            Some(Value::from("app"))
+        );
+    }
+}
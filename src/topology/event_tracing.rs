@@ -0,0 +1,330 @@
+//! Tracing of individual events through the topology, to debug where they go.
+//!
+//! While a tracer is started, the pumps of every source check the events they emit against its
+//! condition, and attach a trace ID to the metadata of those matching it, up to the limit of the
+//! tracer. Every transform and sink the traced events then reach records a span for them, which is
+//! logged and sent to the tracer, and sinks record whether they delivered them in the end.
+
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    task::{Context, Poll},
+};
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use once_cell::sync::Lazy;
+use tokio::sync::mpsc;
+use tracing::Instrument;
+use vector_core::event::{
+    BatchNotifier, BatchStatus, Event, EventArray, EventFinalizer, EventMutRef, EventRef,
+};
+
+use super::TapOutput;
+use crate::{conditions::Condition, internal_events::TracedEventObserved};
+
+/// The started tracers.
+static TRACERS: Lazy<Mutex<Vec<Tracer>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// The number of started tracers, checked before taking the lock so that events are not looked at
+/// while nothing is being traced.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+static NEXT_TRACER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Where a traced event was seen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceStage {
+    /// The event was emitted by a source, which started the trace.
+    Emitted,
+
+    /// The event was received by a transform or a sink.
+    Received,
+
+    /// The event was delivered by a sink.
+    Delivered,
+
+    /// The event had a transient error in delivery by a sink.
+    Errored,
+
+    /// The event was rejected by a sink, or could not be delivered by it.
+    Rejected,
+}
+
+impl TraceStage {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Emitted => "emitted",
+            Self::Received => "received",
+            Self::Delivered => "delivered",
+            Self::Errored => "errored",
+            Self::Rejected => "rejected",
+        }
+    }
+}
+
+impl From<BatchStatus> for TraceStage {
+    fn from(status: BatchStatus) -> Self {
+        match status {
+            BatchStatus::Delivered => Self::Delivered,
+            BatchStatus::Errored => Self::Errored,
+            BatchStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
+/// A traced event, seen by a component.
+#[derive(Clone, Debug)]
+pub struct TraceSpan {
+    pub trace_id: u64,
+    pub component: TapOutput,
+    pub stage: TraceStage,
+    pub timestamp: DateTime<Utc>,
+    /// The event as the component saw it. Not set once the event has been finalized by a sink.
+    pub event: Option<Event>,
+}
+
+struct Tracer {
+    id: u64,
+    condition: Condition,
+    /// The number of events still to start a trace for.
+    remaining: usize,
+    traces: HashSet<u64>,
+    tx: mpsc::Sender<TraceSpan>,
+}
+
+/// A started tracer, which is a stream of the spans of the events it traces. Dropping it stops
+/// tracing.
+pub struct EventTracer {
+    id: u64,
+    rx: mpsc::Receiver<TraceSpan>,
+}
+
+impl Stream for EventTracer {
+    type Item = TraceSpan;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Drop for EventTracer {
+    fn drop(&mut self) {
+        let mut tracers = TRACERS.lock().expect("poisoned lock");
+        tracers.retain(|tracer| tracer.id != self.id);
+        ACTIVE.store(tracers.len(), Ordering::Relaxed);
+    }
+}
+
+/// Starts tracing the next `limit` events emitted by sources that match the condition. Spans are
+/// dropped when more than `buffer` of them are waiting to be read from the tracer.
+pub fn start(condition: Condition, limit: usize, buffer: usize) -> EventTracer {
+    let id = NEXT_TRACER_ID.fetch_add(1, Ordering::Relaxed);
+    let (tx, rx) = mpsc::channel(buffer);
+
+    let mut tracers = TRACERS.lock().expect("poisoned lock");
+    tracers.push(Tracer {
+        id,
+        condition,
+        remaining: limit,
+        traces: HashSet::new(),
+        tx,
+    });
+    ACTIVE.store(tracers.len(), Ordering::Relaxed);
+
+    EventTracer { id, rx }
+}
+
+/// Starts a trace for each event emitted by the source that matches the condition of a tracer.
+pub(super) fn start_traces(component: &TapOutput, events: &mut EventArray) {
+    if ACTIVE.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let mut tracers = TRACERS.lock().expect("poisoned lock");
+    for mut event in events.iter_events_mut() {
+        if !tracers.iter().any(|tracer| tracer.remaining > 0) {
+            break;
+        }
+        if event.metadata().trace_id().is_some() {
+            continue;
+        }
+
+        let mut candidate = clone_event_mut(&event);
+        for tracer in tracers.iter_mut().filter(|tracer| tracer.remaining > 0) {
+            let (matched, checked) = tracer.condition.check(candidate);
+            candidate = checked;
+            if matched {
+                let trace_id = rand::random();
+                event.metadata_mut().set_trace_id(trace_id);
+                candidate.metadata_mut().set_trace_id(trace_id);
+                tracer.remaining -= 1;
+                tracer.traces.insert(trace_id);
+                tracer.send(component, trace_id, TraceStage::Emitted, Some(candidate));
+                break;
+            }
+        }
+    }
+}
+
+/// Records a span for each traced event seen by the component.
+pub(super) fn record(component: &TapOutput, stage: TraceStage, events: &EventArray) {
+    if ACTIVE.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    let tracers = TRACERS.lock().expect("poisoned lock");
+    for event in events.iter_events() {
+        if let Some(trace_id) = trace_id(&event) {
+            if let Some(tracer) = find_tracer(&tracers, trace_id) {
+                tracer.send(component, trace_id, stage, Some(clone_event(event)));
+            }
+        }
+    }
+}
+
+/// Records the final status of each traced event received by the sink, once it is finalized.
+pub(super) fn track_delivery(component: &TapOutput, events: &mut EventArray) {
+    if ACTIVE.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+
+    for mut event in events.iter_events_mut() {
+        let Some(trace_id) = event.metadata().trace_id() else {
+            continue;
+        };
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        event
+            .metadata_mut()
+            .add_finalizer(EventFinalizer::new(batch));
+
+        let component = component.clone();
+        tokio::spawn(
+            async move {
+                let stage = TraceStage::from(receiver.await);
+                let tracers = TRACERS.lock().expect("poisoned lock");
+                if let Some(tracer) = find_tracer(&tracers, trace_id) {
+                    tracer.send(&component, trace_id, stage, None);
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+impl Tracer {
+    fn send(&self, component: &TapOutput, trace_id: u64, stage: TraceStage, event: Option<Event>) {
+        emit!(TracedEventObserved {
+            trace_id,
+            stage: stage.as_str(),
+        });
+        // Tracing must never hold up the topology, so spans are dropped if the tracer is lagging.
+        _ = self.tx.try_send(TraceSpan {
+            trace_id,
+            component: component.clone(),
+            stage,
+            timestamp: Utc::now(),
+            event,
+        });
+    }
+}
+
+fn find_tracer(tracers: &[Tracer], trace_id: u64) -> Option<&Tracer> {
+    tracers
+        .iter()
+        .find(|tracer| tracer.traces.contains(&trace_id))
+}
+
+fn trace_id(event: &EventRef<'_>) -> Option<u64> {
+    match event {
+        EventRef::Log(log) => log.metadata().trace_id(),
+        EventRef::Metric(metric) => metric.metadata().trace_id(),
+        EventRef::Trace(trace) => trace.metadata().trace_id(),
+    }
+}
+
+fn clone_event(event: EventRef<'_>) -> Event {
+    match event {
+        EventRef::Log(log) => Event::Log(log.clone()),
+        EventRef::Metric(metric) => Event::Metric(metric.clone()),
+        EventRef::Trace(trace) => Event::Trace(trace.clone()),
+    }
+}
+
+fn clone_event_mut(event: &EventMutRef<'_>) -> Event {
+    match event {
+        EventMutRef::Log(log) => Event::Log((*log).clone()),
+        EventMutRef::Metric(metric) => Event::Metric((*metric).clone()),
+        EventMutRef::Trace(trace) => Event::Trace((*trace).clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use vector_core::event::{EventStatus, LogEvent};
+
+    use super::*;
+    use crate::{conditions::AnyCondition, config::OutputId};
+
+    fn component(id: &str, component_kind: &'static str) -> TapOutput {
+        TapOutput {
+            output_id: OutputId::from(id),
+            component_kind,
+            component_type: "test".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn traces_matching_events_up_to_the_limit() {
+        let source = component("event_tracing_test_source", "source");
+        let sink = component("event_tracing_test_sink", "sink");
+        // Other tests may be running topologies concurrently, so only the events of this one are
+        // traced.
+        let condition =
+            AnyCondition::String(r#"starts_with!(.message, "event_tracing_test")"#.into())
+                .build(&Default::default())
+                .unwrap();
+        let mut tracer = start(condition, 1, 10);
+
+        let mut events = EventArray::from(vec![
+            LogEvent::from("event_tracing_test first"),
+            LogEvent::from("event_tracing_test second"),
+        ]);
+        start_traces(&source, &mut events);
+        let traced = events
+            .iter_events()
+            .filter_map(|event| trace_id(&event))
+            .collect::<Vec<_>>();
+        assert_eq!(traced.len(), 1);
+
+        let span = tracer.next().await.unwrap();
+        assert_eq!(span.trace_id, traced[0]);
+        assert_eq!(span.component, source);
+        assert_eq!(span.stage, TraceStage::Emitted);
+        assert_eq!(
+            span.event.unwrap().as_log()["message"],
+            "event_tracing_test first".into()
+        );
+
+        record(&sink, TraceStage::Received, &events);
+        let span = tracer.next().await.unwrap();
+        assert_eq!(span.component, sink);
+        assert_eq!(span.stage, TraceStage::Received);
+
+        track_delivery(&sink, &mut events);
+        for event in events.iter_events_mut() {
+            event.metadata().update_status(EventStatus::Rejected);
+        }
+        drop(events);
+        let span = tracer.next().await.unwrap();
+        assert_eq!(span.component, sink);
+        assert_eq!(span.stage, TraceStage::Rejected);
+        assert!(span.event.is_none());
+    }
+}
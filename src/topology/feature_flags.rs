@@ -0,0 +1,316 @@
+//! Polling of the feature flags, and gating of the transforms configured with `enabled_when`.
+//!
+//! The flags are loaded before the topology is started or reloaded, so that transforms see the
+//! flags of their provider from their first event, and are then polled in the background for as
+//! long as the provider configuration doesn't change.
+
+use std::{collections::BTreeMap, future::ready, pin::Pin, sync::Mutex, time::Duration};
+
+use futures::{stream, Stream, StreamExt};
+use hyper::Body;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::{interval_at, Instant},
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use vector_vrl_functions::feature_enabled::{is_feature_enabled, set_feature_flags};
+
+use crate::{
+    config::{FeatureFlagsConfig, FeatureFlagsProvider, HttpFeatureFlagsProvider, ProxyConfig},
+    event::{Event, EventArray, EventContainer},
+    http::HttpClient,
+    internal_events::FeatureFlagsLoadError,
+    spawn_named,
+    tls::TlsSettings,
+    transforms::{SyncTransform, TaskTransform, Transform, TransformOutputsBuf},
+};
+
+/// The configuration of the running poller, and its task.
+static POLLER: Lazy<Mutex<Option<(FeatureFlagsConfig, JoinHandle<()>)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// The value of a flag, as returned by the provider.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FlagValue {
+    Enabled(bool),
+    Rollout { rollout_percentage: f64 },
+}
+
+/// Applies the feature flags configuration of a new or reloaded topology.
+///
+/// The flags are loaded once before returning, and then polled in the background. If the first
+/// load fails, the flags keep their previous state until the next successful poll.
+pub(super) async fn apply(config: Option<&FeatureFlagsConfig>) {
+    let previous = {
+        let mut poller = POLLER.lock().expect("poisoned lock");
+        if poller.as_ref().map(|(config, _)| config) == config {
+            return;
+        }
+        poller.take()
+    };
+    if let Some((_, handle)) = previous {
+        handle.abort();
+    }
+
+    let Some(config) = config.cloned() else {
+        set_feature_flags(BTreeMap::new());
+        return;
+    };
+
+    let hostname = crate::get_hostname().unwrap_or_default();
+    if let Err(error) = load(&config.provider, &hostname).await {
+        emit!(FeatureFlagsLoadError { error });
+    }
+
+    let period = Duration::from_secs(config.poll_interval_secs);
+    let provider = config.provider.clone();
+    let handle = spawn_named(
+        async move {
+            let mut interval = interval_at(Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                if let Err(error) = load(&provider, &hostname).await {
+                    emit!(FeatureFlagsLoadError { error });
+                }
+            }
+        },
+        "feature flags poller",
+    );
+
+    *POLLER.lock().expect("poisoned lock") = Some((config, handle));
+}
+
+async fn load(provider: &FeatureFlagsProvider, hostname: &str) -> crate::Result<()> {
+    let body = match provider {
+        FeatureFlagsProvider::File(file) => tokio::fs::read(&file.path).await?.into(),
+        FeatureFlagsProvider::Http(http) => fetch(http).await?,
+    };
+
+    if set_feature_flags(parse_flags(&body, hostname)?) {
+        info!("Feature flags updated.");
+    }
+    Ok(())
+}
+
+async fn fetch(provider: &HttpFeatureFlagsProvider) -> crate::Result<bytes::Bytes> {
+    let tls_settings = TlsSettings::from_options(&provider.tls)?;
+    let proxy = ProxyConfig::from_env().merge(&provider.proxy);
+    let client = HttpClient::<Body>::new(tls_settings, &proxy)?;
+
+    let mut builder = http::request::Builder::new().uri(provider.url.to_string());
+    for (header, value) in &provider.headers {
+        builder = builder.header(header.as_str(), value.as_str());
+    }
+    let request = builder.body(Body::empty())?;
+
+    let response = client.send(request).await?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Unexpected response status: {}", status).into());
+    }
+
+    Ok(hyper::body::to_bytes(response.into_body()).await?)
+}
+
+/// Parses the flags returned by a provider, resolving the rollouts for this host.
+fn parse_flags(body: &[u8], hostname: &str) -> crate::Result<BTreeMap<String, bool>> {
+    let flags: BTreeMap<String, FlagValue> = serde_json::from_slice(body)?;
+
+    Ok(flags
+        .into_iter()
+        .map(|(name, value)| {
+            let enabled = match value {
+                FlagValue::Enabled(enabled) => enabled,
+                FlagValue::Rollout { rollout_percentage } => {
+                    in_rollout(&name, hostname, rollout_percentage)
+                }
+            };
+            (name, enabled)
+        })
+        .collect())
+}
+
+/// Whether this host is part of the rollout of the flag.
+///
+/// Each host is consistently placed in or out of the rollout of each flag, so that increasing the
+/// percentage only ever enables the flag on more hosts.
+fn in_rollout(name: &str, hostname: &str, percentage: f64) -> bool {
+    let bucket = seahash::hash(format!("{}:{}", name, hostname).as_bytes()) % 10_000;
+    (bucket as f64) < percentage * 100.0
+}
+
+/// Gates a transform behind a feature flag.
+///
+/// While the flag is disabled, the events are forwarded to the default output of the transform
+/// untouched.
+pub(super) fn gate(transform: Transform, flag: String) -> Result<Transform, String> {
+    match transform {
+        Transform::Function(inner) => Ok(Transform::Synchronous(Box::new(GatedSyncTransform {
+            inner: Box::new(inner),
+            flag,
+        }))),
+        Transform::Synchronous(inner) => Ok(Transform::Synchronous(Box::new(GatedSyncTransform {
+            inner,
+            flag,
+        }))),
+        Transform::Task(inner) => Ok(Transform::Task(Box::new(GatedTaskTransform {
+            inner,
+            flag,
+        }))),
+        Transform::MultiOutputTask(_) => {
+            Err("`enabled_when` isn't supported by this transform".to_string())
+        }
+    }
+}
+
+#[derive(Clone)]
+struct GatedSyncTransform {
+    inner: Box<dyn SyncTransform>,
+    flag: String,
+}
+
+impl SyncTransform for GatedSyncTransform {
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        if is_feature_enabled(&self.flag) {
+            self.inner.transform(event, output);
+        } else {
+            output.push(event);
+        }
+    }
+
+    fn transform_all(&mut self, events: EventArray, output: &mut TransformOutputsBuf) {
+        if is_feature_enabled(&self.flag) {
+            self.inner.transform_all(events, output);
+        } else {
+            for event in events.into_events() {
+                output.push(event);
+            }
+        }
+    }
+}
+
+struct GatedTaskTransform {
+    inner: Box<dyn TaskTransform<EventArray>>,
+    flag: String,
+}
+
+impl TaskTransform<EventArray> for GatedTaskTransform {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn Stream<Item = EventArray> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = EventArray> + Send>> {
+        let Self { inner, flag } = *self;
+        let (bypass_tx, bypass_rx) = mpsc::unbounded_channel();
+
+        // The bypass is closed once the input ends, so that the output ends along with the inner
+        // transform.
+        let mut bypass = Some(bypass_tx);
+        let input = task
+            .map(Some)
+            .chain(stream::once(ready(None)))
+            .filter_map(move |events| {
+                let events = match events {
+                    Some(events) if is_feature_enabled(&flag) => Some(events),
+                    Some(events) => {
+                        if let Some(bypass) = &bypass {
+                            _ = bypass.send(events);
+                        }
+                        None
+                    }
+                    None => {
+                        bypass = None;
+                        None
+                    }
+                };
+                ready(events)
+            });
+
+        stream::select(
+            inner.transform(Box::pin(input)),
+            UnboundedReceiverStream::new(bypass_rx),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        config::{DataType, TransformOutput},
+        event::LogEvent,
+        transforms::{FunctionTransform, OutputBuffer},
+    };
+
+    #[derive(Clone)]
+    struct DropAll;
+
+    impl FunctionTransform for DropAll {
+        fn transform(&mut self, _output: &mut OutputBuffer, _event: Event) {}
+    }
+
+    #[test]
+    fn parses_flags() {
+        let body = br#"{"on": true, "off": false, "all": {"rollout_percentage": 100}, "none": {"rollout_percentage": 0}}"#;
+        let flags = parse_flags(body, "host").unwrap();
+
+        assert_eq!(
+            flags,
+            BTreeMap::from([
+                ("all".to_string(), true),
+                ("none".to_string(), false),
+                ("off".to_string(), false),
+                ("on".to_string(), true),
+            ])
+        );
+        assert!(parse_flags(br#"{"on": "yes"}"#, "host").is_err());
+    }
+
+    #[test]
+    fn rollout_is_consistent_per_host() {
+        let hosts = (0..1000).map(|i| format!("host-{}", i)).collect::<Vec<_>>();
+        let enabled = |percentage| {
+            hosts
+                .iter()
+                .filter(|host| in_rollout("flag", host, percentage))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        let quarter = enabled(25.0);
+        let half = enabled(50.0);
+        assert!((150..350).contains(&quarter.len()));
+        assert!(quarter.iter().all(|host| half.contains(host)));
+    }
+
+    #[test]
+    fn gated_transform_forwards_events_while_disabled() {
+        let flag = "gated_transform_forwards_events_while_disabled";
+        let Ok(Transform::Synchronous(mut transform)) =
+            gate(Transform::function(DropAll), flag.to_string())
+        else {
+            panic!("expected a synchronous transform");
+        };
+        let outputs = vec![TransformOutput::new(DataType::Log, HashMap::new())];
+        let event = Event::from(LogEvent::from("foo"));
+
+        let mut buf = TransformOutputsBuf::new_with_capacity(outputs.clone(), 1);
+        transform.transform(event.clone(), &mut buf);
+        assert_eq!(
+            buf.take_primary().into_events().collect::<Vec<_>>(),
+            vec![event.clone()]
+        );
+
+        set_feature_flags(BTreeMap::from([(flag.to_string(), true)]));
+        let mut buf = TransformOutputsBuf::new_with_capacity(outputs, 1);
+        transform.transform(event, &mut buf);
+        assert!(buf.take_primary().is_empty());
+        set_feature_flags(BTreeMap::new());
+    }
+}
@@ -10,11 +10,20 @@
 pub(super) use vector_core::fanout;
 pub mod schema;
 
+pub mod ack_backlog;
+pub mod buffer_control;
 pub mod builder;
 mod controller;
+pub(crate) mod dead_letter;
+pub mod delivery_failures;
+pub mod event_tracing;
+mod feature_flags;
+pub mod pause;
 mod ready_arrays;
 mod running;
+mod schedule;
 mod task;
+pub mod watchdog;
 
 #[cfg(test)]
 mod test;
@@ -109,6 +118,8 @@ pub async fn start_validated(
         return None;
     }
 
+    feature_flags::apply(config.feature_flags.as_ref()).await;
+
     let mut running_topology = RunningTopology::new(config, abort_tx.clone());
 
     if !running_topology
@@ -0,0 +1,157 @@
+//! Pausing of sources through the API.
+//!
+//! A paused source stops forwarding the events it produces, which applies backpressure to it, so
+//! that it stops pulling new data while the components downstream of it keep draining.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use once_cell::sync::Lazy;
+use snafu::Snafu;
+use tokio::sync::watch;
+
+use crate::config::ComponentKey;
+
+/// The paused state of each running source.
+static SOURCES: Lazy<Mutex<HashMap<ComponentKey, watch::Sender<bool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum PauseError {
+    #[snafu(display("No running source with ID {:?}", id))]
+    SourceNotFound { id: String },
+}
+
+/// Pauses the source, returning whether it was running.
+pub fn pause(key: &ComponentKey) -> Result<bool, PauseError> {
+    set_paused(key, true)
+}
+
+/// Resumes the source, returning whether it was paused.
+pub fn resume(key: &ComponentKey) -> Result<bool, PauseError> {
+    set_paused(key, false)
+}
+
+/// Returns whether the source is paused.
+pub fn is_paused(key: &ComponentKey) -> bool {
+    SOURCES
+        .lock()
+        .expect("poisoned lock")
+        .get(key)
+        .map_or(false, |paused| *paused.borrow())
+}
+
+fn set_paused(key: &ComponentKey, paused: bool) -> Result<bool, PauseError> {
+    let sources = SOURCES.lock().expect("poisoned lock");
+    let sender = sources.get(key).ok_or_else(|| PauseError::SourceNotFound {
+        id: key.id().to_string(),
+    })?;
+    let changed = sender.send_replace(paused) != paused;
+    if changed {
+        if paused {
+            info!(component_id = %key.id(), "Source paused.");
+        } else {
+            info!(component_id = %key.id(), "Source resumed.");
+        }
+    }
+    Ok(changed)
+}
+
+/// Registers a source, returning the signal its pumps wait on.
+pub(super) fn register(key: &ComponentKey) -> PauseSignal {
+    let paused = SOURCES
+        .lock()
+        .expect("poisoned lock")
+        .entry(key.clone())
+        .or_insert_with(|| watch::channel(false).0)
+        .subscribe();
+    PauseSignal(paused)
+}
+
+/// Resumes the pumps of a source that is being shut down to be rebuilt, so that the events it
+/// already produced are forwarded. The rebuilt source keeps its paused state.
+pub(super) fn release(key: &ComponentKey) {
+    if let Some(sender) = SOURCES.lock().expect("poisoned lock").get_mut(key) {
+        let paused = *sender.borrow();
+        *sender = watch::channel(paused).0;
+    }
+}
+
+/// Resumes the pumps of a source that is being shut down for good, and forgets it.
+pub(super) fn unregister(key: &ComponentKey) {
+    SOURCES.lock().expect("poisoned lock").remove(key);
+}
+
+#[derive(Clone)]
+pub(super) struct PauseSignal(watch::Receiver<bool>);
+
+impl PauseSignal {
+    /// Waits until the source is resumed, or released.
+    pub(super) async fn wait_until_resumed(&mut self) {
+        // Waiting fails once the source is released, which resumes it.
+        _ = self.0.wait_for(|paused| !paused).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn pauses_and_resumes_sources() {
+        let key = ComponentKey::from("pause_test_source");
+        let mut signal = register(&key);
+
+        assert!(!is_paused(&key));
+        assert_eq!(pause(&key), Ok(true));
+        assert_eq!(pause(&key), Ok(false));
+        assert!(is_paused(&key));
+        assert!(
+            timeout(Duration::from_millis(50), signal.wait_until_resumed())
+                .await
+                .is_err()
+        );
+
+        assert_eq!(resume(&key), Ok(true));
+        assert!(
+            timeout(Duration::from_millis(50), signal.wait_until_resumed())
+                .await
+                .is_ok()
+        );
+
+        unregister(&key);
+        assert_eq!(
+            pause(&key),
+            Err(PauseError::SourceNotFound {
+                id: "pause_test_source".to_owned()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn releases_rebuilt_sources() {
+        let key = ComponentKey::from("pause_test_release");
+        let mut old_signal = register(&key);
+        pause(&key).unwrap();
+
+        release(&key);
+        assert!(
+            timeout(Duration::from_millis(50), old_signal.wait_until_resumed())
+                .await
+                .is_ok()
+        );
+
+        // The rebuilt source is still paused.
+        let mut new_signal = register(&key);
+        assert!(is_paused(&key));
+        assert!(
+            timeout(Duration::from_millis(50), new_signal.wait_until_resumed())
+                .await
+                .is_err()
+        );
+        unregister(&key);
+    }
+}
@@ -22,11 +22,12 @@ use crate::{
     shutdown::SourceShutdownCoordinator,
     spawn_named,
     topology::{
-        build_or_log_errors, builder,
+        ack_backlog, buffer_control, build_or_log_errors, builder,
         builder::Pieces,
         fanout::{ControlChannel, ControlMessage},
-        handle_errors, retain, take_healthchecks,
+        feature_flags, handle_errors, pause, retain, take_healthchecks,
         task::TaskOutput,
+        watchdog::Watched,
         BuiltBuffer, TaskHandle, WatchRx, WatchTx,
     },
 };
@@ -88,6 +89,11 @@ impl RunningTopology {
         self.shutdown_coordinator.shutdown_tripwire()
     }
 
+    /// Returns whether all the sources of this topology are still running.
+    pub fn sources_running(&self) -> bool {
+        self.source_tasks.values().all(|task| !task.is_finished())
+    }
+
     /// Shut down all topology components.
     ///
     /// This function sends the shutdown signal to all sources in this topology
@@ -111,6 +117,15 @@ impl RunningTopology {
         // pump in self.tasks, and the other for source in self.source_tasks.
         let mut check_handles = HashMap::<ComponentKey, Vec<_>>::new();
 
+        // Paused sources are resumed, so that the events they already produced are forwarded.
+        for key in self.source_tasks.keys() {
+            pause::unregister(key);
+            ack_backlog::unregister(key);
+        }
+        for (key, _) in self.config.sinks() {
+            buffer_control::unregister(key);
+        }
+
         // We need to give some time to the sources to gracefully shutdown, so
         // we will merge them with other tasks.
         for (key, task) in self.tasks.into_iter().chain(self.source_tasks.into_iter()) {
@@ -224,6 +239,57 @@ impl RunningTopology {
         //
         // We also shutdown any component that is simply being removed entirely.
         let diff = ConfigDiff::new(&self.config, &new_config);
+        self.apply_diff(diff, new_config).await
+    }
+
+    /// Aborts the running task of a component, so that it can be rebuilt with
+    /// [`Self::rebuild_component`] once the returned task has exited.
+    ///
+    /// This is used by the watchdog to restart stalled components. A task blocked in a poll only
+    /// exits once that poll returns, which may be never.
+    pub(super) fn abort_component(&mut self, key: &ComponentKey) -> Option<TaskHandle> {
+        let task = if self.config.source(key).is_some() {
+            self.source_tasks.remove(key)
+        } else if self.config.transform(key).is_some() || self.config.sink(key).is_some() {
+            self.tasks.remove(key)
+        } else {
+            None
+        }?;
+
+        info!(message = "Restarting component.", component_id = %key);
+        task.abort();
+        Some(task)
+    }
+
+    /// Rebuilds a component in place, after its task was aborted by [`Self::abort_component`] and
+    /// has exited.
+    ///
+    /// The events held in memory by the component are lost. Returns whether the component was
+    /// rebuilt.
+    pub(super) async fn rebuild_component(&mut self, key: &ComponentKey) -> bool {
+        let mut diff = ConfigDiff::new(&self.config, &self.config);
+        let (tasks, difference) = if self.config.source(key).is_some() {
+            (&self.source_tasks, &mut diff.sources)
+        } else if self.config.transform(key).is_some() {
+            (&self.tasks, &mut diff.transforms)
+        } else if self.config.sink(key).is_some() {
+            (&self.tasks, &mut diff.sinks)
+        } else {
+            return false;
+        };
+        // The component may have been rebuilt by a reload since its task was aborted.
+        if tasks.contains_key(key) {
+            return false;
+        }
+
+        difference.to_change.insert(key.clone());
+        let config = self.config.clone();
+        matches!(self.apply_diff(diff, config).await, Ok(true))
+    }
+
+    /// Shuts down, rebuilds and spawns the components in the given configuration diff, restoring
+    /// the current configuration if that fails.
+    async fn apply_diff(&mut self, diff: ConfigDiff, new_config: Config) -> Result<bool, ()> {
         let buffers = self.shutdown_diff(&diff, &new_config).await;
 
         // Gives windows some time to make available any port
@@ -246,6 +312,7 @@ impl RunningTopology {
                 .run_healthchecks(&diff, &mut new_pieces, new_config.healthchecks)
                 .await
             {
+                feature_flags::apply(new_config.feature_flags.as_ref()).await;
                 self.connect_diff(&diff, &mut new_pieces).await;
                 self.spawn_diff(&diff, new_pieces);
                 self.config = new_config;
@@ -334,6 +401,8 @@ impl RunningTopology {
                 let previous = self.tasks.remove(key).unwrap();
                 drop(previous); // detach and forget
 
+                pause::unregister(key);
+                ack_backlog::unregister(key);
                 self.remove_outputs(key);
                 source_shutdown_handles
                     .push(self.shutdown_coordinator.shutdown_source(key, deadline));
@@ -342,6 +411,7 @@ impl RunningTopology {
             for key in &diff.sources.to_change {
                 debug!(component = %key, "Changing source.");
 
+                pause::release(key);
                 self.remove_outputs(key);
                 source_shutdown_handles
                     .push(self.shutdown_coordinator.shutdown_source(key, deadline));
@@ -424,7 +494,9 @@ impl RunningTopology {
             .to_change
             .iter()
             .filter(|&key| {
-                self.config.sink(key).unwrap().buffer == new_config.sink(key).unwrap().buffer
+                // The task of a restarted sink was aborted, along with its buffer.
+                self.tasks.contains_key(key)
+                    && self.config.sink(key).unwrap().buffer == new_config.sink(key).unwrap().buffer
             })
             .cloned()
             .collect::<HashSet<_>>();
@@ -439,7 +511,9 @@ impl RunningTopology {
         // First, we remove any inputs to removed sinks so they can naturally shut down.
         for key in &diff.sinks.to_remove {
             debug!(component = %key, "Removing sink.");
+            buffer_control::unregister(key);
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
         }
 
         // After that, for any changed sinks, we temporarily detach their inputs (not remove) so
@@ -468,6 +542,7 @@ impl RunningTopology {
                 buffer_tx.insert(key.clone(), self.inputs.get(key).unwrap().clone());
             }
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
         }
 
         // Now that we've disconnected or temporarily detached the inputs to all changed/removed
@@ -489,7 +564,10 @@ impl RunningTopology {
         let mut buffers = HashMap::<ComponentKey, BuiltBuffer>::new();
         for key in &diff.sinks.to_change {
             if wait_for_sinks.contains(key) {
-                let previous = self.tasks.remove(key).unwrap();
+                let Some(previous) = self.tasks.remove(key) else {
+                    // The task of a restarted sink was already aborted.
+                    continue;
+                };
                 debug!(message = "Waiting for sink to shutdown.", %key);
                 let buffer = previous.await.unwrap().unwrap();
 
@@ -533,7 +611,8 @@ impl RunningTopology {
             }
 
             for key in &diff.sinks.to_remove {
-                // Sinks only have inputs
+                // Sinks have inputs, and outputs only if they have a dead-letter output
+                self.outputs_tap_metadata.remove(key);
                 self.inputs_tap_metadata.remove(key);
             }
 
@@ -551,10 +630,26 @@ impl RunningTopology {
                 }
             }
 
+            for key in diff.sinks.changed_and_added() {
+                match new_pieces.tasks.get(key) {
+                    Some(task) if new_pieces.outputs.contains_key(key) => {
+                        self.outputs_tap_metadata
+                            .insert(key.clone(), ("sink", task.typetag().to_string()));
+                    }
+                    _ => {
+                        self.outputs_tap_metadata.remove(key);
+                    }
+                }
+            }
+
             for (key, input) in &new_pieces.inputs {
                 self.inputs_tap_metadata
                     .insert(key.clone(), input.1.clone());
             }
+
+            for (key, inputs) in &diff.rewired_sinks {
+                self.inputs_tap_metadata.insert(key.clone(), inputs.clone());
+            }
         }
 
         // We configure the outputs of any changed/added sources first, so they're available to any
@@ -571,6 +666,15 @@ impl RunningTopology {
             self.setup_outputs(key, new_pieces).await;
         }
 
+        // Sinks with a dead-letter output are configured last, as their output can feed into any
+        // transform or sink.
+        for key in diff.sinks.changed_and_added() {
+            if new_pieces.outputs.contains_key(key) {
+                debug!(component = %key, "Configuring dead-letter output for sink.");
+                self.setup_outputs(key, new_pieces).await;
+            }
+        }
+
         // Now that all possible outputs are configured, we can start wiring up inputs, starting
         // with transforms.
         for key in diff.transforms.changed_and_added() {
@@ -584,6 +688,12 @@ impl RunningTopology {
             self.setup_inputs(key, diff, new_pieces).await;
         }
 
+        // Sinks whose inputs are their only change keep running, so they're only reconnected.
+        for (key, inputs) in &diff.rewired_sinks {
+            debug!(component = %key, "Reconnecting inputs for sink.");
+            self.rewire_inputs(key, inputs, diff);
+        }
+
         // We do a final pass here to reconnect unchanged components.
         //
         // Why would we reconnect unchanged components?  Well, as sources and transforms will
@@ -620,6 +730,7 @@ impl RunningTopology {
 
             let mut removals = diff.sources.to_remove.clone();
             removals.extend(diff.transforms.to_remove.iter().cloned());
+            removals.extend(diff.sinks.to_remove.iter().cloned());
             self.watch
                 .0
                 .send(TapResource {
@@ -635,8 +746,8 @@ impl RunningTopology {
                         .changed_and_added()
                         .map(|key| key.to_string())
                         .collect(),
-                    // Note, only sources and transforms are relevant. Sinks do
-                    // not have outputs to tap.
+                    // Note, only sources, transforms, and sinks with a dead-letter
+                    // output have outputs to tap.
                     removals,
                 })
                 .expect("Couldn't broadcast config changes.");
@@ -750,6 +861,42 @@ impl RunningTopology {
         }
     }
 
+    /// Moves a running component from its current inputs to the given ones.
+    fn rewire_inputs(&mut self, key: &ComponentKey, inputs: &Inputs<OutputId>, diff: &ConfigDiff) {
+        let tx = self.inputs.get(key).cloned().expect("component is running");
+        let old_inputs = self
+            .config
+            .inputs_for_node(key)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let new_inputs = inputs.iter().cloned().collect::<HashSet<_>>();
+
+        for input in &old_inputs - &new_inputs {
+            // The outputs of changed or removed components were dropped along with their
+            // connection to this component.
+            if diff.contains(&input.component) {
+                continue;
+            }
+            if let Some(output) = self.outputs.get_mut(&input) {
+                debug!(component = %key, fanout_id = %input, "Removing component input from fanout.");
+
+                _ = output.send(ControlMessage::Remove(key.clone()));
+            }
+        }
+
+        for input in inputs.iter() {
+            // The outputs of changed or added components were recreated without any connection.
+            if diff.contains(&input.component) || !old_inputs.contains(input) {
+                debug!(component = %key, fanout_id = %input, "Adding component input to fanout.");
+
+                let output = self.outputs.get_mut(input).expect("unknown output");
+                _ = output.send(ControlMessage::Add(key.clone(), tx.clone()));
+            }
+        }
+    }
+
     fn reattach_severed_inputs(&mut self, diff: &ConfigDiff) {
         let unchanged_transforms = self
             .config
@@ -769,7 +916,7 @@ impl RunningTopology {
         let unchanged_sinks = self
             .config
             .sinks()
-            .filter(|(key, _)| !diff.sinks.contains(key));
+            .filter(|(key, _)| !diff.sinks.contains(key) && !diff.rewired_sinks.contains_key(key));
         for (sink_key, sink) in unchanged_sinks {
             let changed_outputs = get_changed_outputs(diff, sink.inputs.clone());
             for output_id in changed_outputs {
@@ -845,7 +992,9 @@ impl RunningTopology {
         }
 
         let task_name = format!(">> {} ({})", task.typetag(), task.id());
+        let typetag = task.typetag().to_owned();
         let task = handle_errors(task, self.abort_tx.clone()).instrument(task_span);
+        let task = self.watch_task(key, "sink", &typetag, task);
         let spawned = spawn_named(task, task_name.as_ref());
         if let Some(previous) = self.tasks.insert(key.clone(), spawned) {
             drop(previous); // detach and forget
@@ -882,7 +1031,9 @@ impl RunningTopology {
         }
 
         let task_name = format!(">> {} ({}) >>", task.typetag(), task.id());
+        let typetag = task.typetag().to_owned();
         let task = handle_errors(task, self.abort_tx.clone()).instrument(task_span);
+        let task = self.watch_task(key, "transform", &typetag, task);
         let spawned = spawn_named(task, task_name.as_ref());
         if let Some(previous) = self.tasks.insert(key.clone(), spawned) {
             drop(previous); // detach and forget
@@ -931,10 +1082,24 @@ impl RunningTopology {
 
         // Now spawn the actual source task.
         let source_task = new_pieces.source_tasks.remove(key).unwrap();
+        let typetag = source_task.typetag().to_owned();
         let source_task = handle_errors(source_task, self.abort_tx.clone()).instrument(task_span);
+        let source_task = self.watch_task(key, "source", &typetag, source_task);
         self.source_tasks
             .insert(key.clone(), spawn_named(source_task, task_name.as_ref()));
     }
+
+    /// Wraps the task of a component so that the watchdog detects when it stalls, if enabled.
+    fn watch_task<F>(
+        &self,
+        key: &ComponentKey,
+        kind: &'static str,
+        typetag: &str,
+        task: F,
+    ) -> Watched<F> {
+        let enabled = self.config.global.watchdog.stall_timeout().is_some();
+        Watched::new(enabled, key, kind, typetag, task)
+    }
 }
 
 fn get_changed_outputs(diff: &ConfigDiff, output_ids: Inputs<OutputId>) -> Vec<OutputId> {
@@ -958,5 +1123,14 @@ fn get_changed_outputs(diff: &ConfigDiff, output_ids: Inputs<OutputId>) -> Vec<O
         );
     }
 
+    for sink_key in &diff.sinks.to_change {
+        changed_outputs.extend(
+            output_ids
+                .iter()
+                .filter(|id| &id.component == sink_key)
+                .cloned(),
+        );
+    }
+
     changed_outputs
 }
@@ -0,0 +1,136 @@
+//! Scheduling of the components restricted to windows of time.
+//!
+//! Sources with a `schedule` stop forwarding the events they produce outside of their windows,
+//! which applies backpressure to them, and sinks with a `schedule` stop taking events from their
+//! buffer.
+
+use chrono::{DateTime, NaiveDateTime, Timelike, Utc};
+use tokio::time::{sleep, Duration};
+use vector_common::TimeZone;
+
+use crate::config::ComponentScheduleOptions;
+
+pub(super) struct Schedule {
+    options: ComponentScheduleOptions,
+    timezone: TimeZone,
+}
+
+impl Schedule {
+    pub(super) fn new(options: &ComponentScheduleOptions, timezone: TimeZone) -> Option<Self> {
+        options.is_enabled().then(|| Self {
+            options: options.clone(),
+            timezone: options.timezone.unwrap_or(timezone),
+        })
+    }
+
+    fn local_time(&self, now: DateTime<Utc>) -> NaiveDateTime {
+        match self.timezone {
+            TimeZone::Local => now.with_timezone(&chrono::Local).naive_local(),
+            TimeZone::Named(tz) => now.with_timezone(&tz).naive_local(),
+        }
+    }
+
+    fn is_open(&self, now: DateTime<Utc>) -> bool {
+        let time = self.local_time(now);
+        let active = self.options.active.is_empty()
+            || self
+                .options
+                .active
+                .iter()
+                .any(|window| window.contains(time));
+        active
+            && !self
+                .options
+                .maintenance
+                .iter()
+                .any(|window| window.contains(time))
+    }
+
+    /// Waits until the component is within one of its windows.
+    pub(super) async fn wait_until_open(&self) {
+        if self.is_open(Utc::now()) {
+            return;
+        }
+
+        info!("Outside of the scheduled windows, pausing.");
+        loop {
+            // Windows open and close on minute boundaries.
+            let now = Utc::now();
+            let elapsed = Duration::new(u64::from(now.second()), now.nanosecond() % 1_000_000_000);
+            sleep(Duration::from_secs(60).saturating_sub(elapsed)).await;
+
+            if self.is_open(Utc::now()) {
+                break;
+            }
+        }
+        info!("Within a scheduled window, resuming.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+
+    use super::*;
+    use crate::config::ScheduleWindow;
+
+    fn schedule(options: ComponentScheduleOptions) -> Schedule {
+        Schedule::new(&options, TimeZone::parse("UTC").unwrap()).unwrap()
+    }
+
+    fn window(start: &str, end: &str) -> ScheduleWindow {
+        ScheduleWindow {
+            days: Vec::new(),
+            start: start.to_owned(),
+            end: end.to_owned(),
+        }
+    }
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 5, 1, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn disabled_without_windows() {
+        assert!(Schedule::new(&Default::default(), TimeZone::Local).is_none());
+    }
+
+    #[test]
+    fn maintenance_windows_override_active_windows() {
+        let schedule = schedule(ComponentScheduleOptions {
+            active: vec![window("00:00", "06:00")],
+            maintenance: vec![window("02:00", "03:00")],
+            timezone: None,
+        });
+
+        assert!(schedule.is_open(at(1)));
+        assert!(!schedule.is_open(at(2)));
+        assert!(schedule.is_open(at(3)));
+        assert!(!schedule.is_open(at(6)));
+    }
+
+    #[test]
+    fn runs_outside_maintenance_windows() {
+        let schedule = schedule(ComponentScheduleOptions {
+            active: Vec::new(),
+            maintenance: vec![window("02:00", "03:00")],
+            timezone: None,
+        });
+
+        assert!(schedule.is_open(at(1)));
+        assert!(!schedule.is_open(at(2)));
+    }
+
+    #[test]
+    fn uses_timezone_of_windows() {
+        let schedule = schedule(ComponentScheduleOptions {
+            active: vec![window("09:00", "17:00")],
+            maintenance: Vec::new(),
+            timezone: TimeZone::parse("Asia/Tokyo"),
+        });
+
+        // 09:00 in Tokyo is 00:00 UTC.
+        assert!(schedule.is_open(at(0)));
+        assert!(!schedule.is_open(at(9)));
+    }
+}
@@ -90,6 +90,18 @@ pub fn possible_definitions(
 
             definitions.append(&mut transform_definition);
         }
+
+        // If the input is the dead-letter output of a sink, the definitions of the sink's inputs
+        // pass through unchanged.
+        if let Some(inputs) = config.sink_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                possible_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -193,6 +205,16 @@ pub(super) fn expanded_definitions(
             // Append whatever number of additional pipelines we created to the existing
             // pipeline definitions.
             definitions.append(&mut transform_definition);
+
+        // The dead-letter output of a sink passes through the pipelines feeding into the sink.
+        } else if let Some(inputs) = config.sink_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                expanded_definitions(enrichment_tables.clone(), inputs, config, cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
         }
     }
 
@@ -283,6 +305,18 @@ pub(crate) fn input_definitions(
 
             definitions.append(&mut transform_definitions);
         }
+
+        // If the input is the dead-letter output of a sink we recurse to the upstream components
+        // of the sink, as the events it fails to deliver pass through unchanged.
+        if let Some(inputs) = config.sink_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                input_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -356,6 +390,12 @@ pub trait ComponentContainer {
         input_definitions: &[(OutputId, Definition)],
     ) -> Option<Vec<TransformOutput>>;
 
+    /// Gets the inputs of the sink with the given key, whose events pass through to its dead-letter
+    /// output.
+    fn sink_inputs(&self, _key: &ComponentKey) -> Option<&[OutputId]> {
+        None
+    }
+
     /// Gets the transform output for the given port.
     ///
     /// Returns Err(()) if there is no transform with the given key
@@ -421,6 +461,10 @@ impl ComponentContainer for Config {
         self.transform(key).map(|transform| &transform.inputs[..])
     }
 
+    fn sink_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]> {
+        self.sink(key).map(|sink| &sink.inputs[..])
+    }
+
     fn transform_outputs(
         &self,
         key: &ComponentKey,
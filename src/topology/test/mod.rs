@@ -250,7 +250,7 @@ async fn topology_remove_one_source() {
 
     let (mut in1, source1) = basic_source();
     let (mut in2, source2) = basic_source();
-    let (_out1, sink1) = basic_sink(10);
+    let (out1, sink1) = basic_sink(10);
 
     let mut config = Config::builder();
     config.add_source("in1", source1);
@@ -259,11 +259,10 @@ async fn topology_remove_one_source() {
 
     let (mut topology, _) = start_topology(config.build().unwrap(), false).await;
 
-    let (out1, sink1) = basic_sink(10);
-
+    // Only the inputs of the sink change, so it keeps running and is only disconnected from `in2`.
     let mut config = Config::builder();
     config.add_source("in1", basic_source().1);
-    config.add_sink("out1", &["in1"], sink1);
+    config.add_sink("out1", &["in1"], basic_sink(10).1);
 
     assert!(topology
         .reload_config_and_respawn(config.build().unwrap())
@@ -386,7 +385,7 @@ async fn topology_swap_source() {
 
     // Add source #1 as `in1`, and sink #1 as `out1`, with sink #1 attached to `in1`:
     let (mut in1, source1) = basic_source();
-    let (out1, sink1) = basic_sink(10);
+    let (out1, sink1) = basic_sink_with_data(10, "v1");
 
     let mut config = Config::builder();
     config.add_source("in1", source1);
@@ -396,7 +395,7 @@ async fn topology_swap_source() {
 
     // Now, create sink #2 and replace `out2` with it, and add source #2 as `in2`, attached to `out1`:
     let (mut in2, source2) = basic_source();
-    let (out2, sink2) = basic_sink(10);
+    let (out2, sink2) = basic_sink_with_data(10, "v2");
 
     let mut config = Config::builder();
     config.add_source("in2", source2);
@@ -432,6 +431,53 @@ async fn topology_swap_source() {
     assert_eq!(vec![event2], res2);
 }
 
+#[tokio::test]
+async fn topology_rewire_sink() {
+    trace_init();
+
+    // Add sources #1 and #2 as `in1` and `in2`, and sink #1 as `out1`, attached to `in1`:
+    let (mut in1, source1) = basic_source();
+    let (mut in2, source2) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_source("in2", source2);
+    config.add_sink("out1", &["in1"], sink1);
+
+    let (mut topology, _) = start_topology(config.build().unwrap(), false).await;
+
+    // Now, attach `out1` to `in2` instead, with an otherwise identical sink:
+    let (out2, sink2) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", basic_source().1);
+    config.add_source("in2", basic_source().1);
+    config.add_sink("out1", &["in2"], sink2);
+
+    assert!(topology
+        .reload_config_and_respawn(config.build().unwrap())
+        .await
+        .unwrap());
+
+    let event1 = Event::Log(LogEvent::from("this"));
+    let event2 = Event::Log(LogEvent::from("that"));
+
+    let h_out1 = tokio::spawn(out1.flat_map(into_event_stream).collect::<Vec<_>>());
+    let h_out2 = tokio::spawn(out2.flat_map(into_event_stream).collect::<Vec<_>>());
+    in1.send_event(event1.clone()).await.unwrap();
+    in2.send_event(event2.clone()).await.unwrap();
+
+    drop(in1);
+    drop(in2);
+    topology.stop().await;
+
+    // We should see that sink #1 was not rebuilt, since only its inputs changed, and that it now
+    // only receives the events sent to source #2:
+    assert_eq!(vec![event2], h_out1.await.unwrap());
+    assert_eq!(Vec::<Event>::new(), h_out2.await.unwrap());
+}
+
 #[tokio::test]
 async fn topology_swap_transform() {
     trace_init();
@@ -786,6 +832,7 @@ async fn topology_disk_buffer_flushes_on_idle() {
     );
     sink1_outer.buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: std::num::NonZeroU64::new(268435488).unwrap(),
+        max_age: None,
         when_full: WhenFull::DropNewest,
     });
     config.add_sink_outer("out1", sink1_outer);
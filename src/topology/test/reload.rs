@@ -187,6 +187,7 @@ async fn topology_disk_buffer_conflict() {
     let sink_key = ComponentKey::from("out");
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
+        max_age: None,
         when_full: WhenFull::Block,
     });
 
@@ -194,6 +195,7 @@ async fn topology_disk_buffer_conflict() {
     new_config.sinks[&sink_key].inner = prom_exporter_sink(address_1, 1).into();
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
+        max_age: None,
         when_full: WhenFull::Block,
     });
 
@@ -0,0 +1,390 @@
+//! Detection of stalled components.
+//!
+//! The tasks of the components are wrapped so that the watchdog knows when they are being polled,
+//! and when they were woken up without being polled since. A component is stalled when one of its
+//! polls blocks, or when it isn't polled after being woken up, for longer than the stall timeout.
+//! Components that are idle, waiting for events, are never considered stalled.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    thread,
+    time::{Duration, Instant},
+};
+
+use futures::{
+    stream::FuturesUnordered,
+    task::{waker_ref, ArcWake, AtomicWaker},
+    StreamExt,
+};
+use once_cell::sync::Lazy;
+use pin_project::pin_project;
+use tokio::sync::mpsc;
+
+use super::SharedTopologyController;
+use crate::{
+    config::ComponentKey,
+    internal_events::{ComponentRestarted, ComponentStallRecovered, ComponentStalled},
+};
+
+/// The liveness of the tasks of the running components.
+static TASKS: Lazy<Mutex<HashMap<ComponentKey, Arc<Liveness>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The origin of the timestamps of the liveness of the tasks.
+static EPOCH: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// Returns the milliseconds elapsed since `EPOCH`, offset by one so that zero means unset.
+fn now() -> u64 {
+    EPOCH.elapsed().as_millis() as u64 + 1
+}
+
+thread_local! {
+    /// The ID of the current thread in the kernel, used to capture its stack.
+    static THREAD_ID: u64 = current_thread_id();
+}
+
+#[cfg(target_os = "linux")]
+fn current_thread_id() -> u64 {
+    std::fs::read_link("/proc/thread-self")
+        .ok()
+        .and_then(|path| path.file_name()?.to_str()?.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+const fn current_thread_id() -> u64 {
+    0
+}
+
+/// Captures the kernel stack of a thread blocked in a poll, on a best effort basis.
+///
+/// The full stack is only readable with elevated privileges, otherwise this falls back to the
+/// function and the system call the thread is blocked in.
+#[cfg(target_os = "linux")]
+fn capture_stack(thread_id: u64) -> Option<String> {
+    let read = |name: &str| {
+        std::fs::read_to_string(format!("/proc/self/task/{}/{}", thread_id, name))
+            .ok()
+            .map(|content| content.trim().to_owned())
+            .filter(|content| !content.is_empty())
+    };
+
+    if thread_id == 0 {
+        return None;
+    }
+    read("stack").or_else(|| {
+        let wchan = read("wchan")?;
+        let syscall = read("syscall").unwrap_or_default();
+        Some(format!("wchan: {}, syscall: {}", wchan, syscall))
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+const fn capture_stack(_thread_id: u64) -> Option<String> {
+    None
+}
+
+/// The liveness of the task of a component.
+struct Liveness {
+    key: ComponentKey,
+    kind: &'static str,
+    typetag: String,
+    /// When the current poll started, if the task is being polled.
+    polling_since: AtomicU64,
+    /// When the task was woken up, if it wasn't polled since.
+    woken_since: AtomicU64,
+    /// The thread polling the task, or that last polled it.
+    thread_id: AtomicU64,
+    /// When the stall that was reported started, if the task is still stalled.
+    stalled_since: AtomicU64,
+    /// The waker of the task, woken up in turn when the task is.
+    waker: AtomicWaker,
+}
+
+impl Liveness {
+    /// Returns the reason and the start of the stall of the task, if it's stalled.
+    fn stall(&self, now: u64, timeout: u64) -> Option<(&'static str, u64)> {
+        let stalled = |since: u64| since != 0 && now.saturating_sub(since) >= timeout;
+
+        let polling_since = self.polling_since.load(Ordering::Acquire);
+        let woken_since = self.woken_since.load(Ordering::Acquire);
+        if stalled(polling_since) {
+            Some(("blocked_poll", polling_since))
+        } else if stalled(woken_since) {
+            Some(("not_polled", woken_since))
+        } else {
+            None
+        }
+    }
+}
+
+impl ArcWake for Liveness {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        _ = arc_self
+            .woken_since
+            .compare_exchange(0, now(), Ordering::AcqRel, Ordering::Relaxed);
+        arc_self.waker.wake();
+    }
+}
+
+/// The registration of a task with the watchdog, removed when the task is dropped.
+struct Registration(Arc<Liveness>);
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let mut tasks = TASKS.lock().expect("poisoned lock");
+        // The task may have been replaced by a rebuilt one already.
+        if tasks
+            .get(&self.0.key)
+            .map_or(false, |liveness| Arc::ptr_eq(liveness, &self.0))
+        {
+            tasks.remove(&self.0.key);
+        }
+    }
+}
+
+/// A task whose liveness is tracked by the watchdog, if it's enabled.
+#[pin_project]
+pub(super) struct Watched<F> {
+    #[pin]
+    inner: F,
+    registration: Option<Registration>,
+}
+
+impl<F> Watched<F> {
+    /// Wraps the task of a component, registering it with the watchdog if `enabled`.
+    pub(super) fn new(
+        enabled: bool,
+        key: &ComponentKey,
+        kind: &'static str,
+        typetag: &str,
+        inner: F,
+    ) -> Self {
+        let registration = enabled.then(|| {
+            let liveness = Arc::new(Liveness {
+                key: key.clone(),
+                kind,
+                typetag: typetag.to_owned(),
+                polling_since: AtomicU64::new(0),
+                woken_since: AtomicU64::new(0),
+                thread_id: AtomicU64::new(0),
+                stalled_since: AtomicU64::new(0),
+                waker: AtomicWaker::new(),
+            });
+            TASKS
+                .lock()
+                .expect("poisoned lock")
+                .insert(key.clone(), Arc::clone(&liveness));
+            Registration(liveness)
+        });
+
+        Self {
+            inner,
+            registration,
+        }
+    }
+}
+
+impl<F: Future> Future for Watched<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let Some(Registration(liveness)) = this.registration.as_ref() else {
+            return this.inner.poll(cx);
+        };
+
+        liveness.waker.register(cx.waker());
+        liveness.woken_since.store(0, Ordering::Release);
+        liveness
+            .thread_id
+            .store(THREAD_ID.with(|id| *id), Ordering::Relaxed);
+        liveness.polling_since.store(now(), Ordering::Release);
+
+        let waker = waker_ref(liveness);
+        let result = this.inner.poll(&mut Context::from_waker(&waker));
+
+        liveness.polling_since.store(0, Ordering::Release);
+        let stalled_since = liveness.stalled_since.swap(0, Ordering::AcqRel);
+        if stalled_since != 0 {
+            emit!(ComponentStallRecovered {
+                component_id: &liveness.key,
+                stalled_for: Duration::from_millis(now().saturating_sub(stalled_since)),
+            });
+        }
+
+        result
+    }
+}
+
+/// Returns the tasks that became stalled since the last check.
+fn newly_stalled(timeout: Duration) -> Vec<(Arc<Liveness>, &'static str, u64)> {
+    let now = now();
+    let timeout = timeout.as_millis() as u64;
+
+    TASKS
+        .lock()
+        .expect("poisoned lock")
+        .values()
+        .filter_map(|liveness| {
+            let (reason, since) = liveness.stall(now, timeout)?;
+            // Each stall is only reported once.
+            liveness
+                .stalled_since
+                .compare_exchange(0, since, Ordering::AcqRel, Ordering::Relaxed)
+                .ok()?;
+            Some((Arc::clone(liveness), reason, since))
+        })
+        .collect()
+}
+
+/// Checks the liveness of the tasks, until the watchdog is stopped.
+///
+/// This runs on a dedicated thread, so that stalls are still detected when all the threads of the
+/// runtime are blocked. The stalled components are sent to be restarted if `restart` is set.
+fn check(timeout: Duration, restart: bool, restart_tx: mpsc::UnboundedSender<ComponentKey>) {
+    let period = (timeout / 4).max(Duration::from_millis(100));
+    while !restart_tx.is_closed() {
+        thread::sleep(period);
+
+        for (liveness, reason, since) in newly_stalled(timeout) {
+            let stack = (reason == "blocked_poll")
+                .then(|| capture_stack(liveness.thread_id.load(Ordering::Relaxed)))
+                .flatten();
+            emit!(ComponentStalled {
+                component_id: &liveness.key,
+                component_kind: liveness.kind,
+                component_type: &liveness.typetag,
+                reason,
+                stalled_for: Duration::from_millis(now().saturating_sub(since)),
+                stack,
+            });
+
+            if restart {
+                _ = restart_tx.send(liveness.key.clone());
+            }
+        }
+    }
+}
+
+/// Runs the watchdog, if it's enabled, restarting the stalled components if configured to.
+pub async fn run(topology_controller: SharedTopologyController) {
+    // Global options can't change on reload, so they only need to be read once.
+    let options = topology_controller
+        .lock()
+        .await
+        .topology
+        .config()
+        .global
+        .watchdog
+        .clone();
+    let Some(timeout) = options.stall_timeout() else {
+        return;
+    };
+
+    info!(
+        message = "Watching for stalled components.",
+        stall_timeout_secs = timeout.as_secs(),
+        restart = options.restart,
+    );
+
+    let (restart_tx, mut restart_rx) = mpsc::unbounded_channel();
+    let checker = thread::Builder::new()
+        .name("vector-watchdog".to_owned())
+        .spawn(move || check(timeout, options.restart, restart_tx));
+    if let Err(error) = checker {
+        error!(message = "Failed to start the watchdog.", %error);
+        return;
+    }
+
+    // The components are only rebuilt once their aborted task has exited, which for a task blocked
+    // in a poll is only once that poll returns. The checker stops once the receiver is dropped,
+    // when the watchdog is stopped.
+    let mut aborted = FuturesUnordered::new();
+    loop {
+        tokio::select! {
+            Some(key) = restart_rx.recv() => {
+                let task = topology_controller
+                    .lock()
+                    .await
+                    .topology
+                    .abort_component(&key);
+                match task {
+                    Some(task) => aborted.push(async move {
+                        _ = task.await;
+                        key
+                    }),
+                    None => emit!(ComponentRestarted {
+                        component_id: &key,
+                        success: false,
+                    }),
+                }
+            }
+            Some(key) = aborted.next(), if !aborted.is_empty() => {
+                let success = topology_controller
+                    .lock()
+                    .await
+                    .topology
+                    .rebuild_component(&key)
+                    .await;
+                emit!(ComponentRestarted {
+                    component_id: &key,
+                    success,
+                });
+            }
+            else => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn detects_blocked_polls() {
+        let key = ComponentKey::from("watchdog_blocked");
+        let task = Watched::new(true, &key, "transform", "test", async {
+            thread::sleep(Duration::from_millis(300));
+        });
+        let handle = tokio::spawn(task);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        let stalled = newly_stalled(Duration::from_millis(100));
+        let (liveness, reason, _) = stalled
+            .iter()
+            .find(|(liveness, _, _)| liveness.key == key)
+            .expect("task should be stalled");
+        assert_eq!(*reason, "blocked_poll");
+        assert_ne!(liveness.stalled_since.load(Ordering::Relaxed), 0);
+        // The stall is only reported once.
+        assert!(newly_stalled(Duration::from_millis(100))
+            .iter()
+            .all(|(liveness, _, _)| liveness.key != key));
+
+        handle.await.unwrap();
+        assert!(!TASKS.lock().unwrap().contains_key(&key));
+    }
+
+    #[tokio::test]
+    async fn ignores_idle_tasks() {
+        let key = ComponentKey::from("watchdog_idle");
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(Watched::new(true, &key, "sink", "test", rx));
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        assert!(newly_stalled(Duration::from_millis(100))
+            .iter()
+            .all(|(liveness, _, _)| liveness.key != key));
+
+        tx.send(()).unwrap();
+        handle.await.unwrap().unwrap();
+    }
+}
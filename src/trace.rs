@@ -13,6 +13,7 @@ use futures_util::{future::ready, Stream, StreamExt};
 use lookup::event_path;
 use metrics_tracing_context::MetricsLayer;
 use once_cell::sync::OnceCell;
+use snafu::{ResultExt, Snafu};
 use tokio::sync::{
     broadcast::{self, Receiver, Sender},
     oneshot,
@@ -21,9 +22,10 @@ use tokio_stream::wrappers::BroadcastStream;
 use tracing::{Event, Subscriber};
 use tracing_limit::RateLimitedLayer;
 use tracing_subscriber::{
-    filter::LevelFilter,
+    filter::{LevelFilter, ParseError, Targets},
     layer::{Context, SubscriberExt},
     registry::LookupSpan,
+    reload,
     util::SubscriberInitExt,
     Layer,
 };
@@ -53,6 +55,69 @@ static SUBSCRIBERS: Mutex<Option<Vec<oneshot::Sender<Vec<LogEvent>>>>> =
 /// has been initialized.
 static SENDER: OnceCell<Sender<LogEvent>> = OnceCell::new();
 
+/// LEVELS holds the filter of the internal log events currently in effect, along with the function changing the filter
+/// of the layers emitting them. It's only set once Vector's subscriber has been installed as the global default.
+static LEVELS: Mutex<Option<Levels>> = Mutex::new(None);
+
+type ReloadFn = Box<dyn Fn(&Targets) -> Result<(), reload::Error> + Send>;
+
+struct Levels {
+    levels: String,
+    reload: ReloadFn,
+}
+
+#[derive(Debug, Snafu)]
+pub enum SetLevelsError {
+    #[snafu(display("Invalid log levels: {}", source))]
+    InvalidLevels { source: ParseError },
+
+    #[snafu(display("Logging hasn't been initialized"))]
+    NotInitialized,
+
+    #[snafu(display("Failed to change the log levels: {}", source))]
+    Reload { source: reload::Error },
+}
+
+/// Returns the filter for logging the crates of Vector at the given level, such as `debug`.
+pub fn default_levels(level: &str) -> String {
+    match level {
+        "off" => "off".to_owned(),
+        level => [
+            format!("vector={}", level),
+            format!("codec={}", level),
+            format!("vrl={}", level),
+            format!("file_source={}", level),
+            "tower_limit=trace".to_owned(),
+            format!("rdkafka={}", level),
+            format!("buffers={}", level),
+            format!("lapin={}", level),
+            format!("kube={}", level),
+        ]
+        .join(","),
+    }
+}
+
+/// Returns the filter of the internal log events currently in effect.
+pub fn levels() -> Option<String> {
+    LEVELS
+        .lock()
+        .expect("poisoned lock")
+        .as_ref()
+        .map(|levels| levels.levels.clone())
+}
+
+/// Changes the filter of the internal log events until Vector exits, returning the previous filter.
+///
+/// The filter has the same format as `VECTOR_LOG`, such as `vector=debug,rdkafka=info`.
+pub fn set_levels(levels: &str) -> Result<String, SetLevelsError> {
+    let targets = Targets::from_str(levels).context(InvalidLevelsSnafu)?;
+
+    let mut current = LEVELS.lock().expect("poisoned lock");
+    let current = current.as_mut().ok_or(SetLevelsError::NotInitialized)?;
+    (current.reload)(&targets).context(ReloadSnafu)?;
+    Ok(std::mem::replace(&mut current.levels, levels.to_owned()))
+}
+
 fn metrics_layer_enabled() -> bool {
     !matches!(std::env::var("DISABLE_INTERNAL_METRICS_TRACING_INTEGRATION"), Ok(x) if x == "true")
 }
@@ -65,9 +130,17 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
     let metrics_layer =
         metrics_layer_enabled().then(|| MetricsLayer::new().with_filter(LevelFilter::INFO));
 
+    // The filters are reloadable, so that the levels can be changed through the API.
+    let (broadcast_filter, broadcast_handle) = reload::Layer::new(fmt_filter.clone());
+    let (fmt_filter, fmt_handle) = reload::Layer::new(fmt_filter);
+    let reload: ReloadFn = Box::new(move |targets| {
+        broadcast_handle.reload(targets.clone())?;
+        fmt_handle.reload(targets.clone())
+    });
+
     let broadcast_layer = RateLimitedLayer::new(BroadcastLayer::new())
         .with_default_limit(internal_log_rate_limit)
-        .with_filter(fmt_filter.clone());
+        .with_filter(broadcast_filter);
 
     let subscriber = tracing_subscriber::registry()
         .with(metrics_layer)
@@ -100,7 +173,9 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
             RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
-        _ = subscriber.try_init();
+        if subscriber.try_init().is_ok() {
+            set_reload(levels, reload);
+        }
     } else {
         let formatter = tracing_subscriber::fmt::layer()
             .with_ansi(color)
@@ -113,10 +188,19 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
             RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
-        _ = subscriber.try_init();
+        if subscriber.try_init().is_ok() {
+            set_reload(levels, reload);
+        }
     }
 }
 
+fn set_reload(levels: &str, reload: ReloadFn) {
+    *LEVELS.lock().expect("poisoned lock") = Some(Levels {
+        levels: levels.to_owned(),
+        reload,
+    });
+}
+
 #[cfg(test)]
 pub fn reset_early_buffer() -> Option<Vec<LogEvent>> {
     get_early_buffer().replace(Vec::new())
@@ -5,15 +5,21 @@ use std::{
 };
 
 use async_stream::stream;
+use chrono::Utc;
 use futures::{Stream, StreamExt};
+use indexmap::IndexMap;
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
 
 use crate::{
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
-    event::{metric, Event, EventMetadata},
-    internal_events::{AggregateEventRecorded, AggregateFlushed, AggregateUpdateFailed},
+    event::{metric, Event, EventMetadata, LogEvent, Value},
+    internal_events::{
+        AggregateEventRecorded, AggregateFlushed, AggregateLogValueInvalid, AggregateUpdateFailed,
+        TemplateRenderingError,
+    },
     schema,
+    template::Template,
     transforms::{TaskTransform, Transform},
 };
 
@@ -27,12 +33,94 @@ pub struct AggregateConfig {
     /// During this time frame, metrics with the same series data (name, namespace, tags, and so on) are aggregated.
     #[serde(default = "default_interval_ms")]
     pub interval_ms: u64,
+
+    /// Metrics to aggregate from the values of a field of log events.
+    ///
+    /// When set, log events are also accepted, and the values of their fields are aggregated over
+    /// each interval into a metric per combination of name, namespace, and tags. Log events are not
+    /// emitted themselves.
+    #[serde(default)]
+    pub logs: Vec<LogAggregationConfig>,
+}
+
+/// Aggregation of the values of a field of log events into a metric.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LogAggregationConfig {
+    /// Name of the field in the event containing the value to aggregate.
+    ///
+    /// Events where the field is missing, or isn't a number, are ignored.
+    #[configurable(metadata(docs::examples = "duration_ms"))]
+    pub field: Template,
+
+    /// Overrides the name of the metric.
+    ///
+    /// If not specified, `field` is used as the name of the metric.
+    #[configurable(metadata(docs::examples = "http_request_duration_ms"))]
+    pub name: Option<Template>,
+
+    /// Sets the namespace for the metric.
+    pub namespace: Option<Template>,
+
+    /// Tags to apply to the metric.
+    ///
+    /// Values are aggregated separately for each combination of rendered tags.
+    #[configurable(metadata(docs::additional_props_description = "A metric tag."))]
+    #[configurable(metadata(docs::examples = "example_tags()"))]
+    pub tags: Option<IndexMap<String, Template>>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub mode: LogAggregationMode,
+
+    /// The quantiles to compute, when `mode` is `summary`.
+    #[serde(default = "default_quantiles")]
+    pub quantiles: Vec<f64>,
+
+    /// The upper limits of the buckets to count values in, when `mode` is `histogram`.
+    #[serde(default = "default_buckets")]
+    pub buckets: Vec<f64>,
+}
+
+/// The type of metric to aggregate the values into.
+///
+/// Either way, the minimum and maximum values are also emitted as gauges, named after the metric
+/// with a `_min` and `_max` suffix.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogAggregationMode {
+    /// A summary of the count, sum, and `quantiles` of the values.
+    #[default]
+    Summary,
+
+    /// A histogram of the count, sum, and number of values within each of `buckets`.
+    Histogram,
 }
 
 const fn default_interval_ms() -> u64 {
     10 * 1000
 }
 
+fn default_quantiles() -> Vec<f64> {
+    vec![0.5, 0.75, 0.9, 0.95, 0.99]
+}
+
+fn default_buckets() -> Vec<f64> {
+    vec![
+        0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0,
+        500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+    ]
+}
+
+fn example_tags() -> IndexMap<String, String> {
+    IndexMap::from([
+        ("method".to_owned(), "{{ method }}".to_owned()),
+        ("status".to_owned(), "{{ status }}".to_owned()),
+    ])
+}
+
 impl_generate_config_from_default!(AggregateConfig);
 
 #[async_trait::async_trait]
@@ -43,7 +131,11 @@ impl TransformConfig for AggregateConfig {
     }
 
     fn input(&self) -> Input {
-        Input::metric()
+        if self.logs.is_empty() {
+            Input::metric()
+        } else {
+            Input::new(DataType::Metric | DataType::Log)
+        }
     }
 
     fn outputs(
@@ -58,21 +150,88 @@ impl TransformConfig for AggregateConfig {
 
 type MetricEntry = (metric::MetricData, EventMetadata);
 
+/// The values of a field of log events recorded for a series, by index of their configuration.
+type LogEntry = (Vec<f64>, EventMetadata);
+
 #[derive(Debug)]
 pub struct Aggregate {
     interval: Duration,
     map: HashMap<metric::MetricSeries, MetricEntry>,
+    logs: Vec<LogAggregationConfig>,
+    log_map: HashMap<(usize, metric::MetricSeries), LogEntry>,
 }
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
+        for log in &config.logs {
+            if log
+                .quantiles
+                .iter()
+                .any(|quantile| !(0.0..=1.0).contains(quantile))
+            {
+                return Err("quantiles must be between 0.0 and 1.0".into());
+            }
+            if log.buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+                return Err("buckets must be in increasing order".into());
+            }
+        }
+
         Ok(Self {
             interval: Duration::from_millis(config.interval_ms),
             map: Default::default(),
+            logs: config.logs.clone(),
+            log_map: Default::default(),
         })
     }
 
     fn record(&mut self, event: Event) {
+        match event {
+            Event::Log(log) => self.record_log(log),
+            event => self.record_metric(event),
+        }
+
+        emit!(AggregateEventRecorded);
+    }
+
+    fn record_log(&mut self, log: LogEvent) {
+        let event = Event::Log(log);
+        for (index, config) in self.logs.iter().enumerate() {
+            let field = config.field.get_ref();
+            let value = match event.as_log().get(field) {
+                Some(Value::Integer(value)) => *value as f64,
+                Some(Value::Float(value)) => value.into_inner(),
+                Some(Value::Bytes(bytes)) => match String::from_utf8_lossy(bytes).parse::<f64>() {
+                    Ok(value) => value,
+                    Err(_) => {
+                        emit!(AggregateLogValueInvalid { field });
+                        continue;
+                    }
+                },
+                Some(_) => {
+                    emit!(AggregateLogValueInvalid { field });
+                    continue;
+                }
+                None => continue,
+            };
+
+            let Some(series) = render_series(config, &event) else {
+                continue;
+            };
+
+            match self.log_map.entry((index, series)) {
+                Entry::Occupied(mut entry) => {
+                    let existing = entry.get_mut();
+                    existing.0.push(value);
+                    existing.1.merge(event.metadata().clone());
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((vec![value], event.metadata().clone()));
+                }
+            }
+        }
+    }
+
+    fn record_metric(&mut self, event: Event) {
         let (series, data, metadata) = event.into_metric().into_parts();
 
         match data.kind {
@@ -96,8 +255,6 @@ impl Aggregate {
                 self.map.insert(series, (data, metadata));
             }
         };
-
-        emit!(AggregateEventRecorded);
     }
 
     fn flush_into(&mut self, output: &mut Vec<Event>) {
@@ -107,10 +264,120 @@ impl Aggregate {
             output.push(Event::Metric(metric));
         }
 
+        let log_map = std::mem::take(&mut self.log_map);
+        let timestamp = Some(Utc::now());
+        for ((index, series), (mut values, metadata)) in log_map.into_iter() {
+            let config = &self.logs[index];
+            values.sort_by(|a, b| a.total_cmp(b));
+            let count = values.len() as u64;
+            let sum: f64 = values.iter().sum();
+
+            let (kind, value) = match config.mode {
+                LogAggregationMode::Summary => (
+                    metric::MetricKind::Absolute,
+                    metric::MetricValue::AggregatedSummary {
+                        quantiles: config
+                            .quantiles
+                            .iter()
+                            .map(|&quantile| metric::Quantile {
+                                quantile,
+                                value: quantile_of_sorted(&values, quantile),
+                            })
+                            .collect(),
+                        count,
+                        sum,
+                    },
+                ),
+                LogAggregationMode::Histogram => {
+                    let samples = values
+                        .iter()
+                        .map(|&value| metric::Sample { value, rate: 1 })
+                        .collect::<Vec<_>>();
+                    let (buckets, count, sum) =
+                        metric::samples_to_buckets(&samples, &config.buckets);
+                    (
+                        metric::MetricKind::Incremental,
+                        metric::MetricValue::AggregatedHistogram {
+                            buckets,
+                            count,
+                            sum,
+                        },
+                    )
+                }
+            };
+
+            let gauges = [("_min", values[0]), ("_max", values[values.len() - 1])];
+            for (suffix, value) in gauges {
+                let name = format!("{}{}", series.name.name, suffix);
+                let gauge = metric::Metric::new_with_metadata(
+                    name,
+                    metric::MetricKind::Absolute,
+                    metric::MetricValue::Gauge { value },
+                    metadata.clone(),
+                )
+                .with_namespace(series.name.namespace.clone())
+                .with_tags(series.tags.clone())
+                .with_timestamp(timestamp);
+                output.push(Event::Metric(gauge));
+            }
+
+            let metric = metric::Metric::new_with_metadata(series.name.name, kind, value, metadata)
+                .with_namespace(series.name.namespace)
+                .with_tags(series.tags)
+                .with_timestamp(timestamp);
+            output.push(Event::Metric(metric));
+        }
+
         emit!(AggregateFlushed);
     }
 }
 
+/// Renders the series of the metric a log event's value is aggregated into.
+fn render_series(config: &LogAggregationConfig, event: &Event) -> Option<metric::MetricSeries> {
+    let render = |template: &Template, field: &str| {
+        template
+            .render_string(event)
+            .map_err(|error| {
+                emit!(TemplateRenderingError {
+                    error,
+                    drop_event: false,
+                    field: Some(field),
+                });
+            })
+            .ok()
+    };
+
+    let name = match &config.name {
+        Some(name) => render(name, "name")?,
+        None => config.field.get_ref().to_owned(),
+    };
+    let namespace = match &config.namespace {
+        Some(namespace) => Some(render(namespace, "namespace")?),
+        None => None,
+    };
+    let tags = match &config.tags {
+        Some(tags) => {
+            let mut rendered = metric::MetricTags::default();
+            for (key, template) in tags {
+                rendered.replace(key.clone(), render(template, key)?);
+            }
+            rendered.as_option()
+        }
+        None => None,
+    };
+
+    Some(metric::MetricSeries {
+        name: metric::MetricName { name, namespace },
+        tags,
+    })
+}
+
+/// Returns the nearest-rank quantile of the sorted, non-empty values.
+fn quantile_of_sorted(values: &[f64], quantile: f64) -> f64 {
+    let rank = (quantile * values.len() as f64).ceil() as usize;
+    values[rank.clamp(1, values.len()) - 1]
+}
+
 impl TaskTransform<Event> for Aggregate {
     fn transform(
         mut self: Box<Self>,
@@ -157,7 +424,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        event::{metric, Event, Metric},
+        event::{metric, Event, LogEvent, Metric, Value},
         test_util::components::assert_transform_compliance,
         transforms::test::create_topology,
     };
@@ -179,6 +446,7 @@ mod tests {
     fn incremental() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -249,6 +517,7 @@ mod tests {
     fn absolute() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -314,6 +583,7 @@ mod tests {
     fn conflicting_value_type() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -371,6 +641,7 @@ mod tests {
     fn conflicting_kinds() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -421,6 +692,140 @@ mod tests {
         assert_eq!(&summed, &out[0]);
     }
 
+    fn make_log(duration_ms: impl Into<Value>, method: &str) -> Event {
+        let mut log = LogEvent::from("request");
+        log.insert("duration_ms", duration_ms);
+        log.insert("method", method);
+        Event::Log(log)
+    }
+
+    fn find<'a>(out: &'a [Event], name: &str, method: &str) -> &'a metric::Metric {
+        out.iter()
+            .map(Event::as_metric)
+            .find(|metric| {
+                metric.name() == name
+                    && metric.tags().and_then(|tags| tags.get("method")) == Some(method)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn logs_summary() {
+        let config = toml::from_str::<AggregateConfig>(
+            r#"
+            [[logs]]
+            field = "duration_ms"
+            name = "request_duration_ms"
+            tags.method = "{{ method }}"
+            quantiles = [0.5, 0.99]
+            "#,
+        )
+        .unwrap();
+        let mut agg = Aggregate::new(&config).unwrap();
+
+        for duration_ms in 1..=100 {
+            agg.record(make_log(duration_ms, "GET"));
+        }
+        agg.record(make_log("7.5", "POST"));
+        agg.record(make_log("slow", "POST"));
+
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(6, out.len());
+
+        let summary = find(&out, "request_duration_ms", "GET");
+        assert_eq!(summary.kind(), metric::MetricKind::Absolute);
+        assert_eq!(
+            summary.value(),
+            &metric::MetricValue::AggregatedSummary {
+                quantiles: vec![
+                    metric::Quantile {
+                        quantile: 0.5,
+                        value: 50.0,
+                    },
+                    metric::Quantile {
+                        quantile: 0.99,
+                        value: 99.0,
+                    },
+                ],
+                count: 100,
+                sum: 5050.0,
+            }
+        );
+        assert_eq!(
+            find(&out, "request_duration_ms_min", "GET").value(),
+            &metric::MetricValue::Gauge { value: 1.0 }
+        );
+        assert_eq!(
+            find(&out, "request_duration_ms_max", "GET").value(),
+            &metric::MetricValue::Gauge { value: 100.0 }
+        );
+        assert_eq!(
+            find(&out, "request_duration_ms_max", "POST").value(),
+            &metric::MetricValue::Gauge { value: 7.5 }
+        );
+
+        // Values are only aggregated over a single interval.
+        out.clear();
+        agg.flush_into(&mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn logs_histogram() {
+        let config = toml::from_str::<AggregateConfig>(
+            r#"
+            [[logs]]
+            field = "duration_ms"
+            tags.method = "{{ method }}"
+            mode = "histogram"
+            buckets = [10.0, 100.0]
+            "#,
+        )
+        .unwrap();
+        let mut agg = Aggregate::new(&config).unwrap();
+
+        agg.record(make_log(5, "GET"));
+        agg.record(make_log(50, "GET"));
+        agg.record(make_log(60.0, "GET"));
+
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+
+        let histogram = find(&out, "duration_ms", "GET");
+        assert_eq!(histogram.kind(), metric::MetricKind::Incremental);
+        assert_eq!(
+            histogram.value(),
+            &metric::MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    metric::Bucket {
+                        upper_limit: 10.0,
+                        count: 1,
+                    },
+                    metric::Bucket {
+                        upper_limit: 100.0,
+                        count: 2,
+                    },
+                ],
+                count: 3,
+                sum: 115.0,
+            }
+        );
+    }
+
+    #[test]
+    fn logs_invalid_quantiles() {
+        let config = toml::from_str::<AggregateConfig>(
+            r#"
+            [[logs]]
+            field = "duration_ms"
+            quantiles = [1.5]
+            "#,
+        )
+        .unwrap();
+        assert!(Aggregate::new(&config).is_err());
+    }
+
     #[tokio::test]
     async fn transform_shutdown() {
         let agg = toml::from_str::<AggregateConfig>(
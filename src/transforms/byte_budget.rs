@@ -0,0 +1,399 @@
+use std::num::NonZeroUsize;
+
+use vector_config::configurable_component;
+use vector_core::{
+    config::{clone_input_definitions, LogNamespace},
+    EstimatedJsonEncodedSizeOf,
+};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::{ByteBudgetEventDropped, ByteBudgetExceeded, ByteBudgetFieldsTrimmed},
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// How a field is trimmed when an event exceeds its byte budget.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrimMode {
+    /// String fields are truncated by just enough bytes to fit the event within the budget.
+    ///
+    /// Fields that are not strings, or that are too short for truncation alone to be enough, are
+    /// removed.
+    #[default]
+    Truncate,
+
+    /// Fields are removed entirely.
+    Drop,
+}
+
+/// Configuration for the `byte_budget` transform.
+#[configurable_component(transform(
+    "byte_budget",
+    "Trim or drop lower-priority fields until events fit within a byte budget."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ByteBudgetConfig {
+    /// The maximum size of an event, in bytes.
+    ///
+    /// The size of an event is estimated as the size of its JSON encoding.
+    #[configurable(metadata(docs::examples = 262144))]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_bytes: NonZeroUsize,
+
+    /// The fields that may be trimmed, ordered from highest to lowest priority.
+    ///
+    /// Fields are trimmed starting from the end of the list, stopping as soon as the event fits
+    /// within `max_bytes`. Fields not in this list are never modified.
+    #[configurable(metadata(
+        docs::examples = "message",
+        docs::examples = "request.headers",
+        docs::examples = "stacktrace"
+    ))]
+    pub fields: Vec<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub mode: TrimMode,
+
+    /// The field in which the names of trimmed fields are recorded.
+    ///
+    /// When set, an array of the trimmed field names is inserted at this field for any event that
+    /// was modified. The size of this annotation counts towards the event's byte budget.
+    #[configurable(metadata(docs::examples = "_trimmed"))]
+    #[serde(default = "default_annotation_field")]
+    pub annotation_field: Option<String>,
+
+    /// Whether to drop events that still exceed `max_bytes` after every configured field has been
+    /// trimmed.
+    ///
+    /// When disabled, such events are forwarded as-is after trimming.
+    #[serde(default)]
+    pub drop_on_overflow: bool,
+}
+
+fn default_annotation_field() -> Option<String> {
+    Some("_trimmed".to_owned())
+}
+
+impl GenerateConfig for ByteBudgetConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            max_bytes: NonZeroUsize::new(262_144).unwrap(),
+            fields: vec!["message".to_owned()],
+            mode: TrimMode::default(),
+            annotation_field: default_annotation_field(),
+            drop_on_overflow: false,
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "byte_budget")]
+impl TransformConfig for ByteBudgetConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(ByteBudget::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(
+            DataType::Log,
+            clone_input_definitions(input_definitions),
+        )]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ByteBudget {
+    max_bytes: usize,
+    fields: Vec<String>,
+    mode: TrimMode,
+    annotation_field: Option<String>,
+    drop_on_overflow: bool,
+}
+
+impl ByteBudget {
+    pub fn new(config: &ByteBudgetConfig) -> Self {
+        Self {
+            max_bytes: config.max_bytes.get(),
+            fields: config.fields.clone(),
+            mode: config.mode,
+            annotation_field: config.annotation_field.clone(),
+            drop_on_overflow: config.drop_on_overflow,
+        }
+    }
+
+    /// Trims fields from `log`, lowest priority first, until it fits within the budget.
+    ///
+    /// Returns the names of the fields that were trimmed.
+    fn trim(&self, log: &mut LogEvent) -> Vec<Value> {
+        let mut trimmed = Vec::new();
+
+        for field in self.fields.iter().rev() {
+            if log.estimated_json_encoded_size_of() <= self.max_bytes {
+                break;
+            }
+            if !log.contains(field.as_str()) {
+                continue;
+            }
+
+            // Record the field before measuring, so that the annotation itself is accounted for
+            // when working out how much needs to be trimmed.
+            trimmed.push(Value::from(field.as_str()));
+            if let Some(annotation_field) = &self.annotation_field {
+                log.insert(annotation_field.as_str(), Value::Array(trimmed.clone()));
+            }
+
+            let size = log.estimated_json_encoded_size_of();
+            if size <= self.max_bytes {
+                break;
+            }
+            let overflow = size - self.max_bytes;
+
+            match (self.mode, log.get_mut(field.as_str())) {
+                (TrimMode::Truncate, Some(Value::Bytes(bytes))) if bytes.len() > overflow => {
+                    let mut end = bytes.len() - overflow;
+                    if let Ok(s) = std::str::from_utf8(bytes) {
+                        while !s.is_char_boundary(end) {
+                            end -= 1;
+                        }
+                    }
+                    bytes.truncate(end);
+                }
+                _ => {
+                    log.remove(field.as_str());
+                }
+            }
+        }
+
+        trimmed
+    }
+}
+
+impl FunctionTransform for ByteBudget {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+
+        let trimmed = self.trim(log);
+        if !trimmed.is_empty() {
+            emit!(ByteBudgetFieldsTrimmed {
+                count: trimmed.len()
+            });
+        }
+
+        let size = log.estimated_json_encoded_size_of();
+        if size > self.max_bytes {
+            if self.drop_on_overflow {
+                emit!(ByteBudgetEventDropped {
+                    size,
+                    max_bytes: self.max_bytes,
+                });
+                return;
+            }
+            emit!(ByteBudgetExceeded {
+                size,
+                max_bytes: self.max_bytes,
+            });
+        }
+
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::LogEvent,
+        test_util::components::assert_transform_compliance,
+        transforms::test::{create_topology, transform_one},
+    };
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ByteBudgetConfig>();
+    }
+
+    #[test]
+    fn passes_events_within_budget() {
+        let mut log = LogEvent::default();
+        log.insert("message", "hello");
+        log.insert("details", "world");
+        let event = Event::from(log);
+        let config = toml::from_str::<ByteBudgetConfig>(
+            r#"
+            max_bytes = 1024
+            fields = ["message", "details"]
+            "#,
+        )
+        .unwrap();
+        let mut transform = ByteBudget::new(&config);
+
+        let output = transform_one(&mut transform, event.clone()).unwrap();
+        assert_eq!(output, event);
+    }
+
+    #[test]
+    fn trims_lowest_priority_field_first() {
+        let mut log = LogEvent::default();
+        log.insert("message", "hello");
+        log.insert("details", "x".repeat(200));
+        let config = toml::from_str::<ByteBudgetConfig>(
+            r#"
+            max_bytes = 128
+            fields = ["message", "details"]
+            "#,
+        )
+        .unwrap();
+        let mut transform = ByteBudget::new(&config);
+
+        let output = transform_one(&mut transform, log.into()).unwrap();
+        let log = output.as_log();
+        assert!(log.estimated_json_encoded_size_of() <= 128);
+        assert_eq!(log["message"], "hello".into());
+        assert!(log["details"].as_bytes().unwrap().len() < 200);
+        assert_eq!(log["_trimmed"], Value::Array(vec!["details".into()]));
+    }
+
+    #[test]
+    fn drops_fields_in_drop_mode() {
+        let mut log = LogEvent::default();
+        log.insert("message", "hello");
+        log.insert("details", "x".repeat(200));
+        let config = toml::from_str::<ByteBudgetConfig>(
+            r#"
+            max_bytes = 128
+            fields = ["message", "details"]
+            mode = "drop"
+            "#,
+        )
+        .unwrap();
+        let mut transform = ByteBudget::new(&config);
+
+        let output = transform_one(&mut transform, log.into()).unwrap();
+        let log = output.as_log();
+        assert!(!log.contains("details"));
+        assert_eq!(log["message"], "hello".into());
+    }
+
+    #[test]
+    fn moves_to_higher_priority_fields_when_needed() {
+        let mut log = LogEvent::default();
+        log.insert("message", "m".repeat(200));
+        log.insert("details", "short");
+        let config = toml::from_str::<ByteBudgetConfig>(
+            r#"
+            max_bytes = 128
+            fields = ["message", "details"]
+            "#,
+        )
+        .unwrap();
+        let mut transform = ByteBudget::new(&config);
+
+        let output = transform_one(&mut transform, log.into()).unwrap();
+        let log = output.as_log();
+        assert!(log.estimated_json_encoded_size_of() <= 128);
+        assert!(!log.contains("details"));
+        assert_eq!(
+            log["_trimmed"],
+            Value::Array(vec!["details".into(), "message".into()])
+        );
+    }
+
+    #[test]
+    fn never_touches_unlisted_fields() {
+        let mut log = LogEvent::default();
+        log.insert("message", "m".repeat(200));
+        let event = Event::from(log);
+        let mut transform = ByteBudget::new(&ByteBudgetConfig {
+            max_bytes: NonZeroUsize::new(64).unwrap(),
+            fields: vec!["details".to_string()],
+            mode: TrimMode::Truncate,
+            annotation_field: None,
+            drop_on_overflow: false,
+        });
+
+        let output = transform_one(&mut transform, event.clone()).unwrap();
+        assert_eq!(output, event);
+    }
+
+    #[test]
+    fn drops_events_over_budget_when_configured() {
+        let mut log = LogEvent::default();
+        log.insert("message", "m".repeat(200));
+        let event = Event::from(log);
+        let config = toml::from_str::<ByteBudgetConfig>(
+            r#"
+            max_bytes = 64
+            fields = ["details"]
+            drop_on_overflow = true
+            "#,
+        )
+        .unwrap();
+        let mut transform = ByteBudget::new(&config);
+
+        assert!(transform_one(&mut transform, event).is_none());
+    }
+
+    #[test]
+    fn truncates_on_char_boundaries() {
+        let mut log = LogEvent::default();
+        log.insert("message", "é".repeat(100));
+        let event = Event::from(log);
+        let mut transform = ByteBudget::new(&ByteBudgetConfig {
+            max_bytes: NonZeroUsize::new(101).unwrap(),
+            fields: vec!["message".to_string()],
+            mode: TrimMode::Truncate,
+            annotation_field: None,
+            drop_on_overflow: false,
+        });
+
+        let output = transform_one(&mut transform, event).unwrap();
+        let message = output.as_log()["message"].as_bytes().unwrap().clone();
+        assert!(std::str::from_utf8(&message).is_ok());
+    }
+
+    #[tokio::test]
+    async fn emits_internal_events() {
+        assert_transform_compliance(async move {
+            let config = toml::from_str::<ByteBudgetConfig>(
+                r#"
+                max_bytes = 1024
+                fields = ["message"]
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let log = LogEvent::from("hello world");
+            tx.send(log.into()).await.unwrap();
+
+            _ = out.recv().await;
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+}
@@ -0,0 +1,173 @@
+use std::{hash::Hasher, sync::Arc, time::Duration};
+
+use seahash::SeaHasher;
+use tokio::sync::watch;
+
+use crate::internal_events::{ClusterDiscoveryError, ClusterMembershipChanged};
+
+/// The set of Vector instances that events are sharded across.
+///
+/// This instance is always a member, regardless of what discovery returns.
+#[derive(Debug, Eq, PartialEq)]
+pub(super) struct Membership {
+    peers: Vec<String>,
+}
+
+impl Membership {
+    pub(super) fn new(self_address: &str, discovered: impl IntoIterator<Item = String>) -> Self {
+        let mut peers = discovered.into_iter().collect::<Vec<_>>();
+        peers.push(self_address.to_owned());
+        peers.sort_unstable();
+        peers.dedup();
+        Self { peers }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    pub(super) fn contains(&self, peer: &str) -> bool {
+        self.peers
+            .binary_search_by(|p| p.as_str().cmp(peer))
+            .is_ok()
+    }
+
+    /// Returns the peer that owns `key`.
+    ///
+    /// Ownership is assigned by rendezvous hashing: every peer is scored against the key, and the
+    /// highest score wins. When a peer joins or leaves, only the keys that it gains or loses are
+    /// moved, and every instance with the same membership agrees on the owner of every key.
+    pub(super) fn owner(&self, key: &[u8]) -> &str {
+        self.peers
+            .iter()
+            .max_by_key(|peer| score(peer, key))
+            .expect("membership always contains this instance")
+    }
+}
+
+fn score(peer: &str, key: &[u8]) -> u64 {
+    // SeaHash uses fixed keys, so scores are the same on every instance and across restarts.
+    let mut hasher = SeaHasher::new();
+    hasher.write(peer.as_bytes());
+    hasher.write_u8(0xff);
+    hasher.write(key);
+    hasher.finish()
+}
+
+/// Discovers the peers of this instance.
+pub(super) struct Discovery {
+    pub(super) self_address: String,
+    pub(super) peers: Vec<String>,
+    pub(super) dns: Option<String>,
+}
+
+impl Discovery {
+    pub(super) async fn discover(&self) -> crate::Result<Membership> {
+        let mut peers = self.peers.clone();
+        if let Some(dns) = &self.dns {
+            let addresses = tokio::net::lookup_host(dns.as_str()).await?;
+            peers.extend(addresses.map(|address| address.to_string()));
+        }
+        Ok(Membership::new(&self.self_address, peers))
+    }
+
+    /// Periodically rediscovers peers, publishing any change in membership to `membership`.
+    ///
+    /// Runs until every receiver of `membership` has been dropped.
+    pub(super) async fn run(self, interval: Duration, membership: watch::Sender<Arc<Membership>>) {
+        loop {
+            tokio::time::sleep(interval).await;
+            if membership.is_closed() {
+                break;
+            }
+
+            match self.discover().await {
+                Ok(discovered) => {
+                    if discovered != **membership.borrow() {
+                        emit!(ClusterMembershipChanged {
+                            peers: discovered.len()
+                        });
+                        membership.send_replace(Arc::new(discovered));
+                    }
+                }
+                Err(error) => emit!(ClusterDiscoveryError { error }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn membership(peers: &[&str]) -> Membership {
+        Membership::new(peers[0], peers[1..].iter().map(|peer| peer.to_string()))
+    }
+
+    fn keys() -> impl Iterator<Item = String> {
+        (0..1000).map(|i| format!("key-{}", i))
+    }
+
+    #[test]
+    fn always_includes_self() {
+        let membership = Membership::new("10.0.0.1:6000", ["10.0.0.2:6000".to_string()]);
+        assert!(membership.contains("10.0.0.1:6000"));
+        assert!(membership.contains("10.0.0.2:6000"));
+        assert_eq!(membership.len(), 2);
+
+        let membership = Membership::new(
+            "10.0.0.1:6000",
+            ["10.0.0.1:6000".to_string(), "10.0.0.2:6000".to_string()],
+        );
+        assert_eq!(membership.len(), 2);
+    }
+
+    #[test]
+    fn owner_does_not_depend_on_discovery_order() {
+        let a = membership(&["10.0.0.1:6000", "10.0.0.2:6000", "10.0.0.3:6000"]);
+        let b = membership(&["10.0.0.3:6000", "10.0.0.2:6000", "10.0.0.1:6000"]);
+
+        for key in keys() {
+            assert_eq!(a.owner(key.as_bytes()), b.owner(key.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn spreads_keys_across_peers() {
+        let membership = membership(&["10.0.0.1:6000", "10.0.0.2:6000", "10.0.0.3:6000"]);
+
+        for peer in ["10.0.0.1:6000", "10.0.0.2:6000", "10.0.0.3:6000"] {
+            let owned = keys()
+                .filter(|key| membership.owner(key.as_bytes()) == peer)
+                .count();
+            assert!(owned > 200, "{} owns only {} keys", peer, owned);
+        }
+    }
+
+    #[test]
+    fn only_moves_keys_of_departed_peer() {
+        let before = membership(&["10.0.0.1:6000", "10.0.0.2:6000", "10.0.0.3:6000"]);
+        let after = membership(&["10.0.0.1:6000", "10.0.0.2:6000"]);
+
+        for key in keys() {
+            let owner = before.owner(key.as_bytes());
+            if owner != "10.0.0.3:6000" {
+                assert_eq!(owner, after.owner(key.as_bytes()));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn discovers_static_and_dns_peers() {
+        let discovery = Discovery {
+            self_address: "127.0.0.1:6000".to_owned(),
+            peers: vec!["10.0.0.2:6000".to_owned()],
+            dns: Some("localhost:6001".to_owned()),
+        };
+
+        let membership = discovery.discover().await.unwrap();
+        assert!(membership.contains("127.0.0.1:6000"));
+        assert!(membership.contains("10.0.0.2:6000"));
+        assert!(membership.contains("127.0.0.1:6001") || membership.contains("[::1]:6001"));
+    }
+}
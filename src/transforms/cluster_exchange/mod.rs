@@ -0,0 +1,442 @@
+use std::{collections::HashMap, pin::Pin, sync::Arc, time::Duration};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use serde_with::serde_as;
+use tokio::sync::{mpsc, watch};
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::Instrument;
+use vector_config::configurable_component;
+use vector_core::{
+    config::{clone_input_definitions, LogNamespace},
+    event::EventArray,
+};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, SinkConfig, SinkContext, SinkHealthcheckOptions,
+        TransformConfig, TransformContext, TransformOutput,
+    },
+    event::Event,
+    internal_events::{
+        ClusterDiscoveryError, ClusterEventsForwarded, ClusterForwarderError,
+        ClusterMembershipChanged, TemplateRenderingError,
+    },
+    schema,
+    sinks::vector::VectorConfig as VectorSinkConfig,
+    template::Template,
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+    transforms::{TaskTransform, Transform},
+};
+
+mod membership;
+
+use self::membership::{Discovery, Membership};
+
+/// The number of events buffered for each peer before forwarding applies backpressure.
+const FORWARD_BUFFER_SIZE: usize = 1000;
+
+/// Configuration for the `cluster_exchange` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "cluster_exchange",
+    "Shard events by key across a cluster of Vector instances."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterExchangeConfig {
+    /// The key that events are sharded by.
+    ///
+    /// All events with the same key are sent to the same instance. Events for which the key can't
+    /// be rendered are kept by this instance.
+    #[configurable(metadata(docs::examples = "{{ trace_id }}"))]
+    #[configurable(metadata(docs::examples = "{{ host }}-{{ file }}"))]
+    pub key: Template,
+
+    /// The address of this instance's `vector` source, as it appears to its peers.
+    ///
+    /// This must match the address that discovery returns for this instance, such as the pod IP
+    /// and port when discovering peers through a Kubernetes headless service.
+    #[configurable(metadata(docs::examples = "10.0.0.1:6000"))]
+    #[configurable(metadata(docs::examples = "${POD_IP}:6000"))]
+    pub self_address: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub discovery: DiscoveryConfig,
+
+    /// Whether or not to compress events forwarded to peers.
+    #[serde(default)]
+    pub compression: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub tls: Option<TlsEnableableConfig>,
+}
+
+/// How the peers of this instance are discovered.
+///
+/// The addresses of peers must point to their `vector` source.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DiscoveryConfig {
+    /// A static list of peer addresses.
+    #[configurable(metadata(docs::examples = "10.0.0.2:6000"))]
+    #[serde(default)]
+    pub peers: Vec<String>,
+
+    /// A host name and port that resolves to the addresses of every peer.
+    ///
+    /// Each address that the host name resolves to is a peer, listening on the given port.
+    #[configurable(metadata(
+        docs::examples = "vector-aggregator-headless.observability.svc.cluster.local:6000"
+    ))]
+    pub dns: Option<String>,
+
+    /// The interval between refreshes of the peer list, in seconds.
+    #[serde(default = "default_refresh_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    pub refresh_interval_secs: Duration,
+}
+
+const fn default_refresh_interval_secs() -> Duration {
+    Duration::from_secs(10)
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            peers: Vec::new(),
+            dns: None,
+            refresh_interval_secs: default_refresh_interval_secs(),
+        }
+    }
+}
+
+impl GenerateConfig for ClusterExchangeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"key = "{{ trace_id }}"
+            self_address = "10.0.0.1:6000"
+            discovery.peers = ["10.0.0.2:6000", "10.0.0.3:6000"]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "cluster_exchange")]
+impl TransformConfig for ClusterExchangeConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        // Validate the TLS settings up front, rather than when the first event is forwarded.
+        MaybeTlsSettings::from_config(&self.tls, false)?;
+
+        let discovery = Discovery {
+            self_address: self.self_address.clone(),
+            peers: self.discovery.peers.clone(),
+            dns: self.discovery.dns.clone(),
+        };
+        let membership = match discovery.discover().await {
+            Ok(membership) => membership,
+            Err(error) => {
+                // Peers might not be resolvable yet while the cluster is starting up, so start
+                // with the static peers and pick up the rest on the next refresh.
+                emit!(ClusterDiscoveryError { error });
+                Membership::new(&self.self_address, self.discovery.peers.clone())
+            }
+        };
+        emit!(ClusterMembershipChanged {
+            peers: membership.len()
+        });
+
+        let (membership_tx, membership_rx) = watch::channel(Arc::new(membership));
+        tokio::spawn(
+            discovery
+                .run(self.discovery.refresh_interval_secs, membership_tx)
+                .instrument(info_span!("cluster_exchange: discovery").or_current()),
+        );
+
+        let sink_context = SinkContext {
+            healthcheck: SinkHealthcheckOptions::default(),
+            globals: context.globals.clone(),
+            proxy: context.globals.proxy.clone(),
+            http_client: Default::default(),
+            schema: context.schema,
+        };
+
+        Ok(Transform::event_task(ClusterExchange {
+            key: self.key.clone(),
+            self_address: self.self_address.clone(),
+            compression: self.compression,
+            tls: self.tls.clone(),
+            sink_context,
+            membership: membership_rx,
+            forwarders: HashMap::new(),
+        }))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(
+            DataType::all(),
+            clone_input_definitions(input_definitions),
+        )]
+    }
+}
+
+pub struct ClusterExchange {
+    key: Template,
+    self_address: String,
+    compression: bool,
+    tls: Option<TlsEnableableConfig>,
+    sink_context: SinkContext,
+    membership: watch::Receiver<Arc<Membership>>,
+    /// The senders for events forwarded to each peer, created when first needed.
+    forwarders: HashMap<String, mpsc::Sender<Event>>,
+}
+
+impl ClusterExchange {
+    /// Routes `event` to the instance that owns its key, returning it if that's this instance.
+    async fn route(&mut self, event: Event) -> Option<Event> {
+        let key = match self.key.render(&event) {
+            Ok(key) => key,
+            Err(error) => {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("key"),
+                    drop_event: false,
+                });
+                return Some(event);
+            }
+        };
+
+        if self.membership.has_changed().unwrap_or(false) {
+            let membership = Arc::clone(&self.membership.borrow_and_update());
+            // Dropping the sender of a departed peer lets its sink flush and shut down.
+            self.forwarders.retain(|peer, _| membership.contains(peer));
+        }
+
+        let membership = Arc::clone(&self.membership.borrow());
+        let owner = membership.owner(&key);
+        if owner == self.self_address {
+            return Some(event);
+        }
+
+        let forwarder = match self.forwarders.get(owner) {
+            Some(forwarder) => forwarder.clone(),
+            None => match self.spawn_forwarder(owner).await {
+                Ok(forwarder) => {
+                    self.forwarders.insert(owner.to_owned(), forwarder.clone());
+                    forwarder
+                }
+                Err(error) => {
+                    emit!(ClusterForwarderError { peer: owner, error });
+                    return Some(event);
+                }
+            },
+        };
+
+        match forwarder.send(event).await {
+            Ok(()) => {
+                emit!(ClusterEventsForwarded {
+                    peer: owner,
+                    count: 1
+                });
+                None
+            }
+            Err(mpsc::error::SendError(event)) => {
+                emit!(ClusterForwarderError {
+                    peer: owner,
+                    error: "Forwarder stopped unexpectedly.".into(),
+                });
+                self.forwarders.remove(owner);
+                Some(event)
+            }
+        }
+    }
+
+    /// Starts a `vector` sink that forwards events to `peer`.
+    async fn spawn_forwarder(&self, peer: &str) -> crate::Result<mpsc::Sender<Event>> {
+        let config = VectorSinkConfig::for_peer(peer, self.compression, self.tls.clone());
+        let (sink, _healthcheck) = config.build(self.sink_context.clone()).await?;
+
+        let (tx, rx) = mpsc::channel(FORWARD_BUFFER_SIZE);
+        tokio::spawn(
+            async move {
+                let events = ReceiverStream::new(rx).map(EventArray::from);
+                if sink.run(events).await.is_err() {
+                    error!(message = "Forwarding to peer failed.");
+                }
+            }
+            .instrument(info_span!("cluster_exchange: forwarder", peer = %peer).or_current()),
+        );
+
+        Ok(tx)
+    }
+}
+
+impl TaskTransform<Event> for ClusterExchange {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        Box::pin(stream! {
+            while let Some(event) = input_rx.next().await {
+                if let Some(event) = me.route(event).await {
+                    yield event;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ClusterExchangeConfig>();
+    }
+
+    fn keys() -> Vec<String> {
+        (0..100).map(|i| format!("trace-{}", i)).collect()
+    }
+
+    async fn run(config: ClusterExchangeConfig, input: Vec<Event>) -> Vec<Event> {
+        let transform = config
+            .build(&TransformContext::default())
+            .await
+            .unwrap()
+            .into_task();
+        transform
+            .transform_events(Box::pin(futures::stream::iter(input)))
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn keeps_all_events_without_peers() {
+        let config = toml::from_str::<ClusterExchangeConfig>(
+            r#"
+            key = "{{ trace_id }}"
+            self_address = "127.0.0.1:6000"
+        "#,
+        )
+        .unwrap();
+
+        let input = keys()
+            .into_iter()
+            .map(|key| {
+                let mut log = LogEvent::from("message");
+                log.insert("trace_id", key);
+                Event::from(log)
+            })
+            .collect::<Vec<_>>();
+        let output = run(config, input.clone()).await;
+        assert_eq!(output, input);
+    }
+
+    #[tokio::test]
+    async fn keeps_events_without_key() {
+        let config = toml::from_str::<ClusterExchangeConfig>(
+            r#"
+            key = "{{ trace_id }}"
+            self_address = "127.0.0.1:6000"
+            discovery.peers = ["127.0.0.1:6001"]
+        "#,
+        )
+        .unwrap();
+
+        let input = vec![Event::from(LogEvent::from("message"))];
+        let output = run(config, input.clone()).await;
+        assert_eq!(output, input);
+    }
+
+    #[cfg(feature = "sources-vector")]
+    #[tokio::test]
+    async fn forwards_events_owned_by_peers() {
+        use crate::{
+            config::{SourceConfig, SourceContext},
+            event::Value,
+            sources::vector::VectorConfig as VectorSourceConfig,
+            test_util::{collect_ready, next_addr, wait_for_tcp},
+            SourceSender,
+        };
+
+        let peer = next_addr();
+        let (tx, rx) = SourceSender::new_test();
+        let source = VectorSourceConfig::from_address(peer)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(source);
+        wait_for_tcp(peer).await;
+
+        let self_address = "127.0.0.1:1".to_owned();
+        let config = toml::from_str::<ClusterExchangeConfig>(&format!(
+            r#"
+            key = "{{{{ trace_id }}}}"
+            self_address = "{}"
+            discovery.peers = ["{}"]
+        "#,
+            self_address, peer
+        ))
+        .unwrap();
+
+        let membership = Membership::new(&self_address, [peer.to_string()]);
+        let (kept, mut forwarded): (Vec<_>, Vec<_>) = keys()
+            .into_iter()
+            .partition(|key| membership.owner(key.as_bytes()) == self_address);
+        assert!(!kept.is_empty() && !forwarded.is_empty());
+
+        let input = keys()
+            .into_iter()
+            .map(|key| {
+                let mut log = LogEvent::from("message");
+                log.insert("trace_id", key);
+                Event::from(log)
+            })
+            .collect();
+        let output = run(config, input).await;
+        let output_keys = output
+            .iter()
+            .map(|event| event.as_log()["trace_id"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output_keys,
+            kept.into_iter().map(Value::from).collect::<Vec<_>>()
+        );
+
+        // Forwarders finish sending once the transform is dropped.
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let mut received_keys = collect_ready(rx)
+            .await
+            .iter()
+            .map(|event| {
+                String::from_utf8_lossy(event.as_log()["trace_id"].as_bytes().unwrap()).into_owned()
+            })
+            .collect::<Vec<_>>();
+        received_keys.sort();
+        forwarded.sort();
+        assert_eq!(received_keys, forwarded);
+    }
+}
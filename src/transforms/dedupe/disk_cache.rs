@@ -0,0 +1,169 @@
+//! A cache of the events seen by the `dedupe` transform, stored on disk.
+//!
+//! Entries are identified by a digest of their cache entry, and store when they were last seen.
+//! A second tree orders the entries by when they were last seen, so that the least recently seen
+//! ones can be evicted first. The cache is written to disk periodically, and when it is dropped.
+
+use std::{
+    num::NonZeroUsize,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::internal_events::DedupeCacheError;
+
+/// The length of an entry's digest.
+pub const DIGEST_LEN: usize = 32;
+
+pub struct DiskCache {
+    // The time an entry was last seen, by digest.
+    entries: sled::Tree,
+    // The digests of the entries, prefixed by the time they were last seen.
+    seen: sled::Tree,
+    len: usize,
+    capacity: usize,
+    ttl: Option<Duration>,
+}
+
+impl DiskCache {
+    pub fn open(path: &Path, capacity: NonZeroUsize, ttl: Option<Duration>) -> sled::Result<Self> {
+        let db = sled::open(path)?;
+        let entries = db.open_tree("entries")?;
+        let seen = db.open_tree("seen")?;
+        let len = entries.len();
+
+        let mut cache = Self {
+            entries,
+            seen,
+            len,
+            capacity: capacity.get(),
+            ttl,
+        };
+        // The capacity may have been lowered since the cache was last used.
+        cache.evict(now_millis())?;
+        Ok(cache)
+    }
+
+    /// Records the entry as seen now, returning whether it was already seen within the TTL.
+    pub fn insert(&mut self, digest: &[u8; DIGEST_LEN]) -> sled::Result<bool> {
+        let now = now_millis();
+
+        let previous = self.entries.insert(digest, now.to_be_bytes().as_ref())?;
+        let duplicate = match previous {
+            Some(previous) => {
+                let last_seen = decode_millis(&previous);
+                self.seen.remove(seen_key(last_seen, digest))?;
+                self.ttl.map_or(true, |ttl| {
+                    now.saturating_sub(last_seen) < ttl.as_millis() as u64
+                })
+            }
+            None => {
+                self.len += 1;
+                false
+            }
+        };
+        self.seen
+            .insert(seen_key(now, digest), sled::IVec::default())?;
+
+        self.evict(now)?;
+        Ok(duplicate)
+    }
+
+    /// Removes the least recently seen entries past the capacity, and the expired ones.
+    fn evict(&mut self, now: u64) -> sled::Result<()> {
+        while let Some((key, _)) = self.seen.first()? {
+            let last_seen = decode_millis(&key);
+            let expired = self.ttl.map_or(false, |ttl| {
+                now.saturating_sub(last_seen) >= ttl.as_millis() as u64
+            });
+            if self.len <= self.capacity && !expired {
+                break;
+            }
+
+            self.seen.remove(&key)?;
+            self.entries.remove(&key[8..])?;
+            self.len -= 1;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DiskCache {
+    fn drop(&mut self) {
+        if let Err(error) = self.entries.flush() {
+            emit!(DedupeCacheError { error });
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_millis() as u64)
+}
+
+fn decode_millis(bytes: &[u8]) -> u64 {
+    let mut millis = [0; 8];
+    millis.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(millis)
+}
+
+fn seen_key(millis: u64, digest: &[u8; DIGEST_LEN]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(8 + DIGEST_LEN);
+    key.extend_from_slice(&millis.to_be_bytes());
+    key.extend_from_slice(digest);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(byte: u8) -> [u8; DIGEST_LEN] {
+        [byte; DIGEST_LEN]
+    }
+
+    #[test]
+    fn detects_duplicates_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let capacity = NonZeroUsize::new(10).unwrap();
+
+        let mut cache = DiskCache::open(dir.path(), capacity, None).unwrap();
+        assert!(!cache.insert(&digest(1)).unwrap());
+        assert!(cache.insert(&digest(1)).unwrap());
+        drop(cache);
+
+        let mut cache = DiskCache::open(dir.path(), capacity, None).unwrap();
+        assert!(cache.insert(&digest(1)).unwrap());
+        assert!(!cache.insert(&digest(2)).unwrap());
+    }
+
+    #[test]
+    fn evicts_least_recently_seen() {
+        let dir = tempfile::tempdir().unwrap();
+        let capacity = NonZeroUsize::new(2).unwrap();
+
+        let mut cache = DiskCache::open(dir.path(), capacity, None).unwrap();
+        cache.insert(&digest(1)).unwrap();
+        std::thread::sleep(Duration::from_millis(2));
+        cache.insert(&digest(2)).unwrap();
+        std::thread::sleep(Duration::from_millis(2));
+        cache.insert(&digest(3)).unwrap();
+
+        assert_eq!(cache.len, 2);
+        assert!(!cache.insert(&digest(1)).unwrap());
+    }
+
+    #[test]
+    fn expires_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let capacity = NonZeroUsize::new(10).unwrap();
+        let ttl = Some(Duration::from_millis(10));
+
+        let mut cache = DiskCache::open(dir.path(), capacity, ttl).unwrap();
+        assert!(!cache.insert(&digest(1)).unwrap());
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(!cache.insert(&digest(1)).unwrap());
+        assert_eq!(cache.len, 1);
+    }
+}
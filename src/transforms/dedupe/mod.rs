@@ -1,22 +1,33 @@
-use std::{future::ready, num::NonZeroUsize, pin::Pin};
+use std::{
+    future::ready,
+    num::{NonZeroU64, NonZeroUsize},
+    path::{Path, PathBuf},
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use lru::LruCache;
+use sha2::{Digest, Sha256};
 use vector_config::configurable_component;
 use vector_core::config::{clone_input_definitions, LogNamespace};
 
 use crate::{
     config::{
-        log_schema, DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
-        TransformOutput,
+        log_schema, DataDirSubsystem, DataType, GenerateConfig, Input, OutputId, TransformConfig,
+        TransformContext, TransformOutput,
     },
     event::{Event, Value},
-    internal_events::DedupeEventsDropped,
+    internal_events::{DedupeCacheError, DedupeEventsDropped},
     schema,
     transforms::{TaskTransform, Transform},
 };
 
+mod disk_cache;
+
+use self::disk_cache::{DiskCache, DIGEST_LEN};
+
 /// Options to control what fields to match against.
 ///
 /// When no field matching configuration is specified, events are matched using the `timestamp`,
@@ -66,6 +77,21 @@ pub enum FieldMatchConfig {
 pub struct CacheConfig {
     /// Number of events to cache and use for comparing incoming events to previously seen events.
     pub num_events: NonZeroUsize,
+
+    /// The amount of time, in seconds, that an event is remembered after it was last seen.
+    ///
+    /// Events seen again after this amount of time are no longer considered duplicates. By
+    /// default, events are remembered until they are evicted from the cache.
+    #[configurable(metadata(docs::examples = 3600))]
+    #[serde(default)]
+    pub ttl_secs: Option<NonZeroU64>,
+
+    /// Whether to store the cache on disk rather than in memory.
+    ///
+    /// A persistent cache survives restarts, and can hold more events than would fit in memory.
+    /// It is stored in the `data_dir` directory, under the ID of the transform.
+    #[serde(default)]
+    pub persistent: bool,
 }
 
 /// Configuration for the `dedupe` transform.
@@ -80,11 +106,23 @@ pub struct DedupeConfig {
     #[configurable(derived)]
     #[serde(default = "default_cache_config")]
     pub cache: CacheConfig,
+
+    /// The directory used to persist the cache, when `cache.persistent` is enabled.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used. Make sure the running
+    /// user has write permissions to this directory.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/local/lib/vector/"))]
+    pub data_dir: Option<PathBuf>,
 }
 
 fn default_cache_config() -> CacheConfig {
     CacheConfig {
         num_events: NonZeroUsize::new(5000).expect("static non-zero number"),
+        ttl_secs: None,
+        persistent: false,
     }
 }
 
@@ -129,7 +167,14 @@ impl DedupeConfig {
 
 pub struct Dedupe {
     fields: FieldMatchConfig,
-    cache: LruCache<CacheEntry, bool>,
+    cache: Cache,
+    ttl: Option<Duration>,
+}
+
+/// The events seen so far, with when they were last seen.
+enum Cache {
+    Memory(LruCache<CacheEntry, Instant>),
+    Disk(DiskCache),
 }
 
 impl GenerateConfig for DedupeConfig {
@@ -137,6 +182,7 @@ impl GenerateConfig for DedupeConfig {
         toml::Value::try_from(Self {
             fields: None,
             cache: default_cache_config(),
+            data_dir: None,
         })
         .unwrap()
     }
@@ -145,8 +191,23 @@ impl GenerateConfig for DedupeConfig {
 #[async_trait::async_trait]
 #[typetag::serde(name = "dedupe")]
 impl TransformConfig for DedupeConfig {
-    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::event_task(Dedupe::new(self.clone())))
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let dedupe = if self.cache.persistent {
+            let key = context
+                .key
+                .as_ref()
+                .ok_or("a persistent cache requires the transform to have an ID")?;
+            let data_dir = context.globals.resolve_and_make_data_subdir_for(
+                DataDirSubsystem::Checkpoints,
+                self.data_dir.as_ref(),
+                key.id(),
+            )?;
+            Dedupe::new_persistent(self.clone(), &data_dir)?
+        } else {
+            Dedupe::new(self.clone())
+        };
+
+        Ok(Transform::event_task(dedupe))
     }
 
     fn input(&self) -> Input {
@@ -197,6 +258,41 @@ enum CacheEntry {
     Ignore(Vec<(String, TypeId, Bytes)>),
 }
 
+impl CacheEntry {
+    /// A digest of the entry, identifying it in the disk cache.
+    fn digest(&self) -> [u8; DIGEST_LEN] {
+        fn update_with_len(hasher: &mut Sha256, data: &[u8]) {
+            hasher.update((data.len() as u64).to_be_bytes());
+            hasher.update(data);
+        }
+
+        let mut hasher = Sha256::new();
+        match self {
+            CacheEntry::Match(fields) => {
+                hasher.update([0]);
+                for field in fields {
+                    match field {
+                        Some((type_id, data)) => {
+                            hasher.update([1, *type_id]);
+                            update_with_len(&mut hasher, data);
+                        }
+                        None => hasher.update([0]),
+                    }
+                }
+            }
+            CacheEntry::Ignore(fields) => {
+                hasher.update([1]);
+                for (field_name, type_id, data) in fields {
+                    update_with_len(&mut hasher, field_name.as_bytes());
+                    hasher.update([*type_id]);
+                    update_with_len(&mut hasher, data);
+                }
+            }
+        }
+        hasher.finalize().into()
+    }
+}
+
 /// Assigns a unique number to each of the types supported by Event::Value.
 const fn type_id_for_value(val: &Value) -> TypeId {
     match val {
@@ -218,13 +314,48 @@ impl Dedupe {
         let fields = config.fill_default_fields_match();
         Self {
             fields,
-            cache: LruCache::new(num_entries),
+            cache: Cache::Memory(LruCache::new(num_entries)),
+            ttl: config
+                .cache
+                .ttl_secs
+                .map(|ttl| Duration::from_secs(ttl.get())),
+        }
+    }
+
+    /// Creates a transform whose cache is stored on disk, in the given directory.
+    pub fn new_persistent(config: DedupeConfig, data_dir: &Path) -> crate::Result<Self> {
+        let fields = config.fill_default_fields_match();
+        let ttl = config
+            .cache
+            .ttl_secs
+            .map(|ttl| Duration::from_secs(ttl.get()));
+        let cache = DiskCache::open(data_dir, config.cache.num_events, ttl)?;
+        Ok(Self {
+            fields,
+            cache: Cache::Disk(cache),
+            ttl,
+        })
+    }
+
+    /// Records the event as seen, returning whether it is a duplicate.
+    fn insert(&mut self, cache_entry: CacheEntry) -> bool {
+        match &mut self.cache {
+            Cache::Memory(cache) => cache
+                .put(cache_entry, Instant::now())
+                .map_or(false, |last_seen| {
+                    self.ttl.map_or(true, |ttl| last_seen.elapsed() < ttl)
+                }),
+            Cache::Disk(cache) => cache.insert(&cache_entry.digest()).unwrap_or_else(|error| {
+                // Events are let through rather than dropped when the cache can't be used.
+                emit!(DedupeCacheError { error });
+                false
+            }),
         }
     }
 
     fn transform_one(&mut self, event: Event) -> Option<Event> {
         let cache_entry = build_cache_entry(&event, &self.fields);
-        if self.cache.put(cache_entry, true).is_some() {
+        if self.insert(cache_entry) {
             emit!(DedupeEventsDropped { count: 1 });
             None
         } else {
@@ -284,7 +415,7 @@ impl TaskTransform<Event> for Dedupe {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::BTreeMap;
+    use std::{collections::BTreeMap, num::NonZeroU64, time::Duration};
 
     use tokio::sync::mpsc;
     use tokio_stream::wrappers::ReceiverStream;
@@ -293,7 +424,7 @@ mod tests {
         event::{Event, LogEvent, Value},
         test_util::components::assert_transform_compliance,
         transforms::{
-            dedupe::{CacheConfig, DedupeConfig, FieldMatchConfig},
+            dedupe::{CacheConfig, Dedupe, DedupeConfig, FieldMatchConfig},
             test::create_topology,
         },
     };
@@ -307,7 +438,10 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
+                persistent: false,
             },
+            data_dir: None,
             fields: Some(FieldMatchConfig::MatchFields(fields)),
         }
     }
@@ -320,7 +454,10 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
+                persistent: false,
             },
+            data_dir: None,
             fields: Some(FieldMatchConfig::IgnoreFields(fields)),
         }
     }
@@ -654,4 +791,37 @@ mod tests {
         })
         .await;
     }
+
+    #[test]
+    fn dedupe_ttl() {
+        let mut transform_config = make_match_transform_config(5, vec!["matched".into()]);
+        transform_config.cache.ttl_secs = NonZeroU64::new(1);
+        let mut dedupe = Dedupe::new(transform_config);
+
+        let mut event = Event::Log(LogEvent::from("message"));
+        event.as_mut_log().insert("matched", "some value");
+
+        assert!(dedupe.transform_one(event.clone()).is_some());
+        assert!(dedupe.transform_one(event.clone()).is_none());
+
+        std::thread::sleep(Duration::from_millis(1100));
+        assert!(dedupe.transform_one(event).is_some());
+    }
+
+    #[test]
+    fn dedupe_persistent_across_restarts() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let mut transform_config = make_match_transform_config(5, vec!["matched".into()]);
+        transform_config.cache.persistent = true;
+
+        let mut event = Event::Log(LogEvent::from("message"));
+        event.as_mut_log().insert("matched", "some value");
+
+        let mut dedupe = Dedupe::new_persistent(transform_config.clone(), data_dir.path()).unwrap();
+        assert!(dedupe.transform_one(event.clone()).is_some());
+        drop(dedupe);
+
+        let mut dedupe = Dedupe::new_persistent(transform_config, data_dir.path()).unwrap();
+        assert!(dedupe.transform_one(event).is_none());
+    }
 }
@@ -0,0 +1,423 @@
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
+use lookup::lookup_v2::ConfigTargetPath;
+use lru::LruCache;
+use ordered_float::NotNan;
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::{DeltaEventsDropped, TemplateRenderingError},
+    schema,
+    template::Template,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// Configuration for the `delta` transform.
+#[configurable_component(transform(
+    "delta",
+    "Replace fields with their changes since the previous event with the same key."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct DeltaConfig {
+    /// The fields to compute the changes of.
+    ///
+    /// Numeric fields are replaced by their difference with the previous value. Object fields are
+    /// replaced by the entries that changed, with their new value, or `null` for the entries that
+    /// were removed. Other fields, and fields whose type changed, are kept whole when they change.
+    ///
+    /// Fields that didn't change are removed from the event.
+    #[configurable(metadata(docs::examples = "bytes_sent"))]
+    #[configurable(metadata(docs::examples = "status"))]
+    pub fields: Vec<ConfigTargetPath>,
+
+    /// The name of the key that events are compared within.
+    ///
+    /// Events with the same key are compared with each other, in order. If left unspecified, or if
+    /// the key fails to render, all events are compared with each other.
+    #[configurable(metadata(docs::examples = "{{ host }}", docs::examples = "{{ device_id }}"))]
+    pub key_field: Option<Template>,
+
+    /// The field that the sequence number of the event within its key is written to.
+    ///
+    /// Sequence numbers start at 1 and increase with each event forwarded for the key, so that
+    /// consumers can detect the events they missed.
+    #[serde(default = "default_sequence_field")]
+    #[configurable(metadata(docs::examples = "sequence"))]
+    pub sequence_field: ConfigTargetPath,
+
+    /// Whether to drop the events whose fields didn't change.
+    ///
+    /// The first event of each key is always forwarded, whole.
+    #[serde(default = "crate::serde::default_true")]
+    pub drop_unchanged: bool,
+
+    /// The maximum number of keys to remember the previous event of.
+    ///
+    /// When exceeded, the least recently seen key is forgotten, and its next event is forwarded
+    /// whole, as if it were the first.
+    #[serde(default = "default_max_keys")]
+    #[configurable(metadata(docs::type_unit = "keys"))]
+    pub max_keys: NonZeroUsize,
+}
+
+fn default_sequence_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("sequence".to_string()).expect("valid path")
+}
+
+fn default_max_keys() -> NonZeroUsize {
+    NonZeroUsize::new(5000).expect("static non-zero number")
+}
+
+impl GenerateConfig for DeltaConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"fields = ["bytes_sent", "status"]
+            key_field = "{{ host }}"
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "delta")]
+impl TransformConfig for DeltaConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(Delta::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let definition =
+                    self.fields
+                        .iter()
+                        .fold(definition.clone(), |definition, field| {
+                            // Numbers become differences, which may be floats, and objects may have
+                            // their removed entries set to `null`. Unchanged fields are removed.
+                            let mut kind = definition.kind_at(field).union(Kind::float());
+                            if kind.contains_object() {
+                                kind =
+                                    kind.union(Kind::object(Collection::from_unknown(Kind::any())));
+                            }
+                            definition.with_field(&field.0, kind.or_undefined(), None)
+                        });
+                let definition =
+                    definition.with_field(&self.sequence_field.0, Kind::integer(), None);
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+/// The previous values of the fields of a key.
+#[derive(Debug)]
+struct KeyState {
+    values: Vec<Option<Value>>,
+    sequence: i64,
+}
+
+#[derive(Debug)]
+pub struct Delta {
+    fields: Vec<ConfigTargetPath>,
+    key_field: Option<Template>,
+    sequence_field: ConfigTargetPath,
+    drop_unchanged: bool,
+    states: LruCache<Option<String>, KeyState>,
+}
+
+impl Clone for Delta {
+    fn clone(&self) -> Self {
+        // The state isn't shared between the copies of the transform.
+        Self {
+            fields: self.fields.clone(),
+            key_field: self.key_field.clone(),
+            sequence_field: self.sequence_field.clone(),
+            drop_unchanged: self.drop_unchanged,
+            states: LruCache::new(self.states.cap()),
+        }
+    }
+}
+
+impl Delta {
+    pub fn new(config: &DeltaConfig) -> Self {
+        Self {
+            fields: config.fields.clone(),
+            key_field: config.key_field.clone(),
+            sequence_field: config.sequence_field.clone(),
+            drop_unchanged: config.drop_unchanged,
+            states: LruCache::new(config.max_keys),
+        }
+    }
+
+    /// Replaces the fields of the event with their changes, returning the sequence number of the
+    /// event, or `None` if it should be dropped.
+    fn apply(&mut self, key: Option<String>, log: &mut LogEvent) -> Option<i64> {
+        let current = self
+            .fields
+            .iter()
+            .map(|field| log.get(field).cloned())
+            .collect::<Vec<_>>();
+
+        let Some(state) = self.states.get_mut(&key) else {
+            self.states.put(
+                key,
+                KeyState {
+                    values: current,
+                    sequence: 1,
+                },
+            );
+            return Some(1);
+        };
+
+        let mut changed = false;
+        for ((field, previous), current) in self.fields.iter().zip(&state.values).zip(&current) {
+            match (previous, current) {
+                (Some(previous), Some(current)) => match diff(previous, current) {
+                    Some(change) => {
+                        changed = true;
+                        log.insert(field, change);
+                    }
+                    None => {
+                        log.remove(field);
+                    }
+                },
+                (None, Some(_)) => changed = true,
+                (_, None) => {}
+            }
+        }
+        state.values = current;
+
+        if changed || !self.drop_unchanged {
+            state.sequence += 1;
+            Some(state.sequence)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the change from the previous to the current value, if any.
+fn diff(previous: &Value, current: &Value) -> Option<Value> {
+    match (previous, current) {
+        (Value::Integer(previous), Value::Integer(current)) => {
+            let delta = current.wrapping_sub(*previous);
+            (delta != 0).then_some(Value::Integer(delta))
+        }
+        (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+            let delta = as_f64(current) - as_f64(previous);
+            (delta != 0.0)
+                .then(|| NotNan::new(delta).ok())
+                .flatten()
+                .map(Value::Float)
+        }
+        (Value::Object(previous), Value::Object(current)) => {
+            let mut changes = BTreeMap::new();
+            for (key, value) in current {
+                match previous.get(key) {
+                    Some(previous) if previous == value => {}
+                    Some(previous @ Value::Object(_)) if value.is_object() => {
+                        if let Some(change) = diff(previous, value) {
+                            changes.insert(key.clone(), change);
+                        }
+                    }
+                    _ => {
+                        changes.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+            for key in previous.keys() {
+                if !current.contains_key(key) {
+                    changes.insert(key.clone(), Value::Null);
+                }
+            }
+            (!changes.is_empty()).then_some(Value::Object(changes))
+        }
+        (previous, current) => (previous != current).then(|| current.clone()),
+    }
+}
+
+fn as_f64(value: &Value) -> f64 {
+    match value {
+        Value::Integer(value) => *value as f64,
+        Value::Float(value) => value.into_inner(),
+        _ => unreachable!("only called on numbers"),
+    }
+}
+
+impl FunctionTransform for Delta {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let key = self.key_field.as_ref().and_then(|template| {
+            template
+                .render_string(&event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("key_field"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        });
+
+        let log = event.as_mut_log();
+        match self.apply(key, log) {
+            Some(sequence) => {
+                log.insert(&self.sequence_field, sequence);
+                output.push(event);
+            }
+            None => emit!(DeltaEventsDropped { count: 1 }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use value::btreemap;
+
+    use super::*;
+    use crate::transforms::test::transform_one;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<DeltaConfig>();
+    }
+
+    fn delta(config: &str) -> Delta {
+        Delta::new(&toml::from_str::<DeltaConfig>(config).unwrap())
+    }
+
+    #[test]
+    fn computes_changes() {
+        let mut transform = delta(r#"fields = ["count", "ratio", "status", "labels"]"#);
+
+        let first = transform_one(
+            &mut transform,
+            Event::from(LogEvent::from(Value::from(btreemap! {
+                "count" => 10,
+                "ratio" => 0.5,
+                "status" => "up",
+                "labels" => btreemap! { "a" => 1, "b" => 2 },
+                "other" => "kept",
+            }))),
+        )
+        .unwrap()
+        .into_log();
+        assert_eq!(first["count"], 10.into());
+        assert_eq!(first["sequence"], 1.into());
+
+        let second = transform_one(
+            &mut transform,
+            Event::from(LogEvent::from(Value::from(btreemap! {
+                "count" => 15,
+                "ratio" => 0.5,
+                "status" => "down",
+                "labels" => btreemap! { "a" => 1, "c" => 3 },
+                "other" => "kept",
+            }))),
+        )
+        .unwrap()
+        .into_log();
+        assert_eq!(second["count"], 5.into());
+        assert!(!second.contains("ratio"));
+        assert_eq!(second["status"], "down".into());
+        assert_eq!(
+            second["labels"],
+            Value::from(btreemap! { "b" => Value::Null, "c" => 3 })
+        );
+        assert_eq!(second["other"], "kept".into());
+        assert_eq!(second["sequence"], 2.into());
+    }
+
+    #[test]
+    fn drops_unchanged_events() {
+        let mut transform = delta(r#"fields = ["count"]"#);
+        let event = Event::from(LogEvent::from(Value::from(btreemap! { "count" => 1 })));
+
+        assert!(transform_one(&mut transform, event.clone()).is_some());
+        assert!(transform_one(&mut transform, event.clone()).is_none());
+
+        let mut transform = delta(
+            r#"
+            fields = ["count"]
+            drop_unchanged = false
+            "#,
+        );
+        assert!(transform_one(&mut transform, event.clone()).is_some());
+        let log = transform_one(&mut transform, event).unwrap().into_log();
+        assert!(!log.contains("count"));
+        assert_eq!(log["sequence"], 2.into());
+    }
+
+    #[test]
+    fn compares_within_keys() {
+        let mut transform = delta(
+            r#"
+            fields = ["count"]
+            key_field = "{{ host }}"
+            "#,
+        );
+
+        for (host, count, expected, sequence) in [
+            ("a", 1, 1, 1),
+            ("b", 5, 5, 1),
+            ("a", 3, 2, 2),
+            ("b", 4, -1, 2),
+        ] {
+            let log = transform_one(
+                &mut transform,
+                Event::from(LogEvent::from(Value::from(
+                    btreemap! { "host" => host, "count" => count },
+                ))),
+            )
+            .unwrap()
+            .into_log();
+            assert_eq!(log["count"], expected.into());
+            assert_eq!(log["sequence"], sequence.into());
+        }
+    }
+
+    #[test]
+    fn forgets_least_recent_keys() {
+        let mut transform = delta(
+            r#"
+            fields = ["count"]
+            key_field = "{{ host }}"
+            max_keys = 1
+            "#,
+        );
+
+        for (host, count, expected) in [("a", 1, 1), ("b", 2, 2), ("a", 3, 3)] {
+            let log = transform_one(
+                &mut transform,
+                Event::from(LogEvent::from(Value::from(
+                    btreemap! { "host" => host, "count" => count },
+                ))),
+            )
+            .unwrap()
+            .into_log();
+            assert_eq!(log["count"], expected.into());
+            assert_eq!(log["sequence"], 1.into());
+        }
+    }
+}
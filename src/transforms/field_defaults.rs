@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use lookup::{lookup_v2::ConfigTargetPath, OwnedTargetPath, PathPrefix};
+use value::Kind;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::TemplateRenderingError,
+    schema,
+    template::Template,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// Configuration for the `field_defaults` transform.
+#[configurable_component(transform(
+    "field_defaults",
+    "Fill in missing fields with defaults, by semantic meaning."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FieldDefaultsConfig {
+    /// The defaults to fill in, keyed by the semantic meaning of the field they fill in.
+    ///
+    /// The field with the meaning is looked up in the schema of each event, such that sinks
+    /// requiring the meaning accept the events forwarded by the transform.
+    #[configurable(metadata(
+        docs::additional_props_description = "The default of the field with the meaning."
+    ))]
+    #[configurable(metadata(docs::examples = "example_meanings()"))]
+    pub meanings: BTreeMap<String, FieldDefault>,
+}
+
+fn example_meanings() -> BTreeMap<String, FieldDefault> {
+    BTreeMap::from([
+        (
+            "host".to_owned(),
+            FieldDefault {
+                path: Some(ConfigTargetPath::try_from("host".to_owned()).expect("valid path")),
+                source: DefaultSource::Hostname,
+            },
+        ),
+        (
+            "severity".to_owned(),
+            FieldDefault {
+                path: Some(ConfigTargetPath::try_from("severity".to_owned()).expect("valid path")),
+                source: DefaultSource::Value {
+                    value: Template::try_from("info").expect("valid template"),
+                },
+            },
+        ),
+    ])
+}
+
+/// The default of a field with a semantic meaning.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct FieldDefault {
+    /// The field to fill in, for events whose schema doesn't give any field the meaning.
+    ///
+    /// If left unspecified, such events are forwarded unchanged.
+    #[configurable(metadata(docs::examples = "severity"))]
+    pub path: Option<ConfigTargetPath>,
+
+    #[serde(flatten)]
+    #[configurable(derived)]
+    pub source: DefaultSource,
+}
+
+/// Where the default of a field comes from.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "source", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "Where the default comes from."))]
+pub enum DefaultSource {
+    /// A string, rendered from a template.
+    ///
+    /// If the template fails to render, the field is left missing.
+    Value {
+        /// The template of the default.
+        #[configurable(metadata(docs::examples = "info", docs::examples = "{{ service }}"))]
+        value: Template,
+    },
+
+    /// The time the event is processed by the transform.
+    Now,
+
+    /// The hostname of the machine running Vector.
+    Hostname,
+
+    /// The value of another field of the event, or of its metadata.
+    ///
+    /// If the other field is missing too, the field is left missing.
+    Field {
+        /// The field to copy the default from.
+        #[configurable(metadata(docs::examples = "%kafka.timestamp"))]
+        field: ConfigTargetPath,
+    },
+}
+
+impl GenerateConfig for FieldDefaultsConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"[meanings.timestamp]
+            path = "timestamp"
+            source = "now"
+
+            [meanings.severity]
+            path = "severity"
+            source = "value"
+            value = "info"
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "field_defaults")]
+impl TransformConfig for FieldDefaultsConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(FieldDefaults::new(self)?))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let definition = self.meanings.iter().fold(
+                    definition.clone(),
+                    |definition, (meaning, default)| {
+                        let Some(path) = target_path(&definition, meaning, default) else {
+                            return definition;
+                        };
+                        let kind = match &default.source {
+                            DefaultSource::Value { value } if value.is_dynamic() => {
+                                Kind::bytes().or_undefined()
+                            }
+                            DefaultSource::Value { .. } | DefaultSource::Hostname => Kind::bytes(),
+                            DefaultSource::Now => Kind::timestamp(),
+                            DefaultSource::Field { field } => definition.kind_at(field),
+                        };
+                        let kind = definition.kind_at(&path).without_undefined().union(kind);
+                        definition.with_field(&path, kind, Some(meaning))
+                    },
+                );
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+/// Gets the field with the meaning in the definition, or the configured path if there is none.
+///
+/// Returns `None` if there is no field to fill in, or if the field can't be set because the root of
+/// the event, or of its metadata, isn't an object.
+fn target_path(
+    definition: &schema::Definition,
+    meaning: &str,
+    default: &FieldDefault,
+) -> Option<OwnedTargetPath> {
+    let path = definition
+        .meaning_path(meaning)
+        .cloned()
+        .or_else(|| default.path.as_ref().map(|path| path.0.clone()))?;
+    let root = match path.prefix {
+        PathPrefix::Event => definition.event_kind(),
+        PathPrefix::Metadata => definition.metadata_kind(),
+    };
+    (path.path.is_root() || root.as_object().is_some()).then_some(path)
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldDefaults {
+    meanings: BTreeMap<String, FieldDefault>,
+    hostname: Option<String>,
+}
+
+impl FieldDefaults {
+    pub fn new(config: &FieldDefaultsConfig) -> crate::Result<Self> {
+        let needs_hostname = config
+            .meanings
+            .values()
+            .any(|default| matches!(default.source, DefaultSource::Hostname));
+        let hostname = needs_hostname.then(crate::get_hostname).transpose()?;
+
+        Ok(Self {
+            meanings: config.meanings.clone(),
+            hostname,
+        })
+    }
+
+    /// Gets the default of the field, or `None` if there is none for the event.
+    fn default_value(&self, source: &DefaultSource, log: &LogEvent) -> Option<Value> {
+        match source {
+            DefaultSource::Value { value } => value
+                .render_string(log)
+                .map(Value::from)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("value"),
+                        drop_event: false,
+                    })
+                })
+                .ok(),
+            DefaultSource::Now => Some(Value::Timestamp(Utc::now())),
+            DefaultSource::Hostname => self.hostname.clone().map(Value::from),
+            DefaultSource::Field { field } => log.get(field).cloned(),
+        }
+    }
+}
+
+impl FunctionTransform for FieldDefaults {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+        for (meaning, default) in &self.meanings {
+            // The schema of the event is more specific than the merged schema of the inputs, as it
+            // only holds the fields of the source the event comes from.
+            let Some(path) = target_path(log.metadata().schema_definition(), meaning, default)
+            else {
+                continue;
+            };
+            if !matches!(log.get(&path), None | Some(Value::Null)) {
+                continue;
+            }
+            if let Some(value) = self.default_value(&default.source, log) {
+                log.insert(&path, value);
+            }
+        }
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use value::{btreemap, kind::Collection};
+    use vector_core::schema::Requirement;
+
+    use super::*;
+    use crate::transforms::test::transform_one;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<FieldDefaultsConfig>();
+    }
+
+    fn field_defaults(config: &str) -> FieldDefaults {
+        FieldDefaults::new(&toml::from_str::<FieldDefaultsConfig>(config).unwrap()).unwrap()
+    }
+
+    fn path(path: &str) -> OwnedTargetPath {
+        ConfigTargetPath::try_from(path.to_owned()).unwrap().0
+    }
+
+    #[test]
+    fn fills_in_field_with_meaning() {
+        let mut transform = field_defaults(
+            r#"
+            [meanings.severity]
+            path = "severity"
+            source = "value"
+            value = "info"
+            "#,
+        );
+        let definition = Arc::new(
+            schema::Definition::empty_legacy_namespace().with_event_field(
+                &path("level").path,
+                Kind::bytes().or_undefined(),
+                Some("severity"),
+            ),
+        );
+
+        let mut log = LogEvent::from(Value::from(btreemap! { "message" => "a" }));
+        log.metadata_mut().set_schema_definition(&definition);
+        let filled = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+        assert_eq!(filled["level"], "info".into());
+        assert!(!filled.contains("severity"));
+
+        let mut log = LogEvent::from(Value::from(btreemap! { "level" => "warn" }));
+        log.metadata_mut().set_schema_definition(&definition);
+        let kept = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+        assert_eq!(kept["level"], "warn".into());
+    }
+
+    #[test]
+    fn falls_back_to_path_without_meaning() {
+        let mut transform = field_defaults(
+            r#"
+            [meanings.timestamp]
+            path = "timestamp"
+            source = "field"
+            field = "%ingested_at"
+
+            [meanings.host]
+            source = "value"
+            value = "{{ device }}"
+            "#,
+        );
+        let mut log = LogEvent::from(Value::from(btreemap! { "device" => "d1" }));
+        log.metadata_mut()
+            .set_schema_definition(&Arc::new(schema::Definition::any()));
+        log.insert(&path("%ingested_at"), Value::Integer(1));
+
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+        assert_eq!(log["timestamp"], Value::Integer(1));
+        assert!(!log.contains("host"));
+    }
+
+    #[test]
+    fn outputs_definition_with_meanings() {
+        let config = toml::from_str::<FieldDefaultsConfig>(
+            r#"
+            [meanings.timestamp]
+            path = "timestamp"
+            source = "now"
+
+            [meanings.host]
+            source = "hostname"
+            "#,
+        )
+        .unwrap();
+        let input = schema::Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [LogNamespace::Vector],
+        );
+
+        let outputs = config.outputs(
+            enrichment::TableRegistry::default(),
+            &[(OutputId::dummy(), input)],
+            LogNamespace::Vector,
+        );
+        let definition = outputs[0].schema_definitions(true)[&OutputId::dummy()].clone();
+
+        assert_eq!(
+            definition.meaning_path("timestamp"),
+            Some(&path("timestamp"))
+        );
+        assert_eq!(definition.meaning_path("host"), None);
+        assert!(Requirement::empty()
+            .required_meaning("timestamp", Kind::timestamp())
+            .validate(&definition, true)
+            .is_ok());
+    }
+}
@@ -0,0 +1,414 @@
+use std::num::NonZeroU32;
+
+use lookup::lookup_v2::ConfigTargetPath;
+use value::Kind;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::Event,
+    internal_events::TemplateRenderingError,
+    schema,
+    template::Template,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// A named range of buckets.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct CohortConfig {
+    /// The name of the cohort, written to `cohort_field` for the events in its buckets.
+    #[configurable(metadata(docs::examples = "control"))]
+    pub name: String,
+
+    /// The first bucket of the cohort.
+    pub start: u32,
+
+    /// The last bucket of the cohort, inclusive.
+    pub end: u32,
+}
+
+/// Configuration for the `hash_bucket` transform.
+#[configurable_component(transform(
+    "hash_bucket",
+    "Assign events to stable hash buckets and cohorts, to split traffic consistently."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct HashBucketConfig {
+    /// The key that is hashed to assign events to buckets.
+    ///
+    /// Events with the same key are always assigned to the same bucket. Events whose key fails to
+    /// render are forwarded without a bucket.
+    #[configurable(metadata(docs::examples = "{{ user_id }}"))]
+    #[configurable(metadata(docs::examples = "{{ user_id }}-checkout-experiment"))]
+    pub key: Template,
+
+    /// The number of buckets.
+    ///
+    /// Events are assigned to the buckets from `0` to `buckets - 1`.
+    #[serde(default = "default_buckets")]
+    #[configurable(metadata(docs::examples = 1000))]
+    pub buckets: NonZeroU32,
+
+    /// The seed of the hash.
+    ///
+    /// Changing the seed reshuffles the events between the buckets, which gives independent
+    /// assignments to experiments using the same key.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = 42))]
+    pub seed: u32,
+
+    /// The field that the bucket of the event is written to.
+    #[serde(default = "default_bucket_field")]
+    #[configurable(metadata(docs::examples = "experiment.bucket"))]
+    pub bucket_field: ConfigTargetPath,
+
+    /// The field that the name of the cohort of the event is written to.
+    ///
+    /// Events whose bucket isn't part of any cohort are forwarded without a cohort.
+    #[serde(default = "default_cohort_field")]
+    #[configurable(metadata(docs::examples = "experiment.cohort"))]
+    pub cohort_field: ConfigTargetPath,
+
+    /// The cohorts, as named ranges of buckets.
+    ///
+    /// The ranges of the cohorts must be within the buckets, and must not overlap.
+    #[serde(default)]
+    pub cohorts: Vec<CohortConfig>,
+}
+
+fn default_buckets() -> NonZeroU32 {
+    NonZeroU32::new(100).expect("static non-zero number")
+}
+
+fn default_bucket_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("bucket".to_string()).expect("valid path")
+}
+
+fn default_cohort_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("cohort".to_string()).expect("valid path")
+}
+
+impl GenerateConfig for HashBucketConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"key = "{{ user_id }}"
+            buckets = 100
+            cohorts = [
+              { name = "control", start = 0, end = 49 },
+              { name = "treatment", start = 50, end = 99 },
+            ]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+impl HashBucketConfig {
+    /// Checks that the cohorts are within the buckets and don't overlap.
+    fn validate_cohorts(&self) -> crate::Result<()> {
+        let mut cohorts = self.cohorts.iter().collect::<Vec<_>>();
+        cohorts.sort_by_key(|cohort| cohort.start);
+
+        for cohort in &cohorts {
+            if cohort.start > cohort.end || cohort.end >= self.buckets.get() {
+                return Err(format!(
+                    "Cohort {:?} must range over buckets between 0 and {}.",
+                    cohort.name,
+                    self.buckets.get() - 1
+                )
+                .into());
+            }
+        }
+        for pair in cohorts.windows(2) {
+            if pair[1].start <= pair[0].end {
+                return Err(
+                    format!("Cohorts {:?} and {:?} overlap.", pair[0].name, pair[1].name).into(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "hash_bucket")]
+impl TransformConfig for HashBucketConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        self.validate_cohorts()?;
+        Ok(Transform::function(HashBucket::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let mut definition = definition.clone().with_field(
+                    &self.bucket_field.0,
+                    Kind::integer().or_undefined(),
+                    None,
+                );
+                if !self.cohorts.is_empty() {
+                    definition = definition.with_field(
+                        &self.cohort_field.0,
+                        Kind::bytes().or_undefined(),
+                        None,
+                    );
+                }
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+/// Computes the 32-bit MurmurHash3 of the data, as computed by the x86 variant of the reference
+/// implementation, so that the buckets match those computed by other systems from the same keys.
+fn murmur3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    let mix = |k: u32| k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+
+    let mut hash = seed;
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        let k = u32::from_le_bytes(block.try_into().expect("block of 4 bytes"));
+        hash ^= mix(k);
+        hash = hash
+            .rotate_left(13)
+            .wrapping_mul(5)
+            .wrapping_add(0xe654_6b64);
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .rev()
+            .fold(0, |k, &byte| (k << 8) | u32::from(byte));
+        hash ^= mix(k);
+    }
+
+    // The length is truncated to 32 bits, as in the reference implementation.
+    hash ^= data.len() as u32;
+    hash ^= hash >> 16;
+    hash = hash.wrapping_mul(0x85eb_ca6b);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(0xc2b2_ae35);
+    hash ^ (hash >> 16)
+}
+
+#[derive(Clone, Debug)]
+pub struct HashBucket {
+    key: Template,
+    buckets: u32,
+    seed: u32,
+    bucket_field: ConfigTargetPath,
+    cohort_field: ConfigTargetPath,
+    cohorts: Vec<CohortConfig>,
+}
+
+impl HashBucket {
+    fn new(config: &HashBucketConfig) -> Self {
+        Self {
+            key: config.key.clone(),
+            buckets: config.buckets.get(),
+            seed: config.seed,
+            bucket_field: config.bucket_field.clone(),
+            cohort_field: config.cohort_field.clone(),
+            cohorts: config.cohorts.clone(),
+        }
+    }
+
+    fn bucket(&self, key: &[u8]) -> u32 {
+        murmur3_32(key, self.seed) % self.buckets
+    }
+
+    fn cohort(&self, bucket: u32) -> Option<&str> {
+        self.cohorts
+            .iter()
+            .find(|cohort| (cohort.start..=cohort.end).contains(&bucket))
+            .map(|cohort| cohort.name.as_str())
+    }
+}
+
+impl FunctionTransform for HashBucket {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        match self.key.render(&event) {
+            Ok(key) => {
+                let bucket = self.bucket(&key);
+                let cohort = self.cohort(bucket).map(ToOwned::to_owned);
+                let log = event.as_mut_log();
+                log.insert(&self.bucket_field, i64::from(bucket));
+                if let Some(cohort) = cohort {
+                    log.insert(&self.cohort_field, cohort);
+                }
+            }
+            Err(error) => emit!(TemplateRenderingError {
+                error,
+                field: Some("key"),
+                drop_event: false,
+            }),
+        }
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+    use crate::{
+        event::Value,
+        test_util::components::assert_transform_compliance,
+        transforms::test::{create_topology, transform_one},
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<HashBucketConfig>();
+    }
+
+    #[test]
+    fn computes_murmur3() {
+        assert_eq!(murmur3_32(b"", 0), 0);
+        assert_eq!(murmur3_32(b"", 1), 0x514e_28b7);
+        assert_eq!(murmur3_32(b"abc", 0), 0xb3dd_93fa);
+        assert_eq!(murmur3_32(b"hello", 0), 0x248b_fa47);
+        assert_eq!(
+            murmur3_32(b"The quick brown fox jumps over the lazy dog", 0x9747_b28c),
+            0x2fa8_26cd
+        );
+    }
+
+    #[test]
+    fn assigns_stable_buckets_and_cohorts() {
+        let config = toml::from_str::<HashBucketConfig>(
+            r#"
+            key = "{{ user_id }}"
+            buckets = 100
+            cohorts = [
+              { name = "control", start = 0, end = 49 },
+              { name = "treatment", start = 50, end = 89 },
+            ]
+            "#,
+        )
+        .unwrap();
+        let mut transform = HashBucket::new(&config);
+
+        let mut seen_cohorts = Vec::new();
+        for user_id in ["alice", "bob", "carol", "dave", "erin", "frank"] {
+            let expected = i64::from(murmur3_32(user_id.as_bytes(), 0) % 100);
+            let mut input = LogEvent::from("request");
+            input.insert("user_id", user_id);
+            let input = Event::from(input);
+
+            let output = transform_one(&mut transform, input.clone()).unwrap();
+            let log = output.as_log();
+            assert_eq!(log["bucket"], Value::from(expected));
+            // The same key is always assigned to the same bucket.
+            let again = transform_one(&mut transform, input).unwrap();
+            assert_eq!(again.as_log()["bucket"], Value::from(expected));
+
+            let cohort = log.get("cohort").cloned();
+            match expected {
+                0..=49 => assert_eq!(cohort, Some("control".into())),
+                50..=89 => assert_eq!(cohort, Some("treatment".into())),
+                _ => assert_eq!(cohort, None),
+            }
+            seen_cohorts.push(cohort);
+        }
+        assert!(seen_cohorts.iter().any(Option::is_some));
+    }
+
+    #[test]
+    fn forwards_events_without_key() {
+        let config = toml::from_str::<HashBucketConfig>(
+            r#"
+            key = "{{ user_id }}"
+            "#,
+        )
+        .unwrap();
+        let mut transform = HashBucket::new(&config);
+
+        let input = Event::from(LogEvent::from("request"));
+        let output = transform_one(&mut transform, input.clone()).unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn rejects_invalid_cohorts() {
+        let out_of_range = toml::from_str::<HashBucketConfig>(
+            r#"
+            key = "{{ user_id }}"
+            buckets = 100
+            cohorts = [{ name = "all", start = 0, end = 100 }]
+            "#,
+        )
+        .unwrap();
+        assert!(out_of_range.validate_cohorts().is_err());
+
+        let overlapping = toml::from_str::<HashBucketConfig>(
+            r#"
+            key = "{{ user_id }}"
+            buckets = 100
+            cohorts = [
+              { name = "a", start = 50, end = 99 },
+              { name = "b", start = 0, end = 50 },
+            ]
+            "#,
+        )
+        .unwrap();
+        assert!(overlapping.validate_cohorts().is_err());
+    }
+
+    #[tokio::test]
+    async fn emits_internal_events() {
+        assert_transform_compliance(async {
+            let (tx, rx) = mpsc::channel(1);
+            let config = toml::from_str::<HashBucketConfig>(
+                r#"
+                key = "{{ user_id }}"
+                buckets = 100
+                seed = 7
+                "#,
+            )
+            .unwrap();
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut log = LogEvent::from("request");
+            log.insert("user_id", "alice");
+            tx.send(log.into()).await.unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(
+                output["bucket"],
+                Value::from(i64::from(murmur3_32(b"alice", 7) % 100))
+            );
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,546 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use futures::{stream, Stream, StreamExt};
+use indexmap::IndexMap;
+use serde_with::serde_as;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    conditions::{AnyCondition, Condition},
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{discriminant::Discriminant, Event, EventMetadata, LogEvent, Value},
+    internal_events::{JoinEventsDropped, JoinUnmatchedFlushed},
+    schema::Definition,
+    transforms::{TaskTransform, Transform},
+};
+
+/// A stream of events to join.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinStreamConfig {
+    /// The condition that events of the stream match.
+    pub condition: AnyCondition,
+}
+
+/// What to do with joins that are still incomplete when their window ends.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum UnmatchedStrategy {
+    /// Drop the events of the join.
+    #[default]
+    Drop,
+
+    /// Emit the join with the events of the streams received so far.
+    Emit,
+}
+
+/// What to do when the maximum number of pending joins is reached.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowStrategy {
+    /// End the window of the oldest pending join early, handling it according to `unmatched`.
+    #[default]
+    EvictOldest,
+
+    /// Drop the events that would start a new join.
+    DropNewest,
+}
+
+/// Configuration for the `join` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "join",
+    "Join events of several streams into a single event when their keys match."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinConfig {
+    /// The streams of events to join, by name.
+    ///
+    /// Each event belongs to the first stream, in order, whose condition it matches. Events that
+    /// match no stream are dropped. At least two streams are required.
+    #[configurable(metadata(
+        docs::additional_props_description = "An individual stream of events to join."
+    ))]
+    pub streams: IndexMap<String, JoinStreamConfig>,
+
+    /// An ordered list of fields by which to join events.
+    ///
+    /// Events are joined when they have the same values for all of these fields. Events missing
+    /// any of these fields are dropped.
+    #[configurable(metadata(docs::examples = "request_id"))]
+    pub join_by: Vec<String>,
+
+    /// The maximum period of time to wait for the events of all streams, in milliseconds, after the
+    /// first event of a join is received.
+    #[serde(default = "default_window_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub window_ms: Duration,
+
+    /// The interval to check for and flush any expired joins, in milliseconds.
+    #[serde(default = "default_flush_period_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub flush_period_ms: Duration,
+
+    /// The maximum number of joins waiting for the events of other streams.
+    #[serde(default = "default_max_pending")]
+    pub max_pending: NonZeroUsize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub overflow: OverflowStrategy,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub unmatched: UnmatchedStrategy,
+}
+
+const fn default_window_ms() -> Duration {
+    Duration::from_millis(30000)
+}
+
+const fn default_flush_period_ms() -> Duration {
+    Duration::from_millis(1000)
+}
+
+fn default_max_pending() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl GenerateConfig for JoinConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"join_by = ["request_id"]
+            streams.request.condition = 'exists(.status)'
+            streams.audit.condition = 'exists(.actor)'"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join")]
+impl TransformConfig for JoinConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        Join::new(self, &context.enrichment_tables).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // Joined events nest the events of each stream, whatever their type.
+        let namespaces: BTreeSet<_> = input_definitions
+            .iter()
+            .flat_map(|(_output, definition)| definition.log_namespaces().clone())
+            .collect();
+
+        let definitions = input_definitions
+            .iter()
+            .map(|(output, _definition)| {
+                (
+                    output.clone(),
+                    Definition::default_for_namespace(&namespaces),
+                )
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, definitions)]
+    }
+}
+
+/// The events of a join received so far.
+struct PendingJoin {
+    started: Instant,
+    keys: Vec<Value>,
+    events: BTreeMap<usize, Value>,
+    metadata: EventMetadata,
+}
+
+pub struct Join {
+    streams: Vec<(String, Condition)>,
+    join_by: Vec<String>,
+    window: Duration,
+    flush_period: Duration,
+    max_pending: usize,
+    overflow: OverflowStrategy,
+    unmatched: UnmatchedStrategy,
+    // Pending joins, from the oldest to the newest.
+    pending: IndexMap<Discriminant, PendingJoin>,
+}
+
+impl Join {
+    pub fn new(
+        config: &JoinConfig,
+        enrichment_tables: &enrichment::TableRegistry,
+    ) -> crate::Result<Self> {
+        if config.streams.len() < 2 {
+            return Err("at least two `streams` must be provided".into());
+        }
+        if config.join_by.is_empty() {
+            return Err("at least one `join_by` field must be provided".into());
+        }
+
+        let streams = config
+            .streams
+            .iter()
+            .map(|(name, stream)| Ok((name.clone(), stream.condition.build(enrichment_tables)?)))
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            streams,
+            join_by: config.join_by.clone(),
+            window: config.window_ms,
+            flush_period: config.flush_period_ms,
+            max_pending: config.max_pending.get(),
+            overflow: config.overflow,
+            unmatched: config.unmatched,
+            pending: IndexMap::new(),
+        })
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let mut event = event;
+        let mut stream = None;
+        for (index, (_, condition)) in self.streams.iter().enumerate() {
+            let (matched, checked) = condition.check(event);
+            event = checked;
+            if matched {
+                stream = Some(index);
+                break;
+            }
+        }
+        let Some(stream) = stream else {
+            emit!(JoinEventsDropped {
+                count: 1,
+                reason: "Event matched no stream.",
+            });
+            return;
+        };
+
+        let log = event.into_log();
+        let keys = self
+            .join_by
+            .iter()
+            .map(|field| log.get(field.as_str()).cloned())
+            .collect::<Option<Vec<_>>>();
+        let Some(keys) = keys else {
+            emit!(JoinEventsDropped {
+                count: 1,
+                reason: "Event is missing a `join_by` field.",
+            });
+            return;
+        };
+        let discriminant = Discriminant::from_log_event(&log, &self.join_by);
+
+        if !self.pending.contains_key(&discriminant) && self.pending.len() >= self.max_pending {
+            match self.overflow {
+                OverflowStrategy::EvictOldest => {
+                    if let Some((_, join)) = self.pending.shift_remove_index(0) {
+                        self.flush_unmatched(output, join);
+                    }
+                }
+                OverflowStrategy::DropNewest => {
+                    emit!(JoinEventsDropped {
+                        count: 1,
+                        reason: "Maximum number of pending joins reached.",
+                    });
+                    return;
+                }
+            }
+        }
+
+        let join = self
+            .pending
+            .entry(discriminant.clone())
+            .or_insert_with(|| PendingJoin {
+                started: Instant::now(),
+                keys,
+                events: BTreeMap::new(),
+                metadata: EventMetadata::default(),
+            });
+
+        let (value, metadata) = log.into_parts();
+        join.metadata.merge(metadata);
+        if join.events.insert(stream, value).is_some() {
+            emit!(JoinEventsDropped {
+                count: 1,
+                reason: "Event was replaced by a later event of the same stream.",
+            });
+        }
+
+        if join.events.len() == self.streams.len() {
+            if let Some(join) = self.pending.shift_remove(&discriminant) {
+                output.push(self.joined(join));
+            }
+        }
+    }
+
+    fn flush_unmatched(&self, output: &mut Vec<Event>, join: PendingJoin) {
+        match self.unmatched {
+            UnmatchedStrategy::Drop => emit!(JoinEventsDropped {
+                count: join.events.len(),
+                reason: "Join was not matched by all streams within its window.",
+            }),
+            UnmatchedStrategy::Emit => {
+                emit!(JoinUnmatchedFlushed);
+                output.push(self.joined(join));
+            }
+        }
+    }
+
+    /// Flushes the joins whose window has ended.
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        let now = Instant::now();
+        // Joins are ordered by when they started, so only the oldest ones can have ended.
+        while let Some((_, join)) = self.pending.first() {
+            if now - join.started < self.window {
+                break;
+            }
+            if let Some((_, join)) = self.pending.shift_remove_index(0) {
+                self.flush_unmatched(output, join);
+            }
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        for (_, join) in std::mem::take(&mut self.pending) {
+            self.flush_unmatched(output, join);
+        }
+    }
+
+    /// Builds the joined event, with the event of each stream nested under the name of the stream,
+    /// and the `join_by` fields at the root.
+    fn joined(&self, join: PendingJoin) -> Event {
+        let fields = join
+            .events
+            .into_iter()
+            .map(|(stream, value)| (self.streams[stream].0.clone(), value))
+            .collect();
+        let mut log = LogEvent::from_map(fields, join.metadata);
+        for (field, value) in self.join_by.iter().zip(join.keys) {
+            log.insert(field.as_str(), value);
+        }
+        Event::from(log)
+    }
+}
+
+impl TaskTransform<Event> for Join {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+              loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                      me.flush_into(&mut output);
+                      false
+                    }
+                    maybe_event = input_rx.next() => {
+                      match maybe_event {
+                        None => {
+                          me.flush_all_into(&mut output);
+                          true
+                        }
+                        Some(event) => {
+                          me.transform_one(&mut output, event);
+                          false
+                        }
+                      }
+                    }
+                };
+                yield stream::iter(output.into_iter());
+                if done { break }
+              }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::test_util::components::assert_transform_compliance;
+    use crate::transforms::test::create_topology;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JoinConfig>();
+    }
+
+    fn request(request_id: &str) -> Event {
+        let mut log = LogEvent::from("request");
+        log.insert("request_id", request_id);
+        log.insert("status", 200);
+        log.into()
+    }
+
+    fn audit(request_id: &str) -> Event {
+        let mut log = LogEvent::from("audit");
+        log.insert("request_id", request_id);
+        log.insert("actor", "admin");
+        log.into()
+    }
+
+    #[test]
+    fn requires_two_streams() {
+        let config: JoinConfig = toml::from_str(
+            r#"
+            join_by = ["request_id"]
+            streams.request.condition = 'exists(.status)'
+            "#,
+        )
+        .unwrap();
+
+        assert!(Join::new(&config, &Default::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn joins_events_by_key() {
+        assert_transform_compliance(async move {
+            let config: JoinConfig = toml::from_str(
+                r#"
+                join_by = ["request_id"]
+                window_ms = 100
+                flush_period_ms = 10
+                streams.request.condition = 'exists(.status)'
+                streams.audit.condition = 'exists(.actor)'
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(audit("a")).await.unwrap();
+            tx.send(request("b")).await.unwrap();
+            tx.send(request("a")).await.unwrap();
+
+            let joined = out.recv().await.unwrap().into_log();
+            assert_eq!(joined["request_id"], "a".into());
+            assert_eq!(joined["request.status"], 200.into());
+            assert_eq!(joined["audit.actor"], "admin".into());
+
+            drop(tx);
+            topology.stop().await;
+            // The unmatched join of `b` is dropped.
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn emits_unmatched_joins() {
+        assert_transform_compliance(async move {
+            let config: JoinConfig = toml::from_str(
+                r#"
+                join_by = ["request_id"]
+                window_ms = 100
+                flush_period_ms = 10
+                streams.request.condition = 'exists(.status)'
+                streams.audit.condition = 'exists(.actor)'
+                unmatched = "emit"
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(request("a")).await.unwrap();
+
+            // The join is flushed once its window ends.
+            let joined = out.recv().await.unwrap().into_log();
+            assert_eq!(joined["request_id"], "a".into());
+            assert_eq!(joined["request.status"], 200.into());
+            assert!(joined.get("audit").is_none());
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
+    #[test]
+    fn evicts_oldest_on_overflow() {
+        let config: JoinConfig = toml::from_str(
+            r#"
+            join_by = ["request_id"]
+            window_ms = 100
+            flush_period_ms = 10
+            streams.request.condition = 'exists(.status)'
+            streams.audit.condition = 'exists(.actor)'
+            max_pending = 1
+            unmatched = "emit"
+            "#,
+        )
+        .unwrap();
+        let mut join = Join::new(&config, &Default::default()).unwrap();
+        let mut output = Vec::new();
+
+        join.transform_one(&mut output, request("a"));
+        join.transform_one(&mut output, request("b"));
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].as_log()["request_id"], "a".into());
+        assert_eq!(join.pending.len(), 1);
+    }
+
+    #[test]
+    fn drops_newest_on_overflow() {
+        let config: JoinConfig = toml::from_str(
+            r#"
+            join_by = ["request_id"]
+            window_ms = 100
+            flush_period_ms = 10
+            streams.request.condition = 'exists(.status)'
+            streams.audit.condition = 'exists(.actor)'
+            max_pending = 1
+            overflow = "drop_newest"
+            "#,
+        )
+        .unwrap();
+        let mut join = Join::new(&config, &Default::default()).unwrap();
+        let mut output = Vec::new();
+
+        join.transform_one(&mut output, request("a"));
+        join.transform_one(&mut output, request("b"));
+        join.transform_one(&mut output, audit("a"));
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].as_log()["request_id"], "a".into());
+        assert!(join.pending.is_empty());
+    }
+}
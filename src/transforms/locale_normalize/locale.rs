@@ -0,0 +1,336 @@
+use vector_config::configurable_component;
+
+/// A locale that numbers and dates are written in.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Locale {
+    /// English, such as `1,234.5` and `March 12`.
+    En,
+
+    /// German, such as `1.234,5` and `12. März`.
+    De,
+
+    /// French, such as `1 234,5` and `12 mars`.
+    Fr,
+
+    /// Spanish, such as `1.234,5` and `12 de marzo`.
+    Es,
+
+    /// Italian, such as `1.234,5` and `12 marzo`.
+    It,
+
+    /// Portuguese, such as `1.234,5` and `12 de março`.
+    Pt,
+
+    /// Dutch, such as `1.234,5` and `12 maart`.
+    Nl,
+}
+
+/// Separators of digit groups accepted in every locale: spaces, including the non-breaking and
+/// narrow non-breaking ones that formatting libraries tend to emit.
+const SPACE_SEPARATORS: &[char] = &[' ', '\u{a0}', '\u{202f}'];
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+impl Locale {
+    /// The separator between the integer and fractional parts of numbers.
+    pub const fn decimal_separator(self) -> char {
+        match self {
+            Self::En => '.',
+            Self::De | Self::Fr | Self::Es | Self::It | Self::Pt | Self::Nl => ',',
+        }
+    }
+
+    /// Whether the character separates groups of digits in the integer part of numbers.
+    pub fn is_group_separator(self, c: char) -> bool {
+        SPACE_SEPARATORS.contains(&c)
+            || match self {
+                Self::En => c == ',',
+                Self::De => c == '.' || c == '\'',
+                Self::Fr => c == '.',
+                Self::Es | Self::It | Self::Pt | Self::Nl => c == '.',
+            }
+    }
+
+    /// The names of the months, in order, followed by their abbreviations.
+    const fn months(self) -> &'static [&'static [&'static str]; 12] {
+        match self {
+            Self::En => &[
+                &["january", "jan"],
+                &["february", "feb"],
+                &["march", "mar"],
+                &["april", "apr"],
+                &["may"],
+                &["june", "jun"],
+                &["july", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["october", "oct"],
+                &["november", "nov"],
+                &["december", "dec"],
+            ],
+            Self::De => &[
+                &["januar", "jänner", "jan", "jän"],
+                &["februar", "feb"],
+                &["märz", "mär", "mrz"],
+                &["april", "apr"],
+                &["mai"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["dezember", "dez"],
+            ],
+            Self::Fr => &[
+                &["janvier", "janv"],
+                &["février", "fevrier", "févr", "fevr", "fév"],
+                &["mars"],
+                &["avril", "avr"],
+                &["mai"],
+                &["juin"],
+                &["juillet", "juil"],
+                &["août", "aout"],
+                &["septembre", "sept"],
+                &["octobre", "oct"],
+                &["novembre", "nov"],
+                &["décembre", "decembre", "déc", "dec"],
+            ],
+            Self::Es => &[
+                &["enero", "ene"],
+                &["febrero", "feb"],
+                &["marzo", "mar"],
+                &["abril", "abr"],
+                &["mayo", "may"],
+                &["junio", "jun"],
+                &["julio", "jul"],
+                &["agosto", "ago"],
+                &["septiembre", "setiembre", "sep", "sept", "set"],
+                &["octubre", "oct"],
+                &["noviembre", "nov"],
+                &["diciembre", "dic"],
+            ],
+            Self::It => &[
+                &["gennaio", "gen"],
+                &["febbraio", "feb"],
+                &["marzo", "mar"],
+                &["aprile", "apr"],
+                &["maggio", "mag"],
+                &["giugno", "giu"],
+                &["luglio", "lug"],
+                &["agosto", "ago"],
+                &["settembre", "set"],
+                &["ottobre", "ott"],
+                &["novembre", "nov"],
+                &["dicembre", "dic"],
+            ],
+            Self::Pt => &[
+                &["janeiro", "jan"],
+                &["fevereiro", "fev"],
+                &["março", "marco", "mar"],
+                &["abril", "abr"],
+                &["maio", "mai"],
+                &["junho", "jun"],
+                &["julho", "jul"],
+                &["agosto", "ago"],
+                &["setembro", "set"],
+                &["outubro", "out"],
+                &["novembro", "nov"],
+                &["dezembro", "dez"],
+            ],
+            Self::Nl => &[
+                &["januari", "jan"],
+                &["februari", "feb"],
+                &["maart", "mrt"],
+                &["april", "apr"],
+                &["mei"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["augustus", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["december", "dec"],
+            ],
+        }
+    }
+
+    /// The names of the days of the week, starting on Monday, followed by their abbreviations.
+    const fn weekdays(self) -> &'static [&'static [&'static str]; 7] {
+        match self {
+            Self::En => &[
+                &["monday", "mon"],
+                &["tuesday", "tue", "tues"],
+                &["wednesday", "wed"],
+                &["thursday", "thu", "thur", "thurs"],
+                &["friday", "fri"],
+                &["saturday", "sat"],
+                &["sunday", "sun"],
+            ],
+            Self::De => &[
+                &["montag", "mo"],
+                &["dienstag", "di"],
+                &["mittwoch", "mi"],
+                &["donnerstag", "do"],
+                &["freitag", "fr"],
+                &["samstag", "sonnabend", "sa"],
+                &["sonntag", "so"],
+            ],
+            Self::Fr => &[
+                &["lundi", "lun"],
+                &["mardi", "mar"],
+                &["mercredi", "mer"],
+                &["jeudi", "jeu"],
+                &["vendredi", "ven"],
+                &["samedi", "sam"],
+                &["dimanche", "dim"],
+            ],
+            Self::Es => &[
+                &["lunes", "lun"],
+                &["martes", "mar"],
+                &["miércoles", "miercoles", "mié", "mie"],
+                &["jueves", "jue"],
+                &["viernes", "vie"],
+                &["sábado", "sabado", "sáb", "sab"],
+                &["domingo", "dom"],
+            ],
+            Self::It => &[
+                &["lunedì", "lunedi", "lun"],
+                &["martedì", "martedi", "mar"],
+                &["mercoledì", "mercoledi", "mer"],
+                &["giovedì", "giovedi", "gio"],
+                &["venerdì", "venerdi", "ven"],
+                &["sabato", "sab"],
+                &["domenica", "dom"],
+            ],
+            Self::Pt => &[
+                &["segunda-feira", "segunda", "seg"],
+                &["terça-feira", "terça", "terca", "ter"],
+                &["quarta-feira", "quarta", "qua"],
+                &["quinta-feira", "quinta", "qui"],
+                &["sexta-feira", "sexta", "sex"],
+                &["sábado", "sabado", "sáb", "sab"],
+                &["domingo", "dom"],
+            ],
+            Self::Nl => &[
+                &["maandag", "ma"],
+                &["dinsdag", "di"],
+                &["woensdag", "wo"],
+                &["donderdag", "do"],
+                &["vrijdag", "vr"],
+                &["zaterdag", "za"],
+                &["zondag", "zo"],
+            ],
+        }
+    }
+
+    /// Replaces the names of months and days of the week in the locale by their full English
+    /// names, so that the date can be parsed with the `%B` and `%A` specifiers. Words that are not
+    /// names of the locale, such as the `de` of `12 de marzo`, are left as they are.
+    pub fn translate_names(self, s: &str) -> String {
+        let mut translated = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(start) = rest.find(char::is_alphabetic) {
+            translated.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = word_end(rest);
+            let word = &rest[..end];
+            rest = &rest[end..];
+            match self.english_name(&word.to_lowercase()) {
+                Some((name, abbreviated)) => {
+                    translated.push_str(name);
+                    // Abbreviations may be followed by a period, as in `févr.`.
+                    if abbreviated {
+                        rest = rest.strip_prefix('.').unwrap_or(rest);
+                    }
+                }
+                None => translated.push_str(word),
+            }
+        }
+        translated.push_str(rest);
+        translated
+    }
+
+    /// Returns the English name of a month or day of the week, and whether the word is an
+    /// abbreviation.
+    fn english_name(self, word: &str) -> Option<(&'static str, bool)> {
+        let find = |names: &[&[&str]]| {
+            names.iter().enumerate().find_map(|(index, names)| {
+                let position = names.iter().position(|name| *name == word)?;
+                Some((
+                    index,
+                    word.chars().count() < names[0].chars().count() && position > 0,
+                ))
+            })
+        };
+        find(self.months())
+            .map(|(index, abbreviated)| (ENGLISH_MONTHS[index], abbreviated))
+            .or_else(|| {
+                find(self.weekdays())
+                    .map(|(index, abbreviated)| (ENGLISH_WEEKDAYS[index], abbreviated))
+            })
+    }
+}
+
+/// Returns the end of the word at the start of the string, which is a run of letters that may be
+/// joined by hyphens, as in `segunda-feira`.
+fn word_end(s: &str) -> usize {
+    let mut end = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        let joins =
+            c == '-' && end > 0 && matches!(chars.peek(), Some((_, next)) if next.is_alphabetic());
+        if !c.is_alphabetic() && !joins {
+            break;
+        }
+        end = index + c.len_utf8();
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translates_names() {
+        assert_eq!(
+            Locale::De.translate_names("Dienstag, 12. März 2023"),
+            "tuesday, 12. march 2023"
+        );
+        assert_eq!(
+            Locale::Fr.translate_names("12 févr. 2023"),
+            "12 february 2023"
+        );
+        assert_eq!(
+            Locale::Es.translate_names("12 de septiembre de 2023"),
+            "12 de september de 2023"
+        );
+        assert_eq!(Locale::Nl.translate_names("2023-03-12"), "2023-03-12");
+    }
+}
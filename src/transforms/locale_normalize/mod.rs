@@ -0,0 +1,491 @@
+use bytes::Bytes;
+use chrono::format::{Item, StrftimeItems};
+use lookup::lookup_v2::ConfigTargetPath;
+use value::Kind;
+use vector_common::{conversion::Conversion, TimeZone};
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::LocaleNormalizeError,
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+mod locale;
+mod parse;
+
+pub use self::locale::Locale;
+use self::parse::{parse_number, Epoch};
+pub use self::parse::{EpochUnit, NormalizeError};
+
+/// The type that the values of a field are parsed into.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The type that values are parsed into."))]
+pub enum FieldType {
+    /// An integer, or a float if the value has a fractional part.
+    ///
+    /// Digit group separators are removed, and so are currency symbols and codes such as `€` or
+    /// `USD`.
+    Number {
+        /// The field that the currency symbol or code of the value is written to, as written in
+        /// the value.
+        #[configurable(metadata(docs::examples = "currency"))]
+        currency_field: Option<ConfigTargetPath>,
+    },
+
+    /// A timestamp.
+    ///
+    /// Numbers, and strings of digits, are read as Unix timestamps.
+    Timestamp {
+        /// The formats of dates, in the [`strftime`][strftime] syntax, tried in order.
+        ///
+        /// Names of months and days of the week in the locales are translated to English before
+        /// parsing, such that `%B` and `%A` match `März` and `Dienstag`. If left empty, common
+        /// formats such as RFC 3339 are detected.
+        ///
+        /// [strftime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers
+        #[serde(default)]
+        #[configurable(metadata(docs::examples = "%d. %B %Y %H:%M"))]
+        #[configurable(metadata(docs::examples = "%d/%m/%Y"))]
+        formats: Vec<String>,
+
+        /// The unit of Unix timestamps.
+        ///
+        /// If left unspecified, the unit is detected from the magnitude of the timestamp, which is
+        /// unambiguous for dates between 1973 and 5138.
+        epoch_unit: Option<EpochUnit>,
+    },
+}
+
+/// A rule normalizing the values of a field.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct FieldRule {
+    /// The field containing the values to normalize.
+    #[configurable(metadata(docs::examples = "amount"))]
+    #[configurable(metadata(docs::examples = "order.created"))]
+    pub field: ConfigTargetPath,
+
+    /// The field that the normalized value is written to.
+    ///
+    /// Defaults to `field`, which is overwritten.
+    #[configurable(metadata(docs::examples = "amount_normalized"))]
+    pub target: Option<ConfigTargetPath>,
+
+    /// The locales of the values of the field, overriding the locales of the transform.
+    pub locales: Option<Vec<Locale>>,
+
+    #[serde(flatten)]
+    #[configurable(derived)]
+    pub r#type: FieldType,
+}
+
+/// Configuration for the `locale_normalize` transform.
+#[configurable_component(transform(
+    "locale_normalize",
+    "Parse localized numbers and dates into integers, floats and timestamps."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LocaleNormalizeConfig {
+    /// The locales that values are written in, tried in order until one of them parses the value.
+    ///
+    /// The order matters for ambiguous values: `1.234` is `1.234` in English, but `1234` in German.
+    #[serde(default = "default_locales")]
+    pub locales: Vec<Locale>,
+
+    /// The name of the time zone to apply to dates that do not contain an explicit time zone.
+    ///
+    /// This overrides the [global `timezone`][global_timezone] option. The time zone name may be
+    /// any name in the [TZ database][tz_database] or `local` to indicate system local time.
+    ///
+    /// [global_timezone]: https://vector.dev/docs/reference/configuration//global-options#timezone
+    /// [tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+    pub timezone: Option<TimeZone>,
+
+    /// The fields to normalize, in order.
+    pub fields: Vec<FieldRule>,
+}
+
+fn default_locales() -> Vec<Locale> {
+    vec![Locale::En]
+}
+
+impl GenerateConfig for LocaleNormalizeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"locales = ["de", "en"]
+            fields = [
+                { field = "amount", type = "number", currency_field = "currency" },
+                { field = "created", type = "timestamp", formats = ["%d. %B %Y %H:%M"] },
+            ]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "locale_normalize")]
+impl TransformConfig for LocaleNormalizeConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let timezone = self.timezone.unwrap_or_else(|| context.globals.timezone());
+        LocaleNormalize::new(self, timezone).map(Transform::function)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let definition = self
+                    .fields
+                    .iter()
+                    .fold(definition.clone(), |definition, rule| {
+                        let target = rule.target.as_ref().unwrap_or(&rule.field);
+                        // Values that fail to parse are left unchanged.
+                        let kind = match &rule.r#type {
+                            FieldType::Number { .. } => Kind::integer().or_float(),
+                            FieldType::Timestamp { .. } => Kind::timestamp(),
+                        };
+                        let kind = definition.kind_at(target).union(kind);
+                        let definition = definition.with_field(&target.0, kind, None);
+                        match &rule.r#type {
+                            FieldType::Number {
+                                currency_field: Some(currency_field),
+                            } => {
+                                let kind = definition.kind_at(currency_field).union(Kind::bytes());
+                                definition.with_field(&currency_field.0, kind, None)
+                            }
+                            _ => definition,
+                        }
+                    });
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Parser {
+    Number {
+        currency_field: Option<ConfigTargetPath>,
+    },
+    Timestamp {
+        formats: Vec<Conversion>,
+        detect: Conversion,
+        epoch_unit: Option<EpochUnit>,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    field: ConfigTargetPath,
+    field_name: String,
+    target: ConfigTargetPath,
+    locales: Vec<Locale>,
+    parser: Parser,
+}
+
+impl Rule {
+    fn parse_number(&self, value: &Value) -> Result<(Value, Option<String>), NormalizeError> {
+        match value {
+            Value::Integer(_) | Value::Float(_) => Ok((value.clone(), None)),
+            Value::Bytes(bytes) => {
+                let s = String::from_utf8_lossy(bytes);
+                self.locales
+                    .iter()
+                    .find_map(|locale| parse_number(&s, *locale))
+                    .map(|(value, currency)| (value, currency.map(str::to_owned)))
+                    .ok_or(NormalizeError::NotNumeric)
+            }
+            _ => Err(NormalizeError::NotNumeric),
+        }
+    }
+
+    /// Parses a date with the formats, after translating the names in each of the locales.
+    fn parse_date(&self, s: &str, formats: &[Conversion]) -> Result<Value, NormalizeError> {
+        let mut tried = Vec::with_capacity(self.locales.len());
+        for locale in &self.locales {
+            let translated = locale.translate_names(s);
+            if tried.contains(&translated) {
+                continue;
+            }
+            for format in formats {
+                if let Ok(Value::Timestamp(timestamp)) =
+                    format.convert(Bytes::from(translated.clone()))
+                {
+                    return Ok(Value::Timestamp(timestamp));
+                }
+            }
+            tried.push(translated);
+        }
+        Err(NormalizeError::NotTimestamp)
+    }
+
+    fn parse_timestamp(
+        &self,
+        value: &Value,
+        formats: &[Conversion],
+        detect: &Conversion,
+        epoch_unit: Option<EpochUnit>,
+    ) -> Result<Value, NormalizeError> {
+        let epoch = match value {
+            Value::Timestamp(_) => return Ok(value.clone()),
+            Value::Integer(value) => Epoch::Integer(*value),
+            Value::Float(value) => Epoch::Float(value.into_inner()),
+            Value::Bytes(bytes) => {
+                let s = String::from_utf8_lossy(bytes);
+                let s = s.trim();
+                match Epoch::parse(s) {
+                    // Configured formats may be all digits too, such as `%Y%m%d`.
+                    Some(epoch) => match self.parse_date(s, formats) {
+                        Ok(value) => return Ok(value),
+                        Err(_) => epoch,
+                    },
+                    None if formats.is_empty() => {
+                        return self.parse_date(s, std::slice::from_ref(detect))
+                    }
+                    None => return self.parse_date(s, formats),
+                }
+            }
+            _ => return Err(NormalizeError::NotTimestamp),
+        };
+        epoch.to_timestamp(epoch_unit).map(Value::Timestamp)
+    }
+
+    fn apply(&self, log: &mut LogEvent) {
+        let Some(value) = log.get(&self.field) else {
+            return;
+        };
+
+        let parsed = match &self.parser {
+            Parser::Number { currency_field } => self
+                .parse_number(value)
+                .map(|(value, currency)| (value, currency_field.as_ref().zip(currency))),
+            Parser::Timestamp {
+                formats,
+                detect,
+                epoch_unit,
+            } => self
+                .parse_timestamp(value, formats, detect, *epoch_unit)
+                .map(|value| (value, None)),
+        };
+        match parsed {
+            Ok((value, currency)) => {
+                log.insert(&self.target, value);
+                if let Some((currency_field, currency)) = currency {
+                    log.insert(currency_field, currency);
+                }
+            }
+            Err(error) => emit!(LocaleNormalizeError {
+                field: &self.field_name,
+                error,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LocaleNormalize {
+    rules: Vec<Rule>,
+}
+
+impl LocaleNormalize {
+    pub fn new(config: &LocaleNormalizeConfig, timezone: TimeZone) -> crate::Result<Self> {
+        let rules = config
+            .fields
+            .iter()
+            .map(|rule| {
+                let field_name = String::from(rule.field.0.clone());
+                let locales = rule.locales.as_ref().unwrap_or(&config.locales).clone();
+                if locales.is_empty() {
+                    return Err(format!("Invalid rule for {:?}: no locales", field_name).into());
+                }
+
+                let parser = match &rule.r#type {
+                    FieldType::Number { currency_field } => Parser::Number {
+                        currency_field: currency_field.clone(),
+                    },
+                    FieldType::Timestamp {
+                        formats,
+                        epoch_unit,
+                    } => Parser::Timestamp {
+                        formats: formats
+                            .iter()
+                            .map(|format| {
+                                if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                                    return Err(format!(
+                                        "Invalid rule for {:?}: invalid format {:?}",
+                                        field_name, format
+                                    ));
+                                }
+                                Conversion::parse(format!("timestamp|{}", format), timezone)
+                                    .map_err(|error| {
+                                        format!("Invalid rule for {:?}: {}", field_name, error)
+                                    })
+                            })
+                            .collect::<Result<_, _>>()?,
+                        detect: Conversion::Timestamp(timezone),
+                        epoch_unit: *epoch_unit,
+                    },
+                };
+
+                Ok(Rule {
+                    field: rule.field.clone(),
+                    field_name,
+                    target: rule.target.clone().unwrap_or_else(|| rule.field.clone()),
+                    locales,
+                    parser,
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { rules })
+    }
+}
+
+impl FunctionTransform for LocaleNormalize {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+        for rule in &self.rules {
+            rule.apply(log);
+        }
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone as _, Utc};
+
+    use super::*;
+    use crate::transforms::test::transform_one;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<LocaleNormalizeConfig>();
+    }
+
+    fn locale_normalize(config: &str) -> LocaleNormalize {
+        let config = toml::from_str::<LocaleNormalizeConfig>(config).unwrap();
+        LocaleNormalize::new(&config, config.timezone.unwrap_or_default()).unwrap()
+    }
+
+    #[test]
+    fn normalizes_numbers_in_locales() {
+        let mut transform = locale_normalize(
+            r#"
+            locales = ["en", "de"]
+            fields = [
+                { field = "price", type = "number", currency_field = "currency" },
+                { field = "ratio", type = "number" },
+                { field = "count", type = "number", locales = ["de"], target = "count_normalized" },
+            ]
+            "#,
+        );
+
+        let mut log = LogEvent::default();
+        log.insert("price", "€ 1.234,50");
+        log.insert("ratio", "0,75");
+        log.insert("count", "1.234");
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+
+        assert_eq!(log["price"], 1234.5.into());
+        assert_eq!(log["currency"], "€".into());
+        assert_eq!(log["ratio"], 0.75.into());
+        assert_eq!(log["count"], "1.234".into());
+        assert_eq!(log["count_normalized"], 1234.into());
+    }
+
+    #[test]
+    fn normalizes_dates_in_locales() {
+        let mut transform = locale_normalize(
+            r#"
+            locales = ["de", "fr"]
+            timezone = "Europe/Berlin"
+            fields = [
+                { field = "created", type = "timestamp", formats = ["%d. %B %Y %H:%M", "%d %b %Y %H:%M"] },
+                { field = "received", type = "timestamp" },
+                { field = "sent", type = "timestamp" },
+            ]
+            "#,
+        );
+
+        let mut log = LogEvent::default();
+        log.insert("created", "12. März 2023 10:00");
+        log.insert("received", "2023-03-12T09:00:00Z");
+        log.insert("sent", "1678611600000");
+        let mut french = LogEvent::default();
+        french.insert("created", "12 févr. 2023 10:00");
+
+        let expected = Value::Timestamp(Utc.with_ymd_and_hms(2023, 3, 12, 9, 0, 0).unwrap());
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+        assert_eq!(log["created"], expected);
+        assert_eq!(log["received"], expected);
+        assert_eq!(log["sent"], expected);
+
+        let french = transform_one(&mut transform, french.into())
+            .unwrap()
+            .into_log();
+        assert_eq!(
+            french["created"],
+            Value::Timestamp(Utc.with_ymd_and_hms(2023, 2, 12, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn leaves_unparseable_values() {
+        let mut transform = locale_normalize(
+            r#"
+            fields = [
+                { field = "amount", type = "number" },
+                { field = "created", type = "timestamp", formats = ["%d/%m/%Y"] },
+            ]
+            "#,
+        );
+
+        let mut log = LogEvent::default();
+        log.insert("amount", "1,5");
+        log.insert("created", "yesterday");
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+
+        assert_eq!(log["amount"], "1,5".into());
+        assert_eq!(log["created"], "yesterday".into());
+    }
+
+    #[test]
+    fn rejects_invalid_formats() {
+        let config = toml::from_str::<LocaleNormalizeConfig>(
+            r#"
+            fields = [{ field = "created", type = "timestamp", formats = ["%Q"] }]
+            "#,
+        )
+        .unwrap();
+        assert!(LocaleNormalize::new(&config, TimeZone::default()).is_err());
+    }
+}
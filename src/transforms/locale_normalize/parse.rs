@@ -0,0 +1,290 @@
+use chrono::{DateTime, TimeZone, Utc};
+use ordered_float::NotNan;
+use snafu::Snafu;
+use vector_config::configurable_component;
+
+use super::locale::Locale;
+use crate::event::Value;
+
+/// The unit of Unix timestamps.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EpochUnit {
+    /// Seconds.
+    #[serde(rename = "s")]
+    Seconds,
+
+    /// Milliseconds.
+    #[serde(rename = "ms")]
+    Milliseconds,
+
+    /// Microseconds.
+    #[serde(rename = "us")]
+    Microseconds,
+
+    /// Nanoseconds.
+    #[serde(rename = "ns")]
+    Nanoseconds,
+}
+
+impl EpochUnit {
+    /// Detects the unit of a timestamp from its magnitude, assuming it's between 1973 and 5138.
+    fn detect(magnitude: f64) -> Self {
+        if magnitude < 1e11 {
+            Self::Seconds
+        } else if magnitude < 1e14 {
+            Self::Milliseconds
+        } else if magnitude < 1e17 {
+            Self::Microseconds
+        } else {
+            Self::Nanoseconds
+        }
+    }
+
+    const fn nanoseconds(self) -> i64 {
+        match self {
+            Self::Seconds => 1_000_000_000,
+            Self::Milliseconds => 1_000_000,
+            Self::Microseconds => 1_000,
+            Self::Nanoseconds => 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum NormalizeError {
+    #[snafu(display("value isn't a number in any of the locales"))]
+    NotNumeric,
+
+    #[snafu(display("value isn't a date in any of the locales and formats"))]
+    NotTimestamp,
+
+    #[snafu(display("timestamp is out of range"))]
+    OutOfRange,
+}
+
+/// A Unix timestamp, in a unit that may have to be detected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Epoch {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Epoch {
+    /// Parses a string of digits, with an optional sign and fractional part, as a Unix timestamp.
+    pub fn parse(s: &str) -> Option<Self> {
+        let unsigned = s.strip_prefix('-').unwrap_or(s);
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        match fraction {
+            None if is_digits(integer) => s.parse().ok().map(Self::Integer),
+            Some(fraction) if is_digits(integer) && is_digits(fraction) => {
+                s.parse().ok().map(Self::Float)
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts the timestamp to a date, detecting its unit if it's unspecified.
+    pub fn to_timestamp(self, unit: Option<EpochUnit>) -> Result<DateTime<Utc>, NormalizeError> {
+        let nanoseconds = match self {
+            Self::Integer(value) => {
+                let unit = unit.unwrap_or_else(|| EpochUnit::detect(value.unsigned_abs() as f64));
+                value.checked_mul(unit.nanoseconds())
+            }
+            Self::Float(value) => {
+                let unit = unit.unwrap_or_else(|| EpochUnit::detect(value.abs()));
+                let nanoseconds = value * unit.nanoseconds() as f64;
+                (nanoseconds.is_finite() && nanoseconds.abs() < i64::MAX as f64)
+                    .then_some(nanoseconds as i64)
+            }
+        };
+        nanoseconds
+            .map(|nanoseconds| Utc.timestamp_nanos(nanoseconds))
+            .ok_or(NormalizeError::OutOfRange)
+    }
+}
+
+/// Currency symbols, longest first so that `US$` isn't read as `$`. Three-letter codes such as
+/// `EUR` are recognized separately.
+const CURRENCY_SYMBOLS: &[&str] = &[
+    "US$", "R$", "C$", "A$", "zł", "kr", "$", "€", "£", "¥", "₹", "₽", "₩", "₺", "₪", "₫", "₴",
+    "₦", "₱", "฿",
+];
+
+/// Strips a currency symbol or code from the start or the end of the string.
+fn strip_currency(s: &str) -> (Option<&str>, &str) {
+    for &symbol in CURRENCY_SYMBOLS {
+        if let Some(rest) = s.strip_prefix(symbol) {
+            return (Some(symbol), rest.trim_start());
+        }
+        if let Some(rest) = s.strip_suffix(symbol) {
+            return (Some(symbol), rest.trim_end());
+        }
+    }
+
+    let is_code = |code: &&str| code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase());
+    if let Some(code) = s.get(..3).filter(is_code) {
+        return (Some(code), s[3..].trim_start());
+    }
+    if let Some(code) = s
+        .len()
+        .checked_sub(3)
+        .and_then(|start| s.get(start..))
+        .filter(is_code)
+    {
+        return (Some(code), s[..s.len() - 3].trim_end());
+    }
+    (None, s)
+}
+
+/// Strips a leading sign, returning whether it's negative.
+fn strip_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix(['-', '\u{2212}']) {
+        (true, rest.trim_start())
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s).trim_start())
+    }
+}
+
+/// Rewrites the digits of a number in the locale with a `.` decimal separator and without group
+/// separators, returning whether the number has a fractional part.
+///
+/// Groups are checked to have three digits, such that `1,5` isn't read as `15` in English, and the
+/// next locale gets a chance to parse it.
+fn canonical_digits(s: &str, locale: Locale) -> Option<(String, bool)> {
+    let (integer, fraction) = match s.split_once(locale.decimal_separator()) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (s, None),
+    };
+
+    let mut digits = String::with_capacity(s.len());
+    let mut separator = None;
+    let mut groups = 0;
+    let mut group_len = 0;
+    for c in integer.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            group_len += 1;
+        } else if locale.is_group_separator(c) && separator.map_or(true, |separator| separator == c)
+        {
+            // The first group has one to three digits, and the others exactly three.
+            if group_len == 0 || group_len > 3 || (groups > 0 && group_len != 3) {
+                return None;
+            }
+            separator = Some(c);
+            groups += 1;
+            group_len = 0;
+        } else {
+            return None;
+        }
+    }
+    if groups > 0 && group_len != 3 {
+        return None;
+    }
+
+    match fraction {
+        Some(fraction) if !fraction.is_empty() && fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            if digits.is_empty() {
+                digits.push('0');
+            }
+            digits.push('.');
+            digits.push_str(fraction);
+            Some((digits, true))
+        }
+        Some(_) => None,
+        None if digits.is_empty() => None,
+        None => Some((digits, false)),
+    }
+}
+
+/// Parses a number written in the locale, such as `-1.234,50 €` in German, into an integer, or a
+/// float if it has a fractional part, along with its currency symbol or code.
+pub fn parse_number(s: &str, locale: Locale) -> Option<(Value, Option<&str>)> {
+    let (negative, s) = strip_sign(s.trim());
+    let (currency, s) = strip_currency(s);
+    let (negative, s) = if negative { (true, s) } else { strip_sign(s) };
+
+    let (mut digits, fractional) = canonical_digits(s, locale)?;
+    if negative {
+        digits.insert(0, '-');
+    }
+    let integer = if fractional {
+        None
+    } else {
+        digits.parse().ok().map(Value::Integer)
+    };
+    let value = match integer {
+        Some(value) => value,
+        None => Value::Float(NotNan::new(digits.parse().ok()?).ok()?),
+    };
+    Some((value, currency))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(
+            parse_number("1,234.5", Locale::En),
+            Some((Value::from(1234.5), None))
+        );
+        assert_eq!(
+            parse_number("-1.234,50 €", Locale::De),
+            Some((Value::from(-1234.5), Some("€")))
+        );
+        assert_eq!(
+            parse_number("1\u{202f}234\u{202f}567", Locale::Fr),
+            Some((Value::Integer(1234567), None))
+        );
+        assert_eq!(
+            parse_number("USD 12", Locale::En),
+            Some((Value::Integer(12), Some("USD")))
+        );
+        assert_eq!(
+            parse_number("1.234", Locale::En),
+            Some((Value::from(1.234), None))
+        );
+        assert_eq!(
+            parse_number("1.234", Locale::De),
+            Some((Value::Integer(1234), None))
+        );
+        assert_eq!(parse_number("1,5", Locale::En), None);
+        assert_eq!(parse_number("12,34,567", Locale::En), None);
+        assert_eq!(parse_number("twelve", Locale::En), None);
+    }
+
+    #[test]
+    fn detects_epoch_units() {
+        let expected = Utc.timestamp_opt(1_678_615_200, 0).unwrap();
+        for epoch in [
+            "1678615200",
+            "1678615200000",
+            "1678615200000000",
+            "1678615200000000000",
+        ] {
+            assert_eq!(
+                Epoch::parse(epoch).unwrap().to_timestamp(None),
+                Ok(expected)
+            );
+        }
+        assert_eq!(
+            Epoch::parse("1678615200.5").unwrap().to_timestamp(None),
+            Ok(Utc.timestamp_opt(1_678_615_200, 500_000_000).unwrap())
+        );
+        assert_eq!(
+            Epoch::Integer(1_678_615_200).to_timestamp(Some(EpochUnit::Milliseconds)),
+            Ok(Utc.timestamp_opt(1_678_615, 200_000_000).unwrap())
+        );
+        assert_eq!(
+            Epoch::Integer(i64::MAX / 10).to_timestamp(Some(EpochUnit::Seconds)),
+            Err(NormalizeError::OutOfRange)
+        );
+        assert_eq!(Epoch::parse("2023-03-12"), None);
+    }
+}
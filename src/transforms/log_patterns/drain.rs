@@ -0,0 +1,284 @@
+//! Online log clustering, after [Drain][drain].
+//!
+//! Messages are split into tokens on whitespace, and routed through a fixed depth parse tree keyed
+//! by their number of tokens and their leading tokens. Each leaf of the tree holds a small number
+//! of clusters, and a message joins the most similar of them, or starts a new cluster if none is
+//! similar enough. The template of a cluster is the sequence of tokens shared by all its messages,
+//! with [`WILDCARD`] in place of the tokens that vary, which are the parameters of each message.
+//!
+//! [drain]: https://jiemingzhu.github.io/pub/pjhe_icws2017.pdf
+use std::{collections::HashMap, num::NonZeroUsize};
+
+use lru::LruCache;
+
+/// The token standing for a parameter in templates.
+pub const WILDCARD: &str = "<*>";
+
+/// The outcome of matching a message against the known patterns.
+#[derive(Debug, PartialEq)]
+pub struct PatternMatch {
+    /// The identifier of the cluster the message was assigned to.
+    pub id: u64,
+    /// The template of the cluster, including the message.
+    pub template: String,
+    /// The tokens of the message in place of the wildcards of the template.
+    pub parameters: Vec<String>,
+    /// Whether the message started a new cluster.
+    pub created: bool,
+}
+
+#[derive(Debug)]
+struct Cluster {
+    template: Vec<String>,
+}
+
+impl Cluster {
+    /// The share of tokens of the template that are equal to those of the message, along with the
+    /// number of wildcards of the template.
+    ///
+    /// Both have the same number of tokens, as they are found under the same node of the tree.
+    fn similarity(&self, tokens: &[&str]) -> (f64, usize) {
+        if tokens.is_empty() {
+            return (1.0, 0);
+        }
+
+        let mut equal = 0;
+        let mut wildcards = 0;
+        for (template, token) in self.template.iter().zip(tokens) {
+            if template == WILDCARD {
+                wildcards += 1;
+            } else if template == token {
+                equal += 1;
+            }
+        }
+        (equal as f64 / tokens.len() as f64, wildcards)
+    }
+
+    /// Replaces the tokens of the template that differ from those of the message with wildcards.
+    fn merge(&mut self, tokens: &[&str]) {
+        for (template, token) in self.template.iter_mut().zip(tokens) {
+            if template != token {
+                *template = WILDCARD.to_owned();
+            }
+        }
+    }
+
+    fn parameters(&self, tokens: &[&str]) -> Vec<String> {
+        self.template
+            .iter()
+            .zip(tokens)
+            .filter(|(template, _)| *template == WILDCARD)
+            .map(|(_, token)| (*token).to_owned())
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<String, Node>,
+    clusters: Vec<u64>,
+}
+
+/// The state of the clustering: the parse tree, and the clusters at its leaves.
+#[derive(Debug)]
+pub struct Drain {
+    /// The first layer of the tree, keyed by the number of tokens of messages.
+    root: HashMap<usize, Node>,
+    /// The clusters, of which the least recently matched are evicted first.
+    clusters: LruCache<u64, Cluster>,
+    /// The number of leading tokens that messages are routed on.
+    prefix_tokens: usize,
+    max_children: usize,
+    similarity_threshold: f64,
+    next_id: u64,
+}
+
+impl Drain {
+    /// Creates an empty tree.
+    ///
+    /// `max_depth` counts the root and the layer keyed by the number of tokens, so is at least 2.
+    pub fn new(
+        max_depth: usize,
+        max_children: usize,
+        similarity_threshold: f64,
+        max_patterns: NonZeroUsize,
+    ) -> Self {
+        Self {
+            root: HashMap::new(),
+            clusters: LruCache::new(max_patterns),
+            prefix_tokens: max_depth.saturating_sub(2),
+            max_children,
+            similarity_threshold,
+            next_id: 1,
+        }
+    }
+
+    /// Assigns the message to a cluster, creating one if no existing cluster is similar enough.
+    pub fn process(&mut self, message: &str) -> PatternMatch {
+        let tokens = message.split_whitespace().collect::<Vec<_>>();
+
+        let leaf = leaf(
+            &mut self.root,
+            &tokens,
+            self.prefix_tokens,
+            self.max_children,
+        );
+        // Clusters evicted from the cache are still referenced by their leaf until it is visited.
+        leaf.clusters.retain(|id| self.clusters.contains(id));
+
+        let mut best: Option<(u64, (f64, usize))> = None;
+        for id in &leaf.clusters {
+            let cluster = self.clusters.peek(id).expect("retained clusters exist");
+            let similarity = cluster.similarity(&tokens);
+            if best.map_or(true, |(_, best)| {
+                similarity.0 > best.0 || (similarity.0 == best.0 && similarity.1 > best.1)
+            }) {
+                best = Some((*id, similarity));
+            }
+        }
+
+        if let Some((id, (similarity, _))) = best {
+            if similarity >= self.similarity_threshold {
+                let cluster = self.clusters.get_mut(&id).expect("retained clusters exist");
+                cluster.merge(&tokens);
+                return PatternMatch {
+                    id,
+                    template: cluster.template.join(" "),
+                    parameters: cluster.parameters(&tokens),
+                    created: false,
+                };
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        leaf.clusters.push(id);
+        let cluster = Cluster {
+            template: tokens.iter().map(|token| (*token).to_owned()).collect(),
+        };
+        let template = cluster.template.join(" ");
+        self.clusters.push(id, cluster);
+
+        PatternMatch {
+            id,
+            template,
+            parameters: Vec::new(),
+            created: true,
+        }
+    }
+}
+
+/// Finds the leaf that the message is routed to, adding nodes to the tree as needed.
+///
+/// Tokens containing digits are likely to be parameters, so they are routed to the wildcard child.
+/// Once a node has `max_children` children, any further new tokens are routed to the wildcard child
+/// as well.
+fn leaf<'a>(
+    root: &'a mut HashMap<usize, Node>,
+    tokens: &[&str],
+    prefix_tokens: usize,
+    max_children: usize,
+) -> &'a mut Node {
+    let mut node = root.entry(tokens.len()).or_default();
+
+    for token in tokens.iter().take(prefix_tokens) {
+        let key = if node.children.contains_key(*token) {
+            *token
+        } else if token.chars().any(|c| c.is_ascii_digit())
+            || node.children.len() + 1 >= max_children
+        {
+            WILDCARD
+        } else {
+            *token
+        };
+        node = node.children.entry(key.to_owned()).or_default();
+    }
+
+    node
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain() -> Drain {
+        Drain::new(4, 100, 0.4, NonZeroUsize::new(100).unwrap())
+    }
+
+    #[test]
+    fn clusters_similar_messages() {
+        let mut drain = drain();
+
+        let first = drain.process("connected to 10.0.0.1 in 5 ms");
+        assert!(first.created);
+        assert_eq!(first.template, "connected to 10.0.0.1 in 5 ms");
+        assert!(first.parameters.is_empty());
+
+        let second = drain.process("connected to 10.0.0.2 in 12 ms");
+        assert_eq!(
+            second,
+            PatternMatch {
+                id: first.id,
+                template: "connected to <*> in <*> ms".to_owned(),
+                parameters: vec!["10.0.0.2".to_owned(), "12".to_owned()],
+                created: false,
+            }
+        );
+
+        let other = drain.process("disk full on /var");
+        assert!(other.created);
+        assert_ne!(other.id, first.id);
+
+        // Messages with a different number of tokens never share a cluster.
+        let longer = drain.process("connected to 10.0.0.3 in 7 ms again");
+        assert!(longer.created);
+    }
+
+    #[test]
+    fn starts_new_cluster_below_threshold() {
+        let mut drain = drain();
+
+        let first = drain.process("user alice logged in from web");
+        let second = drain.process("user alice logged out from web");
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.template, "user alice logged <*> from web");
+
+        // Only two tokens in six match, which is below the threshold.
+        let third = drain.process("user alice changed her password today");
+        assert!(third.created);
+        assert_ne!(third.id, first.id);
+    }
+
+    #[test]
+    fn routes_excess_tokens_to_wildcard() {
+        let mut drain = Drain::new(3, 3, 0.4, NonZeroUsize::new(100).unwrap());
+
+        let a = drain.process("alpha started");
+        let b = drain.process("beta started");
+        // The root already has two children, so further first tokens share the wildcard child.
+        let c = drain.process("gamma started");
+        let d = drain.process("delta started");
+
+        assert_ne!(a.id, b.id);
+        assert!(c.created);
+        assert_eq!(d.id, c.id);
+        assert_eq!(d.template, "<*> started");
+        assert_eq!(d.parameters, vec!["delta".to_owned()]);
+    }
+
+    #[test]
+    fn evicts_least_recently_matched_clusters() {
+        let mut drain = Drain::new(4, 100, 0.4, NonZeroUsize::new(2).unwrap());
+
+        let first = drain.process("request served");
+        let second = drain.process("cache miss for key");
+        drain.process("request served");
+        // The cluster of the second message is the least recently matched, so is evicted.
+        drain.process("queue is full now");
+
+        assert_eq!(drain.process("request served").id, first.id);
+        let again = drain.process("cache miss for key");
+        assert!(again.created);
+        assert_ne!(again.id, second.id);
+    }
+}
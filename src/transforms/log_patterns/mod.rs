@@ -0,0 +1,464 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    num::NonZeroUsize,
+    pin::Pin,
+    time::Duration,
+};
+
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use lookup::lookup_v2::ConfigTargetPath;
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+use vector_core::config::{clone_input_definitions, LogNamespace};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{
+        metric::{Metric, MetricKind, MetricTags, MetricValue},
+        Event, Value,
+    },
+    internal_events::LogPatternCreated,
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+mod drain;
+
+use self::drain::{Drain, PatternMatch};
+
+/// Configuration of the per-pattern count metrics.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PatternMetricsConfig {
+    /// The name of the counter.
+    #[serde(default = "default_metric_name")]
+    pub name: String,
+
+    /// The namespace of the counter.
+    #[configurable(metadata(docs::examples = "service"))]
+    pub namespace: Option<String>,
+
+    /// The interval between flushes of the counts, in seconds.
+    ///
+    /// A counter is emitted for every pattern that was matched since the previous flush, tagged
+    /// with the `pattern_id` and `pattern` of the pattern.
+    #[serde(default = "default_flush_interval_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub flush_interval_secs: u64,
+}
+
+fn default_metric_name() -> String {
+    "log_pattern_events_total".to_owned()
+}
+
+const fn default_flush_interval_secs() -> u64 {
+    60
+}
+
+/// Configuration for the `log_patterns` transform.
+#[configurable_component(transform(
+    "log_patterns",
+    "Cluster log messages into patterns, extracting their templates and parameters."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LogPatternsConfig {
+    /// The field containing the message to cluster.
+    #[serde(default = "default_field")]
+    #[configurable(metadata(docs::examples = "message"))]
+    #[configurable(metadata(docs::examples = "error.message"))]
+    pub field: ConfigTargetPath,
+
+    /// The field that the pattern is written to.
+    ///
+    /// The pattern is an object with the `id` of the pattern, its `template`, and the `parameters`
+    /// of the message, which are the tokens of the message in place of the wildcards of the
+    /// template.
+    #[serde(default = "default_target")]
+    #[configurable(metadata(docs::examples = "pattern"))]
+    pub target: ConfigTargetPath,
+
+    /// The minimum share of tokens a message must have in common with a pattern to be assigned
+    /// to it.
+    ///
+    /// Lower values produce fewer, more general patterns.
+    #[serde(default = "default_similarity_threshold")]
+    #[configurable(validation(range(min = 0.0, max = 1.0)))]
+    pub similarity_threshold: f64,
+
+    /// The depth of the parse tree that messages are routed through.
+    ///
+    /// Messages are only compared to patterns with the same number of tokens, and the same first
+    /// `max_depth - 2` tokens. Tokens containing digits are treated as parameters when routing.
+    #[serde(default = "default_max_depth")]
+    pub max_depth: usize,
+
+    /// The maximum number of children of a node of the parse tree.
+    ///
+    /// Once a node is full, messages with other tokens at that position are routed together.
+    #[serde(default = "default_max_children")]
+    pub max_children: NonZeroUsize,
+
+    /// The maximum number of patterns kept.
+    ///
+    /// When full, the least recently matched pattern is forgotten to make room for a new one.
+    #[serde(default = "default_max_patterns")]
+    pub max_patterns: NonZeroUsize,
+
+    #[configurable(derived)]
+    pub metrics: Option<PatternMetricsConfig>,
+}
+
+fn default_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("message".to_owned()).expect("static path is valid")
+}
+
+fn default_target() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("pattern".to_owned()).expect("static path is valid")
+}
+
+const fn default_similarity_threshold() -> f64 {
+    0.4
+}
+
+const fn default_max_depth() -> usize {
+    4
+}
+
+fn default_max_children() -> NonZeroUsize {
+    NonZeroUsize::new(100).expect("static non-zero number")
+}
+
+fn default_max_patterns() -> NonZeroUsize {
+    NonZeroUsize::new(1_000).expect("static non-zero number")
+}
+
+impl GenerateConfig for LogPatternsConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"field = "message"
+            target = "pattern"
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "log_patterns")]
+impl TransformConfig for LogPatternsConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        if self.max_depth < 3 {
+            return Err("`max_depth` must be at least 3".into());
+        }
+        if !(0.0..=1.0).contains(&self.similarity_threshold) {
+            return Err("`similarity_threshold` must be between 0 and 1".into());
+        }
+
+        Ok(Transform::event_task(LogPatterns::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let kind = Kind::object(
+            Collection::empty()
+                .with_known("id", Kind::integer())
+                .with_known("template", Kind::bytes())
+                .with_known(
+                    "parameters",
+                    Kind::array(Collection::empty().with_unknown(Kind::bytes())),
+                ),
+        )
+        .or_undefined();
+
+        let mut schema_definition = clone_input_definitions(input_definitions);
+        for definition in schema_definition.values_mut() {
+            *definition = definition
+                .clone()
+                .with_field(&self.target.0, kind.clone(), None);
+        }
+
+        let data_type = if self.metrics.is_some() {
+            DataType::Log | DataType::Metric
+        } else {
+            DataType::Log
+        };
+
+        vec![TransformOutput::new(data_type, schema_definition)]
+    }
+}
+
+/// The number of messages matching a pattern since the last flush.
+#[derive(Debug)]
+struct PatternCount {
+    template: String,
+    count: u64,
+}
+
+#[derive(Debug)]
+struct PatternMetrics {
+    name: String,
+    namespace: Option<String>,
+    interval: Duration,
+    counts: HashMap<u64, PatternCount>,
+}
+
+impl PatternMetrics {
+    fn record(&mut self, pattern: &PatternMatch) {
+        let count = self
+            .counts
+            .entry(pattern.id)
+            .or_insert_with(|| PatternCount {
+                template: String::new(),
+                count: 0,
+            });
+        // Templates become more general as messages are added, so the latest one is kept.
+        count.template.clone_from(&pattern.template);
+        count.count += 1;
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        for (id, count) in self.counts.drain() {
+            let tags = MetricTags::from(BTreeMap::from([
+                ("pattern_id".to_owned(), id.to_string()),
+                ("pattern".to_owned(), count.template),
+            ]));
+            let metric = Metric::new(
+                self.name.clone(),
+                MetricKind::Incremental,
+                MetricValue::Counter {
+                    value: count.count as f64,
+                },
+            )
+            .with_namespace(self.namespace.clone())
+            .with_tags(Some(tags))
+            .with_timestamp(Some(chrono::Utc::now()));
+            output.push(metric.into());
+        }
+    }
+}
+
+pub struct LogPatterns {
+    field: ConfigTargetPath,
+    target: ConfigTargetPath,
+    drain: Drain,
+    metrics: Option<PatternMetrics>,
+}
+
+impl LogPatterns {
+    pub fn new(config: &LogPatternsConfig) -> Self {
+        Self {
+            field: config.field.clone(),
+            target: config.target.clone(),
+            drain: Drain::new(
+                config.max_depth,
+                config.max_children.get(),
+                config.similarity_threshold,
+                config.max_patterns,
+            ),
+            metrics: config.metrics.as_ref().map(|metrics| PatternMetrics {
+                name: metrics.name.clone(),
+                namespace: metrics.namespace.clone(),
+                interval: Duration::from_secs(metrics.flush_interval_secs),
+                counts: HashMap::new(),
+            }),
+        }
+    }
+
+    fn transform_one(&mut self, mut event: Event) -> Event {
+        let pattern = match event.as_log().get(&self.field) {
+            Some(Value::Bytes(message)) => self.drain.process(&String::from_utf8_lossy(message)),
+            _ => return event,
+        };
+
+        if pattern.created {
+            emit!(LogPatternCreated {
+                id: pattern.id,
+                template: &pattern.template,
+            });
+        }
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.record(&pattern);
+        }
+
+        let value = Value::from(BTreeMap::from([
+            ("id".to_owned(), Value::from(pattern.id as i64)),
+            ("template".to_owned(), Value::from(pattern.template)),
+            (
+                "parameters".to_owned(),
+                Value::Array(pattern.parameters.into_iter().map(Value::from).collect()),
+            ),
+        ]));
+        event.as_mut_log().insert(&self.target, value);
+        event
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.flush_into(output);
+        }
+    }
+}
+
+impl TaskTransform<Event> for LogPatterns {
+    fn transform(
+        mut self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let flush = self.metrics.as_ref().map(|metrics| metrics.interval);
+        let mut flush_stream = tokio::time::interval(
+            flush.unwrap_or(Duration::from_secs(default_flush_interval_secs())),
+        );
+
+        Box::pin(stream! {
+            let mut output = Vec::new();
+            let mut done = false;
+            while !done {
+                tokio::select! {
+                    _ = flush_stream.tick(), if flush.is_some() => {
+                        self.flush_into(&mut output);
+                    },
+                    maybe_event = input_rx.next() => {
+                        match maybe_event {
+                            None => {
+                                self.flush_into(&mut output);
+                                done = true;
+                            }
+                            Some(event) => output.push(self.transform_one(event)),
+                        }
+                    }
+                };
+                for event in output.drain(..) {
+                    yield event;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+    use crate::{
+        test_util::components::assert_transform_compliance, transforms::test::create_topology,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<LogPatternsConfig>();
+    }
+
+    #[tokio::test]
+    async fn assigns_patterns() {
+        assert_transform_compliance(async {
+            let (tx, rx) = mpsc::channel(1);
+            let config = toml::from_str::<LogPatternsConfig>("").unwrap();
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(LogEvent::from("GET /index.html 200 in 12ms").into())
+                .await
+                .unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(output["pattern.id"], 1.into());
+            assert_eq!(
+                output["pattern.template"],
+                "GET /index.html 200 in 12ms".into()
+            );
+            assert_eq!(output["pattern.parameters"], Value::Array(vec![]));
+
+            tx.send(LogEvent::from("GET /index.html 404 in 3ms").into())
+                .await
+                .unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(output["pattern.id"], 1.into());
+            assert_eq!(
+                output["pattern.template"],
+                "GET /index.html <*> in <*>".into()
+            );
+            assert_eq!(
+                output["pattern.parameters"],
+                Value::Array(vec!["404".into(), "3ms".into()])
+            );
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
+    #[test]
+    fn leaves_events_without_message_unchanged() {
+        let config = toml::from_str::<LogPatternsConfig>("").unwrap();
+        let mut transform = LogPatterns::new(&config);
+
+        let mut input = LogEvent::default();
+        input.insert("message", 42);
+        let input = Event::from(input);
+        assert_eq!(transform.transform_one(input.clone()), input);
+    }
+
+    #[test]
+    fn counts_events_per_pattern() {
+        let config = toml::from_str::<LogPatternsConfig>(
+            r#"
+            metrics.namespace = "test"
+            "#,
+        )
+        .unwrap();
+        let mut transform = LogPatterns::new(&config);
+
+        for message in ["worker 1 started", "worker 2 started", "shutting down now"] {
+            transform.transform_one(LogEvent::from(message).into());
+        }
+
+        let mut output = Vec::new();
+        transform.flush_into(&mut output);
+        let mut counts = output
+            .into_iter()
+            .map(|event| {
+                let metric = event.into_metric();
+                assert_eq!(metric.name(), "log_pattern_events_total");
+                assert_eq!(metric.namespace(), Some("test"));
+                let pattern = metric.tag_value("pattern").unwrap();
+                match metric.value() {
+                    MetricValue::Counter { value } => (pattern, *value),
+                    value => panic!("unexpected metric value {:?}", value),
+                }
+            })
+            .collect::<Vec<_>>();
+        counts.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            counts,
+            vec![
+                ("shutting down now".to_owned(), 1.0),
+                ("worker <*> started".to_owned(), 2.0),
+            ]
+        );
+
+        // Counts are reset on every flush.
+        let mut output = Vec::new();
+        transform.flush_into(&mut output);
+        assert!(output.is_empty());
+    }
+}
@@ -8,15 +8,35 @@ use snafu::Snafu;
 pub mod aggregate;
 #[cfg(feature = "transforms-aws_ec2_metadata")]
 pub mod aws_ec2_metadata;
+#[cfg(feature = "transforms-byte_budget")]
+pub mod byte_budget;
+#[cfg(feature = "transforms-cluster_exchange")]
+pub mod cluster_exchange;
 #[cfg(feature = "transforms-dedupe")]
 pub mod dedupe;
+#[cfg(feature = "transforms-delta")]
+pub mod delta;
+#[cfg(feature = "transforms-field_defaults")]
+pub mod field_defaults;
 #[cfg(feature = "transforms-filter")]
 pub mod filter;
+#[cfg(feature = "transforms-hash_bucket")]
+pub mod hash_bucket;
+#[cfg(feature = "transforms-join")]
+pub mod join;
+#[cfg(feature = "transforms-locale_normalize")]
+pub mod locale_normalize;
+#[cfg(feature = "transforms-log_patterns")]
+pub mod log_patterns;
 pub mod log_to_metric;
 #[cfg(feature = "transforms-lua")]
 pub mod lua;
 #[cfg(feature = "transforms-metric_to_log")]
 pub mod metric_to_log;
+#[cfg(feature = "transforms-pii_redact")]
+pub mod pii_redact;
+#[cfg(feature = "transforms-reassemble")]
+pub mod reassemble;
 #[cfg(feature = "transforms-reduce")]
 pub mod reduce;
 #[cfg(feature = "transforms-remap")]
@@ -25,14 +45,24 @@ pub mod remap;
 pub mod route;
 #[cfg(feature = "transforms-sample")]
 pub mod sample;
+#[cfg(feature = "transforms-schema_validation")]
+pub mod schema_validation;
+#[cfg(feature = "transforms-span_metrics")]
+pub mod span_metrics;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub mod tag_cardinality_limit;
 #[cfg(feature = "transforms-throttle")]
 pub mod throttle;
+#[cfg(feature = "transforms-unit_convert")]
+pub mod unit_convert;
+#[cfg(feature = "transforms-url_normalize")]
+pub mod url_normalize;
+#[cfg(feature = "transforms-user_agent")]
+pub mod user_agent;
 
 pub use vector_core::transform::{
-    FunctionTransform, OutputBuffer, SyncTransform, TaskTransform, Transform, TransformOutputs,
-    TransformOutputsBuf,
+    FunctionTransform, MultiOutputTaskTransform, OutputBuffer, SchemaValidator, SyncTransform,
+    TaskTransform, Transform, TransformOutputs, TransformOutputsBuf,
 };
 
 #[derive(Debug, Snafu)]
@@ -0,0 +1,259 @@
+use std::net::IpAddr;
+
+use regex::{Match, Regex, RegexBuilder};
+use vector_config::configurable_component;
+
+/// The kind of personally identifiable information a detector looks for.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The kind of detector."))]
+pub enum DetectorKind {
+    /// Email addresses.
+    Email,
+
+    /// Payment card numbers, of 13 to 19 digits optionally separated by spaces or dashes, that
+    /// pass the Luhn checksum.
+    CreditCard,
+
+    /// Phone numbers, in the international format, such as `+44 20 7946 0958`, or in the North
+    /// American format, such as `(555) 123-4567`.
+    PhoneNumber,
+
+    /// IPv4 and IPv6 addresses.
+    IpAddress,
+
+    /// National identification numbers.
+    ///
+    /// Currently, United States Social Security numbers in the `AAA-GG-SSSS` format.
+    NationalId,
+
+    /// Matches of a regular expression.
+    Regex {
+        /// The regular expression to match.
+        #[configurable(metadata(docs::examples = "\\bEMP-\\d{6}\\b"))]
+        pattern: String,
+    },
+
+    /// Occurrences of words from a dictionary.
+    Dictionary {
+        /// The words to look for.
+        ///
+        /// Words only match as whole words.
+        #[configurable(metadata(docs::examples = "project-falcon"))]
+        words: Vec<String>,
+
+        /// Whether or not the words are matched case sensitively.
+        #[serde(default)]
+        case_sensitive: bool,
+    },
+}
+
+impl DetectorKind {
+    /// The name of the detector, when it isn't given one.
+    pub const fn default_name(&self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::CreditCard => "credit_card",
+            Self::PhoneNumber => "phone_number",
+            Self::IpAddress => "ip_address",
+            Self::NationalId => "national_id",
+            Self::Regex { .. } => "regex",
+            Self::Dictionary { .. } => "dictionary",
+        }
+    }
+}
+
+/// A compiled detector.
+///
+/// Built-in detectors match candidates with a regular expression, and then validate them, such as
+/// with the checksum of credit card numbers, to avoid redacting everything that looks alike.
+#[derive(Clone, Debug)]
+pub struct Detector {
+    regex: Regex,
+    validate: Option<Validator>,
+}
+
+/// Validates a candidate match, given the string it was found in.
+type Validator = fn(&str, &Match<'_>) -> bool;
+
+impl Detector {
+    pub fn new(kind: &DetectorKind) -> crate::Result<Self> {
+        let (regex, validate): (Regex, Option<Validator>) = match kind {
+            DetectorKind::Email => (
+                Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}")?,
+                None,
+            ),
+            DetectorKind::CreditCard => {
+                (Regex::new(r"\b\d(?:[ -]?\d){12,18}\b")?, Some(luhn_valid))
+            }
+            DetectorKind::PhoneNumber => (
+                Regex::new(
+                    r"(?:\+\d{1,3}[ .-]?(?:\(\d{1,4}\)[ .-]?)?\d{1,4}(?:[ .-]?\d{2,4}){2,3}\b)|(?:(?:\(\d{3}\)\s?|\b\d{3}[ .-])\d{3}[ .-]\d{4}\b)",
+                )?,
+                None,
+            ),
+            DetectorKind::IpAddress => (
+                Regex::new(
+                    r"\b(?:\d{1,3}\.){3}\d{1,3}\b|(?:[0-9A-Fa-f]{0,4}:){2,7}[0-9A-Fa-f]{0,4}(?:%[0-9A-Za-z]+)?",
+                )?,
+                Some(ip_valid),
+            ),
+            DetectorKind::NationalId => (Regex::new(r"\b\d{3}-\d{2}-\d{4}\b")?, Some(ssn_valid)),
+            DetectorKind::Regex { pattern } => (Regex::new(pattern)?, None),
+            DetectorKind::Dictionary {
+                words,
+                case_sensitive,
+            } => {
+                if words.is_empty() {
+                    return Err("Dictionary detectors require at least one word.".into());
+                }
+                let alternatives = words
+                    .iter()
+                    .map(|word| regex::escape(word))
+                    .collect::<Vec<_>>()
+                    .join("|");
+                let regex = RegexBuilder::new(&format!(r"\b(?:{})\b", alternatives))
+                    .case_insensitive(!case_sensitive)
+                    .build()?;
+                (regex, None)
+            }
+        };
+        Ok(Self { regex, validate })
+    }
+
+    /// Finds the byte ranges of the matches in the given string.
+    pub fn find(&self, s: &str) -> Vec<(usize, usize)> {
+        self.regex
+            .find_iter(s)
+            .filter(|m| self.validate.map_or(true, |validate| validate(s, m)))
+            .map(|m| (m.start(), m.end()))
+            .collect()
+    }
+}
+
+fn luhn_valid(_: &str, candidate: &Match<'_>) -> bool {
+    let digits = candidate
+        .as_str()
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|b| u32::from(b - b'0'))
+        .collect::<Vec<_>>();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| match (i % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+fn ip_valid(s: &str, candidate: &Match<'_>) -> bool {
+    // Addresses can't be part of a longer token, like the `d::f` of `std::fmt`.
+    let part_of_token = |c: char| c.is_alphanumeric() || c == ':' || c == '_';
+    if s[..candidate.start()]
+        .chars()
+        .next_back()
+        .map_or(false, part_of_token)
+        || s[candidate.end()..]
+            .chars()
+            .next()
+            .map_or(false, part_of_token)
+    {
+        return false;
+    }
+
+    // Zone indices aren't part of the address.
+    let address = candidate.as_str().split('%').next().unwrap_or_default();
+    address.parse::<IpAddr>().is_ok()
+}
+
+fn ssn_valid(_: &str, candidate: &Match<'_>) -> bool {
+    let mut parts = candidate.as_str().split('-');
+    let (Some(area), Some(group), Some(serial)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    area != "000" && area != "666" && !area.starts_with('9') && group != "00" && serial != "0000"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(kind: DetectorKind, s: &str) -> Vec<&str> {
+        Detector::new(&kind)
+            .unwrap()
+            .find(s)
+            .into_iter()
+            .map(|(start, end)| &s[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn emails() {
+        assert_eq!(
+            matches(
+                DetectorKind::Email,
+                "from jane.doe+alerts@example.co.uk to ops@example"
+            ),
+            vec!["jane.doe+alerts@example.co.uk"]
+        );
+    }
+
+    #[test]
+    fn credit_cards() {
+        assert_eq!(
+            matches(
+                DetectorKind::CreditCard,
+                "paid with 4111 1111 1111 1111, not 4111 1111 1111 1112 or 1234567890123"
+            ),
+            vec!["4111 1111 1111 1111"]
+        );
+    }
+
+    #[test]
+    fn phone_numbers() {
+        assert_eq!(
+            matches(
+                DetectorKind::PhoneNumber,
+                "call +44 20 7946 0958 or (555) 123-4567, order 2023"
+            ),
+            vec!["+44 20 7946 0958", "(555) 123-4567"]
+        );
+    }
+
+    #[test]
+    fn ip_addresses() {
+        assert_eq!(
+            matches(
+                DetectorKind::IpAddress,
+                "from 192.168.1.10 and 2001:db8::1 at 12:30, not 999.1.1.1 or std::fmt"
+            ),
+            vec!["192.168.1.10", "2001:db8::1"]
+        );
+    }
+
+    #[test]
+    fn national_ids() {
+        assert_eq!(
+            matches(DetectorKind::NationalId, "ssn 123-45-6789, not 666-45-6789"),
+            vec!["123-45-6789"]
+        );
+    }
+
+    #[test]
+    fn dictionaries() {
+        let kind = DetectorKind::Dictionary {
+            words: vec!["falcon".to_owned(), "kestrel".to_owned()],
+            case_sensitive: false,
+        };
+        assert_eq!(
+            matches(kind, "Project Falcon, falconry and KESTREL"),
+            vec!["Falcon", "KESTREL"]
+        );
+    }
+}
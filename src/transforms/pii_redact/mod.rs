@@ -0,0 +1,459 @@
+use std::borrow::Cow;
+
+use lookup::lookup_v2::ConfigTargetPath;
+use sha2::{Digest, Sha256};
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::config::{clone_input_definitions, LogNamespace};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, Value},
+    internal_events::PiiDetected,
+    schema,
+    transforms::{SyncTransform, Transform, TransformOutputsBuf},
+};
+
+mod detector;
+
+use self::detector::Detector;
+pub use self::detector::DetectorKind;
+
+/// The name of the output for the events rerouted by a detector.
+const REROUTED_OUTPUT: &str = "rerouted";
+
+/// What is done with the information found by a detector.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiAction {
+    /// Replaces the information with the `mask`.
+    #[default]
+    Mask,
+
+    /// Replaces the information with the hex-encoded SHA-256 hash of the `hash_salt` followed by
+    /// the information.
+    ///
+    /// Unlike masking, this preserves the ability to correlate events carrying the same
+    /// information.
+    Hash,
+
+    /// Removes the field containing the information.
+    DropField,
+
+    /// Sends the event to the `rerouted` output, leaving the information in place.
+    ///
+    /// The other detectors still apply to the event.
+    Reroute,
+}
+
+impl PiiAction {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Mask => "mask",
+            Self::Hash => "hash",
+            Self::DropField => "drop_field",
+            Self::Reroute => "reroute",
+        }
+    }
+}
+
+/// Configuration of a detector.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct DetectorConfig {
+    /// The name of the detector, used as the `detector` tag of its metrics.
+    ///
+    /// Defaults to the type of the detector.
+    #[configurable(metadata(docs::examples = "employee_id"))]
+    pub name: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub kind: DetectorKind,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub action: PiiAction,
+}
+
+/// Configuration for the `pii_redact` transform.
+#[configurable_component(transform(
+    "pii_redact",
+    "Detect and redact personally identifiable information in log events."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct PiiRedactConfig {
+    /// The fields to scan.
+    ///
+    /// If empty, all the string values of the event are scanned, including those nested in
+    /// objects and arrays.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "message"))]
+    #[configurable(metadata(docs::examples = "request.query"))]
+    pub fields: Vec<ConfigTargetPath>,
+
+    /// The detectors to apply, in order.
+    ///
+    /// Each detector sees the values as redacted by the detectors before it.
+    pub detectors: Vec<DetectorConfig>,
+
+    /// The text that masked information is replaced with.
+    #[serde(default = "default_mask")]
+    pub mask: String,
+
+    /// The salt that hashed information is prefixed with before being hashed.
+    ///
+    /// Without a salt, the hashes of information with few possible values, such as phone numbers,
+    /// can be reversed by hashing all of the values.
+    pub hash_salt: Option<SensitiveString>,
+}
+
+fn default_mask() -> String {
+    "[REDACTED]".to_owned()
+}
+
+impl GenerateConfig for PiiRedactConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"fields = ["message"]
+            detectors = [
+                { type = "email" },
+                { type = "credit_card", action = "drop_field" },
+            ]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "pii_redact")]
+impl TransformConfig for PiiRedactConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        PiiRedact::new(self).map(Transform::synchronous)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![
+            TransformOutput::new(DataType::Log, clone_input_definitions(input_definitions)),
+            TransformOutput::new(DataType::Log, clone_input_definitions(input_definitions))
+                .with_port(REROUTED_OUTPUT),
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct NamedDetector {
+    name: String,
+    detector: Detector,
+    action: PiiAction,
+}
+
+#[derive(Clone, Debug)]
+pub struct PiiRedact {
+    fields: Vec<ConfigTargetPath>,
+    detectors: Vec<NamedDetector>,
+    mask: String,
+    hash_salt: String,
+}
+
+/// What was found while scanning an event.
+struct Findings {
+    /// The number of matches of each detector.
+    counts: Vec<usize>,
+    reroute: bool,
+}
+
+impl PiiRedact {
+    pub fn new(config: &PiiRedactConfig) -> crate::Result<Self> {
+        let detectors = config
+            .detectors
+            .iter()
+            .map(|detector| {
+                let name = detector
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| detector.kind.default_name().to_owned());
+                Detector::new(&detector.kind)
+                    .map(|compiled| NamedDetector {
+                        name: name.clone(),
+                        detector: compiled,
+                        action: detector.action,
+                    })
+                    .map_err(|error| format!("Invalid detector {:?}: {}", name, error).into())
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            fields: config.fields.clone(),
+            detectors,
+            mask: config.mask.clone(),
+            hash_salt: config
+                .hash_salt
+                .as_ref()
+                .map(|salt| salt.inner().to_owned())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Redacts a value, returning `true` if it must be dropped.
+    fn redact_value(&self, value: &mut Value, findings: &mut Findings) -> bool {
+        match value {
+            Value::Bytes(bytes) => {
+                let redacted = match self.redact_str(&String::from_utf8_lossy(bytes), findings) {
+                    None => return true,
+                    Some(Cow::Borrowed(_)) => None,
+                    Some(Cow::Owned(redacted)) => Some(redacted),
+                };
+                if let Some(redacted) = redacted {
+                    *value = Value::from(redacted);
+                }
+                false
+            }
+            Value::Object(map) => {
+                map.retain(|_, value| !self.redact_value(value, findings));
+                false
+            }
+            Value::Array(values) => {
+                values.retain_mut(|value| !self.redact_value(value, findings));
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Redacts a string, returning `None` if its field must be dropped.
+    fn redact_str<'a>(&self, s: &'a str, findings: &mut Findings) -> Option<Cow<'a, str>> {
+        let mut s = Cow::Borrowed(s);
+        for (i, detector) in self.detectors.iter().enumerate() {
+            let matches = detector.detector.find(&s);
+            if matches.is_empty() {
+                continue;
+            }
+            findings.counts[i] += matches.len();
+
+            match detector.action {
+                PiiAction::Mask => s = Cow::Owned(replace(&s, &matches, |_| self.mask.clone())),
+                PiiAction::Hash => s = Cow::Owned(replace(&s, &matches, |m| self.hash(m))),
+                PiiAction::DropField => return None,
+                PiiAction::Reroute => findings.reroute = true,
+            }
+        }
+        Some(s)
+    }
+
+    fn hash(&self, s: &str) -> String {
+        hex::encode(
+            Sha256::new()
+                .chain_update(&self.hash_salt)
+                .chain_update(s)
+                .finalize(),
+        )
+    }
+}
+
+/// Replaces the given byte ranges of a string.
+fn replace(s: &str, ranges: &[(usize, usize)], with: impl Fn(&str) -> String) -> String {
+    let mut replaced = String::with_capacity(s.len());
+    let mut last = 0;
+    for &(start, end) in ranges {
+        replaced.push_str(&s[last..start]);
+        replaced.push_str(&with(&s[start..end]));
+        last = end;
+    }
+    replaced.push_str(&s[last..]);
+    replaced
+}
+
+impl SyncTransform for PiiRedact {
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        let mut log = event.into_log();
+        let mut findings = Findings {
+            counts: vec![0; self.detectors.len()],
+            reroute: false,
+        };
+
+        if self.fields.is_empty() {
+            if self.redact_value(log.value_mut(), &mut findings) {
+                // The event itself is a string, when using the `Vector` namespace.
+                *log.value_mut() = Value::Null;
+            }
+        } else {
+            for field in &self.fields {
+                let drop = log
+                    .get_mut(field)
+                    .map_or(false, |value| self.redact_value(value, &mut findings));
+                if drop {
+                    log.remove(field);
+                }
+            }
+        }
+
+        for (detector, count) in self.detectors.iter().zip(findings.counts) {
+            if count > 0 {
+                emit!(PiiDetected {
+                    detector: &detector.name,
+                    action: detector.action.as_str(),
+                    count,
+                });
+            }
+        }
+
+        if findings.reroute {
+            output.push_named(REROUTED_OUTPUT, log.into());
+        } else {
+            output.push(log.into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::transform::TransformOutputsBuf;
+
+    use super::*;
+    use crate::event::LogEvent;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<PiiRedactConfig>();
+    }
+
+    fn pii_redact(config: &str) -> (PiiRedact, TransformOutputsBuf) {
+        let config = toml::from_str::<PiiRedactConfig>(config).unwrap();
+        let outputs = config.outputs(
+            enrichment::TableRegistry::default(),
+            &[(
+                "test".into(),
+                schema::Definition::default_legacy_namespace(),
+            )],
+            LogNamespace::Legacy,
+        );
+        let transform = PiiRedact::new(&config).unwrap();
+        (
+            transform,
+            TransformOutputsBuf::new_with_capacity(outputs, 1),
+        )
+    }
+
+    fn transform_one(transform: &mut PiiRedact, buf: &mut TransformOutputsBuf, log: LogEvent) {
+        transform.transform(log.into(), buf);
+    }
+
+    #[test]
+    fn masks_all_strings() {
+        let (mut transform, mut buf) = pii_redact(
+            r#"
+            detectors = [{ type = "email" }, { type = "ip_address" }]
+            "#,
+        );
+
+        let mut log = LogEvent::from("login by jane@example.com");
+        log.insert("client.addresses", vec!["10.0.0.1", "example.com"]);
+        log.insert("status", 200);
+        transform_one(&mut transform, &mut buf, log);
+
+        let log = buf.take_primary().into_events().next().unwrap().into_log();
+        assert_eq!(log["message"], "login by [REDACTED]".into());
+        assert_eq!(
+            log["client.addresses"],
+            vec!["[REDACTED]", "example.com"].into()
+        );
+        assert_eq!(log["status"], 200.into());
+    }
+
+    #[test]
+    fn hashes_with_salt() {
+        let (mut transform, mut buf) = pii_redact(
+            r#"
+            fields = ["message"]
+            hash_salt = "pepper"
+            detectors = [{ type = "email", action = "hash" }]
+            "#,
+        );
+
+        transform_one(&mut transform, &mut buf, LogEvent::from("jane@example.com"));
+
+        let log = buf.take_primary().into_events().next().unwrap().into_log();
+        let expected = hex::encode(Sha256::digest("pepperjane@example.com"));
+        assert_eq!(log["message"], expected.into());
+    }
+
+    #[test]
+    fn drops_fields() {
+        let (mut transform, mut buf) = pii_redact(
+            r#"
+            fields = ["message", "card"]
+            detectors = [{ type = "credit_card", action = "drop_field" }]
+            "#,
+        );
+
+        let mut log = LogEvent::from("paid");
+        log.insert("card", "4111-1111-1111-1111");
+        transform_one(&mut transform, &mut buf, log);
+
+        let log = buf.take_primary().into_events().next().unwrap().into_log();
+        assert_eq!(log["message"], "paid".into());
+        assert!(!log.contains("card"));
+    }
+
+    #[test]
+    fn reroutes_events() {
+        let (mut transform, mut buf) = pii_redact(
+            r#"
+            detectors = [
+                { type = "regex", name = "employee_id", pattern = "EMP-\\d{6}", action = "reroute" },
+                { type = "national_id" },
+            ]
+            "#,
+        );
+
+        transform_one(
+            &mut transform,
+            &mut buf,
+            LogEvent::from("EMP-123456 has ssn 123-45-6789"),
+        );
+        transform_one(&mut transform, &mut buf, LogEvent::from("nothing to see"));
+
+        let primary = buf.take_primary().into_events().collect::<Vec<_>>();
+        assert_eq!(primary.len(), 1);
+        assert_eq!(primary[0].as_log()["message"], "nothing to see".into());
+
+        let rerouted = buf
+            .take_all_named()
+            .remove(REROUTED_OUTPUT)
+            .unwrap()
+            .into_events()
+            .collect::<Vec<_>>();
+        assert_eq!(rerouted.len(), 1);
+        assert_eq!(
+            rerouted[0].as_log()["message"],
+            "EMP-123456 has ssn [REDACTED]".into()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_regex() {
+        let config = toml::from_str::<PiiRedactConfig>(
+            r#"
+            detectors = [{ type = "regex", pattern = "(" }]
+            "#,
+        )
+        .unwrap();
+        assert!(PiiRedact::new(&config).is_err());
+    }
+}
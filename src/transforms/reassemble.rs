@@ -0,0 +1,485 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use bytes::{BufMut, BytesMut};
+use futures::{stream, Stream, StreamExt};
+use indexmap::IndexMap;
+use serde_with::serde_as;
+use vector_config::configurable_component;
+use vector_core::config::{clone_input_definitions, LogNamespace};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{discriminant::Discriminant, Event, LogEvent, Value},
+    internal_events::{ReassembleEventsDropped, ReassembleIncompleteFlushed},
+    schema::Definition,
+    transforms::{TaskTransform, Transform},
+};
+
+/// What to do with messages that are still missing parts when their timeout ends.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IncompleteStrategy {
+    /// Emit the message reassembled from the parts received so far.
+    #[default]
+    Emit,
+
+    /// Drop the parts of the message.
+    Drop,
+}
+
+/// Configuration for the `reassemble` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "reassemble",
+    "Reassemble messages split across several events into a single event."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ReassembleConfig {
+    /// An ordered list of fields identifying the message that an event is a part of.
+    ///
+    /// Parts are reassembled when they have the same values for all of these fields.
+    #[configurable(metadata(docs::examples = "message_id"))]
+    pub group_by: Vec<String>,
+
+    /// The field containing the number of the part, by which parts are ordered.
+    ///
+    /// Parts may be numbered from any integer, and may be received in any order.
+    #[configurable(metadata(docs::examples = "part"))]
+    pub part_field: String,
+
+    /// The field containing the total number of parts of the message.
+    #[configurable(metadata(docs::examples = "total"))]
+    pub total_field: String,
+
+    /// The field whose values are concatenated, in the order of the parts.
+    #[serde(default = "default_merge_field")]
+    #[configurable(metadata(docs::examples = "message"))]
+    pub merge_field: String,
+
+    /// The string inserted between the values of consecutive parts.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "\n"))]
+    pub separator: String,
+
+    /// The maximum period of time to wait for all the parts of a message, in milliseconds, after
+    /// its first part is received.
+    #[serde(default = "default_timeout_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub timeout_ms: Duration,
+
+    /// The interval to check for and flush any expired messages, in milliseconds.
+    #[serde(default = "default_flush_period_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub flush_period_ms: Duration,
+
+    /// The maximum number of messages waiting for their remaining parts.
+    ///
+    /// When reached, the oldest pending message is flushed early, according to `incomplete`.
+    #[serde(default = "default_max_pending")]
+    pub max_pending: NonZeroUsize,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub incomplete: IncompleteStrategy,
+}
+
+fn default_merge_field() -> String {
+    "message".to_owned()
+}
+
+const fn default_timeout_ms() -> Duration {
+    Duration::from_millis(30000)
+}
+
+const fn default_flush_period_ms() -> Duration {
+    Duration::from_millis(1000)
+}
+
+fn default_max_pending() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl GenerateConfig for ReassembleConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"group_by = ["message_id"]
+            part_field = "part"
+            total_field = "total""#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "reassemble")]
+impl TransformConfig for ReassembleConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Reassemble::new(self).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(
+            DataType::Log,
+            clone_input_definitions(input_definitions),
+        )]
+    }
+}
+
+/// The parts of a message received so far.
+struct PendingMessage {
+    started: Instant,
+    total: u64,
+    parts: BTreeMap<i64, LogEvent>,
+}
+
+pub struct Reassemble {
+    group_by: Vec<String>,
+    part_field: String,
+    total_field: String,
+    merge_field: String,
+    separator: String,
+    timeout: Duration,
+    flush_period: Duration,
+    max_pending: usize,
+    incomplete: IncompleteStrategy,
+    // Pending messages, from the oldest to the newest.
+    pending: IndexMap<Discriminant, PendingMessage>,
+}
+
+impl Reassemble {
+    pub fn new(config: &ReassembleConfig) -> crate::Result<Self> {
+        if config.group_by.is_empty() {
+            return Err("at least one `group_by` field must be provided".into());
+        }
+
+        Ok(Self {
+            group_by: config.group_by.clone(),
+            part_field: config.part_field.clone(),
+            total_field: config.total_field.clone(),
+            merge_field: config.merge_field.clone(),
+            separator: config.separator.clone(),
+            timeout: config.timeout_ms,
+            flush_period: config.flush_period_ms,
+            max_pending: config.max_pending.get(),
+            incomplete: config.incomplete,
+            pending: IndexMap::new(),
+        })
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let log = event.into_log();
+
+        // Events that aren't parts of a message are passed through.
+        let part = log
+            .get(self.part_field.as_str())
+            .and_then(Value::as_integer);
+        let total = log
+            .get(self.total_field.as_str())
+            .and_then(Value::as_integer)
+            .and_then(|total| u64::try_from(total).ok());
+        let (Some(part), Some(total)) = (part, total) else {
+            output.push(log.into());
+            return;
+        };
+        if self
+            .group_by
+            .iter()
+            .any(|field| !log.contains(field.as_str()))
+        {
+            output.push(log.into());
+            return;
+        }
+        let discriminant = Discriminant::from_log_event(&log, &self.group_by);
+
+        if !self.pending.contains_key(&discriminant) && self.pending.len() >= self.max_pending {
+            if let Some((_, message)) = self.pending.shift_remove_index(0) {
+                self.flush_incomplete(output, message);
+            }
+        }
+
+        let message = self
+            .pending
+            .entry(discriminant.clone())
+            .or_insert_with(|| PendingMessage {
+                started: Instant::now(),
+                total,
+                parts: BTreeMap::new(),
+            });
+
+        if message.parts.contains_key(&part) {
+            emit!(ReassembleEventsDropped {
+                count: 1,
+                reason: "Part was already received.",
+            });
+            return;
+        }
+        message.parts.insert(part, log);
+
+        if message.parts.len() as u64 >= message.total {
+            if let Some(message) = self.pending.shift_remove(&discriminant) {
+                output.push(self.reassembled(message));
+            }
+        }
+    }
+
+    fn flush_incomplete(&self, output: &mut Vec<Event>, message: PendingMessage) {
+        match self.incomplete {
+            IncompleteStrategy::Drop => emit!(ReassembleEventsDropped {
+                count: message.parts.len(),
+                reason: "Message was not complete within its timeout.",
+            }),
+            IncompleteStrategy::Emit => {
+                emit!(ReassembleIncompleteFlushed);
+                output.push(self.reassembled(message));
+            }
+        }
+    }
+
+    /// Flushes the messages whose timeout has ended.
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        let now = Instant::now();
+        // Messages are ordered by when they started, so only the oldest ones can have expired.
+        while let Some((_, message)) = self.pending.first() {
+            if now - message.started < self.timeout {
+                break;
+            }
+            if let Some((_, message)) = self.pending.shift_remove_index(0) {
+                self.flush_incomplete(output, message);
+            }
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        for (_, message) in std::mem::take(&mut self.pending) {
+            self.flush_incomplete(output, message);
+        }
+    }
+
+    /// Builds the reassembled event from the first part, with the `merge_field` values of all the
+    /// parts concatenated in order.
+    fn reassembled(&self, message: PendingMessage) -> Event {
+        let mut parts = message.parts.into_values();
+        let mut log = parts.next().expect("messages have at least one part");
+
+        let mut merged = BytesMut::new();
+        let mut append = |value: Option<Value>| {
+            if let Some(value) = value {
+                if !merged.is_empty() {
+                    merged.put_slice(self.separator.as_bytes());
+                }
+                merged.put_slice(value.coerce_to_bytes().as_ref());
+            }
+        };
+
+        append(log.remove(self.merge_field.as_str()));
+        for mut part in parts {
+            append(part.remove(self.merge_field.as_str()));
+            let (_, metadata) = part.into_parts();
+            log.metadata_mut().merge(metadata);
+        }
+
+        log.insert(self.merge_field.as_str(), merged.freeze());
+        log.remove(self.part_field.as_str());
+        Event::from(log)
+    }
+}
+
+impl TaskTransform<Event> for Reassemble {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+              loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                      me.flush_into(&mut output);
+                      false
+                    }
+                    maybe_event = input_rx.next() => {
+                      match maybe_event {
+                        None => {
+                          me.flush_all_into(&mut output);
+                          true
+                        }
+                        Some(event) => {
+                          me.transform_one(&mut output, event);
+                          false
+                        }
+                      }
+                    }
+                };
+                yield stream::iter(output.into_iter());
+                if done { break }
+              }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::test_util::components::assert_transform_compliance;
+    use crate::transforms::test::create_topology;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<ReassembleConfig>();
+    }
+
+    fn part(message_id: &str, part: i64, total: i64, message: &str) -> Event {
+        let mut log = LogEvent::from(message);
+        log.insert("message_id", message_id);
+        log.insert("part", part);
+        log.insert("total", total);
+        log.into()
+    }
+
+    #[tokio::test]
+    async fn reassembles_parts_out_of_order() {
+        assert_transform_compliance(async move {
+            let config: ReassembleConfig = toml::from_str(
+                r#"
+                group_by = ["message_id"]
+                part_field = "part"
+                total_field = "total"
+                timeout_ms = 100
+                flush_period_ms = 10
+                separator = " "
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(part("a", 2, 3, "brown")).await.unwrap();
+            tx.send(part("b", 1, 2, "jumps")).await.unwrap();
+            tx.send(part("a", 3, 3, "fox")).await.unwrap();
+            tx.send(part("a", 1, 3, "the quick")).await.unwrap();
+
+            let log = out.recv().await.unwrap().into_log();
+            assert_eq!(log["message"], "the quick brown fox".into());
+            assert_eq!(log["message_id"], "a".into());
+            assert_eq!(log["total"], 3.into());
+            assert!(log.get("part").is_none());
+
+            drop(tx);
+            topology.stop().await;
+            // The incomplete message `b` is emitted with the parts received so far.
+            let log = out.recv().await.unwrap().into_log();
+            assert_eq!(log["message"], "jumps".into());
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
+    #[test]
+    fn passes_through_events_that_are_not_parts() {
+        let config: ReassembleConfig = toml::from_str(
+            r#"
+            group_by = ["message_id"]
+            part_field = "part"
+            total_field = "total"
+            timeout_ms = 100
+            flush_period_ms = 10
+            "#,
+        )
+        .unwrap();
+        let mut reassemble = Reassemble::new(&config).unwrap();
+        let mut output = Vec::new();
+
+        let mut log = LogEvent::from("not chunked");
+        log.insert("message_id", "a");
+        reassemble.transform_one(&mut output, log.into());
+        reassemble.transform_one(&mut output, part("a", 1, 1, "single"));
+
+        assert_eq!(output.len(), 2);
+        assert_eq!(output[0].as_log()["message"], "not chunked".into());
+        assert_eq!(output[1].as_log()["message"], "single".into());
+        assert!(reassemble.pending.is_empty());
+    }
+
+    #[test]
+    fn drops_duplicate_parts() {
+        let config: ReassembleConfig = toml::from_str(
+            r#"
+            group_by = ["message_id"]
+            part_field = "part"
+            total_field = "total"
+            timeout_ms = 100
+            flush_period_ms = 10
+            "#,
+        )
+        .unwrap();
+        let mut reassemble = Reassemble::new(&config).unwrap();
+        let mut output = Vec::new();
+
+        reassemble.transform_one(&mut output, part("a", 0, 2, "first"));
+        reassemble.transform_one(&mut output, part("a", 0, 2, "again"));
+        reassemble.transform_one(&mut output, part("a", 1, 2, "-second"));
+
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].as_log()["message"], "first-second".into());
+    }
+
+    #[test]
+    fn flushes_oldest_on_overflow() {
+        let config: ReassembleConfig = toml::from_str(
+            r#"
+            group_by = ["message_id"]
+            part_field = "part"
+            total_field = "total"
+            timeout_ms = 100
+            flush_period_ms = 10
+            max_pending = 1
+            incomplete = "drop"
+            "#,
+        )
+        .unwrap();
+        let mut reassemble = Reassemble::new(&config).unwrap();
+        let mut output = Vec::new();
+
+        reassemble.transform_one(&mut output, part("a", 1, 2, "a1"));
+        reassemble.transform_one(&mut output, part("b", 1, 2, "b1"));
+        reassemble.transform_one(&mut output, part("a", 2, 2, "a2"));
+
+        // `a` was dropped when `b` started, so its second part starts a new message.
+        assert!(output.is_empty());
+        assert_eq!(reassemble.pending.len(), 1);
+        assert_eq!(reassemble.pending[0].parts.len(), 1);
+    }
+}
@@ -514,11 +514,13 @@ impl ReduceValueMerger for MinNumberMerger {
     }
 }
 
-pub trait ReduceValueMerger: std::fmt::Debug + Send + Sync {
+pub trait ReduceValueMerger: std::fmt::Debug + Send + Sync + dyn_clone::DynClone {
     fn add(&mut self, v: Value) -> Result<(), String>;
     fn insert_into(self: Box<Self>, k: String, v: &mut LogEvent) -> Result<(), String>;
 }
 
+dyn_clone::clone_trait_object!(ReduceValueMerger);
+
 impl From<Value> for Box<dyn ReduceValueMerger> {
     fn from(v: Value) -> Self {
         match v {
@@ -7,7 +7,7 @@ use std::{
 };
 
 use async_stream::stream;
-use futures::{stream, Stream, StreamExt};
+use futures::{future, stream, Stream, StreamExt};
 use indexmap::IndexMap;
 use lookup::lookup_v2::parse_target_path;
 use lookup::PathPrefix;
@@ -18,10 +18,13 @@ use crate::config::OutputId;
 use crate::{
     conditions::{AnyCondition, Condition},
     config::{DataType, Input, TransformConfig, TransformContext, TransformOutput},
-    event::{discriminant::Discriminant, Event, EventMetadata, LogEvent},
-    internal_events::ReduceStaleEventFlushed,
+    event::{
+        discriminant::Discriminant, into_event_stream, EstimatedJsonEncodedSizeOf, Event,
+        EventArray, EventMetadata, LogEvent,
+    },
+    internal_events::{ReduceGroupEvicted, ReduceStaleEventFlushed},
     schema,
-    transforms::{TaskTransform, Transform},
+    transforms::{MultiOutputTaskTransform, TaskTransform, Transform, TransformOutputsBuf},
 };
 
 mod merge_strategy;
@@ -32,6 +35,9 @@ use value::kind::Collection;
 use value::Kind;
 use vector_core::config::LogNamespace;
 
+/// The name of the output for the events of the groups evicted by `max_groups`.
+const EVICTED_OUTPUT: &str = "evicted";
+
 /// Configuration for the `reduce` transform.
 #[serde_as]
 #[configurable_component(transform(
@@ -58,6 +64,26 @@ pub struct ReduceConfig {
     /// The maximum number of events to group together.
     pub max_events: Option<NonZeroUsize>,
 
+    /// The maximum size of the events to group together, in bytes.
+    ///
+    /// The size of a group is the sum of the estimated JSON encoded sizes of its events. The group
+    /// is flushed as soon as its size reaches this limit.
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_bytes: Option<NonZeroUsize>,
+
+    /// The maximum number of groups to reduce at the same time.
+    ///
+    /// When an event starts a new group while this limit is reached, the group that was updated
+    /// least recently is evicted, and flushed as is, to make room for the new group.
+    pub max_groups: Option<NonZeroUsize>,
+
+    /// Whether or not to send the events of the groups evicted by `max_groups` to the `evicted`
+    /// output, instead of the default output.
+    ///
+    /// This allows telling the events that may have been truncated apart from the complete ones.
+    #[serde(default)]
+    pub reroute_evicted: bool,
+
     /// An ordered list of fields by which to group events.
     ///
     /// Each group with matching values for the specified keys is reduced independently, allowing
@@ -102,6 +128,13 @@ pub struct ReduceConfig {
     /// If this condition resolves to `true` for an event, the previous transaction is flushed
     /// (without this event) and a new transaction is started.
     pub starts_when: Option<AnyCondition>,
+
+    /// A condition used to distinguish the complete combined events.
+    ///
+    /// Unlike `ends_when`, this condition is checked against the combined event of the group,
+    /// after each event is merged into it. If it resolves to `true`, the group is immediately
+    /// flushed.
+    pub flush_condition: Option<AnyCondition>,
 }
 
 const fn default_expire_after_ms() -> Duration {
@@ -118,7 +151,7 @@ impl_generate_config_from_default!(ReduceConfig);
 #[typetag::serde(name = "reduce")]
 impl TransformConfig for ReduceConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Reduce::new(self, &context.enrichment_tables).map(Transform::event_task)
+        Reduce::new(self, &context.enrichment_tables).map(Transform::multi_output_task)
     }
 
     fn input(&self) -> Input {
@@ -221,13 +254,23 @@ impl TransformConfig for ReduceConfig {
             output_definitions.insert(output.clone(), schema_definition);
         }
 
-        vec![TransformOutput::new(DataType::Log, output_definitions)]
+        let mut outputs = vec![TransformOutput::new(
+            DataType::Log,
+            output_definitions.clone(),
+        )];
+        if self.reroute_evicted {
+            outputs.push(
+                TransformOutput::new(DataType::Log, output_definitions).with_port(EVICTED_OUTPUT),
+            );
+        }
+        outputs
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct ReduceState {
     events: usize,
+    bytes: usize,
     fields: HashMap<String, Box<dyn ReduceValueMerger>>,
     stale_since: Instant,
     metadata: EventMetadata,
@@ -240,6 +283,7 @@ impl ReduceState {
 
         Self {
             events: 0,
+            bytes: 0,
             stale_since: Instant::now(),
             fields,
             metadata,
@@ -247,6 +291,7 @@ impl ReduceState {
     }
 
     fn add_event(&mut self, e: LogEvent, strategies: &IndexMap<String, MergeStrategy>) {
+        self.bytes += e.estimated_json_encoded_size_of();
         let (value, metadata) = e.into_parts();
         self.metadata.merge(metadata);
 
@@ -304,7 +349,12 @@ pub struct Reduce {
     reduce_merge_states: HashMap<Discriminant, ReduceState>,
     ends_when: Option<Condition>,
     starts_when: Option<Condition>,
+    flush_condition: Option<Condition>,
     max_events: Option<usize>,
+    max_bytes: Option<usize>,
+    max_groups: Option<usize>,
+    reroute_evicted: bool,
+    evicted: Vec<Event>,
 }
 
 impl Reduce {
@@ -326,6 +376,11 @@ impl Reduce {
             .as_ref()
             .map(|c| c.build(enrichment_tables))
             .transpose()?;
+        let flush_condition = config
+            .flush_condition
+            .as_ref()
+            .map(|c| c.build(enrichment_tables))
+            .transpose()?;
         let group_by = config.group_by.clone().into_iter().collect();
         let max_events = config.max_events.map(|max| max.into());
 
@@ -337,7 +392,12 @@ impl Reduce {
             reduce_merge_states: HashMap::new(),
             ends_when,
             starts_when,
+            flush_condition,
             max_events,
+            max_bytes: config.max_bytes.map(Into::into),
+            max_groups: config.max_groups.map(Into::into),
+            reroute_evicted: config.reroute_evicted,
+            evicted: Vec::new(),
         })
     }
 
@@ -363,15 +423,56 @@ impl Reduce {
             .for_each(|(_, s)| output.push(Event::from(s.flush())));
     }
 
-    fn push_or_new_reduce_state(&mut self, event: LogEvent, discriminant: Discriminant) {
-        match self.reduce_merge_states.entry(discriminant) {
-            hash_map::Entry::Vacant(entry) => {
-                let mut state = ReduceState::new();
-                state.add_event(event, &self.merge_strategies);
-                entry.insert(state);
+    /// Evicts the group updated least recently if the maximum number of groups is reached.
+    fn evict_if_full(&mut self) {
+        let Some(max_groups) = self.max_groups else {
+            return;
+        };
+        if self.reduce_merge_states.len() < max_groups {
+            return;
+        }
+
+        let oldest = self
+            .reduce_merge_states
+            .iter()
+            .min_by_key(|(_, state)| state.stale_since)
+            .map(|(discriminant, _)| discriminant.clone());
+        if let Some(state) = oldest.and_then(|k| self.reduce_merge_states.remove(&k)) {
+            emit!(ReduceGroupEvicted);
+            self.evicted.push(state.flush().into());
+        }
+    }
+
+    fn push_or_new_reduce_state(
+        &mut self,
+        output: &mut Vec<Event>,
+        event: LogEvent,
+        discriminant: Discriminant,
+    ) {
+        if !self.reduce_merge_states.contains_key(&discriminant) {
+            self.evict_if_full();
+        }
+
+        let state = self
+            .reduce_merge_states
+            .entry(discriminant.clone())
+            .or_insert_with(ReduceState::new);
+        state.add_event(event, &self.merge_strategies);
+
+        if self
+            .max_bytes
+            .map_or(false, |max_bytes| state.bytes >= max_bytes)
+        {
+            if let Some(state) = self.reduce_merge_states.remove(&discriminant) {
+                output.push(state.flush().into());
             }
-            hash_map::Entry::Occupied(mut entry) => {
-                entry.get_mut().add_event(event, &self.merge_strategies);
+        } else if let Some(condition) = &self.flush_condition {
+            // The condition is checked against a copy of the combined event, as the group may
+            // not be complete yet.
+            let (complete, combined) = condition.check(state.clone().flush().into());
+            if complete {
+                self.reduce_merge_states.remove(&discriminant);
+                output.push(combined);
             }
         }
     }
@@ -406,7 +507,7 @@ impl Reduce {
                 output.push(state.flush().into());
             }
 
-            self.push_or_new_reduce_state(event, discriminant)
+            self.push_or_new_reduce_state(output, event, discriminant)
         } else if ends_here {
             output.push(match self.reduce_merge_states.remove(&discriminant) {
                 Some(mut state) => {
@@ -420,7 +521,46 @@ impl Reduce {
                 }
             })
         } else {
-            self.push_or_new_reduce_state(event, discriminant)
+            self.push_or_new_reduce_state(output, event, discriminant)
+        }
+    }
+
+    /// Reduces the events of the input, yielding the flushed events along with the events of the
+    /// groups evicted in the meantime.
+    fn reduce_stream(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> impl Stream<Item = (Vec<Event>, Vec<Event>)> + Send {
+        let mut me = self;
+
+        let poll_period = me.flush_period;
+
+        let mut flush_stream = tokio::time::interval(poll_period);
+
+        stream! {
+          loop {
+            let mut output = Vec::new();
+            let done = tokio::select! {
+                _ = flush_stream.tick() => {
+                  me.flush_into(&mut output);
+                  false
+                }
+                maybe_event = input_rx.next() => {
+                  match maybe_event {
+                    None => {
+                      me.flush_all_into(&mut output);
+                      true
+                    }
+                    Some(event) => {
+                      me.transform_one(&mut output, event);
+                      false
+                    }
+                  }
+                }
+            };
+            yield (output, std::mem::take(&mut me.evicted));
+            if done { break }
+          }
         }
     }
 }
@@ -428,44 +568,41 @@ impl Reduce {
 impl TaskTransform<Event> for Reduce {
     fn transform(
         self: Box<Self>,
-        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+        input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
     ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
     where
         Self: 'static,
     {
-        let mut me = self;
-
-        let poll_period = me.flush_period;
+        Box::pin(
+            self.reduce_stream(input_rx)
+                .flat_map(|(mut output, mut evicted)| {
+                    evicted.append(&mut output);
+                    stream::iter(evicted)
+                }),
+        )
+    }
+}
 
-        let mut flush_stream = tokio::time::interval(poll_period);
+impl MultiOutputTaskTransform for Reduce {
+    fn transform(
+        self: Box<Self>,
+        input_rx: Pin<Box<dyn Stream<Item = EventArray> + Send>>,
+        outputs: TransformOutputsBuf,
+    ) -> Pin<Box<dyn Stream<Item = TransformOutputsBuf> + Send>> {
+        let reroute_evicted = self.reroute_evicted;
 
         Box::pin(
-            stream! {
-              loop {
-                let mut output = Vec::new();
-                let done = tokio::select! {
-                    _ = flush_stream.tick() => {
-                      me.flush_into(&mut output);
-                      false
-                    }
-                    maybe_event = input_rx.next() => {
-                      match maybe_event {
-                        None => {
-                          me.flush_all_into(&mut output);
-                          true
-                        }
-                        Some(event) => {
-                          me.transform_one(&mut output, event);
-                          false
-                        }
-                      }
+            self.reduce_stream(input_rx.flat_map(into_event_stream).boxed())
+                .filter_map(move |(mut output, mut evicted)| {
+                    let mut buf = outputs.clone();
+                    if reroute_evicted {
+                        buf.append_named(EVICTED_OUTPUT, &mut evicted);
+                    } else {
+                        buf.append(&mut evicted);
                     }
-                };
-                yield stream::iter(output.into_iter());
-                if done { break }
-              }
-            }
-            .flatten(),
+                    buf.append(&mut output);
+                    future::ready((!buf.is_empty()).then_some(buf))
+                }),
         )
     }
 }
@@ -908,4 +1045,137 @@ merge_strategies.bar = "concat"
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn max_bytes() {
+        let mut e_1 = LogEvent::from("test 1");
+        e_1.insert("id", "1");
+        let size = e_1.estimated_json_encoded_size_of();
+
+        let reduce_config = toml::from_str::<ReduceConfig>(&format!(
+            r#"
+group_by = [ "id" ]
+merge_strategies.message = "array"
+max_bytes = {}
+            "#,
+            2 * size - 1
+        ))
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            let mut e_2 = LogEvent::from("test 2");
+            e_2.insert("id", "1");
+
+            let mut e_3 = LogEvent::from("test 3");
+            e_3.insert("id", "1");
+
+            for event in vec![e_1.into(), e_2.into(), e_3.into()] {
+                tx.send(event).await.unwrap();
+            }
+
+            let output_1 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_1["message"], vec!["test 1", "test 2"].into());
+
+            drop(tx);
+            topology.stop().await;
+
+            let output_2 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_2["message"], vec!["test 3"].into());
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn flush_condition() {
+        let reduce_config = toml::from_str::<ReduceConfig>(
+            r#"
+group_by = [ "id" ]
+merge_strategies.message = "array"
+
+[flush_condition]
+  type = "vrl"
+  source = "length(array!(.message)) >= 2"
+            "#,
+        )
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            for message in ["test 1", "test 2", "test 3"] {
+                let mut event = LogEvent::from(message);
+                event.insert("id", "1");
+                tx.send(event.into()).await.unwrap();
+            }
+
+            let output_1 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_1["message"], vec!["test 1", "test 2"].into());
+
+            drop(tx);
+            topology.stop().await;
+
+            let output_2 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_2["message"], vec!["test 3"].into());
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn max_groups_reroutes_evicted() {
+        let reduce_config = toml::from_str::<ReduceConfig>(
+            r#"
+group_by = [ "id" ]
+merge_strategies.message = "array"
+max_groups = 2
+reroute_evicted = true
+            "#,
+        )
+        .unwrap();
+        let outputs = reduce_config.outputs(
+            enrichment::TableRegistry::default(),
+            &[(
+                "test".into(),
+                schema::Definition::default_legacy_namespace(),
+            )],
+            LogNamespace::Legacy,
+        );
+        let reduce = Reduce::new(&reduce_config, &Default::default()).unwrap();
+
+        let input = ["1", "2", "1", "3"].map(|id| {
+            let mut event = LogEvent::from(format!("test {}", id));
+            event.insert("id", id);
+            EventArray::from(event)
+        });
+        let bufs = MultiOutputTaskTransform::transform(
+            Box::new(reduce),
+            Box::pin(stream::iter(input)),
+            TransformOutputsBuf::new_with_capacity(outputs, 0),
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut primary = Vec::new();
+        let mut evicted = Vec::new();
+        for mut buf in bufs {
+            primary.extend(buf.take_primary().into_events());
+            evicted.extend(
+                buf.take_all_named()
+                    .remove(EVICTED_OUTPUT)
+                    .unwrap()
+                    .into_events(),
+            );
+        }
+
+        // The second group is evicted when the third one starts, as the first one was updated
+        // since.
+        assert_eq!(evicted.len(), 1);
+        assert_eq!(evicted[0].as_log()["message"], vec!["test 2"].into());
+        assert_eq!(primary.len(), 2);
+    }
 }
@@ -1,15 +1,18 @@
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::{
     collections::BTreeMap,
     fs::File,
     io::{self, Read},
+    num::{NonZeroU64, NonZeroUsize},
     path::PathBuf,
+    time::{Duration, Instant},
 };
 
 use codecs::MetricTagValues;
-use lookup::lookup_v2::{parse_value_path, ValuePath};
+use lookup::lookup_v2::{parse_value_path, ConfigValuePath, ValuePath};
 use lookup::{metadata_path, owned_value_path, path, OwnedTargetPath, PathPrefix};
+use lru::LruCache;
 use snafu::{ResultExt, Snafu};
 use value::kind::merge::{CollisionStrategy, Strategy};
 use value::kind::Collection;
@@ -34,7 +37,7 @@ use crate::{
         TransformOutput,
     },
     event::{Event, TargetEvents, VrlTarget},
-    internal_events::{RemapMappingAbort, RemapMappingError},
+    internal_events::{RemapCacheLookup, RemapMappingAbort, RemapMappingError},
     schema,
     transforms::{SyncTransform, Transform, TransformOutputsBuf},
     Result,
@@ -136,6 +139,54 @@ pub struct RemapConfig {
     #[configurable(derived, metadata(docs::hidden))]
     #[serde(default)]
     pub runtime: VrlRuntime,
+
+    /// Caches the results of the program for events with identical key fields.
+    ///
+    /// When many events share the same inputs, such as a user agent or an IP address, caching
+    /// avoids running expensive parsing or lookups over and over.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub cache: Option<RemapCacheConfig>,
+}
+
+/// Caching configuration for the `remap` transform.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RemapCacheConfig {
+    /// The fields whose values make up the cache key.
+    ///
+    /// Events with the same values for all of these fields are assumed to produce the same
+    /// values for `fields`. Missing fields are part of the key.
+    #[configurable(metadata(docs::examples = "user_agent", docs::examples = "client.ip"))]
+    pub key_fields: Vec<ConfigValuePath>,
+
+    /// The fields set by the program that are cached.
+    ///
+    /// On a cache hit, the program isn't run, and these fields are set to their cached values
+    /// instead. Fields that the program removed are removed again. Any other change made by the
+    /// program is not applied.
+    #[configurable(metadata(docs::examples = "agent", docs::examples = "geoip"))]
+    pub fields: Vec<ConfigValuePath>,
+
+    /// How long, in seconds, a cached result is used before the program is run again.
+    #[serde(default = "default_cache_ttl_secs")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub ttl_secs: NonZeroU64,
+
+    /// The maximum number of results kept in the cache.
+    ///
+    /// Once full, the least recently used result is evicted.
+    #[serde(default = "default_cache_max_entries")]
+    pub max_entries: NonZeroUsize,
+}
+
+fn default_cache_ttl_secs() -> NonZeroU64 {
+    NonZeroU64::new(300).expect("static non-zero number")
+}
+
+fn default_cache_max_entries() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
 }
 
 impl RemapConfig {
@@ -384,6 +435,88 @@ where
     dropped_schema_definition: Arc<schema::Definition>,
     runner: Runner,
     metric_tag_values: MetricTagValues,
+    cache: Option<ResultCache>,
+}
+
+/// The results of the program, shared by all the instances of the transform.
+#[derive(Debug, Clone)]
+struct ResultCache {
+    key_fields: Vec<ConfigValuePath>,
+    fields: Vec<ConfigValuePath>,
+    ttl: Duration,
+    entries: Arc<Mutex<LruCache<String, CachedResult>>>,
+}
+
+#[derive(Debug)]
+struct CachedResult {
+    created: Instant,
+    values: Vec<Option<value::Value>>,
+}
+
+impl ResultCache {
+    fn new(config: &RemapCacheConfig) -> Self {
+        Self {
+            key_fields: config.key_fields.clone(),
+            fields: config.fields.clone(),
+            ttl: Duration::from_secs(config.ttl_secs.get()),
+            entries: Arc::new(Mutex::new(LruCache::new(config.max_entries))),
+        }
+    }
+
+    /// Builds the cache key of the event, from the values of its key fields.
+    fn key(&self, event: &Event) -> Option<String> {
+        let log = event.maybe_as_log()?;
+        let values = self
+            .key_fields
+            .iter()
+            .map(|field| log.get((PathPrefix::Event, &field.0)))
+            .collect::<Vec<_>>();
+        serde_json::to_string(&values).ok()
+    }
+
+    /// Applies the cached result for the key to the event, if there is one that hasn't expired.
+    fn apply(&self, key: &str, event: &mut Event) -> bool {
+        let mut entries = self.entries.lock().expect("cache mutex poisoned");
+        let Some(result) = entries.get(key) else {
+            return false;
+        };
+        if result.created.elapsed() >= self.ttl {
+            entries.pop(key);
+            return false;
+        }
+
+        let log = event.as_mut_log();
+        for (field, value) in self.fields.iter().zip(&result.values) {
+            match value {
+                Some(value) => {
+                    log.insert((PathPrefix::Event, &field.0), value.clone());
+                }
+                None => {
+                    log.remove((PathPrefix::Event, &field.0));
+                }
+            }
+        }
+        true
+    }
+
+    /// Caches the values of the fields of the event produced by the program.
+    fn insert(&self, key: String, event: &Event) {
+        let Some(log) = event.maybe_as_log() else {
+            return;
+        };
+        let values = self
+            .fields
+            .iter()
+            .map(|field| log.get((PathPrefix::Event, &field.0)).cloned())
+            .collect();
+        self.entries.lock().expect("cache mutex poisoned").put(
+            key,
+            CachedResult {
+                created: Instant::now(),
+                values,
+            },
+        );
+    }
 }
 
 pub trait VrlRunner {
@@ -483,6 +616,7 @@ where
             dropped_schema_definition: Arc::new(dropped_schema_definition),
             runner,
             metric_tag_values: config.metric_tag_values,
+            cache: config.cache.as_ref().map(ResultCache::new),
         })
     }
 
@@ -558,7 +692,17 @@ impl<Runner> SyncTransform for Remap<Runner>
 where
     Runner: VrlRunner + Clone + Send + Sync,
 {
-    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+    fn transform(&mut self, mut event: Event, output: &mut TransformOutputsBuf) {
+        let cache_key = self.cache.as_ref().and_then(|cache| cache.key(&event));
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            let hit = cache.apply(key, &mut event);
+            emit!(RemapCacheLookup { hit });
+            if hit {
+                push_default(event, output, &self.default_schema_definition);
+                return;
+            }
+        }
+
         // If a program can fail or abort at runtime and we know that we will still need to forward
         // the event in that case (either to the main output or `dropped`, depending on the
         // config), we need to clone the original event and keep it around, to allow us to discard
@@ -594,6 +738,9 @@ where
         match result {
             Ok(_) => match target.into_events() {
                 TargetEvents::One(event) => {
+                    if let (Some(cache), Some(key)) = (&self.cache, cache_key) {
+                        cache.insert(key, &event);
+                    }
                     push_default(event, output, &self.default_schema_definition)
                 }
                 TargetEvents::Logs(events) => events
@@ -838,6 +985,80 @@ mod tests {
             .into_owned()
     }
 
+    #[test]
+    fn cache_reuses_results() {
+        let conf = RemapConfig {
+            source: Some(".parsed = .sentinel\ndel(.removed)".to_string()),
+            cache: Some(RemapCacheConfig {
+                key_fields: vec![ConfigValuePath::try_from("agent".to_string()).unwrap()],
+                fields: vec![
+                    ConfigValuePath::try_from("parsed".to_string()).unwrap(),
+                    ConfigValuePath::try_from("removed".to_string()).unwrap(),
+                ],
+                ttl_secs: default_cache_ttl_secs(),
+                max_entries: NonZeroUsize::new(10).unwrap(),
+            }),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let mut log = LogEvent::from("event");
+        log.insert("agent", "curl");
+        log.insert("sentinel", "first");
+        log.insert("removed", true);
+        let result = transform_one(&mut tform, log.clone().into()).unwrap();
+        assert_eq!(get_field_string(&result, "parsed"), "first");
+        assert!(result.as_log().get("removed").is_none());
+
+        // The program isn't run for the same key, so `parsed` keeps its cached value.
+        log.insert("sentinel", "second");
+        let result = transform_one(&mut tform, log.clone().into()).unwrap();
+        assert_eq!(get_field_string(&result, "parsed"), "first");
+        assert!(result.as_log().get("removed").is_none());
+        assert_eq!(
+            result.metadata().schema_definition(),
+            &test_default_schema_definition()
+        );
+
+        log.insert("agent", "wget");
+        log.insert("sentinel", "third");
+        let result = transform_one(&mut tform, log.into()).unwrap();
+        assert_eq!(get_field_string(&result, "parsed"), "third");
+    }
+
+    #[test]
+    fn cache_evicts_least_recently_used() {
+        let conf = RemapConfig {
+            source: Some(".parsed = .sentinel\ndel(.removed)".to_string()),
+            cache: Some(RemapCacheConfig {
+                key_fields: vec![ConfigValuePath::try_from("agent".to_string()).unwrap()],
+                fields: vec![
+                    ConfigValuePath::try_from("parsed".to_string()).unwrap(),
+                    ConfigValuePath::try_from("removed".to_string()).unwrap(),
+                ],
+                ttl_secs: default_cache_ttl_secs(),
+                max_entries: NonZeroUsize::new(1).unwrap(),
+            }),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let mut log = LogEvent::from("event");
+        log.insert("agent", "curl");
+        log.insert("sentinel", "first");
+        log.insert("removed", true);
+        transform_one(&mut tform, log.clone().into()).unwrap();
+
+        log.insert("agent", "wget");
+        log.insert("sentinel", "second");
+        transform_one(&mut tform, log.clone().into()).unwrap();
+
+        log.insert("agent", "curl");
+        log.insert("sentinel", "third");
+        let result = transform_one(&mut tform, log.into()).unwrap();
+        assert_eq!(get_field_string(&result, "parsed"), "third");
+    }
+
     #[test]
     fn check_remap_doesnt_share_state_between_events() {
         let conf = RemapConfig {
@@ -1,6 +1,7 @@
 use indexmap::IndexMap;
 use vector_config::configurable_component;
 use vector_core::config::{clone_input_definitions, LogNamespace};
+use vector_core::internal_event::{ComponentEventsDropped, INTENTIONAL};
 use vector_core::transform::SyncTransform;
 
 use crate::{
@@ -19,6 +20,8 @@ pub(crate) const UNMATCHED_ROUTE: &str = "_unmatched";
 #[derive(Clone)]
 pub struct Route {
     conditions: Vec<(String, Condition)>,
+    mode: RouteMode,
+    reroute_unmatched: bool,
 }
 
 impl Route {
@@ -28,7 +31,11 @@ impl Route {
             let condition = condition.build(&context.enrichment_tables)?;
             conditions.push((output_name.clone(), condition));
         }
-        Ok(Self { conditions })
+        Ok(Self {
+            conditions,
+            mode: config.mode,
+            reroute_unmatched: config.reroute_unmatched,
+        })
     }
 }
 
@@ -38,21 +45,43 @@ impl SyncTransform for Route {
         event: Event,
         output: &mut vector_core::transform::TransformOutputsBuf,
     ) {
-        let mut check_failed: usize = 0;
+        let mut matched = false;
         for (output_name, condition) in &self.conditions {
             let (result, event) = condition.check(event.clone());
             if result {
                 output.push_named(output_name, event);
-            } else {
-                check_failed += 1;
+                matched = true;
+                if self.mode == RouteMode::First {
+                    break;
+                }
             }
         }
-        if check_failed == self.conditions.len() {
-            output.push_named(UNMATCHED_ROUTE, event);
+        if !matched {
+            if self.reroute_unmatched {
+                output.push_named(UNMATCHED_ROUTE, event);
+            } else {
+                emit!(ComponentEventsDropped::<INTENTIONAL> {
+                    count: 1,
+                    reason: "Event didn't match any route.",
+                });
+            }
         }
     }
 }
 
+/// How events are delivered to the routes they match.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RouteMode {
+    /// Events are sent to all of the routes they match.
+    #[default]
+    All,
+
+    /// Events are only sent to the first route they match, in the order of `route`.
+    First,
+}
+
 /// Configuration for the `route` transform.
 #[configurable_component(transform(
     "route",
@@ -65,18 +94,30 @@ pub struct RouteConfig {
     ///
     /// Each route can then be referenced as an input by other components with the name
     /// `<transform_name>.<route_id>`. If an event doesn’t match any route, it is sent to the
-    /// `<transform_name>._unmatched` output.
+    /// `<transform_name>._unmatched` output, unless `reroute_unmatched` is disabled.
     ///
     /// Both `_unmatched`, as well as `_default`, are reserved output names and thus cannot be used
     /// as a route name.
     #[configurable(metadata(docs::additional_props_description = "An individual route."))]
     route: IndexMap<String, AnyCondition>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    mode: RouteMode,
+
+    /// Whether or not to send the events that don't match any route to the `_unmatched` output.
+    ///
+    /// When disabled, those events are dropped, and the `_unmatched` output doesn't exist.
+    #[serde(default = "crate::serde::default_true")]
+    reroute_unmatched: bool,
 }
 
 impl GenerateConfig for RouteConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             route: IndexMap::new(),
+            mode: RouteMode::default(),
+            reroute_unmatched: true,
         })
         .unwrap()
     }
@@ -118,10 +159,12 @@ impl TransformConfig for RouteConfig {
                     .with_port(output_name)
             })
             .collect();
-        result.push(
-            TransformOutput::new(DataType::all(), clone_input_definitions(input_definitions))
-                .with_port(UNMATCHED_ROUTE),
-        );
+        if self.reroute_unmatched {
+            result.push(
+                TransformOutput::new(DataType::all(), clone_input_definitions(input_definitions))
+                    .with_port(UNMATCHED_ROUTE),
+            );
+        }
         result
     }
 
@@ -162,7 +205,7 @@ mod test {
 
         assert_eq!(
             serde_json::to_string(&config).unwrap(),
-            r#"{"route":{"first":{"type":"vrl","source":".message == \"hello world\"","runtime":"ast"}}}"#
+            r#"{"route":{"first":{"type":"vrl","source":".message == \"hello world\"","runtime":"ast"}},"mode":"all","reroute_unmatched":true}"#
         );
     }
 
@@ -293,6 +336,81 @@ mod test {
         }
     }
 
+    #[test]
+    fn route_first_match_only() {
+        let output_names = vec!["first", "second", "third", UNMATCHED_ROUTE];
+        let event = Event::try_from(
+            serde_json::json!({"message": "hello world", "second": "second", "third": "third"}),
+        )
+        .unwrap();
+        let config = toml::from_str::<RouteConfig>(
+            r#"
+            mode = "first"
+
+            route.first.type = "vrl"
+            route.first.source = '.message == "NOPE"'
+
+            route.second.type = "vrl"
+            route.second.source = '.second == "second"'
+
+            route.third.type = "vrl"
+            route.third.source = '.third == "third"'
+        "#,
+        )
+        .unwrap();
+
+        let mut transform = Route::new(&config, &Default::default()).unwrap();
+        let mut outputs = TransformOutputsBuf::new_with_capacity(
+            output_names
+                .iter()
+                .map(|output_name| {
+                    TransformOutput::new(DataType::all(), HashMap::new())
+                        .with_port(output_name.to_owned())
+                })
+                .collect(),
+            1,
+        );
+
+        transform.transform(event.clone(), &mut outputs);
+        for output_name in output_names {
+            let mut events: Vec<_> = outputs.drain_named(output_name).collect();
+            if output_name == "second" {
+                assert_eq!(events.len(), 1);
+                assert_eq!(events.pop().unwrap(), event);
+            }
+            assert_eq!(events.len(), 0);
+        }
+    }
+
+    #[test]
+    fn route_drop_unmatched() {
+        let config = toml::from_str::<RouteConfig>(
+            r#"
+            reroute_unmatched = false
+
+            route.first.type = "vrl"
+            route.first.source = '.message == "hello world"'
+        "#,
+        )
+        .unwrap();
+
+        let outputs = config.outputs(
+            enrichment::TableRegistry::default(),
+            &[("test".into(), schema::Definition::any())],
+            LogNamespace::Legacy,
+        );
+        assert!(outputs
+            .iter()
+            .all(|output| output.port.as_deref() != Some(UNMATCHED_ROUTE)));
+
+        let mut transform = Route::new(&config, &Default::default()).unwrap();
+        let mut outputs = TransformOutputsBuf::new_with_capacity(outputs, 1);
+
+        let event = Event::try_from(serde_json::json!({"message": "NOPE"})).unwrap();
+        transform.transform(event, &mut outputs);
+        assert_eq!(outputs.drain_named("first").count(), 0);
+    }
+
     #[tokio::test]
     async fn route_metrics_with_output_tag() {
         init_test();
@@ -0,0 +1,385 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use jsonschema::{Draft, JSONSchema};
+use lookup::{
+    lookup_v2::{parse_value_path, ValuePath},
+    metadata_path, owned_value_path, path, PathPrefix,
+};
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+use vector_core::config::{log_schema, LogNamespace};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent},
+    internal_events::SchemaValidationFailed,
+    schema,
+    transforms::{SyncTransform, Transform, TransformOutputsBuf},
+};
+
+/// The name of the output for the events that fail validation.
+const INVALID_OUTPUT: &str = "invalid";
+
+/// Configuration for the `schema_validation` transform.
+#[configurable_component(transform(
+    "schema_validation",
+    "Validate log events against a JSON Schema."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SchemaValidationConfig {
+    /// The path of the JSON Schema file to validate events against.
+    ///
+    /// The schema is read when the transform is built, and follows the 2020-12 draft of JSON
+    /// Schema unless it declares another draft with `$schema`.
+    #[configurable(metadata(docs::examples = "/etc/vector/schemas/orders.json"))]
+    pub schema_file: PathBuf,
+
+    /// The maximum number of violations recorded for an invalid event.
+    #[serde(default = "default_max_violations")]
+    pub max_violations: usize,
+}
+
+const fn default_max_violations() -> usize {
+    10
+}
+
+impl GenerateConfig for SchemaValidationConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            schema_file: PathBuf::from("/etc/vector/schemas/orders.json"),
+            max_violations: default_max_violations(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "schema_validation")]
+impl TransformConfig for SchemaValidationConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        SchemaValidation::new(self, context).map(Transform::synchronous)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let mut default_definitions = HashMap::new();
+        let mut invalid_definitions = HashMap::new();
+
+        for (output_id, input_definition) in input_definitions {
+            default_definitions.insert(output_id.clone(), input_definition.clone());
+
+            // Invalid events are annotated with the violations they were rejected for.
+            let mut invalid_definition = schema::Definition::new_with_default_metadata(
+                Kind::never(),
+                input_definition.log_namespaces().clone(),
+            );
+            if input_definition
+                .log_namespaces()
+                .contains(&LogNamespace::Legacy)
+            {
+                invalid_definition =
+                    invalid_definition.merge(input_definition.clone().with_event_field(
+                        &parse_value_path(log_schema().metadata_key()).expect("valid metadata key"),
+                        Kind::object(BTreeMap::from([("invalid".into(), annotation_kind())])),
+                        Some("metadata"),
+                    ));
+            }
+            if input_definition
+                .log_namespaces()
+                .contains(&LogNamespace::Vector)
+            {
+                invalid_definition =
+                    invalid_definition.merge(input_definition.clone().with_metadata_field(
+                        &owned_value_path!("vector", "invalid"),
+                        annotation_kind(),
+                        None,
+                    ));
+            }
+            invalid_definitions.insert(output_id.clone(), invalid_definition);
+        }
+
+        vec![
+            TransformOutput::new(DataType::Log, default_definitions),
+            TransformOutput::new(DataType::Log, invalid_definitions).with_port(INVALID_OUTPUT),
+        ]
+    }
+}
+
+fn annotation_kind() -> Kind {
+    let violation = Kind::object(BTreeMap::from([
+        ("path".into(), Kind::bytes()),
+        ("schema_path".into(), Kind::bytes()),
+        ("message".into(), Kind::bytes()),
+    ]));
+    Kind::object(BTreeMap::from([
+        (
+            "violations".into(),
+            Kind::array(Collection::empty().with_unknown(violation)),
+        ),
+        ("component_id".into(), Kind::bytes()),
+        ("component_type".into(), Kind::bytes()),
+        ("component_kind".into(), Kind::bytes()),
+    ]))
+}
+
+#[derive(Clone)]
+pub struct SchemaValidation {
+    schema: Arc<JSONSchema>,
+    max_violations: usize,
+    component_key: Option<String>,
+}
+
+impl SchemaValidation {
+    pub fn new(config: &SchemaValidationConfig, context: &TransformContext) -> crate::Result<Self> {
+        let schema = std::fs::read_to_string(&config.schema_file).map_err(|error| {
+            format!(
+                "Failed to read schema file {:?}: {}",
+                config.schema_file, error
+            )
+        })?;
+        let schema = serde_json::from_str(&schema).map_err(|error| {
+            format!(
+                "Failed to parse schema file {:?}: {}",
+                config.schema_file, error
+            )
+        })?;
+        let schema = JSONSchema::options()
+            .with_draft(Draft::Draft202012)
+            .compile(&schema)
+            .map_err(|error| {
+                format!("Invalid schema in file {:?}: {}", config.schema_file, error)
+            })?;
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            max_violations: config.max_violations,
+            component_key: context.key.as_ref().map(ToString::to_string),
+        })
+    }
+
+    /// Validates the event, returning its violations of the schema if it's invalid.
+    fn violations(&self, log: &LogEvent) -> Option<Vec<serde_json::Value>> {
+        let instance = match serde_json::to_value(log.value()) {
+            Ok(instance) => instance,
+            Err(error) => {
+                return Some(vec![serde_json::json!({
+                    "path": "",
+                    "schema_path": "",
+                    "message": format!("event can't be represented as JSON: {}", error),
+                })])
+            }
+        };
+
+        let violations = self.schema.validate(&instance).err()?;
+        Some(
+            violations
+                .take(self.max_violations)
+                .map(|error| {
+                    serde_json::json!({
+                        "path": error.instance_path.to_string(),
+                        "schema_path": error.schema_path.to_string(),
+                        "message": error.to_string(),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn annotate_invalid(&self, log: &mut LogEvent, violations: Vec<serde_json::Value>) {
+        let annotation = serde_json::json!({
+            "violations": violations,
+            "component_id": self.component_key,
+            "component_type": "schema_validation",
+            "component_kind": "transform",
+        });
+        match log.namespace() {
+            LogNamespace::Legacy => {
+                log.insert(
+                    (
+                        PathPrefix::Event,
+                        log_schema().metadata_key().concat(path!("invalid")),
+                    ),
+                    annotation,
+                );
+            }
+            LogNamespace::Vector => {
+                log.insert(metadata_path!("vector", "invalid"), annotation);
+            }
+        }
+    }
+}
+
+impl SyncTransform for SchemaValidation {
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        let mut log = event.into_log();
+        match self.violations(&log) {
+            None => output.push(log.into()),
+            Some(violations) => {
+                emit!(SchemaValidationFailed {
+                    count: violations.len(),
+                });
+                self.annotate_invalid(&mut log, violations);
+                output.push_named(INVALID_OUTPUT, log.into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use vector_core::transform::TransformOutputsBuf;
+
+    use super::*;
+    use crate::event::Value;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SchemaValidationConfig>();
+    }
+
+    fn schema_validation(schema: serde_json::Value) -> (SchemaValidation, Vec<TransformOutput>) {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "{}", schema).unwrap();
+
+        let config = SchemaValidationConfig {
+            schema_file: file.path().to_owned(),
+            max_violations: default_max_violations(),
+        };
+        let outputs = config.outputs(
+            enrichment::TableRegistry::default(),
+            &[(
+                "test".into(),
+                schema::Definition::default_legacy_namespace(),
+            )],
+            LogNamespace::Legacy,
+        );
+        let transform = SchemaValidation::new(&config, &TransformContext::default()).unwrap();
+        (transform, outputs)
+    }
+
+    fn order_schema() -> serde_json::Value {
+        serde_json::json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["order_id", "amount"],
+            "properties": {
+                "order_id": { "type": "string" },
+                "amount": { "type": "number", "minimum": 0 },
+            },
+        })
+    }
+
+    #[test]
+    fn forwards_valid_events() {
+        let (mut transform, outputs) = schema_validation(order_schema());
+        let mut buf = TransformOutputsBuf::new_with_capacity(outputs, 1);
+
+        let mut log = LogEvent::default();
+        log.insert("order_id", "a1");
+        log.insert("amount", 12.5);
+        transform.transform(log.clone().into(), &mut buf);
+
+        assert_eq!(
+            buf.take_primary().into_events().collect::<Vec<_>>(),
+            vec![Event::from(log)]
+        );
+        assert!(buf.take_all_named()[INVALID_OUTPUT].is_empty());
+    }
+
+    #[test]
+    fn reroutes_invalid_events() {
+        let (mut transform, outputs) = schema_validation(order_schema());
+        let mut buf = TransformOutputsBuf::new_with_capacity(outputs, 1);
+
+        let mut log = LogEvent::default();
+        log.insert("amount", -1);
+        transform.transform(log.into(), &mut buf);
+
+        assert!(buf.take_primary().is_empty());
+        let mut invalid = buf
+            .take_all_named()
+            .remove(INVALID_OUTPUT)
+            .unwrap()
+            .into_events()
+            .collect::<Vec<_>>();
+        assert_eq!(invalid.len(), 1);
+
+        let log = invalid.remove(0).into_log();
+        let violations = log
+            .get("metadata.invalid.violations")
+            .and_then(Value::as_array)
+            .unwrap();
+        assert_eq!(violations.len(), 2);
+        let paths = violations
+            .iter()
+            .map(|violation| {
+                violation
+                    .get("path")
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"".to_owned()));
+        assert!(paths.contains(&"/amount".to_owned()));
+        assert_eq!(
+            log.get("metadata.invalid.component_type"),
+            Some(&Value::from("schema_validation"))
+        );
+    }
+
+    #[test]
+    fn limits_violations() {
+        let (mut transform, outputs) = schema_validation(order_schema());
+        transform.max_violations = 1;
+        let mut buf = TransformOutputsBuf::new_with_capacity(outputs, 1);
+
+        transform.transform(LogEvent::default().into(), &mut buf);
+
+        let invalid = buf
+            .take_all_named()
+            .remove(INVALID_OUTPUT)
+            .unwrap()
+            .into_events()
+            .next()
+            .unwrap()
+            .into_log();
+        assert_eq!(
+            invalid
+                .get("metadata.invalid.violations")
+                .and_then(Value::as_array)
+                .map(|violations| violations.len()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schema() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"type": "not-a-type"}}"#).unwrap();
+
+        let config = SchemaValidationConfig {
+            schema_file: file.path().to_owned(),
+            max_violations: default_max_violations(),
+        };
+        assert!(SchemaValidation::new(&config, &TransformContext::default()).is_err());
+    }
+}
@@ -0,0 +1,552 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    pin::Pin,
+    time::Duration,
+};
+
+use async_stream::stream;
+use chrono::Utc;
+use futures::{stream, Stream, StreamExt};
+use indexmap::IndexMap;
+use lookup::{lookup_v2::ConfigValuePath, path};
+use serde_with::serde_as;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{
+        metric::{Bucket, Metric, MetricKind, MetricTags, MetricValue},
+        Event, EventMetadata, TraceEvent, Value,
+    },
+    internal_events::{SpanMetricsFlushed, SpanMetricsSpansRecorded},
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+/// Configuration for the `span_metrics` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "span_metrics",
+    "Derive request, error, and duration metrics from the spans of traces."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SpanMetricsConfig {
+    /// Additional tags to apply to the metrics, from the values of fields of the spans.
+    ///
+    /// Metrics are always tagged with the `service` and the `operation` of the spans, and are
+    /// aggregated separately for each combination of tags. Spans where a field is missing don't
+    /// have the corresponding tag.
+    #[serde(default)]
+    #[configurable(metadata(docs::additional_props_description = "A span field."))]
+    #[configurable(metadata(docs::examples = "example_dimensions()"))]
+    pub dimensions: IndexMap<String, ConfigValuePath>,
+
+    /// Sets the namespace of the metrics.
+    #[configurable(metadata(docs::examples = "traces"))]
+    pub namespace: Option<String>,
+
+    /// The upper limits of the buckets of the duration histogram, in seconds.
+    #[serde(default = "default_buckets")]
+    pub buckets: Vec<f64>,
+
+    /// The interval between flushes of the metrics, in milliseconds.
+    ///
+    /// During this time frame, spans with the same tags are aggregated into the same metrics.
+    #[serde(default = "default_flush_period_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub flush_period_ms: Duration,
+}
+
+fn default_buckets() -> Vec<f64> {
+    vec![
+        0.002, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+    ]
+}
+
+const fn default_flush_period_ms() -> Duration {
+    Duration::from_millis(10000)
+}
+
+fn example_dimensions() -> IndexMap<String, String> {
+    IndexMap::from([
+        ("resource".to_owned(), "resource".to_owned()),
+        (
+            "status_code".to_owned(),
+            "meta.\"http.status_code\"".to_owned(),
+        ),
+    ])
+}
+
+impl GenerateConfig for SpanMetricsConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"dimensions.resource = "resource""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "span_metrics")]
+impl TransformConfig for SpanMetricsConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        SpanMetrics::new(self).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::trace()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        _: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        vec![TransformOutput::new(DataType::Metric, HashMap::new())]
+    }
+}
+
+/// The spans recorded for a combination of tags.
+struct SpanStats {
+    calls: u64,
+    errors: u64,
+    // The number of durations in each bucket, by index of its upper limit.
+    buckets: Vec<u64>,
+    count: u64,
+    sum: f64,
+    metadata: EventMetadata,
+}
+
+pub struct SpanMetrics {
+    dimensions: Vec<(String, ConfigValuePath)>,
+    namespace: Option<String>,
+    buckets: Vec<f64>,
+    flush_period: Duration,
+    stats: HashMap<MetricTags, SpanStats>,
+}
+
+impl SpanMetrics {
+    pub fn new(config: &SpanMetricsConfig) -> crate::Result<Self> {
+        if config.buckets.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err("buckets must be in increasing order".into());
+        }
+        if let Some(name) = config
+            .dimensions
+            .keys()
+            .find(|name| *name == "service" || *name == "operation")
+        {
+            return Err(
+                format!("dimension {:?} is always set and can't be overridden", name).into(),
+            );
+        }
+
+        Ok(Self {
+            dimensions: config
+                .dimensions
+                .iter()
+                .map(|(name, path)| (name.clone(), path.clone()))
+                .collect(),
+            namespace: config.namespace.clone(),
+            buckets: config.buckets.clone(),
+            flush_period: config.flush_period_ms,
+            stats: HashMap::new(),
+        })
+    }
+
+    fn record(&mut self, trace: TraceEvent) {
+        let (fields, metadata) = trace.into_parts();
+        let Some(Value::Array(spans)) = fields.get("spans") else {
+            return;
+        };
+
+        for span in spans {
+            self.record_span(span, &metadata);
+        }
+        emit!(SpanMetricsSpansRecorded { count: spans.len() });
+    }
+
+    fn record_span(&mut self, span: &Value, metadata: &EventMetadata) {
+        let mut tags = MetricTags::default();
+        let fields = [("service", path!("service")), ("operation", path!("name"))];
+        for (name, field) in fields {
+            if let Some(value) = span.get(field) {
+                tags.replace(name.to_owned(), value.to_string_lossy().into_owned());
+            }
+        }
+        for (name, path) in &self.dimensions {
+            if let Some(value) = span.get(&path.0) {
+                tags.replace(name.clone(), value.to_string_lossy().into_owned());
+            }
+        }
+
+        let stats = match self.stats.entry(tags) {
+            Entry::Occupied(entry) => {
+                let stats = entry.into_mut();
+                stats.metadata.merge(metadata.clone());
+                stats
+            }
+            Entry::Vacant(entry) => entry.insert(SpanStats {
+                calls: 0,
+                errors: 0,
+                buckets: vec![0; self.buckets.len()],
+                count: 0,
+                sum: 0.0,
+                metadata: metadata.clone(),
+            }),
+        };
+
+        stats.calls += 1;
+        let error = match span.get(path!("error")) {
+            Some(Value::Integer(error)) => *error != 0,
+            Some(Value::Boolean(error)) => *error,
+            _ => false,
+        };
+        if error {
+            stats.errors += 1;
+        }
+
+        // Durations of spans are in nanoseconds.
+        if let Some(Value::Integer(duration)) = span.get(path!("duration")) {
+            let seconds = *duration as f64 / 1e9;
+            if let Some(index) = self.buckets.iter().position(|limit| *limit >= seconds) {
+                stats.buckets[index] += 1;
+            }
+            stats.count += 1;
+            stats.sum += seconds;
+        }
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        if self.stats.is_empty() {
+            return;
+        }
+
+        let timestamp = Some(Utc::now());
+        for (tags, stats) in std::mem::take(&mut self.stats) {
+            let buckets = self
+                .buckets
+                .iter()
+                .zip(stats.buckets)
+                .map(|(&upper_limit, count)| Bucket { upper_limit, count })
+                .collect();
+            let values = [
+                (
+                    "span_calls_total",
+                    MetricValue::Counter {
+                        value: stats.calls as f64,
+                    },
+                ),
+                (
+                    "span_errors_total",
+                    MetricValue::Counter {
+                        value: stats.errors as f64,
+                    },
+                ),
+                (
+                    "span_duration_seconds",
+                    MetricValue::AggregatedHistogram {
+                        buckets,
+                        count: stats.count,
+                        sum: stats.sum,
+                    },
+                ),
+            ];
+
+            for (name, value) in values {
+                let metric = Metric::new_with_metadata(
+                    name,
+                    MetricKind::Incremental,
+                    value,
+                    stats.metadata.clone(),
+                )
+                .with_namespace(self.namespace.clone())
+                .with_tags(Some(tags.clone()))
+                .with_timestamp(timestamp);
+                output.push(Event::Metric(metric));
+            }
+        }
+
+        emit!(SpanMetricsFlushed);
+    }
+}
+
+impl TaskTransform<Event> for SpanMetrics {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+              loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                      me.flush_into(&mut output);
+                      false
+                    }
+                    maybe_event = input_rx.next() => {
+                      match maybe_event {
+                        None => {
+                          me.flush_into(&mut output);
+                          true
+                        }
+                        Some(event) => {
+                          me.record(event.into_trace());
+                          false
+                        }
+                      }
+                    }
+                };
+                yield stream::iter(output.into_iter());
+                if done { break }
+              }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::metric_tags;
+
+    use super::*;
+    use crate::event::LogEvent;
+    use crate::test_util::components::assert_transform_compliance;
+    use crate::transforms::test::create_topology;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SpanMetricsConfig>();
+    }
+
+    fn span(service: &str, name: &str, resource: &str, duration_ms: i64, error: i64) -> Value {
+        Value::from(BTreeMap::from([
+            ("service".to_owned(), Value::from(service)),
+            ("name".to_owned(), Value::from(name)),
+            ("resource".to_owned(), Value::from(resource)),
+            ("duration".to_owned(), Value::from(duration_ms * 1_000_000)),
+            ("error".to_owned(), Value::from(error)),
+            (
+                "meta".to_owned(),
+                Value::from(BTreeMap::from([(
+                    "http.status_code".to_owned(),
+                    Value::from("200"),
+                )])),
+            ),
+        ]))
+    }
+
+    fn trace(spans: Vec<Value>) -> Event {
+        let mut trace = TraceEvent::from(LogEvent::default());
+        trace.insert("spans", Value::Array(spans));
+        Event::Trace(trace)
+    }
+
+    fn find<'a>(metrics: &'a [Metric], name: &str, tags: &MetricTags) -> &'a MetricValue {
+        metrics
+            .iter()
+            .find(|metric| metric.name() == name && metric.tags() == Some(tags))
+            .map(Metric::value)
+            .unwrap_or_else(|| panic!("no {} metric with tags {:?}", name, tags))
+    }
+
+    #[tokio::test]
+    async fn derives_metrics_from_spans() {
+        let config: SpanMetricsConfig = toml::from_str(
+            r#"
+                buckets = [0.1, 1.0]
+                flush_period_ms = 10
+            "#,
+        )
+        .unwrap();
+        let mut transform = SpanMetrics::new(&config).unwrap();
+
+        transform.record(
+            trace(vec![
+                span("api", "http.request", "GET /", 50, 0),
+                span("api", "http.request", "POST /", 500, 1),
+                span("db", "query", "SELECT", 2000, 0),
+            ])
+            .into_trace(),
+        );
+        let mut output = Vec::new();
+        transform.flush_into(&mut output);
+        let metrics = output
+            .into_iter()
+            .map(Event::into_metric)
+            .collect::<Vec<_>>();
+        assert_eq!(metrics.len(), 6);
+
+        let api = metric_tags!("service" => "api", "operation" => "http.request");
+        assert_eq!(
+            find(&metrics, "span_calls_total", &api),
+            &MetricValue::Counter { value: 2.0 }
+        );
+        assert_eq!(
+            find(&metrics, "span_errors_total", &api),
+            &MetricValue::Counter { value: 1.0 }
+        );
+        assert_eq!(
+            find(&metrics, "span_duration_seconds", &api),
+            &MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 0.1,
+                        count: 1
+                    },
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 1
+                    },
+                ],
+                count: 2,
+                sum: 0.55,
+            }
+        );
+
+        // Durations above the last bucket are only counted in the total.
+        let db = metric_tags!("service" => "db", "operation" => "query");
+        assert_eq!(
+            find(&metrics, "span_duration_seconds", &db),
+            &MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 0.1,
+                        count: 0
+                    },
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 0
+                    },
+                ],
+                count: 1,
+                sum: 2.0,
+            }
+        );
+
+        // Nothing is emitted until new spans are recorded.
+        let mut output = Vec::new();
+        transform.flush_into(&mut output);
+        assert!(output.is_empty());
+    }
+
+    #[tokio::test]
+    async fn tags_metrics_with_dimensions() {
+        let config: SpanMetricsConfig = toml::from_str(
+            r#"
+                buckets = [0.1, 1.0]
+                flush_period_ms = 10
+                namespace = "traces"
+                dimensions.resource = "resource"
+                dimensions.status_code = "meta.\"http.status_code\""
+                dimensions.missing = "meta.missing"
+            "#,
+        )
+        .unwrap();
+        let mut transform = SpanMetrics::new(&config).unwrap();
+
+        transform.record(
+            trace(vec![
+                span("api", "http.request", "GET /", 50, 0),
+                span("api", "http.request", "POST /", 50, 0),
+            ])
+            .into_trace(),
+        );
+        let mut output = Vec::new();
+        transform.flush_into(&mut output);
+        let metrics = output
+            .into_iter()
+            .map(Event::into_metric)
+            .collect::<Vec<_>>();
+        assert_eq!(metrics.len(), 6);
+        assert!(metrics
+            .iter()
+            .all(|metric| metric.namespace() == Some("traces")));
+
+        let tags = metric_tags!(
+            "service" => "api",
+            "operation" => "http.request",
+            "resource" => "GET /",
+            "status_code" => "200",
+        );
+        assert_eq!(
+            find(&metrics, "span_calls_total", &tags),
+            &MetricValue::Counter { value: 1.0 }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let config: SpanMetricsConfig = toml::from_str(
+            r#"
+                buckets = [1.0, 0.1]
+            "#,
+        )
+        .unwrap();
+        assert!(SpanMetrics::new(&config).is_err());
+
+        let config: SpanMetricsConfig = toml::from_str(
+            r#"
+                buckets = [0.1, 1.0]
+                flush_period_ms = 10
+                dimensions.service = "resource"
+            "#,
+        )
+        .unwrap();
+        assert!(SpanMetrics::new(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn emits_metrics_from_topology() {
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let config: SpanMetricsConfig = toml::from_str(
+                r#"
+                    buckets = [0.1, 1.0]
+                    flush_period_ms = 10
+                "#,
+            )
+            .unwrap();
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(trace(vec![span("api", "http.request", "GET /", 50, 0)]))
+                .await
+                .unwrap();
+
+            let mut names = Vec::new();
+            for _ in 0..3 {
+                let metric = out.recv().await.unwrap().into_metric();
+                names.push(metric.name().to_owned());
+            }
+            names.sort();
+            assert_eq!(
+                names,
+                [
+                    "span_calls_total",
+                    "span_duration_seconds",
+                    "span_errors_total"
+                ]
+            );
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
@@ -1,8 +1,10 @@
-use std::{num::NonZeroU32, pin::Pin, time::Duration};
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
 
-use async_stream::stream;
-use futures::{Stream, StreamExt};
-use governor::{clock, Quota, RateLimiter};
+use governor::{
+    clock::{self, Reference},
+    state::keyed::DashMapStateStore,
+    Quota, RateLimiter,
+};
 use serde_with::serde_as;
 use snafu::Snafu;
 use vector_config::configurable_component;
@@ -15,9 +17,11 @@ use crate::{
     internal_events::{TemplateRenderingError, ThrottleEventDiscarded},
     schema,
     template::Template,
-    transforms::{TaskTransform, Transform},
+    transforms::{SyncTransform, Transform, TransformOutputsBuf},
 };
 
+const OVERFLOW: &str = "overflow";
+
 /// Configuration for the `throttle` transform.
 #[serde_as]
 #[configurable_component(transform("throttle", "Rate limit logs passing through a topology."))]
@@ -33,6 +37,13 @@ pub struct ThrottleConfig {
     #[serde_as(as = "serde_with::DurationSeconds<f64>")]
     window_secs: Duration,
 
+    /// The number of events allowed at once for a given bucket.
+    ///
+    /// Each unique key has its own bucket, which refills at the rate of `threshold` events per
+    /// `window_secs`, up to `burst` events. If left unspecified, `threshold` is used.
+    #[configurable(metadata(docs::examples = 100))]
+    burst: Option<u32>,
+
     /// The name of the log field whose value is hashed to determine if the event should be
     /// rate limited.
     ///
@@ -44,6 +55,13 @@ pub struct ThrottleConfig {
 
     /// A logical condition used to exclude events from sampling.
     exclude: Option<AnyCondition>,
+
+    /// Reroutes rate limited events to a named output instead of dropping them.
+    ///
+    /// When set to `true`, events that exceed the rate limit are sent to a specially-named
+    /// output, `overflow`, rather than being discarded.
+    #[serde(default = "crate::serde::default_false")]
+    reroute_overflow: bool,
 }
 
 impl_generate_config_from_default!(ThrottleConfig);
@@ -52,7 +70,7 @@ impl_generate_config_from_default!(ThrottleConfig);
 #[typetag::serde(name = "throttle")]
 impl TransformConfig for ThrottleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Throttle::new(self, context, clock::MonotonicClock).map(Transform::event_task)
+        Throttle::new(self, context, clock::MonotonicClock).map(Transform::synchronous)
     }
 
     fn input(&self) -> Input {
@@ -66,20 +84,31 @@ impl TransformConfig for ThrottleConfig {
         _: LogNamespace,
     ) -> Vec<TransformOutput> {
         // The event is not modified, so the definition is passed through as-is
-        vec![TransformOutput::new(
+        let mut outputs = vec![TransformOutput::new(
             DataType::Log,
             clone_input_definitions(input_definitions),
-        )]
+        )];
+        if self.reroute_overflow {
+            outputs.push(
+                TransformOutput::new(DataType::Log, clone_input_definitions(input_definitions))
+                    .with_port(OVERFLOW),
+            );
+        }
+        outputs
     }
 }
 
+type KeyedRateLimiter<C> = RateLimiter<Option<String>, DashMapStateStore<Option<String>>, C>;
+
 #[derive(Clone)]
 pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
-    quota: Quota,
+    limiter: Arc<KeyedRateLimiter<C>>,
+    clock: C,
     flush_keys_interval: Duration,
+    last_flush: I,
     key_field: Option<Template>,
     exclude: Option<Condition>,
-    clock: C,
+    reroute_overflow: bool,
 }
 
 impl<C, I> Throttle<C, I>
@@ -98,11 +127,18 @@ where
             Some(threshold) => threshold,
             None => return Err(Box::new(ConfigError::NonZero)),
         };
+        let burst = match config.burst {
+            Some(burst) => match NonZeroU32::new(burst) {
+                Some(burst) => burst,
+                None => return Err(Box::new(ConfigError::NonZero)),
+            },
+            None => threshold,
+        };
 
         let quota = match Quota::with_period(Duration::from_secs_f64(
             flush_keys_interval.as_secs_f64() / f64::from(threshold.get()),
         )) {
-            Some(quota) => quota.allow_burst(threshold),
+            Some(quota) => quota.allow_burst(burst),
             None => return Err(Box::new(ConfigError::NonZero)),
         };
         let exclude = config
@@ -112,105 +148,82 @@ where
             .transpose()?;
 
         Ok(Self {
-            quota,
+            limiter: Arc::new(RateLimiter::dashmap_with_clock(quota, &clock)),
+            last_flush: clock.now(),
             clock,
             flush_keys_interval,
             key_field: config.key_field.clone(),
             exclude,
+            reroute_overflow: config.reroute_overflow,
         })
     }
+
+    /// Forgets the keys whose buckets are full again, so that they don't accumulate.
+    fn flush_keys(&mut self) {
+        let now = self.clock.now();
+        if Duration::from(now.duration_since(self.last_flush)) >= self.flush_keys_interval * 2 {
+            self.limiter.retain_recent();
+            self.last_flush = now;
+        }
+    }
 }
 
-impl<C, I> TaskTransform<Event> for Throttle<C, I>
+impl<C, I> SyncTransform for Throttle<C, I>
 where
-    C: clock::Clock<Instant = I> + Send + 'static,
-    I: clock::Reference + Send + 'static,
+    C: clock::Clock<Instant = I> + Send + Sync + 'static,
+    I: clock::Reference + Send + Sync + 'static,
 {
-    fn transform(
-        self: Box<Self>,
-        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
-    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
-    where
-        Self: 'static,
-    {
-        let mut flush_keys = tokio::time::interval(self.flush_keys_interval * 2);
-
-        let limiter = RateLimiter::dashmap_with_clock(self.quota, &self.clock);
-
-        Box::pin(stream! {
-          loop {
-            let done = tokio::select! {
-                biased;
-
-                maybe_event = input_rx.next() => {
-                    match maybe_event {
-                        None => true,
-                        Some(event) => {
-                            let (throttle, event) = match self.exclude.as_ref() {
-                                Some(condition) => {
-                                    let (result, event) = condition.check(event);
-                                    (!result, event)
-                                },
-                                _ => (true, event)
-                            };
-                            let output = if throttle {
-                                let key = self.key_field.as_ref().and_then(|t| {
-                                    t.render_string(&event)
-                                        .map_err(|error| {
-                                            emit!(TemplateRenderingError {
-                                                error,
-                                                field: Some("key_field"),
-                                                drop_event: false,
-                                            })
-                                        })
-                                        .ok()
-                                });
-
-                                match limiter.check_key(&key) {
-                                    Ok(()) => {
-                                        Some(event)
-                                    }
-                                    _ => {
-                                        if let Some(key) = key {
-                                            emit!(ThrottleEventDiscarded{key})
-                                        } else {
-                                            emit!(ThrottleEventDiscarded{key: "None".to_string()})
-                                        }
-                                        None
-                                    }
-                                }
-                            } else {
-                                Some(event)
-                            };
-                            if let Some(event) = output {
-                                yield event;
-                            }
-                            false
-                        }
-                    }
-                }
-                _ = flush_keys.tick() => {
-                    limiter.retain_recent();
-                    false
+    fn transform(&mut self, event: Event, output: &mut TransformOutputsBuf) {
+        self.flush_keys();
+
+        let (throttle, event) = match self.exclude.as_ref() {
+            Some(condition) => {
+                let (result, event) = condition.check(event);
+                (!result, event)
+            }
+            _ => (true, event),
+        };
+        if !throttle {
+            output.push(event);
+            return;
+        }
+
+        let key = self.key_field.as_ref().and_then(|t| {
+            t.render_string(&event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("key_field"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        });
+
+        match self.limiter.check_key(&key) {
+            Ok(()) => output.push(event),
+            _ => {
+                emit!(ThrottleEventDiscarded {
+                    key: key.unwrap_or_else(|| "None".to_string()),
+                    rerouted: self.reroute_overflow,
+                });
+                if self.reroute_overflow {
+                    output.push_named(OVERFLOW, event);
                 }
-            };
-            if done { break }
-          }
-        })
+            }
+        }
     }
 }
 
 #[derive(Debug, Snafu)]
 pub enum ConfigError {
-    #[snafu(display("`threshold`, and `window_secs` must be non-zero"))]
+    #[snafu(display("`threshold`, `window_secs`, and `burst` must be non-zero"))]
     NonZero,
 }
 
 #[cfg(test)]
 mod tests {
-    use std::task::Poll;
-
-    use futures::SinkExt;
+    use std::collections::HashMap;
 
     use super::*;
     use crate::{
@@ -225,73 +238,59 @@ mod tests {
         crate::test_util::test_generate_config::<ThrottleConfig>();
     }
 
-    #[tokio::test]
-    async fn throttle_events() {
+    fn throttle(config: &str, clock: &clock::FakeRelativeClock) -> Box<dyn SyncTransform> {
+        let config = toml::from_str::<ThrottleConfig>(config).unwrap();
+        Box::new(Throttle::new(&config, &TransformContext::default(), clock.clone()).unwrap())
+    }
+
+    /// Returns the number of events sent to the default and `overflow` outputs.
+    fn transform(throttle: &mut dyn SyncTransform, event: impl Into<Event>) -> (usize, usize) {
+        let mut outputs = TransformOutputsBuf::new_with_capacity(
+            vec![
+                TransformOutput::new(DataType::Log, HashMap::new()),
+                TransformOutput::new(DataType::Log, HashMap::new()).with_port(OVERFLOW),
+            ],
+            1,
+        );
+        throttle.transform(event.into(), &mut outputs);
+
+        let overflow = outputs
+            .take_all_named()
+            .remove(OVERFLOW)
+            .map_or(0, |buf| buf.len());
+        (outputs.take_primary().len(), overflow)
+    }
+
+    #[test]
+    fn throttle_events() {
         let clock = clock::FakeRelativeClock::default();
-        let config = toml::from_str::<ThrottleConfig>(
+        let mut throttle = throttle(
             r#"
 threshold = 2
 window_secs = 5
 "#,
-        )
-        .unwrap();
-
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
+            &clock,
+        );
 
-        let throttle = throttle.into_task();
-
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
-
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
-
-        tx.send(LogEvent::default().into()).await.unwrap();
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
 
         clock.advance(Duration::from_secs(2));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        // We should be back to pending, having the second event dropped
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        // The rate limit is exceeded, so the event is dropped
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (0, 0));
 
         clock.advance(Duration::from_secs(3));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
         // The rate limiter should now be refreshed and allow an additional event through
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
-
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
-
-        tx.disconnect();
-
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (0, 0));
     }
 
-    #[tokio::test]
-    async fn throttle_exclude() {
+    #[test]
+    fn throttle_exclude() {
         let clock = clock::FakeRelativeClock::default();
-        let config = toml::from_str::<ThrottleConfig>(
+        let mut throttle = throttle(
             r#"
 threshold = 2
 window_secs = 5
@@ -299,72 +298,50 @@ exclude = """
 exists(.special)
 """
 "#,
-        )
-        .unwrap();
-
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
-
-        let throttle = throttle.into_task();
-
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
-
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+            &clock,
+        );
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
 
         clock.advance(Duration::from_secs(2));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        // We should be back to pending, having the second event dropped
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (0, 0));
 
+        // The rate limiter should allow this log through regardless of current limit
         let mut special_log = LogEvent::default();
         special_log.insert("special", "true");
-        tx.send(special_log.into()).await.unwrap();
-        // The rate limiter should allow this log through regardless of current limit
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
+        assert_eq!(transform(throttle.as_mut(), special_log), (1, 0));
 
         clock.advance(Duration::from_secs(3));
 
-        tx.send(LogEvent::default().into()).await.unwrap();
-
-        // The rate limiter should now be refreshed and allow an additional event through
-        if let Some(_event) = out_stream.next().await {
-        } else {
-            panic!("Unexpectedly received None in output stream");
-        }
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
+    }
 
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+    #[test]
+    fn throttle_buckets() {
+        let clock = clock::FakeRelativeClock::default();
+        let mut throttle = throttle(
+            r#"
+threshold = 1
+window_secs = 5
+key_field = "{{ bucket }}"
+"#,
+            &clock,
+        );
 
-        tx.disconnect();
+        let mut log_a = LogEvent::default();
+        log_a.insert("bucket", "a");
+        let mut log_b = LogEvent::default();
+        log_b.insert("bucket", "b");
 
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+        assert_eq!(transform(throttle.as_mut(), log_a.clone()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), log_b), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), log_a), (0, 0));
     }
 
-    #[tokio::test]
-    async fn throttle_buckets() {
+    #[test]
+    fn throttle_flushes_keys() {
         let clock = clock::FakeRelativeClock::default();
         let config = toml::from_str::<ThrottleConfig>(
             r#"
@@ -374,44 +351,83 @@ key_field = "{{ bucket }}"
 "#,
         )
         .unwrap();
+        let mut throttle =
+            Throttle::new(&config, &TransformContext::default(), clock.clone()).unwrap();
+
+        let mut log = LogEvent::default();
+        log.insert("bucket", "a");
+        assert_eq!(transform(&mut throttle, log.clone()), (1, 0));
+        assert_eq!(throttle.limiter.len(), 1);
+
+        // Keys are forgotten once two windows have passed on the clock of the limiter.
+        clock.advance(Duration::from_secs(10));
+        let mut log = LogEvent::default();
+        log.insert("bucket", "b");
+        assert_eq!(transform(&mut throttle, log), (1, 0));
+        assert_eq!(throttle.limiter.len(), 1);
+    }
 
-        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
-            .map(Transform::event_task)
-            .unwrap();
+    #[test]
+    fn throttle_burst() {
+        let clock = clock::FakeRelativeClock::default();
+        let mut throttle = throttle(
+            r#"
+threshold = 1
+window_secs = 5
+burst = 3
+key_field = "{{ bucket }}"
+"#,
+            &clock,
+        );
 
-        let throttle = throttle.into_task();
+        let mut log = LogEvent::default();
+        log.insert("bucket", "a");
 
-        let (mut tx, rx) = futures::channel::mpsc::channel(10);
-        let mut out_stream = throttle.transform_events(Box::pin(rx));
+        for _ in 0..3 {
+            assert_eq!(transform(throttle.as_mut(), log.clone()), (1, 0));
+        }
+        assert_eq!(transform(throttle.as_mut(), log.clone()), (0, 0));
 
-        // tokio interval is always immediately ready, so we poll once to make sure
-        // we trip it/set the interval in the future
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        // The bucket refills at the rate of the threshold, not of the burst.
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(transform(throttle.as_mut(), log.clone()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), log), (0, 0));
+    }
 
-        let mut log_a = LogEvent::default();
-        log_a.insert("bucket", "a");
-        let mut log_b = LogEvent::default();
-        log_b.insert("bucket", "b");
-        tx.send(log_a.into()).await.unwrap();
-        tx.send(log_b.into()).await.unwrap();
-
-        let mut count = 0_u8;
-        while count < 2 {
-            if let Some(_event) = out_stream.next().await {
-                count += 1;
-            } else {
-                panic!("Unexpectedly received None in output stream");
-            }
-        }
-        assert_eq!(2, count);
+    #[test]
+    fn throttle_reroute_overflow() {
+        let clock = clock::FakeRelativeClock::default();
+        let mut throttle = throttle(
+            r#"
+threshold = 1
+window_secs = 5
+reroute_overflow = true
+"#,
+            &clock,
+        );
 
-        // We should be back to pending, having nothing waiting for us
-        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (1, 0));
+        assert_eq!(transform(throttle.as_mut(), LogEvent::default()), (0, 1));
+    }
 
-        tx.disconnect();
+    #[test]
+    fn overflow_output() {
+        let config = toml::from_str::<ThrottleConfig>(
+            r#"
+threshold = 1
+window_secs = 5
+reroute_overflow = true
+"#,
+        )
+        .unwrap();
 
-        // And still nothing there
-        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+        let outputs = config.outputs(
+            enrichment::TableRegistry::default(),
+            &[],
+            LogNamespace::Legacy,
+        );
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[1].port.as_deref(), Some(OVERFLOW));
     }
 
     #[tokio::test]
@@ -420,8 +436,10 @@ key_field = "{{ bucket }}"
             let config = ThrottleConfig {
                 threshold: 1,
                 window_secs: Duration::from_secs_f64(1.0),
+                burst: None,
                 key_field: None,
                 exclude: None,
+                reroute_overflow: false,
             };
             let (tx, rx) = mpsc::channel(1);
             let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
@@ -0,0 +1,314 @@
+use lookup::lookup_v2::ConfigTargetPath;
+use ordered_float::NotNan;
+use value::Kind;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::UnitConversionError,
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+mod unit;
+
+use self::unit::parse_quantity;
+pub use self::unit::{ConversionError, Unit};
+
+/// A rule converting the values of a field to a unit.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ConversionRule {
+    /// The field containing the values to convert.
+    #[configurable(metadata(docs::examples = "duration"))]
+    #[configurable(metadata(docs::examples = "response.size"))]
+    pub field: ConfigTargetPath,
+
+    /// The field that the converted value is written to.
+    ///
+    /// Defaults to `field`, which is overwritten.
+    #[configurable(metadata(docs::examples = "duration_seconds"))]
+    pub target: Option<ConfigTargetPath>,
+
+    /// The unit of numeric values, and of strings without a unit suffix.
+    ///
+    /// Strings with a unit suffix, such as `12ms` or `3.4GB`, are converted from the unit of their
+    /// suffix instead, when `parse_suffix` is enabled.
+    #[configurable(derived)]
+    pub from: Option<Unit>,
+
+    #[configurable(derived)]
+    pub to: Unit,
+
+    /// Whether or not to parse the unit suffix of string values.
+    #[serde(default = "crate::serde::default_true")]
+    pub parse_suffix: bool,
+
+    /// The field that the unit of the converted value is written to.
+    #[configurable(metadata(docs::examples = "duration_unit"))]
+    pub unit_field: Option<ConfigTargetPath>,
+}
+
+/// Configuration for the `unit_convert` transform.
+#[configurable_component(transform(
+    "unit_convert",
+    "Convert numeric fields between units, such as bytes, durations and temperatures."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct UnitConvertConfig {
+    /// The conversions to apply, in order.
+    pub rules: Vec<ConversionRule>,
+}
+
+impl GenerateConfig for UnitConvertConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"rules = [
+                { field = "duration", from = "ms", to = "s" },
+                { field = "size", to = "MB" },
+            ]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "unit_convert")]
+impl TransformConfig for UnitConvertConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        UnitConvert::new(self).map(Transform::function)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let definition = self
+                    .rules
+                    .iter()
+                    .fold(definition.clone(), |definition, rule| {
+                        let target = rule.target.as_ref().unwrap_or(&rule.field);
+                        // Values that fail to convert are left unchanged.
+                        let kind = definition.kind_at(target).union(Kind::float());
+                        let definition = definition.with_field(&target.0, kind, None);
+                        match &rule.unit_field {
+                            Some(unit_field) => {
+                                let kind = definition.kind_at(unit_field).union(Kind::bytes());
+                                definition.with_field(&unit_field.0, kind, None)
+                            }
+                            None => definition,
+                        }
+                    });
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    field: ConfigTargetPath,
+    field_name: String,
+    target: ConfigTargetPath,
+    from: Option<Unit>,
+    to: Unit,
+    parse_suffix: bool,
+    unit_field: Option<ConfigTargetPath>,
+}
+
+impl Rule {
+    fn convert(&self, value: &Value) -> Result<f64, ConversionError> {
+        let (value, from) = match value {
+            Value::Integer(value) => (*value as f64, None),
+            Value::Float(value) => (value.into_inner(), None),
+            Value::Bytes(bytes) if self.parse_suffix => {
+                parse_quantity(&String::from_utf8_lossy(bytes))?
+            }
+            Value::Bytes(bytes) => (
+                String::from_utf8_lossy(bytes)
+                    .trim()
+                    .parse()
+                    .map_err(|_| ConversionError::NotNumeric)?,
+                None,
+            ),
+            _ => return Err(ConversionError::NotNumeric),
+        };
+        let from = from.or(self.from).ok_or(ConversionError::MissingUnit)?;
+        from.convert(value, self.to)
+    }
+
+    fn apply(&self, log: &mut LogEvent) {
+        let Some(value) = log.get(&self.field) else {
+            return;
+        };
+
+        let converted = self
+            .convert(value)
+            .and_then(|value| NotNan::new(value).map_err(|_| ConversionError::NotNumeric));
+        match converted {
+            Ok(value) => {
+                log.insert(&self.target, Value::Float(value));
+                if let Some(unit_field) = &self.unit_field {
+                    log.insert(unit_field, self.to.name());
+                }
+            }
+            Err(error) => emit!(UnitConversionError {
+                field: &self.field_name,
+                error,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UnitConvert {
+    rules: Vec<Rule>,
+}
+
+impl UnitConvert {
+    pub fn new(config: &UnitConvertConfig) -> crate::Result<Self> {
+        let rules = config
+            .rules
+            .iter()
+            .map(|rule| {
+                let field_name = String::from(rule.field.0.clone());
+                if let Some(from) = rule.from {
+                    from.convert(0.0, rule.to)
+                        .map_err(|error| format!("Invalid rule for {:?}: {}", field_name, error))?;
+                } else if !rule.parse_suffix {
+                    return Err(format!(
+                        "Invalid rule for {:?}: `from` is required when `parse_suffix` is disabled",
+                        field_name
+                    )
+                    .into());
+                }
+
+                Ok(Rule {
+                    field: rule.field.clone(),
+                    field_name,
+                    target: rule.target.clone().unwrap_or_else(|| rule.field.clone()),
+                    from: rule.from,
+                    to: rule.to,
+                    parse_suffix: rule.parse_suffix,
+                    unit_field: rule.unit_field.clone(),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { rules })
+    }
+}
+
+impl FunctionTransform for UnitConvert {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+        for rule in &self.rules {
+            rule.apply(log);
+        }
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transforms::test::transform_one;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<UnitConvertConfig>();
+    }
+
+    fn unit_convert(config: &str) -> UnitConvert {
+        UnitConvert::new(&toml::from_str::<UnitConvertConfig>(config).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn converts_numbers_and_quantities() {
+        let mut transform = unit_convert(
+            r#"
+            rules = [
+                { field = "duration", from = "ms", to = "s", unit_field = "duration_unit" },
+                { field = "size", to = "MB" },
+                { field = "temperature", from = "celsius", to = "fahrenheit", target = "temperature_f" },
+            ]
+            "#,
+        );
+
+        let mut log = LogEvent::default();
+        log.insert("duration", 1500);
+        log.insert("size", "3.4GB");
+        log.insert("temperature", 100.0);
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+
+        assert_eq!(log["duration"], 1.5.into());
+        assert_eq!(log["duration_unit"], "s".into());
+        assert_eq!(log["size"], 3400.0.into());
+        assert_eq!(log["temperature"], 100.0.into());
+        assert_eq!(log["temperature_f"], 212.0.into());
+    }
+
+    #[test]
+    fn leaves_unconvertible_values() {
+        let mut transform = unit_convert(
+            r#"
+            rules = [
+                { field = "duration", to = "s" },
+                { field = "size", from = "B", to = "kB" },
+            ]
+            "#,
+        );
+
+        let mut log = LogEvent::default();
+        log.insert("duration", "12 parsecs");
+        let log = transform_one(&mut transform, log.into())
+            .unwrap()
+            .into_log();
+
+        assert_eq!(log["duration"], "12 parsecs".into());
+        assert!(!log.contains("size"));
+    }
+
+    #[test]
+    fn rejects_incompatible_units() {
+        let config = toml::from_str::<UnitConvertConfig>(
+            r#"
+            rules = [{ field = "size", from = "B", to = "s" }]
+            "#,
+        )
+        .unwrap();
+        assert!(UnitConvert::new(&config).is_err());
+    }
+
+    #[test]
+    fn requires_unit_without_suffix_parsing() {
+        let config = toml::from_str::<UnitConvertConfig>(
+            r#"
+            rules = [{ field = "size", to = "B", parse_suffix = false }]
+            "#,
+        )
+        .unwrap();
+        assert!(UnitConvert::new(&config).is_err());
+    }
+}
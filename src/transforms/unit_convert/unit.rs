@@ -0,0 +1,294 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use snafu::Snafu;
+use vector_config::configurable_component;
+
+/// A unit of measurement.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// Bytes.
+    #[serde(rename = "B")]
+    Bytes,
+
+    /// Kilobytes, of 1000 bytes.
+    #[serde(rename = "kB")]
+    Kilobytes,
+
+    /// Megabytes, of 1000 kilobytes.
+    #[serde(rename = "MB")]
+    Megabytes,
+
+    /// Gigabytes, of 1000 megabytes.
+    #[serde(rename = "GB")]
+    Gigabytes,
+
+    /// Terabytes, of 1000 gigabytes.
+    #[serde(rename = "TB")]
+    Terabytes,
+
+    /// Kibibytes, of 1024 bytes.
+    #[serde(rename = "KiB")]
+    Kibibytes,
+
+    /// Mebibytes, of 1024 kibibytes.
+    #[serde(rename = "MiB")]
+    Mebibytes,
+
+    /// Gibibytes, of 1024 mebibytes.
+    #[serde(rename = "GiB")]
+    Gibibytes,
+
+    /// Tebibytes, of 1024 gibibytes.
+    #[serde(rename = "TiB")]
+    Tebibytes,
+
+    /// Nanoseconds.
+    #[serde(rename = "ns")]
+    Nanoseconds,
+
+    /// Microseconds.
+    #[serde(rename = "us")]
+    Microseconds,
+
+    /// Milliseconds.
+    #[serde(rename = "ms")]
+    Milliseconds,
+
+    /// Seconds.
+    #[serde(rename = "s")]
+    Seconds,
+
+    /// Minutes.
+    #[serde(rename = "min")]
+    Minutes,
+
+    /// Hours.
+    #[serde(rename = "h")]
+    Hours,
+
+    /// Days.
+    #[serde(rename = "d")]
+    Days,
+
+    /// Degrees Celsius.
+    #[serde(rename = "celsius")]
+    Celsius,
+
+    /// Degrees Fahrenheit.
+    #[serde(rename = "fahrenheit")]
+    Fahrenheit,
+
+    /// Kelvins.
+    #[serde(rename = "kelvin")]
+    Kelvin,
+}
+
+/// The quantity a unit measures. Only units of the same dimension can be converted between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dimension {
+    Data,
+    Time,
+    Temperature,
+}
+
+impl Unit {
+    /// The name of the unit, as configured.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Bytes => "B",
+            Self::Kilobytes => "kB",
+            Self::Megabytes => "MB",
+            Self::Gigabytes => "GB",
+            Self::Terabytes => "TB",
+            Self::Kibibytes => "KiB",
+            Self::Mebibytes => "MiB",
+            Self::Gibibytes => "GiB",
+            Self::Tebibytes => "TiB",
+            Self::Nanoseconds => "ns",
+            Self::Microseconds => "us",
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+            Self::Days => "d",
+            Self::Celsius => "celsius",
+            Self::Fahrenheit => "fahrenheit",
+            Self::Kelvin => "kelvin",
+        }
+    }
+
+    pub const fn dimension(self) -> Dimension {
+        match self {
+            Self::Bytes
+            | Self::Kilobytes
+            | Self::Megabytes
+            | Self::Gigabytes
+            | Self::Terabytes
+            | Self::Kibibytes
+            | Self::Mebibytes
+            | Self::Gibibytes
+            | Self::Tebibytes => Dimension::Data,
+            Self::Nanoseconds
+            | Self::Microseconds
+            | Self::Milliseconds
+            | Self::Seconds
+            | Self::Minutes
+            | Self::Hours
+            | Self::Days => Dimension::Time,
+            Self::Celsius | Self::Fahrenheit | Self::Kelvin => Dimension::Temperature,
+        }
+    }
+
+    /// Parses the unit suffix of a quantity, such as the `ms` of `12ms`.
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        Some(match suffix {
+            "B" | "byte" | "bytes" => Self::Bytes,
+            "kB" | "KB" => Self::Kilobytes,
+            "MB" => Self::Megabytes,
+            "GB" => Self::Gigabytes,
+            "TB" => Self::Terabytes,
+            "KiB" | "Ki" => Self::Kibibytes,
+            "MiB" | "Mi" => Self::Mebibytes,
+            "GiB" | "Gi" => Self::Gibibytes,
+            "TiB" | "Ti" => Self::Tebibytes,
+            "ns" => Self::Nanoseconds,
+            "us" | "µs" | "μs" => Self::Microseconds,
+            "ms" => Self::Milliseconds,
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Seconds,
+            "m" | "min" | "mins" | "minute" | "minutes" => Self::Minutes,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hours,
+            "d" | "day" | "days" => Self::Days,
+            "C" | "°C" | "celsius" => Self::Celsius,
+            "F" | "°F" | "fahrenheit" => Self::Fahrenheit,
+            "K" | "kelvin" => Self::Kelvin,
+            _ => return None,
+        })
+    }
+
+    /// The number of base units, bytes, seconds or degrees Celsius, in one of this unit.
+    ///
+    /// The other temperature scales have different origins than Celsius, so they're converted
+    /// separately.
+    fn factor(self) -> f64 {
+        match self {
+            Self::Bytes | Self::Seconds | Self::Celsius => 1.0,
+            Self::Kilobytes => 1e3,
+            Self::Megabytes => 1e6,
+            Self::Gigabytes => 1e9,
+            Self::Terabytes => 1e12,
+            Self::Kibibytes => 1024.0,
+            Self::Mebibytes => 1024.0 * 1024.0,
+            Self::Gibibytes => 1024.0 * 1024.0 * 1024.0,
+            Self::Tebibytes => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+            Self::Nanoseconds => 1e-9,
+            Self::Microseconds => 1e-6,
+            Self::Milliseconds => 1e-3,
+            Self::Minutes => 60.0,
+            Self::Hours => 3600.0,
+            Self::Days => 86400.0,
+            Self::Fahrenheit | Self::Kelvin => 1.0,
+        }
+    }
+
+    fn to_base(self, value: f64) -> f64 {
+        match self {
+            Self::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+            Self::Kelvin => value - 273.15,
+            _ => value * self.factor(),
+        }
+    }
+
+    fn from_base(self, value: f64) -> f64 {
+        match self {
+            Self::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+            Self::Kelvin => value + 273.15,
+            _ => value / self.factor(),
+        }
+    }
+
+    /// Converts a value of this unit to another unit of the same dimension.
+    pub fn convert(self, value: f64, to: Self) -> Result<f64, ConversionError> {
+        if self.dimension() != to.dimension() {
+            return Err(ConversionError::IncompatibleUnits {
+                from: self.name(),
+                to: to.name(),
+            });
+        }
+        if self == to {
+            return Ok(value);
+        }
+        Ok(to.from_base(self.to_base(value)))
+    }
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum ConversionError {
+    #[snafu(display("value isn't a number"))]
+    NotNumeric,
+
+    #[snafu(display("unknown unit {:?}", unit))]
+    UnknownUnit { unit: String },
+
+    #[snafu(display("value has no unit"))]
+    MissingUnit,
+
+    #[snafu(display("can't convert {} to {}", from, to))]
+    IncompatibleUnits {
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+static QUANTITY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*([-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)\s*(\S*)\s*$").expect("valid regex")
+});
+
+/// Parses a quantity, such as `3.4GB`, into its value and unit, if it has a unit suffix.
+pub fn parse_quantity(s: &str) -> Result<(f64, Option<Unit>), ConversionError> {
+    let captures = QUANTITY.captures(s).ok_or(ConversionError::NotNumeric)?;
+    let value = captures[1]
+        .parse()
+        .map_err(|_| ConversionError::NotNumeric)?;
+    let unit = match &captures[2] {
+        "" => None,
+        suffix => Some(
+            Unit::from_suffix(suffix).ok_or_else(|| ConversionError::UnknownUnit {
+                unit: suffix.to_owned(),
+            })?,
+        ),
+    };
+    Ok((value, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_units() {
+        assert_eq!(Unit::Milliseconds.convert(1500.0, Unit::Seconds), Ok(1.5));
+        assert_eq!(Unit::Kibibytes.convert(2.0, Unit::Bytes), Ok(2048.0));
+        assert_eq!(Unit::Gigabytes.convert(3.4, Unit::Megabytes), Ok(3400.0));
+        assert_eq!(Unit::Celsius.convert(100.0, Unit::Fahrenheit), Ok(212.0));
+        assert_eq!(
+            Unit::Seconds.convert(1.0, Unit::Bytes),
+            Err(ConversionError::IncompatibleUnits { from: "s", to: "B" })
+        );
+    }
+
+    #[test]
+    fn parses_quantities() {
+        assert_eq!(parse_quantity("12ms"), Ok((12.0, Some(Unit::Milliseconds))));
+        assert_eq!(parse_quantity(" 3.4 GB "), Ok((3.4, Some(Unit::Gigabytes))));
+        assert_eq!(parse_quantity("-40°F"), Ok((-40.0, Some(Unit::Fahrenheit))));
+        assert_eq!(parse_quantity("1e3"), Ok((1000.0, None)));
+        assert_eq!(
+            parse_quantity("12 parsecs"),
+            Err(ConversionError::UnknownUnit {
+                unit: "parsecs".to_owned()
+            })
+        );
+        assert_eq!(parse_quantity("fast"), Err(ConversionError::NotNumeric));
+    }
+}
@@ -0,0 +1,461 @@
+use lookup::lookup_v2::ConfigTargetPath;
+use regex::Regex;
+use value::Kind;
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, Value},
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// A pattern of path segments collapsed into a placeholder.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SegmentPatternConfig {
+    /// The regular expression that path segments are matched against.
+    ///
+    /// Only segments matched as a whole are collapsed.
+    #[configurable(metadata(docs::examples = "[a-z]{2}-[A-Z]{2}"))]
+    #[configurable(metadata(docs::examples = "[A-Z]{3}-\\d+"))]
+    pub pattern: String,
+
+    /// The placeholder replacing the matching segments.
+    #[configurable(metadata(docs::examples = "{locale}"))]
+    pub placeholder: String,
+}
+
+/// Configuration for the `url_normalize` transform.
+#[configurable_component(transform(
+    "url_normalize",
+    "Normalize URLs and classify status codes into low-cardinality fields."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct UrlNormalizeConfig {
+    /// The field holding the URL of the request.
+    ///
+    /// Both absolute URLs and paths, with or without a query, are normalized. Events without a
+    /// URL are forwarded without a normalized URL.
+    #[serde(default = "default_url_field")]
+    #[configurable(metadata(docs::examples = "request.url"))]
+    pub url_field: ConfigTargetPath,
+
+    /// The field that the normalized URL is written to.
+    #[serde(default = "default_normalized_url_field")]
+    #[configurable(metadata(docs::examples = "request.route"))]
+    pub normalized_url_field: ConfigTargetPath,
+
+    /// The query parameters kept in the normalized URL, along with their values.
+    ///
+    /// All the other query parameters, and the fragment, are stripped.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "format"))]
+    #[configurable(metadata(docs::examples = "version"))]
+    pub allowed_query_parameters: Vec<String>,
+
+    /// Whether to collapse the path segments holding IDs into placeholders.
+    ///
+    /// UUIDs are replaced by `uuid_placeholder`, and numbers and hexadecimal IDs of at least 16
+    /// digits, such as hashes, by `id_placeholder`.
+    #[serde(default = "crate::serde::default_true")]
+    pub collapse_ids: bool,
+
+    /// The placeholder replacing the path segments holding numbers and hexadecimal IDs.
+    #[serde(default = "default_id_placeholder")]
+    #[configurable(metadata(docs::examples = ":id"))]
+    pub id_placeholder: String,
+
+    /// The placeholder replacing the path segments holding UUIDs.
+    #[serde(default = "default_uuid_placeholder")]
+    #[configurable(metadata(docs::examples = ":uuid"))]
+    pub uuid_placeholder: String,
+
+    /// Additional patterns of path segments collapsed into placeholders.
+    ///
+    /// The patterns are tried in order, before the IDs are collapsed.
+    #[serde(default)]
+    pub segment_patterns: Vec<SegmentPatternConfig>,
+
+    /// The field holding the HTTP status code of the response.
+    ///
+    /// Both integers and strings are supported. Events without a valid status code, between `100`
+    /// and `599`, are forwarded without a status class.
+    #[serde(default = "default_status_field")]
+    #[configurable(metadata(docs::examples = "response.status"))]
+    pub status_field: ConfigTargetPath,
+
+    /// The field that the class of the status code, such as `2xx` or `5xx`, is written to.
+    #[serde(default = "default_status_class_field")]
+    #[configurable(metadata(docs::examples = "response.status_class"))]
+    pub status_class_field: ConfigTargetPath,
+}
+
+fn default_url_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("url".to_string()).expect("valid path")
+}
+
+fn default_normalized_url_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("normalized_url".to_string()).expect("valid path")
+}
+
+fn default_id_placeholder() -> String {
+    "{id}".to_string()
+}
+
+fn default_uuid_placeholder() -> String {
+    "{uuid}".to_string()
+}
+
+fn default_status_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("status".to_string()).expect("valid path")
+}
+
+fn default_status_class_field() -> ConfigTargetPath {
+    ConfigTargetPath::try_from("status_class".to_string()).expect("valid path")
+}
+
+impl GenerateConfig for UrlNormalizeConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"url_field = "url"
+            status_field = "status"
+            allowed_query_parameters = ["format"]
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "url_normalize")]
+impl TransformConfig for UrlNormalizeConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(UrlNormalize::new(self)?))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let definition = definition
+                    .clone()
+                    .with_field(
+                        &self.normalized_url_field.0,
+                        Kind::bytes().or_undefined(),
+                        None,
+                    )
+                    .with_field(
+                        &self.status_class_field.0,
+                        Kind::bytes().or_undefined(),
+                        None,
+                    );
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+/// Whether the segment is a UUID, in its canonical hyphenated form.
+fn is_uuid(segment: &str) -> bool {
+    segment.len() == 36
+        && segment.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Whether the segment is a number, or a hexadecimal ID of at least 16 digits.
+///
+/// Hexadecimal IDs must contain a decimal digit, so that long words made of the letters `a` to `f`
+/// aren't mistaken for IDs.
+fn is_id(segment: &str) -> bool {
+    let is_number = !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit());
+    let is_hex_id = segment.len() >= 16
+        && segment.bytes().all(|byte| byte.is_ascii_hexdigit())
+        && segment.bytes().any(|byte| byte.is_ascii_digit());
+    is_number || is_hex_id
+}
+
+/// Returns the class of the status code, such as `4xx`, if it's a valid status code.
+fn status_class(status: &Value) -> Option<String> {
+    let code = match status {
+        Value::Integer(code) => *code,
+        Value::Bytes(code) => std::str::from_utf8(code).ok()?.trim().parse().ok()?,
+        _ => return None,
+    };
+    (100..=599)
+        .contains(&code)
+        .then(|| format!("{}xx", code / 100))
+}
+
+#[derive(Clone, Debug)]
+pub struct UrlNormalize {
+    url_field: ConfigTargetPath,
+    normalized_url_field: ConfigTargetPath,
+    allowed_query_parameters: Vec<String>,
+    collapse_ids: bool,
+    id_placeholder: String,
+    uuid_placeholder: String,
+    segment_patterns: Vec<(Regex, String)>,
+    status_field: ConfigTargetPath,
+    status_class_field: ConfigTargetPath,
+}
+
+impl UrlNormalize {
+    fn new(config: &UrlNormalizeConfig) -> crate::Result<Self> {
+        let segment_patterns = config
+            .segment_patterns
+            .iter()
+            .map(|segment_pattern| {
+                // The pattern is anchored so that only whole segments are matched.
+                Regex::new(&format!("^(?:{})$", segment_pattern.pattern))
+                    .map(|regex| (regex, segment_pattern.placeholder.clone()))
+                    .map_err(|error| {
+                        format!(
+                            "Invalid segment pattern {:?}: {}",
+                            segment_pattern.pattern, error
+                        )
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            url_field: config.url_field.clone(),
+            normalized_url_field: config.normalized_url_field.clone(),
+            allowed_query_parameters: config.allowed_query_parameters.clone(),
+            collapse_ids: config.collapse_ids,
+            id_placeholder: config.id_placeholder.clone(),
+            uuid_placeholder: config.uuid_placeholder.clone(),
+            segment_patterns,
+            status_field: config.status_field.clone(),
+            status_class_field: config.status_class_field.clone(),
+        })
+    }
+
+    fn normalize_segment<'a>(&'a self, segment: &'a str) -> &'a str {
+        if segment.is_empty() {
+            return segment;
+        }
+        if let Some((_, placeholder)) = self
+            .segment_patterns
+            .iter()
+            .find(|(regex, _)| regex.is_match(segment))
+        {
+            return placeholder;
+        }
+        if self.collapse_ids {
+            if is_uuid(segment) {
+                return &self.uuid_placeholder;
+            }
+            if is_id(segment) {
+                return &self.id_placeholder;
+            }
+        }
+        segment
+    }
+
+    fn normalize(&self, url: &str) -> String {
+        let url = url.split_once('#').map_or(url, |(url, _fragment)| url);
+        let (path, query) = match url.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (url, None),
+        };
+        // The scheme and the authority of absolute URLs are kept as they are.
+        let (origin, path) = match path.find("://") {
+            Some(scheme_end) => {
+                let authority_start = scheme_end + 3;
+                let path_start = path[authority_start..]
+                    .find('/')
+                    .map_or(path.len(), |index| authority_start + index);
+                path.split_at(path_start)
+            }
+            None => ("", path),
+        };
+
+        let mut normalized = String::with_capacity(url.len());
+        normalized.push_str(origin);
+        for (index, segment) in path.split('/').enumerate() {
+            if index > 0 {
+                normalized.push('/');
+            }
+            normalized.push_str(self.normalize_segment(segment));
+        }
+
+        let parameters = query
+            .into_iter()
+            .flat_map(|query| query.split('&'))
+            .filter(|parameter| {
+                let name = parameter
+                    .split_once('=')
+                    .map_or(*parameter, |(name, _)| name);
+                self.allowed_query_parameters
+                    .iter()
+                    .any(|allowed| allowed == name)
+            })
+            .collect::<Vec<_>>();
+        if !parameters.is_empty() {
+            normalized.push('?');
+            normalized.push_str(&parameters.join("&"));
+        }
+
+        normalized
+    }
+}
+
+impl FunctionTransform for UrlNormalize {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+
+        if let Some(Value::Bytes(url)) = log.get(&self.url_field) {
+            let normalized = self.normalize(&String::from_utf8_lossy(url));
+            log.insert(&self.normalized_url_field, normalized);
+        }
+        if let Some(class) = log.get(&self.status_field).and_then(status_class) {
+            log.insert(&self.status_class_field, class);
+        }
+
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+    use crate::{
+        test_util::components::assert_transform_compliance,
+        transforms::test::{create_topology, transform_one},
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<UrlNormalizeConfig>();
+    }
+
+    #[test]
+    fn collapses_ids() {
+        let config: UrlNormalizeConfig = toml::from_str("").unwrap();
+        let transform = UrlNormalize::new(&config).unwrap();
+
+        for (url, expected) in [
+            ("/users/123/orders/42", "/users/{id}/orders/{id}"),
+            (
+                "/items/3f2504e0-4f89-11d3-9a0c-0305e82c3301/",
+                "/items/{uuid}/",
+            ),
+            ("/objects/507f1f77bcf86cd799439011", "/objects/{id}"),
+            ("/v2/deadbeefdeadbeef/feed", "/v2/deadbeefdeadbeef/feed"),
+            (
+                "https://api.example.com:8443/users/7",
+                "https://api.example.com:8443/users/{id}",
+            ),
+            ("https://example.com", "https://example.com"),
+            ("/", "/"),
+        ] {
+            assert_eq!(transform.normalize(url), expected, "{}", url);
+        }
+    }
+
+    #[test]
+    fn strips_query_parameters() {
+        let config: UrlNormalizeConfig =
+            toml::from_str(r#"allowed_query_parameters = ["format", "flag"]"#).unwrap();
+        let transform = UrlNormalize::new(&config).unwrap();
+
+        assert_eq!(
+            transform.normalize("/search?q=vector&format=json&page=2&flag#results"),
+            "/search?format=json&flag"
+        );
+        assert_eq!(transform.normalize("/search?q=vector&page=2"), "/search");
+    }
+
+    #[test]
+    fn applies_segment_patterns() {
+        let config: UrlNormalizeConfig = toml::from_str(
+            r#"
+            id_placeholder = ":id"
+            segment_patterns = [{ pattern = "[a-z]{2}-[A-Z]{2}", placeholder = "{locale}" }]
+            "#,
+        )
+        .unwrap();
+        let transform = UrlNormalize::new(&config).unwrap();
+
+        assert_eq!(
+            transform.normalize("/en-US/docs/12/en-US-old"),
+            "/{locale}/docs/:id/en-US-old"
+        );
+
+        let config: UrlNormalizeConfig =
+            toml::from_str(r#"segment_patterns = [{ pattern = "(", placeholder = "x" }]"#).unwrap();
+        assert!(UrlNormalize::new(&config).is_err());
+    }
+
+    #[test]
+    fn classifies_status_codes() {
+        assert_eq!(status_class(&Value::from(204_i64)), Some("2xx".to_string()));
+        assert_eq!(status_class(&Value::from("503")), Some("5xx".to_string()));
+        assert_eq!(status_class(&Value::from(99_i64)), None);
+        assert_eq!(status_class(&Value::from(600_i64)), None);
+        assert_eq!(status_class(&Value::from("OK")), None);
+    }
+
+    #[test]
+    fn writes_normalized_fields() {
+        let config: UrlNormalizeConfig = toml::from_str("").unwrap();
+        let mut transform = UrlNormalize::new(&config).unwrap();
+
+        let mut log = LogEvent::from("request");
+        log.insert("url", "/users/123?token=secret");
+        log.insert("status", 404_i64);
+        let output = transform_one(&mut transform, log.into()).unwrap();
+        let output = output.as_log();
+        assert_eq!(output["normalized_url"], Value::from("/users/{id}"));
+        assert_eq!(output["status_class"], Value::from("4xx"));
+        assert_eq!(output["url"], Value::from("/users/123?token=secret"));
+
+        let input = Event::from(LogEvent::from("request"));
+        let output = transform_one(&mut transform, input.clone()).unwrap();
+        assert_eq!(output, input);
+    }
+
+    #[tokio::test]
+    async fn emits_internal_events() {
+        assert_transform_compliance(async {
+            let (tx, rx) = mpsc::channel(1);
+            let config: UrlNormalizeConfig = toml::from_str("").unwrap();
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut log = LogEvent::from("request");
+            log.insert("url", "/orders/9");
+            tx.send(log.into()).await.unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(output["normalized_url"], Value::from("/orders/{id}"));
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    future::ready,
+    num::NonZeroUsize,
+    pin::Pin,
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use arc_swap::ArcSwap;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use lookup::lookup_v2::ConfigTargetPath;
+use lru::LruCache;
+use tracing::Instrument;
+use value::{kind::Collection, Kind};
+use vector_config::configurable_component;
+use vector_core::config::LogNamespace;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, Value},
+    internal_events::{UserAgentRulesetRefreshError, UserAgentRulesetRefreshed},
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+mod ruleset;
+
+use self::ruleset::{Ruleset, UserAgent};
+
+/// Configuration for the `user_agent` transform.
+#[configurable_component(transform(
+    "user_agent",
+    "Classify user agents by browser, operating system, device and bot status."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct UserAgentConfig {
+    /// The field containing the user agent string.
+    #[configurable(metadata(docs::examples = "user_agent"))]
+    #[configurable(metadata(docs::examples = "request.headers.user_agent"))]
+    pub field: ConfigTargetPath,
+
+    /// The field that the classification is written to.
+    ///
+    /// The classification is an object with `browser`, `os` and `device` objects, and an `is_bot`
+    /// boolean.
+    #[configurable(metadata(docs::examples = "user_agent_info"))]
+    pub target: ConfigTargetPath,
+
+    /// The path to the ruleset, in the [uap-core][uap_core] `regexes.yaml` format.
+    ///
+    /// [uap_core]: https://github.com/ua-parser/uap-core/blob/master/regexes.yaml
+    #[configurable(metadata(docs::examples = "/etc/vector/regexes.yaml"))]
+    pub path: String,
+
+    /// The interval between checks for changes to the ruleset, in seconds.
+    ///
+    /// On every check, the ruleset is re-read from `path` if the file has been modified. The new
+    /// ruleset is swapped in atomically, and if it fails to load, the previously loaded ruleset
+    /// continues to be used.
+    ///
+    /// When unset, the ruleset is only reloaded on configuration reload.
+    #[configurable(metadata(docs::examples = 3600))]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[serde(default)]
+    pub refresh_interval_secs: Option<u64>,
+
+    /// The number of distinct user agents whose classification is cached.
+    ///
+    /// Most traffic comes from a small number of user agents, so caching their classification
+    /// avoids matching the same string against the ruleset over and over. The cache is cleared
+    /// whenever the ruleset is reloaded.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: NonZeroUsize,
+}
+
+fn default_cache_size() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl GenerateConfig for UserAgentConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"field = "user_agent"
+            target = "user_agent_info"
+            path = "/etc/vector/regexes.yaml"
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "user_agent")]
+impl TransformConfig for UserAgentConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        let ruleset = Arc::new(Ruleset::load(&self.path)?);
+        let shared = Arc::new(ArcSwap::new(Arc::clone(&ruleset)));
+
+        if let Some(interval) = self.refresh_interval_secs {
+            let refresher = Refresher {
+                path: self.path.clone(),
+                ruleset: Arc::downgrade(&shared),
+                interval: Duration::from_secs(interval),
+            };
+            tokio::spawn(
+                refresher.run().instrument(
+                    info_span!("user_agent: refresher", path = %self.path).or_current(),
+                ),
+            );
+        }
+
+        Ok(Transform::event_task(UserAgentTransform {
+            field: self.field.clone(),
+            target: self.target.clone(),
+            ruleset: shared,
+            cache: LruCache::new(self.cache_size),
+            cached_ruleset: ruleset,
+        }))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let strings = |fields: &[&str]| {
+            Kind::object(
+                fields
+                    .iter()
+                    .fold(Collection::empty(), |collection, field| {
+                        collection.with_known(*field, Kind::bytes().or_null())
+                    }),
+            )
+        };
+        let kind = Kind::object(
+            Collection::empty()
+                .with_known("browser", strings(&BROWSER_FIELDS))
+                .with_known("os", strings(&OS_FIELDS))
+                .with_known("device", strings(&DEVICE_FIELDS))
+                .with_known("is_bot", Kind::boolean()),
+        )
+        .or_undefined();
+
+        let schema_definition = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                (
+                    output.clone(),
+                    definition
+                        .clone()
+                        .with_field(&self.target.0, kind.clone(), None),
+                )
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, schema_definition)]
+    }
+}
+
+const BROWSER_FIELDS: [&str; 4] = ["family", "major", "minor", "patch"];
+const OS_FIELDS: [&str; 5] = ["family", "major", "minor", "patch", "patch_minor"];
+const DEVICE_FIELDS: [&str; 3] = ["family", "brand", "model"];
+
+fn object<const N: usize>(names: [&str; N], values: [Option<String>; N]) -> Value {
+    names
+        .into_iter()
+        .zip(values)
+        .map(|(name, value)| (name.to_owned(), value.map_or(Value::Null, Value::from)))
+        .collect::<BTreeMap<_, _>>()
+        .into()
+}
+
+impl From<UserAgent> for Value {
+    fn from(user_agent: UserAgent) -> Self {
+        let is_bot = user_agent.is_bot();
+        Value::from(BTreeMap::from([
+            (
+                "browser".to_owned(),
+                object(BROWSER_FIELDS, user_agent.browser),
+            ),
+            ("os".to_owned(), object(OS_FIELDS, user_agent.os)),
+            (
+                "device".to_owned(),
+                object(DEVICE_FIELDS, user_agent.device),
+            ),
+            ("is_bot".to_owned(), Value::Boolean(is_bot)),
+        ]))
+    }
+}
+
+pub struct UserAgentTransform {
+    field: ConfigTargetPath,
+    target: ConfigTargetPath,
+    ruleset: Arc<ArcSwap<Ruleset>>,
+    cache: LruCache<Bytes, Value>,
+    /// The ruleset that the cached classifications were made with.
+    cached_ruleset: Arc<Ruleset>,
+}
+
+impl UserAgentTransform {
+    fn transform_one(&mut self, mut event: Event) -> Event {
+        let user_agent = match event.as_log().get(&self.field) {
+            Some(Value::Bytes(user_agent)) => user_agent.clone(),
+            _ => return event,
+        };
+
+        let ruleset = self.ruleset.load();
+        if !Arc::ptr_eq(&ruleset, &self.cached_ruleset) {
+            self.cache.clear();
+            self.cached_ruleset = Arc::clone(&ruleset);
+        }
+
+        let classification = match self.cache.get(&user_agent) {
+            Some(classification) => classification.clone(),
+            None => {
+                let classification = Value::from(ruleset.parse(&user_agent));
+                self.cache.put(user_agent, classification.clone());
+                classification
+            }
+        };
+
+        event.as_mut_log().insert(&self.target, classification);
+        event
+    }
+}
+
+impl TaskTransform<Event> for UserAgentTransform {
+    fn transform(
+        self: Box<Self>,
+        task: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut inner = self;
+        Box::pin(task.filter_map(move |event| ready(Some(inner.transform_one(event)))))
+    }
+}
+
+/// Periodically reloads the ruleset shared with a [`UserAgentTransform`] when its file changes.
+///
+/// Runs until the transform has been dropped.
+struct Refresher {
+    path: String,
+    ruleset: Weak<ArcSwap<Ruleset>>,
+    interval: Duration,
+}
+
+impl Refresher {
+    async fn run(self) {
+        loop {
+            tokio::time::sleep(self.interval).await;
+
+            let ruleset = match self.ruleset.upgrade() {
+                Some(ruleset) => ruleset,
+                None => break,
+            };
+
+            match self.refresh(&ruleset) {
+                Ok(true) => emit!(UserAgentRulesetRefreshed { path: &self.path }),
+                Ok(false) => {}
+                Err(error) => emit!(UserAgentRulesetRefreshError {
+                    path: &self.path,
+                    error
+                }),
+            }
+        }
+    }
+
+    /// Reloads the ruleset if its file has been modified, returning whether it was reloaded.
+    fn refresh(&self, ruleset: &ArcSwap<Ruleset>) -> crate::Result<bool> {
+        let modified = fs::metadata(&self.path)?.modified()?;
+        if modified == ruleset.load().last_modified {
+            return Ok(false);
+        }
+
+        ruleset.store(Arc::new(Ruleset::load(&self.path)?));
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+    use crate::{
+        test_util::components::assert_transform_compliance, transforms::test::create_topology,
+    };
+
+    const RULESET_PATH: &str = "tests/data/user_agent/regexes.yaml";
+
+    const CHROME: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/112.0.5615.49 Safari/537.36";
+    const GOOGLEBOT: &str =
+        "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)";
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<UserAgentConfig>();
+    }
+
+    fn transform(path: &str) -> (UserAgentTransform, Refresher) {
+        let ruleset = Arc::new(Ruleset::load(path).unwrap());
+        let shared = Arc::new(ArcSwap::new(Arc::clone(&ruleset)));
+        let refresher = Refresher {
+            path: path.to_owned(),
+            ruleset: Arc::downgrade(&shared),
+            interval: Duration::from_millis(10),
+        };
+        let transform = UserAgentTransform {
+            field: ConfigTargetPath::try_from("http.user_agent".to_string()).unwrap(),
+            target: ConfigTargetPath::try_from("user_agent_info".to_string()).unwrap(),
+            ruleset: shared,
+            cache: LruCache::new(default_cache_size()),
+            cached_ruleset: ruleset,
+        };
+        (transform, refresher)
+    }
+
+    #[tokio::test]
+    async fn classifies_user_agents() {
+        assert_transform_compliance(async {
+            let (tx, rx) = mpsc::channel(1);
+            let config = toml::from_str::<UserAgentConfig>(&format!(
+                r#"
+                field = "http.user_agent"
+                target = "user_agent_info"
+                path = "{}"
+            "#,
+                RULESET_PATH
+            ))
+            .unwrap();
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut log = LogEvent::from("request");
+            log.insert("http.user_agent", CHROME);
+            tx.send(log.into()).await.unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(
+                output["user_agent_info.browser"],
+                Value::from(BTreeMap::from([
+                    ("family".to_owned(), Value::from("Chrome")),
+                    ("major".to_owned(), Value::from("112")),
+                    ("minor".to_owned(), Value::from("0")),
+                    ("patch".to_owned(), Value::from("5615")),
+                ]))
+            );
+            assert_eq!(output["user_agent_info.os.family"], "Windows".into());
+            assert_eq!(output["user_agent_info.os.major"], "10".into());
+            assert_eq!(output["user_agent_info.os.minor"], Value::Null);
+            assert_eq!(output["user_agent_info.device.family"], "Other".into());
+            assert_eq!(output["user_agent_info.is_bot"], false.into());
+
+            let mut log = LogEvent::from("request");
+            log.insert("http.user_agent", GOOGLEBOT);
+            tx.send(log.into()).await.unwrap();
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(output["user_agent_info.browser.family"], "Googlebot".into());
+            assert_eq!(output["user_agent_info.device.family"], "Spider".into());
+            assert_eq!(output["user_agent_info.is_bot"], true.into());
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+
+    #[test]
+    fn leaves_events_without_user_agent_unchanged() {
+        let (mut transform, _) = transform(RULESET_PATH);
+
+        let input = Event::from(LogEvent::from("request"));
+        let output = transform.transform_one(input.clone());
+        assert_eq!(output, input);
+
+        let mut input = LogEvent::from("request");
+        input.insert("http.user_agent", 42);
+        let input = Event::from(input);
+        let output = transform.transform_one(input.clone());
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn reloads_modified_ruleset() {
+        let path = crate::test_util::temp_file();
+        fs::copy(RULESET_PATH, &path).unwrap();
+        let path = path.to_str().unwrap();
+        let (mut transform, refresher) = transform(path);
+        let mut event = LogEvent::from("request");
+        event.insert("http.user_agent", CHROME);
+        let event = Event::from(event);
+
+        let output = transform.transform_one(event.clone()).into_log();
+        assert_eq!(output["user_agent_info.browser.family"], "Chrome".into());
+
+        // Unmodified files are left alone.
+        assert!(!refresher.refresh(&transform.ruleset).unwrap());
+
+        fs::write(
+            path,
+            "user_agent_parsers:\n  - regex: 'Chrome/'\n    family_replacement: 'Chromium'\n",
+        )
+        .unwrap();
+        assert!(refresher.refresh(&transform.ruleset).unwrap());
+
+        // Classifications cached with the previous ruleset are discarded.
+        let output = transform.transform_one(event).into_log();
+        assert_eq!(output["user_agent_info.browser.family"], "Chromium".into());
+        assert_eq!(output["user_agent_info.os.family"], "Other".into());
+    }
+
+    #[test]
+    fn keeps_previous_ruleset_when_reload_fails() {
+        let path = crate::test_util::temp_file();
+        fs::copy(RULESET_PATH, &path).unwrap();
+        let path = path.to_str().unwrap();
+        let (mut transform, refresher) = transform(path);
+
+        fs::write(path, "user_agent_parsers:\n  - regex: '(unclosed'\n").unwrap();
+        assert!(refresher.refresh(&transform.ruleset).is_err());
+
+        let mut event = LogEvent::from("request");
+        event.insert("http.user_agent", CHROME);
+        let output = transform.transform_one(event.into()).into_log();
+        assert_eq!(output["user_agent_info.browser.family"], "Chrome".into());
+    }
+
+    #[tokio::test]
+    async fn refresher_stops_when_transform_dropped() {
+        let (transform, refresher) = transform(RULESET_PATH);
+        drop(transform);
+
+        tokio::time::timeout(Duration::from_secs(5), refresher.run())
+            .await
+            .expect("refresher should stop once the transform is dropped");
+    }
+}
@@ -0,0 +1,343 @@
+//! Parsing of user agent strings with a [uap-core][uap_core] ruleset.
+//!
+//! [uap_core]: https://github.com/ua-parser/uap-core
+use std::{fs, time::SystemTime};
+
+use regex::bytes::{Captures, Regex, RegexBuilder};
+use serde::Deserialize;
+
+/// The device family that uap-core assigns to crawlers, spiders and other bots.
+const BOT_DEVICE_FAMILY: &str = "Spider";
+
+/// The family reported when no rule matches.
+const OTHER_FAMILY: &str = "Other";
+
+#[derive(Deserialize)]
+struct RulesetFile {
+    #[serde(default)]
+    user_agent_parsers: Vec<UserAgentRule>,
+    #[serde(default)]
+    os_parsers: Vec<OsRule>,
+    #[serde(default)]
+    device_parsers: Vec<DeviceRule>,
+}
+
+#[derive(Deserialize)]
+struct UserAgentRule {
+    regex: String,
+    regex_flag: Option<String>,
+    family_replacement: Option<String>,
+    v1_replacement: Option<String>,
+    v2_replacement: Option<String>,
+    v3_replacement: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OsRule {
+    regex: String,
+    regex_flag: Option<String>,
+    os_replacement: Option<String>,
+    os_v1_replacement: Option<String>,
+    os_v2_replacement: Option<String>,
+    os_v3_replacement: Option<String>,
+    os_v4_replacement: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct DeviceRule {
+    regex: String,
+    regex_flag: Option<String>,
+    device_replacement: Option<String>,
+    brand_replacement: Option<String>,
+    model_replacement: Option<String>,
+}
+
+/// How one output field is derived from a matching rule.
+struct FieldRule {
+    /// A template in which `$1` to `$9` are replaced with the matching capture groups.
+    replacement: Option<String>,
+    /// The capture group used when there is no replacement.
+    group: Option<usize>,
+}
+
+impl FieldRule {
+    const fn new(replacement: Option<String>, group: Option<usize>) -> Self {
+        Self { replacement, group }
+    }
+
+    fn extract(&self, captures: &Captures) -> Option<String> {
+        let value = match (&self.replacement, self.group) {
+            (Some(replacement), _) => substitute(replacement, captures),
+            (None, Some(group)) => {
+                String::from_utf8_lossy(captures.get(group)?.as_bytes()).into_owned()
+            }
+            (None, None) => return None,
+        };
+
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_owned())
+    }
+}
+
+/// A compiled rule, yielding `N` fields when it matches.
+struct Rule<const N: usize> {
+    regex: Regex,
+    fields: [FieldRule; N],
+}
+
+impl<const N: usize> Rule<N> {
+    fn compile(
+        section: &str,
+        index: usize,
+        pattern: &str,
+        flag: Option<&str>,
+        fields: [FieldRule; N],
+    ) -> crate::Result<Self> {
+        // Rulesets are written for ASCII character classes, which are also much cheaper to match.
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(flag == Some("i"))
+            .unicode(false)
+            .build()
+            .map_err(|error| format!("Invalid regex in {} rule {}: {}", section, index, error))?;
+
+        Ok(Self { regex, fields })
+    }
+}
+
+/// Returns the fields of the first rule matching `user_agent`.
+fn first_match<const N: usize>(
+    rules: &[Rule<N>],
+    user_agent: &[u8],
+) -> Option<[Option<String>; N]> {
+    rules.iter().find_map(|rule| {
+        let captures = rule.regex.captures(user_agent)?;
+        Some(std::array::from_fn(|i| rule.fields[i].extract(&captures)))
+    })
+}
+
+fn substitute(template: &str, captures: &Captures) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '$' {
+            if let Some(group) = chars.peek().and_then(|next| next.to_digit(10)) {
+                chars.next();
+                if let Some(capture) = captures.get(group as usize) {
+                    output.push_str(&String::from_utf8_lossy(capture.as_bytes()));
+                }
+                continue;
+            }
+        }
+        output.push(c);
+    }
+
+    output
+}
+
+/// The browser, operating system and device described by a user agent.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(super) struct UserAgent {
+    pub(super) browser: [Option<String>; 4],
+    pub(super) os: [Option<String>; 5],
+    pub(super) device: [Option<String>; 3],
+}
+
+impl UserAgent {
+    pub(super) fn is_bot(&self) -> bool {
+        self.device[0].as_deref() == Some(BOT_DEVICE_FAMILY)
+    }
+}
+
+/// A loaded ruleset.
+pub(super) struct Ruleset {
+    user_agent: Vec<Rule<4>>,
+    os: Vec<Rule<5>>,
+    device: Vec<Rule<3>>,
+    pub(super) last_modified: SystemTime,
+}
+
+impl Ruleset {
+    /// Loads the ruleset in the uap-core `regexes.yaml` format at `path`.
+    pub(super) fn load(path: &str) -> crate::Result<Self> {
+        let last_modified = fs::metadata(path)?.modified()?;
+        let contents = fs::read_to_string(path)?;
+        Self::from_yaml(&contents, last_modified)
+    }
+
+    fn from_yaml(contents: &str, last_modified: SystemTime) -> crate::Result<Self> {
+        let file: RulesetFile = serde_yaml::from_str(contents)?;
+
+        let user_agent = file
+            .user_agent_parsers
+            .into_iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                Rule::compile(
+                    "user_agent_parsers",
+                    i,
+                    &rule.regex,
+                    rule.regex_flag.as_deref(),
+                    [
+                        FieldRule::new(rule.family_replacement, Some(1)),
+                        FieldRule::new(rule.v1_replacement, Some(2)),
+                        FieldRule::new(rule.v2_replacement, Some(3)),
+                        FieldRule::new(rule.v3_replacement, Some(4)),
+                    ],
+                )
+            })
+            .collect::<crate::Result<_>>()?;
+
+        let os = file
+            .os_parsers
+            .into_iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                Rule::compile(
+                    "os_parsers",
+                    i,
+                    &rule.regex,
+                    rule.regex_flag.as_deref(),
+                    [
+                        FieldRule::new(rule.os_replacement, Some(1)),
+                        FieldRule::new(rule.os_v1_replacement, Some(2)),
+                        FieldRule::new(rule.os_v2_replacement, Some(3)),
+                        FieldRule::new(rule.os_v3_replacement, Some(4)),
+                        FieldRule::new(rule.os_v4_replacement, Some(5)),
+                    ],
+                )
+            })
+            .collect::<crate::Result<_>>()?;
+
+        let device = file
+            .device_parsers
+            .into_iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                Rule::compile(
+                    "device_parsers",
+                    i,
+                    &rule.regex,
+                    rule.regex_flag.as_deref(),
+                    [
+                        FieldRule::new(rule.device_replacement, Some(1)),
+                        // Unlike the other fields, the brand is only ever set by a replacement.
+                        FieldRule::new(rule.brand_replacement, None),
+                        FieldRule::new(rule.model_replacement, Some(1)),
+                    ],
+                )
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            user_agent,
+            os,
+            device,
+            last_modified,
+        })
+    }
+
+    pub(super) fn parse(&self, user_agent: &[u8]) -> UserAgent {
+        let other = || Some(OTHER_FAMILY.to_owned());
+
+        let mut browser = first_match(&self.user_agent, user_agent).unwrap_or_default();
+        browser[0] = browser[0].take().or_else(other);
+        let mut os = first_match(&self.os, user_agent).unwrap_or_default();
+        os[0] = os[0].take().or_else(other);
+        let mut device = first_match(&self.device, user_agent).unwrap_or_default();
+        device[0] = device[0].take().or_else(other);
+
+        UserAgent {
+            browser,
+            os,
+            device,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ruleset() -> Ruleset {
+        Ruleset::load("tests/data/user_agent/regexes.yaml").unwrap()
+    }
+
+    fn strings<const N: usize>(values: [Option<&str>; N]) -> [Option<String>; N] {
+        values.map(|value| value.map(str::to_owned))
+    }
+
+    #[test]
+    fn parses_with_capture_groups() {
+        let parsed = ruleset().parse(
+            b"Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/112.0.5615.49 Safari/537.36",
+        );
+
+        assert_eq!(
+            parsed,
+            UserAgent {
+                browser: strings([Some("Chrome"), Some("112"), Some("0"), Some("5615")]),
+                os: strings([Some("Windows"), Some("10"), None, None, None]),
+                device: strings([Some("Other"), None, None]),
+            }
+        );
+        assert!(!parsed.is_bot());
+    }
+
+    #[test]
+    fn parses_with_replacements() {
+        let parsed = ruleset().parse(
+            b"Mozilla/5.0 (iPhone; CPU iPhone OS 16_4 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.4 Mobile/15E148 Safari/604.1",
+        );
+
+        assert_eq!(
+            parsed,
+            UserAgent {
+                browser: strings([Some("Safari"), Some("16"), Some("4"), None]),
+                os: strings([Some("iOS"), Some("16"), Some("4"), None, None]),
+                device: strings([Some("iPhone"), Some("Apple"), Some("iPhone")]),
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_bots() {
+        let parsed = ruleset()
+            .parse(b"Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)");
+
+        assert_eq!(
+            parsed.browser,
+            strings([Some("Googlebot"), Some("2"), Some("1"), None])
+        );
+        assert_eq!(
+            parsed.device,
+            strings([Some("Spider"), Some("Spider"), Some("Desktop")])
+        );
+        assert!(parsed.is_bot());
+    }
+
+    #[test]
+    fn reports_other_when_nothing_matches() {
+        let parsed = ruleset().parse(b"curl/7.88.1");
+
+        assert_eq!(parsed.browser, strings([Some("Other"), None, None, None]));
+        assert_eq!(parsed.os, strings([Some("Other"), None, None, None, None]));
+        assert_eq!(parsed.device, strings([Some("Other"), None, None]));
+    }
+
+    #[test]
+    fn rejects_invalid_regex() {
+        let error = Ruleset::from_yaml(
+            "user_agent_parsers:\n  - regex: '(unclosed'\n",
+            SystemTime::UNIX_EPOCH,
+        )
+        .err()
+        .unwrap();
+
+        assert!(
+            error.to_string().contains("user_agent_parsers rule 0"),
+            "{}",
+            error
+        );
+    }
+}
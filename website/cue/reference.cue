@@ -55,7 +55,7 @@ _values: {
 // * `removed` - The component has been removed.
 #DevelopmentStatus: "beta" | "stable" | "deprecated" | "removed"
 
-#EncodingCodec: "json" | "logfmt" | "text" | "csv" | "native" | "native_json" | "avro" | "gelf"
+#EncodingCodec: "json" | "logfmt" | "text" | "csv" | "native" | "native_json" | "avro" | "gelf" | "protobuf" | "msgpack" | "cef"
 
 #Endpoint: {
 	description: string
@@ -416,6 +416,16 @@ _values: {
 			description: "A [Datadog Search](\(urls.datadog_search_syntax)) query string."
 			example:     #"*stack"#
 		},
+		{
+			name:        "field_matcher"
+			description: """
+				A list of `matchers`, each checking the field at `path` with any of `exists`, `equals`,
+				`regex`, `gt`, `gte`, `lt` and `lte`. All the matchers must hold for the event to
+				match. Unlike VRL, nothing is compiled, which suits configurations generating
+				thousands of simple conditions.
+				"""
+			example:     #"[{ path = ".status", gte = 500 }, { path = ".env", equals = "production" }]"#
+		},
 	]
 
 	options: {
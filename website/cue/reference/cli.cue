@@ -174,6 +174,74 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"checkpoints": {
+			description: """
+				List, export, and import the checkpoints of a `file` source, such as to carry them over
+				when replacing a host or moving the data directory, without ingesting files again or
+				missing data.
+
+				The `list` subcommand prints the checkpoints of the source, `export` writes them as JSON,
+				and `import` reads checkpoints exported with `export` and merges them with the existing
+				ones. Vector must be stopped while importing checkpoints, as the running source would
+				otherwise overwrite them.
+
+				Checkpoints using the `device_and_inode` fingerprint strategy are only meaningful on the
+				filesystem they were created on.
+				"""
+
+			example: "vector checkpoints export my_file_source --config /etc/vector/vector.toml --output checkpoints.json"
+
+			flags: {
+				"replace": {
+					description: "With `import`, drop the existing checkpoints of the source instead of merging the imported ones with them"
+				}
+			}
+
+			options: {
+				"config": {
+					_short:      "c"
+					description: env_vars.VECTOR_CONFIG.description
+					type:        "string"
+					default:     env_vars.VECTOR_CONFIG.type.string.default
+					env_var:     "VECTOR_CONFIG"
+				}
+				"config-dir": {
+					_short:      "C"
+					description: env_vars.VECTOR_CONFIG_DIR.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_DIR"
+				}
+				"data-dir": {
+					description: "The data directory the source's checkpoints are stored in. By default, it's resolved from the configuration"
+					type:        "string"
+					example:     "/var/lib/vector"
+				}
+				"input": {
+					_short:      "i"
+					description: "With `import`, the file to read the checkpoints from. Defaults to stdin"
+					type:        "string"
+				}
+				"output": {
+					_short:      "o"
+					description: "With `export`, the file to write the checkpoints to. Defaults to stdout"
+					type:        "string"
+				}
+			}
+
+			args: {
+				subcommand: {
+					description: "The action to take: `list`, `export`, or `import`"
+					required:    true
+					type:        "string"
+				}
+				source: {
+					description: "The ID of the `file` source"
+					required:    true
+					type:        "string"
+				}
+			}
+		}
+
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
@@ -234,6 +302,101 @@ cli: {
 			}
 		}
 
+		"pause": {
+			description: """
+				Pause a source of a running Vector instance, through its API. The source stops
+				pulling new data, while the components downstream of it keep draining the events
+				it already produced. Paused sources are resumed when Vector restarts.
+				"""
+
+			options: {
+				"url": {
+					_short:      "u"
+					description: "Vector GraphQL API server endpoint"
+					type:        "string"
+				}
+			}
+
+			args: {
+				component_id: {
+					type:        "string"
+					description: "The ID of the source"
+					required:    true
+				}
+			}
+		}
+
+		"replay": {
+			description: """
+				Send the events written to the dead-letter files of sinks with a `dead_letter.path` back
+				to the sinks, such as once the destination they failed to be delivered to has recovered.
+
+				Each event is sent to the sink that failed to deliver it, unless `--sink` is given. The
+				sinks are built from the configuration, without running the rest of the topology, so
+				Vector doesn't need to be stopped while replaying. The command exits with an error if any
+				event fails to be delivered again, in which case the files are kept.
+				"""
+
+			example: "vector replay --config /etc/vector/vector.toml --remove /var/lib/vector/dead_letter/http_out.ndjson"
+
+			flags: {
+				"remove": {
+					description: "Remove the files once all of their events have been delivered"
+				}
+			}
+
+			options: {
+				"config": {
+					_short:      "c"
+					description: env_vars.VECTOR_CONFIG.description
+					type:        "string"
+					default:     env_vars.VECTOR_CONFIG.type.string.default
+					env_var:     "VECTOR_CONFIG"
+				}
+				"config-dir": {
+					_short:      "C"
+					description: env_vars.VECTOR_CONFIG_DIR.description
+					type:        "string"
+					env_var:     "VECTOR_CONFIG_DIR"
+				}
+				"sink": {
+					description: "The ID of the sink to send the events to. By default, each event is sent to the sink that failed to deliver it"
+					type:        "string"
+				}
+			}
+
+			args: {
+				files: {
+					description: "The dead-letter files to replay"
+					required:    true
+					type:        "list"
+				}
+			}
+		}
+
+		"resume": {
+			description: """
+				Resume a source of a running Vector instance that was paused with `vector pause`,
+				through its API
+				"""
+
+			options: {
+				"url": {
+					_short:      "u"
+					description: "Vector GraphQL API server endpoint"
+					type:        "string"
+				}
+			}
+
+			args: {
+				component_id: {
+					type:        "string"
+					description: "The ID of the source"
+					required:    true
+				}
+			}
+		}
+
 		"test": {
 			description: """
 				Run Vector config unit tests, then exit. This command is experimental and
@@ -328,6 +491,32 @@ cli: {
 					description: "Components (sources, transforms) to observe for their inputs (comma-separated; accepts glob patterns)"
 					type:        "list"
 				}
+				"filter": {
+					description: """
+						Only observe the events matching a field filter, such as
+						`.status >= 500`, `.message =~ timeout` or `!.user.id`. The
+						filter is either a path, to check that the field exists, a path
+						prefixed with `!`, to check that it doesn't, or a path followed by
+						one of `==`, `=~`, `>`, `>=`, `<` or `<=` and a value. Can be
+						repeated; events must match every filter.
+						"""
+					type: "list"
+				}
+				"trace": {
+					description: """
+						Follow the next events emitted by sources that match a VRL boolean
+						expression through the topology, instead of sampling component
+						outputs. Each transform and sink the events reach is printed along
+						with the event as it saw it, followed by whether the sinks delivered
+						the events in the end.
+						"""
+					type: "string"
+				}
+				"trace-limit": {
+					description: "Maximum number of events to follow with `--trace`"
+					type:        "integer"
+					default:     1
+				}
 			}
 
 			args: {
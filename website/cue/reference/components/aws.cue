@@ -42,6 +42,16 @@ components: _aws: {
 								examples: ["arn:aws:iam::123456789098:role/my_role"]
 							}
 						}
+						external_id: {
+							category:    "Auth"
+							common:      false
+							description: "The external ID to pass when assuming the role set by `assume_role`. Required by roles whose trust policy checks an [external ID](\(urls.aws_iam_external_id))."
+							required:    false
+							type: string: {
+								default: null
+								examples: ["randomEXAMPLEidString"]
+							}
+						}
 						region: {
 							category:    "Auth"
 							common:      false
@@ -52,6 +62,16 @@ components: _aws: {
 								examples: ["us-west-2"]
 							}
 						}
+						sts_endpoint: {
+							category:    "Auth"
+							common:      false
+							description: "Custom endpoint to send STS requests to. The `{region}` placeholder is replaced by the region STS requests are sent to. If not set, the regional STS endpoint is used."
+							required:    false
+							type: string: {
+								default: null
+								examples: ["https://sts.{region}.amazonaws.com"]
+							}
+						}
 						load_timeout_secs: {
 							category:    "Auth"
 							common:      false
@@ -223,10 +243,31 @@ components: _aws: {
 					body: """
 						Vector can assume an AWS IAM role via the [`auth.assume_role`](#auth.assume_role) option. This is an
 						optional setting that is helpful for a variety of use cases, such as cross
-						account access.
+						account access. Roles whose trust policy requires an external ID are assumed with the
+						[`auth.external_id`](#auth.external_id) option.
+
+						The credentials of the assumed role are refreshed five minutes before they expire, and
+						picked up by the running component on its next request, so a long-running Vector
+						never needs to be reloaded to renew them. STS requests are sent to the regional
+						endpoint of [`auth.region`](#auth.region), or to [`auth.sts_endpoint`](#auth.sts_endpoint)
+						if set, such as an STS VPC endpoint.
 						"""
 				},
 			]
 		}
+		aws_endpoints: {
+			title: "AWS endpoints"
+			body: """
+				By default, Vector sends requests to the standard endpoint of the target service in
+				the configured region. The [`use_fips`](#use_fips) and [`use_dual_stack`](#use_dual_stack)
+				options select the FIPS and/or dual-stack (IPv4 and IPv6) endpoint of the service instead,
+				such as `logs-fips.us-gov-west-1.amazonaws.com` or `firehose.us-east-1.api.aws`,
+				as required in government and IPv6-only environments.
+
+				The [`endpoint`](#endpoint) option overrides the endpoint entirely, for instance to
+				use a VPC endpoint or an AWS-compatible service. Its `{region}` placeholder is replaced
+				by the region, so that the same configuration can be shared across regions.
+				"""
+		}
 	}
 }
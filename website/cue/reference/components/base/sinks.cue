@@ -12,6 +12,24 @@ base: components: sinks: configuration: {
 			"""
 		required: false
 		type: object: options: {
+			max_age: {
+				description: """
+					The maximum age of buffered events, in seconds.
+
+					Data files that have not been written to for longer than this are expired when they are
+					read: their events are dropped instead of being sent to the sink, and the data file is
+					deleted. This prevents a backlog of stale events from flooding downstream systems once a
+					sink that has been failing for a long time recovers.
+
+					By default, events never expire.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: uint: {
+					examples: [86400]
+					unit: "seconds"
+				}
+			}
 			max_events: {
 				description:   "The maximum number of events allowed in the buffer."
 				relevant_when: "type = \"memory\""
@@ -71,11 +89,85 @@ base: components: sinks: configuration: {
 														highest priority, and it is preferable to temporarily lose events rather than cause a
 														slowdown in the acceptance/consumption of events.
 														"""
+						overflow: """
+														Overflows to the next stage in the buffer topology.
+
+														If the current buffer stage is full, attempt to send this event to the next buffer stage.
+														That stage may also be configured overflow, and so on, but ultimately the last stage in a
+														buffer topology must use one of the other handling behaviors. This means that next stage may
+														potentially be able to buffer the event, but it may also block or drop the event.
+
+														This mode can only be used when two or more buffer stages are configured, such as to spill
+														events from an in-memory buffer to a disk buffer when the in-memory buffer is full.
+														"""
 					}
 				}
 			}
 		}
 	}
+	dead_letter: {
+		description: """
+			Dead-letter configuration.
+
+			When enabled, the events that the sink fails to deliver are sent to its `dead_letter` output,
+			which other components can consume by using `<sink_id>.dead_letter` as an input. Alternatively,
+			or additionally, they can be written to a local file, and replayed later with `vector replay`.
+			"""
+		required: false
+		type: object: options: {
+			enabled: {
+				description: """
+					Whether or not to send the events that the sink fails to deliver to its `dead_letter`
+					output.
+
+					Events are only sent once the sink has given up on delivering them, such as after
+					exhausting its retries. The sent events don't carry acknowledgements, so sources are still
+					notified of the failed delivery.
+					"""
+				required: false
+				type: bool: default: false
+			}
+			path: {
+				description: """
+					The file to append the events that the sink fails to deliver to.
+
+					Each line holds one event, in Vector's native JSON representation, along with the ID of
+					the sink and the time and status of the failure. The events can be sent to the sink again
+					with `vector replay`.
+					"""
+				required: false
+				type: string: examples: ["/var/lib/vector/dead_letter/http_out.ndjson"]
+			}
+		}
+	}
+	delivery_failures: {
+		description: """
+			Delivery failure reporting configuration.
+
+			When enabled, the events that the sink fails to deliver are reported to the
+			`delivery_failures` sources, which emit an event for each failed batch.
+			"""
+		required: false
+		type: object: options: {
+			enabled: {
+				description: "Whether or not to report the events that the sink fails to deliver."
+				required:    false
+				type: bool: default: false
+			}
+			key_fields: {
+				description: """
+					The fields of log and trace events to include in the reports.
+
+					Metric events are reported with their name, namespace, and tags.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: examples: ["message_id", "order.id"]
+				}
+			}
+		}
+	}
 	healthcheck: {
 		description: "Healthcheck configuration."
 		required:    false
@@ -97,6 +189,76 @@ base: components: sinks: configuration: {
 			}
 		}
 	}
+	http_client: {
+		description: """
+			Connection pool and protocol options of the HTTP client.
+
+			Reusing connections, and resuming TLS sessions when new connections are opened, avoids the
+			latency of the TCP and TLS handshakes for most requests.
+			"""
+		required: false
+		type: object: options: {
+			http_version: {
+				description: "The version of HTTP used by a client."
+				required:    false
+				type: string: {
+					default: "auto"
+					enum: {
+						auto: """
+							HTTP/2 is used if the server selects it through TLS ALPN, as offered by the
+							`tls.alpn_protocols` option, and HTTP/1.1 otherwise.
+							"""
+						http1: "Only HTTP/1.1 is used."
+						http2: """
+							Only HTTP/2 is used.
+
+							It's negotiated through TLS ALPN over HTTPS, and used with prior knowledge over plain HTTP.
+							"""
+					}
+				}
+			}
+			pool_idle_timeout_secs: {
+				description: """
+					How long an idle connection is kept open, in seconds.
+
+					By default, idle connections are closed after 90 seconds.
+					"""
+				required: false
+				type: uint: unit: "seconds"
+			}
+			pool_max_idle_per_host: {
+				description: """
+					The maximum number of idle connections kept open to each host.
+
+					By default, the number of idle connections is not limited.
+					"""
+				required: false
+				type: uint: unit: "connections"
+			}
+			tcp_keepalive_secs: {
+				description: """
+					The interval of the TCP keepalive probes sent on open connections, in seconds.
+
+					By default, TCP keepalive is disabled.
+					"""
+				required: false
+				type: uint: unit: "seconds"
+			}
+			tls_session_cache_capacity: {
+				description: """
+					The maximum number of TLS sessions cached for each host.
+
+					Cached sessions are resumed when new connections are opened to the host, which saves the
+					full TLS handshake. Set to `0` to disable TLS session resumption.
+					"""
+				required: false
+				type: uint: {
+					default: 8
+					unit:    "sessions"
+				}
+			}
+		}
+	}
 	inputs: {
 		description: """
 			A list of upstream [source][sources] or [transform][transforms] IDs.
@@ -171,4 +333,118 @@ base: components: sinks: configuration: {
 			}
 		}
 	}
+	schedule: {
+		description: """
+			Scheduling configuration.
+
+			Restricts the times at which the component runs. Outside of its scheduled windows, a source
+			is paused, and a sink stops taking events from its buffer, until the next window opens.
+			"""
+		required: false
+		type: object: options: {
+			active: {
+				description: """
+					The windows during which the component runs.
+
+					If empty, the component runs at all times, except during `maintenance` windows.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: object: options: {
+						days: {
+							description: """
+								The days of the week the window opens on.
+
+								If empty, the window opens every day.
+								"""
+							required: false
+							type: array: {
+								default: []
+								items: type: string: enum: {
+									monday:    "Monday."
+									tuesday:   "Tuesday."
+									wednesday: "Wednesday."
+									thursday:  "Thursday."
+									friday:    "Friday."
+									saturday:  "Saturday."
+									sunday:    "Sunday."
+								}
+							}
+						}
+						end: {
+							description: """
+								The time of day the window closes at, in the `HH:MM` format.
+
+								If it's earlier than `start`, the window closes on the next day.
+								"""
+							required: true
+							type: string: examples: ["06:00"]
+						}
+						start: {
+							description: "The time of day the window opens at, in the `HH:MM` format."
+							required:    true
+							type: string: examples: ["22:00"]
+						}
+					}
+				}
+			}
+			maintenance: {
+				description: "The windows during which the component doesn't run, even during an `active` window."
+				required:    false
+				type: array: {
+					default: []
+					items: type: object: options: {
+						days: {
+							description: """
+								The days of the week the window opens on.
+
+								If empty, the window opens every day.
+								"""
+							required: false
+							type: array: {
+								default: []
+								items: type: string: enum: {
+									monday:    "Monday."
+									tuesday:   "Tuesday."
+									wednesday: "Wednesday."
+									thursday:  "Thursday."
+									friday:    "Friday."
+									saturday:  "Saturday."
+									sunday:    "Sunday."
+								}
+							}
+						}
+						end: {
+							description: """
+								The time of day the window closes at, in the `HH:MM` format.
+
+								If it's earlier than `start`, the window closes on the next day.
+								"""
+							required: true
+							type: string: examples: ["06:00"]
+						}
+						start: {
+							description: "The time of day the window opens at, in the `HH:MM` format."
+							required:    true
+							type: string: examples: ["22:00"]
+						}
+					}
+				}
+			}
+			timezone: {
+				description: """
+					The name of the time zone the windows are expressed in.
+
+					This overrides the [global `timezone`][global_timezone] option. The time zone name may be
+					any name in the [TZ database][tz_database], or `local` to indicate system local time.
+
+					[global_timezone]: https://vector.dev/docs/reference/configuration//global-options#timezone
+					[tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+					"""
+				required: false
+				type: string: examples: ["local", "America/New_York", "EST5EDT"]
+			}
+		}
+	}
 }
@@ -1,60 +1,176 @@
 package metadata
 
-base: components: sources: configuration: proxy: {
-	description: """
-		Proxy configuration.
-
-		Configure to proxy traffic through an HTTP(S) proxy when making external requests.
-
-		Similar to common proxy configuration convention, users can set different proxies
-		to use based on the type of traffic being proxied, as well as set specific hosts that
-		should not be proxied.
-		"""
-	required: false
-	type: object: options: {
-		enabled: {
-			description: "Enables proxying support."
-			required:    false
-			type: bool: default: true
-		}
-		http: {
-			description: """
-				Proxy endpoint to use when proxying HTTP traffic.
-
-				Must be a valid URI string.
-				"""
-			required: false
-			type: string: examples: ["http://foo.bar:3128"]
-		}
-		https: {
-			description: """
-				Proxy endpoint to use when proxying HTTPS traffic.
-
-				Must be a valid URI string.
-				"""
-			required: false
-			type: string: examples: ["http://foo.bar:3128"]
+base: components: sources: configuration: {
+	proxy: {
+		description: """
+			Proxy configuration.
+
+			Configure to proxy traffic through an HTTP(S) proxy when making external requests.
+
+			Similar to common proxy configuration convention, users can set different proxies
+			to use based on the type of traffic being proxied, as well as set specific hosts that
+			should not be proxied.
+			"""
+		required: false
+		type: object: options: {
+			enabled: {
+				description: "Enables proxying support."
+				required:    false
+				type: bool: default: true
+			}
+			http: {
+				description: """
+					Proxy endpoint to use when proxying HTTP traffic.
+
+					Must be a valid URI string.
+					"""
+				required: false
+				type: string: examples: ["http://foo.bar:3128"]
+			}
+			https: {
+				description: """
+					Proxy endpoint to use when proxying HTTPS traffic.
+
+					Must be a valid URI string.
+					"""
+				required: false
+				type: string: examples: ["http://foo.bar:3128"]
+			}
+			no_proxy: {
+				description: """
+					A list of hosts to avoid proxying.
+
+					Multiple patterns are allowed:
+
+					| Pattern             | Example match                                                               |
+					| ------------------- | --------------------------------------------------------------------------- |
+					| Domain names        | `example.com` matches requests to `example.com`                     |
+					| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+					| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+					| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+					| Splat               | `*` matches all hosts                                                   |
+
+					[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: examples: ["localhost", ".foo.bar", "*"]
+				}
+			}
 		}
-		no_proxy: {
-			description: """
-				A list of hosts to avoid proxying.
-
-				Multiple patterns are allowed:
-
-				| Pattern             | Example match                                                               |
-				| ------------------- | --------------------------------------------------------------------------- |
-				| Domain names        | `example.com` matches requests to `example.com`                     |
-				| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
-				| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
-				| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
-				| Splat               | `*` matches all hosts                                                   |
-
-				[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
-				"""
-			required: false
-			type: array: {
-				default: []
-				items: type: string: examples: ["localhost", ".foo.bar", "*"]
+	}
+	schedule: {
+		description: """
+			Scheduling configuration.
+
+			Restricts the times at which the component runs. Outside of its scheduled windows, a source
+			is paused, and a sink stops taking events from its buffer, until the next window opens.
+			"""
+		required: false
+		type: object: options: {
+			active: {
+				description: """
+					The windows during which the component runs.
+
+					If empty, the component runs at all times, except during `maintenance` windows.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: object: options: {
+						days: {
+							description: """
+								The days of the week the window opens on.
+
+								If empty, the window opens every day.
+								"""
+							required: false
+							type: array: {
+								default: []
+								items: type: string: enum: {
+									monday:    "Monday."
+									tuesday:   "Tuesday."
+									wednesday: "Wednesday."
+									thursday:  "Thursday."
+									friday:    "Friday."
+									saturday:  "Saturday."
+									sunday:    "Sunday."
+								}
+							}
+						}
+						end: {
+							description: """
+								The time of day the window closes at, in the `HH:MM` format.
+
+								If it's earlier than `start`, the window closes on the next day.
+								"""
+							required: true
+							type: string: examples: ["06:00"]
+						}
+						start: {
+							description: "The time of day the window opens at, in the `HH:MM` format."
+							required:    true
+							type: string: examples: ["22:00"]
+						}
+					}
+				}
+			}
+			maintenance: {
+				description: "The windows during which the component doesn't run, even during an `active` window."
+				required:    false
+				type: array: {
+					default: []
+					items: type: object: options: {
+						days: {
+							description: """
+								The days of the week the window opens on.
+
+								If empty, the window opens every day.
+								"""
+							required: false
+							type: array: {
+								default: []
+								items: type: string: enum: {
+									monday:    "Monday."
+									tuesday:   "Tuesday."
+									wednesday: "Wednesday."
+									thursday:  "Thursday."
+									friday:    "Friday."
+									saturday:  "Saturday."
+									sunday:    "Sunday."
+								}
+							}
+						}
+						end: {
+							description: """
+								The time of day the window closes at, in the `HH:MM` format.
+
+								If it's earlier than `start`, the window closes on the next day.
+								"""
+							required: true
+							type: string: examples: ["06:00"]
+						}
+						start: {
+							description: "The time of day the window opens at, in the `HH:MM` format."
+							required:    true
+							type: string: examples: ["22:00"]
+						}
+					}
+				}
+			}
+			timezone: {
+				description: """
+					The name of the time zone the windows are expressed in.
+
+					This overrides the [global `timezone`][global_timezone] option. The time zone name may be
+					any name in the [TZ database][tz_database], or `local` to indicate system local time.
+
+					[global_timezone]: https://vector.dev/docs/reference/configuration//global-options#timezone
+					[tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+					"""
+				required: false
+				type: string: examples: ["local", "America/New_York", "EST5EDT"]
 			}
 		}
 	}
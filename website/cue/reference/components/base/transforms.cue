@@ -1,17 +1,29 @@
 package metadata
 
-base: components: transforms: configuration: inputs: {
-	description: """
-		A list of upstream [source][sources] or [transform][transforms] IDs.
+base: components: transforms: configuration: {
+	enabled_when: {
+		description: """
+			The name of a feature flag gating this transform.
 
-		Wildcards (`*`) are supported.
+			While the flag is disabled, events pass through the transform unchanged, to its default
+			output. Requires `feature_flags` to be configured.
+			"""
+		required: false
+		type: string: examples: ["new_parser"]
+	}
+	inputs: {
+		description: """
+			A list of upstream [source][sources] or [transform][transforms] IDs.
 
-		See [configuration][configuration] for more info.
+			Wildcards (`*`) are supported.
 
-		[sources]: https://vector.dev/docs/reference/configuration/sources/
-		[transforms]: https://vector.dev/docs/reference/configuration/transforms/
-		[configuration]: https://vector.dev/docs/reference/configuration/
-		"""
-	required: true
-	type: array: items: type: string: examples: ["my-source-or-transform-id", "prefix-*"]
+			See [configuration][configuration] for more info.
+
+			[sources]: https://vector.dev/docs/reference/configuration/sources/
+			[transforms]: https://vector.dev/docs/reference/configuration/transforms/
+			[configuration]: https://vector.dev/docs/reference/configuration/
+			"""
+		required: true
+		type: array: items: type: string: examples: ["my-source-or-transform-id", "prefix-*"]
+	}
 }
@@ -105,6 +105,17 @@ components: _kafka: {
 				- `tls.verify_certificate` - Set to `true`.
 				"""
 		}
+		passthrough: {
+			title: "Replicating messages"
+			body:  """
+				With `passthrough` enabled on both the `kafka` source and the `kafka` sink, Vector
+				can replicate messages between Kafka clusters, possibly filtering or transforming
+				them along the way, without losing their identity. The source stores the topic,
+				partition, timestamp, key, and headers of each message in the event metadata, and
+				the sink produces the event with them, in place of its `topic`, `key_field`, and
+				`headers_key` options.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -6,8 +6,11 @@ components: sinks: [Name=string]: {
 	features: _
 
 	configuration: {
-		inputs: base.components.sinks.configuration.inputs
-		buffer: base.components.sinks.configuration.buffer
+		inputs:            base.components.sinks.configuration.inputs
+		buffer:            base.components.sinks.configuration.buffer
+		dead_letter:       base.components.sinks.configuration.dead_letter
+		delivery_failures: base.components.sinks.configuration.delivery_failures
+		schedule:          base.components.sinks.configuration.schedule
 		healthcheck: {
 			description: base.components.sinks.configuration.healthcheck.description
 			required:    base.components.sinks.configuration.healthcheck.required
@@ -26,7 +29,8 @@ components: sinks: [Name=string]: {
 
 		if features.send != _|_ && features.send.proxy != _|_ {
 			if features.send.proxy.enabled {
-				proxy: base.components.sinks.configuration.proxy
+				proxy:       base.components.sinks.configuration.proxy
+				http_client: base.components.sinks.configuration.http_client
 			}
 		}
 
@@ -188,6 +192,27 @@ components: sinks: [Name=string]: {
 														[apache_avro]: https://avro.apache.org/
 														"""
 												}
+												if codec == "cef" {
+													cef: """
+														Encodes an event as a [Common Event Format (CEF)][cef] message.
+
+														[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+														"""
+												}
+												if codec == "msgpack" {
+													msgpack: """
+														Encodes an event as a [MessagePack][msgpack] map.
+
+														[msgpack]: https://msgpack.org/
+														"""
+												}
+												if codec == "protobuf" {
+													protobuf: """
+														Encodes an event as a [Protobuf][protobuf] message of a user-provided message type.
+
+														[protobuf]: https://protobuf.dev/
+														"""
+												}
 											}
 										}
 									}
@@ -216,6 +241,36 @@ components: sinks: [Name=string]: {
 												}
 											}
 										}
+										if codec == "protobuf" {
+											protobuf: {
+												description:   "Protobuf-specific encoder options."
+												required:      true
+												relevant_when: "codec = `protobuf`"
+												type: object: options: {
+													desc_file: {
+														description: "The path to the Protobuf descriptor set file."
+														required:    true
+														type: string: examples: ["/etc/vector/protobuf_descriptor_set.desc"]
+													}
+													message_type: {
+														description: "The full name of the message type to encode events as."
+														required:    true
+														type: string: examples: ["package.Message"]
+													}
+													unknown_fields: {
+														description: "How to handle the fields of events that have no matching field in the message type."
+														required:    false
+														type: string: {
+															default: "ignore"
+															enum: {
+																ignore: "The fields are left out of the encoded message."
+																reject: "Events with such fields fail to encode, and are dropped."
+															}
+														}
+													}
+												}
+											}
+										}
 									}
 								}
 
@@ -340,6 +395,27 @@ components: sinks: [Name=string]: {
 												required:    false
 												type: float: default: 0.7
 											}
+											max_concurrency_limit: {
+												common:      false
+												description: "The maximum concurrency limit. The adaptive concurrency algorithm never raises the limit above this value. When `partitioned` is enabled, this applies to each partition."
+												required:    false
+												type: uint: default: 200
+											}
+											min_rtt_window_secs: {
+												common:      false
+												description: "The minimum amount of time over which RTTs are averaged before adjusting the limit. By default, the RTTs are averaged over an interval equal to the past RTT average. Larger values smooth out short spikes in the RTT at the cost of adjusting the limit more slowly."
+												required:    false
+												type: float: {
+													default: 0.0
+													unit:    "seconds"
+												}
+											}
+											partitioned: {
+												common:      false
+												description: "Whether or not to manage a separate concurrency limit for each partition of the requests, such as the token and index of the `splunk_hec_logs` sink, so that one slow backend doesn't throttle the whole sink. Sinks that don't partition their requests ignore this option. The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the partition receives requests again."
+												required:    false
+												type: bool: default: false
+											}
 											rtt_deviation_scale: {
 												common: false
 												description: """
@@ -664,5 +740,10 @@ components: sinks: [Name=string]: {
 		buffer_sent_events_total:             components.sources.internal_metrics.output.metrics.buffer_sent_events_total
 		buffer_sent_event_bytes_total:        components.sources.internal_metrics.output.metrics.buffer_sent_event_bytes_total
 		buffer_discarded_events_total:        components.sources.internal_metrics.output.metrics.buffer_discarded_events_total
+		buffer_expired_events_total:          components.sources.internal_metrics.output.metrics.buffer_expired_events_total
+		buffer_expired_bytes_total:           components.sources.internal_metrics.output.metrics.buffer_expired_bytes_total
+		buffer_oldest_event_age_seconds:      components.sources.internal_metrics.output.metrics.buffer_oldest_event_age_seconds
+		buffer_purged_events_total:           components.sources.internal_metrics.output.metrics.buffer_purged_events_total
+		buffer_purged_bytes_total:            components.sources.internal_metrics.output.metrics.buffer_purged_bytes_total
 	}
 }
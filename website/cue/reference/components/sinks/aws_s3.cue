@@ -174,6 +174,38 @@ components: sinks: aws_s3: components._aws & {
 				"""
 		}
 
+		parquet: {
+			title: "Parquet files"
+			body:  """
+				Setting `batch_encoding.codec` to `parquet` writes each batch as an
+				[Apache Parquet](https://parquet.apache.org/) file, with the `.parquet`
+				extension, which query engines read far more efficiently than JSON. The
+				`encoding` option isn't needed then, and the `except_fields`, `only_fields` and
+				`timestamp_format` options are set on `batch_encoding` instead.
+
+				The columns of the files are given by `batch_encoding.parquet.schema`, or else
+				derived from the schema definition of the events, for the fields whose type is
+				known. Values that don't match the type of their column are written as nulls, and
+				reported as errors, rather than failing the batch. When neither describes any
+				field, the columns are inferred from the events of each batch, so set a schema
+				when the files are queried as a single table.
+
+				Files are rolled per partition, that is per rendered `key_prefix`, when their batch
+				reaches `batch.max_bytes` or `batch.max_events`, or is `batch.timeout_secs` old.
+				Within a file, rows are grouped by `batch_encoding.parquet.row_group_size`, and
+				column chunks are compressed with `batch_encoding.parquet.compression`, while the
+				`compression` option is ignored.
+
+				```toml
+				key_prefix = "logs/date=%F/hour=%H/"
+
+				[sinks.s3.batch_encoding]
+				codec = "parquet"
+				parquet.schema = { timestamp = "timestamp", message = "string", status = "int64" }
+				```
+				"""
+		}
+
 		server_side_encryption: {
 			title: "Server-Side Encryption (SSE)"
 			body:  """
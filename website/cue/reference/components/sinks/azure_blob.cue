@@ -122,6 +122,59 @@ components: sinks: azure_blob: {
 				```
 				"""
 		}
+
+		append_blobs: {
+			title: "Append blobs"
+			body: """
+				By default, each batch is written to its own block blob, which can result in a
+				large number of small blobs for continuously written logs. When
+				[`blob_type`](#blob_type) is set to `append`, the batches of each rendered
+				[`blob_prefix`](#blob_prefix) are instead appended to a single
+				[append blob](\(urls.azure_blob_storage_append_blobs)), named the same way as block
+				blobs when it is started.
+
+				Vector starts a new blob once the current one would exceed
+				[`max_blob_size_bytes`](#max_blob_size_bytes), or reaches the limit of 50,000
+				blocks, and whenever it restarts. Batches are appended in blocks of at most
+				[`block_size_bytes`](#block_size_bytes), and a batch is never split across blobs,
+				so that `gzip` compressed blobs remain readable.
+				"""
+		}
+
+		parquet: {
+			title: "Parquet files"
+			body:  """
+				Setting `batch_encoding.codec` to `parquet` writes each batch as an
+				[Apache Parquet](https://parquet.apache.org/) file, with the `.parquet`
+				extension, which query engines read far more efficiently than JSON. The
+				`encoding` option isn't needed then, and the `except_fields`, `only_fields` and
+				`timestamp_format` options are set on `batch_encoding` instead.
+
+				The columns of the files are given by `batch_encoding.parquet.schema`, or else
+				derived from the schema definition of the events, for the fields whose type is
+				known. Values that don't match the type of their column are written as nulls, and
+				reported as errors, rather than failing the batch. When neither describes any
+				field, the columns are inferred from the events of each batch, so set a schema
+				when the files are queried as a single table.
+
+				Files are rolled per partition, that is per rendered `blob_prefix`, when their batch
+				reaches `batch.max_bytes` or `batch.max_events`, or is `batch.timeout_secs` old.
+				Within a file, rows are grouped by `batch_encoding.parquet.row_group_size`, and
+				column chunks are compressed with `batch_encoding.parquet.compression`, while the
+				`compression` option is ignored.
+
+				Parquet files can't be appended to one another, so batch encodings are only
+				supported with block blobs.
+
+				```toml
+				blob_prefix = "logs/date=%F/hour=%H/"
+
+				[sinks.azure.batch_encoding]
+				codec = "parquet"
+				parquet.schema = { timestamp = "timestamp", message = "string", status = "int64" }
+				```
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -0,0 +1,119 @@
+package metadata
+
+components: sinks: azure_event_hubs: {
+	title: "Azure Event Hubs"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["Azure"]
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    900_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: {
+					enabled: true
+					enum: ["json", "text"]
+				}
+			}
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: enabled: false
+			to: {
+				service: services.azure_event_hubs
+
+				interface: {
+					socket: {
+						api: {
+							title: "AMQP 1.0"
+							url:   urls.azure_event_hubs_amqp
+						}
+						direction: "outgoing"
+						protocols: ["tcp"]
+						ssl:       "required"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: [
+			"""
+				The Event Hubs namespace must be reachable over AMQPS, on port 5671.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.azure_event_hubs.configuration
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: false
+	}
+
+	how_it_works: {
+		batching: {
+			title: "Batching"
+			body: """
+				Events are batched by partition key, and each batch is sent as a single batched
+				message, which Event Hubs accepts or rejects as a whole. Event Hubs limits batches to
+				1 MB, so `batch.max_bytes` can't be set higher than that, and defaults to a lower
+				value to leave room for the overhead of encoding each event.
+
+				Batches rejected by Event Hubs, such as those that are too large, are not retried.
+				"""
+		}
+		partitioning: {
+			title: "Partitioning"
+			body: """
+				When `partition_key` is set, it is rendered for each event, and all the events with
+				the same key are sent to the same partition, in order. Events whose key can't be
+				rendered are dropped. Without a partition key, Event Hubs distributes batches
+				across partitions.
+				"""
+		}
+		authentication: {
+			title: "Authentication"
+			body: """
+				The sink authenticates through the claims-based security of Event Hubs, with either
+				a shared access signature signed by a shared access key, or an Azure Active Directory
+				token. Tokens are renewed on the open connection before they expire.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		events_out_total:                 components.sources.internal_metrics.output.metrics.events_out_total
+	}
+}
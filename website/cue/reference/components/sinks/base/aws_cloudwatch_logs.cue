@@ -50,6 +50,18 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 				required:    true
 				type: string: examples: ["/my/aws/credentials"]
 			}
+			external_id: {
+				description: """
+					The external ID to pass when assuming the role.
+
+					Required by roles whose trust policy checks an [external ID][external_id], as is common
+					for cross-account access granted to third parties.
+
+					[external_id]: https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html
+					"""
+				required: false
+				type: string: examples: ["randomEXAMPLEidString"]
+			}
 			imds: {
 				description: "Configuration for authenticating with AWS through IMDS."
 				required:    false
@@ -118,6 +130,17 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 				required:    true
 				type: string: examples: ["wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY"]
 			}
+			sts_endpoint: {
+				description: """
+					Custom endpoint to send STS requests to.
+
+					The `{region}` placeholder is replaced by the region STS requests are sent to, so that the
+					same endpoint template can be used across regions, such as for VPC endpoints. If not set,
+					the regional STS endpoint is used.
+					"""
+				required: false
+				type: string: examples: ["https://sts.{region}.amazonaws.com"]
+			}
 		}
 	}
 	batch: {
@@ -129,7 +152,8 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 					The maximum size of a batch that is processed by a sink.
 
 					This is based on the uncompressed size of the batched events, before they are
-					serialized/compressed.
+					serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+					`aws_cloudwatch_logs`, use the size of the encoded payload instead.
 					"""
 				required: false
 				type: uint: {
@@ -208,10 +232,290 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 				description:   "Apache Avro-specific encoder options."
 				relevant_when: "codec = \"avro\""
 				required:      true
-				type: object: options: schema: {
-					description: "The Avro schema."
-					required:    true
-					type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+				type: object: options: {
+					schema: {
+						description: "The Avro schema."
+						required:    true
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					schema_registry: {
+						description: """
+							The schema registry that the schema is registered in.
+
+							If set, the schema is registered under the subject named by the `subject_name_strategy`,
+							and messages are encoded in the Confluent wire format, prefixed by the id of the schema.
+							"""
+						required: false
+						type: object: options: {
+							password: {
+								description: "The password for basic authentication to the schema registry."
+								required:    false
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							proxy: {
+								description: """
+									The proxy used to reach the schema registry.
+
+									Proxies set in the environment are used unless overridden here.
+									"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+											Proxy endpoint to use when proxying HTTP traffic.
+
+											Must be a valid URI string.
+											"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+											Proxy endpoint to use when proxying HTTPS traffic.
+
+											Must be a valid URI string.
+											"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+											A list of hosts to avoid proxying.
+
+											Multiple patterns are allowed:
+
+											| Pattern             | Example match                                                               |
+											| ------------------- | --------------------------------------------------------------------------- |
+											| Domain names        | `example.com` matches requests to `example.com`                     |
+											| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+											| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+											| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+											| Splat               | `*` matches all hosts                                                   |
+
+											[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+											"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							subject_name_strategy: {
+								description: "The strategy naming the subject that the schema is registered under, when encoding."
+								required:    false
+								type: string: {
+									default: "topic_name"
+									enum: {
+										record_name: "The subject is named after the full name of the record of the schema."
+										topic_name:  "The subject is named after the topic, as `<topic>-value`."
+										topic_record_name: """
+											The subject is named after both the topic and the full name of the record of the schema,
+											as `<topic>-<record>`.
+											"""
+									}
+								}
+							}
+							tls: {
+								description: "TLS configuration."
+								required:    false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+											Sets the list of supported ALPN protocols.
+
+											Declare the supported ALPN protocols, which are used during negotiation with peer. They are prioritized in the order
+											that they are defined.
+											"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+											Absolute path to an additional CA certificate file.
+
+											The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+											"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+											Absolute path to a certificate file used to identify this server.
+
+											The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+											an inline string in PEM format.
+
+											If this is set, and is not a PKCS#12 archive, `key_file` must also be set.
+											"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+											Absolute path to a private key file used to identify this server.
+
+											The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+											"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+											Passphrase used to unlock the encrypted key file.
+
+											This has no effect unless `key_file` is set.
+											"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									verify_certificate: {
+										description: """
+											Enables certificate verification.
+
+											If enabled, certificates must not be expired and must be issued by a trusted
+											issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+											certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+											so on until the verification process reaches a root certificate.
+
+											Relevant for both incoming and outgoing connections.
+
+											Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+											"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+											Enables hostname verification.
+
+											If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+											the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+											Only relevant for outgoing connections.
+
+											Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+											"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							topic: {
+								description: """
+									The topic that the subject is named after, when encoding.
+
+									Required by the `topic_name` and `topic_record_name` strategies.
+									"""
+								required: false
+								type: string: examples: ["logs"]
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+							username: {
+								description: "The username for basic authentication to the schema registry."
+								required:    false
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+				}
+			}
+			cef: {
+				description:   "The CEF Serializer Options."
+				relevant_when: "codec = \"cef\""
+				required:      true
+				type: object: options: {
+					device_event_class_id_field: {
+						description: """
+							The field containing the identifier of the type of event, written to the
+							`Device Event Class ID` header field.
+							"""
+						required: false
+						type: string: {
+							default: "deviceEventClassId"
+							examples: ["event.code"]
+						}
+					}
+					device_product: {
+						description: "The product that produced the events, written to the `Device Product` header field."
+						required:    true
+						type: string: examples: ["Vector"]
+					}
+					device_vendor: {
+						description: """
+							The vendor of the device that produced the events, written to the `Device Vendor` header
+							field.
+							"""
+						required: true
+						type: string: examples: ["Datadog"]
+					}
+					device_version: {
+						description: """
+							The version of the product that produced the events, written to the `Device Version` header
+							field.
+							"""
+						required: true
+						type: string: examples: ["0.30.0"]
+					}
+					extensions: {
+						description: """
+							The extension fields to encode, as a map of CEF extension keys to the event fields their
+							values are read from.
+
+							If empty, all top-level fields of the event are encoded, except the header fields, and the
+							fields that are objects or arrays, or whose name isn't a valid extension key.
+
+							Fields that are missing from the event are skipped. Timestamps are encoded as milliseconds
+							since the Unix epoch.
+							"""
+						required: false
+						type: object: {
+							examples: [{
+								dst: "destination.ip"
+								msg: "message"
+								src: "source.ip"
+							}]
+							options: "*": {
+								description: "An event field to read the extension value from."
+								required:    true
+								type: string: {}
+							}
+						}
+					}
+					name_field: {
+						description: """
+							The field containing the human-readable description of the event, written to the `Name`
+							header field.
+							"""
+						required: false
+						type: string: {
+							default: "name"
+							examples: ["message"]
+						}
+					}
+					severity_field: {
+						description: """
+							The field containing the importance of the event, written to the `Severity` header field.
+
+							CEF severities are integers from 0 to 10, or one of `Unknown`, `Low`, `Medium`, `High`
+							and `Very-High`.
+							"""
+						required: false
+						type: string: {
+							default: "severity"
+							examples: ["level"]
+						}
+					}
 				}
 			}
 			codec: {
@@ -223,6 +527,13 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 
 						[apache_avro]: https://avro.apache.org/
 						"""
+					cef: """
+						Encodes an event as a [Common Event Format (CEF)][cef] message.
+
+						This codec must be configured with the device vendor, product, and version.
+
+						[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+						"""
 					csv: """
 						Encodes an event as a CSV message.
 
@@ -243,6 +554,15 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as a [MessagePack][msgpack] map.
+
+						Logs and traces are encoded as a map of their fields, with timestamps encoded with the
+						timestamp extension type of MessagePack. Metrics are encoded as a map with the same
+						structure as the one of the `json` codec.
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -259,6 +579,13 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 						[vector_native_json]: https://github.com/vectordotdev/vector/blob/master/lib/codecs/tests/data/native_encoding/schema.cue
 						[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
 						"""
+					protobuf: """
+						Encodes an event as a [Protobuf][protobuf] message of a user-provided message type.
+
+						The fields of the event are mapped to the fields of the message type by name.
+
+						[protobuf]: https://protobuf.dev/
+						"""
 					raw_message: """
 						No encoding.
 
@@ -310,7 +637,7 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 					When set to `single`, only the last non-bare value of tags are displayed with the
 					metric.  When set to `full`, all metric tags are exposed as separate assignments.
 					"""
-				relevant_when: "codec = \"json\" or codec = \"text\""
+				relevant_when: "codec = \"json\" or codec = \"msgpack\" or codec = \"text\""
 				required:      false
 				type: string: {
 					default: "single"
@@ -329,6 +656,39 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 				required:    false
 				type: array: items: type: string: {}
 			}
+			protobuf: {
+				description:   "Protobuf-specific encoder options."
+				relevant_when: "codec = \"protobuf\""
+				required:      true
+				type: object: options: {
+					desc_file: {
+						description: """
+							The path to the Protobuf descriptor set file.
+
+							This file is the output of `protoc -I <include path> -o <desc output path> <proto>`, and
+							must contain the message type to encode events as, along with its dependencies.
+							"""
+						required: true
+						type: string: examples: ["/etc/vector/protobuf_descriptor_set.desc"]
+					}
+					message_type: {
+						description: "The full name of the message type to encode events as."
+						required:    true
+						type: string: examples: ["package.Message"]
+					}
+					unknown_fields: {
+						description: "How to handle the fields of events that have no matching field in the message type."
+						required:    false
+						type: string: {
+							default: "ignore"
+							enum: {
+								ignore: "The fields are left out of the encoded message."
+								reject: "Events with such fields fail to encode, and are dropped."
+							}
+						}
+					}
+				}
+			}
 			timestamp_format: {
 				description: "Format used for timestamp fields."
 				required:    false
@@ -340,9 +700,14 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 		}
 	}
 	endpoint: {
-		description: "Custom endpoint for use with AWS-compatible services."
-		required:    false
-		type: string: examples: ["http://127.0.0.0:5000/path/to/service"]
+		description: """
+			Custom endpoint for use with AWS-compatible services.
+
+			The `{region}` placeholder is replaced by the region of the target service, so that the same
+			endpoint template can be used across regions, such as for VPC endpoints.
+			"""
+		required: false
+		type: string: examples: ["http://127.0.0.0:5000/path/to/service", "https://bucket.vpce-1a2b3c4d-5e6f.s3.{region}.vpce.amazonaws.com"]
 	}
 	group_name: {
 		description: """
@@ -403,6 +768,46 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 						required: false
 						type: float: default: 0.4
 					}
+					max_concurrency_limit: {
+						description: """
+																The maximum concurrency limit.
+
+																ARC never raises the concurrency limit above this value, which caps the number of in-flight requests
+																regardless of how well the service responds. When `partitioned` is enabled, this applies to each partition.
+																"""
+						required: false
+						type: uint: default: 200
+					}
+					min_rtt_window_secs: {
+						description: """
+																The minimum amount of time, in seconds, over which RTTs are averaged before adjusting the limit.
+
+																By default, the RTTs are averaged over an interval equal to the past RTT average, which is short for services
+																responding quickly. Larger values smooth out short spikes in the RTT, which may otherwise decrease the limit,
+																at the cost of adjusting the limit more slowly.
+																"""
+						required: false
+						type: float: {
+							default: 0.0
+							unit:    "seconds"
+						}
+					}
+					partitioned: {
+						description: """
+																Whether or not to manage a separate concurrency limit for each partition of the requests.
+
+																By default, a single limit applies to all of the requests of the sink, so one slow backend can throttle the
+																whole sink. When enabled, requests are grouped by their partition, such as the token and index of the
+																`splunk_hec_logs` sink, and each group adapts its own limit. Sinks that don't partition their requests ignore
+																this option, and sinks distributing requests across several endpoints, such as `elasticsearch`, always keep a
+																separate limit for each endpoint.
+
+																The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the
+																partition receives requests again.
+																"""
+						required: false
+						type: bool: default: false
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -419,6 +824,17 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 					}
 				}
 			}
+			bandwidth_group: {
+				description: """
+					The name of a group of sinks sharing a single `max_bytes_per_second` limit.
+
+					All the sinks of a group draw from the same budget, which lets a set of sinks be capped
+					as a whole, for example when they all egress over the same link. The group uses the
+					`max_bytes_per_second` of the first of its sinks to be built.
+					"""
+				required: false
+				type: string: examples: ["wan_backups"]
+			}
 			concurrency: {
 				description: "Configuration for outbound request concurrency."
 				required:    false
@@ -456,6 +872,17 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 					}
 				}
 			}
+			max_bytes_per_second: {
+				description: """
+					The maximum number of bytes per second the sink is allowed to send.
+
+					Requests are delayed, not dropped, to stay under the limit. The limit is measured on the
+					encoded requests, before any compression applied by the transport. Sinks that don't
+					measure the size of their requests reject this option.
+					"""
+				required: false
+				type: uint: unit: "bytes"
+			}
 			rate_limit_duration_secs: {
 				description: "The time window used for the `rate_limit_num` option."
 				required:    false
@@ -620,4 +1047,28 @@ base: components: sinks: aws_cloudwatch_logs: configuration: {
 			}
 		}
 	}
+	use_dual_stack: {
+		description: """
+			Whether to use the [dual-stack endpoint][dual_stack] of the target service.
+
+			Dual-stack endpoints are reachable over both IPv4 and IPv6, as required in IPv6-only
+			environments. Ignored if `endpoint` is set.
+
+			[dual_stack]: https://docs.aws.amazon.com/general/latest/gr/rande.html#dual-stack-endpoints
+			"""
+		required: false
+		type: bool: default: false
+	}
+	use_fips: {
+		description: """
+			Whether to use the [FIPS endpoint][fips] of the target service.
+
+			FIPS endpoints use cryptographic modules validated under FIPS 140-2, as required for
+			some government workloads. Ignored if `endpoint` is set.
+
+			[fips]: https://aws.amazon.com/compliance/fips/
+			"""
+		required: false
+		type: bool: default: false
+	}
 }
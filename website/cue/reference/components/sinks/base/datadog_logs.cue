@@ -36,7 +36,8 @@ base: components: sinks: datadog_logs: configuration: {
 					The maximum size of a batch that is processed by a sink.
 
 					This is based on the uncompressed size of the batched events, before they are
-					serialized/compressed.
+					serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+					`aws_cloudwatch_logs`, use the size of the encoded payload instead.
 					"""
 				required: false
 				type: uint: {
@@ -141,6 +142,70 @@ base: components: sinks: datadog_logs: configuration: {
 			us: "US region."
 		}
 	}
+	reserved_attributes: {
+		description: """
+			Templates for the Datadog [reserved attributes][reserved_attributes] of each log.
+
+			Attributes are rendered from each event as it is sent, and take precedence over any value the
+			event already has. When a template fails to render, the event is sent with the attribute it
+			already has, if any.
+
+			[reserved_attributes]: https://docs.datadoghq.com/logs/log_configuration/attributes_naming_convention/#reserved-attributes
+			"""
+		required: false
+		type: object: options: {
+			ddsource: {
+				description: """
+					The technology that the log originated from, sent as the `ddsource` attribute.
+
+					Datadog uses this attribute to select the integration pipeline that processes the log.
+					"""
+				required: false
+				type: string: {
+					examples: ["nginx", "{{ source_type }}"]
+					syntax: "template"
+				}
+			}
+			ddtags: {
+				description: """
+					Tags to add to the `ddtags` attribute.
+
+					Each tag is rendered separately, and should be of the form `key:value`. Tags must start with
+					a letter, be at most 200 characters long, and only contain alphanumerics, underscores,
+					minuses, colons, periods, and slashes. Tags that don't render to a valid tag are dropped.
+
+					Tags already present in the `ddtags` field of the event are kept.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: {
+						examples: ["env:production", "team:{{ team }}"]
+						syntax: "template"
+					}
+				}
+			}
+			hostname: {
+				description: "The host that the log originated from, sent as the `hostname` attribute."
+				required:    false
+				type: string: {
+					examples: ["{{ kubernetes.pod_node_name }}"]
+					syntax: "template"
+				}
+			}
+			service: {
+				description: """
+					The name of the application or service that generated the log, sent as the `service`
+					attribute.
+					"""
+				required: false
+				type: string: {
+					examples: ["{{ kubernetes.pod_labels.app }}", "checkout"]
+					syntax: "template"
+				}
+			}
+		}
+	}
 	request: {
 		description: "Outbound HTTP request settings."
 		required:    false
@@ -179,6 +244,46 @@ base: components: sinks: datadog_logs: configuration: {
 						required: false
 						type: float: default: 0.4
 					}
+					max_concurrency_limit: {
+						description: """
+																The maximum concurrency limit.
+
+																ARC never raises the concurrency limit above this value, which caps the number of in-flight requests
+																regardless of how well the service responds. When `partitioned` is enabled, this applies to each partition.
+																"""
+						required: false
+						type: uint: default: 200
+					}
+					min_rtt_window_secs: {
+						description: """
+																The minimum amount of time, in seconds, over which RTTs are averaged before adjusting the limit.
+
+																By default, the RTTs are averaged over an interval equal to the past RTT average, which is short for services
+																responding quickly. Larger values smooth out short spikes in the RTT, which may otherwise decrease the limit,
+																at the cost of adjusting the limit more slowly.
+																"""
+						required: false
+						type: float: {
+							default: 0.0
+							unit:    "seconds"
+						}
+					}
+					partitioned: {
+						description: """
+																Whether or not to manage a separate concurrency limit for each partition of the requests.
+
+																By default, a single limit applies to all of the requests of the sink, so one slow backend can throttle the
+																whole sink. When enabled, requests are grouped by their partition, such as the token and index of the
+																`splunk_hec_logs` sink, and each group adapts its own limit. Sinks that don't partition their requests ignore
+																this option, and sinks distributing requests across several endpoints, such as `elasticsearch`, always keep a
+																separate limit for each endpoint.
+
+																The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the
+																partition receives requests again.
+																"""
+						required: false
+						type: bool: default: false
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -195,6 +300,17 @@ base: components: sinks: datadog_logs: configuration: {
 					}
 				}
 			}
+			bandwidth_group: {
+				description: """
+					The name of a group of sinks sharing a single `max_bytes_per_second` limit.
+
+					All the sinks of a group draw from the same budget, which lets a set of sinks be capped
+					as a whole, for example when they all egress over the same link. The group uses the
+					`max_bytes_per_second` of the first of its sinks to be built.
+					"""
+				required: false
+				type: string: examples: ["wan_backups"]
+			}
 			concurrency: {
 				description: "Configuration for outbound request concurrency."
 				required:    false
@@ -232,6 +348,17 @@ base: components: sinks: datadog_logs: configuration: {
 					}
 				}
 			}
+			max_bytes_per_second: {
+				description: """
+					The maximum number of bytes per second the sink is allowed to send.
+
+					Requests are delayed, not dropped, to stay under the limit. The limit is measured on the
+					encoded requests, before any compression applied by the transport. Sinks that don't
+					measure the size of their requests reject this option.
+					"""
+				required: false
+				type: uint: unit: "bytes"
+			}
 			rate_limit_duration_secs: {
 				description: "The time window used for the `rate_limit_num` option."
 				required:    false
@@ -36,7 +36,8 @@ base: components: sinks: datadog_metrics: configuration: {
 					The maximum size of a batch that is processed by a sink.
 
 					This is based on the uncompressed size of the batched events, before they are
-					serialized/compressed.
+					serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+					`aws_cloudwatch_logs`, use the size of the encoded payload instead.
 					"""
 				required: false
 				type: uint: unit: "bytes"
@@ -93,6 +94,45 @@ base: components: sinks: datadog_metrics: configuration: {
 		required: false
 		type: string: examples: ["http://127.0.0.1:8080", "http://example.com:12345"]
 	}
+	name_sanitization: {
+		description: """
+			Configuration for sanitizing metric names and tag keys.
+
+			Names include the namespace of metrics. Metrics whose name is empty once sanitized are dropped.
+			"""
+		required: false
+		type: object: options: {
+			replacement: {
+				description: """
+					The string which invalid characters are replaced with, when the strategy is `replace`.
+
+					It must only contain characters which are valid in the names and tag keys of the
+					destination.
+					"""
+				required: false
+				type: string: {
+					default: "_"
+					examples: ["_"]
+				}
+			}
+			strategy: {
+				description: "How metric names and tag keys which are invalid for the destination are handled."
+				required:    false
+				type: string: {
+					default: "none"
+					enum: {
+						drop:   "Metrics with an invalid name or tag key are dropped."
+						none:   "Metrics are sent as they are, leaving it to the destination to reject or rewrite them."
+						remove: "Invalid characters are removed, and names which are too long are truncated."
+						replace: """
+							Invalid characters are replaced with the `replacement`, and names which are too long are
+							truncated.
+							"""
+					}
+				}
+			}
+		}
+	}
 	region: {
 		deprecated:         true
 		deprecated_message: "This option has been deprecated, use the `site` option instead."
@@ -145,6 +185,46 @@ base: components: sinks: datadog_metrics: configuration: {
 						required: false
 						type: float: default: 0.4
 					}
+					max_concurrency_limit: {
+						description: """
+																The maximum concurrency limit.
+
+																ARC never raises the concurrency limit above this value, which caps the number of in-flight requests
+																regardless of how well the service responds. When `partitioned` is enabled, this applies to each partition.
+																"""
+						required: false
+						type: uint: default: 200
+					}
+					min_rtt_window_secs: {
+						description: """
+																The minimum amount of time, in seconds, over which RTTs are averaged before adjusting the limit.
+
+																By default, the RTTs are averaged over an interval equal to the past RTT average, which is short for services
+																responding quickly. Larger values smooth out short spikes in the RTT, which may otherwise decrease the limit,
+																at the cost of adjusting the limit more slowly.
+																"""
+						required: false
+						type: float: {
+							default: 0.0
+							unit:    "seconds"
+						}
+					}
+					partitioned: {
+						description: """
+																Whether or not to manage a separate concurrency limit for each partition of the requests.
+
+																By default, a single limit applies to all of the requests of the sink, so one slow backend can throttle the
+																whole sink. When enabled, requests are grouped by their partition, such as the token and index of the
+																`splunk_hec_logs` sink, and each group adapts its own limit. Sinks that don't partition their requests ignore
+																this option, and sinks distributing requests across several endpoints, such as `elasticsearch`, always keep a
+																separate limit for each endpoint.
+
+																The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the
+																partition receives requests again.
+																"""
+						required: false
+						type: bool: default: false
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -161,6 +241,17 @@ base: components: sinks: datadog_metrics: configuration: {
 					}
 				}
 			}
+			bandwidth_group: {
+				description: """
+					The name of a group of sinks sharing a single `max_bytes_per_second` limit.
+
+					All the sinks of a group draw from the same budget, which lets a set of sinks be capped
+					as a whole, for example when they all egress over the same link. The group uses the
+					`max_bytes_per_second` of the first of its sinks to be built.
+					"""
+				required: false
+				type: string: examples: ["wan_backups"]
+			}
 			concurrency: {
 				description: "Configuration for outbound request concurrency."
 				required:    false
@@ -183,6 +274,17 @@ base: components: sinks: datadog_metrics: configuration: {
 					uint: {}
 				}
 			}
+			max_bytes_per_second: {
+				description: """
+					The maximum number of bytes per second the sink is allowed to send.
+
+					Requests are delayed, not dropped, to stay under the limit. The limit is measured on the
+					encoded requests, before any compression applied by the transport. Sinks that don't
+					measure the size of their requests reject this option.
+					"""
+				required: false
+				type: uint: unit: "bytes"
+			}
 			rate_limit_duration_secs: {
 				description: "The time window used for the `rate_limit_num` option."
 				required:    false
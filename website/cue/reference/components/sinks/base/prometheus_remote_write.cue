@@ -53,6 +53,18 @@ base: components: sinks: prometheus_remote_write: configuration: {
 				required:      true
 				type: string: examples: ["/my/aws/credentials"]
 			}
+			external_id: {
+				description: """
+					The external ID to pass when assuming the role.
+
+					Required by roles whose trust policy checks an [external ID][external_id], as is common
+					for cross-account access granted to third parties.
+
+					[external_id]: https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html
+					"""
+				required: false
+				type: string: examples: ["randomEXAMPLEidString"]
+			}
 			imds: {
 				description:   "Configuration for authenticating with AWS through IMDS."
 				relevant_when: "strategy = \"aws\""
@@ -132,6 +144,17 @@ base: components: sinks: prometheus_remote_write: configuration: {
 				required:      true
 				type: string: examples: ["wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY"]
 			}
+			sts_endpoint: {
+				description: """
+					Custom endpoint to send STS requests to.
+
+					The `{region}` placeholder is replaced by the region STS requests are sent to, so that the
+					same endpoint template can be used across regions, such as for VPC endpoints. If not set,
+					the regional STS endpoint is used.
+					"""
+				required: false
+				type: string: examples: ["https://sts.{region}.amazonaws.com"]
+			}
 			strategy: {
 				description: "The authentication strategy to use."
 				required:    true
@@ -164,9 +187,14 @@ base: components: sinks: prometheus_remote_write: configuration: {
 		required:    false
 		type: object: options: {
 			endpoint: {
-				description: "Custom endpoint for use with AWS-compatible services."
-				required:    false
-				type: string: examples: ["http://127.0.0.0:5000/path/to/service"]
+				description: """
+					Custom endpoint for use with AWS-compatible services.
+
+					The `{region}` placeholder is replaced by the region of the target service, so that the same
+					endpoint template can be used across regions, such as for VPC endpoints.
+					"""
+				required: false
+				type: string: examples: ["http://127.0.0.0:5000/path/to/service", "https://bucket.vpce-1a2b3c4d-5e6f.s3.{region}.vpce.amazonaws.com"]
 			}
 			region: {
 				description: """
@@ -177,6 +205,30 @@ base: components: sinks: prometheus_remote_write: configuration: {
 				required: false
 				type: string: examples: ["us-east-1"]
 			}
+			use_dual_stack: {
+				description: """
+					Whether to use the [dual-stack endpoint][dual_stack] of the target service.
+
+					Dual-stack endpoints are reachable over both IPv4 and IPv6, as required in IPv6-only
+					environments. Ignored if `endpoint` is set.
+
+					[dual_stack]: https://docs.aws.amazon.com/general/latest/gr/rande.html#dual-stack-endpoints
+					"""
+				required: false
+				type: bool: default: false
+			}
+			use_fips: {
+				description: """
+					Whether to use the [FIPS endpoint][fips] of the target service.
+
+					FIPS endpoints use cryptographic modules validated under FIPS 140-2, as required for
+					some government workloads. Ignored if `endpoint` is set.
+
+					[fips]: https://aws.amazon.com/compliance/fips/
+					"""
+				required: false
+				type: bool: default: false
+			}
 		}
 	}
 	batch: {
@@ -188,7 +240,8 @@ base: components: sinks: prometheus_remote_write: configuration: {
 					The maximum size of a batch that is processed by a sink.
 
 					This is based on the uncompressed size of the batched events, before they are
-					serialized/compressed.
+					serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+					`aws_cloudwatch_logs`, use the size of the encoded payload instead.
 					"""
 				required: false
 				type: uint: unit: "bytes"
@@ -246,6 +299,45 @@ base: components: sinks: prometheus_remote_write: configuration: {
 		required: true
 		type: string: examples: ["https://localhost:8087/api/v1/write"]
 	}
+	name_sanitization: {
+		description: """
+			Configuration for sanitizing metric names and tag keys.
+
+			Names include the namespace of metrics. Metrics whose name is empty once sanitized are dropped.
+			"""
+		required: false
+		type: object: options: {
+			replacement: {
+				description: """
+					The string which invalid characters are replaced with, when the strategy is `replace`.
+
+					It must only contain characters which are valid in the names and tag keys of the
+					destination.
+					"""
+				required: false
+				type: string: {
+					default: "_"
+					examples: ["_"]
+				}
+			}
+			strategy: {
+				description: "How metric names and tag keys which are invalid for the destination are handled."
+				required:    false
+				type: string: {
+					default: "none"
+					enum: {
+						drop:   "Metrics with an invalid name or tag key are dropped."
+						none:   "Metrics are sent as they are, leaving it to the destination to reject or rewrite them."
+						remove: "Invalid characters are removed, and names which are too long are truncated."
+						replace: """
+							Invalid characters are replaced with the `replacement`, and names which are too long are
+							truncated.
+							"""
+					}
+				}
+			}
+		}
+	}
 	quantiles: {
 		description: """
 			Quantiles to use for aggregating [distribution][dist_metric_docs] metrics into a summary.
@@ -300,6 +392,46 @@ base: components: sinks: prometheus_remote_write: configuration: {
 						required: false
 						type: float: default: 0.4
 					}
+					max_concurrency_limit: {
+						description: """
+																The maximum concurrency limit.
+
+																ARC never raises the concurrency limit above this value, which caps the number of in-flight requests
+																regardless of how well the service responds. When `partitioned` is enabled, this applies to each partition.
+																"""
+						required: false
+						type: uint: default: 200
+					}
+					min_rtt_window_secs: {
+						description: """
+																The minimum amount of time, in seconds, over which RTTs are averaged before adjusting the limit.
+
+																By default, the RTTs are averaged over an interval equal to the past RTT average, which is short for services
+																responding quickly. Larger values smooth out short spikes in the RTT, which may otherwise decrease the limit,
+																at the cost of adjusting the limit more slowly.
+																"""
+						required: false
+						type: float: {
+							default: 0.0
+							unit:    "seconds"
+						}
+					}
+					partitioned: {
+						description: """
+																Whether or not to manage a separate concurrency limit for each partition of the requests.
+
+																By default, a single limit applies to all of the requests of the sink, so one slow backend can throttle the
+																whole sink. When enabled, requests are grouped by their partition, such as the token and index of the
+																`splunk_hec_logs` sink, and each group adapts its own limit. Sinks that don't partition their requests ignore
+																this option, and sinks distributing requests across several endpoints, such as `elasticsearch`, always keep a
+																separate limit for each endpoint.
+
+																The limit of a partition without any request for five minutes is dropped, and starts over from `1` once the
+																partition receives requests again.
+																"""
+						required: false
+						type: bool: default: false
+					}
 					rtt_deviation_scale: {
 						description: """
 																Scale of RTT deviations which are not considered anomalous.
@@ -316,6 +448,17 @@ base: components: sinks: prometheus_remote_write: configuration: {
 					}
 				}
 			}
+			bandwidth_group: {
+				description: """
+					The name of a group of sinks sharing a single `max_bytes_per_second` limit.
+
+					All the sinks of a group draw from the same budget, which lets a set of sinks be capped
+					as a whole, for example when they all egress over the same link. The group uses the
+					`max_bytes_per_second` of the first of its sinks to be built.
+					"""
+				required: false
+				type: string: examples: ["wan_backups"]
+			}
 			concurrency: {
 				description: "Configuration for outbound request concurrency."
 				required:    false
@@ -338,6 +481,17 @@ base: components: sinks: prometheus_remote_write: configuration: {
 					uint: {}
 				}
 			}
+			max_bytes_per_second: {
+				description: """
+					The maximum number of bytes per second the sink is allowed to send.
+
+					Requests are delayed, not dropped, to stay under the limit. The limit is measured on the
+					encoded requests, before any compression applied by the transport. Sinks that don't
+					measure the size of their requests reject this option.
+					"""
+				required: false
+				type: uint: unit: "bytes"
+			}
 			rate_limit_duration_secs: {
 				description: "The time window used for the `rate_limit_num` option."
 				required:    false
@@ -49,7 +49,8 @@ base: components: sinks: statsd: configuration: {
 					The maximum size of a batch that is processed by a sink.
 
 					This is based on the uncompressed size of the batched events, before they are
-					serialized/compressed.
+					serialized/compressed. Sinks that encode events before batching them, such as `loki` and
+					`aws_cloudwatch_logs`, use the size of the encoded payload instead.
 					"""
 				required: false
 				type: uint: {
@@ -104,6 +105,45 @@ base: components: sinks: statsd: configuration: {
 			unix: "Send over a Unix domain socket (UDS)."
 		}
 	}
+	name_sanitization: {
+		description: """
+			Configuration for sanitizing metric names and tag keys.
+
+			Names include the namespace of metrics. Metrics whose name is empty once sanitized are dropped.
+			"""
+		required: false
+		type: object: options: {
+			replacement: {
+				description: """
+					The string which invalid characters are replaced with, when the strategy is `replace`.
+
+					It must only contain characters which are valid in the names and tag keys of the
+					destination.
+					"""
+				required: false
+				type: string: {
+					default: "_"
+					examples: ["_"]
+				}
+			}
+			strategy: {
+				description: "How metric names and tag keys which are invalid for the destination are handled."
+				required:    false
+				type: string: {
+					default: "none"
+					enum: {
+						drop:   "Metrics with an invalid name or tag key are dropped."
+						none:   "Metrics are sent as they are, leaving it to the destination to reject or rewrite them."
+						remove: "Invalid characters are removed, and names which are too long are truncated."
+						replace: """
+							Invalid characters are replaced with the `replacement`, and names which are too long are
+							truncated.
+							"""
+					}
+				}
+			}
+		}
+	}
 	path: {
 		description: """
 			The Unix socket path.
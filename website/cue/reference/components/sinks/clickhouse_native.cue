@@ -0,0 +1,126 @@
+package metadata
+
+components: sinks: clickhouse_native: {
+	title: "ClickHouse (Native Protocol)"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["Yandex"]
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: {
+				enabled: true
+				default: "lz4"
+				algorithms: ["none", "lz4", "zstd"]
+				levels: ["default"]
+			}
+			encoding: {
+				enabled: true
+				codec: enabled: false
+			}
+			proxy: enabled: false
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: enabled: false
+			to: {
+				service: services.clickhouse
+
+				interface: {
+					socket: {
+						api: {
+							title: "ClickHouse Native Protocol"
+							url:   urls.clickhouse_native
+						}
+						direction: "outgoing"
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: [
+			"""
+				The table must exist before events are inserted, and its columns must use one of the
+				supported types: `Bool`, integers, floats, `Decimal` with a precision of up to 18,
+				`String`, `FixedString`, `Date`, `DateTime`, `DateTime64`, `UUID`, `Nullable` of these,
+				or `Array` of strings and numbers. Columns of other types, such as `LowCardinality` and
+				`Map`, must have a `DEFAULT` expression; otherwise the healthcheck fails, naming the
+				column.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.clickhouse_native.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		columnar_inserts: {
+			title: "Columnar inserts"
+			body: """
+				Rather than sending JSON rows over HTTP, this sink fetches the schema of the table with
+				`DESCRIBE TABLE` and converts each batch of events into a columnar block, which is sent
+				over ClickHouse's native TCP protocol. Top-level event fields are matched to columns by
+				name; columns that no event in the batch has a value for are left out of the insert, so
+				ClickHouse fills them in with their defaults.
+
+				Events with a field that can't be converted to the type of its column cause the whole
+				batch to be rejected. The schema is fetched again after any error returned by the
+				server, so changes to the table are picked up without restarting Vector.
+				"""
+		}
+
+		compression: {
+			title: "Compression"
+			body: """
+				Blocks are compressed with LZ4 by default. Setting `compression` to `zstd` compresses them
+				with ZSTD instead, which reduces the network traffic to ClickHouse at the cost of more CPU
+				time on both ends.
+				"""
+		}
+
+		async_inserts: {
+			title: "Asynchronous inserts"
+			body: """
+				Enabling `async_insert` lets ClickHouse buffer the data sent by many Vector instances
+				and write it to the table in larger parts. See [asynchronous inserts](\(urls.clickhouse_async_insert))
+				for details. By default Vector still waits for the buffered data to be written before
+				acknowledging events; set `wait_for_async_insert` to `false` to trade durability for
+				lower latency.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		events_out_total:                 components.sources.internal_metrics.output.metrics.events_out_total
+	}
+}
@@ -23,7 +23,7 @@ components: sinks: console: {
 				codec: {
 					enabled: true
 					framing: true
-					enum: ["json", "text"]
+					enum: ["json", "native", "text"]
 				}
 			}
 			request: enabled: false
@@ -56,6 +56,26 @@ components: sinks: console: {
 		traces: true
 	}
 
+	how_it_works: {
+		piping_between_processes: {
+			title: "Piping events between processes"
+			body: """
+				With `encoding.codec` set to `native`, events are written to the standard output in Vector's
+				native protobuf format, each preceded by its length, which is the default framing of that
+				codec. Unlike `json` or `text`, this keeps the full fidelity of the events, metrics and traces
+				included, along with their metadata.
+
+				The output can be read back by another Vector instance with a [`stdin`][stdin] or
+				[`exec`][exec] source using `decoding.codec = "native"`, for example in a
+				`vector --config a.toml | other-tool | vector --config b.toml` pipeline, as long as the
+				tools in between pass the bytes through unchanged.
+
+				[stdin]: /docs/reference/configuration/sources/stdin/
+				[exec]: /docs/reference/configuration/sources/exec/
+				"""
+		}
+	}
+
 	telemetry: metrics: {
 		processed_bytes_total:   components.sources.internal_metrics.output.metrics.processed_bytes_total
 		processed_events_total:  components.sources.internal_metrics.output.metrics.processed_events_total
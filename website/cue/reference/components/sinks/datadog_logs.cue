@@ -75,5 +75,20 @@ components: sinks: datadog_logs: {
 				If your event contains any of these fields they will be used as described by the [API reference](https://docs.datadoghq.com/api/latest/logs/#send-logs).
 				"""
 		}
+
+		reserved_attributes: {
+			title: "Reserved attributes"
+			body: """
+				The `service`, `ddsource`, `hostname`, and `ddtags` attributes can be set from templates
+				with the `reserved_attributes` option, instead of with a `remap` transform renaming
+				fields before the sink. Rendered values take precedence over fields already present on
+				the event, except for `ddtags`, to which the rendered tags are appended.
+
+				Tags are checked against Datadog's [tag syntax](https://docs.datadoghq.com/getting_started/tagging/#define-tags):
+				tags that don't depend on the event are checked when the sink starts, and any other tag
+				that renders to an invalid tag is dropped, with an error logged. Datadog silently rewrites
+				invalid tags, so rejecting them keeps tags from being lost to typos.
+				"""
+		}
 	}
 }
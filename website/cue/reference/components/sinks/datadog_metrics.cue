@@ -75,5 +75,6 @@ components: sinks: datadog_metrics: {
 	telemetry: metrics: {
 		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
 		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		metric_name_collisions_total:     components.sources.internal_metrics.output.metrics.metric_name_collisions_total
 	}
 }
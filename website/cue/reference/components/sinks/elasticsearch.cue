@@ -104,6 +104,18 @@ components: sinks: elasticsearch: {
 				"""
 		}
 
+		mapping_conflicts: {
+			title: "Mapping conflicts"
+			body:  """
+				Documents rejected because they don't match the mapping of their index, such as a field
+				that was first indexed as a number and later sent as a string, can't be indexed by
+				retrying them. Their events are rejected on their own, while the other documents of the
+				request are acknowledged as delivered, so that enabling the `dead_letter` output of the
+				sink sends only the conflicting events to it, once, even when the request is retried
+				because of other failures.
+				"""
+		}
+
 		distribution: {
 			title: "Distribution"
 			body: """
@@ -119,6 +131,33 @@ components: sinks: elasticsearch: {
 				"""
 		}
 
+		ingest_pipelines: {
+			title: "Ingest pipelines"
+			body:  """
+				Documents can be pre-processed by an Elasticsearch ingest pipeline, named by the `pipeline`
+				option. When the name is a template, it's rendered for each event, and set on the bulk
+				action of its document, so that the events of a single batch can go through different
+				pipelines. Events for which the template can't be rendered are dropped.
+				"""
+		}
+
+		opensearch_serverless: {
+			title: "OpenSearch Serverless"
+			body:  """
+				To send events to an [Amazon OpenSearch Serverless](\(urls.aws_opensearch_serverless)) collection,
+				set `opensearch_service_type` to `serverless` and use the `aws` authentication strategy. Requests are
+				signed with SigV4 for the `aoss` service.
+
+				OpenSearch Serverless doesn't expose the cluster APIs, so the API version isn't detected, and the
+				healthcheck is skipped. Ingest pipelines aren't supported, and time series collections don't accept
+				document IDs, so `id_key` should be left unset when writing to them.
+
+				The IAM principal used by Vector must be granted `aoss:WriteDocument`, and `aoss:CreateIndex` if
+				indices are created on write, by a data access policy on the collection. Requests that aren't
+				allowed by the data access policy are rejected with `403 Forbidden` and aren't retried.
+				"""
+		}
+
 		partial_failures: {
 			title: "Partial Failures"
 			body:  """
@@ -132,6 +171,16 @@ components: sinks: elasticsearch: {
 				"""
 		}
 
+		retries: {
+			title: "Retries"
+			body:  """
+				Requests that fail with a `429 Too Many Requests` response, a server error, or a
+				network error are retried with an exponential backoff. When Elasticsearch responds with a
+				`Retry-After` header, Vector waits at least as long as it asks before retrying, up to
+				`request.retry_max_duration_secs`.
+				"""
+		}
+
 		aws_authentication: components._aws.how_it_works.aws_authentication
 	}
 
@@ -0,0 +1,96 @@
+package metadata
+
+components: sinks: gcp_chronicle_udm: {
+	title: "GCP Chronicle UDM"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["GCP"]
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    1_000_000
+				timeout_secs: 15.0
+			}
+			compression: enabled: false
+			encoding: enabled: false
+			proxy: enabled: true
+			request: {
+				enabled:        true
+				rate_limit_num: 1000
+				headers:        false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        true
+				enabled_by_scheme:      true
+			}
+			to: {
+				service: services.gcp_chronicle
+
+				interface: {
+					socket: {
+						api: {
+							title: "GCP XML Interface"
+							url:   urls.gcp_xml_interface
+						}
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "required"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.gcp_chronicle_udm.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		udm_mapping: {
+			title: "UDM mapping"
+			body: """
+				Each event is mapped to a [UDM event](\(urls.gcp_chronicle_udm)) by the mapping of its
+				log type, rendered from the `log_type` option. The mapping sets the event type and the
+				product of the UDM event, copies the configured event fields to their UDM fields, and
+				records the log type in `metadata.log_type`. Events are then batched per log type.
+
+				Before being sent, the UDM events are checked for the fields required by their event
+				type, such as a `principal` and a `target` for `NETWORK_CONNECTION` events, along with
+				the `required_fields` of the mapping. Events missing a required field, and events of a
+				log type without a mapping, are dropped and rejected.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		events_discarded_total:           components.sources.internal_metrics.output.metrics.events_discarded_total
+		processing_errors_total:          components.sources.internal_metrics.output.metrics.processing_errors_total
+	}
+}
@@ -74,6 +74,8 @@ components: sinks: kafka: {
 		component_sent_bytes_total:          components.sources.internal_metrics.output.metrics.component_sent_bytes_total
 		events_discarded_total:              components.sources.internal_metrics.output.metrics.events_discarded_total
 		processing_errors_total:             components.sources.internal_metrics.output.metrics.processing_errors_total
+		kafka_delivery_latency_seconds:      components.sources.internal_metrics.output.metrics.kafka_delivery_latency_seconds
+		kafka_delivery_reports_total:        components.sources.internal_metrics.output.metrics.kafka_delivery_reports_total
 		kafka_queue_messages:                components.sources.internal_metrics.output.metrics.kafka_queue_messages
 		kafka_queue_messages_bytes:          components.sources.internal_metrics.output.metrics.kafka_queue_messages_bytes
 		kafka_requests_total:                components.sources.internal_metrics.output.metrics.kafka_requests_total
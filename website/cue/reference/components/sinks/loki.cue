@@ -156,6 +156,29 @@ components: sinks: loki: {
 				on the configured `compression`.
 				"""
 		}
+
+		structured_metadata: {
+			title: "Structured Metadata"
+			body: """
+				Loki 3.0 and newer can store [structured metadata](\(urls.loki_structured_metadata))
+				alongside each log line. Unlike labels, structured metadata doesn't define streams and
+				isn't indexed, so high-cardinality values such as trace or request IDs can be attached
+				to events without increasing the number of streams.
+
+				The `structured_metadata` option accepts the same templated keys and values, and the
+				same expansion of objects, as `labels`. It's sent in both the JSON and protobuf
+				encodings of push requests.
+				"""
+		}
+
+		tenants: {
+			title: "Tenants"
+			body: """
+				The `tenant_id` option is templateable. Events are batched separately for each
+				rendered tenant, and each batch is sent with its tenant in the `X-Scope-OrgID`
+				header, so a single sink can write to several tenants.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -0,0 +1,108 @@
+package metadata
+
+components: sinks: mqtt: {
+	title: "MQTT"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "stream"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: {
+					enabled: true
+					enum: ["json", "text"]
+				}
+			}
+			request: enabled: false
+			tls: {
+				enabled:                true
+				can_verify_certificate: false
+				can_verify_hostname:    false
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: services.mqtt
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.mqtt.configuration
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: false
+	}
+
+	how_it_works: {
+		topics: {
+			title: "Topics"
+			body: """
+				Each event is published as a single message, on the topic rendered from `topic` for
+				that event. Events whose topic can't be rendered, or renders to a topic containing
+				the `+` or `#` wildcards, are dropped.
+				"""
+		}
+		quality_of_service: {
+			title: "Quality of service"
+			body: """
+				Messages are published with the [quality of service](\(urls.mqtt_qos)) set by
+				`quality_of_service`, which also decides when events are acknowledged: as soon as
+				they are written to the connection for `at_most_once`, and once the broker
+				acknowledges the message otherwise. Unacknowledged messages are published again
+				after reconnecting to the broker.
+				"""
+		}
+		authentication: {
+			title: "Authentication"
+			body: """
+				The sink authenticates with the broker with `user` and `password` when they are set,
+				and with a client certificate when `tls.crt_file` and `tls.key_file` are set.
+
+				When TLS is enabled, the broker's certificate is verified against the certificate
+				authority set by `tls.ca_file`, which is required, rather than against the
+				certificate authorities of the system.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+	}
+}
@@ -0,0 +1,146 @@
+package metadata
+
+components: sinks: opentelemetry: {
+	title: "OpenTelemetry"
+
+	description: """
+		Exports logs, metrics, and traces to a receiver that speaks the OpenTelemetry
+		Protocol (OTLP), such as the OpenTelemetry Collector, over either gRPC or HTTP.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: []
+		stateful: false
+	}
+	features: {
+		acknowledgements: true
+		auto_generated:   true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: enabled:    false
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: services.opentelemetry
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			summary:      true
+			set:          true
+		}
+		traces: true
+	}
+
+	configuration: base.components.sinks.opentelemetry.configuration
+
+	how_it_works: {
+		protocols: {
+			title: "Protocols"
+			body: """
+				With the default `grpc` protocol, events are exported through the `LogsService`,
+				`MetricsService`, and `TraceService` collector services. With the `http` protocol,
+				binary protobuf requests are posted to the `/v1/logs`, `/v1/metrics`, and
+				`/v1/traces` paths of the configured endpoint.
+
+				Each batch holds a single signal, so logs, metrics, and traces are always exported
+				in separate requests.
+				"""
+		}
+
+		logs: {
+			title: "Logs"
+			body: """
+				Logs received by the `opentelemetry` source are exported with their original
+				resource, scope, attributes, trace context, and severity. For any other log, the
+				message becomes the body of the log record and the remaining fields become its
+				attributes. Logs without a message are exported with the whole event as the body.
+				"""
+		}
+
+		metrics: {
+			title: "Metrics"
+			body: """
+				Counters are exported as monotonic sums, and incremental gauges as non-monotonic
+				delta sums. Absolute gauges and sets are exported as gauges, with a set reporting the
+				number of distinct values. Aggregated histograms and distributions are exported as
+				histograms, and aggregated summaries and sketches as summaries. The metric
+				namespace is joined to the name with a `.`, and tags become attributes.
+				"""
+		}
+
+		traces: {
+			title: "Traces"
+			body: """
+				Traces are exported span by span. Spans produced by the `datadog_agent` source are
+				mapped to their OTLP equivalents, with the span `service` becoming the
+				`service.name` resource attribute.
+				"""
+		}
+
+		resource_attributes: {
+			title: "Resource attributes"
+			body: """
+				Records are grouped by resource within each request. Attributes configured with
+				`resource_attributes` are added to every resource, replacing any attribute of the
+				same name carried by an event.
+				"""
+		}
+
+		retries: {
+			title: "Retries"
+			body: """
+				Failures that the OTLP specification marks as retryable, such as the `UNAVAILABLE`
+				gRPC status or the HTTP `429` and `503` statuses, are retried. Any other failure
+				rejects the events in the request.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+	}
+}
@@ -100,11 +100,25 @@ components: sinks: prometheus_remote_write: {
 				values for each name, Vector will only send the last value specified.
 				"""
 		}
+
+		exemplars_and_metadata: {
+			title: "Exemplars and metadata"
+			body: """
+				The help text, the unit, and the exemplars that metrics were received with by the
+				[`prometheus_remote_write` source][prometheus_remote_write_source] are forwarded
+				along with them. The exemplars of aggregated histograms are attached to the first
+				bucket their value fits in. Without them, the help text of each metric family is
+				its name.
+
+				[prometheus_remote_write_source]: /docs/reference/configuration/sources/prometheus_remote_write/
+				"""
+		}
 	}
 
 	telemetry: metrics: {
 		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
 		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
 		processing_errors_total:          components.sources.internal_metrics.output.metrics.processing_errors_total
+		metric_name_collisions_total:     components.sources.internal_metrics.output.metrics.metric_name_collisions_total
 	}
 }
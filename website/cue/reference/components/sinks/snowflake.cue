@@ -0,0 +1,113 @@
+package metadata
+
+components: sinks: snowflake: {
+	title: "Snowflake"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: ["Snowflake"]
+		stateful: false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    4_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: enabled: false
+			}
+			proxy: enabled: true
+			request: {
+				enabled: true
+				headers: false
+			}
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        true
+				enabled_by_scheme:      true
+			}
+			to: {
+				service: services.snowflake
+
+				interface: {
+					socket: {
+						api: {
+							title: "Snowpipe Streaming REST API"
+							url:   urls.snowpipe_streaming
+						}
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "required"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: [
+			"""
+				The configured user must have a registered public key for
+				[key pair authentication](\(urls.snowflake_key_pair_auth)), and a default role that
+				is allowed to insert into the pipe.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.sinks.snowflake.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		channels: {
+			title: "Channels"
+			body: """
+				Rows are appended to a single streaming channel of the configured pipe, which is
+				opened when the first batch is sent. Each batch is sent as one append request of
+				newline delimited JSON rows, with the batch settings controlling how often rows are
+				flushed to Snowflake.
+
+				Snowflake only allows one writer per channel, so each Vector instance writing to the
+				same pipe must use a distinct `channel`, which defaults to one derived from the
+				hostname. Opening a channel invalidates any previous writer of it, and the sink
+				reopens its channel whenever an append fails, continuing from the last offset that
+				Snowflake committed.
+				"""
+		}
+		authentication: {
+			title: "Authentication"
+			body: """
+				The sink authenticates with a JWT signed by the private key of the user, which is
+				exchanged for a token scoped to the ingest host of the account. Tokens are renewed
+				well before they expire.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		events_out_total:                 components.sources.internal_metrics.output.metrics.events_out_total
+	}
+}
@@ -67,6 +67,18 @@ components: sinks: socket: {
 		traces:  false
 	}
 
+	how_it_works: {
+		named_pipes: {
+			title: "Windows named pipes"
+			body: """
+				On Windows, the `named_pipe` mode writes to a named pipe, such as `\\\\.\\pipe\\vector`,
+				with the same `path`, `encoding` and `framing` options as the `unix` mode. The pipe
+				must be created by the process reading from it. Vector reconnects with a backoff
+				whenever the pipe is unavailable or closed.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
 		connection_errors_total: components.sources.internal_metrics.output.metrics.connection_errors_total
 		processed_bytes_total:   components.sources.internal_metrics.output.metrics.processed_bytes_total
@@ -90,5 +90,20 @@ components: sinks: splunk_hec_metrics: {
 				If Splunk receives a tag with multiple values it will only take the last value specified,
 				so Vector only sends this last value.
 				"""
-		}}
+		}
+
+		multi_metric_events: {
+			title: "Multiple-metric Events"
+			body: """
+				By default, each metric is sent as its own HEC event. When `multi_metric` is enabled, the
+				metrics of a batch that share the same timestamp, host, index, source, sourcetype, and
+				tags are sent as a single event, with the value of each metric in a
+				`metric_name:<name>` field. A metric whose name is already present in the matching event
+				starts a new event.
+
+				Combined with indexer acknowledgements, the events of a batch are only acknowledged to
+				upstream sources once Splunk confirms that the whole payload was indexed.
+				"""
+		}
+	}
 }
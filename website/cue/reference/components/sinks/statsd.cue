@@ -53,5 +53,6 @@ components: sinks: statsd: {
 		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
 		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
 		processing_errors_total:          components.sources.internal_metrics.output.metrics.processing_errors_total
+		metric_name_collisions_total:     components.sources.internal_metrics.output.metrics.metric_name_collisions_total
 	}
 }
@@ -6,6 +6,8 @@ components: sources: [Name=string]: {
 	features: _
 
 	configuration: {
+		schedule: base.components.sources.configuration.schedule
+
 		if features.collect != _|_ {
 			if features.collect.proxy != _|_ {
 				if features.collect.proxy.enabled {
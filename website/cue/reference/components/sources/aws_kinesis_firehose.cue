@@ -184,6 +184,17 @@ components: sources: aws_kinesis_firehose: {
 				   forward the events to your delivery stream
 				"""
 		}
+
+		acknowledgements_and_retries: {
+			title: "Acknowledgements and retries"
+			body: """
+				When end-to-end acknowledgements are enabled, Vector only responds successfully to a
+				Firehose request once every record in it has been delivered. If some records fail, the
+				response reports how many failed and Firehose retries the request. Firehose reuses the
+				request ID on retries, so Vector skips the records it already delivered and only sends
+				the failed records downstream again.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
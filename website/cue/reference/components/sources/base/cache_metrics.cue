@@ -0,0 +1,48 @@
+package metadata
+
+base: components: sources: cache_metrics: configuration: {
+	endpoints: {
+		description: """
+			A list of Redis and memcached instances to poll.
+
+			Redis instances are given as `redis://`, `rediss://` or `redis+unix://` URLs, which may
+			include a password and a database. Memcached instances are given as `memcached://` URLs,
+			with the port defaulting to `11211`.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["redis://127.0.0.1:6379", "memcached://127.0.0.1:11211"]
+	}
+	namespace: {
+		description: """
+			Overrides the default namespace for the metrics emitted by the source.
+
+			If set to an empty string, no namespace is added to the metrics.
+
+			By default, `redis` is used for Redis instances, and `memcached` for memcached instances.
+			"""
+		required: false
+		type: string: examples: ["cache"]
+	}
+	scrape_interval_secs: {
+		description: """
+			The interval between polls.
+
+			Instances that don't reply within the interval are reported as down.
+			"""
+		required: false
+		type: uint: {
+			default: 15
+			unit:    "seconds"
+		}
+	}
+	slowlog_max_entries: {
+		description: """
+			The maximum number of entries to read from the slow log of Redis instances on each poll.
+
+			Only the entries logged since the previous poll are emitted. If set to `0`, the slow log
+			isn't read.
+			"""
+		required: false
+		type: uint: default: 128
+	}
+}
@@ -13,48 +13,283 @@ base: components: sources: demo_logs: configuration: {
 	decoding: {
 		description: "Configures how events are decoded from raw bytes."
 		required:    false
-		type: object: options: codec: {
-			description: "The codec to use for decoding events."
-			required:    false
-			type: string: {
-				default: "bytes"
-				enum: {
-					bytes: "Uses the raw bytes as-is."
-					gelf: """
-						Decodes the raw bytes as a [GELF][gelf] message.
-
-						[gelf]: https://docs.graylog.org/docs/gelf
-						"""
-					json: """
-						Decodes the raw bytes as [JSON][json].
+		type: object: options: {
+			avro: {
+				description:   "Apache Avro-specific decoder options."
+				relevant_when: "codec = \"avro\""
+				required:      true
+				type: object: options: {
+					schema: {
+						description: """
+							The Avro schema of the messages.
 
-						[json]: https://www.json.org/
-						"""
-					native: """
-						Decodes the raw bytes as Vector’s [native Protocol Buffers format][vector_native_protobuf].
+							Required, unless a `schema_registry` is set, in which case the messages are decoded with
+							the schema they were encoded with, and then resolved to this schema if it's set.
+							"""
+						required: false
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					schema_registry: {
+						description: """
+							The schema registry that the schemas of the messages are fetched from.
+
+							If set, messages are expected in the Confluent wire format, prefixed by the id of their
+							schema in the registry. Schemas are fetched once, and cached.
+							"""
+						required: false
+						type: object: options: {
+							password: {
+								description: "The password for basic authentication to the schema registry."
+								required:    false
+								type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+							}
+							proxy: {
+								description: """
+									The proxy used to reach the schema registry.
 
-						This codec is **[experimental][experimental]**.
+									Proxies set in the environment are used unless overridden here.
+									"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+											Proxy endpoint to use when proxying HTTP traffic.
 
-						[vector_native_protobuf]: https://github.com/vectordotdev/vector/blob/master/lib/vector-core/proto/event.proto
-						[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
-						"""
-					native_json: """
-						Decodes the raw bytes as Vector’s [native JSON format][vector_native_json].
+											Must be a valid URI string.
+											"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+											Proxy endpoint to use when proxying HTTPS traffic.
 
-						This codec is **[experimental][experimental]**.
+											Must be a valid URI string.
+											"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+											A list of hosts to avoid proxying.
 
-						[vector_native_json]: https://github.com/vectordotdev/vector/blob/master/lib/codecs/tests/data/native_encoding/schema.cue
-						[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
-						"""
-					syslog: """
-						Decodes the raw bytes as a Syslog message.
+											Multiple patterns are allowed:
 
-						Decodes either as the [RFC 3164][rfc3164]-style format ("old" style) or the
-						[RFC 5424][rfc5424]-style format ("new" style, includes structured data).
+											| Pattern             | Example match                                                               |
+											| ------------------- | --------------------------------------------------------------------------- |
+											| Domain names        | `example.com` matches requests to `example.com`                     |
+											| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+											| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+											| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+											| Splat               | `*` matches all hosts                                                   |
 
-						[rfc3164]: https://www.ietf.org/rfc/rfc3164.txt
-						[rfc5424]: https://www.ietf.org/rfc/rfc5424.txt
-						"""
+											[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+											"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							subject_name_strategy: {
+								description: "The strategy naming the subject that the schema is registered under, when encoding."
+								required:    false
+								type: string: {
+									default: "topic_name"
+									enum: {
+										record_name: "The subject is named after the full name of the record of the schema."
+										topic_name:  "The subject is named after the topic, as `<topic>-value`."
+										topic_record_name: """
+											The subject is named after both the topic and the full name of the record of the schema,
+											as `<topic>-<record>`.
+											"""
+									}
+								}
+							}
+							tls: {
+								description: "TLS configuration."
+								required:    false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+											Sets the list of supported ALPN protocols.
+
+											Declare the supported ALPN protocols, which are used during negotiation with peer. They are prioritized in the order
+											that they are defined.
+											"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+											Absolute path to an additional CA certificate file.
+
+											The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+											"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+											Absolute path to a certificate file used to identify this server.
+
+											The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+											an inline string in PEM format.
+
+											If this is set, and is not a PKCS#12 archive, `key_file` must also be set.
+											"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+											Absolute path to a private key file used to identify this server.
+
+											The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+											"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+											Passphrase used to unlock the encrypted key file.
+
+											This has no effect unless `key_file` is set.
+											"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									verify_certificate: {
+										description: """
+											Enables certificate verification.
+
+											If enabled, certificates must not be expired and must be issued by a trusted
+											issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+											certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+											so on until the verification process reaches a root certificate.
+
+											Relevant for both incoming and outgoing connections.
+
+											Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+											"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+											Enables hostname verification.
+
+											If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+											the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+											Only relevant for outgoing connections.
+
+											Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+											"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							topic: {
+								description: """
+									The topic that the subject is named after, when encoding.
+
+									Required by the `topic_name` and `topic_record_name` strategies.
+									"""
+								required: false
+								type: string: examples: ["logs"]
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+							username: {
+								description: "The username for basic authentication to the schema registry."
+								required:    false
+								type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+							}
+						}
+					}
+				}
+			}
+			codec: {
+				description: "The codec to use for decoding events."
+				required:    false
+				type: string: {
+					default: "bytes"
+					enum: {
+						avro: """
+							Decodes the raw bytes as an [Apache Avro][apache_avro] message.
+
+							Messages are either encoded with the configured schema, or in the Confluent wire format
+							with a schema of the configured schema registry.
+
+							[apache_avro]: https://avro.apache.org/
+							"""
+						bytes: "Uses the raw bytes as-is."
+						cef: """
+							Decodes the raw bytes as a [Common Event Format (CEF)][cef] message.
+
+							The header fields are decoded to the `cefVersion`, `deviceVendor`, `deviceProduct`,
+							`deviceVersion`, `deviceEventClassId`, `name`, and `severity` fields, and the extension
+							fields to fields of the same name. Anything before the `CEF:` prefix, such as a syslog
+							header, is ignored.
+
+							[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.4/pdfdoc/cef-implementation-standard/cef-implementation-standard.pdf
+							"""
+						gelf: """
+							Decodes the raw bytes as a [GELF][gelf] message.
+
+							[gelf]: https://docs.graylog.org/docs/gelf
+							"""
+						json: """
+							Decodes the raw bytes as [JSON][json].
+
+							[json]: https://www.json.org/
+							"""
+						msgpack: """
+							Decodes the raw bytes as a [MessagePack][msgpack] map.
+
+							Like the `json` codec, an array of maps is decoded as multiple events. Values of the
+							timestamp extension type of MessagePack are decoded as timestamps.
+
+							[msgpack]: https://msgpack.org/
+							"""
+						native: """
+							Decodes the raw bytes as Vector’s [native Protocol Buffers format][vector_native_protobuf].
+
+							This codec is **[experimental][experimental]**.
+
+							[vector_native_protobuf]: https://github.com/vectordotdev/vector/blob/master/lib/vector-core/proto/event.proto
+							[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
+							"""
+						native_json: """
+							Decodes the raw bytes as Vector’s [native JSON format][vector_native_json].
+
+							This codec is **[experimental][experimental]**.
+
+							[vector_native_json]: https://github.com/vectordotdev/vector/blob/master/lib/codecs/tests/data/native_encoding/schema.cue
+							[experimental]: https://vector.dev/highlights/2022-03-31-native-event-codecs
+							"""
+						syslog: """
+							Decodes the raw bytes as a Syslog message.
+
+							Decodes either as the [RFC 3164][rfc3164]-style format ("old" style) or the
+							[RFC 5424][rfc5424]-style format ("new" style, includes structured data).
+
+							[rfc3164]: https://www.ietf.org/rfc/rfc3164.txt
+							[rfc5424]: https://www.ietf.org/rfc/rfc5424.txt
+							"""
+					}
 				}
 			}
 		}
@@ -139,6 +374,10 @@ base: components: sources: demo_logs: configuration: {
 						bytes:               "Byte frames are passed through as-is according to the underlying I/O boundaries (for example, split between messages or stream segments)."
 						character_delimited: "Byte frames which are delimited by a chosen character."
 						length_delimited:    "Byte frames which are prefixed by an unsigned big-endian 32-bit integer indicating the length."
+						multiline: """
+							Byte frames which are delimited by a newline character, with consecutive lines aggregated
+							into a single frame according to patterns, such as the lines of a stack trace.
+							"""
 						newline_delimited:   "Byte frames which are delimited by a newline character."
 						octet_counting: """
 															Byte frames according to the [octet counting][octet_counting] format.
@@ -148,6 +387,91 @@ base: components: sources: demo_logs: configuration: {
 					}
 				}
 			}
+			multiline: {
+				description: """
+					Options for the multiline decoder.
+
+					Sources that receive discrete messages, such as `kafka` or `http_server`, frame each
+					message on its own, so lines are only aggregated within the same message.
+					"""
+				relevant_when: "method = \"multiline\""
+				required:      true
+				type: object: options: {
+					condition_pattern: {
+						description: """
+							Regular expression pattern that is used to determine whether or not more lines should be read.
+
+							This setting must be configured in conjunction with `mode`.
+							"""
+						required: true
+						type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
+					}
+					max_length: {
+						description: """
+							The maximum length of a line, before aggregation.
+
+							This length does *not* include the trailing newline.
+
+							By default, there is no maximum length enforced. Lines longer than this are discarded.
+							"""
+						required: false
+						type: uint: {}
+					}
+					mode: {
+						description: """
+							Aggregation mode.
+
+							This setting must be configured in conjunction with `condition_pattern`.
+							"""
+						required: true
+						type: string: enum: {
+							continue_past: """
+								All consecutive lines matching this pattern, plus one additional line, are included in the group.
+
+								This is useful in cases where a log message ends with a continuation marker, such as a backslash, indicating
+								that the following line is part of the same message.
+								"""
+							continue_through: """
+								All consecutive lines matching this pattern are included in the group.
+
+								The first line (the line that matched the start pattern) does not need to match the `ContinueThrough` pattern.
+
+								This is useful in cases such as a Java stack trace, where some indicator in the line (such as a leading
+								whitespace) indicates that it is an extension of the proceeding line.
+								"""
+							halt_before: """
+								All consecutive lines not matching this pattern are included in the group.
+
+								This is useful where a log line contains a marker indicating that it begins a new message.
+								"""
+							halt_with: """
+								All consecutive lines, up to and including the first line matching this pattern, are included in the group.
+
+								This is useful where a log line ends with a termination marker, such as a semicolon.
+								"""
+						}
+					}
+					start_pattern: {
+						description: "Regular expression pattern that is used to match the start of a new message."
+						required:    true
+						type: string: examples: ["^[\\s]+", "\\\\$", "^(INFO|ERROR) ", ";$"]
+					}
+					timeout_ms: {
+						description: """
+							The maximum amount of time to wait for the next additional line, in milliseconds.
+
+							As framing happens as data is read, the timeout is checked when more data is received, and
+							the buffered message is flushed as soon as the input ends, even if incomplete.
+							"""
+						required: false
+						type: uint: {
+							default: 1000
+							examples: [1000, 600000]
+							unit: "milliseconds"
+						}
+					}
+				}
+			}
 			newline_delimited: {
 				description:   "Options for the newline delimited decoder."
 				relevant_when: "method = \"newline_delimited\""
@@ -1,6 +1,17 @@
 package metadata
 
 base: components: sources: file: configuration: {
+	archive_dir: {
+		description: """
+			The directory that files are moved to, instead of being deleted, when `remove_after_secs` is set.
+
+			Archived files keep their name, suffixed with a number if a file of the same name was already
+			archived. The directory is created if it doesn't exist, and must not be matched by the
+			`include` patterns, otherwise archived files are read again.
+			"""
+		required: false
+		type: string: examples: ["/var/log/archive"]
+	}
 	acknowledgements: {
 		deprecated: true
 		description: """
@@ -338,6 +349,10 @@ base: components: sources: file: configuration: {
 		description: """
 			Timeout from reaching `EOF` after which the file is removed from the filesystem, unless new data is written in the meantime.
 
+			Files are only removed once all the data read from them has been checkpointed. When
+			acknowledgements are enabled, this is once all the events read from them have been
+			acknowledged.
+
 			If not specified, files are not removed.
 			"""
 		required: false
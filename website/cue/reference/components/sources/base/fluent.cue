@@ -60,6 +60,27 @@ base: components: sources: fluent: configuration: {
 			unit: "bytes"
 		}
 	}
+	security: {
+		description: "Shared key authentication of the clients."
+		required:    false
+		type: object: options: {
+			self_hostname: {
+				description: "The hostname the source identifies itself with during the handshake."
+				required:    true
+				type: string: examples: ["vector.example.com"]
+			}
+			shared_key: {
+				description: """
+					The key shared with the clients.
+
+					Clients must prove that they know the key, with the handshake of the Forward protocol,
+					before sending events.
+					"""
+				required: true
+				type: string: examples: ["${FLUENT_SHARED_KEY}"]
+			}
+		}
+	}
 	tls: {
 		description: "TlsEnableableConfig for `sources`, adding metadata from the client certificate."
 		required:    false
@@ -26,6 +26,9 @@ base: components: sources: journald: configuration: {
 		description: """
 			The systemd journal is read in batches, and a checkpoint is set at the end of each batch.
 
+			When acknowledgements are enabled, the checkpoint is only set once all the events of the
+			batch have been delivered.
+
 			This option limits the size of the batch.
 			"""
 		required: false
@@ -69,6 +72,19 @@ base: components: sources: journald: configuration: {
 			}
 		}
 	}
+	exclude_fields: {
+		description: """
+			A list of journal fields to remove from log events.
+
+			The `MESSAGE`, `_HOSTNAME`, `_SOURCE_REALTIME_TIMESTAMP`, and `__REALTIME_TIMESTAMP`
+			fields are always kept, as they are used to build the log event.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["_CAP_EFFECTIVE", "_SYSTEMD_INVOCATION_ID"]
+		}
+	}
 	exclude_units: {
 		description: """
 			A list of unit names to exclude from monitoring.
@@ -82,6 +98,23 @@ base: components: sources: journald: configuration: {
 			items: type: string: examples: ["badservice", "sysinit.target"]
 		}
 	}
+	include_fields: {
+		description: """
+			A list of journal fields to keep in log events.
+
+			If empty or not present, all fields are kept. The `MESSAGE`, `_HOSTNAME`,
+			`_SOURCE_REALTIME_TIMESTAMP`, and `__REALTIME_TIMESTAMP` fields are always kept, as they
+			are used to build the log event.
+
+			Fields are filtered after `include_matches` and `exclude_matches` are applied, so entries
+			can still be matched on fields that are not kept.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["_SYSTEMD_UNIT", "PRIORITY"]
+		}
+	}
 	include_matches: {
 		description: """
 			A list of sets of field/value pairs to monitor.
@@ -6,7 +6,9 @@ base: components: sources: kubernetes_logs: configuration: {
 			Whether or not to automatically merge partial events.
 
 			Partial events are messages that were split by the Kubernetes Container Runtime
-			log driver.
+			log driver. They are merged per container output stream, including across the rotations
+			of the log files, and the lines read twice while a log file is being rotated are
+			discarded.
 			"""
 		required: false
 		type: bool: default: true
@@ -0,0 +1,27 @@
+package metadata
+
+base: components: sources: linux_audit: configuration: {
+	reassembly_timeout_secs: {
+		description: """
+			The time to wait for the last record of an event.
+
+			Once elapsed, the records of the event received so far are emitted as the event, as some
+			kernel events aren't terminated by an `EOE` record.
+			"""
+		required: false
+		type: uint: {
+			default: 2
+			unit:    "seconds"
+		}
+	}
+	socket_path: {
+		description: """
+			The path of the socket of the `af_unix` plugin of `audisp`, to read the records from.
+
+			The plugin must be configured with the `string` format. If not set, the records are read
+			from the audit netlink socket of the kernel, which requires the `CAP_AUDIT_READ` capability.
+			"""
+		required: false
+		type: string: examples: ["/var/run/audispd_events"]
+	}
+}
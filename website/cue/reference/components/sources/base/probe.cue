@@ -0,0 +1,165 @@
+package metadata
+
+base: components: sources: probe: configuration: {
+	interval_secs: {
+		description: "The interval between probes."
+		required:    false
+		type: uint: {
+			default: 15
+			unit:    "seconds"
+		}
+	}
+	namespace: {
+		description: """
+			The namespace of the metrics.
+
+			Disabled if empty.
+			"""
+		required: false
+		type: string: default: "probe"
+	}
+	targets: {
+		description: "The targets to probe."
+		required:    true
+		type: array: items: type: object: options: {
+			address: {
+				description:   "The address to connect to, as `<host>:<port>`."
+				relevant_when: "type = \"tcp\""
+				required:      true
+				type: string: examples: ["example.com:443"]
+			}
+			expected_status_codes: {
+				description: """
+					The status codes of successful responses.
+
+					If empty, any `2xx` status code is successful.
+					"""
+				relevant_when: "type = \"http\""
+				required:      false
+				type: array: {
+					default: []
+					items: type: uint: examples: [200]
+				}
+			}
+			host: {
+				description:   "The hostname or IP address of the host."
+				relevant_when: "type = \"icmp\""
+				required:      true
+				type: string: examples: ["example.com", "10.0.0.1"]
+			}
+			type: {
+				description: "The type of probe."
+				required:    true
+				type: string: enum: {
+					http: "Send a `GET` request to the URL."
+					icmp: """
+						Send an ICMP echo request to the host, and wait for the reply.
+
+						The echo request is sent from an unprivileged ICMP socket. On Linux, the group Vector runs
+						as must be allowed to open these with the `net.ipv4.ping_group_range` sysctl.
+						"""
+					tcp: "Open a TCP connection to the address."
+				}
+			}
+			url: {
+				description:   "The URL to request."
+				relevant_when: "type = \"http\""
+				required:      true
+				type: string: examples: ["https://example.com/health"]
+			}
+		}
+	}
+	timeout_secs: {
+		description: "The time to wait for a probe to complete before considering it failed."
+		required:    false
+		type: uint: {
+			default: 5
+			unit:    "seconds"
+		}
+	}
+	tls: {
+		description: "TLS configuration."
+		required:    false
+		type: object: options: {
+			alpn_protocols: {
+				description: """
+					Sets the list of supported ALPN protocols.
+
+					Declare the supported ALPN protocols, which are used during negotiation with peer. They are prioritized in the order
+					that they are defined.
+					"""
+				required: false
+				type: array: items: type: string: examples: ["h2"]
+			}
+			ca_file: {
+				description: """
+					Absolute path to an additional CA certificate file.
+
+					The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/certificate_authority.crt"]
+			}
+			crt_file: {
+				description: """
+					Absolute path to a certificate file used to identify this server.
+
+					The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+					an inline string in PEM format.
+
+					If this is set, and is not a PKCS#12 archive, `key_file` must also be set.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.crt"]
+			}
+			key_file: {
+				description: """
+					Absolute path to a private key file used to identify this server.
+
+					The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.key"]
+			}
+			key_pass: {
+				description: """
+					Passphrase used to unlock the encrypted key file.
+
+					This has no effect unless `key_file` is set.
+					"""
+				required: false
+				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+			}
+			verify_certificate: {
+				description: """
+					Enables certificate verification.
+
+					If enabled, certificates must not be expired and must be issued by a trusted
+					issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+					certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+					so on until the verification process reaches a root certificate.
+
+					Relevant for both incoming and outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+					"""
+				required: false
+				type: bool: {}
+			}
+			verify_hostname: {
+				description: """
+					Enables hostname verification.
+
+					If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+					the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+					Only relevant for outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+					"""
+				required: false
+				type: bool: {}
+			}
+		}
+	}
+}
@@ -0,0 +1,258 @@
+package metadata
+
+components: sources: cache_metrics: {
+	title:       "Cache Metrics"
+	description: "The cache metrics source polls the statistics of Redis and memcached instances, and the slow log of Redis, so that the telemetry of a fleet of caches can be collected without running an exporter next to each instance."
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["daemon", "sidecar"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: {
+				service: {
+					name:     "Redis and memcached"
+					thing:    "Redis and memcached instances"
+					url:      urls.redis
+					versions: null
+				}
+
+				interface: {
+					socket: {
+						api: {
+							title: "Redis INFO command"
+							url:   urls.redis_info
+						}
+						direction: "outgoing"
+						protocols: ["tcp"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.cache_metrics.configuration
+
+	how_it_works: {
+		polling: {
+			title: "Polling"
+			body: """
+				Each `scrape_interval_secs`, the source queries every instance in `endpoints`: Redis
+				instances with the [`INFO`](\(urls.redis_info)) and [`SLOWLOG GET`](\(urls.redis_slowlog))
+				commands, and memcached instances with the [`stats`](\(urls.memcached_stats)) command.
+				Instances that fail to reply within the interval are reported with an `up` metric of
+				`0`.
+
+				Every numeric field of the replies is emitted as a metric named after the field, such
+				as `connected_clients` or `total_commands_processed` for Redis, and `curr_connections`
+				or `cmd_get` for memcached. Fields that only ever increase are emitted as counters, and
+				the others as gauges. The keyspace section of Redis is emitted as the `db_keys`,
+				`db_expires` and `db_avg_ttl_seconds` metrics, tagged with the database.
+				"""
+		}
+		slow_log: {
+			title: "Slow log"
+			body: """
+				The entries of the slow log of Redis are emitted as log events on the `slowlog`
+				output, up to `slowlog_max_entries` per poll. Only the entries logged since the
+				previous poll are emitted; the entries already in the slow log when Vector starts are
+				emitted on the first poll.
+				"""
+		}
+		credentials: {
+			title: "Credentials"
+			body: """
+				The password of Redis endpoints is masked in the `endpoint` tags and fields emitted by
+				the source.
+				"""
+		}
+	}
+
+	outputs: [
+		{
+			name: components._default_output.name
+			description: """
+				The metrics of the instances. Use this component's ID as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "slowlog"
+			description: """
+				The entries of the slow log of Redis instances. Use `<component_id>.slowlog` as an input to downstream transforms and sinks.
+				"""
+		},
+	]
+
+	output: {
+		logs: slowlog: {
+			description: "An entry of the slow log of a Redis instance."
+			fields: {
+				message: {
+					description: "The arguments of the command, separated by spaces."
+					required:    true
+					type: string: {
+						examples: ["KEYS *"]
+					}
+				}
+				id: {
+					description: "The ID of the entry, unique until the instance restarts."
+					required:    true
+					type: uint: {
+						examples: [42]
+						unit: null
+					}
+				}
+				duration_microseconds: {
+					description: "The time taken to execute the command."
+					required:    true
+					type: uint: {
+						examples: [12000]
+						unit: "microseconds"
+					}
+				}
+				command: {
+					description: "The arguments of the command."
+					required:    true
+					type: array: items: type: string: examples: ["KEYS", "*"]
+				}
+				client_address: {
+					description: "The address of the client that sent the command. Requires Redis 4.0 or later."
+					required:    false
+					type: string: {
+						examples: ["10.0.0.12:52340"]
+					}
+				}
+				client_name: {
+					description: "The name of the client that sent the command, if it set one."
+					required:    false
+					type: string: {
+						examples: ["worker-1"]
+					}
+				}
+				endpoint: {
+					description: "The endpoint of the instance, with its password masked."
+					required:    true
+					type: string: {
+						examples: ["redis://127.0.0.1:6379"]
+					}
+				}
+				host: {
+					description: "The host and port of the instance."
+					required:    true
+					type: string: {
+						examples: ["127.0.0.1:6379"]
+					}
+				}
+				source_type: {
+					description: "The name of the source type."
+					required:    true
+					type: string: {
+						examples: ["cache_metrics"]
+					}
+				}
+				timestamp: fields._current_timestamp & {
+					description: "The time at which the command was executed."
+				}
+			}
+		}
+
+		metrics: {
+			_cache_metrics_tags: {
+				endpoint: {
+					description: "The endpoint of the instance, with its password masked."
+					required:    true
+					examples: ["redis://127.0.0.1:6379", "memcached://127.0.0.1:11211"]
+				}
+				host: {
+					description: "The host and port of the instance."
+					required:    true
+					examples: ["127.0.0.1:6379"]
+				}
+			}
+
+			up: {
+				description:       "If the instance replied to the last poll or not."
+				type:              "gauge"
+				default_namespace: "redis"
+				tags:              _cache_metrics_tags
+			}
+			connected_clients: {
+				description:       "The number of clients connected to the Redis instance. Every other numeric field of `INFO` is emitted the same way."
+				type:              "gauge"
+				default_namespace: "redis"
+				tags:              _cache_metrics_tags
+			}
+			total_commands_processed: {
+				description:       "The total number of commands processed by the Redis instance."
+				type:              "counter"
+				default_namespace: "redis"
+				tags:              _cache_metrics_tags
+			}
+			db_keys: {
+				description:       "The number of keys in a database of the Redis instance."
+				type:              "gauge"
+				default_namespace: "redis"
+				tags:              _cache_metrics_tags & {
+					db: {
+						description: "The database."
+						required:    true
+						examples: ["0"]
+					}
+				}
+			}
+			db_expires: {
+				description:       "The number of keys with an expiry in a database of the Redis instance."
+				type:              "gauge"
+				default_namespace: "redis"
+				tags:              db_keys.tags
+			}
+			db_avg_ttl_seconds: {
+				description:       "The average time to live of the keys with an expiry in a database of the Redis instance."
+				type:              "gauge"
+				default_namespace: "redis"
+				tags:              db_keys.tags
+			}
+			curr_connections: {
+				description:       "The number of clients connected to the memcached instance. Every other numeric statistic of `stats` is emitted the same way."
+				type:              "gauge"
+				default_namespace: "memcached"
+				tags:              _cache_metrics_tags
+			}
+			cmd_get: {
+				description:       "The total number of `get` commands processed by the memcached instance."
+				type:              "counter"
+				default_namespace: "memcached"
+				tags:              _cache_metrics_tags
+			}
+		}
+	}
+
+	telemetry: metrics: {
+		collect_completed_total:              components.sources.internal_metrics.output.metrics.collect_completed_total
+		collect_duration_seconds:             components.sources.internal_metrics.output.metrics.collect_duration_seconds
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+}
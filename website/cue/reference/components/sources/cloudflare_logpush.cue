@@ -0,0 +1,139 @@
+package metadata
+
+components: sources: cloudflare_logpush: {
+	_port: 443
+
+	title: "Cloudflare Logpush"
+
+	description: """
+		Receives logs pushed by Cloudflare Logpush jobs to an HTTP destination.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		auto_generated:   true
+		acknowledgements: true
+		multiline: enabled: false
+		receive: {
+			from: {
+				service: services.cloudflare
+
+				interface: socket: {
+					api: {
+						title: "Logpush HTTP destination"
+						url:   urls.cloudflare_logpush_http
+					}
+					direction: "incoming"
+					port:      _port
+					protocols: ["http"]
+					ssl: "optional"
+				}
+			}
+
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				enabled_default:        false
+			}
+		}
+	}
+
+	support: {
+		requirements: [
+			"""
+				Logpush only pushes to HTTPS destinations that are reachable from the internet,
+				so the source must either serve a valid certificate through its `tls` options or
+				sit behind a load balancer terminating TLS.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.cloudflare_logpush.configuration
+
+	output: logs: line: {
+		description: """
+			An individual log from a batch pushed by a Logpush job. The fields of the log
+			are the fields selected for the job, which depend on its dataset.
+			"""
+		fields: {
+			"*": {
+				description: "In addition to the defined fields, all fields of the log are inserted as root level fields."
+				required:    true
+				type: string: {
+					examples: ["192.0.2.1"]
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["cloudflare_logpush"]
+				}
+			}
+			timestamp: fields._current_timestamp
+		}
+	}
+
+	how_it_works: {
+		destination: {
+			title: "Setting up the Logpush job"
+			body:  """
+				Create a [Logpush job](\(urls.cloudflare_logpush_http)) with the address of
+				the source as its HTTP destination, with the `ndjson` output format. Since
+				the source accepts pushes on any path, the destination can carry query
+				parameters to set on every log with the `query_parameters` option, such as
+				the dataset of the job:
+
+				```text
+				https://vector.example.com/logpush?dataset=http_requests&header_Authorization=Basic%20<credentials>
+				```
+
+				Logpush sends the `header_*` query parameters as headers, which is how the
+				`Authorization` header expected by the `auth` option is set.
+				"""
+		}
+
+		validation: {
+			title: "Destination validation"
+			body:  """
+				When a job is created, and when the ownership of its destination is
+				challenged, Logpush pushes a single JSON object with a `content` field, and an
+				optional `filename` field, rather than logs. The source accepts these payloads
+				without emitting any events, and logs their content at the `info` level, where
+				the challenge token can be read.
+				"""
+		}
+
+		decompression: {
+			title: "Decompression"
+			body:  """
+				Logpush compresses its pushes with gzip, and sets the `Content-Encoding` header
+				accordingly. Bodies are decompressed based on that header, one JSON object per
+				line. Lines which are not JSON objects are dropped, with an error.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+		requests_received_total:              components.sources.internal_metrics.output.metrics.requests_received_total
+	}
+}
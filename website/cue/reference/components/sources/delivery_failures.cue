@@ -0,0 +1,122 @@
+package metadata
+
+components: sources: delivery_failures: {
+	title:       "Delivery Failures"
+	description: "The delivery failures source emits an event for each batch of events that a sink with `delivery_failures.enabled` fails to deliver."
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		deployment_roles: ["aggregator", "daemon", "sidecar"]
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: service: {
+				name:     "Vector instance"
+				thing:    "a \(name)"
+				url:      urls.vector_docs
+				versions: null
+			}
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		notices: []
+		requirements: []
+		warnings: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.delivery_failures.configuration
+
+	output: logs: failure: {
+		description: "A batch of events that a sink failed to deliver."
+		fields: {
+			component_id: {
+				description: "The ID of the sink that failed to deliver the events."
+				required:    true
+				type: string: {
+					examples: ["kafka_out"]
+				}
+			}
+			status: {
+				description: "How the delivery of the batch failed."
+				required:    true
+				type: string: {
+					enum: {
+						errored:  "The delivery of at least one event of the batch errored."
+						rejected: "At least one event of the batch was rejected by the destination."
+					}
+				}
+			}
+			count: {
+				description: "The number of events in the batch."
+				required:    true
+				type: uint: {
+					examples: [100]
+					unit: null
+				}
+			}
+			events: {
+				description: """
+					The key fields of each event of the batch, as set by the `delivery_failures.key_fields`
+					option of the sink. Metric events have their `name`, `namespace`, and `tags`.
+					"""
+				required: true
+				type: array: items: type: object: {
+					examples: [{"message_id": "a7c9e1"}]
+					options: {}
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["delivery_failures"]
+				}
+			}
+			timestamp: fields._current_timestamp & {
+				description: "The time at which the failure was reported."
+			}
+		}
+	}
+
+	how_it_works: {
+		batches: {
+			title: "Batches"
+			body: """
+				Sinks with `delivery_failures.enabled` track the delivery of each batch of events
+				they receive from their buffer. When any event of a batch errors or is rejected, the
+				whole batch is reported, with the key fields of each of its events. Only sinks that
+				report the delivery of events, as used for end-to-end acknowledgements, can report
+				failures.
+				"""
+		}
+		compensation: {
+			title: "Compensation"
+			body: """
+				Failures are emitted as regular log events, so pipelines can route them to alerting,
+				or republish the affected events elsewhere by their key fields. Failures reported
+				while no `delivery_failures` source is running are discarded, as are failures
+				reported faster than they can be emitted.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+}
@@ -14,7 +14,7 @@ components: sources: exec: {
 
 	features: {
 		auto_generated:   true
-		acknowledgements: false
+		acknowledgements: true
 		multiline: enabled: false
 		codecs: {
 			enabled:         true
@@ -102,6 +102,18 @@ components: sources: exec: {
 	]
 
 	how_it_works: {
+		acknowledgements: {
+			title: "Acknowledgements"
+			body: """
+				When acknowledgements are enabled, the events decoded from the output of the command are
+				sent with end-to-end acknowledgements, and the output keeps being read while they are
+				delivered.
+
+				With [`stdin_acknowledgements`](#stdin_acknowledgements), the delivery status of each
+				frame is also written to the standard input of the command, as a line reading `ack` or
+				`nack`, so that it can record its progress or retry the output that was not delivered.
+				"""
+		}
 		line_delimiters: {
 			title: "Line Delimiters"
 			body: """
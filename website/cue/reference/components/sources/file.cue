@@ -49,6 +49,11 @@ components: sources: file: {
 	}
 
 	configuration: base.components.sources.file.configuration & {
+		archive_dir: warnings: [
+			"""
+				Vector’s process must have permission to move files into the archive directory.
+				""",
+		]
 		remove_after_secs: warnings: [
 			"""
 				Vector’s process must have permission to delete files.
@@ -149,6 +154,23 @@ components: sources: file: {
 				"""
 		}
 
+		file_removal: {
+			title: "File Removal"
+			body: """
+				When `remove_after_secs` is set, Vector removes files once it has read them up to
+				`EOF` and no new data has been written to them for that long. This keeps directories
+				that other processes drop batches of files into from growing unboundedly.
+
+				A file is only removed once all the data read from it has been checkpointed. When
+				end-to-end acknowledgements are enabled, checkpoints only advance once events are
+				acknowledged by the sinks, so files are never removed before their data has been
+				delivered.
+
+				If `archive_dir` is set, files are moved into that directory instead of being
+				deleted, keeping an audit trail of everything that was ingested.
+				"""
+		}
+
 		file_read_order: {
 			title: "File Read Order"
 			body: """
@@ -432,6 +454,7 @@ components: sources: file: {
 		checkpoints_total:                    components.sources.internal_metrics.output.metrics.checkpoints_total
 		checksum_errors_total:                components.sources.internal_metrics.output.metrics.checksum_errors_total
 		file_delete_errors_total:             components.sources.internal_metrics.output.metrics.file_delete_errors_total
+		files_archived_total:                 components.sources.internal_metrics.output.metrics.files_archived_total
 		file_watch_errors_total:              components.sources.internal_metrics.output.metrics.file_watch_errors_total
 		files_added_total:                    components.sources.internal_metrics.output.metrics.files_added_total
 		files_deleted_total:                  components.sources.internal_metrics.output.metrics.files_deleted_total
@@ -163,15 +163,29 @@ components: sources: fluent: {
 		secure_mode: {
 			title: "Secure forward mode support"
 			body:  """
-				The `fluent` source currently supports using TLS, but does not support the authentication part of the
-				Fluent protocol including:
+				The `fluent` source supports TLS, and the shared key authentication of the Fluent protocol. When
+				`security` is set, clients must complete the handshake of the protocol, proving that they know
+				`security.shared_key`, before sending events. Connections of clients that fail the handshake are
+				closed.
+
+				This matches the `Shared_Key` and `Self_Hostname` options of the Fluent Bit `forward` output, and the
+				`<security>` section of the Fluentd `forward` output. Username and password authentication is not
+				supported.
+				"""
+		}
 
-				- Shared key
-				- Username and password
+		acknowledgements: {
+			title: "Chunk acknowledgements"
+			body: """
+				When a client sets the `chunk` option on a message, such as Fluent Bit with `Require_ack_response`
+				or Fluentd with `require_ack_response`, the source answers with an `ack` response for the chunk once
+				its events have been delivered, when end-to-end acknowledgements are enabled, or accepted by the
+				source otherwise.
 
-				And so these options of the secure forward output plugins for Fluent and Fluent Bit cannot be used.
+				When the events of a chunk are rejected or fail to be delivered, no response is sent and the
+				connection is closed, so that the client sends the chunk again.
 
-				If you would find this useful, [please let us know](\(urls.vector_repo)/issues/7532).
+				Chunks sent in the `CompressedPackedForward` mode, compressed with gzip, are supported.
 				"""
 		}
 	}
@@ -183,5 +197,6 @@ components: sources: fluent: {
 		processed_events_total:          components.sources.internal_metrics.output.metrics.processed_events_total
 		component_received_bytes_total:  components.sources.internal_metrics.output.metrics.component_received_bytes_total
 		component_received_events_total: components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_errors_total:          components.sources.internal_metrics.output.metrics.component_errors_total
 	}
 }
@@ -133,5 +133,20 @@ components: sources: gcp_pubsub: {
 					without opening up extra connections at startup.
 				"""
 		}
+		flow_control: {
+			title: "Flow Control"
+			body: """
+				Each stream asks Pub/Sub to stop delivering messages once `max_outstanding_messages`
+				messages, or `max_outstanding_bytes` bytes, have been received and not yet acknowledged.
+				With end-to-end acknowledgements enabled, this keeps the number of events held by Vector
+				bounded while sinks are slow or unavailable.
+
+				While messages are awaiting acknowledgement, their acknowledgement deadline is extended
+				every half of `ack_deadline_secs`, so they are not redelivered while still being
+				processed. The deadline is extended for at most `max_ack_extension_secs`, after which
+				the messages may be redelivered. Messages that sinks fail to deliver are not
+				acknowledged, and are redelivered once their deadline expires.
+				"""
+		}
 	}
 }
@@ -183,6 +183,35 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		byte_budget_exceeded_total: {
+			description:       "The total number of events that still exceeded the byte budget after all configured fields were trimmed."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		byte_budget_fields_trimmed_total: {
+			description:       "The total number of fields trimmed to fit events within the byte budget."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		cluster_events_forwarded_total: {
+			description:       "The total number of events forwarded to the cluster peer that owns their key."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				peer: {
+					description: "The address of the peer that events were forwarded to."
+					required:    true
+				}
+			}
+		}
+		cluster_peers: {
+			description:       "The number of Vector instances, including this one, that events are sharded across."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		checkpoint_write_errors_total: {
 			description:       "The total number of errors writing checkpoints. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -277,6 +306,34 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		data_dir_quota_bytes: {
+			description:       "The configured `data_dir_quota`, in bytes."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		data_dir_quota_exceeded_total: {
+			description:       "The total number of times persisted state was measured to exceed `data_dir_quota`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		data_dir_used_bytes: {
+			description:       "The number of bytes of state persisted by a subsystem in the data directories."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags & {
+				subsystem: {
+					description: "The subsystem persisting the state."
+					required:    true
+					enum: {
+						buffers:          "Disk buffers."
+						checkpoints:      "Source checkpoints."
+						enrichment_cache: "Cached enrichment table data."
+					}
+				}
+			}
+		}
 		decode_errors_total: {
 			description:       "The total number of decode errors seen when decoding data in a source component."
 			type:              "counter"
@@ -295,12 +352,29 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		k8s_duplicate_lines_suppressed_total: {
+			description:       "The total number of log lines discarded because they were read twice while a log file was being rotated."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		k8s_event_annotation_failures_total: {
 			description:       "The total number of failures to annotate Vector events with Kubernetes Pod metadata."
 			type:              "counter"
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		k8s_partial_lines_merged_total: {
+			description:       "The total number of log lines merged from partial lines split by the container runtime."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				complete: {
+					description: "Whether the last partial line was read, or the line was emitted incomplete after waiting for it."
+					required:    true
+				}
+			}
+		}
 		k8s_reflector_desyncs_total: {
 			description:       "The total number of desyncs for the reflector."
 			type:              "counter"
@@ -430,6 +504,36 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		buffer_expired_bytes_total: {
+			description:       "The number of bytes dropped by this disk buffer because they were older than its `max_age`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		buffer_expired_events_total: {
+			description:       "The number of events dropped by this disk buffer because they were older than its `max_age`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		buffer_oldest_event_age_seconds: {
+			description:       "The age of the oldest event in this buffer, in seconds, or zero if the buffer is empty. For disk buffers, events already in the buffer when Vector started are aged from startup."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		buffer_purged_bytes_total: {
+			description:       "The number of bytes discarded by this buffer because it was purged through the API."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		buffer_purged_events_total: {
+			description:       "The number of events discarded by this buffer because it was purged through the API. Purged events are also counted as sent by the buffer."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		buffer_received_event_bytes_total: {
 			description:       "The number of bytes received by this buffer."
 			type:              "counter"
@@ -579,6 +683,16 @@ components: sources: internal_metrics: {
 				}
 			}
 		}
+		component_schema_violations_total: {
+			description: """
+				The number of sampled events output by this transform that don't match the schema
+				definition of their output. Only reported when `schema.enabled` is set, for one out of
+				every `schema.runtime_validation_sample_rate` events.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {output: _output}
+		}
 		component_sent_events_total: {
 			description:       "The total number of events emitted by this component."
 			type:              "counter"
@@ -591,6 +705,40 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags & {output: _output}
 		}
+		component_stalls_total: {
+			description:       "The total number of times this component stalled, as detected by the watchdog."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags & {
+				component_kind: _component_kind
+				component_id:   _component_id
+				component_type: _component_type
+				reason: {
+					description: "Why the component is considered stalled."
+					required:    true
+					enum: {
+						blocked_poll: "The component blocked a thread in a single poll."
+						not_polled:   "The component was not polled after being woken up."
+					}
+				}
+			}
+		}
+		component_restarts_total: {
+			description:       "The total number of times the watchdog restarted this component after it stalled."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags & {
+				component_id: _component_id
+				success: {
+					description: "Whether the component was rebuilt."
+					required:    true
+					enum: {
+						"true":  "The component was rebuilt."
+						"false": "The component could not be stopped or rebuilt."
+					}
+				}
+			}
+		}
 		datadog_logs_received_in_total: {
 			description:       "Number of Datadog logs received."
 			type:              "counter"
@@ -615,6 +763,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              internal_metrics_cardinality.tags
 		}
+		kafka_delivery_latency_seconds: {
+			description:       "The time between producing a message and receiving its delivery report, including the time spent in the producer's queue."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _kafka_delivery_report_tags
+		}
+		kafka_delivery_reports_total: {
+			description:       "Total number of delivery reports received for produced messages, by outcome."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _kafka_delivery_report_tags
+		}
 		kafka_queue_messages: {
 			description:       "Current number of messages in producer queues."
 			type:              "gauge"
@@ -690,6 +850,12 @@ components: sources: internal_metrics: {
 				}
 			}
 		}
+		feature_flags_load_errors_total: {
+			description:       "The total number of failures to load the feature flags from their provider."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
 		file_delete_errors_total: {
 			description:       "The total number of failures to delete a file. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -698,6 +864,18 @@ components: sources: internal_metrics: {
 				file: _file
 			}
 		}
+		file_table_refresh_failed_total: {
+			description:       "The total number of failed reloads of `file` enrichment tables."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		file_table_refresh_successful_total: {
+			description:       "The total number of reloads of `file` enrichment tables after their file was modified."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
 		file_watch_errors_total: {
 			description:       "The total number of errors encountered when watching files. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -714,6 +892,14 @@ components: sources: internal_metrics: {
 				file: _file
 			}
 		}
+		files_archived_total: {
+			description:       "The total number of files archived."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags & {
+				file: _file
+			}
+		}
 		files_deleted_total: {
 			description:       "The total number of files deleted."
 			type:              "counter"
@@ -746,6 +932,18 @@ components: sources: internal_metrics: {
 				file: _file
 			}
 		}
+		geoip_refresh_failed_total: {
+			description:       "The total number of failed refreshes of `geoip` enrichment tables."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		geoip_refresh_successful_total: {
+			description:       "The total number of refreshes of `geoip` enrichment tables that loaded a new database."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
 		glob_errors_total: {
 			description:       "The total number of errors encountered when globbing paths. This metric is deprecated in favor of `component_errors_total`."
 			type:              "counter"
@@ -754,6 +952,12 @@ components: sources: internal_metrics: {
 				path: _path
 			}
 		}
+		groups_evicted_total: {
+			description:       "The number of groups that Vector has evicted to make room for new groups."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		http_bad_requests_total: {
 			description:       "The total number of HTTP `400 Bad Request` errors encountered."
 			type:              "counter"
@@ -800,6 +1004,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		incomplete_reassemblies_flushed_total: {
+			description:       "The number of messages that Vector has flushed before receiving all of their parts."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		invalid_record_total: {
 			description:       "The total number of invalid records that have been discarded."
 			type:              "counter"
@@ -812,6 +1022,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		log_patterns_created_total: {
+			description:       "The total number of new log patterns found by the `log_patterns` transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		logging_driver_errors_total: {
 			description: """
 				The total number of logging driver errors encountered caused by not using either
@@ -839,6 +1055,24 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		metric_name_collisions_total: {
+			description: """
+				The total number of metric names or tag keys that were sanitized to the same value as a
+				different name or tag key.
+				"""
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags & {
+				kind: {
+					description: "Whether a metric name or a tag key collided."
+					required:    true
+					enum: {
+						name:    "A metric name collided."
+						tag_key: "A tag key collided."
+					}
+				}
+			}
+		}
 		open_connections: {
 			description:       "The number of current open connections to Vector."
 			type:              "gauge"
@@ -851,6 +1085,27 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		pii_detections_total: {
+			description:       "The number of pieces of personally identifiable information found by a detector."
+			type:              "counter"
+			default_namespace: "vector"
+			tags: _component_tags & {
+				detector: {
+					description: "The name of the detector."
+					required:    true
+				}
+				action: {
+					description: "The action taken on the information."
+					required:    true
+					enum: {
+						mask:       "The information was masked."
+						hash:       "The information was hashed."
+						drop_field: "The field containing the information was dropped."
+						reroute:    "The event was rerouted."
+					}
+				}
+			}
+		}
 		processed_bytes_total: {
 			description:       "The number of bytes processed by the component."
 			type:              "counter"
@@ -897,6 +1152,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		remap_cache_hits_total: {
+			description:       "The number of events whose program results were found in the cache of the `remap` transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		remap_cache_misses_total: {
+			description:       "The number of events whose program results weren't found in the cache of the `remap` transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		request_errors_total: {
 			description:       "The total number of requests errors for this component."
 			type:              "counter"
@@ -933,6 +1200,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		schema_violations_total: {
+			description:       "The number of schema violations found in the events Vector validated."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		send_errors_total: {
 			description:       "The total number of errors sending messages."
 			type:              "counter"
@@ -945,6 +1218,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		span_metrics_flushes_total: {
+			description:       "The number of flushes done by the span_metrics transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		span_metrics_spans_recorded_total: {
+			description:       "The number of spans recorded by the span_metrics transform."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		splunk_pending_acks: {
 			description:       "The number of outstanding Splunk HEC indexer acknowledgement acks."
 			type:              "gauge"
@@ -957,6 +1242,18 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		sqlite_table_refresh_failed_total: {
+			description:       "The total number of failed refreshes of `sqlite` enrichment tables."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
+		sqlite_table_refresh_successful_total: {
+			description:       "The total number of refreshes of `sqlite` enrichment tables that reopened a modified database."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _internal_metrics_tags
+		}
 		sqs_message_delete_failed_total: {
 			description:       "The total number of failures to delete SQS messages."
 			type:              "counter"
@@ -1041,12 +1338,30 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		unmatched_joins_flushed_total: {
+			description:       "The number of joins that Vector has flushed without the events of all streams."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		uptime_seconds: {
 			description:       "The total number of seconds the Vector instance has been up."
 			type:              "gauge"
 			default_namespace: "vector"
 			tags:              _internal_metrics_tags
 		}
+		user_agent_ruleset_refresh_failed_total: {
+			description:       "The total number of times the user agent ruleset failed to be reloaded."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		user_agent_ruleset_refresh_successful_total: {
+			description:       "The total number of times the user agent ruleset was reloaded after its file was modified."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		utf8_convert_errors_total: {
 			description:       "The total number of errors converting bytes to a UTF-8 string in UDP mode."
 			type:              "counter"
@@ -1162,6 +1477,26 @@ components: sources: internal_metrics: {
 			component_name: _component_name
 			component_type: _component_type
 		}
+		_kafka_delivery_report_tags: _component_tags & {
+			topic: {
+				description: "The Kafka topic the message was produced to."
+				required:    true
+			}
+			partition: {
+				description: "The Kafka partition the message was produced to, or `-1` if it wasn't assigned one."
+				required:    true
+			}
+			status: {
+				description: "The outcome of the delivery."
+				required:    true
+				enum: {
+					delivered:    "The message was delivered."
+					timed_out:    "The message timed out before it could be delivered, as set by `message.timeout.ms`."
+					broker_error: "The message was rejected by the brokers."
+					client_error: "The message failed within the client, other than by timing out."
+				}
+			}
+		}
 
 		// All available tags
 		_collector: {
@@ -138,6 +138,26 @@ components: sources: journald: {
 				[issue #1473](\(urls.vector_issues)/1473).
 				"""
 		}
+		checkpointing: {
+			title: "Checkpointing"
+			body:  """
+				Vector checkpoints the journal cursor of the last entry of each batch it reads, so that
+				it resumes from there when restarted. When [end-to-end acknowledgements][e2e_acks] are
+				enabled, the cursor is only advanced once the sinks have acknowledged the events of
+				the batch, so events that were not delivered are read again after a restart.
+
+				[e2e_acks]: /docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+				"""
+		}
+		field_filtering: {
+			title: "Field Filtering"
+			body:  """
+				Journal entries carry a lot of systemd metadata, such as `_CAP_EFFECTIVE` or
+				`_SYSTEMD_INVOCATION_ID`. The `include_fields` and `exclude_fields` options drop the
+				fields that aren't needed before the events enter the topology. Entries are matched
+				against `include_matches` and `exclude_matches` before their fields are filtered.
+				"""
+		}
 		non_ascii: {
 			title: "Non-ASCII Messages"
 			body: """
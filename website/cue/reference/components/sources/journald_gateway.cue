@@ -0,0 +1,132 @@
+package metadata
+
+components: sources: journald_gateway: {
+	title: "Journald Gateway"
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		deployment_roles: ["aggregator"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: true
+		auto_generated:   true
+		collect: {
+			checkpoint: enabled: true
+			from: {
+				service: services.journald
+
+				interface: socket: {
+					direction: "outgoing"
+					port:      19531
+					protocols: ["http"]
+					ssl: "optional"
+				}
+			}
+			proxy: enabled: true
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      true
+			}
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		targets: {
+			"x86_64-apple-darwin":   false
+			"x86_64-pc-windows-msv": false
+		}
+
+		requirements: [
+			"""
+				Each remote host must run [`systemd-journal-gatewayd`](\(urls.journald_gatewayd)), and expose it
+				to Vector.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.journald_gateway.configuration
+
+	output: logs: {
+		event: {
+			description: "A Journald event"
+			fields: {
+				host: {
+					description: "The hostname of the remote system the entry was logged on."
+					required:    true
+					type: string: {
+						examples: ["db-1"]
+					}
+				}
+				message: {
+					description: "The message of the journal entry."
+					required:    true
+					type: string: {
+						examples: ["reply from 192.168.1.2: offset -0.001791 delay 0.000176, next query 1500s"]
+					}
+				}
+				source_type: {
+					description: "The name of the source type."
+					required:    true
+					type: string: {
+						examples: ["journald_gateway"]
+					}
+				}
+				timestamp: fields._current_timestamp & {
+					description: "The time at which the event appeared in the journal."
+				}
+				"*": {
+					common:      false
+					description: "Any Journald field"
+					required:    false
+					type: string: {
+						default: null
+						examples: ["/usr/sbin/ntpd", "c36e9ea52800a19d214cb71b53263a28"]
+					}
+				}
+			}
+		}
+	}
+
+	how_it_works: {
+		communication_strategy: {
+			title: "Communication Strategy"
+			body:  """
+				Vector follows the journal of each endpoint by requesting `/entries?follow` from
+				[`systemd-journal-gatewayd`](\(urls.journald_gatewayd)), and reading the entries as they are
+				streamed back. When the connection drops, Vector reconnects after a short backoff.
+
+				Only pulling from `systemd-journal-gatewayd` is supported. Vector can't receive the journals
+				uploaded by `systemd-journal-upload`.
+				"""
+		}
+		checkpointing: {
+			title: "Checkpointing"
+			body: """
+				Vector checkpoints the cursor of the last entry read from each remote system, keyed by the
+				machine ID reported by `/machine`. Reading resumes after that cursor on restart or reconnection,
+				even if the system is reached through a different endpoint.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+}
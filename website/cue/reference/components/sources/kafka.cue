@@ -110,5 +110,16 @@ components: sources: kafka: {
 		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
 	}
 
-	how_it_works: components._kafka.how_it_works
+	how_it_works: components._kafka.how_it_works & {
+		backpressure: {
+			title: "Backpressure"
+			body: """
+				When the downstream components can't keep up and the output of the source is full, Vector
+				pauses fetching from all assigned partitions until the pending events are accepted. This
+				keeps `librdkafka` from buffering an unbounded amount of messages in memory while a sink
+				is stalled. Consumer lag keeps growing while paused, and can be tracked per partition by
+				enabling `metrics.topic_lag_metric`.
+				"""
+		}
+	}
 }
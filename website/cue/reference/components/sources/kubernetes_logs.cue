@@ -359,11 +359,25 @@ components: sources: kubernetes_logs: {
 			title: "Partial message merging"
 			body:  """
 					Vector, by default, will merge partial messages that are
-					split due to the Docker size limit. For everything else, it
-					is recommended to use the [`reduce`
+					split due to the Docker size limit, or flagged as partial by
+					the CRI log format. For everything else, it is recommended to
+					use the [`reduce`
 					transform](\(urls.vector_reduce_transform)) which offers
 					the ability to handle custom merging of things like
 					stacktraces.
+
+					Partial messages are merged separately for the `stdout` and
+					`stderr` streams of each container, so that the lines
+					written concurrently to both streams aren't mixed. A message
+					split across a log rotation is merged as well, from the end of
+					the rotated log file and the beginning of the new one. A
+					message that isn't completed within 30 seconds is emitted as
+					is, with the `_partial` field still set.
+
+					While the kubelet rotates a log file, the same lines can be
+					read from both the rotated file and the new one. The lines
+					read twice, with the same timestamp and content as one of the
+					last 256 lines of their stream, are discarded.
 					"""
 		}
 
@@ -468,7 +482,9 @@ components: sources: kubernetes_logs: {
 		events_in_total:                        components.sources.internal_metrics.output.metrics.events_in_total
 		k8s_format_picker_edge_cases_total:     components.sources.internal_metrics.output.metrics.k8s_format_picker_edge_cases_total
 		k8s_docker_format_parse_failures_total: components.sources.internal_metrics.output.metrics.k8s_docker_format_parse_failures_total
+		k8s_duplicate_lines_suppressed_total:   components.sources.internal_metrics.output.metrics.k8s_duplicate_lines_suppressed_total
 		k8s_event_annotation_failures_total:    components.sources.internal_metrics.output.metrics.k8s_event_annotation_failures_total
+		k8s_partial_lines_merged_total:         components.sources.internal_metrics.output.metrics.k8s_partial_lines_merged_total
 		k8s_reflector_desyncs_total:            components.sources.internal_metrics.output.metrics.k8s_reflector_desyncs_total
 		k8s_state_ops_total:                    components.sources.internal_metrics.output.metrics.k8s_state_ops_total
 		k8s_stream_chunks_processed_total:      components.sources.internal_metrics.output.metrics.k8s_stream_chunks_processed_total
@@ -0,0 +1,145 @@
+package metadata
+
+components: sources: linux_audit: {
+	title:       "Linux audit"
+	description: "The Linux audit source collects the events of the Linux audit framework, by subscribing to the audit netlink socket of the kernel or by reading the records forwarded by `audisp`, and reassembles the records of each event into a single structured event."
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		deployment_roles: ["daemon"]
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: service: {
+				name:     "Linux audit"
+				thing:    "the \(name) framework"
+				url:      urls.linux_audit
+				versions: null
+			}
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		targets: {
+			"x86_64-apple-darwin":   false
+			"x86_64-pc-windows-msv": false
+		}
+
+		requirements: [
+			"""
+				Without `socket_path`, Vector must run with the `CAP_AUDIT_READ` capability, and the
+				kernel must be version 3.16 or later.
+				""",
+		]
+		warnings: [
+			"""
+				The records the kernel can't send to Vector fast enough are dropped by the kernel,
+				which is reported as an error of the source. The audit daemon still receives these
+				records.
+				""",
+		]
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.linux_audit.configuration
+
+	output: logs: event: {
+		description: "An audit event, reassembled from its records."
+		fields: {
+			message: {
+				description: "The records of the event, as written by `auditd`, separated by newlines."
+				required:    true
+				type: string: {
+					examples: ["type=USER_LOGIN msg=audit(1364481364.000:24288): pid=99 uid=0 msg='op=login acct=\"alice\" exe=\"/usr/sbin/sshd\" addr=10.0.0.1 res=success'"]
+				}
+			}
+			serial: {
+				description: "The serial number of the event, shared by its records."
+				required:    true
+				type: uint: {
+					examples: [24287]
+					unit: null
+				}
+			}
+			record_types: {
+				description: "The types of the records of the event, in order."
+				required:    true
+				type: array: items: type: string: examples: ["SYSCALL", "PATH", "PROCTITLE"]
+			}
+			"*": {
+				description: """
+					The fields of each record of the event, keyed by the lowercase name of its type,
+					such as `syscall` or `user_login`. Records of uncommon types are keyed by
+					`unknown_<type>`.
+
+					The values of the fields are strings, stripped of their quotes and decoded if hex
+					encoded. The arguments of `execve` records are gathered into an `args` array, and
+					the `msg` field of records reported by user space programs is parsed into an object.
+					`path` and `obj_pid` records are always arrays, as are the records of other types
+					occurring more than once in the event.
+					"""
+				required: false
+				type: object: {}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["linux_audit"]
+				}
+			}
+			timestamp: fields._current_timestamp & {
+				description: "The time at which the audited action occurred."
+			}
+		}
+	}
+
+	how_it_works: {
+		reading_records: {
+			title: "Reading records"
+			body: """
+				By default, the source joins the read-only multicast group of the audit netlink socket,
+				to which the kernel sends a copy of every audit record. This doesn't interfere with the
+				audit daemon, so `auditd` keeps writing its logs and applying its rules, and auditbeat is
+				no longer needed. The rules deciding which actions are audited are still managed with
+				`auditctl`.
+
+				Alternatively, when `socket_path` is set, the records are read from the socket of the
+				`af_unix` plugin of `audisp`, which must be configured with the `string` format. The
+				source reconnects to the socket whenever the audit daemon restarts.
+				"""
+		}
+		reassembly: {
+			title: "Reassembly of events"
+			body: """
+				The kernel reports each audited action as several records sharing the same serial
+				number, such as a `SYSCALL` record followed by `CWD`, `PATH` and `PROCTITLE` records,
+				and terminated by an `EOE` record. The source reassembles these records into a single
+				event. Records reported by user space programs, such as `USER_LOGIN`, are emitted as
+				events on their own.
+
+				The records of events which aren't terminated within `reassembly_timeout_secs` are
+				emitted as an event once the timeout elapses.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+}
@@ -41,7 +41,7 @@ components: sources: opentelemetry: {
 		requirements: []
 		warnings: [
 			"""
-				The `opentelemetry` source only supports log events at this time.
+				The `opentelemetry` source only supports log and trace events at this time.
 				""",
 		]
 		notices: []
@@ -60,6 +60,12 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "traces"
+			description: """
+				Received trace events will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -194,6 +200,149 @@ components: sources: opentelemetry: {
 		}
 	}
 
+	output: traces: event: {
+		description: "An individual span from a batch of spans received through an OTLP request"
+		fields: {
+			trace_id: {
+				description: "The hex-encoded ID of the trace the span belongs to."
+				required:    true
+				type: string: {
+					examples: ["66346462623365646437363566363230"]
+				}
+			}
+			span_id: {
+				description: "The hex-encoded ID of the span."
+				required:    true
+				type: string: {
+					examples: ["43222c2d51a7abe3"]
+				}
+			}
+			parent_span_id: {
+				description: "The hex-encoded ID of the parent span. Root spans have no parent."
+				required:    false
+				common:      true
+				type: string: {
+					default: null
+					examples: ["53222c2d51a7abe3"]
+				}
+			}
+			trace_state: {
+				description: "The [W3C trace state](https://www.w3.org/TR/trace-context/#tracestate-header) of the span."
+				required:    false
+				common:      false
+				type: string: {
+					default: null
+					examples: ["vendor=value"]
+				}
+			}
+			name: {
+				description: "The name of the operation the span describes."
+				required:    true
+				type: string: {
+					examples: ["GET /cart"]
+				}
+			}
+			kind: {
+				description: "The OTLP `SpanKind` of the span, such as `2` for a server span."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			start_time_unix_nano: {
+				description: "The time the span started."
+				required:    true
+				type: timestamp: {}
+			}
+			end_time_unix_nano: {
+				description: "The time the span ended."
+				required:    true
+				type: timestamp: {}
+			}
+			attributes: {
+				description: "Attributes that describe the span."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"http.method": "GET"}]
+					options: {}
+				}
+			}
+			events: {
+				description: "Timestamped events that occurred during the span, each with a `name`, `time_unix_nano`, `attributes`, and `dropped_attributes_count`."
+				required:    false
+				common:      false
+				type: array: items: type: object: options: {}
+			}
+			links: {
+				description: "Links to spans in this or other traces, each with a `trace_id`, `span_id`, optional `trace_state`, `attributes`, and `dropped_attributes_count`."
+				required:    false
+				common:      false
+				type: array: items: type: object: options: {}
+			}
+			status: {
+				description: "The status of the span, with the OTLP status `code` and an optional `message`."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"code": 2, "message": "upstream timed out"}]
+					options: {}
+				}
+			}
+			resources: {
+				description: "Attributes of the resource that produced the span."
+				required:    false
+				common:      true
+				type: object: {
+					examples: [{"service.name": "checkout"}]
+					options: {}
+				}
+			}
+			scope: {
+				description: "The instrumentation scope that produced the span, with its `name` and, if set, its `version` and `attributes`."
+				required:    false
+				common:      false
+				type: object: {
+					examples: [{"name": "io.opentelemetry.tracer", "version": "1.0.0"}]
+					options: {}
+				}
+			}
+			dropped_attributes_count: {
+				description: "Counts for attributes dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			dropped_events_count: {
+				description: "Counts for events dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			dropped_links_count: {
+				description: "Counts for links dropped due to collection limits."
+				required:    true
+				type: uint: {
+					unit: null
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["opentelemetry"]
+				}
+			}
+			ingest_timestamp: {
+				description: "The time the span was received by Vector."
+				required:    true
+				type: timestamp: {}
+			}
+		}
+	}
+
 	telemetry: metrics: {
 		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
 		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
@@ -211,5 +360,15 @@ components: sources: opentelemetry: {
 				  adjust TLS behavior via the `grpc.tls.*` and `http.tls.*` options.
 				  """
 		}
+		traces: {
+			title: "Traces"
+			body:  """
+				Spans are accepted over gRPC and on the `/v1/traces` HTTP endpoint. Each span
+				becomes a trace event of its own on the `traces` output, keeping its events,
+				links, and status. These spans can be sent on unchanged to the `opentelemetry` sink,
+				or reshaped with `remap` for sinks that expect another layout, such as
+				`datadog_traces`.
+				"""
+		}
 	}
 }
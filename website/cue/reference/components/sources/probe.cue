@@ -0,0 +1,179 @@
+package metadata
+
+components: sources: probe: {
+	title:       "Probe"
+	description: "The probe source periodically pings hosts, opens TCP connections, or sends HTTP requests to the configured targets, and reports their availability and latency."
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		deployment_roles: ["aggregator", "daemon", "sidecar"]
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      false
+	}
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: service: {
+				name:     "hosts and services"
+				thing:    "\(name)"
+				url:      urls.vector_docs
+				versions: null
+			}
+			proxy: enabled: true
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      true
+			}
+		}
+		multiline: enabled: false
+	}
+
+	support: {
+		requirements: []
+		warnings: [
+			"""
+				ICMP probes use unprivileged ICMP sockets. On Linux, the group Vector runs as must be
+				within the `net.ipv4.ping_group_range` sysctl, otherwise ICMP probes fail with a
+				permission error.
+				""",
+		]
+		notices: []
+	}
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: base.components.sources.probe.configuration
+
+	output: {
+		metrics: {
+			_probe_tags: {
+				probe: {
+					description: "The type of probe."
+					required:    true
+					enum: {
+						http: "An HTTP request."
+						icmp: "An ICMP echo request."
+						tcp:  "A TCP connection."
+					}
+				}
+				target: {
+					description: "The probed target: the host, address, or URL stripped of any credentials."
+					required:    true
+					examples: ["example.com", "example.com:443", "https://example.com/health"]
+				}
+			}
+
+			success: {
+				description:       "Whether the probe succeeded (`1`) or failed (`0`)."
+				type:              "gauge"
+				default_namespace: "probe"
+				tags:              _probe_tags
+			}
+			duration_seconds: {
+				description:       "The time to receive the echo reply, establish the connection, or receive the response headers. Not reported if the probe got no reply."
+				type:              "gauge"
+				default_namespace: "probe"
+				tags:              _probe_tags
+			}
+			http_status_code: {
+				description:       "The status code of the response to an HTTP probe."
+				type:              "gauge"
+				default_namespace: "probe"
+				tags:              _probe_tags
+			}
+		}
+
+		logs: failure: {
+			description: "An event emitted for each failed probe."
+			fields: {
+				message: {
+					description: "The reason the probe failed."
+					required:    true
+					type: string: {
+						examples: ["Failed to connect: Connection refused (os error 111)"]
+					}
+				}
+				probe: {
+					description: "The type of probe."
+					required:    true
+					type: string: {
+						enum: {
+							http: "An HTTP request."
+							icmp: "An ICMP echo request."
+							tcp:  "A TCP connection."
+						}
+					}
+				}
+				target: {
+					description: "The probed target: the host, address, or URL stripped of any credentials."
+					required:    true
+					type: string: {
+						examples: ["example.com:443"]
+					}
+				}
+				status_code: {
+					description: "The status code of the response to an HTTP probe, if one was received."
+					required:    false
+					type: uint: {
+						examples: [503]
+						unit: null
+					}
+				}
+				source_type: {
+					description: "The name of the source type."
+					required:    true
+					type: string: {
+						examples: ["probe"]
+					}
+				}
+				timestamp: fields._current_timestamp & {
+					description: "The time at which the probe completed."
+				}
+			}
+		}
+	}
+
+	how_it_works: {
+		probes: {
+			title: "Probes"
+			body: """
+				Every `interval_secs`, all the targets are probed concurrently, and each probe that
+				doesn't complete within `timeout_secs` fails:
+
+				* `icmp` probes send an ICMP echo request to the host and wait for its reply.
+				* `tcp` probes open a TCP connection to the address, and close it once established.
+				* `http` probes send a `GET` request to the URL, and succeed if the response has one of
+				  the `expected_status_codes`, or a `2xx` status code if none are set.
+
+				The outcome of every probe is reported as metrics, and each failed probe also emits a
+				log event describing the failure.
+				"""
+		}
+		icmp_permissions: {
+			title: "ICMP permissions"
+			body: """
+				ICMP probes don't require raw sockets, and so don't require Vector to run as root or
+				with the `CAP_NET_RAW` capability. Instead, they use the unprivileged ICMP sockets
+				available on Linux and macOS. On Linux, these are limited to the groups in the
+				`net.ipv4.ping_group_range` sysctl, which can be set to include the group Vector runs
+				as, for example with `sysctl -w net.ipv4.ping_group_range="0 2147483647"`.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+}
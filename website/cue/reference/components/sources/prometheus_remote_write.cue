@@ -52,8 +52,10 @@ components: sources: prometheus_remote_write: {
 	configuration: base.components.sources.prometheus_remote_write.configuration
 
 	output: metrics: {
-		counter: output._passthrough_counter
-		gauge:   output._passthrough_gauge
+		counter:   output._passthrough_counter
+		gauge:     output._passthrough_gauge
+		histogram: output._passthrough_histogram
+		summary:   output._passthrough_summary
 	}
 
 	how_it_works: {
@@ -61,15 +63,15 @@ components: sources: prometheus_remote_write: {
 			title: "Metric type interpretation"
 			body: """
 				The remote_write protocol used by this source transmits
-				only the metric tags, timestamp, and numerical value. No
-				explicit information about the original type of the
-				metric (i.e. counter, histogram, etc) is included. As
-				such, this source makes a guess as to what the original
-				metric type was.
-
-				For metrics named with a suffix of `_total`, this source
-				emits the value as a counter metric. All other metrics
-				are emitted as gauges.
+				the metric tags, timestamp, and numerical value, along
+				with the metadata of the metric families when the sender
+				includes it. Metrics whose family has metadata are emitted
+				with its type: counters, gauges, aggregated histograms,
+				and aggregated summaries.
+
+				Metrics without metadata are emitted as gauges, except for
+				native histograms, which are always emitted as aggregated
+				histograms.
 				"""
 		}
 
@@ -81,6 +83,48 @@ components: sources: prometheus_remote_write: {
 				but will only take the last value for each tag name specified.
 				"""
 		}
+
+		protocol_versions: {
+			title: "Protocol versions"
+			body: """
+				This source accepts both versions of the remote-write protocol, and tells them
+				apart by the `proto` parameter of the `Content-Type` header of each request:
+				`io.prometheus.write.v2.Request` for the second version, and
+				`prometheus.WriteRequest`, or no parameter at all, for the first one. Requests
+				for any other message are rejected with a `415 Unsupported Media Type` status, on
+				which senders fall back to the first version of the protocol.
+
+				The counts of written samples, histograms, and exemplars that the second version
+				of the protocol lets receivers return in response headers aren't returned.
+				"""
+		}
+
+		native_histograms: {
+			title: "Native histograms"
+			body: """
+				Native histograms are converted into aggregated histograms, whose buckets are
+				bounded by the boundaries of the exponential buckets of their schema. Negative
+				buckets are bounded by their upper boundary, and the zero bucket by its
+				threshold. The counts of float histograms are rounded to integers.
+				"""
+		}
+
+		exemplars_and_metadata: {
+			title: "Exemplars and metadata"
+			body: """
+				The help text and the unit of metric families, along with the exemplars of their
+				series, are kept in the metadata of the metrics under `prometheus.help`,
+				`prometheus.unit`, and `prometheus.exemplars`, for the
+				[`prometheus_remote_write` sink][prometheus_remote_write_sink] to forward them.
+				Exemplars are attached to the metrics of their series, regardless of their
+				timestamps, and dropped if their series has no samples.
+
+				Event metadata isn't kept by disk buffers, so exemplars and metadata are lost
+				when metrics go through one.
+
+				[prometheus_remote_write_sink]: /docs/reference/configuration/sinks/prometheus_remote_write/
+				"""
+		}
 	}
 
 	telemetry: metrics: {
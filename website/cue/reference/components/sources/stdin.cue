@@ -82,6 +82,18 @@ components: sources: stdin: {
 				Each line is read until a new line delimiter, the `0xA` byte, is found.
 				"""
 		}
+		native_events: {
+			title: "Reading events from another Vector instance"
+			body: """
+				With `decoding.codec` set to `native`, the source reads events in Vector's native protobuf
+				format, framed by their length by default, such as those written by a [`console`][console]
+				sink with `encoding.codec = "native"`. Logs, metrics, and traces are decoded with their
+				metadata, and the fields already set on logs, such as `source_type` or `host`, are left as
+				they are.
+
+				[console]: /docs/reference/configuration/sinks/console/
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -21,5 +21,6 @@ components: transforms: [Name=string]: {
 		utilization:                          components.sources.internal_metrics.output.metrics.utilization
 		component_sent_events_total:          components.sources.internal_metrics.output.metrics.component_sent_events_total
 		component_sent_event_bytes_total:     components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+		component_schema_violations_total:    components.sources.internal_metrics.output.metrics.component_schema_violations_total
 	}
 }
@@ -6,7 +6,8 @@ components: transforms: aggregate: {
 	description: """
 		Aggregates multiple metric events into a single metric event based
 		on a defined interval window. This helps to reduce metric volume at
-		the cost of granularity.
+		the cost of granularity. The values of fields of log events can also
+		be aggregated into summaries or histograms.
 		"""
 
 	classes: {
@@ -29,7 +30,7 @@ components: transforms: aggregate: {
 	configuration: base.components.transforms.aggregate.configuration
 
 	input: {
-		logs: false
+		logs: true
 		metrics: {
 			counter:      true
 			distribution: true
@@ -173,6 +174,22 @@ components: transforms: aggregate: {
 				"""
 		}
 
+		log_aggregation: {
+			title: "Log Aggregation"
+			body: """
+				With `logs` set, the numeric values of a field of log events, such as the
+				duration of requests, are aggregated over each interval, separately for each
+				combination of the rendered `name`, `namespace`, and `tags`. At the end of each
+				interval, an `absolute` `summary` with the `quantiles` of the values, or an
+				`incremental` `histogram` of the values over `buckets`, is emitted along with
+				`absolute` `gauge` metrics of the minimum and maximum values. Both the summary
+				and the histogram include the count and sum of the values.
+
+				Quantiles are computed exactly, from all of the values of the interval, so the
+				memory used grows with the number of values received during an interval.
+				"""
+		}
+
 		advantages: {
 			title: "Advantages of Use"
 			body: """
@@ -190,5 +207,6 @@ components: transforms: aggregate: {
 		aggregate_events_recorded_total: components.sources.internal_metrics.output.metrics.aggregate_events_recorded_total
 		aggregate_failed_updates:        components.sources.internal_metrics.output.metrics.aggregate_failed_updates
 		aggregate_flushes_total:         components.sources.internal_metrics.output.metrics.aggregate_flushes_total
+		component_errors_total:          components.sources.internal_metrics.output.metrics.component_errors_total
 	}
 }
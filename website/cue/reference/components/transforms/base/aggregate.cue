@@ -1,11 +1,108 @@
 package metadata
 
-base: components: transforms: aggregate: configuration: interval_ms: {
-	description: """
-		The interval between flushes, in milliseconds.
-
-		During this time frame, metrics with the same series data (name, namespace, tags, and so on) are aggregated.
-		"""
-	required: false
-	type: uint: default: 10000
+base: components: transforms: aggregate: configuration: {
+	interval_ms: {
+		description: """
+			The interval between flushes, in milliseconds.
+
+			During this time frame, metrics with the same series data (name, namespace, tags, and so on) are aggregated.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	logs: {
+		description: """
+			Metrics to aggregate from the values of a field of log events.
+
+			When set, log events are also accepted, and the values of their fields are aggregated over
+			each interval into a metric per combination of name, namespace, and tags. Log events are not
+			emitted themselves.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: object: options: {
+				buckets: {
+					description: "The upper limits of the buckets to count values in, when `mode` is `histogram`."
+					required:    false
+					type: array: {
+						default: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0]
+						items: type: float: {}
+					}
+				}
+				field: {
+					description: """
+						Name of the field in the event containing the value to aggregate.
+
+						Events where the field is missing, or isn't a number, are ignored.
+						"""
+					required: true
+					type: string: {
+						examples: ["duration_ms"]
+						syntax: "template"
+					}
+				}
+				mode: {
+					description: """
+						The type of metric to aggregate the values into.
+
+						Either way, the minimum and maximum values are also emitted as gauges, named after the metric
+						with a `_min` and `_max` suffix.
+						"""
+					required: false
+					type: string: {
+						default: "summary"
+						enum: {
+							histogram: "A histogram of the count, sum, and number of values within each of `buckets`."
+							summary:   "A summary of the count, sum, and `quantiles` of the values."
+						}
+					}
+				}
+				name: {
+					description: """
+						Overrides the name of the metric.
+
+						If not specified, `field` is used as the name of the metric.
+						"""
+					required: false
+					type: string: {
+						examples: ["http_request_duration_ms"]
+						syntax: "template"
+					}
+				}
+				namespace: {
+					description: "Sets the namespace for the metric."
+					required:    false
+					type: string: syntax: "template"
+				}
+				quantiles: {
+					description: "The quantiles to compute, when `mode` is `summary`."
+					required:    false
+					type: array: {
+						default: [0.5, 0.75, 0.9, 0.95, 0.99]
+						items: type: float: {}
+					}
+				}
+				tags: {
+					description: """
+						Tags to apply to the metric.
+
+						Values are aggregated separately for each combination of rendered tags.
+						"""
+					required: false
+					type: object: {
+						examples: [{
+							method: "{{ method }}"
+							status: "{{ status }}"
+						}]
+						options: "*": {
+							description: "A metric tag."
+							required:    true
+							type: string: syntax: "template"
+						}
+					}
+				}
+			}
+		}
+	}
 }
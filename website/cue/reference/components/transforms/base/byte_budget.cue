@@ -0,0 +1,65 @@
+package metadata
+
+base: components: transforms: byte_budget: configuration: {
+	annotation_field: {
+		description: """
+			The field in which the names of trimmed fields are recorded.
+
+			When set, an array of the trimmed field names is inserted at this field for any event that
+			was modified. The size of this annotation counts towards the event's byte budget.
+			"""
+		required: false
+		type: string: {
+			default: "_trimmed"
+			examples: ["_trimmed"]
+		}
+	}
+	drop_on_overflow: {
+		description: """
+			Whether to drop events that still exceed `max_bytes` after every configured field has been
+			trimmed.
+
+			When disabled, such events are forwarded as-is after trimming.
+			"""
+		required: false
+		type: bool: default: false
+	}
+	fields: {
+		description: """
+			The fields that may be trimmed, ordered from highest to lowest priority.
+
+			Fields are trimmed starting from the end of the list, stopping as soon as the event fits
+			within `max_bytes`. Fields not in this list are never modified.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["message", "request.headers", "stacktrace"]
+	}
+	max_bytes: {
+		description: """
+			The maximum size of an event, in bytes.
+
+			The size of an event is estimated as the size of its JSON encoding.
+			"""
+		required: true
+		type: uint: {
+			examples: [262144]
+			unit: "bytes"
+		}
+	}
+	mode: {
+		description: "How a field is trimmed when an event exceeds its byte budget."
+		required:    false
+		type: string: {
+			default: "truncate"
+			enum: {
+				drop: "Fields are removed entirely."
+				truncate: """
+					String fields are truncated by just enough bytes to fit the event within the budget.
+
+					Fields that are not strings, or that are too short for truncation alone to be enough, are
+					removed.
+					"""
+			}
+		}
+	}
+}
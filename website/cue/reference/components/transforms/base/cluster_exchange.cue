@@ -0,0 +1,158 @@
+package metadata
+
+base: components: transforms: cluster_exchange: configuration: {
+	compression: {
+		description: "Whether or not to compress events forwarded to peers."
+		required:    false
+		type: bool: default: false
+	}
+	discovery: {
+		description: """
+			How the peers of this instance are discovered.
+
+			The addresses of peers must point to their `vector` source.
+			"""
+		required: false
+		type: object: options: {
+			dns: {
+				description: """
+					A host name and port that resolves to the addresses of every peer.
+
+					Each address that the host name resolves to is a peer, listening on the given port.
+					"""
+				required: false
+				type: string: examples: ["vector-aggregator-headless.observability.svc.cluster.local:6000"]
+			}
+			peers: {
+				description: "A static list of peer addresses."
+				required:    false
+				type: array: {
+					default: []
+					items: type: string: examples: ["10.0.0.2:6000"]
+				}
+			}
+			refresh_interval_secs: {
+				description: "The interval between refreshes of the peer list, in seconds."
+				required:    false
+				type: uint: default: 10
+			}
+		}
+	}
+	key: {
+		description: """
+			The key that events are sharded by.
+
+			All events with the same key are sent to the same instance. Events for which the key can't
+			be rendered are kept by this instance.
+			"""
+		required: true
+		type: string: {
+			examples: ["{{ trace_id }}", "{{ host }}-{{ file }}"]
+			syntax: "template"
+		}
+	}
+	self_address: {
+		description: """
+			The address of this instance's `vector` source, as it appears to its peers.
+
+			This must match the address that discovery returns for this instance, such as the pod IP
+			and port when discovering peers through a Kubernetes headless service.
+			"""
+		required: true
+		type: string: examples: ["10.0.0.1:6000", "${POD_IP}:6000"]
+	}
+	tls: {
+		description: "Configures the TLS options for incoming/outgoing connections."
+		required:    false
+		type: object: options: {
+			alpn_protocols: {
+				description: """
+					Sets the list of supported ALPN protocols.
+
+					Declare the supported ALPN protocols, which are used during negotiation with peer. They are prioritized in the order
+					that they are defined.
+					"""
+				required: false
+				type: array: items: type: string: examples: ["h2"]
+			}
+			ca_file: {
+				description: """
+					Absolute path to an additional CA certificate file.
+
+					The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/certificate_authority.crt"]
+			}
+			crt_file: {
+				description: """
+					Absolute path to a certificate file used to identify this server.
+
+					The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+					an inline string in PEM format.
+
+					If this is set, and is not a PKCS#12 archive, `key_file` must also be set.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.crt"]
+			}
+			enabled: {
+				description: """
+					Whether or not to require TLS for incoming or outgoing connections.
+
+					When enabled and used for incoming connections, an identity certificate is also required. See `tls.crt_file` for
+					more information.
+					"""
+				required: false
+				type: bool: {}
+			}
+			key_file: {
+				description: """
+					Absolute path to a private key file used to identify this server.
+
+					The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: examples: ["/path/to/host_certificate.key"]
+			}
+			key_pass: {
+				description: """
+					Passphrase used to unlock the encrypted key file.
+
+					This has no effect unless `key_file` is set.
+					"""
+				required: false
+				type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+			}
+			verify_certificate: {
+				description: """
+					Enables certificate verification.
+
+					If enabled, certificates must not be expired and must be issued by a trusted
+					issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+					certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+					so on until the verification process reaches a root certificate.
+
+					Relevant for both incoming and outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+					"""
+				required: false
+				type: bool: {}
+			}
+			verify_hostname: {
+				description: """
+					Enables hostname verification.
+
+					If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+					the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+					Only relevant for outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+					"""
+				required: false
+				type: bool: {}
+			}
+		}
+	}}
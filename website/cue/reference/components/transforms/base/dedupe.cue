@@ -4,12 +4,46 @@ base: components: transforms: dedupe: configuration: {
 	cache: {
 		description: "Caching configuration for deduplication."
 		required:    false
-		type: object: options: num_events: {
-			description: "Number of events to cache and use for comparing incoming events to previously seen events."
-			required:    false
-			type: uint: default: 5000
+		type: object: options: {
+			num_events: {
+				description: "Number of events to cache and use for comparing incoming events to previously seen events."
+				required:    false
+				type: uint: default: 5000
+			}
+			persistent: {
+				description: """
+					Whether to store the cache on disk rather than in memory.
+
+					A persistent cache survives restarts, and can hold more events than would fit in memory.
+					It is stored in the `data_dir` directory, under the ID of the transform.
+					"""
+				required: false
+				type: bool: default: false
+			}
+			ttl_secs: {
+				description: """
+					The amount of time, in seconds, that an event is remembered after it was last seen.
+
+					Events seen again after this amount of time are no longer considered duplicates. By
+					default, events are remembered until they are evicted from the cache.
+					"""
+				required: false
+				type: uint: examples: [3600]
+			}
 		}
 	}
+	data_dir: {
+		description: """
+			The directory used to persist the cache, when `cache.persistent` is enabled.
+
+			By default, the [global `data_dir` option][global_data_dir] is used. Make sure the running
+			user has write permissions to this directory.
+
+			[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+			"""
+		required: false
+		type: string: examples: ["/var/local/lib/vector/"]
+	}
 	fields: {
 		description: """
 			Options to control what fields to match against.
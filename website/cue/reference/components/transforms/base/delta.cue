@@ -0,0 +1,65 @@
+package metadata
+
+base: components: transforms: delta: configuration: {
+	drop_unchanged: {
+		description: """
+			Whether to drop the events whose fields didn't change.
+
+			The first event of each key is always forwarded, whole.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	fields: {
+		description: """
+			The fields to compute the changes of.
+
+			Numeric fields are replaced by their difference with the previous value. Object fields are
+			replaced by the entries that changed, with their new value, or `null` for the entries that
+			were removed. Other fields, and fields whose type changed, are kept whole when they change.
+
+			Fields that didn't change are removed from the event.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["bytes_sent", "status"]
+	}
+	key_field: {
+		description: """
+			The name of the key that events are compared within.
+
+			Events with the same key are compared with each other, in order. If left unspecified, or if
+			the key fails to render, all events are compared with each other.
+			"""
+		required: false
+		type: string: {
+			examples: ["{{ host }}", "{{ device_id }}"]
+			syntax: "template"
+		}
+	}
+	max_keys: {
+		description: """
+			The maximum number of keys to remember the previous event of.
+
+			When exceeded, the least recently seen key is forgotten, and its next event is forwarded
+			whole, as if it were the first.
+			"""
+		required: false
+		type: uint: {
+			default: 5000
+			unit:    "keys"
+		}
+	}
+	sequence_field: {
+		description: """
+			The field that the sequence number of the event within its key is written to.
+
+			Sequence numbers start at 1 and increase with each event forwarded for the key, so that
+			consumers can detect the events they missed.
+			"""
+		required: false
+		type: string: {
+			default: "sequence"
+			examples: ["sequence"]
+		}
+	}
+}
@@ -0,0 +1,72 @@
+package metadata
+
+base: components: transforms: field_defaults: configuration: meanings: {
+	description: """
+		The defaults to fill in, keyed by the semantic meaning of the field they fill in.
+
+		The field with the meaning is looked up in the schema of each event, such that sinks
+		requiring the meaning accept the events forwarded by the transform.
+		"""
+	required: true
+	type: object: {
+		examples: [{
+			host: {
+				path:   "host"
+				source: "hostname"
+			}
+			severity: {
+				path:   "severity"
+				source: "value"
+				value:  "info"
+			}
+		}]
+		options: "*": {
+			description: "The default of the field with the meaning."
+			required:    true
+			type: object: options: {
+				field: {
+					description:   "The field to copy the default from."
+					relevant_when: "source = \"field\""
+					required:      true
+					type: string: examples: ["%kafka.timestamp"]
+				}
+				path: {
+					description: """
+						The field to fill in, for events whose schema doesn't give any field the meaning.
+
+						If left unspecified, such events are forwarded unchanged.
+						"""
+					required: false
+					type: string: examples: ["severity"]
+				}
+				source: {
+					description: "Where the default comes from."
+					required:    true
+					type: string: enum: {
+						field: """
+							The value of another field of the event, or of its metadata.
+
+							If the other field is missing too, the field is left missing.
+							"""
+						hostname: "The hostname of the machine running Vector."
+						now:      "The time the event is processed by the transform."
+						value: """
+							A string, rendered from a template.
+
+							If the template fails to render, the field is left missing.
+							"""
+					}
+				}
+				value: {
+					description:   "The template of the default."
+					relevant_when: "source = \"value\""
+					required:      true
+					type: string: {
+						examples: ["info", "{{ service }}"]
+						syntax: "template"
+					}
+				}
+			}
+		}
+	}
+}
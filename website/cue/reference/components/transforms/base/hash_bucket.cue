@@ -0,0 +1,90 @@
+package metadata
+
+base: components: transforms: hash_bucket: configuration: {
+	bucket_field: {
+		description: "The field that the bucket of the event is written to."
+		required:    false
+		type: string: {
+			default: "bucket"
+			examples: ["experiment.bucket"]
+		}
+	}
+	buckets: {
+		description: """
+			The number of buckets.
+
+			Events are assigned to the buckets from `0` to `buckets - 1`.
+			"""
+		required: false
+		type: uint: {
+			default: 100
+			examples: [1000]
+		}
+	}
+	cohort_field: {
+		description: """
+			The field that the name of the cohort of the event is written to.
+
+			Events whose bucket isn't part of any cohort are forwarded without a cohort.
+			"""
+		required: false
+		type: string: {
+			default: "cohort"
+			examples: ["experiment.cohort"]
+		}
+	}
+	cohorts: {
+		description: """
+			The cohorts, as named ranges of buckets.
+
+			The ranges of the cohorts must be within the buckets, and must not overlap.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: object: options: {
+				end: {
+					description: "The last bucket of the cohort, inclusive."
+					required:    true
+					type: uint: {}
+				}
+				name: {
+					description: "The name of the cohort, written to `cohort_field` for the events in its buckets."
+					required:    true
+					type: string: examples: ["control"]
+				}
+				start: {
+					description: "The first bucket of the cohort."
+					required:    true
+					type: uint: {}
+				}
+			}
+		}
+	}
+	key: {
+		description: """
+			The key that is hashed to assign events to buckets.
+
+			Events with the same key are always assigned to the same bucket. Events whose key fails to
+			render are forwarded without a bucket.
+			"""
+		required: true
+		type: string: {
+			examples: ["{{ user_id }}", "{{ user_id }}-checkout-experiment"]
+			syntax: "template"
+		}
+	}
+	seed: {
+		description: """
+			The seed of the hash.
+
+			Changing the seed reshuffles the events between the buckets, which gives independent
+			assignments to experiments using the same key.
+			"""
+		required: false
+		type: uint: {
+			default: 0
+			examples: [42]
+		}
+	}
+}
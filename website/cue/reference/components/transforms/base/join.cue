@@ -0,0 +1,78 @@
+package metadata
+
+base: components: transforms: join: configuration: {
+	flush_period_ms: {
+		description: "The interval to check for and flush any expired joins, in milliseconds."
+		required:    false
+		type: uint: {
+			default: 1000
+			unit:    "milliseconds"
+		}
+	}
+	join_by: {
+		description: """
+			An ordered list of fields by which to join events.
+
+			Events are joined when they have the same values for all of these fields. Events missing
+			any of these fields are dropped.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["request_id"]
+	}
+	max_pending: {
+		description: "The maximum number of joins waiting for the events of other streams."
+		required:    false
+		type: uint: default: 10000
+	}
+	overflow: {
+		description: "What to do when the maximum number of pending joins is reached."
+		required:    false
+		type: string: {
+			default: "evict_oldest"
+			enum: {
+				drop_newest:  "Drop the events that would start a new join."
+				evict_oldest: "End the window of the oldest pending join early, handling it according to `unmatched`."
+			}
+		}
+	}
+	streams: {
+		description: """
+			The streams of events to join, by name.
+
+			Each event belongs to the first stream, in order, whose condition it matches. Events that
+			match no stream are dropped. At least two streams are required.
+			"""
+		required: true
+		type: object: options: "*": {
+			description: "An individual stream of events to join."
+			required:    true
+			type: object: options: condition: {
+				description: "The condition that events of the stream match."
+				required:    true
+				type: condition: {}
+			}
+		}
+	}
+	unmatched: {
+		description: "What to do with joins that are still incomplete when their window ends."
+		required:    false
+		type: string: {
+			default: "drop"
+			enum: {
+				drop: "Drop the events of the join."
+				emit: "Emit the join with the events of the streams received so far."
+			}
+		}
+	}
+	window_ms: {
+		description: """
+			The maximum period of time to wait for the events of all streams, in milliseconds, after the
+			first event of a join is received.
+			"""
+		required: false
+		type: uint: {
+			default: 30000
+			unit:    "milliseconds"
+		}
+	}
+}
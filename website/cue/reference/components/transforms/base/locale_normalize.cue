@@ -0,0 +1,129 @@
+package metadata
+
+base: components: transforms: locale_normalize: configuration: {
+	fields: {
+		description: "The fields to normalize, in order."
+		required:    true
+		type: array: items: type: object: options: {
+			currency_field: {
+				description: """
+					The field that the currency symbol or code of the value is written to, as written in
+					the value.
+					"""
+				relevant_when: "type = \"number\""
+				required:      false
+				type: string: examples: ["currency"]
+			}
+			epoch_unit: {
+				description: """
+					The unit of Unix timestamps.
+
+					If left unspecified, the unit is detected from the magnitude of the timestamp, which is
+					unambiguous for dates between 1973 and 5138.
+					"""
+				relevant_when: "type = \"timestamp\""
+				required:      false
+				type: string: enum: {
+					ms: "Milliseconds."
+					ns: "Nanoseconds."
+					s:  "Seconds."
+					us: "Microseconds."
+				}
+			}
+			field: {
+				description: "The field containing the values to normalize."
+				required:    true
+				type: string: examples: ["amount", "order.created"]
+			}
+			formats: {
+				description: """
+					The formats of dates, in the [`strftime`][strftime] syntax, tried in order.
+
+					Names of months and days of the week in the locales are translated to English before
+					parsing, such that `%B` and `%A` match `März` and `Dienstag`. If left empty, common
+					formats such as RFC 3339 are detected.
+
+					[strftime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers
+					"""
+				relevant_when: "type = \"timestamp\""
+				required:      false
+				type: array: {
+					default: []
+					items: type: string: examples: ["%d. %B %Y %H:%M", "%d/%m/%Y"]
+				}
+			}
+			locales: {
+				description: "The locales of the values of the field, overriding the locales of the transform."
+				required:    false
+				type: array: items: type: string: enum: {
+					de: "German, such as `1.234,5` and `12. März`."
+					en: "English, such as `1,234.5` and `March 12`."
+					es: "Spanish, such as `1.234,5` and `12 de marzo`."
+					fr: "French, such as `1 234,5` and `12 mars`."
+					it: "Italian, such as `1.234,5` and `12 marzo`."
+					nl: "Dutch, such as `1.234,5` and `12 maart`."
+					pt: "Portuguese, such as `1.234,5` and `12 de março`."
+				}
+			}
+			target: {
+				description: """
+					The field that the normalized value is written to.
+
+					Defaults to `field`, which is overwritten.
+					"""
+				required: false
+				type: string: examples: ["amount_normalized"]
+			}
+			type: {
+				description: "The type that values are parsed into."
+				required:    true
+				type: string: enum: {
+					number: """
+						An integer, or a float if the value has a fractional part.
+
+						Digit group separators are removed, and so are currency symbols and codes such as `€` or
+						`USD`.
+						"""
+					timestamp: """
+						A timestamp.
+
+						Numbers, and strings of digits, are read as Unix timestamps.
+						"""
+				}
+			}
+		}
+	}
+	locales: {
+		description: """
+			The locales that values are written in, tried in order until one of them parses the value.
+
+			The order matters for ambiguous values: `1.234` is `1.234` in English, but `1234` in German.
+			"""
+		required: false
+		type: array: {
+			default: ["en"]
+			items: type: string: enum: {
+				de: "German, such as `1.234,5` and `12. März`."
+				en: "English, such as `1,234.5` and `March 12`."
+				es: "Spanish, such as `1.234,5` and `12 de marzo`."
+				fr: "French, such as `1 234,5` and `12 mars`."
+				it: "Italian, such as `1.234,5` and `12 marzo`."
+				nl: "Dutch, such as `1.234,5` and `12 maart`."
+				pt: "Portuguese, such as `1.234,5` and `12 de março`."
+			}
+		}
+	}
+	timezone: {
+		description: """
+			The name of the time zone to apply to dates that do not contain an explicit time zone.
+
+			This overrides the [global `timezone`][global_timezone] option. The time zone name may be
+			any name in the [TZ database][tz_database] or `local` to indicate system local time.
+
+			[global_timezone]: https://vector.dev/docs/reference/configuration//global-options#timezone
+			[tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+			"""
+		required: false
+		type: string: examples: ["local", "America/New_York", "EST5EDT"]
+	}
+}
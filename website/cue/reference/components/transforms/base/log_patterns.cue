@@ -0,0 +1,93 @@
+package metadata
+
+base: components: transforms: log_patterns: configuration: {
+	field: {
+		description: "The field containing the message to cluster."
+		required:    false
+		type: string: {
+			default: "message"
+			examples: ["message", "error.message"]
+		}
+	}
+	max_children: {
+		description: """
+			The maximum number of children of a node of the parse tree.
+
+			Once a node is full, messages with other tokens at that position are routed together.
+			"""
+		required: false
+		type: uint: default: 100
+	}
+	max_depth: {
+		description: """
+			The depth of the parse tree that messages are routed through.
+
+			Messages are only compared to patterns with the same number of tokens, and the same first
+			`max_depth - 2` tokens. Tokens containing digits are treated as parameters when routing.
+			"""
+		required: false
+		type: uint: default: 4
+	}
+	max_patterns: {
+		description: """
+			The maximum number of patterns kept.
+
+			When full, the least recently matched pattern is forgotten to make room for a new one.
+			"""
+		required: false
+		type: uint: default: 1000
+	}
+	metrics: {
+		description: "Configuration of the per-pattern count metrics."
+		required:    false
+		type: object: options: {
+			flush_interval_secs: {
+				description: """
+					The interval between flushes of the counts, in seconds.
+
+					A counter is emitted for every pattern that was matched since the previous flush, tagged
+					with the `pattern_id` and `pattern` of the pattern.
+					"""
+				required: false
+				type: uint: {
+					default: 60
+					unit:    "seconds"
+				}
+			}
+			name: {
+				description: "The name of the counter."
+				required:    false
+				type: string: default: "log_pattern_events_total"
+			}
+			namespace: {
+				description: "The namespace of the counter."
+				required:    false
+				type: string: examples: ["service"]
+			}
+		}
+	}
+	similarity_threshold: {
+		description: """
+			The minimum share of tokens a message must have in common with a pattern to be assigned
+			to it.
+
+			Lower values produce fewer, more general patterns.
+			"""
+		required: false
+		type: float: default: 0.4
+	}
+	target: {
+		description: """
+			The field that the pattern is written to.
+
+			The pattern is an object with the `id` of the pattern, its `template`, and the `parameters`
+			of the message, which are the tokens of the message in place of the wildcards of the
+			template.
+			"""
+		required: false
+		type: string: {
+			default: "pattern"
+			examples: ["pattern"]
+		}
+	}
+}
@@ -0,0 +1,119 @@
+package metadata
+
+base: components: transforms: pii_redact: configuration: {
+	detectors: {
+		description: """
+			The detectors to apply, in order.
+
+			Each detector sees the values as redacted by the detectors before it.
+			"""
+		required: true
+		type: array: items: type: object: options: {
+			action: {
+				description: "What is done with the information found by a detector."
+				required:    false
+				type: string: {
+					default: "mask"
+					enum: {
+						drop_field: "Removes the field containing the information."
+						hash: """
+							Replaces the information with the hex-encoded SHA-256 hash of the `hash_salt` followed by
+							the information.
+
+							Unlike masking, this preserves the ability to correlate events carrying the same
+							information.
+							"""
+						mask: "Replaces the information with the `mask`."
+						reroute: """
+							Sends the event to the `rerouted` output, leaving the information in place.
+
+							The other detectors still apply to the event.
+							"""
+					}
+				}
+			}
+			case_sensitive: {
+				description:   "Whether or not the words are matched case sensitively."
+				relevant_when: "type = \"dictionary\""
+				required:      false
+				type: bool: default: false
+			}
+			name: {
+				description: """
+					The name of the detector, used as the `detector` tag of its metrics.
+
+					Defaults to the type of the detector.
+					"""
+				required: false
+				type: string: examples: ["employee_id"]
+			}
+			pattern: {
+				description:   "The regular expression to match."
+				relevant_when: "type = \"regex\""
+				required:      true
+				type: string: examples: ["\\bEMP-\\d{6}\\b"]
+			}
+			type: {
+				description: "The kind of detector."
+				required:    true
+				type: string: enum: {
+					credit_card: """
+						Payment card numbers, of 13 to 19 digits optionally separated by spaces or dashes, that
+						pass the Luhn checksum.
+						"""
+					dictionary: "Occurrences of words from a dictionary."
+					email:      "Email addresses."
+					ip_address: "IPv4 and IPv6 addresses."
+					national_id: """
+						National identification numbers.
+
+						Currently, United States Social Security numbers in the `AAA-GG-SSSS` format.
+						"""
+					phone_number: """
+						Phone numbers, in the international format, such as `+44 20 7946 0958`, or in the North
+						American format, such as `(555) 123-4567`.
+						"""
+					regex: "Matches of a regular expression."
+				}
+			}
+			words: {
+				description: """
+					The words to look for.
+
+					Words only match as whole words.
+					"""
+				relevant_when: "type = \"dictionary\""
+				required:      true
+				type: array: items: type: string: examples: ["project-falcon"]
+			}
+		}
+	}
+	fields: {
+		description: """
+			The fields to scan.
+
+			If empty, all the string values of the event are scanned, including those nested in
+			objects and arrays.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["message", "request.query"]
+		}
+	}
+	hash_salt: {
+		description: """
+			The salt that hashed information is prefixed with before being hashed.
+
+			Without a salt, the hashes of information with few possible values, such as phone numbers,
+			can be reversed by hashing all of the values.
+			"""
+		required: false
+		type: string: {}
+	}
+	mask: {
+		description: "The text that masked information is replaced with."
+		required:    false
+		type: string: default: "[REDACTED]"
+	}
+}
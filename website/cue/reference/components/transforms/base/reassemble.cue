@@ -0,0 +1,82 @@
+package metadata
+
+base: components: transforms: reassemble: configuration: {
+	flush_period_ms: {
+		description: "The interval to check for and flush any expired messages, in milliseconds."
+		required:    false
+		type: uint: {
+			default: 1000
+			unit:    "milliseconds"
+		}
+	}
+	group_by: {
+		description: """
+			An ordered list of fields identifying the message that an event is a part of.
+
+			Parts are reassembled when they have the same values for all of these fields.
+			"""
+		required: true
+		type: array: items: type: string: examples: ["message_id"]
+	}
+	incomplete: {
+		description: "What to do with messages that are still missing parts when their timeout ends."
+		required:    false
+		type: string: {
+			default: "emit"
+			enum: {
+				drop: "Drop the parts of the message."
+				emit: "Emit the message reassembled from the parts received so far."
+			}
+		}
+	}
+	max_pending: {
+		description: """
+			The maximum number of messages waiting for their remaining parts.
+
+			When reached, the oldest pending message is flushed early, according to `incomplete`.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	merge_field: {
+		description: "The field whose values are concatenated, in the order of the parts."
+		required:    false
+		type: string: {
+			default: "message"
+			examples: ["message"]
+		}
+	}
+	part_field: {
+		description: """
+			The field containing the number of the part, by which parts are ordered.
+
+			Parts may be numbered from any integer, and may be received in any order.
+			"""
+		required: true
+		type: string: examples: ["part"]
+	}
+	separator: {
+		description: "The string inserted between the values of consecutive parts."
+		required:    false
+		type: string: {
+			default: ""
+			examples: ["\n"]
+		}
+	}
+	timeout_ms: {
+		description: """
+			The maximum period of time to wait for all the parts of a message, in milliseconds, after
+			its first part is received.
+			"""
+		required: false
+		type: uint: {
+			default: 30000
+			unit:    "milliseconds"
+		}
+	}
+	total_field: {
+		description: "The field containing the total number of parts of the message."
+		required:    true
+		type: string: examples: ["total"]
+	}
+}
@@ -30,6 +30,17 @@ base: components: transforms: reduce: configuration: {
 			unit:    "milliseconds"
 		}
 	}
+	flush_condition: {
+		description: """
+			A condition used to distinguish the complete combined events.
+
+			Unlike `ends_when`, this condition is checked against the combined event of the group,
+			after each event is merged into it. If it resolves to `true`, the group is immediately
+			flushed.
+			"""
+		required: false
+		type: condition: {}
+	}
 	group_by: {
 		description: """
 			An ordered list of fields by which to group events.
@@ -47,11 +58,31 @@ base: components: transforms: reduce: configuration: {
 			items: type: string: examples: ["request_id", "user_id", "transaction_id"]
 		}
 	}
+	max_bytes: {
+		description: """
+			The maximum size of the events to group together, in bytes.
+
+			The size of a group is the sum of the estimated JSON encoded sizes of its events. The group
+			is flushed as soon as its size reaches this limit.
+			"""
+		required: false
+		type: uint: unit: "bytes"
+	}
 	max_events: {
 		description: "The maximum number of events to group together."
 		required:    false
 		type: uint: {}
 	}
+	max_groups: {
+		description: """
+			The maximum number of groups to reduce at the same time.
+
+			When an event starts a new group while this limit is reached, the group that was updated
+			least recently is evicted, and flushed as is, to make room for the new group.
+			"""
+		required: false
+		type: uint: {}
+	}
 	merge_strategies: {
 		description: """
 			A map of field names to custom merge strategies.
@@ -90,6 +121,16 @@ base: components: transforms: reduce: configuration: {
 			}
 		}
 	}
+	reroute_evicted: {
+		description: """
+			Whether or not to send the events of the groups evicted by `max_groups` to the `evicted`
+			output, instead of the default output.
+
+			This allows telling the events that may have been truncated apart from the complete ones.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	starts_when: {
 		description: """
 			A condition used to distinguish the first event of a transaction.
@@ -1,6 +1,55 @@
 package metadata
 
 base: components: transforms: remap: configuration: {
+	cache: {
+		description: """
+			Caches the results of the program for events with identical key fields.
+
+			When many events share the same inputs, such as a user agent or an IP address, caching
+			avoids running expensive parsing or lookups over and over.
+			"""
+		required: false
+		type: object: options: {
+			fields: {
+				description: """
+					The fields set by the program that are cached.
+
+					On a cache hit, the program isn't run, and these fields are set to their cached values
+					instead. Fields that the program removed are removed again. Any other change made by the
+					program is not applied.
+					"""
+				required: true
+				type: array: items: type: string: examples: ["agent", "geoip"]
+			}
+			key_fields: {
+				description: """
+					The fields whose values make up the cache key.
+
+					Events with the same values for all of these fields are assumed to produce the same
+					values for `fields`. Missing fields are part of the key.
+					"""
+				required: true
+				type: array: items: type: string: examples: ["user_agent", "client.ip"]
+			}
+			max_entries: {
+				description: """
+					The maximum number of results kept in the cache.
+
+					Once full, the least recently used result is evicted.
+					"""
+				required: false
+				type: uint: default: 10000
+			}
+			ttl_secs: {
+				description: "How long, in seconds, a cached result is used before the program is run again."
+				required:    false
+				type: uint: {
+					default: 300
+					unit:    "seconds"
+				}
+			}
+		}
+	}
 	drop_on_abort: {
 		description: """
 			Drops any event that is manually aborted during processing.
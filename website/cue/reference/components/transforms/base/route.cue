@@ -1,20 +1,42 @@
 package metadata
 
-base: components: transforms: route: configuration: route: {
-	description: """
-		A table of route identifiers to logical conditions representing the filter of the route.
+base: components: transforms: route: configuration: {
+	mode: {
+		description: "How events are delivered to the routes they match."
+		required:    false
+		type: string: {
+			default: "all"
+			enum: {
+				all:   "Events are sent to all of the routes they match."
+				first: "Events are only sent to the first route they match, in the order of `route`."
+			}
+		}
+	}
+	reroute_unmatched: {
+		description: """
+			Whether or not to send the events that don't match any route to the `_unmatched` output.
+
+			When disabled, those events are dropped, and the `_unmatched` output doesn't exist.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	route: {
+		description: """
+			A table of route identifiers to logical conditions representing the filter of the route.
 
-		Each route can then be referenced as an input by other components with the name
-		`<transform_name>.<route_id>`. If an event doesn’t match any route, it is sent to the
-		`<transform_name>._unmatched` output.
+			Each route can then be referenced as an input by other components with the name
+			`<transform_name>.<route_id>`. If an event doesn’t match any route, it is sent to the
+			`<transform_name>._unmatched` output, unless `reroute_unmatched` is disabled.
 
-		Both `_unmatched`, as well as `_default`, are reserved output names and thus cannot be used
-		as a route name.
-		"""
-	required: false
-	type: object: options: "*": {
-		description: "An individual route."
-		required:    true
-		type: condition: {}
+			Both `_unmatched`, as well as `_default`, are reserved output names and thus cannot be used
+			as a route name.
+			"""
+		required: false
+		type: object: options: "*": {
+			description: "An individual route."
+			required:    true
+			type: condition: {}
+		}
 	}
 }
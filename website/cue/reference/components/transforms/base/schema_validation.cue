@@ -0,0 +1,19 @@
+package metadata
+
+base: components: transforms: schema_validation: configuration: {
+	max_violations: {
+		description: "The maximum number of violations recorded for an invalid event."
+		required:    false
+		type: uint: default: 10
+	}
+	schema_file: {
+		description: """
+			The path of the JSON Schema file to validate events against.
+
+			The schema is read when the transform is built, and follows the 2020-12 draft of JSON
+			Schema unless it declares another draft with `$schema`.
+			"""
+		required: true
+		type: string: examples: ["/etc/vector/schemas/orders.json"]
+	}
+}
@@ -0,0 +1,50 @@
+package metadata
+
+base: components: transforms: span_metrics: configuration: {
+	buckets: {
+		description: "The upper limits of the buckets of the duration histogram, in seconds."
+		required:    false
+		type: array: {
+			default: [0.002, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0]
+			items: type: float: {}
+		}
+	}
+	dimensions: {
+		description: """
+			Additional tags to apply to the metrics, from the values of fields of the spans.
+
+			Metrics are always tagged with the `service` and the `operation` of the spans, and are
+			aggregated separately for each combination of tags. Spans where a field is missing don't
+			have the corresponding tag.
+			"""
+		required: false
+		type: object: {
+			examples: [{
+				resource:    "resource"
+				status_code: "meta.\"http.status_code\""
+			}]
+			options: "*": {
+				description: "A span field."
+				required:    true
+				type: string: {}
+			}
+		}
+	}
+	flush_period_ms: {
+		description: """
+			The interval between flushes of the metrics, in milliseconds.
+
+			During this time frame, spans with the same tags are aggregated into the same metrics.
+			"""
+		required: false
+		type: uint: {
+			default: 10000
+			unit:    "milliseconds"
+		}
+	}
+	namespace: {
+		description: "Sets the namespace of the metrics."
+		required:    false
+		type: string: examples: ["traces"]
+	}
+}
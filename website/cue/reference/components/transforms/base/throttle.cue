@@ -1,6 +1,16 @@
 package metadata
 
 base: components: transforms: throttle: configuration: {
+	burst: {
+		description: """
+			The number of events allowed at once for a given bucket.
+
+			Each unique key has its own bucket, which refills at the rate of `threshold` events per
+			`window_secs`, up to `burst` events. If left unspecified, `threshold` is used.
+			"""
+		required: false
+		type: uint: examples: [100]
+	}
 	exclude: {
 		description: "A logical condition used to exclude events from sampling."
 		required:    false
@@ -21,6 +31,16 @@ base: components: transforms: throttle: configuration: {
 			syntax: "template"
 		}
 	}
+	reroute_overflow: {
+		description: """
+			Reroutes rate limited events to a named output instead of dropping them.
+
+			When set to `true`, events that exceed the rate limit are sent to a specially-named
+			output, `overflow`, rather than being discarded.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	threshold: {
 		description: """
 			The number of events allowed for a given bucket per configured `window_secs`.
@@ -0,0 +1,87 @@
+package metadata
+
+base: components: transforms: unit_convert: configuration: rules: {
+	description: "The conversions to apply, in order."
+	required:    true
+	type: array: items: type: object: options: {
+		field: {
+			description: "The field containing the values to convert."
+			required:    true
+			type: string: examples: ["duration", "response.size"]
+		}
+		from: {
+			description: """
+				The unit of numeric values, and of strings without a unit suffix.
+
+				Strings with a unit suffix, such as `12ms` or `3.4GB`, are converted from the unit of their
+				suffix instead, when `parse_suffix` is enabled.
+				"""
+			required: false
+			type: string: enum: {
+				B:          "Bytes."
+				GB:         "Gigabytes, of 1000 megabytes."
+				GiB:        "Gibibytes, of 1024 mebibytes."
+				KiB:        "Kibibytes, of 1024 bytes."
+				MB:         "Megabytes, of 1000 kilobytes."
+				MiB:        "Mebibytes, of 1024 kibibytes."
+				TB:         "Terabytes, of 1000 gigabytes."
+				TiB:        "Tebibytes, of 1024 gibibytes."
+				celsius:    "Degrees Celsius."
+				d:          "Days."
+				fahrenheit: "Degrees Fahrenheit."
+				h:          "Hours."
+				kB:         "Kilobytes, of 1000 bytes."
+				kelvin:     "Kelvins."
+				min:        "Minutes."
+				ms:         "Milliseconds."
+				ns:         "Nanoseconds."
+				s:          "Seconds."
+				us:         "Microseconds."
+			}
+		}
+		parse_suffix: {
+			description: "Whether or not to parse the unit suffix of string values."
+			required:    false
+			type: bool: default: true
+		}
+		target: {
+			description: """
+				The field that the converted value is written to.
+
+				Defaults to `field`, which is overwritten.
+				"""
+			required: false
+			type: string: examples: ["duration_seconds"]
+		}
+		to: {
+			description: "A unit of measurement."
+			required:    true
+			type: string: enum: {
+				B:          "Bytes."
+				GB:         "Gigabytes, of 1000 megabytes."
+				GiB:        "Gibibytes, of 1024 mebibytes."
+				KiB:        "Kibibytes, of 1024 bytes."
+				MB:         "Megabytes, of 1000 kilobytes."
+				MiB:        "Mebibytes, of 1024 kibibytes."
+				TB:         "Terabytes, of 1000 gigabytes."
+				TiB:        "Tebibytes, of 1024 gibibytes."
+				celsius:    "Degrees Celsius."
+				d:          "Days."
+				fahrenheit: "Degrees Fahrenheit."
+				h:          "Hours."
+				kB:         "Kilobytes, of 1000 bytes."
+				kelvin:     "Kelvins."
+				min:        "Minutes."
+				ms:         "Milliseconds."
+				ns:         "Nanoseconds."
+				s:          "Seconds."
+				us:         "Microseconds."
+			}
+		}
+		unit_field: {
+			description: "The field that the unit of the converted value is written to."
+			required:    false
+			type: string: examples: ["duration_unit"]
+		}
+	}
+}
@@ -0,0 +1,111 @@
+package metadata
+
+base: components: transforms: url_normalize: configuration: {
+	allowed_query_parameters: {
+		description: """
+			The query parameters kept in the normalized URL, along with their values.
+
+			All the other query parameters, and the fragment, are stripped.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: examples: ["format", "version"]
+		}
+	}
+	collapse_ids: {
+		description: """
+			Whether to collapse the path segments holding IDs into placeholders.
+
+			UUIDs are replaced by `uuid_placeholder`, and numbers and hexadecimal IDs of at least 16
+			digits, such as hashes, by `id_placeholder`.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	id_placeholder: {
+		description: "The placeholder replacing the path segments holding numbers and hexadecimal IDs."
+		required:    false
+		type: string: {
+			default: "{id}"
+			examples: [":id"]
+		}
+	}
+	normalized_url_field: {
+		description: "The field that the normalized URL is written to."
+		required:    false
+		type: string: {
+			default: "normalized_url"
+			examples: ["request.route"]
+		}
+	}
+	segment_patterns: {
+		description: """
+			Additional patterns of path segments collapsed into placeholders.
+
+			The patterns are tried in order, before the IDs are collapsed.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: object: options: {
+				pattern: {
+					description: """
+						The regular expression that path segments are matched against.
+
+						Only segments matched as a whole are collapsed.
+						"""
+					required: true
+					type: string: examples: ["[a-z]{2}-[A-Z]{2}", "[A-Z]{3}-\\d+"]
+				}
+				placeholder: {
+					description: "The placeholder replacing the matching segments."
+					required:    true
+					type: string: examples: ["{locale}"]
+				}
+			}
+		}
+	}
+	status_class_field: {
+		description: "The field that the class of the status code, such as `2xx` or `5xx`, is written to."
+		required:    false
+		type: string: {
+			default: "status_class"
+			examples: ["response.status_class"]
+		}
+	}
+	status_field: {
+		description: """
+			The field holding the HTTP status code of the response.
+
+			Both integers and strings are supported. Events without a valid status code, between `100`
+			and `599`, are forwarded without a status class.
+			"""
+		required: false
+		type: string: {
+			default: "status"
+			examples: ["response.status"]
+		}
+	}
+	url_field: {
+		description: """
+			The field holding the URL of the request.
+
+			Both absolute URLs and paths, with or without a query, are normalized. Events without a
+			URL are forwarded without a normalized URL.
+			"""
+		required: false
+		type: string: {
+			default: "url"
+			examples: ["request.url"]
+		}
+	}
+	uuid_placeholder: {
+		description: "The placeholder replacing the path segments holding UUIDs."
+		required:    false
+		type: string: {
+			default: "{uuid}"
+			examples: [":uuid"]
+		}
+	}
+}
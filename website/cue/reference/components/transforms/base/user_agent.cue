@@ -0,0 +1,55 @@
+package metadata
+
+base: components: transforms: user_agent: configuration: {
+	cache_size: {
+		description: """
+			The number of distinct user agents whose classification is cached.
+
+			Most traffic comes from a small number of user agents, so caching their classification
+			avoids matching the same string against the ruleset over and over. The cache is cleared
+			whenever the ruleset is reloaded.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	field: {
+		description: "The field containing the user agent string."
+		required:    true
+		type: string: examples: ["user_agent", "request.headers.user_agent"]
+	}
+	path: {
+		description: """
+			The path to the ruleset, in the [uap-core][uap_core] `regexes.yaml` format.
+
+			[uap_core]: https://github.com/ua-parser/uap-core/blob/master/regexes.yaml
+			"""
+		required: true
+		type: string: examples: ["/etc/vector/regexes.yaml"]
+	}
+	refresh_interval_secs: {
+		description: """
+			The interval between checks for changes to the ruleset, in seconds.
+
+			On every check, the ruleset is re-read from `path` if the file has been modified. The new
+			ruleset is swapped in atomically, and if it fails to load, the previously loaded ruleset
+			continues to be used.
+
+			When unset, the ruleset is only reloaded on configuration reload.
+			"""
+		required: false
+		type: uint: {
+			examples: [3600]
+			unit: "seconds"
+		}
+	}
+	target: {
+		description: """
+			The field that the classification is written to.
+
+			The classification is an object with `browser`, `os` and `device` objects, and an `is_bot`
+			boolean.
+			"""
+		required: true
+		type: string: examples: ["user_agent_info"]
+	}
+}
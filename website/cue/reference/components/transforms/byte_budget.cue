@@ -0,0 +1,62 @@
+package metadata
+
+components: transforms: byte_budget: {
+	title: "Byte Budget"
+
+	description: """
+		Trims or drops lower-priority fields until an event fits within a configured byte budget,
+		recording which fields were trimmed.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		shape: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.byte_budget.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		trimming_order: {
+			title: "Trimming Order"
+			body: """
+				Fields listed in `fields` are ordered from highest to lowest priority. When an event
+				exceeds `max_bytes`, fields are trimmed starting from the end of the list, and trimming
+				stops as soon as the event fits. In `truncate` mode, string fields are shortened by just
+				enough bytes to fit the budget, while other fields are removed. In `drop` mode, fields are
+				always removed.
+				"""
+		}
+
+		size_estimation: {
+			title: "Size Estimation"
+			body: """
+				The size of an event is estimated as the size of its JSON encoding, without inspecting
+				individual string bytes for escaping. Sinks that encode events differently, or add
+				their own envelope, may need a smaller `max_bytes` than their hard per-entry limit.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		byte_budget_exceeded_total:       components.sources.internal_metrics.output.metrics.byte_budget_exceeded_total
+		byte_budget_fields_trimmed_total: components.sources.internal_metrics.output.metrics.byte_budget_fields_trimmed_total
+	}
+}
@@ -0,0 +1,112 @@
+package metadata
+
+components: transforms: cluster_exchange: {
+	title: "Cluster Exchange"
+
+	description: """
+		Shards events by key across a cluster of Vector aggregators, so that stateful transforms
+		such as `reduce` and `dedupe` see every event for a key on the same instance.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		shape: {}
+	}
+
+	support: {
+		requirements: [
+			"""
+				Every instance in the cluster must run a `vector` source at the address used by its
+				peers, and that source must not be an input of the `cluster_exchange` transform.
+				""",
+		]
+		warnings: [
+			"""
+				Events for a key are only routed consistently while every instance agrees on the
+				cluster membership. While peers are joining or leaving, some keys can briefly be
+				processed by more than one instance.
+				""",
+		]
+		notices: []
+	}
+
+	configuration: base.components.transforms.cluster_exchange.configuration
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: true
+	}
+
+	how_it_works: {
+		topology: {
+			title: "Topology"
+			body: """
+				Each instance routes its input through `cluster_exchange`, which passes on the events
+				whose key it owns and forwards the rest to their owners using the Vector protocol. The
+				stateful transform takes its input from both the `cluster_exchange` transform and a
+				`vector` source that receives the events forwarded by peers:
+
+				```toml
+				[sources.peers]
+				type = "vector"
+				address = "0.0.0.0:6000"
+
+				[transforms.shard]
+				type = "cluster_exchange"
+				inputs = ["agents"]
+				key = "{{ trace_id }}"
+				self_address = "${POD_IP}:6000"
+				discovery.dns = "vector-aggregator-headless:6000"
+
+				[transforms.traces]
+				type = "reduce"
+				inputs = ["shard", "peers"]
+				group_by = ["trace_id"]
+				```
+
+				Forwarded events are never exchanged again, so they must not be routed back into
+				`cluster_exchange`.
+				"""
+		}
+
+		ownership: {
+			title: "Key Ownership"
+			body: """
+				Keys are assigned to instances by rendezvous hashing over the addresses of the current
+				members, which are the static `discovery.peers`, the addresses that `discovery.dns`
+				resolves to, and `self_address`. When an instance joins or leaves, only the keys it
+				gains or loses change owner. Membership is refreshed every
+				`discovery.refresh_interval_secs`; if discovery fails, the previous members are kept.
+				"""
+		}
+
+		delivery: {
+			title: "Delivery"
+			body: """
+				Events forwarded to a peer are batched and retried like the `vector` sink, and
+				end-to-end acknowledgements are passed along with them. If a peer is unavailable,
+				events for its keys are buffered and eventually apply backpressure until it comes back
+				or discovery removes it from the cluster.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		cluster_events_forwarded_total: components.sources.internal_metrics.output.metrics.cluster_events_forwarded_total
+		cluster_peers:                  components.sources.internal_metrics.output.metrics.cluster_peers
+	}
+}
@@ -47,6 +47,23 @@ components: transforms: dedupe: {
 				"""
 		}
 
+		persistent_cache: {
+			title: "Persistent Cache"
+			body: """
+				By default, the cache is held in memory, so it is lost when Vector
+				restarts, and events seen before the restart are no longer considered
+				duplicates. With `cache.persistent` enabled, the cache is stored on
+				disk instead, in the `data_dir` directory, so that it survives
+				restarts and can hold more than `cache.num_events` would allow in
+				memory. Only a digest of the fields being considered for matching is
+				stored for each event.
+
+				With `cache.ttl_secs` set, events are only considered duplicates of
+				events seen within that many seconds, and older entries are removed
+				from the persistent cache.
+				"""
+		}
+
 		memory_usage_details: {
 			title: "Memory Usage Details"
 			body: """
@@ -95,6 +112,7 @@ components: transforms: dedupe: {
 	}
 
 	telemetry: metrics: {
+		component_errors_total: components.sources.internal_metrics.output.metrics.component_errors_total
 		events_discarded_total: components.sources.internal_metrics.output.metrics.events_discarded_total
 	}
 }
@@ -0,0 +1,96 @@
+package metadata
+
+components: transforms: delta: {
+	title: "Delta"
+
+	description: """
+		Replaces fields with their changes since the previous event with the same key,
+		and drops the events without changes, turning a stream of snapshots into a
+		change-only feed.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.delta.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Forward the changes of device readings"
+
+			notes: "The previous reading of device `a` had `bytes_sent` set to 1000, `status` to `\"up\"`, and `labels` to `{rack: \"r1\", zone: \"eu\"}`."
+
+			configuration: {
+				fields: ["bytes_sent", "status", "labels"]
+				key_field: "{{ device_id }}"
+			}
+
+			input: log: {
+				device_id:  "a"
+				bytes_sent: 1500
+				status:     "down"
+				labels: {rack: "r2", zone: "eu"}
+			}
+			output: log: {
+				device_id:  "a"
+				bytes_sent: 500
+				status:     "down"
+				sequence:   2
+				labels: {rack: "r2"}
+			}
+		},
+	]
+
+	how_it_works: {
+		changes: {
+			title: "Changes"
+			body: """
+				For each key, the transform remembers the values of `fields` in the previous
+				event. Numbers are replaced by their difference with the previous number, objects
+				by the entries that changed, recursively, with `null` for removed entries, and
+				other values are kept as they are when they differ from the previous value.
+				Fields that didn't change are removed from the event.
+
+				The first event of a key, including one whose key was forgotten because more than
+				`max_keys` keys were seen since, is forwarded whole. Events without any change are
+				dropped, unless `drop_unchanged` is disabled.
+				"""
+		}
+		sequence_numbers: {
+			title: "Sequence numbers"
+			body: """
+				Each forwarded event has its sequence number within its key written to
+				`sequence_field`, starting at 1. Since events only hold changes, consumers
+				can use gaps in the sequence to detect missed events and resynchronize.
+
+				The state is held in memory, and is lost when Vector restarts or the
+				transform is reloaded, after which sequence numbers start over.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		events_discarded_total:           components.sources.internal_metrics.output.metrics.events_discarded_total
+	}
+}
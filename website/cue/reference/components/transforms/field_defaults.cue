@@ -0,0 +1,85 @@
+package metadata
+
+components: transforms: field_defaults: {
+	title: "Field Defaults"
+
+	description: """
+		Fills in the missing fields of events with defaults, by semantic meaning, so
+		that sinks requiring fields with certain meanings accept sparse events.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		shape: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.field_defaults.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Default the severity and timestamp"
+
+			notes: "The schema of the events gives the `severity` meaning to the `level` field, and doesn't give any field the `timestamp` meaning."
+
+			configuration: {
+				meanings: {
+					severity: {
+						path:   "severity"
+						source: "value"
+						value:  "info"
+					}
+					timestamp: {
+						path:   "timestamp"
+						source: "field"
+						field:  "received_at"
+					}
+				}
+			}
+
+			input: log: {
+				message:     "Connection closed"
+				received_at: "2023-02-14T10:08:51.512Z"
+			}
+			output: log: {
+				message:     "Connection closed"
+				level:       "info"
+				received_at: "2023-02-14T10:08:51.512Z"
+				timestamp:   "2023-02-14T10:08:51.512Z"
+			}
+		},
+	]
+
+	how_it_works: {
+		meanings: {
+			title: "Semantic meanings"
+			body: """
+				For each configured meaning, the transform looks up the field with the meaning in
+				the schema of the event, falling back to `path` if the schema doesn't give any
+				field the meaning. The field is filled in with the default when it's missing or
+				`null`; existing values are never overwritten.
+
+				The schema of the output of the transform gives the meaning to the field it fills
+				in, with the type of the default, such that sinks requiring the meaning accept
+				the events, including when schema validation is enabled.
+				"""
+		}
+	}
+}
@@ -0,0 +1,83 @@
+package metadata
+
+components: transforms: hash_bucket: {
+	title: "Hash Bucket"
+
+	description: """
+		Assigns events to stable hash buckets computed from a key, and labels them with the named
+		cohort their bucket belongs to, to split traffic consistently across experiments and sampled
+		sinks.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.hash_bucket.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Split users between two cohorts"
+			configuration: {
+				key:     "{{ user_id }}"
+				buckets: 100
+				cohorts: [
+					{name: "control", start: 0, end: 49},
+					{name: "treatment", start: 50, end: 99},
+				]
+			}
+			input: log: {
+				user_id: "alice"
+			}
+			output: log: {
+				user_id: "alice"
+				bucket:  5
+				cohort:  "control"
+			}
+		},
+	]
+
+	how_it_works: {
+		hashing: {
+			title: "Hashing"
+			body: """
+				The bucket of an event is the 32-bit [MurmurHash3](\(urls.murmurhash)) of its rendered
+				`key`, as computed by the x86 variant of the reference implementation with the given
+				`seed`, modulo the number of `buckets`. Since the hash doesn't depend on the Vector
+				instance or on the order of the events, the same key is always assigned to the same
+				bucket, across restarts and across instances, and other systems can compute the same
+				buckets from the same keys.
+
+				Using different seeds for different experiments keeps their assignments independent, so
+				that the users of a cohort in one experiment are spread across all the cohorts of another.
+				"""
+		}
+
+		routing: {
+			title: "Routing by cohort"
+			body: """
+				The `bucket` and `cohort` fields can be used by downstream components to route the events
+				of each cohort to a different pipeline, for example with a `route` transform, or to
+				forward a consistent sample of the traffic, such as all the events of the first ten
+				buckets out of a hundred.
+				"""
+		}
+	}
+}
@@ -0,0 +1,93 @@
+package metadata
+
+components: transforms: join: {
+	title: "Join"
+
+	description: """
+		Joins log events of several streams into a single log event when the values
+		of their `join_by` fields match within a window of time.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.join.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Join audit logs with request logs"
+
+			configuration: {
+				join_by: ["request_id"]
+				streams: {
+					request: condition: "exists(.status)"
+					audit: condition:   "exists(.actor)"
+				}
+			}
+
+			input: [
+				{log: {request_id: "abcd1234", actor:  "admin", action: "delete"}},
+				{log: {request_id: "abcd1234", status: 200, path:       "/users/7"}},
+			]
+			output: log: {
+				request_id: "abcd1234"
+				request: {request_id: "abcd1234", status: 200, path:       "/users/7"}
+				audit: {request_id:   "abcd1234", actor:  "admin", action: "delete"}
+			}
+		},
+	]
+
+	how_it_works: {
+		joining: {
+			title: "Joining"
+			body: """
+				Each event is assigned to the first stream, in order, whose condition it
+				matches, and is held until an event of every other stream with the same
+				values for the `join_by` fields is received. The joined event then contains
+				the event of each stream under the name of the stream, along with the
+				`join_by` fields at its root.
+
+				When several events of the same stream are received for a pending join, the
+				latest one is kept and the others are dropped.
+				"""
+		}
+
+		windows: {
+			title: "Windows"
+			body: """
+				A join is pending for at most `window_ms` after its first event is received.
+				Joins that are still missing the events of some streams at the end of their
+				window are dropped, or emitted with the events received so far when
+				`unmatched` is set to `emit`.
+
+				At most `max_pending` joins are held in memory. When that limit is reached,
+				`overflow` decides whether the oldest pending join ends early or the events
+				that would start a new join are dropped.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		unmatched_joins_flushed_total: components.sources.internal_metrics.output.metrics.unmatched_joins_flushed_total
+	}
+}
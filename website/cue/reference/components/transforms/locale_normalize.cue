@@ -0,0 +1,95 @@
+package metadata
+
+components: transforms: locale_normalize: {
+	title: "Locale Normalize"
+
+	description: """
+		Parses numbers and dates written for people in various locales, such as `1.234,50 €`
+		or `12. März 2023`, into integers, floats and timestamps.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		shape: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.locale_normalize.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Normalize German amounts and dates"
+
+			configuration: {
+				locales:  ["de", "en"]
+				timezone: "Europe/Berlin"
+				fields: [
+					{field: "amount", type: "number", currency_field: "currency"},
+					{field: "created", type: "timestamp", formats: ["%d. %B %Y %H:%M"]},
+					{field: "sent", type: "timestamp"},
+				]
+			}
+
+			input: log: {
+				amount:  "1.234,50 €"
+				created: "12. März 2023 10:00"
+				sent:    "1678611600000"
+			}
+			output: log: {
+				amount:   1234.5
+				currency: "€"
+				created:  "2023-03-12T09:00:00Z"
+				sent:     "2023-03-12T09:00:00Z"
+			}
+		},
+	]
+
+	how_it_works: {
+		numbers: {
+			title: "Numbers"
+			body: """
+				Strings are parsed with the decimal and digit group separators of each locale, in
+				order, until one of them parses the whole string. Groups of digits must have three
+				digits, so that `1,5` is rejected in English and parsed as `1.5` in the next locale,
+				such as German. A leading sign and a currency symbol or three-letter code, before or
+				after the number, are allowed. Values without a fractional part become integers, and
+				the others floats.
+				"""
+		}
+		dates: {
+			title: "Dates"
+			body: """
+				Names of months and days of the week, in full or abbreviated, are translated from
+				each locale to English, and the result is parsed with the configured `formats`, or
+				common formats such as RFC 3339 if there are none.
+
+				Numbers, and strings of digits that don't match any of the formats, are read as Unix
+				timestamps. Their unit is detected from their magnitude, unless `epoch_unit` is set.
+				"""
+		}
+		errors: {
+			title: "Unparseable values"
+			body: """
+				Values that can't be parsed in any of the locales are left unchanged, and an error is
+				logged.
+				"""
+		}
+	}
+}
@@ -0,0 +1,135 @@
+package metadata
+
+components: transforms: log_patterns: {
+	title: "Log Patterns"
+
+	description: """
+		Clusters log messages into patterns as they pass through, assigning each event the id of
+		its pattern, the pattern's template, and the parameters of the message. Patterns can be
+		used to reduce logs, for example by sampling per pattern, or to detect novel messages.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		parse: {
+			format: {
+				name:     "Log message"
+				url:      null
+				versions: null
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: [
+			"""
+				Pattern ids are assigned in the order patterns are found, and are not preserved across
+				restarts or configuration reloads, nor shared between Vector instances. Use the
+				`template` to identify a pattern outside of a single running instance.
+				""",
+		]
+		notices: []
+	}
+
+	configuration: base.components.transforms.log_patterns.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: ["1"]
+				}
+				pattern: {
+					description: "The template of the pattern."
+					required:    true
+					examples: ["connected to <*> in <*> ms"]
+				}
+			}
+			type: "counter"
+			default_namespace: null
+		}
+	}
+
+	examples: [
+		{
+			title: "Extract the template of a message"
+			configuration: {}
+			input: [
+				{log: message: "connected to 10.0.0.1 in 5 ms"},
+				{log: message: "connected to 10.0.0.2 in 12 ms"},
+			]
+			output: [
+				{log: {
+					message: "connected to 10.0.0.1 in 5 ms"
+					pattern: {
+						id:       1
+						template: "connected to 10.0.0.1 in 5 ms"
+						parameters: []
+					}
+				}},
+				{log: {
+					message: "connected to 10.0.0.2 in 12 ms"
+					pattern: {
+						id:       1
+						template: "connected to <*> in <*> ms"
+						parameters: ["10.0.0.2", "12"]
+					}
+				}},
+			]
+		},
+	]
+
+	how_it_works: {
+		clustering: {
+			title: "Clustering"
+			body: """
+				Messages are clustered with an online algorithm based on
+				[Drain](https://jiemingzhu.github.io/pub/pjhe_icws2017.pdf). Each message is split into
+				tokens on whitespace, and routed through a parse tree by its number of tokens and its
+				first `max_depth - 2` tokens. Among the patterns at the end of that route, the message
+				joins the one sharing the most tokens with it, provided that at least
+				`similarity_threshold` of the tokens are shared. Otherwise, it starts a new pattern.
+
+				The template of a pattern is the sequence of tokens shared by all of its messages, with
+				`<*>` in place of the tokens that vary. Templates only ever become more general, so the
+				template reported for a pattern can change as more messages join it. The parameters of
+				a message are its tokens in place of the `<*>` wildcards.
+
+				Events whose `field` is missing or isn't a string are passed through unchanged.
+				"""
+		}
+
+		metrics: {
+			title: "Pattern Metrics"
+			body: """
+				When `metrics` is configured, the number of events matching each pattern is counted,
+				and every `flush_interval_secs` an incremental counter is emitted for each pattern that
+				was matched since the previous flush, tagged with its `pattern_id` and `pattern`
+				template. The metrics are emitted alongside the log events, so a `route` or `filter`
+				transform can be used to send them elsewhere.
+				"""
+		}
+
+		memory: {
+			title: "Memory Usage"
+			body: """
+				At most `max_patterns` patterns are kept, and the least recently matched pattern is
+				forgotten when a new one is found. A message of a forgotten pattern starts a new
+				pattern, with a new id.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		log_patterns_created_total: components.sources.internal_metrics.output.metrics.log_patterns_created_total
+	}
+}
@@ -0,0 +1,105 @@
+package metadata
+
+components: transforms: pii_redact: {
+	title: "PII Redact"
+
+	description: """
+		Detects personally identifiable information (PII) in log events, such as email
+		addresses, credit card numbers, phone numbers, IP addresses and national
+		identification numbers, as well as matches of custom regular expressions and
+		dictionaries, and masks, hashes, drops or reroutes it.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		sanitize: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: [
+			"""
+				Detection is based on patterns, and can't tell apart information that merely
+				looks like PII, such as an order number formatted like a phone number, nor
+				find PII in formats it doesn't know of. Make sure the detectors fit your data.
+				""",
+		]
+		notices: []
+	}
+
+	configuration: base.components.transforms.pii_redact.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	outputs: [
+		components._default_output,
+		{
+			name: "rerouted"
+			description: """
+				Events in which a detector with the `reroute` action found information are
+				sent to the `rerouted` output. For a transform component named `foo`, this
+				output can be accessed by specifying `foo.rerouted` as the input to another
+				component.
+				"""
+		},
+	]
+
+	examples: [
+		{
+			title: "Mask emails and drop card numbers"
+
+			configuration: {
+				fields: ["message", "card"]
+				detectors: [
+					{type: "email"},
+					{type: "credit_card", action: "drop_field"},
+				]
+			}
+
+			input: log: {
+				message: "Order placed by jane@example.com"
+				card:    "4111 1111 1111 1111"
+			}
+			output: log: {
+				message: "Order placed by [REDACTED]"
+			}
+		},
+	]
+
+	how_it_works: {
+		detectors: {
+			title: "Detectors"
+			body: """
+				Detectors are applied in order, to every string value of the scanned fields.
+				The built-in detectors match candidates with a regular expression, and then
+				validate them where possible: card numbers must pass the Luhn checksum, IP
+				addresses must be valid addresses, and Social Security numbers can't use the
+				area, group or serial numbers that are never assigned.
+				"""
+		}
+		hashing: {
+			title: "Hashing"
+			body: """
+				The `hash` action replaces the information with the hex-encoded SHA-256 hash of
+				the `hash_salt` followed by the information, so that the same information is
+				always replaced with the same hash. Set a secret `hash_salt`, otherwise the
+				hashes of information with few possible values, such as phone numbers, can be
+				reversed by hashing all of them.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		pii_detections_total: components.sources.internal_metrics.output.metrics.pii_detections_total
+	}
+}
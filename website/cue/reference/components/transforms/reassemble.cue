@@ -0,0 +1,98 @@
+package metadata
+
+components: transforms: reassemble: {
+	title: "Reassemble"
+
+	description: """
+		Reassembles messages that were split across several log events, such as
+		chunked application logs carrying the number of each part and the total
+		number of parts, into a single log event.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.reassemble.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Reassemble a chunked message received out of order"
+
+			configuration: {
+				group_by: ["message_id"]
+				part_field:  "part"
+				total_field: "total"
+			}
+
+			input: [
+				{log: {message_id: "f00d", part: 2, total: 2, message: "world"}},
+				{log: {message_id: "f00d", part: 1, total: 2, message: "hello "}},
+			]
+			output: log: {
+				message_id: "f00d"
+				total:      2
+				message:    "hello world"
+			}
+		},
+	]
+
+	how_it_works: {
+		reassembling: {
+			title: "Reassembling"
+			body: """
+				Events with the same values for the `group_by` fields are the parts of the
+				same message. Parts are held until as many parts as the `total_field` of
+				the first part received are, and are then ordered by their `part_field`,
+				in whatever order they were received. The reassembled event is the part
+				with the lowest number, with the `merge_field` values of all the parts
+				concatenated with `separator`, and without its `part_field`.
+
+				Events missing any of the `group_by` fields, or without an integer
+				`part_field` and `total_field`, are passed through unchanged. Parts
+				received again for a pending message are dropped.
+
+				Unlike the `multiline` option of some sources, which joins lines matching
+				patterns as they are read, this transform relies on fields of the events,
+				so it works across sources and with parts that are interleaved with other
+				messages.
+				"""
+		}
+
+		timeouts: {
+			title: "Timeouts"
+			body: """
+				A message is pending for at most `timeout_ms` after its first part is
+				received. Messages still missing parts at the end of their timeout are
+				emitted with the parts received so far, or dropped when `incomplete` is
+				set to `drop`.
+
+				At most `max_pending` messages are held in memory. When that limit is
+				reached, the oldest pending message times out early.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		incomplete_reassemblies_flushed_total: components.sources.internal_metrics.output.metrics.incomplete_reassemblies_flushed_total
+	}
+}
@@ -144,7 +144,36 @@ components: transforms: reduce: {
 		},
 	]
 
+	outputs: [
+		components._default_output,
+		{
+			name: "evicted"
+			description: """
+				This transform also implements an additional `evicted` output. When `reroute_evicted` is set to
+				`true`, the events of the groups evicted to stay within `max_groups` are sent to the `evicted`
+				output instead of the default output. For a transform component named `foo`, this output can be
+				accessed by specifying `foo.evicted` as the input to another component.
+				"""
+		},
+	]
+
+	how_it_works: {
+		limits: {
+			title: "Limiting groups"
+			body: """
+				Besides `ends_when`, `starts_when`, and `expire_after_ms`, groups can be flushed once they
+				reach a size, with `max_events` and `max_bytes`, or once their combined event satisfies the
+				`flush_condition`.
+
+				The `max_groups` option bounds the memory used by the transform. When a new group would exceed
+				it, the group updated least recently is evicted and flushed as is, even though it may not be
+				complete. Set `reroute_evicted` to tell these events apart with the `evicted` output.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
+		groups_evicted_total:       components.sources.internal_metrics.output.metrics.groups_evicted_total
 		stale_events_flushed_total: components.sources.internal_metrics.output.metrics.stale_events_flushed_total
 	}
 }
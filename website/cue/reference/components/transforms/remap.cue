@@ -136,6 +136,24 @@ components: transforms: "remap": {
 				```
 				"""#
 		}
+		result_caching: {
+			title: "Caching program results"
+			body: """
+				When `cache` is set, the results of the program are cached for log events, keyed by
+				the values of `cache.key_fields`. When an event has the same key as a cached result
+				that is younger than `cache.ttl_secs`, the program isn't run: the fields listed in
+				`cache.fields` are set to their cached values, or removed if the program removed
+				them, and any other change the program would have made is not applied.
+
+				This is useful when many events share the same inputs, such as a user agent or an IP
+				address, and the program does expensive parsing or lookups on them. The program must
+				only depend on the key fields for the cached results to be correct.
+
+				Results are only cached when the program runs successfully and emits a single event.
+				The cache is shared by all the instances of the transform, and holds up to
+				`cache.max_entries` results, evicting the least recently used result once full.
+				"""
+		}
 	}
 
 	outputs: [
@@ -156,6 +174,8 @@ components: transforms: "remap": {
 	]
 
 	telemetry: metrics: {
-		processing_errors_total: components.sources.internal_metrics.output.metrics.processing_errors_total
+		processing_errors_total:  components.sources.internal_metrics.output.metrics.processing_errors_total
+		remap_cache_hits_total:   components.sources.internal_metrics.output.metrics.remap_cache_hits_total
+		remap_cache_misses_total: components.sources.internal_metrics.output.metrics.remap_cache_misses_total
 	}
 }
@@ -94,5 +94,26 @@ components: transforms: route: {
 			name:        "<route_id>"
 			description: "Each route can be referenced as an input by other components with the name `<transform_name>.<route_id>`."
 		},
+		{
+			name: "_unmatched"
+			description: """
+				Events that don't match any route are sent to the `_unmatched` output, which
+				can be referenced as an input by other components with the name
+				`<transform_name>._unmatched`. This output doesn't exist when
+				`reroute_unmatched` is disabled.
+				"""
+		},
 	]
+
+	how_it_works: {
+		matching: {
+			title: "Matching"
+			body: """
+				By default, an event is sent to every route whose condition it matches. With
+				`mode` set to `first`, it's only sent to the first of them, in the order the
+				routes are configured, which makes routes behave like the arms of a `match`
+				statement.
+				"""
+		}
+	}
 }
@@ -0,0 +1,93 @@
+package metadata
+
+components: transforms: schema_validation: {
+	title: "Schema Validation"
+
+	description: """
+		Validates log events against a [JSON Schema](\(urls.json_schema)), sending the
+		events that don't conform to it to the `invalid` output.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.schema_validation.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	outputs: [
+		components._default_output,
+		{
+			name: "invalid"
+			description: """
+				Events that don't conform to the schema are sent to the `invalid` output,
+				annotated with the violations they were rejected for. For a transform
+				component named `foo`, this output can be accessed by specifying
+				`foo.invalid` as the input to another component.
+				"""
+		},
+	]
+
+	examples: [
+		{
+			title: "Reject orders without an ID"
+
+			configuration: {
+				schema_file: "/etc/vector/schemas/orders.json"
+			}
+
+			input: log: {
+				amount: 12.5
+			}
+			output: log: {
+				amount: 12.5
+				metadata: invalid: {
+					violations: [{
+						path:        ""
+						schema_path: "/required"
+						message:     "\"order_id\" is a required property"
+					}]
+					component_id:   "schema_validation"
+					component_type: "schema_validation"
+					component_kind: "transform"
+				}
+			}
+		},
+	]
+
+	how_it_works: {
+		annotations: {
+			title: "Annotations"
+			body: """
+				Invalid events are annotated with the violations they were rejected for, at
+				`metadata.invalid`, or at `%vector.invalid` when using the Vector log
+				namespace. Each violation has the JSON pointer of the offending value, as
+				`path`, the JSON pointer of the keyword of the schema it violates, as
+				`schema_path`, and a `message`. At most `max_violations` violations are
+				recorded for an event.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		schema_violations_total: components.sources.internal_metrics.output.metrics.schema_violations_total
+	}
+}
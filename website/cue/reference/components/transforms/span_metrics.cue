@@ -0,0 +1,104 @@
+package metadata
+
+components: transforms: span_metrics: {
+	title: "Span Metrics"
+
+	description: """
+		Derives RED metrics, the rate of requests, the rate of errors, and the
+		distribution of durations, from the spans of traces, for each service and
+		operation.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "batch"
+		stateful:      true
+	}
+
+	features: {
+		convert: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.span_metrics.configuration
+
+	input: {
+		logs:    false
+		metrics: null
+		traces:  true
+	}
+
+	output: metrics: {
+		_tags: {
+			service: {
+				description: "The service of the spans."
+				required:    false
+				examples: ["web-store"]
+			}
+			operation: {
+				description: "The operation of the spans, from their `name`."
+				required:    false
+				examples: ["http.request"]
+			}
+			"*": {
+				description: "The tags configured in `dimensions`."
+				required:    false
+			}
+		}
+
+		span_calls_total: {
+			description:       "The number of spans."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _tags
+		}
+		span_duration_seconds: {
+			description:       "The durations of the spans, in seconds."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _tags
+		}
+		span_errors_total: {
+			description:       "The number of spans with an error."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _tags
+		}
+	}
+
+	how_it_works: {
+		aggregation: {
+			title: "Aggregation"
+			body: """
+				Spans are grouped by their tags, the `service` and `name` of the span, and
+				the values of the fields configured in `dimensions`. Every `flush_period_ms`,
+				each group is emitted as `incremental` metrics: a counter of the spans, a
+				counter of the spans whose `error` field is set, and a histogram of their
+				`duration`, which is in nanoseconds in spans, converted to seconds.
+
+				The traces themselves aren't emitted. To also send them to a tracing
+				backend, use the source of the traces as an input of both this transform
+				and the trace sink.
+				"""
+		}
+		cardinality: {
+			title: "Cardinality"
+			body: """
+				Each distinct combination of tags produces its own series. Only use fields
+				with a bounded number of values, such as the `resource` of spans with
+				templated routes or an HTTP status code, as `dimensions`.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		span_metrics_flushes_total:        components.sources.internal_metrics.output.metrics.span_metrics_flushes_total
+		span_metrics_spans_recorded_total: components.sources.internal_metrics.output.metrics.span_metrics_spans_recorded_total
+	}
+}
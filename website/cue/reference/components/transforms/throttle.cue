@@ -96,9 +96,10 @@ components: transforms: throttle: {
 						pass through a rate limiter. Each event passing through the transform consumes an available cell,
 						if there is no available cell the event will be rate limited.
 
-						A rate limiter is created with a maximum number of cells equal to the `threshold`, and cells replenish
-						at a rate of `window_secs` divided by `threshold`. For example, a `window_secs` of 60 with a `threshold` of 10
-						replenishes a cell every 6 seconds and allows a burst of up to 10 events.
+						A rate limiter is created with a maximum number of cells equal to the `burst`, or the `threshold` if
+						`burst` isn't set, and cells replenish at a rate of `window_secs` divided by `threshold`. For example,
+						a `window_secs` of 60 with a `threshold` of 10 replenishes a cell every 6 seconds and allows a burst
+						of up to 10 events, or up to 50 events with a `burst` of 50.
 						"""
 				},
 				{
@@ -108,9 +109,25 @@ components: transforms: throttle: {
 						for that particular bucket when the rate limiter is at capacity. Any event passed when the rate
 						limiter is at capacity will be discarded and tracked by an `events_discarded_total` metric tagged
 						by the bucket's `key`.
+
+						When `reroute_overflow` is set to `true`, these events are sent to the `overflow` output instead of
+						being discarded.
 						"""
 				},
 			]
 		}
 	}
+
+	outputs: [
+		components._default_output,
+		{
+			name: "overflow"
+			description: """
+				This transform also implements an additional `overflow` output. When `reroute_overflow` is set to
+				`true`, events that exceed the rate limit of their bucket are sent to the `overflow` output instead
+				of being discarded. For a transform component named `foo`, this output can be accessed by
+				specifying `foo.overflow` as the input to another component.
+				"""
+		},
+	]
 }
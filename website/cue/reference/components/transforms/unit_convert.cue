@@ -0,0 +1,75 @@
+package metadata
+
+components: transforms: unit_convert: {
+	title: "Unit Convert"
+
+	description: """
+		Converts numeric fields between units of data sizes, durations and temperatures,
+		parsing the unit suffix of strings such as `12ms` or `3.4GB`.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		shape: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.unit_convert.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Normalize durations and sizes"
+
+			configuration: {
+				rules: [
+					{field: "duration", from: "ms", to: "s", unit_field: "duration_unit"},
+					{field: "size", to: "MB"},
+				]
+			}
+
+			input: log: {
+				duration: 1500
+				size:     "3.4GB"
+			}
+			output: log: {
+				duration:      1.5
+				duration_unit: "s"
+				size:          3400.0
+			}
+		},
+	]
+
+	how_it_works: {
+		conversion: {
+			title: "Conversion"
+			body: """
+				Integers, floats, and strings holding a number are converted from the `from`
+				unit of their rule. Strings with a unit suffix, such as `12ms`, `3.4 GB` or
+				`-40°F`, are converted from the unit of their suffix instead, so that a
+				field mixing units is normalized to a single one. Converted values are
+				always floats.
+
+				Values that can't be converted, because they aren't numbers, have an unknown
+				unit, or a unit of another kind than the `to` unit of their rule, are left
+				unchanged, and an error is logged.
+				"""
+		}
+	}
+}
@@ -0,0 +1,88 @@
+package metadata
+
+components: transforms: url_normalize: {
+	title: "URL Normalize"
+
+	description: """
+		Normalizes the URLs of requests, by stripping their query parameters and collapsing the IDs in
+		their paths into placeholders, and classifies the status codes of responses, to produce
+		low-cardinality fields for generating metrics from access logs.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.url_normalize.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Normalize an access log"
+			configuration: {
+				allowed_query_parameters: ["format"]
+			}
+			input: log: {
+				url:    "/users/123/orders/3f2504e0-4f89-11d3-9a0c-0305e82c3301?format=json&session=abc"
+				status: 404
+			}
+			output: log: {
+				url:            "/users/123/orders/3f2504e0-4f89-11d3-9a0c-0305e82c3301?format=json&session=abc"
+				status:         404
+				normalized_url: "/users/{id}/orders/{uuid}?format=json"
+				status_class:   "4xx"
+			}
+		},
+	]
+
+	how_it_works: {
+		normalization: {
+			title: "URL normalization"
+			body: """
+				The scheme and the authority of absolute URLs are kept as they are, while their path is
+				split into segments. Segments matching one of the `segment_patterns` as a whole are
+				replaced by its placeholder. Then, if `collapse_ids` is enabled, segments holding a UUID
+				are replaced by `uuid_placeholder`, and segments holding a number, or a hexadecimal ID of
+				at least 16 digits such as a hash or an object ID, by `id_placeholder`.
+
+				Only the query parameters listed in `allowed_query_parameters` are kept, in their
+				original order and with their values, so that parameters holding identifiers, tokens, or
+				search terms don't end up in metric tags. The fragment is always stripped.
+				"""
+		}
+
+		status_classes: {
+			title: "Status classes"
+			body: """
+				The status code is read as an integer or as a string, and its class, from `1xx` to
+				`5xx`, is written to `status_class_field`. Values outside of the range of HTTP status
+				codes are ignored.
+				"""
+		}
+
+		metrics: {
+			title: "Generating metrics"
+			body: """
+				The normalized URL and the status class are meant to be used as tags of the metrics
+				generated from access logs, for example by a `log_to_metric` transform, in place of the
+				raw URL and status code that would otherwise create a series for each ID.
+				"""
+		}
+	}
+}
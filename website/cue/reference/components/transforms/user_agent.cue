@@ -0,0 +1,124 @@
+package metadata
+
+components: transforms: user_agent: {
+	title: "User Agent"
+
+	description: """
+		Classifies user agent strings by browser, operating system and device, and flags bots,
+		using a ruleset that can be updated without restarting Vector.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		enrich: {
+			from: service: {
+				name:     "uap-core"
+				url:      urls.uap
+				versions: null
+			}
+		}
+	}
+
+	support: {
+		requirements: [
+			"""
+				A ruleset in the [uap-core](\(urls.uap)) `regexes.yaml` format must be available at
+				`path`.
+				""",
+		]
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.user_agent.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	examples: [
+		{
+			title: "Classify a browser"
+			configuration: {
+				field:  "user_agent"
+				target: "user_agent_info"
+				path:   "/etc/vector/regexes.yaml"
+			}
+			input: log: {
+				user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/112.0.5615.49 Safari/537.36"
+			}
+			output: log: {
+				user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/112.0.5615.49 Safari/537.36"
+				user_agent_info: {
+					browser: {
+						family: "Chrome"
+						major:  "112"
+						minor:  "0"
+						patch:  "5615"
+					}
+					os: {
+						family:      "Windows"
+						major:       "10"
+						minor:       null
+						patch:       null
+						patch_minor: null
+					}
+					device: {
+						family: "Other"
+						brand:  null
+						model:  null
+					}
+					is_bot: false
+				}
+			}
+		},
+	]
+
+	how_it_works: {
+		ruleset: {
+			title: "Ruleset"
+			body: """
+				Each user agent is matched against the `user_agent_parsers`, `os_parsers` and
+				`device_parsers` rules of the ruleset in order, and the first matching rule of each kind
+				determines the browser, operating system and device. When no rule matches, the family is
+				reported as `Other`. Events whose `field` is missing or isn't a string are passed through
+				unchanged.
+
+				Unlike the `parse_user_agent` VRL function, whose rules are built into Vector, the ruleset
+				is read from a file. Keeping the file up to date, for example by periodically downloading
+				the latest `regexes.yaml` from uap-core, keeps the classification current. When
+				`refresh_interval_secs` is set, modifications to the file are picked up without a
+				configuration reload.
+				"""
+		}
+
+		bots: {
+			title: "Bot Detection"
+			body: """
+				`is_bot` is `true` when the device family is `Spider`, which is how uap-core classifies
+				crawlers, spiders and other automated clients.
+				"""
+		}
+
+		caching: {
+			title: "Caching"
+			body: """
+				Classifications are cached by user agent string, so that repeated user agents don't need
+				to be matched against the ruleset again. The size of the cache is set by `cache_size`.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		user_agent_ruleset_refresh_failed_total:     components.sources.internal_metrics.output.metrics.user_agent_ruleset_refresh_failed_total
+		user_agent_ruleset_refresh_successful_total: components.sources.internal_metrics.output.metrics.user_agent_ruleset_refresh_successful_total
+	}
+}
@@ -37,6 +37,26 @@ configuration: {
 			}
 		}
 
+		data_dir_quota: {
+			common: false
+			description: """
+				The maximum size, in bytes, of the state Vector persists in `data_dir` and the
+				directories of `state_dirs`, combined.
+
+				Disk buffers are accounted for at their configured `max_size`, and checkpoints and
+				cached enrichment table data at their current size. Vector refuses to start if these
+				add up to more than the quota, and refuses to cache enrichment table data, such as
+				downloaded GeoIP databases, that doesn't fit in it. Usage is reported per subsystem
+				through the `data_dir_used_bytes` internal metric.
+				"""
+			required: false
+			type: uint: {
+				default: null
+				examples: [10737418240]
+				unit: "bytes"
+			}
+		}
+
 		expire_metrics: {
 			common: false
 			description: """
@@ -98,6 +118,7 @@ configuration: {
 
 				* [CSV](\(urls.csv)) files
 				* [MaxMind](\(urls.maxmind)) databases
+				* [SQLite](\(urls.sqlite)) databases
 
 				For the lookup in the enrichment tables to be as performant as possible, the data is indexed according
 				to the fields that are used in the search. Note that indices can only be created for fields for which an
@@ -115,8 +136,9 @@ configuration: {
 					required: true
 					type: string: {
 						enum: {
-							"file":  "Enrich data from a CSV file."
-							"geoip": "Enrich data from a [MaxMind](\(urls.maxmind)) database."
+							"file":   "Enrich data from a CSV file."
+							"geoip":  "Enrich data from a [MaxMind](\(urls.maxmind)) database."
+							"sqlite": "Enrich data from a table in a [SQLite](\(urls.sqlite)) database."
 						}
 					}
 				}
@@ -179,6 +201,34 @@ configuration: {
 							}
 						}
 
+						reload: {
+							description: "Configuration options for reloading the file when it is modified."
+							required:    false
+							common:      false
+							type: object: options: {
+								enabled: {
+									description: """
+										Whether or not to reload the file when it is modified. When enabled, the file is
+										re-parsed whenever its modification time changes, and the new data is swapped in
+										atomically, without requiring a configuration reload. If the modified file can't
+										be parsed, the previously loaded data continues to be used.
+										"""
+									required: false
+									common:   true
+									type: bool: default: false
+								}
+								poll_interval_secs: {
+									description: "The interval between checks for modifications to the file."
+									required:    false
+									common:      false
+									type: uint: {
+										default: 30
+										unit:    "seconds"
+									}
+								}
+							}
+						}
+
 						schema: {
 							description: _coercing_fields
 							required:    false
@@ -247,6 +297,298 @@ configuration: {
 								examples: ["de", "en", "es", "fr", "ja", "pt-BR", "ru", "zh-CN"]
 							}
 						}
+						download_url: {
+							description: """
+								The URL to download the database from. The URL must serve a single database file,
+								either uncompressed or gzip-compressed; archives such as `tar.gz` are not supported.
+
+								When set, the database is downloaded to `path` when the table is loaded, including on
+								configuration reload, and on every `refresh_interval_secs`. Downloads are conditional on
+								the ETag and modification time of the file at `path`, so an unchanged database is not
+								downloaded again. If a download fails, the database already at `path`, if any, continues
+								to be used.
+								"""
+							required: false
+							common:   false
+							type: string: {
+								examples: ["https://example.com/geoip/GeoLite2-City.mmdb.gz"]
+							}
+						}
+						refresh_interval_secs: {
+							description: """
+								The interval between refreshes of the database. On every refresh, the database is
+								downloaded again if `download_url` is set and it has changed, and re-read from `path`
+								if the file has been modified. The new database is swapped in atomically.
+
+								When unset, the database is only reloaded on configuration reload.
+								"""
+							required: false
+							common:   false
+							type: uint: {
+								examples: [86400]
+								unit: "seconds"
+							}
+						}
+					}
+				}
+				sqlite: {
+					required:    true
+					description: """
+						Configuration options for [SQLite](\(urls.sqlite)) databases.
+
+						Rows are looked up by querying the database, rather than being loaded into memory, so
+						this enrichment table is suited to datasets too large for a `file` enrichment table.
+						Every lookup must include an exact match on at least one of the `key_columns`, and
+						case-insensitive matches use SQLite's `NOCASE` collation, which only folds ASCII
+						characters. Date range conditions require dates to be stored as ISO 8601 text.
+						"""
+					type: object: options: {
+						path: {
+							description: """
+								The path of the SQLite database file. The database is opened read-only.
+								"""
+							warnings: [
+								"In order to be used by Vector, you need to assign read access to the database file.",
+							]
+							required: true
+							type: string: {
+								examples: ["/data/enrichment.db"]
+							}
+						}
+						table: {
+							description: "The name of the table to look up rows in."
+							required:    true
+							type: string: {
+								examples: ["hosts"]
+							}
+						}
+						key_columns: {
+							description: """
+								The columns that lookups are keyed on. Every lookup must include an exact match
+								on at least one of these columns. Each column must be the leading column of an
+								index on `table`, or its primary key, so that lookups never scan the whole table.
+
+								Case-insensitive lookups must include an exact match on a column whose index uses
+								the `NOCASE` collation, for example created with
+								`CREATE INDEX ... (column COLLATE NOCASE)`.
+								"""
+							required: true
+							type: array: items: type: string: {
+								examples: ["hostname", "ip"]
+							}
+						}
+						refresh_interval_secs: {
+							description: """
+								The interval between checks for modifications to the database file. When the
+								file has been modified or replaced, the database is reopened. If the new
+								database can't be opened, or no longer has the configured table and key
+								columns, the previously opened database continues to be used.
+								"""
+							required: false
+							common:   false
+							type: uint: {
+								default: 10
+								unit:    "seconds"
+							}
+						}
+					}
+				}
+			}
+		}
+
+		feature_flags: {
+			common: false
+			description: """
+				Configuration options for polling feature flags from a provider. Feature flags gate the
+				behavior of transforms, either entirely with the `enabled_when` option of the transform, or
+				within a [`remap`](\(urls.vector_remap_transform)) program with the `feature_enabled`
+				function. This allows rolling out changes to the pipeline gradually across a fleet of Vector
+				instances, without pushing new configuration.
+
+				The provider returns a JSON object mapping the name of each flag to either a boolean, or an
+				object with a `rollout_percentage` between 0 and 100:
+
+				```json
+				{
+					"new_parser": true,
+					"sampling_v2": {"rollout_percentage": 10}
+				}
+				```
+
+				Flags with a `rollout_percentage` are enabled on that percentage of the hosts, which are
+				consistently selected from their hostname. Flags unknown to the provider are disabled. If
+				the provider can't be reached, or returns an invalid response, the previously loaded flags
+				are kept.
+				"""
+			required: false
+			type: object: options: {
+				provider: {
+					description: "The provider to load the feature flags from."
+					required:    true
+					type: object: options: {
+						type: {
+							description: "The type of the provider."
+							required:    true
+							type: string: enum: {
+								file: "Read the flags from a file."
+								http: "Fetch the flags from an HTTP endpoint."
+							}
+						}
+						path: {
+							description:   "The path of the file to read the flags from."
+							relevant_when: "type = \"file\""
+							required:      true
+							type: string: examples: ["/etc/vector/feature_flags.json"]
+						}
+						url: {
+							description:   "The URL to fetch the flags from."
+							relevant_when: "type = \"http\""
+							required:      true
+							type: string: examples: ["https://flags.example.com/vector.json"]
+						}
+						headers: {
+							common:        false
+							description:   "HTTP headers to add to the request."
+							relevant_when: "type = \"http\""
+							required:      false
+							type: object: {
+								examples: [{"Authorization": "Bearer ${FLAGS_TOKEN}"}]
+								options: {}
+							}
+						}
+					}
+				}
+				kind: {
+					description:   "The kind of Kubernetes object holding the configuration."
+					relevant_when: "type = \"kubernetes\""
+					required:      false
+					type: string: {
+						default: "config_map"
+						enum: {
+							config_map:      "A ConfigMap, whose entries are configuration files named after their format, such as `vector.yaml`."
+							vector_pipeline: "A `VectorPipeline` custom resource, whose spec is a configuration."
+						}
+					}
+				}
+				name: {
+					description:   "The name of the object holding the configuration."
+					relevant_when: "type = \"kubernetes\""
+					required:      true
+					type: string: examples: ["vector-config"]
+				}
+				namespace: {
+					common: false
+					description: """
+						The namespace of the object holding the configuration. Defaults to the namespace
+						Vector runs in, or the namespace of the current context of the kubeconfig file.
+						"""
+					relevant_when: "type = \"kubernetes\""
+					required:      false
+					type: string: examples: ["observability"]
+				}
+				key: {
+					common: false
+					description: """
+						The entry of the ConfigMap holding the configuration. If not set, all the entries
+						whose names have the extension of a configuration format are loaded and merged, in
+						the lexicographical order of their names.
+						"""
+					relevant_when: "type = \"kubernetes\" and kind = \"config_map\""
+					required:      false
+					type: string: examples: ["vector.yaml"]
+				}
+				kube_config_file: {
+					common: false
+					description: """
+						The path to a kubeconfig file. If not set, the in-cluster configuration is used.
+						"""
+					relevant_when: "type = \"kubernetes\""
+					required:      false
+					type: string: examples: ["/path/to/.kube/config"]
+				}
+				poll_interval_secs: {
+					common:        false
+					description:   "How often to poll the provider."
+					relevant_when: "type != \"kubernetes\""
+					required:      false
+					type: uint: {
+						default: 30
+						unit:    "seconds"
+					}
+				}
+			}
+		}
+
+		pipelines: {
+			common: false
+			description: """
+				Configures pipelines, each instantiating the transforms of a [pipeline
+				template](#pipeline-templates) with its own parameters. The transforms of a pipeline are
+				named after the pipeline and the transform in the template, such as `team_a.parse` for the
+				`parse` transform of the `team_a` pipeline.
+				"""
+			required: false
+			type: object: options: {
+				"*": {
+					description: "The configuration of a pipeline."
+					required:    true
+					type: object: options: {
+						template: {
+							description: "The name of the template to instantiate."
+							required:    true
+							type: string: examples: ["team_parsing"]
+						}
+						inputs: {
+							description: "The inputs of the transforms of the template that don't have any of their own."
+							required:    true
+							type: array: items: type: string: examples: ["kafka_in"]
+						}
+						params: {
+							description: "The values of the parameters of the template, overriding their default values."
+							required:    false
+							type: object: {
+								examples: [{"index": "team-a"}]
+								options: {}
+							}
+						}
+					}
+				}
+			}
+		}
+
+		templates: {
+			common: false
+			description: """
+				Configures pipeline templates, which are parameterized groups of transforms instantiated by
+				`pipelines`. See [Pipeline templates](#pipeline-templates) for more details.
+				"""
+			required: false
+			type: object: options: {
+				"*": {
+					description: "The configuration of a pipeline template."
+					required:    true
+					type: object: options: {
+						params: {
+							description: "The default values of the parameters of the template."
+							required:    false
+							type: object: {
+								examples: [{"retention": "30d"}]
+								options: {}
+							}
+						}
+						transforms: {
+							description: """
+								The transforms of the template, configured like the top-level `transforms`. The
+								transforms without `inputs` take the inputs of the pipeline, and the inputs
+								referencing another transform of the template reference that transform in the same
+								pipeline.
+								"""
+							required: true
+							type: object: {
+								examples: [{"parse": {"type": "remap", "source": ".index = \"{{ params.index }}\""}}]
+								options: {}
+							}
+						}
 					}
 				}
 			}
@@ -369,7 +711,7 @@ configuration: {
 			common: false
 			description: """
 				Configuration options to retrieve secrets from external backend in order to avoid storing secrets in plaintext
-				in Vector config. Currently, only the exec backend is supported. Multiple backends can be configured. To signify
+				in Vector config. The exec and Vault backends are supported. Multiple backends can be configured. To signify
 				Vector that it should look for a secret to retrieve use the `SECRET[<backend_name>.<secret_key>]`. This placeholder
 				will then be replaced by the secret retrieved from the relevant backend.
 				"""
@@ -424,6 +766,223 @@ configuration: {
 						}
 					}
 				}
+				vault: {
+					required: true
+					description: """
+						Retrieve secrets from the KV secrets engine of a [HashiCorp Vault](\(urls.vault)) server.
+
+						The secret key is the path of the secret under the mount of the engine, with its segments
+						separated by dots, followed by the name of the field of the secret to use:
+						`SECRET[<backend_name>.<path>.<field>]`. For example, `SECRET[vault.vector.api_key]` is the
+						`api_key` field of the `vector` secret.
+
+						Secrets are kept in memory only, and cached for `cache_ttl_secs` so that reloading Vector
+						doesn't retrieve them again until they expire.
+						"""
+					type: object: options: {
+						endpoint: {
+							description: "The address of the Vault server."
+							required:    true
+							type: string: examples: ["https://vault.example.com:8200"]
+						}
+						namespace: {
+							description: "The Vault Enterprise namespace the secrets are in."
+							required:    false
+							common:      false
+							type: string: examples: ["team-a"]
+						}
+						mount: {
+							description: "The path the KV secrets engine holding the secrets is mounted at."
+							required:    false
+							common:      false
+							type: string: default: "secret"
+						}
+						kv_version: {
+							description: "The version of the KV secrets engine."
+							required:    false
+							common:      false
+							type: string: {
+								default: "v2"
+								enum: {
+									v1: "Version 1, whose secrets aren't versioned."
+									v2: "Version 2, whose secrets are versioned. The latest version of each secret is retrieved."
+								}
+							}
+						}
+						auth: {
+							description: """
+								How to authenticate to Vault. The tokens obtained by logging in are renewed for as long as
+								Vector runs, and Vector logs in again when it can't renew them anymore.
+								"""
+							required: true
+							type: object: options: {
+								strategy: {
+									description: "The authentication strategy to use."
+									required:    true
+									type: string: enum: {
+										token:      "Authenticate with a token, which is renewed if it's renewable."
+										approle:    "Log in with the AppRole method."
+										kubernetes: "Log in with the Kubernetes method, with the token of the service account Vector runs as."
+									}
+								}
+								token: {
+									description:   "The Vault token."
+									required:      true
+									relevant_when: "strategy = \"token\""
+									type: string: examples: ["${VAULT_TOKEN}"]
+								}
+								role_id: {
+									description:   "The ID of the role."
+									required:      true
+									relevant_when: "strategy = \"approle\""
+									type: string: examples: ["vector"]
+								}
+								secret_id: {
+									description:   "The secret ID of the role."
+									required:      true
+									relevant_when: "strategy = \"approle\""
+									type: string: examples: ["${VAULT_SECRET_ID}"]
+								}
+								role: {
+									description:   "The role to log in as."
+									required:      true
+									relevant_when: "strategy = \"kubernetes\""
+									type: string: examples: ["vector"]
+								}
+								token_path: {
+									description:   "The path of the token of the service account."
+									required:      false
+									relevant_when: "strategy = \"kubernetes\""
+									type: string: default: "/var/run/secrets/kubernetes.io/serviceaccount/token"
+								}
+								mount: {
+									description:   "The path the authentication method is mounted at."
+									required:      false
+									relevant_when: "strategy = \"approle\" or strategy = \"kubernetes\""
+									type: string: examples: ["approle", "kubernetes"]
+								}
+							}
+						}
+						cache_ttl_secs: {
+							description: """
+								How long to cache each secret for. Secrets whose lease is shorter expire sooner. Set to `0`
+								to disable the cache.
+								"""
+							required: false
+							common:   false
+							type: uint: {
+								default: 300
+								unit:    "seconds"
+							}
+						}
+						timeout: {
+							description: "The amount of time Vector will wait for the secrets to be retrieved."
+							required:    false
+							common:      false
+							type: uint: {
+								default: 5
+								unit:    "seconds"
+							}
+						}
+					}
+				}
+			}
+		}
+
+		provider: {
+			common: false
+			description: """
+				Loads the configuration from a remote provider instead of the configuration files, and
+				reloads Vector when it changes. The configuration files then only contain the `provider`
+				table. See [remote configuration](#remote-configuration) for details.
+				"""
+			required: false
+			type: object: options: {
+				type: {
+					description: "The type of the provider."
+					required:    true
+					type: string: enum: {
+						aws_s3:            "Fetch the configuration from AWS S3."
+						gcp_cloud_storage: "Fetch the configuration from GCP Cloud Storage."
+						http:              "Fetch the configuration from an HTTP endpoint."
+						kubernetes:        "Watch the configuration in a Kubernetes ConfigMap or `VectorPipeline` custom resource."
+					}
+				}
+				uri: {
+					description: """
+						The URI of the configuration file. If it ends with a `/`, all the configuration files
+						under that prefix are loaded and merged, in the lexicographical order of their keys,
+						with the format of each file determined by its extension.
+						"""
+					relevant_when: "type = \"aws_s3\" or type = \"gcp_cloud_storage\""
+					required:      true
+					type: string: examples: ["s3://my-bucket/vector/vector.toml", "gs://my-bucket/vector/"]
+				}
+				url: {
+					description:   "The URL to fetch the configuration from, in the TOML format."
+					relevant_when: "type = \"http\""
+					required:      true
+					type: string: examples: ["https://config.example.com/vector.toml"]
+				}
+				verify_checksum: {
+					common: false
+					description: """
+						Whether to verify the checksum of each configuration file before loading it. The
+						checksum is read from an object named after the file with a `.sha256` suffix, such as
+						`vector.toml.sha256`, containing the hex-encoded SHA-256 digest of the file, as written
+						by `sha256sum`.
+						"""
+					relevant_when: "type = \"aws_s3\" or type = \"gcp_cloud_storage\""
+					required:      false
+					type: bool: default: false
+				}
+				poll_interval_secs: {
+					common:      false
+					description: "How often to poll the provider."
+					required:    false
+					type: uint: {
+						default: 30
+						unit:    "seconds"
+					}
+				}
+			}
+		}
+
+		state_dirs: {
+			common: false
+			description: """
+				Per-subsystem overrides of `data_dir`, allowing Vector's state to be split across
+				multiple directories. Each subsystem uses `data_dir` unless overridden here.
+
+				Vector only ever writes to `data_dir`, these directories, and paths explicitly configured
+				on components, so it can run with a read-only root filesystem as long as they are on a
+				writable volume. Every configured directory, and the buffer directory whenever a disk
+				buffer is in use, is checked at startup, and Vector refuses to start if any of them does
+				not exist or is not writable.
+				"""
+			required: false
+			type: object: options: {
+				buffers: {
+					description: "The directory used for disk buffers."
+					required:    false
+					type: string: examples: ["/mnt/buffers"]
+				}
+				checkpoints: {
+					description: """
+						The directory used for source checkpoints, such as those of the `file`,
+						`journald`, and `kubernetes_logs` sources.
+						"""
+					required: false
+					type: string: examples: ["/var/lib/vector/checkpoints"]
+				}
+				enrichment_cache: {
+					description: """
+						The directory used for cached enrichment table data, such as downloaded GeoIP
+						databases.
+						"""
+					required: false
+					type: string: examples: ["/var/cache/vector/enrichment"]
+				}
 			}
 		}
 
@@ -494,6 +1053,49 @@ configuration: {
 				}
 			}
 		}
+
+		watchdog: {
+			common: false
+			description: """
+				Configures the watchdog detecting stalled components.
+
+				A component is stalled when its task blocks a thread in a single poll, or is not polled
+				after being woken up, for longer than the stall timeout. Components that are idle because
+				they are waiting for events are never considered stalled. Each stall is reported once with
+				an error log and the `component_stalls_total` internal metric, along with the kernel stack
+				of the blocked thread when it can be read.
+
+				The stack is only the kernel stack read from `/proc/<tid>/stack` on Linux, without the
+				frames of Vector itself. Reading it requires elevated privileges, without which only the
+				kernel function and the system call the thread is blocked in are reported.
+				"""
+			required: false
+			type: object: options: {
+				stall_timeout_secs: {
+					common:      false
+					description: "How long a component can stall before it's reported. The watchdog is disabled if unset."
+					required:    false
+					type: uint: {
+						default: null
+						examples: [60]
+						unit: "seconds"
+					}
+				}
+				restart: {
+					common: false
+					description: """
+						Whether to rebuild the stalled components in place.
+
+						The task of a stalled component is aborted before the component is rebuilt, so the
+						events it holds in memory are lost. Aborting a task can't interrupt a poll it is
+						blocked in. The component is only rebuilt, and the restart reported, once that poll
+						returns, so a component blocked forever is never restarted.
+						"""
+					required: false
+					type: bool: default: false
+				}
+			}
+		}
 	}
 
 	how_it_works: {
@@ -554,8 +1156,9 @@ configuration: {
 				sensitive token are configured in a dedicated section (`secret`). In the rest of the configuration you should use
 				the `SECRET[<backend_name>.<secret_key>]` notation to interpolate the secret. Interpolation will happen immediately after
 				environment variables interpolation. While Vector supports multiple commands to retrieve secrets, a
-				secret backend cannot use the secret interpolation feature for its own configuration. Currently the only supported
-				kind of secret backend is the `exec` one that runs an external command to retrieve secrets.
+				secret backend cannot use the secret interpolation feature for its own configuration. The supported kinds of secret
+				backends are the `exec` one that runs an external command to retrieve secrets, and the `vault` one that retrieves
+				them from HashiCorp Vault.
 
 				The following example shows a simple configuration with two backends defined:
 
@@ -616,6 +1219,34 @@ configuration: {
 						Currently Vector will always query backend with `"version": "1.0"`.
 						"""
 				},
+				{
+					title: "The `vault` backend"
+					body:  """
+						When using the `vault` type for a secret backend, Vector logs in to Vault and reads the secrets
+						from its KV secrets engine. Each secret key names a field of a secret, after the path of the
+						secret with its segments separated by dots:
+
+						```toml title="vector.toml"
+						[secret.vault]
+						type = "vault"
+						endpoint = "https://vault.example.com:8200"
+						auth.strategy = "approle"
+						auth.role_id = "vector"
+						auth.secret_id = "${VAULT_SECRET_ID}"
+
+						[sinks.dd_logs]
+						type = "datadog_logs"
+						default_api_key = "SECRET[vault.observability.datadog.api_key]"
+						```
+
+						In that example Vector will retrieve the `api_key` field of the `observability/datadog` secret.
+
+						The fields of each secret are retrieved with a single request, and cached in memory for
+						`cache_ttl_secs`, so that reloading Vector doesn't query Vault again until they expire. The token
+						obtained by logging in is renewed in the background for as long as Vector runs, and Vector logs
+						in again when it expires.
+						"""
+				},
 			]
 		}
 		formats: {
@@ -676,6 +1307,111 @@ configuration: {
 				```
 				"""
 		}
+		remote_configuration: {
+			title: "Remote configuration"
+			body: """
+				Instead of distributing configuration files to each host, Vector can fetch its configuration
+				from a central location with a `provider`:
+
+				```toml
+				[provider]
+				type = "aws_s3"
+				uri = "s3://my-bucket/vector/"
+				region = "us-east-1"
+				verify_checksum = true
+				```
+
+				The `aws_s3` and `gcp_cloud_storage` providers poll the configuration every
+				`poll_interval_secs`, and reload Vector when any of the files changes. If the new
+				configuration can't be fetched, fails its checksum verification, or is invalid, the error is
+				logged and the running configuration is kept.
+
+				They authenticate like the `aws_s3` and `gcp_cloud_storage` sinks, with the `auth` options for
+				AWS, and the `credentials_path` or `api_key` options for GCP.
+
+				In Kubernetes, the `kubernetes` provider watches a ConfigMap, or a `VectorPipeline` custom
+				resource, and reloads Vector as soon as it changes, so pipelines managed with GitOps are
+				applied without restarting the pods or running a reloader sidecar:
+
+				```toml
+				[provider]
+				type = "kubernetes"
+				kind = "vector_pipeline"
+				name = "pipelines"
+				```
+
+				The spec of a `VectorPipeline` is a Vector configuration:
+
+				```yaml
+				apiVersion: vector.dev/v1alpha1
+				kind: VectorPipeline
+				metadata:
+				  name: pipelines
+				spec:
+				  sources:
+				    in:
+				      type: http_server
+				      address: 0.0.0.0:8080
+				  sinks:
+				    out:
+				      type: console
+				      inputs: [in]
+				      encoding:
+				        codec: json
+				```
+
+				Its custom resource definition is `vectorpipelines.vector.dev`, with a namespaced
+				`v1alpha1` version whose schema preserves the unknown fields of the spec. The service
+				account of Vector needs the `get`, `list`, and `watch` permissions on the watched
+				resource. Changes to the metadata of the object are ignored, and if it's deleted, the
+				running configuration is kept.
+				"""
+		}
+		pipeline_templates: {
+			title: "Pipeline templates"
+			body: """
+				Groups of transforms that only differ by a few values, such as per-team parsing pipelines
+				writing to their own index, can be defined once as a template under `templates`, and
+				instantiated with different parameters under `pipelines`:
+
+				```toml
+				[templates.team_parsing]
+				params.retention = "30d" # default value
+
+				[templates.team_parsing.transforms.parse]
+				type = "remap"
+				source = '''
+				. = parse_json!(.message)
+				.index = "{{ params.index }}"
+				.retention = "{{ params.retention }}"
+				'''
+
+				[templates.team_parsing.transforms.drop_debug]
+				type = "filter"
+				inputs = ["parse"]
+				condition = '.level != "debug"'
+
+				[pipelines.team_a]
+				template = "team_parsing"
+				inputs = ["kafka_team_a"]
+				params.index = "team-a"
+
+				[pipelines.team_b]
+				template = "team_parsing"
+				inputs = ["kafka_team_b"]
+				params = { index = "team-b", retention = "7d" }
+
+				[sinks.elasticsearch]
+				type = "elasticsearch"
+				inputs = ["*.drop_debug"]
+				```
+
+				The pipelines are expanded when the configuration is loaded, before the topology is built,
+				into the transforms `team_a.parse`, `team_a.drop_debug`, `team_b.parse` and
+				`team_b.drop_debug`. References to undefined parameters are reported as errors. Other
+				templates, such as `{{ message }}`, are left as they are.
+				"""
+		}
 		wildcards: {
 			title: "Wildcards in component names"
 			body: """
@@ -0,0 +1,30 @@
+package metadata
+
+remap: functions: feature_enabled: {
+	category: "System"
+	description: """
+		Returns whether the feature flag specified by `name` is enabled, as last polled from the
+		provider configured with `feature_flags`. Flags unknown to the provider are disabled.
+		"""
+
+	arguments: [
+		{
+			name:        "name"
+			description: "The name of the feature flag."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Check whether a feature flag is enabled"
+			source: #"""
+				feature_enabled("new_parser")
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,15 @@
+package metadata
+
+services: cloudflare: {
+	name:     "Cloudflare"
+	thing:    "\(name) Logpush jobs"
+	url:      urls.cloudflare_logpush
+	versions: null
+
+	description: """
+		[Cloudflare](\(urls.cloudflare)) is a content delivery network and security
+		platform. Its [Logpush](\(urls.cloudflare_logpush)) service pushes the logs of
+		zones and accounts, such as HTTP requests and firewall events, to a destination
+		in batches.
+		"""
+}
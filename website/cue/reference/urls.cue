@@ -3,7 +3,9 @@ package metadata
 urls: {
 	appsignal:                                  "https://www.appsignal.com/"
 	azure_blob_storage:                         "https://azure.microsoft.com/en-us/services/storage/blobs/"
+	azure_blob_storage_append_blobs:            "https://learn.microsoft.com/en-us/rest/api/storageservices/understanding-block-blobs--append-blobs--and-page-blobs#about-append-blobs"
 	azure_event_hubs:                           "https://learn.microsoft.com/en-us/azure/event-hubs/"
+	azure_event_hubs_amqp:                      "https://learn.microsoft.com/en-us/azure/service-bus-messaging/service-bus-amqp-overview"
 	azure_event_hubs_kafka:                     "https://learn.microsoft.com/en-us/azure/event-hubs/event-hubs-for-kafka-ecosystem-overview"
 	azure_event_hubs_connection_string:         "https://learn.microsoft.com/en-us/azure/event-hubs/event-hubs-get-connection-string"
 	azure_event_hubs_tiers:                     "https://learn.microsoft.com/en-us/azure/event-hubs/compare-tiers"
@@ -54,6 +56,7 @@ urls: {
 	aws_elb_access_format:                      "\(aws_docs)/elasticloadbalancing/latest/application/load-balancer-access-logs.html#access-log-entry-examples"
 	aws_elb_https:                              "\(aws_docs)/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html"
 	aws_iam:                                    "\(aws_docs)/IAM/latest/UserGuide/introduction.html"
+	aws_iam_external_id:                        "\(aws_docs)/IAM/latest/UserGuide/id_roles_create_for-user_externalid.html"
 	aws_iam_role:                               "\(aws_docs)/IAM/latest/UserGuide/id_roles.html"
 	aws_imds_v1_security_problems:              "https://aws.amazon.com/blogs/security/defense-in-depth-open-firewalls-reverse-proxies-ssrf-vulnerabilities-ec2-instance-metadata-service/"
 	aws_kinesis_firehose:                       "https://aws.amazon.com/kinesis/data-firehose/"
@@ -67,6 +70,7 @@ urls: {
 	aws_kinesis_streams_api:                    "\(aws_docs)/kinesis/latest/APIReference/API_PutRecords.html"
 	aws_kinesis_streams_service_limits:         "\(aws_docs)/streams/latest/dev/service-sizes-and-limits.html"
 	aws_kinesis_split_shards:                   "\(aws_docs)/streams/latest/dev/kinesis-using-sdk-java-resharding-split.html"
+	aws_opensearch_serverless:                  "\(aws_docs)/opensearch-service/latest/developerguide/serverless.html"
 	aws_prometheus:                             "\(aws_docs)/prometheus"
 	aws_regions:                                "\(aws_docs)/AmazonRDS/latest/UserGuide/Concepts.RegionsAndAvailabilityZones.html"
 	aws_s3:                                     "https://aws.amazon.com/s3/"
@@ -108,7 +112,12 @@ urls: {
 	chrono_time_formats:                        "https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers"
 	cgroups_limit_resources:                    "https://the.binbashtheory.com/control-resources-cgroups/"
 	clickhouse:                                 "https://clickhouse.com/"
+	clickhouse_async_insert:                    "https://clickhouse.com/docs/en/optimize/asynchronous-inserts"
 	clickhouse_http:                            "https://clickhouse.com/docs/en/interfaces/http/"
+	clickhouse_native:                          "https://clickhouse.com/docs/en/interfaces/tcp"
+	cloudflare:                                 "https://www.cloudflare.com/"
+	cloudflare_logpush:                         "https://developers.cloudflare.com/logs/about/"
+	cloudflare_logpush_http:                    "https://developers.cloudflare.com/logs/get-started/enable-destinations/http/"
 	cloudsmith:                                 "https://cloudsmith.io/~timber/repos/vector/packages/"
 	cloudsmith_apt:                             "https://cloudsmith.io/~timber/repos/vector/setup/#formats-deb"
 	cloudsmith_yum:                             "https://cloudsmith.io/~timber/repos/vector/setup/#formats-rpm"
@@ -209,6 +218,7 @@ urls: {
 	gcp_authentication_service_account:         "\(gcp)/docs/authentication/production#obtaining_and_providing_service_account_credentials_manually"
 	gcp_cloud_storage:                          "\(gcp)/storage"
 	gcp_chronicle:                              "https://chronicle.security"
+	gcp_chronicle_udm:                          "https://cloud.google.com/chronicle/docs/unified-data-model/udm-overview"
 	gcp_folders:                                "\(gcp)/resource-manager/docs/creating-managing-folders"
 	gcp_pubsub:                                 "\(gcp)/pubsub/"
 	gcp_pubsub_rest:                            "\(gcp)/pubsub/docs/reference/rest/"
@@ -279,7 +289,9 @@ urls: {
 	issue_1694:                                 "\(vector_repo)/issues/1694"
 	journalctl:                                 "https://www.freedesktop.org/software/systemd/man/journalctl.html"
 	journald:                                   "https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html"
+	journald_gatewayd:                          "https://www.freedesktop.org/software/systemd/man/systemd-journal-gatewayd.service.html"
 	json:                                       "\(wikipedia)/wiki/JSON"
+	json_schema:                                "https://json-schema.org"
 	json_types:                                 "\(wikipedia)/wiki/JSON#Data_types_and_syntax"
 	jsonnet:                                    "https://jsonnet.org/"
 	kafka:                                      "https://kafka.apache.org/"
@@ -307,7 +319,10 @@ urls: {
 	leveldb_sys_3:                              "\(github)/vectordotdev/leveldb-sys/tree/v3.0.0"
 	librdkafka:                                 "\(github)/edenhill/librdkafka"
 	librdkafka_config:                          "\(github)/edenhill/librdkafka/blob/master/CONFIGURATION.md"
+	linux_audit:                                "https://man7.org/linux/man-pages/man8/auditd.8.html"
 	linux_capability:                           "https://man7.org/linux/man-pages/man7/capabilities.7.html"
+	memcached:                                  "https://memcached.org"
+	memcached_stats:                            "\(github)/memcached/memcached/blob/master/doc/protocol.txt"
 	mezmo:                                      "https://mezmo.com/"
 	logfmt:                                     "https://brandur.org/logfmt"
 	logfmt_specs:                               "https://pkg.go.dev/github.com/kr/logfmt#section-documentation"
@@ -315,6 +330,7 @@ urls: {
 	logstash_protocol:                          "https://github.com/elastic/logstash-forwarder/blob/master/PROTOCOL.md"
 	loki:                                       "https://grafana.com/oss/loki/"
 	loki_multi_tenancy:                         "\(github)/grafana/loki/blob/master/docs/operations/multi-tenancy.md"
+	loki_structured_metadata:                   "https://grafana.com/docs/loki/latest/get-started/labels/structured-metadata/"
 	log_event_source:                           "\(vector_repo)/blob/master/src/event/"
 	logplex:                                    "https://devcenter.heroku.com/articles/logplex"
 	logplex_protocol:                           "\(github)/heroku/logplex/blob/master/doc/README.http_drains.md"
@@ -355,6 +371,9 @@ urls: {
 	mongodb:                                    "https://www.mongodb.com"
 	mongodb_command_server_status:              "https://docs.mongodb.com/manual/reference/command/serverStatus/"
 	mongodb_connection_string_uri_format:       "https://docs.mongodb.com/manual/reference/connection-string/"
+	mqtt:                                       "https://mqtt.org/"
+	mqtt_qos:                                   "https://docs.oasis-open.org/mqtt/mqtt/v3.1.1/os/mqtt-v3.1.1-os.html#_Toc398718099"
+	murmurhash:                                 "https://github.com/aappleby/smhasher/wiki/MurmurHash3"
 	musl_builder_docker_image:                  "\(vector_repo)/blob/master/scripts/ci-docker-images/builder-x86_64-unknown-linux-musl/Dockerfile"
 	native_proto_schema:                        "\(vector_repo)/blob/master/lib/vector-core/proto/event.proto"
 	native_json_schema:                         "\(vector_repo)/blob/master/lib/codecs/tests/data/native_encoding/schema.cue"
@@ -448,7 +467,9 @@ urls: {
 	rust_tokio:                                 "\(github)/tokio-rs/tokio"
 	rustup:                                     "https://rustup.rs"
 	redis:                                      "https://redis.io"
+	redis_info:                                 "https://redis.io/commands/info/"
 	redis_rs:                                   "https://github.com/mitsuhiko/redis-rs"
+	redis_slowlog:                              "https://redis.io/commands/slowlog-get/"
 	seahash:                                    "https://docs.rs/seahash/latest/seahash/"
 	sematext:                                   "https://sematext.com"
 	sematext_create_logs_app:                   "https://apps.sematext.com/ui/integrations"
@@ -463,6 +484,9 @@ urls: {
 	signal:                                     "\(wikipedia)/wiki/Signal_(IPC)"
 	snake_case:                                 "\(wikipedia)/wiki/Snake_case"
 	snappy:                                     "https://google.github.io/snappy/"
+	snowflake:                                  "https://www.snowflake.com"
+	snowflake_key_pair_auth:                    "https://docs.snowflake.com/en/user-guide/key-pair-auth"
+	snowpipe_streaming:                         "https://docs.snowflake.com/en/user-guide/data-load-snowpipe-streaming-overview"
 	socket:                                     "\(wikipedia)/wiki/Network_socket"
 	splunk:                                     "https://www.splunk.com"
 	splunk_hec:                                 "https://dev.splunk.com/enterprise/docs/dataapps/httpeventcollector/"
@@ -475,6 +499,7 @@ urls: {
 	splunk_hec_setup:                           "https://docs.splunk.com/Documentation/Splunk/latest/Data/UsetheHTTPEventCollector"
 	splunk_hec_metadata:                        "https://docs.splunk.com/Documentation/Splunk/latest/Data/FormateventsforHTTPEventCollector#Event_metadata"
 	specs_instrumentation:                      "\(vector_repo)/blob/master/docs/specs/instrumentation.md)"
+	sqlite:                                     "https://www.sqlite.org/"
 	standard_streams:                           "\(wikipedia)/wiki/Standard_streams"
 	statsd:                                     "\(github)/statsd/statsd"
 	statsd_multi:                               "\(github)/statsd/statsd/blob/master/docs/metric_types.md#multi-metric-packets"
@@ -518,6 +543,7 @@ urls: {
 	url:                                        "\(wikipedia)/wiki/URL"
 	us_social_security_number:                  "https://www.ssa.gov/history/ssn/geocard.html"
 	user_agent:                                 "https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent"
+	vault:                                      "https://developer.hashicorp.com/vault"
 	vector_agent_role:                          "/docs/setup/deployment/roles/#agent"
 	vector_aggregator_role:                     "/docs/setup/deployment/roles/#aggregator"
 	vector_aws_s3_sink:                         "/docs/reference/configuration/sinks/aws_s3/"